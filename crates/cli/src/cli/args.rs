@@ -335,6 +335,7 @@ impl ValueEnum for OutputFormatArg {
             Self(OutputFormat::Human),
             Self(OutputFormat::Json),
             Self(OutputFormat::JsonCompact),
+            Self(OutputFormat::Ndjson),
             Self(OutputFormat::Quiet),
         ]
     }
@@ -348,6 +349,8 @@ impl ValueEnum for OutputFormatArg {
             }
             OutputFormat::JsonCompact => clap::builder::PossibleValue::new("json-compact")
                 .help("Compact JSON output (single line)"),
+            OutputFormat::Ndjson => clap::builder::PossibleValue::new("ndjson")
+                .help("Newline-delimited JSON, streamed record by record"),
             OutputFormat::Quiet => {
                 clap::builder::PossibleValue::new("quiet").help("Minimal output")
             }
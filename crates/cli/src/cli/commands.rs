@@ -108,6 +108,55 @@ pub enum Commands {
     /// Clones a Git repository and automatically initializes or validates
     /// workspace configuration for immediate development.
     Clone(CloneArgs),
+
+    /// Manage git hooks declared in configuration.
+    ///
+    /// Installs, inspects, and removes the hooks configured in the
+    /// `hooks` section, detecting hooks that drifted from configuration.
+    #[command(subcommand)]
+    Hooks(HooksCommands),
+
+    /// Show a live workspace dashboard.
+    ///
+    /// Displays packages, pending changesets, recent releases, outdated
+    /// dependencies and graph metrics, refreshing periodically. Keybindings
+    /// trigger common actions such as adding a changeset or previewing a bump.
+    Monitor(MonitorArgs),
+
+    /// Manage workspace backups.
+    ///
+    /// Lists, restores, and prunes the backups taken before upgrades and
+    /// other file-modifying operations.
+    #[command(subcommand)]
+    Backup(BackupCommands),
+
+    /// Revert a recent workspace operation.
+    ///
+    /// Lists operations recorded in the transaction log (version bumps,
+    /// upgrades, and other file-modifying commands) and restores the files
+    /// of a selected one.
+    Undo(UndoArgs),
+
+    /// Explain why a package is in the dependency tree.
+    ///
+    /// Lists the workspace packages that declare the package, with their
+    /// specs, and the dependency chains leading to it. Works for workspace
+    /// packages and external dependencies.
+    Why(WhyArgs),
+
+    /// Create missing tags for already-released versions.
+    ///
+    /// Finds the commit that set each version in a package's package.json
+    /// and tags it with the configured tag format, so baseline detection
+    /// works on repositories whose past releases were never tagged.
+    Tag(TagArgs),
+
+    /// Generate import aliases for workspace packages.
+    ///
+    /// Writes tsconfig `paths` (and optionally package.json `imports`)
+    /// for the internal dependencies of the workspace, or checks that
+    /// they match the dependencies with `--check`.
+    Aliases(AliasesArgs),
 }
 
 // ============================================================================
@@ -165,6 +214,20 @@ pub struct InitArgs {
     #[arg(long = "config-format", value_name = "FORMAT")]
     pub config_format: Option<String>,
 
+    /// Project archetype template.
+    ///
+    /// A built-in template (library-monorepo, app-monorepo, single-package,
+    /// design-system) or a path to a template file. Flags take precedence
+    /// over the template's settings.
+    #[arg(long, value_name = "NAME|PATH")]
+    pub template: Option<String>,
+
+    /// Write a built-in template to `<NAME>.template.toml` for customizing.
+    ///
+    /// No configuration is generated. Pass the file back to `--template`.
+    #[arg(long, value_name = "NAME", conflicts_with = "template")]
+    pub eject_template: Option<String>,
+
     /// Overwrite existing configuration.
     ///
     /// Forces initialization even if a config file already exists.
@@ -215,7 +278,12 @@ pub struct ConfigShowArgs {
 /// Arguments for the `config validate` command.
 #[derive(Debug, Args)]
 pub struct ConfigValidateArgs {
-    // No additional args beyond global options
+    /// Print the documentation of a configuration key instead of validating.
+    ///
+    /// Accepts a key such as `version.default_bump` (the `package_tools.` prefix is optional)
+    /// or a section such as `changelog` to list all of its keys.
+    #[arg(long, value_name = "KEY")]
+    pub explain: Option<String>,
 }
 
 // ============================================================================
@@ -275,6 +343,45 @@ pub enum ChangesetCommands {
     /// Checks if a changeset exists for the current or specified branch.
     /// Useful for Git hooks.
     Check(ChangesetCheckArgs),
+
+    /// Split packages out of a changeset.
+    ///
+    /// Moves the given packages into a new changeset with the same bump,
+    /// environments, and commits.
+    Split(ChangesetSplitArgs),
+
+    /// Merge changesets into one.
+    ///
+    /// Combines packages, environments, and commits, keeping the largest bump.
+    Merge(ChangesetMergeArgs),
+
+    /// Promote a changeset into an environment.
+    ///
+    /// Records the promotion on a pending or released changeset, following the
+    /// configured promotion order. Released changesets can also be tagged for
+    /// the environment or have their npm dist-tags moved.
+    Promote(ChangesetPromoteArgs),
+
+    /// Copy changesets to another storage backend or location.
+    ///
+    /// Copies pending and archived changesets, verifying each copy. Re-running
+    /// an interrupted migration skips changesets that were already copied.
+    Migrate(ChangesetMigrateArgs),
+
+    /// Clean up packages that are no longer in the workspace.
+    ///
+    /// Renames packages mapped in `changeset.package_renames`. Interactive mode
+    /// asks what each remaining package became; otherwise `--prune` removes them.
+    /// Changesets left without packages are deleted.
+    Clean(ChangesetCleanArgs),
+
+    /// Git merge driver for changeset files.
+    ///
+    /// Merges two edits of a changeset file against their ancestor and writes
+    /// the result over the current version. Register it with
+    /// `git config merge.workspace-changeset.driver "workspace changeset merge-driver %O %A %B"`
+    /// and `.changesets/*.json merge=workspace-changeset` in `.gitattributes`.
+    MergeDriver(ChangesetMergeDriverArgs),
 }
 
 /// Arguments for the `changeset create` command.
@@ -315,6 +422,35 @@ pub struct ChangesetCreateArgs {
     /// Uses provided flags without prompting.
     #[arg(long)]
     pub non_interactive: bool,
+
+    /// Read the changeset document (JSON or YAML) from stdin.
+    ///
+    /// The document holds `bump`, `packages`, and optionally `branch`,
+    /// `environments`, and `message`. Flags given alongside it take precedence.
+    /// Implies non-interactive mode.
+    #[arg(long, conflicts_with = "from_file")]
+    pub stdin: bool,
+
+    /// Read the changeset document (JSON or YAML) from a file.
+    ///
+    /// Same document as `--stdin`.
+    #[arg(long, value_name = "PATH")]
+    pub from_file: Option<PathBuf>,
+
+    /// Propose packages and bump type from the branch's diff.
+    ///
+    /// Pre-selects the packages changed since the merge-base with the base
+    /// branch and proposes the bump their conventional commits call for:
+    /// breaking changes are major, `feat` is minor, anything else is patch.
+    /// Prompts still allow adjusting both.
+    #[arg(long, conflicts_with_all = ["stdin", "from_file"])]
+    pub from_diff: bool,
+
+    /// Base branch for `--from-diff`.
+    ///
+    /// Defaults to `main`, or `master` if there is no `main`.
+    #[arg(long, value_name = "REF", requires = "from_diff")]
+    pub base: Option<String>,
 }
 
 /// Arguments for the `changeset update` command.
@@ -368,18 +504,49 @@ pub struct ChangesetListArgs {
     #[arg(long, value_name = "ENV")]
     pub filter_env: Option<String>,
 
+    /// Filter by commit author.
+    ///
+    /// Matches changesets containing a commit whose author name or email
+    /// contains the given text (case-insensitive).
+    #[arg(long, value_name = "AUTHOR")]
+    pub filter_author: Option<String>,
+
+    /// Only show changesets created within this age.
+    ///
+    /// Format: a number followed by m, h, d, or w (e.g., 12h, 7d, 2w).
+    #[arg(long, value_name = "AGE")]
+    pub newer_than: Option<String>,
+
+    /// Only show changesets created at least this long ago.
+    ///
+    /// Format: a number followed by m, h, d, or w (e.g., 12h, 7d, 2w).
+    #[arg(long, value_name = "AGE")]
+    pub older_than: Option<String>,
+
     /// Sort by field.
     ///
-    /// Options: date, bump, branch
+    /// Options: date, created, bump, branch
     #[arg(long, value_name = "FIELD", default_value = "date")]
     pub sort: String,
+
+    /// Reverse the sort order.
+    #[arg(long)]
+    pub reverse: bool,
+
+    /// Maximum number of changesets to show.
+    #[arg(long, value_name = "N")]
+    pub limit: Option<usize>,
+
+    /// Number of matching changesets to skip.
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub offset: usize,
 }
 
 /// Arguments for the `changeset show` command.
 #[derive(Debug, Args)]
 pub struct ChangesetShowArgs {
-    /// Branch name or changeset ID.
-    #[arg(value_name = "BRANCH")]
+    /// Branch name, changeset id, or generated name (e.g. `brave-lions-smile`).
+    #[arg(value_name = "CHANGESET")]
     pub branch: String,
 }
 
@@ -449,6 +616,120 @@ pub struct ChangesetCheckArgs {
     pub branch: Option<String>,
 }
 
+/// Arguments for the `changeset split` command.
+#[derive(Debug, Args)]
+pub struct ChangesetSplitArgs {
+    /// Branch name of the changeset to split.
+    #[arg(value_name = "BRANCH")]
+    pub branch: String,
+
+    /// Comma-separated list of packages to move.
+    #[arg(long, value_name = "LIST", value_delimiter = ',', required = true)]
+    pub packages: Vec<String>,
+
+    /// Branch name of the new changeset.
+    #[arg(long, value_name = "NAME")]
+    pub into: String,
+}
+
+/// Arguments for the `changeset merge` command.
+#[derive(Debug, Args)]
+pub struct ChangesetMergeArgs {
+    /// Branch names of the changesets to merge.
+    #[arg(value_name = "BRANCH", num_args = 2.., required = true)]
+    pub branches: Vec<String>,
+
+    /// Branch name of the merged changeset.
+    ///
+    /// May name one of the merged changesets.
+    #[arg(long, value_name = "NAME")]
+    pub into: String,
+}
+
+/// Arguments for the `changeset promote` command.
+#[derive(Debug, Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ChangesetPromoteArgs {
+    /// Id, name, or branch of the changeset.
+    #[arg(value_name = "CHANGESET")]
+    pub reference: String,
+
+    /// Environment to promote the changeset into.
+    #[arg(long, value_name = "ENV")]
+    pub to: String,
+
+    /// Tag the released versions for the environment.
+    ///
+    /// Creates `<ENV>/<release tag>` at the release commit. Only released
+    /// changesets can be tagged.
+    #[arg(long)]
+    pub retag: bool,
+
+    /// Point the npm dist-tag named after the environment at the released versions.
+    ///
+    /// Runs `npm dist-tag add`. Only released changesets have versions to tag.
+    #[arg(long)]
+    pub dist_tag: bool,
+
+    /// Push the environment tags to the remote.
+    #[arg(long, requires = "retag")]
+    pub push: bool,
+
+    /// Check the promotion without recording it or re-releasing.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the `changeset migrate` command.
+#[derive(Debug, Args)]
+pub struct ChangesetMigrateArgs {
+    /// Storage backend to copy the changesets into.
+    ///
+    /// Options: file
+    #[arg(long, value_name = "BACKEND", default_value = "file")]
+    pub to: String,
+
+    /// Location of pending changesets in the target backend.
+    ///
+    /// For the file backend, a directory relative to the workspace root.
+    #[arg(long, value_name = "PATH")]
+    pub path: String,
+
+    /// Location of archived changesets in the target backend.
+    ///
+    /// Defaults to `<PATH>/history`.
+    #[arg(long, value_name = "PATH")]
+    pub history_path: Option<String>,
+}
+
+/// Arguments for the `changeset clean` command.
+#[derive(Debug, Args)]
+pub struct ChangesetCleanArgs {
+    /// Remove packages that have no rename instead of asking or keeping them.
+    #[arg(long)]
+    pub prune: bool,
+
+    /// List the stale packages without modifying any changeset.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+/// Arguments for the `changeset merge-driver` command.
+#[derive(Debug, Args)]
+pub struct ChangesetMergeDriverArgs {
+    /// Common ancestor version of the file (`%O`).
+    #[arg(value_name = "BASE")]
+    pub base: PathBuf,
+
+    /// Current branch's version of the file (`%A`), overwritten with the result.
+    #[arg(value_name = "OURS")]
+    pub ours: PathBuf,
+
+    /// Other branch's version of the file (`%B`).
+    #[arg(value_name = "THEIRS")]
+    pub theirs: PathBuf,
+}
+
 // ============================================================================
 // Bump Command
 // ============================================================================
@@ -504,6 +785,15 @@ pub struct BumpArgs {
     #[arg(long, value_name = "LIST", value_delimiter = ',')]
     pub packages: Option<Vec<String>>,
 
+    /// Release only the packages matched by a filter expression.
+    ///
+    /// Comma-separated package names or globs; prefix a term with `!` to
+    /// exclude it (e.g. "@myorg/*,!@myorg/docs"). Changesets listing other
+    /// packages are split: the matched packages are released and archived,
+    /// the rest stay pending. Requires the independent strategy.
+    #[arg(long, value_name = "EXPR")]
+    pub filter: Option<String>,
+
     /// Create Git tags for releases.
     ///
     /// Tags are created in the format: package@version
@@ -546,6 +836,58 @@ pub struct BumpArgs {
     /// and deletions. Only applies to preview mode.
     #[arg(long)]
     pub show_diff: bool,
+
+    /// Publish the release plan to the GitHub Actions job summary.
+    ///
+    /// Appends a Markdown table of the packages to release to the file named
+    /// by GITHUB_STEP_SUMMARY and emits a notice annotation such as
+    /// "This PR will release core@2.1.0, utils@1.4.2". Only applies to
+    /// preview mode.
+    #[arg(long)]
+    pub github_summary: bool,
+
+    /// Check that the released packages can be published before bumping.
+    ///
+    /// Validates each non-private package's `publishConfig` registry and
+    /// access, required fields (license, repository), and that `files`
+    /// includes every `exports` target. Blocking issues fail the command
+    /// before any file is modified.
+    #[arg(long)]
+    pub validate_publish: bool,
+
+    /// Preview the release as of a past Git reference.
+    ///
+    /// Reads package manifests and pending changesets from the commit the
+    /// branch, tag, or SHA points to instead of the working directory, to
+    /// reproduce what would have been released at that commit. The current
+    /// configuration is used. Only applies to preview mode.
+    #[arg(long, value_name = "REF", conflicts_with_all = ["execute", "snapshot", "sandbox"])]
+    pub at: Option<String>,
+
+    /// Run the release in a temporary git worktree.
+    ///
+    /// `run` (the default) applies versions, changelogs and changeset archival
+    /// in a sandbox worktree and commits the result there, leaving the working
+    /// directory untouched. `promote` fast-forwards the current branch to the
+    /// sandbox commit (creating tags with --git-tag); `discard` removes it.
+    #[arg(
+        long,
+        value_name = "ACTION",
+        num_args = 0..=1,
+        default_missing_value = "run",
+        value_parser = ["run", "promote", "discard"],
+        conflicts_with_all = ["dry_run", "snapshot"]
+    )]
+    pub sandbox: Option<String>,
+
+    /// Write a release manifest to this file.
+    ///
+    /// The JSON manifest lists, for each consumed changeset, the packages
+    /// released with their versions and changelog anchors, and the packages
+    /// skipped (filtered or private). Relative paths are resolved against the
+    /// workspace root. Only applies with --execute or --sandbox.
+    #[arg(long, value_name = "PATH")]
+    pub manifest: Option<PathBuf>,
 }
 
 // ============================================================================
@@ -638,6 +980,25 @@ pub struct UpgradeCheckArgs {
     /// Uses this registry instead of the configured one.
     #[arg(long, value_name = "URL")]
     pub registry: Option<String>,
+
+    /// Include weekly download statistics and trends.
+    ///
+    /// Queries the npm downloads API for every upgradable public dependency.
+    #[arg(long)]
+    pub stats: bool,
+
+    /// Check toolchain pins instead of dependencies.
+    ///
+    /// Reports outdated `engines.node`, `packageManager`, and Volta pins.
+    #[arg(long)]
+    pub toolchain: bool,
+
+    /// Resume an interrupted check.
+    ///
+    /// Records registry lookups in the backup directory and reuses the ones left by an
+    /// earlier `--resume` run that did not complete.
+    #[arg(long)]
+    pub resume: bool,
 }
 
 /// Arguments for the `upgrade apply` command.
@@ -664,7 +1025,8 @@ pub struct UpgradeApplyArgs {
 
     /// Comma-separated list of packages to upgrade.
     ///
-    /// Only upgrades specified packages.
+    /// Only upgrades specified packages. Use `.` for the workspace root manifest;
+    /// dependencies declared only at the root are left alone unless it is selected.
     #[arg(long, value_name = "LIST", value_delimiter = ',')]
     pub packages: Option<Vec<String>>,
 
@@ -687,6 +1049,25 @@ pub struct UpgradeApplyArgs {
     #[arg(long)]
     pub no_backup: bool,
 
+    /// Skip post-upgrade verification.
+    ///
+    /// Does not run the command configured in `upgrade.verify`.
+    #[arg(long)]
+    pub no_verify: bool,
+
+    /// Output a pull request description instead of the results table.
+    ///
+    /// Renders the applied upgrades, their release notes, risks, and the
+    /// changesets created as Markdown. JSON output includes it as `prBody`.
+    #[arg(long)]
+    pub pr_body: bool,
+
+    /// Upgrade toolchain pins instead of dependencies.
+    ///
+    /// Updates `engines.node`, `packageManager`, and Volta pins.
+    #[arg(long, conflicts_with = "pr_body")]
+    pub toolchain: bool,
+
     /// Skip confirmations.
     ///
     /// Automatically confirms all prompts.
@@ -747,6 +1128,55 @@ pub struct UpgradeBackupCleanArgs {
     pub force: bool,
 }
 
+/// Subcommands for `backup`.
+#[derive(Debug, Subcommand)]
+pub enum BackupCommands {
+    /// List all backups.
+    List(UpgradeBackupListArgs),
+
+    /// Restore a backup after verifying its integrity.
+    Restore(UpgradeBackupRestoreArgs),
+
+    /// Remove backups exceeding the retention limits.
+    ///
+    /// Applies the count, age, and size limits from `[upgrade.backup]`,
+    /// optionally overridden by the options below.
+    Prune(BackupPruneArgs),
+}
+
+/// Arguments for the `backup prune` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "backup", "prune", "--max-age-days", "30"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct BackupPruneArgs {
+    /// Number of recent backups to keep (overrides `max_backups`).
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    pub keep: Option<u64>,
+
+    /// Remove backups older than this many days (overrides `max_age_days`).
+    #[arg(long, value_name = "DAYS", value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_age_days: Option<u32>,
+
+    /// Keep the total size of backups under this many megabytes (overrides `max_total_size_mb`).
+    #[arg(long, value_name = "MB", value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_size_mb: Option<u64>,
+
+    /// Show which backups would be removed without removing them.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip confirmation prompt.
+    #[arg(long)]
+    pub force: bool,
+}
+
 // ============================================================================
 // Audit Command
 // ============================================================================
@@ -761,11 +1191,13 @@ pub struct UpgradeBackupCleanArgs {
 ///
 /// let cli = Cli::parse_from(["workspace", "audit"]);
 /// ```
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Args)]
 pub struct AuditArgs {
     /// Comma-separated list of sections to audit.
     ///
-    /// Options: all, upgrades, dependencies, version-consistency, breaking-changes
+    /// Options: all, upgrades, dependencies, version-consistency, breaking-changes,
+    /// registry-ownership
     /// Default: all
     #[arg(long, value_name = "LIST", value_delimiter = ',', default_value = "all")]
     pub sections: Vec<String>,
@@ -810,6 +1242,45 @@ pub struct AuditArgs {
     /// Requires --export to specify the format.
     #[arg(long, value_name = "PATH", requires = "export")]
     pub export_file: Option<PathBuf>,
+
+    /// Apply the fixes of fixable issues.
+    ///
+    /// Only issues at or above --min-severity are fixed. Files are backed up
+    /// first so the fix can be reverted with `workspace undo`.
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Print the fixes as diffs without writing any file.
+    ///
+    /// Requires --fix.
+    #[arg(long, requires = "fix")]
+    pub dry_run: bool,
+
+    /// Suppress the known findings recorded in a baseline file.
+    ///
+    /// Findings missing from the baseline, or whose suppression has expired,
+    /// are reported as new, and the command fails if any are at or above
+    /// --min-severity. Defaults to .audit-baseline.json when given without a path.
+    #[arg(
+        long,
+        value_name = "PATH",
+        num_args = 0..=1,
+        default_missing_value = ".audit-baseline.json"
+    )]
+    pub baseline: Option<PathBuf>,
+
+    /// Record the current findings in the baseline file.
+    ///
+    /// Writes to --baseline, or .audit-baseline.json. Findings already in the
+    /// baseline keep their expiry date and reason.
+    #[arg(long, conflicts_with = "fix")]
+    pub write_baseline: bool,
+
+    /// Expiry date (YYYY-MM-DD) of the findings added by --write-baseline.
+    ///
+    /// After this date the findings are reported as new again.
+    #[arg(long, value_name = "DATE", requires = "write_baseline")]
+    pub baseline_expires: Option<String>,
 }
 
 // ============================================================================
@@ -825,21 +1296,22 @@ pub struct AuditArgs {
 /// use sublime_cli_tools::cli::Cli;
 ///
 /// let cli = Cli::parse_from(["workspace", "changes", "--since", "HEAD~1"]);
+/// let cli = Cli::parse_from(["workspace", "changes", "--from", "v1.0.0", "--paths", "packages/core"]);
 /// ```
 #[derive(Debug, Args)]
 pub struct ChangesArgs {
     /// Since commit/branch/tag.
     ///
-    /// Analyzes changes since this Git reference.
+    /// Analyzes changes since this Git reference (tag, SHA, or branch).
     /// If not provided, analyzes working directory changes.
-    #[arg(long, value_name = "REF")]
+    #[arg(long, visible_alias = "from", value_name = "REF")]
     pub since: Option<String>,
 
     /// Until commit/branch/tag.
     ///
     /// Analyzes changes until this Git reference.
-    /// Default: HEAD
-    #[arg(long, value_name = "REF")]
+    /// Default: HEAD. Without --since, each package is analyzed from its last release tag.
+    #[arg(long, visible_alias = "to", value_name = "REF")]
     pub until: Option<String>,
 
     /// Compare against branch.
@@ -865,6 +1337,20 @@ pub struct ChangesArgs {
     /// Only shows changes for specified packages.
     #[arg(long, value_name = "LIST", value_delimiter = ',')]
     pub packages: Option<Vec<String>>,
+
+    /// Comma-separated list of path filters.
+    ///
+    /// Only reports files under these paths, relative to the repository root.
+    /// Entries containing `*`, `?` or `[` are glob patterns (e.g. `**/*.ts`).
+    #[arg(long, value_name = "LIST", value_delimiter = ',')]
+    pub paths: Option<Vec<String>>,
+
+    /// Add uncommitted changes to a commit range.
+    ///
+    /// Merges staged, unstaged and untracked changes into the range analysis.
+    /// Without --since/--until/--branch, analyzes everything since the last release.
+    #[arg(long, conflicts_with_all = ["staged", "unstaged"])]
+    pub include_uncommitted: bool,
 }
 
 // ============================================================================
@@ -890,6 +1376,65 @@ pub struct VersionArgs {
     pub verbose: bool,
 }
 
+// ============================================================================
+// Hooks Commands
+// ============================================================================
+
+/// Subcommands for the `hooks` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "hooks", "install", "--force"]);
+/// ```
+#[derive(Debug, Subcommand)]
+pub enum HooksCommands {
+    /// Install configured hooks.
+    ///
+    /// Writes missing and outdated hooks and removes managed hooks that are
+    /// no longer configured. Hooks edited by hand are kept unless `--force`.
+    Install(HooksInstallArgs),
+
+    /// Show the state of configured hooks.
+    ///
+    /// Reports hooks that are missing, outdated, modified, unmanaged, or orphaned.
+    Status(HooksStatusArgs),
+
+    /// Remove managed hooks.
+    ///
+    /// Hooks not installed by workspace tools are never removed.
+    Uninstall(HooksUninstallArgs),
+}
+
+/// Arguments for the `hooks install` command.
+#[derive(Debug, Args)]
+pub struct HooksInstallArgs {
+    /// Replace hooks that were edited by hand or not installed by workspace tools.
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Arguments for the `hooks status` command.
+#[derive(Debug, Args)]
+pub struct HooksStatusArgs {
+    /// Exit with an error when any hook drifted from configuration.
+    ///
+    /// Useful in CI to verify hooks are installed and up to date.
+    #[arg(long)]
+    pub check: bool,
+}
+
+/// Arguments for the `hooks uninstall` command.
+#[derive(Debug, Args)]
+pub struct HooksUninstallArgs {
+    /// Also remove managed hooks that were edited by hand.
+    #[arg(long)]
+    pub force: bool,
+}
+
 // ============================================================================
 // Clone Command
 // ============================================================================
@@ -980,3 +1525,165 @@ pub struct CloneArgs {
     #[arg(long)]
     pub depth: Option<u32>,
 }
+
+// ============================================================================
+// Monitor Command
+// ============================================================================
+
+/// Arguments for the `monitor` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "monitor", "--interval", "10"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct MonitorArgs {
+    /// Seconds between automatic refreshes.
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 5,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub interval: u64,
+
+    /// Print a single snapshot and exit.
+    ///
+    /// Implied when stdout is not a terminal or the output format is not human.
+    #[arg(long)]
+    pub once: bool,
+}
+
+// ============================================================================
+// Undo Command
+// ============================================================================
+
+/// Arguments for the `undo` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "undo", "--kind", "bump"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct UndoArgs {
+    /// Transaction ID to revert.
+    ///
+    /// Defaults to the most recent completed operation.
+    #[arg(value_name = "ID", conflicts_with = "list")]
+    pub id: Option<String>,
+
+    /// List recent operations instead of reverting one.
+    #[arg(long)]
+    pub list: bool,
+
+    /// Only consider operations of this kind.
+    ///
+    /// Options: bump, upgrade, constraint-fix, package-move, audit-fix, alias-sync
+    #[arg(long, value_name = "KIND")]
+    pub kind: Option<String>,
+
+    /// Maximum number of operations to list.
+    #[arg(long, value_name = "N", default_value_t = 10)]
+    pub limit: usize,
+
+    /// Skip confirmation and revert even if newer operations changed the same files.
+    #[arg(long)]
+    pub force: bool,
+}
+
+// ============================================================================
+// Why Command
+// ============================================================================
+
+/// Arguments for the `why` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "why", "lodash", "--from", "@org/app"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct WhyArgs {
+    /// Package to explain.
+    ///
+    /// A workspace package or an external dependency.
+    #[arg(value_name = "PACKAGE")]
+    pub package: String,
+
+    /// Only show chains starting at this workspace package.
+    #[arg(long, value_name = "PACKAGE")]
+    pub from: Option<String>,
+}
+
+// ============================================================================
+// Tag Command
+// ============================================================================
+
+/// Arguments for the `tag` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "tag", "--dry-run"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct TagArgs {
+    /// Only tag these packages (comma-separated).
+    #[arg(long, value_name = "LIST", value_delimiter = ',')]
+    pub packages: Option<Vec<String>>,
+
+    /// List the missing tags without creating them.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Push the created tags to origin.
+    #[arg(long)]
+    pub push: bool,
+
+    /// Skip confirmation.
+    #[arg(long)]
+    pub force: bool,
+}
+
+// ============================================================================
+// Aliases Command
+// ============================================================================
+
+/// Arguments for the `aliases` command.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use sublime_cli_tools::cli::Cli;
+///
+/// let cli = Cli::parse_from(["workspace", "aliases", "--check"]);
+/// ```
+#[derive(Debug, Args)]
+pub struct AliasesArgs {
+    /// Fail if the alias maps drifted from the dependencies, without writing anything.
+    #[arg(long, conflicts_with = "dry_run")]
+    pub check: bool,
+
+    /// Show the changes without writing them.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Also generate package.json `imports`, whatever the configuration says.
+    #[arg(long)]
+    pub imports: bool,
+}
@@ -39,8 +39,11 @@
 
 use super::branding;
 use crate::cli::{Cli, Commands};
-use crate::commands::{audit, bump, changeset, config, init, upgrade, version};
-use crate::error::Result;
+use crate::commands::{
+    aliases, audit, bump, changeset, config, hooks, init, monitor, roots, tag, undo, upgrade,
+    version, why,
+};
+use crate::error::{CliError, Result};
 use crate::output::{Output, OutputFormat};
 use std::path::{Path, PathBuf};
 
@@ -98,6 +101,11 @@ pub async fn dispatch_command(cli: &Cli) -> Result<()> {
         branding::print_header(env!("CARGO_PKG_VERSION"));
     }
 
+    if cli.is_all_roots() {
+        return Box::pin(dispatch_all_roots(cli, root, format, config_path.map(PathBuf::as_path)))
+            .await;
+    }
+
     match &cli.command {
         Commands::Init(args) => {
             init::execute_init(args, root, format).await?;
@@ -184,14 +192,71 @@ pub async fn dispatch_command(cli: &Cli) -> Result<()> {
                     )
                     .await?;
                 }
+                ChangesetCommands::Split(args) => {
+                    changeset::execute_split(
+                        args,
+                        &output,
+                        Some(root),
+                        config_path.as_ref().map(|p| p.as_path()),
+                    )
+                    .await?;
+                }
+                ChangesetCommands::Merge(args) => {
+                    changeset::execute_merge(
+                        args,
+                        &output,
+                        Some(root),
+                        config_path.as_ref().map(|p| p.as_path()),
+                    )
+                    .await?;
+                }
+                ChangesetCommands::Promote(args) => {
+                    changeset::execute_promote(
+                        args,
+                        &output,
+                        Some(root),
+                        config_path.as_ref().map(|p| p.as_path()),
+                    )
+                    .await?;
+                }
+                ChangesetCommands::Migrate(args) => {
+                    changeset::execute_migrate(
+                        args,
+                        &output,
+                        Some(root),
+                        config_path.as_ref().map(|p| p.as_path()),
+                    )
+                    .await?;
+                }
+                ChangesetCommands::Clean(args) => {
+                    changeset::execute_clean(
+                        args,
+                        &output,
+                        Some(root),
+                        config_path.as_ref().map(|p| p.as_path()),
+                    )
+                    .await?;
+                }
+                ChangesetCommands::MergeDriver(args) => {
+                    changeset::execute_merge_driver(args, &output).await?;
+                }
             }
         }
 
         Commands::Bump(args) => {
             let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
 
-            // Route to snapshot, execute, or preview mode based on flags
-            if args.snapshot {
+            // Route to sandbox, snapshot, execute, or preview mode based on flags
+            if args.sandbox.is_some() {
+                // Sandbox mode - run, promote, or discard a release in a temporary worktree
+                bump::execute_bump_sandbox(
+                    args,
+                    &output,
+                    root,
+                    config_path.as_ref().map(|p| p.as_path()),
+                )
+                .await?;
+            } else if args.snapshot {
                 // Snapshot mode - generate snapshot versions without consuming changesets
                 bump::execute_bump_snapshot(
                     args,
@@ -202,12 +267,12 @@ pub async fn dispatch_command(cli: &Cli) -> Result<()> {
                 .await?;
             } else if args.execute {
                 // Execute mode - apply version bumps
-                bump::execute_bump_apply(
+                Box::pin(bump::execute_bump_apply(
                     args,
                     &output,
                     root,
                     config_path.as_ref().map(|p| p.as_path()),
-                )
+                ))
                 .await?;
             } else {
                 // Preview mode (default) - dry-run
@@ -274,16 +339,142 @@ pub async fn dispatch_command(cli: &Cli) -> Result<()> {
             )
             .await?;
         }
+
+        Commands::Hooks(hooks_cmd) => {
+            use crate::cli::commands::HooksCommands;
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            let config_path = config_path.map(PathBuf::as_path);
+            match hooks_cmd {
+                HooksCommands::Install(args) => {
+                    hooks::execute_install(args, &output, root, config_path).await?;
+                }
+                HooksCommands::Status(args) => {
+                    hooks::execute_status(args, &output, root, config_path).await?;
+                }
+                HooksCommands::Uninstall(args) => {
+                    hooks::execute_uninstall(args, &output, root, config_path).await?;
+                }
+            }
+        }
+
+        Commands::Monitor(args) => {
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            Box::pin(monitor::execute_monitor(
+                args,
+                &output,
+                root,
+                config_path.map(PathBuf::as_path),
+            ))
+            .await?;
+        }
+
+        Commands::Backup(backup_cmd) => {
+            use crate::cli::commands::BackupCommands;
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            match backup_cmd {
+                BackupCommands::List(args) => {
+                    upgrade::execute_backup_list(args, &output, root).await?;
+                }
+                BackupCommands::Restore(args) => {
+                    upgrade::execute_backup_restore(args, &output, root).await?;
+                }
+                BackupCommands::Prune(args) => {
+                    upgrade::execute_backup_prune(args, &output, root).await?;
+                }
+            }
+        }
+
+        Commands::Undo(args) => {
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            undo::execute_undo(args, &output, root, config_path.map(PathBuf::as_path)).await?;
+        }
+
+        Commands::Why(args) => {
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            why::execute_why(args, &output, root, config_path.map(PathBuf::as_path)).await?;
+        }
+        Commands::Tag(args) => {
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            tag::execute_tag(args, &output, root, config_path.map(PathBuf::as_path)).await?;
+        }
+        Commands::Aliases(args) => {
+            let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+            aliases::execute_aliases(args, &output, root, config_path.map(PathBuf::as_path))
+                .await?;
+        }
     }
 
     Ok(())
 }
 
+/// Dispatches a command run with `--all-roots` to every workspace root.
+///
+/// # Errors
+///
+/// Returns an error if the command does not support `--all-roots`, or if it fails in any
+/// workspace root.
+async fn dispatch_all_roots(
+    cli: &Cli,
+    root: &Path,
+    format: OutputFormat,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    use crate::cli::commands::UpgradeCommands;
+
+    let output = Output::new(format, std::io::stdout(), cli.is_color_disabled());
+    match &cli.command {
+        Commands::Changes(args) => {
+            Box::pin(roots::execute_for_roots(
+                &output,
+                root,
+                config_path,
+                |output, root, config| async move {
+                    crate::commands::changes::execute_changes(
+                        args,
+                        &output,
+                        &root,
+                        config.as_deref(),
+                    )
+                    .await
+                },
+            ))
+            .await
+        }
+        Commands::Audit(args) => {
+            roots::execute_for_roots(
+                &output,
+                root,
+                config_path,
+                |output, root, config| async move {
+                    audit::execute_audit(args, &output, &root, config.as_deref()).await
+                },
+            )
+            .await
+        }
+        Commands::Upgrade(UpgradeCommands::Check(args)) => {
+            // Upgrade detection reads each root's own configuration
+            roots::execute_for_roots(
+                &output,
+                root,
+                config_path,
+                |output, root, _config| async move {
+                    Box::pin(upgrade::execute_upgrade_check(args, &output, &root)).await
+                },
+            )
+            .await
+        }
+        _ => Err(CliError::validation(
+            "--all-roots is only supported by 'changes', 'audit', and 'upgrade check'",
+        )),
+    }
+}
+
 /// Determines if the branded header should be displayed.
 ///
 /// The header is shown when:
 /// - Output format is Human (not JSON, JSON-compact, or Quiet)
 /// - Command is not `version` (version command shows header always, even in quiet modes)
+/// - Command is not `upgrade apply --pr-body` (the description is piped into a pull request)
 ///
 /// # Arguments
 ///
@@ -315,6 +506,13 @@ fn should_show_header(command: &Commands, format: OutputFormat) -> bool {
         return false;
     }
 
+    // A pull request description is meant to be piped, so it must stand alone
+    if let Commands::Upgrade(crate::cli::commands::UpgradeCommands::Apply(args)) = command
+        && args.pr_body
+    {
+        return false;
+    }
+
     // Version command shows header unconditionally (in its own implementation)
     !matches!(command, Commands::Version(_))
 }
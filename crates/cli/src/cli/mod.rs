@@ -50,6 +50,7 @@ mod tests;
 
 use clap::Parser;
 use std::path::PathBuf;
+use std::time::Duration;
 
 pub use args::{LogLevel, OutputFormatArg};
 pub use commands::Commands;
@@ -74,6 +75,7 @@ use crate::output::OutputFormat;
 /// - `--format`: Controls output format (stdout only)
 /// - `--no-color`: Disables ANSI colors in output and logs
 /// - `--config`: Override default config file location
+/// - `--yes` / `--non-interactive`: Never prompt (also implied without a terminal)
 ///
 /// # Stream Separation
 ///
@@ -103,6 +105,7 @@ use crate::output::OutputFormat;
 /// workspace --log-level debug changeset list
 /// ```
 #[derive(Debug, Parser)]
+#[allow(clippy::struct_excessive_bools)]
 #[command(name = "workspace")]
 #[command(version = env!("CARGO_PKG_VERSION"))]
 #[command(about = "Workspace Tools - Changeset-based version management")]
@@ -155,6 +158,7 @@ pub struct Cli {
     /// - human: Human-readable with colors and tables (default)
     /// - json: Pretty-printed JSON
     /// - json-compact: Compact JSON (single line)
+    /// - ndjson: Newline-delimited JSON records, streamed as they are computed
     /// - quiet: Minimal output
     ///
     /// Default: human
@@ -170,6 +174,58 @@ pub struct Cli {
     #[arg(global = true, long)]
     pub no_color: bool,
 
+    /// Reject every filesystem and git write.
+    ///
+    /// Any attempt to modify files, the git repository, or a remote fails with
+    /// an error naming the offending path. Use it to prove commands such as
+    /// `changes` or `audit` are side-effect free in locked-down CI.
+    /// Also enabled by setting WORKSPACE_READ_ONLY=true.
+    #[arg(global = true, long, env = "WORKSPACE_READ_ONLY")]
+    pub read_only: bool,
+
+    /// Never reach the network.
+    ///
+    /// Registry lookups are served from the local cache or skipped and listed as
+    /// such, remote configuration uses its cached copy, and notifications and git
+    /// remote operations are disabled. Commands report which results may be stale.
+    /// Also enabled by setting WORKSPACE_OFFLINE=true.
+    #[arg(global = true, long, env = "WORKSPACE_OFFLINE")]
+    pub offline: bool,
+
+    /// Deadline for the whole command, in seconds.
+    ///
+    /// Registry lookups, git remote operations, and spawned commands still
+    /// running when it passes are cancelled and the command exits with code 75.
+    /// Commands that can report partial results, such as `upgrade check` and
+    /// `audit`, print what finished in time first.
+    /// Also set by WORKSPACE_TIMEOUT.
+    #[arg(global = true, long, value_name = "SECONDS", env = "WORKSPACE_TIMEOUT")]
+    pub timeout: Option<u64>,
+
+    /// Answer yes to every confirmation and never prompt.
+    ///
+    /// Other prompts fall back to their defaults; prompts without a default fail
+    /// with an error naming the flag to pass instead.
+    /// Also enabled by setting WORKSPACE_YES=true.
+    #[arg(global = true, short = 'y', long, env = "WORKSPACE_YES")]
+    pub yes: bool,
+
+    /// Never prompt.
+    ///
+    /// Selections fall back to their defaults; confirmations fail unless `--yes`
+    /// or the command's `--force` is given. Implied when stdin is not a terminal.
+    /// Also enabled by setting WORKSPACE_NON_INTERACTIVE=true.
+    #[arg(global = true, long, env = "WORKSPACE_NON_INTERACTIVE")]
+    pub non_interactive: bool,
+
+    /// Run the command in every workspace root of a meta-repository.
+    ///
+    /// Roots are listed as `[[roots]]` entries in the configuration.
+    /// Results are grouped per root. Supported by `changes`, `audit`, and
+    /// `upgrade check`.
+    #[arg(global = true, long)]
+    pub all_roots: bool,
+
     /// Path to config file.
     ///
     /// Override default config file location.
@@ -178,6 +234,14 @@ pub struct Cli {
     /// Default: Auto-detect (.changesets.{toml,json,yaml,yml})
     #[arg(global = true, short = 'c', long, value_name = "PATH")]
     pub config: Option<PathBuf>,
+
+    /// Configuration profile to apply.
+    ///
+    /// Merges the `[profile.<NAME>]` table of the config file over the base
+    /// configuration, e.g. stricter audits or another registry in CI.
+    /// Also selected by setting WORKSPACE_PROFILE.
+    #[arg(global = true, long, value_name = "NAME", env = "WORKSPACE_PROFILE")]
+    pub profile: Option<String>,
 }
 
 impl Cli {
@@ -232,6 +296,106 @@ impl Cli {
         self.no_color || std::env::var("NO_COLOR").is_ok()
     }
 
+    /// Returns whether read-only mode is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--read-only", "version"]);
+    /// assert!(cli.is_read_only());
+    /// ```
+    #[must_use]
+    pub const fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns whether offline mode is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--offline", "upgrade", "check"]);
+    /// assert!(cli.is_offline());
+    /// ```
+    #[must_use]
+    pub const fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the deadline for the whole command, if one is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    /// use std::time::Duration;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--timeout", "30", "upgrade", "check"]);
+    /// assert_eq!(cli.timeout(), Some(Duration::from_secs(30)));
+    /// ```
+    #[must_use]
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_secs)
+    }
+
+    /// Returns whether confirmations are answered with yes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "-y", "version"]);
+    /// assert!(cli.assume_yes());
+    /// ```
+    #[must_use]
+    pub const fn assume_yes(&self) -> bool {
+        self.yes
+    }
+
+    /// Returns whether non-interactive mode is requested.
+    ///
+    /// Stdin TTY detection is not included; see
+    /// [`crate::interactive::is_interactive`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--non-interactive", "version"]);
+    /// assert!(cli.is_non_interactive());
+    /// ```
+    #[must_use]
+    pub const fn is_non_interactive(&self) -> bool {
+        self.non_interactive
+    }
+
+    /// Returns whether the command runs in every workspace root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--all-roots", "changes"]);
+    /// assert!(cli.is_all_roots());
+    /// ```
+    #[must_use]
+    pub const fn is_all_roots(&self) -> bool {
+        self.all_roots
+    }
+
     /// Returns the root directory.
     ///
     /// # Examples
@@ -265,4 +429,20 @@ impl Cli {
     pub const fn config_path(&self) -> Option<&PathBuf> {
         self.config.as_ref()
     }
+
+    /// Returns the configuration profile to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use clap::Parser;
+    /// use sublime_cli_tools::cli::Cli;
+    ///
+    /// let cli = Cli::parse_from(["workspace", "--profile", "ci", "version"]);
+    /// assert_eq!(cli.profile(), Some("ci"));
+    /// ```
+    #[must_use]
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
 }
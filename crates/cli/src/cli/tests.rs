@@ -37,7 +37,8 @@ use clap_complete::Shell;
 
 use super::*;
 use crate::cli::commands::{
-    ChangesetCommands, ConfigCommands, UpgradeBackupCommands, UpgradeCommands,
+    BackupCommands, ChangesetCommands, Commands, ConfigCommands, UpgradeBackupCommands,
+    UpgradeCommands,
 };
 
 // ============================================================================
@@ -77,6 +78,9 @@ fn test_output_format_parsing() {
 
     let cli = Cli::parse_from(["workspace", "--format", "quiet", "version"]);
     assert_eq!(cli.output_format(), OutputFormat::Quiet);
+
+    let cli = Cli::parse_from(["workspace", "--format", "ndjson", "changes"]);
+    assert_eq!(cli.output_format(), OutputFormat::Ndjson);
 }
 
 #[test]
@@ -85,6 +89,72 @@ fn test_no_color_flag() {
     assert!(cli.is_color_disabled());
 }
 
+#[test]
+fn test_read_only_flag() {
+    let cli = Cli::parse_from(["workspace", "--read-only", "changes"]);
+    assert!(cli.is_read_only());
+}
+
+#[test]
+fn test_offline_flag() {
+    let cli = Cli::parse_from(["workspace", "upgrade", "check", "--offline"]);
+    assert!(cli.is_offline());
+}
+
+#[test]
+fn test_timeout_flag() {
+    let cli = Cli::parse_from(["workspace", "audit", "--timeout", "90"]);
+    assert_eq!(cli.timeout(), Some(std::time::Duration::from_secs(90)));
+
+    let cli = Cli::parse_from(["workspace", "audit"]);
+    assert_eq!(cli.timeout(), None);
+
+    assert!(Cli::try_parse_from(["workspace", "--timeout", "soon", "audit"]).is_err());
+}
+
+#[test]
+fn test_all_roots_flag() {
+    let cli = Cli::parse_from(["workspace", "--all-roots", "audit"]);
+    assert!(cli.is_all_roots());
+
+    let cli = Cli::parse_from(["workspace", "upgrade", "check", "--all-roots"]);
+    assert!(cli.is_all_roots());
+
+    let cli = Cli::parse_from(["workspace", "changes"]);
+    assert!(!cli.is_all_roots());
+}
+
+#[test]
+fn test_non_interactive_flags() {
+    let cli = Cli::parse_from(["workspace", "--yes", "changes"]);
+    assert!(cli.assume_yes());
+    assert!(!cli.is_non_interactive());
+
+    let cli = Cli::parse_from(["workspace", "--non-interactive", "upgrade", "check"]);
+    assert!(cli.is_non_interactive());
+    assert!(!cli.assume_yes());
+}
+
+#[test]
+fn test_non_interactive_flag_shared_with_subcommands() {
+    // `init` and `changeset add` keep their own --non-interactive flag; both spellings work
+    let cli = Cli::parse_from(["workspace", "init", "--non-interactive"]);
+    assert!(cli.is_non_interactive());
+    assert!(matches!(&cli.command, Commands::Init(args) if args.non_interactive));
+
+    let cli = Cli::parse_from(["workspace", "--non-interactive", "init"]);
+    assert!(matches!(&cli.command, Commands::Init(args) if args.non_interactive));
+}
+
+#[test]
+fn test_profile_flag() {
+    let cli = Cli::parse_from(["workspace", "--profile", "ci", "changes"]);
+    assert_eq!(cli.profile(), Some("ci"));
+
+    let cli = Cli::parse_from(["workspace", "changes", "--profile", "release"]);
+    assert_eq!(cli.profile(), Some("release"));
+}
+
 #[test]
 fn test_root_directory() {
     let cli = Cli::parse_from(["workspace", "--root", "/tmp", "version"]);
@@ -175,6 +245,34 @@ fn test_init_command_with_options() {
     }
 }
 
+#[test]
+fn test_init_command_templates() {
+    let cli = Cli::parse_from(["workspace", "init", "--template", "design-system"]);
+    if let Commands::Init(args) = cli.command {
+        assert_eq!(args.template, Some("design-system".to_string()));
+        assert_eq!(args.eject_template, None);
+    } else {
+        panic!("Expected Init command");
+    }
+
+    let cli = Cli::parse_from(["workspace", "init", "--eject-template", "app-monorepo"]);
+    if let Commands::Init(args) = cli.command {
+        assert_eq!(args.eject_template, Some("app-monorepo".to_string()));
+    } else {
+        panic!("Expected Init command");
+    }
+
+    let result = Cli::try_parse_from([
+        "workspace",
+        "init",
+        "--template",
+        "app-monorepo",
+        "--eject-template",
+        "app-monorepo",
+    ]);
+    assert!(result.is_err());
+}
+
 // ============================================================================
 // Config Command Tests
 // ============================================================================
@@ -201,6 +299,18 @@ fn test_config_validate_command() {
     }
 }
 
+#[test]
+fn test_config_validate_explain() {
+    let cli =
+        Cli::parse_from(["workspace", "config", "validate", "--explain", "version.default_bump"]);
+
+    if let Commands::Config(ConfigCommands::Validate(args)) = cli.command {
+        assert_eq!(args.explain.as_deref(), Some("version.default_bump"));
+    } else {
+        panic!("Expected Config Validate command");
+    }
+}
+
 // ============================================================================
 // Changeset Command Tests
 // ============================================================================
@@ -247,6 +357,49 @@ fn test_changeset_create_with_options() {
     }
 }
 
+#[test]
+fn test_changeset_create_document_sources() {
+    let cli = Cli::parse_from(["workspace", "changeset", "create", "--stdin"]);
+    assert!(
+        matches!(cli.command, Commands::Changeset(ChangesetCommands::Create(args)) if args.stdin)
+    );
+
+    let cli = Cli::parse_from(["workspace", "changeset", "create", "--from-file", "cs.yaml"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Changeset(ChangesetCommands::Create(args))
+            if args.from_file == Some(PathBuf::from("cs.yaml"))
+    ));
+
+    let result = Cli::try_parse_from([
+        "workspace",
+        "changeset",
+        "create",
+        "--stdin",
+        "--from-file",
+        "cs.yaml",
+    ]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_changeset_create_from_diff() {
+    let cli =
+        Cli::parse_from(["workspace", "changeset", "create", "--from-diff", "--base", "develop"]);
+    assert!(matches!(
+        cli.command,
+        Commands::Changeset(ChangesetCommands::Create(args))
+            if args.from_diff && args.base.as_deref() == Some("develop")
+    ));
+
+    // --base only applies to --from-diff, which cannot be combined with a document
+    assert!(Cli::try_parse_from(["workspace", "changeset", "create", "--base", "main"]).is_err());
+    assert!(
+        Cli::try_parse_from(["workspace", "changeset", "create", "--from-diff", "--stdin"])
+            .is_err()
+    );
+}
+
 #[test]
 fn test_changeset_list_command() {
     let cli = Cli::parse_from([
@@ -273,6 +426,37 @@ fn test_changeset_list_command() {
     }
 }
 
+#[test]
+fn test_changeset_list_pagination_and_age_flags() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "list",
+        "--filter-author",
+        "alice",
+        "--newer-than",
+        "7d",
+        "--older-than",
+        "1h",
+        "--reverse",
+        "--limit",
+        "10",
+        "--offset",
+        "20",
+    ]);
+
+    if let Commands::Changeset(ChangesetCommands::List(args)) = cli.command {
+        assert_eq!(args.filter_author, Some("alice".to_string()));
+        assert_eq!(args.newer_than, Some("7d".to_string()));
+        assert_eq!(args.older_than, Some("1h".to_string()));
+        assert!(args.reverse);
+        assert_eq!(args.limit, Some(10));
+        assert_eq!(args.offset, 20);
+    } else {
+        panic!("Expected Changeset List command");
+    }
+}
+
 #[test]
 fn test_changeset_show_command() {
     let cli = Cli::parse_from(["workspace", "changeset", "show", "feature/branch"]);
@@ -411,6 +595,169 @@ fn test_changeset_check_command() {
     }
 }
 
+#[test]
+fn test_changeset_split_command() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "split",
+        "feature/auth",
+        "--packages",
+        "@myorg/docs,@myorg/site",
+        "--into",
+        "feature/docs",
+    ]);
+
+    if let Commands::Changeset(ChangesetCommands::Split(args)) = cli.command {
+        assert_eq!(args.branch, "feature/auth");
+        assert_eq!(args.packages, vec!["@myorg/docs".to_string(), "@myorg/site".to_string()]);
+        assert_eq!(args.into, "feature/docs");
+    } else {
+        panic!("Expected Changeset Split command");
+    }
+}
+
+#[test]
+fn test_changeset_split_requires_packages() {
+    let result =
+        Cli::try_parse_from(["workspace", "changeset", "split", "feature/auth", "--into", "x"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_changeset_merge_command() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "merge",
+        "fix/login",
+        "feature/sso",
+        "--into",
+        "release/auth",
+    ]);
+
+    if let Commands::Changeset(ChangesetCommands::Merge(args)) = cli.command {
+        assert_eq!(args.branches, vec!["fix/login".to_string(), "feature/sso".to_string()]);
+        assert_eq!(args.into, "release/auth");
+    } else {
+        panic!("Expected Changeset Merge command");
+    }
+}
+
+#[test]
+fn test_changeset_merge_requires_two_branches() {
+    let result =
+        Cli::try_parse_from(["workspace", "changeset", "merge", "fix/login", "--into", "x"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_changeset_promote_command() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "promote",
+        "brave-lions-smile",
+        "--to",
+        "production",
+        "--retag",
+        "--push",
+    ]);
+
+    if let Commands::Changeset(ChangesetCommands::Promote(args)) = cli.command {
+        assert_eq!(args.reference, "brave-lions-smile");
+        assert_eq!(args.to, "production");
+        assert!(args.retag);
+        assert!(args.push);
+        assert!(!args.dist_tag);
+        assert!(!args.dry_run);
+    } else {
+        panic!("Expected Changeset Promote command");
+    }
+}
+
+#[test]
+fn test_changeset_promote_push_requires_retag() {
+    let result = Cli::try_parse_from([
+        "workspace",
+        "changeset",
+        "promote",
+        "feature/sso",
+        "--to",
+        "staging",
+        "--push",
+    ]);
+    assert!(result.is_err());
+    assert!(Cli::try_parse_from(["workspace", "changeset", "promote", "feature/sso"]).is_err());
+}
+
+#[test]
+fn test_changeset_migrate_command() {
+    let cli = Cli::parse_from(["workspace", "changeset", "migrate", "--path", "changes"]);
+
+    if let Commands::Changeset(ChangesetCommands::Migrate(args)) = cli.command {
+        assert_eq!(args.to, "file");
+        assert_eq!(args.path, "changes");
+        assert_eq!(args.history_path, None);
+    } else {
+        panic!("Expected Changeset Migrate command");
+    }
+
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "migrate",
+        "--to",
+        "file",
+        "--path",
+        "changes",
+        "--history-path",
+        "archive",
+    ]);
+    if let Commands::Changeset(ChangesetCommands::Migrate(args)) = cli.command {
+        assert_eq!(args.to, "file");
+        assert_eq!(args.history_path, Some("archive".to_string()));
+    } else {
+        panic!("Expected Changeset Migrate command");
+    }
+
+    assert!(Cli::try_parse_from(["workspace", "changeset", "migrate"]).is_err());
+}
+
+#[test]
+fn test_changeset_clean_command() {
+    let cli = Cli::parse_from(["workspace", "changeset", "clean", "--prune", "--dry-run"]);
+
+    if let Commands::Changeset(ChangesetCommands::Clean(args)) = cli.command {
+        assert!(args.prune);
+        assert!(args.dry_run);
+    } else {
+        panic!("Expected Changeset Clean command");
+    }
+}
+
+#[test]
+fn test_changeset_merge_driver_command() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changeset",
+        "merge-driver",
+        ".merge_file_a1",
+        ".merge_file_b2",
+        ".merge_file_c3",
+    ]);
+
+    if let Commands::Changeset(ChangesetCommands::MergeDriver(args)) = cli.command {
+        assert_eq!(args.base, PathBuf::from(".merge_file_a1"));
+        assert_eq!(args.ours, PathBuf::from(".merge_file_b2"));
+        assert_eq!(args.theirs, PathBuf::from(".merge_file_c3"));
+    } else {
+        panic!("Expected Changeset MergeDriver command");
+    }
+
+    assert!(Cli::try_parse_from(["workspace", "changeset", "merge-driver", "a", "b"]).is_err());
+}
+
 // ============================================================================
 // Bump Command Tests
 // ============================================================================
@@ -439,6 +786,87 @@ fn test_bump_command_execute() {
     }
 }
 
+#[test]
+fn test_bump_command_filter() {
+    let cli =
+        Cli::parse_from(["workspace", "bump", "--execute", "--filter", "@myorg/*,!@myorg/docs"]);
+
+    if let Commands::Bump(args) = cli.command {
+        assert_eq!(args.filter.as_deref(), Some("@myorg/*,!@myorg/docs"));
+    } else {
+        panic!("Expected Bump command");
+    }
+}
+
+#[test]
+fn test_bump_command_sandbox() {
+    let cli = Cli::parse_from(["workspace", "bump", "--sandbox", "--git-tag"]);
+    if let Commands::Bump(args) = cli.command {
+        assert_eq!(args.sandbox.as_deref(), Some("run"));
+        assert!(args.git_tag);
+    } else {
+        panic!("Expected Bump command");
+    }
+
+    let cli = Cli::parse_from(["workspace", "bump", "--sandbox", "promote"]);
+    if let Commands::Bump(args) = cli.command {
+        assert_eq!(args.sandbox.as_deref(), Some("promote"));
+    } else {
+        panic!("Expected Bump command");
+    }
+
+    assert!(Cli::try_parse_from(["workspace", "bump", "--sandbox", "merge"]).is_err());
+    assert!(Cli::try_parse_from(["workspace", "bump", "--sandbox", "--dry-run"]).is_err());
+}
+
+#[test]
+fn test_bump_command_github_summary() {
+    let cli = Cli::parse_from(["workspace", "bump", "--dry-run", "--github-summary"]);
+
+    if let Commands::Bump(args) = cli.command {
+        assert!(args.github_summary);
+    } else {
+        panic!("Expected Bump command");
+    }
+}
+
+#[test]
+fn test_bump_command_validate_publish() {
+    let cli = Cli::parse_from(["workspace", "bump", "--execute", "--validate-publish"]);
+
+    if let Commands::Bump(args) = cli.command {
+        assert!(args.validate_publish);
+    } else {
+        panic!("Expected Bump command");
+    }
+}
+
+#[test]
+fn test_bump_command_at() {
+    let cli = Cli::parse_from(["workspace", "bump", "--dry-run", "--at", "v1.2.0"]);
+
+    if let Commands::Bump(args) = cli.command {
+        assert_eq!(args.at.as_deref(), Some("v1.2.0"));
+    } else {
+        panic!("Expected Bump command");
+    }
+
+    assert!(Cli::try_parse_from(["workspace", "bump", "--execute", "--at", "v1.2.0"]).is_err());
+    assert!(Cli::try_parse_from(["workspace", "bump", "--snapshot", "--at", "v1.2.0"]).is_err());
+}
+
+#[test]
+fn test_bump_command_manifest() {
+    let cli =
+        Cli::parse_from(["workspace", "bump", "--execute", "--manifest", "dist/release.json"]);
+
+    if let Commands::Bump(args) = cli.command {
+        assert_eq!(args.manifest, Some(PathBuf::from("dist/release.json")));
+    } else {
+        panic!("Expected Bump command");
+    }
+}
+
 #[test]
 fn test_bump_command_with_git_options() {
     let cli = Cli::parse_from([
@@ -587,6 +1015,57 @@ fn test_upgrade_apply_command() {
     }
 }
 
+#[test]
+fn test_upgrade_apply_pr_body_flag() {
+    let cli = Cli::parse_from(["workspace", "upgrade", "apply", "--force", "--pr-body"]);
+
+    if let Commands::Upgrade(UpgradeCommands::Apply(args)) = cli.command {
+        assert!(args.pr_body);
+        assert!(!args.dry_run);
+    } else {
+        panic!("Expected Upgrade Apply command");
+    }
+}
+
+#[test]
+fn test_upgrade_toolchain_flag() {
+    let cli = Cli::parse_from(["workspace", "upgrade", "check", "--toolchain"]);
+    if let Commands::Upgrade(UpgradeCommands::Check(args)) = cli.command {
+        assert!(args.toolchain);
+    } else {
+        panic!("Expected Upgrade Check command");
+    }
+
+    let cli = Cli::parse_from(["workspace", "upgrade", "apply", "--toolchain", "--dry-run"]);
+    if let Commands::Upgrade(UpgradeCommands::Apply(args)) = cli.command {
+        assert!(args.toolchain);
+        assert!(args.dry_run);
+    } else {
+        panic!("Expected Upgrade Apply command");
+    }
+
+    assert!(
+        Cli::try_parse_from(["workspace", "upgrade", "apply", "--toolchain", "--pr-body"]).is_err()
+    );
+}
+
+#[test]
+fn test_upgrade_check_resume_flag() {
+    let cli = Cli::parse_from(["workspace", "upgrade", "check"]);
+    if let Commands::Upgrade(UpgradeCommands::Check(args)) = cli.command {
+        assert!(!args.resume);
+    } else {
+        panic!("Expected Upgrade Check command");
+    }
+
+    let cli = Cli::parse_from(["workspace", "upgrade", "check", "--resume"]);
+    if let Commands::Upgrade(UpgradeCommands::Check(args)) = cli.command {
+        assert!(args.resume);
+    } else {
+        panic!("Expected Upgrade Check command");
+    }
+}
+
 #[test]
 fn test_upgrade_backups_list_command() {
     let cli = Cli::parse_from(["workspace", "upgrade", "backups", "list"]);
@@ -718,6 +1197,36 @@ fn test_changes_command_with_options() {
     }
 }
 
+#[test]
+fn test_changes_command_refs_and_paths() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "changes",
+        "--from",
+        "v1.0.0",
+        "--to",
+        "abc1234",
+        "--paths",
+        "packages/core,**/*.ts",
+        "--include-uncommitted",
+    ]);
+
+    if let Commands::Changes(args) = cli.command {
+        assert_eq!(args.since, Some("v1.0.0".to_string()));
+        assert_eq!(args.until, Some("abc1234".to_string()));
+        assert_eq!(args.paths, Some(vec!["packages/core".to_string(), "**/*.ts".to_string()]));
+        assert!(args.include_uncommitted);
+    } else {
+        panic!("Expected Changes command");
+    }
+}
+
+#[test]
+fn test_changes_command_include_uncommitted_conflicts_with_staged() {
+    let result = Cli::try_parse_from(["workspace", "changes", "--include-uncommitted", "--staged"]);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_changes_command_staged() {
     let cli = Cli::parse_from(["workspace", "changes", "--staged"]);
@@ -903,3 +1412,199 @@ fn test_output_format_arg_from_str() {
     let arg = OutputFormatArg::from_str("json-compact").expect("Failed to parse");
     assert_eq!(arg.0, OutputFormat::JsonCompact);
 }
+
+// ============================================================================
+// Monitor Command Tests
+// ============================================================================
+
+#[test]
+fn test_monitor_command_defaults() {
+    let cli = Cli::parse_from(["workspace", "monitor"]);
+
+    if let Commands::Monitor(args) = cli.command {
+        assert_eq!(args.interval, 5);
+        assert!(!args.once);
+    } else {
+        panic!("Expected Monitor command");
+    }
+}
+
+#[test]
+fn test_monitor_command_with_options() {
+    let cli = Cli::parse_from(["workspace", "monitor", "--interval", "10", "--once"]);
+
+    if let Commands::Monitor(args) = cli.command {
+        assert_eq!(args.interval, 10);
+        assert!(args.once);
+    } else {
+        panic!("Expected Monitor command");
+    }
+}
+
+#[test]
+fn test_monitor_command_rejects_zero_interval() {
+    let result = Cli::try_parse_from(["workspace", "monitor", "--interval", "0"]);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Undo Command Tests
+// ============================================================================
+
+#[test]
+fn test_undo_command_defaults() {
+    let cli = Cli::parse_from(["workspace", "undo"]);
+
+    if let Commands::Undo(args) = cli.command {
+        assert!(args.id.is_none());
+        assert!(!args.list);
+        assert!(args.kind.is_none());
+        assert_eq!(args.limit, 10);
+        assert!(!args.force);
+    } else {
+        panic!("Expected Undo command");
+    }
+}
+
+#[test]
+fn test_undo_command_with_options() {
+    let cli = Cli::parse_from(["workspace", "undo", "2024-01-15T10-30-45-000-bump", "--force"]);
+
+    if let Commands::Undo(args) = cli.command {
+        assert_eq!(args.id.as_deref(), Some("2024-01-15T10-30-45-000-bump"));
+        assert!(args.force);
+    } else {
+        panic!("Expected Undo command");
+    }
+}
+
+#[test]
+fn test_undo_command_list_conflicts_with_id() {
+    let result = Cli::try_parse_from(["workspace", "undo", "some-id", "--list"]);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Why Command Tests
+// ============================================================================
+
+#[test]
+fn test_why_command() {
+    let cli = Cli::parse_from(["workspace", "why", "lodash", "--from", "@org/app"]);
+
+    if let Commands::Why(args) = cli.command {
+        assert_eq!(args.package, "lodash");
+        assert_eq!(args.from.as_deref(), Some("@org/app"));
+    } else {
+        panic!("Expected Why command");
+    }
+}
+
+#[test]
+fn test_why_command_requires_package() {
+    let result = Cli::try_parse_from(["workspace", "why"]);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Tag Command Tests
+// ============================================================================
+
+#[test]
+fn test_tag_command() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "tag",
+        "--packages",
+        "@org/core,@org/utils",
+        "--dry-run",
+        "--push",
+    ]);
+
+    if let Commands::Tag(args) = cli.command {
+        assert_eq!(args.packages, Some(vec!["@org/core".to_string(), "@org/utils".to_string()]));
+        assert!(args.dry_run);
+        assert!(args.push);
+        assert!(!args.force);
+    } else {
+        panic!("Expected Tag command");
+    }
+}
+
+// ============================================================================
+// Aliases Command Tests
+// ============================================================================
+
+#[test]
+fn test_aliases_command_check() {
+    let cli = Cli::parse_from(["workspace", "aliases", "--check", "--imports"]);
+
+    if let Commands::Aliases(args) = cli.command {
+        assert!(args.check);
+        assert!(args.imports);
+        assert!(!args.dry_run);
+    } else {
+        panic!("Expected Aliases command");
+    }
+}
+
+#[test]
+fn test_aliases_command_check_conflicts_with_dry_run() {
+    let result = Cli::try_parse_from(["workspace", "aliases", "--check", "--dry-run"]);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Backup Command Tests
+// ============================================================================
+
+#[test]
+fn test_backup_list_command() {
+    let cli = Cli::parse_from(["workspace", "backup", "list"]);
+
+    assert!(matches!(cli.command, Commands::Backup(BackupCommands::List(_))));
+}
+
+#[test]
+fn test_backup_restore_command() {
+    let cli = Cli::parse_from(["workspace", "backup", "restore", "backup-1", "--force"]);
+
+    if let Commands::Backup(BackupCommands::Restore(args)) = cli.command {
+        assert_eq!(args.id, "backup-1");
+        assert!(args.force);
+    } else {
+        panic!("Expected Backup Restore command");
+    }
+}
+
+#[test]
+fn test_backup_prune_command_with_limits() {
+    let cli = Cli::parse_from([
+        "workspace",
+        "backup",
+        "prune",
+        "--keep",
+        "5",
+        "--max-age-days",
+        "30",
+        "--max-size-mb",
+        "100",
+        "--dry-run",
+    ]);
+
+    if let Commands::Backup(BackupCommands::Prune(args)) = cli.command {
+        assert_eq!(args.keep, Some(5));
+        assert_eq!(args.max_age_days, Some(30));
+        assert_eq!(args.max_size_mb, Some(100));
+        assert!(args.dry_run);
+        assert!(!args.force);
+    } else {
+        panic!("Expected Backup Prune command");
+    }
+}
+
+#[test]
+fn test_backup_prune_rejects_zero_limits() {
+    assert!(Cli::try_parse_from(["workspace", "backup", "prune", "--max-age-days", "0"]).is_err());
+    assert!(Cli::try_parse_from(["workspace", "backup", "prune", "--keep", "0"]).is_err());
+}
@@ -0,0 +1,193 @@
+//! Aliases command implementation.
+//!
+//! This module implements the `workspace aliases` command which generates import aliases for
+//! the internal dependencies of the workspace.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_aliases` - Writes the tsconfig `paths` (and optionally package.json `imports`)
+//!   the workspace dependencies call for, previews them with `--dry-run`, or fails with
+//!   `--check` when they drifted
+//!
+//! # How
+//!
+//! The command discovers the workspace packages and asks `AliasGenerator` from
+//! `sublime_pkg_tools` for a plan of the entries that are missing, point elsewhere, or name a
+//! package that is no longer a dependency. Unless checking or previewing, the plan is applied
+//! as an `alias-sync` transaction that `workspace undo` can revert.
+//!
+//! # Why
+//!
+//! Hand-maintained alias maps drift from the declared dependencies. Regenerating them keeps
+//! internal packages resolving to their sources, and `--check` lets CI catch the drift.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Update tsconfig paths
+//! workspace aliases
+//!
+//! # Fail if any alias map drifted
+//! workspace aliases --check
+//! ```
+
+use crate::cli::commands::AliasesArgs;
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::output::table::{TableBuilder, TableTheme};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::config::AliasesConfig;
+use sublime_pkg_tools::version::VersionResolver;
+use sublime_pkg_tools::workspace::{AliasDrift, AliasGenerator, AliasPlan};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Executes the `aliases` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration cannot be read
+/// - Workspace packages cannot be discovered
+/// - An alias file cannot be read, parsed, or written
+/// - `--check` is given and an alias map drifted from the dependencies
+pub async fn execute_aliases(
+    args: &AliasesArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+    let resolver = VersionResolver::new(root.to_path_buf(), config.clone())
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
+    let packages = resolver
+        .discover_packages()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?;
+
+    let aliases = AliasesConfig {
+        package_imports: config.aliases.package_imports || args.imports,
+        ..config.aliases.clone()
+    };
+    let generator = AliasGenerator::new(
+        root.to_path_buf(),
+        aliases,
+        config.upgrade.backup.clone(),
+        FileSystemManager::new(),
+    );
+    let plan = generator
+        .plan(&packages)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to compute aliases: {e}")))?;
+    debug!("{} alias entries drifted in {} files", plan.drift_count(), plan.files.len());
+
+    if plan.is_in_sync() || args.check || args.dry_run {
+        output_result(output, &plan, root, false)?;
+        if args.check && !plan.is_in_sync() {
+            return Err(CliError::validation(format!(
+                "{} alias entries drifted from the workspace dependencies. Run 'workspace \
+                 aliases' to update them.",
+                plan.drift_count()
+            )));
+        }
+        return Ok(());
+    }
+
+    info!("Writing aliases to {} files", plan.files.len());
+    generator
+        .apply(&plan)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to write aliases: {e}")))?;
+
+    output_result(output, &plan, root, true)
+}
+
+/// Renders the plan and whether it was written.
+fn output_result(output: &Output, plan: &AliasPlan, root: &Path, written: bool) -> Result<()> {
+    if output.format().is_json() {
+        let files = plan
+            .files
+            .iter()
+            .map(|file| AliasFileJson {
+                path: relative(&file.path, root),
+                map: file.kind.to_string(),
+                drift: file.drift.clone(),
+            })
+            .collect();
+        return output.json(&JsonResponse::success(AliasesJson {
+            in_sync: plan.is_in_sync(),
+            written,
+            files,
+        }));
+    }
+
+    if plan.is_in_sync() {
+        return output.success("Aliases match the workspace dependencies");
+    }
+
+    let mut table = TableBuilder::new()
+        .theme(TableTheme::Minimal)
+        .columns(&["File", "Map", "Entry", "State"])
+        .build();
+    for file in &plan.files {
+        let path = relative(&file.path, root);
+        for drift in &file.drift {
+            table.add_row(&[&path, file.kind.as_str(), &drift.key, drift.kind.as_str()]);
+        }
+    }
+    output.table(&mut table)?;
+    output.blank_line()?;
+
+    if written {
+        output.success(&format!(
+            "Updated {} alias entries in {} file(s)",
+            plan.drift_count(),
+            plan.files.len()
+        ))
+    } else {
+        output.warning(&format!(
+            "{} alias entries drifted from the workspace dependencies",
+            plan.drift_count()
+        ))
+    }
+}
+
+/// Returns a path relative to the workspace root for display.
+fn relative(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root).unwrap_or(path).display().to_string()
+}
+
+/// JSON response for `aliases`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AliasesJson {
+    /// Whether every alias map matched the dependencies.
+    in_sync: bool,
+    /// Whether the drift was corrected.
+    written: bool,
+    /// Files whose maps drifted.
+    files: Vec<AliasFileJson>,
+}
+
+/// JSON representation of a drifted file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AliasFileJson {
+    /// Path relative to the workspace root.
+    path: String,
+    /// Map in the file (`paths` or `imports`).
+    map: String,
+    /// Entries that drifted.
+    drift: Vec<AliasDrift>,
+}
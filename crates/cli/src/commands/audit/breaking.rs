@@ -102,7 +102,7 @@ pub async fn execute_breaking_changes_audit(
 
     // Initialize audit manager
     output.info("Initializing breaking changes audit...")?;
-    let audit_manager = AuditManager::new(workspace_root.to_path_buf(), config)
+    let audit_manager = Box::pin(AuditManager::new(workspace_root.to_path_buf(), config))
         .await
         .map_err(|e| CliError::execution(format!("Failed to initialize audit manager: {e}")))?;
 
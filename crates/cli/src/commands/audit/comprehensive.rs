@@ -12,6 +12,8 @@
 //! - Health score calculation
 //! - Report generation and display
 //! - File output handling
+//! - Applying or previewing fixes of fixable issues
+//! - Suppressing known findings with a baseline file, and writing that file
 //!
 //! # How
 //!
@@ -19,12 +21,18 @@
 //! 1. Load workspace configuration
 //! 2. Parse and validate command arguments
 //! 3. Initialize audit manager from sublime-package-tools
-//! 4. Execute selected audit sections (or all by default)
-//! 5. Aggregate results from all sections
-//! 6. Calculate overall health score
-//! 7. Apply severity filtering if requested
-//! 8. Generate formatted report
-//! 9. Display report via Output system or write to file
+//! 4. Run selected audit checks (or all by default) concurrently, reporting each
+//!    one as it completes and skipping checks that exceed their timeout
+//! 5. Aggregate results from all checks
+//! 6. With `--write-baseline`, record the findings in the baseline file; with
+//!    `--baseline`, drop the findings it suppresses
+//! 7. Calculate overall health score
+//! 8. Apply severity filtering if requested
+//! 9. Generate formatted report
+//! 10. Display report via Output system or write to file
+//! 11. With `--fix`, apply the fixes of fixable issues (or print them as diffs
+//!     with `--dry-run`)
+//! 12. With `--baseline`, fail if new findings remain at or above `--min-severity`
 //!
 //! # Why
 //!
@@ -35,19 +43,29 @@
 //! - Supports multiple output formats and destinations
 
 use crate::cli::commands::AuditArgs;
-use crate::commands::audit::report::format_audit_report;
-use crate::commands::audit::types::{AuditSection, MinSeverity, parse_sections, parse_verbosity};
+use crate::commands::audit::report::{
+    filter_issues_by_severity, format_audit_report, output_ndjson_issues,
+};
+use crate::commands::audit::types::{
+    AuditSection, MinSeverity, parse_sections, parse_verbosity, selected_checks,
+};
 use crate::error::{CliError, Result};
 use crate::output::Output;
+use chrono::{NaiveDate, Utc};
+use futures::StreamExt;
 use serde::Serialize;
-use std::path::Path;
-use sublime_pkg_tools::audit::AuditManager;
+use std::path::{Path, PathBuf};
+use std::pin::pin;
+use sublime_pkg_tools::audit::{AuditBaseline, BaselineComparison, DEFAULT_BASELINE_FILE};
+use sublime_pkg_tools::audit::{AuditCheckReport, AuditManager, BuiltinCheck};
 use sublime_pkg_tools::audit::{AuditIssue, IssueSeverity};
 use sublime_pkg_tools::audit::{
-    BreakingChangesAuditSection, DependencyAuditSection, UpgradeAuditSection,
-    VersionConsistencyAuditSection,
+    BreakingChangesAuditSection, CustomCheckSection, DependencyAuditSection,
+    REGISTRY_OWNERSHIP_CHECK_ID, UpgradeAuditSection, VersionConsistencyAuditSection,
 };
 use sublime_pkg_tools::config::ConfigLoader;
+use sublime_pkg_tools::error::AuditError;
+use sublime_standard_tools::filesystem::FileSystemManager;
 
 /// Aggregated results from all audit sections.
 ///
@@ -62,6 +80,7 @@ use sublime_pkg_tools::config::ConfigLoader;
 ///     dependencies: Some(dependencies_section),
 ///     version_consistency: Some(version_section),
 ///     breaking_changes: None, // Not yet implemented
+///     custom: Vec::new(),
 /// };
 /// ```
 #[derive(Debug)]
@@ -77,6 +96,9 @@ pub struct AuditResults {
 
     /// Results from breaking changes audit section.
     pub breaking_changes: Option<BreakingChangesAuditSection>,
+
+    /// Results from checks that are not built in, such as the registry ownership check.
+    pub custom: Vec<CustomCheckSection>,
 }
 
 impl AuditResults {
@@ -112,6 +134,10 @@ impl AuditResults {
             issues.extend(breaking_changes.issues.iter());
         }
 
+        for section in &self.custom {
+            issues.extend(section.issues.iter());
+        }
+
         issues
     }
 
@@ -136,6 +162,37 @@ impl AuditResults {
         self.all_issues().iter().filter(|issue| &issue.severity == severity).count()
     }
 
+    /// Removes the findings suppressed by a baseline.
+    ///
+    /// # Arguments
+    ///
+    /// * `baseline` - Known findings
+    /// * `today` - Day used to decide which baseline entries have expired
+    ///
+    /// # Returns
+    ///
+    /// How the findings compared with the baseline, before suppressed ones were removed.
+    pub fn apply_baseline(
+        &mut self,
+        baseline: &AuditBaseline,
+        today: NaiveDate,
+    ) -> BaselineComparison {
+        let comparison = baseline.compare(self.all_issues(), today);
+
+        let sections = [
+            self.upgrades.as_mut().map(|section| &mut section.issues),
+            self.dependencies.as_mut().map(|section| &mut section.issues),
+            self.version_consistency.as_mut().map(|section| &mut section.issues),
+            self.breaking_changes.as_mut().map(|section| &mut section.issues),
+        ];
+        let custom = self.custom.iter_mut().map(|section| &mut section.issues);
+        for issues in sections.into_iter().flatten().chain(custom) {
+            issues.retain(|issue| !baseline.suppresses(issue, today));
+        }
+
+        comparison
+    }
+
     /// Calculates an overall health score from all audit sections.
     ///
     /// The health score is calculated based on:
@@ -284,6 +341,19 @@ struct ExportIssue {
     recommendation: Option<String>,
 }
 
+/// Converts audit issues into their export form.
+fn export_issues(issues: &[AuditIssue]) -> Vec<ExportIssue> {
+    issues
+        .iter()
+        .map(|issue| ExportIssue {
+            severity: format!("{:?}", issue.severity),
+            category: format!("{:?}", issue.category),
+            description: issue.description.clone(),
+            recommendation: issue.suggestion.clone(),
+        })
+        .collect()
+}
+
 /// Creates exportable data from audit results.
 ///
 /// Converts internal audit results into a serializable structure
@@ -314,16 +384,7 @@ pub(crate) fn create_exportable_data(
 
     // Upgrades section
     if let Some(ref upgrades) = results.upgrades {
-        let issues: Vec<ExportIssue> = upgrades
-            .issues
-            .iter()
-            .map(|issue| ExportIssue {
-                severity: format!("{:?}", issue.severity),
-                category: format!("{:?}", issue.category),
-                description: issue.description.clone(),
-                recommendation: issue.suggestion.clone(),
-            })
-            .collect();
+        let issues = export_issues(&upgrades.issues);
 
         sections.push(ExportSection {
             name: "Upgrades".to_string(),
@@ -339,16 +400,7 @@ pub(crate) fn create_exportable_data(
 
     // Dependencies section
     if let Some(ref dependencies) = results.dependencies {
-        let issues: Vec<ExportIssue> = dependencies
-            .issues
-            .iter()
-            .map(|issue| ExportIssue {
-                severity: format!("{:?}", issue.severity),
-                category: format!("{:?}", issue.category),
-                description: issue.description.clone(),
-                recommendation: issue.suggestion.clone(),
-            })
-            .collect();
+        let issues = export_issues(&dependencies.issues);
 
         sections.push(ExportSection {
             name: "Dependencies".to_string(),
@@ -363,16 +415,7 @@ pub(crate) fn create_exportable_data(
 
     // Version consistency section
     if let Some(ref version_consistency) = results.version_consistency {
-        let issues: Vec<ExportIssue> = version_consistency
-            .issues
-            .iter()
-            .map(|issue| ExportIssue {
-                severity: format!("{:?}", issue.severity),
-                category: format!("{:?}", issue.category),
-                description: issue.description.clone(),
-                recommendation: issue.suggestion.clone(),
-            })
-            .collect();
+        let issues = export_issues(&version_consistency.issues);
 
         sections.push(ExportSection {
             name: "Version Consistency".to_string(),
@@ -386,16 +429,7 @@ pub(crate) fn create_exportable_data(
 
     // Breaking changes section
     if let Some(ref breaking_changes) = results.breaking_changes {
-        let issues: Vec<ExportIssue> = breaking_changes
-            .issues
-            .iter()
-            .map(|issue| ExportIssue {
-                severity: format!("{:?}", issue.severity),
-                category: format!("{:?}", issue.category),
-                description: issue.description.clone(),
-                recommendation: issue.suggestion.clone(),
-            })
-            .collect();
+        let issues = export_issues(&breaking_changes.issues);
 
         sections.push(ExportSection {
             name: "Breaking Changes".to_string(),
@@ -408,6 +442,17 @@ pub(crate) fn create_exportable_data(
         });
     }
 
+    // Sections of custom checks
+    for section in &results.custom {
+        let issues = export_issues(&section.issues);
+
+        sections.push(ExportSection {
+            name: section.id.clone(),
+            description: format!("Results of the {} check.", section.id),
+            issues,
+        });
+    }
+
     ExportableAuditData {
         title: "Project Audit Report".to_string(),
         health_score,
@@ -457,6 +502,11 @@ pub(crate) fn create_exportable_data(
 ///     no_health_score: false,
 ///     export: None,
 ///     export_file: None,
+///     fix: false,
+///     dry_run: false,
+///     baseline: None,
+///     write_baseline: false,
+///     baseline_expires: None,
 /// };
 ///
 /// let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -464,6 +514,7 @@ pub(crate) fn create_exportable_data(
 ///
 /// execute_audit(&args, &output, workspace_root, None).await?;
 /// ```
+#[allow(clippy::too_many_lines)]
 pub async fn execute_audit(
     args: &AuditArgs,
     output: &Output,
@@ -474,65 +525,78 @@ pub async fn execute_audit(
     let sections = parse_sections(&args.sections)?;
     let min_severity = MinSeverity::parse(&args.min_severity)?;
     let verbosity = parse_verbosity(&args.verbosity)?;
+    let baseline_expires = parse_baseline_expires(args.baseline_expires.as_deref())?;
 
     // Load configuration
     let config = load_audit_config(config_path).await?;
 
     // Initialize audit manager
-    let audit_manager = AuditManager::new(workspace_root.to_path_buf(), config)
+    let audit_manager = Box::pin(AuditManager::new(workspace_root.to_path_buf(), config))
         .await
         .map_err(|e| CliError::execution(format!("Failed to initialize audit manager: {e}")))?;
 
-    // Execute audit sections based on selection
+    // Run the selected checks concurrently, reporting each one as it completes
+    let checks = available_checks(&audit_manager, &sections)?;
     let mut results = AuditResults {
         upgrades: None,
         dependencies: None,
         version_consistency: None,
         breaking_changes: None,
+        custom: Vec::new(),
     };
 
-    // Determine which sections to run
-    let run_all = sections.iter().any(|s| s.is_all());
-
-    // Run upgrade audit if requested or all
-    if run_all || sections.contains(&AuditSection::Upgrades) {
-        output.info("Running upgrade audit...")?;
-        let upgrades = audit_manager
-            .audit_upgrades()
-            .await
-            .map_err(|e| CliError::execution(format!("Upgrade audit failed: {e}")))?;
-        results.upgrades = Some(upgrades);
-    }
-
-    // Run dependency audit if requested or all
-    if run_all || sections.contains(&AuditSection::Dependencies) {
-        output.info("Running dependency audit...")?;
-        let dependencies = audit_manager
-            .audit_dependencies()
-            .await
-            .map_err(|e| CliError::execution(format!("Dependency audit failed: {e}")))?;
-        results.dependencies = Some(dependencies);
-    }
-
-    // Run version consistency audit if requested or all
-    if run_all || sections.contains(&AuditSection::VersionConsistency) {
-        output.info("Running version consistency audit...")?;
-        let version_consistency = audit_manager
-            .audit_version_consistency()
-            .await
-            .map_err(|e| CliError::execution(format!("Version consistency audit failed: {e}")))?;
-        results.version_consistency = Some(version_consistency);
+    output.info(&format!("Running {} audit check(s)...", checks.len()))?;
+
+    let mut cut_short = Vec::new();
+    let mut outcomes = pin!(audit_manager.check_stream(&checks));
+    while let Some(outcome) = outcomes.next().await {
+        let check = BuiltinCheck::from_id(&outcome.check)
+            .map_or_else(|| outcome.check.clone(), |builtin| builtin.to_string());
+        let report = match outcome.result {
+            Ok(report) => report,
+            Err(AuditError::Timeout { duration_secs }) => {
+                output.warning(&format!(
+                    "Skipped {check} audit: no result after {duration_secs}s \
+                     (raise audit.check_timeout_secs to allow more time)"
+                ))?;
+                continue;
+            }
+            Err(AuditError::DeadlineExceeded { .. }) => {
+                output.warning(&format!("Skipped {check} audit: the --timeout deadline passed"))?;
+                cut_short.push(check);
+                continue;
+            }
+            Err(e) => {
+                return Err(CliError::execution(format!(
+                    "{} audit failed: {e}",
+                    capitalize(&check)
+                )));
+            }
+        };
+
+        output.info(&format!(
+            "Finished {check} audit in {:.1}s ({} issue(s))",
+            outcome.duration.as_secs_f64(),
+            report.issues().len()
+        ))?;
+        output_ndjson_issues(&report, min_severity, output)?;
+
+        match report {
+            AuditCheckReport::Upgrades(section) => results.upgrades = Some(section),
+            AuditCheckReport::Dependencies(section) => results.dependencies = Some(section),
+            AuditCheckReport::VersionConsistency(section) => {
+                results.version_consistency = Some(section);
+            }
+            AuditCheckReport::BreakingChanges(section) => {
+                results.breaking_changes = Some(section);
+            }
+            AuditCheckReport::Custom(section) => results.custom.push(section),
+        }
     }
 
-    // Run breaking changes audit if requested or all
-    if run_all || sections.contains(&AuditSection::BreakingChanges) {
-        output.info("Running breaking changes audit...")?;
-        let breaking_changes = audit_manager
-            .audit_breaking_changes()
-            .await
-            .map_err(|e| CliError::execution(format!("Breaking changes audit failed: {e}")))?;
-        results.breaking_changes = Some(breaking_changes);
-    }
+    // Record the findings in, or suppress them with, the baseline
+    let baseline_applied =
+        apply_baseline_args(args, baseline_expires, &mut results, workspace_root, output).await?;
 
     // Calculate health score
     let health_score =
@@ -571,6 +635,246 @@ pub async fn execute_audit(
         ))?;
     }
 
+    if !cut_short.is_empty() {
+        // The report above covers the checks that finished in time
+        return Err(CliError::timeout(format!(
+            "{} audit check(s) did not finish before the deadline: {}",
+            cut_short.len(),
+            cut_short.join(", ")
+        )));
+    }
+
+    if args.fix {
+        fix_issues(&audit_manager, &results, min_severity, args.dry_run, output).await?;
+    }
+
+    if baseline_applied {
+        let new_findings = filter_issues_by_severity(&results, min_severity).len();
+        if new_findings > 0 {
+            return Err(CliError::validation(format!(
+                "{new_findings} audit finding(s) not in the baseline; fix them or record them \
+                 with --write-baseline"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses the `--baseline-expires` date.
+///
+/// # Errors
+///
+/// Returns a validation error if the date is not in `YYYY-MM-DD` format.
+fn parse_baseline_expires(date: Option<&str>) -> Result<Option<NaiveDate>> {
+    date.map(|date| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+            CliError::validation(format!(
+                "Invalid --baseline-expires date '{date}': expected YYYY-MM-DD"
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Handles `--write-baseline` and `--baseline`.
+///
+/// # Returns
+///
+/// Whether the results were filtered by a baseline, so remaining findings are new.
+///
+/// # Errors
+///
+/// Returns an error if the baseline cannot be read, parsed or written.
+async fn apply_baseline_args(
+    args: &AuditArgs,
+    expires: Option<NaiveDate>,
+    results: &mut AuditResults,
+    workspace_root: &Path,
+    output: &Output,
+) -> Result<bool> {
+    if args.write_baseline {
+        write_baseline(results, workspace_root, args.baseline.as_deref(), expires, output).await?;
+        Ok(false)
+    } else if let Some(path) = &args.baseline {
+        apply_baseline(results, &workspace_root.join(path), output).await?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+/// Records the findings of an audit in the baseline file.
+///
+/// Findings already in the baseline keep their expiry date and reason; findings no longer
+/// reported are dropped.
+///
+/// # Arguments
+///
+/// * `results` - The audit results
+/// * `workspace_root` - Directory relative baseline paths are resolved against
+/// * `path` - Path of the baseline file, or `None` for the default
+/// * `expires` - Expiry date of the entries added for new findings
+/// * `output` - The output context
+///
+/// # Errors
+///
+/// Returns an error if an existing baseline cannot be parsed or the file cannot be written.
+async fn write_baseline(
+    results: &AuditResults,
+    workspace_root: &Path,
+    path: Option<&Path>,
+    expires: Option<NaiveDate>,
+    output: &Output,
+) -> Result<()> {
+    let path: PathBuf = workspace_root.join(path.unwrap_or(Path::new(DEFAULT_BASELINE_FILE)));
+    let fs = FileSystemManager::new();
+
+    let existing = if path.exists() {
+        AuditBaseline::load(&fs, &path).await.map_err(|e| CliError::validation(e.to_string()))?
+    } else {
+        AuditBaseline::default()
+    };
+    let baseline = existing.updated(results.all_issues(), expires);
+    baseline.save(&fs, &path).await.map_err(|e| CliError::io(e.to_string()))?;
+
+    output.success(&format!(
+        "Recorded {} finding(s) in baseline {}",
+        baseline.entries.len(),
+        path.display()
+    ))?;
+    Ok(())
+}
+
+/// Drops the findings suppressed by the baseline file from the results.
+///
+/// Warns about expired suppressions and reports entries that no longer match a finding.
+///
+/// # Errors
+///
+/// Returns a validation error if the baseline cannot be read or parsed.
+async fn apply_baseline(
+    results: &mut AuditResults,
+    path: &Path,
+    output: &Output,
+) -> Result<BaselineComparison> {
+    let baseline = AuditBaseline::load(&FileSystemManager::new(), path)
+        .await
+        .map_err(|e| CliError::validation(e.to_string()))?;
+    let comparison = results.apply_baseline(&baseline, Utc::now().date_naive());
+
+    output.info(&format!(
+        "Baseline {} suppressed {} known finding(s); {} new",
+        path.display(),
+        comparison.suppressed,
+        comparison.new_findings
+    ))?;
+    for entry in &comparison.expired {
+        let expires = entry.expires.map(|date| date.to_string()).unwrap_or_default();
+        output
+            .warning(&format!("Baseline suppression of '{}' expired on {expires}", entry.title))?;
+    }
+    if !comparison.stale.is_empty() {
+        output.info(&format!(
+            "{} baseline entr(y/ies) no longer match a finding; refresh with --write-baseline",
+            comparison.stale.len()
+        ))?;
+    }
+
+    Ok(comparison)
+}
+
+/// Returns the selected checks that are registered on the audit manager.
+///
+/// The registry ownership check is only registered when the organization's scopes are
+/// configured; it is dropped from a full audit without them, and requesting it explicitly
+/// is an error.
+///
+/// # Errors
+///
+/// Returns a validation error if the registry ownership section was requested but
+/// `audit.registry_ownership.scopes` is empty.
+fn available_checks(
+    audit_manager: &AuditManager,
+    sections: &[AuditSection],
+) -> Result<Vec<&'static str>> {
+    let registered = audit_manager.check_ids();
+    let (checks, missing): (Vec<_>, Vec<_>) =
+        selected_checks(sections).into_iter().partition(|id| registered.contains(id));
+
+    if missing.contains(&REGISTRY_OWNERSHIP_CHECK_ID)
+        && sections.contains(&AuditSection::RegistryOwnership)
+    {
+        return Err(CliError::validation(
+            "The registry-ownership section requires audit.registry_ownership.scopes \
+             to list the organization's scopes",
+        ));
+    }
+
+    Ok(checks)
+}
+
+/// Applies, or with `dry_run` previews, the fixes of the reported issues.
+///
+/// # Arguments
+///
+/// * `audit_manager` - The audit manager that produced the results
+/// * `results` - The audit results
+/// * `min_severity` - Only issues at or above this severity are fixed
+/// * `dry_run` - Whether to print the fixes as diffs instead of writing files
+/// * `output` - The output context
+///
+/// # Errors
+///
+/// Returns an error if the fixes cannot be planned or written.
+async fn fix_issues(
+    audit_manager: &AuditManager,
+    results: &AuditResults,
+    min_severity: MinSeverity,
+    dry_run: bool,
+    output: &Output,
+) -> Result<()> {
+    let fixes: Vec<_> = filter_issues_by_severity(results, min_severity)
+        .into_iter()
+        .filter(|issue| issue.is_fixable())
+        .filter_map(|issue| issue.fix.as_ref())
+        .collect();
+
+    let fixer = audit_manager.fixer();
+    let plan = fixer
+        .plan(&fixes)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to plan fixes: {e}")))?;
+
+    if plan.is_empty() {
+        output.info("No fixable issues found")?;
+        return Ok(());
+    }
+
+    if dry_run {
+        output.plain(&plan.diff())?;
+        output.info(&format!(
+            "{} fix(es) would change {} file(s); run without --dry-run to apply them",
+            plan.fixes.len(),
+            plan.files.len()
+        ))?;
+        return Ok(());
+    }
+
+    let outcome = fixer
+        .apply(&plan)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to apply fixes: {e}")))?;
+
+    output.success(&format!(
+        "Applied {} fix(es) to {} file(s)",
+        outcome.fixes_applied,
+        outcome.files.len()
+    ))?;
+    if let Some(id) = outcome.transaction_id {
+        output.info(&format!("Revert with: workspace undo {id}"))?;
+    }
+
     Ok(())
 }
 
@@ -605,3 +909,9 @@ async fn load_audit_config(
 
     Ok(config)
 }
+
+/// Capitalizes the display name of a check for use at the start of a message.
+fn capitalize(check: &str) -> String {
+    let mut chars = check.chars();
+    chars.next().map(|first| first.to_uppercase().chain(chars).collect()).unwrap_or_default()
+}
@@ -101,7 +101,7 @@ pub async fn execute_dependency_audit(
 
     // Initialize audit manager
     output.info("Initializing dependency audit...")?;
-    let audit_manager = AuditManager::new(workspace_root.to_path_buf(), config)
+    let audit_manager = Box::pin(AuditManager::new(workspace_root.to_path_buf(), config))
         .await
         .map_err(|e| CliError::execution(format!("Failed to initialize audit manager: {e}")))?;
 
@@ -187,6 +187,12 @@ fn format_dependency_report(
         display_circular_dependencies(dependencies, output)?;
     }
 
+    // Display package collisions (always shown if any exist)
+    if dependencies.has_package_collisions() {
+        output.info("")?;
+        display_package_collisions(dependencies, output)?;
+    }
+
     // Display version conflicts (always shown if any exist)
     if !dependencies.version_conflicts.is_empty() {
         output.info("")?;
@@ -227,7 +233,10 @@ fn display_dependency_summary(
     output.info("━━━ Summary ━━━")?;
 
     // Overall health message
-    if dependencies.circular_dependencies.is_empty() && dependencies.version_conflicts.is_empty() {
+    if dependencies.circular_dependencies.is_empty()
+        && dependencies.version_conflicts.is_empty()
+        && dependencies.package_collisions.is_empty()
+    {
         output.success("✓ No critical dependency issues detected!")?;
     } else {
         if !dependencies.circular_dependencies.is_empty() {
@@ -236,6 +245,12 @@ fn display_dependency_summary(
                 dependencies.circular_dependencies.len()
             ))?;
         }
+        if dependencies.has_package_collisions() {
+            output.error(&format!(
+                "⚠️  {} package collisions detected",
+                dependencies.package_collisions.len()
+            ))?;
+        }
         if !dependencies.version_conflicts.is_empty() {
             output.warning(&format!(
                 "⚠️  {} version conflicts detected",
@@ -308,6 +323,36 @@ fn display_circular_dependencies(
     Ok(())
 }
 
+/// Displays package collisions section.
+///
+/// # Arguments
+///
+/// * `dependencies` - The dependency audit section results
+/// * `output` - The output context
+///
+/// # Errors
+///
+/// Returns an error if output operations fail.
+fn display_package_collisions(
+    dependencies: &sublime_pkg_tools::audit::DependencyAuditSection,
+    output: &Output,
+) -> Result<()> {
+    output.info("━━━ Package Collisions (CRITICAL) ━━━")?;
+    output.info("")?;
+
+    for collision in &dependencies.package_collisions {
+        output.error(&format!("💥 {}", collision.package_name()))?;
+        output.info(&format!("   {}", collision.describe()))?;
+        output.info("")?;
+    }
+
+    output.info("💡 Package collisions can cause:")?;
+    output.info("   - The wrong package being versioned or published")?;
+    output.info("   - Dependents installing a registry copy instead of the local package")?;
+
+    Ok(())
+}
+
 /// Displays version conflicts section.
 ///
 /// # Arguments
@@ -485,6 +530,14 @@ fn display_dependency_recommendations(
         recommendations.push("   - Restructure package dependencies");
     }
 
+    // Package collisions - as severe as cycles
+    if dependencies.has_package_collisions() {
+        recommendations.push("🚨 Resolve package collisions");
+        recommendations.push("   - Give every workspace package a unique name");
+        recommendations.push("   - Move nested packages out of other package directories");
+        recommendations.push("   - Widen ranges or use workspace: for local packages");
+    }
+
     // Version conflicts - important
     if !dependencies.version_conflicts.is_empty() {
         recommendations.push("⚠️  Resolve version conflicts to ensure consistency");
@@ -33,7 +33,7 @@ use crate::error::Result;
 use crate::output::{JsonResponse, Output};
 use serde::Serialize;
 use std::path::Path;
-use sublime_pkg_tools::audit::{AuditIssue, IssueSeverity, Verbosity};
+use sublime_pkg_tools::audit::{AuditCheckReport, AuditIssue, IssueSeverity, Verbosity};
 
 /// Formats and displays an audit report.
 ///
@@ -87,6 +87,11 @@ pub async fn format_audit_report(
     output: &Output,
     output_file: Option<&Path>,
 ) -> Result<()> {
+    // Issues were streamed as each check completed; only the summary is left
+    if output.format().is_ndjson() {
+        return output_ndjson_summary(results, health_score, min_severity, output);
+    }
+
     // Check if JSON output is requested
     if output.format().is_json() {
         return output_json_report(results, health_score, min_severity, output);
@@ -145,17 +150,20 @@ pub(crate) fn filter_issues_by_severity(
     results
         .all_issues()
         .into_iter()
-        .filter(|issue| {
-            matches!(
-                (min_severity, &issue.severity),
-                (MinSeverity::Critical, IssueSeverity::Critical)
-                    | (MinSeverity::Warning, IssueSeverity::Critical | IssueSeverity::Warning)
-                    | (MinSeverity::Info, _)
-            )
-        })
+        .filter(|issue| meets_min_severity(issue, min_severity))
         .collect()
 }
 
+/// Returns whether an issue is at or above the minimum severity.
+fn meets_min_severity(issue: &AuditIssue, min_severity: MinSeverity) -> bool {
+    matches!(
+        (min_severity, &issue.severity),
+        (MinSeverity::Critical, IssueSeverity::Critical)
+            | (MinSeverity::Warning, IssueSeverity::Critical | IssueSeverity::Warning)
+            | (MinSeverity::Info, _)
+    )
+}
+
 /// Displays the health score with color coding.
 ///
 /// # Arguments
@@ -439,6 +447,7 @@ fn display_section_results(
     if results.breaking_changes.is_some() {
         sections_run.push("Breaking Changes");
     }
+    sections_run.extend(results.custom.iter().map(|section| section.id.as_str()));
 
     output.info("")?;
     output.info(&format!("Sections audited: {}", sections_run.join(", ")))?;
@@ -615,6 +624,31 @@ struct AuditSectionJson {
     issues: Vec<AuditIssueJson>,
 }
 
+/// One record of the NDJSON audit stream.
+#[derive(Debug, Serialize)]
+#[serde(tag = "record", rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum AuditRecordJson {
+    /// An issue, written as soon as the check that found it completes.
+    Issue {
+        /// Section the issue belongs to.
+        section: &'static str,
+
+        /// The issue.
+        #[serde(flatten)]
+        issue: AuditIssueJson,
+    },
+
+    /// The summary, written once every check has completed.
+    Summary {
+        /// Overall health score (0-100).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        health_score: Option<u8>,
+
+        /// Summary of issues found.
+        summary: AuditSummaryJson,
+    },
+}
+
 /// An individual issue in JSON output.
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -680,6 +714,48 @@ fn output_json_report(
     Ok(())
 }
 
+/// Streams the issues of a completed check as NDJSON records.
+///
+/// Called as each check completes, so consumers receive issues without waiting for
+/// the slowest check. Issues below `min_severity` are skipped.
+///
+/// # Errors
+///
+/// Returns an error if JSON serialization or writing fails.
+pub(crate) fn output_ndjson_issues(
+    report: &AuditCheckReport,
+    min_severity: MinSeverity,
+    output: &Output,
+) -> Result<()> {
+    let section = match report {
+        AuditCheckReport::Upgrades(_) => "Upgrades",
+        AuditCheckReport::Dependencies(_) => "Dependencies",
+        AuditCheckReport::VersionConsistency(_) => "VersionConsistency",
+        AuditCheckReport::BreakingChanges(_) => "BreakingChanges",
+        AuditCheckReport::Custom(_) => "Custom",
+    };
+
+    for issue in report.issues().iter().filter(|issue| meets_min_severity(issue, min_severity)) {
+        output
+            .json_line(&AuditRecordJson::Issue { section, issue: convert_issue_to_json(issue) })?;
+    }
+
+    Ok(())
+}
+
+/// Writes the closing summary record of the NDJSON audit stream.
+fn output_ndjson_summary(
+    results: &AuditResults,
+    health_score: Option<u8>,
+    min_severity: MinSeverity,
+    output: &Output,
+) -> Result<()> {
+    let filtered_issues = filter_issues_by_severity(results, min_severity);
+    let summary = build_json_summary(results, &filtered_issues);
+
+    output.json_line(&AuditRecordJson::Summary { health_score, summary })
+}
+
 /// Builds the JSON summary from audit results.
 fn build_json_summary(results: &AuditResults, filtered_issues: &[&AuditIssue]) -> AuditSummaryJson {
     // Count issues by severity
@@ -801,6 +877,20 @@ fn build_json_sections(
         }
     }
 
+    // Custom check sections, named by check id
+    for section in &results.custom {
+        let section_issues: Vec<AuditIssueJson> = section
+            .issues
+            .iter()
+            .filter(|issue| filtered_issues.iter().any(|fi| std::ptr::eq(*fi, *issue)))
+            .map(convert_issue_to_json)
+            .collect();
+
+        if !section_issues.is_empty() {
+            sections.push(AuditSectionJson { name: section.id.clone(), issues: section_issues });
+        }
+    }
+
     sections
 }
 
@@ -46,6 +46,11 @@ mod comprehensive_tests {
             no_health_score: false,
             export: None,
             export_file: None,
+            fix: false,
+            dry_run: false,
+            baseline: None,
+            write_baseline: false,
+            baseline_expires: None,
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -70,6 +75,11 @@ mod comprehensive_tests {
             no_health_score: false,
             export: None,
             export_file: None,
+            fix: false,
+            dry_run: false,
+            baseline: None,
+            write_baseline: false,
+            baseline_expires: None,
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -89,6 +99,11 @@ mod comprehensive_tests {
             no_health_score: false,
             export: None,
             export_file: None,
+            fix: false,
+            dry_run: false,
+            baseline: None,
+            write_baseline: false,
+            baseline_expires: None,
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -108,6 +123,11 @@ mod comprehensive_tests {
             no_health_score: false,
             export: None,
             export_file: None,
+            fix: false,
+            dry_run: false,
+            baseline: None,
+            write_baseline: false,
+            baseline_expires: None,
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -130,6 +150,7 @@ mod audit_results_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         assert_eq!(results.all_issues().len(), 0);
@@ -142,6 +163,7 @@ mod audit_results_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         assert_eq!(results.count_by_severity(&IssueSeverity::Critical), 0);
@@ -156,6 +178,7 @@ mod audit_results_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         assert_eq!(results.calculate_health_score(), 100);
@@ -166,9 +189,9 @@ mod audit_results_tests {
 #[allow(clippy::expect_used)]
 mod types_tests {
     use crate::commands::audit::types::{
-        AuditSection, MinSeverity, parse_sections, parse_verbosity,
+        AuditSection, MinSeverity, parse_sections, parse_verbosity, selected_checks,
     };
-    use sublime_pkg_tools::audit::{FormatOptions, Verbosity};
+    use sublime_pkg_tools::audit::{BuiltinCheck, FormatOptions, Verbosity};
 
     /// Helper function to build format options for testing.
     fn build_format_options(verbosity: Verbosity) -> FormatOptions {
@@ -251,6 +274,20 @@ mod types_tests {
         assert_eq!(sections[0], AuditSection::All);
     }
 
+    #[test]
+    fn test_selected_checks_keeps_report_order() {
+        let checks = selected_checks(&[AuditSection::BreakingChanges, AuditSection::Dependencies]);
+        assert_eq!(checks, vec!["dependencies", "breaking-changes"]);
+
+        let all = selected_checks(&[AuditSection::All]);
+        let mut expected = BuiltinCheck::ALL.map(BuiltinCheck::id).to_vec();
+        expected.push("registry-ownership");
+        assert_eq!(all, expected);
+
+        let ownership = selected_checks(&[AuditSection::RegistryOwnership]);
+        assert_eq!(ownership, vec!["registry-ownership"]);
+    }
+
     #[test]
     fn test_parse_sections_deduplicates() {
         let sections = parse_sections(&["upgrades".to_string(), "upgrades".to_string()])
@@ -359,6 +396,7 @@ mod report_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         let filtered = filter_issues_by_severity(&results, MinSeverity::Info);
@@ -397,6 +435,7 @@ mod report_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         let temp_dir = match TempDir::new() {
@@ -427,6 +466,7 @@ mod report_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -441,6 +481,7 @@ mod report_tests {
             dependencies: None,
             version_consistency: None,
             breaking_changes: None,
+            custom: Vec::new(),
         };
 
         let output = Output::new(OutputFormat::Human, std::io::stdout(), false);
@@ -722,7 +763,12 @@ mod upgrade_report_formatting_tests {
                 latest_prerelease: None,
                 deprecated: None,
                 published_at: None,
+                current_deprecated: None,
+                target_deprecated: None,
+                downloads: None,
+                repository: None,
             },
+            patch: None,
         };
 
         upgrades_map.insert("my-app".to_string(), vec![upgrade]);
@@ -6,6 +6,7 @@
 //!
 //! Provides:
 //! - Section parsing and validation
+//! - Mapping sections to the audit checks to run
 //! - Severity level parsing
 //! - Verbosity level parsing
 //! - Format option construction
@@ -26,7 +27,7 @@
 //! - Enables easy testing of argument parsing
 
 use crate::error::{CliError, Result};
-use sublime_pkg_tools::audit::Verbosity;
+use sublime_pkg_tools::audit::{BuiltinCheck, REGISTRY_OWNERSHIP_CHECK_ID, Verbosity};
 
 /// Audit sections that can be executed.
 ///
@@ -53,6 +54,8 @@ pub(crate) enum AuditSection {
     VersionConsistency,
     /// Breaking changes audit.
     BreakingChanges,
+    /// Registry ownership of the organization's scoped packages.
+    RegistryOwnership,
 }
 
 impl AuditSection {
@@ -83,9 +86,10 @@ impl AuditSection {
             "dependencies" => Ok(Self::Dependencies),
             "version-consistency" => Ok(Self::VersionConsistency),
             "breaking-changes" => Ok(Self::BreakingChanges),
+            "registry-ownership" => Ok(Self::RegistryOwnership),
             _ => Err(CliError::validation(format!(
                 "Invalid audit section '{s}'. Valid options: all, upgrades, dependencies, \
-                 version-consistency, breaking-changes"
+                 version-consistency, breaking-changes, registry-ownership"
             ))),
         }
     }
@@ -153,6 +157,43 @@ pub(crate) fn parse_sections(sections: &[String]) -> Result<Vec<AuditSection>> {
     Ok(parsed)
 }
 
+/// Returns the ids of the audit checks to run for the selected sections, in report order.
+///
+/// The registry ownership check is included with the built-in checks; it only runs when
+/// `audit.registry_ownership.scopes` is configured.
+///
+/// # Arguments
+///
+/// * `sections` - The parsed sections, as returned by `parse_sections`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let checks = selected_checks(&[AuditSection::Dependencies]);
+/// assert_eq!(checks, vec!["dependencies"]);
+/// ```
+pub(crate) fn selected_checks(sections: &[AuditSection]) -> Vec<&'static str> {
+    let run_all = sections.iter().any(|s| s.is_all());
+
+    BuiltinCheck::ALL
+        .into_iter()
+        .filter(|check| {
+            run_all
+                || sections.contains(&match check {
+                    BuiltinCheck::Upgrades => AuditSection::Upgrades,
+                    BuiltinCheck::Dependencies => AuditSection::Dependencies,
+                    BuiltinCheck::VersionConsistency => AuditSection::VersionConsistency,
+                    BuiltinCheck::BreakingChanges => AuditSection::BreakingChanges,
+                })
+        })
+        .map(BuiltinCheck::id)
+        .chain(
+            (run_all || sections.contains(&AuditSection::RegistryOwnership))
+                .then_some(REGISTRY_OWNERSHIP_CHECK_ID),
+        )
+        .collect()
+}
+
 /// Minimum severity level for filtering audit issues.
 ///
 /// # Examples
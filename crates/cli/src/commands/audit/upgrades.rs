@@ -98,7 +98,7 @@ pub async fn execute_upgrade_audit(
 
     // Initialize audit manager
     output.info("Initializing upgrade audit...")?;
-    let audit_manager = AuditManager::new(workspace_root.to_path_buf(), config)
+    let audit_manager = Box::pin(AuditManager::new(workspace_root.to_path_buf(), config))
         .await
         .map_err(|e| CliError::execution(format!("Failed to initialize audit manager: {e}")))?;
 
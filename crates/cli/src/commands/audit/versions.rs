@@ -99,7 +99,7 @@ pub async fn execute_version_consistency_audit(
 
     // Initialize audit manager
     output.info("Initializing version consistency audit...")?;
-    let audit_manager = AuditManager::new(workspace_root.to_path_buf(), config)
+    let audit_manager = Box::pin(AuditManager::new(workspace_root.to_path_buf(), config))
         .await
         .map_err(|e| CliError::execution(format!("Failed to initialize audit manager: {e}")))?;
 
@@ -21,9 +21,11 @@
 //! The command flow:
 //! 1. Validates repository state (if git operations requested)
 //! 2. Loads workspace configuration to determine versioning strategy
-//! 3. Creates ChangesetManager and loads all pending changesets
+//! 3. Creates ChangesetManager and loads all pending changesets, narrowed to the
+//!    packages selected by `--filter` when given
 //! 4. If no changesets exist, reports "nothing to bump" and exits
 //! 5. Uses VersionResolver to calculate all version bumps
+//!    and, with --validate-publish, checks that the released packages can be published
 //! 6. Shows confirmation prompt (unless --force)
 //! 7. Applies version updates via `VersionResolver::apply_versions()`
 //! 8. Generates changelogs for each affected package (if enabled)
@@ -31,7 +33,9 @@
 //! 10. Commits changes to Git (if --git-commit)
 //! 11. Creates Git tags for releases (if --git-tag)
 //! 12. Pushes tags to remote (if --git-push)
-//! 13. Displays success summary
+//! 13. Notifies the channels in `[package_tools.notifications]` of the release, or of the
+//!     failure when steps 7-9 fail
+//! 14. Displays success summary
 //!
 //! ## Strategy Handling
 //!
@@ -65,6 +69,8 @@
 //! - Changesets are only archived after all updates succeed
 //! - If archival is disabled (--no-archive), changesets remain in place
 //! - Archived changesets include full release metadata for audit trail
+//! - With `--filter`, a changeset that also lists unselected packages is split: the
+//!   released packages are archived as `<branch>@<packages>` and the rest stay pending
 //!
 //! **Note**: For full rollback capability including git operations, users should:
 //! 1. Use `--dry-run` mode first to preview changes
@@ -105,6 +111,12 @@
 //!     no_archive: false,
 //!     force: false,
 //!     show_diff: false,
+//!     github_summary: false,
+//!     validate_publish: false,
+//!     at: None,
+//!     filter: None,
+//!     sandbox: None,
+//!     manifest: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -118,21 +130,29 @@ use crate::commands::bump::git_integration::{
     commit_version_changes, create_release_tags, get_current_commit_sha, push_tags_to_remote,
     validate_repository_state,
 };
-use crate::commands::bump::preview::{load_config, merge_changesets};
+use crate::commands::bump::preview::{
+    filter_changesets, load_config, merge_changesets, no_changesets_message,
+};
+use crate::commands::bump::publish::validate_publish;
 use crate::commands::bump::snapshot::{BumpSnapshot, BumpSummary, ChangesetInfo, PackageBumpInfo};
 use crate::error::{CliError, Result};
 use crate::interactive::prompts::prompt_confirm;
 use crate::output::styling::{StatusSymbol, print_item};
 use crate::output::{JsonResponse, Output};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use sublime_git_tools::Repo;
 use sublime_pkg_tools::changelog::ChangelogGenerator;
-use sublime_pkg_tools::changeset::ChangesetManager;
-
-use sublime_pkg_tools::types::{Changeset, ReleaseInfo};
+use sublime_pkg_tools::changeset::{ChangesetManager, FileBasedChangesetStorage};
+use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_pkg_tools::notifications::{Notification, Notifier, ReleasedPackage};
+use sublime_pkg_tools::types::{
+    ArchivedChangeset, Changeset, PackageUpdate, ReleaseInfo, ReleaseManifest, ReleaseSkipReason,
+    SkippedPackage,
+};
 use sublime_pkg_tools::version::VersionResolver;
-use sublime_standard_tools::filesystem::FileSystemManager;
+use sublime_pkg_tools::workspace::{OperationKind, TransactionLog};
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, error, info, warn};
 
 /// Execute the bump apply command.
@@ -181,17 +201,49 @@ use tracing::{debug, error, info, warn};
 /// # Ok(())
 /// # }
 /// ```
+pub async fn execute_bump_apply(
+    args: &BumpArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let Some(result) = Box::pin(apply_bump(args, output, root, config_path, false)).await? else {
+        return Ok(());
+    };
+
+    if output.format().is_json() {
+        let response: JsonResponse<ExecuteResult> = JsonResponse::success(result);
+        output.json(&response)?;
+    } else {
+        display_result(output, &result)?;
+    }
+
+    info!("Version bump completed successfully");
+    Ok(())
+}
+
+/// Runs the bump pipeline and returns its result without displaying it.
+///
+/// Reports "nothing to bump" and cancellation itself and returns `None` in those cases.
+/// With `sandboxed` set the run happens in a throwaway worktree: no notifications are sent,
+/// no undo transaction is recorded, and the commit captures every change in the worktree,
+/// archived changesets included.
+///
+/// # Errors
+///
+/// Returns the same errors as [`execute_bump_apply`].
 // Allow too_many_lines: This function orchestrates the complete version bump workflow with many steps
 // including validation, version resolution, file updates, changelog generation, changeset archival,
 // and git operations. Breaking it into smaller functions would reduce readability and make the
 // sequential workflow harder to follow. The length is justified by the complex multi-step process.
 #[allow(clippy::too_many_lines)]
-pub async fn execute_bump_apply(
+pub(crate) async fn apply_bump(
     args: &BumpArgs,
     output: &Output,
     root: &Path,
     config_path: Option<&Path>,
-) -> Result<()> {
+    sandboxed: bool,
+) -> Result<Option<ExecuteResult>> {
     let workspace_root = root;
     info!("Executing bump apply in workspace: {}", workspace_root.display());
 
@@ -229,12 +281,16 @@ pub async fn execute_bump_apply(
         .await
         .map_err(|e| CliError::execution(format!("Failed to create changeset manager: {e}")))?;
 
-    let loaded_changesets = manager
+    let pending_changesets = manager
         .list_pending()
         .await
         .map_err(|e| CliError::execution(format!("Failed to load changesets: {e}")))?;
 
-    debug!("Loaded {} changeset(s)", loaded_changesets.len());
+    debug!("Loaded {} changeset(s)", pending_changesets.len());
+
+    // Step 3b: Narrow changesets to the packages selected by --filter
+    let loaded_changesets =
+        filter_changesets(args.filter.as_deref(), &config.version, &pending_changesets)?;
 
     // Step 4: Check if there are any changesets
     if loaded_changesets.is_empty() {
@@ -246,18 +302,22 @@ pub async fn execute_bump_apply(
                 files_modified: vec![],
                 tags_created: vec![],
                 commit_sha: None,
+                transaction_id: None,
+                changesets_kept_pending: vec![],
+                release_manifest: None,
                 snapshot: BumpSnapshot {
                     strategy: config.version.strategy.to_string(),
                     packages: vec![],
                     changesets: vec![],
                     summary: BumpSummary::default(),
+                    warnings: vec![],
                 },
             });
             output.json(&response)?;
         } else {
-            output.info("No changesets found. Nothing to bump.")?;
+            output.info(&no_changesets_message(args))?;
         }
-        return Ok(());
+        return Ok(None);
     }
 
     info!("Processing {} changeset(s)", loaded_changesets.len());
@@ -287,18 +347,32 @@ pub async fn execute_bump_apply(
                 files_modified: vec![],
                 tags_created: vec![],
                 commit_sha: None,
+                transaction_id: None,
+                changesets_kept_pending: vec![],
+                release_manifest: None,
                 snapshot: BumpSnapshot {
                     strategy: config.version.strategy.to_string(),
                     packages: vec![],
                     changesets: vec![],
                     summary: BumpSummary::default(),
+                    warnings: vec![],
                 },
             });
             output.json(&response)?;
         } else {
             output.info("No version updates needed. All packages are up to date.")?;
         }
-        return Ok(());
+        return Ok(None);
+    }
+
+    // Step 6b: Check that the released packages can be published, before any file changes
+    if args.validate_publish {
+        let packages = resolver
+            .discover_packages()
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?;
+        let released = resolution.updates.iter().map(|update| update.name.as_str()).collect();
+        validate_publish(output, &packages, &released).await?;
     }
 
     // Step 7: Show confirmation prompt (unless --force)
@@ -328,136 +402,283 @@ pub async fn execute_bump_apply(
         let confirmed = prompt_confirm("Proceed with version bump?", false, false)?;
         if !confirmed {
             output.info("Version bump cancelled by user.")?;
-            return Ok(());
+            return Ok(None);
         }
     }
 
     info!("Applying version updates");
 
-    // Step 8: Apply version updates
-    let apply_result = resolver.apply_versions(&merged_changeset, false).await.map_err(|e| {
-        error!("Failed to apply version updates: {}", e);
-        CliError::execution(format!("Failed to apply version updates: {e}"))
-    })?;
-
-    info!("Successfully updated {} packages", apply_result.summary.packages_updated);
-
-    // Collect modified files for git commit (package.json files that were updated)
-    let mut modified_files: Vec<PathBuf> =
-        apply_result.resolution.updates.iter().map(|u| u.path.join("package.json")).collect();
-
-    // Step 9: Generate changelogs (if enabled)
-    if !args.no_changelog && config.changelog.enabled {
-        info!("Generating changelogs");
-
-        // Try to open git repository for changelog generation
-        // Changelog needs git history, independent of whether git operations are requested
-        let repo_result = Repo::open(workspace_root.to_str().ok_or_else(|| {
-            CliError::execution("Workspace path contains invalid UTF-8".to_string())
-        })?);
-
-        if let Ok(repo_for_changelog) = repo_result {
-            let changelog_gen = ChangelogGenerator::new(
-                workspace_root.to_path_buf(),
-                repo_for_changelog,
-                fs.clone(),
-                config.changelog.clone(),
-            )
+    // Step 8: Record a transaction so the bump can be undone
+    let transaction = if config.upgrade.backup.enabled && !sandboxed {
+        let log = TransactionLog::new(
+            workspace_root.to_path_buf(),
+            config.upgrade.backup.clone(),
+            fs.clone(),
+        );
+        let files = bump_transaction_files(
+            workspace_root,
+            &config,
+            args,
+            &manager,
+            &pending_changesets,
+            &loaded_changesets,
+            &resolution.updates,
+        );
+        let id = log
+            .begin(OperationKind::VersionApply, &files)
             .await
-            .map_err(|e| {
-                error!("Failed to create changelog generator: {}", e);
-                CliError::execution(format!("Failed to create changelog generator: {e}"))
+            .map_err(|e| CliError::execution(format!("Failed to record bump transaction: {e}")))?;
+        Some((log, id))
+    } else {
+        None
+    };
+
+    // Steps 9-11: Apply version updates, write changelogs, and archive changesets
+    let written = Box::pin(async {
+        // Step 9: Apply version updates
+        let apply_result =
+            resolver.apply_versions(&merged_changeset, false).await.map_err(|e| {
+                error!("Failed to apply version updates: {}", e);
+                CliError::execution(format!("Failed to apply version updates: {e}"))
             })?;
 
-            for changeset in &loaded_changesets {
-                debug!("Generating changelog for changeset: {}", changeset.branch);
+        info!("Successfully updated {} packages", apply_result.summary.packages_updated);
+
+        // Collect modified files for git commit (package.json files that were updated)
+        let mut modified_files: Vec<PathBuf> =
+            apply_result.resolution.updates.iter().map(|u| u.path.join("package.json")).collect();
+        // Changelog sections by package, for release notifications
+        let mut release_notes: HashMap<String, String> = HashMap::new();
+        // Changelog anchors by package, for the archived release info
+        let mut changelog_anchors: HashMap<String, String> = HashMap::new();
+
+        // Step 10: Generate changelogs (if enabled)
+        if !args.no_changelog && config.changelog.enabled {
+            info!("Generating changelogs");
+
+            // Try to open git repository for changelog generation
+            // Changelog needs git history, independent of whether git operations are requested
+            let repo_result = Repo::open(workspace_root.to_str().ok_or_else(|| {
+                CliError::execution("Workspace path contains invalid UTF-8".to_string())
+            })?);
+
+            if let Ok(repo_for_changelog) = repo_result {
+                let changelog_gen = ChangelogGenerator::new(
+                    workspace_root.to_path_buf(),
+                    repo_for_changelog,
+                    fs.clone(),
+                    config.changelog.clone(),
+                )
+                .await
+                .map_err(|e| {
+                    error!("Failed to create changelog generator: {}", e);
+                    CliError::execution(format!("Failed to create changelog generator: {e}"))
+                })?;
+
+                // The release sections replace the sections listing pending changes
+                if config.changelog.unreleased_section {
+                    let rolled =
+                        changelog_gen.update_unreleased(&[], false).await.map_err(|e| {
+                            error!("Failed to remove unreleased changelog sections: {}", e);
+                            CliError::execution(format!(
+                                "Failed to remove unreleased changelog sections: {e}"
+                            ))
+                        })?;
+                    modified_files.extend(rolled);
+                }
 
-                let changelogs = changelog_gen
-                    .generate_from_changeset(changeset, &apply_result.resolution)
-                    .await
-                    .map_err(|e| {
+                for changeset in &loaded_changesets {
+                    debug!("Generating changelog for changeset: {}", changeset.branch);
+
+                    let changelogs = changelog_gen
+                        .generate_from_changeset(changeset, &apply_result.resolution)
+                        .await
+                        .map_err(|e| {
+                            error!(
+                                "Failed to generate changelog for changeset '{}': {}",
+                                changeset.branch, e
+                            );
+                            CliError::execution(format!(
+                                "Failed to generate changelog for changeset '{}': {}",
+                                changeset.branch, e
+                            ))
+                        })?;
+
+                    info!(
+                        "Generated {} changelog(s) for changeset '{}'",
+                        changelogs.len(),
+                        changeset.branch
+                    );
+
+                    // Write CHANGELOG.md files to disk and add to modified files list
+                    changelog_gen.write_changelogs(&changelogs).await.map_err(|e| {
                         error!(
-                            "Failed to generate changelog for changeset '{}': {}",
+                            "Failed to write changelogs for changeset '{}': {}",
                             changeset.branch, e
                         );
                         CliError::execution(format!(
-                            "Failed to generate changelog for changeset '{}': {}",
+                            "Failed to write changelogs for changeset '{}': {}",
                             changeset.branch, e
                         ))
                     })?;
 
-                info!(
-                    "Generated {} changelog(s) for changeset '{}'",
-                    changelogs.len(),
-                    changeset.branch
-                );
+                    for changelog in changelogs {
+                        info!("Written changelog to: {}", changelog.changelog_path.display());
 
-                // Write CHANGELOG.md files to disk and add to modified files list
-                for changelog in changelogs {
-                    // Write the changelog to filesystem
-                    changelog.write(&fs).await.map_err(|e| {
-                        error!(
-                            "Failed to write changelog for package {:?}: {}",
-                            changelog.package_name, e
-                        );
-                        CliError::execution(format!(
-                            "Failed to write changelog for package {:?}: {}",
-                            changelog.package_name, e
-                        ))
-                    })?;
+                        // Add to modified files for git commit
+                        modified_files.push(changelog.changelog_path.clone());
 
-                    info!("Written changelog to: {}", changelog.changelog_path.display());
+                        let path = changelog
+                            .changelog_path
+                            .strip_prefix(workspace_root)
+                            .unwrap_or(&changelog.changelog_path);
+                        let anchor = format!(
+                            "{}#{}",
+                            path.display().to_string().replace('\\', "/"),
+                            changelog.changelog.anchor(&config.changelog)
+                        );
 
-                    // Add to modified files for git commit
-                    modified_files.push(changelog.changelog_path.clone());
+                        // The root changelog links packages released at its version that
+                        // have no changelog of their own
+                        if changelog.package_name.is_none() {
+                            for update in &apply_result.resolution.updates {
+                                if update.next_version.to_string() == changelog.changelog.version {
+                                    changelog_anchors
+                                        .entry(update.name.clone())
+                                        .or_insert_with(|| anchor.clone());
+                                }
+                            }
+                        }
+
+                        if let Some(name) = &changelog.package_name {
+                            changelog_anchors.insert(name.clone(), anchor);
+                            release_notes.insert(
+                                name.clone(),
+                                changelog.changelog.to_markdown(&config.changelog),
+                            );
+                        }
+                    }
                 }
+            } else {
+                warn!("Changelog generation requested but no git repository available, skipping");
             }
         } else {
-            warn!("Changelog generation requested but no git repository available, skipping");
+            debug!("Changelog generation disabled");
         }
-    } else {
-        debug!("Changelog generation disabled");
-    }
-
-    // Step 10: Archive changesets (if enabled)
-    let mut archived_count = 0;
-    if args.no_archive {
-        debug!("Changeset archival disabled");
-    } else {
-        info!("Archiving changesets");
 
-        // Build release info
-        let commit_sha = if let Some(ref repo) = git_repo {
-            get_current_commit_sha(repo).unwrap_or_else(|_| "unknown".to_string())
+        // Step 11: Archive changesets (if enabled); with --filter, changesets that list
+        // unselected packages are split and keep those packages pending
+        let mut archived_count = 0;
+        let mut kept_pending = Vec::new();
+        let mut consumed = Vec::new();
+        if args.no_archive && args.manifest.is_none() {
+            debug!("Changeset archival disabled");
         } else {
-            "unknown".to_string()
-        };
+            // Build release info
+            let commit_sha = if let Some(ref repo) = git_repo {
+                get_current_commit_sha(repo).unwrap_or_else(|_| "unknown".to_string())
+            } else {
+                "unknown".to_string()
+            };
+
+            let mut versions_map = HashMap::new();
+            for update in &apply_result.resolution.updates {
+                versions_map.insert(update.name.clone(), update.next_version.to_string());
+            }
 
-        let mut versions_map = HashMap::new();
-        for update in &apply_result.resolution.updates {
-            versions_map.insert(update.name.clone(), update.next_version.to_string());
-        }
+            let release_info = ReleaseInfo::new("workspace-cli", commit_sha.as_str(), versions_map);
+            let private = private_packages(&resolver).await?;
 
-        let release_info = ReleaseInfo::new("workspace-cli", commit_sha.as_str(), versions_map);
+            if args.no_archive {
+                debug!("Changeset archival disabled");
+            } else {
+                info!("Archiving changesets");
+            }
 
-        for changeset in &loaded_changesets {
-            debug!("Archiving changeset: {}", changeset.branch);
+            for changeset in &loaded_changesets {
+                let original =
+                    pending_changesets.iter().find(|original| original.branch == changeset.branch);
+                let release_info = changeset_release_info(
+                    &release_info,
+                    original,
+                    changeset,
+                    &private,
+                    &changelog_anchors,
+                );
+                let released = original.map_or_else(
+                    || changeset.clone(),
+                    |original| original.split_packages(&changeset.packages).0,
+                );
+                consumed.push(ArchivedChangeset::new(released, release_info.clone()));
 
-            manager.archive(&changeset.branch, release_info.clone()).await.map_err(|e| {
-                error!("Failed to archive changeset '{}': {}", changeset.branch, e);
-                CliError::execution(format!(
-                    "Failed to archive changeset '{}': {}",
-                    changeset.branch, e
-                ))
-            })?;
+                if args.no_archive {
+                    continue;
+                }
+
+                debug!("Archiving changeset: {}", changeset.branch);
+
+                let pending = manager
+                    .archive_packages(&changeset.branch, &changeset.packages, release_info)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to archive changeset '{}': {}", changeset.branch, e);
+                        CliError::execution(format!(
+                            "Failed to archive changeset '{}': {}",
+                            changeset.branch, e
+                        ))
+                    })?;
+
+                archived_count += 1;
+                if let Some(pending) = pending {
+                    info!(
+                        "Changeset '{}' keeps {} package(s) pending",
+                        pending.branch,
+                        pending.packages.len()
+                    );
+                    kept_pending.push(pending.branch);
+                }
+            }
 
-            archived_count += 1;
+            if !args.no_archive {
+                info!("Archived {} changeset(s)", archived_count);
+            }
         }
 
-        info!("Archived {} changeset(s)", archived_count);
+        Ok::<_, CliError>((
+            apply_result,
+            modified_files,
+            archived_count,
+            kept_pending,
+            release_notes,
+            consumed,
+        ))
+    })
+    .await;
+
+    let (apply_result, modified_files, archived_count, kept_pending, mut release_notes, consumed) =
+        match written {
+            Ok(written) => written,
+            Err(e) => {
+                if let Some((log, id)) = &transaction
+                    && let Err(rollback_error) = log.rollback(id).await
+                {
+                    error!("Failed to roll back bump transaction '{}': {}", id, rollback_error);
+                }
+                if !sandboxed {
+                    send_notification(&config, &Notification::failure("bump", e.to_string())).await;
+                }
+                return Err(e);
+            }
+        };
+
+    if let Some((log, id)) = &transaction {
+        log.commit(id).await.map_err(|e| {
+            CliError::execution(format!("Failed to commit bump transaction '{id}': {e}"))
+        })?;
+        info!("Recorded bump transaction: {}", id);
     }
 
-    // Step 11: Git operations
+    // Step 12: Git operations
     let mut commit_sha = None;
     let mut tags_created = Vec::new();
 
@@ -467,6 +688,11 @@ pub async fn execute_bump_apply(
             info!("Committing version changes to Git");
 
             let commit_message = build_commit_message(&apply_result.resolution.updates);
+            if sandboxed {
+                repo.add_all().map_err(|e| {
+                    CliError::execution(format!("Failed to stage sandbox changes: {e}"))
+                })?;
+            }
             let sha = commit_version_changes(repo, &modified_files, &commit_message)?;
 
             commit_sha = Some(sha.clone());
@@ -484,7 +710,7 @@ pub async fn execute_bump_apply(
                 .map(|u| (u.name.clone(), u.next_version.to_string()))
                 .collect();
 
-            tags_created = create_release_tags(repo, &package_versions)?;
+            tags_created = create_release_tags(repo, &package_versions, &config.changelog)?;
             info!("Created {} tag(s)", tags_created.len());
         }
 
@@ -498,30 +724,190 @@ pub async fn execute_bump_apply(
         }
     }
 
-    // Step 12: Build and display result
-    let result = ExecuteResult {
+    let released = apply_result
+        .resolution
+        .updates
+        .iter()
+        .map(|update| {
+            let package = ReleasedPackage::new(&update.name, update.next_version.to_string())
+                .with_previous_version(update.current_version.to_string());
+            match release_notes.remove(&update.name) {
+                Some(notes) => package.with_changelog(notes),
+                None => package,
+            }
+        })
+        .collect();
+    if !sandboxed {
+        send_notification(&config, &Notification::release(released)).await;
+    }
+
+    // Step 13: Write the release manifest (if requested)
+    let release_manifest = match &args.manifest {
+        Some(path) => {
+            let path = if path.is_absolute() { path.clone() } else { workspace_root.join(path) };
+            let mut manifest = ReleaseManifest::from_archived(&consumed);
+            if let Some(sha) = &commit_sha {
+                manifest.git_commit.clone_from(sha);
+            }
+            write_release_manifest(&fs, &path, &manifest).await?;
+            info!(
+                "Wrote release manifest to {} ({} released, {} skipped)",
+                path.display(),
+                manifest.released_count(),
+                manifest.skipped_count()
+            );
+            Some(path)
+        }
+        None => None,
+    };
+
+    // Step 14: Build the result
+    Ok(Some(ExecuteResult {
         strategy: config.version.strategy.to_string(),
         packages_updated: apply_result.summary.packages_updated,
         changesets_archived: archived_count,
         files_modified: modified_files.clone(),
         tags_created: tags_created.clone(),
         commit_sha: commit_sha.clone(),
+        transaction_id: transaction.map(|(_, id)| id),
+        changesets_kept_pending: kept_pending,
+        release_manifest,
         snapshot: build_result_snapshot(
             &config,
             &apply_result.resolution.updates,
             &loaded_changesets,
+            &apply_result.warnings,
         ),
+    }))
+}
+
+/// Returns the names of the workspace packages marked `"private": true`.
+async fn private_packages(resolver: &VersionResolver) -> Result<HashSet<String>> {
+    let packages = resolver
+        .discover_packages()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?;
+    Ok(packages
+        .iter()
+        .filter(|package| package.package_json().private)
+        .map(|package| package.name().to_string())
+        .collect())
+}
+
+/// Builds the release info archived with a changeset.
+///
+/// Records the packages `--filter` left pending and the private packages of the changeset
+/// as skipped, and the changelog anchors of its packages.
+fn changeset_release_info(
+    release_info: &ReleaseInfo,
+    original: Option<&Changeset>,
+    changeset: &Changeset,
+    private: &HashSet<String>,
+    changelog_anchors: &HashMap<String, String>,
+) -> ReleaseInfo {
+    let filtered = original
+        .into_iter()
+        .flat_map(|original| &original.packages)
+        .filter(|name| !changeset.packages.contains(name))
+        .map(|name| SkippedPackage::new(name, ReleaseSkipReason::Filtered));
+    let private = changeset
+        .packages
+        .iter()
+        .filter(|name| private.contains(*name))
+        .map(|name| SkippedPackage::new(name, ReleaseSkipReason::Private));
+    let changelogs = changeset
+        .packages
+        .iter()
+        .filter_map(|name| changelog_anchors.get(name).map(|anchor| (name.clone(), anchor.clone())))
+        .collect();
+
+    release_info.clone().with_skipped(filtered.chain(private).collect()).with_changelogs(changelogs)
+}
+
+/// Writes the release manifest as pretty-printed JSON.
+async fn write_release_manifest(
+    fs: &FileSystemManager,
+    path: &Path,
+    manifest: &ReleaseManifest,
+) -> Result<()> {
+    let content = serde_json::to_string_pretty(manifest).map_err(|e| {
+        CliError::execution(format!("Failed to serialize the release manifest: {e}"))
+    })?;
+    fs.write_file_string(path, &content).await.map_err(|e| {
+        CliError::io(format!("Failed to write release manifest to {}: {e}", path.display()))
+    })
+}
+
+/// Sends a notification to the channels in `[package_tools.notifications]`.
+///
+/// Notifications never fail the bump; channels that cannot be reached are logged as warnings.
+pub(crate) async fn send_notification(config: &PackageToolsConfig, notification: &Notification) {
+    if !config.notifications.enabled || config.notifications.channels.is_empty() {
+        return;
+    }
+
+    let notifier = match Notifier::new(config.notifications.clone()) {
+        Ok(notifier) => notifier,
+        Err(e) => {
+            warn!("Notifications not sent: {}", e);
+            return;
+        }
     };
 
-    if output.format().is_json() {
-        let response: JsonResponse<ExecuteResult> = JsonResponse::success(result);
-        output.json(&response)?;
-    } else {
-        display_result(output, &result)?;
+    let report = notifier.notify(notification).await;
+    for failure in report.failures() {
+        if let Some(error) = &failure.error {
+            warn!("{}", error);
+        }
     }
+    debug!("Sent {} notification(s)", report.deliveries.len());
+}
 
-    info!("Version bump completed successfully");
-    Ok(())
+/// Collects the files a bump may write so they can be restored by `workspace undo`.
+///
+/// Includes package manifests, changelogs (package and root), and pending and archived
+/// changeset files. Files that do not exist yet are recorded as created by the bump.
+/// Changesets are archived under their id; those narrowed by `--filter` under the id of
+/// their released part.
+fn bump_transaction_files(
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+    args: &BumpArgs,
+    manager: &ChangesetManager<FileBasedChangesetStorage<FileSystemManager>>,
+    pending: &[Changeset],
+    changesets: &[Changeset],
+    updates: &[PackageUpdate],
+) -> Vec<PathBuf> {
+    let absolute = |path: &Path| {
+        if path.is_absolute() { path.to_path_buf() } else { workspace_root.join(path) }
+    };
+    let mut files: Vec<PathBuf> =
+        updates.iter().map(|u| absolute(&u.path).join("package.json")).collect();
+
+    if !args.no_changelog && config.changelog.enabled {
+        files.extend(
+            updates
+                .iter()
+                .map(|u| absolute(&u.path).join(config.changelog.package_filename(&u.name))),
+        );
+        files.push(workspace_root.join(&config.changelog.filename));
+    }
+
+    for changeset in changesets {
+        files.push(manager.storage().changeset_path(&changeset.branch));
+        if !args.no_archive {
+            let archived_id =
+                pending.iter().find(|original| original.branch == changeset.branch).map_or_else(
+                    || changeset.id.clone(),
+                    |original| original.split_packages(&changeset.packages).0.id,
+                );
+            files.push(manager.storage().archive_path(&archived_id));
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    files.retain(|file| seen.insert(file.clone()));
+    files
 }
 
 /// Builds a list of operations that will be performed.
@@ -596,6 +982,7 @@ fn build_result_snapshot(
     config: &sublime_pkg_tools::config::PackageToolsConfig,
     updates: &[sublime_pkg_tools::types::PackageUpdate],
     changesets: &[Changeset],
+    warnings: &[sublime_pkg_tools::types::Warning],
 ) -> BumpSnapshot {
     let packages: Vec<PackageBumpInfo> = updates
         .iter()
@@ -631,11 +1018,12 @@ fn build_result_snapshot(
         packages,
         changesets: changeset_infos,
         summary,
+        warnings: warnings.to_vec(),
     }
 }
 
 /// Displays the execution result.
-fn display_result(output: &Output, result: &ExecuteResult) -> Result<()> {
+pub(crate) fn display_result(output: &Output, result: &ExecuteResult) -> Result<()> {
     StatusSymbol::Success.print_line("Version bump completed successfully!");
     output.blank_line()?;
 
@@ -644,6 +1032,10 @@ fn display_result(output: &Output, result: &ExecuteResult) -> Result<()> {
     print_item("  Files modified", &result.files_modified.len().to_string(), false);
     print_item("  Changesets archived", &result.changesets_archived.to_string(), false);
 
+    if !result.changesets_kept_pending.is_empty() {
+        print_item("  Changesets kept pending", &result.changesets_kept_pending.join(", "), false);
+    }
+
     if !result.tags_created.is_empty() {
         print_item("  Tags created", &result.tags_created.len().to_string(), false);
     }
@@ -652,6 +1044,10 @@ fn display_result(output: &Output, result: &ExecuteResult) -> Result<()> {
         print_item("  Commit", &sha[..8.min(sha.len())], true);
     }
 
+    if let Some(ref manifest) = result.release_manifest {
+        print_item("  Release manifest", &manifest.display().to_string(), false);
+    }
+
     output.blank_line()?;
 
     StatusSymbol::Info.print_line("Updated packages:");
@@ -663,6 +1059,11 @@ fn display_result(output: &Output, result: &ExecuteResult) -> Result<()> {
         );
     }
 
+    if !result.snapshot.warnings.is_empty() {
+        output.blank_line()?;
+        output.warnings(&result.snapshot.warnings)?;
+    }
+
     Ok(())
 }
 
@@ -687,6 +1088,18 @@ pub struct ExecuteResult {
     /// Git commit SHA (if commit was created)
     pub commit_sha: Option<String>,
 
+    /// Transaction recorded for `workspace undo` (if backups are enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_id: Option<String>,
+
+    /// Changesets split by `--filter` that keep unselected packages pending
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub changesets_kept_pending: Vec<String>,
+
+    /// Release manifest written with `--manifest`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_manifest: Option<PathBuf>,
+
     /// Full snapshot of the bump operation
     pub snapshot: BumpSnapshot,
 }
@@ -37,6 +37,7 @@
 //!     commit_version_changes, create_release_tags, push_tags_to_remote
 //! };
 //! use sublime_git_tools::Repo;
+//! use sublime_pkg_tools::config::ChangelogConfig;
 //! use std::path::PathBuf;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -51,7 +52,7 @@
 //!
 //! // Create tags
 //! let tags = vec![("@org/core".to_string(), "1.2.0".to_string())];
-//! create_release_tags(&repo, &tags)?;
+//! create_release_tags(&repo, &tags, &ChangelogConfig::default())?;
 //!
 //! // Push tags
 //! push_tags_to_remote(&repo)?;
@@ -62,6 +63,7 @@
 use crate::error::{CliError, Result};
 use std::path::Path;
 use sublime_git_tools::Repo;
+use sublime_pkg_tools::config::ChangelogConfig;
 use tracing::{debug, info, warn};
 
 /// Commits version changes to the Git repository.
@@ -183,13 +185,14 @@ pub fn commit_version_changes(
 /// Creates Git tags for released packages.
 ///
 /// Creates annotated tags for each package that was bumped. Tag names follow
-/// the format: `<package-name>@<version>` for scoped/monorepo packages,
-/// or `v<version>` for single-package repositories.
+/// the package's tag format (`version_tag_format` unless the package overrides
+/// it), or `root_tag_format` for single-package repositories.
 ///
 /// # Arguments
 ///
 /// * `repo` - Git repository instance
 /// * `package_versions` - List of (package_name, version) tuples to tag
+/// * `config` - Changelog configuration providing the tag formats
 ///
 /// # Returns
 ///
@@ -204,40 +207,42 @@ pub fn commit_version_changes(
 ///
 /// # Tag Naming Convention
 ///
+/// With the default formats:
+///
 /// - Scoped packages: `@org/package@1.2.0`
 /// - Unscoped packages (monorepo): `package@1.2.0`
 /// - Single package repo: `v1.2.0`
 ///
+/// A package with a `tag_format` override, e.g. `{name}/v{version}`, is always
+/// tagged with it.
+///
 /// # Examples
 ///
 /// ```rust,ignore
 /// use sublime_git_tools::Repo;
+/// use sublime_pkg_tools::config::ChangelogConfig;
 ///
 /// let repo = Repo::open(".")?;
 /// let packages = vec![
 ///     ("@myorg/core".to_string(), "1.2.0".to_string()),
 ///     ("@myorg/utils".to_string(), "2.0.0".to_string()),
 /// ];
-/// let tags = create_release_tags(&repo, &packages)?;
+/// let tags = create_release_tags(&repo, &packages, &ChangelogConfig::default())?;
 /// println!("Created {} tags", tags.len());
 /// ```
 pub fn create_release_tags(
     repo: &Repo,
     package_versions: &[(String, String)],
+    config: &ChangelogConfig,
 ) -> Result<Vec<String>> {
     info!("Creating Git tags for {} packages", package_versions.len());
 
     let mut created_tags = Vec::new();
 
     for (package_name, version) in package_versions {
-        // Determine tag name based on package name
-        let tag_name = if package_versions.len() == 1 && !package_name.starts_with('@') {
-            // Single package repository - use v<version>
-            format!("v{version}")
-        } else {
-            // Monorepo or scoped package - use <package>@<version>
-            format!("{package_name}@{version}")
-        };
+        // Single package releases use the root format unless the package has its own
+        let tag_name =
+            config.package_release_tag(package_name, version, package_versions.len() == 1);
 
         debug!("Creating tag: {}", tag_name);
 
@@ -0,0 +1,190 @@
+//! GitHub Actions output for the release plan.
+//!
+//! This module turns a bump preview into the formats GitHub Actions renders on a pull
+//! request, for `workspace bump --github-summary`.
+//!
+//! # What
+//!
+//! Provides:
+//! - `render_step_summary` - Markdown for the job summary, listing the packages to release
+//! - `render_annotation` - A `::notice` workflow command naming each `package@version`
+//! - `publish_github_summary` - Appends the summary to `GITHUB_STEP_SUMMARY` and emits the
+//!   annotation
+//!
+//! # How
+//!
+//! Both formats are built from the `BumpSnapshot` of the preview, so they list exactly the
+//! packages the preview reports as bumping. The summary is appended because other steps of
+//! the job may write to the same file. The annotation goes to stdout, where the runner picks
+//! up workflow commands; it is left out of JSON output to keep it parseable.
+//!
+//! # Why
+//!
+//! Reviewers see "this PR will release core@2.1.0, utils@1.4.2" on the pull request
+//! without each repository scripting it from the JSON output.
+
+use crate::commands::bump::snapshot::{BumpSnapshot, PackageBumpInfo};
+use crate::error::{CliError, Result};
+use crate::output::Output;
+use std::fmt::Write as _;
+use std::fs::OpenOptions;
+use std::io::Write as _;
+use std::path::Path;
+use tracing::{debug, warn};
+
+/// Environment variable naming the job summary file of a GitHub Actions step.
+pub const STEP_SUMMARY_ENV: &str = "GITHUB_STEP_SUMMARY";
+
+/// Title of the release plan annotation and summary.
+const TITLE: &str = "Release plan";
+
+/// Renders the release plan as Markdown for the GitHub Actions job summary.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::bump::github::render_step_summary;
+/// use sublime_cli_tools::commands::bump::snapshot::BumpSnapshot;
+///
+/// let snapshot = BumpSnapshot {
+///     strategy: "independent".to_string(),
+///     packages: vec![],
+///     changesets: vec![],
+///     summary: Default::default(),
+///     warnings: vec![],
+/// };
+/// assert!(render_step_summary(&snapshot).contains("will not release any packages"));
+/// ```
+#[must_use]
+pub fn render_step_summary(snapshot: &BumpSnapshot) -> String {
+    let releases = releases(snapshot);
+    let mut markdown = format!("## {TITLE}\n\n");
+
+    if releases.is_empty() {
+        markdown.push_str("This PR will not release any packages.\n");
+        return markdown;
+    }
+
+    let noun = if releases.len() == 1 { "package" } else { "packages" };
+    let _ = writeln!(
+        markdown,
+        "This PR will release {} {noun} ({} strategy).\n",
+        releases.len(),
+        snapshot.strategy.to_lowercase()
+    );
+    markdown.push_str("| Package | Current | Next | Bump |\n");
+    markdown.push_str("| --- | --- | --- | --- |\n");
+    for package in &releases {
+        let _ = writeln!(
+            markdown,
+            "| `{}` | {} | {} | {} |",
+            package.name,
+            package.current_version,
+            package.next_version,
+            package.bump_type.to_string().to_lowercase()
+        );
+    }
+
+    if !snapshot.changesets.is_empty() {
+        markdown.push_str("\n**Changesets**\n\n");
+        for changeset in &snapshot.changesets {
+            let _ = writeln!(
+                markdown,
+                "- `{}` ({}, {} package(s))",
+                changeset.branch,
+                changeset.bump_type.to_string().to_lowercase(),
+                changeset.packages.len()
+            );
+        }
+    }
+
+    markdown
+}
+
+/// Renders the release plan as a GitHub Actions `::notice` workflow command.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::bump::github::render_annotation;
+/// use sublime_cli_tools::commands::bump::snapshot::BumpSnapshot;
+///
+/// let snapshot = BumpSnapshot {
+///     strategy: "independent".to_string(),
+///     packages: vec![],
+///     changesets: vec![],
+///     summary: Default::default(),
+///     warnings: vec![],
+/// };
+/// assert_eq!(
+///     render_annotation(&snapshot),
+///     "::notice title=Release plan::This PR will not release any packages"
+/// );
+/// ```
+#[must_use]
+pub fn render_annotation(snapshot: &BumpSnapshot) -> String {
+    let releases = releases(snapshot);
+    let message = if releases.is_empty() {
+        "This PR will not release any packages".to_string()
+    } else {
+        let list: Vec<String> = releases
+            .iter()
+            .map(|package| format!("{}@{}", package.name, package.next_version))
+            .collect();
+        format!("This PR will release {}", list.join(", "))
+    };
+
+    format!("::notice title={TITLE}::{}", escape_data(&message))
+}
+
+/// Publishes the release plan to GitHub Actions.
+///
+/// Appends the job summary to the file named by `GITHUB_STEP_SUMMARY` and, outside JSON
+/// output, writes the notice annotation to stdout. When the variable is not set, as when
+/// running outside GitHub Actions, the summary is written to stdout instead.
+///
+/// # Errors
+///
+/// Returns an error if the summary file cannot be written.
+pub fn publish_github_summary(output: &Output, snapshot: &BumpSnapshot) -> Result<()> {
+    let markdown = render_step_summary(snapshot);
+    let json = output.format().is_json();
+
+    match std::env::var_os(STEP_SUMMARY_ENV).filter(|path| !path.is_empty()) {
+        Some(path) => append_summary(Path::new(&path), &markdown)?,
+        None if json => warn!("{STEP_SUMMARY_ENV} is not set; skipping the job summary"),
+        None => {
+            warn!("{STEP_SUMMARY_ENV} is not set; writing the job summary to stdout");
+            output.blank_line()?;
+            output.write_raw(markdown.as_bytes())?;
+        }
+    }
+
+    if !json {
+        output.write_raw(format!("{}\n", render_annotation(snapshot)).as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Appends Markdown to the job summary file.
+fn append_summary(path: &Path, markdown: &str) -> Result<()> {
+    debug!("Appending release plan to {}", path.display());
+
+    let mut file =
+        OpenOptions::new().create(true).append(true).open(path).map_err(|e| {
+            CliError::io(format!("Failed to open job summary {}: {e}", path.display()))
+        })?;
+    writeln!(file, "{markdown}")
+        .map_err(|e| CliError::io(format!("Failed to write job summary {}: {e}", path.display())))
+}
+
+/// Returns the packages the preview releases.
+fn releases(snapshot: &BumpSnapshot) -> Vec<&PackageBumpInfo> {
+    snapshot.packages.iter().filter(|package| package.will_bump).collect()
+}
+
+/// Escapes a workflow command message.
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
@@ -7,6 +7,9 @@
 //! Provides implementations for version bump commands:
 //! - `preview` - Preview version bumps without applying changes (default, story 5.1)
 //! - `execute` - Apply version bumps and update files (story 5.2)
+//! - `sandbox` - Run the release in a temporary git worktree, then promote or discard it
+//! - `github` - Publish the release plan to the GitHub Actions job summary
+//! - `publish` - Check that the released packages can be published (`--validate-publish`)
 //!
 //! # How
 //!
@@ -63,6 +66,12 @@
 //!     no_archive: false,
 //!     force: false,
 //!     show_diff: false,
+//!     github_summary: false,
+//!     validate_publish: false,
+//!     at: None,
+//!     filter: None,
+//!     sandbox: None,
+//!     manifest: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -74,7 +83,10 @@
 
 pub mod execute;
 pub mod git_integration;
+pub mod github;
 pub mod preview;
+pub(crate) mod publish;
+pub mod sandbox;
 pub mod snapshot;
 
 #[cfg(test)]
@@ -83,4 +95,5 @@ mod tests;
 // Re-export command functions for convenience
 pub use execute::execute_bump_apply;
 pub use preview::execute_bump_preview;
+pub use sandbox::execute_bump_sandbox;
 pub use snapshot::execute_bump_snapshot;
@@ -26,6 +26,14 @@
 //!    - Unified: All workspace packages
 //! 7. Builds BumpSnapshot with all package information
 //! 8. Outputs as formatted table (human mode) or JSON (automation mode)
+//! 9. With `--github-summary`, publishes the plan to the GitHub Actions job summary
+//!
+//! With `--at <REF>`, manifests and changesets are read from the commit the reference points
+//! to, through `GitTreeFileSystem`, instead of the working directory. The current
+//! configuration is used.
+//!
+//! With `--validate-publish`, the packages that will bump are checked for publishing before
+//! the results are output, and blocking issues fail the preview.
 //!
 //! ## Strategy Handling
 //!
@@ -71,6 +79,12 @@
 //!     no_archive: false,
 //!     force: false,
 //!     show_diff: false,
+//!     github_summary: false,
+//!     validate_publish: false,
+//!     at: None,
+//!     filter: None,
+//!     sandbox: None,
+//!     manifest: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -80,6 +94,8 @@
 //! ```
 
 use crate::cli::commands::BumpArgs;
+use crate::commands::bump::github::publish_github_summary;
+use crate::commands::bump::publish::validate_publish;
 use crate::commands::bump::snapshot::{BumpSnapshot, BumpSummary, ChangesetInfo, PackageBumpInfo};
 use crate::error::{CliError, Result};
 use crate::output::diff::{DiffRenderer, VersionDiff};
@@ -88,10 +104,15 @@ use crate::output::table::{ColumnAlignment, TableBuilder, TableTheme};
 use crate::output::{JsonResponse, Output};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use sublime_pkg_tools::changeset::ChangesetManager;
-use sublime_pkg_tools::config::ConfigLoader;
+use sublime_git_tools::Repo;
+use sublime_pkg_tools::changeset::{ChangesetManager, ChangesetStorage, FileBasedChangesetStorage};
+use sublime_pkg_tools::config::{
+    ConfigLoader, PackageToolsConfig, VersionConfig, VersioningStrategy,
+};
+use sublime_pkg_tools::error::VersionError;
+use sublime_pkg_tools::git_tree::GitTreeFileSystem;
 use sublime_pkg_tools::types::{Changeset, PackageInfo, Version, VersionBump};
-use sublime_pkg_tools::version::VersionResolver;
+use sublime_pkg_tools::version::{PackageFilter, VersionResolver};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
@@ -148,18 +169,83 @@ pub async fn execute_bump_preview(
     let config = load_config(workspace_root, config_path).await?;
     info!("Configuration loaded successfully");
 
-    // Step 2: Load all pending changesets
+    // Step 2: Load all pending changesets, from a past commit with --at
+    if let Some(git_ref) = args.at.as_deref() {
+        let fs = open_git_tree(workspace_root, git_ref)?;
+        let workspace_root = workspace_root.canonicalize().map_err(|e| {
+            CliError::io(format!("Failed to resolve {}: {e}", workspace_root.display()))
+        })?;
+        if !output.format().is_json() {
+            output.info(&format!(
+                "Previewing as of '{git_ref}' ({})",
+                &fs.commit()[..fs.commit().len().min(7)]
+            ))?;
+        }
+        let storage = FileBasedChangesetStorage::new(
+            workspace_root.clone(),
+            config.changeset.path.clone(),
+            config.changeset.history_path.clone(),
+            fs.clone(),
+        );
+        let pending = storage
+            .list_pending()
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to load changesets: {e}")))?;
+        return preview_changesets(args, output, &workspace_root, &config, fs, &pending).await;
+    }
+
     let fs = FileSystemManager::new();
     let manager = ChangesetManager::new(workspace_root.to_path_buf(), fs.clone(), config.clone())
         .await
         .map_err(|e| CliError::execution(format!("Failed to create changeset manager: {e}")))?;
 
-    let changesets = manager
+    let pending = manager
         .list_pending()
         .await
         .map_err(|e| CliError::execution(format!("Failed to load changesets: {e}")))?;
 
-    debug!("Loaded {} changeset(s)", changesets.len());
+    preview_changesets(args, output, workspace_root, &config, fs, &pending).await
+}
+
+/// Opens a read-only filesystem over the commit a Git reference points to.
+///
+/// # Errors
+///
+/// Returns a Git error if the workspace is not in a Git repository or the reference
+/// cannot be resolved to a commit.
+fn open_git_tree(workspace_root: &Path, git_ref: &str) -> Result<GitTreeFileSystem> {
+    let repo = Repo::open(workspace_root.to_str().ok_or_else(|| {
+        CliError::execution("Workspace root path contains invalid UTF-8".to_string())
+    })?)
+    .map_err(|e| CliError::git(format!("Failed to open git repository: {e}")))?;
+
+    GitTreeFileSystem::new(repo, git_ref)
+        .map_err(|e| CliError::git(format!("Failed to read Git reference '{git_ref}': {e}")))
+}
+
+/// Resolves and outputs the release plan for the loaded pending changesets.
+///
+/// Packages are read through `fs`, which is the working directory or, with `--at`, the
+/// commit tree of a past reference.
+///
+/// # Errors
+///
+/// Returns an error if the filter is invalid, packages cannot be discovered, version
+/// resolution fails, publish validation finds blocking issues, or output fails.
+async fn preview_changesets<F>(
+    args: &BumpArgs,
+    output: &Output,
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+    fs: F,
+    pending: &[Changeset],
+) -> Result<()>
+where
+    F: AsyncFileSystem + Clone + Send + Sync + 'static,
+{
+    debug!("Loaded {} changeset(s)", pending.len());
+
+    let changesets = filter_changesets(args.filter.as_deref(), &config.version, pending)?;
 
     // Step 3: Check if there are any changesets
     if changesets.is_empty() {
@@ -169,10 +255,23 @@ pub async fn execute_bump_preview(
                 packages: vec![],
                 changesets: vec![],
                 summary: BumpSummary::default(),
+                warnings: vec![],
             });
             output.json(&response)?;
         } else {
-            output.info("No changesets found. Nothing to bump.")?;
+            output.info(&no_changesets_message(args))?;
+        }
+        if args.github_summary {
+            publish_github_summary(
+                output,
+                &BumpSnapshot {
+                    strategy: config.version.strategy.to_string(),
+                    packages: vec![],
+                    changesets: vec![],
+                    summary: BumpSummary::default(),
+                    warnings: vec![],
+                },
+            )?;
         }
         return Ok(());
     }
@@ -180,9 +279,10 @@ pub async fn execute_bump_preview(
     info!("Processing {} changeset(s)", changesets.len());
 
     // Step 4: Create VersionResolver (which will discover packages internally)
-    let resolver = VersionResolver::new(workspace_root.to_path_buf(), config.clone())
-        .await
-        .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
+    let resolver =
+        VersionResolver::with_filesystem(workspace_root.to_path_buf(), fs, config.clone())
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
 
     // Step 5: Discover all workspace packages
     let all_packages = resolver
@@ -205,14 +305,25 @@ pub async fn execute_bump_preview(
 
     debug!("Built bump snapshot with {} packages", snapshot.packages.len());
 
+    // Step 6b: Check that the released packages can be published
+    if args.validate_publish {
+        let released =
+            snapshot.packages.iter().filter(|p| p.will_bump).map(|p| p.name.as_str()).collect();
+        validate_publish(output, &all_packages, &released).await?;
+    }
+
     // Step 7: Output results
     if output.format().is_json() {
-        let response: JsonResponse<BumpSnapshot> = JsonResponse::success(snapshot);
+        let response: JsonResponse<&BumpSnapshot> = JsonResponse::success(&snapshot);
         output.json(&response)?;
     } else {
         output_table(output, &snapshot, args.show_diff)?;
     }
 
+    if args.github_summary {
+        publish_github_summary(output, &snapshot)?;
+    }
+
     Ok(())
 }
 
@@ -220,12 +331,15 @@ pub async fn execute_bump_preview(
 ///
 /// In Independent mode, only packages explicitly listed in changesets receive
 /// version bumps. Other packages remain unchanged.
-async fn build_independent_snapshot(
-    resolver: &VersionResolver,
+async fn build_independent_snapshot<F>(
+    resolver: &VersionResolver<F>,
     changesets: &[Changeset],
     all_packages: &[PackageInfo],
     workspace_root: &Path,
-) -> Result<BumpSnapshot> {
+) -> Result<BumpSnapshot>
+where
+    F: AsyncFileSystem + Clone + Send + Sync + 'static,
+{
     debug!("Building snapshot for Independent versioning strategy");
 
     // Collect all packages that are in changesets
@@ -334,19 +448,24 @@ async fn build_independent_snapshot(
         packages: packages_info,
         changesets: changeset_infos,
         summary,
+        warnings: resolution.warnings.clone(),
     })
 }
 
 /// Builds a bump snapshot for Unified versioning strategy.
 ///
-/// In Unified mode, ALL workspace packages receive the same version bump
-/// when any changeset exists. The highest bump type from all changesets is used.
-async fn build_unified_snapshot(
-    resolver: &VersionResolver,
+/// In Unified mode, ALL packages of the unified group receive the same version bump
+/// when any changeset lists one of them. The highest bump type from all changesets is
+/// used. Packages in `version.independent_packages` are bumped on their own.
+async fn build_unified_snapshot<F>(
+    resolver: &VersionResolver<F>,
     changesets: &[Changeset],
     all_packages: &[PackageInfo],
     workspace_root: &Path,
-) -> Result<BumpSnapshot> {
+) -> Result<BumpSnapshot>
+where
+    F: AsyncFileSystem + Clone + Send + Sync + 'static,
+{
     debug!("Building snapshot for Unified versioning strategy");
 
     // Merge all changesets to get the combined bump requirements
@@ -360,8 +479,8 @@ async fn build_unified_snapshot(
 
     debug!("Resolved {} package updates", resolution.updates.len());
 
-    // In unified mode, ALL packages bump (since changesets exist)
-    let packages_to_bump = all_packages.len();
+    // In unified mode, the whole group bumps along with the updated exceptions
+    let packages_to_bump = resolution.updates.len();
 
     // Build package bump info
     let mut packages_info = Vec::new();
@@ -382,10 +501,11 @@ async fn build_unified_snapshot(
             let is_in_changeset = changeset_packages.contains(package.name());
 
             // Provide clear reason for unified bump
-            let reason = if is_in_changeset {
-                "unified bump (package in changeset)".to_string()
-            } else {
-                "unified bump (all packages bumped together)".to_string()
+            let reason = match (update.is_unified(), is_in_changeset) {
+                (true, true) => "unified bump (package in changeset)".to_string(),
+                (true, false) => "unified bump (all packages bumped together)".to_string(),
+                (false, true) => "independent bump (package in changeset)".to_string(),
+                (false, false) => "independent bump (dependency propagation)".to_string(),
             };
 
             packages_info.push(PackageBumpInfo {
@@ -433,9 +553,64 @@ async fn build_unified_snapshot(
         packages: packages_info,
         changesets: changeset_infos,
         summary,
+        warnings: resolution.warnings.clone(),
     })
 }
 
+/// Message shown when there is nothing to bump.
+pub(crate) fn no_changesets_message(args: &BumpArgs) -> String {
+    match &args.filter {
+        Some(filter) => format!("No pending changesets match filter '{filter}'. Nothing to bump."),
+        None => "No changesets found. Nothing to bump.".to_string(),
+    }
+}
+
+/// Narrows pending changesets to the packages selected by `--filter`.
+///
+/// Without a filter the changesets are returned unchanged. Otherwise changesets
+/// listing none of the selected packages are left out and the others list only
+/// the selected packages.
+///
+/// # Errors
+///
+/// Returns a validation error if the filter is invalid, or if the strategy is unified
+/// and the filter selects a package of the unified group, where every package shares
+/// one version and a partial release is not possible. Filters selecting only
+/// `version.independent_packages` are allowed.
+pub(crate) fn filter_changesets(
+    filter: Option<&str>,
+    version: &VersionConfig,
+    changesets: &[Changeset],
+) -> Result<Vec<Changeset>> {
+    let Some(expression) = filter else {
+        return Ok(changesets.to_vec());
+    };
+
+    let filter = PackageFilter::parse(expression).map_err(sublime_pkg_tools::error::Error::from)?;
+    let narrowed: Vec<Changeset> =
+        changesets.iter().filter_map(|changeset| filter.narrow(changeset)).collect();
+
+    if version.strategy == VersioningStrategy::Unified {
+        let independent =
+            version.independent_filter().map_err(sublime_pkg_tools::error::Error::from)?;
+        let is_exception = |name: &String| independent.as_ref().is_some_and(|f| f.matches(name));
+        if narrowed.is_empty()
+            || !narrowed.iter().all(|changeset| changeset.packages.iter().all(is_exception))
+        {
+            return Err(sublime_pkg_tools::error::Error::from(VersionError::InvalidFilter {
+                expression: filter.expression().to_string(),
+                reason: "partial releases are only supported for independent packages with the \
+                         unified strategy"
+                    .to_string(),
+            })
+            .into());
+        }
+    }
+
+    debug!("Filter '{}' selected {} changeset(s)", filter, narrowed.len());
+    Ok(narrowed)
+}
+
 /// Merges multiple changesets into a single changeset for version resolution.
 ///
 /// Combines packages, commits, and environments from all changesets.
@@ -659,6 +834,11 @@ fn output_table(output: &Output, snapshot: &BumpSnapshot, show_diff: bool) -> Re
             .warning("Circular dependencies detected. Review dependency graph before bumping.")?;
     }
 
+    if !snapshot.warnings.is_empty() {
+        output.blank_line()?;
+        output.warnings(&snapshot.warnings)?;
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,88 @@
+//! Pre-publish checks of the released packages.
+//!
+//! This module runs the publish checks of `sublime_pkg_tools::publish` for
+//! `workspace bump --validate-publish`.
+//!
+//! # What
+//!
+//! Provides `validate_publish`, which checks the packages a bump releases and fails the
+//! command when any of them cannot be published.
+//!
+//! # How
+//!
+//! Private packages are skipped. In human mode each issue is printed with its package and
+//! how to fix it; blocking issues are then returned as a validation error, so the bump
+//! stops before any file is modified. In JSON mode only the error is reported, keeping a
+//! single JSON document on stdout.
+//!
+//! # Why
+//!
+//! The version bump is the last step before `npm publish`. Stopping there keeps the
+//! changesets pending until the package.json is fixed, instead of leaving a bumped version
+//! that never reaches the registry.
+
+use crate::error::{CliError, Result};
+use crate::output::Output;
+use std::collections::HashSet;
+use sublime_pkg_tools::publish::PublishValidator;
+use sublime_pkg_tools::types::PackageInfo;
+use tracing::debug;
+
+/// Checks that the released packages can be published.
+///
+/// # Arguments
+///
+/// * `output` - Output handler for the issues
+/// * `packages` - All workspace packages
+/// * `released` - Names of the packages the bump releases
+///
+/// # Errors
+///
+/// Returns a validation error listing the blocking issues, if any.
+pub(crate) async fn validate_publish(
+    output: &Output,
+    packages: &[PackageInfo],
+    released: &HashSet<&str>,
+) -> Result<()> {
+    let packages = packages.iter().filter(|package| released.contains(package.name()));
+    let report = PublishValidator::new().validate(packages).await;
+    debug!(
+        "Checked {} package(s) for publishing, skipped {} private",
+        report.packages.len(),
+        report.skipped_private.len()
+    );
+
+    if !output.format().is_json() {
+        for issue in &report.issues {
+            let message = match &issue.suggestion {
+                Some(suggestion) => format!("{}: {} ({suggestion})", issue.package, issue.message),
+                None => format!("{}: {}", issue.package, issue.message),
+            };
+            if issue.is_blocking() {
+                output.error(&message)?;
+            } else {
+                output.warning(&message)?;
+            }
+        }
+    }
+
+    if report.is_blocking() {
+        let blocked: HashSet<&str> =
+            report.blocking_issues().map(|issue| issue.package.as_str()).collect();
+        let issues: Vec<String> = report
+            .blocking_issues()
+            .map(|issue| format!("{}: {}", issue.package, issue.message))
+            .collect();
+        return Err(CliError::validation(format!(
+            "{} package(s) cannot be published:\n  {}",
+            blocked.len(),
+            issues.join("\n  ")
+        )));
+    }
+
+    if !output.format().is_json() && !report.packages.is_empty() {
+        output.success(&format!("{} package(s) ready to publish", report.packages.len()))?;
+    }
+
+    Ok(())
+}
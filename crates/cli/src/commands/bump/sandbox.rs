@@ -0,0 +1,472 @@
+//! Release sandbox for the bump command.
+//!
+//! This module implements `bump --sandbox`, which runs the release pipeline in a
+//! temporary git worktree instead of the user's working directory.
+//!
+//! # What
+//!
+//! Provides the `execute_bump_sandbox` function that handles three actions:
+//! - `run` (default) - Applies versions, changelogs and changeset archival in a sandbox
+//!   worktree and commits the result there
+//! - `promote` - Fast-forwards the current branch to the sandbox commit, creates and
+//!   pushes tags when requested, and removes the sandbox
+//! - `discard` - Removes the sandbox
+//!
+//! # How
+//!
+//! The sandbox is a linked worktree in the system temp directory, on a branch named
+//! `wnt-release-sandbox` created from HEAD. The bump pipeline runs with the worktree as
+//! its workspace root, so the working directory is never touched. The original branch,
+//! the base commit and the released versions are recorded in `wnt-release-sandbox.json`
+//! in the git directory, so `promote` can check that the branch has not moved and tag
+//! the versions that were actually released.
+//!
+//! Tags are only created on `promote`: tags are shared by all worktrees, and creating
+//! them earlier would leave stale tags behind when a sandbox is discarded.
+//!
+//! # Why
+//!
+//! A release rewrites manifests, changelogs and changesets in one go. The sandbox lets
+//! users inspect the exact resulting commit before it lands on their branch, and throw
+//! it away at no cost.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Run the release in a sandbox and inspect it
+//! workspace bump --sandbox
+//! git show wnt-release-sandbox
+//!
+//! # Land it on the current branch with tags, or throw it away
+//! workspace bump --sandbox promote --git-tag
+//! workspace bump --sandbox discard
+//! ```
+
+use crate::cli::commands::BumpArgs;
+use crate::commands::bump::execute::{
+    ExecuteResult, apply_bump, display_result, send_notification,
+};
+use crate::commands::bump::git_integration::{
+    create_release_tags, get_current_commit_sha, push_tags_to_remote, validate_repository_state,
+};
+use crate::commands::bump::preview::load_config;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use serde::{Deserialize, Serialize};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use sublime_git_tools::{Repo, RepoError};
+use sublime_pkg_tools::notifications::{Notification, ReleasedPackage};
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+use tracing::{debug, info, warn};
+
+/// Name of the sandbox worktree and of its branch.
+pub const SANDBOX_NAME: &str = "wnt-release-sandbox";
+
+/// File in the git directory recording the sandbox state.
+const SANDBOX_STATE_FILE: &str = "wnt-release-sandbox.json";
+
+/// State of a sandbox release, recorded for `promote`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SandboxState {
+    /// Directory of the sandbox worktree.
+    path: PathBuf,
+    /// Branch the sandbox was created from and is promoted to.
+    branch: String,
+    /// Commit the sandbox was created from.
+    base_sha: String,
+    /// Release commit in the sandbox.
+    commit_sha: String,
+    /// Packages released in the sandbox.
+    packages: Vec<SandboxPackage>,
+}
+
+/// A package released in the sandbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SandboxPackage {
+    /// Package name.
+    name: String,
+    /// Version before the release.
+    previous_version: String,
+    /// Released version.
+    version: String,
+}
+
+/// Response data for the sandbox actions (JSON output).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SandboxResponse {
+    /// Action performed: `run`, `promote`, or `discard`.
+    action: String,
+    /// Directory of the sandbox worktree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<PathBuf>,
+    /// Branch the sandbox belongs to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    /// Release commit in the sandbox.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_sha: Option<String>,
+    /// Tags created on promotion.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags_created: Vec<String>,
+    /// Whether a sandbox was removed.
+    removed: bool,
+    /// Result of the release run in the sandbox.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    release: Option<ExecuteResult>,
+}
+
+/// Execute the bump command in sandbox mode.
+///
+/// Dispatches on the `--sandbox` action: `run` (the default), `promote`, or `discard`.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments; `--sandbox` selects the action
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Workspace root directory, which must be the repository root
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not a git repository or HEAD is detached
+/// - The sandbox worktree cannot be created or removed
+/// - The release fails inside the sandbox (the sandbox is removed again)
+/// - `promote` finds no sandbox, or the branch has moved since the sandbox was created
+/// - Tags cannot be created or pushed
+pub async fn execute_bump_sandbox(
+    args: &BumpArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let repo = Repo::open(
+        root.to_str()
+            .ok_or_else(|| CliError::execution("Workspace path contains invalid UTF-8"))?,
+    )
+    .map_err(|e| {
+        CliError::git(format!("Failed to open Git repository at {}: {e}", root.display()))
+    })?;
+
+    match args.sandbox.as_deref().unwrap_or("run") {
+        "promote" => Box::pin(promote_sandbox(args, output, root, config_path, &repo)).await,
+        "discard" => discard_sandbox(output, &repo).await,
+        _ => Box::pin(run_sandbox(args, output, config_path, &repo)).await,
+    }
+}
+
+/// Runs the release in a fresh sandbox worktree, replacing any previous sandbox.
+async fn run_sandbox(
+    args: &BumpArgs,
+    output: &Output,
+    config_path: Option<&Path>,
+    repo: &Repo,
+) -> Result<()> {
+    validate_repository_state(repo, true)?;
+    let branch = repo
+        .get_current_branch()
+        .map_err(|e| CliError::git(format!("Failed to get current branch: {e}")))?;
+    let base_sha = get_current_commit_sha(repo)?;
+
+    let has_changes = repo
+        .status_porcelain()
+        .map_err(|e| CliError::git(format!("Failed to get repository status: {e}")))?
+        .iter()
+        .any(|line| !line.is_empty());
+    if has_changes && !output.format().is_json() {
+        output.warning("The sandbox starts from HEAD; uncommitted changes are not included")?;
+    }
+
+    let sandbox_root = create_sandbox(repo).await?;
+
+    let sandbox_args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: args.prerelease.clone(),
+        packages: args.packages.clone(),
+        git_tag: false,
+        git_push: false,
+        git_commit: true,
+        no_changelog: args.no_changelog,
+        no_archive: args.no_archive,
+        force: args.force,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: args.validate_publish,
+        at: None,
+        filter: args.filter.clone(),
+        sandbox: None,
+        manifest: args.manifest.as_ref().map(|path| repo.get_repo_path().join(path)),
+    };
+
+    let result =
+        match Box::pin(apply_bump(&sandbox_args, output, &sandbox_root, config_path, true)).await {
+            Ok(Some(result)) => result,
+            Ok(None) => {
+                remove_sandbox(repo).await?;
+                return Ok(());
+            }
+            Err(e) => {
+                if let Err(cleanup_error) = remove_sandbox(repo).await {
+                    warn!("Failed to remove the release sandbox: {}", cleanup_error);
+                }
+                return Err(e);
+            }
+        };
+
+    let commit_sha = result
+        .commit_sha
+        .clone()
+        .ok_or_else(|| CliError::execution("The sandbox release did not create a commit"))?;
+    let state = SandboxState {
+        path: sandbox_root.clone(),
+        branch: branch.clone(),
+        base_sha,
+        commit_sha: commit_sha.clone(),
+        packages: result
+            .snapshot
+            .packages
+            .iter()
+            .map(|package| SandboxPackage {
+                name: package.name.clone(),
+                previous_version: package.current_version.clone(),
+                version: package.next_version.clone(),
+            })
+            .collect(),
+    };
+    write_state(repo, &state).await?;
+
+    info!("Release committed in sandbox: {}", commit_sha);
+
+    if output.format().is_json() {
+        output.json(&JsonResponse::success(SandboxResponse {
+            action: "run".to_string(),
+            path: Some(sandbox_root),
+            branch: Some(branch),
+            commit_sha: Some(commit_sha),
+            tags_created: vec![],
+            removed: false,
+            release: Some(result),
+        }))?;
+    } else {
+        display_result(output, &result)?;
+        output.blank_line()?;
+        output.info(&format!(
+            "Release committed on branch '{SANDBOX_NAME}' in {}",
+            sandbox_root.display()
+        ))?;
+        output.info(&format!("Inspect it with `git show {SANDBOX_NAME}`"))?;
+        output.info(&format!(
+            "Run `workspace bump --sandbox promote` to fast-forward '{branch}' to it (add \
+             --git-tag to tag the release), or `workspace bump --sandbox discard` to drop it"
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Creates the sandbox worktree from HEAD, replacing any previous sandbox.
+///
+/// Returns the worktree directory.
+async fn create_sandbox(repo: &Repo) -> Result<PathBuf> {
+    if remove_sandbox(repo).await? {
+        info!("Replaced the previous release sandbox");
+    }
+
+    // A directory left behind by an interrupted run blocks the worktree
+    let path = sandbox_path(repo);
+    let fs = FileSystemManager::new();
+    if fs.exists(&path).await {
+        fs.remove(&path)
+            .await
+            .map_err(|e| CliError::io(format!("Failed to clear {}: {e}", path.display())))?;
+    }
+
+    let sandbox_repo = repo
+        .add_worktree(SANDBOX_NAME, &path)
+        .map_err(|e| CliError::git(format!("Failed to create the release sandbox: {e}")))?;
+    let sandbox_root = sandbox_repo.get_repo_path().to_path_buf();
+    debug!("Created release sandbox at {}", sandbox_root.display());
+
+    Ok(sandbox_root)
+}
+
+/// Fast-forwards the sandbox's branch to the sandbox commit and removes the sandbox.
+async fn promote_sandbox(
+    args: &BumpArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+    repo: &Repo,
+) -> Result<()> {
+    let state = read_state(repo).await?.ok_or_else(|| {
+        CliError::validation(
+            "No release sandbox found. Run `workspace bump --sandbox` to create one",
+        )
+    })?;
+
+    let branch = repo
+        .get_current_branch()
+        .map_err(|e| CliError::git(format!("Failed to get current branch: {e}")))?;
+    if branch != state.branch {
+        return Err(CliError::validation(format!(
+            "The release sandbox was created from '{}'; check it out before promoting",
+            state.branch
+        )));
+    }
+
+    repo.fast_forward(&state.commit_sha).map_err(|e| match e {
+        RepoError::MergeError(_) => CliError::validation(format!(
+            "'{branch}' has moved since the sandbox was created from {}; run `workspace bump \
+             --sandbox` again",
+            short_sha(&state.base_sha)
+        )),
+        e => CliError::git(format!("Failed to fast-forward '{branch}': {e}")),
+    })?;
+    info!("Fast-forwarded '{}' to {}", branch, state.commit_sha);
+
+    let config = load_config(root, config_path).await?;
+    let mut tags_created = Vec::new();
+    if args.git_tag {
+        let package_versions: Vec<(String, String)> = state
+            .packages
+            .iter()
+            .map(|package| (package.name.clone(), package.version.clone()))
+            .collect();
+        tags_created = create_release_tags(repo, &package_versions, &config.changelog)?;
+        if args.git_push {
+            push_tags_to_remote(repo)?;
+        }
+    }
+
+    remove_sandbox(repo).await?;
+
+    let released = state
+        .packages
+        .iter()
+        .map(|package| {
+            ReleasedPackage::new(&package.name, &package.version)
+                .with_previous_version(&package.previous_version)
+        })
+        .collect();
+    send_notification(&config, &Notification::release(released)).await;
+
+    if output.format().is_json() {
+        output.json(&JsonResponse::success(SandboxResponse {
+            action: "promote".to_string(),
+            path: None,
+            branch: Some(branch),
+            commit_sha: Some(state.commit_sha),
+            tags_created,
+            removed: true,
+            release: None,
+        }))?;
+    } else {
+        output.success(&format!(
+            "Fast-forwarded '{branch}' to the release commit {}",
+            short_sha(&state.commit_sha)
+        ))?;
+        if !tags_created.is_empty() {
+            output.info(&format!("Created tags: {}", tags_created.join(", ")))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the sandbox without touching the current branch.
+async fn discard_sandbox(output: &Output, repo: &Repo) -> Result<()> {
+    let removed = remove_sandbox(repo).await?;
+
+    if output.format().is_json() {
+        output.json(&JsonResponse::success(SandboxResponse {
+            action: "discard".to_string(),
+            path: None,
+            branch: None,
+            commit_sha: None,
+            tags_created: vec![],
+            removed,
+            release: None,
+        }))?;
+    } else if removed {
+        output.success("Discarded the release sandbox")?;
+    } else {
+        output.info("No release sandbox to discard")?;
+    }
+
+    Ok(())
+}
+
+/// Removes the sandbox worktree, its branch and its state file.
+///
+/// Returns whether anything was removed.
+async fn remove_sandbox(repo: &Repo) -> Result<bool> {
+    let fs = FileSystemManager::new();
+    let state_path = repo.get_common_git_dir().join(SANDBOX_STATE_FILE);
+    let had_worktree = repo
+        .find_worktree(SANDBOX_NAME)
+        .map_err(|e| CliError::git(format!("Failed to look up the release sandbox: {e}")))?
+        .is_some();
+    let had_branch = repo
+        .branch_exists(SANDBOX_NAME)
+        .map_err(|e| CliError::git(format!("Failed to look up the release sandbox: {e}")))?;
+    let had_state = fs.exists(&state_path).await;
+
+    repo.remove_worktree(SANDBOX_NAME)
+        .map_err(|e| CliError::git(format!("Failed to remove the release sandbox: {e}")))?;
+    if had_state {
+        fs.remove(&state_path)
+            .await
+            .map_err(|e| CliError::io(format!("Failed to remove the sandbox state: {e}")))?;
+    }
+
+    Ok(had_worktree || had_branch || had_state)
+}
+
+/// Reads the sandbox state, if a sandbox exists.
+async fn read_state(repo: &Repo) -> Result<Option<SandboxState>> {
+    let fs = FileSystemManager::new();
+    let state_path = repo.get_common_git_dir().join(SANDBOX_STATE_FILE);
+    if !fs.exists(&state_path).await {
+        return Ok(None);
+    }
+
+    let content = fs
+        .read_file_string(&state_path)
+        .await
+        .map_err(|e| CliError::io(format!("Failed to read the sandbox state: {e}")))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| CliError::execution(format!("Invalid sandbox state: {e}")))
+}
+
+/// Records the sandbox state in the git directory.
+async fn write_state(repo: &Repo, state: &SandboxState) -> Result<()> {
+    let content = serde_json::to_string_pretty(state)
+        .map_err(|e| CliError::execution(format!("Failed to serialize the sandbox state: {e}")))?;
+    FileSystemManager::new()
+        .write_file_string(&repo.get_common_git_dir().join(SANDBOX_STATE_FILE), &content)
+        .await
+        .map_err(|e| CliError::io(format!("Failed to write the sandbox state: {e}")))
+}
+
+/// Returns the sandbox directory for a repository.
+///
+/// Derived from the repository path so sandboxes of different repositories never share
+/// a directory.
+fn sandbox_path(repo: &Repo) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    repo.get_common_git_dir().hash(&mut hasher);
+    std::env::temp_dir().join(format!("{SANDBOX_NAME}-{:016x}", hasher.finish()))
+}
+
+/// Shortens a commit SHA for display.
+fn short_sha(sha: &str) -> &str {
+    &sha[..8.min(sha.len())]
+}
@@ -118,6 +118,12 @@
 //!     no_archive: false,
 //!     force: false,
 //!     show_diff: false,
+//!     github_summary: false,
+//!     validate_publish: false,
+//!     at: None,
+//!     filter: None,
+//!     sandbox: None,
+//!     manifest: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -137,7 +143,7 @@ use std::collections::HashSet;
 use std::path::Path;
 use sublime_git_tools::Repo;
 use sublime_pkg_tools::changeset::ChangesetManager;
-use sublime_pkg_tools::types::{Changeset, PackageInfo, VersionBump};
+use sublime_pkg_tools::types::{Changeset, PackageInfo, VersionBump, Warning};
 use sublime_pkg_tools::version::{SnapshotContext, SnapshotGenerator, VersionResolver};
 use sublime_standard_tools::filesystem::FileSystemManager;
 use tracing::{debug, info, warn};
@@ -158,6 +164,7 @@ use tracing::{debug, info, warn};
 ///     packages: vec![],
 ///     changesets: vec![],
 ///     summary: Default::default(),
+///     warnings: vec![],
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize)]
@@ -177,6 +184,10 @@ pub struct BumpSnapshot {
 
     /// Summary statistics for the bump operation.
     pub summary: BumpSummary,
+
+    /// Non-fatal issues found while resolving the versions, such as circular dependencies.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
 }
 
 /// Information about a single package's version bump.
@@ -491,6 +502,7 @@ pub async fn execute_bump_snapshot(
                 packages: vec![],
                 changesets: vec![],
                 summary: BumpSummary::default(),
+                warnings: vec![],
             });
             output.json(&response)?;
         } else {
@@ -668,14 +680,16 @@ async fn build_independent_snapshots(
         packages: packages_info,
         changesets: changeset_infos,
         summary,
+        warnings: resolution.warnings.clone(),
     })
 }
 
 /// Builds snapshot versions for Unified versioning strategy.
 ///
-/// In Unified mode, ALL workspace packages receive snapshot versions when
-/// any changeset exists. All packages get the same snapshot version based
-/// on the highest bump type from all changesets.
+/// In Unified mode, ALL packages of the unified group receive snapshot versions when
+/// any changeset lists one of them. They get the same snapshot version based on the
+/// highest bump type from all changesets. Packages in `version.independent_packages`
+/// get their own snapshot versions.
 async fn build_unified_snapshots(
     resolver: &VersionResolver,
     generator: &SnapshotGenerator,
@@ -707,8 +721,8 @@ async fn build_unified_snapshots(
         .map_err(|e| CliError::execution(format!("Failed to get current timestamp: {e}")))?
         .as_secs() as i64;
 
-    // In unified mode, ALL packages get snapshot versions
-    let packages_with_snapshots = all_packages.len();
+    // In unified mode, the whole group gets snapshot versions along with the exceptions
+    let packages_with_snapshots = resolution.updates.len();
 
     // Build snapshot versions for all packages
     let mut packages_info = Vec::new();
@@ -739,10 +753,11 @@ async fn build_unified_snapshots(
         let is_in_changeset = changeset_packages.contains(&update.name);
 
         // Provide clear reason for unified snapshot
-        let reason = if is_in_changeset {
-            "unified snapshot (package in changeset)".to_string()
-        } else {
-            "unified snapshot (all packages bumped together)".to_string()
+        let reason = match (update.is_unified(), is_in_changeset) {
+            (true, true) => "unified snapshot (package in changeset)".to_string(),
+            (true, false) => "unified snapshot (all packages bumped together)".to_string(),
+            (false, true) => "independent snapshot (package in changeset)".to_string(),
+            (false, false) => "independent snapshot (dependency propagation)".to_string(),
         };
 
         packages_info.push(PackageBumpInfo {
@@ -789,6 +804,7 @@ async fn build_unified_snapshots(
         packages: packages_info,
         changesets: changeset_infos,
         summary,
+        warnings: resolution.warnings.clone(),
     })
 }
 
@@ -904,6 +920,11 @@ fn output_snapshot_table(output: &Output, snapshot: &BumpSnapshot) -> Result<()>
         output.warning("Circular dependencies detected. Review dependency graph.")?;
     }
 
+    if !snapshot.warnings.is_empty() {
+        output.blank_line()?;
+        output.warnings(&snapshot.warnings)?;
+    }
+
     output.blank_line()?;
     StatusSymbol::Success.print_line("Snapshot versions generated successfully!");
     output.info("Note: No files were modified. Changesets remain active for future release.")?;
@@ -99,6 +99,7 @@ fn test_bump_snapshot_serialization() {
         packages: vec![],
         changesets: vec![],
         summary: BumpSummary::new(10, 3, 2, false),
+        warnings: vec![],
     };
 
     let json = serde_json::to_string(&snapshot).expect("Failed to serialize BumpSnapshot");
@@ -125,6 +126,12 @@ fn test_bump_args_defaults() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     // Default behavior should be preview mode (neither dry_run nor execute)
@@ -149,6 +156,12 @@ fn test_execute_bump_preview_args_structure() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     // Verify args structure is valid
@@ -167,6 +180,7 @@ fn test_output_handles_json_bump_snapshot() {
         packages: vec![],
         changesets: vec![],
         summary: BumpSummary::default(),
+        warnings: vec![],
     };
 
     // Verify we can serialize and output
@@ -236,6 +250,7 @@ fn test_bump_snapshot_independent_strategy() {
         ],
         changesets: vec![],
         summary: BumpSummary::new(2, 1, 1, false),
+        warnings: vec![],
     };
 
     assert_eq!(snapshot.strategy, "independent");
@@ -271,6 +286,7 @@ fn test_bump_snapshot_unified_strategy() {
         ],
         changesets: vec![],
         summary: BumpSummary::new(2, 2, 1, false),
+        warnings: vec![],
     };
 
     assert_eq!(snapshot.strategy, "unified");
@@ -393,6 +409,12 @@ fn test_snapshot_args_structure() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert!(args.snapshot);
@@ -418,6 +440,12 @@ fn test_snapshot_format_customization() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert_eq!(args.snapshot_format.as_deref(), Some("{version}-snapshot.{short_commit}"));
@@ -440,6 +468,12 @@ fn test_snapshot_default_format() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert!(args.snapshot);
@@ -486,6 +520,7 @@ fn test_bump_snapshot_independent_snapshots() {
             commit_count: 3,
         }],
         summary: BumpSummary::new(2, 1, 1, false),
+        warnings: vec![],
     };
 
     assert_eq!(snapshot.strategy, "independent");
@@ -527,6 +562,7 @@ fn test_bump_snapshot_unified_snapshots() {
             commit_count: 3,
         }],
         summary: BumpSummary::new(2, 2, 1, false),
+        warnings: vec![],
     };
 
     assert_eq!(snapshot.strategy, "unified");
@@ -556,6 +592,7 @@ fn test_snapshot_serialization_completeness() {
         }],
         changesets: vec![],
         summary: BumpSummary::new(1, 1, 1, false),
+        warnings: vec![],
     };
 
     let json = serde_json::to_string(&snapshot).expect("Failed to serialize snapshot");
@@ -588,6 +625,12 @@ fn test_snapshot_and_execute_mutually_exclusive() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let args_execute = BumpArgs {
@@ -604,6 +647,12 @@ fn test_snapshot_and_execute_mutually_exclusive() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     // These should be mutually exclusive
@@ -628,6 +677,12 @@ fn test_show_diff_flag_default() {
         no_archive: false,
         force: false,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert!(!args.show_diff);
@@ -650,6 +705,12 @@ fn test_show_diff_flag_enabled() {
         no_archive: false,
         force: false,
         show_diff: true,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert!(args.show_diff);
@@ -672,8 +733,104 @@ fn test_show_diff_with_preview_mode() {
         no_archive: false,
         force: false,
         show_diff: true,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     assert!(args.dry_run);
     assert!(args.show_diff);
 }
+
+/// Tests that filters under the unified strategy may select independent packages only.
+#[test]
+fn test_filter_changesets_unified_exceptions() {
+    use super::preview::filter_changesets;
+    use sublime_pkg_tools::config::{VersionConfig, VersioningStrategy};
+    use sublime_pkg_tools::types::{Changeset, VersionBump};
+
+    let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+    changeset.add_package("@myorg/core");
+    changeset.add_package("@myorg/plugin-auth");
+    let changesets = vec![changeset];
+
+    let mut version = VersionConfig { strategy: VersioningStrategy::Unified, ..Default::default() };
+    assert!(filter_changesets(Some("@myorg/plugin-auth"), &version, &changesets).is_err());
+
+    version.independent_packages = vec!["@myorg/plugin-*".to_string()];
+    let narrowed = filter_changesets(Some("@myorg/plugin-auth"), &version, &changesets)
+        .map(|narrowed| narrowed[0].packages.clone());
+    assert_eq!(narrowed.ok(), Some(vec!["@myorg/plugin-auth".to_string()]));
+    assert!(filter_changesets(Some("@myorg/core"), &version, &changesets).is_err());
+    assert_eq!(filter_changesets(None, &version, &changesets).map(|all| all.len()).ok(), Some(1));
+}
+
+/// Builds a package entry for the GitHub summary tests.
+fn github_package(name: &str, current: &str, next: &str, will_bump: bool) -> PackageBumpInfo {
+    PackageBumpInfo {
+        name: name.to_string(),
+        path: format!("packages/{name}"),
+        current_version: current.to_string(),
+        next_version: next.to_string(),
+        bump_type: sublime_pkg_tools::types::VersionBump::Minor,
+        will_bump,
+        reason: "direct change from changeset".to_string(),
+    }
+}
+
+/// Tests that the GitHub annotation lists every released package with its next version.
+#[test]
+fn test_github_annotation_lists_releases() {
+    use super::github::render_annotation;
+
+    let snapshot = BumpSnapshot {
+        strategy: "independent".to_string(),
+        packages: vec![
+            github_package("core", "2.0.0", "2.1.0", true),
+            github_package("docs", "1.0.0", "1.0.0", false),
+            github_package("utils", "1.4.1", "1.4.2", true),
+        ],
+        changesets: vec![],
+        summary: BumpSummary::new(3, 2, 1, false),
+        warnings: vec![],
+    };
+
+    assert_eq!(
+        render_annotation(&snapshot),
+        "::notice title=Release plan::This PR will release core@2.1.0, utils@1.4.2"
+    );
+}
+
+/// Tests that the GitHub step summary renders a table of released packages and changesets.
+#[test]
+fn test_github_step_summary_table() {
+    use super::github::render_step_summary;
+
+    let snapshot = BumpSnapshot {
+        strategy: "independent".to_string(),
+        packages: vec![
+            github_package("core", "2.0.0", "2.1.0", true),
+            github_package("docs", "1.0.0", "1.0.0", false),
+        ],
+        changesets: vec![ChangesetInfo {
+            id: "feature-auth".to_string(),
+            branch: "feature/auth".to_string(),
+            bump_type: sublime_pkg_tools::types::VersionBump::Minor,
+            packages: vec!["core".to_string()],
+            commit_count: 2,
+        }],
+        summary: BumpSummary::new(2, 1, 1, false),
+        warnings: vec![],
+    };
+
+    let markdown = render_step_summary(&snapshot);
+
+    assert!(markdown.starts_with("## Release plan\n"));
+    assert!(markdown.contains("This PR will release 1 package (independent strategy)."));
+    assert!(markdown.contains("| `core` | 2.0.0 | 2.1.0 | minor |"));
+    assert!(!markdown.contains("`docs`"));
+    assert!(markdown.contains("- `feature/auth` (minor, 1 package(s))"));
+}
@@ -7,8 +7,9 @@
 //!
 //! Provides the `execute_changes` function that:
 //! - Analyzes working directory changes (unstaged/staged/both)
-//! - Analyzes commit range changes (between refs)
+//! - Analyzes commit range changes (between any tags, SHAs, or branches)
 //! - Analyzes branch comparison changes
+//! - Narrows results to --paths filters and adds uncommitted changes on request
 //! - Maps changed files to affected packages
 //! - Calculates change statistics (lines added/deleted, files changed)
 //! - Displays results in table or JSON format
@@ -20,10 +21,10 @@
 //! 2. Opens Git repository for the workspace
 //! 3. Determines analysis mode based on command arguments:
 //!    - Working directory: --staged, --unstaged, or both (default)
-//!    - Commit range: --since and --until
+//!    - Commit range: --since and --until (--until alone starts at the last release)
 //!    - Branch comparison: --branch
 //! 4. Uses `ChangesAnalyzer` from pkg tools to perform analysis
-//! 5. Filters results by --packages if specified
+//! 5. Filters results by --paths and --packages if specified
 //! 6. Formats output as table (human) or JSON (automation)
 //!
 //! ## Analysis Modes
@@ -38,6 +39,15 @@
 //! - --since: Starting reference (exclusive)
 //! - --until: Ending reference (default: HEAD)
 //! - Shows all commits and files changed in the range
+//! - With only --until, each package starts from its last release: its newest
+//!   `{name}@{version}` tag, else the newest `v{version}` tag, else the first commit
+//!
+//! ### Path Filters and Uncommitted Changes
+//! - --paths keeps only files under the given paths or matching glob patterns,
+//!   in every mode
+//! - --include-uncommitted merges staged, unstaged and untracked changes into a
+//!   range; on its own it reports everything since the last release
+//! - --from and --to are aliases of --since and --until
 //!
 //! ### Branch Comparison Mode (--branch)
 //! - Compares current branch against specified branch
@@ -71,6 +81,8 @@
 //!     staged: false,
 //!     unstaged: false,
 //!     packages: None,
+//!     paths: None,
+//!     include_uncommitted: false,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -86,8 +98,9 @@ use crate::output::{JsonResponse, Output};
 use serde::Serialize;
 use std::path::Path;
 use sublime_git_tools::Repo;
-use sublime_pkg_tools::changes::ChangesAnalyzer;
+use sublime_pkg_tools::changes::{ChangesAnalyzer, ChangesQuery};
 use sublime_pkg_tools::config::ConfigLoader;
+use sublime_pkg_tools::types::{Warning, WarningKind};
 use sublime_standard_tools::filesystem::FileSystemManager;
 use tracing::{debug, info, warn};
 
@@ -135,7 +148,9 @@ use tracing::{debug, info, warn};
 ///     branch: None,
 ///     staged: false,
 ///     unstaged: false,
-///     packages: None,
+///     packages: Some(vec!["@myorg/core".to_string()]),
+///     paths: Some(vec!["packages/core/src".to_string()]),
+///     include_uncommitted: true,
 /// };
 ///
 /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -208,16 +223,27 @@ pub async fn execute_changes(
             }
 
             // Analyze all working directory changes
-            analyzer.analyze_working_directory().await.map_err(|e| {
+            let mut report = analyzer.analyze_working_directory().await.map_err(|e| {
                 CliError::execution(format!("Failed to analyze working directory: {e}"))
-            })?
+            })?;
+            let query = build_query(args, None, "HEAD");
+            if !query.paths.is_empty() {
+                report.retain_files(|file| query.matches_path(&file.path));
+            }
+            report
         }
         AnalysisMode::CommitRange { from, to } => {
             info!("Analyzing commit range: {from}..{to}");
-            analyzer.analyze_commit_range(&from, &to).await.map_err(|e| {
+            analyzer.analyze(&build_query(args, Some(&from), &to)).await.map_err(|e| {
                 CliError::execution(format!("Failed to analyze commit range {from}..{to}: {e}"))
             })?
         }
+        AnalysisMode::SinceLastRelease { to } => {
+            info!("Analyzing changes since the last release up to {to}");
+            analyzer.analyze(&build_query(args, None, &to)).await.map_err(|e| {
+                CliError::execution(format!("Failed to analyze changes since last release: {e}"))
+            })?
+        }
         AnalysisMode::BranchComparison { target } => {
             info!("Comparing current branch against: {target}");
 
@@ -230,11 +256,13 @@ pub async fn execute_changes(
             debug!("Current branch: {current_branch}");
 
             // Analyze changes between branches
-            analyzer.analyze_commit_range(&target, &current_branch).await.map_err(|e| {
-                CliError::execution(format!(
-                    "Failed to compare branches {target}..{current_branch}: {e}"
-                ))
-            })?
+            analyzer.analyze(&build_query(args, Some(&target), &current_branch)).await.map_err(
+                |e| {
+                    CliError::execution(format!(
+                        "Failed to compare branches {target}..{current_branch}: {e}"
+                    ))
+                },
+            )?
         }
     };
 
@@ -247,7 +275,9 @@ pub async fn execute_changes(
     };
 
     // Output results
-    if output.format().is_json() {
+    if output.format().is_ndjson() {
+        output_ndjson(output, &filtered_report)?;
+    } else if output.format().is_json() {
         output_json(output, &filtered_report)?;
     } else {
         output_human(output, &filtered_report, args)?;
@@ -256,11 +286,35 @@ pub async fn execute_changes(
     Ok(())
 }
 
+/// Builds the pkg analysis query for a range from the command arguments.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments providing path filters and `--include-uncommitted`
+/// * `from` - Starting reference, or `None` to start at each package's last release
+/// * `to` - Ending reference
+///
+/// # Returns
+///
+/// The query passed to `ChangesAnalyzer::analyze`
+pub(crate) fn build_query(args: &ChangesArgs, from: Option<&str>, to: &str) -> ChangesQuery {
+    let query = ChangesQuery::new()
+        .to_ref(to)
+        .with_paths(args.paths.iter().flatten().cloned())
+        .include_uncommitted(args.include_uncommitted);
+
+    match from {
+        Some(from) => query.from_ref(from),
+        None => query,
+    }
+}
+
 /// Determines the analysis mode from command arguments.
 ///
 /// Returns the appropriate mode based on which flags and options are set:
 /// - Branch comparison if --branch is specified
-/// - Commit range if --since or --until is specified
+/// - Commit range if --since is specified
+/// - Since last release if only --until or --include-uncommitted is specified
 /// - Working directory otherwise (with staged/unstaged flags)
 ///
 /// # Arguments
@@ -273,10 +327,13 @@ pub async fn execute_changes(
 pub(crate) fn determine_mode(args: &ChangesArgs) -> AnalysisMode {
     if let Some(ref branch) = args.branch {
         AnalysisMode::BranchComparison { target: branch.clone() }
-    } else if args.since.is_some() || args.until.is_some() {
-        let from = args.since.as_deref().unwrap_or("HEAD~1");
+    } else if let Some(ref from) = args.since {
         let to = args.until.as_deref().unwrap_or("HEAD");
-        AnalysisMode::CommitRange { from: from.to_string(), to: to.to_string() }
+        AnalysisMode::CommitRange { from: from.clone(), to: to.to_string() }
+    } else if let Some(ref to) = args.until {
+        AnalysisMode::SinceLastRelease { to: to.clone() }
+    } else if args.include_uncommitted {
+        AnalysisMode::SinceLastRelease { to: "HEAD".to_string() }
     } else {
         AnalysisMode::WorkingDirectory { staged: args.staged, unstaged: args.unstaged }
     }
@@ -299,6 +356,11 @@ pub(crate) enum AnalysisMode {
         /// Ending reference (inclusive)
         to: String,
     },
+    /// Per-package analysis from the last release tag up to a ref
+    SinceLastRelease {
+        /// Ending reference (inclusive)
+        to: String,
+    },
     /// Branch comparison against target branch
     BranchComparison {
         /// Target branch to compare against
@@ -321,6 +383,9 @@ pub(crate) fn filter_report_by_packages(
     package_names: &[String],
 ) -> sublime_pkg_tools::changes::ChangesReport {
     report.packages.retain(|pkg| package_names.contains(&pkg.package_name));
+    report
+        .warnings
+        .retain(|warning| warning.package.as_ref().is_none_or(|name| package_names.contains(name)));
 
     // Update summary
     report.summary.packages_with_changes = report.packages.len();
@@ -351,6 +416,41 @@ fn output_json(output: &Output, report: &sublime_pkg_tools::changes::ChangesRepo
     Ok(())
 }
 
+/// Outputs the changes report as newline-delimited JSON records.
+///
+/// Writes one `package` record per affected package, one `unattributedFile` record per
+/// file outside every package, and a closing `summary` record. Each record is serialized
+/// and flushed on its own, so large reports are never held as a single JSON document.
+///
+/// # Arguments
+///
+/// * `output` - Output handler
+/// * `report` - Changes report to output
+///
+/// # Returns
+///
+/// Returns `Ok(())` if output succeeds, or an error if JSON serialization fails
+fn output_ndjson(
+    output: &Output,
+    report: &sublime_pkg_tools::changes::ChangesReport,
+) -> Result<()> {
+    debug!("Streaming output as NDJSON");
+
+    for package in &report.packages {
+        output.json_line(&ChangesRecordJson::Package(PackageChangesJson::from_package(package)))?;
+    }
+    for file in &report.unattributed_files {
+        output.json_line(&ChangesRecordJson::UnattributedFile(FileChangeJson::from_file(file)))?;
+    }
+    for warning in &report.warnings {
+        output.json_line(&ChangesRecordJson::Warning(warning.clone()))?;
+    }
+    output.json_line(&ChangesRecordJson::Summary(ChangesSummaryJson::from_summary(
+        &report.summary,
+        &report.packages,
+    )))
+}
+
 /// Outputs the changes report in human-readable format.
 ///
 /// Displays a formatted table with package changes and statistics, followed by
@@ -380,7 +480,8 @@ fn output_human(
     // Check if there are any changes
     if report.summary.packages_with_changes == 0 {
         output.info("No changes detected")?;
-        return Ok(());
+        display_unattributed_files(output, report)?;
+        return display_warnings(output, report);
     }
 
     // Display affected packages table
@@ -389,6 +490,12 @@ fn output_human(
 
     display_packages_table(output, report)?;
 
+    // Display changed files per package
+    output.blank_line()?;
+    display_package_files(output, report)?;
+    display_unattributed_files(output, report)?;
+    display_warnings(output, report)?;
+
     // Display summary
     output.blank_line()?;
     display_summary(output, &report.summary)?;
@@ -420,8 +527,8 @@ fn display_analysis_context(
             if let Some(ref branch) = args.branch {
                 return output.info(&format!("Branch Comparison: current vs {branch}"));
             }
-            let from = args.since.as_deref().unwrap_or("HEAD~1");
-            let to = args.until.as_deref().unwrap_or("HEAD");
+            let from = report.base_ref.as_deref().unwrap_or("HEAD~1");
+            let to = report.head_ref.as_deref().unwrap_or("HEAD");
             return output.info(&format!("Commit Range: {from}..{to}"));
         }
         PkgAnalysisMode::SingleCommit => "Single Commit Changes",
@@ -429,9 +536,114 @@ fn display_analysis_context(
     };
 
     output.info(context_msg)?;
+    display_query_filters(output, args)
+}
+
+/// Displays the path filters and uncommitted flag applied to the analysis, if any.
+fn display_query_filters(output: &Output, args: &ChangesArgs) -> Result<()> {
+    if let Some(paths) = args.paths.as_ref().filter(|paths| !paths.is_empty()) {
+        output.plain(&format!("  Paths: {}", paths.join(", ")))?;
+    }
+    if args.include_uncommitted {
+        output.plain("  Including uncommitted changes")?;
+    }
+    Ok(())
+}
+
+/// Displays the changed files of each affected package.
+///
+/// Each file is prefixed with its change type code (see `change_type_code`).
+///
+/// # Arguments
+///
+/// * `output` - Output handler
+/// * `report` - Changes report containing package data
+///
+/// # Returns
+///
+/// Returns `Ok(())` if output succeeds
+fn display_package_files(
+    output: &Output,
+    report: &sublime_pkg_tools::changes::ChangesReport,
+) -> Result<()> {
+    output.info("Changed Files:")?;
+
+    for package in report.packages.iter().filter(|package| !package.files.is_empty()) {
+        output.plain(&format!("  {}", package.package_name))?;
+        for file in &package.files {
+            output.plain(&format!(
+                "    {} {}",
+                change_type_code(file.change_type),
+                file.path.display()
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Displays changed files outside every package that no root file rule attributes.
+///
+/// These changes affect no package; listing them makes it explicit that a
+/// `changes.root_files` rule may be missing.
+fn display_unattributed_files(
+    output: &Output,
+    report: &sublime_pkg_tools::changes::ChangesReport,
+) -> Result<()> {
+    if report.unattributed_files.is_empty() {
+        return Ok(());
+    }
+
+    output.blank_line()?;
+    output.warning(&format!(
+        "Unattributed Files: {} (no package affected; see changes.root_files)",
+        report.unattributed_files.len()
+    ))?;
+    for file in &report.unattributed_files {
+        output.plain(&format!(
+            "    {} {}",
+            change_type_code(file.change_type),
+            file.path.display()
+        ))?;
+    }
+
     Ok(())
 }
 
+/// Displays the warnings of the analysis not already listed with the unattributed files.
+fn display_warnings(
+    output: &Output,
+    report: &sublime_pkg_tools::changes::ChangesReport,
+) -> Result<()> {
+    let warnings: Vec<Warning> = report
+        .warnings
+        .iter()
+        .filter(|warning| warning.kind != WarningKind::UnattributedFile)
+        .cloned()
+        .collect();
+    if warnings.is_empty() {
+        return Ok(());
+    }
+
+    output.blank_line()?;
+    output.warnings(&warnings)
+}
+
+/// Returns the single-letter code used for a file change type in human output.
+pub(crate) fn change_type_code(change_type: sublime_pkg_tools::changes::FileChangeType) -> char {
+    use sublime_pkg_tools::changes::FileChangeType;
+
+    match change_type {
+        FileChangeType::Added => 'A',
+        FileChangeType::Modified => 'M',
+        FileChangeType::Deleted => 'D',
+        FileChangeType::Renamed => 'R',
+        FileChangeType::Copied => 'C',
+        FileChangeType::Untracked => '?',
+        FileChangeType::Submodule => 'S',
+    }
+}
+
 /// Displays a table of affected packages with their change statistics.
 ///
 /// # Arguments
@@ -540,6 +752,11 @@ struct ChangesJsonResponse {
     affected_packages: Vec<PackageChangesJson>,
     /// Summary statistics
     summary: ChangesSummaryJson,
+    /// Changed files outside every package that no root file rule attributes
+    unattributed_files: Vec<FileChangeJson>,
+    /// Non-fatal issues found during the analysis
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<Warning>,
 }
 
 impl ChangesJsonResponse {
@@ -552,10 +769,30 @@ impl ChangesJsonResponse {
                 .map(PackageChangesJson::from_package)
                 .collect(),
             summary: ChangesSummaryJson::from_summary(&report.summary, &report.packages),
+            unattributed_files: report
+                .unattributed_files
+                .iter()
+                .map(FileChangeJson::from_file)
+                .collect(),
+            warnings: report.warnings.clone(),
         }
     }
 }
 
+/// One record of the NDJSON changes stream.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "record", rename_all = "camelCase")]
+enum ChangesRecordJson {
+    /// Changes of one affected package
+    Package(PackageChangesJson),
+    /// A changed file outside every package
+    UnattributedFile(FileChangeJson),
+    /// A non-fatal issue found during the analysis
+    Warning(Warning),
+    /// Summary statistics, written last
+    Summary(ChangesSummaryJson),
+}
+
 /// JSON representation of package changes.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -617,6 +854,7 @@ impl FileChangeJson {
             FileChangeType::Renamed => "renamed",
             FileChangeType::Copied => "copied",
             FileChangeType::Untracked => "untracked",
+            FileChangeType::Submodule => "submodule",
         };
 
         Self {
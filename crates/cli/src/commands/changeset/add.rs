@@ -16,11 +16,16 @@
 //! The command flow:
 //! 1. Loads workspace configuration and validates initialization
 //! 2. Opens git repository and detects current branch
-//! 3. Detects affected packages from git changes (if not specified)
+//! 3. Detects affected packages from git changes (if not specified); with `--from-diff`,
+//!    proposes packages and a bump type from the branch's commits since its merge-base
+//!    with the base branch, pre-selected in the prompts
 //! 4. In interactive mode: prompts for packages, bump type, environments, and summary
-//! 5. In non-interactive mode: uses provided flags or defaults
-//! 6. Creates changeset using ChangesetManager
-//! 7. Outputs success message with changeset details
+//! 5. In non-interactive mode (`--non-interactive`, `--yes`, or no terminal on stdin):
+//!    uses provided flags or defaults
+//! 6. With `--stdin` or `--from-file`: reads a JSON or YAML changeset document
+//!    instead, validates it, and fills every value not given as a flag from it
+//! 7. Creates changeset using ChangesetManager
+//! 8. Outputs success message with changeset details
 //!
 //! Uses:
 //! - `ChangesetManager` from pkg tools for changeset operations
@@ -32,7 +37,8 @@
 //!
 //! Centralizing changeset creation logic provides:
 //! - Consistent changeset workflow
-//! - Support for both interactive and automated usage
+//! - Support for both interactive and automated usage, including bots that
+//!   pipe a changeset document instead of writing into `.changesets` directly
 //! - Proper validation and error handling
 //! - User-friendly prompts and feedback
 //!
@@ -52,6 +58,10 @@
 //!     message: Some("Add new feature".to_string()),
 //!     packages: None,
 //!     non_interactive: true,
+//!     stdin: false,
+//!     from_file: None,
+//!     from_diff: false,
+//!     base: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -62,22 +72,26 @@
 
 use crate::cli::commands::ChangesetCreateArgs;
 use crate::error::{CliError, Result};
+use crate::interactive::is_interactive;
 use crate::interactive::prompts::{
-    prompt_bump_type, prompt_environments, prompt_packages, prompt_summary,
+    prompt_bump_type_with_default, prompt_environments, prompt_packages, prompt_summary,
 };
 use crate::output::styling::{Section, StatusSymbol, TextStyle, print_item};
 use crate::output::{JsonResponse, Output};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use sublime_git_tools::Repo;
-use sublime_pkg_tools::changeset::ChangesetManager;
-use sublime_pkg_tools::config::PackageToolsConfig;
-use sublime_pkg_tools::types::Changeset;
-use sublime_standard_tools::filesystem::FileSystemManager;
+use sublime_pkg_tools::changeset::{ChangesetManager, ChangesetProposal, PackageDetector};
+use sublime_pkg_tools::config::{FilePatternsConfig, PackageToolsConfig};
+use sublime_pkg_tools::types::{Changeset, VersionBump};
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
 // Import shared functionality
-use super::common::{load_config, parse_bump_type, validate_bump_type, validate_environments};
+use super::common::{
+    load_config, parse_bump_type, refresh_unreleased_changelogs, validate_bump_type,
+    validate_environments,
+};
 use super::types::ChangesetInfo;
 
 /// Response data for changeset add command (JSON output).
@@ -91,6 +105,130 @@ struct ChangesetAddResponse {
     changeset: ChangesetInfo,
     /// Optional summary message.
     message: Option<String>,
+    /// Packages and bump proposed by `--from-diff`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proposal: Option<ChangesetProposal>,
+}
+
+/// Changeset document accepted by `--stdin` and `--from-file`.
+///
+/// Written as JSON or YAML. Unknown fields are rejected so typos do not silently
+/// drop data. Ids, names, and timestamps are always generated.
+///
+/// ```yaml
+/// bump: minor
+/// packages: ["@myorg/auth"]
+/// environments: [staging]   # optional, defaults to configured environments
+/// branch: feature/oauth      # optional, defaults to the current branch
+/// message: Add OAuth support # optional
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ChangesetDocument {
+    /// Bump type: major, minor, patch, or none.
+    pub(crate) bump: String,
+    /// Affected package names; at least one is required.
+    pub(crate) packages: Vec<String>,
+    /// Target environments.
+    #[serde(default)]
+    pub(crate) environments: Option<Vec<String>>,
+    /// Branch name.
+    #[serde(default)]
+    pub(crate) branch: Option<String>,
+    /// Summary message.
+    #[serde(default)]
+    pub(crate) message: Option<String>,
+}
+
+/// Parses and validates a changeset document.
+///
+/// Documents starting with `{` are parsed as JSON, everything else as YAML.
+///
+/// # Arguments
+///
+/// * `content` - The document text
+/// * `source` - Where the document came from, used in error messages
+///
+/// # Errors
+///
+/// Returns `CliError::Validation` if the document is empty, malformed, has unknown or
+/// missing fields, an invalid bump type, or no packages.
+pub(crate) fn parse_changeset_document(content: &str, source: &str) -> Result<ChangesetDocument> {
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        return Err(CliError::validation(format!("Changeset document from {source} is empty")));
+    }
+
+    let document: ChangesetDocument = if trimmed.starts_with('{') {
+        serde_json::from_str(trimmed).map_err(|e| e.to_string())
+    } else {
+        serde_yaml::from_str(trimmed).map_err(|e| e.to_string())
+    }
+    .map_err(|e| CliError::validation(format!("Invalid changeset document from {source}: {e}")))?;
+
+    validate_bump_type(&document.bump)?;
+    if document.packages.is_empty() {
+        return Err(CliError::validation(format!(
+            "Changeset document from {source} must list at least one package"
+        )));
+    }
+    if document.packages.iter().any(|package| package.trim().is_empty()) {
+        return Err(CliError::validation(format!(
+            "Changeset document from {source} contains an empty package name"
+        )));
+    }
+    if document.branch.as_ref().is_some_and(|branch| branch.trim().is_empty()) {
+        return Err(CliError::validation(format!(
+            "Changeset document from {source} has an empty branch name"
+        )));
+    }
+
+    Ok(document)
+}
+
+/// Builds the effective arguments from a changeset document.
+///
+/// Flags given on the command line take precedence over document values.
+pub(crate) fn merge_document_args(
+    args: &ChangesetCreateArgs,
+    document: ChangesetDocument,
+) -> ChangesetCreateArgs {
+    ChangesetCreateArgs {
+        bump: args.bump.clone().or(Some(document.bump)),
+        env: args.env.clone().or(document.environments),
+        branch: args.branch.clone().or(document.branch),
+        message: args.message.clone().or(document.message),
+        packages: args.packages.clone().or(Some(document.packages)),
+        non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
+    }
+}
+
+/// Reads the changeset document requested by `--stdin` or `--from-file`, if any.
+///
+/// Relative `--from-file` paths resolve against the current directory.
+async fn read_changeset_document(args: &ChangesetCreateArgs) -> Result<Option<ChangesetDocument>> {
+    if args.stdin {
+        debug!("Reading changeset document from stdin");
+        // Reading stdin blocks until the writer closes it; keep that off the runtime
+        let content = tokio::task::spawn_blocking(|| std::io::read_to_string(std::io::stdin()))
+            .await
+            .map_err(|e| CliError::execution(format!("Stdin reader task failed: {e}")))?
+            .map_err(|e| CliError::io(format!("Failed to read changeset from stdin: {e}")))?;
+        return parse_changeset_document(&content, "stdin").map(Some);
+    }
+
+    let Some(path) = &args.from_file else {
+        return Ok(None);
+    };
+    debug!("Reading changeset document from {}", path.display());
+    let content = FileSystemManager::new().read_file_string(path).await.map_err(|e| {
+        CliError::io(format!("Failed to read changeset from '{}': {e}", path.display()))
+    })?;
+    parse_changeset_document(&content, &format!("'{}'", path.display())).map(Some)
 }
 
 /// Executes the changeset add command.
@@ -116,6 +254,7 @@ struct ChangesetAddResponse {
 /// - Git repository errors (not a git repo, detached HEAD, etc.)
 /// - Changeset already exists for the branch
 /// - Invalid input (empty packages, invalid bump type, etc.)
+/// - The `--stdin`/`--from-file` document cannot be read or fails validation
 /// - File system errors when saving changeset
 /// - User cancellation in interactive mode
 ///
@@ -136,6 +275,10 @@ struct ChangesetAddResponse {
 ///     message: Some("Add new feature".to_string()),
 ///     packages: Some(vec!["my-package".to_string()]),
 ///     non_interactive: true,
+///     stdin: false,
+///     from_file: None,
+///     from_diff: false,
+///     base: None,
 /// };
 ///
 /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -156,6 +299,11 @@ pub async fn execute_add(
     let workspace_root = root.unwrap_or_else(|| PathBuf::from("."));
     debug!("Using workspace root: {}", workspace_root.display());
 
+    // A changeset document supplies every value not given as a flag
+    let document_args =
+        read_changeset_document(args).await?.map(|document| merge_document_args(args, document));
+    let args = document_args.as_ref().unwrap_or(args);
+
     // Load configuration
     debug!("Loading workspace configuration");
     let config = load_config(&workspace_root, config_path.as_deref()).await?;
@@ -206,19 +354,54 @@ pub async fn execute_add(
     // Load packages from workspace
     let all_packages = load_workspace_packages(&workspace_root, &config).await;
 
+    // Propose packages and bump type from the branch's diff against its base branch
+    let proposal = if args.from_diff {
+        let proposal = propose_from_diff(
+            &workspace_root,
+            &repo,
+            &fs,
+            &config.changes.file_patterns,
+            &branch,
+            args.base.as_deref(),
+        )
+        .await?;
+        output_proposal(output, &proposal)?;
+        Some(proposal)
+    } else {
+        None
+    };
+    let proposed_bump =
+        proposal.as_ref().map(|proposal| proposal.bump).filter(|bump| *bump != VersionBump::None);
+
     // Detect affected packages from git if not provided
-    let detected_packages = if args.packages.is_none() && !all_packages.is_empty() {
+    let detected_packages = if let Some(proposal) = &proposal {
+        proposal
+            .package_names()
+            .into_iter()
+            .filter(|name| all_packages.is_empty() || all_packages.contains(name))
+            .collect()
+    } else if args.packages.is_none() && !all_packages.is_empty() {
         debug!("Detecting affected packages from git changes");
-        detect_affected_packages(&workspace_root, &repo, &fs, &all_packages).await
+        detect_affected_packages(
+            &workspace_root,
+            &repo,
+            &fs,
+            &config.changes.file_patterns,
+            &all_packages,
+        )
+        .await
     } else {
         vec![]
     };
 
+    // Without a terminal (or under --yes) prompting is impossible, so behave as --non-interactive
+    let non_interactive = args.non_interactive || !is_interactive();
+
     // Determine packages
     let packages = if let Some(pkg_list) = &args.packages {
         debug!("Using provided packages: {:?}", pkg_list);
         pkg_list.clone()
-    } else if args.non_interactive {
+    } else if non_interactive {
         if detected_packages.is_empty() {
             warn!("No packages detected from git changes in non-interactive mode");
             return Err(CliError::validation(
@@ -248,14 +431,18 @@ pub async fn execute_add(
         debug!("Using provided bump type: {}", bump);
         validate_bump_type(bump)?;
         bump.clone()
-    } else if args.non_interactive {
+    } else if let (true, Some(proposed)) = (non_interactive, proposed_bump) {
+        debug!("Using proposed bump type in non-interactive mode: {}", proposed);
+        proposed.as_str().to_string()
+    } else if non_interactive {
         return Err(CliError::validation(
             "Bump type must be specified with --bump flag in non-interactive mode",
         ));
     } else {
         // Interactive mode
-        debug!("Prompting for bump type selection");
-        prompt_bump_type(output.no_color())?
+        debug!("Prompting for bump type selection (proposed: {:?})", proposed_bump);
+        let default = proposed_bump.map_or("patch", |bump| bump.as_str());
+        prompt_bump_type_with_default(default, output.no_color())?
     };
 
     let bump = parse_bump_type(&bump_str)?;
@@ -268,7 +455,7 @@ pub async fn execute_add(
         debug!("Using provided environments: {:?}", env_list);
         validate_environments(env_list, &available_envs)?;
         env_list.clone()
-    } else if args.non_interactive {
+    } else if non_interactive {
         if default_envs.is_empty() {
             warn!("No default environments configured");
             vec![]
@@ -291,7 +478,7 @@ pub async fn execute_add(
     let message = if let Some(msg) = &args.message {
         debug!("Using provided message");
         Some(msg.clone())
-    } else if args.non_interactive {
+    } else if non_interactive {
         None
     } else {
         // Interactive mode
@@ -331,7 +518,75 @@ pub async fn execute_add(
     info!("Changeset created successfully for branch: {}", changeset.branch);
 
     // Output results
-    output_results(output, &changeset, message.as_ref())?;
+    output_results(output, &changeset, message.as_ref(), proposal)?;
+    refresh_unreleased_changelogs(&workspace_root, &config, output).await?;
+
+    Ok(())
+}
+
+/// Proposes packages and a bump type from the branch's diff against its base branch.
+///
+/// Without `--base`, the branch is compared with `main`, then `master`.
+///
+/// # Errors
+///
+/// Returns `CliError::Git` if the base branch cannot be found or the diff cannot be
+/// analyzed.
+async fn propose_from_diff(
+    workspace_root: &Path,
+    repo: &Repo,
+    fs: &FileSystemManager,
+    file_patterns: &FilePatternsConfig,
+    branch: &str,
+    base: Option<&str>,
+) -> Result<ChangesetProposal> {
+    let detector = PackageDetector::new(workspace_root, repo, fs.clone())
+        .with_file_patterns(file_patterns.clone());
+
+    if let Some(base) = base {
+        debug!("Proposing changeset from diff of '{}' against '{}'", branch, base);
+        return detector.propose_changeset(base, branch).await.map_err(|e| {
+            CliError::git(format!("Failed to analyze the diff against '{base}': {e}"))
+        });
+    }
+
+    for base in ["main", "master"].into_iter().filter(|base| *base != branch) {
+        match detector.propose_changeset(base, branch).await {
+            Ok(proposal) => return Ok(proposal),
+            Err(e) => debug!("Cannot compare '{}' with '{}': {}", branch, base, e),
+        }
+    }
+
+    Err(CliError::git(format!("No base branch found for '{branch}'. Specify one with --base.")))
+}
+
+/// Outputs the proposal of `--from-diff` before the prompts (human output only).
+fn output_proposal(output: &Output, proposal: &ChangesetProposal) -> Result<()> {
+    if !output.format().is_human() {
+        return Ok(());
+    }
+
+    if proposal.packages.is_empty() {
+        output.warning(&format!(
+            "No package changes found in {} commit(s) since '{}'",
+            proposal.commit_count, proposal.base
+        ))?;
+        return Ok(());
+    }
+
+    output.info(&format!(
+        "Proposed from {} commit(s) since '{}': {} bump",
+        proposal.commit_count, proposal.base, proposal.bump
+    ))?;
+    for package in &proposal.packages {
+        output.info(&format!(
+            "  {} - {} ({} commit(s))",
+            package.name,
+            package.bump,
+            package.commits.len()
+        ))?;
+    }
+    output.blank_line()?;
 
     Ok(())
 }
@@ -392,6 +647,7 @@ async fn load_workspace_packages(
 /// * `workspace_root` - Root directory of the workspace
 /// * `repo` - Git repository instance
 /// * `fs` - Filesystem manager for file operations
+/// * `file_patterns` - Patterns classifying changed files; generated and binary files are skipped
 /// * `all_packages` - List of all available packages (for validation)
 ///
 /// # Returns
@@ -411,12 +667,14 @@ async fn load_workspace_packages(
 /// let workspace_root = Path::new(".");
 /// let repo = Repo::open(".")?;
 /// let fs = FileSystemManager::new();
+/// let file_patterns = FilePatternsConfig::default();
 /// let all_packages = vec!["pkg-a".to_string(), "pkg-b".to_string()];
 ///
 /// let affected = detect_affected_packages(
 ///     workspace_root,
 ///     &repo,
 ///     &fs,
+///     &file_patterns,
 ///     &all_packages
 /// ).await;
 ///
@@ -426,6 +684,7 @@ pub(crate) async fn detect_affected_packages(
     workspace_root: &Path,
     repo: &Repo,
     fs: &FileSystemManager,
+    file_patterns: &FilePatternsConfig,
     all_packages: &[String],
 ) -> Vec<String> {
     use sublime_pkg_tools::changeset::PackageDetector;
@@ -433,7 +692,8 @@ pub(crate) async fn detect_affected_packages(
     debug!("Detecting affected packages from git changes");
 
     // Create PackageDetector
-    let detector = PackageDetector::new(workspace_root, repo, fs.clone());
+    let detector = PackageDetector::new(workspace_root, repo, fs.clone())
+        .with_file_patterns(file_patterns.clone());
 
     // Get recent commits (since last tag or all recent commits)
     // We use None to get recent commits without a specific reference
@@ -492,7 +752,12 @@ pub(crate) async fn detect_affected_packages(
 /// Outputs the command results based on the output format.
 ///
 /// Formats and displays the created changeset information using modern styling.
-fn output_results(output: &Output, changeset: &Changeset, message: Option<&String>) -> Result<()> {
+fn output_results(
+    output: &Output,
+    changeset: &Changeset,
+    message: Option<&String>,
+    proposal: Option<ChangesetProposal>,
+) -> Result<()> {
     use crate::output::styling::print_bullet;
     use console::Color;
 
@@ -502,6 +767,7 @@ fn output_results(output: &Output, changeset: &Changeset, message: Option<&Strin
             success: true,
             changeset: changeset.into(),
             message: message.cloned(),
+            proposal,
         };
 
         let json_response = JsonResponse::success(response);
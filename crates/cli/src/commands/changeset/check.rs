@@ -9,6 +9,8 @@
 //! - Checks if a changeset exists for the current or specified branch
 //! - Returns appropriate exit codes for scripting (0 = exists, 1 = not exists)
 //! - Supports optional branch parameter (defaults to current Git branch)
+//! - Passes without a changeset when the branch's commits touch no package, for example
+//!   when every changed file matches a package's `.pkgignore` rules
 //! - Outputs minimal information suitable for automation
 //! - Handles errors gracefully for use in Git hooks
 //!
@@ -19,7 +21,10 @@
 //! 2. Loads workspace configuration and validates initialization
 //! 3. Creates ChangesetManager to access changeset storage
 //! 4. Attempts to load the changeset for the specified branch
-//! 5. Returns success (exit 0) if changeset exists, validation error (exit 1) if not
+//! 5. If no changeset exists, maps the branch's commits since its merge-base with
+//!    `main` (or `master`) to packages, honoring package ignore rules
+//! 6. Returns success (exit 0) if changeset exists or none is required, validation
+//!    error (exit 1) otherwise
 //!
 //! Uses:
 //! - `ChangesetManager` from pkg tools for changeset retrieval
@@ -65,7 +70,7 @@ use serde::Serialize;
 use std::path::Path;
 use sublime_git_tools::Repo;
 use sublime_pkg_tools::changeset::ChangesetManager;
-use sublime_pkg_tools::config::{ConfigLoader, PackageToolsConfig};
+use sublime_pkg_tools::config::{ConfigLoader, FilePatternsConfig, PackageToolsConfig};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
@@ -78,6 +83,8 @@ struct ChangesetCheckResponse {
     exists: bool,
     /// The branch that was checked.
     branch: String,
+    /// Whether the branch's changes require a changeset.
+    required: bool,
     /// Optional message describing the result.
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
@@ -90,7 +97,7 @@ struct ChangesetCheckResponse {
 ///
 /// # Exit Codes
 ///
-/// - `0`: Changeset exists for the branch
+/// - `0`: Changeset exists for the branch, or the branch changes no package
 /// - `1`: Changeset does not exist (returned as CliError::Validation)
 ///
 /// # Arguments
@@ -158,6 +165,7 @@ pub async fn execute_check(
     let config = load_config(workspace_root, config_path).await?;
 
     info!("Configuration loaded successfully");
+    let file_patterns = config.changes.file_patterns.clone();
 
     // Create changeset manager
     let manager =
@@ -177,23 +185,89 @@ pub async fn execute_check(
         }
     };
 
+    // Without a changeset, only branches whose changes affect a package need one
+    let required = exists || changeset_required(workspace_root, &branch, &file_patterns).await;
+
     // Output results
     if output.format().is_json() {
-        output_json(output, &branch, exists)?;
+        output_json(output, &branch, exists, required)?;
     } else if !output.format().is_quiet() {
-        output_human(output, &branch, exists)?;
+        output_human(output, &branch, exists, required)?;
     }
 
     // Return appropriate result for exit code handling
     if exists {
         info!("Changeset check succeeded: exists on branch '{branch}'");
         Ok(())
+    } else if !required {
+        info!("Changeset check succeeded: branch '{branch}' changes no package");
+        Ok(())
     } else {
         warn!("Changeset check failed: not found on branch '{branch}'");
         Err(CliError::Validation(format!("No changeset found for branch '{branch}'")))
     }
 }
 
+/// Determines whether the changes on a branch require a changeset.
+///
+/// Collects the branch's commits since its merge-base with `main` (or `master`) and maps
+/// the changed files to packages with `PackageDetector`, which skips generated and binary
+/// files and files matched by a package's `.pkgignore` or `packageTools.ignoreChanges` rules.
+///
+/// # Arguments
+///
+/// * `workspace_root` - Root directory of the workspace
+/// * `branch` - Branch to inspect
+/// * `file_patterns` - Patterns classifying changed files
+///
+/// # Returns
+///
+/// `false` only if the branch has commits and none of them affect a package. Any failure
+/// to inspect the branch (no base branch, Git or detection errors) returns `true`.
+async fn changeset_required(
+    workspace_root: &Path,
+    branch: &str,
+    file_patterns: &FilePatternsConfig,
+) -> bool {
+    use sublime_pkg_tools::changeset::PackageDetector;
+
+    let Some(repo) = workspace_root.to_str().and_then(|root| Repo::open(root).ok()) else {
+        return true;
+    };
+
+    let Some(merge_base) = ["main", "master"]
+        .into_iter()
+        .filter(|base| *base != branch)
+        .find_map(|base| repo.get_merge_base(branch, base).ok())
+    else {
+        debug!("No base branch found for '{branch}'; a changeset is required");
+        return true;
+    };
+
+    let detector = PackageDetector::new(workspace_root, &repo, FileSystemManager::new())
+        .with_file_patterns(file_patterns.clone());
+    let commits = match detector.get_commits_between(&merge_base, branch) {
+        Ok(commits) if !commits.is_empty() => commits,
+        Ok(_) => return true,
+        Err(e) => {
+            warn!("Failed to get commits for branch '{branch}': {e}");
+            return true;
+        }
+    };
+
+    let commit_ids: Vec<String> = commits.into_iter().map(|commit| commit.hash).collect();
+    match detector.detect_affected_packages(&commit_ids).await {
+        Ok(packages) => {
+            debug!("Branch '{branch}' affects {} package(s): {:?}", packages.len(), packages);
+            !packages.is_empty()
+        }
+        Err(e) => {
+            warn!("Failed to detect affected packages for branch '{branch}': {e}");
+            true
+        }
+    }
+}
+
 /// Detect the current Git branch.
 ///
 /// # Arguments
@@ -295,12 +369,15 @@ async fn load_config(
 }
 
 /// Output check result in JSON format.
-fn output_json(output: &Output, branch: &str, exists: bool) -> Result<()> {
+fn output_json(output: &Output, branch: &str, exists: bool, required: bool) -> Result<()> {
     let response = ChangesetCheckResponse {
         exists,
         branch: branch.to_string(),
+        required,
         message: if exists {
             Some(format!("Changeset exists for branch '{branch}'"))
+        } else if !required {
+            Some(format!("No changeset required for branch '{branch}': no package changes"))
         } else {
             Some(format!("No changeset found for branch '{branch}'"))
         },
@@ -316,11 +393,17 @@ fn output_json(output: &Output, branch: &str, exists: bool) -> Result<()> {
 }
 
 /// Output check result in human-readable format.
-fn output_human(output: &Output, branch: &str, exists: bool) -> Result<()> {
+fn output_human(output: &Output, branch: &str, exists: bool, required: bool) -> Result<()> {
     if exists {
         output
             .success(&format!("✓ Changeset exists for branch '{branch}'"))
             .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+    } else if !required {
+        output
+            .success(&format!(
+                "✓ No changeset required for branch '{branch}': its changes touch no package"
+            ))
+            .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
     } else {
         output
             .error(&format!("✗ No changeset found for branch '{branch}'"))
@@ -0,0 +1,253 @@
+//! Changeset clean command implementation.
+//!
+//! This module implements the `changeset clean` command for rewriting pending changesets
+//! that list packages the workspace no longer has.
+//!
+//! # What
+//!
+//! Provides the `execute_clean` function that:
+//! - Lists the packages of pending changesets that are not in the workspace
+//! - Renames them when `changeset.package_renames` maps them to a workspace package
+//! - Asks which package each remaining one became, or removes it, in interactive mode
+//! - Removes the remaining ones with `--prune` in non-interactive mode
+//! - Deletes changesets left without packages
+//! - Formats output in human-readable or JSON format
+//!
+//! # How
+//!
+//! The command flow:
+//! 1. Loads workspace configuration and validates initialization
+//! 2. Discovers the workspace packages with `VersionResolver`
+//! 3. Finds stale entries with `ChangesetManager::find_stale_packages`
+//! 4. Prompts for the unmapped packages when interactive and `--prune` is not set
+//! 5. Rewrites the changesets with `ChangesetManager::clean_stale_packages`, unless
+//!    `--dry-run` is set
+//! 6. Outputs what was renamed, removed, kept, and deleted
+//!
+//! # Why
+//!
+//! A changeset written before a package was removed or renamed makes `workspace bump`
+//! fail. Cleaning rewrites those entries in one step instead of editing changeset files
+//! by hand.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_clean;
+//! use sublime_cli_tools::cli::commands::ChangesetCleanArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetCleanArgs { prune: true, dry_run: false };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_clean(&args, &output, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::common::load_config;
+use crate::cli::commands::ChangesetCleanArgs;
+use crate::error::{CliError, Result};
+use crate::interactive::mode::is_interactive;
+use crate::interactive::select::fuzzy_select;
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use sublime_pkg_tools::changeset::{ChangesetManager, StaleCleanupReport, StalePackage};
+use sublime_pkg_tools::version::VersionResolver;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Choice offered after the workspace packages when asking what a stale package became.
+const REMOVE_CHOICE: &str = "(removed) drop it from the changesets";
+
+/// Response data for changeset clean command (JSON output).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangesetCleanResponse {
+    /// Whether changesets were left untouched.
+    dry_run: bool,
+    /// Stale entries found before cleaning.
+    stale: Vec<StalePackage>,
+    /// What was renamed, removed, kept, and deleted; absent on a dry run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report: Option<StaleCleanupReport>,
+}
+
+/// Execute the changeset clean command.
+///
+/// Renames or removes the packages of pending changesets that are not in the workspace.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Optional workspace root directory (defaults to current directory)
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not initialized (no configuration found)
+/// - Workspace packages cannot be discovered
+/// - A prompt is cancelled
+/// - File system operations fail
+pub async fn execute_clean(
+    args: &ChangesetCleanArgs,
+    output: &Output,
+    root: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let workspace_root = root.unwrap_or_else(|| Path::new("."));
+    debug!("Cleaning stale changeset packages in workspace: {}", workspace_root.display());
+
+    // Load configuration
+    let config = load_config(workspace_root, config_path).await?;
+
+    info!("Configuration loaded successfully");
+
+    let resolver = VersionResolver::new(workspace_root.to_path_buf(), config.clone())
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
+    let workspace_packages: HashSet<String> = resolver
+        .discover_packages()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?
+        .iter()
+        .map(|package| package.name().to_string())
+        .collect();
+
+    // Create changeset manager
+    let manager =
+        ChangesetManager::new(workspace_root.to_path_buf(), FileSystemManager::new(), config)
+            .await
+            .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
+
+    let stale = manager
+        .find_stale_packages(&workspace_packages)
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!("Found {} stale changeset package(s)", stale.len());
+
+    if args.dry_run || stale.is_empty() {
+        return output_stale(output, stale, args.dry_run);
+    }
+
+    let mut renames = BTreeMap::new();
+    let mut prune = args.prune;
+    if !prune && is_interactive() {
+        renames = prompt_renames(&stale, &workspace_packages, output.no_color())?;
+        prune = true;
+    }
+
+    let report = manager
+        .clean_stale_packages(&workspace_packages, &renames, prune)
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!(
+        "Renamed {}, removed {}, kept {} stale package(s); deleted {} changeset(s)",
+        report.renamed.len(),
+        report.removed.len(),
+        report.kept.len(),
+        report.deleted.len()
+    );
+
+    // Output results
+    if output.format().is_json() {
+        let response = ChangesetCleanResponse { dry_run: false, stale, report: Some(report) };
+        output.json(&JsonResponse::success(response))?;
+    } else {
+        for entry in &report.renamed {
+            let target = entry.renamed_to.as_deref().unwrap_or_default();
+            output.success(&format!("{}: renamed {} to {target}", entry.branch, entry.package))?;
+        }
+        for entry in &report.removed {
+            output.success(&format!("{}: removed {}", entry.branch, entry.package))?;
+        }
+        for branch in &report.deleted {
+            output.info(&format!("Deleted changeset '{branch}', which had no packages left"))?;
+        }
+        if !report.kept.is_empty() {
+            output.warning(&format!(
+                "{} stale package(s) kept. Map them in changeset.package_renames or re-run with \
+                 --prune to remove them",
+                report.kept.len()
+            ))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Asks which workspace package each unmapped stale package became.
+///
+/// Packages that are answered with the removal choice are left out of the renames.
+///
+/// # Errors
+///
+/// Returns an error if a prompt is cancelled.
+fn prompt_renames(
+    stale: &[StalePackage],
+    workspace_packages: &HashSet<String>,
+    no_color: bool,
+) -> Result<BTreeMap<String, String>> {
+    let unmapped: BTreeSet<&str> = stale
+        .iter()
+        .filter(|entry| entry.renamed_to.is_none())
+        .map(|entry| entry.package.as_str())
+        .collect();
+
+    let mut choices: Vec<&str> = workspace_packages.iter().map(String::as_str).collect();
+    choices.sort_unstable();
+    choices.push(REMOVE_CHOICE);
+    let remove = choices.len() - 1;
+
+    let mut renames = BTreeMap::new();
+    for package in unmapped {
+        let selection = fuzzy_select(
+            &format!("'{package}' is not in the workspace. Which package did it become?"),
+            &choices,
+            Some(remove),
+            no_color,
+        )?;
+        if selection != remove {
+            renames.insert(package.to_string(), choices[selection].to_string());
+        }
+    }
+
+    Ok(renames)
+}
+
+/// Outputs the stale entries without modifying any changeset.
+///
+/// # Errors
+///
+/// Returns an error if output operations fail.
+fn output_stale(output: &Output, stale: Vec<StalePackage>, dry_run: bool) -> Result<()> {
+    if output.format().is_json() {
+        let response = ChangesetCleanResponse { dry_run, stale, report: None };
+        output.json(&JsonResponse::success(response))?;
+        return Ok(());
+    }
+
+    if stale.is_empty() {
+        output.success("All pending changesets list workspace packages")?;
+        return Ok(());
+    }
+
+    output.info(&format!("{} stale package(s) in pending changesets:", stale.len()))?;
+    for entry in &stale {
+        let line = match &entry.renamed_to {
+            Some(target) => format!("  {}: {} -> {target}", entry.branch, entry.package),
+            None => format!("  {}: {} (not in the workspace)", entry.branch, entry.package),
+        };
+        output.plain(&line)?;
+    }
+
+    Ok(())
+}
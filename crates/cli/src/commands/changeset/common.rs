@@ -41,12 +41,15 @@
 //! ```
 
 use crate::error::{CliError, Result};
+use crate::output::Output;
 use std::path::{Path, PathBuf};
 use sublime_git_tools::Repo;
+use sublime_pkg_tools::changelog::ChangelogGenerator;
+use sublime_pkg_tools::changeset::{ChangesetManager, FileBasedChangesetStorage};
 use sublime_pkg_tools::config::{ConfigLoader, PackageToolsConfig};
 use sublime_pkg_tools::types::VersionBump;
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 /// Loads workspace configuration from file or defaults.
 ///
@@ -203,10 +206,10 @@ pub(crate) fn detect_current_branch(workspace_root: &Path) -> Result<String> {
     Ok(branch)
 }
 
-/// Gets the file path for a changeset.
+/// Finds the file of the pending changeset for a branch.
 ///
-/// Constructs the full path to a changeset file based on the workspace root,
-/// configuration, and branch name. The branch name is sanitized for filesystem safety.
+/// Pending changesets are stored in files named by id, so the file is looked up by branch
+/// through the file-based changeset storage.
 ///
 /// # Arguments
 ///
@@ -216,56 +219,37 @@ pub(crate) fn detect_current_branch(workspace_root: &Path) -> Result<String> {
 ///
 /// # Returns
 ///
-/// Returns the path to the changeset file as a `PathBuf`.
+/// Returns the path to the changeset file, or `None` if the branch has no pending
+/// changeset.
+///
+/// # Errors
+///
+/// Returns an error if the changeset directory cannot be read.
 ///
 /// # Examples
 ///
 /// ```rust,ignore
-/// use super::common::get_changeset_file_path;
+/// use super::common::find_changeset_file_path;
 /// use std::path::Path;
 ///
-/// let path = get_changeset_file_path(
-///     Path::new("."),
-///     &config,
-///     "feature/new-api"
-/// );
-/// // Returns: ./.changesets/feature-new-api.json
+/// let path = find_changeset_file_path(Path::new("."), &config, "feature/new-api").await?;
+/// // Returns: Some("./.changesets/5b0e7d13c9a2.json")
 /// ```
-pub(crate) fn get_changeset_file_path(
+pub(crate) async fn find_changeset_file_path(
     workspace_root: &Path,
     config: &PackageToolsConfig,
     branch: &str,
-) -> PathBuf {
-    let changeset_dir = workspace_root.join(&config.changeset.path);
-    let sanitized_branch = sanitize_branch_name(branch);
-    changeset_dir.join(format!("{sanitized_branch}.json"))
-}
-
-/// Sanitizes a branch name for use as a filename.
-///
-/// Replaces characters that are invalid or problematic in filenames with safe
-/// alternatives (hyphens). This ensures branch names can be safely used as
-/// part of file paths on all platforms.
-///
-/// # Arguments
-///
-/// * `branch` - The branch name to sanitize
-///
-/// # Returns
-///
-/// Returns the sanitized branch name as a String.
-///
-/// # Examples
-///
-/// ```rust,ignore
-/// use super::common::sanitize_branch_name;
-///
-/// assert_eq!(sanitize_branch_name("feature/new-api"), "feature-new-api");
-/// assert_eq!(sanitize_branch_name("fix:bug"), "fix-bug");
-/// assert_eq!(sanitize_branch_name("test<file>"), "test-file-");
-/// ```
-pub(crate) fn sanitize_branch_name(branch: &str) -> String {
-    branch.replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "-")
+) -> Result<Option<PathBuf>> {
+    let storage = FileBasedChangesetStorage::new(
+        workspace_root.to_path_buf(),
+        config.changeset.path.clone(),
+        config.changeset.history_path.clone(),
+        FileSystemManager::new(),
+    );
+    storage
+        .find_changeset_path(branch)
+        .await
+        .map_err(|e| CliError::io(format!("Failed to find changeset file: {e}")))
 }
 
 /// Validates a bump type string.
@@ -402,3 +386,80 @@ pub(crate) fn validate_environments(provided: &[String], available: &[String]) -
 
     Ok(())
 }
+
+/// Rewrites the `## [Unreleased]` changelog sections from the pending changesets.
+///
+/// Does nothing unless `changelog.unreleased_section` is enabled. The changeset itself is
+/// already saved, so a failure is reported as a warning instead of failing the command.
+///
+/// # Arguments
+///
+/// * `workspace_root` - Root directory of the workspace
+/// * `config` - Loaded package tools configuration
+/// * `output` - Output context; the outcome is only reported in human output
+///
+/// # Errors
+///
+/// Returns an error only if writing to the output fails.
+pub(crate) async fn refresh_unreleased_changelogs(
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+    output: &Output,
+) -> Result<()> {
+    if !config.changelog.enabled || !config.changelog.unreleased_section {
+        return Ok(());
+    }
+
+    match update_unreleased_changelogs(workspace_root, config).await {
+        Ok(updated) => {
+            info!("Updated the unreleased section of {} changelog(s)", updated.len());
+            if output.format().is_human() && !updated.is_empty() {
+                output.info(&format!(
+                    "Updated the Unreleased section of {} changelog(s)",
+                    updated.len()
+                ))?;
+            }
+        }
+        Err(e) => {
+            warn!("Failed to update unreleased changelog sections: {}", e);
+            if output.format().is_human() {
+                output
+                    .warning(&format!("Failed to update the Unreleased changelog section: {e}"))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads the pending changesets and rewrites the unreleased sections from them.
+async fn update_unreleased_changelogs(
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+) -> Result<Vec<PathBuf>> {
+    let fs = FileSystemManager::new();
+    let manager = ChangesetManager::new(workspace_root.to_path_buf(), fs.clone(), config.clone())
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create changeset manager: {e}")))?;
+    let pending = manager
+        .list_pending()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to list pending changesets: {e}")))?;
+
+    let repo =
+        Repo::open(workspace_root.to_str().ok_or_else(|| {
+            CliError::execution("Workspace path contains invalid UTF-8".to_string())
+        })?)
+        .map_err(|e| CliError::git(format!("Failed to open git repository: {e}")))?;
+    let generator =
+        ChangelogGenerator::new(workspace_root.to_path_buf(), repo, fs, config.changelog.clone())
+            .await
+            .map_err(|e| {
+                CliError::execution(format!("Failed to create changelog generator: {e}"))
+            })?;
+
+    generator
+        .update_unreleased(&pending, false)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to update changelogs: {e}")))
+}
@@ -67,11 +67,11 @@ use serde::Serialize;
 use std::path::Path;
 use sublime_pkg_tools::changeset::ChangesetManager;
 use sublime_pkg_tools::types::Changeset;
-use sublime_standard_tools::filesystem::FileSystemManager;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
 // Import shared functionality
-use super::common::{detect_current_branch, get_changeset_file_path, load_config};
+use super::common::{detect_current_branch, find_changeset_file_path, load_config};
 use super::types::{ChangesetInfo, format_bump_type};
 
 /// Response data for changeset edit command (JSON output).
@@ -174,16 +174,11 @@ pub async fn execute_edit(
     debug!("Original changeset loaded");
 
     // Get the changeset file path
-    let changeset_path = get_changeset_file_path(&workspace_root, &config, &branch);
+    let changeset_path = find_changeset_file_path(&workspace_root, &config, &branch)
+        .await?
+        .ok_or_else(|| CliError::io(format!("Changeset file not found for branch '{branch}'")))?;
     debug!("Changeset file path: {}", changeset_path.display());
 
-    if !changeset_path.exists() {
-        return Err(CliError::io(format!(
-            "Changeset file not found: {}",
-            changeset_path.display()
-        )));
-    }
-
     // Open in editor
     info!("Opening changeset file in editor");
     let _ = output.info("Opening changeset in editor...");
@@ -194,7 +189,7 @@ pub async fn execute_edit(
     let _ = output.info("Editor closed. Validating changes...");
 
     // Reload and validate the edited changeset
-    match validate_edited_changeset(&manager, &branch).await {
+    match validate_edited_changeset(&changeset_path, &branch).await {
         Ok(edited_changeset) => {
             debug!("Changeset validation successful");
             output_results(output, &branch, &edited_changeset)?;
@@ -222,11 +217,14 @@ pub async fn execute_edit(
     }
 }
 
-/// Validates an edited changeset by reloading it.
+/// Validates an edited changeset by reloading the edited file.
+///
+/// The file is read directly rather than looked up by branch, so an edit that changes
+/// the `branch` field is reported as such instead of as a missing changeset.
 ///
 /// # Arguments
 ///
-/// * `manager` - The changeset manager
+/// * `changeset_path` - The path of the edited changeset file
 /// * `branch` - The branch name
 ///
 /// # Returns
@@ -239,16 +237,14 @@ pub async fn execute_edit(
 /// - The changeset file cannot be read
 /// - The changeset JSON is invalid
 /// - The changeset fails validation
-async fn validate_edited_changeset(
-    manager: &ChangesetManager<
-        sublime_pkg_tools::changeset::FileBasedChangesetStorage<FileSystemManager>,
-    >,
-    branch: &str,
-) -> Result<Changeset> {
+async fn validate_edited_changeset(changeset_path: &Path, branch: &str) -> Result<Changeset> {
     debug!("Validating edited changeset for branch: {}", branch);
 
     // Attempt to reload the changeset
-    let changeset = manager.load(branch).await.map_err(|e| {
+    let content = FileSystemManager::new().read_file_string(changeset_path).await.map_err(|e| {
+        CliError::io(format!("Failed to read edited changeset '{}': {e}", changeset_path.display()))
+    })?;
+    let changeset: Changeset = serde_json::from_str(&content).map_err(|e| {
         CliError::validation(format!(
             "Failed to parse edited changeset. The file may contain invalid JSON or \
              missing required fields: {e}"
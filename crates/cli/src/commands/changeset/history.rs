@@ -370,13 +370,22 @@ fn output_table(output: &Output, changesets: &[ArchivedChangeset]) -> Result<()>
     // Create table
     let mut table = TableBuilder::new()
         .theme(TableTheme::Default)
-        .columns(&["Branch", "Bump", "Packages", "Environments", "Applied At", "Applied By"])
+        .columns(&[
+            "Branch",
+            "Name",
+            "Bump",
+            "Packages",
+            "Environments",
+            "Applied At",
+            "Applied By",
+        ])
         .alignment(0, ColumnAlignment::Left)
         .alignment(1, ColumnAlignment::Left)
         .alignment(2, ColumnAlignment::Left)
         .alignment(3, ColumnAlignment::Left)
         .alignment(4, ColumnAlignment::Left)
         .alignment(5, ColumnAlignment::Left)
+        .alignment(6, ColumnAlignment::Left)
         .max_width(120)
         .build();
 
@@ -402,6 +411,7 @@ fn output_table(output: &Output, changesets: &[ArchivedChangeset]) -> Result<()>
 
         table.add_row(&[
             &archived.changeset.branch,
+            archived.changeset.name.as_deref().unwrap_or(&archived.changeset.id),
             &bump_display,
             &packages_display,
             &environments_display,
@@ -6,8 +6,9 @@
 //!
 //! Provides the `execute_list` function that:
 //! - Lists all pending changesets in the workspace
-//! - Supports filtering by package name, bump type, and environment
-//! - Supports sorting by date, branch, or bump type
+//! - Supports filtering by package name, bump type, environment, commit author, and age
+//! - Supports sorting by date, creation date, branch, or bump type
+//! - Supports pagination with `--limit` and `--offset`
 //! - Outputs results in table or JSON format
 //! - Handles empty changeset directories gracefully
 //!
@@ -16,11 +17,10 @@
 //! The command flow:
 //! 1. Loads workspace configuration and validates initialization
 //! 2. Creates ChangesetManager to access changeset storage
-//! 3. Retrieves all pending changesets via `list_pending()`
-//! 4. Applies filters based on command arguments (package, bump, environment)
-//! 5. Sorts changesets by specified field (date, branch, bump)
-//! 6. Formats output as either a human-readable table or JSON
-//! 7. Displays summary statistics (total count)
+//! 3. Builds a `ChangesetQuery` from the command arguments
+//! 4. Runs it via `ChangesetManager::query()`, which filters, sorts, and paginates
+//! 5. Formats output as either a human-readable table or JSON
+//! 6. Displays summary statistics (shown and matching counts)
 //!
 //! Uses:
 //! - `ChangesetManager` from pkg tools for changeset retrieval
@@ -48,7 +48,13 @@
 //!     filter_package: Some("my-package".to_string()),
 //!     filter_bump: Some("minor".to_string()),
 //!     filter_env: None,
+//!     filter_author: None,
+//!     newer_than: None,
+//!     older_than: None,
 //!     sort: "date".to_string(),
+//!     reverse: false,
+//!     limit: None,
+//!     offset: 0,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -62,9 +68,12 @@ use crate::error::{CliError, Result};
 use crate::output::styling::{Section, print_item};
 use crate::output::table::{ColumnAlignment, TableBuilder, TableTheme};
 use crate::output::{JsonResponse, Output};
+use chrono::{Duration, Utc};
 use serde::Serialize;
 use std::path::Path;
-use sublime_pkg_tools::changeset::ChangesetManager;
+use sublime_pkg_tools::changeset::{
+    ChangesetManager, ChangesetPage, ChangesetQuery, ChangesetSortField,
+};
 use sublime_pkg_tools::config::{ConfigLoader, PackageToolsConfig};
 use sublime_pkg_tools::types::{Changeset, VersionBump};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
@@ -81,12 +90,21 @@ struct ChangesetListResponse {
     changesets: Vec<ChangesetListItem>,
     /// Total number of changesets returned.
     total: usize,
+    /// Number of changesets matching the filters, before pagination.
+    matching: usize,
+    /// Number of matching changesets skipped.
+    offset: usize,
 }
 
 /// Individual changeset information for list output.
 #[derive(Debug, Serialize)]
 struct ChangesetListItem {
-    /// Branch name (also serves as unique identifier).
+    /// Unique changeset id.
+    id: String,
+    /// Human-friendly changeset name, if one was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Branch name.
     branch: String,
     /// Version bump type.
     bump: String,
@@ -105,6 +123,8 @@ struct ChangesetListItem {
 impl From<Changeset> for ChangesetListItem {
     fn from(changeset: Changeset) -> Self {
         Self {
+            id: changeset.id,
+            name: changeset.name,
             branch: changeset.branch,
             bump: changeset.bump.to_string().to_lowercase(),
             packages: changeset.packages,
@@ -153,7 +173,13 @@ impl From<Changeset> for ChangesetListItem {
 ///     filter_package: None,
 ///     filter_bump: None,
 ///     filter_env: None,
+///     filter_author: None,
+///     newer_than: None,
+///     older_than: None,
 ///     sort: "date".to_string(),
+///     reverse: false,
+///     limit: None,
+///     offset: 0,
 /// };
 ///
 /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -181,42 +207,19 @@ pub async fn execute_list(
             .await
             .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
 
-    // Load all pending changesets
-    let mut changesets = manager
-        .list_pending()
+    let query = build_query(args)?;
+    let page = manager
+        .query(&query)
         .await
         .map_err(|e| CliError::Execution(format!("Failed to load changesets: {e}")))?;
 
-    debug!("Loaded {} changeset(s)", changesets.len());
-
-    // Apply filters
-    if let Some(package) = &args.filter_package {
-        debug!("Filtering by package: {}", package);
-        changesets.retain(|cs| cs.has_package(package));
-    }
-
-    if let Some(bump_str) = &args.filter_bump {
-        let bump = parse_bump_type(bump_str)?;
-        debug!("Filtering by bump type: {:?}", bump);
-        changesets.retain(|cs| cs.bump == bump);
-    }
-
-    if let Some(env) = &args.filter_env {
-        debug!("Filtering by environment: {}", env);
-        changesets.retain(|cs| cs.environments.contains(env));
-    }
-
-    // Sort changesets
-    sort_changesets(&mut changesets, &args.sort)?;
-
-    let total = changesets.len();
-    info!("Displaying {} changeset(s) after filtering", total);
+    info!("Displaying {} of {} matching changeset(s)", page.changesets.len(), page.total);
 
     // Output results
     if output.format().is_json() {
-        output_json(output, changesets)?;
+        output_json(output, page)?;
     } else {
-        output_table(output, &changesets)?;
+        output_table(output, &page)?;
     }
 
     Ok(())
@@ -285,53 +288,97 @@ async fn load_config(
     Ok(config)
 }
 
-/// Sort changesets by the specified field.
+/// Build a changeset query from the command arguments.
+///
+/// # Errors
+///
+/// Returns an error if a bump type, age, or sort field is invalid.
+pub(crate) fn build_query(args: &ChangesetListArgs) -> Result<ChangesetQuery> {
+    let mut query = ChangesetQuery::new()
+        .sort_by(parse_sort_field(&args.sort)?)
+        .reversed(args.reverse)
+        .with_offset(args.offset);
+
+    if let Some(package) = &args.filter_package {
+        debug!("Filtering by package: {}", package);
+        query = query.with_package(package);
+    }
+
+    if let Some(bump_str) = &args.filter_bump {
+        let bump = parse_bump_type(bump_str)?;
+        debug!("Filtering by bump type: {:?}", bump);
+        query = query.with_bump(bump);
+    }
+
+    if let Some(env) = &args.filter_env {
+        debug!("Filtering by environment: {}", env);
+        query = query.with_environment(env);
+    }
+
+    if let Some(author) = &args.filter_author {
+        debug!("Filtering by author: {}", author);
+        query = query.with_author(author);
+    }
+
+    let now = Utc::now();
+    if let Some(age) = &args.newer_than {
+        query = query.created_after(now - parse_age(age)?);
+    }
+    if let Some(age) = &args.older_than {
+        query = query.created_before(now - parse_age(age)?);
+    }
+
+    if let Some(limit) = args.limit {
+        query = query.with_limit(limit);
+    }
+
+    Ok(query)
+}
+
+/// Parse a sort field name.
 ///
 /// # Arguments
 ///
-/// * `changesets` - Mutable reference to the list of changesets to sort
-/// * `sort_by` - Field to sort by: "date", "branch", or "bump"
+/// * `sort_by` - Field to sort by: "date", "created", "branch", or "bump"
 ///
 /// # Errors
 ///
 /// Returns an error if the sort field is invalid.
-fn sort_changesets(changesets: &mut [Changeset], sort_by: &str) -> Result<()> {
+pub(crate) fn parse_sort_field(sort_by: &str) -> Result<ChangesetSortField> {
     match sort_by {
-        "date" => {
-            changesets.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
-            debug!("Sorted by date (most recent first)");
-        }
-        "branch" => {
-            changesets.sort_by(|a, b| a.branch.cmp(&b.branch));
-            debug!("Sorted by branch (alphabetical)");
-        }
-        "bump" => {
-            changesets.sort_by(|a, b| {
-                let a_order = bump_order(a.bump);
-                let b_order = bump_order(b.bump);
-                b_order.cmp(&a_order)
-            });
-            debug!("Sorted by bump type (major > minor > patch)");
-        }
-        _ => {
-            return Err(CliError::Validation(format!(
-                "Invalid sort field '{sort_by}'. Valid options: date, branch, bump"
-            )));
-        }
+        "date" => Ok(ChangesetSortField::UpdatedAt),
+        "created" => Ok(ChangesetSortField::CreatedAt),
+        "branch" => Ok(ChangesetSortField::Branch),
+        "bump" => Ok(ChangesetSortField::Bump),
+        _ => Err(CliError::Validation(format!(
+            "Invalid sort field '{sort_by}'. Valid options: date, created, branch, bump"
+        ))),
     }
-
-    Ok(())
 }
 
-/// Get sort order for bump types.
+/// Parse an age such as `30m`, `12h`, `7d`, or `2w`.
 ///
-/// Major bumps are highest priority (3), followed by minor (2), then patch (1).
-fn bump_order(bump: VersionBump) -> u8 {
-    match bump {
-        VersionBump::Major => 3,
-        VersionBump::Minor => 2,
-        VersionBump::Patch => 1,
-        VersionBump::None => 0,
+/// # Errors
+///
+/// Returns an error if the amount is not a number or the unit is unknown.
+pub(crate) fn parse_age(age: &str) -> Result<Duration> {
+    let invalid = || {
+        CliError::Validation(format!(
+            "Invalid age '{age}'. Use a number followed by m, h, d, or w (e.g., 7d)"
+        ))
+    };
+
+    let age = age.trim();
+    let split = age.len().checked_sub(1).ok_or_else(invalid)?;
+    let (amount, unit) = age.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+
+    match unit {
+        "m" => Ok(Duration::minutes(amount)),
+        "h" => Ok(Duration::hours(amount)),
+        "d" => Ok(Duration::days(amount)),
+        "w" => Ok(Duration::weeks(amount)),
+        _ => Err(invalid()),
     }
 }
 
@@ -356,13 +403,20 @@ pub(crate) fn parse_bump_type(bump_str: &str) -> Result<VersionBump> {
 }
 
 /// Output changesets in JSON format.
-fn output_json(output: &Output, changesets: Vec<Changeset>) -> Result<()> {
+fn output_json(output: &Output, page: ChangesetPage) -> Result<()> {
+    let matching = page.total;
+    let offset = page.offset;
     let items: Vec<ChangesetListItem> =
-        changesets.into_iter().map(std::convert::Into::into).collect();
+        page.changesets.into_iter().map(std::convert::Into::into).collect();
 
     let total = items.len();
-    let response =
-        JsonResponse::success(ChangesetListResponse { success: true, changesets: items, total });
+    let response = JsonResponse::success(ChangesetListResponse {
+        success: true,
+        changesets: items,
+        total,
+        matching,
+        offset,
+    });
 
     output
         .json(&response)
@@ -372,7 +426,8 @@ fn output_json(output: &Output, changesets: Vec<Changeset>) -> Result<()> {
 }
 
 /// Output changesets in human-readable table format.
-fn output_table(output: &Output, changesets: &[Changeset]) -> Result<()> {
+fn output_table(output: &Output, page: &ChangesetPage) -> Result<()> {
+    let changesets = &page.changesets;
     let total = changesets.len();
 
     if total == 0 {
@@ -388,13 +443,14 @@ fn output_table(output: &Output, changesets: &[Changeset]) -> Result<()> {
     // Create table
     let mut table = TableBuilder::new()
         .theme(TableTheme::Default)
-        .columns(&["Branch", "Bump", "Packages", "Environments", "Commits", "Updated"])
+        .columns(&["Branch", "Name", "Bump", "Packages", "Environments", "Commits", "Updated"])
         .alignment(0, ColumnAlignment::Left)
         .alignment(1, ColumnAlignment::Left)
         .alignment(2, ColumnAlignment::Left)
         .alignment(3, ColumnAlignment::Left)
-        .alignment(4, ColumnAlignment::Right)
-        .alignment(5, ColumnAlignment::Left)
+        .alignment(4, ColumnAlignment::Left)
+        .alignment(5, ColumnAlignment::Right)
+        .alignment(6, ColumnAlignment::Left)
         .max_width(120)
         .build();
 
@@ -421,6 +477,7 @@ fn output_table(output: &Output, changesets: &[Changeset]) -> Result<()> {
 
         table.add_row(&[
             &changeset.branch,
+            changeset.name.as_deref().unwrap_or(&changeset.id),
             &bump_display,
             &packages_display,
             &environments_display,
@@ -439,7 +496,13 @@ fn output_table(output: &Output, changesets: &[Changeset]) -> Result<()> {
     // Display summary
     let section = Section::new("Summary");
     section.print();
-    print_item("Total Changesets", &total.to_string(), true);
+    if total == page.total {
+        print_item("Total Changesets", &total.to_string(), true);
+    } else {
+        let first = page.offset + 1;
+        let last = page.offset + total;
+        print_item("Showing", &format!("{first}-{last} of {}", page.total), true);
+    }
 
     Ok(())
 }
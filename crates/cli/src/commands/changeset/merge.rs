@@ -0,0 +1,154 @@
+//! Changeset merge command implementation.
+//!
+//! This module implements the `changeset merge` command for combining several pending
+//! changesets into one.
+//!
+//! # What
+//!
+//! Provides the `execute_merge` function that:
+//! - Combines the packages, environments, and commits of the given changesets
+//! - Keeps the largest bump, so no package is released with a smaller bump than before
+//! - Saves the merged changeset and removes the merged sources
+//! - Records the merge in the operation history of the merged changeset
+//! - Formats output in human-readable or JSON format
+//!
+//! # How
+//!
+//! The command flow:
+//! 1. Loads workspace configuration and validates initialization
+//! 2. Creates ChangesetManager to access changeset storage
+//! 3. Merges the changesets with `ChangesetManager::merge`
+//! 4. Outputs the merged changeset
+//!
+//! # Why
+//!
+//! Merging keeps one changeset per release when work from several branches ships
+//! together, such as a hotfix folded into a feature release.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_merge;
+//! use sublime_cli_tools::cli::commands::ChangesetMergeArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetMergeArgs {
+//!     branches: vec!["fix/login".to_string(), "feature/sso".to_string()],
+//!     into: "release/auth".to_string(),
+//! };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_merge(&args, &output, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::common::load_config;
+use super::split::print_changeset;
+use super::types::ChangesetInfo;
+use crate::cli::commands::ChangesetMergeArgs;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::changeset::ChangesetManager;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Response data for changeset merge command (JSON output).
+#[derive(Debug, Serialize)]
+struct ChangesetMergeResponse {
+    /// Branch names of the merged changesets.
+    merged: Vec<String>,
+    /// The merged changeset.
+    changeset: ChangesetInfo,
+}
+
+/// Execute the changeset merge command.
+///
+/// Merges changesets into one and outputs the merged changeset.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments with the changesets to merge and the target branch
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Optional workspace root directory (defaults to current directory)
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not initialized (no configuration found)
+/// - Fewer than two distinct changesets are given
+/// - A changeset does not exist
+/// - The target branch names an existing changeset that is not merged
+/// - File system operations fail
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_cli_tools::commands::changeset::execute_merge;
+/// use sublime_cli_tools::cli::commands::ChangesetMergeArgs;
+/// use sublime_cli_tools::output::{Output, OutputFormat};
+/// use std::io;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let args = ChangesetMergeArgs {
+///     branches: vec!["fix/login".to_string(), "feature/sso".to_string()],
+///     into: "feature/sso".to_string(),
+/// };
+///
+/// let output = Output::new(OutputFormat::Human, io::stdout(), false);
+/// execute_merge(&args, &output, None, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn execute_merge(
+    args: &ChangesetMergeArgs,
+    output: &Output,
+    root: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let workspace_root = root.unwrap_or_else(|| Path::new("."));
+    debug!(
+        "Merging changesets {:?} into '{}' in workspace: {}",
+        args.branches,
+        args.into,
+        workspace_root.display()
+    );
+
+    // Load configuration
+    let config = load_config(workspace_root, config_path).await?;
+
+    info!("Configuration loaded successfully");
+
+    // Create changeset manager
+    let manager =
+        ChangesetManager::new(workspace_root.to_path_buf(), FileSystemManager::new(), config)
+            .await
+            .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
+
+    let merged = manager
+        .merge(&args.branches, &args.into)
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!("Changesets {:?} merged into '{}'", args.branches, merged.branch);
+
+    // Output results
+    if output.format().is_json() {
+        let response =
+            ChangesetMergeResponse { merged: args.branches.clone(), changeset: merged.into() };
+        output.json(&JsonResponse::success(response))?;
+    } else {
+        output.success(&format!("Merged {} into '{}'", args.branches.join(", "), merged.branch))?;
+        output
+            .blank_line()
+            .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+        print_changeset(output, &merged)?;
+    }
+
+    Ok(())
+}
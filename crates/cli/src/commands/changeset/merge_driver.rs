@@ -0,0 +1,121 @@
+//! Changeset merge driver command implementation.
+//!
+//! This module implements the `changeset merge-driver` command, a git merge driver for
+//! changeset files.
+//!
+//! # What
+//!
+//! Provides the `execute_merge_driver` function that:
+//! - Reads the ancestor, current, and other versions of a changeset file
+//! - Merges the two edits field by field with `merge_changeset_files`
+//! - Writes the merged changeset in canonical form over the current version
+//! - Fails without touching the file when both sides changed a field differently
+//!
+//! # How
+//!
+//! Git runs the driver with the `%O %A %B` placeholders once it is registered:
+//!
+//! ```text
+//! git config merge.workspace-changeset.driver "workspace changeset merge-driver %O %A %B"
+//! echo ".changesets/*.json merge=workspace-changeset" >> .gitattributes
+//! ```
+//!
+//! A non-zero exit leaves the file conflicted for manual resolution.
+//!
+//! # Why
+//!
+//! Branches that add packages or commits to the same changeset otherwise conflict on
+//! neighbouring lines even though the edits combine cleanly.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_merge_driver;
+//! use sublime_cli_tools::cli::commands::ChangesetMergeDriverArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//! use std::path::PathBuf;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetMergeDriverArgs {
+//!     base: PathBuf::from(".merge_file_base"),
+//!     ours: PathBuf::from(".merge_file_ours"),
+//!     theirs: PathBuf::from(".merge_file_theirs"),
+//! };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_merge_driver(&args, &output).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cli::commands::ChangesetMergeDriverArgs;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::changeset::merge_changeset_files;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+use tracing::debug;
+
+/// Response data for changeset merge-driver command (JSON output).
+#[derive(Debug, Serialize)]
+struct ChangesetMergeDriverResponse {
+    /// Path of the merged file.
+    path: String,
+}
+
+/// Execute the changeset merge-driver command.
+///
+/// Merges the current and other versions of a changeset file against their ancestor and
+/// writes the result over the current version.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments with the paths git passes to the driver
+/// * `output` - Output handler for formatting and displaying results
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - A version cannot be read or is not a changeset
+/// - Both sides changed the same field differently
+/// - The merged file cannot be written
+pub async fn execute_merge_driver(args: &ChangesetMergeDriverArgs, output: &Output) -> Result<()> {
+    debug!(
+        "Merging changeset file {} with {} (ancestor {})",
+        args.ours.display(),
+        args.theirs.display(),
+        args.base.display()
+    );
+
+    let fs = FileSystemManager::new();
+    // Git passes an empty ancestor when both sides added the file
+    let base =
+        if fs.exists(&args.base).await { read(&fs, &args.base).await? } else { String::new() };
+    let ours = read(&fs, &args.ours).await?;
+    let theirs = read(&fs, &args.theirs).await?;
+
+    let merged = merge_changeset_files(&base, &ours, &theirs)
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    fs.write_file_string(&args.ours, &merged).await.map_err(|e| {
+        CliError::io(format!("Failed to write merged changeset {}: {e}", args.ours.display()))
+    })?;
+
+    if output.format().is_json() {
+        let response = ChangesetMergeDriverResponse { path: args.ours.display().to_string() };
+        output.json(&JsonResponse::success(response))?;
+    } else {
+        output.success(&format!("Merged changeset edits into {}", args.ours.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Reads one version of the changeset file.
+async fn read(fs: &FileSystemManager, path: &Path) -> Result<String> {
+    fs.read_file_string(path)
+        .await
+        .map_err(|e| CliError::io(format!("Failed to read {}: {e}", path.display())))
+}
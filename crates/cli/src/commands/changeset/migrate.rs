@@ -0,0 +1,188 @@
+//! Changeset migrate command implementation.
+//!
+//! This module implements the `changeset migrate` command for copying changesets to
+//! another storage backend or location.
+//!
+//! # What
+//!
+//! Provides the `execute_migrate` function that:
+//! - Copies every pending and archived changeset from the configured storage
+//! - Verifies each copy by reading it back from the target
+//! - Skips changesets the target already holds, so interrupted runs can be resumed
+//! - Formats output in human-readable or JSON format
+//!
+//! # How
+//!
+//! The command flow:
+//! 1. Loads workspace configuration and validates initialization
+//! 2. Resolves the target backend by name through `ChangesetBackendRegistry`
+//! 3. Refuses targets that share a directory with the configured storage, comparing
+//!    normalized paths
+//! 4. Copies the changesets with `migrate_changesets`
+//! 5. Outputs what was copied and skipped
+//!
+//! # Why
+//!
+//! Moving changesets by hand risks losing release history or copying a half-written
+//! directory. The migration leaves the source untouched until the user switches the
+//! configuration over.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_migrate;
+//! use sublime_cli_tools::cli::commands::ChangesetMigrateArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetMigrateArgs {
+//!     to: "file".to_string(),
+//!     path: "changes".to_string(),
+//!     history_path: None,
+//! };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_migrate(&args, &output, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::common::load_config;
+use crate::cli::commands::ChangesetMigrateArgs;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::changeset::{
+    ChangesetBackendRegistry, ChangesetMigrationReport, ChangesetStorageLocation, FILE_BACKEND,
+    migrate_changesets,
+};
+use tracing::{debug, info};
+
+/// Response data for changeset migrate command (JSON output).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangesetMigrateResponse {
+    /// Backend the changesets were copied to.
+    backend: String,
+    /// Directory the pending changesets were copied to.
+    path: String,
+    /// Directory the archived changesets were copied to.
+    history_path: String,
+    /// What was copied and skipped.
+    #[serde(flatten)]
+    report: ChangesetMigrationReport,
+}
+
+/// Execute the changeset migrate command.
+///
+/// Copies all changesets to the target location and outputs a summary.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments with the target backend and directories
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Optional workspace root directory (defaults to current directory)
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not initialized (no configuration found)
+/// - The target backend is not registered
+/// - The target is the configured storage location
+/// - The target holds a different changeset under the same branch or id
+/// - A copied changeset does not read back unchanged
+/// - File system operations fail
+pub async fn execute_migrate(
+    args: &ChangesetMigrateArgs,
+    output: &Output,
+    root: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let workspace_root = root.unwrap_or_else(|| Path::new("."));
+    let history_path =
+        args.history_path.clone().unwrap_or_else(|| format!("{}/history", args.path));
+    debug!(
+        "Migrating changesets to {} backend at '{}' (history '{}') in workspace: {}",
+        args.to,
+        args.path,
+        history_path,
+        workspace_root.display()
+    );
+
+    // Load configuration
+    let config = load_config(workspace_root, config_path).await?;
+
+    info!("Configuration loaded successfully");
+
+    // The configured storage is always file-based
+    let source_location = ChangesetStorageLocation::new(
+        workspace_root.to_path_buf(),
+        config.changeset.path.clone(),
+        config.changeset.history_path.clone(),
+    );
+    let target_location = ChangesetStorageLocation::new(
+        workspace_root.to_path_buf(),
+        args.path.clone(),
+        history_path.clone(),
+    );
+    if args.to == FILE_BACKEND && source_location.overlaps(&target_location) {
+        return Err(CliError::validation(format!(
+            "The target must differ from the configured storage ('{}', history '{}')",
+            source_location.path, source_location.history_path
+        )));
+    }
+
+    let registry = ChangesetBackendRegistry::default();
+    let source = registry
+        .open(FILE_BACKEND, &source_location)
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+    let target =
+        registry.open(&args.to, &target_location).map_err(sublime_pkg_tools::error::Error::from)?;
+
+    let report = migrate_changesets(source.as_ref(), target.as_ref())
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!(
+        "Migrated {} changesets to {} backend at '{}' ({} already present)",
+        report.copied_count(),
+        args.to,
+        args.path,
+        report.skipped_count()
+    );
+
+    // Output results
+    if output.format().is_json() {
+        let response = ChangesetMigrateResponse {
+            backend: args.to.clone(),
+            path: args.path.clone(),
+            history_path,
+            report,
+        };
+        output.json(&JsonResponse::success(response))?;
+    } else {
+        output.success(&format!(
+            "Copied {} pending and {} archived changesets from '{}' to '{}'",
+            report.pending_copied.len(),
+            report.archived_copied.len(),
+            source_location.path,
+            args.path
+        ))?;
+        if report.skipped_count() > 0 {
+            output.info(&format!(
+                "Skipped {} changesets already present in the target",
+                report.skipped_count()
+            ))?;
+        }
+        output.info(&format!(
+            "Set changeset.path = \"{}\" and changeset.history_path = \"{}\" to use the new \
+             location",
+            args.path, history_path
+        ))?;
+    }
+
+    Ok(())
+}
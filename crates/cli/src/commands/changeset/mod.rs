@@ -12,6 +12,12 @@
 //! - `delete` - Remove a changeset
 //! - `history` - Query archived changesets
 //! - `check` - Verify if a changeset exists for a branch
+//! - `split` - Move packages of a changeset into a new changeset
+//! - `merge` - Combine several changesets into one
+//! - `promote` - Promote a changeset into the next environment
+//! - `migrate` - Copy changesets to another storage location
+//! - `clean` - Rename or remove packages that are no longer in the workspace
+//! - `merge-driver` - Resolve git merges of changeset files
 //!
 //! # How
 //!
@@ -49,6 +55,10 @@
 //!     message: Some("Add new feature".to_string()),
 //!     packages: None,
 //!     non_interactive: true,
+//!     stdin: false,
+//!     from_file: None,
+//!     from_diff: false,
+//!     base: None,
 //! };
 //!
 //! let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -59,11 +69,17 @@
 
 pub mod add;
 pub mod check;
+pub mod clean;
 pub mod edit;
 pub mod history;
 pub mod list;
+pub mod merge;
+pub mod merge_driver;
+pub mod migrate;
+pub mod promote;
 pub mod remove;
 pub mod show;
+pub mod split;
 pub mod update;
 
 // Internal modules for shared functionality
@@ -76,9 +92,15 @@ mod tests;
 // Re-export command functions for convenience
 pub use add::execute_add;
 pub use check::execute_check;
+pub use clean::execute_clean;
 pub use edit::execute_edit;
 pub use history::execute_history;
 pub use list::execute_list;
+pub use merge::execute_merge;
+pub use merge_driver::execute_merge_driver;
+pub use migrate::execute_migrate;
+pub use promote::execute_promote;
 pub use remove::execute_remove;
 pub use show::execute_show;
+pub use split::execute_split;
 pub use update::execute_update;
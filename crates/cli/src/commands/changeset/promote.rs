@@ -0,0 +1,318 @@
+//! Changeset promote command implementation.
+//!
+//! This module implements the `changeset promote` command for recording that a changeset
+//! reached a deployment environment.
+//!
+//! # What
+//!
+//! Provides the `execute_promote` function that:
+//! - Finds the changeset among the pending ones or in history
+//! - Checks the promotion against the configured promotion order
+//! - Optionally re-releases the changeset's released versions for the environment, by
+//!   creating environment tags at the release commit or moving npm dist-tags
+//! - Records the promotion in the changeset file
+//! - Formats output in human-readable or JSON format
+//!
+//! # How
+//!
+//! The command flow:
+//! 1. Loads workspace configuration and validates initialization
+//! 2. Plans the promotion with `ChangesetManager::plan_promotion`
+//! 3. With `--retag`, creates `<env>/<release tag>` for each released version and, with
+//!    `--push`, pushes the tags to `origin`
+//! 4. With `--dist-tag`, runs `npm dist-tag add <package>@<version> <env>` for each version
+//! 5. Records the promotion with `ChangesetManager::apply_promotion`
+//!
+//! The promotion is recorded last, so a failed re-release can be retried: environment tags
+//! an earlier attempt left at the release commit are kept, and npm dist-tags are moved again.
+//!
+//! # Why
+//!
+//! Teams that release to staging before production need to know, and enforce, which
+//! environments a release has reached. Keeping that in the changeset keeps it in the
+//! repository next to the release it describes.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_promote;
+//! use sublime_cli_tools::cli::commands::ChangesetPromoteArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetPromoteArgs {
+//!     reference: "brave-lions-smile".to_string(),
+//!     to: "production".to_string(),
+//!     retag: true,
+//!     dist_tag: false,
+//!     push: true,
+//!     dry_run: false,
+//! };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_promote(&args, &output, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::common::load_config;
+use super::split::print_changeset;
+use super::types::ChangesetInfo;
+use crate::cli::commands::ChangesetPromoteArgs;
+use crate::commands::bump::git_integration::push_tags_to_remote;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use sublime_git_tools::Repo;
+use sublime_pkg_tools::changeset::{ChangesetManager, ChangesetPromotion};
+use sublime_pkg_tools::config::ChangelogConfig;
+use sublime_pkg_tools::types::EnvironmentPromotion;
+use sublime_standard_tools::command::{CommandBuilder, DefaultCommandExecutor, Executor};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Response data for changeset promote command (JSON output).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangesetPromoteResponse {
+    /// Whether the promotion was only checked.
+    dry_run: bool,
+    /// Environment the changeset was promoted into.
+    environment: String,
+    /// Environment the changeset reached before, if one precedes it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous: Option<String>,
+    /// Whether the changeset was already released.
+    archived: bool,
+    /// The promoted changeset.
+    changeset: ChangesetInfo,
+    /// Environments the changeset has been promoted into.
+    promotions: Vec<EnvironmentPromotion>,
+    /// Environment tags at the release commit.
+    tags: Vec<String>,
+    /// Package versions the environment dist-tag now points at.
+    dist_tags: Vec<String>,
+}
+
+/// Execute the changeset promote command.
+///
+/// Promotes a pending or released changeset into an environment, optionally re-releasing
+/// its versions for that environment, and outputs the result.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments with the changeset, the environment, and re-release options
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Optional workspace root directory (defaults to current directory)
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not initialized (no configuration found)
+/// - The changeset does not exist
+/// - The promotion breaks the configured promotion order
+/// - `--retag` or `--dist-tag` is used on a changeset that was not released
+/// - A tag cannot be created or pushed, or `npm dist-tag` fails
+/// - File system operations fail
+pub async fn execute_promote(
+    args: &ChangesetPromoteArgs,
+    output: &Output,
+    root: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let workspace_root = root.unwrap_or_else(|| Path::new("."));
+    debug!(
+        "Promoting changeset '{}' to '{}' in workspace: {}",
+        args.reference,
+        args.to,
+        workspace_root.display()
+    );
+
+    // Load configuration
+    let config = load_config(workspace_root, config_path).await?;
+    let changelog = config.changelog.clone();
+
+    info!("Configuration loaded successfully");
+
+    // Create changeset manager
+    let manager =
+        ChangesetManager::new(workspace_root.to_path_buf(), FileSystemManager::new(), config)
+            .await
+            .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
+
+    let promotion = manager
+        .plan_promotion(&args.reference, &args.to)
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    if (args.retag || args.dist_tag) && !promotion.is_archived() {
+        return Err(CliError::validation(format!(
+            "Changeset '{}' has not been released, so there is nothing to re-release for '{}'",
+            args.reference, args.to
+        )));
+    }
+
+    if args.dry_run {
+        let changeset = promotion.changeset.clone();
+        return output_promotion(output, &promotion, changeset, Vec::new(), Vec::new(), true);
+    }
+
+    let tags = if args.retag {
+        create_environment_tags(workspace_root, &promotion, &changelog, args.push)?
+    } else {
+        Vec::new()
+    };
+    let dist_tags =
+        if args.dist_tag { move_dist_tags(workspace_root, &promotion).await? } else { Vec::new() };
+
+    let changeset =
+        manager.apply_promotion(&promotion).await.map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!("Changeset '{}' promoted to '{}'", changeset.branch, promotion.environment);
+
+    output_promotion(output, &promotion, changeset, tags, dist_tags, false)
+}
+
+/// Tags the released versions of a promotion for its environment.
+///
+/// Each version gets `<env>/<release tag>` at the release commit, so the commit deployed
+/// to an environment can be found from its tags. Release tags are named as bump named them,
+/// from the number of packages in the whole release. Tags left at the release commit by an
+/// earlier, failed promotion are kept, so the promotion can be retried.
+fn create_environment_tags(
+    workspace_root: &Path,
+    promotion: &ChangesetPromotion,
+    changelog: &ChangelogConfig,
+    push: bool,
+) -> Result<Vec<String>> {
+    let Some(release) = &promotion.release else {
+        return Ok(Vec::new());
+    };
+    let repo =
+        Repo::open(workspace_root.to_str().ok_or_else(|| {
+            CliError::execution("Workspace path contains invalid UTF-8".to_string())
+        })?)
+        .map_err(|e| CliError::git(format!("Failed to open Git repository: {e}")))?;
+    let existing: HashMap<String, String> = repo
+        .get_remote_or_local_tags(Some(true))
+        .map_err(|e| CliError::git(format!("Failed to read tags: {e}")))?
+        .into_iter()
+        .map(|tag| (tag.tag, tag.hash))
+        .collect();
+
+    let single_package = release.versions.len() == 1;
+    let versions = promotion.released_versions();
+    let mut tags = Vec::with_capacity(versions.len());
+    for (package, version) in &versions {
+        let release_tag = changelog.package_release_tag(package, version, single_package);
+        let tag = format!("{}/{release_tag}", promotion.environment);
+
+        match existing.get(&tag) {
+            Some(commit)
+                if !release.git_commit.is_empty() && commit.starts_with(&release.git_commit) =>
+            {
+                debug!("Tag {} already points at the release commit", tag);
+            }
+            Some(commit) => {
+                return Err(CliError::git(format!(
+                    "Tag '{tag}' already exists at {commit}, not at release commit {}",
+                    release.git_commit
+                )));
+            }
+            None => {
+                debug!("Creating tag: {}", tag);
+                let message =
+                    format!("Promote {package} version {version} to {}", promotion.environment);
+                repo.create_tag_at(&tag, &release.git_commit, Some(message))
+                    .map_err(|e| CliError::git(format!("Failed to create tag '{tag}': {e}")))?;
+            }
+        }
+        tags.push(tag);
+    }
+
+    if push {
+        push_tags_to_remote(&repo)?;
+    }
+
+    Ok(tags)
+}
+
+/// Points the npm dist-tag named after the environment at the released versions.
+async fn move_dist_tags(
+    workspace_root: &Path,
+    promotion: &ChangesetPromotion,
+) -> Result<Vec<String>> {
+    let executor = DefaultCommandExecutor::new();
+    let mut moved = Vec::new();
+    for (package, version) in promotion.released_versions() {
+        let spec = format!("{package}@{version}");
+        debug!("Moving dist-tag '{}' to {}", promotion.environment, spec);
+
+        let command = CommandBuilder::new("npm")
+            .arg("dist-tag")
+            .arg("add")
+            .arg(&spec)
+            .arg(&promotion.environment)
+            .current_dir(workspace_root)
+            .build();
+        executor.execute(command).await.map_err(|e| {
+            CliError::execution(format!(
+                "Failed to move dist-tag '{}' to {spec}: {e}",
+                promotion.environment
+            ))
+        })?;
+        moved.push(spec);
+    }
+    Ok(moved)
+}
+
+/// Outputs the result of a promotion.
+fn output_promotion(
+    output: &Output,
+    promotion: &ChangesetPromotion,
+    changeset: sublime_pkg_tools::types::Changeset,
+    tags: Vec<String>,
+    dist_tags: Vec<String>,
+    dry_run: bool,
+) -> Result<()> {
+    if output.format().is_json() {
+        let response = ChangesetPromoteResponse {
+            dry_run,
+            environment: promotion.environment.clone(),
+            previous: promotion.previous.clone(),
+            archived: promotion.is_archived(),
+            promotions: changeset.promotions.clone(),
+            changeset: changeset.into(),
+            tags,
+            dist_tags,
+        };
+        output.json(&JsonResponse::success(response))?;
+        return Ok(());
+    }
+
+    if dry_run {
+        output.info(&format!(
+            "Changeset '{}' can be promoted to '{}'",
+            changeset.branch, promotion.environment
+        ))?;
+    } else {
+        output.success(&format!(
+            "Promoted changeset '{}' to '{}'",
+            changeset.branch, promotion.environment
+        ))?;
+    }
+    for tag in &tags {
+        output.info(&format!("Tagged {tag}"))?;
+    }
+    for spec in &dist_tags {
+        output.info(&format!("Dist-tag '{}' now points at {spec}", promotion.environment))?;
+    }
+    output.blank_line().map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+    print_changeset(output, &changeset)?;
+
+    Ok(())
+}
@@ -77,6 +77,8 @@ use sublime_pkg_tools::types::{Changeset, ReleaseInfo};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
+use super::common::refresh_unreleased_changelogs;
+
 /// Response data for changeset remove command (JSON output).
 ///
 /// Contains information about the removed changeset.
@@ -187,10 +189,13 @@ pub async fn execute_remove(
     info!("Configuration loaded successfully");
 
     // Create changeset manager
-    let manager =
-        ChangesetManager::new(workspace_root.to_path_buf(), FileSystemManager::new(), config)
-            .await
-            .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
+    let manager = ChangesetManager::new(
+        workspace_root.to_path_buf(),
+        FileSystemManager::new(),
+        config.clone(),
+    )
+    .await
+    .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
 
     // Check if changeset exists by trying to load it
     // The ChangesetManager doesn't have an exists() method, so we try to load
@@ -265,6 +270,7 @@ pub async fn execute_remove(
 
     // Output results
     output_results(output, &args.branch, &changeset, true)?;
+    refresh_unreleased_changelogs(workspace_root, &config, output).await?;
 
     Ok(())
 }
@@ -7,7 +7,7 @@
 //!
 //! Provides the `execute_show` function that:
 //! - Displays complete details of a specific changeset
-//! - Loads changeset by branch name or ID
+//! - Loads changeset by branch name, id, or generated name
 //! - Shows all metadata including packages, environments, commits, and timestamps
 //! - Formats output in human-readable or JSON format
 //! - Handles not-found errors gracefully
@@ -17,7 +17,7 @@
 //! The command flow:
 //! 1. Loads workspace configuration and validates initialization
 //! 2. Creates ChangesetManager to access changeset storage
-//! 3. Loads the specific changeset using the branch name
+//! 3. Resolves the changeset by branch name, then by id or name
 //! 4. Formats and displays all changeset details with sections and styling
 //! 5. Outputs in table/formatted view or JSON based on user preference
 //!
@@ -62,7 +62,9 @@ use serde::Serialize;
 use std::path::Path;
 use sublime_pkg_tools::changeset::ChangesetManager;
 use sublime_pkg_tools::config::{ConfigLoader, PackageToolsConfig};
-use sublime_pkg_tools::types::{Changeset, VersionBump};
+use sublime_pkg_tools::types::{
+    Changeset, ChangesetAuthor, ChangesetOperation, ChangesetRevision, VersionBump,
+};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info};
 
@@ -80,7 +82,12 @@ struct ChangesetShowResponse {
 /// Detailed changeset information for show output.
 #[derive(Debug, Serialize)]
 struct ChangesetShowItem {
-    /// Branch name (also serves as unique identifier).
+    /// Unique changeset id.
+    id: String,
+    /// Human-friendly changeset name, if one was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Branch name.
     branch: String,
     /// Version bump type.
     bump: String,
@@ -94,11 +101,25 @@ struct ChangesetShowItem {
     created_at: String,
     /// Last update timestamp (RFC3339 format).
     updated_at: String,
+    /// Who created the changeset, when author tracking is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<ChangesetAuthor>,
+    /// Branch checked out when the changeset was created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_on_branch: Option<String>,
+    /// Recorded modifications, oldest first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    revisions: Vec<ChangesetRevision>,
+    /// Split and merge operations that produced the changeset, oldest first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    operations: Vec<ChangesetOperation>,
 }
 
 impl From<Changeset> for ChangesetShowItem {
     fn from(changeset: Changeset) -> Self {
         Self {
+            id: changeset.id,
+            name: changeset.name,
             branch: changeset.branch,
             bump: changeset.bump.to_string().to_lowercase(),
             packages: changeset.packages,
@@ -106,18 +127,23 @@ impl From<Changeset> for ChangesetShowItem {
             commits: changeset.changes,
             created_at: changeset.created_at.to_rfc3339(),
             updated_at: changeset.updated_at.to_rfc3339(),
+            author: changeset.author,
+            created_on_branch: changeset.created_on_branch,
+            revisions: changeset.revisions,
+            operations: changeset.operations,
         }
     }
 }
 
 /// Execute the changeset show command.
 ///
-/// Displays detailed information for a specific changeset identified by branch name.
+/// Displays detailed information for a specific changeset identified by branch name,
+/// id, or generated name.
 /// Outputs results in either human-readable formatted view or JSON format.
 ///
 /// # Arguments
 ///
-/// * `args` - Command arguments including the branch name to display
+/// * `args` - Command arguments including the changeset to display
 /// * `output` - Output handler for formatting and displaying results
 /// * `root` - Optional workspace root directory (defaults to current directory)
 /// * `config_path` - Optional path to config file (from global `--config` option)
@@ -172,9 +198,9 @@ pub async fn execute_show(
             .await
             .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
 
-    // Load the specific changeset
+    // Resolve the changeset by branch, id, or name
     let changeset = manager
-        .load(&args.branch)
+        .resolve(&args.branch)
         .await
         .map_err(|e| CliError::Execution(format!("Changeset '{}' not found: {e}", args.branch)))?;
 
@@ -267,6 +293,27 @@ fn output_json(output: &Output, changeset: Changeset) -> Result<()> {
     Ok(())
 }
 
+/// Prints the id, name, branch, bump, timestamps, and author of a changeset.
+fn print_basic_information(changeset: &Changeset) {
+    let section = Section::new("Basic Information");
+    section.print();
+
+    print_item("Id", &changeset.id, true);
+    if let Some(name) = &changeset.name {
+        print_item("Name", name, true);
+    }
+    print_item("Branch", &changeset.branch, true);
+    print_item("Bump Type", &format_bump_type(changeset.bump), true);
+    print_item("Created", &changeset.created_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(), true);
+    print_item("Updated", &changeset.updated_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(), true);
+    if let Some(author) = &changeset.author {
+        print_item("Author", &format!("{author} (via {})", author.source), true);
+    }
+    if let Some(branch) = &changeset.created_on_branch {
+        print_item("Created On", branch, true);
+    }
+}
+
 /// Output changeset in human-readable formatted view.
 fn output_formatted(output: &Output, changeset: &Changeset) -> Result<()> {
     // Header section
@@ -276,13 +323,7 @@ fn output_formatted(output: &Output, changeset: &Changeset) -> Result<()> {
     output.blank_line().map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
 
     // Basic information
-    let section = Section::new("Basic Information");
-    section.print();
-
-    print_item("Branch", &changeset.branch, true);
-    print_item("Bump Type", &format_bump_type(changeset.bump), true);
-    print_item("Created", &changeset.created_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(), true);
-    print_item("Updated", &changeset.updated_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(), true);
+    print_basic_information(changeset);
 
     output.blank_line().map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
 
@@ -341,6 +382,54 @@ fn output_formatted(output: &Output, changeset: &Changeset) -> Result<()> {
 
     output.blank_line().map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
 
+    // Revision history
+    if !changeset.revisions.is_empty() {
+        let section = Section::new("History");
+        section.print();
+
+        for revision in &changeset.revisions {
+            let who =
+                revision.author.as_ref().map_or_else(|| "unknown".to_string(), ToString::to_string);
+            print_item(
+                &revision.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                &format!("{who}: {}", revision.changes.join("; ")),
+                false,
+            );
+        }
+
+        output
+            .blank_line()
+            .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+    }
+
+    // Split and merge operations
+    if !changeset.operations.is_empty() {
+        let section = Section::new("Operations");
+        section.print();
+
+        for operation in &changeset.operations {
+            let who = operation
+                .author
+                .as_ref()
+                .map_or_else(|| "unknown".to_string(), ToString::to_string);
+            print_item(
+                &operation.timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+                &format!(
+                    "{who}: {} {} -> {} ({})",
+                    operation.kind,
+                    operation.from.join(", "),
+                    operation.into.join(", "),
+                    operation.packages.join(", ")
+                ),
+                false,
+            );
+        }
+
+        output
+            .blank_line()
+            .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+    }
+
     Ok(())
 }
 
@@ -0,0 +1,182 @@
+//! Changeset split command implementation.
+//!
+//! This module implements the `changeset split` command for moving packages of a
+//! changeset into a new changeset.
+//!
+//! # What
+//!
+//! Provides the `execute_split` function that:
+//! - Moves the given packages out of a pending changeset
+//! - Creates a new changeset for them with the same bump, environments, and commits
+//! - Records the split in the operation history of both changesets
+//! - Formats output in human-readable or JSON format
+//!
+//! # How
+//!
+//! The command flow:
+//! 1. Loads workspace configuration and validates initialization
+//! 2. Creates ChangesetManager to access changeset storage
+//! 3. Splits the changeset with `ChangesetManager::split`
+//! 4. Outputs both resulting changesets
+//!
+//! # Why
+//!
+//! Splitting lets a team release part of a changeset on its own schedule, or give
+//! packages that were grouped by accident a bump of their own, without recreating
+//! changesets by hand.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::changeset::execute_split;
+//! use sublime_cli_tools::cli::commands::ChangesetSplitArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let args = ChangesetSplitArgs {
+//!     branch: "feature/auth".to_string(),
+//!     packages: vec!["@myorg/docs".to_string()],
+//!     into: "feature/auth-docs".to_string(),
+//! };
+//!
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_split(&args, &output, None, None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::common::load_config;
+use super::types::ChangesetInfo;
+use crate::cli::commands::ChangesetSplitArgs;
+use crate::error::{CliError, Result};
+use crate::output::styling::{Section, print_item};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::changeset::ChangesetManager;
+use sublime_pkg_tools::types::Changeset;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Response data for changeset split command (JSON output).
+#[derive(Debug, Serialize)]
+struct ChangesetSplitResponse {
+    /// The changeset that was split, with its remaining packages.
+    source: ChangesetInfo,
+    /// The new changeset holding the moved packages.
+    split: ChangesetInfo,
+}
+
+/// Execute the changeset split command.
+///
+/// Moves packages of a changeset into a new changeset and outputs both changesets.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments with the changeset, the packages to move, and the new branch
+/// * `output` - Output handler for formatting and displaying results
+/// * `root` - Optional workspace root directory (defaults to current directory)
+/// * `config_path` - Optional path to config file (from global `--config` option)
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The workspace is not initialized (no configuration found)
+/// - The changeset does not exist
+/// - A changeset already exists for the new branch
+/// - A package is not in the changeset, or no package would stay
+/// - File system operations fail
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_cli_tools::commands::changeset::execute_split;
+/// use sublime_cli_tools::cli::commands::ChangesetSplitArgs;
+/// use sublime_cli_tools::output::{Output, OutputFormat};
+/// use std::io;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let args = ChangesetSplitArgs {
+///     branch: "feature/auth".to_string(),
+///     packages: vec!["@myorg/docs".to_string()],
+///     into: "feature/auth-docs".to_string(),
+/// };
+///
+/// let output = Output::new(OutputFormat::Human, io::stdout(), false);
+/// execute_split(&args, &output, None, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn execute_split(
+    args: &ChangesetSplitArgs,
+    output: &Output,
+    root: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let workspace_root = root.unwrap_or_else(|| Path::new("."));
+    debug!(
+        "Splitting {:?} out of changeset '{}' into '{}' in workspace: {}",
+        args.packages,
+        args.branch,
+        args.into,
+        workspace_root.display()
+    );
+
+    // Load configuration
+    let config = load_config(workspace_root, config_path).await?;
+
+    info!("Configuration loaded successfully");
+
+    // Create changeset manager
+    let manager =
+        ChangesetManager::new(workspace_root.to_path_buf(), FileSystemManager::new(), config)
+            .await
+            .map_err(|e| CliError::Execution(format!("Failed to create changeset manager: {e}")))?;
+
+    let (source, split) = manager
+        .split(&args.branch, &args.packages, &args.into)
+        .await
+        .map_err(sublime_pkg_tools::error::Error::from)?;
+
+    info!("Changeset '{}' split into '{}'", source.branch, split.branch);
+
+    // Output results
+    if output.format().is_json() {
+        let response = ChangesetSplitResponse { source: source.into(), split: split.into() };
+        output.json(&JsonResponse::success(response))?;
+    } else {
+        output.success(&format!(
+            "Split {} package(s) out of '{}' into '{}'",
+            split.packages.len(),
+            source.branch,
+            split.branch
+        ))?;
+        output
+            .blank_line()
+            .map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+        print_changeset(output, &source)?;
+        print_changeset(output, &split)?;
+    }
+
+    Ok(())
+}
+
+/// Prints a short summary of a changeset produced by a split or merge.
+///
+/// # Errors
+///
+/// Returns an error if output operations fail.
+pub(crate) fn print_changeset(output: &Output, changeset: &Changeset) -> Result<()> {
+    let section = Section::new(format!("Changeset: {}", changeset.branch));
+    section.print();
+
+    print_item("Bump", &changeset.bump.to_string().to_lowercase(), false);
+    print_item("Packages", &changeset.packages.join(", "), false);
+    print_item("Environments", &changeset.environments.join(", "), false);
+    print_item("Commits", &format!("{} commit(s)", changeset.changes.len()), true);
+
+    output.blank_line().map_err(|e| CliError::Execution(format!("Failed to write output: {e}")))?;
+
+    Ok(())
+}
@@ -37,7 +37,7 @@
 mod tests {
     use crate::cli::commands::{ChangesetCreateArgs, ChangesetShowArgs};
     use crate::commands::changeset::common::{
-        get_changeset_file_path, parse_bump_type, validate_bump_type, validate_environments,
+        find_changeset_file_path, parse_bump_type, validate_bump_type, validate_environments,
     };
     use crate::commands::changeset::types::format_bump_type;
     use crate::error::CliError;
@@ -121,20 +121,39 @@ mod tests {
         assert_eq!(format_bump_type(VersionBump::None), "none");
     }
 
-    #[test]
-    fn test_get_changeset_file_path() {
-        use std::path::PathBuf;
+    #[tokio::test]
+    #[allow(clippy::expect_used)]
+    async fn test_find_changeset_file_path() {
+        use sublime_pkg_tools::changeset::{ChangesetStorage, FileBasedChangesetStorage};
         use sublime_pkg_tools::config::PackageToolsConfig;
-
-        let workspace_root = PathBuf::from("/tmp/workspace");
-        let mut config = PackageToolsConfig::default();
-        config.changeset.path = ".changesets".to_string();
-
-        let path = get_changeset_file_path(&workspace_root, &config, "feature/test");
-        assert_eq!(path, PathBuf::from("/tmp/workspace/.changesets/feature-test.json"));
-
-        let path = get_changeset_file_path(&workspace_root, &config, "main");
-        assert_eq!(path, PathBuf::from("/tmp/workspace/.changesets/main.json"));
+        use sublime_pkg_tools::types::{Changeset, VersionBump};
+        use sublime_standard_tools::filesystem::FileSystemManager;
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let config = PackageToolsConfig::default();
+        let storage = FileBasedChangesetStorage::new(
+            temp_dir.path().to_path_buf(),
+            config.changeset.path.clone(),
+            config.changeset.history_path.clone(),
+            FileSystemManager::new(),
+        );
+        let changeset = Changeset::new("feature/test", VersionBump::Minor, vec![]);
+        storage.save(&changeset).await.expect("Failed to save changeset");
+
+        let path = find_changeset_file_path(temp_dir.path(), &config, "feature/test")
+            .await
+            .expect("Failed to find changeset file");
+        assert_eq!(
+            path,
+            Some(
+                temp_dir.path().join(&config.changeset.path).join(format!("{}.json", changeset.id))
+            )
+        );
+
+        let path = find_changeset_file_path(temp_dir.path(), &config, "main")
+            .await
+            .expect("Failed to find changeset file");
+        assert_eq!(path, None);
     }
 
     // Integration tests would go here but require file system setup
@@ -182,6 +201,10 @@ mod tests {
             message: None,
             packages: None,
             non_interactive: false,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.bump.is_none());
@@ -212,6 +235,8 @@ mod tests {
         use sublime_pkg_tools::types::{Changeset, VersionBump};
 
         let changeset = Changeset {
+            id: "3f9a1c0b7d2e".to_string(),
+            name: None,
             branch: "feature/test".to_string(),
             bump: VersionBump::Minor,
             packages: vec!["pkg-a".to_string(), "pkg-b".to_string()],
@@ -219,6 +244,12 @@ mod tests {
             changes: vec!["abc123".to_string(), "def456".to_string(), "ghi789".to_string()],
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            author: None,
+            created_on_branch: None,
+            revisions: Vec::new(),
+            operations: Vec::new(),
+            association: None,
+            promotions: Vec::new(),
         };
 
         let info = RemovedChangesetInfo::from(&changeset);
@@ -266,6 +297,10 @@ mod tests {
             message: Some("Add new feature".to_string()),
             packages: Some(vec!["pkg-a".to_string(), "pkg-b".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert_eq!(args.bump.as_deref(), Some("minor"));
@@ -288,6 +323,10 @@ mod tests {
             message: Some("Test message".to_string()),
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         // All required fields are present
@@ -306,6 +345,10 @@ mod tests {
             message: Some("Test message".to_string()),
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.bump.is_none());
@@ -322,6 +365,10 @@ mod tests {
             message: Some("Test message".to_string()),
             packages: None,
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.packages.is_none());
@@ -338,6 +385,10 @@ mod tests {
             message: None,
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.env.is_none());
@@ -350,6 +401,8 @@ mod tests {
 
     use crate::cli::commands::ChangesetListArgs;
     use crate::commands::changeset::list::parse_bump_type as list_parse_bump_type;
+    use crate::commands::changeset::list::{build_query, parse_age, parse_sort_field};
+    use sublime_pkg_tools::changeset::ChangesetSortField;
 
     #[test]
     fn test_list_args_defaults() {
@@ -357,7 +410,13 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_none());
@@ -372,7 +431,13 @@ mod tests {
             filter_package: Some("my-package".to_string()),
             filter_bump: Some("major".to_string()),
             filter_env: Some("production".to_string()),
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "branch".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert_eq!(args.filter_package.as_deref(), Some("my-package"));
@@ -415,7 +480,13 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
         assert_eq!(sort_date.sort, "date");
 
@@ -423,7 +494,13 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "branch".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
         assert_eq!(sort_branch.sort, "branch");
 
@@ -431,11 +508,69 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "bump".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
         assert_eq!(sort_bump.sort, "bump");
     }
 
+    #[test]
+    fn test_list_parse_sort_field() {
+        assert!(matches!(parse_sort_field("date"), Ok(ChangesetSortField::UpdatedAt)));
+        assert!(matches!(parse_sort_field("created"), Ok(ChangesetSortField::CreatedAt)));
+        assert!(matches!(parse_sort_field("branch"), Ok(ChangesetSortField::Branch)));
+        assert!(matches!(parse_sort_field("bump"), Ok(ChangesetSortField::Bump)));
+        assert!(parse_sort_field("size").is_err());
+    }
+
+    #[test]
+    fn test_list_parse_age() {
+        assert_eq!(parse_age("30m").ok(), Some(chrono::Duration::minutes(30)));
+        assert_eq!(parse_age("12h").ok(), Some(chrono::Duration::hours(12)));
+        assert_eq!(parse_age("7d").ok(), Some(chrono::Duration::days(7)));
+        assert_eq!(parse_age("2w").ok(), Some(chrono::Duration::weeks(2)));
+        assert!(parse_age("").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("7y").is_err());
+        assert!(parse_age("seven days").is_err());
+    }
+
+    #[test]
+    #[allow(clippy::panic)]
+    fn test_list_build_query() {
+        let args = ChangesetListArgs {
+            filter_package: Some("core".to_string()),
+            filter_bump: Some("minor".to_string()),
+            filter_env: Some("staging".to_string()),
+            filter_author: Some("alice".to_string()),
+            newer_than: Some("7d".to_string()),
+            older_than: None,
+            sort: "bump".to_string(),
+            reverse: true,
+            limit: Some(5),
+            offset: 10,
+        };
+
+        let Ok(query) = build_query(&args) else {
+            panic!("Expected query to build");
+        };
+        assert_eq!(query.package.as_deref(), Some("core"));
+        assert_eq!(query.bump, Some(VersionBump::Minor));
+        assert_eq!(query.environment.as_deref(), Some("staging"));
+        assert_eq!(query.author.as_deref(), Some("alice"));
+        assert!(query.created_after.is_some());
+        assert!(query.created_before.is_none());
+        assert_eq!(query.sort, ChangesetSortField::Bump);
+        assert!(query.reverse);
+        assert_eq!(query.limit, Some(5));
+        assert_eq!(query.offset, 10);
+    }
+
     #[test]
     fn test_list_multiple_filters() {
         // Test combining multiple filters
@@ -443,7 +578,13 @@ mod tests {
             filter_package: Some("core".to_string()),
             filter_bump: Some("minor".to_string()),
             filter_env: Some("staging".to_string()),
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_some());
@@ -458,7 +599,13 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_none());
@@ -472,7 +619,13 @@ mod tests {
             filter_package: Some("my-package".to_string()),
             filter_bump: None,
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_some());
@@ -486,7 +639,13 @@ mod tests {
             filter_package: None,
             filter_bump: Some("major".to_string()),
             filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_none());
@@ -500,7 +659,13 @@ mod tests {
             filter_package: None,
             filter_bump: None,
             filter_env: Some("production".to_string()),
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
             sort: "date".to_string(),
+            reverse: false,
+            limit: None,
+            offset: 0,
         };
 
         assert!(args.filter_package.is_none());
@@ -518,6 +683,10 @@ mod tests {
             message: None, // Optional
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.message.is_none());
@@ -534,6 +703,10 @@ mod tests {
             message: Some("Multi-package change".to_string()),
             packages: Some(vec!["pkg-a".to_string(), "pkg-b".to_string(), "pkg-c".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert_eq!(args.packages.as_ref().map(Vec::len), Some(3));
@@ -550,6 +723,10 @@ mod tests {
             message: None,
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert_eq!(args.env.as_ref().map(Vec::len), Some(3));
@@ -566,6 +743,10 @@ mod tests {
             message: None,
             packages: None, // Will be prompted
             non_interactive: false,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(!args.non_interactive);
@@ -689,6 +870,10 @@ mod tests {
             message: Some("Custom branch".to_string()),
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert_eq!(args.branch.as_deref(), Some("custom/branch-name"));
@@ -704,6 +889,10 @@ mod tests {
             message: Some("Use current branch".to_string()),
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.branch.is_none());
@@ -719,6 +908,10 @@ mod tests {
             message: None,
             packages: Some(vec![]), // Empty list
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.packages.as_ref().is_some_and(Vec::is_empty));
@@ -734,6 +927,10 @@ mod tests {
             message: None,
             packages: Some(vec!["pkg-a".to_string()]),
             non_interactive: true,
+            stdin: false,
+            from_file: None,
+            from_diff: false,
+            base: None,
         };
 
         assert!(args.env.as_ref().is_some_and(Vec::is_empty));
@@ -1585,6 +1782,8 @@ mod tests {
         use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, ReleaseInfo, VersionBump};
 
         let changeset = Changeset {
+            id: "3f9a1c0b7d2e".to_string(),
+            name: None,
             branch: "feature/test".to_string(),
             bump: VersionBump::Minor,
             packages: vec!["pkg-a".to_string()],
@@ -1592,6 +1791,12 @@ mod tests {
             changes: vec!["abc123".to_string()],
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            author: None,
+            created_on_branch: None,
+            revisions: Vec::new(),
+            operations: Vec::new(),
+            association: None,
+            promotions: Vec::new(),
         };
 
         let mut versions = HashMap::new();
@@ -1602,6 +1807,8 @@ mod tests {
             applied_by: "ci-bot".to_string(),
             git_commit: "abc123def456".to_string(),
             versions,
+            skipped: vec![],
+            changelogs: HashMap::new(),
         };
 
         let archived = ArchivedChangeset { changeset, release_info };
@@ -1623,6 +1830,8 @@ mod tests {
         use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, ReleaseInfo, VersionBump};
 
         let changeset = Changeset {
+            id: "3f9a1c0b7d2e".to_string(),
+            name: None,
             branch: "hotfix/security".to_string(),
             bump: VersionBump::Patch,
             packages: vec!["pkg-b".to_string()],
@@ -1630,6 +1839,12 @@ mod tests {
             changes: vec!["def456".to_string()],
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            author: None,
+            created_on_branch: None,
+            revisions: Vec::new(),
+            operations: Vec::new(),
+            association: None,
+            promotions: Vec::new(),
         };
 
         let mut versions = HashMap::new();
@@ -1640,6 +1855,8 @@ mod tests {
             applied_by: "manual".to_string(),
             git_commit: "def456abc789".to_string(),
             versions,
+            skipped: vec![],
+            changelogs: HashMap::new(),
         };
 
         let archived = ArchivedChangeset { changeset, release_info };
@@ -1661,6 +1878,8 @@ mod tests {
         use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, ReleaseInfo, VersionBump};
 
         let changeset = Changeset {
+            id: "3f9a1c0b7d2e".to_string(),
+            name: None,
             branch: "feature/multi".to_string(),
             bump: VersionBump::Major,
             packages: vec!["pkg-a".to_string(), "pkg-b".to_string(), "pkg-c".to_string()],
@@ -1668,6 +1887,12 @@ mod tests {
             changes: vec!["abc".to_string(), "def".to_string(), "ghi".to_string()],
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            author: None,
+            created_on_branch: None,
+            revisions: Vec::new(),
+            operations: Vec::new(),
+            association: None,
+            promotions: Vec::new(),
         };
 
         let mut versions = HashMap::new();
@@ -1680,6 +1905,8 @@ mod tests {
             applied_by: "release-bot".to_string(),
             git_commit: "ghi789jkl012".to_string(),
             versions,
+            skipped: vec![],
+            changelogs: HashMap::new(),
         };
 
         let archived = ArchivedChangeset { changeset, release_info };
@@ -1805,6 +2032,8 @@ mod tests {
         if let (Some(created), Some(updated), Some(applied)) = (created_at, updated_at, applied_at)
         {
             let changeset = Changeset {
+                id: "3f9a1c0b7d2e".to_string(),
+                name: None,
                 branch: "test".to_string(),
                 bump: VersionBump::Minor,
                 packages: vec![],
@@ -1812,6 +2041,12 @@ mod tests {
                 changes: vec![],
                 created_at: created,
                 updated_at: updated,
+                author: None,
+                created_on_branch: None,
+                revisions: Vec::new(),
+                operations: Vec::new(),
+                association: None,
+                promotions: Vec::new(),
             };
 
             let mut versions = HashMap::new();
@@ -1822,6 +2057,8 @@ mod tests {
                 applied_by: "bot".to_string(),
                 git_commit: "abc123".to_string(),
                 versions,
+                skipped: vec![],
+                changelogs: HashMap::new(),
             };
 
             let archived = ArchivedChangeset { changeset, release_info };
@@ -2096,4 +2333,79 @@ mod tests {
         // 4. Verify appropriate error message
         // 5. Verify suggests using --packages flag
     }
+
+    // Tests for changeset documents (--stdin / --from-file)
+
+    #[test]
+    fn test_parse_changeset_document_json_and_yaml() {
+        use crate::commands::changeset::add::parse_changeset_document;
+
+        let json = r#"{"bump": "minor", "packages": ["@org/a"], "environments": ["staging"]}"#;
+        let document = parse_changeset_document(json, "stdin");
+        assert!(document.as_ref().is_ok_and(|d| d.bump == "minor"
+            && d.packages == vec!["@org/a".to_string()]
+            && d.environments == Some(vec!["staging".to_string()])
+            && d.branch.is_none()));
+
+        let yaml = "bump: patch\npackages:\n  - '@org/b'\nbranch: feature/x\nmessage: Fix it\n";
+        let document = parse_changeset_document(yaml, "stdin");
+        assert!(document.as_ref().is_ok_and(|d| d.bump == "patch"
+            && d.branch.as_deref() == Some("feature/x")
+            && d.message.as_deref() == Some("Fix it")));
+    }
+
+    #[test]
+    fn test_parse_changeset_document_rejects_invalid_documents() {
+        use crate::commands::changeset::add::parse_changeset_document;
+
+        let invalid = [
+            "",
+            "{not json",
+            r#"{"bump": "minor"}"#,
+            r#"{"bump": "huge", "packages": ["a"]}"#,
+            r#"{"bump": "minor", "packages": []}"#,
+            r#"{"bump": "minor", "packages": [" "]}"#,
+            r#"{"bump": "minor", "packages": ["a"], "branch": ""}"#,
+            r#"{"bump": "minor", "packages": ["a"], "pakages": ["b"]}"#,
+        ];
+        for content in invalid {
+            let result = parse_changeset_document(content, "stdin");
+            assert!(matches!(result, Err(CliError::Validation(_))), "accepted: {content}");
+        }
+    }
+
+    #[test]
+    #[allow(clippy::panic)]
+    fn test_merge_document_args_flags_take_precedence() {
+        use crate::commands::changeset::add::{merge_document_args, parse_changeset_document};
+
+        let document = parse_changeset_document(
+            "bump: patch\npackages: [a, b]\nenvironments: [staging]\nbranch: feature/doc\n",
+            "stdin",
+        );
+        let Ok(document) = document else {
+            panic!("document should parse");
+        };
+        let args = ChangesetCreateArgs {
+            bump: Some("major".to_string()),
+            env: None,
+            branch: None,
+            message: Some("From flag".to_string()),
+            packages: None,
+            non_interactive: false,
+            stdin: true,
+            from_file: None,
+            from_diff: false,
+            base: None,
+        };
+
+        let merged = merge_document_args(&args, document);
+        assert_eq!(merged.bump.as_deref(), Some("major"));
+        assert_eq!(merged.env, Some(vec!["staging".to_string()]));
+        assert_eq!(merged.branch.as_deref(), Some("feature/doc"));
+        assert_eq!(merged.message.as_deref(), Some("From flag"));
+        assert_eq!(merged.packages, Some(vec!["a".to_string(), "b".to_string()]));
+        assert!(merged.non_interactive);
+        assert!(!merged.stdin);
+    }
 }
@@ -49,7 +49,9 @@ use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, VersionBump};
 ///
 /// # Fields
 ///
-/// * `branch` - Branch name (also serves as unique identifier)
+/// * `id` - Unique changeset id
+/// * `name` - Human-friendly changeset name, if one was generated
+/// * `branch` - Branch name
 /// * `bump` - Version bump type as lowercase string (major, minor, patch, none)
 /// * `packages` - List of affected package names
 /// * `environments` - List of target environment names
@@ -64,6 +66,8 @@ use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, VersionBump};
 /// use serde_json;
 ///
 /// let info = ChangesetInfo {
+///     id: "3f9a1c0b7d2e".to_string(),
+///     name: Some("brave-lions-smile".to_string()),
 ///     branch: "feature/new-api".to_string(),
 ///     bump: "minor".to_string(),
 ///     packages: vec!["my-package".to_string()],
@@ -78,7 +82,12 @@ use sublime_pkg_tools::types::{ArchivedChangeset, Changeset, VersionBump};
 /// ```
 #[derive(Debug, Serialize, Clone)]
 pub(crate) struct ChangesetInfo {
-    /// Branch name (also serves as unique identifier).
+    /// Unique changeset id.
+    pub id: String,
+    /// Human-friendly changeset name, if one was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Branch name.
     pub branch: String,
     /// Version bump type (major, minor, patch, none).
     pub bump: String,
@@ -113,6 +122,8 @@ impl From<Changeset> for ChangesetInfo {
     /// ```
     fn from(changeset: Changeset) -> Self {
         Self {
+            id: changeset.id,
+            name: changeset.name,
             branch: changeset.branch,
             bump: format_bump_type(changeset.bump),
             packages: changeset.packages,
@@ -141,6 +152,8 @@ impl From<&Changeset> for ChangesetInfo {
     /// ```
     fn from(changeset: &Changeset) -> Self {
         Self {
+            id: changeset.id.clone(),
+            name: changeset.name.clone(),
             branch: changeset.branch.clone(),
             bump: format_bump_type(changeset.bump),
             packages: changeset.packages.clone(),
@@ -160,7 +173,9 @@ impl From<&Changeset> for ChangesetInfo {
 ///
 /// # Fields
 ///
-/// * `branch` - Branch name (also serves as unique identifier)
+/// * `id` - Unique changeset id
+/// * `name` - Human-friendly changeset name, if one was generated
+/// * `branch` - Branch name
 /// * `bump` - Version bump type as lowercase string (major, minor, patch, none)
 /// * `packages` - List of affected package names
 /// * `environments` - List of target environment names
@@ -183,6 +198,8 @@ impl From<&Changeset> for ChangesetInfo {
 /// versions.insert("my-package".to_string(), "1.2.0".to_string());
 ///
 /// let info = ArchivedChangesetInfo {
+///     id: "3f9a1c0b7d2e".to_string(),
+///     name: Some("brave-lions-smile".to_string()),
 ///     branch: "feature/new-api".to_string(),
 ///     bump: "minor".to_string(),
 ///     packages: vec!["my-package".to_string()],
@@ -201,7 +218,12 @@ impl From<&Changeset> for ChangesetInfo {
 /// ```
 #[derive(Debug, Serialize, Clone)]
 pub(crate) struct ArchivedChangesetInfo {
-    /// Branch name (also serves as unique identifier).
+    /// Unique changeset id.
+    pub id: String,
+    /// Human-friendly changeset name, if one was generated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Branch name.
     pub branch: String,
     /// Version bump type (major, minor, patch, none).
     pub bump: String,
@@ -245,6 +267,8 @@ impl From<ArchivedChangeset> for ArchivedChangesetInfo {
     /// ```
     fn from(archived: ArchivedChangeset) -> Self {
         Self {
+            id: archived.changeset.id,
+            name: archived.changeset.name,
             branch: archived.changeset.branch,
             bump: format_bump_type(archived.changeset.bump),
             packages: archived.changeset.packages,
@@ -277,6 +301,8 @@ impl From<&ArchivedChangeset> for ArchivedChangesetInfo {
     /// ```
     fn from(archived: &ArchivedChangeset) -> Self {
         Self {
+            id: archived.changeset.id.clone(),
+            name: archived.changeset.name.clone(),
             branch: archived.changeset.branch.clone(),
             bump: format_bump_type(archived.changeset.bump),
             packages: archived.changeset.packages.clone(),
@@ -75,6 +75,8 @@ use sublime_pkg_tools::types::{Changeset, VersionBump};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info};
 
+use super::common::refresh_unreleased_changelogs;
+
 /// Response data for changeset update command (JSON output).
 ///
 /// Contains information about the update operation.
@@ -337,6 +339,7 @@ pub async fn execute_update(
     let summary = UpdateSummary { packages_added, commits_added, bump_updated, environments_added };
 
     output_results(output, &changeset, &summary)?;
+    refresh_unreleased_changelogs(workspace_root, &config, output).await?;
 
     Ok(())
 }
@@ -980,6 +980,8 @@ pub(crate) fn convert_to_init_args(
         strategy,
         registry,
         config_format,
+        template: None,
+        eject_template: None,
         force: false, // Never force during clone
         non_interactive: args.non_interactive,
     }
@@ -996,7 +998,10 @@ fn output_init_starting(format: OutputFormat) {
         OutputFormat::Human => {
             println!("\n🔧 No workspace configuration found. Starting initialization...\n");
         }
-        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => {}
+        OutputFormat::Json
+        | OutputFormat::JsonCompact
+        | OutputFormat::Ndjson
+        | OutputFormat::Quiet => {}
     }
 }
 
@@ -1011,7 +1016,10 @@ fn output_validation_starting(format: OutputFormat) {
         OutputFormat::Human => {
             println!("\n🔍 Validating workspace configuration...\n");
         }
-        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => {}
+        OutputFormat::Json
+        | OutputFormat::JsonCompact
+        | OutputFormat::Ndjson
+        | OutputFormat::Quiet => {}
     }
 }
 
@@ -1043,7 +1051,10 @@ fn output_validation_success(validation: &ValidationResult, format: OutputFormat
             }
             println!();
         }
-        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => {}
+        OutputFormat::Json
+        | OutputFormat::JsonCompact
+        | OutputFormat::Ndjson
+        | OutputFormat::Quiet => {}
     }
 }
 /// Outputs clone completion message (without init).
@@ -1069,7 +1080,7 @@ fn output_clone_complete(destination: &Path, validated: bool, format: OutputForm
             println!("  workspace bump --dry-run   # Preview version bump\n");
             Ok(())
         }
-        OutputFormat::Json | OutputFormat::JsonCompact => {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
             #[derive(serde::Serialize)]
             #[allow(non_snake_case)]
             struct CloneResponse {
@@ -1095,7 +1106,7 @@ fn output_clone_complete(destination: &Path, validated: bool, format: OutputForm
                 },
             };
 
-            let json = if format == OutputFormat::JsonCompact {
+            let json = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
                 serde_json::to_string(&response)
             } else {
                 serde_json::to_string_pretty(&response)
@@ -1134,7 +1145,7 @@ fn output_clone_complete_with_init(destination: &Path, format: OutputFormat) ->
             println!("  workspace bump --dry-run   # Preview version bump\n");
             Ok(())
         }
-        OutputFormat::Json | OutputFormat::JsonCompact => {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
             #[derive(serde::Serialize)]
             #[allow(non_snake_case)]
             struct CloneResponse {
@@ -1155,7 +1166,7 @@ fn output_clone_complete_with_init(destination: &Path, format: OutputFormat) ->
                 outcome: CloneOutcome::NewWorkspaceInitialized,
             };
 
-            let json = if format == OutputFormat::JsonCompact {
+            let json = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
                 serde_json::to_string(&response)
             } else {
                 serde_json::to_string_pretty(&response)
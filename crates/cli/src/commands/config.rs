@@ -7,7 +7,7 @@
 //!
 //! Provides the config subcommands:
 //! - `show` - Display current configuration
-//! - `validate` - Validate configuration file (future story 2.3)
+//! - `validate` - Validate configuration file, or explain a key with `--explain`
 //!
 //! # How
 //!
@@ -37,7 +37,12 @@
 //! - Checks referenced directories exist or can be created
 //! - Validates environment names (no duplicates)
 //! - Validates registry URLs
+//! - Reports conflicting settings in the active profile (`--profile`)
 //! - Provides clear, actionable error messages
+//!
+//! Every rule violation is reported, not just the first: each issue shows the TOML path of
+//! the key, the received value, the allowed values and an excerpt of the key's `ConfigDoc`.
+//! `--explain <key>` prints that documentation on its own, without reading a config file.
 
 // Allow print to stdout for command output
 #![allow(clippy::print_stdout)]
@@ -49,7 +54,11 @@ use crate::output::{JsonResponse, OutputFormat};
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::Path;
-use sublime_pkg_tools::config::{ConfigLoader, PackageToolsConfig, validate_config};
+use sublime_pkg_tools::config::{
+    ConfigDoc, ConfigLoader, ConfigValidationIssue, PackageToolsConfig, active_profile,
+    validate_config_all,
+};
+use sublime_standard_tools::config::Configurable;
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use tracing::{debug, info, warn};
 
@@ -154,7 +163,9 @@ pub async fn execute_show(
     // Output based on format
     match format {
         OutputFormat::Human => output_human_format(&config, is_default),
-        OutputFormat::Json | OutputFormat::JsonCompact => output_json_format(&config, format)?,
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            output_json_format(&config, format)?;
+        }
         OutputFormat::Quiet => output_quiet_format(&config),
     }
 
@@ -192,6 +203,7 @@ pub async fn execute_show(
 /// - The configuration file doesn't exist
 /// - The configuration file cannot be parsed
 /// - Any validation check fails
+/// - `--explain` names a key that is not documented
 ///
 /// # Examples
 ///
@@ -202,17 +214,21 @@ pub async fn execute_show(
 /// use std::path::Path;
 ///
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-/// let args = ConfigValidateArgs {};
+/// let args = ConfigValidateArgs { explain: None };
 /// execute_validate(&args, Path::new("."), None, OutputFormat::Human).await?;
 /// # Ok(())
 /// # }
 /// ```
 pub async fn execute_validate(
-    _args: &ConfigValidateArgs,
+    args: &ConfigValidateArgs,
     root: &Path,
     config_path: Option<&Path>,
     format: OutputFormat,
 ) -> Result<()> {
+    if let Some(key) = &args.explain {
+        return explain_key(key, format);
+    }
+
     debug!("Validating configuration from: {}", root.display());
 
     // Try to find config file
@@ -262,16 +278,22 @@ pub async fn execute_validate(
 
     info!("Found configuration file: {}", config_file_path.display());
 
-    // Load configuration - this checks parseability
-    let config = ConfigLoader::load_from_file(&config_file_path).await.map_err(|e| {
-        CliError::configuration(format!(
-            "Failed to load configuration file: {e}\n\
+    // Load configuration - this checks parseability; rules are checked below so that every
+    // violation is reported
+    let config =
+        ConfigLoader::load_from_file_unvalidated(&config_file_path).await.map_err(|e| {
+            CliError::configuration(format!(
+                "Failed to load configuration file: {e}\n\
             Please check the file syntax and format."
-        ))
-    })?;
+            ))
+        })?;
 
     // Perform comprehensive validation
-    let validation_checks = perform_validation_checks(&config, root, &fs).await;
+    let issues = validate_config_all(&config);
+    let mut validation_checks = perform_validation_checks(&config, &issues, root, &fs).await;
+    if let Some(profile) = active_profile() {
+        validation_checks.push(validate_profile(&config_file_path, &profile).await);
+    }
 
     // Count passed and failed checks
     let total_checks = validation_checks.len();
@@ -284,10 +306,10 @@ pub async fn execute_validate(
     // Output results based on format
     match format {
         OutputFormat::Human => {
-            output_validate_human(&validation_checks, is_valid, &config_file_path);
+            output_validate_human(&validation_checks, &issues, is_valid, &config_file_path);
         }
-        OutputFormat::Json | OutputFormat::JsonCompact => {
-            output_validate_json(&validation_checks, is_valid, format)?;
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            output_validate_json(&validation_checks, &issues, is_valid, format)?;
         }
         OutputFormat::Quiet => output_validate_quiet(is_valid),
     }
@@ -315,7 +337,7 @@ struct ValidationCheck {
 /// Perform all validation checks on the configuration.
 ///
 /// This function runs comprehensive validation including:
-/// - Basic field validation via validate_config
+/// - Field validation via validate_config_all
 /// - Path existence checks
 /// - Environment configuration checks
 /// - Registry connectivity (future enhancement)
@@ -323,6 +345,7 @@ struct ValidationCheck {
 /// # Arguments
 ///
 /// * `config` - The configuration to validate
+/// * `issues` - Issues reported by `validate_config_all`
 /// * `root` - Workspace root directory
 /// * `fs` - File system manager for path checks
 ///
@@ -331,6 +354,7 @@ struct ValidationCheck {
 /// A vector of validation check results.
 async fn perform_validation_checks(
     config: &PackageToolsConfig,
+    issues: &[ConfigValidationIssue],
     root: &Path,
     fs: &FileSystemManager,
 ) -> Vec<ValidationCheck> {
@@ -343,18 +367,11 @@ async fn perform_validation_checks(
         error: None,
     });
 
-    // Check 2: All required fields present - use the validation function
-    let fields_check = match validate_config(config) {
-        Ok(()) => ValidationCheck {
-            name: "All required fields present".to_string(),
-            passed: true,
-            error: None,
-        },
-        Err(e) => ValidationCheck {
-            name: "All required fields present".to_string(),
-            passed: false,
-            error: Some(format!("{e}")),
-        },
+    // Check 2: All required fields present - each issue is detailed separately
+    let fields_check = ValidationCheck {
+        name: "All required fields present".to_string(),
+        passed: issues.is_empty(),
+        error: (!issues.is_empty()).then(|| format!("{} issue(s) found", issues.len())),
     };
     checks.push(fields_check);
 
@@ -393,6 +410,26 @@ async fn perform_validation_checks(
     checks
 }
 
+/// Validate that the active profile declares no conflicting settings.
+///
+/// A conflict is a setting the profile declares twice with different values, or a
+/// table it replaces with a plain value (or the reverse).
+async fn validate_profile(config_path: &Path, profile: &str) -> ValidationCheck {
+    let name = format!("Profile '{profile}' has no conflicting settings");
+
+    match ConfigLoader::load_unified(config_path).await {
+        Ok(unified) if unified.has_conflicts() => ValidationCheck {
+            name,
+            passed: false,
+            error: Some(
+                unified.conflicts.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "),
+            ),
+        },
+        Ok(_) => ValidationCheck { name, passed: true, error: None },
+        Err(e) => ValidationCheck { name, passed: false, error: Some(format!("{e}")) },
+    }
+}
+
 /// Validate environment configuration.
 fn validate_environments(config: &PackageToolsConfig) -> ValidationCheck {
     let envs = &config.changeset.available_environments;
@@ -420,6 +457,15 @@ fn validate_environments(config: &PackageToolsConfig) -> ValidationCheck {
         };
     }
 
+    // Check environment definitions (promotion order, references)
+    if let Err(e) = config.changeset.validate() {
+        return ValidationCheck {
+            name: "Environments valid".to_string(),
+            passed: false,
+            error: Some(e.to_string()),
+        };
+    }
+
     ValidationCheck { name: "Environments valid".to_string(), passed: true, error: None }
 }
 
@@ -591,7 +637,12 @@ fn validate_snapshot_format(config: &PackageToolsConfig) -> ValidationCheck {
 }
 
 /// Output validation results in human-readable format.
-fn output_validate_human(checks: &[ValidationCheck], is_valid: bool, config_path: &Path) {
+fn output_validate_human(
+    checks: &[ValidationCheck],
+    issues: &[ConfigValidationIssue],
+    is_valid: bool,
+    config_path: &Path,
+) {
     println!();
     if is_valid {
         println!("✓ Configuration is valid");
@@ -618,24 +669,119 @@ fn output_validate_human(checks: &[ValidationCheck], is_valid: bool, config_path
         }
     }
 
+    if !issues.is_empty() {
+        println!();
+        println!("Issues:");
+        for issue in issues {
+            println!();
+            print_issue(issue);
+        }
+    }
+
     println!();
 }
 
+/// Print a validation issue with its value, allowed values and documentation excerpt.
+fn print_issue(issue: &ConfigValidationIssue) {
+    if issue.path.is_empty() {
+        println!("  ✗ {}", issue.message);
+        return;
+    }
+
+    println!("  ✗ {}", issue.path);
+    println!("    Error: {}", issue.message);
+    if let Some(value) = &issue.value {
+        println!("    Received: {value}");
+    }
+    if !issue.allowed.is_empty() {
+        println!("    Allowed: {}", issue.allowed.join(", "));
+    }
+    if let Some(doc) = issue.doc() {
+        println!("    Docs: {}", doc.summary);
+        println!("          {}", doc.details);
+    }
+}
+
+/// Print the documentation of a configuration key, or of every key in a section.
+fn explain_key(key: &str, format: OutputFormat) -> Result<()> {
+    let docs = match ConfigDoc::lookup(key) {
+        Some(doc) => vec![doc],
+        None => ConfigDoc::section(key),
+    };
+    if docs.is_empty() {
+        return Err(CliError::validation(format!(
+            "Unknown configuration key '{key}'. Use a dotted path such as \
+            'version.default_bump', or a section such as 'changelog'."
+        )));
+    }
+
+    match format {
+        OutputFormat::Human => {
+            for doc in &docs {
+                println!();
+                println!("{}", doc.key);
+                println!("  {}", doc.summary);
+                if !doc.default.is_empty() {
+                    println!("  Default: {}", doc.default);
+                }
+                if !doc.allowed.is_empty() {
+                    println!("  Allowed: {}", doc.allowed.join(", "));
+                }
+                println!();
+                println!("  {}", doc.details);
+            }
+            println!();
+        }
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            let response = JsonResponse::success(ExplainResult { keys: docs });
+            let json_str = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
+                serde_json::to_string(&response)
+            } else {
+                serde_json::to_string_pretty(&response)
+            }
+            .map_err(|e| CliError::execution(format!("Failed to serialize JSON: {e}")))?;
+            println!("{json_str}");
+        }
+        OutputFormat::Quiet => {
+            for doc in &docs {
+                println!("{}", doc.key);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Documentation result structure for `--explain` JSON output.
+#[derive(Debug, Serialize)]
+struct ExplainResult {
+    /// Documented keys matching the requested key or section
+    keys: Vec<&'static ConfigDoc>,
+}
+
 /// Output validation results in JSON format.
 fn output_validate_json(
     checks: &[ValidationCheck],
+    issues: &[ConfigValidationIssue],
     is_valid: bool,
     format: OutputFormat,
 ) -> Result<()> {
     let response = if is_valid {
-        JsonResponse::success(ValidationResult { valid: true, checks: checks.to_vec() })
+        JsonResponse::success(ValidationResult {
+            valid: true,
+            checks: checks.to_vec(),
+            issues: Vec::new(),
+        })
     } else {
         // For validation failures, we still use success=true but valid=false
         // The command itself returns an error exit code
-        JsonResponse::success(ValidationResult { valid: false, checks: checks.to_vec() })
+        JsonResponse::success(ValidationResult {
+            valid: false,
+            checks: checks.to_vec(),
+            issues: issues.iter().map(IssueResult::from).collect(),
+        })
     };
 
-    let json_str = if format == OutputFormat::JsonCompact {
+    let json_str = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
         serde_json::to_string(&response)
             .map_err(|e| CliError::execution(format!("Failed to serialize JSON: {e}")))?
     } else {
@@ -663,6 +809,26 @@ struct ValidationResult {
     valid: bool,
     /// List of validation checks performed
     checks: Vec<ValidationCheck>,
+    /// Every configuration rule violation, with its documentation
+    issues: Vec<IssueResult>,
+}
+
+/// A configuration issue in JSON output.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct IssueResult {
+    /// The issue as reported by `validate_config_all`
+    #[serde(flatten)]
+    issue: ConfigValidationIssue,
+    /// Documentation of the offending key, if it is documented
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<&'static ConfigDoc>,
+}
+
+impl From<&ConfigValidationIssue> for IssueResult {
+    fn from(issue: &ConfigValidationIssue) -> Self {
+        Self { issue: issue.clone(), doc: issue.doc() }
+    }
 }
 
 /// Output configuration in human-readable format.</parameter>
@@ -750,7 +916,7 @@ fn output_json_format(config: &PackageToolsConfig, format: OutputFormat) -> Resu
     let response = JsonResponse::success(config_data);
 
     // Serialize based on format
-    let json_str = if format == OutputFormat::JsonCompact {
+    let json_str = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
         serde_json::to_string(&response)
             .map_err(|e| CliError::execution(format!("Failed to serialize JSON: {e}")))?
     } else {
@@ -954,6 +1120,12 @@ struct BackupConfigData {
     keep_after_success: bool,
     /// Maximum number of backups to keep
     max_backups: usize,
+    /// Maximum backup age in days
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_age_days: Option<u32>,
+    /// Maximum total backup size in megabytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_total_size_mb: Option<u64>,
 }
 
 impl From<&sublime_pkg_tools::config::BackupConfig> for BackupConfigData {
@@ -963,6 +1135,8 @@ impl From<&sublime_pkg_tools::config::BackupConfig> for BackupConfigData {
             backup_dir: config.backup_dir.clone(),
             keep_after_success: config.keep_after_success,
             max_backups: config.max_backups,
+            max_age_days: config.max_age_days,
+            max_total_size_mb: config.max_total_size_mb,
         }
     }
 }
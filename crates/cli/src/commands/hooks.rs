@@ -0,0 +1,268 @@
+//! Git hook management command implementation.
+//!
+//! This module implements the `workspace hooks` commands which install, inspect, and
+//! remove the git hooks declared in the `hooks` configuration section.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_install` - Writes configured hooks and registers `core.hooksPath` if configured
+//! - `execute_status` - Reports hooks that drifted from configuration
+//! - `execute_uninstall` - Removes hooks installed by workspace tools
+//!
+//! # How
+//!
+//! Each command loads the workspace configuration, builds a `HookInstaller` from
+//! `sublime_pkg_tools`, and renders the resulting operations or statuses as a table
+//! (human) or JSON (automation).
+//!
+//! # Why
+//!
+//! Declaring hooks in configuration lets every clone install the same hooks with one
+//! command, and lets CI verify they are current with `hooks status --check`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::hooks::execute_install;
+//! use sublime_cli_tools::cli::commands::HooksInstallArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//! use std::path::Path;
+//!
+//! # async fn example() -> sublime_cli_tools::error::Result<()> {
+//! let args = HooksInstallArgs { force: false };
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_install(&args, &output, Path::new("."), None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cli::commands::{HooksInstallArgs, HooksStatusArgs, HooksUninstallArgs};
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::output::table::{TableBuilder, TableTheme};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_pkg_tools::hooks::{
+    HookAction, HookInstaller, HookOperation, HookState, HooksStatusReport,
+};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Executes the `hooks install` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration cannot be loaded
+/// - A hook not installed by workspace tools would be overwritten without `--force`
+/// - Hooks cannot be written or `core.hooksPath` cannot be set
+pub async fn execute_install(
+    args: &HooksInstallArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    info!("Installing git hooks");
+    let installer = create_installer(root, config_path).await?;
+
+    if !installer.config().enabled {
+        return output_operations(output, &[], "Hook management is disabled in configuration");
+    }
+    if installer.config().hooks.is_empty() {
+        return output_operations(output, &[], "No hooks configured");
+    }
+
+    let operations = installer.install(args.force).await?;
+    output_operations(output, &operations, "Hooks installed")?;
+
+    if output.format().is_human() && operations.iter().any(|o| o.action == HookAction::Skipped) {
+        output.warning(
+            "Some hooks were edited by hand and left unchanged; use --force to replace them",
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Executes the `hooks status` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if the configuration or repository cannot be read, or a validation
+/// error if `--check` is set and any hook drifted from configuration.
+pub async fn execute_status(
+    args: &HooksStatusArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    info!("Checking git hook status");
+    let installer = create_installer(root, config_path).await?;
+    let report = installer.status().await?;
+
+    output_status(output, &report)?;
+
+    if args.check && report.has_drift() {
+        return Err(CliError::validation(
+            "Git hooks are out of date; run `workspace hooks install` to update them",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Executes the `hooks uninstall` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded or hooks cannot be removed.
+pub async fn execute_uninstall(
+    args: &HooksUninstallArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    info!("Removing git hooks");
+    let installer = create_installer(root, config_path).await?;
+    let operations = installer.uninstall(args.force).await?;
+
+    output_operations(output, &operations, "Hooks removed")
+}
+
+/// Loads the configuration and creates a hook installer for the workspace.
+async fn create_installer(
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<HookInstaller<FileSystemManager>> {
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+    debug!("Configured hooks: {:?}", config.hooks.hooks.keys().collect::<Vec<_>>());
+
+    Ok(HookInstaller::new(root.to_path_buf(), config.hooks, FileSystemManager::new()))
+}
+
+/// Renders the operations applied to hooks.
+fn output_operations(output: &Output, operations: &[HookOperation], title: &str) -> Result<()> {
+    if output.format().is_json() {
+        let hooks = operations
+            .iter()
+            .map(|o| HookJson {
+                name: o.name.clone(),
+                path: o.path.display().to_string(),
+                state: o.action.to_string(),
+            })
+            .collect();
+        return output.json(&JsonResponse::success(HooksJsonResponse { hooks_dir: None, hooks }));
+    }
+
+    if operations.is_empty() {
+        return output.info(title);
+    }
+
+    output.success(title)?;
+    output.blank_line()?;
+
+    let mut table =
+        TableBuilder::new().theme(TableTheme::Minimal).columns(&["Hook", "Action", "Path"]).build();
+    for operation in operations {
+        table.add_row(&[
+            operation.name.as_str(),
+            operation.action.as_str(),
+            &operation.path.display().to_string(),
+        ]);
+    }
+    output.table(&mut table)
+}
+
+/// Renders the status of every hook.
+fn output_status(output: &Output, report: &HooksStatusReport) -> Result<()> {
+    if output.format().is_json() {
+        let hooks = report
+            .hooks
+            .iter()
+            .map(|h| HookJson {
+                name: h.name.clone(),
+                path: h.path.display().to_string(),
+                state: h.state.to_string(),
+            })
+            .collect();
+        return output.json(&JsonResponse::success(HooksJsonResponse {
+            hooks_dir: Some(report.hooks_dir.display().to_string()),
+            hooks,
+        }));
+    }
+
+    output.info(&format!("Hooks directory: {}", report.hooks_dir.display()))?;
+    if !report.hooks_path_registered {
+        output.warning("Hooks directory is not registered as core.hooksPath")?;
+    }
+
+    if report.hooks.is_empty() {
+        return output.info("No hooks configured");
+    }
+
+    output.blank_line()?;
+    let mut table =
+        TableBuilder::new().theme(TableTheme::Minimal).columns(&["Hook", "State"]).build();
+    for hook in &report.hooks {
+        table.add_row(&[hook.name.as_str(), hook.state.as_str()]);
+    }
+    output.table(&mut table)?;
+
+    if report.hooks.iter().any(|h| h.state == HookState::Modified) {
+        output
+            .warning("Modified hooks were edited by hand; `hooks install --force` replaces them")?;
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// JSON Response Types
+// ============================================================================
+
+/// JSON response for hook commands.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HooksJsonResponse {
+    /// Directory hooks are installed into (status only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hooks_dir: Option<String>,
+    /// Hooks with their state or the action taken.
+    hooks: Vec<HookJson>,
+}
+
+/// JSON representation of a hook.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HookJson {
+    /// Git hook name.
+    name: String,
+    /// Path of the hook file.
+    path: String,
+    /// Hook state (status) or action taken (install/uninstall).
+    state: String,
+}
@@ -7,6 +7,7 @@
 //!
 //! Provides the init command that:
 //! - Detects workspace structure (single package or monorepo)
+//! - Applies a project archetype template, when one is selected
 //! - Collects configuration through interactive prompts or CLI flags
 //! - Generates repo.config.[format] file
 //! - Creates necessary directory structure (.changesets, .changesets/history, .workspace-backups)
@@ -19,7 +20,7 @@
 //! 1. Validates the target directory is a Node.js project
 //! 2. Detects if it's a monorepo or single package
 //! 3. Checks for existing configuration
-//! 4. Collects configuration via prompts or flags
+//! 4. Resolves the template and collects configuration via prompts or flags
 //! 5. Generates configuration file
 //! 6. Creates directory structure
 //! 7. Updates .gitignore
@@ -32,8 +33,12 @@
 //! provide helpful defaults, and ensure proper setup for the changeset workflow.
 //! Clear output and documentation help users understand the setup.
 
+mod templates;
+
+use self::templates::{BUILTIN_TEMPLATES, InitTemplate, builtin_template, resolve_template};
 use crate::cli::commands::InitArgs;
 use crate::error::{CliError, Result};
+use crate::interactive::is_interactive;
 use crate::output::{JsonResponse, OutputFormat};
 use dialoguer::{Input, MultiSelect, Select};
 use serde::Serialize;
@@ -63,6 +68,7 @@ use tracing::{debug, info};
 /// Returns an error if:
 /// - The directory is not a Node.js project
 /// - Configuration already exists and force flag is not set
+/// - The selected template does not exist or is invalid
 /// - File system operations fail
 /// - Configuration validation fails
 ///
@@ -88,6 +94,11 @@ use tracing::{debug, info};
 pub async fn execute_init(args: &InitArgs, root: &Path, format: OutputFormat) -> Result<()> {
     debug!("Initializing workspace at: {}", root.display());
 
+    // Ejecting a template only writes the template file
+    if let Some(name) = &args.eject_template {
+        return eject_template(root, name, args.force, format).await;
+    }
+
     // Validate workspace is a Node.js project
     validate_nodejs_project(root).await?;
 
@@ -107,18 +118,24 @@ pub async fn execute_init(args: &InitArgs, root: &Path, format: OutputFormat) ->
         info!("Force flag set, will overwrite existing configuration");
     }
 
-    // Collect configuration
-    let init_config = if args.non_interactive {
-        collect_config_non_interactive(args, &workspace_info)
+    // Resolve the template and collect configuration
+    let interactive = !args.non_interactive && is_interactive();
+    let template = match &args.template {
+        Some(value) => Some(resolve_template(root, value).await?),
+        None if interactive => select_template(&workspace_info)?,
+        None => None,
+    };
+    let init_config = if interactive {
+        collect_config_interactive(args, &workspace_info, template.as_ref())?
     } else {
-        collect_config_interactive(args, &workspace_info)?
+        collect_config_non_interactive(args, &workspace_info, template.as_ref())
     };
 
     // Validate configuration
     validate_init_config(&init_config)?;
 
     // Generate configuration file
-    let config_file_path = generate_config_file(root, &init_config).await?;
+    let config_file_path = generate_config_file(root, &init_config, template.as_ref()).await?;
     info!("Configuration file created: {}", config_file_path.display());
 
     // Create directory structure
@@ -178,6 +195,11 @@ impl WorkspaceInfo {
             "independent"
         }
     }
+
+    /// Returns the name of the template preselected for the workspace.
+    fn recommended_template(&self) -> &str {
+        if self.is_monorepo { "library-monorepo" } else { "single-package" }
+    }
 }
 
 /// Configuration collected during initialization.
@@ -195,6 +217,8 @@ struct InitConfig {
     registry: String,
     /// Configuration file format
     config_format: String,
+    /// Name of the applied template
+    template: Option<String>,
 }
 
 /// Validates that the directory is a Node.js project.
@@ -314,20 +338,67 @@ async fn find_existing_config(root: &Path) -> Result<Option<PathBuf>> {
     Ok(None)
 }
 
+/// Selects a template interactively.
+///
+/// The template matching the detected workspace is preselected; choosing "none" keeps the
+/// detected defaults.
+fn select_template(workspace_info: &WorkspaceInfo) -> Result<Option<InitTemplate>> {
+    let mut items = vec!["none (use detected defaults)".to_string()];
+    items.extend(
+        BUILTIN_TEMPLATES
+            .iter()
+            .map(|template| format!("{} - {}", template.name, template.description)),
+    );
+
+    let recommended = workspace_info.recommended_template();
+    let default = BUILTIN_TEMPLATES
+        .iter()
+        .position(|template| template.name == recommended)
+        .map_or(0, |index| index + 1);
+
+    let selection = Select::new()
+        .with_prompt("Project template")
+        .items(&items)
+        .default(default)
+        .interact()
+        .map_err(|e| CliError::user(format!("Failed to read selection: {e}")))?;
+
+    match selection.checked_sub(1).and_then(|index| BUILTIN_TEMPLATES.get(index)) {
+        Some(builtin) => Ok(Some(InitTemplate::parse(builtin.name, builtin.content)?)),
+        None => Ok(None),
+    }
+}
+
 /// Collects configuration in non-interactive mode.
-fn collect_config_non_interactive(args: &InitArgs, workspace_info: &WorkspaceInfo) -> InitConfig {
+///
+/// Flags take precedence over the template, and the template over the detected defaults.
+fn collect_config_non_interactive(
+    args: &InitArgs,
+    workspace_info: &WorkspaceInfo,
+    template: Option<&InitTemplate>,
+) -> InitConfig {
     // Use provided values or defaults
     let changeset_path = args.changeset_path.to_string_lossy().to_string();
 
-    let environments = args.environments.clone().unwrap_or_else(|| {
-        vec!["dev".to_string(), "staging".to_string(), "production".to_string()]
-    });
-
-    let default_environments =
-        args.default_env.clone().unwrap_or_else(|| vec!["production".to_string()]);
-
-    let strategy =
-        args.strategy.clone().unwrap_or_else(|| workspace_info.recommended_strategy().to_string());
+    let environments = args
+        .environments
+        .clone()
+        .or_else(|| template.and_then(InitTemplate::environments))
+        .unwrap_or_else(|| {
+            vec!["dev".to_string(), "staging".to_string(), "production".to_string()]
+        });
+
+    let default_environments = args
+        .default_env
+        .clone()
+        .or_else(|| template.and_then(InitTemplate::default_environments))
+        .unwrap_or_else(|| vec!["production".to_string()]);
+
+    let strategy = args
+        .strategy
+        .clone()
+        .or_else(|| template.and_then(InitTemplate::strategy))
+        .unwrap_or_else(|| workspace_info.recommended_strategy().to_string());
 
     let registry = args.registry.clone();
 
@@ -340,13 +411,17 @@ fn collect_config_non_interactive(args: &InitArgs, workspace_info: &WorkspaceInf
         strategy,
         registry,
         config_format,
+        template: template.map(|template| template.name.clone()),
     }
 }
 
 /// Collects configuration through interactive prompts.
+///
+/// Prompts default to the template's values when a template is selected.
 fn collect_config_interactive(
     args: &InitArgs,
     workspace_info: &WorkspaceInfo,
+    template: Option<&InitTemplate>,
 ) -> Result<InitConfig> {
     eprintln!("\n🚀 Initialize Workspace Tools\n");
     eprintln!(
@@ -362,7 +437,9 @@ fn collect_config_interactive(
     let environments = if let Some(envs) = &args.environments {
         envs.clone()
     } else {
-        let default = ["dev", "staging", "production"];
+        let default = template.and_then(InitTemplate::environments).unwrap_or_else(|| {
+            vec!["dev".to_string(), "staging".to_string(), "production".to_string()]
+        });
         let input: String = Input::new()
             .with_prompt("Available environments (comma-separated)")
             .default(default.join(", "))
@@ -375,9 +452,15 @@ fn collect_config_interactive(
     let default_environments = if let Some(default_env) = &args.default_env {
         default_env.clone()
     } else {
-        // Create defaults array: true for last item (production), false for others
-        let defaults: Vec<bool> =
-            (0..environments.len()).map(|i| i == environments.len().saturating_sub(1)).collect();
+        // Preselect the template's defaults, or the last item (production) without one
+        let defaults: Vec<bool> = match template.and_then(InitTemplate::default_environments) {
+            Some(template_defaults) => {
+                environments.iter().map(|env| template_defaults.contains(env)).collect()
+            }
+            None => {
+                (0..environments.len()).map(|i| i == environments.len().saturating_sub(1)).collect()
+            }
+        };
 
         let indices = MultiSelect::new()
             .with_prompt("Select default environments (use space to select, enter to confirm)")
@@ -394,7 +477,10 @@ fn collect_config_interactive(
         strat.clone()
     } else {
         let strategies = vec!["independent", "unified"];
-        let recommended_idx = usize::from(workspace_info.recommended_strategy() == "unified");
+        let recommended = template
+            .and_then(InitTemplate::strategy)
+            .unwrap_or_else(|| workspace_info.recommended_strategy().to_string());
+        let recommended_idx = usize::from(recommended == "unified");
 
         let selection = Select::new()
             .with_prompt("Versioning strategy")
@@ -431,6 +517,7 @@ fn collect_config_interactive(
         strategy,
         registry,
         config_format,
+        template: template.map(|template| template.name.clone()),
     })
 }
 
@@ -481,11 +568,22 @@ fn validate_init_config(config: &InitConfig) -> Result<()> {
 }
 
 /// Generates the configuration file.
-async fn generate_config_file(root: &Path, config: &InitConfig) -> Result<PathBuf> {
+///
+/// The template, if any, is merged over the default configuration before the collected
+/// settings are applied.
+async fn generate_config_file(
+    root: &Path,
+    config: &InitConfig,
+    template: Option<&InitTemplate>,
+) -> Result<PathBuf> {
     let fs = FileSystemManager::new();
 
-    // Create PackageToolsConfig with user settings
-    let mut pkg_config = PackageToolsConfig::default();
+    // Create PackageToolsConfig with the template and user settings
+    let mut pkg_config = match template {
+        Some(template) => template.apply(PackageToolsConfig::default())?,
+        None => PackageToolsConfig::default(),
+    };
+    let template_workspace = pkg_config.workspace.take();
 
     // Extract workspace patterns from package.json if it's a monorepo
     let workspace_patterns = extract_workspace_patterns(root, &fs).await?;
@@ -495,9 +593,12 @@ async fn generate_config_file(root: &Path, config: &InitConfig) -> Result<PathBu
         // Check if it should be a monorepo (has workspaces field even if empty)
         let has_workspace_field = check_has_workspace_field(root, &fs).await?;
         if has_workspace_field {
-            // Monorepo with empty patterns - still need to include workspace config
-            pkg_config.workspace = Some(sublime_pkg_tools::config::WorkspaceConfig::empty());
-            debug!("Added empty workspace config for monorepo with no patterns yet");
+            // Monorepo with empty patterns - use the template's patterns, if any
+            pkg_config.workspace = Some(
+                template_workspace
+                    .unwrap_or_else(sublime_pkg_tools::config::WorkspaceConfig::empty),
+            );
+            debug!("Added workspace config for monorepo with no patterns yet");
         }
         // If no workspaces field, workspace remains None (single-package project)
     } else {
@@ -761,7 +862,7 @@ updatedAt: "2024-01-01T00:00:00Z"
 #[allow(clippy::print_stdout)]
 fn output_init_result(config_path: &Path, config: &InitConfig, format: OutputFormat) -> Result<()> {
     match format {
-        OutputFormat::Json | OutputFormat::JsonCompact => {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
             #[derive(Serialize)]
             #[allow(non_snake_case)]
             struct InitResult {
@@ -772,6 +873,8 @@ fn output_init_result(config_path: &Path, config: &InitConfig, format: OutputFor
                 environments: Vec<String>,
                 defaultEnvironments: Vec<String>,
                 registry: String,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                template: Option<String>,
             }
 
             let result = InitResult {
@@ -786,6 +889,7 @@ fn output_init_result(config_path: &Path, config: &InitConfig, format: OutputFor
                 environments: config.environments.clone(),
                 defaultEnvironments: config.default_environments.clone(),
                 registry: config.registry.clone(),
+                template: config.template.clone(),
             };
 
             let response = JsonResponse::success(result);
@@ -809,6 +913,9 @@ fn output_init_result(config_path: &Path, config: &InitConfig, format: OutputFor
                 "  Config file: {}",
                 config_path.file_name().and_then(|n| n.to_str()).unwrap_or("repo.config")
             );
+            if let Some(template) = &config.template {
+                println!("  Template: {template}");
+            }
             println!("  Strategy: {}", config.strategy);
             println!("  Changesets: {}", config.changeset_path);
             println!("  Environments: {}", config.environments.join(", "));
@@ -820,6 +927,69 @@ fn output_init_result(config_path: &Path, config: &InitConfig, format: OutputFor
     Ok(())
 }
 
+/// Writes a built-in template to `<name>.template.toml` in the workspace root.
+///
+/// The file can be edited and passed back to `--template` as a path.
+#[allow(clippy::print_stdout)]
+async fn eject_template(root: &Path, name: &str, force: bool, format: OutputFormat) -> Result<()> {
+    let Some(builtin) = builtin_template(name) else {
+        let names: Vec<&str> = BUILTIN_TEMPLATES.iter().map(|template| template.name).collect();
+        return Err(CliError::validation(format!(
+            "Unknown init template '{name}'. Use one of: {}",
+            names.join(", ")
+        )));
+    };
+
+    let fs = FileSystemManager::new();
+    let file_name = format!("{}.template.toml", builtin.name);
+    let path = root.join(&file_name);
+    if fs.exists(&path).await && !force {
+        return Err(CliError::configuration(format!(
+            "Template file already exists: {}. Use --force to overwrite.",
+            path.display()
+        )));
+    }
+
+    fs.write_file(&path, builtin.content.as_bytes()).await.map_err(|e| {
+        CliError::io(format!("Failed to write template file {}: {}", path.display(), e))
+    })?;
+    info!("Template ejected: {}", path.display());
+
+    match format {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
+            #[derive(Serialize)]
+            #[allow(non_snake_case)]
+            struct EjectResult {
+                template: String,
+                templateFile: String,
+            }
+
+            let response = JsonResponse::success(EjectResult {
+                template: builtin.name.to_string(),
+                templateFile: file_name,
+            });
+            let json = if format == OutputFormat::JsonCompact {
+                serde_json::to_string(&response)
+            } else {
+                serde_json::to_string_pretty(&response)
+            }
+            .map_err(|e| CliError::execution(format!("Failed to serialize JSON: {e}")))?;
+
+            println!("{json}");
+        }
+        OutputFormat::Quiet => {
+            println!("{file_name}");
+        }
+        OutputFormat::Human => {
+            println!("\n✓ Template '{}' ejected to {file_name}\n", builtin.name);
+            println!("  Edit it, then run: workspace init --template {file_name}");
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
 /// Parses comma-separated values into a vector of trimmed strings.
 fn parse_comma_separated(input: &str) -> Vec<String> {
     input.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
@@ -0,0 +1,196 @@
+//! Init templates by project archetype.
+//!
+//! # What
+//!
+//! Provides the archetype templates offered by `workspace init`:
+//! - `library-monorepo`: independently versioned packages published to a registry
+//! - `app-monorepo`: applications and internal packages released together
+//! - `single-package`: a single package at the repository root
+//! - `design-system`: tokens, components and icons released in lockstep
+//!
+//! # How
+//!
+//! Templates are TOML files embedded in the binary. Each one holds a `[template]` table
+//! with its name and description, followed by configuration sections laid out like
+//! `repo.config.toml`. The sections are merged over the default configuration: tables
+//! merge key by key and every other value replaces the default. A template can be ejected
+//! to a file, edited, and passed back to `--template` as a path.
+//!
+//! # Why
+//!
+//! Projects of the same kind want the same workspace patterns, environments, changelog
+//! style and hooks. Templates give them a starting point without answering every prompt,
+//! while keeping the settings in the same format as the configuration they produce.
+
+use crate::error::{CliError, Result};
+use std::path::Path;
+use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// A template embedded in the binary.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BuiltinTemplate {
+    /// Name selected with `--template`
+    pub(crate) name: &'static str,
+    /// One-line description shown in prompts
+    pub(crate) description: &'static str,
+    /// TOML content of the template
+    pub(crate) content: &'static str,
+}
+
+/// The templates embedded in the binary, in the order they are offered.
+pub(crate) const BUILTIN_TEMPLATES: &[BuiltinTemplate] = &[
+    BuiltinTemplate {
+        name: "library-monorepo",
+        description: "Independently versioned packages published to a registry",
+        content: include_str!("templates/library-monorepo.toml"),
+    },
+    BuiltinTemplate {
+        name: "app-monorepo",
+        description: "Applications and internal packages released together",
+        content: include_str!("templates/app-monorepo.toml"),
+    },
+    BuiltinTemplate {
+        name: "single-package",
+        description: "A single package at the repository root",
+        content: include_str!("templates/single-package.toml"),
+    },
+    BuiltinTemplate {
+        name: "design-system",
+        description: "Tokens, components and icons released in lockstep",
+        content: include_str!("templates/design-system.toml"),
+    },
+];
+
+/// Returns the built-in template with the given name.
+pub(crate) fn builtin_template(name: &str) -> Option<&'static BuiltinTemplate> {
+    BUILTIN_TEMPLATES.iter().find(|template| template.name == name)
+}
+
+/// A parsed template, ready to apply.
+#[derive(Debug, Clone)]
+pub(crate) struct InitTemplate {
+    /// Template name, from its `[template]` table
+    pub(crate) name: String,
+    /// Configuration sections merged over the defaults
+    overlay: toml::Table,
+}
+
+impl InitTemplate {
+    /// Parses a template from its TOML content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid TOML, has no `[template]` table with a
+    /// name, or its sections do not form a valid configuration.
+    pub(crate) fn parse(source: &str, content: &str) -> Result<Self> {
+        let mut overlay: toml::Table = content.parse().map_err(|e| {
+            CliError::validation(format!("Failed to parse init template {source}: {e}"))
+        })?;
+
+        let name = overlay
+            .remove("template")
+            .and_then(|header| header.get("name").and_then(toml::Value::as_str).map(String::from))
+            .ok_or_else(|| {
+                CliError::validation(format!(
+                    "Init template {source} must declare its name in a [template] table"
+                ))
+            })?;
+
+        let template = Self { name, overlay };
+        template.apply(PackageToolsConfig::default())?;
+        Ok(template)
+    }
+
+    /// Returns the versioning strategy the template selects, if any.
+    pub(crate) fn strategy(&self) -> Option<String> {
+        self.value(&["version", "strategy"]).and_then(toml::Value::as_str).map(String::from)
+    }
+
+    /// Returns the environments the template declares, if any.
+    pub(crate) fn environments(&self) -> Option<Vec<String>> {
+        self.strings(&["changeset", "available_environments"])
+    }
+
+    /// Returns the default environments the template declares, if any.
+    pub(crate) fn default_environments(&self) -> Option<Vec<String>> {
+        self.strings(&["changeset", "default_environments"])
+    }
+
+    /// Merges the template over a configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the merged sections do not form a valid configuration.
+    pub(crate) fn apply(&self, config: PackageToolsConfig) -> Result<PackageToolsConfig> {
+        let mut merged = toml::Table::try_from(&config)
+            .map_err(|e| CliError::execution(format!("Failed to serialize configuration: {e}")))?;
+        merge_tables(&mut merged, self.overlay.clone());
+
+        let mut applied: PackageToolsConfig = merged.try_into().map_err(|e| {
+            CliError::validation(format!("Init template '{}' is invalid: {e}", self.name))
+        })?;
+        applied.standard_config = config.standard_config;
+        Ok(applied)
+    }
+
+    /// Returns the value at a path of nested tables.
+    fn value(&self, path: &[&str]) -> Option<&toml::Value> {
+        let (last, tables) = path.split_last()?;
+        let mut table = &self.overlay;
+        for key in tables {
+            table = table.get(*key)?.as_table()?;
+        }
+        table.get(*last)
+    }
+
+    /// Returns the string array at a path of nested tables.
+    fn strings(&self, path: &[&str]) -> Option<Vec<String>> {
+        let values = self.value(path)?.as_array()?;
+        Some(values.iter().filter_map(|value| value.as_str().map(String::from)).collect())
+    }
+}
+
+/// Resolves `--template` to a template.
+///
+/// Built-in template names are resolved first; any other value is read as a template file,
+/// relative to `root` unless absolute.
+///
+/// # Errors
+///
+/// Returns an error if the value names neither a built-in template nor a readable template
+/// file, or if the template is invalid.
+pub(crate) async fn resolve_template(root: &Path, value: &str) -> Result<InitTemplate> {
+    if let Some(builtin) = builtin_template(value) {
+        return InitTemplate::parse(builtin.name, builtin.content);
+    }
+
+    let fs = FileSystemManager::new();
+    let path = root.join(value);
+    if !fs.exists(&path).await {
+        let names: Vec<&str> = BUILTIN_TEMPLATES.iter().map(|template| template.name).collect();
+        return Err(CliError::validation(format!(
+            "Unknown init template '{value}'. Use one of: {}, or a path to a template file",
+            names.join(", ")
+        )));
+    }
+
+    let content = fs.read_file_string(&path).await.map_err(|e| {
+        CliError::io(format!("Failed to read init template {}: {}", path.display(), e))
+    })?;
+    InitTemplate::parse(&path.display().to_string(), &content)
+}
+
+/// Merges `overlay` into `base`: tables merge key by key, other values replace the base.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
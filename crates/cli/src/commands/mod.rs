@@ -10,6 +10,12 @@
 //! - Version management commands (`bump`, `changes`)
 //! - Upgrade commands (`check`, `apply`, `rollback`)
 //! - Audit commands (`audit` with various modes)
+//! - Hook commands (`hooks install`, `hooks status`, `hooks uninstall`)
+//! - Monitor command (`monitor` live workspace dashboard)
+//! - Undo command (`undo` revert a recorded workspace operation)
+//! - Why command (`why` explain why a package is in the dependency tree)
+//! - Tag command (`tag` create missing tags for already-released versions)
+//! - Aliases command (`aliases` generate import aliases for workspace packages)
 //!
 //! # How
 //!
@@ -37,17 +43,30 @@
 //! - `upgrade.rs` - Dependency upgrade commands
 //! - `audit.rs` - Audit and health check commands
 //! - `changes.rs` - Change analysis commands
+//! - `hooks.rs` - Git hook management commands
+//! - `monitor.rs` - Live workspace dashboard
+//! - `undo.rs` - Transaction log listing and undo
+//! - `why.rs` - Dependency chain explanation
+//! - `tag.rs` - Retroactive release tagging
+//! - `aliases.rs` - Import alias generation and drift checks
 
 // Module exports
+pub mod aliases;
 pub mod audit;
 pub mod bump;
 pub mod changes;
 pub mod changeset;
 pub mod clone;
 pub mod config;
+pub mod hooks;
 pub mod init;
+pub mod monitor;
+pub mod roots;
+pub mod tag;
+pub mod undo;
 pub mod upgrade;
 pub mod version;
+pub mod why;
 
 // Common utilities
 use crate::error::{CliError, Result};
@@ -0,0 +1,529 @@
+//! Workspace monitor command implementation.
+//!
+//! This module implements the `workspace monitor` command, a terminal dashboard that keeps
+//! the state of the workspace on screen while you work.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_monitor` - Runs the live dashboard, or prints a single snapshot
+//! - A view of packages, dirty packages, pending changesets, recent releases, outdated
+//!   dependencies and dependency graph metrics
+//! - Keybindings: `q`/`Esc` quit, `r` refresh, `a` add a changeset, `p` preview the bump plan
+//!
+//! # How
+//!
+//! Each refresh collects a snapshot from `AuditManager::dashboard` (the aggregated data API),
+//! the pending changesets, and the changeset history. The live view draws the snapshot on the
+//! alternate screen with `crossterm` and waits for a key or the refresh interval. Actions run
+//! the corresponding `workspace` subcommand in the normal screen and return to the dashboard.
+//!
+//! When stdout is not a terminal, the output format is not human, or `--once` is given, a
+//! single snapshot is printed instead (as text or JSON).
+//!
+//! There is no task runner in the workspace yet, so the view has no running-tasks panel.
+//!
+//! # Why
+//!
+//! Release managers want to see at a glance what is pending and what changed, without
+//! running several commands. A single data call keeps the refresh cheap.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::monitor::execute_monitor;
+//! use sublime_cli_tools::cli::commands::MonitorArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//! use std::path::Path;
+//!
+//! # async fn example() -> sublime_cli_tools::error::Result<()> {
+//! let args = MonitorArgs { interval: 5, once: true };
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_monitor(&args, &output, Path::new("."), None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cli::commands::MonitorArgs;
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use sublime_pkg_tools::audit::{AuditManager, WorkspaceDashboard};
+use sublime_pkg_tools::changeset::{ChangesetHistory, FileBasedChangesetStorage};
+use sublime_pkg_tools::types::{ArchivedChangeset, Changeset};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Number of releases shown in the recent releases panel.
+const RECENT_RELEASES: usize = 5;
+
+/// Executes the `monitor` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration cannot be loaded
+/// - The workspace cannot be analyzed
+/// - The terminal cannot be switched to or from the dashboard screen
+pub async fn execute_monitor(
+    args: &MonitorArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    info!("Starting workspace monitor");
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+
+    let history = ChangesetHistory::new(Box::new(FileBasedChangesetStorage::new(
+        root.to_path_buf(),
+        config.changeset.path.clone(),
+        config.changeset.history_path.clone(),
+        FileSystemManager::new(),
+    )));
+    let manager = Box::pin(AuditManager::new(root.to_path_buf(), config))
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to initialize monitor: {e}")))?;
+
+    let live = !args.once && output.format().is_human() && std::io::stdout().is_terminal();
+    if !live {
+        let snapshot = collect_snapshot(&manager, &history).await?;
+        return output_snapshot(output, &snapshot);
+    }
+
+    run_live(args, &manager, &history, root, config_path).await
+}
+
+/// Everything shown by one refresh of the monitor.
+#[derive(Debug, Clone)]
+pub(crate) struct MonitorSnapshot {
+    /// Aggregated workspace metrics.
+    pub(crate) dashboard: WorkspaceDashboard,
+    /// Pending changesets, oldest first.
+    pub(crate) pending: Vec<Changeset>,
+    /// Most recent releases, newest first.
+    pub(crate) releases: Vec<ArchivedChangeset>,
+}
+
+/// Collects a snapshot of the workspace.
+async fn collect_snapshot(
+    manager: &AuditManager,
+    history: &ChangesetHistory,
+) -> Result<MonitorSnapshot> {
+    let dashboard = manager
+        .dashboard()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to collect workspace data: {e}")))?;
+
+    let mut pending = manager
+        .changeset_manager()
+        .list_pending()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to list pending changesets: {e}")))?;
+    pending.sort_by_key(|c| c.created_at);
+
+    let mut releases = history
+        .list_all()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to read changeset history: {e}")))?;
+    releases.sort_by(|a, b| b.release_info.applied_at.cmp(&a.release_info.applied_at));
+    releases.truncate(RECENT_RELEASES);
+
+    debug!(
+        "Snapshot: {} packages, {} pending, {} releases",
+        dashboard.package_count,
+        pending.len(),
+        releases.len()
+    );
+
+    Ok(MonitorSnapshot { dashboard, pending, releases })
+}
+
+/// Prints a single snapshot as text or JSON.
+fn output_snapshot(output: &Output, snapshot: &MonitorSnapshot) -> Result<()> {
+    if output.format().is_json() {
+        return output.json(&JsonResponse::success(MonitorJsonResponse::from(snapshot)));
+    }
+
+    for line in render_lines(snapshot) {
+        output.plain(&line)?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Rendering
+// ============================================================================
+
+/// Renders a snapshot as lines of plain text.
+pub(crate) fn render_lines(snapshot: &MonitorSnapshot) -> Vec<String> {
+    let dashboard = &snapshot.dashboard;
+    let mut lines = Vec::new();
+
+    let kind = if dashboard.is_monorepo { "monorepo" } else { "single package" };
+    lines.push(format!(
+        "Workspace: {} ({kind})  updated {}",
+        dashboard.workspace_root.display(),
+        dashboard.generated_at.format("%H:%M:%S")
+    ));
+    lines.push(format!(
+        "Packages: {}  Dirty: {}  Graph depth: {}  Cycles: {}",
+        dashboard.package_count,
+        dashboard.dirty_packages.len(),
+        dashboard.graph.max_depth,
+        dashboard.graph.cycle_count
+    ));
+    lines.push(match dashboard.outdated {
+        Some(outdated) => format!(
+            "Outdated: {} major, {} minor, {} patch, {} deprecated",
+            outdated.major, outdated.minor, outdated.patch, outdated.deprecated
+        ),
+        None => "Outdated: upgrade audit disabled".to_string(),
+    });
+
+    lines.push(String::new());
+    match dashboard.oldest_changeset_age() {
+        Some(age) => lines.push(format!(
+            "Pending changesets ({}, oldest {})",
+            snapshot.pending.len(),
+            format_age(age)
+        )),
+        None => lines.push("Pending changesets (0)".to_string()),
+    }
+    for changeset in &snapshot.pending {
+        lines.push(format!(
+            "  {}  {}  {}",
+            changeset.branch,
+            changeset.bump,
+            changeset.packages.join(", ")
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("Recent releases ({})", snapshot.releases.len()));
+    for release in &snapshot.releases {
+        let versions: BTreeMap<_, _> = release.release_info.versions.iter().collect();
+        let versions: Vec<String> =
+            versions.into_iter().map(|(name, version)| format!("{name}@{version}")).collect();
+        lines.push(format!(
+            "  {}  {}  {}",
+            release.release_info.applied_at.format("%Y-%m-%d"),
+            release.changeset.branch,
+            versions.join(", ")
+        ));
+    }
+
+    if dashboard.has_dirty_packages() {
+        lines.push(String::new());
+        lines.push("Dirty packages".to_string());
+        for name in &dashboard.dirty_packages {
+            lines.push(format!("  {name}"));
+        }
+    }
+
+    lines
+}
+
+/// Formats a duration as a compact age, using its largest unit (e.g. `3d`, `5h`, `12m`).
+pub(crate) fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3_600 => format!("{}m", secs / 60),
+        3_600..86_400 => format!("{}h", secs / 3_600),
+        _ => format!("{}d", secs / 86_400),
+    }
+}
+
+// ============================================================================
+// Live View
+// ============================================================================
+
+/// Keybinding help shown at the bottom of the live view.
+const KEY_HELP: &str = "[q] quit  [r] refresh  [a] add changeset  [p] bump plan";
+
+/// Actions triggered from the live view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MonitorAction {
+    /// Leave the monitor.
+    Quit,
+    /// Collect a new snapshot.
+    Refresh,
+    /// Run `changeset create`.
+    AddChangeset,
+    /// Run the bump preview.
+    BumpPlan,
+}
+
+/// Maps a key press to a monitor action.
+pub(crate) fn action_for_key(key: &KeyEvent) -> Option<MonitorAction> {
+    if key.kind != KeyEventKind::Press {
+        return None;
+    }
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(MonitorAction::Quit)
+        }
+        KeyCode::Char('q') | KeyCode::Esc => Some(MonitorAction::Quit),
+        KeyCode::Char('r') => Some(MonitorAction::Refresh),
+        KeyCode::Char('a') => Some(MonitorAction::AddChangeset),
+        KeyCode::Char('p') => Some(MonitorAction::BumpPlan),
+        _ => None,
+    }
+}
+
+/// Runs the live dashboard until the user quits.
+async fn run_live(
+    args: &MonitorArgs,
+    manager: &AuditManager,
+    history: &ChangesetHistory,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let interval = Duration::from_secs(args.interval);
+    let mut screen = DashboardScreen::enter()?;
+    let mut snapshot = collect_snapshot(manager, history).await?;
+    let mut refreshed_at = Instant::now();
+
+    loop {
+        DashboardScreen::draw(&render_lines(&snapshot))?;
+
+        let action = wait_for_action(interval.saturating_sub(refreshed_at.elapsed()))?;
+        match action {
+            Some(MonitorAction::Quit) => break,
+            Some(MonitorAction::AddChangeset) => {
+                screen.suspend()?;
+                run_subcommand(root, config_path, &["changeset", "create"], false)?;
+                screen.resume()?;
+            }
+            Some(MonitorAction::BumpPlan) => {
+                screen.suspend()?;
+                run_subcommand(root, config_path, &["bump"], true)?;
+                screen.resume()?;
+            }
+            // Refresh on request or when the interval elapsed
+            Some(MonitorAction::Refresh) | None => {}
+        }
+
+        snapshot = collect_snapshot(manager, history).await?;
+        refreshed_at = Instant::now();
+    }
+
+    Ok(())
+}
+
+/// Waits up to `timeout` for a key that maps to an action.
+///
+/// Returns `None` when the timeout elapses without an action.
+fn wait_for_action(timeout: Duration) -> Result<Option<MonitorAction>> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Ok(None);
+        }
+
+        let ready = event::poll(remaining)
+            .map_err(|e| CliError::io(format!("Failed to read terminal events: {e}")))?;
+        if !ready {
+            return Ok(None);
+        }
+
+        let event = event::read()
+            .map_err(|e| CliError::io(format!("Failed to read terminal event: {e}")))?;
+        if let Event::Key(key) = event
+            && let Some(action) = action_for_key(&key)
+        {
+            return Ok(Some(action));
+        }
+    }
+}
+
+/// Runs a `workspace` subcommand on the normal screen with inherited stdio.
+///
+/// Failures are reported to the user but do not stop the monitor.
+fn run_subcommand(
+    root: &Path,
+    config_path: Option<&Path>,
+    subcommand: &[&str],
+    wait_for_enter: bool,
+) -> Result<()> {
+    let executable = std::env::current_exe()
+        .map_err(|e| CliError::execution(format!("Failed to locate the workspace binary: {e}")))?;
+
+    let mut command = std::process::Command::new(executable);
+    command.arg("--root").arg(root);
+    if let Some(config_path) = config_path {
+        command.arg("--config").arg(config_path);
+    }
+    command.args(subcommand);
+
+    match command.status() {
+        Ok(status) if !status.success() => {
+            eprintln!("`workspace {}` exited with {status}", subcommand.join(" "));
+        }
+        Ok(_) => {}
+        Err(e) => eprintln!("Failed to run `workspace {}`: {e}", subcommand.join(" ")),
+    }
+
+    if wait_for_enter {
+        let mut stdout = std::io::stdout();
+        writeln!(stdout, "\nPress Enter to return to the monitor")
+            .and_then(|()| stdout.flush())
+            .map_err(|e| CliError::io(format!("Failed to write to stdout: {e}")))?;
+        let mut line = String::new();
+        std::io::stdin()
+            .read_line(&mut line)
+            .map_err(|e| CliError::io(format!("Failed to read from stdin: {e}")))?;
+    }
+
+    Ok(())
+}
+
+/// The alternate screen the dashboard is drawn on.
+///
+/// The terminal is restored when the screen is dropped, including on errors.
+struct DashboardScreen {
+    /// Whether the terminal is currently in dashboard mode.
+    active: bool,
+}
+
+impl DashboardScreen {
+    /// Switches the terminal to the dashboard screen.
+    fn enter() -> Result<Self> {
+        let mut screen = Self { active: false };
+        screen.resume()?;
+        Ok(screen)
+    }
+
+    /// Enters raw mode and the alternate screen.
+    fn resume(&mut self) -> Result<()> {
+        terminal::enable_raw_mode()
+            .map_err(|e| CliError::io(format!("Failed to enable raw mode: {e}")))?;
+        self.active = true;
+        execute!(std::io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)
+            .map_err(|e| CliError::io(format!("Failed to enter the dashboard screen: {e}")))
+    }
+
+    /// Returns to the normal screen so subcommands can use the terminal.
+    fn suspend(&mut self) -> Result<()> {
+        self.active = false;
+        execute!(std::io::stdout(), cursor::Show, terminal::LeaveAlternateScreen)
+            .map_err(|e| CliError::io(format!("Failed to leave the dashboard screen: {e}")))?;
+        terminal::disable_raw_mode()
+            .map_err(|e| CliError::io(format!("Failed to disable raw mode: {e}")))
+    }
+
+    /// Draws the given lines, clipped to the terminal size, followed by the key help.
+    fn draw(lines: &[String]) -> Result<()> {
+        let (width, height) = terminal::size().unwrap_or((80, 24));
+        let width = usize::from(width);
+        let body_rows = usize::from(height).saturating_sub(2);
+
+        let mut stdout = std::io::stdout();
+        let io_error = |e: std::io::Error| CliError::io(format!("Failed to draw monitor: {e}"));
+
+        queue!(stdout, cursor::MoveTo(0, 0), terminal::Clear(terminal::ClearType::All))
+            .map_err(io_error)?;
+        for line in lines.iter().take(body_rows) {
+            let clipped: String = line.chars().take(width).collect();
+            write!(stdout, "{clipped}\r\n").map_err(io_error)?;
+        }
+        queue!(stdout, cursor::MoveTo(0, height.saturating_sub(1))).map_err(io_error)?;
+        write!(stdout, "{}", KEY_HELP.chars().take(width).collect::<String>()).map_err(io_error)?;
+        stdout.flush().map_err(io_error)
+    }
+}
+
+impl Drop for DashboardScreen {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.suspend();
+        }
+    }
+}
+
+// ============================================================================
+// JSON Response Types
+// ============================================================================
+
+/// JSON response for the `monitor` command.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MonitorJsonResponse {
+    /// Aggregated workspace metrics.
+    dashboard: WorkspaceDashboard,
+    /// Pending changesets, oldest first.
+    pending_changesets: Vec<PendingChangesetJson>,
+    /// Most recent releases, newest first.
+    recent_releases: Vec<ReleaseJson>,
+}
+
+/// JSON representation of a pending changeset.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PendingChangesetJson {
+    /// Branch the changeset belongs to.
+    branch: String,
+    /// Requested version bump.
+    bump: String,
+    /// Packages included in the changeset.
+    packages: Vec<String>,
+    /// Creation timestamp (RFC 3339).
+    created_at: String,
+}
+
+/// JSON representation of a release.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReleaseJson {
+    /// Branch of the released changeset.
+    branch: String,
+    /// Release timestamp (RFC 3339).
+    applied_at: String,
+    /// Released versions by package name.
+    versions: BTreeMap<String, String>,
+}
+
+impl From<&MonitorSnapshot> for MonitorJsonResponse {
+    fn from(snapshot: &MonitorSnapshot) -> Self {
+        Self {
+            dashboard: snapshot.dashboard.clone(),
+            pending_changesets: snapshot
+                .pending
+                .iter()
+                .map(|c| PendingChangesetJson {
+                    branch: c.branch.clone(),
+                    bump: c.bump.to_string(),
+                    packages: c.packages.clone(),
+                    created_at: c.created_at.to_rfc3339(),
+                })
+                .collect(),
+            recent_releases: snapshot
+                .releases
+                .iter()
+                .map(|r| ReleaseJson {
+                    branch: r.changeset.branch.clone(),
+                    applied_at: r.release_info.applied_at.to_rfc3339(),
+                    versions: r.release_info.versions.clone().into_iter().collect(),
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Multi-root command execution.
+//!
+//! This module runs a command once per workspace root of a meta-repository, for the global
+//! `--all-roots` flag.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_for_roots` - Runs a command in every root listed in the `roots` configuration and
+//!   groups the results per root
+//!
+//! # How
+//!
+//! The roots come from the configuration of the meta-repository. A root with its own
+//! `repo.config.*` file uses it; other roots use the meta-repository configuration. Roots run
+//! one after another and a failing root does not stop the others:
+//! - Human output prints a heading per root followed by the command's usual output
+//! - JSON output captures each root's response and emits a single response with one entry
+//!   per root, holding either the command's `data` or its error
+//!
+//! The command fails after all roots ran if any of them failed.
+//!
+//! # Why
+//!
+//! A meta-repository holding several independent workspaces otherwise needs one invocation
+//! per workspace, and scripts have to merge the results themselves.
+
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output, OutputFormat};
+use serde_json::Value;
+use std::future::Future;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use sublime_pkg_tools::workspace::WorkspaceSet;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+use tracing::debug;
+
+/// Default configuration file names, in lookup order.
+const CONFIG_FILES: [&str; 4] =
+    ["repo.config.toml", "repo.config.json", "repo.config.yaml", "repo.config.yml"];
+
+/// Runs a command in every workspace root of a meta-repository.
+///
+/// # Arguments
+///
+/// * `output` - Output context for the combined result
+/// * `root` - Root directory of the meta-repository
+/// * `config_path` - Optional path to the meta-repository config file
+/// * `command` - The command, given an output context, the workspace root, and the config
+///   file to use for it
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The meta-repository configuration cannot be loaded or lists no roots
+/// - The command fails in any root
+pub async fn execute_for_roots<F, Fut>(
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+    command: F,
+) -> Result<()>
+where
+    F: Fn(Output, PathBuf, Option<PathBuf>) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let config = super::find_and_load_config(root, config_path).await?.ok_or_else(|| {
+        CliError::configuration(
+            "Workspace not initialized. Run 'workspace init' and list the workspace roots \
+             as [[roots]] entries to use --all-roots.",
+        )
+    })?;
+    if config.roots.is_empty() {
+        return Err(CliError::configuration(
+            "No workspace roots configured. List them as [[roots]] entries to use --all-roots.",
+        ));
+    }
+
+    let fs = FileSystemManager::new();
+    let meta_config = match config_path {
+        Some(path) => Some(root.join(path)),
+        None => find_config_file(&fs, root).await,
+    };
+    let set = WorkspaceSet::from_config(root, &config.roots);
+    let capture = output.format().is_json();
+    let command = &command;
+    let fs = &fs;
+    let meta_config = meta_config.as_deref();
+
+    let report = set
+        .run(|workspace| async move {
+            // A root's own configuration takes precedence over the meta-repository's
+            let config = match find_config_file(fs, &workspace.path).await {
+                Some(_) => None,
+                None => meta_config.map(Path::to_path_buf),
+            };
+            debug!(
+                "Running in workspace root '{}' with config {:?}",
+                workspace.name,
+                config.as_deref().map(Path::display)
+            );
+
+            if capture {
+                let buffer = Arc::new(Mutex::new(Vec::new()));
+                let captured = Output::new(
+                    OutputFormat::Json,
+                    SharedBuffer(Arc::clone(&buffer)),
+                    output.no_color(),
+                );
+                command(captured, workspace.path, config).await?;
+
+                let bytes = buffer.lock().unwrap_or_else(PoisonError::into_inner).clone();
+                let response: Value = serde_json::from_slice(&bytes).map_err(|e| {
+                    CliError::execution(format!("Failed to parse root output: {e}"))
+                })?;
+                Ok::<_, CliError>(response.get("data").cloned().unwrap_or(response))
+            } else {
+                output.blank_line()?;
+                output.info(&format!(
+                    "Workspace root: {} ({})",
+                    workspace.name,
+                    workspace.path.display()
+                ))?;
+                output.flush()?;
+                let root_output =
+                    Output::new(output.format(), std::io::stdout(), output.no_color());
+                command(root_output, workspace.path, config).await?;
+                Ok::<_, CliError>(Value::Null)
+            }
+        })
+        .await;
+
+    let failed: Vec<&str> = report.failed().map(|outcome| outcome.name.as_str()).collect();
+    if capture {
+        output.json(&JsonResponse::success(&report))?;
+    } else {
+        output.blank_line()?;
+        for outcome in report.failed() {
+            output.error(&format!(
+                "{}: {}",
+                outcome.name,
+                outcome.error.as_deref().unwrap_or_default()
+            ))?;
+        }
+        if failed.is_empty() {
+            output.success(&format!("Completed in {} workspace roots", report.roots.len()))?;
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CliError::execution(format!(
+            "{} of {} workspace roots failed: {}",
+            failed.len(),
+            report.roots.len(),
+            failed.join(", ")
+        )))
+    }
+}
+
+/// Finds the default configuration file of a workspace.
+async fn find_config_file(fs: &FileSystemManager, root: &Path) -> Option<PathBuf> {
+    for name in CONFIG_FILES {
+        let path = root.join(name);
+        if fs.exists(&path).await {
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// Writer collecting a root's output for the combined JSON response.
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
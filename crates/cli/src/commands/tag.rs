@@ -0,0 +1,236 @@
+//! Tag command implementation.
+//!
+//! This module implements the `workspace tag` command which creates the release tags missing
+//! for versions the workspace has already released.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_tag` - Lists the missing release tags and creates them at the commits that
+//!   released each version, optionally pushing them
+//!
+//! # How
+//!
+//! The command discovers the workspace packages and loads the archived changesets, then asks
+//! `ReleaseTagBackfill` from `sublime_pkg_tools` for a plan: one tag per version each
+//! `package.json` held, at the commit that first set it, named with the configured tag
+//! formats. Existing tags are skipped. After confirmation (unless `--force` or `--dry-run`)
+//! the tags are created and, with `--push`, pushed to `origin`.
+//!
+//! # Why
+//!
+//! Release baselines are resolved from tags. Repositories adopting the tool often released by
+//! hand without per-package tags, so every package would look unreleased and its first
+//! changelog would cover the whole history.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # Show the tags that would be created
+//! workspace tag --dry-run
+//!
+//! # Create and push the missing tags of two packages
+//! workspace tag --packages @org/core,@org/utils --push
+//! ```
+
+use crate::cli::commands::TagArgs;
+use crate::commands::bump::git_integration::push_tags_to_remote;
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::interactive::prompts::prompt_confirm;
+use crate::output::table::{TableBuilder, TableTheme};
+use crate::output::{JsonResponse, Output};
+use serde::Serialize;
+use std::path::Path;
+use sublime_git_tools::Repo;
+use sublime_pkg_tools::changelog::{
+    MissingReleaseTag, ReleaseTagBackfill, TagBackfillPlan, UnresolvedRelease,
+};
+use sublime_pkg_tools::changeset::{ChangesetHistory, FileBasedChangesetStorage};
+use sublime_pkg_tools::version::VersionResolver;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Executes the `tag` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration, Git repository, or changeset history cannot be read
+/// - Workspace packages cannot be discovered
+/// - `--packages` names a package that is not in the workspace
+/// - A tag cannot be created or the tags cannot be pushed
+pub async fn execute_tag(
+    args: &TagArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+    let repo =
+        Repo::open(root.to_str().ok_or_else(|| {
+            CliError::execution("Workspace path contains invalid UTF-8".to_string())
+        })?)
+        .map_err(|e| CliError::git(format!("Failed to open Git repository: {e}")))?;
+
+    let resolver = VersionResolver::new(root.to_path_buf(), config.clone())
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
+    let mut packages = resolver
+        .discover_packages()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?;
+    if let Some(selected) = &args.packages {
+        if let Some(unknown) =
+            selected.iter().find(|name| packages.iter().all(|package| package.name() != *name))
+        {
+            return Err(CliError::validation(format!("'{unknown}' is not a workspace package")));
+        }
+        packages.retain(|package| selected.iter().any(|name| name == package.name()));
+    }
+
+    let storage = FileBasedChangesetStorage::new(
+        root.to_path_buf(),
+        config.changeset.path.clone(),
+        config.changeset.history_path.clone(),
+        FileSystemManager::new(),
+    );
+    let archived = ChangesetHistory::new(Box::new(storage))
+        .list_all()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to read changeset history: {e}")))?;
+
+    let backfill = ReleaseTagBackfill::new(&repo, &config.changelog);
+    let plan = backfill
+        .plan(&packages, &archived)
+        .map_err(|e| CliError::git(format!("Failed to find missing release tags: {e}")))?;
+    debug!(
+        "{} missing, {} existing, {} unresolved release tags",
+        plan.missing.len(),
+        plan.existing.len(),
+        plan.unresolved.len()
+    );
+
+    if plan.missing.is_empty() || args.dry_run {
+        return output_result(output, &plan, &[], args.dry_run, false);
+    }
+
+    if !args.force && output.format().is_human() {
+        output_plan(output, &plan)?;
+        let prompt = format!("Create {} tag(s)?", plan.missing.len());
+        if !prompt_confirm(&prompt, true, output.no_color())? {
+            output.info("Tagging cancelled")?;
+            return Ok(());
+        }
+    }
+
+    info!("Creating {} release tags", plan.missing.len());
+    let created = backfill
+        .apply(&plan)
+        .map_err(|e| CliError::git(format!("Failed to create release tags: {e}")))?;
+
+    if args.push {
+        push_tags_to_remote(&repo)?;
+    }
+
+    output_result(output, &plan, &created, false, args.push)
+}
+
+/// Shows the tags about to be created and the releases that cannot be tagged.
+fn output_plan(output: &Output, plan: &TagBackfillPlan) -> Result<()> {
+    let mut table = TableBuilder::new()
+        .theme(TableTheme::Minimal)
+        .columns(&["Tag", "Commit", "Recorded"])
+        .build();
+    for tag in &plan.missing {
+        let commit = tag.commit.get(..7).unwrap_or(&tag.commit);
+        table.add_row(&[&tag.tag_name, commit, if tag.recorded { "yes" } else { "no" }]);
+    }
+    output.table(&mut table)?;
+
+    for release in &plan.unresolved {
+        output.warning(&format!(
+            "{}@{} is recorded as released, but no commit sets that version in its package.json",
+            release.package_name, release.version
+        ))?;
+    }
+    output.blank_line()
+}
+
+/// Renders the outcome of the command.
+fn output_result(
+    output: &Output,
+    plan: &TagBackfillPlan,
+    created: &[String],
+    dry_run: bool,
+    pushed: bool,
+) -> Result<()> {
+    if output.format().is_json() {
+        return output.json(&JsonResponse::success(TagJson {
+            dry_run,
+            missing: plan.missing.clone(),
+            created: created.to_vec(),
+            existing: plan.existing.len(),
+            unresolved: plan.unresolved.clone(),
+            pushed,
+        }));
+    }
+
+    if output.format().is_quiet() {
+        for tag in created {
+            output.plain(tag)?;
+        }
+        return Ok(());
+    }
+
+    if plan.missing.is_empty() {
+        output.success(&format!(
+            "No release tags missing ({} already tagged)",
+            plan.existing.len()
+        ))?;
+        for release in &plan.unresolved {
+            output.warning(&format!(
+                "{}@{} is recorded as released, but no commit sets that version in its \
+                 package.json",
+                release.package_name, release.version
+            ))?;
+        }
+        return Ok(());
+    }
+
+    if dry_run {
+        output_plan(output, plan)?;
+        return output.info("This was a dry run. No tags were created.");
+    }
+
+    output.success(&format!("Created {} release tag(s)", created.len()))?;
+    if pushed {
+        output.plain("Tags pushed to origin")?;
+    }
+    Ok(())
+}
+
+/// JSON response for `tag`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TagJson {
+    /// Whether the tags were only listed.
+    dry_run: bool,
+    /// Release tags missing before the command ran.
+    missing: Vec<MissingReleaseTag>,
+    /// Tags created.
+    created: Vec<String>,
+    /// Number of release tags that already existed.
+    existing: usize,
+    /// Archived releases no commit could be found for.
+    unresolved: Vec<UnresolvedRelease>,
+    /// Whether the tags were pushed to `origin`.
+    pushed: bool,
+}
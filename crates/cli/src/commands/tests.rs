@@ -148,6 +148,8 @@ mod init_tests {
             strategy: None,
             registry: "https://registry.npmjs.org".to_string(),
             config_format: None,
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -170,6 +172,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -224,6 +228,8 @@ mod init_tests {
             strategy: Some("unified".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("yaml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -252,6 +258,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("json".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -284,6 +292,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -310,6 +320,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: true,
             non_interactive: true,
         };
@@ -336,6 +348,8 @@ mod init_tests {
             strategy: Some("invalid-strategy".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -358,6 +372,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("xml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -380,6 +396,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -402,6 +420,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "invalid-url".to_string(), // missing protocol
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -424,6 +444,8 @@ mod init_tests {
             strategy: None,     // should default based on workspace type
             registry: "https://registry.npmjs.org".to_string(), // default value
             config_format: None, // should default to toml
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -457,6 +479,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -484,6 +508,8 @@ mod init_tests {
             strategy: Some("unified".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -508,6 +534,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -529,6 +557,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -557,6 +587,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -578,6 +610,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -624,6 +658,8 @@ mod init_tests {
             strategy: None, // Let it auto-detect
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -667,6 +703,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -708,6 +746,8 @@ mod init_tests {
             strategy: Some("unified".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -750,6 +790,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -788,6 +830,8 @@ mod init_tests {
             strategy: Some("independent".to_string()),
             registry: "https://registry.npmjs.org".to_string(),
             config_format: Some("toml".to_string()),
+            template: None,
+            eject_template: None,
             force: false,
             non_interactive: true,
         };
@@ -818,6 +862,165 @@ mod init_tests {
         assert!(gitignore_content.contains(".workspace-backups/"));
         assert!(gitignore_content.contains("Workspace Tools"));
     }
+
+    // ============================================================================
+    // Tests for Init Templates
+    // ============================================================================
+
+    /// Helper to build non-interactive args that only select a template
+    fn template_args(template: &str) -> InitArgs {
+        InitArgs {
+            changeset_path: PathBuf::from(".changesets"),
+            environments: None,
+            default_env: None,
+            strategy: None,
+            registry: "https://registry.npmjs.org".to_string(),
+            config_format: Some("toml".to_string()),
+            template: Some(template.to_string()),
+            eject_template: None,
+            force: false,
+            non_interactive: true,
+        }
+    }
+
+    /// Helper to read the generated TOML configuration
+    fn read_config(temp_dir: &TempDir) -> sublime_pkg_tools::config::PackageToolsConfig {
+        let content = fs::read_to_string(temp_dir.path().join("repo.config.toml"))
+            .expect("Failed to read config");
+        toml::from_str(&content).expect("Failed to parse config")
+    }
+
+    #[tokio::test]
+    async fn test_init_applies_template() {
+        let temp_dir = create_test_monorepo_empty();
+
+        let result =
+            execute_init(&template_args("design-system"), temp_dir.path(), OutputFormat::Quiet)
+                .await;
+        assert!(result.is_ok(), "Init with template failed: {result:?}");
+
+        let config = read_config(&temp_dir);
+        assert_eq!(config.version.strategy, sublime_pkg_tools::types::VersioningStrategy::Unified);
+        assert_eq!(config.changeset.available_environments, vec!["canary", "production"]);
+        assert_eq!(config.changeset.default_environments, vec!["production"]);
+        assert_eq!(config.changelog.monorepo_mode, sublime_pkg_tools::config::MonorepoMode::Both);
+        assert!(config.changelog.include_authors);
+        assert_eq!(
+            config.hooks.hooks.get("pre-push").map(String::as_str),
+            Some("workspace changeset check")
+        );
+        // The monorepo declares no patterns, so the template's are used
+        assert_eq!(config.workspace.map(|w| w.patterns), Some(vec!["packages/*".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_init_builtin_templates_apply() {
+        for template in ["library-monorepo", "app-monorepo", "single-package", "design-system"] {
+            let temp_dir = create_test_monorepo();
+            let result =
+                execute_init(&template_args(template), temp_dir.path(), OutputFormat::Quiet).await;
+            assert!(result.is_ok(), "Init with template {template} failed: {result:?}");
+            read_config(&temp_dir);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_flags_override_template() {
+        let temp_dir = create_test_monorepo();
+
+        let args = InitArgs {
+            strategy: Some("independent".to_string()),
+            environments: Some(vec!["dev".to_string(), "prod".to_string()]),
+            default_env: Some(vec!["prod".to_string()]),
+            ..template_args("app-monorepo")
+        };
+        let result = execute_init(&args, temp_dir.path(), OutputFormat::Quiet).await;
+        assert!(result.is_ok(), "Init with template failed: {result:?}");
+
+        let config = read_config(&temp_dir);
+        assert_eq!(
+            config.version.strategy,
+            sublime_pkg_tools::types::VersioningStrategy::Independent
+        );
+        assert_eq!(config.changeset.available_environments, vec!["dev", "prod"]);
+        assert_eq!(config.changelog.monorepo_mode, sublime_pkg_tools::config::MonorepoMode::Root);
+        // Patterns declared in package.json take precedence over the template's
+        assert_eq!(config.workspace.map(|w| w.patterns), Some(vec!["packages/*".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_init_single_package_ignores_template_workspace() {
+        let temp_dir = create_test_workspace();
+
+        let result =
+            execute_init(&template_args("library-monorepo"), temp_dir.path(), OutputFormat::Quiet)
+                .await;
+        assert!(result.is_ok(), "Init with template failed: {result:?}");
+
+        assert!(read_config(&temp_dir).workspace.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_init_eject_and_reuse_template() {
+        let temp_dir = create_test_workspace();
+
+        let eject = InitArgs {
+            template: None,
+            eject_template: Some("single-package".to_string()),
+            ..template_args("single-package")
+        };
+        let result = execute_init(&eject, temp_dir.path(), OutputFormat::Quiet).await;
+        assert!(result.is_ok(), "Eject failed: {result:?}");
+
+        let template_path = temp_dir.path().join("single-package.template.toml");
+        assert!(template_path.exists(), "Template file not written");
+        assert!(!temp_dir.path().join("repo.config.toml").exists(), "Eject must not init");
+
+        // Ejecting again requires --force
+        let result = execute_init(&eject, temp_dir.path(), OutputFormat::Quiet).await;
+        assert!(result.is_err());
+
+        // Customize the ejected template and use it
+        let content = fs::read_to_string(&template_path).expect("Failed to read template");
+        let content = content.replace(
+            r#"available_environments = ["production"]"#,
+            r#"available_environments = ["preview", "production"]"#,
+        );
+        fs::write(&template_path, content).expect("Failed to write template");
+
+        let result = execute_init(
+            &template_args("single-package.template.toml"),
+            temp_dir.path(),
+            OutputFormat::Quiet,
+        )
+        .await;
+        assert!(result.is_ok(), "Init with ejected template failed: {result:?}");
+        assert_eq!(
+            read_config(&temp_dir).changeset.available_environments,
+            vec!["preview", "production"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_init_rejects_unknown_or_invalid_template() {
+        let temp_dir = create_test_workspace();
+
+        let result =
+            execute_init(&template_args("microservices"), temp_dir.path(), OutputFormat::Quiet)
+                .await;
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("repo.config.toml").exists());
+
+        fs::write(
+            temp_dir.path().join("broken.toml"),
+            "[template]\nname = \"broken\"\n\n[version]\nstrategy = \"sideways\"\n",
+        )
+        .expect("Failed to write template");
+        let result =
+            execute_init(&template_args("broken.toml"), temp_dir.path(), OutputFormat::Quiet).await;
+        assert!(result.is_err());
+        assert!(!temp_dir.path().join("repo.config.toml").exists());
+    }
 }
 
 #[cfg(test)]
@@ -825,8 +1028,10 @@ mod init_tests {
 #[allow(clippy::unwrap_used)]
 mod changes_tests {
     use crate::cli::commands::ChangesArgs;
-    use crate::commands::changes::{AnalysisMode, determine_mode, format_change_types};
-    use sublime_pkg_tools::changes::PackageChangeStats;
+    use crate::commands::changes::{
+        AnalysisMode, build_query, change_type_code, determine_mode, format_change_types,
+    };
+    use sublime_pkg_tools::changes::{FileChangeType, FileClassCounts, PackageChangeStats};
 
     #[test]
     fn test_determine_mode_working_directory_default() {
@@ -837,6 +1042,8 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -852,6 +1059,8 @@ mod changes_tests {
             staged: true,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -867,6 +1076,8 @@ mod changes_tests {
             staged: false,
             unstaged: true,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -882,6 +1093,8 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -900,6 +1113,8 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -910,7 +1125,7 @@ mod changes_tests {
     }
 
     #[test]
-    fn test_determine_mode_commit_range_with_until_only() {
+    fn test_determine_mode_since_last_release_with_until_only() {
         let args = ChangesArgs {
             since: None,
             until: Some("develop".to_string()),
@@ -918,13 +1133,60 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
-        assert_eq!(
-            mode,
-            AnalysisMode::CommitRange { from: "HEAD~1".to_string(), to: "develop".to_string() }
-        );
+        assert_eq!(mode, AnalysisMode::SinceLastRelease { to: "develop".to_string() });
+    }
+
+    #[test]
+    fn test_determine_mode_include_uncommitted_only() {
+        let args = ChangesArgs {
+            since: None,
+            until: None,
+            branch: None,
+            staged: false,
+            unstaged: false,
+            packages: None,
+            paths: None,
+            include_uncommitted: true,
+        };
+
+        let mode = determine_mode(&args);
+        assert_eq!(mode, AnalysisMode::SinceLastRelease { to: "HEAD".to_string() });
+    }
+
+    #[test]
+    fn test_build_query_from_args() {
+        let args = ChangesArgs {
+            since: Some("v1.0.0".to_string()),
+            until: Some("abc1234".to_string()),
+            branch: None,
+            staged: false,
+            unstaged: false,
+            packages: None,
+            paths: Some(vec!["packages/core".to_string(), "**/*.ts".to_string()]),
+            include_uncommitted: true,
+        };
+
+        let query = build_query(&args, Some("v1.0.0"), "abc1234");
+        assert_eq!(query.from_ref.as_deref(), Some("v1.0.0"));
+        assert_eq!(query.to(), "abc1234");
+        assert_eq!(query.paths, vec!["packages/core", "**/*.ts"]);
+        assert!(query.include_uncommitted);
+
+        let query = build_query(&args, None, "HEAD");
+        assert!(query.from_ref.is_none());
+    }
+
+    #[test]
+    fn test_change_type_code() {
+        assert_eq!(change_type_code(FileChangeType::Added), 'A');
+        assert_eq!(change_type_code(FileChangeType::Modified), 'M');
+        assert_eq!(change_type_code(FileChangeType::Deleted), 'D');
+        assert_eq!(change_type_code(FileChangeType::Untracked), '?');
     }
 
     #[test]
@@ -936,6 +1198,8 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -951,6 +1215,8 @@ mod changes_tests {
             staged: false,
             unstaged: false,
             packages: None,
+            paths: None,
+            include_uncommitted: false,
         };
 
         let mode = determine_mode(&args);
@@ -967,6 +1233,7 @@ mod changes_tests {
             commits: 2,
             lines_added: 150,
             lines_deleted: 75,
+            classes: FileClassCounts::default(),
         };
 
         let result = format_change_types(&stats);
@@ -983,6 +1250,7 @@ mod changes_tests {
             commits: 1,
             lines_added: 50,
             lines_deleted: 20,
+            classes: FileClassCounts::default(),
         };
 
         let result = format_change_types(&stats);
@@ -999,6 +1267,7 @@ mod changes_tests {
             commits: 1,
             lines_added: 100,
             lines_deleted: 0,
+            classes: FileClassCounts::default(),
         };
 
         let result = format_change_types(&stats);
@@ -1015,6 +1284,7 @@ mod changes_tests {
             commits: 0,
             lines_added: 0,
             lines_deleted: 0,
+            classes: FileClassCounts::default(),
         };
 
         let result = format_change_types(&stats);
@@ -1060,7 +1330,8 @@ mod changes_tests {
         use chrono::Utc;
         use std::path::PathBuf;
         use sublime_pkg_tools::changes::{
-            AnalysisMode, ChangesReport, ChangesSummary, PackageChangeStats, PackageChanges,
+            AnalysisMode, ChangesReport, ChangesSummary, FileClassCounts, PackageChangeStats,
+            PackageChanges,
         };
         use sublime_standard_tools::monorepo::WorkspacePackage;
 
@@ -1097,6 +1368,7 @@ mod changes_tests {
                     commits: 1,
                     lines_added: 50,
                     lines_deleted: 10,
+                    classes: FileClassCounts::default(),
                 },
             }],
             summary: ChangesSummary {
@@ -1109,6 +1381,8 @@ mod changes_tests {
                 total_lines_deleted: 10,
             },
             is_monorepo: true,
+            unattributed_files: vec![],
+            warnings: vec![],
         };
 
         let filter_names = vec!["nonexistent".to_string()];
@@ -1257,7 +1531,7 @@ mod config_tests {
         let temp_dir = create_test_workspace();
         // Don't create config file
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_err(), "Config validate should fail without config file");
@@ -1273,7 +1547,7 @@ mod config_tests {
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "toml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_ok(), "Config validate should pass with valid TOML config: {result:?}");
@@ -1284,7 +1558,7 @@ mod config_tests {
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "json");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_ok(), "Config validate should pass with valid JSON config: {result:?}");
@@ -1295,7 +1569,7 @@ mod config_tests {
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "yaml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_ok(), "Config validate should pass with valid YAML config: {result:?}");
@@ -1379,7 +1653,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_err(), "Config validate should fail with empty environments");
@@ -1459,7 +1733,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(
@@ -1542,7 +1816,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_err(), "Config validate should fail with invalid registry URL");
@@ -1622,7 +1896,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_err(), "Config validate should fail with invalid bump type");
@@ -1702,7 +1976,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(
@@ -1716,7 +1990,7 @@ version_consistency = true
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "toml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Human).await;
 
         assert!(result.is_ok(), "Config validate in human format failed: {result:?}");
@@ -1727,7 +2001,7 @@ version_consistency = true
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "toml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Json).await;
 
         assert!(result.is_ok(), "Config validate in JSON format failed: {result:?}");
@@ -1738,7 +2012,7 @@ version_consistency = true
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "toml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result =
             execute_validate(&args, temp_dir.path(), None, OutputFormat::JsonCompact).await;
 
@@ -1750,7 +2024,7 @@ version_consistency = true
         let temp_dir = create_test_workspace();
         create_config_file(&temp_dir, "toml");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(result.is_ok(), "Config validate in quiet format failed: {result:?}");
@@ -1830,7 +2104,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("repo.config.toml"), config)
             .expect("Failed to write config");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(
@@ -1846,7 +2120,7 @@ version_consistency = true
         // Create the changeset directory
         fs::create_dir(temp_dir.path().join(".changesets")).expect("Failed to create directory");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let result = execute_validate(&args, temp_dir.path(), None, OutputFormat::Quiet).await;
 
         assert!(
@@ -1898,7 +2172,7 @@ version_consistency = true
         fs::write(temp_dir.path().join("my-config.toml"), config_content)
             .expect("Failed to write custom config file");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let custom_path = temp_dir.path().join("my-config.toml");
         let result =
             execute_validate(&args, temp_dir.path(), Some(&custom_path), OutputFormat::Quiet).await;
@@ -1910,7 +2184,7 @@ version_consistency = true
     async fn test_config_validate_fails_with_nonexistent_custom_config() {
         let temp_dir = create_test_workspace();
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let custom_path = temp_dir.path().join("missing.toml");
         let result =
             execute_validate(&args, temp_dir.path(), Some(&custom_path), OutputFormat::Quiet).await;
@@ -1935,7 +2209,7 @@ version_consistency = true
         fs::write(config_dir.join("repo.config.toml"), config_content)
             .expect("Failed to write config file");
 
-        let args = ConfigValidateArgs {};
+        let args = ConfigValidateArgs { explain: None };
         let custom_path = temp_dir.path().join("config/repo.config.toml");
         let result =
             execute_validate(&args, temp_dir.path(), Some(&custom_path), OutputFormat::Quiet).await;
@@ -3212,3 +3486,388 @@ custom/backups/
         assert!(init_args_non_interactive.non_interactive);
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+mod monitor_tests {
+    use crate::commands::monitor::{
+        MonitorAction, MonitorSnapshot, action_for_key, format_age, render_lines,
+    };
+    use chrono::Utc;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use std::path::PathBuf;
+    use std::time::Duration;
+    use sublime_pkg_tools::audit::{
+        GraphMetrics, OutdatedDependencyCounts, PendingChangesetStats, WorkspaceDashboard,
+    };
+    use sublime_pkg_tools::types::{Changeset, VersionBump};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn snapshot(
+        pending: Vec<Changeset>,
+        outdated: Option<OutdatedDependencyCounts>,
+    ) -> MonitorSnapshot {
+        let now = Utc::now();
+        MonitorSnapshot {
+            dashboard: WorkspaceDashboard {
+                generated_at: now,
+                workspace_root: PathBuf::from("/workspace"),
+                is_monorepo: true,
+                package_count: 3,
+                dirty_packages: vec!["@org/core".to_string()],
+                changesets: PendingChangesetStats::from_changesets(&pending, now),
+                outdated,
+                graph: GraphMetrics::default(),
+            },
+            pending,
+            releases: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_action_for_key_bindings() {
+        assert_eq!(action_for_key(&key(KeyCode::Char('q'))), Some(MonitorAction::Quit));
+        assert_eq!(action_for_key(&key(KeyCode::Esc)), Some(MonitorAction::Quit));
+        assert_eq!(action_for_key(&key(KeyCode::Char('r'))), Some(MonitorAction::Refresh));
+        assert_eq!(action_for_key(&key(KeyCode::Char('a'))), Some(MonitorAction::AddChangeset));
+        assert_eq!(action_for_key(&key(KeyCode::Char('p'))), Some(MonitorAction::BumpPlan));
+        assert_eq!(action_for_key(&key(KeyCode::Char('x'))), None);
+    }
+
+    #[test]
+    fn test_action_for_key_ctrl_c_quits() {
+        let event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        assert_eq!(action_for_key(&event), Some(MonitorAction::Quit));
+        assert_eq!(action_for_key(&key(KeyCode::Char('c'))), None);
+    }
+
+    #[test]
+    fn test_format_age_uses_largest_unit() {
+        assert_eq!(format_age(Duration::from_secs(30)), "30s");
+        assert_eq!(format_age(Duration::from_secs(5 * 60)), "5m");
+        assert_eq!(format_age(Duration::from_secs(3 * 3_600)), "3h");
+        assert_eq!(format_age(Duration::from_secs(2 * 86_400 + 10)), "2d");
+    }
+
+    #[test]
+    fn test_render_lines_lists_pending_changesets() {
+        let mut changeset =
+            Changeset::new("feature/login", VersionBump::Minor, vec!["production".to_string()]);
+        changeset.add_package("@org/core");
+
+        let lines = render_lines(&snapshot(vec![changeset], None));
+
+        assert!(lines.iter().any(|l| l.starts_with("Pending changesets (1, oldest")));
+        assert!(lines.iter().any(|l| l.contains("feature/login") && l.contains("@org/core")));
+        assert!(lines.iter().any(|l| l == "Outdated: upgrade audit disabled"));
+        assert!(lines.iter().any(|l| l == "Dirty packages"));
+    }
+
+    #[test]
+    fn test_render_lines_without_pending_changesets() {
+        let outdated = OutdatedDependencyCounts { major: 1, minor: 2, patch: 3, deprecated: 0 };
+        let lines = render_lines(&snapshot(Vec::new(), Some(outdated)));
+
+        assert!(lines.iter().any(|l| l == "Pending changesets (0)"));
+        assert!(lines.iter().any(|l| l == "Outdated: 1 major, 2 minor, 3 patch, 0 deprecated"));
+        assert!(lines.iter().any(|l| l == "Recent releases (0)"));
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::panic)]
+mod undo_tests {
+    use crate::cli::commands::UndoArgs;
+    use crate::commands::undo::{execute_undo, filter_entries};
+    use crate::output::{Output, OutputFormat};
+    use chrono::Utc;
+    use std::io::Cursor;
+    use std::path::PathBuf;
+    use sublime_pkg_tools::config::BackupConfig;
+    use sublime_pkg_tools::workspace::{OperationKind, TransactionEntry, TransactionLog};
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    fn entry(id: &str, kind: OperationKind) -> TransactionEntry {
+        TransactionEntry {
+            id: id.to_string(),
+            kind,
+            created_at: Utc::now(),
+            files: vec![],
+            created_files: vec![],
+            committed: true,
+        }
+    }
+
+    fn args(id: Option<&str>, kind: Option<&str>, force: bool) -> UndoArgs {
+        UndoArgs {
+            id: id.map(str::to_string),
+            list: false,
+            kind: kind.map(str::to_string),
+            limit: 10,
+            force,
+        }
+    }
+
+    fn json_output() -> Output {
+        Output::new(OutputFormat::Json, Cursor::new(Vec::new()), false)
+    }
+
+    async fn record(
+        temp: &TempDir,
+        kind: OperationKind,
+        file: &str,
+        before: &str,
+        after: &str,
+    ) -> (PathBuf, String) {
+        let log = TransactionLog::new(
+            temp.path().to_path_buf(),
+            BackupConfig::default(),
+            FileSystemManager::new(),
+        );
+        let path = temp.path().join(file);
+        std::fs::write(&path, before).unwrap();
+        let id = log.begin(kind, std::slice::from_ref(&path)).await.unwrap();
+        std::fs::write(&path, after).unwrap();
+        log.commit(&id).await.unwrap();
+        (path, id)
+    }
+
+    #[test]
+    fn test_filter_entries_by_kind_and_limit() {
+        let entries = vec![
+            entry("3", OperationKind::VersionApply),
+            entry("2", OperationKind::UpgradeApply),
+            entry("1", OperationKind::VersionApply),
+        ];
+
+        let bumps = filter_entries(entries.clone(), Some(&OperationKind::VersionApply), 10);
+        assert_eq!(bumps.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(), vec!["3", "1"]);
+
+        let limited = filter_entries(entries, None, 2);
+        assert_eq!(limited.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_undo_last_bump_restores_file() {
+        let temp = TempDir::new().unwrap();
+        let (manifest, _) =
+            record(&temp, OperationKind::VersionApply, "package.json", "1.0.0", "1.1.0").await;
+
+        execute_undo(&args(None, Some("bump"), true), &json_output(), temp.path(), None)
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read_to_string(manifest).unwrap(), "1.0.0");
+    }
+
+    #[tokio::test]
+    async fn test_undo_requires_force_when_superseded() {
+        let temp = TempDir::new().unwrap();
+        let (_, first) =
+            record(&temp, OperationKind::VersionApply, "package.json", "1.0.0", "1.1.0").await;
+        record(&temp, OperationKind::UpgradeApply, "package.json", "1.1.0", "1.1.0-deps").await;
+
+        let result =
+            execute_undo(&args(Some(&first), None, false), &json_output(), temp.path(), None).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_undo_rejects_kind_mismatch() {
+        let temp = TempDir::new().unwrap();
+        let (_, id) =
+            record(&temp, OperationKind::UpgradeApply, "package.json", "1.0.0", "1.1.0").await;
+
+        let result =
+            execute_undo(&args(Some(&id), Some("bump"), true), &json_output(), temp.path(), None)
+                .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_undo_without_operations_fails() {
+        let temp = TempDir::new().unwrap();
+
+        let result = execute_undo(&args(None, None, true), &json_output(), temp.path(), None).await;
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::panic)]
+mod backup_tests {
+    use crate::cli::commands::BackupPruneArgs;
+    use crate::commands::upgrade::rollback::prune_config;
+    use sublime_pkg_tools::config::BackupConfig;
+
+    fn args(
+        keep: Option<u64>,
+        max_age_days: Option<u32>,
+        max_size_mb: Option<u64>,
+    ) -> BackupPruneArgs {
+        BackupPruneArgs { keep, max_age_days, max_size_mb, dry_run: false, force: false }
+    }
+
+    #[test]
+    fn test_prune_config_keeps_configured_limits() {
+        let config = BackupConfig {
+            max_backups: 7,
+            max_age_days: Some(14),
+            max_total_size_mb: Some(50),
+            keep_after_success: false,
+            ..BackupConfig::default()
+        };
+
+        let pruned = prune_config(&config, &args(None, None, None));
+
+        assert_eq!(pruned.max_backups, 7);
+        assert_eq!(pruned.max_age_days, Some(14));
+        assert_eq!(pruned.max_total_size_mb, Some(50));
+        assert!(pruned.keep_after_success);
+    }
+
+    #[test]
+    fn test_prune_config_applies_overrides() {
+        let pruned = prune_config(&BackupConfig::default(), &args(Some(3), Some(30), Some(100)));
+
+        assert_eq!(pruned.max_backups, 3);
+        assert_eq!(pruned.max_age_days, Some(30));
+        assert_eq!(pruned.max_total_size_mb, Some(100));
+    }
+}
+
+// =============================================================================
+// Workspace Roots Tests
+// =============================================================================
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+mod roots_tests {
+    use crate::commands::roots::execute_for_roots;
+    use crate::error::CliError;
+    use crate::output::{JsonResponse, Output, OutputFormat};
+    use std::io::Write;
+    use std::path::Path;
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
+
+    #[derive(Clone)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn meta_repository() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("repo.config.toml"),
+            r#"
+[[roots]]
+path = "frontend"
+
+[[roots]]
+path = "services/api"
+name = "api"
+"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(temp.path().join("frontend")).unwrap();
+        std::fs::create_dir_all(temp.path().join("services/api")).unwrap();
+        std::fs::write(temp.path().join("services/api/repo.config.toml"), "[changeset]\n").unwrap();
+        temp
+    }
+
+    #[tokio::test]
+    async fn test_groups_json_results_per_root() {
+        let temp = meta_repository();
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let output = Output::new(OutputFormat::Json, BufferWriter(Arc::clone(&buffer)), true);
+
+        let result =
+            execute_for_roots(&output, temp.path(), None, |output, root, config| async move {
+                let name = root.file_name().unwrap().to_string_lossy().to_string();
+                let response = serde_json::json!({
+                    "root": name,
+                    "ownConfig": config.is_none(),
+                });
+                output.json(&JsonResponse::success(response))
+            })
+            .await;
+        assert!(result.is_ok());
+
+        let json: serde_json::Value = serde_json::from_slice(&buffer.lock().unwrap()).unwrap();
+        let roots = json["data"]["roots"].as_array().unwrap();
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots[0]["name"], "frontend");
+        assert_eq!(roots[0]["result"]["root"], "frontend");
+        assert_eq!(roots[0]["result"]["ownConfig"], false);
+        assert_eq!(roots[1]["name"], "api");
+        assert_eq!(roots[1]["result"]["ownConfig"], true);
+    }
+
+    #[tokio::test]
+    async fn test_failing_root_does_not_stop_others() {
+        let temp = meta_repository();
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let output = Output::new(OutputFormat::Json, BufferWriter(Arc::clone(&buffer)), true);
+
+        let result =
+            execute_for_roots(&output, temp.path(), None, |output, root, _config| async move {
+                if root.ends_with("frontend") {
+                    return Err(CliError::execution("frontend is broken"));
+                }
+                output.json(&JsonResponse::success(serde_json::json!({ "ok": true })))
+            })
+            .await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(error.contains("1 of 2 workspace roots failed: frontend"));
+
+        let json: serde_json::Value = serde_json::from_slice(&buffer.lock().unwrap()).unwrap();
+        let roots = json["data"]["roots"].as_array().unwrap();
+        assert!(roots[0]["error"].as_str().unwrap().contains("frontend is broken"));
+        assert_eq!(roots[1]["result"]["ok"], true);
+    }
+
+    #[tokio::test]
+    async fn test_requires_configured_roots() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("repo.config.toml"), "[changeset]\n").unwrap();
+        let output = Output::new(OutputFormat::Json, std::io::sink(), true);
+
+        let result =
+            execute_for_roots(&output, temp.path(), None, |_output, _root, _config| async move {
+                Ok(())
+            })
+            .await;
+
+        assert!(result.unwrap_err().to_string().contains("No workspace roots configured"));
+        assert!(
+            execute_for_roots(&output, Path::new("/nonexistent"), None, |_o, _r, _c| async {
+                Ok(())
+            })
+            .await
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Undo command implementation.
+//!
+//! This module implements the `workspace undo` command which lists operations recorded in
+//! the workspace transaction log and reverts a selected one.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_undo` - Lists recent operations (`--list`) or reverts one by ID or by kind
+//!
+//! # How
+//!
+//! The command builds a `TransactionLog` from `sublime_pkg_tools` over the configured backup
+//! directory. Without an ID, the most recent completed operation (optionally of one `--kind`)
+//! is reverted. Reverting an operation that newer operations also changed discards their
+//! changes to the shared files, so this requires `--force`.
+//!
+//! # Why
+//!
+//! Version bumps, upgrades, and other commands rewrite manifests, changelogs, and changesets.
+//! A single undo entry point lets users recover from any of them without knowing which
+//! command kept which backup.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::undo::execute_undo;
+//! use sublime_cli_tools::cli::commands::UndoArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//! use std::path::Path;
+//!
+//! # async fn example() -> sublime_cli_tools::error::Result<()> {
+//! let args = UndoArgs { id: None, list: true, kind: None, limit: 10, force: false };
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_undo(&args, &output, Path::new("."), None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cli::commands::UndoArgs;
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::interactive::prompts::prompt_confirm;
+use crate::output::table::{TableBuilder, TableTheme};
+use crate::output::{JsonResponse, Output};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use sublime_pkg_tools::workspace::{OperationKind, TransactionEntry, TransactionLog};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tracing::{debug, info};
+
+/// Executes the `undo` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration or transaction log cannot be read
+/// - No matching operation exists
+/// - Newer operations changed the same files and `--force` is not set
+/// - Files cannot be restored
+pub async fn execute_undo(
+    args: &UndoArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+    let log =
+        TransactionLog::new(root.to_path_buf(), config.upgrade.backup, FileSystemManager::new());
+    let kind = args.kind.as_deref().map(OperationKind::from);
+
+    if args.list {
+        let entries = log
+            .list()
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to read transaction log: {e}")))?;
+        let entries = filter_entries(entries, kind.as_ref(), args.limit);
+        return output_list(output, &entries);
+    }
+
+    let entry = select_entry(&log, args.id.as_deref(), kind.as_ref()).await?;
+    debug!("Selected transaction {} ({})", entry.id, entry.kind);
+
+    let superseding = log
+        .superseded_by(&entry.id)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to read transaction log: {e}")))?;
+    if !superseding.is_empty() && !args.force {
+        let ids = superseding.iter().map(|e| e.id.as_str()).collect::<Vec<_>>().join(", ");
+        return Err(CliError::validation(format!(
+            "Newer operations changed the same files ({ids}); undo them first or use --force"
+        )));
+    }
+
+    if !args.force && output.format().is_human() {
+        output_entry_summary(output, &entry)?;
+        if !prompt_confirm("Revert this operation?", false, output.no_color())? {
+            output.info("Undo cancelled")?;
+            return Ok(());
+        }
+    }
+
+    info!("Reverting transaction: {}", entry.id);
+    let entry = log
+        .undo(&entry.id)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to revert '{}': {e}", entry.id)))?;
+
+    output_undo_result(output, &entry)
+}
+
+/// Finds the transaction to revert by ID, or the most recent completed one.
+async fn select_entry(
+    log: &TransactionLog<FileSystemManager>,
+    id: Option<&str>,
+    kind: Option<&OperationKind>,
+) -> Result<TransactionEntry> {
+    let Some(id) = id else {
+        return log
+            .last(kind)
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to read transaction log: {e}")))?
+            .ok_or_else(|| match kind {
+                Some(kind) => CliError::validation(format!("No '{kind}' operations to undo")),
+                None => CliError::validation("No operations to undo"),
+            });
+    };
+
+    let entry = log
+        .get(id)
+        .await
+        .map_err(|_| CliError::validation(format!("Operation not found: {id}")))?;
+    if let Some(kind) = kind
+        && &entry.kind != kind
+    {
+        return Err(CliError::validation(format!(
+            "Operation {id} is a '{}' operation, not '{kind}'",
+            entry.kind
+        )));
+    }
+    Ok(entry)
+}
+
+/// Keeps entries of `kind` (if given), up to `limit`, newest first.
+pub(crate) fn filter_entries(
+    entries: Vec<TransactionEntry>,
+    kind: Option<&OperationKind>,
+    limit: usize,
+) -> Vec<TransactionEntry> {
+    entries.into_iter().filter(|e| kind.is_none_or(|kind| &e.kind == kind)).take(limit).collect()
+}
+
+/// Renders the list of recorded operations.
+fn output_list(output: &Output, entries: &[TransactionEntry]) -> Result<()> {
+    if output.format().is_json() {
+        let operations: Vec<OperationJson> = entries.iter().map(OperationJson::from).collect();
+        let total = operations.len();
+        return output.json(&JsonResponse::success(OperationListJson { operations, total }));
+    }
+
+    if output.format().is_quiet() {
+        for entry in entries {
+            output.plain(&entry.id)?;
+        }
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        return output.info("No recorded operations");
+    }
+
+    let mut table = TableBuilder::new()
+        .theme(TableTheme::Minimal)
+        .columns(&["ID", "Kind", "Created", "Files", "Status"])
+        .build();
+    for entry in entries {
+        let created = entry.created_at.format("%Y-%m-%d %H:%M:%S").to_string();
+        let files = (entry.files.len() + entry.created_files.len()).to_string();
+        let status = if entry.committed { "completed" } else { "incomplete" };
+        table.add_row(&[&entry.id, entry.kind.as_str(), &created, &files, status]);
+    }
+    output.table(&mut table)?;
+    output.blank_line()?;
+    output.plain("Use `workspace undo <ID>` to revert an operation")
+}
+
+/// Shows what reverting `entry` will change.
+fn output_entry_summary(output: &Output, entry: &TransactionEntry) -> Result<()> {
+    output.blank_line()?;
+    output.plain(&format!(
+        "This will revert '{}' operation {} ({})",
+        entry.kind,
+        entry.id,
+        entry.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+    ))?;
+    for file in &entry.files {
+        output.plain(&format!("  restore {}", file.display()))?;
+    }
+    for file in &entry.created_files {
+        output.plain(&format!("  remove  {}", file.display()))?;
+    }
+    output.blank_line()
+}
+
+/// Renders the result of reverting an operation.
+fn output_undo_result(output: &Output, entry: &TransactionEntry) -> Result<()> {
+    if output.format().is_json() {
+        return output.json(&JsonResponse::success(UndoJson {
+            id: entry.id.clone(),
+            kind: entry.kind.to_string(),
+            files_restored: entry.files.clone(),
+            files_removed: entry.created_files.clone(),
+        }));
+    }
+
+    if output.format().is_quiet() {
+        return output.plain(&entry.id);
+    }
+
+    output.success(&format!("Reverted '{}' operation {}", entry.kind, entry.id))?;
+    output.plain(&format!(
+        "Restored {} file(s), removed {} file(s)",
+        entry.files.len(),
+        entry.created_files.len()
+    ))
+}
+
+/// JSON response for `undo --list`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OperationListJson {
+    /// Recorded operations, newest first.
+    operations: Vec<OperationJson>,
+    /// Number of operations listed.
+    total: usize,
+}
+
+/// JSON representation of a recorded operation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OperationJson {
+    /// Transaction ID.
+    id: String,
+    /// Operation kind.
+    kind: String,
+    /// When the operation started.
+    created_at: DateTime<Utc>,
+    /// Files whose previous content is recorded.
+    files: Vec<PathBuf>,
+    /// Files the operation created.
+    created_files: Vec<PathBuf>,
+    /// Whether the operation completed.
+    committed: bool,
+}
+
+impl From<&TransactionEntry> for OperationJson {
+    fn from(entry: &TransactionEntry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            kind: entry.kind.to_string(),
+            created_at: entry.created_at,
+            files: entry.files.clone(),
+            created_files: entry.created_files.clone(),
+            committed: entry.committed,
+        }
+    }
+}
+
+/// JSON response for a reverted operation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UndoJson {
+    /// Transaction ID.
+    id: String,
+    /// Operation kind.
+    kind: String,
+    /// Files restored to their previous content.
+    files_restored: Vec<PathBuf>,
+    /// Files deleted because the operation created them.
+    files_removed: Vec<PathBuf>,
+}
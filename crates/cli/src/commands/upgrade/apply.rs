@@ -20,11 +20,13 @@
 //! 1. Loads configuration from workspace root
 //! 2. Detects available upgrades
 //! 3. Filters based on user selection criteria
-//! 4. Creates backup (unless --no-backup)
-//! 5. Applies upgrades to package.json files
-//! 6. Optionally creates changeset (if --auto-changeset)
-//! 7. Validates modified files
-//! 8. Outputs results and summary
+//! 4. Warns about upgrades of locally patched dependencies and applies the
+//!    `upgrade.patched_dependencies` policy (warn, confirm, block)
+//! 5. Creates backup (unless --no-backup)
+//! 6. Applies upgrades to package.json files
+//! 7. Optionally creates changeset (if --auto-changeset)
+//! 8. Validates modified files
+//! 9. Outputs results and summary, or a pull request description (if --pr-body)
 //!
 //! # Why
 //!
@@ -52,24 +54,31 @@
 //!
 //! # Apply with JSON output
 //! workspace upgrade apply --format json
+//!
+//! # Apply and open a pull request described by the result
+//! workspace upgrade apply --force --auto-changeset --pr-body > pr.md
+//! gh pr create --title "Upgrade dependencies" --body-file pr.md
 //! ```
 
 use crate::cli::commands::UpgradeApplyArgs;
+use crate::commands::upgrade::check::report_registry_retries;
+use crate::commands::upgrade::toolchain::execute_toolchain_apply;
 use crate::commands::upgrade::types::{
-    AppliedUpgradeInfo, ApplySummary, SkippedUpgradeInfo, UpgradeApplyResponse,
+    AppliedUpgradeInfo, ApplySummary, SkippedUpgradeInfo, UpgradeApplyResponse, VerificationInfo,
 };
 use crate::error::{CliError, Result};
 use crate::interactive::prompts::prompt_confirm;
 use crate::output::{JsonResponse, Output, table::TableBuilder};
 use std::collections::HashSet;
 use std::path::Path;
-use sublime_pkg_tools::config::PackageToolsConfig;
-use sublime_pkg_tools::types::VersionBump;
+use sublime_pkg_tools::config::{PackageToolsConfig, UpgradeConfig};
+use sublime_pkg_tools::error::UpgradeError;
+use sublime_pkg_tools::types::{VersionBump, Warning, WarningKind};
 use sublime_pkg_tools::upgrade::{
     AppliedUpgrade, DependencyUpgrade, DetectionOptions, PackageUpgrades, UpgradeManager,
-    UpgradeSelection, UpgradeType,
+    UpgradeSelection, UpgradeType, render_pr_body,
 };
-use tracing::{debug, info, instrument, warn};
+use tracing::{debug, info, instrument};
 
 /// Executes the upgrade apply command.
 ///
@@ -115,6 +124,9 @@ use tracing::{debug, info, instrument, warn};
 ///     auto_changeset: false,
 ///     changeset_bump: "patch".to_string(),
 ///     no_backup: false,
+///     no_verify: false,
+///     pr_body: false,
+///     toolchain: false,
 ///     force: false,
 /// };
 /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
@@ -142,36 +154,54 @@ pub async fn execute_upgrade_apply(
     debug!("Loading configuration");
     let config = load_config(workspace_root).await?;
 
+    if args.toolchain {
+        return execute_toolchain_apply(args, output, workspace_root, &config).await;
+    }
+
     // Step 3: Detect available upgrades
     info!("Detecting available upgrades");
     let detection_options = create_detection_options(args);
 
     // Create upgrade manager with upgrade-specific config
-    let mut upgrade_manager =
-        UpgradeManager::new(workspace_root.to_path_buf(), config.upgrade.clone())
-            .await
-            .map_err(|e| CliError::execution(format!("Failed to create upgrade manager: {e}")))?;
-
-    let available_upgrades = upgrade_manager
-        .detect_upgrades(detection_options)
+    let upgrade_config = create_upgrade_config(args, &config);
+    let mut upgrade_manager = UpgradeManager::new(workspace_root.to_path_buf(), upgrade_config)
         .await
-        .map_err(|e| CliError::execution(format!("Failed to detect upgrades: {e}")))?;
+        .map_err(|e| CliError::execution(format!("Failed to create upgrade manager: {e}")))?
+        .with_changeset_config(config.changeset.clone());
+    report_registry_retries(&upgrade_manager, output);
+
+    let available_upgrades =
+        upgrade_manager.detect_upgrades(detection_options).await.map_err(|e| match e {
+            UpgradeError::DeadlineExceeded { .. } => CliError::timeout(e.to_string()),
+            e => CliError::execution(format!("Failed to detect upgrades: {e}")),
+        })?;
 
     debug!("Found {} packages with upgrades", available_upgrades.packages.len());
 
     // Step 5: Create upgrade selection from args
-    let selection = create_upgrade_selection(args);
+    let mut selection = create_upgrade_selection(args);
 
     // Step 6: Check if there are any upgrades to apply
     let upgrades_to_apply = count_selected_upgrades(&available_upgrades.packages, &selection);
     if upgrades_to_apply == 0 {
         info!("No upgrades match the selection criteria");
-        output_no_upgrades(output)?;
+        output_no_upgrades(output, available_upgrades.warnings)?;
         return Ok(());
     }
 
     info!("Found {} upgrades matching selection criteria", upgrades_to_apply);
 
+    // Step 6b: Apply the patched dependency policy
+    let patched = selected_patched_upgrades(&available_upgrades.packages, &selection);
+    if !patched.is_empty() {
+        selection.include_patched = resolve_patched_upgrades(
+            output,
+            &patched,
+            &upgrade_manager.config().patched_dependencies,
+            args,
+        )?;
+    }
+
     // Step 7: Confirm with user (unless --force or --dry-run)
     if !args.force && !args.dry_run && output.format().is_human() {
         let should_proceed = confirm_apply(output, upgrades_to_apply, output.no_color())?;
@@ -183,13 +213,6 @@ pub async fn execute_upgrade_apply(
     }
 
     // Step 8: Apply upgrades (or dry-run)
-    // Note: Changeset creation is controlled by UpgradeManager's config.auto_changeset
-    // For now, we'll note this limitation and implement changeset support in a future story
-    if args.auto_changeset {
-        warn!("Auto-changeset option requested but changeset integration not yet implemented");
-        // TODO: will be implemented on story 6.2 completion - integrate with ChangesetManager
-    }
-
     info!("Applying upgrades (dry_run={})", args.dry_run);
 
     // Clone selection to use after apply_upgrades (which consumes it)
@@ -208,14 +231,106 @@ pub async fn execute_upgrade_apply(
         &available_upgrades.packages,
         &selection_for_result,
         args.dry_run,
+        workspace_root,
     );
 
-    output_results(output, applied, skipped, summary, args.dry_run)?;
+    let pr_body =
+        args.pr_body.then(|| render_pr_body(&upgrade_result, &available_upgrades.packages));
+
+    output_results(
+        output,
+        applied,
+        skipped,
+        summary,
+        pr_body,
+        upgrade_result.warnings.clone(),
+        args.dry_run,
+    )?;
 
     info!("Upgrade apply completed successfully");
     Ok(())
 }
 
+/// Collects the selected upgrades of dependencies that carry a local patch.
+///
+/// # Arguments
+///
+/// * `packages` - All available upgrades that were detected
+/// * `selection` - Selection criteria
+///
+/// # Returns
+///
+/// * `Vec<&DependencyUpgrade>` - Selected upgrades with a local patch
+fn selected_patched_upgrades<'a>(
+    packages: &'a [PackageUpgrades],
+    selection: &UpgradeSelection,
+) -> Vec<&'a DependencyUpgrade> {
+    packages
+        .iter()
+        .filter(|pkg| selection.matches_target(&pkg.package_name, pkg.workspace_root))
+        .flat_map(|pkg| &pkg.upgrades)
+        .filter(|upgrade| {
+            upgrade.patch.is_some()
+                && selection.matches_type(upgrade.upgrade_type)
+                && selection.matches_dependency(&upgrade.name)
+        })
+        .collect()
+}
+
+/// Warns about upgrades of patched dependencies and decides whether they are applied.
+///
+/// With the `confirm` policy the user is asked, unless `--force` is set; without an
+/// interactive prompt the upgrades are held back.
+///
+/// # Arguments
+///
+/// * `output` - Output handler
+/// * `patched` - Selected upgrades with a local patch
+/// * `policy` - The `upgrade.patched_dependencies` policy
+/// * `args` - Command arguments
+///
+/// # Returns
+///
+/// * `Result<bool>` - Whether patched upgrades are confirmed
+///
+/// # Errors
+///
+/// Returns an error if writing output or reading the confirmation fails.
+fn resolve_patched_upgrades(
+    output: &Output,
+    patched: &[&DependencyUpgrade],
+    policy: &str,
+    args: &UpgradeApplyArgs,
+) -> Result<bool> {
+    for upgrade in patched {
+        let patch_file = upgrade.patch.as_ref().map(|patch| patch.patch_file.display().to_string());
+        output.warning(&format!(
+            "{} is patched locally ({}); upgrading to {} may invalidate the patch",
+            upgrade.name,
+            patch_file.unwrap_or_default(),
+            upgrade.latest_version
+        ))?;
+    }
+
+    match policy {
+        "block" => {
+            output.warning("Upgrades of patched dependencies are held back (policy: block)")?;
+            Ok(false)
+        }
+        "confirm" if args.force => Ok(true),
+        "confirm" if !args.dry_run && output.format().is_human() => {
+            prompt_confirm("Upgrade patched dependencies anyway?", false, output.no_color())
+        }
+        "confirm" => {
+            output.warning(
+                "Upgrades of patched dependencies are held back; use --force to apply them",
+            )?;
+            Ok(false)
+        }
+        _ => Ok(true),
+    }
+}
+
 /// Validates command arguments for consistency.
 ///
 /// # Arguments
@@ -259,6 +374,43 @@ async fn load_config(_workspace_root: &Path) -> Result<PackageToolsConfig> {
         .map_err(|e| CliError::configuration(format!("Failed to load configuration: {e}")))
 }
 
+/// Applies command-line overrides to the configured upgrade settings.
+///
+/// `--auto-changeset` forces changeset creation with the `--changeset-bump` bump for every
+/// upgrade type, and `--no-backup` disables the automatic backup.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `config` - Loaded package tools configuration
+///
+/// # Returns
+///
+/// * `UpgradeConfig` - Upgrade configuration for the upgrade manager
+pub(crate) fn create_upgrade_config(
+    args: &UpgradeApplyArgs,
+    config: &PackageToolsConfig,
+) -> UpgradeConfig {
+    let mut upgrade_config = config.upgrade.clone();
+
+    if args.auto_changeset {
+        upgrade_config.auto_changeset = true;
+        upgrade_config.changeset_bump = args.changeset_bump.to_lowercase();
+        upgrade_config.changeset_bump_by_upgrade.clear();
+    }
+
+    if args.no_backup {
+        upgrade_config.backup.enabled = false;
+    }
+
+    if args.no_verify {
+        upgrade_config.verify.command = None;
+        upgrade_config.verify.by_upgrade.clear();
+    }
+
+    upgrade_config
+}
+
 /// Creates detection options from command arguments.
 ///
 /// # Arguments
@@ -278,6 +430,8 @@ fn create_detection_options(args: &UpgradeApplyArgs) -> DetectionOptions {
         dependency_filter: None,
         include_prereleases: false,
         concurrency: 10,
+        include_download_stats: false,
+        ..DetectionOptions::default()
     }
 }
 
@@ -290,7 +444,7 @@ fn create_detection_options(args: &UpgradeApplyArgs) -> DetectionOptions {
 /// # Returns
 ///
 /// * `UpgradeSelection` - Selection criteria for upgrade manager
-fn create_upgrade_selection(args: &UpgradeApplyArgs) -> UpgradeSelection {
+pub(crate) fn create_upgrade_selection(args: &UpgradeApplyArgs) -> UpgradeSelection {
     // Create base selection based on upgrade type flags
     let mut selection = if args.patch_only {
         UpgradeSelection::patch_only()
@@ -325,7 +479,7 @@ fn count_selected_upgrades(
 ) -> usize {
     available_upgrades
         .iter()
-        .filter(|pkg| selection.matches_package(&pkg.package_name))
+        .filter(|pkg| selection.matches_target(&pkg.package_name, pkg.workspace_root))
         .flat_map(|pkg| &pkg.upgrades)
         .filter(|upgrade| {
             let matches_type = selection.matches_type(upgrade.upgrade_type);
@@ -402,6 +556,7 @@ fn confirm_apply(output: &Output, count: usize, no_color: bool) -> Result<bool>
 /// * `available_upgrades` - All available upgrades that were detected
 /// * `selection` - The selection criteria used for filtering
 /// * `is_dry_run` - Whether this was a dry-run operation
+/// * `workspace_root` - Workspace root the reported manifests are relative to
 ///
 /// # Returns
 ///
@@ -411,17 +566,23 @@ fn convert_upgrade_result(
     available_upgrades: &[PackageUpgrades],
     selection: &UpgradeSelection,
     is_dry_run: bool,
+    workspace_root: &Path,
 ) -> (Vec<AppliedUpgradeInfo>, Vec<SkippedUpgradeInfo>, ApplySummary) {
-    let applied: Vec<AppliedUpgradeInfo> =
-        result.applied.iter().map(convert_applied_upgrade).collect();
+    let applied: Vec<AppliedUpgradeInfo> = result
+        .applied
+        .iter()
+        .map(|applied| convert_applied_upgrade(applied, workspace_root))
+        .collect();
 
     // Calculate skipped upgrades: available upgrades that were NOT applied
     let applied_deps: HashSet<String> =
         result.applied.iter().map(|a| a.dependency_name.clone()).collect();
+    let unchanged_deps: HashSet<&str> =
+        result.skipped.iter().map(|s| s.dependency_name.as_str()).collect();
 
     let skipped: Vec<SkippedUpgradeInfo> = available_upgrades
         .iter()
-        .filter(|pkg| selection.matches_package(&pkg.package_name))
+        .filter(|pkg| selection.matches_target(&pkg.package_name, pkg.workspace_root))
         .flat_map(|pkg| &pkg.upgrades)
         .filter(|upgrade| {
             // Only include if it matches selection criteria and wasn't applied
@@ -433,7 +594,11 @@ fn convert_upgrade_result(
         })
         .map(|upgrade| {
             // Determine reason for skipping
-            let reason = if selection.matches_type(upgrade.upgrade_type) {
+            let reason = if unchanged_deps.contains(upgrade.name.as_str()) {
+                "unchanged_spec".to_string()
+            } else if upgrade.patch.is_some() {
+                "patched_dependency".to_string()
+            } else if selection.matches_type(upgrade.upgrade_type) {
                 "filtered_by_selection".to_string()
             } else {
                 format!("{}_version_filtered", upgrade_type_to_string(upgrade.upgrade_type))
@@ -446,6 +611,14 @@ fn convert_upgrade_result(
     let summary = ApplySummary {
         total_applied: applied.len(),
         total_skipped: skipped.len(),
+        changeset_id: result.changeset_id.clone(),
+        verification: result.verification.as_ref().map(|outcome| VerificationInfo {
+            command: outcome.command.clone(),
+            upgrade_type: upgrade_type_to_string(outcome.upgrade_type).to_string(),
+            passed: outcome.passed,
+            duration_ms: outcome.duration_ms,
+            reason: outcome.reason.clone(),
+        }),
         backup_id: if is_dry_run {
             None
         } else {
@@ -480,11 +653,12 @@ fn upgrade_type_to_string(upgrade_type: UpgradeType) -> &'static str {
 /// # Arguments
 ///
 /// * `applied` - Applied upgrade from package tools
+/// * `workspace_root` - Workspace root the manifest is reported relative to
 ///
 /// # Returns
 ///
 /// * `AppliedUpgradeInfo` - Converted applied upgrade
-fn convert_applied_upgrade(applied: &AppliedUpgrade) -> AppliedUpgradeInfo {
+fn convert_applied_upgrade(applied: &AppliedUpgrade, workspace_root: &Path) -> AppliedUpgradeInfo {
     let upgrade_type = match applied.upgrade_type {
         UpgradeType::Major => "major",
         UpgradeType::Minor => "minor",
@@ -496,6 +670,13 @@ fn convert_applied_upgrade(applied: &AppliedUpgrade) -> AppliedUpgradeInfo {
         from: applied.old_version.clone(),
         to: applied.new_version.clone(),
         upgrade_type: upgrade_type.to_string(),
+        manifest: applied
+            .manifest_path
+            .strip_prefix(workspace_root)
+            .unwrap_or(&applied.manifest_path)
+            .display()
+            .to_string(),
+        workspace_root: applied.workspace_root,
     }
 }
 
@@ -523,23 +704,29 @@ fn convert_skipped_upgrade(skipped: &DependencyUpgrade, reason: &str) -> Skipped
 /// # Arguments
 ///
 /// * `output` - Output context
+/// * `warnings` - Non-fatal issues found during detection
 ///
 /// # Returns
 ///
 /// * `Result<()>` - Success or error
-fn output_no_upgrades(output: &Output) -> Result<()> {
+fn output_no_upgrades(output: &Output, warnings: Vec<Warning>) -> Result<()> {
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => {
             let response = UpgradeApplyResponse {
                 success: true,
                 applied: vec![],
                 skipped: vec![],
                 summary: ApplySummary::new(),
+                pr_body: None,
+                warnings,
             };
             let json_response = JsonResponse::success(response);
             output.json(&json_response)
         }
         crate::output::OutputFormat::Human => {
+            output.warnings(&warnings)?;
             output.info("No upgrades match the selection criteria")?;
             Ok(())
         }
@@ -555,6 +742,8 @@ fn output_no_upgrades(output: &Output) -> Result<()> {
 /// * `applied` - Applied upgrades
 /// * `skipped` - Skipped upgrades
 /// * `summary` - Summary statistics
+/// * `pr_body` - Pull request description, replacing the human-readable results
+/// * `warnings` - Non-fatal issues found during detection and application
 /// * `is_dry_run` - Whether this was a dry-run operation
 ///
 /// # Returns
@@ -569,14 +758,24 @@ fn output_results(
     applied: Vec<AppliedUpgradeInfo>,
     skipped: Vec<SkippedUpgradeInfo>,
     summary: ApplySummary,
+    pr_body: Option<String>,
+    warnings: Vec<Warning>,
     is_dry_run: bool,
 ) -> Result<()> {
+    if let Some(body) = &pr_body
+        && !output.format().is_json()
+    {
+        return output.write_raw(body.as_bytes());
+    }
+
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
-            output_json(output, applied, skipped, summary)
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => {
+            output_json(output, applied, skipped, summary, pr_body, warnings)
         }
         crate::output::OutputFormat::Human => {
-            output_human(output, &applied, &skipped, &summary, is_dry_run)
+            output_human(output, &applied, &skipped, &summary, &warnings, is_dry_run)
         }
         crate::output::OutputFormat::Quiet => output_quiet(output, &summary, is_dry_run),
     }
@@ -590,6 +789,8 @@ fn output_results(
 /// * `applied` - Applied upgrades
 /// * `skipped` - Skipped upgrades
 /// * `summary` - Summary statistics
+/// * `pr_body` - Pull request description, when requested
+/// * `warnings` - Non-fatal issues found during detection and application
 ///
 /// # Returns
 ///
@@ -599,8 +800,11 @@ fn output_json(
     applied: Vec<AppliedUpgradeInfo>,
     skipped: Vec<SkippedUpgradeInfo>,
     summary: ApplySummary,
+    pr_body: Option<String>,
+    warnings: Vec<Warning>,
 ) -> Result<()> {
-    let response = UpgradeApplyResponse { success: true, applied, skipped, summary };
+    let response =
+        UpgradeApplyResponse { success: true, applied, skipped, summary, pr_body, warnings };
 
     let json_response = JsonResponse::success(response);
     output.json(&json_response)
@@ -614,6 +818,7 @@ fn output_json(
 /// * `applied` - Applied upgrades
 /// * `skipped` - Skipped upgrades
 /// * `summary` - Summary statistics
+/// * `warnings` - Non-fatal issues found during detection and application
 /// * `is_dry_run` - Whether this was a dry-run
 ///
 /// # Returns
@@ -624,6 +829,7 @@ fn output_human(
     applied: &[AppliedUpgradeInfo],
     skipped: &[SkippedUpgradeInfo],
     summary: &ApplySummary,
+    warnings: &[Warning],
     is_dry_run: bool,
 ) -> Result<()> {
     use console::style;
@@ -644,10 +850,17 @@ fn output_human(
             if is_dry_run { style("Would Upgrade:").bold() } else { style("Upgraded:").bold() };
         output.plain(&section_title.to_string())?;
 
-        let mut table = TableBuilder::new().columns(&["Package", "From", "To", "Type"]).build();
+        let mut table =
+            TableBuilder::new().columns(&["Package", "From", "To", "Type", "Manifest"]).build();
 
         for upgrade in applied {
-            table.add_row(&[&upgrade.package, &upgrade.from, &upgrade.to, &upgrade.upgrade_type]);
+            table.add_row(&[
+                &upgrade.package,
+                &upgrade.from,
+                &upgrade.to,
+                &upgrade.upgrade_type,
+                &upgrade.manifest,
+            ]);
         }
 
         output.table(&mut table)?;
@@ -679,6 +892,47 @@ fn output_human(
     output.plain(&format!("  Total applied: {}", summary.total_applied))?;
     output.plain(&format!("  Total skipped: {}", summary.total_skipped))?;
 
+    // Withheld, unchanged and unverified upgrades are already listed above
+    let warnings: Vec<Warning> = warnings
+        .iter()
+        .filter(|warning| {
+            !matches!(
+                warning.kind,
+                WarningKind::PatchedDependencyWithheld
+                    | WarningKind::UnchangedSpec
+                    | WarningKind::VerificationFailed
+            )
+        })
+        .cloned()
+        .collect();
+    if !warnings.is_empty() {
+        output.blank_line()?;
+        output.warnings(&warnings)?;
+    }
+
+    if let Some(ref verification) = summary.verification {
+        output.blank_line()?;
+        if verification.passed {
+            output.success(&format!("Verification passed: {}", verification.command))?;
+        } else {
+            output.warning(&format!(
+                "Verification failed: {} ({})",
+                verification.command,
+                verification.reason.as_deref().unwrap_or("unknown error")
+            ))?;
+            output.plain(
+                &style("  Upgrades were kept by upgrade.verify.on_failure = \"keep\"")
+                    .dim()
+                    .to_string(),
+            )?;
+        }
+    }
+
+    if let Some(ref changeset_id) = summary.changeset_id {
+        output.blank_line()?;
+        output.plain(&format!("Changeset updated: {changeset_id}"))?;
+    }
+
     if let Some(ref backup_id) = summary.backup_id {
         output.blank_line()?;
         output.plain(&format!("Backup created: {backup_id}"))?;
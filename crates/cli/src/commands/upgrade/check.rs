@@ -45,18 +45,27 @@
 //!
 //! # Check specific packages
 //! workspace upgrade check --packages "typescript,eslint"
+//!
+//! # Resume a check interrupted by a timeout or Ctrl-C
+//! workspace upgrade check --resume
 //! ```
 
 use crate::cli::commands::UpgradeCheckArgs;
+use crate::commands::upgrade::toolchain::execute_toolchain_check;
 use crate::commands::upgrade::types::{
-    DependencyUpgradeInfo, PackageUpgradeInfo, UpgradeCheckResponse, UpgradeSummary,
+    DependencyUpgradeInfo, PackageUpgradeInfo, UpgradeCheckResponse, UpgradeInsightsInfo,
+    UpgradeSummary,
 };
 use crate::error::{CliError, Result};
 use crate::output::{JsonResponse, Output, table::TableBuilder};
 use std::path::Path;
+use std::sync::Arc;
 use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_pkg_tools::error::UpgradeError;
+use sublime_pkg_tools::types::Warning;
 use sublime_pkg_tools::upgrade::{
-    DependencyUpgrade, DetectionOptions, PackageUpgrades, UpgradeManager, UpgradeType,
+    DependencyUpgrade, DetectionOptions, PackageUpgrades, RegistryRetry, UpgradeManager,
+    UpgradeType,
 };
 use tracing::{debug, info, instrument};
 
@@ -104,6 +113,9 @@ use tracing::{debug, info, instrument};
 ///     peer: false,
 ///     packages: None,
 ///     registry: None,
+///     stats: false,
+///     toolchain: false,
+///     resume: false,
 /// };
 /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
 /// let workspace_root = Path::new(".");
@@ -128,6 +140,10 @@ pub async fn execute_upgrade_check(
     // Step 2: Create detection options from arguments
     debug!("Creating detection options");
     let detection_options = create_detection_options(args)?;
+
+    if args.toolchain {
+        return Box::pin(execute_toolchain_check(args, output, workspace_root, config)).await;
+    }
     debug!(
         "Detection options: include_deps={}, include_dev={}, include_peer={}",
         detection_options.include_dependencies,
@@ -141,13 +157,25 @@ pub async fn execute_upgrade_check(
         UpgradeManager::new(workspace_root.to_path_buf(), config.upgrade)
             .await
             .map_err(|e| CliError::execution(format!("Failed to create upgrade manager: {e}")))?;
-
-    let upgrade_preview = upgrade_manager
-        .detect_upgrades(detection_options)
-        .await
-        .map_err(|e| CliError::execution(format!("Failed to detect upgrades: {e}")))?;
+    report_registry_retries(&upgrade_manager, output);
+
+    // Lookups cut short by --timeout still leave the upgrades found in time to report
+    let upgrade_preview = match upgrade_manager.detect_upgrades(detection_options).await {
+        Ok(preview) => preview,
+        Err(UpgradeError::DeadlineExceeded { partial: Some(preview), .. }) => *preview,
+        Err(e @ UpgradeError::DeadlineExceeded { .. }) => {
+            return Err(CliError::timeout(e.to_string()));
+        }
+        Err(e) => return Err(CliError::execution(format!("Failed to detect upgrades: {e}"))),
+    };
 
     debug!("Found upgrades in {} packages", upgrade_preview.packages.len());
+    if upgrade_preview.resumed_lookups > 0 {
+        output.info(&format!(
+            "Resumed an interrupted detection: {} registry lookups reused",
+            upgrade_preview.resumed_lookups
+        ))?;
+    }
 
     // Step 4: Filter results by upgrade type based on CLI flags
     let include_major = args.major && !args.no_major;
@@ -165,7 +193,21 @@ pub async fn execute_upgrade_check(
     let (packages, summary) = convert_and_summarize(&filtered_upgrades);
 
     // Step 6: Output results
-    output_results(output, packages, summary)?;
+    let deadline_skipped = upgrade_preview.deadline_skipped.len();
+    output_results(
+        output,
+        packages,
+        summary,
+        upgrade_preview.offline_skipped,
+        upgrade_preview.deadline_skipped,
+        upgrade_preview.warnings,
+    )?;
+
+    if deadline_skipped > 0 {
+        return Err(CliError::timeout(format!(
+            "Registry lookups for {deadline_skipped} dependencies did not finish before the deadline"
+        )));
+    }
 
     info!("Upgrade check completed successfully");
     Ok(())
@@ -230,11 +272,45 @@ pub(crate) fn create_detection_options(args: &UpgradeCheckArgs) -> Result<Detect
         dependency_filter: None,    // Not exposed in CLI yet
         include_prereleases: false, // Not exposed in CLI yet
         concurrency: 10,            // Default concurrency
+        include_download_stats: args.stats,
+        resume: args.resume,
+        ..DetectionOptions::default()
     };
 
     Ok(options)
 }
 
+/// Reports registry rate limit retries on stderr in human output.
+///
+/// Large scans can hit registry rate limits. The registry client waits as long as the
+/// registry asks before retrying, so the user is told why the scan pauses and for how long.
+/// Stdout is left untouched so JSON and quiet output stay parseable.
+///
+/// # Arguments
+///
+/// * `upgrade_manager` - Upgrade manager whose registry client reports the retries
+/// * `output` - Output context deciding whether retries are shown
+pub(crate) fn report_registry_retries(upgrade_manager: &UpgradeManager, output: &Output) {
+    if !output.format().is_human() {
+        return;
+    }
+    upgrade_manager.registry_client().set_retry_listener(Arc::new(|retry| {
+        eprintln!("{}", format_registry_retry(retry));
+    }));
+}
+
+/// Formats a registry retry notice, e.g. "Registry rate limited (HTTP 429); retrying in 30s".
+pub(crate) fn format_registry_retry(retry: &RegistryRetry) -> String {
+    let reason = if retry.status == 429 { "rate limited" } else { "unavailable" };
+    format!(
+        "Registry {reason} (HTTP {}); retrying in {}s (attempt {}/{})",
+        retry.status,
+        retry.wait.as_secs(),
+        retry.attempt,
+        retry.max_retries
+    )
+}
+
 /// Filters upgrade results by upgrade type (major, minor, patch).
 ///
 /// This filters out upgrades based on the CLI flags. The package tools API
@@ -275,6 +351,7 @@ fn filter_by_upgrade_type(
                 package_path: pkg.package_path.clone(),
                 current_version: pkg.current_version.clone(),
                 upgrades: filtered_upgrades,
+                workspace_root: pkg.workspace_root,
             }
         })
         .filter(|pkg| !pkg.upgrades.is_empty())
@@ -358,6 +435,29 @@ fn convert_dependency_upgrade(
         latest_version: dep.latest_version.clone(),
         upgrade_type: upgrade_type.to_string(),
         breaking,
+        insights: convert_insights(dep),
+    }
+}
+
+/// Extracts the registry insights of a dependency upgrade.
+///
+/// # Arguments
+///
+/// * `dep` - Dependency upgrade from package tools
+///
+/// # Returns
+///
+/// * `UpgradeInsightsInfo` - Deprecation, publication, download, and patch information
+fn convert_insights(dep: &DependencyUpgrade) -> UpgradeInsightsInfo {
+    let info = &dep.version_info;
+
+    UpgradeInsightsInfo {
+        published_at: info.published_at.map(|date| date.to_rfc3339()),
+        current_deprecated: info.current_deprecated.clone(),
+        target_deprecated: info.target_deprecated.clone(),
+        weekly_downloads: info.downloads.map(|stats| stats.weekly_downloads),
+        download_trend: info.downloads.map(|stats| stats.trend.to_string()),
+        patch_file: dep.patch.as_ref().map(|patch| patch.patch_file.display().to_string()),
     }
 }
 
@@ -368,6 +468,9 @@ fn convert_dependency_upgrade(
 /// * `output` - Output context
 /// * `packages` - Package upgrade information
 /// * `summary` - Summary statistics
+/// * `offline_skipped` - Dependencies not looked up in offline mode
+/// * `deadline_skipped` - Dependencies whose lookup did not finish before the deadline
+/// * `warnings` - Non-fatal issues found during detection
 ///
 /// # Returns
 ///
@@ -380,12 +483,24 @@ fn output_results(
     output: &Output,
     packages: Vec<PackageUpgradeInfo>,
     summary: UpgradeSummary,
+    offline_skipped: Vec<String>,
+    deadline_skipped: Vec<String>,
+    warnings: Vec<Warning>,
 ) -> Result<()> {
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
-            output_json(output, packages, summary)
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => {
+            output_json(output, packages, summary, offline_skipped, deadline_skipped, warnings)
         }
-        crate::output::OutputFormat::Human => output_human(output, &packages, &summary),
+        crate::output::OutputFormat::Human => output_human(
+            output,
+            &packages,
+            &summary,
+            &offline_skipped,
+            &deadline_skipped,
+            &warnings,
+        ),
         crate::output::OutputFormat::Quiet => output_quiet(output, &summary),
     }
 }
@@ -397,6 +512,9 @@ fn output_results(
 /// * `output` - Output context
 /// * `packages` - Package upgrade information
 /// * `summary` - Summary statistics
+/// * `offline_skipped` - Dependencies not looked up in offline mode
+/// * `deadline_skipped` - Dependencies whose lookup did not finish before the deadline
+/// * `warnings` - Non-fatal issues found during detection
 ///
 /// # Returns
 ///
@@ -405,13 +523,69 @@ fn output_json(
     output: &Output,
     packages: Vec<PackageUpgradeInfo>,
     summary: UpgradeSummary,
+    offline_skipped: Vec<String>,
+    deadline_skipped: Vec<String>,
+    warnings: Vec<Warning>,
 ) -> Result<()> {
-    let response = UpgradeCheckResponse { success: true, packages, summary };
+    let response = UpgradeCheckResponse {
+        success: deadline_skipped.is_empty(),
+        packages,
+        summary,
+        offline_skipped,
+        deadline_skipped,
+        warnings,
+    };
 
     let json_response = JsonResponse::success(response);
     output.json(&json_response)
 }
 
+/// Reports the dependencies whose registry lookup did not complete.
+///
+/// # Arguments
+///
+/// * `output` - Output context
+/// * `offline_skipped` - Dependencies not looked up in offline mode
+/// * `deadline_skipped` - Dependencies whose lookup did not finish before the deadline
+/// * `warnings` - Non-fatal issues found during detection
+///
+/// # Returns
+///
+/// * `Result<bool>` - Whether every dependency was checked
+fn output_incomplete_lookups(
+    output: &Output,
+    offline_skipped: &[String],
+    deadline_skipped: &[String],
+    warnings: &[Warning],
+) -> Result<bool> {
+    if !offline_skipped.is_empty() {
+        output.warning(&format!(
+            "Offline mode: registry lookups skipped for {} dependencies, which may have \
+             upgrades not listed here: {}",
+            offline_skipped.len(),
+            offline_skipped.join(", ")
+        ))?;
+        output.blank_line()?;
+    }
+
+    if !deadline_skipped.is_empty() {
+        output.warning(&format!(
+            "Deadline reached: registry lookups did not finish for {} dependencies, which may \
+             have upgrades not listed here: {}",
+            deadline_skipped.len(),
+            deadline_skipped.join(", ")
+        ))?;
+        output.blank_line()?;
+    }
+
+    if !warnings.is_empty() {
+        output.warnings(warnings)?;
+        output.blank_line()?;
+    }
+
+    Ok(offline_skipped.is_empty() && deadline_skipped.is_empty() && warnings.is_empty())
+}
+
 /// Outputs results in human-readable format with tables.
 ///
 /// # Arguments
@@ -419,6 +593,9 @@ fn output_json(
 /// * `output` - Output context
 /// * `packages` - Package upgrade information
 /// * `summary` - Summary statistics
+/// * `offline_skipped` - Dependencies not looked up in offline mode
+/// * `deadline_skipped` - Dependencies whose lookup did not finish before the deadline
+/// * `warnings` - Non-fatal issues found during detection
 ///
 /// # Returns
 ///
@@ -427,6 +604,9 @@ fn output_human(
     output: &Output,
     packages: &[PackageUpgradeInfo],
     summary: &UpgradeSummary,
+    offline_skipped: &[String],
+    deadline_skipped: &[String],
+    warnings: &[Warning],
 ) -> Result<()> {
     use console::style;
 
@@ -436,8 +616,14 @@ fn output_human(
     output.plain("━━━━━━━━━━━━━━━━━━━━━━━━━━━━")?;
     output.blank_line()?;
 
+    let complete = output_incomplete_lookups(output, offline_skipped, deadline_skipped, warnings)?;
+
     if !summary.has_upgrades() {
-        output.success("All dependencies are up to date!")?;
+        if complete {
+            output.success("All dependencies are up to date!")?;
+        } else {
+            output.info("No upgrades found among the dependencies checked.")?;
+        }
         return Ok(());
     }
 
@@ -450,19 +636,62 @@ fn output_human(
         let package_header = style(&package.name).bold().green();
         output.plain(&format!("{package_header}:"))?;
 
-        let mut table =
-            TableBuilder::new().columns(&["Package", "Current", "Latest", "Type"]).build();
+        let with_downloads =
+            package.upgrades.iter().any(|upgrade| upgrade.insights.weekly_downloads.is_some());
+        let mut columns = vec!["Package", "Current", "Latest", "Type", "Published"];
+        if with_downloads {
+            columns.push("Downloads/wk");
+        }
+        let mut table = TableBuilder::new().columns(&columns).build();
 
         for upgrade in &package.upgrades {
-            table.add_row(&[
-                &upgrade.package,
-                &upgrade.current_version,
-                &upgrade.latest_version,
-                &upgrade.upgrade_type,
-            ]);
+            let published = upgrade
+                .insights
+                .published_at
+                .as_deref()
+                .and_then(|date| date.get(..10))
+                .unwrap_or("-")
+                .to_string();
+            let mut row = vec![
+                upgrade.package.clone(),
+                upgrade.current_version.clone(),
+                upgrade.latest_version.clone(),
+                upgrade.upgrade_type.clone(),
+                published,
+            ];
+            if with_downloads {
+                row.push(format_downloads(&upgrade.insights));
+            }
+            let cells: Vec<&str> = row.iter().map(String::as_str).collect();
+            table.add_row(&cells);
         }
 
         output.table(&mut table)?;
+
+        for upgrade in package.upgrades.iter().filter(|upgrade| upgrade.insights.has_deprecation())
+        {
+            if let Some(notice) = &upgrade.insights.current_deprecated {
+                output.warning(&format!(
+                    "{}@{} is deprecated: {notice}",
+                    upgrade.package, upgrade.current_version
+                ))?;
+            }
+            if let Some(notice) = &upgrade.insights.target_deprecated {
+                output.warning(&format!(
+                    "{}@{} is deprecated: {notice}",
+                    upgrade.package, upgrade.latest_version
+                ))?;
+            }
+        }
+
+        for upgrade in &package.upgrades {
+            if let Some(patch_file) = &upgrade.insights.patch_file {
+                output.warning(&format!(
+                    "{} is patched locally ({patch_file}); upgrading to {} may invalidate the patch",
+                    upgrade.package, upgrade.latest_version
+                ))?;
+            }
+        }
     }
 
     // Summary
@@ -478,6 +707,37 @@ fn output_human(
     Ok(())
 }
 
+/// Formats weekly downloads with their trend for table display.
+///
+/// # Arguments
+///
+/// * `insights` - Registry insights of a dependency upgrade
+///
+/// # Returns
+///
+/// * `String` - Formatted downloads (e.g., "1,204,331 ↑") or "-" when unavailable
+pub(crate) fn format_downloads(insights: &UpgradeInsightsInfo) -> String {
+    let Some(downloads) = insights.weekly_downloads else {
+        return "-".to_string();
+    };
+
+    let digits = downloads.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    match insights.download_trend.as_deref() {
+        Some("rising") => format!("{grouped} ↑"),
+        Some("declining") => format!("{grouped} ↓"),
+        Some("stable") => format!("{grouped} →"),
+        _ => grouped,
+    }
+}
+
 /// Outputs minimal results in quiet mode.
 ///
 /// # Arguments
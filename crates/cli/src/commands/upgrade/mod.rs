@@ -38,6 +38,7 @@
 pub mod apply;
 pub mod check;
 pub mod rollback;
+pub mod toolchain;
 pub mod types;
 
 #[cfg(test)]
@@ -46,4 +47,6 @@ mod tests;
 // Re-export command implementations
 pub use apply::execute_upgrade_apply;
 pub use check::execute_upgrade_check;
-pub use rollback::{execute_backup_clean, execute_backup_list, execute_backup_restore};
+pub use rollback::{
+    execute_backup_clean, execute_backup_list, execute_backup_prune, execute_backup_restore,
+};
@@ -8,7 +8,8 @@
 //! - Listing available upgrade backups with metadata
 //! - Restoring package.json files from specific backups
 //! - Cleaning up old backups to manage disk space
-//! - Validation of backup integrity before restore
+//! - Pruning backups by count, age, and total size
+//! - Validation of backup integrity (checksums) before restore
 //! - Formatted output (table or JSON)
 //!
 //! # How
@@ -45,12 +46,15 @@
 //! # Clean old backups, keeping last 10
 //! workspace upgrade backups clean --keep 10
 //!
+//! # Remove backups older than 30 days
+//! workspace backup prune --max-age-days 30
+//!
 //! # JSON output for programmatic use
 //! workspace upgrade backups list --format json
 //! ```
 
 use crate::cli::commands::{
-    UpgradeBackupCleanArgs, UpgradeBackupListArgs, UpgradeBackupRestoreArgs,
+    BackupPruneArgs, UpgradeBackupCleanArgs, UpgradeBackupListArgs, UpgradeBackupRestoreArgs,
 };
 use crate::error::{CliError, Result};
 use crate::interactive::prompts::prompt_confirm;
@@ -59,6 +63,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_pkg_tools::error::UpgradeError;
 use sublime_pkg_tools::upgrade::BackupManager;
 use sublime_standard_tools::filesystem::FileSystemManager;
 use tracing::{debug, info, instrument};
@@ -362,10 +367,13 @@ pub async fn execute_backup_restore(
 
     // Perform restore
     info!("Restoring backup: {}", args.id);
-    backup_manager
-        .restore_backup(&args.id)
-        .await
-        .map_err(|e| CliError::execution(format!("Failed to restore backup: {e}")))?;
+    backup_manager.restore_backup(&args.id).await.map_err(|e| match e {
+        UpgradeError::BackupCorrupted { .. } => CliError::validation(format!(
+            "Backup {} failed integrity verification and was not restored: {e}",
+            args.id
+        )),
+        e => CliError::execution(format!("Failed to restore backup: {e}")),
+    })?;
 
     // Output results
     let files_restored = backup_metadata.files.len();
@@ -488,6 +496,119 @@ pub async fn execute_backup_clean(
     Ok(())
 }
 
+/// Executes the backup prune command.
+///
+/// Removes backups exceeding the configured count, age, and total size limits.
+/// Command-line options override the configured limits. Successful backups are
+/// never removed just for being successful, so undo history is preserved.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments from CLI
+/// * `output` - Output context for formatting
+/// * `workspace_root` - Path to the workspace root directory
+///
+/// # Errors
+///
+/// Returns an error if configuration cannot be loaded or the backups cannot be pruned.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_cli_tools::commands::upgrade::rollback::execute_backup_prune;
+/// use sublime_cli_tools::cli::commands::BackupPruneArgs;
+/// use sublime_cli_tools::output::{Output, OutputFormat};
+/// use std::io;
+/// use std::path::Path;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let args = BackupPruneArgs {
+///     keep: None,
+///     max_age_days: Some(30),
+///     max_size_mb: None,
+///     dry_run: true,
+///     force: false,
+/// };
+/// let output = Output::new(OutputFormat::Human, io::stdout(), false);
+///
+/// execute_backup_prune(&args, &output, Path::new(".")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[instrument(skip(output), level = "debug")]
+pub async fn execute_backup_prune(
+    args: &BackupPruneArgs,
+    output: &Output,
+    workspace_root: &Path,
+) -> Result<()> {
+    info!("Pruning backups");
+    debug!("Workspace root: {}", workspace_root.display());
+
+    // Load configuration
+    let config = load_config().await?;
+
+    let backup_config = prune_config(&config.upgrade.backup, args);
+    let backup_manager =
+        BackupManager::new(workspace_root.to_path_buf(), backup_config, FileSystemManager::new());
+
+    let candidates = backup_manager
+        .prune_candidates()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to list backups: {e}")))?;
+
+    if candidates.is_empty() {
+        return output_clean_result(output, "No backups exceed the retention limits");
+    }
+
+    if args.dry_run {
+        let ids = candidates.iter().map(|b| b.id.as_str()).collect::<Vec<_>>().join(", ");
+        let message = format!("Would remove {} backup(s): {ids}", candidates.len());
+        return output_clean_result(output, &message);
+    }
+
+    // Confirm with user (unless --force)
+    if !args.force && output.format().is_human() {
+        let should_proceed = confirm_clean(output, candidates.len(), output.no_color())?;
+        if !should_proceed {
+            info!("User cancelled backup prune");
+            output.info("Prune cancelled")?;
+            return Ok(());
+        }
+    }
+
+    let removed = backup_manager
+        .prune()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to prune backups: {e}")))?;
+
+    output_clean_result(output, &format!("Pruned {} backup(s)", removed.len()))?;
+
+    info!("Backup prune completed successfully");
+    Ok(())
+}
+
+/// Builds the backup configuration used by `backup prune`.
+///
+/// Applies the command-line overrides and keeps successful backups, which are only
+/// subject to the count, age, and size limits.
+pub(crate) fn prune_config(
+    config: &sublime_pkg_tools::config::BackupConfig,
+    args: &BackupPruneArgs,
+) -> sublime_pkg_tools::config::BackupConfig {
+    let mut backup_config = config.clone();
+    backup_config.keep_after_success = true;
+    if let Some(keep) = args.keep {
+        backup_config.max_backups = usize::try_from(keep).unwrap_or(usize::MAX);
+    }
+    if args.max_age_days.is_some() {
+        backup_config.max_age_days = args.max_age_days;
+    }
+    if args.max_size_mb.is_some() {
+        backup_config.max_total_size_mb = args.max_size_mb;
+    }
+    backup_config
+}
+
 /// Loads package tools configuration.
 ///
 /// # Returns
@@ -596,9 +717,9 @@ fn confirm_clean(output: &Output, count: usize, no_color: bool) -> Result<bool>
 /// * `Result<()>` - Success or error
 fn output_backup_list(output: &Output, backups: Vec<BackupInfo>) -> Result<()> {
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
-            output_backup_list_json(output, backups)
-        }
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => output_backup_list_json(output, backups),
         crate::output::OutputFormat::Human => output_backup_list_human(output, &backups),
         crate::output::OutputFormat::Quiet => output_backup_list_quiet(output, &backups),
     }
@@ -702,7 +823,9 @@ fn output_backup_list_quiet(output: &Output, backups: &[BackupInfo]) -> Result<(
 /// * `Result<()>` - Success or error
 fn output_restore_result(output: &Output, backup_id: &str, files: usize) -> Result<()> {
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => {
             let response = BackupRestoreResponse {
                 success: true,
                 backup_id: backup_id.to_string(),
@@ -740,7 +863,9 @@ fn output_restore_result(output: &Output, backup_id: &str, files: usize) -> Resu
 /// * `Result<()>` - Success or error
 fn output_clean_result(output: &Output, message: &str) -> Result<()> {
     match output.format() {
-        crate::output::OutputFormat::Json | crate::output::OutputFormat::JsonCompact => {
+        crate::output::OutputFormat::Json
+        | crate::output::OutputFormat::JsonCompact
+        | crate::output::OutputFormat::Ndjson => {
             let response = BackupCleanResponse { success: true, message: message.to_string() };
             let json_response = JsonResponse::success(response);
             output.json(&json_response)
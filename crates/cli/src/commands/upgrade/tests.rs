@@ -27,9 +27,12 @@
 
 #![allow(clippy::expect_used)]
 
-use crate::cli::commands::UpgradeCheckArgs;
+use crate::cli::commands::{UpgradeApplyArgs, UpgradeCheckArgs};
+use crate::commands::upgrade::apply::create_upgrade_config;
 use crate::commands::upgrade::check::create_detection_options;
 use crate::commands::upgrade::types::*;
+use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_pkg_tools::types::{Warning, WarningKind};
 
 // TODO: will be implemented on story 6.2
 // Integration tests will be added when we implement mock UpgradeManager
@@ -52,12 +55,18 @@ fn test_create_detection_options_defaults() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let options = create_detection_options(&args).expect("Should create detection options");
     assert!(options.include_dependencies);
     assert!(options.include_dev_dependencies);
     assert!(!options.include_peer_dependencies);
+    // Detection only records a checkpoint when asked to resume
+    assert!(!options.resume);
+    assert!(options.checkpoint.is_none());
 }
 
 #[test]
@@ -73,6 +82,9 @@ fn test_create_detection_options_no_major() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let options = create_detection_options(&args).expect("Should create detection options");
@@ -93,6 +105,9 @@ fn test_create_detection_options_all_disabled() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let result = create_detection_options(&args);
@@ -153,6 +168,7 @@ fn test_package_upgrade_info_methods() {
                 latest_version: "5.3.3".to_string(),
                 upgrade_type: "minor".to_string(),
                 breaking: false,
+                insights: UpgradeInsightsInfo::default(),
             },
             DependencyUpgradeInfo {
                 package: "eslint".to_string(),
@@ -160,6 +176,7 @@ fn test_package_upgrade_info_methods() {
                 latest_version: "9.0.0".to_string(),
                 upgrade_type: "major".to_string(),
                 breaking: true,
+                insights: UpgradeInsightsInfo::default(),
             },
         ],
     };
@@ -176,6 +193,7 @@ fn test_dependency_upgrade_info_type_detection() {
         latest_version: "18.2.0".to_string(),
         upgrade_type: "major".to_string(),
         breaking: true,
+        insights: UpgradeInsightsInfo::default(),
     };
 
     assert!(major_upgrade.is_major());
@@ -189,6 +207,7 @@ fn test_dependency_upgrade_info_type_detection() {
         latest_version: "4.18.0".to_string(),
         upgrade_type: "minor".to_string(),
         breaking: false,
+        insights: UpgradeInsightsInfo::default(),
     };
 
     assert!(!minor_upgrade.is_major());
@@ -202,6 +221,7 @@ fn test_dependency_upgrade_info_type_detection() {
         latest_version: "1.6.2".to_string(),
         upgrade_type: "patch".to_string(),
         breaking: false,
+        insights: UpgradeInsightsInfo::default(),
     };
 
     assert!(!patch_upgrade.is_major());
@@ -223,6 +243,7 @@ fn test_upgrade_check_response_serialization() {
                 latest_version: "5.3.3".to_string(),
                 upgrade_type: "minor".to_string(),
                 breaking: false,
+                insights: UpgradeInsightsInfo::default(),
             }],
         }],
         summary: UpgradeSummary {
@@ -233,6 +254,9 @@ fn test_upgrade_check_response_serialization() {
             minor_upgrades: 1,
             patch_upgrades: 0,
         },
+        offline_skipped: vec![],
+        deadline_skipped: vec![],
+        warnings: vec![],
     };
 
     // Test JSON serialization
@@ -255,6 +279,57 @@ fn test_upgrade_check_response_serialization() {
     assert_eq!(deserialized.summary.total_upgrades, 1);
 }
 
+#[test]
+fn test_upgrade_check_response_offline_skipped() {
+    let mut response = UpgradeCheckResponse {
+        success: true,
+        packages: vec![],
+        summary: UpgradeSummary::default(),
+        offline_skipped: vec![],
+        deadline_skipped: vec![],
+        warnings: vec![],
+    };
+
+    let json = serde_json::to_string(&response).expect("Should serialize to JSON");
+    assert!(!json.contains("offlineSkipped"));
+    assert!(!json.contains("deadlineSkipped"));
+
+    response.offline_skipped = vec!["lodash".to_string()];
+    response.deadline_skipped = vec!["react".to_string()];
+    let json = serde_json::to_value(&response).expect("Should serialize to JSON");
+    assert_eq!(json["offlineSkipped"], serde_json::json!(["lodash"]));
+    assert_eq!(json["deadlineSkipped"], serde_json::json!(["react"]));
+}
+
+#[test]
+fn test_upgrade_check_response_warnings() {
+    let mut response = UpgradeCheckResponse {
+        success: true,
+        packages: vec![],
+        summary: UpgradeSummary::default(),
+        offline_skipped: vec![],
+        deadline_skipped: vec![],
+        warnings: vec![],
+    };
+
+    let json = serde_json::to_string(&response).expect("Should serialize to JSON");
+    assert!(!json.contains("warnings"));
+
+    response.warnings = vec![
+        Warning::new(WarningKind::RegistryLookupFailed, "Failed to check lodash: timed out")
+            .with_package("lodash"),
+    ];
+    let json = serde_json::to_value(&response).expect("Should serialize to JSON");
+    assert_eq!(
+        json["warnings"],
+        serde_json::json!([{
+            "kind": "registry-lookup-failed",
+            "message": "Failed to check lodash: timed out",
+            "package": "lodash"
+        }])
+    );
+}
+
 // ============================================================================
 // Apply Type Tests
 // ============================================================================
@@ -275,6 +350,8 @@ fn test_apply_summary_calculations() {
         total_applied: 10,
         total_skipped: 3,
         backup_id: Some("backup_20240115_103045".to_string()),
+        changeset_id: None,
+        verification: None,
     };
 
     assert_eq!(summary.total_applied, 10);
@@ -290,6 +367,8 @@ fn test_applied_upgrade_info() {
         from: "5.0.0".to_string(),
         to: "5.3.3".to_string(),
         upgrade_type: "minor".to_string(),
+        manifest: "package.json".to_string(),
+        workspace_root: true,
     };
 
     assert_eq!(applied.package, "typescript");
@@ -322,6 +401,8 @@ fn test_upgrade_apply_response_serialization() {
             from: "5.0.0".to_string(),
             to: "5.3.3".to_string(),
             upgrade_type: "minor".to_string(),
+            manifest: "package.json".to_string(),
+            workspace_root: true,
         }],
         skipped: vec![SkippedUpgradeInfo {
             package: "eslint".to_string(),
@@ -333,7 +414,11 @@ fn test_upgrade_apply_response_serialization() {
             total_applied: 1,
             total_skipped: 1,
             backup_id: Some("backup_20240115_103045".to_string()),
+            changeset_id: None,
+            verification: None,
         },
+        pr_body: None,
+        warnings: vec![],
     };
 
     // Test JSON serialization
@@ -371,6 +456,8 @@ fn test_upgrade_apply_response_empty() {
         applied: vec![],
         skipped: vec![],
         summary: ApplySummary::default(),
+        pr_body: None,
+        warnings: vec![],
     };
 
     let json = serde_json::to_string_pretty(&response).expect("Should serialize to JSON");
@@ -385,6 +472,27 @@ fn test_upgrade_apply_response_empty() {
     assert!(!json.contains("backupId"));
 }
 
+#[test]
+fn test_upgrade_apply_response_pr_body() {
+    let response = UpgradeApplyResponse {
+        success: true,
+        applied: vec![],
+        skipped: vec![],
+        summary: ApplySummary::default(),
+        pr_body: Some("## Dependency upgrades\n".to_string()),
+        warnings: vec![],
+    };
+
+    let json = serde_json::to_value(&response).expect("Should serialize to JSON");
+
+    assert_eq!(json["prBody"], "## Dependency upgrades\n");
+    assert!(
+        !serde_json::to_string(&UpgradeApplyResponse { pr_body: None, ..response })
+            .expect("Should serialize to JSON")
+            .contains("prBody")
+    );
+}
+
 #[test]
 fn test_upgrade_apply_response_no_backup() {
     let response = UpgradeApplyResponse {
@@ -394,9 +502,19 @@ fn test_upgrade_apply_response_no_backup() {
             from: "4.17.0".to_string(),
             to: "4.17.21".to_string(),
             upgrade_type: "patch".to_string(),
+            manifest: "package.json".to_string(),
+            workspace_root: true,
         }],
         skipped: vec![],
-        summary: ApplySummary { total_applied: 1, total_skipped: 0, backup_id: None },
+        summary: ApplySummary {
+            total_applied: 1,
+            total_skipped: 0,
+            backup_id: None,
+            changeset_id: None,
+            verification: None,
+        },
+        pr_body: None,
+        warnings: vec![],
     };
 
     let json = serde_json::to_string_pretty(&response).expect("Should serialize to JSON");
@@ -412,6 +530,55 @@ fn test_upgrade_apply_response_no_backup() {
 // a test harness for UpgradeManager to test the full command execution
 // flow including detection, application, backup, and changeset creation.
 
+#[test]
+fn test_create_upgrade_config_applies_overrides() {
+    let mut config = PackageToolsConfig::default();
+    config.upgrade.auto_changeset = false;
+    config.upgrade.changeset_bump_by_upgrade.insert("major".to_string(), "major".to_string());
+    config.upgrade.verify.command = Some("pnpm -r test".to_string());
+
+    let args = UpgradeApplyArgs {
+        dry_run: false,
+        patch_only: false,
+        minor_and_patch: false,
+        packages: None,
+        auto_changeset: true,
+        changeset_bump: "Minor".to_string(),
+        no_backup: true,
+        no_verify: true,
+        pr_body: false,
+        toolchain: false,
+        force: true,
+    };
+
+    let upgrade_config = create_upgrade_config(&args, &config);
+    assert!(upgrade_config.auto_changeset);
+    assert_eq!(upgrade_config.changeset_bump, "minor");
+    assert!(upgrade_config.changeset_bump_by_upgrade.is_empty());
+    assert!(!upgrade_config.backup.enabled);
+    assert_eq!(upgrade_config.verify.command_for("patch"), None);
+}
+
+#[test]
+fn test_create_upgrade_config_keeps_configured_values() {
+    let config = PackageToolsConfig::default();
+    let args = UpgradeApplyArgs {
+        dry_run: false,
+        patch_only: false,
+        minor_and_patch: false,
+        packages: None,
+        auto_changeset: false,
+        changeset_bump: "patch".to_string(),
+        no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
+        force: false,
+    };
+
+    assert_eq!(create_upgrade_config(&args, &config), config.upgrade);
+}
+
 // ============================================================================
 // Rollback/Backup Type Tests (Story 6.3)
 // ============================================================================
@@ -620,3 +787,104 @@ fn test_backup_clean_response_no_cleanup_needed() {
 // TODO: Integration tests for execute_backup_list, execute_backup_restore,
 // and execute_backup_clean will be added when we have a proper test harness
 // with mock FileSystemManager and BackupManager.
+
+#[test]
+fn test_format_downloads() {
+    use crate::commands::upgrade::check::format_downloads;
+
+    assert_eq!(format_downloads(&UpgradeInsightsInfo::default()), "-");
+
+    let insights = UpgradeInsightsInfo {
+        weekly_downloads: Some(1_204_331),
+        download_trend: Some("rising".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(format_downloads(&insights), "1,204,331 ↑");
+
+    let insights = UpgradeInsightsInfo {
+        weekly_downloads: Some(999),
+        download_trend: Some("declining".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(format_downloads(&insights), "999 ↓");
+}
+
+#[test]
+fn test_format_registry_retry() {
+    use crate::commands::upgrade::check::format_registry_retry;
+    use std::time::Duration;
+    use sublime_pkg_tools::upgrade::RegistryRetry;
+
+    let retry = RegistryRetry {
+        url: "https://registry.npmjs.org/lodash".to_string(),
+        status: 429,
+        attempt: 1,
+        max_retries: 3,
+        wait: Duration::from_secs(30),
+    };
+    assert_eq!(
+        format_registry_retry(&retry),
+        "Registry rate limited (HTTP 429); retrying in 30s (attempt 1/3)"
+    );
+
+    let retry = RegistryRetry { status: 503, attempt: 2, ..retry };
+    assert_eq!(
+        format_registry_retry(&retry),
+        "Registry unavailable (HTTP 503); retrying in 30s (attempt 2/3)"
+    );
+}
+
+#[test]
+fn test_upgrade_insights_serialization_skips_empty_fields() {
+    let insights = UpgradeInsightsInfo {
+        target_deprecated: Some("no longer maintained".to_string()),
+        ..Default::default()
+    };
+
+    let json = serde_json::to_value(&insights).expect("Should serialize");
+    assert_eq!(json, serde_json::json!({ "targetDeprecated": "no longer maintained" }));
+    assert!(insights.has_deprecation());
+}
+
+#[test]
+fn test_toolchain_responses_serialization() {
+    use crate::commands::upgrade::toolchain::convert_toolchain_upgrade;
+    use std::path::PathBuf;
+    use sublime_pkg_tools::upgrade::{ToolchainPin, ToolchainUpgrade, UpgradeType};
+
+    let upgrade = ToolchainUpgrade {
+        package_name: "web".to_string(),
+        package_path: PathBuf::from("/workspace/packages/web"),
+        pin: ToolchainPin::PackageManager,
+        tool: "pnpm".to_string(),
+        current_spec: "pnpm@9.1.0".to_string(),
+        current_version: "9.1.0".to_string(),
+        latest_version: "9.4.0".to_string(),
+        new_spec: "pnpm@9.4.0".to_string(),
+        upgrade_type: UpgradeType::Minor,
+    };
+    let info = convert_toolchain_upgrade(&upgrade);
+
+    let check = ToolchainCheckResponse {
+        success: true,
+        upgrades: vec![info.clone()],
+        offline_skipped: vec!["node".to_string()],
+    };
+    let json = serde_json::to_value(&check).expect("Should serialize to JSON");
+    assert_eq!(json["upgrades"][0]["field"], "packageManager");
+    assert_eq!(json["upgrades"][0]["latest"], "pnpm@9.4.0");
+    assert_eq!(json["upgrades"][0]["type"], "minor");
+    assert_eq!(json["offlineSkipped"][0], "node");
+
+    let apply = ToolchainApplyResponse {
+        success: true,
+        dry_run: true,
+        applied: vec![info],
+        backup_id: None,
+        changeset_id: None,
+    };
+    let json = serde_json::to_value(&apply).expect("Should serialize to JSON");
+    assert_eq!(json["dryRun"], true);
+    assert!(json.get("backupId").is_none());
+    assert!(json.get("changesetId").is_none());
+}
@@ -0,0 +1,294 @@
+//! Toolchain pin upgrades for the upgrade commands.
+//!
+//! This module implements `workspace upgrade check --toolchain` and
+//! `workspace upgrade apply --toolchain`, which work on toolchain pins instead of
+//! dependencies.
+//!
+//! # What
+//!
+//! Provides:
+//! - Detection of outdated `engines.node`, `packageManager`, and Volta pins
+//! - Filtering by upgrade type and package, like dependency upgrades
+//! - Application with the same backup and changeset handling as dependency upgrades
+//! - Formatted output (table or JSON)
+//!
+//! # How
+//!
+//! The commands:
+//! 1. Create an `UpgradeManager` from the loaded configuration
+//! 2. Detect toolchain upgrades against the Node.js and npm release feeds
+//! 3. Filter them with the upgrade type and package flags
+//! 4. For apply, confirm (unless --force or --dry-run) and apply the selection
+//! 5. Output the pins as a table (human) or JSON
+//!
+//! # Why
+//!
+//! Node.js and package manager pins go stale as quickly as dependencies. Reporting and
+//! applying them through the upgrade commands keeps one workflow for both.
+//!
+//! # Examples
+//!
+//! ```bash
+//! # List outdated toolchain pins
+//! workspace upgrade check --toolchain
+//!
+//! # Upgrade non-breaking toolchain pins with a changeset
+//! workspace upgrade apply --toolchain --minor-and-patch --auto-changeset
+//! ```
+
+use crate::cli::commands::{UpgradeApplyArgs, UpgradeCheckArgs};
+use crate::commands::upgrade::apply::{create_upgrade_config, create_upgrade_selection};
+use crate::commands::upgrade::check::report_registry_retries;
+use crate::commands::upgrade::types::{
+    ToolchainApplyResponse, ToolchainCheckResponse, ToolchainUpgradeInfo,
+};
+use crate::error::{CliError, Result};
+use crate::interactive::prompts::prompt_confirm;
+use crate::output::{JsonResponse, Output, table::TableBuilder};
+use std::path::Path;
+use sublime_pkg_tools::config::PackageToolsConfig;
+use sublime_pkg_tools::upgrade::{ToolchainUpgrade, UpgradeManager, UpgradeType};
+use tracing::{debug, info};
+
+/// Executes `upgrade check --toolchain`.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments; the upgrade type flags and `--packages` filter the pins
+/// * `output` - Output context for formatting
+/// * `workspace_root` - Path to the workspace root directory
+/// * `config` - Loaded package tools configuration
+///
+/// # Errors
+///
+/// Returns an error if the upgrade manager cannot be created, detection fails, or output
+/// cannot be written.
+pub(crate) async fn execute_toolchain_check(
+    args: &UpgradeCheckArgs,
+    output: &Output,
+    workspace_root: &Path,
+    config: PackageToolsConfig,
+) -> Result<()> {
+    info!("Detecting toolchain pin upgrades");
+    let upgrade_manager =
+        UpgradeManager::new(workspace_root.to_path_buf(), config.upgrade)
+            .await
+            .map_err(|e| CliError::execution(format!("Failed to create upgrade manager: {e}")))?;
+    report_registry_retries(&upgrade_manager, output);
+
+    let preview = upgrade_manager
+        .detect_toolchain_upgrades()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to detect toolchain upgrades: {e}")))?;
+
+    let include_major = args.major && !args.no_major;
+    let include_minor = args.minor && !args.no_minor;
+    let include_patch = args.patch && !args.no_patch;
+    let upgrades: Vec<ToolchainUpgradeInfo> = preview
+        .upgrades
+        .iter()
+        .filter(|upgrade| match upgrade.upgrade_type {
+            UpgradeType::Major => include_major,
+            UpgradeType::Minor => include_minor,
+            UpgradeType::Patch => include_patch,
+        })
+        .filter(|upgrade| {
+            args.packages.as_ref().is_none_or(|packages| packages.contains(&upgrade.package_name))
+        })
+        .map(convert_toolchain_upgrade)
+        .collect();
+
+    debug!("Found {} toolchain pin upgrades", upgrades.len());
+
+    if output.format().is_json() {
+        let response = ToolchainCheckResponse {
+            success: true,
+            upgrades,
+            offline_skipped: preview.offline_skipped,
+        };
+        return output.json(&JsonResponse::success(response));
+    }
+
+    if !output.format().is_human() {
+        if !upgrades.is_empty() {
+            output.plain(&format!("{} toolchain upgrades available", upgrades.len()))?;
+        }
+        return Ok(());
+    }
+
+    let title = console::style("Toolchain Upgrades Available").bold().cyan();
+    output.plain(&title.to_string())?;
+    output.plain("━━━━━━━━━━━━━━━━━━━━━━━━━━━━")?;
+    output.blank_line()?;
+
+    if !preview.offline_skipped.is_empty() {
+        output.warning(&format!(
+            "Offline mode: release feeds skipped for {}, which may have upgrades not listed here",
+            preview.offline_skipped.join(", ")
+        ))?;
+        output.blank_line()?;
+    }
+
+    if upgrades.is_empty() {
+        output.success("All toolchain pins are up to date!")?;
+        return Ok(());
+    }
+
+    output_table(output, &upgrades, &["Package", "Field", "Current", "Latest", "Type"])
+}
+
+/// Executes `upgrade apply --toolchain`.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments; selection, backup, and changeset flags apply to the pins
+/// * `output` - Output context for formatting
+/// * `workspace_root` - Path to the workspace root directory
+/// * `config` - Loaded package tools configuration
+///
+/// # Errors
+///
+/// Returns an error if the upgrade manager cannot be created, or detection, application,
+/// backup, or changeset creation fails.
+pub(crate) async fn execute_toolchain_apply(
+    args: &UpgradeApplyArgs,
+    output: &Output,
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+) -> Result<()> {
+    let upgrade_config = create_upgrade_config(args, config);
+    let mut upgrade_manager = UpgradeManager::new(workspace_root.to_path_buf(), upgrade_config)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create upgrade manager: {e}")))?
+        .with_changeset_config(config.changeset.clone());
+    report_registry_retries(&upgrade_manager, output);
+
+    let selection = create_upgrade_selection(args);
+
+    if !args.force && !args.dry_run && output.format().is_human() {
+        let preview = upgrade_manager.detect_toolchain_upgrades().await.map_err(|e| {
+            CliError::execution(format!("Failed to detect toolchain upgrades: {e}"))
+        })?;
+        let count = preview
+            .upgrades
+            .iter()
+            .filter(|upgrade| {
+                selection.matches_type(upgrade.upgrade_type)
+                    && selection.matches_package(&upgrade.package_name)
+            })
+            .count();
+        if count == 0 {
+            output.info("No toolchain upgrades match the selection criteria")?;
+            return Ok(());
+        }
+
+        output.blank_line()?;
+        output.plain(
+            &console::style(format!(
+                "This will upgrade {count} toolchain pin{} in your workspace.",
+                if count == 1 { "" } else { "s" }
+            ))
+            .yellow()
+            .to_string(),
+        )?;
+        output.blank_line()?;
+        if !prompt_confirm("Do you want to proceed?", true, output.no_color())? {
+            info!("User cancelled toolchain upgrade");
+            output.info("Upgrade cancelled")?;
+            return Ok(());
+        }
+    }
+
+    info!("Applying toolchain upgrades (dry_run={})", args.dry_run);
+    let result = upgrade_manager
+        .apply_toolchain_upgrades(selection, args.dry_run)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to apply toolchain upgrades: {e}")))?;
+
+    let applied: Vec<ToolchainUpgradeInfo> =
+        result.applied.iter().map(convert_toolchain_upgrade).collect();
+    let backup_id = result
+        .backup_path
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string());
+
+    if output.format().is_json() {
+        let response = ToolchainApplyResponse {
+            success: true,
+            dry_run: args.dry_run,
+            applied,
+            backup_id,
+            changeset_id: result.changeset_id,
+        };
+        return output.json(&JsonResponse::success(response));
+    }
+
+    if !output.format().is_human() {
+        if !applied.is_empty() {
+            let verb = if args.dry_run { "would upgrade" } else { "upgraded" };
+            output.plain(&format!("{} {verb}", applied.len()))?;
+        }
+        return Ok(());
+    }
+
+    if applied.is_empty() {
+        output.info("No toolchain upgrades match the selection criteria")?;
+        return Ok(());
+    }
+
+    let title = if args.dry_run {
+        console::style("Toolchain Upgrade Preview (Dry Run)").bold().cyan()
+    } else {
+        console::style("Toolchain Upgrades Applied").bold().green()
+    };
+    output.plain(&title.to_string())?;
+    output.plain("━━━━━━━━━━━━━━━━━━━━━━━━━━━━")?;
+    output.blank_line()?;
+    output_table(output, &applied, &["Package", "Field", "From", "To", "Type"])?;
+
+    if let Some(changeset_id) = &result.changeset_id {
+        output.blank_line()?;
+        output.plain(&format!("Changeset updated: {changeset_id}"))?;
+    }
+    if let Some(backup_id) = &backup_id {
+        output.blank_line()?;
+        output.plain(&format!("Backup created: {backup_id}"))?;
+    }
+    if args.dry_run {
+        output.blank_line()?;
+        output.info("This was a dry run. No files were modified.")?;
+    }
+
+    Ok(())
+}
+
+/// Converts a toolchain upgrade from package tools to its CLI representation.
+pub(crate) fn convert_toolchain_upgrade(upgrade: &ToolchainUpgrade) -> ToolchainUpgradeInfo {
+    ToolchainUpgradeInfo {
+        package: upgrade.package_name.clone(),
+        field: upgrade.field(),
+        current: upgrade.current_spec.clone(),
+        latest: upgrade.new_spec.clone(),
+        upgrade_type: upgrade.upgrade_type.as_str().to_string(),
+    }
+}
+
+/// Outputs toolchain upgrades as a table with the given column headers.
+fn output_table(
+    output: &Output,
+    upgrades: &[ToolchainUpgradeInfo],
+    columns: &[&str],
+) -> Result<()> {
+    let mut table = TableBuilder::new().columns(columns).build();
+    for upgrade in upgrades {
+        table.add_row(&[
+            &upgrade.package,
+            &upgrade.field,
+            &upgrade.current,
+            &upgrade.latest,
+            &upgrade.upgrade_type,
+        ]);
+    }
+    output.table(&mut table)
+}
@@ -26,6 +26,7 @@
 //! - Simplifies serialization logic
 
 use serde::{Deserialize, Serialize};
+use sublime_pkg_tools::types::Warning;
 
 /// Summary statistics for upgrade detection.
 ///
@@ -165,11 +166,15 @@ impl Default for UpgradeSummary {
 ///     success: true,
 ///     packages: vec![],
 ///     summary: Default::default(),
+///     offline_skipped: vec![],
+///     deadline_skipped: vec![],
+///     warnings: vec![],
 /// };
 ///
 /// let json = serde_json::to_string_pretty(&response).unwrap();
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UpgradeCheckResponse {
     /// Whether the command succeeded
     pub success: bool,
@@ -179,6 +184,18 @@ pub struct UpgradeCheckResponse {
 
     /// Summary statistics
     pub summary: UpgradeSummary,
+
+    /// Dependencies whose registry lookup was skipped in offline mode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub offline_skipped: Vec<String>,
+
+    /// Dependencies whose registry lookup did not finish before the `--timeout` deadline
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deadline_skipped: Vec<String>,
+
+    /// Non-fatal issues found during detection, such as failed registry lookups
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
 }
 
 /// Information about available upgrades for a single package.
@@ -259,7 +276,7 @@ impl PackageUpgradeInfo {
 /// # Examples
 ///
 /// ```rust
-/// use sublime_cli_tools::commands::upgrade::types::DependencyUpgradeInfo;
+/// use sublime_cli_tools::commands::upgrade::types::{DependencyUpgradeInfo, UpgradeInsightsInfo};
 ///
 /// let upgrade = DependencyUpgradeInfo {
 ///     package: "typescript".to_string(),
@@ -267,6 +284,7 @@ impl PackageUpgradeInfo {
 ///     latest_version: "5.3.3".to_string(),
 ///     upgrade_type: "minor".to_string(),
 ///     breaking: false,
+///     insights: UpgradeInsightsInfo::default(),
 /// };
 ///
 /// assert!(!upgrade.breaking);
@@ -291,6 +309,70 @@ pub struct DependencyUpgradeInfo {
 
     /// Whether this upgrade includes breaking changes
     pub breaking: bool,
+
+    /// Registry metadata that helps judge the risk of the upgrade
+    #[serde(default)]
+    pub insights: UpgradeInsightsInfo,
+}
+
+/// Registry metadata attached to a dependency upgrade.
+///
+/// Surfaces deprecation notices, the publication date of the target version, the local
+/// patch of the dependency and, when requested, the weekly download trend so users can
+/// judge upgrade risk.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::upgrade::types::UpgradeInsightsInfo;
+///
+/// let insights = UpgradeInsightsInfo {
+///     target_deprecated: Some("Use v6 instead".to_string()),
+///     ..Default::default()
+/// };
+///
+/// assert!(insights.has_deprecation());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpgradeInsightsInfo {
+    /// Publication date of the target version (RFC 3339)
+    #[serde(rename = "publishedAt", skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+
+    /// Deprecation notice of the currently used version
+    #[serde(rename = "currentDeprecated", skip_serializing_if = "Option::is_none")]
+    pub current_deprecated: Option<String>,
+
+    /// Deprecation notice of the target version
+    #[serde(rename = "targetDeprecated", skip_serializing_if = "Option::is_none")]
+    pub target_deprecated: Option<String>,
+
+    /// Downloads over the last seven days
+    #[serde(rename = "weeklyDownloads", skip_serializing_if = "Option::is_none")]
+    pub weekly_downloads: Option<u64>,
+
+    /// Week-over-week download trend (rising, stable, declining)
+    #[serde(rename = "downloadTrend", skip_serializing_if = "Option::is_none")]
+    pub download_trend: Option<String>,
+
+    /// Local patch of the dependency that the upgrade would invalidate
+    #[serde(rename = "patchFile", skip_serializing_if = "Option::is_none")]
+    pub patch_file: Option<String>,
+}
+
+impl UpgradeInsightsInfo {
+    /// Returns true if either the current or the target version is deprecated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::commands::upgrade::types::UpgradeInsightsInfo;
+    ///
+    /// assert!(!UpgradeInsightsInfo::default().has_deprecation());
+    /// ```
+    pub fn has_deprecation(&self) -> bool {
+        self.current_deprecated.is_some() || self.target_deprecated.is_some()
+    }
 }
 
 impl DependencyUpgradeInfo {
@@ -299,7 +381,7 @@ impl DependencyUpgradeInfo {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_cli_tools::commands::upgrade::types::DependencyUpgradeInfo;
+    /// use sublime_cli_tools::commands::upgrade::types::{DependencyUpgradeInfo, UpgradeInsightsInfo};
     ///
     /// let upgrade = DependencyUpgradeInfo {
     ///     package: "eslint".to_string(),
@@ -307,6 +389,7 @@ impl DependencyUpgradeInfo {
     ///     latest_version: "9.0.0".to_string(),
     ///     upgrade_type: "major".to_string(),
     ///     breaking: true,
+    ///     insights: UpgradeInsightsInfo::default(),
     /// };
     ///
     /// assert!(upgrade.is_major());
@@ -320,7 +403,7 @@ impl DependencyUpgradeInfo {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_cli_tools::commands::upgrade::types::DependencyUpgradeInfo;
+    /// use sublime_cli_tools::commands::upgrade::types::{DependencyUpgradeInfo, UpgradeInsightsInfo};
     ///
     /// let upgrade = DependencyUpgradeInfo {
     ///     package: "typescript".to_string(),
@@ -328,6 +411,7 @@ impl DependencyUpgradeInfo {
     ///     latest_version: "5.3.3".to_string(),
     ///     upgrade_type: "minor".to_string(),
     ///     breaking: false,
+    ///     insights: UpgradeInsightsInfo::default(),
     /// };
     ///
     /// assert!(upgrade.is_minor());
@@ -341,7 +425,7 @@ impl DependencyUpgradeInfo {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_cli_tools::commands::upgrade::types::DependencyUpgradeInfo;
+    /// use sublime_cli_tools::commands::upgrade::types::{DependencyUpgradeInfo, UpgradeInsightsInfo};
     ///
     /// let upgrade = DependencyUpgradeInfo {
     ///     package: "vitest".to_string(),
@@ -349,6 +433,7 @@ impl DependencyUpgradeInfo {
     ///     latest_version: "1.0.1".to_string(),
     ///     upgrade_type: "patch".to_string(),
     ///     breaking: false,
+    ///     insights: UpgradeInsightsInfo::default(),
     /// };
     ///
     /// assert!(upgrade.is_patch());
@@ -373,11 +458,14 @@ impl DependencyUpgradeInfo {
 ///     applied: vec![],
 ///     skipped: vec![],
 ///     summary: Default::default(),
+///     pr_body: None,
+///     warnings: vec![],
 /// };
 ///
 /// assert!(response.success);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UpgradeApplyResponse {
     /// Whether the command succeeded
     pub success: bool,
@@ -390,6 +478,14 @@ pub struct UpgradeApplyResponse {
 
     /// Summary statistics
     pub summary: ApplySummary,
+
+    /// Pull request description of the applied upgrades (with `--pr-body`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pr_body: Option<String>,
+
+    /// Non-fatal issues found while detecting and applying the upgrades
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<Warning>,
 }
 
 /// Information about a successfully applied upgrade.
@@ -406,6 +502,8 @@ pub struct UpgradeApplyResponse {
 ///     from: "5.0.0".to_string(),
 ///     to: "5.3.3".to_string(),
 ///     upgrade_type: "minor".to_string(),
+///     manifest: "packages/app/package.json".to_string(),
+///     workspace_root: false,
 /// };
 ///
 /// assert_eq!(applied.package, "typescript");
@@ -424,6 +522,14 @@ pub struct AppliedUpgradeInfo {
     /// Upgrade type (major, minor, patch)
     #[serde(rename = "type")]
     pub upgrade_type: String,
+
+    /// Manifest the upgrade was written to, relative to the workspace root
+    #[serde(default)]
+    pub manifest: String,
+
+    /// Whether the manifest is the workspace root
+    #[serde(default)]
+    pub workspace_root: bool,
 }
 
 /// Information about a skipped upgrade.
@@ -449,7 +555,7 @@ pub struct SkippedUpgradeInfo {
     /// Dependency package name
     pub package: String,
 
-    /// Reason for skipping (e.g., "major_version", "user_filter", "conflict")
+    /// Reason for skipping (e.g., "major_version", "user_filter", "unchanged_spec")
     pub reason: String,
 
     /// Current version
@@ -461,6 +567,44 @@ pub struct SkippedUpgradeInfo {
     pub latest_version: String,
 }
 
+/// Outcome of the post-upgrade verification command.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::upgrade::types::VerificationInfo;
+///
+/// let verification = VerificationInfo {
+///     command: "pnpm -r test".to_string(),
+///     upgrade_type: "minor".to_string(),
+///     passed: true,
+///     duration_ms: 5400,
+///     reason: None,
+/// };
+///
+/// assert!(verification.passed);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationInfo {
+    /// Command that was run
+    pub command: String,
+
+    /// Semver level the command was chosen for (major, minor, patch)
+    #[serde(rename = "upgradeType")]
+    pub upgrade_type: String,
+
+    /// Whether the command succeeded
+    pub passed: bool,
+
+    /// How long the command ran, in milliseconds
+    #[serde(rename = "durationMs")]
+    pub duration_ms: u64,
+
+    /// Why the command failed, if it did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
 /// Summary statistics for an upgrade apply operation.
 ///
 /// Provides counts and metadata about the apply operation including backup information.
@@ -474,6 +618,8 @@ pub struct SkippedUpgradeInfo {
 ///     total_applied: 10,
 ///     total_skipped: 2,
 ///     backup_id: Some("backup_20240115_103045".to_string()),
+///     changeset_id: Some("feature/upgrade-deps".to_string()),
+///     verification: None,
 /// };
 ///
 /// assert_eq!(summary.total_applied, 10);
@@ -492,6 +638,14 @@ pub struct ApplySummary {
     /// Backup ID if backup was created
     #[serde(rename = "backupId", skip_serializing_if = "Option::is_none")]
     pub backup_id: Option<String>,
+
+    /// Changeset created or updated for the upgrades, if any
+    #[serde(rename = "changesetId", default, skip_serializing_if = "Option::is_none")]
+    pub changeset_id: Option<String>,
+
+    /// Outcome of the post-upgrade verification, if a command ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<VerificationInfo>,
 }
 
 impl ApplySummary {
@@ -508,7 +662,13 @@ impl ApplySummary {
     /// assert!(summary.backup_id.is_none());
     /// ```
     pub fn new() -> Self {
-        Self { total_applied: 0, total_skipped: 0, backup_id: None }
+        Self {
+            total_applied: 0,
+            total_skipped: 0,
+            backup_id: None,
+            changeset_id: None,
+            verification: None,
+        }
     }
 
     /// Returns the total number of upgrades processed (applied + skipped).
@@ -522,6 +682,8 @@ impl ApplySummary {
     ///     total_applied: 10,
     ///     total_skipped: 2,
     ///     backup_id: None,
+    ///     changeset_id: None,
+    ///     verification: None,
     /// };
     ///
     /// assert_eq!(summary.total_processed(), 12);
@@ -536,3 +698,102 @@ impl Default for ApplySummary {
         Self::new()
     }
 }
+
+/// Information about an upgrade of a toolchain pin.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::upgrade::types::ToolchainUpgradeInfo;
+///
+/// let upgrade = ToolchainUpgradeInfo {
+///     package: "my-workspace".to_string(),
+///     field: "packageManager".to_string(),
+///     current: "pnpm@8.15.0".to_string(),
+///     latest: "pnpm@9.12.0".to_string(),
+///     upgrade_type: "major".to_string(),
+/// };
+///
+/// assert_eq!(upgrade.field, "packageManager");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolchainUpgradeInfo {
+    /// Package whose package.json holds the pin
+    pub package: String,
+
+    /// Pinned field (e.g., "engines.node", "packageManager", "volta.node")
+    pub field: String,
+
+    /// Value of the pin as written
+    pub current: String,
+
+    /// Value the upgrade writes
+    pub latest: String,
+
+    /// Upgrade type (major, minor, patch)
+    #[serde(rename = "type")]
+    pub upgrade_type: String,
+}
+
+/// JSON response for `upgrade check --toolchain`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::upgrade::types::ToolchainCheckResponse;
+///
+/// let response = ToolchainCheckResponse { success: true, upgrades: vec![], offline_skipped: vec![] };
+///
+/// assert!(response.upgrades.is_empty());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolchainCheckResponse {
+    /// Whether the command succeeded
+    pub success: bool,
+
+    /// Available toolchain pin upgrades
+    pub upgrades: Vec<ToolchainUpgradeInfo>,
+
+    /// Tools whose release feed was not queried in offline mode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub offline_skipped: Vec<String>,
+}
+
+/// JSON response for `upgrade apply --toolchain`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_cli_tools::commands::upgrade::types::ToolchainApplyResponse;
+///
+/// let response = ToolchainApplyResponse {
+///     success: true,
+///     dry_run: true,
+///     applied: vec![],
+///     backup_id: None,
+///     changeset_id: None,
+/// };
+///
+/// assert!(response.dry_run);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolchainApplyResponse {
+    /// Whether the command succeeded
+    pub success: bool,
+
+    /// Whether this was a dry run
+    pub dry_run: bool,
+
+    /// Pin upgrades applied, or that would be applied in a dry run
+    pub applied: Vec<ToolchainUpgradeInfo>,
+
+    /// Backup kept after success, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup_id: Option<String>,
+
+    /// Changeset created or updated, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changeset_id: Option<String>,
+}
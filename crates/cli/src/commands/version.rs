@@ -227,9 +227,9 @@ pub fn execute_version(args: &VersionArgs, _root: &Path, format: OutputFormat) -
         OutputFormat::Human => {
             display_human_version(&version_info, args.verbose);
         }
-        OutputFormat::Json | OutputFormat::JsonCompact => {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
             let json_response = JsonResponse::success(version_info);
-            let json_str = if format == OutputFormat::JsonCompact {
+            let json_str = if matches!(format, OutputFormat::JsonCompact | OutputFormat::Ndjson) {
                 serde_json::to_string(&json_response)
             } else {
                 serde_json::to_string_pretty(&json_response)
@@ -0,0 +1,146 @@
+//! Why command implementation.
+//!
+//! This module implements the `workspace why` command which explains why a package is part
+//! of the workspace dependency tree.
+//!
+//! # What
+//!
+//! Provides:
+//! - `execute_why` - Lists the workspace packages that declare a package, with their specs,
+//!   and the dependency chains leading to it
+//!
+//! # How
+//!
+//! The command discovers the workspace packages, builds a `DependencyGraph` from
+//! `sublime_pkg_tools` and asks it to explain the package with `DependencyGraph::why`. With
+//! `--from`, only chains starting at that workspace package are shown.
+//!
+//! # Why
+//!
+//! Like `pnpm why`, this answers "who pulls this in?" for workspace packages and external
+//! dependencies alike, which helps when deciding whether a dependency can be removed or why a
+//! change affects a given package.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use sublime_cli_tools::commands::why::execute_why;
+//! use sublime_cli_tools::cli::commands::WhyArgs;
+//! use sublime_cli_tools::output::{Output, OutputFormat};
+//! use std::io;
+//! use std::path::Path;
+//!
+//! # async fn example() -> sublime_cli_tools::error::Result<()> {
+//! let args = WhyArgs { package: "lodash".to_string(), from: None };
+//! let output = Output::new(OutputFormat::Human, io::stdout(), false);
+//! execute_why(&args, &output, Path::new("."), None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cli::commands::WhyArgs;
+use crate::commands::find_and_load_config;
+use crate::error::{CliError, Result};
+use crate::output::{JsonResponse, Output};
+use std::path::Path;
+use sublime_pkg_tools::types::DependencyExplanation;
+use sublime_pkg_tools::version::{DependencyGraph, VersionResolver};
+use tracing::{debug, info};
+
+/// Executes the `why` command.
+///
+/// # Arguments
+///
+/// * `args` - Command arguments
+/// * `output` - Output context
+/// * `root` - Workspace root directory
+/// * `config_path` - Optional path to config file
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The configuration cannot be read
+/// - Workspace packages cannot be discovered
+/// - `--from` names a package that is not in the workspace
+/// - The package is neither a workspace package nor declared by one
+pub async fn execute_why(
+    args: &WhyArgs,
+    output: &Output,
+    root: &Path,
+    config_path: Option<&Path>,
+) -> Result<()> {
+    debug!("Explaining why '{}' is in workspace: {}", args.package, root.display());
+
+    let config = find_and_load_config(root, config_path).await?.unwrap_or_default();
+    let resolver = VersionResolver::new(root.to_path_buf(), config)
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to create version resolver: {e}")))?;
+    let packages = resolver
+        .discover_packages()
+        .await
+        .map_err(|e| CliError::execution(format!("Failed to discover packages: {e}")))?;
+    let graph = DependencyGraph::from_packages(&packages)
+        .map_err(|e| CliError::execution(format!("Failed to build dependency graph: {e}")))?;
+
+    if let Some(from) = args.from.as_deref()
+        && !graph.contains(from)
+    {
+        return Err(CliError::validation(format!("'{from}' is not a workspace package")));
+    }
+
+    let explanation = graph.why(&args.package, args.from.as_deref()).ok_or_else(|| {
+        CliError::validation(format!(
+            "'{}' is not a workspace package and no workspace package depends on it",
+            args.package
+        ))
+    })?;
+
+    info!(
+        "'{}' is declared by {} package(s) through {} chain(s)",
+        args.package,
+        explanation.required_by.len(),
+        explanation.chains.len()
+    );
+
+    if output.format().is_json() {
+        return output.json(&JsonResponse::success(explanation));
+    }
+
+    display_explanation(&explanation, args.from.as_deref(), output)
+}
+
+/// Prints the direct declarations and dependency chains of an explanation.
+fn display_explanation(
+    explanation: &DependencyExplanation,
+    from: Option<&str>,
+    output: &Output,
+) -> Result<()> {
+    let kind = if explanation.internal { "workspace package" } else { "external dependency" };
+    output.info(&format!("{} ({kind})", explanation.package))?;
+
+    if explanation.required_by.is_empty() {
+        return match from {
+            Some(from) => output.plain(&format!("Not reachable from {from}")),
+            None => output.plain("No workspace package depends on it"),
+        };
+    }
+
+    output.blank_line()?;
+    output.plain("Required by:")?;
+    for requirement in &explanation.required_by {
+        output.plain(&format!(
+            "  {} {} ({})",
+            requirement.dependent,
+            requirement.version_spec,
+            requirement.dependency_type.as_str()
+        ))?;
+    }
+
+    output.blank_line()?;
+    output.plain("Dependency chains:")?;
+    for chain in explanation.display_chains() {
+        output.plain(&format!("  {chain}"))?;
+    }
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 //! including helper methods, conversions, and trait implementations.
 
 use crate::error::exit_codes::ExitCode;
+use sublime_pkg_tools::error::{AuditError, Diagnose, Diagnostic, UpgradeError};
 
 /// Result type alias for CLI operations.
 ///
@@ -37,6 +38,10 @@ pub type Result<T> = std::result::Result<T, CliError>;
 /// - `Io`: File system errors, permission issues
 /// - `Network`: Registry unreachable, download failures
 /// - `User`: Invalid input, cancelled operations
+/// - `Timeout`: The `--timeout` deadline passed before the operation finished
+///
+/// Errors converted from `sublime_pkg_tools` keep their structured diagnostic (error code,
+/// help, source location) in the `Diagnosed` variant, which wraps the categorized error.
 ///
 /// # Examples
 ///
@@ -144,6 +149,28 @@ pub enum CliError {
     /// - Interactive prompt declined
     #[error("User error: {0}")]
     User(String),
+
+    /// The global operation deadline passed.
+    ///
+    /// Exit code: 75 (EX_TEMPFAIL)
+    ///
+    /// # Examples
+    ///
+    /// - Registry lookups still pending when `--timeout` expires
+    /// - A git fetch or push stalled past the deadline
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
+    /// An error carrying a structured diagnostic from a library crate.
+    ///
+    /// Exit code, kind, and message are those of the wrapped error.
+    #[error("{error}")]
+    Diagnosed {
+        /// The categorized error.
+        error: Box<CliError>,
+        /// Error code, help, and source location.
+        diagnostic: Box<Diagnostic>,
+    },
 }
 
 impl CliError {
@@ -259,6 +286,20 @@ impl CliError {
         Self::User(msg.into())
     }
 
+    /// Creates a new Timeout error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::error::CliError;
+    ///
+    /// let error = CliError::timeout("Registry lookups exceeded the deadline");
+    /// assert_eq!(error.exit_code(), 75);
+    /// ```
+    pub fn timeout(msg: impl Into<String>) -> Self {
+        Self::Timeout(msg.into())
+    }
+
     /// Returns the exit code for this error following sysexits convention.
     ///
     /// # Exit Codes
@@ -271,6 +312,7 @@ impl CliError {
     /// - `Io`: 74 (EX_IOERR)
     /// - `Network`: 69 (EX_UNAVAILABLE)
     /// - `User`: 64 (EX_USAGE)
+    /// - `Timeout`: 75 (EX_TEMPFAIL)
     ///
     /// # Examples
     ///
@@ -291,6 +333,8 @@ impl CliError {
             Self::Io(_) => ExitCode::IOERR,
             Self::Network(_) => ExitCode::UNAVAILABLE,
             Self::User(_) => ExitCode::USAGE,
+            Self::Timeout(_) => ExitCode::TEMPFAIL,
+            Self::Diagnosed { error, .. } => error.exit_code(),
         }
     }
 
@@ -318,6 +362,8 @@ impl CliError {
             Self::Io(msg) => format!("I/O error: {msg}"),
             Self::Network(msg) => format!("Network error: {msg}"),
             Self::User(msg) => format!("Error: {msg}"),
+            Self::Timeout(msg) => format!("Timed out: {msg}"),
+            Self::Diagnosed { error, .. } => error.user_message(),
         }
     }
 
@@ -346,6 +392,48 @@ impl CliError {
             Self::Io(_) => "Io",
             Self::Network(_) => "Network",
             Self::User(_) => "User",
+            Self::Timeout(_) => "Timeout",
+            Self::Diagnosed { error, .. } => error.kind(),
+        }
+    }
+
+    /// Attaches a structured diagnostic to this error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::error::CliError;
+    /// use sublime_pkg_tools::error::{ConfigError, Diagnose};
+    /// use std::path::PathBuf;
+    ///
+    /// let source = ConfigError::NotFound { path: PathBuf::from("repo.config.toml") };
+    /// let error = CliError::configuration(source.to_string()).with_diagnostic(source.diagnostic());
+    ///
+    /// assert_eq!(error.exit_code(), 78);
+    /// assert_eq!(error.diagnostic().map(|d| d.code.as_str()), Some("config::not_found"));
+    /// ```
+    #[must_use]
+    pub fn with_diagnostic(self, diagnostic: Diagnostic) -> Self {
+        let diagnostic = Box::new(diagnostic);
+        match self {
+            Self::Diagnosed { error, .. } => Self::Diagnosed { error, diagnostic },
+            error => Self::Diagnosed { error: Box::new(error), diagnostic },
+        }
+    }
+
+    /// Returns the structured diagnostic, if the error carries one.
+    pub fn diagnostic(&self) -> Option<&Diagnostic> {
+        match self {
+            Self::Diagnosed { diagnostic, .. } => Some(diagnostic),
+            _ => None,
+        }
+    }
+
+    /// Returns the categorized error, without any attached diagnostic.
+    pub fn categorized(&self) -> &Self {
+        match self {
+            Self::Diagnosed { error, .. } => error.categorized(),
+            _ => self,
         }
     }
 }
@@ -377,6 +465,8 @@ impl AsRef<str> for CliError {
             Self::Io(_) => "CliError::Io",
             Self::Network(_) => "CliError::Network",
             Self::User(_) => "CliError::User",
+            Self::Timeout(_) => "CliError::Timeout",
+            Self::Diagnosed { error, .. } => <Self as AsRef<str>>::as_ref(error),
         }
     }
 }
@@ -398,16 +488,27 @@ impl From<sublime_git_tools::RepoError> for CliError {
     /// assert_eq!(cli_error.exit_code(), 70);
     /// ```
     fn from(error: sublime_git_tools::RepoError) -> Self {
-        Self::Git(format!("{error}"))
+        match error {
+            sublime_git_tools::RepoError::DeadlineExceeded { .. } => {
+                Self::Timeout(format!("{error}"))
+            }
+            _ => Self::Git(format!("{error}")),
+        }
     }
 }
 
 impl From<sublime_pkg_tools::error::Error> for CliError {
     /// Converts a package tools error to a CLI error.
     ///
-    /// Maps package tool errors to appropriate CLI error categories.
+    /// Maps package tool errors to appropriate CLI error categories and keeps
+    /// the error's structured diagnostic for rendering.
     fn from(error: sublime_pkg_tools::error::Error) -> Self {
-        match error {
+        let diagnostic = error.diagnostic();
+        let categorized = match error {
+            sublime_pkg_tools::error::Error::Upgrade(UpgradeError::DeadlineExceeded { .. })
+            | sublime_pkg_tools::error::Error::Audit(AuditError::DeadlineExceeded { .. }) => {
+                Self::Timeout(format!("{error}"))
+            }
             sublime_pkg_tools::error::Error::Config(_) => Self::Configuration(format!("{error}")),
             sublime_pkg_tools::error::Error::Version(_) => Self::Validation(format!("{error}")),
             sublime_pkg_tools::error::Error::Changeset(_)
@@ -415,11 +516,38 @@ impl From<sublime_pkg_tools::error::Error> for CliError {
             | sublime_pkg_tools::error::Error::Changelog(_)
             | sublime_pkg_tools::error::Error::Upgrade(_)
             | sublime_pkg_tools::error::Error::Audit(_)
+            | sublime_pkg_tools::error::Error::Hook(_)
+            | sublime_pkg_tools::error::Error::Notification(_)
+            | sublime_pkg_tools::error::Error::Editor(_)
             | sublime_pkg_tools::error::Error::Json(_) => Self::Execution(format!("{error}")),
             sublime_pkg_tools::error::Error::FileSystem(msg) => Self::Io(msg),
             sublime_pkg_tools::error::Error::Git(msg) => Self::Git(msg),
             sublime_pkg_tools::error::Error::IO(io_err) => Self::Io(format!("{io_err}")),
-        }
+        };
+        categorized.with_diagnostic(diagnostic)
+    }
+}
+
+impl From<sublime_pkg_tools::error::HookError> for CliError {
+    /// Converts a hook management error to a CLI error.
+    ///
+    /// Refusing to overwrite a user's hook and invalid hook configuration are
+    /// validation failures; filesystem and git failures keep their categories.
+    fn from(error: sublime_pkg_tools::error::HookError) -> Self {
+        use sublime_pkg_tools::error::HookError;
+        let diagnostic = error.diagnostic();
+        let categorized = match error {
+            HookError::UnknownHook { .. }
+            | HookError::UnmanagedHook { .. }
+            | HookError::InvalidConfig { .. }
+            | HookError::DuplicateHook { .. } => Self::Validation(format!("{error}")),
+            HookError::FileSystemError { .. } => Self::Io(format!("{error}")),
+            HookError::ContextError { .. } | HookError::ExecutionFailed { .. } => {
+                Self::Execution(format!("{error}"))
+            }
+            HookError::GitError { .. } => Self::Git(format!("{error}")),
+        };
+        categorized.with_diagnostic(diagnostic)
     }
 }
 
@@ -439,6 +567,9 @@ impl From<sublime_standard_tools::error::Error> for CliError {
                 Self::Package(format!("{error}"))
             }
             sublime_standard_tools::error::Error::Operation(msg) => Self::Execution(msg),
+            sublime_standard_tools::error::Error::DeadlineExceeded(_) => {
+                Self::Timeout(format!("{error}"))
+            }
         }
     }
 }
@@ -8,6 +8,7 @@
 //! Provides:
 //! - Color-coded error messages
 //! - Contextual suggestions for common errors
+//! - Error codes, source locations, and pointed snippets for diagnosed errors
 //! - Helpful next steps
 //! - Error formatting utilities
 //!
@@ -15,7 +16,10 @@
 //!
 //! Uses the `console` crate for terminal styling and provides helpers
 //! to format errors with appropriate colors and suggestions based on
-//! the error type.
+//! the error type. Errors carrying a structured diagnostic (see
+//! [`CliError::diagnostic`]) show their code in the header, the offending
+//! file and key, and, when the file content is supplied, the line with the
+//! problem underlined.
 //!
 //! # Why
 //!
@@ -35,6 +39,7 @@
 use crate::error::CliError;
 use console::style;
 use std::fmt::Write;
+use sublime_pkg_tools::error::{SourceLocation, SourceSpan};
 
 /// Enhanced error display formatter.
 ///
@@ -52,6 +57,7 @@ use std::fmt::Write;
 pub struct ErrorDisplay<'a> {
     error: &'a CliError,
     use_colors: bool,
+    source: Option<&'a str>,
 }
 
 impl<'a> ErrorDisplay<'a> {
@@ -71,7 +77,41 @@ impl<'a> ErrorDisplay<'a> {
     /// let display = ErrorDisplay::new(&error, true);
     /// ```
     pub fn new(error: &'a CliError, use_colors: bool) -> Self {
-        Self { error, use_colors }
+        Self { error, use_colors, source: None }
+    }
+
+    /// Sets the content of the file the error's diagnostic points at.
+    ///
+    /// With the content available, the offending line is printed and the
+    /// reported key or position is underlined.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Content of the file named by the diagnostic location
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::error::{CliError, ErrorDisplay};
+    /// use sublime_pkg_tools::error::{Diagnostic, SourceLocation};
+    ///
+    /// let diagnostic = Diagnostic {
+    ///     code: "config::invalid_config".to_string(),
+    ///     message: "Invalid strategy".to_string(),
+    ///     help: None,
+    ///     location: Some(SourceLocation::file("repo.config.toml").with_key("strategy")),
+    /// };
+    /// let error = CliError::configuration("Invalid strategy").with_diagnostic(diagnostic);
+    ///
+    /// let source = "[package_tools]\nstrategy = \"sometimes\"\n";
+    /// let formatted = ErrorDisplay::new(&error, false).with_source(source).format();
+    /// assert!(formatted.contains("--> repo.config.toml:2:1"));
+    /// assert!(formatted.contains("^^^^^^^^"));
+    /// ```
+    #[must_use]
+    pub fn with_source(mut self, source: &'a str) -> Self {
+        self.source = Some(source);
+        self
     }
 
     /// Formats the error with colors and suggestions.
@@ -99,6 +139,11 @@ impl<'a> ErrorDisplay<'a> {
         output.push_str(&self.format_message());
         output.push('\n');
 
+        // Location and snippet for diagnosed errors
+        if let Some(location) = self.error.diagnostic().and_then(|d| d.location.as_ref()) {
+            output.push_str(&self.format_location(location));
+        }
+
         // Suggestions if available
         if let Some(suggestion) = self.get_suggestion() {
             output.push('\n');
@@ -119,14 +164,86 @@ impl<'a> ErrorDisplay<'a> {
     fn format_header(&self) -> String {
         let icon = "✖";
         let error_type = self.error.kind();
+        let code = self
+            .error
+            .diagnostic()
+            .map(|diagnostic| format!(" [{}]", diagnostic.code))
+            .unwrap_or_default();
 
         if self.use_colors {
-            format!("{} {} Error", style(icon).red().bold(), style(error_type).red().bold())
+            format!(
+                "{} {}{}",
+                style(icon).red().bold(),
+                style(format!("{error_type} Error")).red().bold(),
+                style(code).red()
+            )
         } else {
-            format!("{icon} {error_type} Error")
+            format!("{icon} {error_type} Error{code}")
         }
     }
 
+    /// Formats the diagnostic location and, when the source is known, the
+    /// offending line with the problem underlined.
+    fn format_location(&self, location: &SourceLocation) -> String {
+        let span = self.source.and_then(|source| location.locate(source));
+        let mut target = location
+            .path
+            .as_ref()
+            .map_or_else(|| "<input>".to_string(), |path| path.display().to_string());
+        match (span.as_ref(), location.line) {
+            (Some(span), _) => {
+                let _ = write!(target, ":{}:{}", span.line, span.column);
+            }
+            (None, Some(line)) => {
+                let _ = write!(target, ":{line}");
+            }
+            (None, None) => {}
+        }
+        if span.is_none()
+            && let Some(key) = &location.key
+        {
+            let _ = write!(target, " (key: {key})");
+        }
+
+        let mut output = String::new();
+        if self.use_colors {
+            let _ = writeln!(output, "  {} {}", style("-->").blue().bold(), target);
+        } else {
+            let _ = writeln!(output, "  --> {target}");
+        }
+
+        if let (Some(span), Some(source)) = (span, self.source) {
+            output.push_str(&self.format_snippet(source, &span));
+        }
+
+        output
+    }
+
+    /// Formats the line at `span` with a gutter and carets under the span.
+    fn format_snippet(&self, source: &str, span: &SourceSpan) -> String {
+        let Some(text) = source.lines().nth(span.line.saturating_sub(1)) else {
+            return String::new();
+        };
+        let number = span.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let padding = " ".repeat(span.column.saturating_sub(1));
+        let carets = "^".repeat(span.length.max(1));
+
+        let mut output = String::new();
+        if self.use_colors {
+            let bar = style("|").blue().bold();
+            let _ = writeln!(output, "  {gutter} {bar}");
+            let _ = writeln!(output, "  {} {bar} {text}", style(&number).blue().bold());
+            let _ = writeln!(output, "  {gutter} {bar} {padding}{}", style(carets).red().bold());
+        } else {
+            let _ = writeln!(output, "  {gutter} |");
+            let _ = writeln!(output, "  {number} | {text}");
+            let _ = writeln!(output, "  {gutter} | {padding}{carets}");
+        }
+
+        output
+    }
+
     /// Formats the error message.
     fn format_message(&self) -> String {
         let message = self.error.user_message();
@@ -182,8 +299,14 @@ impl<'a> ErrorDisplay<'a> {
     }
 
     /// Gets a contextual suggestion based on the error type.
+    ///
+    /// The help message of an attached diagnostic takes precedence.
     fn get_suggestion(&self) -> Option<String> {
-        match self.error {
+        if let Some(help) = self.error.diagnostic().and_then(|d| d.help.clone()) {
+            return Some(help);
+        }
+
+        match self.error.categorized() {
             CliError::Configuration(msg) if msg.contains("not found") => {
                 Some("Run 'workspace init' to create a new configuration file.".to_string())
             }
@@ -222,7 +345,7 @@ impl<'a> ErrorDisplay<'a> {
 
     /// Gets next steps based on the error type.
     fn get_next_steps(&self) -> Option<Vec<String>> {
-        match self.error {
+        match self.error.categorized() {
             CliError::Configuration(msg) if msg.contains("not found") => Some(vec![
                 "Run 'workspace init' to create a configuration file".to_string(),
                 "Or specify a config path with --config".to_string(),
@@ -275,6 +398,55 @@ impl std::fmt::Display for ErrorDisplay<'_> {
 mod tests {
     use super::*;
 
+    fn diagnosed(location: SourceLocation) -> CliError {
+        let diagnostic = sublime_pkg_tools::error::Diagnostic {
+            code: "config::missing_field".to_string(),
+            message: "Missing field".to_string(),
+            help: Some("Add 'name' to the configuration.".to_string()),
+            location: Some(location),
+        };
+        CliError::configuration("Missing field").with_diagnostic(diagnostic)
+    }
+
+    #[test]
+    fn test_format_diagnostic_with_snippet() {
+        let error = diagnosed(SourceLocation::file("package.json").with_key("dependencies.react"));
+        let source = "{\n  \"dependencies\": {\n    \"react\": \"^x\"\n  }\n}";
+        let formatted = ErrorDisplay::new(&error, false).with_source(source).format();
+
+        assert!(formatted.contains("Configuration Error [config::missing_field]"));
+        assert!(formatted.contains("--> package.json:3:5"));
+        assert!(formatted.contains("3 |     \"react\": \"^x\""));
+        assert!(formatted.contains("  |     ^^^^^^^\n"));
+        assert!(formatted.contains("Add 'name' to the configuration."));
+    }
+
+    #[test]
+    fn test_format_diagnostic_without_source() {
+        let error = diagnosed(SourceLocation::file("package.json").with_key("version"));
+        let formatted = ErrorDisplay::new(&error, false).format();
+
+        assert!(formatted.contains("--> package.json (key: version)"));
+        assert!(!formatted.contains('^'));
+    }
+
+    #[test]
+    fn test_diagnostic_keeps_categorized_next_steps() {
+        let error = CliError::configuration("Config file not found").with_diagnostic(
+            sublime_pkg_tools::error::Diagnostic {
+                code: "config::not_found".to_string(),
+                message: "Config file not found".to_string(),
+                help: None,
+                location: None,
+            },
+        );
+        let display = ErrorDisplay::new(&error, false);
+
+        assert!(display.get_suggestion().is_some_and(|s| s.contains("workspace init")));
+        assert!(display.get_next_steps().is_some());
+        assert_eq!(error.exit_code(), 78);
+    }
+
     #[test]
     fn test_format_without_colors() {
         let error = CliError::configuration("Test error");
@@ -50,6 +50,11 @@ fn test_error_creation_helpers() {
     assert_eq!(error.exit_code(), ExitCode::USAGE);
     assert_eq!(error.kind(), "User");
     assert_eq!(error.as_ref(), "CliError::User");
+
+    let error = CliError::timeout("test");
+    assert_eq!(error.exit_code(), ExitCode::TEMPFAIL);
+    assert_eq!(error.kind(), "Timeout");
+    assert_eq!(error.as_ref(), "CliError::Timeout");
 }
 
 #[test]
@@ -190,6 +195,63 @@ fn test_from_anyhow_error() {
     assert!(cli_error.user_message().contains("Execution error"));
 }
 
+#[test]
+fn test_from_pkg_error_keeps_diagnostic() {
+    use sublime_pkg_tools::error::{ConfigError, Error as PkgError};
+
+    let pkg_error = PkgError::Config(ConfigError::MissingField { field: "changeset.path".into() });
+    let cli_error: CliError = pkg_error.into();
+
+    assert_eq!(cli_error.exit_code(), ExitCode::CONFIG);
+    assert_eq!(cli_error.kind(), "Configuration");
+    assert_eq!(cli_error.as_ref(), "CliError::Configuration");
+    assert!(matches!(cli_error.categorized(), CliError::Configuration(_)));
+
+    let diagnostic = cli_error.diagnostic().unwrap();
+    assert_eq!(diagnostic.code, "config::missing_field");
+    assert!(diagnostic.help.as_deref().unwrap().contains("changeset.path"));
+    assert_eq!(
+        diagnostic.location.as_ref().and_then(|location| location.key.as_deref()),
+        Some("changeset.path")
+    );
+}
+
+#[test]
+fn test_deadline_errors_convert_to_timeout() {
+    use sublime_pkg_tools::error::{Error as PkgError, UpgradeError};
+    use sublime_standard_tools::error::{DeadlineExceeded, Error as StandardError};
+
+    let pkg_error = PkgError::Upgrade(UpgradeError::DeadlineExceeded {
+        operation: "upgrade detection".to_string(),
+        partial: None,
+    });
+    let cli_error: CliError = pkg_error.into();
+    assert_eq!(cli_error.exit_code(), ExitCode::TEMPFAIL);
+    assert_eq!(cli_error.diagnostic().unwrap().code, "upgrade::deadline_exceeded");
+
+    let cli_error: CliError = StandardError::from(DeadlineExceeded::new("run npm test")).into();
+    assert!(matches!(cli_error, CliError::Timeout(_)));
+
+    let git_error =
+        sublime_git_tools::RepoError::DeadlineExceeded { operation: "fetch".to_string() };
+    assert_eq!(CliError::from(git_error).kind(), "Timeout");
+}
+
+#[test]
+fn test_with_diagnostic_replaces_existing_diagnostic() {
+    use sublime_pkg_tools::error::{Diagnose, VersionError};
+
+    let first = VersionError::InvalidBumpType { bump_type: "huge".to_string() };
+    let second = VersionError::InvalidStrategy { strategy: "sometimes".to_string() };
+    let error = CliError::validation("bad input")
+        .with_diagnostic(first.diagnostic())
+        .with_diagnostic(second.diagnostic());
+
+    assert!(matches!(error.categorized(), CliError::Validation(_)));
+    assert_eq!(error.diagnostic().unwrap().code, "version::invalid_strategy");
+    assert!(error.user_message().contains("bad input"));
+}
+
 #[test]
 fn test_error_trait_implementation() {
     let error = CliError::configuration("test error");
@@ -322,6 +384,7 @@ fn test_all_error_variants_have_unique_as_ref() {
         CliError::io("test"),
         CliError::network("test"),
         CliError::user("test"),
+        CliError::timeout("test"),
     ];
 
     let refs: Vec<&str> = errors.iter().map(AsRef::as_ref).collect();
@@ -347,6 +410,7 @@ fn test_all_error_variants_have_unique_kinds() {
         CliError::io("test"),
         CliError::network("test"),
         CliError::user("test"),
+        CliError::timeout("test"),
     ];
 
     let kinds: Vec<&str> = errors.iter().map(CliError::kind).collect();
@@ -372,6 +436,7 @@ fn test_exit_codes_are_valid_sysexits() {
         CliError::io("test"),
         CliError::network("test"),
         CliError::user("test"),
+        CliError::timeout("test"),
     ];
 
     let valid_codes = [
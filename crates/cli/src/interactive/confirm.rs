@@ -53,12 +53,15 @@
 //! ```
 
 use crate::error::{CliError, Result};
+use crate::interactive::mode;
 use crate::interactive::theme::WntTheme;
 use dialoguer::Confirm;
 
 /// Prompts user for a yes/no confirmation.
 ///
-/// Displays a simple confirmation prompt with a default value.
+/// Displays a simple confirmation prompt with a default value. When prompts are
+/// unavailable (see [`mode::prompt_mode`]) it answers `true` under `--yes` and fails
+/// otherwise: a confirmation guards a change, so it is never answered implicitly.
 ///
 /// # Arguments
 ///
@@ -75,6 +78,7 @@ use dialoguer::Confirm;
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable and `--yes` was not given
 ///
 /// # Examples
 ///
@@ -91,6 +95,14 @@ use dialoguer::Confirm;
 /// # }
 /// ```
 pub fn confirm(message: &str, default: bool, no_color: bool) -> Result<bool> {
+    match mode::prompt_mode() {
+        mode::PromptMode::Interactive => {}
+        mode::PromptMode::AssumeYes => return Ok(true),
+        mode::PromptMode::NonInteractive => {
+            return Err(mode::prompt_unavailable(message, "re-run with --yes or --force"));
+        }
+    }
+
     let theme = WntTheme::new(no_color);
 
     Confirm::with_theme(&theme)
@@ -158,7 +170,8 @@ pub fn confirm_with_context(
 /// Prompts user for confirmation of a dangerous operation.
 ///
 /// Displays a prominent warning before asking for confirmation. The default
-/// is always `false` to prevent accidental destructive actions.
+/// is always `false` to prevent accidental destructive actions. When prompts are
+/// unavailable only `--yes` confirms; otherwise an error is returned.
 ///
 /// # Arguments
 ///
@@ -175,6 +188,7 @@ pub fn confirm_with_context(
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable and `--yes` was not given
 ///
 /// # Examples
 ///
@@ -195,6 +209,10 @@ pub fn confirm_with_context(
 /// # }
 /// ```
 pub fn confirm_dangerous(message: &str, warning: &str, no_color: bool) -> Result<bool> {
+    if !mode::is_interactive() {
+        return confirm(message, false, no_color);
+    }
+
     // Print warning prominently
     if no_color {
         println!("WARNING: {warning}");
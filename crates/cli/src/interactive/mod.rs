@@ -23,6 +23,7 @@
 //! - `validation`: Enhanced validation logic with helpful suggestions
 //! - `select`: Fuzzy search and enhanced selection capabilities
 //! - `confirm`: Enhanced confirmation dialogs with context
+//! - `mode`: Process-wide non-interactive mode and TTY detection
 //! - `prompts`: High-level prompt functions combining all features
 //!
 //! The module is built on:
@@ -35,6 +36,9 @@
 //! - Fast and responsive with fuzzy search
 //! - Accessible across different terminal environments
 //! - Cancelable with proper error handling (Ctrl+C)
+//! - Safe in CI: without a terminal, or with `--yes`/`--non-interactive`, selections
+//!   fall back to their defaults, confirmations require `--yes`, and prompts without
+//!   a default fail with an error naming the missing flag
 //! - Consistent in appearance and behavior
 //!
 //! # Why
@@ -112,6 +116,7 @@
 
 // Module declarations
 pub mod confirm;
+pub mod mode;
 pub mod prompts;
 pub mod select;
 pub mod theme;
@@ -122,8 +127,13 @@ mod tests;
 
 // Re-export commonly used prompt functions for convenience
 pub use prompts::{
-    prompt_bump_type, prompt_confirm, prompt_confirm_dangerous, prompt_confirm_with_context,
-    prompt_environments, prompt_packages, prompt_summary,
+    prompt_bump_type, prompt_bump_type_with_default, prompt_confirm, prompt_confirm_dangerous,
+    prompt_confirm_with_context, prompt_environments, prompt_packages, prompt_summary,
+};
+
+// Re-export interactivity mode controls
+pub use mode::{
+    PromptMode, assume_yes, is_interactive, prompt_mode, set_assume_yes, set_non_interactive,
 };
 
 // Re-export theme for external use
@@ -0,0 +1,139 @@
+//! Process-wide interactivity mode for prompts.
+//!
+//! # What
+//!
+//! Tracks whether prompts may interact with the user. Interaction is disabled when:
+//! - `--non-interactive` (or `WORKSPACE_NON_INTERACTIVE`) is given
+//! - `--yes` (or `WORKSPACE_YES`) is given, which also answers confirmations with yes
+//! - stdin is not a terminal (pipes, CI runners, cron jobs)
+//!
+//! # How
+//!
+//! `main` stores the global flags with [`set_non_interactive`] and [`set_assume_yes`]
+//! before dispatching. Every prompt checks [`prompt_mode`] first and, when interaction
+//! is not possible, falls back to its default selection or fails with the error built
+//! by [`prompt_unavailable`]. Confirmations never fall back: every confirmation gates
+//! a write, so without a terminal only `--yes` answers them.
+//!
+//! # Why
+//!
+//! A prompt waiting on a stdin that nobody will ever write to hangs CI runs until the
+//! job times out. Deciding once, in one place, keeps every command consistent.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_cli_tools::interactive::mode::{PromptMode, prompt_mode, set_assume_yes};
+//!
+//! set_assume_yes(true);
+//! assert_eq!(prompt_mode(), PromptMode::AssumeYes);
+//! ```
+
+use crate::error::CliError;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide flag set by `--non-interactive`.
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Process-wide flag set by `--yes`.
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    /// Per-thread mode used by tests instead of the process-wide flags.
+    static TEST_MODE: std::cell::Cell<Option<PromptMode>> = const { std::cell::Cell::new(None) };
+}
+
+/// How prompts behave for the current process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptMode {
+    /// Prompts are shown on the terminal.
+    Interactive,
+    /// Prompts are not shown; selections use their defaults, confirmations fail.
+    NonInteractive,
+    /// Prompts are not shown; selections use their defaults, confirmations answer yes.
+    AssumeYes,
+}
+
+/// Enables or disables the explicit non-interactive mode.
+pub fn set_non_interactive(enabled: bool) {
+    NON_INTERACTIVE.store(enabled, Ordering::SeqCst);
+}
+
+/// Enables or disables answering every confirmation with yes.
+///
+/// Implies non-interactive mode.
+pub fn set_assume_yes(enabled: bool) {
+    ASSUME_YES.store(enabled, Ordering::SeqCst);
+}
+
+/// Returns the current prompt mode.
+///
+/// `--yes` wins over `--non-interactive`; without either flag the mode depends on
+/// whether stdin is a terminal.
+#[must_use]
+pub fn prompt_mode() -> PromptMode {
+    #[cfg(test)]
+    if let Some(mode) = TEST_MODE.with(std::cell::Cell::get) {
+        return mode;
+    }
+
+    if ASSUME_YES.load(Ordering::SeqCst) {
+        PromptMode::AssumeYes
+    } else if NON_INTERACTIVE.load(Ordering::SeqCst) || !std::io::stdin().is_terminal() {
+        PromptMode::NonInteractive
+    } else {
+        PromptMode::Interactive
+    }
+}
+
+/// Returns whether confirmations are answered with yes.
+#[must_use]
+pub fn assume_yes() -> bool {
+    prompt_mode() == PromptMode::AssumeYes
+}
+
+/// Returns whether prompts may interact with the user.
+///
+/// False when `--non-interactive` or `--yes` is set, or when stdin is not a terminal.
+#[must_use]
+pub fn is_interactive() -> bool {
+    prompt_mode() == PromptMode::Interactive
+}
+
+/// Builds the error returned by a prompt that cannot be answered without the user.
+///
+/// # Arguments
+///
+/// * `prompt` - The prompt that could not be shown
+/// * `hint` - How to provide the value without a prompt (e.g. "pass --message")
+#[must_use]
+pub fn prompt_unavailable(prompt: &str, hint: &str) -> CliError {
+    let reason = if NON_INTERACTIVE.load(Ordering::SeqCst) || ASSUME_YES.load(Ordering::SeqCst) {
+        "non-interactive mode is enabled"
+    } else {
+        "stdin is not a terminal"
+    };
+    CliError::user(format!("Cannot prompt for '{prompt}': {reason}. To continue, {hint}"))
+}
+
+/// Overrides the prompt mode for the current thread until the guard is dropped.
+#[cfg(test)]
+pub(crate) fn override_prompt_mode(mode: PromptMode) -> PromptModeGuard {
+    let previous = TEST_MODE.with(|cell| cell.replace(Some(mode)));
+    PromptModeGuard { previous }
+}
+
+/// Restores the previous per-thread prompt mode when dropped.
+#[cfg(test)]
+pub(crate) struct PromptModeGuard {
+    previous: Option<PromptMode>,
+}
+
+#[cfg(test)]
+impl Drop for PromptModeGuard {
+    fn drop(&mut self) {
+        TEST_MODE.with(|cell| cell.set(self.previous));
+    }
+}
@@ -54,7 +54,7 @@
 //! ```
 
 use crate::error::{CliError, Result};
-use crate::interactive::{confirm, select, theme::WntTheme, validation};
+use crate::interactive::{confirm, mode, select, theme::WntTheme, validation};
 use dialoguer::Input;
 
 /// Prompts user to select a bump type with enhanced UI.
@@ -89,14 +89,54 @@ use dialoguer::Input;
 /// # }
 /// ```
 pub fn prompt_bump_type(no_color: bool) -> Result<String> {
+    prompt_bump_type_with_default("patch", no_color)
+}
+
+/// Prompts user to select a version bump type, with a proposed type selected.
+///
+/// Same menu as [`prompt_bump_type`], with `default` highlighted instead of patch, e.g.
+/// the bump proposed from the branch's commits. Unknown defaults fall back to patch.
+///
+/// # Arguments
+///
+/// * `default` - The bump type to select initially ("patch", "minor", or "major")
+/// * `no_color` - Whether to disable colored output
+///
+/// # Returns
+///
+/// * `Result<String>` - The selected bump type ("patch", "minor", or "major")
+///
+/// # Errors
+///
+/// Returns `CliError::User` if:
+/// - User cancels the prompt (Ctrl+C)
+/// - Terminal interaction fails
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_cli_tools::interactive::prompts::prompt_bump_type_with_default;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let bump = prompt_bump_type_with_default("minor", false)?;
+/// assert!(["patch", "minor", "major"].contains(&bump.as_str()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn prompt_bump_type_with_default(default: &str, no_color: bool) -> Result<String> {
     let items = vec![
         "patch - Bug fixes and small changes (0.0.X)",
         "minor - New features, backwards compatible (0.X.0)",
         "major - Breaking changes (X.0.0)",
     ];
+    let default_index = match default {
+        "minor" => 1,
+        "major" => 2,
+        _ => 0,
+    };
 
     let prompt_text = "Select bump type";
-    let selection = select::simple_select(prompt_text, &items, Some(0), no_color)?;
+    let selection = select::simple_select(prompt_text, &items, Some(default_index), no_color)?;
 
     match selection {
         0 => Ok("patch".to_string()),
@@ -256,6 +296,7 @@ pub fn prompt_environments(
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable (no terminal, `--yes` or `--non-interactive`)
 ///
 /// Returns `CliError::Validation` if:
 /// - Input is empty after trimming
@@ -272,6 +313,10 @@ pub fn prompt_environments(
 /// # }
 /// ```
 pub fn prompt_summary(placeholder: Option<&str>, no_color: bool) -> Result<String> {
+    if !mode::is_interactive() {
+        return Err(mode::prompt_unavailable("changeset summary", "pass it with --message"));
+    }
+
     let theme = WntTheme::new(no_color);
     let prompt_text = "Enter changeset summary";
     let default_placeholder = "Brief description of changes";
@@ -53,6 +53,7 @@
 //! ```
 
 use crate::error::{CliError, Result};
+use crate::interactive::mode;
 use crate::interactive::theme::WntTheme;
 use dialoguer::{FuzzySelect, MultiSelect, Select};
 use fuzzy_matcher::FuzzyMatcher;
@@ -79,6 +80,7 @@ use fuzzy_matcher::skim::SkimMatcherV2;
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable and there is no default to fall back to
 ///
 /// # Examples
 ///
@@ -102,6 +104,10 @@ pub fn fuzzy_select<T: ToString>(
         return Err(CliError::validation("No items available to select"));
     }
 
+    if !mode::is_interactive() {
+        return default_selection(prompt, items.len(), default);
+    }
+
     let theme = WntTheme::new(no_color);
     let mut builder = FuzzySelect::with_theme(&theme).with_prompt(prompt);
 
@@ -139,6 +145,7 @@ pub fn fuzzy_select<T: ToString>(
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable and there are no defaults to fall back to
 ///
 /// Returns `CliError::Validation` if:
 /// - No items are selected
@@ -165,6 +172,18 @@ pub fn fuzzy_multi_select<T: ToString>(
         return Err(CliError::validation("No items available to select"));
     }
 
+    if !mode::is_interactive() {
+        let selections: Vec<usize> =
+            (0..items.len()).filter(|index| defaults.contains(index)).collect();
+        if selections.is_empty() {
+            return Err(mode::prompt_unavailable(
+                prompt,
+                "pass the selection with command-line flags",
+            ));
+        }
+        return Ok(selections);
+    }
+
     let theme = WntTheme::new(no_color);
     let items_str: Vec<String> = items.iter().map(std::string::ToString::to_string).collect();
 
@@ -207,6 +226,7 @@ pub fn fuzzy_multi_select<T: ToString>(
 /// Returns `CliError::User` if:
 /// - User cancels the prompt (Ctrl+C)
 /// - Terminal interaction fails
+/// - Prompts are unavailable and there is no default to fall back to
 ///
 /// # Examples
 ///
@@ -229,6 +249,10 @@ pub fn simple_select<T: ToString>(
         return Err(CliError::validation("No items available to select"));
     }
 
+    if !mode::is_interactive() {
+        return default_selection(prompt, items.len(), default);
+    }
+
     let theme = WntTheme::new(no_color);
     let mut builder = Select::with_theme(&theme).with_prompt(prompt);
 
@@ -242,6 +266,14 @@ pub fn simple_select<T: ToString>(
     builder.interact().map_err(|e| CliError::user(format!("Selection cancelled: {e}")))
 }
 
+/// Resolves a single selection without prompting, using the default index.
+fn default_selection(prompt: &str, len: usize, default: Option<usize>) -> Result<usize> {
+    match default {
+        Some(index) if index < len => Ok(index),
+        _ => Err(mode::prompt_unavailable(prompt, "pass the value with a command-line flag")),
+    }
+}
+
 /// Filters items using fuzzy matching and returns ranked results.
 ///
 /// This is a utility function that can be used independently of the prompt
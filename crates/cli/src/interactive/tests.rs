@@ -11,6 +11,7 @@
 //! - Fuzzy search and filtering
 //! - Selection logic (empty lists, defaults, etc.)
 //! - Confirmation dialog logic
+//! - Fallbacks when prompts are unavailable (`--yes`, no terminal)
 //! - Interactive prompt functions
 //! - Error handling for cancelled prompts
 //! - Edge cases (empty lists, invalid inputs)
@@ -500,6 +501,80 @@ mod confirm_tests {
     }
 }
 
+#[cfg(test)]
+mod mode_tests {
+    use crate::error::CliError;
+    use crate::interactive::confirm::{confirm, confirm_dangerous};
+    use crate::interactive::mode::{
+        PromptMode, assume_yes, is_interactive, override_prompt_mode, prompt_mode,
+        prompt_unavailable,
+    };
+    use crate::interactive::prompts::{
+        prompt_bump_type, prompt_bump_type_with_default, prompt_summary,
+    };
+    use crate::interactive::select::{fuzzy_multi_select, fuzzy_select, simple_select};
+
+    #[test]
+    fn test_assume_yes_answers_without_prompting() {
+        let _guard = override_prompt_mode(PromptMode::AssumeYes);
+        assert!(assume_yes());
+        assert!(!is_interactive());
+
+        assert!(matches!(confirm("Continue?", false, true), Ok(true)));
+        assert!(matches!(confirm_dangerous("Delete?", "Irreversible", true), Ok(true)));
+        assert!(matches!(prompt_bump_type(true).as_deref(), Ok("patch")));
+        assert!(matches!(prompt_bump_type_with_default("major", true).as_deref(), Ok("major")));
+    }
+
+    #[test]
+    fn test_non_interactive_refuses_confirmations() {
+        let _guard = override_prompt_mode(PromptMode::NonInteractive);
+        assert!(!assume_yes());
+
+        // Neither default answers a confirmation implicitly
+        assert!(matches!(confirm("Continue?", false, true), Err(CliError::User(_))));
+        assert!(matches!(confirm("Clean up?", true, true), Err(CliError::User(_))));
+        assert!(matches!(
+            confirm_dangerous("Delete?", "Irreversible", true),
+            Err(CliError::User(_))
+        ));
+    }
+
+    #[test]
+    fn test_non_interactive_selections_use_defaults() {
+        let _guard = override_prompt_mode(PromptMode::NonInteractive);
+
+        let items = vec!["a", "b", "c"];
+        assert!(matches!(fuzzy_select("Pick", &items, Some(1), true), Ok(1)));
+        assert!(fuzzy_select("Pick", &items, None, true).is_err());
+        assert!(simple_select("Pick", &items, Some(5), true).is_err());
+        assert!(matches!(fuzzy_multi_select("Pick", &items, &[2, 0], true).as_deref(), Ok([0, 2])));
+        assert!(fuzzy_multi_select("Pick", &items, &[], true).is_err());
+        assert!(matches!(prompt_summary(None, true), Err(CliError::User(_))));
+    }
+
+    #[test]
+    fn test_override_prompt_mode_restores_previous_mode() {
+        let before = prompt_mode();
+        {
+            let _outer = override_prompt_mode(PromptMode::AssumeYes);
+            {
+                let _inner = override_prompt_mode(PromptMode::NonInteractive);
+                assert_eq!(prompt_mode(), PromptMode::NonInteractive);
+            }
+            assert_eq!(prompt_mode(), PromptMode::AssumeYes);
+        }
+        assert_eq!(prompt_mode(), before);
+    }
+
+    #[test]
+    fn test_prompt_unavailable_names_the_prompt_and_hint() {
+        let message = prompt_unavailable("bump type", "pass it with --bump").to_string();
+        assert!(message.contains("bump type"));
+        assert!(message.contains("--bump"));
+    }
+}
+
 #[cfg(test)]
 mod integration_tests {
     use crate::interactive::*;
@@ -37,15 +37,21 @@
 
 use clap::Parser;
 use std::process;
+use std::time::Duration;
 use sublime_cli_tools::cli::{Cli, dispatch_command};
-use sublime_cli_tools::error::Result;
+use sublime_cli_tools::error::{CliError, ErrorDisplay, Result};
+use sublime_standard_tools::deadline::Deadline;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// Time a command gets past `--timeout` to report partial results before it is abandoned.
+const TIMEOUT_GRACE: Duration = Duration::from_secs(5);
 
 /// Main entry point for the CLI.
 ///
 /// This function:
 /// 1. Initializes the tokio async runtime
 /// 2. Calls the async main function
-/// 3. Handles the result and sets the exit code
+/// 3. Handles the result, printing errors with their diagnostics, and sets the exit code
 ///
 /// # Examples
 ///
@@ -73,7 +79,19 @@ fn main() {
     match result {
         Ok(()) => process::exit(0),
         Err(e) => {
-            eprintln!("Error: {e}");
+            let use_colors = console::Term::stderr().is_term() && console::colors_enabled_stderr();
+            let source = e
+                .diagnostic()
+                .and_then(|diagnostic| diagnostic.location.as_ref())
+                .and_then(|location| location.path.as_ref())
+                .and_then(|path| {
+                    runtime.block_on(FileSystemManager::new().read_file_string(path)).ok()
+                });
+            let display = ErrorDisplay::new(&e, use_colors);
+            match &source {
+                Some(source) => eprintln!("{}", display.with_source(source)),
+                None => eprintln!("{display}"),
+            }
             let exit_code = e.exit_code();
             process::exit(exit_code);
         }
@@ -86,8 +104,13 @@ fn main() {
 /// 1. Parses CLI arguments using clap
 /// 2. Initializes logging based on `--log-level` (stderr only)
 /// 3. Changes working directory if `--root` is specified
-/// 4. Dispatches to the appropriate command handler
-/// 5. Returns results for proper exit code handling
+/// 4. Enables the filesystem and git write guards if `--read-only` is specified
+/// 5. Disables network access if `--offline` is specified
+/// 6. Sets the operation deadline if `--timeout` is specified
+/// 7. Selects the configuration profile if `--profile` is specified
+/// 8. Disables prompts if `--yes` or `--non-interactive` is specified
+/// 9. Dispatches to the appropriate command handler
+/// 10. Returns results for proper exit code handling
 ///
 /// # Errors
 ///
@@ -111,25 +134,65 @@ async fn async_main() -> Result<()> {
 
     // 2. Initialize logging based on --log-level (affects stderr only)
     sublime_cli_tools::output::logger::init_logging(cli.log_level(), cli.is_color_disabled())?;
+    if cli.is_color_disabled() {
+        console::set_colors_enabled_stderr(false);
+    }
 
     // 3. Change to root directory if specified
     if let Some(root) = cli.root() {
         std::env::set_current_dir(root).map_err(|e| {
-            sublime_cli_tools::CliError::io(format!(
-                "Failed to change directory to '{}': {}",
-                root.display(),
-                e
-            ))
+            CliError::io(format!("Failed to change directory to '{}': {}", root.display(), e))
         })?;
     }
 
-    // 4. Dispatch to command handler
+    // 4. Block every filesystem and git write for the rest of the process
+    if cli.is_read_only() {
+        sublime_standard_tools::filesystem::set_read_only_mode(true);
+        sublime_git_tools::Repo::set_read_only_mode(true);
+    }
+
+    // 5. Serve network data from caches and skip what is not cached
+    if cli.is_offline() {
+        sublime_standard_tools::network::set_offline_mode(true);
+        sublime_git_tools::Repo::set_offline_mode(true);
+        tracing::warn!("Offline mode: network access is disabled and results may be stale");
+    }
+
+    // 6. Bound registry, git, and command operations by --timeout
+    if let Some(timeout) = cli.timeout() {
+        sublime_standard_tools::deadline::set_global_deadline(Some(timeout));
+        sublime_git_tools::Repo::set_deadline(Some(std::time::Instant::now() + timeout));
+    }
+
+    // 7. Apply the selected configuration profile to every config load
+    if let Some(profile) = cli.profile() {
+        sublime_pkg_tools::config::set_active_profile(Some(profile.to_string()));
+    }
+
+    // 8. Never prompt under --yes/--non-interactive (no-TTY stdin is detected per prompt)
+    sublime_cli_tools::interactive::set_assume_yes(cli.assume_yes());
+    sublime_cli_tools::interactive::set_non_interactive(cli.is_non_interactive());
+
+    // 9. Dispatch to command handler
     // Each command handler will:
     // - Receive the parsed arguments
     // - Execute the command logic
     // - Return results
     // - Handle output formatting based on global --format option
-    dispatch_command(&cli).await?;
+    // Boxed so the combined state of every command handler lives on the heap.
+    let command = Box::pin(dispatch_command(&cli));
+    match cli.timeout() {
+        // Operations stop at the deadline; the grace period lets commands report
+        // partial results before the whole command is abandoned.
+        Some(timeout) => {
+            let hard_stop = Deadline::after(timeout + TIMEOUT_GRACE);
+            hard_stop
+                .run("command", command)
+                .await
+                .map_err(|e| CliError::timeout(e.to_string()))??;
+        }
+        None => command.await?,
+    }
 
     Ok(())
 }
@@ -87,6 +87,7 @@ use serde::Serialize;
 use serde_json::Value;
 use std::fs;
 use std::path::Path;
+use sublime_standard_tools::error::FileSystemError;
 
 /// Export format for command output.
 ///
@@ -664,7 +665,7 @@ impl Exporter for MarkdownExporter {
 /// Returns an error if:
 /// - The data cannot be serialized
 /// - The export conversion fails
-/// - The file cannot be written
+/// - The file cannot be written (including when read-only mode is active)
 ///
 /// # Examples
 ///
@@ -710,6 +711,12 @@ pub fn export_data<T: Serialize>(data: &T, format: ExportFormat, output_path: &P
     };
 
     // Write to file
+    if sublime_standard_tools::filesystem::is_read_only_mode() {
+        return Err(sublime_standard_tools::error::Error::FileSystem(FileSystemError::ReadOnly {
+            path: output_path.to_path_buf(),
+        })
+        .into());
+    }
     fs::write(output_path, content)
         .map_err(|e| CliError::io(format!("Failed to write export file: {e}")))?;
 
@@ -81,6 +81,7 @@ use crate::error::{CliError, Result};
 use serde::Serialize;
 use std::cell::RefCell;
 use std::io::Write;
+use sublime_pkg_tools::types::Warning;
 
 // Public re-exports
 pub use diff::{
@@ -126,6 +127,13 @@ pub enum OutputFormat {
     /// Same structure as Json but minified for piping and processing.
     JsonCompact,
 
+    /// Newline-delimited JSON output.
+    ///
+    /// Commands with large results (`changes`, `audit`) write one JSON record per
+    /// line as soon as it is computed; other commands write their response as a
+    /// single compact line.
+    Ndjson,
+
     /// Minimal output mode.
     ///
     /// Only essential information is displayed. Useful for scripting.
@@ -133,7 +141,7 @@ pub enum OutputFormat {
 }
 
 impl OutputFormat {
-    /// Returns true if this format is JSON (either pretty or compact).
+    /// Returns true if this format is JSON (pretty, compact, or newline-delimited).
     ///
     /// # Examples
     ///
@@ -142,10 +150,25 @@ impl OutputFormat {
     ///
     /// assert!(OutputFormat::Json.is_json());
     /// assert!(OutputFormat::JsonCompact.is_json());
+    /// assert!(OutputFormat::Ndjson.is_json());
     /// assert!(!OutputFormat::Human.is_json());
     /// ```
     pub fn is_json(&self) -> bool {
-        matches!(self, Self::Json | Self::JsonCompact)
+        matches!(self, Self::Json | Self::JsonCompact | Self::Ndjson)
+    }
+
+    /// Returns true if this format is newline-delimited JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::output::OutputFormat;
+    ///
+    /// assert!(OutputFormat::Ndjson.is_ndjson());
+    /// assert!(!OutputFormat::JsonCompact.is_ndjson());
+    /// ```
+    pub fn is_ndjson(&self) -> bool {
+        matches!(self, Self::Ndjson)
     }
 
     /// Returns true if this format is human-readable.
@@ -189,6 +212,7 @@ impl std::fmt::Display for OutputFormat {
             Self::Human => write!(f, "human"),
             Self::Json => write!(f, "json"),
             Self::JsonCompact => write!(f, "json-compact"),
+            Self::Ndjson => write!(f, "ndjson"),
             Self::Quiet => write!(f, "quiet"),
         }
     }
@@ -202,9 +226,10 @@ impl std::str::FromStr for OutputFormat {
             "human" => Ok(Self::Human),
             "json" => Ok(Self::Json),
             "json-compact" | "compact" => Ok(Self::JsonCompact),
+            "ndjson" => Ok(Self::Ndjson),
             "quiet" => Ok(Self::Quiet),
             _ => Err(format!(
-                "Invalid output format '{s}'. Valid options: human, json, json-compact, quiet"
+                "Invalid output format '{s}'. Valid options: human, json, json-compact, ndjson, quiet"
             )),
         }
     }
@@ -329,7 +354,7 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{styled}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact => {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
                 // In JSON mode, success is part of the final JSON response
                 Ok(())
             }
@@ -368,7 +393,7 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "Error: {message}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact => {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
                 // In JSON mode, errors are part of the final JSON response
                 Ok(())
             }
@@ -401,10 +426,37 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{styled}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => Ok(()),
+            OutputFormat::Json
+            | OutputFormat::JsonCompact
+            | OutputFormat::Ndjson
+            | OutputFormat::Quiet => Ok(()),
         }
     }
 
+    /// Outputs the warnings of an operation result, one per line.
+    ///
+    /// Rendered like [`Output::warning`]: in human mode each warning message is displayed
+    /// with a yellow warning symbol, in JSON and quiet modes nothing is written. JSON output
+    /// carries the warnings in the `warnings` array of the command's data instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::output::{Output, OutputFormat};
+    /// use sublime_pkg_tools::types::{Warning, WarningKind};
+    /// use std::io;
+    ///
+    /// let output = Output::new(OutputFormat::Human, io::stdout(), false);
+    /// let warnings = vec![Warning::new(WarningKind::CircularDependency, "a -> b -> a")];
+    /// output.warnings(&warnings).unwrap();
+    /// ```
+    pub fn warnings(&self, warnings: &[Warning]) -> Result<()> {
+        for warning in warnings {
+            self.warning(&warning.message)?;
+        }
+        Ok(())
+    }
+
     /// Outputs an informational message.
     ///
     /// In human mode, this is displayed with a blue info symbol.
@@ -431,7 +483,10 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{styled}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => Ok(()),
+            OutputFormat::Json
+            | OutputFormat::JsonCompact
+            | OutputFormat::Ndjson
+            | OutputFormat::Quiet => Ok(()),
         }
     }
 
@@ -456,7 +511,7 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{message}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact => {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Ndjson => {
                 // In JSON mode, plain text is ignored
                 Ok(())
             }
@@ -468,7 +523,7 @@ impl Output {
     /// This is the primary method for outputting structured data in JSON mode.
     /// The data is serialized according to the current output format:
     /// - `Json`: Pretty-printed with indentation
-    /// - `JsonCompact`: Single line, no whitespace
+    /// - `JsonCompact`/`Ndjson`: Single line, no whitespace
     /// - `Human`/`Quiet`: Ignored (use other methods for human output)
     ///
     /// # Examples
@@ -500,7 +555,7 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{json}")?;
                 Ok(())
             }
-            OutputFormat::JsonCompact => {
+            OutputFormat::JsonCompact | OutputFormat::Ndjson => {
                 let json = serde_json::to_string(data)
                     .map_err(|e| CliError::execution(format!("JSON serialization failed: {e}")))?;
                 writeln!(self.writer.borrow_mut(), "{json}")?;
@@ -513,6 +568,39 @@ impl Output {
         }
     }
 
+    /// Outputs one record of a newline-delimited JSON stream.
+    ///
+    /// In NDJSON mode, writes the record as a single compact line and flushes it,
+    /// so consumers receive each record as soon as it is computed.
+    /// In every other mode, this is ignored: commands build a single response for
+    /// `json()` instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_cli_tools::output::{Output, OutputFormat};
+    /// use std::io;
+    ///
+    /// let output = Output::new(OutputFormat::Ndjson, io::stdout(), false);
+    /// output.json_line(&serde_json::json!({ "record": "package", "name": "@org/core" })).unwrap();
+    /// ```
+    pub fn json_line<T: Serialize>(&self, record: &T) -> Result<()> {
+        if !self.format.is_ndjson() {
+            return Ok(());
+        }
+
+        let json = serde_json::to_string(record)
+            .map_err(|e| CliError::execution(format!("JSON serialization failed: {e}")))?;
+        let mut writer = self.writer.borrow_mut();
+        writeln!(writer, "{json}")?;
+        writer.flush()?;
+        Ok(())
+    }
+
     /// Renders and outputs a table.
     ///
     /// In human mode, displays a formatted table.
@@ -539,7 +627,10 @@ impl Output {
                 writeln!(self.writer.borrow_mut(), "{rendered}")?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => {
+            OutputFormat::Json
+            | OutputFormat::JsonCompact
+            | OutputFormat::Ndjson
+            | OutputFormat::Quiet => {
                 // In JSON/quiet modes, tables are ignored
                 Ok(())
             }
@@ -588,7 +679,10 @@ impl Output {
                 writeln!(self.writer.borrow_mut())?;
                 Ok(())
             }
-            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Quiet => Ok(()),
+            OutputFormat::Json
+            | OutputFormat::JsonCompact
+            | OutputFormat::Ndjson
+            | OutputFormat::Quiet => Ok(()),
         }
     }
 
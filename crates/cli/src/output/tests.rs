@@ -54,6 +54,7 @@ fn test_output_format_default() {
 fn test_output_format_is_json() {
     assert!(OutputFormat::Json.is_json());
     assert!(OutputFormat::JsonCompact.is_json());
+    assert!(OutputFormat::Ndjson.is_json());
     assert!(!OutputFormat::Human.is_json());
     assert!(!OutputFormat::Quiet.is_json());
 }
@@ -79,6 +80,7 @@ fn test_output_format_display() {
     assert_eq!(OutputFormat::Human.to_string(), "human");
     assert_eq!(OutputFormat::Json.to_string(), "json");
     assert_eq!(OutputFormat::JsonCompact.to_string(), "json-compact");
+    assert_eq!(OutputFormat::Ndjson.to_string(), "ndjson");
     assert_eq!(OutputFormat::Quiet.to_string(), "quiet");
 }
 
@@ -88,6 +90,7 @@ fn test_output_format_from_str() {
     assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
     assert_eq!("json-compact".parse::<OutputFormat>().unwrap(), OutputFormat::JsonCompact);
     assert_eq!("compact".parse::<OutputFormat>().unwrap(), OutputFormat::JsonCompact);
+    assert_eq!("ndjson".parse::<OutputFormat>().unwrap(), OutputFormat::Ndjson);
     assert_eq!("quiet".parse::<OutputFormat>().unwrap(), OutputFormat::Quiet);
 
     // Case insensitive
@@ -247,6 +250,30 @@ fn test_json_output_compact() {
     assert_eq!(lines.len(), 1);
 }
 
+#[test]
+fn test_json_line_streams_records_in_ndjson_mode() {
+    let (output, buffer) = create_output_with_buffer(OutputFormat::Ndjson, false);
+
+    output.json_line(&serde_json::json!({ "record": "package", "name": "a" })).unwrap();
+    output.json_line(&serde_json::json!({ "record": "summary", "total": 1 })).unwrap();
+
+    let output_str = get_output_string(&buffer);
+    let lines: Vec<serde_json::Value> =
+        output_str.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["name"], "a");
+    assert_eq!(lines[1]["record"], "summary");
+}
+
+#[test]
+fn test_json_line_ignored_outside_ndjson_mode() {
+    let (output, buffer) = create_output_with_buffer(OutputFormat::Json, false);
+
+    output.json_line(&serde_json::json!({ "record": "package" })).unwrap();
+
+    assert!(get_output_string(&buffer).is_empty());
+}
+
 #[test]
 fn test_json_ignored_in_human_mode() {
     use serde::Serialize;
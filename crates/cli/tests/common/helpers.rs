@@ -88,11 +88,26 @@ impl std::io::Write for SharedWriter {
 /// let json: serde_json::Value = serde_json::from_slice(&output_bytes).unwrap();
 /// ```
 pub fn create_shared_json_output() -> (Output, std::sync::Arc<std::sync::Mutex<Vec<u8>>>) {
+    create_shared_output(OutputFormat::Json)
+}
+
+/// Creates an output in the given format with a shared buffer.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let (output, buffer) = create_shared_output(OutputFormat::Ndjson);
+/// command_execute(&output).await?;
+/// let text = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+/// ```
+pub fn create_shared_output(
+    format: OutputFormat,
+) -> (Output, std::sync::Arc<std::sync::Mutex<Vec<u8>>>) {
     use std::sync::{Arc, Mutex};
 
     let buffer = Arc::new(Mutex::new(Vec::new()));
     let writer = SharedWriter { buffer: Arc::clone(&buffer) };
-    let output = Output::new(OutputFormat::Json, Box::new(writer), false);
+    let output = Output::new(format, Box::new(writer), false);
     (output, buffer)
 }
 
@@ -428,13 +443,12 @@ pub fn list_changesets(workspace_root: &Path) -> Vec<std::path::PathBuf> {
 /// # Examples
 ///
 /// ```rust,ignore
-/// if changeset_exists(&workspace.root(), "feature-test").await {
+/// if changeset_exists(&workspace.root(), "feature-test") {
 ///     println!("Changeset exists");
 /// }
 /// ```
-pub async fn changeset_exists(workspace_root: &Path, changeset_name: &str) -> bool {
-    let changeset_path = workspace_root.join(".changesets").join(format!("{changeset_name}.json"));
-    tokio::fs::metadata(&changeset_path).await.is_ok()
+pub fn changeset_exists(workspace_root: &Path, changeset_name: &str) -> bool {
+    find_changeset_file(&workspace_root.join(".changesets"), changeset_name).is_some()
 }
 
 /// Checks if a changeset is archived in the .changesets/history directory.
@@ -444,14 +458,40 @@ pub async fn changeset_exists(workspace_root: &Path, changeset_name: &str) -> bo
 /// # Examples
 ///
 /// ```rust,ignore
-/// if changeset_is_archived(&workspace.root(), "feature-test").await {
+/// if changeset_is_archived(&workspace.root(), "feature-test") {
 ///     println!("Changeset is archived");
 /// }
 /// ```
-pub async fn changeset_is_archived(workspace_root: &Path, changeset_name: &str) -> bool {
-    let archived_path =
-        workspace_root.join(".changesets").join("history").join(format!("{changeset_name}.json"));
-    tokio::fs::metadata(&archived_path).await.is_ok()
+pub fn changeset_is_archived(workspace_root: &Path, changeset_name: &str) -> bool {
+    find_changeset_file(&workspace_root.join(".changesets").join("history"), changeset_name)
+        .is_some()
+}
+
+/// Finds the changeset file of a branch in a changesets directory.
+///
+/// Changeset files are named by id, so the branch recorded in each file is compared
+/// against `changeset_name`, the branch name with `/` replaced by `-`. Archived
+/// changesets keep the branch under `changeset`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let path = find_changeset_file(&workspace.root().join(".changesets"), "feature-test");
+/// assert!(path.is_some());
+/// ```
+pub fn find_changeset_file(dir: &Path, changeset_name: &str) -> Option<std::path::PathBuf> {
+    std::fs::read_dir(dir).ok()?.filter_map(std::result::Result::ok).map(|entry| entry.path()).find(
+        |path| {
+            let Ok(content) = std::fs::read_to_string(path) else {
+                return false;
+            };
+            let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+                return false;
+            };
+            let branch = json["branch"].as_str().or_else(|| json["changeset"]["branch"].as_str());
+            branch.is_some_and(|branch| branch.replace('/', "-") == changeset_name)
+        },
+    )
 }
 
 // =============================================================================
@@ -783,6 +823,53 @@ pub fn create_noop_mock_editor() -> (std::path::PathBuf, EnvVarGuard) {
     create_mock_editor(|content| content)
 }
 
+/// Creates a mock editor that rewrites the `branch` field of the edited changeset.
+///
+/// This simulates a user changing the branch while editing, which the edit command
+/// must reject.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let (_path, _guard) = create_branch_changing_mock_editor("feature/other");
+/// let result = execute_edit(&args, &output, Some(workspace.root()), None).await;
+/// assert!(result.is_err());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the mock editor script cannot be created or made executable.
+#[cfg(unix)]
+#[allow(clippy::expect_used)]
+pub fn create_branch_changing_mock_editor(branch: &str) -> (std::path::PathBuf, EnvVarGuard) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_nanos();
+    let script_path = std::env::temp_dir().join(format!(
+        "mock_editor_branch_{}_{}.sh",
+        std::process::id(),
+        timestamp
+    ));
+
+    let script_content = format!(
+        r#"#!/bin/bash
+sed -i.bak 's|"branch": *"[^"]*"|"branch": "{branch}"|' "$1" && rm -f "$1.bak"
+"#
+    );
+    std::fs::write(&script_path, script_content).expect("Failed to write mock editor script");
+
+    let mut permissions =
+        std::fs::metadata(&script_path).expect("Failed to get script metadata").permissions();
+    permissions.set_mode(0o755);
+    std::fs::set_permissions(&script_path, permissions).expect("Failed to set script permissions");
+
+    let guard = set_env_var("EDITOR", script_path.to_str().expect("Invalid path"));
+    (script_path, guard)
+}
+
 /// Creates a mock editor that fails (exits with non-zero status).
 ///
 /// This simulates editor failure scenarios.
@@ -874,7 +961,8 @@ pub fn modify_changeset_file<F>(workspace_root: &Path, changeset_name: &str, mod
 where
     F: FnOnce(serde_json::Value) -> serde_json::Value,
 {
-    let changeset_path = workspace_root.join(".changesets").join(format!("{changeset_name}.json"));
+    let changeset_path = find_changeset_file(&workspace_root.join(".changesets"), changeset_name)
+        .unwrap_or_else(|| panic!("No changeset file found for {changeset_name}"));
 
     let content = read_file(&changeset_path);
     let json: serde_json::Value =
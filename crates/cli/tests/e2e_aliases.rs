@@ -0,0 +1,77 @@
+//! # E2E Tests for Aliases Command
+//!
+//! **What**: End-to-end tests for the `aliases` command that generates tsconfig `paths` and
+//! package.json `imports` from the workspace dependencies.
+//!
+//! **How**: Creates real temporary monorepos, runs the command in write, check, and import
+//! modes, and validates the written files and the drift reported as JSON.
+//!
+//! **Why**: Ensures the alias maps follow the declared dependencies and that `--check` fails
+//! CI when they drift.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use common::fixtures::WorkspaceFixture;
+use common::helpers::create_shared_json_output;
+use sublime_cli_tools::cli::commands::AliasesArgs;
+use sublime_cli_tools::commands::aliases::execute_aliases;
+
+fn read_json(path: &std::path::Path) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(path).expect("File should exist"))
+        .expect("File should be valid JSON")
+}
+
+/// Test: Aliases command writes tsconfig paths, after which check passes
+#[tokio::test]
+async fn test_aliases_writes_tsconfig_paths() {
+    let workspace =
+        WorkspaceFixture::monorepo_with_internal_deps().with_default_config().finalize();
+    let args = AliasesArgs { check: false, dry_run: false, imports: false };
+    let (output, _buffer) = create_shared_json_output();
+
+    let result = execute_aliases(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Aliases should succeed: {:?}", result.err());
+
+    let tsconfig = read_json(&workspace.root().join("tsconfig.json"));
+    assert_eq!(
+        tsconfig["compilerOptions"]["paths"],
+        serde_json::json!({
+            "@test/pkg-a": ["./packages/pkg-a/src/index.ts"],
+            "@test/pkg-a/*": ["./packages/pkg-a/src/*"],
+        })
+    );
+
+    let check = AliasesArgs { check: true, dry_run: false, imports: false };
+    let (output, buffer) = create_shared_json_output();
+    let result = execute_aliases(&check, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Check should pass after writing: {:?}", result.err());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&buffer.lock().unwrap()).expect("Output should be valid JSON");
+    assert_eq!(json["data"]["inSync"], true);
+}
+
+/// Test: Aliases check fails on drift without writing anything
+#[tokio::test]
+async fn test_aliases_check_fails_on_drift() {
+    let workspace =
+        WorkspaceFixture::monorepo_with_internal_deps().with_default_config().finalize();
+    let args = AliasesArgs { check: true, dry_run: false, imports: true };
+    let (output, buffer) = create_shared_json_output();
+
+    let result = execute_aliases(&args, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Check should fail when aliases are missing");
+    assert!(!workspace.root().join("tsconfig.json").exists());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&buffer.lock().unwrap()).expect("Output should be valid JSON");
+    let files = json["data"]["files"].as_array().expect("files should be an array");
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0]["path"], "tsconfig.json");
+    assert_eq!(files[1]["map"], "imports");
+    assert_eq!(files[1]["drift"][0]["kind"], "missing");
+}
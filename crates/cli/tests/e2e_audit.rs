@@ -135,6 +135,11 @@ async fn test_audit_comprehensive_core_sections() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -160,6 +165,11 @@ async fn test_audit_comprehensive_json_output() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, buffer) = create_json_output();
@@ -196,6 +206,11 @@ async fn test_audit_comprehensive_minimal_verbosity() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -224,6 +239,11 @@ async fn test_audit_comprehensive_detailed_verbosity() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -249,6 +269,11 @@ async fn test_audit_comprehensive_no_health_score() {
         no_health_score: true,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -278,6 +303,11 @@ async fn test_audit_version_consistency_executes() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -306,6 +336,11 @@ async fn test_audit_version_consistency_with_internal_deps() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -331,6 +366,11 @@ async fn test_audit_version_consistency_critical_severity_only() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -360,6 +400,11 @@ async fn test_audit_dependencies_executes() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -388,6 +433,11 @@ async fn test_audit_dependencies_with_circular() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -416,6 +466,11 @@ async fn test_audit_dependencies_with_version_conflicts() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -441,6 +496,11 @@ async fn test_audit_dependencies_with_internal_deps() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -466,6 +526,11 @@ async fn test_audit_dependencies_normal_verbosity() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -499,6 +564,11 @@ async fn test_audit_upgrades_executes() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -528,6 +598,11 @@ async fn test_audit_upgrades_detailed_output() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -555,6 +630,11 @@ async fn test_audit_upgrades_filters_by_severity() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -586,6 +666,11 @@ async fn test_audit_multiple_sections() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -614,6 +699,11 @@ async fn test_audit_duplicate_sections_deduplication() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -645,6 +735,11 @@ async fn test_audit_generates_report_file() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -678,6 +773,11 @@ async fn test_audit_export_html_format() {
         no_health_score: false,
         export: Some("html".to_string()),
         export_file: Some(export_path.clone()),
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -712,6 +812,11 @@ async fn test_audit_export_markdown_format() {
         no_health_score: false,
         export: Some("markdown".to_string()),
         export_file: Some(export_path.clone()),
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -746,6 +851,11 @@ async fn test_audit_export_custom_sections() {
         no_health_score: false,
         export: Some("html".to_string()),
         export_file: Some(export_path.clone()),
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -781,6 +891,11 @@ async fn test_audit_invalid_section_fails_gracefully() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -806,6 +921,11 @@ async fn test_audit_invalid_severity_fails_gracefully() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -831,6 +951,11 @@ async fn test_audit_invalid_verbosity_fails_gracefully() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -854,6 +979,11 @@ async fn test_audit_missing_config_uses_defaults() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -863,6 +993,96 @@ async fn test_audit_missing_config_uses_defaults() {
     assert!(result.is_ok(), "Audit should use defaults when config missing: {:?}", result.err());
 }
 
+// ============================================================================
+// Fix Tests
+// ============================================================================
+
+/// Returns the spec of `dependency` in the dependencies of a package.json.
+fn dependency_spec(package_json: &std::path::Path, dependency: &str) -> String {
+    let manifest: serde_json::Value = read_json_file(package_json);
+    manifest["dependencies"][dependency].as_str().unwrap_or_default().to_string()
+}
+
+/// Test: --fix --dry-run leaves the workspace untouched
+#[tokio::test]
+async fn test_audit_fix_dry_run_writes_nothing() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+    create_internal_version_inconsistencies(&workspace);
+
+    let pkg_b_json = workspace.root().join("packages/pkg-b/package.json");
+    let pkg_c_json = workspace.root().join("packages/pkg-c/package.json");
+    let pkg_b_before = read_file(&pkg_b_json);
+    let pkg_c_before = read_file(&pkg_c_json);
+
+    let args = AuditArgs {
+        sections: vec!["version-consistency".to_string()],
+        output: None,
+        min_severity: "info".to_string(),
+        verbosity: "normal".to_string(),
+        no_health_score: false,
+        export: None,
+        export_file: None,
+        fix: true,
+        dry_run: true,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_audit(&args, &output, workspace.root(), None).await;
+
+    assert!(result.is_ok(), "Dry run should succeed: {:?}", result.err());
+    assert_eq!(read_file(&pkg_b_json), pkg_b_before);
+    assert_eq!(read_file(&pkg_c_json), pkg_c_before);
+}
+
+/// Test: --fix resolves internal version inconsistencies
+#[tokio::test]
+async fn test_audit_fix_resolves_version_inconsistencies() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+    create_internal_version_inconsistencies(&workspace);
+
+    let pkg_b_json = workspace.root().join("packages/pkg-b/package.json");
+    let pkg_c_json = workspace.root().join("packages/pkg-c/package.json");
+    assert_ne!(
+        dependency_spec(&pkg_b_json, "@test/pkg-a"),
+        dependency_spec(&pkg_c_json, "@test/pkg-a")
+    );
+
+    let args = AuditArgs {
+        sections: vec!["version-consistency".to_string()],
+        output: None,
+        min_severity: "info".to_string(),
+        verbosity: "normal".to_string(),
+        no_health_score: false,
+        export: None,
+        export_file: None,
+        fix: true,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_audit(&args, &output, workspace.root(), None).await;
+
+    assert!(result.is_ok(), "Fix should succeed: {:?}", result.err());
+    assert_eq!(
+        dependency_spec(&pkg_b_json, "@test/pkg-a"),
+        dependency_spec(&pkg_c_json, "@test/pkg-a")
+    );
+}
+
 // ============================================================================
 // Health Score Calculation Tests
 // ============================================================================
@@ -887,6 +1107,11 @@ async fn test_audit_calculates_health_score() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -912,6 +1137,11 @@ async fn test_audit_clean_workspace_health() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -944,6 +1174,11 @@ async fn test_audit_breaking_changes_section() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -991,6 +1226,11 @@ async fn test_audit_output_to_file() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1006,6 +1246,58 @@ async fn test_audit_output_to_file() {
     assert!(!content.is_empty(), "Output file should contain audit results");
 }
 
+/// Test: A baseline suppresses known findings and fails on new ones
+#[tokio::test]
+async fn test_audit_baseline_suppresses_known_findings() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+    create_internal_version_inconsistencies(&workspace);
+
+    let args = |baseline: Option<&str>, write_baseline: bool| AuditArgs {
+        sections: vec!["version-consistency".to_string()],
+        output: None,
+        min_severity: "info".to_string(),
+        verbosity: "normal".to_string(),
+        no_health_score: false,
+        export: None,
+        export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: baseline.map(Into::into),
+        write_baseline,
+        baseline_expires: write_baseline.then(|| "2999-12-31".to_string()),
+    };
+
+    // Record the current findings
+    let (output, _buffer) = create_test_output();
+    let result = execute_audit(&args(None, true), &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Writing the baseline should succeed: {:?}", result.err());
+
+    let baseline: serde_json::Value =
+        read_json_file(&workspace.root().join(".audit-baseline.json"));
+    let entries = baseline["entries"].as_array().expect("entries array");
+    assert!(!entries.is_empty(), "Baseline should record the version inconsistency");
+    assert_eq!(entries[0]["expires"], "2999-12-31");
+
+    // Known findings no longer fail the audit
+    let (output, _buffer) = create_test_output();
+    let result =
+        execute_audit(&args(Some(".audit-baseline.json"), false), &output, workspace.root(), None)
+            .await;
+    assert!(result.is_ok(), "Known findings should be suppressed: {:?}", result.err());
+
+    // Findings missing from the baseline do
+    std::fs::write(workspace.root().join("empty-baseline.json"), r#"{"entries": []}"#).unwrap();
+    let (output, _buffer) = create_test_output();
+    let result =
+        execute_audit(&args(Some("empty-baseline.json"), false), &output, workspace.root(), None)
+            .await;
+    assert!(result.is_err(), "New findings should fail the audit");
+}
+
 // ============================================================================
 // Robust Content Validation Tests (Bug Regression Prevention)
 // ============================================================================
@@ -1021,6 +1313,57 @@ async fn test_audit_output_to_file() {
 /// - Summary fields (totalIssues, criticalCount, etc.)
 /// - Sections array with proper structure
 /// - Health score when enabled
+#[tokio::test]
+async fn test_audit_ndjson_output_streams_issues() {
+    use common::helpers::create_shared_output;
+
+    // ARRANGE: Create workspace with version inconsistencies
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+    create_version_inconsistencies(&workspace);
+
+    let args = AuditArgs {
+        sections: vec!["dependencies".to_string(), "version-consistency".to_string()],
+        output: None,
+        min_severity: "info".to_string(),
+        verbosity: "normal".to_string(),
+        no_health_score: false,
+        export: None,
+        export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
+    };
+    let (output, buffer) = create_shared_output(OutputFormat::Ndjson);
+
+    // ACT: Execute audit command
+    let result = execute_audit(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Audit should succeed: {:?}", result.err());
+
+    // ASSERT: Issues are streamed as records, followed by one summary record
+    let text = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+    let records: Vec<serde_json::Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be valid JSON"))
+        .collect();
+    let (summary, issues) = records.split_last().expect("Should write records");
+
+    assert_eq!(summary["record"], "summary");
+    assert!(summary["healthScore"].is_number());
+    assert_eq!(summary["summary"]["totalIssues"].as_u64(), Some(issues.len() as u64));
+    assert!(!issues.is_empty(), "Should stream the inconsistency issues: {text}");
+    for issue in issues {
+        assert_eq!(issue["record"], "issue");
+        assert!(issue["section"].is_string());
+        assert!(issue["title"].is_string());
+    }
+}
+
 #[tokio::test]
 async fn test_audit_json_output_structure_validation() {
     use common::helpers::create_shared_json_output;
@@ -1043,6 +1386,11 @@ async fn test_audit_json_output_structure_validation() {
         no_health_score: false,
         export: None,
         export_file: None,
+        fix: false,
+        dry_run: false,
+        baseline: None,
+        write_baseline: false,
+        baseline_expires: None,
     };
 
     let (output, buffer) = create_shared_json_output();
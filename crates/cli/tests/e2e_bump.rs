@@ -17,9 +17,13 @@
 mod common;
 
 use common::fixtures::{ChangesetBuilder, WorkspaceFixture};
-use common::helpers::{create_json_output, get_package_version};
+use common::helpers::{
+    create_json_output, create_shared_json_output, find_changeset_file, get_package_version,
+};
 use sublime_cli_tools::cli::commands::BumpArgs;
-use sublime_cli_tools::commands::bump::{execute_bump_apply, execute_bump_preview};
+use sublime_cli_tools::commands::bump::{
+    execute_bump_apply, execute_bump_preview, execute_bump_sandbox,
+};
 
 // ============================================================================
 // Preview Tests - Dry Run Mode
@@ -48,6 +52,12 @@ async fn test_bump_preview_shows_changes() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -84,6 +94,12 @@ async fn test_bump_preview_independent_strategy() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -125,6 +141,12 @@ async fn test_bump_preview_unified_strategy() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -157,6 +179,12 @@ async fn test_bump_preview_no_changesets() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -191,6 +219,12 @@ async fn test_bump_preview_json_output() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -203,6 +237,80 @@ async fn test_bump_preview_json_output() {
     assert_eq!(version, "1.0.0", "Version should remain 1.0.0 in preview");
 }
 
+/// Test: Preview with --at resolves the release as of a past commit
+#[tokio::test]
+async fn test_bump_preview_at_past_ref() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .add_changeset(ChangesetBuilder::minor().branch("feature/audit"))
+        .with_default_config()
+        .finalize()
+        .commit_all("feat: add changeset");
+
+    let git = |args: &[&str]| {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(workspace.root())
+            .output()
+            .expect("Failed to run git");
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    };
+    let before_release = git(&["rev-parse", "HEAD"]);
+
+    let mut args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: false,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
+    };
+    let (output, _buffer) = create_json_output();
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Execute should succeed: {:?}", result.err());
+    git(&["add", "."]);
+    git(&["commit", "-m", "chore: release"]);
+    assert_eq!(get_package_version(workspace.root()).await.unwrap(), "1.1.0");
+
+    args.execute = false;
+    args.dry_run = true;
+    args.at = Some(before_release[..10].to_string());
+    let (output, buffer) = create_shared_json_output();
+    let result = execute_bump_preview(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Preview at past ref should succeed: {:?}", result.err());
+
+    let response: serde_json::Value =
+        serde_json::from_slice(&buffer.lock().unwrap()).expect("Output should be JSON");
+    let changesets = response["data"]["changesets"].as_array().unwrap();
+    assert_eq!(changesets.len(), 1);
+    assert_eq!(changesets[0]["branch"], "feature/audit");
+    let packages = response["data"]["packages"].as_array().unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0]["currentVersion"], "1.0.0");
+    assert_eq!(packages[0]["nextVersion"], "1.1.0");
+
+    // The working directory is untouched
+    assert_eq!(get_package_version(workspace.root()).await.unwrap(), "1.1.0");
+
+    args.at = Some("no-such-ref".to_string());
+    let (output, _buffer) = create_json_output();
+    let result = execute_bump_preview(&args, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Unknown reference should fail");
+}
+
 // ============================================================================
 // Execute Tests - Apply Version Bumps
 // ============================================================================
@@ -229,6 +337,12 @@ async fn test_bump_execute_applies_versions() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -263,6 +377,12 @@ async fn test_bump_execute_updates_package_json() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -308,6 +428,12 @@ async fn test_bump_execute_creates_changelog() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -328,6 +454,57 @@ async fn test_bump_execute_creates_changelog() {
     assert_eq!(version, "1.1.0", "Version should be bumped to 1.1.0");
 }
 
+/// Test: Execute replaces the Unreleased changelog section with the release section
+#[tokio::test]
+async fn test_bump_execute_rolls_unreleased_section() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/unreleased"))
+        .with_custom_config(
+            r#"{
+            "changeset": {"path": ".changesets/"},
+            "version": {"strategy": "independent", "defaultBump": "patch"},
+            "changelog": {"enabled": true, "unreleased_section": true}
+        }"#,
+        )
+        .finalize();
+    let changelog_path = workspace.root().join("CHANGELOG.md");
+    std::fs::write(&changelog_path, "# Changelog\n\n## [Unreleased]\n\n- Pending change\n")
+        .unwrap();
+
+    let args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: false,
+        no_archive: true,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
+    };
+
+    let (output, _buffer) = create_json_output();
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Execute should succeed: {:?}", result.err());
+
+    let changelog = std::fs::read_to_string(&changelog_path).unwrap();
+    assert!(!changelog.contains("## [Unreleased]"), "Changelog: {changelog}");
+    assert!(!changelog.contains("Pending change"), "Changelog: {changelog}");
+    assert!(changelog.contains("1.1.0"), "Changelog: {changelog}");
+}
+
 /// Test: Execute archives changesets after successful bump
 #[tokio::test]
 async fn test_bump_execute_archives_changesets() {
@@ -353,6 +530,12 @@ async fn test_bump_execute_archives_changesets() {
         no_archive: false, // Enable archival
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -392,6 +575,12 @@ async fn test_bump_execute_with_git_tag() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -435,6 +624,12 @@ async fn test_bump_execute_with_git_commit() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -478,6 +673,12 @@ async fn test_bump_execute_cascading_bumps() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -515,6 +716,12 @@ async fn test_bump_execute_dry_run() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -555,6 +762,12 @@ async fn test_bump_execute_no_changelog() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -599,6 +812,12 @@ async fn test_bump_execute_unified_version() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -638,6 +857,12 @@ async fn test_bump_execute_multiple_changesets() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -672,6 +897,12 @@ async fn test_bump_execute_major_bump() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -706,6 +937,12 @@ async fn test_bump_execute_independent_only_bumps_changeset_packages() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -720,6 +957,290 @@ async fn test_bump_execute_independent_only_bumps_changeset_packages() {
     workspace.assert_package_version("@test/pkg-b", "1.0.0");
 }
 
+/// Test: Execute with --filter releases matched packages and keeps the rest pending
+#[tokio::test]
+async fn test_bump_execute_filter_splits_changeset() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .add_changeset(
+            ChangesetBuilder::patch()
+                .branch("fix/hotfix")
+                .package("@test/pkg-a")
+                .package("@test/pkg-b"),
+        )
+        .with_default_config()
+        .finalize();
+
+    let args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: false,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: Some("@test/pkg-a".to_string()),
+        sandbox: None,
+        manifest: None,
+    };
+
+    let (output, _buffer) = create_json_output();
+
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Filtered execute should succeed: {:?}", result.err());
+
+    workspace.assert_package_version("@test/pkg-a", "1.0.1");
+    workspace.assert_package_version("@test/pkg-b", "1.0.0");
+
+    // The changeset stays pending with the unreleased package only
+    workspace.assert_changeset_count(1);
+    let pending_path = find_changeset_file(&workspace.root().join(".changesets"), "fix-hotfix")
+        .expect("Pending changeset should remain");
+    let pending = std::fs::read_to_string(pending_path).expect("Should read pending changeset");
+    let pending: serde_json::Value = serde_json::from_str(&pending).unwrap();
+    assert_eq!(pending["packages"], serde_json::json!(["@test/pkg-b"]));
+
+    // The released part is archived under its own branch and id
+    let history_dir = workspace.root().join(".changesets/history");
+    let archived: Vec<serde_json::Value> = std::fs::read_dir(&history_dir)
+        .expect("Should read history directory")
+        .filter_map(|entry| std::fs::read_to_string(entry.ok()?.path()).ok())
+        .filter_map(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .filter(|archived| archived.get("changeset").is_some())
+        .collect();
+    assert_eq!(archived.len(), 1, "Released part should be archived");
+    assert_eq!(archived[0]["changeset"]["branch"], "fix/hotfix@@test/pkg-a");
+    assert_ne!(archived[0]["changeset"]["id"], pending["id"]);
+    let id = archived[0]["changeset"]["id"].as_str().unwrap();
+    assert!(history_dir.join(format!("{id}.json")).exists(), "Archive should be named by id");
+}
+
+/// Test: --manifest reports released and filtered packages of each changeset
+#[tokio::test]
+async fn test_bump_execute_writes_release_manifest() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .add_changeset(
+            ChangesetBuilder::patch()
+                .branch("fix/hotfix")
+                .package("@test/pkg-a")
+                .package("@test/pkg-b"),
+        )
+        .with_default_config()
+        .finalize();
+
+    let args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: false,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: Some("@test/pkg-a".to_string()),
+        sandbox: None,
+        manifest: Some(std::path::PathBuf::from("dist/release-manifest.json")),
+    };
+
+    let (output, buffer) = create_shared_json_output();
+
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Execute with manifest should succeed: {:?}", result.err());
+
+    let manifest_path = workspace.root().join("dist/release-manifest.json");
+    let manifest = std::fs::read_to_string(&manifest_path).expect("Manifest should be written");
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+
+    let changesets = manifest["changesets"].as_array().unwrap();
+    assert_eq!(changesets.len(), 1);
+    assert_eq!(changesets[0]["branch"], "fix/hotfix@@test/pkg-a");
+    assert_eq!(
+        changesets[0]["released"],
+        serde_json::json!([{"name": "@test/pkg-a", "version": "1.0.1"}])
+    );
+    assert_eq!(
+        changesets[0]["skipped"],
+        serde_json::json!([{"name": "@test/pkg-b", "reason": "filtered"}])
+    );
+
+    // The archived release info records the skipped package too
+    let history_dir = workspace.root().join(".changesets/history");
+    let archived: Vec<serde_json::Value> = std::fs::read_dir(&history_dir)
+        .expect("Should read history directory")
+        .filter_map(|entry| std::fs::read_to_string(entry.ok()?.path()).ok())
+        .filter_map(|contents| serde_json::from_str(&contents).ok())
+        .collect();
+    assert_eq!(archived[0]["release_info"]["skipped"][0]["name"], "@test/pkg-b");
+    assert_eq!(archived[0]["changeset"]["id"], changesets[0]["id"]);
+
+    // The manifest is reported, but is a CI artifact rather than a release change
+    let response: serde_json::Value =
+        serde_json::from_slice(&buffer.lock().unwrap()).expect("Output should be JSON");
+    assert_eq!(response["data"]["release_manifest"], manifest_path.display().to_string());
+    let files = response["data"]["files_modified"].as_array().unwrap();
+    assert!(files.iter().all(|file| !file.as_str().unwrap().ends_with("release-manifest.json")));
+}
+
+/// Test: --manifest links the changelog anchors of released packages
+#[tokio::test]
+async fn test_bump_execute_release_manifest_links_changelogs() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/changelog"))
+        .with_default_config()
+        .finalize();
+
+    let args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: false,
+        no_archive: true,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: Some(std::path::PathBuf::from("release-manifest.json")),
+    };
+
+    let (output, _buffer) = create_json_output();
+
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Execute with manifest should succeed: {:?}", result.err());
+
+    // Without archival the changeset stays pending but is still reported
+    workspace.assert_changeset_count(1);
+
+    let manifest = std::fs::read_to_string(workspace.root().join("release-manifest.json"))
+        .expect("Manifest should be written");
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    let released = &manifest["changesets"][0]["released"][0];
+    assert_eq!(released["version"], "1.1.0");
+    let changelog = released["changelog"].as_str().expect("Changelog anchor should be linked");
+    assert!(changelog.starts_with("CHANGELOG.md#110---"), "Unexpected anchor: {changelog}");
+}
+
+/// Test: --filter is rejected with the unified strategy
+#[tokio::test]
+async fn test_bump_filter_rejected_for_unified_strategy() {
+    let workspace = WorkspaceFixture::monorepo_unified()
+        .add_changeset(ChangesetBuilder::minor().branch("feature/unified").package("@test/pkg-a"))
+        .with_custom_config(
+            r#"{
+            "changeset": {"path": ".changesets/"},
+            "version": {"strategy": "unified", "defaultBump": "patch"},
+            "changelog": {"enabled": false}
+        }"#,
+        )
+        .finalize();
+
+    let args = BumpArgs {
+        dry_run: true,
+        execute: false,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: true,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: Some("@test/pkg-a".to_string()),
+        sandbox: None,
+        manifest: None,
+    };
+
+    let (output, _buffer) = create_json_output();
+
+    let result = execute_bump_preview(&args, &output, workspace.root(), None).await;
+    let error = result.expect_err("Unified strategy should reject --filter");
+    assert_eq!(error.diagnostic().map(|d| d.code.as_str()), Some("version::invalid_filter"));
+}
+
+/// Test: --validate-publish blocks the bump until the package can be published
+#[tokio::test]
+async fn test_bump_execute_validate_publish_blocks_unpublishable_package() {
+    let workspace = WorkspaceFixture::single_package()
+        .add_changeset(ChangesetBuilder::minor().branch("feature/publish"))
+        .with_default_config()
+        .finalize();
+
+    let args = BumpArgs {
+        dry_run: false,
+        execute: true,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag: false,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: true,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: true,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
+    };
+
+    let (output, _buffer) = create_json_output();
+
+    let error = execute_bump_apply(&args, &output, workspace.root(), None)
+        .await
+        .expect_err("Missing license and repository should block the bump");
+    assert!(error.to_string().contains("Missing required field 'license'"), "{error}");
+    let version = get_package_version(workspace.root()).await.unwrap();
+    assert_eq!(version, "1.0.0", "Nothing should be modified when publishing is blocked");
+
+    let manifest_path = workspace.root().join("package.json");
+    let mut manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+    manifest["license"] = "MIT".into();
+    manifest["repository"] = "github:test/test-package".into();
+    std::fs::write(&manifest_path, manifest.to_string()).unwrap();
+
+    let result = execute_bump_apply(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Publishable package should bump: {:?}", result.err());
+    let version = get_package_version(workspace.root()).await.unwrap();
+    assert_eq!(version, "1.1.0");
+}
+
 // ============================================================================
 // Error Cases Tests
 // ============================================================================
@@ -743,6 +1264,12 @@ async fn test_bump_execute_fails_no_changesets() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -777,6 +1304,12 @@ async fn test_bump_execute_fails_uninitialized_workspace() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -814,6 +1347,12 @@ async fn test_bump_execute_fails_dirty_git() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -848,6 +1387,12 @@ async fn test_bump_preview_with_show_diff() {
         no_archive: false,
         force: true,
         show_diff: true, // Enable diff display
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -887,6 +1432,12 @@ async fn test_bump_execute_with_package_filter() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -929,6 +1480,12 @@ async fn test_bump_execute_missing_package_json() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -966,6 +1523,12 @@ async fn test_bump_execute_rollback_on_error() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1013,6 +1576,12 @@ async fn test_bump_snapshot_default_format() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1047,6 +1616,12 @@ async fn test_bump_snapshot_custom_format() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1083,6 +1658,12 @@ async fn test_bump_snapshot_independent_strategy() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1121,6 +1702,12 @@ async fn test_bump_snapshot_unified_strategy() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1154,6 +1741,12 @@ async fn test_bump_snapshot_no_changesets() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1193,6 +1786,12 @@ async fn test_bump_snapshot_doesnt_consume_changesets() {
         no_archive: true,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1235,6 +1834,12 @@ async fn test_bump_prerelease_alpha() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1290,6 +1895,12 @@ async fn test_bump_prerelease_beta() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1342,6 +1953,12 @@ async fn test_bump_prerelease_rc() {
         no_archive: false,
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1403,6 +2020,12 @@ async fn test_bump_no_archive_keeps_changesets() {
         no_archive: true, // Don't archive changesets
         force: true,
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1457,6 +2080,12 @@ async fn test_bump_force_skips_confirmations() {
         no_archive: false,
         force: true, // Skip confirmations
         show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: None,
+        manifest: None,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1480,6 +2109,112 @@ async fn test_bump_force_skips_confirmations() {
         .expect("Should read history directory")
         .filter_map(Result::ok)
         .filter(|e| e.path().extension().is_some_and(|ext| ext == "json"))
+        .filter(|e| e.file_name() != "index.json")
         .count();
     assert_eq!(history_count, 1, "History should contain 1 archived changeset");
 }
+
+// ============================================================================
+// Sandbox Tests
+// ============================================================================
+
+/// Builds bump arguments for a `--sandbox` action.
+fn sandbox_args(action: &str, git_tag: bool) -> BumpArgs {
+    BumpArgs {
+        dry_run: false,
+        execute: false,
+        snapshot: false,
+        snapshot_format: None,
+        prerelease: None,
+        packages: None,
+        git_tag,
+        git_push: false,
+        git_commit: false,
+        no_changelog: true,
+        no_archive: false,
+        force: true,
+        show_diff: false,
+        github_summary: false,
+        validate_publish: false,
+        at: None,
+        filter: None,
+        sandbox: Some(action.to_string()),
+        manifest: None,
+    }
+}
+
+/// Test: A sandbox release commits in a worktree and promotion fast-forwards the branch
+#[tokio::test]
+async fn test_bump_sandbox_run_and_promote() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .add_changeset(ChangesetBuilder::minor().branch("feature/sandbox"))
+        .with_default_config()
+        .finalize()
+        .commit_all("Initial commit");
+    let initial_sha = common::helpers::get_latest_commit_sha(workspace.root());
+
+    let (output, _buffer) = create_json_output();
+    let result =
+        execute_bump_sandbox(&sandbox_args("run", false), &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Sandbox run should succeed: {:?}", result.err());
+
+    // The working directory and branch are untouched
+    let version = get_package_version(workspace.root()).await.unwrap();
+    assert_eq!(version, "1.0.0", "Working directory should keep the old version");
+    workspace.assert_changeset_count(1);
+    assert_eq!(common::helpers::get_latest_commit_sha(workspace.root()), initial_sha);
+
+    // The release commit archives the changeset
+    let sandbox_files = common::helpers::run_git_command(
+        workspace.root(),
+        &["show", "--name-status", "--format=", "wnt-release-sandbox"],
+    );
+    assert!(sandbox_files.contains("package.json"), "Release commit: {sandbox_files}");
+    assert!(sandbox_files.contains(".changesets/history"), "Release commit: {sandbox_files}");
+
+    let (output, _buffer) = create_json_output();
+    let result =
+        execute_bump_sandbox(&sandbox_args("promote", true), &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Sandbox promote should succeed: {:?}", result.err());
+
+    let version = get_package_version(workspace.root()).await.unwrap();
+    assert_eq!(version, "1.1.0", "Promotion should bring the release into the branch");
+    workspace.assert_changeset_count(0);
+    assert!(!common::helpers::list_git_tags(workspace.root()).is_empty(), "Tags should exist");
+    let branches = common::helpers::run_git_command(workspace.root(), &["branch", "--list"]);
+    assert!(!branches.contains("wnt-release-sandbox"), "Sandbox branch should be removed");
+}
+
+/// Test: Discarding a sandbox leaves the branch untouched
+#[tokio::test]
+async fn test_bump_sandbox_discard() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .add_changeset(ChangesetBuilder::patch().branch("fix/sandbox"))
+        .with_default_config()
+        .finalize()
+        .commit_all("Initial commit");
+    let initial_sha = common::helpers::get_latest_commit_sha(workspace.root());
+
+    let (output, _buffer) = create_json_output();
+    execute_bump_sandbox(&sandbox_args("run", false), &output, workspace.root(), None)
+        .await
+        .expect("Sandbox run should succeed");
+
+    let (output, _buffer) = create_json_output();
+    execute_bump_sandbox(&sandbox_args("discard", false), &output, workspace.root(), None)
+        .await
+        .expect("Sandbox discard should succeed");
+
+    let branches = common::helpers::run_git_command(workspace.root(), &["branch", "--list"]);
+    assert!(!branches.contains("wnt-release-sandbox"), "Sandbox branch should be removed");
+    assert_eq!(common::helpers::get_latest_commit_sha(workspace.root()), initial_sha);
+
+    // Nothing is left to promote
+    let (output, _buffer) = create_json_output();
+    let result =
+        execute_bump_sandbox(&sandbox_args("promote", false), &output, workspace.root(), None)
+            .await;
+    assert!(result.is_err(), "Promote without a sandbox should fail");
+}
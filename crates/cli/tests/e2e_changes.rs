@@ -80,6 +80,8 @@ async fn test_changes_detects_working_directory_changes() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -120,6 +122,8 @@ async fn test_changes_staged_only() {
         staged: true,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -155,6 +159,8 @@ async fn test_changes_unstaged_only() {
         staged: false,
         unstaged: true,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -203,6 +209,8 @@ async fn test_changes_commit_range() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -214,6 +222,54 @@ async fn test_changes_commit_range() {
     assert!(result.is_ok(), "Changes with commit range should succeed: {:?}", result.err());
 }
 
+/// Test: Changes command filters a commit range by paths and adds uncommitted changes
+///
+/// Verifies that `changes --from <ref> --paths <path> --include-uncommitted` combines
+/// committed and working directory changes and narrows them to the given paths.
+#[tokio::test]
+async fn test_changes_commit_range_with_paths_and_uncommitted() {
+    // ARRANGE: Create monorepo with a committed change in each package
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+
+    create_file_change(&workspace.root().join("packages/pkg-a"), "src/a.js", "export {};\n");
+    create_file_change(&workspace.root().join("packages/pkg-b"), "src/b.js", "export {};\n");
+
+    let repo = sublime_git_tools::Repo::open(workspace.root().to_str().unwrap())
+        .expect("Failed to open repo");
+    repo.add_all().expect("Failed to stage files");
+    repo.commit("feat: add sources").expect("Failed to commit");
+
+    // Leave an uncommitted change in pkg-a
+    create_file_change(&workspace.root().join("packages/pkg-a"), "src/wip.js", "export {};\n");
+
+    let args = ChangesArgs {
+        since: Some("HEAD~1".to_string()),
+        until: None,
+        branch: None,
+        staged: false,
+        unstaged: false,
+        packages: None,
+        paths: Some(vec!["packages/pkg-a".to_string()]),
+        include_uncommitted: true,
+    };
+
+    let output = create_test_output(OutputFormat::Json);
+
+    // ACT: Execute changes command with path filter and uncommitted changes
+    let result = execute_changes(&args, &output, workspace.root(), None).await;
+
+    // ASSERT: Command should succeed
+    assert!(
+        result.is_ok(),
+        "Changes with paths and uncommitted changes should succeed: {:?}",
+        result.err()
+    );
+}
+
 /// Test: Changes command with only --since flag
 ///
 /// Verifies that the `changes --since` command defaults --until to HEAD.
@@ -233,6 +289,8 @@ async fn test_changes_since_only() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -263,6 +321,8 @@ async fn test_changes_until_only() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -314,6 +374,8 @@ async fn test_changes_branch_comparison() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -352,6 +414,8 @@ async fn test_changes_json_output() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Json);
@@ -363,6 +427,52 @@ async fn test_changes_json_output() {
     assert!(result.is_ok(), "Changes with JSON output should succeed: {:?}", result.err());
 }
 
+/// Test: Changes command streams NDJSON records
+///
+/// Verifies that every line is a standalone JSON record, with one `package`
+/// record per package and a closing `summary` record.
+#[tokio::test]
+async fn test_changes_ndjson_output() {
+    use common::helpers::create_shared_output;
+
+    // ARRANGE: Create monorepo with a change in one package
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+    create_file_change(workspace.root(), "packages/pkg-a/src/new.js", "export const a = 1;\n");
+
+    let args = ChangesArgs {
+        since: None,
+        until: None,
+        branch: None,
+        staged: false,
+        unstaged: false,
+        packages: None,
+        paths: None,
+        include_uncommitted: false,
+    };
+    let (output, buffer) = create_shared_output(OutputFormat::Ndjson);
+
+    // ACT: Execute changes command with NDJSON format
+    let result = execute_changes(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Changes with NDJSON output should succeed: {:?}", result.err());
+
+    // ASSERT: Each line is a record and the summary comes last
+    let text = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+    let records: Vec<serde_json::Value> = text
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be valid JSON"))
+        .collect();
+    let last = records.last().expect("Should write records");
+    assert_eq!(last["record"], "summary");
+    assert!(
+        records.iter().any(|r| r["record"] == "package" && r["filesChanged"].as_u64() > Some(0)),
+        "Should stream the changed package: {text}"
+    );
+}
+
 /// Test: Changes command with quiet output format
 ///
 /// Verifies that the `changes` command works with quiet output format.
@@ -382,6 +492,8 @@ async fn test_changes_quiet_output() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Quiet);
@@ -417,6 +529,8 @@ async fn test_changes_no_changes_detected() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -448,6 +562,8 @@ async fn test_changes_invalid_git_reference() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -475,6 +591,8 @@ async fn test_changes_fails_without_git() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -506,6 +624,8 @@ async fn test_changes_nonexistent_branch() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -589,6 +709,8 @@ async fn test_changes_with_custom_config() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -640,6 +762,8 @@ async fn test_changes_filter_by_packages() {
         staged: false,
         unstaged: false,
         packages: Some(vec!["@test/pkg-a".to_string()]),
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -685,6 +809,8 @@ async fn test_changes_filter_by_packages_with_dependencies() {
         staged: false,
         unstaged: false,
         packages: Some(vec!["@test/pkg-b".to_string()]),
+        paths: None,
+        include_uncommitted: false,
     };
 
     let output = create_test_output(OutputFormat::Human);
@@ -760,6 +886,8 @@ async fn test_changes_working_directory_line_statistics_validation() {
         staged: false,
         unstaged: false,
         packages: None,
+        paths: None,
+        include_uncommitted: false,
     };
 
     let (output, buffer) = create_shared_json_output();
@@ -18,15 +18,20 @@
 mod common;
 
 use common::fixtures::{ChangesetBuilder, WorkspaceFixture};
-use common::helpers::{count_changesets, list_changesets, read_json_file};
+use common::helpers::{
+    count_changesets, get_latest_commit_sha, list_changesets, list_git_tags, read_json_file,
+};
 use std::io::Cursor;
 use sublime_cli_tools::cli::commands::{
-    ChangesetCreateArgs, ChangesetDeleteArgs, ChangesetListArgs, ChangesetShowArgs,
-    ChangesetUpdateArgs,
+    ChangesetCleanArgs, ChangesetCreateArgs, ChangesetDeleteArgs, ChangesetListArgs,
+    ChangesetMergeArgs, ChangesetMigrateArgs, ChangesetPromoteArgs, ChangesetShowArgs,
+    ChangesetSplitArgs, ChangesetUpdateArgs,
 };
 use sublime_cli_tools::commands::changeset::{
-    execute_add, execute_list, execute_remove, execute_show, execute_update,
+    execute_add, execute_clean, execute_list, execute_merge, execute_migrate, execute_promote,
+    execute_remove, execute_show, execute_split, execute_update,
 };
+use sublime_cli_tools::error::CliError;
 use sublime_cli_tools::output::{Output, OutputFormat};
 
 // ============================================================================
@@ -40,6 +45,31 @@ fn create_test_output() -> (Output, Cursor<Vec<u8>>) {
     (output, buffer)
 }
 
+/// Configuration promoting releases from staging to production.
+const PROMOTION_CONFIG: &str = r#"{
+    "changeset": {
+        "path": ".changesets/",
+        "available_environments": ["staging", "production"],
+        "default_environments": ["staging", "production"]
+    },
+    "version": {
+        "strategy": "independent",
+        "defaultBump": "patch"
+    }
+}"#;
+
+/// Creates promote arguments that only record the promotion.
+fn promote_args(reference: &str, to: &str) -> ChangesetPromoteArgs {
+    ChangesetPromoteArgs {
+        reference: reference.to_string(),
+        to: to.to_string(),
+        retag: false,
+        dist_tag: false,
+        push: false,
+        dry_run: false,
+    }
+}
+
 // ============================================================================
 // Changeset Create/Add Tests
 // ============================================================================
@@ -61,6 +91,10 @@ async fn test_changeset_create_single_package() {
         message: Some("Add new feature".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -79,6 +113,152 @@ async fn test_changeset_create_single_package() {
     assert_eq!(changeset["bump"].as_str().unwrap(), "minor");
 }
 
+/// Test: Create changeset from a YAML document with --from-file
+#[tokio::test]
+async fn test_changeset_create_from_file_document() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .with_branch("feature/bot")
+        .finalize();
+
+    let document = workspace.root().join("changeset.yaml");
+    std::fs::write(
+        &document,
+        "bump: patch\npackages: [test-package]\nenvironments: [production]\nbranch: feature/bot\n",
+    )
+    .unwrap();
+
+    let args = ChangesetCreateArgs {
+        bump: None,
+        env: None,
+        branch: None,
+        message: None,
+        packages: None,
+        non_interactive: false,
+        stdin: false,
+        from_file: Some(document),
+        from_diff: false,
+        base: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_add(&args, &output, Some(workspace.root().to_path_buf()), None).await;
+    assert!(result.is_ok(), "Create from document should succeed: {:?}", result.err());
+
+    let changesets = list_changesets(workspace.root());
+    assert_eq!(changesets.len(), 1);
+    let changeset: serde_json::Value = read_json_file(&changesets[0]);
+    assert_eq!(changeset["branch"].as_str().unwrap(), "feature/bot");
+    assert_eq!(changeset["bump"].as_str().unwrap(), "patch");
+    assert_eq!(changeset["packages"][0].as_str().unwrap(), "test-package");
+}
+
+/// Test: Create changeset with packages and bump proposed from the branch diff
+#[tokio::test]
+async fn test_changeset_create_from_diff() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .finalize()
+        .commit_all("chore: initial workspace")
+        .with_branch("feature/from-diff");
+
+    std::fs::write(workspace.root().join("packages/pkg-a/login.js"), "// login\n").unwrap();
+    let workspace = workspace.commit_all("feat(pkg-a): add login");
+
+    let args = ChangesetCreateArgs {
+        bump: None,
+        env: None,
+        branch: None,
+        message: None,
+        packages: None,
+        non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: true,
+        base: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_add(&args, &output, Some(workspace.root().to_path_buf()), None).await;
+    assert!(result.is_ok(), "Create from diff should succeed: {:?}", result.err());
+
+    let changesets = list_changesets(workspace.root());
+    assert_eq!(changesets.len(), 1);
+    let changeset: serde_json::Value = read_json_file(&changesets[0]);
+    assert_eq!(changeset["branch"].as_str().unwrap(), "feature/from-diff");
+    assert_eq!(changeset["bump"].as_str().unwrap(), "minor");
+    assert_eq!(changeset["packages"], serde_json::json!(["@test/pkg-a"]));
+}
+
+/// Test: Creating a changeset writes the Unreleased changelog section when enabled
+#[tokio::test]
+async fn test_changeset_create_updates_unreleased_changelog() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(
+            r#"{
+            "changeset": {"path": ".changesets/"},
+            "changelog": {"enabled": true, "unreleased_section": true}
+        }"#,
+        )
+        .with_git()
+        .finalize()
+        .commit_all("feat: add search")
+        .with_branch("feature/unreleased");
+
+    let args = ChangesetCreateArgs {
+        bump: Some("minor".to_string()),
+        env: None,
+        branch: None,
+        message: None,
+        packages: Some(vec!["test-package".to_string()]),
+        non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_add(&args, &output, Some(workspace.root().to_path_buf()), None).await;
+    assert!(result.is_ok(), "Create changeset should succeed: {:?}", result.err());
+
+    let changelog = std::fs::read_to_string(workspace.root().join("CHANGELOG.md")).unwrap();
+    assert!(changelog.contains("## [Unreleased]"), "Changelog: {changelog}");
+    assert!(changelog.contains("add search"), "Changelog: {changelog}");
+}
+
+/// Test: --from-diff fails when the base branch does not exist
+#[tokio::test]
+async fn test_changeset_create_from_diff_unknown_base() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .with_branch("feature/no-base")
+        .finalize();
+
+    let args = ChangesetCreateArgs {
+        bump: None,
+        env: None,
+        branch: None,
+        message: None,
+        packages: None,
+        non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: true,
+        base: Some("develop".to_string()),
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_add(&args, &output, Some(workspace.root().to_path_buf()), None).await;
+    assert!(result.is_err(), "Unknown base branch should fail");
+    assert_eq!(count_changesets(workspace.root()), 0);
+}
+
 /// Test: Create changeset with major bump
 #[tokio::test]
 async fn test_changeset_create_with_major_bump() {
@@ -96,6 +276,10 @@ async fn test_changeset_create_with_major_bump() {
         message: Some("Breaking changes".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -125,6 +309,10 @@ async fn test_changeset_create_with_patch_bump() {
         message: Some("Fix bug".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -154,6 +342,10 @@ async fn test_changeset_create_monorepo_multiple_packages() {
         message: Some("Update multiple packages".to_string()),
         packages: Some(vec!["@test/pkg-a".to_string(), "@test/pkg-b".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -208,6 +400,10 @@ async fn test_changeset_create_with_multiple_environments() {
         message: Some("Multi-environment release".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -246,6 +442,10 @@ async fn test_changeset_create_detects_git_branch() {
         message: Some("Auto-detect branch".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -276,6 +476,10 @@ async fn test_changeset_create_fails_duplicate_branch() {
         message: Some("Duplicate".to_string()),
         packages: None,
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -416,7 +620,13 @@ async fn test_changeset_list_shows_all() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -443,7 +653,13 @@ async fn test_changeset_list_filters_by_package() {
         filter_package: Some("@test/pkg-a".to_string()),
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -466,7 +682,13 @@ async fn test_changeset_list_json_output() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let buffer = Cursor::new(Vec::new());
@@ -495,7 +717,13 @@ async fn test_changeset_list_empty_workspace() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -651,6 +879,456 @@ async fn test_changeset_remove_not_found() {
     assert!(result.is_err(), "Remove should fail when changeset not found");
 }
 
+// ============================================================================
+// Changeset Split/Merge Tests
+// ============================================================================
+
+/// Test: Split moves packages into a new changeset
+#[tokio::test]
+async fn test_changeset_split_creates_new_changeset() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(
+            ChangesetBuilder::minor()
+                .branch("feature/split-me")
+                .package("@test/pkg-a")
+                .package("@test/pkg-b"),
+        )
+        .finalize();
+
+    let args = ChangesetSplitArgs {
+        branch: "feature/split-me".to_string(),
+        packages: vec!["@test/pkg-b".to_string()],
+        into: "feature/pkg-b".to_string(),
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_split(&args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_ok(), "Split should succeed: {:?}", result.err());
+    assert_eq!(count_changesets(workspace.root()), 2, "Should have 2 changesets after split");
+}
+
+/// Test: Split fails for a package not in the changeset
+#[tokio::test]
+async fn test_changeset_split_fails_unknown_package() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(
+            ChangesetBuilder::minor()
+                .branch("feature/split-me")
+                .package("@test/pkg-a")
+                .package("@test/pkg-b"),
+        )
+        .finalize();
+
+    let args = ChangesetSplitArgs {
+        branch: "feature/split-me".to_string(),
+        packages: vec!["@test/unknown".to_string()],
+        into: "feature/unknown".to_string(),
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_split(&args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_err(), "Split should fail for a package not in the changeset");
+    assert_eq!(count_changesets(workspace.root()), 1);
+}
+
+/// Test: Merge combines changesets and removes the sources
+#[tokio::test]
+async fn test_changeset_merge_combines_changesets() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changesets(vec![
+            ChangesetBuilder::patch().branch("fix/merge-a").package("@test/pkg-a"),
+            ChangesetBuilder::major().branch("feature/merge-b").package("@test/pkg-b"),
+        ])
+        .finalize();
+
+    let args = ChangesetMergeArgs {
+        branches: vec!["fix/merge-a".to_string(), "feature/merge-b".to_string()],
+        into: "release/merged".to_string(),
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_merge(&args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_ok(), "Merge should succeed: {:?}", result.err());
+    assert_eq!(count_changesets(workspace.root()), 1, "Should have 1 changeset after merge");
+}
+
+/// Test: Merge fails when a changeset does not exist
+#[tokio::test]
+async fn test_changeset_merge_fails_not_found() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/exists"))
+        .finalize();
+
+    let args = ChangesetMergeArgs {
+        branches: vec!["feature/exists".to_string(), "nonexistent".to_string()],
+        into: "release/merged".to_string(),
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_merge(&args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_err(), "Merge should fail when a changeset is missing");
+    assert_eq!(count_changesets(workspace.root()), 1);
+}
+
+/// Test: Migrate copies changesets to the new location and is safe to re-run
+#[tokio::test]
+async fn test_changeset_migrate_copies_changesets() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changesets(vec![
+            ChangesetBuilder::patch().branch("fix/migrate-a").package("@test/pkg-a"),
+            ChangesetBuilder::minor().branch("feature/migrate-b").package("@test/pkg-b"),
+        ])
+        .finalize();
+
+    let args = ChangesetMigrateArgs {
+        to: "file".to_string(),
+        path: "changes".to_string(),
+        history_path: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_migrate(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Migrate should succeed: {:?}", result.err());
+
+    let copied = std::fs::read_dir(workspace.root().join("changes"))
+        .expect("target directory should exist")
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .count();
+    assert_eq!(copied, 2, "Both changesets should be copied");
+    assert_eq!(count_changesets(workspace.root()), 2, "The source should be left untouched");
+
+    // A second run finds everything in place
+    let (output, _buffer) = create_test_output();
+    let rerun = execute_migrate(&args, &output, Some(workspace.root()), None).await;
+    assert!(rerun.is_ok(), "Re-running migrate should succeed: {:?}", rerun.err());
+}
+
+/// Test: Migrate refuses to copy onto the configured storage
+#[tokio::test]
+async fn test_changeset_migrate_rejects_configured_location() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/exists"))
+        .finalize();
+
+    // Spellings of the configured directory must not get past the check
+    for path in [".changesets", "./.changesets", "changes/../.changesets"] {
+        let args = ChangesetMigrateArgs {
+            to: "file".to_string(),
+            path: path.to_string(),
+            history_path: None,
+        };
+
+        let (output, _buffer) = create_test_output();
+        let result = execute_migrate(&args, &output, Some(workspace.root()), None).await;
+
+        assert!(
+            matches!(result, Err(CliError::Validation(_))),
+            "Migrate onto the configured storage via '{path}' should fail: {result:?}"
+        );
+    }
+}
+
+/// Test: Migrate rejects a storage backend that is not registered
+#[tokio::test]
+async fn test_changeset_migrate_rejects_unknown_backend() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/exists"))
+        .finalize();
+
+    let args = ChangesetMigrateArgs {
+        to: "s3".to_string(),
+        path: "changes".to_string(),
+        history_path: None,
+    };
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_migrate(&args, &output, Some(workspace.root()), None).await;
+
+    let error = result.expect_err("An unknown backend should be rejected");
+    assert!(error.to_string().contains("s3"), "Error should name the backend: {error}");
+    assert!(!workspace.root().join("changes").exists(), "Nothing should be copied");
+}
+
+/// Test: Clean prunes packages that left the workspace and deletes emptied changesets
+#[tokio::test]
+async fn test_changeset_clean_prunes_removed_packages() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .add_changesets(vec![
+            ChangesetBuilder::minor()
+                .branch("feature/clean-a")
+                .package("@test/pkg-a")
+                .package("@test/removed"),
+            ChangesetBuilder::patch().branch("feature/clean-b").package("@test/removed"),
+        ])
+        .finalize();
+
+    // A dry run leaves the changesets untouched
+    let (output, _buffer) = create_test_output();
+    let args = ChangesetCleanArgs { prune: true, dry_run: true };
+    let result = execute_clean(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Dry run should succeed: {:?}", result.err());
+    assert_eq!(count_changesets(workspace.root()), 2);
+
+    let (output, _buffer) = create_test_output();
+    let args = ChangesetCleanArgs { prune: true, dry_run: false };
+    let result = execute_clean(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Clean should succeed: {:?}", result.err());
+
+    let remaining = list_changesets(workspace.root());
+    assert_eq!(remaining.len(), 1, "The changeset left without packages should be deleted");
+    let changeset: serde_json::Value = read_json_file(&remaining[0]);
+    assert_eq!(changeset["packages"], serde_json::json!(["@test/pkg-a"]));
+}
+
+// ============================================================================
+// Changeset Promote Tests
+// ============================================================================
+
+/// Test: Promote records environments in the configured promotion order
+#[tokio::test]
+async fn test_changeset_promote_follows_promotion_order() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(1)
+        .add_changeset(
+            ChangesetBuilder::minor()
+                .branch("feature/sso")
+                .environments(&["staging", "production"]),
+        )
+        .finalize();
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(
+        &promote_args("feature/sso", "production"),
+        &output,
+        Some(workspace.root()),
+        None,
+    )
+    .await;
+    assert!(result.is_err(), "Production should require staging first");
+
+    for environment in ["staging", "production"] {
+        let (output, _buffer) = create_test_output();
+        let result = execute_promote(
+            &promote_args("feature/sso", environment),
+            &output,
+            Some(workspace.root()),
+            None,
+        )
+        .await;
+        assert!(result.is_ok(), "Promote to {environment} should succeed: {:?}", result.err());
+    }
+
+    let changesets = list_changesets(workspace.root());
+    let changeset: serde_json::Value = read_json_file(&changesets[0]);
+    let promotions = changeset["promotions"].as_array().expect("promotions should be recorded");
+    assert_eq!(promotions.len(), 2);
+    assert_eq!(promotions[0]["environment"], "staging");
+    assert_eq!(promotions[1]["environment"], "production");
+}
+
+/// Writes an archived changeset `c0ffee00` released at `commit` to the history directory.
+fn write_archived_changeset(
+    root: &std::path::Path,
+    packages: &[&str],
+    versions: &serde_json::Value,
+    commit: &str,
+) -> std::path::PathBuf {
+    let history_dir = root.join(".changesets/history");
+    std::fs::create_dir_all(&history_dir).expect("Failed to create history dir");
+    let archived = serde_json::json!({
+        "changeset": {
+            "id": "c0ffee00",
+            "branch": "feature/sso",
+            "bump": "minor",
+            "environments": ["staging", "production"],
+            "packages": packages,
+            "changes": [],
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        },
+        "release_info": {
+            "applied_at": "2024-01-02T00:00:00Z",
+            "applied_by": "ci-bot",
+            "git_commit": commit,
+            "versions": versions
+        }
+    });
+    let path = history_dir.join("c0ffee00.json");
+    std::fs::write(&path, serde_json::to_string_pretty(&archived).expect("Failed to serialize"))
+        .expect("Failed to write archived changeset");
+    path
+}
+
+/// Test: Promoting a released changeset tags the release commit for the environment
+#[tokio::test]
+async fn test_changeset_promote_archived_with_retag() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(2)
+        .finalize();
+    let release_commit = get_latest_commit_sha(workspace.root());
+    let archive = write_archived_changeset(
+        workspace.root(),
+        &["test-package"],
+        &serde_json::json!({ "test-package": "1.1.0" }),
+        &release_commit,
+    );
+
+    let args = ChangesetPromoteArgs { retag: true, ..promote_args("c0ffee00", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Promote should succeed: {:?}", result.err());
+
+    assert!(list_git_tags(workspace.root()).contains(&"staging/v1.1.0".to_string()));
+    let tagged = common::helpers::run_git_command(
+        workspace.root(),
+        &["rev-list", "-n", "1", "staging/v1.1.0"],
+    );
+    assert_eq!(tagged.trim(), release_commit);
+
+    let archived: serde_json::Value = read_json_file(&archive);
+    assert_eq!(archived["changeset"]["promotions"][0]["environment"], "staging");
+    assert_eq!(archived["release_info"]["git_commit"], release_commit.as_str());
+}
+
+/// Test: A promotion whose push failed after tagging can be retried
+#[tokio::test]
+async fn test_changeset_promote_retag_retries_after_failure() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(2)
+        .finalize();
+    let release_commit = get_latest_commit_sha(workspace.root());
+    let archive = write_archived_changeset(
+        workspace.root(),
+        &["test-package"],
+        &serde_json::json!({ "test-package": "1.1.0" }),
+        &release_commit,
+    );
+
+    // The fixture has no remote, so pushing fails after the tag was created
+    let args =
+        ChangesetPromoteArgs { retag: true, push: true, ..promote_args("c0ffee00", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_err(), "Promote should fail when the push fails");
+    assert!(list_git_tags(workspace.root()).contains(&"staging/v1.1.0".to_string()));
+    let archived: serde_json::Value = read_json_file(&archive);
+    assert!(archived["changeset"].get("promotions").is_none());
+
+    let args = ChangesetPromoteArgs { retag: true, ..promote_args("c0ffee00", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Retrying the promotion should succeed: {:?}", result.err());
+    let archived: serde_json::Value = read_json_file(&archive);
+    assert_eq!(archived["changeset"]["promotions"][0]["environment"], "staging");
+}
+
+/// Test: Retag refuses an environment tag that points away from the release commit
+#[tokio::test]
+async fn test_changeset_promote_retag_rejects_moved_tag() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(2)
+        .finalize();
+    let release_commit = get_latest_commit_sha(workspace.root());
+    common::helpers::run_git_command(workspace.root(), &["tag", "staging/v1.1.0", "HEAD~1"]);
+    let archive = write_archived_changeset(
+        workspace.root(),
+        &["test-package"],
+        &serde_json::json!({ "test-package": "1.1.0" }),
+        &release_commit,
+    );
+
+    let args = ChangesetPromoteArgs { retag: true, ..promote_args("c0ffee00", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_err(), "Promote should fail when the tag is elsewhere");
+    let archived: serde_json::Value = read_json_file(&archive);
+    assert!(archived["changeset"].get("promotions").is_none());
+}
+
+/// Test: Retag names tags after the whole release and only tags the changeset's packages
+#[tokio::test]
+async fn test_changeset_promote_retag_uses_release_tag_names() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(2)
+        .finalize();
+    let release_commit = get_latest_commit_sha(workspace.root());
+    write_archived_changeset(
+        workspace.root(),
+        &["pkg-a"],
+        &serde_json::json!({ "pkg-a": "1.1.0", "pkg-b": "2.0.0" }),
+        &release_commit,
+    );
+
+    let args = ChangesetPromoteArgs { retag: true, ..promote_args("c0ffee00", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_ok(), "Promote should succeed: {:?}", result.err());
+
+    // Bump tagged the two released packages `pkg-a@1.1.0` and `pkg-b@2.0.0`
+    let tags = list_git_tags(workspace.root());
+    assert!(tags.contains(&"staging/pkg-a@1.1.0".to_string()), "tags: {tags:?}");
+    assert!(!tags.iter().any(|tag| tag.contains("pkg-b") || tag == "staging/v1.1.0"));
+}
+
+/// Test: Re-releasing a pending changeset fails without recording the promotion
+#[tokio::test]
+async fn test_changeset_promote_retag_requires_release() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_custom_config(PROMOTION_CONFIG)
+        .with_git()
+        .with_commits(1)
+        .add_changeset(ChangesetBuilder::minor().branch("feature/sso").environments(&["staging"]))
+        .finalize();
+
+    let args = ChangesetPromoteArgs { retag: true, ..promote_args("feature/sso", "staging") };
+    let (output, _buffer) = create_test_output();
+    let result = execute_promote(&args, &output, Some(workspace.root()), None).await;
+    assert!(result.is_err(), "Retag should fail for a pending changeset");
+
+    let changesets = list_changesets(workspace.root());
+    let changeset: serde_json::Value = read_json_file(&changesets[0]);
+    assert!(changeset.get("promotions").is_none());
+}
+
 // ============================================================================
 // Additional Integration Tests
 // ============================================================================
@@ -672,6 +1350,10 @@ async fn test_complete_changeset_workflow() {
         message: Some("First feature".to_string()),
         packages: Some(vec!["@test/pkg-a".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _) = create_test_output();
@@ -685,6 +1367,10 @@ async fn test_complete_changeset_workflow() {
         message: Some("Bug fix".to_string()),
         packages: Some(vec!["@test/pkg-b".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _) = create_test_output();
@@ -697,7 +1383,13 @@ async fn test_complete_changeset_workflow() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _) = create_test_output();
@@ -965,6 +1657,10 @@ async fn test_changeset_edit_no_changes() {
         message: Some("Initial changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1009,6 +1705,10 @@ async fn test_changeset_edit_with_modifications() {
         message: Some("Initial changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1050,7 +1750,7 @@ async fn test_changeset_edit_with_modifications() {
 /// This is acceptable behavior as the user should fix the JSON manually.
 #[tokio::test]
 async fn test_changeset_edit_invalid_json_fails() {
-    use common::helpers::{create_noop_mock_editor, write_file};
+    use common::helpers::{create_noop_mock_editor, find_changeset_file, write_file};
     use sublime_cli_tools::cli::commands::ChangesetEditArgs;
     use sublime_cli_tools::commands::changeset::execute_edit;
 
@@ -1069,6 +1769,10 @@ async fn test_changeset_edit_invalid_json_fails() {
         message: Some("Valid changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1080,7 +1784,9 @@ async fn test_changeset_edit_invalid_json_fails() {
     let (_editor_path, _guard) = create_noop_mock_editor();
 
     // Corrupt the changeset file (invalid JSON)
-    let changeset_path = workspace.root().join(".changesets").join("feature-invalid.json");
+    let changeset_path =
+        find_changeset_file(&workspace.root().join(".changesets"), "feature-invalid")
+            .expect("Changeset file should exist");
     write_file(&changeset_path, "{ invalid json }}");
 
     // Execute edit command - should fail
@@ -1123,6 +1829,10 @@ async fn test_changeset_edit_empty_packages_fails() {
         message: Some("Valid changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1176,6 +1886,10 @@ async fn test_changeset_edit_empty_environments_reverts() {
         message: Some("Valid changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1206,9 +1920,12 @@ async fn test_changeset_edit_empty_environments_reverts() {
 }
 
 /// Test: Edit command validates branch name cannot be changed
+#[cfg(unix)]
 #[tokio::test]
 async fn test_changeset_edit_branch_name_change_reverts() {
-    use common::helpers::{create_noop_mock_editor, modify_changeset_file};
+    use common::helpers::{
+        create_branch_changing_mock_editor, find_changeset_file, read_json_file,
+    };
     use sublime_cli_tools::cli::commands::ChangesetEditArgs;
     use sublime_cli_tools::commands::changeset::execute_edit;
 
@@ -1227,6 +1944,10 @@ async fn test_changeset_edit_branch_name_change_reverts() {
         message: Some("Valid changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1234,14 +1955,8 @@ async fn test_changeset_edit_branch_name_change_reverts() {
         .await
         .expect("Failed to create changeset");
 
-    // Setup mock editor
-    let (_editor_path, _guard) = create_noop_mock_editor();
-
-    // Try to change branch name (not allowed)
-    modify_changeset_file(workspace.root(), "feature-original", |mut json| {
-        json["branch"] = serde_json::Value::String("feature/different".to_string());
-        json
-    });
+    // Setup mock editor that tries to change the branch name (not allowed)
+    let (_editor_path, _guard) = create_branch_changing_mock_editor("feature/different");
 
     // Execute edit command - should fail
     let edit_args = ChangesetEditArgs { branch: Some("feature/original".to_string()) };
@@ -1254,6 +1969,13 @@ async fn test_changeset_edit_branch_name_change_reverts() {
         result.unwrap_err().to_string().contains("Branch name mismatch"),
         "Error should mention branch name cannot be changed"
     );
+
+    // The original changeset is restored
+    let changeset_path =
+        find_changeset_file(&workspace.root().join(".changesets"), "feature-original")
+            .expect("Original changeset should be restored");
+    let restored: serde_json::Value = read_json_file(&changeset_path);
+    assert_eq!(restored["branch"], "feature/original");
 }
 
 /// Test: Edit command with failing editor (editor crashes or returns error)
@@ -1278,6 +2000,10 @@ async fn test_changeset_edit_editor_fails() {
         message: Some("Test changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1338,6 +2064,10 @@ async fn test_changeset_edit_auto_detect_branch() {
         message: Some("Test changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1379,6 +2109,10 @@ async fn test_changeset_edit_monorepo() {
         message: Some("Multi-package change".to_string()),
         packages: Some(vec!["@test/pkg-a".to_string(), "@test/pkg-b".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1423,6 +2157,10 @@ async fn test_changeset_edit_json_output() {
         message: Some("Test changeset".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1481,6 +2219,10 @@ async fn test_changeset_create_with_custom_message() {
         message: Some("feat: Added new authentication system".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1519,6 +2261,10 @@ async fn test_changeset_create_with_custom_branch() {
         message: None,
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1553,6 +2299,10 @@ async fn test_changeset_create_auto_detect_packages_vs_manual() {
         message: None,
         packages: Some(vec!["@test/pkg-a".to_string()]), // Manual override
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1744,7 +2494,13 @@ async fn test_changeset_list_filter_by_bump_type() {
         filter_package: None,
         filter_bump: Some("major".to_string()),
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1775,7 +2531,13 @@ async fn test_changeset_list_filter_by_environment() {
         filter_package: None,
         filter_bump: None,
         filter_env: Some("production".to_string()),
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "date".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1802,7 +2564,13 @@ async fn test_changeset_list_sort_by_bump() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "bump".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1829,7 +2597,13 @@ async fn test_changeset_list_sort_by_branch() {
         filter_package: None,
         filter_bump: None,
         filter_env: None,
+        filter_author: None,
+        newer_than: None,
+        older_than: None,
         sort: "branch".to_string(),
+        reverse: false,
+        limit: None,
+        offset: 0,
     };
 
     let (output, _buffer) = create_test_output();
@@ -1840,6 +2614,40 @@ async fn test_changeset_list_sort_by_branch() {
     // Verify: Should be sorted alphabetically by branch name
 }
 
+/// Test: List with pagination
+///
+/// Validates that `--limit` and `--offset` are accepted, including an offset
+/// past the last matching changeset.
+#[tokio::test]
+async fn test_changeset_list_pagination() {
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .add_changeset(ChangesetBuilder::minor().branch("feature/zebra"))
+        .add_changeset(ChangesetBuilder::minor().branch("feature/alpha"))
+        .add_changeset(ChangesetBuilder::minor().branch("feature/beta"))
+        .finalize();
+
+    for offset in [1, 5] {
+        let args = ChangesetListArgs {
+            filter_package: None,
+            filter_bump: None,
+            filter_env: None,
+            filter_author: None,
+            newer_than: None,
+            older_than: None,
+            sort: "branch".to_string(),
+            reverse: false,
+            limit: Some(1),
+            offset,
+        };
+
+        let (output, _buffer) = create_test_output();
+        let result = execute_list(&args, &output, Some(workspace.root()), None).await;
+
+        assert!(result.is_ok(), "List with offset {offset} should succeed");
+    }
+}
+
 // ============================================================================
 // Changeset History Tests - HIGH PRIORITY GAP COVERAGE
 // ============================================================================
@@ -2032,6 +2840,10 @@ async fn test_changeset_check_exists_for_current_branch() {
         message: Some("Add new API".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _) = create_test_output();
@@ -2077,6 +2889,10 @@ async fn test_changeset_check_exists_for_specific_branch() {
         message: Some("Bug fix".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _) = create_test_output();
@@ -2133,6 +2949,54 @@ async fn test_changeset_check_not_exists() {
     );
 }
 
+/// Test: Check passes without a changeset when only ignored files changed
+///
+/// Validates that `changeset check` does not require a changeset when every file
+/// changed on the branch matches the package's `.pkgignore` rules.
+#[tokio::test]
+async fn test_changeset_check_not_required_for_ignored_changes() {
+    use sublime_cli_tools::cli::commands::ChangesetCheckArgs;
+    use sublime_cli_tools::commands::changeset::execute_check;
+
+    let workspace = WorkspaceFixture::single_package()
+        .with_default_config()
+        .with_git()
+        .with_commits(1)
+        .finalize();
+
+    let repo = sublime_git_tools::Repo::open(workspace.root().to_str().unwrap())
+        .expect("Failed to open repo");
+    std::fs::write(workspace.root().join(".pkgignore"), "**/*.test.js\n")
+        .expect("Failed to write .pkgignore");
+    repo.add(".pkgignore").expect("Failed to stage .pkgignore");
+    repo.commit("chore: ignore test files").expect("Failed to commit");
+
+    repo.create_branch("feature/tests-only").expect("Failed to create branch");
+    repo.checkout("feature/tests-only").expect("Failed to checkout branch");
+    std::fs::create_dir_all(workspace.root().join("src")).expect("Failed to create src");
+    std::fs::write(workspace.root().join("src/index.test.js"), "test();\n")
+        .expect("Failed to write test file");
+    repo.add("src/index.test.js").expect("Failed to stage test file");
+    repo.commit("test: cover index").expect("Failed to commit");
+
+    let check_args = ChangesetCheckArgs { branch: None };
+    let (output, _buffer) = create_test_output();
+    let result = execute_check(&check_args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_ok(), "Check should pass for ignored-only changes: {:?}", result.err());
+
+    // A source change on the branch requires a changeset again
+    std::fs::write(workspace.root().join("src/index.js"), "run();\n")
+        .expect("Failed to write source file");
+    repo.add("src/index.js").expect("Failed to stage source file");
+    repo.commit("feat: run").expect("Failed to commit");
+
+    let (output, _buffer) = create_test_output();
+    let result = execute_check(&check_args, &output, Some(workspace.root()), None).await;
+
+    assert!(result.is_err(), "Check should fail once a package file changes");
+}
+
 /// Test: Check exit codes for Git hooks integration
 ///
 /// Validates that `changeset check` returns correct exit codes:
@@ -2172,6 +3036,10 @@ async fn test_changeset_check_exit_code_for_git_hooks() {
         message: Some("Feature for hook test".to_string()),
         packages: Some(vec!["test-package".to_string()]),
         non_interactive: true,
+        stdin: false,
+        from_file: None,
+        from_diff: false,
+        base: None,
     };
 
     let (output, _) = create_test_output();
@@ -253,7 +253,7 @@ async fn test_config_validate_valid_config() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, create_valid_config()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -275,7 +275,7 @@ async fn test_config_validate_invalid_config() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, create_invalid_config()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -293,7 +293,7 @@ async fn test_config_validate_missing_file() {
     // ARRANGE: Create workspace WITHOUT configuration file
     let workspace = WorkspaceFixture::single_package().with_git().with_commits(1).finalize();
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command (no config exists)
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -315,7 +315,7 @@ async fn test_config_validate_json_output() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, create_valid_config()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate with JSON format
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Json).await;
@@ -338,7 +338,7 @@ async fn test_config_validate_with_custom_path() {
     std::fs::write(&custom_config_path, create_valid_config())
         .expect("Failed to write custom config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate with custom path
     let result =
@@ -359,7 +359,7 @@ async fn test_config_validate_custom_path_not_found() {
     let workspace = WorkspaceFixture::single_package().with_git().with_commits(1).finalize();
 
     let non_existent_path = workspace.root().join("non-existent.config.json");
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate with non-existent path
     let result =
@@ -383,7 +383,7 @@ async fn test_config_validate_quiet_output() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, create_valid_config()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate with quiet format
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Quiet).await;
@@ -479,7 +479,7 @@ async fn test_config_validate_monorepo_unified() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, config.to_string()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -556,7 +556,7 @@ async fn test_config_validate_conflicting_environments() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, config.to_string()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -625,7 +625,7 @@ async fn test_config_validate_invalid_registry_url() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, config.to_string()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -694,7 +694,7 @@ async fn test_config_validate_invalid_snapshot_format() {
     let config_path = workspace.root().join("repo.config.json");
     std::fs::write(&config_path, config.to_string()).expect("Failed to write config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -906,7 +906,7 @@ min_severity = "info"
     let config_path = workspace.root().join("repo.config.toml");
     std::fs::write(&config_path, toml_config).expect("Failed to write TOML config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -976,7 +976,7 @@ audit:
     let config_path = workspace.root().join("repo.config.yaml");
     std::fs::write(&config_path, yaml_config).expect("Failed to write YAML config");
 
-    let args = ConfigValidateArgs {};
+    let args = ConfigValidateArgs { explain: None };
 
     // ACT: Execute config validate command
     let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
@@ -984,3 +984,76 @@ audit:
     // ASSERT: Validation should succeed with YAML format
     assert!(result.is_ok(), "Config validate should succeed with YAML format: {:?}", result.err());
 }
+
+/// Test: Config validate reports every issue
+///
+/// Verifies that validation fails when several keys are invalid and that every
+/// issue is collected, not only the first one.
+#[tokio::test]
+async fn test_config_validate_reports_every_issue() {
+    // ARRANGE: Create workspace with several invalid keys
+    let workspace = WorkspaceFixture::single_package().with_git().with_commits(1).finalize();
+
+    let config = json!({
+        "version": { "default_bump": "huge" },
+        "upgrade": { "registry": { "retry_attempts": 20 } },
+        "changelog": { "root_tag_format": "release" }
+    });
+    let config_path = workspace.root().join("repo.config.json");
+    std::fs::write(&config_path, config.to_string()).expect("Failed to write config");
+
+    let args = ConfigValidateArgs { explain: None };
+
+    // ACT: Execute config validate with JSON output
+    let result = execute_validate(&args, workspace.root(), None, OutputFormat::Json).await;
+
+    // ASSERT: Validation should fail on the rules, not while loading the file
+    let err = result.expect_err("Config validate should fail with several invalid keys");
+    assert!(
+        err.to_string().contains("Configuration validation failed"),
+        "Invalid keys should be reported as validation issues: {err}"
+    );
+
+    let loaded = sublime_pkg_tools::config::ConfigLoader::load_from_file_unvalidated(&config_path)
+        .await
+        .expect("Config should parse");
+    let issues = sublime_pkg_tools::config::validate_config_all(&loaded);
+    assert_eq!(issues.len(), 3, "Expected every issue to be reported: {issues:?}");
+}
+
+/// Test: Config validate --explain documents a key
+///
+/// Verifies that `--explain` prints documentation without needing a config file.
+#[tokio::test]
+async fn test_config_validate_explain_key() {
+    // ARRANGE: Create workspace without any config file
+    let workspace = WorkspaceFixture::single_package().with_git().with_commits(1).finalize();
+
+    let args =
+        ConfigValidateArgs { explain: Some("package_tools.version.default_bump".to_string()) };
+
+    // ACT & ASSERT: Explaining a key and a section succeeds
+    let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
+    assert!(result.is_ok(), "Explaining a documented key should succeed: {:?}", result.err());
+
+    let args = ConfigValidateArgs { explain: Some("changelog".to_string()) };
+    let result = execute_validate(&args, workspace.root(), None, OutputFormat::Json).await;
+    assert!(result.is_ok(), "Explaining a section should succeed: {:?}", result.err());
+}
+
+/// Test: Config validate --explain rejects unknown keys
+///
+/// Verifies that `--explain` fails for keys that are not documented.
+#[tokio::test]
+async fn test_config_validate_explain_unknown_key() {
+    // ARRANGE: Create workspace without any config file
+    let workspace = WorkspaceFixture::single_package().with_git().with_commits(1).finalize();
+
+    let args = ConfigValidateArgs { explain: Some("version.not_a_key".to_string()) };
+
+    // ACT: Explain an unknown key
+    let result = execute_validate(&args, workspace.root(), None, OutputFormat::Human).await;
+
+    // ASSERT: The command should fail
+    assert!(result.is_err(), "Explaining an unknown key should fail");
+}
@@ -0,0 +1,136 @@
+//! # E2E Tests for Hooks Commands
+//!
+//! **What**: End-to-end tests for the `hooks install`, `hooks status`, and
+//! `hooks uninstall` commands. Tests cover installing configured hooks, drift
+//! detection with `--check`, refusing to overwrite unmanaged hooks, and removal.
+//!
+//! **How**: Creates real temporary git workspaces with a `hooks` configuration
+//! section, executes the hook commands, and inspects the hook files on disk.
+//!
+//! **Why**: Ensures hooks declared in configuration are materialized reliably and
+//! that CI can detect when a clone's hooks drifted from the configuration.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use common::fixtures::WorkspaceFixture;
+use serde_json::json;
+use std::io::Cursor;
+use sublime_cli_tools::cli::commands::{HooksInstallArgs, HooksStatusArgs, HooksUninstallArgs};
+use sublime_cli_tools::commands::hooks::{execute_install, execute_status, execute_uninstall};
+use sublime_cli_tools::output::{Output, OutputFormat};
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Creates a test output with buffer for capturing output.
+fn create_test_output() -> (Output, Cursor<Vec<u8>>) {
+    let buffer = Cursor::new(Vec::new());
+    let output = Output::new(OutputFormat::Human, Box::new(buffer.clone()), false);
+    (output, buffer)
+}
+
+/// Creates a git workspace with a `pre-commit` hook configured.
+fn create_hooks_workspace(script: &str) -> WorkspaceFixture {
+    let config = json!({
+        "hooks": {
+            "hooks": {
+                "pre-commit": script
+            }
+        }
+    });
+
+    WorkspaceFixture::single_package()
+        .with_git()
+        .with_custom_config(&serde_json::to_string_pretty(&config).expect("Failed to serialize"))
+        .finalize()
+}
+
+// ============================================================================
+// Hooks Tests
+// ============================================================================
+
+/// Test: Hooks install writes configured hooks
+///
+/// Verifies that `hooks install` writes each configured hook into `.git/hooks`
+/// and that `hooks status --check` succeeds afterwards.
+#[tokio::test]
+async fn test_hooks_install_writes_configured_hooks() {
+    let workspace = create_hooks_workspace("workspace changeset check");
+    let (output, _buffer) = create_test_output();
+
+    let result =
+        execute_install(&HooksInstallArgs { force: false }, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Hooks install should succeed: {:?}", result.err());
+
+    let hook = std::fs::read_to_string(workspace.root().join(".git/hooks/pre-commit"))
+        .expect("Hook should be installed");
+    assert!(hook.contains("workspace changeset check"));
+
+    let result =
+        execute_status(&HooksStatusArgs { check: true }, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Hooks status --check should pass after install: {:?}", result.err());
+}
+
+/// Test: Hooks status --check fails on drift
+///
+/// Verifies that `hooks status --check` fails when a configured hook is missing.
+#[tokio::test]
+async fn test_hooks_status_check_fails_on_missing_hook() {
+    let workspace = create_hooks_workspace("workspace changeset check");
+    let (output, _buffer) = create_test_output();
+
+    let result =
+        execute_status(&HooksStatusArgs { check: true }, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Hooks status --check should fail when hooks are missing");
+
+    let result =
+        execute_status(&HooksStatusArgs { check: false }, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Hooks status without --check should succeed: {:?}", result.err());
+}
+
+/// Test: Hooks install refuses to overwrite unmanaged hooks
+///
+/// Verifies that an existing hook not written by workspace tools is only
+/// replaced when `--force` is given.
+#[tokio::test]
+async fn test_hooks_install_requires_force_for_unmanaged_hook() {
+    let workspace = create_hooks_workspace("workspace changeset check");
+    let hook_path = workspace.root().join(".git/hooks/pre-commit");
+    std::fs::create_dir_all(hook_path.parent().unwrap()).unwrap();
+    std::fs::write(&hook_path, "#!/bin/sh\nnpm test\n").unwrap();
+    let (output, _buffer) = create_test_output();
+
+    let result =
+        execute_install(&HooksInstallArgs { force: false }, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Hooks install should refuse to overwrite an unmanaged hook");
+    assert!(std::fs::read_to_string(&hook_path).unwrap().contains("npm test"));
+
+    let result =
+        execute_install(&HooksInstallArgs { force: true }, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Hooks install --force should succeed: {:?}", result.err());
+    assert!(std::fs::read_to_string(&hook_path).unwrap().contains("workspace changeset check"));
+}
+
+/// Test: Hooks uninstall removes managed hooks
+///
+/// Verifies that `hooks uninstall` removes the hooks installed by `hooks install`.
+#[tokio::test]
+async fn test_hooks_uninstall_removes_hooks() {
+    let workspace = create_hooks_workspace("workspace changeset check");
+    let (output, _buffer) = create_test_output();
+
+    execute_install(&HooksInstallArgs { force: false }, &output, workspace.root(), None)
+        .await
+        .expect("Hooks install should succeed");
+
+    let result =
+        execute_uninstall(&HooksUninstallArgs { force: false }, &output, workspace.root(), None)
+            .await;
+    assert!(result.is_ok(), "Hooks uninstall should succeed: {:?}", result.err());
+    assert!(!workspace.root().join(".git/hooks/pre-commit").exists());
+}
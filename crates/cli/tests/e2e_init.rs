@@ -39,6 +39,8 @@ async fn test_init_single_package_creates_config() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -76,6 +78,8 @@ async fn test_init_monorepo_creates_config() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -105,6 +109,8 @@ async fn test_init_unified_strategy() {
         strategy: Some("unified".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -131,6 +137,8 @@ async fn test_init_multiple_environments() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -157,6 +165,8 @@ async fn test_init_json_format() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -180,6 +190,8 @@ async fn test_init_toml_format() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("toml".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -203,6 +215,8 @@ async fn test_init_yaml_format() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("yaml".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -231,6 +245,8 @@ async fn test_init_fails_when_config_exists() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -252,6 +268,8 @@ async fn test_init_force_overwrites_config() {
         strategy: Some("unified".to_string()), // Different from default
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: true, // Force overwrite
         non_interactive: true,
     };
@@ -280,6 +298,8 @@ async fn test_init_fails_without_package_json() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -300,6 +320,8 @@ async fn test_init_custom_changeset_path() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -324,6 +346,8 @@ async fn test_init_custom_registry() {
         strategy: Some("independent".to_string()),
         registry: "https://custom-registry.example.com".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -354,6 +378,8 @@ async fn test_init_with_default_environments() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -388,6 +414,8 @@ async fn test_init_invalid_strategy_fails() {
         strategy: Some("invalid-strategy".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -414,6 +442,8 @@ async fn test_init_invalid_format_fails() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("xml".to_string()), // Invalid format
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -440,6 +470,8 @@ async fn test_init_invalid_registry_url_fails() {
         strategy: Some("independent".to_string()),
         registry: "not-a-valid-url".to_string(), // Invalid URL
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -470,6 +502,8 @@ async fn test_init_creates_gitignore_entries() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -505,6 +539,8 @@ async fn test_init_with_git_not_initialized() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: true,
     };
@@ -532,6 +568,8 @@ async fn test_init_interactive_prompts() {
         strategy: Some("independent".to_string()),
         registry: "https://registry.npmjs.org".to_string(),
         config_format: Some("json".to_string()),
+        template: None,
+        eject_template: None,
         force: false,
         non_interactive: false, // Interactive mode
     };
@@ -0,0 +1,69 @@
+//! # E2E Tests for Read-Only Mode
+//!
+//! **What**: End-to-end tests for the global `--read-only` mode, which blocks every
+//! filesystem and git write made through the workspace tools.
+//!
+//! **How**: Enables the process-wide filesystem and git guards (as `main` does for
+//! `--read-only`), runs an inspection command and a mutating command against a real
+//! temporary workspace, and checks that only the mutating command fails.
+//!
+//! **Why**: CI consumers rely on read-only mode to prove inspection commands are
+//! side-effect free. The guards are process-wide, so this file holds a single test
+//! to avoid interfering with other tests.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use common::fixtures::WorkspaceFixture;
+use serde_json::json;
+use std::io;
+use sublime_cli_tools::cli::commands::{ChangesArgs, HooksInstallArgs};
+use sublime_cli_tools::commands::changes::execute_changes;
+use sublime_cli_tools::commands::hooks::execute_install;
+use sublime_cli_tools::output::{Output, OutputFormat};
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::set_read_only_mode;
+
+/// Test: Read-only mode allows inspection and blocks writes
+///
+/// Verifies that `changes` succeeds while `hooks install` fails with an error
+/// naming the blocked path, and that nothing is written.
+#[tokio::test]
+async fn test_read_only_mode_blocks_writes_only() {
+    let config = json!({ "hooks": { "hooks": { "pre-commit": "workspace changeset check" } } });
+    let workspace = WorkspaceFixture::single_package()
+        .with_git()
+        .with_commits(1)
+        .with_custom_config(&serde_json::to_string_pretty(&config).expect("Failed to serialize"))
+        .finalize();
+    let output = Output::new(OutputFormat::Human, io::sink(), false);
+
+    set_read_only_mode(true);
+    Repo::set_read_only_mode(true);
+
+    let changes_args = ChangesArgs {
+        since: None,
+        until: None,
+        branch: None,
+        staged: false,
+        unstaged: false,
+        packages: None,
+        paths: None,
+        include_uncommitted: false,
+    };
+    let changes = execute_changes(&changes_args, &output, workspace.root(), None).await;
+    let install =
+        execute_install(&HooksInstallArgs { force: false }, &output, workspace.root(), None).await;
+
+    set_read_only_mode(false);
+    Repo::set_read_only_mode(false);
+
+    assert!(changes.is_ok(), "Changes should succeed in read-only mode: {:?}", changes.err());
+
+    let error = install.expect_err("Hooks install should fail in read-only mode");
+    assert!(error.to_string().contains("read-only"), "Unexpected error: {error}");
+    assert!(error.to_string().contains(".git/hooks"), "Error should name the path: {error}");
+    assert!(!workspace.root().join(".git/hooks/pre-commit").exists());
+}
@@ -0,0 +1,108 @@
+//! # E2E Tests for Tag Command
+//!
+//! **What**: End-to-end tests for the `tag` command that creates the release tags missing
+//! for already-released versions.
+//!
+//! **How**: Creates real temporary monorepos with an untagged history and executes the tag
+//! command in dry-run and forced modes, validating the JSON plan and the tags created.
+//!
+//! **Why**: Ensures repositories adopting the tool get the per-package tags that baseline
+//! detection relies on, without touching tags that already exist.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use common::fixtures::WorkspaceFixture;
+use common::helpers::create_shared_json_output;
+use sublime_cli_tools::cli::commands::TagArgs;
+use sublime_cli_tools::commands::tag::execute_tag;
+
+/// Lists the tags of a repository.
+fn list_tags(root: &std::path::Path) -> Vec<String> {
+    let output = std::process::Command::new("git")
+        .args(["tag", "--list"])
+        .current_dir(root)
+        .output()
+        .expect("Failed to list tags");
+    String::from_utf8_lossy(&output.stdout).lines().map(str::to_string).collect()
+}
+
+/// Test: Tag command lists the missing tags without creating them on dry run
+#[tokio::test]
+async fn test_tag_dry_run_lists_missing_tags() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_git()
+        .with_default_config()
+        .finalize()
+        .commit_all("chore: initial commit");
+    let args = TagArgs { packages: None, dry_run: true, push: false, force: false };
+    let (output, buffer) = create_shared_json_output();
+
+    let result = execute_tag(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Tag should succeed: {:?}", result.err());
+
+    let output_bytes = buffer.lock().unwrap().clone();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output_bytes).expect("Output should be valid JSON");
+    let data = &json["data"];
+
+    assert_eq!(data["dryRun"], true);
+    let mut tags: Vec<&str> = data["missing"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| tag["tagName"].as_str().unwrap())
+        .collect();
+    tags.sort_unstable();
+    assert_eq!(tags, vec!["@test/pkg-a@1.0.0", "@test/pkg-b@1.0.0"]);
+    assert!(list_tags(workspace.root()).is_empty(), "Dry run must not create tags");
+}
+
+/// Test: Tag command creates the missing tags of the selected packages
+#[tokio::test]
+async fn test_tag_creates_selected_package_tags() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_git()
+        .with_default_config()
+        .finalize()
+        .commit_all("chore: initial commit");
+    let args = TagArgs {
+        packages: Some(vec!["@test/pkg-a".to_string()]),
+        dry_run: false,
+        push: false,
+        force: true,
+    };
+    let (output, buffer) = create_shared_json_output();
+
+    let result = execute_tag(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Tag should succeed: {:?}", result.err());
+
+    let output_bytes = buffer.lock().unwrap().clone();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output_bytes).expect("Output should be valid JSON");
+    assert_eq!(json["data"]["created"], serde_json::json!(["@test/pkg-a@1.0.0"]));
+    assert_eq!(list_tags(workspace.root()), vec!["@test/pkg-a@1.0.0"]);
+}
+
+/// Test: Tag command rejects packages outside the workspace
+#[tokio::test]
+async fn test_tag_unknown_package_fails() {
+    let workspace = WorkspaceFixture::monorepo_independent()
+        .with_git()
+        .with_default_config()
+        .finalize()
+        .commit_all("chore: initial commit");
+    let args = TagArgs {
+        packages: Some(vec!["@test/missing".to_string()]),
+        dry_run: true,
+        push: false,
+        force: false,
+    };
+    let (output, _buffer) = create_shared_json_output();
+
+    let result = execute_tag(&args, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Unknown package should fail");
+}
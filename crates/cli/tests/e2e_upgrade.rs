@@ -57,6 +57,9 @@ async fn test_upgrade_check_detects_outdated() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -110,6 +113,9 @@ async fn test_upgrade_check_respects_npmrc() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -164,6 +170,9 @@ async fn test_upgrade_check_filters_by_type() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -210,6 +219,9 @@ async fn test_upgrade_check_json_output() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, buffer) = create_json_output();
@@ -259,6 +271,9 @@ async fn test_upgrade_apply_updates_package_json() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true, // Skip confirmation
     };
 
@@ -314,6 +329,9 @@ async fn test_upgrade_apply_creates_backup() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false, // Enable backup
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -374,6 +392,9 @@ async fn test_upgrade_apply_updates_lock_file() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -425,6 +446,9 @@ async fn test_upgrade_apply_auto_changeset() {
         auto_changeset: true, // Enable auto-changeset
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -488,6 +512,9 @@ async fn test_upgrade_apply_dry_run() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -549,6 +576,9 @@ async fn test_upgrade_apply_patch_only() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -606,6 +636,9 @@ async fn test_upgrade_backups_list() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false, // Enable backup
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -700,6 +733,9 @@ async fn test_upgrade_backups_restore() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -789,6 +825,9 @@ async fn test_upgrade_rollback_restores_backup() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -871,6 +910,9 @@ async fn test_upgrade_rollback_lists_available() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: false, // Enable backup
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -951,6 +993,9 @@ async fn test_upgrade_check_no_major() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -980,6 +1025,9 @@ async fn test_upgrade_check_no_minor() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1010,6 +1058,9 @@ async fn test_upgrade_check_no_patch() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1039,6 +1090,9 @@ async fn test_upgrade_check_with_peer_dependencies() {
         peer: true,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1081,6 +1135,9 @@ async fn test_upgrade_check_without_dev_dependencies() {
         peer: false,
         packages: None,
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1123,6 +1180,9 @@ async fn test_upgrade_check_specific_packages() {
         peer: false,
         packages: Some(vec!["@test/pkg-a".to_string()]),
         registry: None,
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1166,6 +1226,9 @@ async fn test_upgrade_check_custom_registry() {
         peer: false,
         packages: None,
         registry: Some("https://custom-registry.example.com".to_string()),
+        stats: false,
+        toolchain: false,
+        resume: false,
     };
 
     let (output, _buffer) = create_json_output();
@@ -1198,6 +1261,9 @@ async fn test_upgrade_apply_minor_and_patch_only() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: true,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -1226,6 +1292,9 @@ async fn test_upgrade_apply_no_backup() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: true,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -1259,6 +1328,9 @@ async fn test_upgrade_apply_force() {
         auto_changeset: false,
         changeset_bump: "patch".to_string(),
         no_backup: true,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
@@ -1291,6 +1363,9 @@ async fn test_upgrade_apply_custom_changeset_bump() {
         auto_changeset: true,
         changeset_bump: "major".to_string(),
         no_backup: true,
+        no_verify: false,
+        pr_body: false,
+        toolchain: false,
         force: true,
     };
 
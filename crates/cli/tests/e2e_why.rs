@@ -0,0 +1,72 @@
+//! # E2E Tests for Why Command
+//!
+//! **What**: End-to-end tests for the `why` command that explains why a package is in
+//! the workspace dependency tree.
+//!
+//! **How**: Creates real temporary monorepos and executes the why command for workspace
+//! packages and unknown packages, validating the JSON explanation and error handling.
+//!
+//! **Why**: Ensures the command reports the declaring packages and dependency chains that
+//! users rely on when deciding whether a dependency can be removed.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use common::fixtures::WorkspaceFixture;
+use common::helpers::create_shared_json_output;
+use sublime_cli_tools::cli::commands::WhyArgs;
+use sublime_cli_tools::commands::why::execute_why;
+
+/// Test: Why command explains an internal dependency
+///
+/// Verifies that the JSON output lists the declaring package with its spec and the
+/// chain leading to the package.
+#[tokio::test]
+async fn test_why_internal_dependency_json() {
+    let workspace =
+        WorkspaceFixture::monorepo_with_internal_deps().with_default_config().finalize();
+    let args = WhyArgs { package: "@test/pkg-a".to_string(), from: None };
+    let (output, buffer) = create_shared_json_output();
+
+    let result = execute_why(&args, &output, workspace.root(), None).await;
+    assert!(result.is_ok(), "Why should succeed: {:?}", result.err());
+
+    let output_bytes = buffer.lock().unwrap().clone();
+    let json: serde_json::Value =
+        serde_json::from_slice(&output_bytes).expect("Output should be valid JSON");
+    let data = &json["data"];
+
+    assert_eq!(data["package"], "@test/pkg-a");
+    assert_eq!(data["internal"], true);
+    assert_eq!(data["requiredBy"][0]["dependent"], "@test/pkg-b");
+    assert_eq!(data["requiredBy"][0]["versionSpec"], "^1.0.0");
+    assert_eq!(data["chains"][0], serde_json::json!(["@test/pkg-b", "@test/pkg-a"]));
+}
+
+/// Test: Why command rejects packages nothing depends on
+#[tokio::test]
+async fn test_why_unknown_package_fails() {
+    let workspace =
+        WorkspaceFixture::monorepo_with_internal_deps().with_default_config().finalize();
+    let args = WhyArgs { package: "left-pad".to_string(), from: None };
+    let (output, _buffer) = create_shared_json_output();
+
+    let result = execute_why(&args, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Unknown package should fail");
+}
+
+/// Test: Why command rejects a --from package outside the workspace
+#[tokio::test]
+async fn test_why_from_unknown_package_fails() {
+    let workspace =
+        WorkspaceFixture::monorepo_with_internal_deps().with_default_config().finalize();
+    let args =
+        WhyArgs { package: "@test/pkg-a".to_string(), from: Some("@test/missing".to_string()) };
+    let (output, _buffer) = create_shared_json_output();
+
+    let result = execute_why(&args, &output, workspace.root(), None).await;
+    assert!(result.is_err(), "Unknown --from package should fail");
+}
@@ -120,7 +120,8 @@ mod types;
 mod tests;
 
 pub use types::{
-    GitChangedFile, GitDiffStats, GitFileStatus, Repo, RepoCommit, RepoError, RepoTags,
+    CommitHistory, GitChangedFile, GitDiffStats, GitFileKind, GitFileStatus, HistoryQuery, Repo,
+    RepoCommit, RepoError, RepoTags,
 };
 
 /// Result type alias for git operations.
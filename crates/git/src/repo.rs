@@ -55,16 +55,24 @@
 //! ```
 
 use git2::{
-    BranchType, Commit, Cred, CredentialType, Delta, DiffOptions, Direction, Error as Git2Error,
-    FetchOptions, FetchPrune, IndexAddOption, MergeOptions, Oid, PushOptions, RemoteCallbacks,
-    Repository, RepositoryInitOptions, StatusOptions, TreeWalkMode, TreeWalkResult,
+    AttrCheckFlags, BranchType, Commit, Cred, CredentialType, Delta, DiffDelta, DiffOptions,
+    Direction, Error as Git2Error, ErrorCode, FetchOptions, FetchPrune, FileMode, IndexAddOption,
+    MergeOptions, Oid, PushOptions, RemoteCallbacks, Repository, RepositoryInitOptions,
+    StatusOptions, TreeWalkMode, TreeWalkResult, WorktreePruneOptions,
     build::{CheckoutBuilder, RepoBuilder},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::canonicalize;
 use std::path::{Path, PathBuf};
-
-use crate::{GitChangedFile, GitFileStatus, Repo, RepoCommit, RepoError, RepoTags};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+use crate::types::{CachedWalk, CommitCache, CommitWalk};
+use crate::{
+    CommitHistory, GitChangedFile, GitFileKind, GitFileStatus, HistoryQuery, Repo, RepoCommit,
+    RepoError, RepoTags,
+};
 
 /// Canonicalizes a path string to its absolute form
 ///
@@ -98,6 +106,64 @@ fn canonicalize_path(path: &str) -> Result<String, RepoError> {
     Ok(path.display().to_string())
 }
 
+/// First line of every Git LFS pointer file
+const LFS_POINTER_PREFIX: &[u8] = b"version https://git-lfs.github.com/spec/v1";
+
+/// Process-wide flag that blocks every mutating repository operation
+static READ_ONLY_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Process-wide flag that blocks every operation reaching a remote
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Process-wide deadline for operations reaching a remote
+static DEADLINE: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Fails with `RepoError::Offline` if offline mode is enabled, or with
+/// `RepoError::DeadlineExceeded` if the process deadline has passed
+fn ensure_online(operation: &str) -> Result<(), RepoError> {
+    if Repo::is_offline_mode() {
+        return Err(RepoError::Offline { operation: operation.to_string() });
+    }
+    if deadline_passed() {
+        return Err(RepoError::DeadlineExceeded { operation: operation.to_string() });
+    }
+    Ok(())
+}
+
+/// Returns whether the process deadline is set and has passed
+fn deadline_passed() -> bool {
+    Repo::deadline().is_some_and(|deadline| Instant::now() >= deadline)
+}
+
+/// Reports a failed remote operation as `RepoError::DeadlineExceeded` when the process
+/// deadline has passed, since the transfer callbacks abort it at that point
+fn remote_failure(operation: &str, error: RepoError) -> RepoError {
+    if deadline_passed() {
+        RepoError::DeadlineExceeded { operation: operation.to_string() }
+    } else {
+        error
+    }
+}
+
+/// Makes libgit2 abort a transfer once the process deadline has passed
+///
+/// The progress callbacks run while data flows, so a transfer stops at the next packet.
+/// A connection that receives nothing at all is only bounded by libgit2's own timeouts.
+fn abort_at_deadline(callbacks: &mut RemoteCallbacks<'_>) {
+    callbacks.sideband_progress(|_| !deadline_passed());
+    callbacks.push_negotiation(|_| {
+        if deadline_passed() { Err(Git2Error::from_str("deadline exceeded")) } else { Ok(()) }
+    });
+}
+
+/// Fails with `RepoError::ReadOnly` for a path-based operation if read-only mode is enabled
+fn ensure_writable_path(operation: &str, path: &str) -> Result<(), RepoError> {
+    if Repo::is_read_only_mode() {
+        return Err(RepoError::ReadOnly { operation: operation.to_string(), path: path.into() });
+    }
+    Ok(())
+}
+
 impl From<Git2Error> for RepoError {
     fn from(err: Git2Error) -> Self {
         // You might want to match on error code to create specific errors
@@ -248,6 +314,17 @@ impl Clone for RepoError {
                 let git_err = Git2Error::from_str(&format!("{self}"));
                 RepoError::MergeConflictError(git_err)
             }
+            RepoError::WorktreeError(_) => {
+                let git_err = Git2Error::from_str(&format!("{self}"));
+                RepoError::WorktreeError(git_err)
+            }
+            RepoError::ReadOnly { operation, path } => {
+                RepoError::ReadOnly { operation: operation.clone(), path: path.clone() }
+            }
+            RepoError::Offline { operation } => RepoError::Offline { operation: operation.clone() },
+            RepoError::DeadlineExceeded { operation } => {
+                RepoError::DeadlineExceeded { operation: operation.clone() }
+            }
         }
     }
 }
@@ -289,6 +366,10 @@ impl AsRef<str> for RepoError {
             RepoError::MergeError(_) => "MergeError",
             RepoError::CheckoutError(_) => "CheckoutError",
             RepoError::MergeConflictError(_) => "MergeConflictError",
+            RepoError::WorktreeError(_) => "WorktreeError",
+            RepoError::ReadOnly { .. } => "ReadOnly",
+            RepoError::Offline { .. } => "Offline",
+            RepoError::DeadlineExceeded { .. } => "DeadlineExceeded",
         }
     }
 }
@@ -304,6 +385,94 @@ impl std::fmt::Debug for Repo {
 }
 
 impl Repo {
+    /// Enables or disables the process-wide read-only mode
+    ///
+    /// While enabled, every operation that would modify a repository, its configuration,
+    /// or a remote (creating, cloning, committing, tagging, checking out, fetching, pushing, ...)
+    /// fails with `RepoError::ReadOnly`. Reading history, status, and diffs is unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_git_tools::Repo;
+    ///
+    /// Repo::set_read_only_mode(true);
+    /// assert!(Repo::is_read_only_mode());
+    /// Repo::set_read_only_mode(false);
+    /// ```
+    pub fn set_read_only_mode(enabled: bool) {
+        READ_ONLY_MODE.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Returns whether the process-wide read-only mode is enabled
+    #[must_use]
+    pub fn is_read_only_mode() -> bool {
+        READ_ONLY_MODE.load(Ordering::SeqCst)
+    }
+
+    /// Enables or disables the process-wide offline mode
+    ///
+    /// While enabled, every operation that reaches a remote (cloning, fetching, pulling,
+    /// pushing, listing remote tags) fails with `RepoError::Offline`. Local operations are
+    /// unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_git_tools::Repo;
+    ///
+    /// Repo::set_offline_mode(true);
+    /// assert!(Repo::is_offline_mode());
+    /// Repo::set_offline_mode(false);
+    /// ```
+    pub fn set_offline_mode(enabled: bool) {
+        OFFLINE_MODE.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Returns whether the process-wide offline mode is enabled
+    #[must_use]
+    pub fn is_offline_mode() -> bool {
+        OFFLINE_MODE.load(Ordering::SeqCst)
+    }
+
+    /// Sets or clears the process-wide deadline for remote operations
+    ///
+    /// Once the deadline has passed, cloning, fetching, pulling, pushing, and listing remote
+    /// tags fail with `RepoError::DeadlineExceeded` instead of starting, and transfers in
+    /// progress are aborted. Local operations are unaffected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// Repo::set_deadline(Some(deadline));
+    /// assert_eq!(Repo::deadline(), Some(deadline));
+    /// Repo::set_deadline(None);
+    /// ```
+    pub fn set_deadline(deadline: Option<Instant>) {
+        *DEADLINE.lock().unwrap_or_else(PoisonError::into_inner) = deadline;
+    }
+
+    /// Returns the process-wide deadline for remote operations, if one is set
+    #[must_use]
+    pub fn deadline() -> Option<Instant> {
+        *DEADLINE.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Fails with `RepoError::ReadOnly` if read-only mode is enabled
+    fn ensure_writable(&self, operation: &str) -> Result<(), RepoError> {
+        if Self::is_read_only_mode() {
+            return Err(RepoError::ReadOnly {
+                operation: operation.to_string(),
+                path: self.local_path.clone(),
+            });
+        }
+        Ok(())
+    }
+
     /// Creates a new Git repository at the specified path
     ///
     /// This initializes a new Git repository with an initial commit on the 'main' branch.
@@ -333,6 +502,8 @@ impl Repo {
     /// println!("Repository created at: {}", repo.get_repo_path().display());
     /// ```
     pub fn create(path: &str) -> Result<Self, RepoError> {
+        ensure_writable_path("create repository", path)?;
+
         let location = canonicalize_path(path)?;
         let location_buf = PathBuf::from(location);
 
@@ -344,7 +515,7 @@ impl Repo {
         .map_err(RepoError::CreateRepoFailure)?;
 
         // Just return the repo without making any commits
-        let result = Self { repo, local_path: location_buf };
+        let result = Self { repo, local_path: location_buf, commit_cache: Mutex::default() };
 
         // Now make the initial commit using our new instance
         result.make_initial_commit()?;
@@ -384,7 +555,7 @@ impl Repo {
         let local_path = canonicalize_path(path)?;
         let repo = Repository::open(path).map_err(RepoError::OpenRepoFailure)?;
 
-        Ok(Self { repo, local_path: PathBuf::from(local_path) })
+        Ok(Self { repo, local_path: PathBuf::from(local_path), commit_cache: Mutex::default() })
     }
 
     /// Clones a Git repository from a URL to a local path
@@ -417,10 +588,23 @@ impl Repo {
     ///     .expect("Failed to clone repository");
     /// ```
     pub fn clone(url: &str, path: &str) -> Result<Self, RepoError> {
+        ensure_writable_path("clone", path)?;
+        ensure_online("clone")?;
+
         let local_path = canonicalize_path(path)?;
-        let repo = Repository::clone(url, path).map_err(RepoError::CloneRepoFailure)?;
 
-        Ok(Self { repo, local_path: PathBuf::from(local_path) })
+        let mut callbacks = RemoteCallbacks::new();
+        abort_at_deadline(&mut callbacks);
+        callbacks.transfer_progress(|_| !deadline_passed());
+        let mut fetch_opts = FetchOptions::new();
+        fetch_opts.remote_callbacks(callbacks);
+
+        let repo = RepoBuilder::new()
+            .fetch_options(fetch_opts)
+            .clone(url, Path::new(path))
+            .map_err(|e| remote_failure("clone", RepoError::CloneRepoFailure(e)))?;
+
+        Ok(Self { repo, local_path: PathBuf::from(local_path), commit_cache: Mutex::default() })
     }
 
     /// Clones a Git repository from a URL to a local path with additional options.
@@ -492,10 +676,17 @@ impl Repo {
         path: &str,
         depth: Option<i32>,
     ) -> Result<Self, RepoError> {
+        ensure_writable_path("clone", path)?;
+        ensure_online("clone")?;
+
         let local_path = canonicalize_path(path)?;
 
         // Build fetch options with depth if specified
+        let mut callbacks = RemoteCallbacks::new();
+        abort_at_deadline(&mut callbacks);
+        callbacks.transfer_progress(|_| !deadline_passed());
         let mut fetch_opts = FetchOptions::new();
+        fetch_opts.remote_callbacks(callbacks);
         if let Some(depth_value) = depth {
             fetch_opts.depth(depth_value);
         }
@@ -505,9 +696,11 @@ impl Repo {
         builder.fetch_options(fetch_opts);
 
         // Perform the clone
-        let repo = builder.clone(url, Path::new(path)).map_err(RepoError::CloneRepoFailure)?;
+        let repo = builder
+            .clone(url, Path::new(path))
+            .map_err(|e| remote_failure("clone", RepoError::CloneRepoFailure(e)))?;
 
-        Ok(Self { repo, local_path: PathBuf::from(local_path) })
+        Ok(Self { repo, local_path: PathBuf::from(local_path), commit_cache: Mutex::default() })
     }
 
     /// Clones a Git repository with progress tracking.
@@ -549,11 +742,15 @@ impl Repo {
     where
         F: FnMut(usize, usize) + 'static,
     {
+        ensure_writable_path("clone", path)?;
+        ensure_online("clone")?;
+
         // Setup callbacks for progress tracking
         let mut callbacks = RemoteCallbacks::new();
+        abort_at_deadline(&mut callbacks);
         callbacks.transfer_progress(move |stats| {
             progress_cb(stats.received_objects(), stats.total_objects());
-            true
+            !deadline_passed()
         });
 
         // Build fetch options with callbacks and depth
@@ -568,12 +765,14 @@ impl Repo {
         builder.fetch_options(fetch_opts);
 
         // Perform the clone
-        let repo = builder.clone(url, Path::new(path)).map_err(RepoError::CloneRepoFailure)?;
+        let repo = builder
+            .clone(url, Path::new(path))
+            .map_err(|e| remote_failure("clone", RepoError::CloneRepoFailure(e)))?;
 
         // Canonicalize the path AFTER cloning (when the directory exists)
         let local_path = canonicalize_path(path)?;
 
-        Ok(Self { repo, local_path: PathBuf::from(local_path) })
+        Ok(Self { repo, local_path: PathBuf::from(local_path), commit_cache: Mutex::default() })
     }
 
     /// Gets the local path of the repository
@@ -595,6 +794,29 @@ impl Repo {
         self.local_path.as_path()
     }
 
+    /// Gets the path of the repository's common git directory
+    ///
+    /// For regular repositories this is the `.git` directory. For linked worktrees it is the
+    /// main repository's git directory, which holds the hooks and configuration shared by
+    /// every worktree.
+    ///
+    /// # Returns
+    ///
+    /// * `&Path` - The path to the common git directory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// println!("Git directory: {}", repo.get_common_git_dir().display());
+    /// ```
+    #[must_use]
+    pub fn get_common_git_dir(&self) -> &Path {
+        self.repo.commondir()
+    }
+
     /// Configures the repository with user information and core settings
     ///
     /// # Arguments
@@ -623,6 +845,8 @@ impl Repo {
     /// repo.config("Jane Doe", "jane@example.com").expect("Failed to configure repository");
     /// ```
     pub fn config(&self, username: &str, email: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("configure user")?;
+
         let mut config = self.repo.config().map_err(RepoError::ConfigError)?;
         config.set_str("user.name", username)?;
         config.set_str("user.email", email)?;
@@ -660,6 +884,8 @@ impl Repo {
     /// repo.create_branch("feature/new-feature").expect("Failed to create branch");
     /// ```
     pub fn create_branch(&self, branch_name: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("create branch")?;
+
         let head = self.repo.head().map_err(RepoError::HeadError)?;
         let commit = head.peel_to_commit().map_err(RepoError::PeelError)?;
 
@@ -880,6 +1106,7 @@ impl Repo {
                     status,
                     staged: false,
                     workdir: false,
+                    kind: self.delta_kind(&delta),
                 });
 
                 true // Continue processing
@@ -984,6 +1211,7 @@ impl Repo {
                     status,
                     staged: false,
                     workdir: false,
+                    kind: self.delta_kind(&delta),
                 });
 
                 true // Continue processing
@@ -997,6 +1225,146 @@ impl Repo {
         Ok(changed_files)
     }
 
+    /// Lists every file tracked in the tree of a reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - The reference (branch, tag, or commit SHA) whose tree to list
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<String>, RepoError>` - File paths relative to the repository root, using `/`
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reference cannot be resolved to a commit
+    /// - The commit tree cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// let files = repo.get_files_at_ref("v1.0.0").expect("Failed to list files");
+    /// let manifests: Vec<_> = files.iter().filter(|f| f.ends_with("package.json")).collect();
+    /// ```
+    pub fn get_files_at_ref(&self, git_ref: &str) -> Result<Vec<String>, RepoError> {
+        let tree = self
+            .repo
+            .revparse_single(git_ref)
+            .map_err(RepoError::ReferenceError)?
+            .peel_to_commit()
+            .map_err(RepoError::CommitError)?
+            .tree()
+            .map_err(RepoError::TreeError)?;
+
+        let mut files = Vec::new();
+        tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+            if entry.kind() == Some(git2::ObjectType::Blob)
+                && let Some(name) = entry.name()
+            {
+                files.push(format!("{dir}{name}"));
+            }
+            git2::TreeWalkResult::Ok
+        })
+        .map_err(RepoError::TreeError)?;
+
+        Ok(files)
+    }
+
+    /// Resolves a reference to the SHA of the commit it points to.
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - The reference (branch, tag, or commit SHA, abbreviated or not) to resolve
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, RepoError>` - The full SHA of the commit
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reference cannot be resolved
+    /// - The reference does not point to a commit
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// let sha = repo.get_sha_at_ref("v1.0.0").expect("Failed to resolve tag");
+    /// println!("v1.0.0 is {sha}");
+    /// ```
+    pub fn get_sha_at_ref(&self, git_ref: &str) -> Result<String, RepoError> {
+        let commit = self
+            .repo
+            .revparse_single(git_ref)
+            .map_err(RepoError::ReferenceError)?
+            .peel_to_commit()
+            .map_err(RepoError::CommitError)?;
+
+        Ok(commit.id().to_string())
+    }
+
+    /// Reads the content of a file as it was at a reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `git_ref` - The reference (branch, tag, or commit SHA) to read from
+    /// * `path` - The file path relative to the repository root, using `/`
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<String>, RepoError>` - The file content, or `None` if the file does not
+    ///   exist at that reference. Invalid UTF-8 is replaced with `U+FFFD`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The reference cannot be resolved to a commit
+    /// - The commit tree or the file blob cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// if let Some(content) = repo.get_file_content_at_ref("main", "package.json")
+    ///     .expect("Failed to read file")
+    /// {
+    ///     println!("{content}");
+    /// }
+    /// ```
+    pub fn get_file_content_at_ref(
+        &self,
+        git_ref: &str,
+        path: &str,
+    ) -> Result<Option<String>, RepoError> {
+        let tree = self
+            .repo
+            .revparse_single(git_ref)
+            .map_err(RepoError::ReferenceError)?
+            .peel_to_commit()
+            .map_err(RepoError::CommitError)?
+            .tree()
+            .map_err(RepoError::TreeError)?;
+
+        let Ok(entry) = tree.get_path(Path::new(path)) else {
+            return Ok(None);
+        };
+        let object = entry.to_object(&self.repo).map_err(RepoError::TreeError)?;
+        let Some(blob) = object.as_blob() else {
+            return Ok(None);
+        };
+
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+
     /// Lists all configuration entries for the repository
     ///
     /// # Returns
@@ -1041,24 +1409,20 @@ impl Repo {
         Ok(config_map)
     }
 
-    /// Checks out a local branch
+    /// Reads a single configuration value from the repository configuration
     ///
     /// # Arguments
     ///
-    /// * `branch_name` - The name of the branch to checkout
+    /// * `key` - The configuration key (e.g., "core.hooksPath")
     ///
     /// # Returns
     ///
-    /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
+    /// * `Result<Option<String>, RepoError>` - The value, `None` if the key is not set, or an error
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The specified branch does not exist
-    /// - The branch reference is invalid or corrupted
-    /// - The HEAD reference cannot be updated
-    /// - There are uncommitted changes that would be lost
-    /// - File system permissions prevent checkout
+    /// - The repository configuration cannot be accessed
     ///
     /// # Examples
     ///
@@ -1066,40 +1430,33 @@ impl Repo {
     /// use git::repo::Repo;
     ///
     /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
-    /// repo.checkout("feature-branch").expect("Failed to checkout branch");
+    /// let hooks_path = repo.get_config_value("core.hooksPath").expect("Failed to read config");
     /// ```
-    pub fn checkout(&self, branch_name: &str) -> Result<&Self, RepoError> {
-        let branch = self
-            .repo
-            .find_branch(branch_name, BranchType::Local)
-            .map_err(RepoError::CheckoutBranchError)?;
-
-        // Get the reference name from the branch
-        let branch_ref = branch.get().name().ok_or_else(|| {
-            RepoError::BranchNameError(Git2Error::from_str(
-                format!("Invalid branch reference: {branch_name}").as_str(),
-            ))
-        })?;
-
-        // Set head to the reference name
-        self.repo.set_head(branch_ref).map_err(RepoError::CheckoutBranchError)?;
-
-        Ok(self)
+    pub fn get_config_value(&self, key: &str) -> Result<Option<String>, RepoError> {
+        let config = self.repo.config().map_err(RepoError::ConfigError)?;
+        match config.get_string(key) {
+            Ok(value) => Ok(Some(value)),
+            Err(error) if error.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(error) => Err(RepoError::ConfigError(error)),
+        }
     }
 
-    /// Gets the name of the currently checked out branch
+    /// Sets a configuration value in the repository configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key (e.g., "core.hooksPath")
+    /// * `value` - The value to store
     ///
     /// # Returns
     ///
-    /// * `Result<String, RepoError>` - The current branch name, or an error
+    /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The HEAD reference cannot be accessed
-    /// - The HEAD reference is invalid or corrupted
-    /// - The repository is in a detached HEAD state
-    /// - The branch name contains invalid characters
+    /// - The repository configuration cannot be accessed
+    /// - The configuration setting cannot be written
     ///
     /// # Examples
     ///
@@ -1107,37 +1464,33 @@ impl Repo {
     /// use git::repo::Repo;
     ///
     /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
-    /// let branch = repo.get_current_branch().expect("Failed to get current branch");
-    /// println!("Current branch: {}", branch);
+    /// repo.set_config_value("core.hooksPath", ".husky").expect("Failed to write config");
     /// ```
-    pub fn get_current_branch(&self) -> Result<String, RepoError> {
-        let head = self.repo.head().map_err(RepoError::HeadError)?;
-        let branch = head.shorthand().ok_or_else(|| {
-            RepoError::BranchNameError(Git2Error::from_str("Invalid branch reference"))
-        })?;
+    pub fn set_config_value(&self, key: &str, value: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("set config")?;
 
-        Ok(branch.to_string())
+        let mut config = self.repo.config().map_err(RepoError::ConfigError)?;
+        config.set_str(key, value).map_err(RepoError::ConfigError)?;
+        Ok(self)
     }
 
-    /// Creates a new tag at the current HEAD
+    /// Removes a configuration value from the repository configuration
+    ///
+    /// Removing a key that is not set is not an error.
     ///
     /// # Arguments
     ///
-    /// * `tag` - The name for the new tag
-    /// * `message` - Optional message for the tag
+    /// * `key` - The configuration key to remove
     ///
     /// # Returns
     ///
-    /// * `Result<(), RepoError>` - Success or an error
+    /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - A tag with the same name already exists
-    /// - The repository signature cannot be created
-    /// - The HEAD reference cannot be accessed
-    /// - The target object for the tag cannot be found
-    /// - Insufficient permissions to create the tag
+    /// - The repository configuration cannot be accessed
+    /// - The configuration setting cannot be removed
     ///
     /// # Examples
     ///
@@ -1145,18 +1498,139 @@ impl Repo {
     /// use git::repo::Repo;
     ///
     /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
-    /// repo.create_tag("v1.0.0", Some("Version 1.0.0 release".to_string()))
-    ///     .expect("Failed to create tag");
+    /// repo.unset_config_value("core.hooksPath").expect("Failed to write config");
     /// ```
-    pub fn create_tag(&self, tag: &str, message: Option<String>) -> Result<&Self, RepoError> {
-        let signature = self.repo.signature().map_err(RepoError::SignatureError)?;
-        let tag_message = match message {
-            Some(msg) => msg,
-            None => format!("chore: tag creation: {tag}"),
-        };
-
-        // Get the HEAD reference
-        let head = self.repo.head().map_err(RepoError::HeadError)?;
+    pub fn unset_config_value(&self, key: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("unset config")?;
+
+        let mut config = self.repo.config().map_err(RepoError::ConfigError)?;
+        match config.remove(key) {
+            Ok(()) => Ok(self),
+            Err(error) if error.code() == git2::ErrorCode::NotFound => Ok(self),
+            Err(error) => Err(RepoError::ConfigError(error)),
+        }
+    }
+
+    /// Checks out a local branch
+    ///
+    /// # Arguments
+    ///
+    /// * `branch_name` - The name of the branch to checkout
+    ///
+    /// # Returns
+    ///
+    /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The specified branch does not exist
+    /// - The branch reference is invalid or corrupted
+    /// - The HEAD reference cannot be updated
+    /// - There are uncommitted changes that would be lost
+    /// - File system permissions prevent checkout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// repo.checkout("feature-branch").expect("Failed to checkout branch");
+    /// ```
+    pub fn checkout(&self, branch_name: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("checkout")?;
+
+        let branch = self
+            .repo
+            .find_branch(branch_name, BranchType::Local)
+            .map_err(RepoError::CheckoutBranchError)?;
+
+        // Get the reference name from the branch
+        let branch_ref = branch.get().name().ok_or_else(|| {
+            RepoError::BranchNameError(Git2Error::from_str(
+                format!("Invalid branch reference: {branch_name}").as_str(),
+            ))
+        })?;
+
+        // Set head to the reference name
+        self.repo.set_head(branch_ref).map_err(RepoError::CheckoutBranchError)?;
+
+        Ok(self)
+    }
+
+    /// Gets the name of the currently checked out branch
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, RepoError>` - The current branch name, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The HEAD reference cannot be accessed
+    /// - The HEAD reference is invalid or corrupted
+    /// - The repository is in a detached HEAD state
+    /// - The branch name contains invalid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// let branch = repo.get_current_branch().expect("Failed to get current branch");
+    /// println!("Current branch: {}", branch);
+    /// ```
+    pub fn get_current_branch(&self) -> Result<String, RepoError> {
+        let head = self.repo.head().map_err(RepoError::HeadError)?;
+        let branch = head.shorthand().ok_or_else(|| {
+            RepoError::BranchNameError(Git2Error::from_str("Invalid branch reference"))
+        })?;
+
+        Ok(branch.to_string())
+    }
+
+    /// Creates a new tag at the current HEAD
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The name for the new tag
+    /// * `message` - Optional message for the tag
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RepoError>` - Success or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A tag with the same name already exists
+    /// - The repository signature cannot be created
+    /// - The HEAD reference cannot be accessed
+    /// - The target object for the tag cannot be found
+    /// - Insufficient permissions to create the tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// repo.create_tag("v1.0.0", Some("Version 1.0.0 release".to_string()))
+    ///     .expect("Failed to create tag");
+    /// ```
+    pub fn create_tag(&self, tag: &str, message: Option<String>) -> Result<&Self, RepoError> {
+        self.ensure_writable("create tag")?;
+
+        let signature = self.repo.signature().map_err(RepoError::SignatureError)?;
+        let tag_message = match message {
+            Some(msg) => msg,
+            None => format!("chore: tag creation: {tag}"),
+        };
+
+        // Get the HEAD reference
+        let head = self.repo.head().map_err(RepoError::HeadError)?;
 
         // Get the target OID (object ID) from the reference
         let target_oid = head
@@ -1174,6 +1648,62 @@ impl Repo {
         Ok(self)
     }
 
+    /// Creates a new annotated tag pointing at a given commit
+    ///
+    /// Unlike [`Repo::create_tag`], which tags `HEAD`, this tags any commit reachable by
+    /// reference, which is how releases made before tagging was adopted get their tags.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The name for the new tag
+    /// * `target` - The commit to tag (branch, tag, or commit SHA)
+    /// * `message` - Optional message for the tag
+    ///
+    /// # Returns
+    ///
+    /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A tag with the same name already exists
+    /// - The target cannot be resolved to a commit
+    /// - The repository signature cannot be created
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// repo.create_tag_at("@myorg/core@1.0.0", "4f2a9c1", None)
+    ///     .expect("Failed to create tag");
+    /// ```
+    pub fn create_tag_at(
+        &self,
+        tag: &str,
+        target: &str,
+        message: Option<String>,
+    ) -> Result<&Self, RepoError> {
+        self.ensure_writable("create tag")?;
+
+        let signature = self.repo.signature().map_err(RepoError::SignatureError)?;
+        let tag_message = message.unwrap_or_else(|| format!("chore: tag creation: {tag}"));
+
+        let commit = self
+            .repo
+            .revparse_single(target)
+            .map_err(RepoError::ReferenceError)?
+            .peel_to_commit()
+            .map_err(RepoError::PeelError)?;
+
+        self.repo
+            .tag(tag, commit.as_object(), &signature, &tag_message, false)
+            .map_err(RepoError::CreateTagError)?;
+
+        Ok(self)
+    }
+
     /// Adds a file to the Git index
     ///
     /// # Arguments
@@ -1202,6 +1732,8 @@ impl Repo {
     /// repo.add("src/main.rs").expect("Failed to add file");
     /// ```
     pub fn add(&self, file_path: &str) -> Result<&Self, RepoError> {
+        self.ensure_writable("add")?;
+
         let mut index = self.repo.index().map_err(RepoError::IndexError)?;
         let path = Path::new(file_path);
         // get the relative path of the file_path
@@ -1217,6 +1749,9 @@ impl Repo {
 
     /// Adds all changed files to the Git index
     ///
+    /// Like `git add -A`, files deleted from the working directory are removed from the
+    /// index as well.
+    ///
     /// # Returns
     ///
     /// * `Result<&Self, RepoError>` - A reference to self for method chaining, or an error
@@ -1239,11 +1774,15 @@ impl Repo {
     /// repo.add_all().expect("Failed to add all changes");
     /// ```
     pub fn add_all(&self) -> Result<&Self, RepoError> {
+        self.ensure_writable("add")?;
+
         let mut index = self.repo.index().map_err(RepoError::IndexError)?;
         // Add all files to the index
         index
             .add_all(["*"].iter(), IndexAddOption::DEFAULT, None)
             .map_err(RepoError::IndexError)?;
+        // Stage deletions of tracked files
+        index.update_all(["*"].iter(), None).map_err(RepoError::IndexError)?;
 
         // Write the index to disk
         index.write().map_err(RepoError::IndexError)?;
@@ -1514,6 +2053,46 @@ impl Repo {
         Ok(previous_sha)
     }
 
+    /// Gets the SHA of the first (root) commit reachable from HEAD
+    ///
+    /// When the history has several root commits (e.g., merged unrelated histories),
+    /// the oldest one in topological order is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, RepoError>` - The root commit SHA, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The HEAD reference cannot be accessed (e.g., empty repository)
+    /// - The revision walk cannot be initialized
+    /// - The commit history cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// let first_sha = repo.get_first_sha().expect("Failed to get first SHA");
+    /// println!("First commit: {}", first_sha);
+    /// ```
+    pub fn get_first_sha(&self) -> Result<String, RepoError> {
+        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+        revwalk.push_head().map_err(RepoError::RevWalkError)?;
+        revwalk
+            .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)
+            .map_err(RepoError::RevWalkError)?;
+
+        let first = revwalk
+            .next()
+            .ok_or_else(|| RepoError::HeadError(Git2Error::from_str("No commits found")))?
+            .map_err(RepoError::CommitOidError)?;
+
+        Ok(first.to_string())
+    }
+
     /// Creates a new commit with the current index
     ///
     /// # Arguments
@@ -1547,6 +2126,8 @@ impl Repo {
     /// println!("Created commit: {}", commit_id);
     /// ```
     pub fn commit(&self, message: &str) -> Result<String, RepoError> {
+        self.ensure_writable("commit")?;
+
         let signature = self.repo.signature().map_err(RepoError::SignatureError)?;
         let head_ref = self.repo.head().map_err(RepoError::HeadError)?;
         let head_commit = head_ref.peel_to_commit().map_err(RepoError::PeelError)?;
@@ -1598,6 +2179,8 @@ impl Repo {
     /// println!("Created commit: {}", commit_id);
     /// ```
     pub fn commit_changes(&self, message: &str) -> Result<String, RepoError> {
+        self.ensure_writable("commit")?;
+
         let signature = self.repo.signature().map_err(RepoError::SignatureError)?;
         let head_ref = self.repo.head().map_err(RepoError::HeadError)?;
         let head_commit = head_ref.peel_to_commit().map_err(RepoError::PeelError)?;
@@ -1715,6 +2298,7 @@ impl Repo {
         let statuses =
             self.repo.statuses(Some(&mut status_options)).map_err(RepoError::StatusError)?;
 
+        let submodules = self.submodule_paths();
         let mut result = Vec::new();
 
         for entry in statuses.iter() {
@@ -1742,7 +2326,8 @@ impl Repo {
                 (GitFileStatus::Untracked, false, true)
             };
 
-            result.push(GitChangedFile { path, status, staged, workdir });
+            let kind = self.workdir_kind(&path, &submodules);
+            result.push(GitChangedFile { path, status, staged, workdir, kind });
         }
 
         Ok(result)
@@ -2029,6 +2614,8 @@ impl Repo {
     /// # }
     /// ```
     pub fn merge(&self, branch_name: &str) -> Result<(), RepoError> {
+        self.ensure_writable("merge")?;
+
         // 1. Get HEAD commit (the branch we are merging INTO)
         let head_ref = self.repo.head().map_err(RepoError::HeadError)?;
         let head_oid = head_ref.target().ok_or_else(|| {
@@ -2156,73 +2743,288 @@ impl Repo {
         Err(RepoError::MergeError(Git2Error::from_str("Unhandled merge analysis result")))
     }
 
-    /// Pushes the current branch to a remote repository
+    /// Fast-forwards the current branch to the given reference
+    ///
+    /// Unlike `merge`, this never creates a merge commit: it fails unless the reference
+    /// descends from HEAD. The working directory is updated with a safe checkout, so local
+    /// changes to files the fast-forward touches make it fail instead of being overwritten.
     ///
     /// # Arguments
     ///
-    /// * `remote_name` - The name of the remote (e.g., "origin")
-    /// * `follow_tags` - Whether to also push tags
+    /// * `git_ref` - Branch, tag, or commit SHA to fast-forward to
     ///
     /// # Returns
     ///
-    /// * `Result<bool, RepoError>` - Success indicator or an error
+    /// * `Result<String, RepoError>` - The SHA the branch now points to, or an error
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The specified remote does not exist
-    /// - Authentication fails (SSH keys, credentials)
-    /// - Network connectivity issues prevent pushing
-    /// - The remote repository rejects the push (non-fast-forward, etc.)
-    /// - The current branch has no commits to push
-    /// - File system permissions prevent accessing SSH keys
-    /// - The remote server is unreachable or down
+    /// - HEAD is detached or cannot be resolved
+    /// - The reference cannot be resolved to a commit
+    /// - The reference does not descend from HEAD
+    /// - Local changes conflict with the files being updated
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use git::repo::Repo;
     ///
-    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
-    /// // Push current branch with tags
-    /// repo.push("origin", Some(true)).expect("Failed to push");
+    /// # fn example() -> Result<(), git::repo::RepoError> {
+    /// let repo = Repo::open("./my-repo")?;
+    /// let sha = repo.fast_forward("release-candidate")?;
+    /// println!("main is now at {sha}");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn push(&self, remote_name: &str, follow_tags: Option<bool>) -> Result<bool, RepoError> {
-        // Get the current branch name
-        let head = self.repo.head().map_err(RepoError::HeadError)?;
-        let branch_name = head.shorthand().ok_or_else(|| {
-            RepoError::BranchNameError(Git2Error::from_str("Invalid branch reference"))
-        })?;
-
-        // Get the remote
-        let mut remote = self.repo.find_remote(remote_name).map_err(RepoError::RemoteError)?;
+    pub fn fast_forward(&self, git_ref: &str) -> Result<String, RepoError> {
+        self.ensure_writable("fast-forward")?;
 
-        // Create callbacks for credentials, progress reporting, etc.
-        let mut callbacks = RemoteCallbacks::new();
+        let head_ref = self.repo.head().map_err(RepoError::HeadError)?;
+        if !head_ref.is_branch() {
+            return Err(RepoError::HeadError(Git2Error::from_str(
+                "Cannot fast-forward a detached HEAD",
+            )));
+        }
+        let ref_name = head_ref.name().ok_or_else(|| {
+            RepoError::HeadError(Git2Error::from_str("Cannot get HEAD reference name"))
+        })?;
+        let head_oid = head_ref.target().ok_or_else(|| {
+            RepoError::HeadError(Git2Error::from_str("HEAD reference has no target OID"))
+        })?;
 
-        // Setup SSH authentication with default key paths
-        callbacks.credentials(|url, username_from_url, allowed_types| {
-            self.create_ssh_credentials(url, username_from_url, allowed_types, None)
-        });
+        let target = self
+            .repo
+            .revparse_single(git_ref)
+            .map_err(RepoError::ReferenceError)?
+            .peel_to_commit()
+            .map_err(RepoError::PeelError)?;
+        if target.id() == head_oid {
+            return Ok(head_oid.to_string());
+        }
+        if !self.repo.graph_descendant_of(target.id(), head_oid).map_err(RepoError::GraphError)? {
+            return Err(RepoError::MergeError(Git2Error::from_str(&format!(
+                "'{git_ref}' is not a fast-forward of HEAD"
+            ))));
+        }
 
-        // Add progress reporting
-        callbacks.push_update_reference(|refname, status| {
-            if let Some(error_msg) = status {
-                return Err(Git2Error::from_str(&format!(
-                    "Failed to update {refname} with error: {error_msg}",
-                )));
-            }
-            Ok(())
-        });
+        // Update the working directory first so a conflicting local change leaves HEAD alone
+        let mut checkout_builder = CheckoutBuilder::new();
+        checkout_builder.safe();
+        self.repo
+            .checkout_tree(target.as_object(), Some(&mut checkout_builder))
+            .map_err(RepoError::CheckoutError)?;
 
-        // Setup push options
-        let mut push_options = PushOptions::new();
-        push_options.remote_callbacks(callbacks);
+        let mut reference = self.repo.find_reference(ref_name).map_err(RepoError::HeadError)?;
+        reference
+            .set_target(target.id(), &format!("Fast-forward to {git_ref}"))
+            .map_err(RepoError::ReferenceError)?;
 
-        // Create refspecs
-        let mut refspecs = Vec::new();
+        Ok(target.id().to_string())
+    }
 
-        // Add the branch refspec (e.g., "refs/heads/main:refs/heads/main")
+    /// Adds a linked worktree at `path` on a new branch created from HEAD
+    ///
+    /// The branch is named after the worktree, as `git worktree add` does when no branch
+    /// is given.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the worktree and of the branch created for it
+    /// * `path` - Directory for the worktree; it must not exist yet
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, RepoError>` - A `Repo` instance for the new worktree, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A worktree or branch with this name already exists
+    /// - The directory cannot be created
+    /// - The new worktree cannot be opened
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use git::repo::Repo;
+    /// use std::path::Path;
+    ///
+    /// # fn example() -> Result<(), git::repo::RepoError> {
+    /// let repo = Repo::open("./my-repo")?;
+    /// let sandbox = repo.add_worktree("sandbox", Path::new("/tmp/sandbox"))?;
+    /// println!("Worktree on {}", sandbox.get_current_branch()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_worktree(&self, name: &str, path: &Path) -> Result<Self, RepoError> {
+        self.ensure_writable("add worktree")?;
+
+        let worktree = self.repo.worktree(name, path, None).map_err(RepoError::WorktreeError)?;
+        let repo = Repository::open_from_worktree(&worktree).map_err(RepoError::OpenRepoFailure)?;
+        let local_path = canonicalize(path).map_err(RepoError::CanonicalPathFailure)?;
+
+        Ok(Self { repo, local_path, commit_cache: Mutex::default() })
+    }
+
+    /// Returns the directory of a linked worktree, if a worktree with this name exists
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the worktree
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<PathBuf>, RepoError>` - The worktree directory, or `None`
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the worktree metadata cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use git::repo::Repo;
+    ///
+    /// # fn example() -> Result<(), git::repo::RepoError> {
+    /// let repo = Repo::open("./my-repo")?;
+    /// if let Some(path) = repo.find_worktree("sandbox")? {
+    ///     println!("Sandbox at {}", path.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_worktree(&self, name: &str) -> Result<Option<PathBuf>, RepoError> {
+        match self.repo.find_worktree(name) {
+            Ok(worktree) => Ok(Some(worktree.path().to_path_buf())),
+            Err(e) if e.code() == ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(RepoError::WorktreeError(e)),
+        }
+    }
+
+    /// Removes a linked worktree, its directory, and the branch named after it
+    ///
+    /// Missing worktrees and branches are ignored, so removal can be retried after a
+    /// partial failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the worktree to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), RepoError>` - Success or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The worktree cannot be pruned or its directory removed
+    /// - The branch cannot be deleted
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use git::repo::Repo;
+    ///
+    /// # fn example() -> Result<(), git::repo::RepoError> {
+    /// let repo = Repo::open("./my-repo")?;
+    /// repo.remove_worktree("sandbox")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_worktree(&self, name: &str) -> Result<(), RepoError> {
+        self.ensure_writable("remove worktree")?;
+
+        match self.repo.find_worktree(name) {
+            Ok(worktree) => {
+                let mut prune_options = WorktreePruneOptions::new();
+                prune_options.valid(true).working_tree(true);
+                worktree.prune(Some(&mut prune_options)).map_err(RepoError::WorktreeError)?;
+            }
+            Err(e) if e.code() == ErrorCode::NotFound => {}
+            Err(e) => return Err(RepoError::WorktreeError(e)),
+        }
+
+        match self.repo.find_branch(name, BranchType::Local) {
+            Ok(mut branch) => branch.delete().map_err(RepoError::BranchError),
+            Err(e) if e.code() == ErrorCode::NotFound => Ok(()),
+            Err(e) => Err(RepoError::BranchError(e)),
+        }
+    }
+
+    /// Pushes the current branch to a remote repository
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_name` - The name of the remote (e.g., "origin")
+    /// * `follow_tags` - Whether to also push tags
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, RepoError>` - Success indicator or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The specified remote does not exist
+    /// - Authentication fails (SSH keys, credentials)
+    /// - Network connectivity issues prevent pushing
+    /// - The remote repository rejects the push (non-fast-forward, etc.)
+    /// - The current branch has no commits to push
+    /// - File system permissions prevent accessing SSH keys
+    /// - The remote server is unreachable or down
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// // Push current branch with tags
+    /// repo.push("origin", Some(true)).expect("Failed to push");
+    /// ```
+    pub fn push(&self, remote_name: &str, follow_tags: Option<bool>) -> Result<bool, RepoError> {
+        self.ensure_writable("push")?;
+        ensure_online("push")?;
+
+        // Get the current branch name
+        let head = self.repo.head().map_err(RepoError::HeadError)?;
+        let branch_name = head.shorthand().ok_or_else(|| {
+            RepoError::BranchNameError(Git2Error::from_str("Invalid branch reference"))
+        })?;
+
+        // Get the remote
+        let mut remote = self.repo.find_remote(remote_name).map_err(RepoError::RemoteError)?;
+
+        // Create callbacks for credentials, progress reporting, etc.
+        let mut callbacks = RemoteCallbacks::new();
+
+        // Setup SSH authentication with default key paths
+        callbacks.credentials(|url, username_from_url, allowed_types| {
+            self.create_ssh_credentials(url, username_from_url, allowed_types, None)
+        });
+
+        // Add progress reporting
+        callbacks.push_update_reference(|refname, status| {
+            if let Some(error_msg) = status {
+                return Err(Git2Error::from_str(&format!(
+                    "Failed to update {refname} with error: {error_msg}",
+                )));
+            }
+            Ok(())
+        });
+
+        // Stop the transfer once the process deadline passes
+        abort_at_deadline(&mut callbacks);
+
+        // Setup push options
+        let mut push_options = PushOptions::new();
+        push_options.remote_callbacks(callbacks);
+
+        // Create refspecs
+        let mut refspecs = Vec::new();
+
+        // Add the branch refspec (e.g., "refs/heads/main:refs/heads/main")
         let branch_refspec = format!("refs/heads/{branch_name}:refs/heads/{branch_name}");
         refspecs.push(branch_refspec);
 
@@ -2233,7 +3035,9 @@ impl Repo {
         }
 
         // Perform the push operation
-        remote.push(&refspecs, Some(&mut push_options)).map_err(RepoError::RemoteError)?;
+        remote
+            .push(&refspecs, Some(&mut push_options))
+            .map_err(|e| remote_failure("push", RepoError::RemoteError(e)))?;
 
         Ok(true)
     }
@@ -2280,6 +3084,9 @@ impl Repo {
         refspecs: Option<&[&str]>,
         prune: bool,
     ) -> Result<bool, RepoError> {
+        self.ensure_writable("fetch")?;
+        ensure_online("fetch")?;
+
         // Find the remote
         let mut remote = self.repo.find_remote(remote_name).map_err(RepoError::RemoteError)?;
 
@@ -2294,6 +3101,10 @@ impl Repo {
             self.create_ssh_credentials(url, username_from_url, allowed_types, None)
         });
 
+        // Stop the transfer once the process deadline passes
+        abort_at_deadline(&mut callbacks);
+        callbacks.transfer_progress(|_| !deadline_passed());
+
         // Apply the callbacks
         fetch_opts.remote_callbacks(callbacks);
 
@@ -2340,7 +3151,7 @@ impl Repo {
                 Some(&mut fetch_opts),
                 None, // log message
             )
-            .map_err(RepoError::RemoteError)?;
+            .map_err(|e| remote_failure("fetch", RepoError::RemoteError(e)))?;
 
         Ok(true)
     }
@@ -2383,6 +3194,9 @@ impl Repo {
     /// repo.pull("origin", Some("feature-branch")).expect("Failed to pull from feature branch");
     /// ```
     pub fn pull(&self, remote_name: &str, branch_name: Option<&str>) -> Result<bool, RepoError> {
+        self.ensure_writable("pull")?;
+        ensure_online("pull")?;
+
         // First, fetch from remote
         self.fetch(remote_name, None, false)?;
 
@@ -2524,6 +3338,9 @@ impl Repo {
         follow_tags: Option<bool>,
         ssh_key_paths: Vec<PathBuf>,
     ) -> Result<bool, RepoError> {
+        self.ensure_writable("push")?;
+        ensure_online("push")?;
+
         // Get the current branch name
         let head = self.repo.head().map_err(RepoError::HeadError)?;
         let branch_name = head.shorthand().ok_or_else(|| {
@@ -2544,6 +3361,9 @@ impl Repo {
         // Rest of push implementation is the same...
         // [implementation continues as in the regular push method]
 
+        // Stop the transfer once the process deadline passes
+        abort_at_deadline(&mut callbacks);
+
         // Setup push options
         let mut push_options = PushOptions::new();
         push_options.remote_callbacks(callbacks);
@@ -2562,7 +3382,9 @@ impl Repo {
         }
 
         // Perform the push operation
-        remote.push(&refspecs, Some(&mut push_options)).map_err(RepoError::RemoteError)?;
+        remote
+            .push(&refspecs, Some(&mut push_options))
+            .map_err(|e| remote_failure("push", RepoError::RemoteError(e)))?;
 
         Ok(true)
     }
@@ -2724,6 +3546,7 @@ impl Repo {
                                 status,
                                 staged: false,  // Historical changes are not staged
                                 workdir: false, // Historical changes are already committed
+                                kind: self.delta_kind(&delta),
                             });
                         }
                     }
@@ -2747,7 +3570,7 @@ impl Repo {
                     if let Some(name) = entry.name() {
                         let path =
                             if dir.is_empty() { name.to_string() } else { format!("{dir}{name}") };
-                        old_files.insert(path, entry.id());
+                        old_files.insert(path, (entry.filemode(), entry.id()));
                     }
                     TreeWalkResult::Ok
                 })
@@ -2766,7 +3589,7 @@ impl Repo {
                 .map_err(RepoError::DiffError)?;
 
             // Any remaining files in old_files were deleted
-            for (path, _) in old_files {
+            for (path, (mode, id)) in old_files {
                 let full_path = self.local_path.join(&path);
                 if let Some(path_str) = full_path.to_str() {
                     // Make sure we don't add duplicates
@@ -2777,6 +3600,7 @@ impl Repo {
                             status: GitFileStatus::Deleted,
                             staged: false,  // Historical changes are not staged
                             workdir: false, // Historical changes are already committed
+                            kind: self.object_kind(mode == i32::from(FileMode::Commit), id),
                         });
                     }
                 }
@@ -2906,6 +3730,10 @@ impl Repo {
 
     /// Gets commits made since a specific reference or from the beginning
     ///
+    /// The walk for a given HEAD and `since` is cached on the repository, along with the
+    /// paths each commit changed, so querying the same range for several paths walks
+    /// history once. The cache follows HEAD; see [`Repo::clear_commit_cache`].
+    ///
     /// # Arguments
     ///
     /// * `since` - Optional reference to start from (branch, tag, or commit SHA)
@@ -2957,85 +3785,103 @@ impl Repo {
         since: Option<String>,
         relative: &Option<String>,
     ) -> Result<Vec<RepoCommit>, RepoError> {
-        // Start a revwalk to iterate through commits
-        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
-
-        // Configure the revwalk based on the inputs
-        if let Some(since) = since {
-            // Resolve the 'since' reference to an OID
-            let obj = self.repo.revparse_single(&since).map_err(RepoError::ReferenceError)?;
-            let since_commit = obj.peel_to_commit().map_err(RepoError::PeelError)?;
-
-            // Push HEAD as the end point
-            revwalk.push_head().map_err(RepoError::RevWalkError)?;
+        // Resolve the 'since' reference to an OID
+        let boundary = match since {
+            Some(since) => {
+                let obj = self.repo.revparse_single(&since).map_err(RepoError::ReferenceError)?;
+                Some(obj.peel_to_commit().map_err(RepoError::PeelError)?.id())
+            }
+            None => None,
+        };
+        let head = self.repo.refname_to_id("HEAD").map_err(RepoError::RevWalkError)?;
 
-            // Hide any commits reachable from 'since'
+        self.cached_walk(CommitWalk::Since, head, boundary, relative.as_deref(), || {
+            // Start a revwalk from HEAD, hiding any commits reachable from 'since'
             // This effectively gives us commits that are in HEAD but not in 'since'
-            revwalk.hide(since_commit.id()).map_err(RepoError::CommitError)?;
-        } else {
-            // If no 'since' is provided, just walk from HEAD
-            revwalk.push_head().map_err(RepoError::RevWalkError)?;
-        }
-
-        // Set sorting (newest first, like 'git log')
-        revwalk.set_sorting(git2::Sort::TIME).map_err(RepoError::RevWalkError)?;
-
-        // Collect commits
-        let mut commits = Vec::new();
-
-        for oid_result in revwalk {
-            let oid = oid_result.map_err(RepoError::CommitOidError)?;
-            let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
-
-            // If relative path is provided, check if commit touches this path
-            if let Some(rel_path) = &relative {
-                let rel_path_buf = PathBuf::from(rel_path);
+            let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+            revwalk.push(head).map_err(RepoError::RevWalkError)?;
+            if let Some(boundary) = boundary {
+                revwalk.hide(boundary).map_err(RepoError::CommitError)?;
+            }
 
-                // Skip this commit if it doesn't touch the specified path
-                if !self.commit_touches_path(&commit, &rel_path_buf)? {
-                    continue;
-                }
+            // Set sorting (newest first, like 'git log')
+            revwalk.set_sorting(git2::Sort::TIME).map_err(RepoError::RevWalkError)?;
+
+            let mut commits = Vec::new();
+            for oid_result in revwalk {
+                let oid = oid_result.map_err(RepoError::CommitOidError)?;
+                let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+
+                // Format the commit date
+                let time = commit.time();
+                let offset = time.offset_minutes();
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset_hours = offset.abs() / 60;
+                let offset_minutes = offset.abs() % 60;
+
+                // Create a DateTime object
+                let datetime = chrono::DateTime::<chrono::Utc>::from_timestamp(time.seconds(), 0)
+                    .unwrap_or_else(chrono::Utc::now);
+
+                // Format in RFC2822 format (to match git log --date=rfc2822)
+                let date_str = format!(
+                    "{} {}{:02}:{:02}",
+                    datetime.format("%a, %d %b %Y %H:%M:%S"),
+                    sign,
+                    offset_hours,
+                    offset_minutes
+                );
+
+                // Get author information
+                let author = commit.author();
+                let name = author.name().unwrap_or("").to_string();
+                let email = author.email().unwrap_or("").to_string();
+
+                // Get commit message
+                let message = commit.message().unwrap_or("").to_string();
+
+                // Create and add the repository commit
+                commits.push((
+                    oid,
+                    RepoCommit {
+                        hash: commit.id().to_string(),
+                        author_name: name,
+                        author_email: email,
+                        author_date: date_str,
+                        message,
+                    },
+                ));
             }
 
-            // Format the commit date
-            let time = commit.time();
-            let offset = time.offset_minutes();
-            let sign = if offset < 0 { '-' } else { '+' };
-            let offset_hours = offset.abs() / 60;
-            let offset_minutes = offset.abs() % 60;
-
-            // Create a DateTime object
-            let datetime = chrono::DateTime::<chrono::Utc>::from_timestamp(time.seconds(), 0)
-                .unwrap_or_else(chrono::Utc::now);
-
-            // Format in RFC2822 format (to match git log --date=rfc2822)
-            let date_str = format!(
-                "{} {}{:02}:{:02}",
-                datetime.format("%a, %d %b %Y %H:%M:%S"),
-                sign,
-                offset_hours,
-                offset_minutes
-            );
-
-            // Get author information
-            let author = commit.author();
-            let name = author.name().unwrap_or("").to_string();
-            let email = author.email().unwrap_or("").to_string();
-
-            // Get commit message
-            let message = commit.message().unwrap_or("").to_string();
-
-            // Create and add the repository commit
-            commits.push(RepoCommit {
-                hash: commit.id().to_string(),
-                author_name: name,
-                author_email: email,
-                author_date: date_str,
-                message,
-            });
-        }
+            Ok(commits)
+        })
+    }
 
-        Ok(commits)
+    /// Clears the cached commit history
+    ///
+    /// History queries (`get_commits_since`, `get_commits_between` and their first-parent
+    /// variants) cache their walks and the paths each commit changed. Cached walks are
+    /// keyed by commit and dropped when HEAD moves, so new commits are always seen; clearing
+    /// is only needed to release the memory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use sublime_git_tools::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    /// for package in ["packages/a", "packages/b"] {
+    ///     let commits = repo.get_commits_since(Some("v1.0.0".to_string()), &Some(package.to_string()))
+    ///         .expect("Failed to get commits");
+    ///     println!("{package}: {} commits", commits.len());
+    /// }
+    /// repo.clear_commit_cache();
+    /// ```
+    pub fn clear_commit_cache(&self) {
+        let mut cache = self.lock_commit_cache();
+        cache.head = None;
+        cache.walks.clear();
+        cache.paths.clear();
     }
 
     /// Gets all commits between two references
@@ -3079,9 +3925,6 @@ impl Repo {
         to_ref: &str,
         relative: &Option<String>,
     ) -> Result<Vec<RepoCommit>, RepoError> {
-        // Start a revwalk to iterate through commits
-        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
-
         // Resolve both references to OIDs
         let from_obj = self.repo.revparse_single(from_ref).map_err(RepoError::ReferenceError)?;
         let from_commit = from_obj.peel_to_commit().map_err(RepoError::PeelError)?;
@@ -3089,72 +3932,339 @@ impl Repo {
         let to_obj = self.repo.revparse_single(to_ref).map_err(RepoError::ReferenceError)?;
         let to_commit = to_obj.peel_to_commit().map_err(RepoError::PeelError)?;
 
-        // Push the 'to' commit as the starting point
-        revwalk.push(to_commit.id()).map_err(RepoError::RevWalkError)?;
+        let (tip, boundary) = (to_commit.id(), from_commit.id());
+        self.cached_walk(CommitWalk::Between, tip, Some(boundary), relative.as_deref(), || {
+            // Start a revwalk from the 'to' commit, hiding commits reachable from 'from'
+            // This gives us commits that are in 'to' but not in 'from'
+            let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+            revwalk.push(tip).map_err(RepoError::RevWalkError)?;
+            revwalk.hide(boundary).map_err(RepoError::CommitError)?;
+
+            // Set sorting (newest first, like 'git log')
+            revwalk.set_sorting(git2::Sort::TIME).map_err(RepoError::RevWalkError)?;
+
+            let mut commits = Vec::new();
+            for oid_result in revwalk {
+                let oid = oid_result.map_err(RepoError::CommitOidError)?;
+                let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+
+                // Get author information
+                let signature = commit.author();
+                let name = signature.name().unwrap_or("Unknown").to_string();
+                let email = signature.email().unwrap_or("unknown@example.com").to_string();
+
+                // Convert timestamp to RFC3339 format
+                let time = commit.time();
+                let offset = time.offset_minutes();
+                let datetime = chrono::DateTime::from_timestamp(time.seconds(), 0)
+                    .unwrap_or_else(chrono::Utc::now);
+
+                // Handle timezone offset safely - fallback to UTC if invalid
+                let date_str = match chrono::FixedOffset::east_opt(offset * 60) {
+                    Some(offset_duration) => {
+                        let date_with_offset = datetime.with_timezone(&offset_duration);
+                        date_with_offset.to_rfc3339()
+                    }
+                    None => {
+                        // Invalid offset, use UTC
+                        datetime.to_rfc3339()
+                    }
+                };
 
-        // Hide commits reachable from 'from' commit
-        // This gives us commits that are in 'to' but not in 'from'
-        revwalk.hide(from_commit.id()).map_err(RepoError::CommitError)?;
+                // Get commit message
+                let message = commit.message().unwrap_or("").to_string();
+
+                // Create and add the repository commit
+                commits.push((
+                    oid,
+                    RepoCommit {
+                        hash: commit.id().to_string(),
+                        author_name: name,
+                        author_email: email,
+                        author_date: date_str,
+                        message,
+                    },
+                ));
+            }
 
-        // Set sorting (newest first, like 'git log')
-        revwalk.set_sorting(git2::Sort::TIME).map_err(RepoError::RevWalkError)?;
+            Ok(commits)
+        })
+    }
 
-        // Collect commits
-        let mut commits = Vec::new();
+    /// Gets commits made since a reference, following the first-parent history
+    ///
+    /// Behaves like [`Repo::get_commits_since`], but walks only the first parent of each
+    /// commit on the current branch. Merge commits are replaced by the commits they brought
+    /// in, so a merged branch yields the same commits as if it had been rebased.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - Optional reference to start from (branch, tag, or commit SHA)
+    /// * `relative` - Optional path to filter commits by (only commits touching this path)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepoCommit>, RepoError>` - List of commits, newest first, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The provided reference (since) does not exist or is invalid
+    /// - The revision walk cannot be initialized
+    /// - Commit objects cannot be accessed or are corrupted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    ///
+    /// let commits = repo.get_first_parent_commits_since(
+    ///     Some("v1.0.0".to_string()),
+    ///     &None
+    /// ).expect("Failed to get commits");
+    /// ```
+    pub fn get_first_parent_commits_since(
+        &self,
+        since: Option<String>,
+        relative: &Option<String>,
+    ) -> Result<Vec<RepoCommit>, RepoError> {
+        let head = self.repo.head().map_err(RepoError::HeadError)?;
+        let tip = head.peel_to_commit().map_err(RepoError::PeelError)?;
 
-        for oid_result in revwalk {
-            let oid = oid_result.map_err(RepoError::CommitOidError)?;
-            let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+        let boundary = match since {
+            Some(since) => {
+                let obj = self.repo.revparse_single(&since).map_err(RepoError::ReferenceError)?;
+                Some(obj.peel_to_commit().map_err(RepoError::PeelError)?.id())
+            }
+            None => None,
+        };
 
-            // If relative path is provided, check if commit touches this path
-            if let Some(rel_path) = &relative {
-                let rel_path_buf = PathBuf::from(rel_path);
+        let tip = tip.id();
+        self.cached_walk(CommitWalk::FirstParent, tip, boundary, relative.as_deref(), || {
+            self.walk_first_parent(tip, boundary)
+        })
+    }
 
-                // Skip this commit if it doesn't touch the specified path
-                if !self.commit_touches_path(&commit, &rel_path_buf)? {
-                    continue;
-                }
+    /// Gets commits between two references, following the first-parent history
+    ///
+    /// Behaves like [`Repo::get_commits_between`], but walks only the first parent of each
+    /// commit reachable from `to_ref`. Merge commits are replaced by the commits they brought
+    /// in, so a merged branch yields the same commits as if it had been rebased.
+    ///
+    /// # Arguments
+    ///
+    /// * `from_ref` - Starting reference (commits after this point)
+    /// * `to_ref` - Ending reference (commits up to this point)
+    /// * `relative` - Optional path to filter commits that touch specific files
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepoCommit>, RepoError>` - List of commits, newest first, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Either reference cannot be resolved
+    /// - Git operations fail
+    /// - Commit objects are corrupted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use git::repo::Repo;
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    ///
+    /// let commits = repo.get_first_parent_commits_between("v1.0.0", "v1.1.0", &None)
+    ///     .expect("Failed to get commits");
+    /// ```
+    pub fn get_first_parent_commits_between(
+        &self,
+        from_ref: &str,
+        to_ref: &str,
+        relative: &Option<String>,
+    ) -> Result<Vec<RepoCommit>, RepoError> {
+        let from_obj = self.repo.revparse_single(from_ref).map_err(RepoError::ReferenceError)?;
+        let from_commit = from_obj.peel_to_commit().map_err(RepoError::PeelError)?;
+
+        let to_obj = self.repo.revparse_single(to_ref).map_err(RepoError::ReferenceError)?;
+        let to_commit = to_obj.peel_to_commit().map_err(RepoError::PeelError)?;
+
+        let (tip, boundary) = (to_commit.id(), Some(from_commit.id()));
+        self.cached_walk(CommitWalk::FirstParent, tip, boundary, relative.as_deref(), || {
+            self.walk_first_parent(tip, boundary)
+        })
+    }
+
+    /// Iterates commit history lazily
+    ///
+    /// Unlike [`Repo::get_commits_since`], commits are read one at a time as the returned
+    /// iterator advances, and nothing is cached, so histories of any length can be paged
+    /// through with bounded memory. Commits are yielded newest first, never before their
+    /// descendants; in first-parent mode
+    /// merge commits are replaced by the commits they merged, as in
+    /// [`Repo::get_first_parent_commits_since`].
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The range, path filter, limit and walk mode
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CommitHistory<'_>, RepoError>` - An iterator over the commits, or an error
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The `since` or `until` reference cannot be resolved to a commit
+    /// - `HEAD` cannot be resolved when `until` is not set
+    /// - The revision walk cannot be initialized
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_git_tools::{HistoryQuery, Repo};
+    ///
+    /// let repo = Repo::open("./my-repo").expect("Failed to open repository");
+    ///
+    /// // Second page of 100 commits since v1.0.0
+    /// let query = HistoryQuery::new().since("v1.0.0");
+    /// for commit in repo.history(&query)?.skip(100).take(100) {
+    ///     let commit = commit?;
+    ///     println!("{}: {}", commit.hash, commit.message);
+    /// }
+    /// ```
+    pub fn history(&self, query: &HistoryQuery) -> Result<CommitHistory<'_>, RepoError> {
+        let tip = match &query.until {
+            Some(until) => self.resolve_commit_id(until)?,
+            None => {
+                let head = self.repo.head().map_err(RepoError::HeadError)?;
+                head.peel_to_commit().map_err(RepoError::PeelError)?.id()
             }
+        };
+        let boundary =
+            query.since.as_deref().map(|since| self.resolve_commit_id(since)).transpose()?;
 
-            // Get author information
-            let signature = commit.author();
-            let name = signature.name().unwrap_or("Unknown").to_string();
-            let email = signature.email().unwrap_or("unknown@example.com").to_string();
+        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+        revwalk.push(tip).map_err(RepoError::RevWalkError)?;
+        if let Some(boundary) = boundary {
+            revwalk.hide(boundary).map_err(RepoError::CommitError)?;
+        }
+        if query.first_parent {
+            revwalk.simplify_first_parent().map_err(RepoError::RevWalkError)?;
+            revwalk.set_sorting(git2::Sort::TOPOLOGICAL).map_err(RepoError::RevWalkError)?;
+        } else {
+            revwalk
+                .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+                .map_err(RepoError::RevWalkError)?;
+        }
 
-            // Convert timestamp to RFC3339 format
-            let time = commit.time();
-            let offset = time.offset_minutes();
-            let datetime = chrono::DateTime::from_timestamp(time.seconds(), 0)
-                .unwrap_or_else(chrono::Utc::now);
+        Ok(CommitHistory {
+            repo: self,
+            revwalk,
+            boundary,
+            first_parent: query.first_parent,
+            pending: VecDeque::new(),
+            path: query.path.clone(),
+            remaining: query.limit,
+            failed: false,
+        })
+    }
 
-            // Handle timezone offset safely - fallback to UTC if invalid
-            let date_str = match chrono::FixedOffset::east_opt(offset * 60) {
-                Some(offset_duration) => {
-                    let date_with_offset = datetime.with_timezone(&offset_duration);
-                    date_with_offset.to_rfc3339()
-                }
-                None => {
-                    // Invalid offset, use UTC
-                    datetime.to_rfc3339()
-                }
+    /// Resolves a reference (branch, tag, or commit SHA) to the id of its commit
+    fn resolve_commit_id(&self, reference: &str) -> Result<Oid, RepoError> {
+        let obj = self.repo.revparse_single(reference).map_err(RepoError::ReferenceError)?;
+        Ok(obj.peel_to_commit().map_err(RepoError::PeelError)?.id())
+    }
+
+    /// Walks the first-parent history from `tip`, expanding merge commits
+    ///
+    /// Each merge commit on the first-parent line is replaced by the non-merge commits
+    /// reachable from its other parents but not from its first parent or `boundary`.
+    fn walk_first_parent(&self, tip: Oid, boundary: Option<Oid>) -> Result<CachedWalk, RepoError> {
+        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+        revwalk.push(tip).map_err(RepoError::RevWalkError)?;
+        if let Some(boundary) = boundary {
+            revwalk.hide(boundary).map_err(RepoError::CommitError)?;
+        }
+        revwalk.simplify_first_parent().map_err(RepoError::RevWalkError)?;
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL).map_err(RepoError::RevWalkError)?;
+
+        let mut commits = Vec::new();
+
+        for oid_result in revwalk {
+            let oid = oid_result.map_err(RepoError::CommitOidError)?;
+            let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+
+            let expanded = if commit.parent_count() > 1 {
+                self.merged_commits(&commit, boundary)?
+            } else {
+                vec![commit]
             };
 
-            // Get commit message
-            let message = commit.message().unwrap_or("").to_string();
+            commits
+                .extend(expanded.iter().map(|commit| (commit.id(), Self::to_repo_commit(commit))));
+        }
 
-            // Create and add the repository commit
-            commits.push(RepoCommit {
-                hash: commit.id().to_string(),
-                author_name: name,
-                author_email: email,
-                author_date: date_str,
-                message,
-            });
+        Ok(commits)
+    }
+
+    /// Returns the non-merge commits a merge commit brought into its first parent
+    fn merged_commits(
+        &self,
+        merge: &Commit<'_>,
+        boundary: Option<Oid>,
+    ) -> Result<Vec<Commit<'_>>, RepoError> {
+        let mut revwalk = self.repo.revwalk().map_err(RepoError::GitFailure)?;
+        for parent_id in merge.parent_ids().skip(1) {
+            revwalk.push(parent_id).map_err(RepoError::RevWalkError)?;
+        }
+        let first_parent = merge.parent_id(0).map_err(RepoError::CommitError)?;
+        revwalk.hide(first_parent).map_err(RepoError::CommitError)?;
+        if let Some(boundary) = boundary {
+            revwalk.hide(boundary).map_err(RepoError::CommitError)?;
+        }
+        revwalk
+            .set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(RepoError::RevWalkError)?;
+
+        let mut commits = Vec::new();
+        for oid_result in revwalk {
+            let oid = oid_result.map_err(RepoError::CommitOidError)?;
+            let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+            if commit.parent_count() <= 1 {
+                commits.push(commit);
+            }
         }
 
         Ok(commits)
     }
 
+    /// Converts a commit into a `RepoCommit` with an RFC 3339 author date
+    fn to_repo_commit(commit: &Commit<'_>) -> RepoCommit {
+        let signature = commit.author();
+        let name = signature.name().unwrap_or("Unknown").to_string();
+        let email = signature.email().unwrap_or("unknown@example.com").to_string();
+
+        let time = commit.time();
+        let datetime =
+            chrono::DateTime::from_timestamp(time.seconds(), 0).unwrap_or_else(chrono::Utc::now);
+        let date_str = match chrono::FixedOffset::east_opt(time.offset_minutes() * 60) {
+            Some(offset) => datetime.with_timezone(&offset).to_rfc3339(),
+            None => datetime.to_rfc3339(),
+        };
+
+        RepoCommit {
+            hash: commit.id().to_string(),
+            author_name: name,
+            author_email: email,
+            author_date: date_str,
+            message: commit.message().unwrap_or("").to_string(),
+        }
+    }
+
     /// Gets tags from either local repository or remote
     ///
     /// # Arguments
@@ -3289,6 +4399,131 @@ impl Repo {
         Cred::ssh_key_from_agent(&username)
     }
 
+    /// Answers a history query from the commit cache, walking history on a miss
+    ///
+    /// The unfiltered walk is cached per walk kind, tip and boundary, then filtered by
+    /// `relative` with the cached paths of each commit.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The walk being answered
+    /// * `tip` - The commit the walk starts from
+    /// * `boundary` - The commit whose history is excluded, if any
+    /// * `relative` - Optional path the commits must touch
+    /// * `walk` - Walks history when the result is not cached
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<RepoCommit>, RepoError>` - The commits in walk order, or an error
+    fn cached_walk(
+        &self,
+        kind: CommitWalk,
+        tip: Oid,
+        boundary: Option<Oid>,
+        relative: Option<&str>,
+        walk: impl FnOnce() -> Result<CachedWalk, RepoError>,
+    ) -> Result<Vec<RepoCommit>, RepoError> {
+        let key = (kind, tip, boundary);
+        let cached = {
+            let mut cache = self.lock_commit_cache();
+            // Walks made before HEAD moved are dropped; commit paths never change
+            let head = self.repo.refname_to_id("HEAD").ok();
+            if cache.head != head {
+                cache.walks.clear();
+                cache.head = head;
+            }
+            cache.walks.get(&key).cloned()
+        };
+
+        let commits = match cached {
+            Some(commits) => commits,
+            None => {
+                let commits = walk()?;
+                self.lock_commit_cache().walks.insert(key, commits.clone());
+                commits
+            }
+        };
+
+        let Some(relative) = relative else {
+            return Ok(commits.into_iter().map(|(_, commit)| commit).collect());
+        };
+
+        let mut filtered = Vec::new();
+        for (oid, commit) in commits {
+            if self.cached_commit_touches_path(oid, relative)? {
+                filtered.push(commit);
+            }
+        }
+        Ok(filtered)
+    }
+
+    /// Checks if a commit touches a path using the cached paths it changed
+    ///
+    /// Paths with glob characters or `.`/`..` components are passed to Git as a pathspec
+    /// by [`Repo::commit_touches_path`] instead, since their matching differs from a
+    /// plain directory prefix.
+    fn cached_commit_touches_path(&self, oid: Oid, relative: &str) -> Result<bool, RepoError> {
+        let path = relative.trim_end_matches('/');
+        let literal = !path.is_empty()
+            && !path.contains(['*', '?', '[', '\\'])
+            && path.split('/').all(|component| !matches!(component, "" | "." | ".."));
+        if !literal {
+            let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+            return self.commit_touches_path(&commit, &PathBuf::from(relative));
+        }
+
+        let touches = |paths: &[String]| {
+            paths.iter().any(|changed| {
+                changed
+                    .strip_prefix(path)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+        };
+
+        if let Some(paths) = self.lock_commit_cache().paths.get(&oid) {
+            return Ok(touches(paths));
+        }
+
+        let commit = self.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+        let paths = self.commit_changed_paths(&commit)?;
+        let result = touches(&paths);
+        self.lock_commit_cache().paths.insert(oid, paths);
+        Ok(result)
+    }
+
+    /// Lists the paths a commit changed compared with its first parent
+    ///
+    /// Both sides of renames are listed; every path of the tree is listed for root commits.
+    fn commit_changed_paths(&self, commit: &Commit) -> Result<Vec<String>, RepoError> {
+        let commit_tree = commit.tree().map_err(RepoError::GitFailure)?;
+        let parent_tree = if commit.parent_count() == 0 {
+            None
+        } else {
+            let parent = commit.parent(0).map_err(RepoError::GitFailure)?;
+            Some(parent.tree().map_err(RepoError::GitFailure)?)
+        };
+
+        let diff = self
+            .repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&commit_tree), None)
+            .map_err(RepoError::DiffError)?;
+
+        let mut paths: Vec<String> = diff
+            .deltas()
+            .flat_map(|delta| [delta.old_file().path(), delta.new_file().path()])
+            .flatten()
+            .filter_map(|path| path.to_str().map(String::from))
+            .collect();
+        paths.sort();
+        paths.dedup();
+        Ok(paths)
+    }
+
+    /// Locks the commit cache, recovering it if a panicking thread poisoned the lock
+    fn lock_commit_cache(&self) -> MutexGuard<'_, CommitCache> {
+        self.commit_cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
     /// Checks if a commit touches a specific path
     ///
     /// This is an internal helper method used by `get_commits_since`.
@@ -3331,6 +4566,70 @@ impl Repo {
         Ok(diff.deltas().count() > 0)
     }
 
+    /// Classifies the entry a diff delta describes
+    ///
+    /// Deleted entries are classified from their old side, every other delta from its new side.
+    fn delta_kind(&self, delta: &DiffDelta<'_>) -> GitFileKind {
+        let file =
+            if delta.status() == Delta::Deleted { delta.old_file() } else { delta.new_file() };
+        let submodule =
+            file.mode() == FileMode::Commit || delta.old_file().mode() == FileMode::Commit;
+        self.object_kind(submodule, file.id())
+    }
+
+    /// Classifies a tree entry from its object
+    ///
+    /// # Arguments
+    ///
+    /// * `submodule` - Whether the entry is recorded as a commit (gitlink)
+    /// * `id` - The object the entry points to
+    ///
+    /// # Returns
+    ///
+    /// * `GitFileKind` - `Submodule` for gitlinks, `LfsPointer` for blobs holding a Git LFS
+    ///   pointer, and `Regular` otherwise
+    fn object_kind(&self, submodule: bool, id: Oid) -> GitFileKind {
+        if submodule {
+            return GitFileKind::Submodule;
+        }
+        if id.is_zero() {
+            return GitFileKind::Regular;
+        }
+
+        match self.repo.find_blob(id) {
+            Ok(blob) if blob.content().starts_with(LFS_POINTER_PREFIX) => GitFileKind::LfsPointer,
+            _ => GitFileKind::Regular,
+        }
+    }
+
+    /// Classifies a path reported by the working directory status
+    ///
+    /// The working copy of an LFS file holds its real content, so LFS files are recognized
+    /// from the `filter=lfs` attribute instead of their content.
+    fn workdir_kind(&self, path: &str, submodules: &HashSet<String>) -> GitFileKind {
+        if submodules.contains(path.trim_end_matches('/')) {
+            return GitFileKind::Submodule;
+        }
+
+        match self.repo.get_attr(Path::new(path), "filter", AttrCheckFlags::FILE_THEN_INDEX) {
+            Ok(Some("lfs")) => GitFileKind::LfsPointer,
+            _ => GitFileKind::Regular,
+        }
+    }
+
+    /// Gets the paths of the submodules registered in the repository
+    fn submodule_paths(&self) -> HashSet<String> {
+        self.repo
+            .submodules()
+            .map(|submodules| {
+                submodules
+                    .iter()
+                    .filter_map(|submodule| submodule.path().to_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Gets all local tags in the repository
     ///
     /// This is an internal helper method used by `get_remote_or_local_tags`.
@@ -3385,16 +4684,22 @@ impl Repo {
     ///
     /// * `Result<Vec<RepoTags>, RepoError>` - List of remote tags, or an error
     fn get_remote_tags(&self) -> Result<Vec<RepoTags>, RepoError> {
+        ensure_online("list remote tags")?;
+
         let mut tags = Vec::new();
 
         // Find the "origin" remote
         let mut remote = self.repo.find_remote("origin").map_err(RepoError::RemoteError)?;
 
-        // Connect to the remote
-        remote.connect(Direction::Fetch).map_err(RepoError::RemoteError)?;
+        // Connect to the remote, giving up once the process deadline passes
+        let mut callbacks = RemoteCallbacks::new();
+        abort_at_deadline(&mut callbacks);
+        let connection = remote
+            .connect_auth(Direction::Fetch, Some(callbacks), None)
+            .map_err(|e| remote_failure("list remote tags", RepoError::RemoteError(e)))?;
 
         // List all references on the remote
-        let remote_refs = remote.list().map_err(RepoError::RemoteError)?;
+        let remote_refs = connection.list().map_err(RepoError::RemoteError)?;
 
         // Filter and extract tag references
         for remote_ref in remote_refs {
@@ -3445,6 +4750,76 @@ impl Repo {
     }
 }
 
+// Implementation of Debug that skips the revision walk since it doesn't implement Debug
+impl std::fmt::Debug for CommitHistory<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommitHistory")
+            .field("repo", &self.repo)
+            .field("boundary", &self.boundary)
+            .field("first_parent", &self.first_parent)
+            .field("path", &self.path)
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> CommitHistory<'a> {
+    /// Returns the next commit of the walk that matches the path filter
+    fn next_commit(&mut self) -> Result<Option<Commit<'a>>, RepoError> {
+        loop {
+            let commit = match self.pending.pop_front() {
+                Some(commit) => commit,
+                None => {
+                    let Some(oid_result) = self.revwalk.next() else {
+                        return Ok(None);
+                    };
+                    let oid = oid_result.map_err(RepoError::CommitOidError)?;
+                    let commit = self.repo.repo.find_commit(oid).map_err(RepoError::CommitError)?;
+
+                    // Queue the commits a merge brought in, in place of the merge itself
+                    if self.first_parent && commit.parent_count() > 1 {
+                        self.pending = self.repo.merged_commits(&commit, self.boundary)?.into();
+                        continue;
+                    }
+                    commit
+                }
+            };
+
+            let touches = match &self.path {
+                Some(path) => self.repo.commit_touches_path(&commit, &PathBuf::from(path))?,
+                None => true,
+            };
+            if touches {
+                return Ok(Some(commit));
+            }
+        }
+    }
+}
+
+impl Iterator for CommitHistory<'_> {
+    type Item = Result<RepoCommit, RepoError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.remaining == Some(0) {
+            return None;
+        }
+
+        match self.next_commit() {
+            Ok(Some(commit)) => {
+                if let Some(remaining) = &mut self.remaining {
+                    *remaining -= 1;
+                }
+                Some(Ok(Repo::to_repo_commit(&commit)))
+            }
+            Ok(None) => None,
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3457,8 +4832,11 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir); // Clean up if exists
         std::fs::create_dir_all(&temp_dir).unwrap();
 
-        let repo =
-            Repo { repo: git2::Repository::init_bare(&temp_dir).unwrap(), local_path: temp_dir };
+        let repo = Repo {
+            repo: git2::Repository::init_bare(&temp_dir).unwrap(),
+            local_path: temp_dir,
+            commit_cache: Mutex::default(),
+        };
 
         // Test standard semantic versions
         assert_eq!(repo.parse_semantic_version("1.2.3"), Some((1, 2, 3, None)));
@@ -3491,8 +4869,11 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir); // Clean up if exists
         std::fs::create_dir_all(&temp_dir).unwrap();
 
-        let repo =
-            Repo { repo: git2::Repository::init_bare(&temp_dir).unwrap(), local_path: temp_dir };
+        let repo = Repo {
+            repo: git2::Repository::init_bare(&temp_dir).unwrap(),
+            local_path: temp_dir,
+            commit_cache: Mutex::default(),
+        };
 
         // Test semantic version comparison
         assert_eq!(repo.compare_version_tags("v1.2.3", "v2.0.0"), Ordering::Less);
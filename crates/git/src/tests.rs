@@ -5,7 +5,7 @@
 mod tests {
     use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait};
 
-    use crate::{GitFileStatus, Repo, RepoError};
+    use crate::{GitFileKind, GitFileStatus, HistoryQuery, Repo, RepoError};
     use std::{
         env::temp_dir,
         fs::{File, canonicalize, create_dir, remove_dir_all},
@@ -133,6 +133,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_config_value_roundtrip() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+
+        assert_eq!(repo.get_config_value("core.hooksPath")?, None);
+
+        repo.set_config_value("core.hooksPath", ".husky")?;
+        assert_eq!(repo.get_config_value("core.hooksPath")?, Some(".husky".to_string()));
+
+        repo.unset_config_value("core.hooksPath")?;
+        assert_eq!(repo.get_config_value("core.hooksPath")?, None);
+
+        // Removing an unset key is a no-op
+        repo.unset_config_value("core.hooksPath")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_common_git_dir() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+
+        assert!(repo.get_common_git_dir().ends_with(".git"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_checkout_branch() -> Result<(), RepoError> {
         let workspace = TestWorkspace::new().unwrap();
@@ -180,6 +213,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_tag_at() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+        let first = repo.get_current_sha()?;
+        std::fs::write(workspace_path.join("file.txt"), "content").unwrap();
+        repo.add_all()?.commit("feat: second")?;
+
+        repo.create_tag_at("@myorg/core@1.0.0", &first, None)?;
+
+        let tags = repo.get_remote_or_local_tags(Some(true))?;
+        let tag = tags.iter().find(|t| t.tag == "@myorg/core@1.0.0").unwrap();
+        assert_eq!(tag.hash, first);
+        assert!(repo.create_tag_at("@myorg/core@1.0.0", "HEAD", None).is_err());
+        assert!(repo.create_tag_at("@myorg/core@2.0.0", "missing-ref", None).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_current_sha() -> Result<(), RepoError> {
         let workspace = TestWorkspace::new().unwrap();
@@ -235,6 +290,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_first_sha() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+        let initial_sha = repo.get_current_sha()?;
+
+        std::fs::write(workspace_path.join("README.md"), "Hello").expect("Failed to write file");
+        repo.add_all()?.commit("docs: add readme")?;
+
+        assert_eq!(repo.get_first_sha()?, initial_sha);
+        assert_ne!(repo.get_current_sha()?, initial_sha);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_files_and_content_at_ref() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+
+        std::fs::create_dir_all(workspace_path.join("packages/core"))
+            .expect("Failed to create directory");
+        std::fs::write(
+            workspace_path.join("packages/core/package.json"),
+            "{\"version\":\"1.0.0\"}",
+        )
+        .expect("Failed to write file");
+        repo.add_all()?.commit("feat: add core")?;
+        let first_sha = repo.get_current_sha()?;
+
+        std::fs::write(
+            workspace_path.join("packages/core/package.json"),
+            "{\"version\":\"2.0.0\"}",
+        )
+        .expect("Failed to write file");
+        repo.add_all()?.commit("feat: bump core")?;
+
+        let files = repo.get_files_at_ref(&first_sha)?;
+        assert!(files.contains(&"packages/core/package.json".to_string()));
+
+        let old = repo.get_file_content_at_ref(&first_sha, "packages/core/package.json")?;
+        let new = repo.get_file_content_at_ref("HEAD", "packages/core/package.json")?;
+        assert_eq!(old.as_deref(), Some("{\"version\":\"1.0.0\"}"));
+        assert_eq!(new.as_deref(), Some("{\"version\":\"2.0.0\"}"));
+        assert_eq!(repo.get_file_content_at_ref("HEAD", "missing.json")?, None);
+        assert_eq!(repo.get_file_content_at_ref("HEAD", "packages")?, None);
+
+        assert_eq!(repo.get_sha_at_ref(&first_sha[..7])?, first_sha);
+        assert_eq!(repo.get_sha_at_ref("HEAD")?, repo.get_current_sha()?);
+        assert!(repo.get_sha_at_ref("no-such-ref").is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_previous_sha_without_parent() -> Result<(), RepoError> {
         let workspace = TestWorkspace::new().unwrap();
@@ -523,6 +638,107 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_commit_cache_answers_path_queries() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.to_str().unwrap())?;
+
+        let write = |name: &str| {
+            let path = workspace_path.join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, name).unwrap();
+        };
+
+        write("packages/a/index.js");
+        repo.add_all()?;
+        let base = repo.commit("chore: add package a")?;
+        write("packages/a/lib.js");
+        repo.add_all()?;
+        let a_commit = repo.commit("feat: change a")?;
+        write("packages/ab/index.js");
+        repo.add_all()?;
+        let ab_commit = repo.commit("feat: add ab")?;
+        std::fs::create_dir_all(workspace_path.join("packages/b")).unwrap();
+        std::fs::rename(
+            workspace_path.join("packages/a/lib.js"),
+            workspace_path.join("packages/b/lib.js"),
+        )
+        .unwrap();
+        repo.add_all()?;
+        let move_commit = repo.commit("refactor: move lib to b")?;
+
+        // Commits made within the same second have no defined order
+        let hashes = |commits: Vec<crate::RepoCommit>| -> Vec<String> {
+            let mut hashes: Vec<String> = commits.into_iter().map(|commit| commit.hash).collect();
+            hashes.sort();
+            hashes
+        };
+        let sorted = |mut hashes: Vec<String>| {
+            hashes.sort();
+            hashes
+        };
+        let since = |path: &str| {
+            repo.get_commits_since(Some(base.clone()), &Some(path.to_string())).map(hashes)
+        };
+
+        // Directory prefixes match whole path components, and both sides of a move count
+        assert_eq!(since("packages/a")?, sorted(vec![move_commit.clone(), a_commit.clone()]));
+        assert_eq!(since("packages/a/")?, sorted(vec![move_commit.clone(), a_commit.clone()]));
+        assert_eq!(since("packages/ab")?, vec![ab_commit.clone()]);
+        assert_eq!(since("packages/b")?, vec![move_commit.clone()]);
+        assert_eq!(
+            since("packages/a/lib.js")?,
+            sorted(vec![move_commit.clone(), a_commit.clone()])
+        );
+        assert!(since("packages/c")?.is_empty());
+
+        // Globs are matched by Git
+        assert_eq!(since("packages/*/index.js")?, vec![ab_commit.clone()]);
+
+        // Repeated queries and a cleared cache give the same answers
+        assert_eq!(since("packages/a")?, sorted(vec![move_commit.clone(), a_commit.clone()]));
+        repo.clear_commit_cache();
+        assert_eq!(since("packages/ab")?, vec![ab_commit.clone()]);
+
+        // Other walks share the cached paths
+        let all = repo.get_commits_between(&base, "HEAD", &Some("packages".to_string()))?;
+        assert_eq!(hashes(all), sorted(vec![move_commit, ab_commit, a_commit]));
+        let history = repo.get_commits_since(None, &Some("packages/a/index.js".to_string()))?;
+        assert_eq!(hashes(history), vec![base]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_cache_sees_new_commits() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.to_str().unwrap())?;
+
+        std::fs::create_dir_all(workspace_path.join("docs")).unwrap();
+        std::fs::write(workspace_path.join("docs/guide.md"), "# Guide").unwrap();
+        repo.add_all()?;
+        let base = repo.commit("docs: add guide")?;
+
+        let docs = Some("docs".to_string());
+        assert!(repo.get_commits_since(Some(base.clone()), &docs)?.is_empty());
+        assert!(repo.get_first_parent_commits_since(Some(base.clone()), &docs)?.is_empty());
+
+        std::fs::write(workspace_path.join("docs/guide.md"), "# Guide\n\nMore").unwrap();
+        repo.add_all()?;
+        let update = repo.commit("docs: extend guide")?;
+
+        let since = repo.get_commits_since(Some(base.clone()), &docs)?;
+        assert_eq!(since.len(), 1);
+        assert_eq!(since[0].hash, update);
+        let first_parent = repo.get_first_parent_commits_since(Some(base), &docs)?;
+        assert_eq!(first_parent.len(), 1);
+        assert_eq!(first_parent[0].hash, update);
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_commits_between() -> Result<(), RepoError> {
         let workspace = TestWorkspace::new().unwrap();
@@ -614,6 +830,118 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_first_parent_commits_expands_merges() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.to_str().unwrap())?;
+
+        let write = |name: &str| {
+            let mut handle = File::create(workspace_path.join(name)).unwrap();
+            writeln!(handle, "{name}").unwrap();
+        };
+
+        write("initial.txt");
+        repo.add("initial.txt")?;
+        let initial_commit = repo.commit("Initial commit")?;
+        let main_branch = repo.get_current_branch()?;
+        write("main.txt");
+        repo.add("main.txt")?;
+        let main_commit = repo.commit("chore: main work")?;
+
+        // Two commits on a feature branch
+        repo.create_branch("feat/merge-me")?;
+        repo.checkout("feat/merge-me")?;
+        create_dir(workspace_path.join("docs")).unwrap();
+        write("docs/feature.txt");
+        repo.add("docs/feature.txt")?;
+        let feature_commit = repo.commit("feat: add feature")?;
+        write("fix.txt");
+        repo.add("fix.txt")?;
+        let fix_commit = repo.commit("fix: follow-up fix")?;
+
+        // Main merges the branch with a merge commit
+        repo.checkout(&main_branch)?;
+        repo.merge("feat/merge-me")?;
+        let merge_commit = repo.get_current_sha()?;
+
+        let commits = repo.get_first_parent_commits_between(&initial_commit, "HEAD", &None)?;
+        let hashes: Vec<&str> = commits.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(
+            hashes,
+            vec![fix_commit.as_str(), feature_commit.as_str(), main_commit.as_str()]
+        );
+        assert!(!hashes.contains(&merge_commit.as_str()), "Merge commit should be expanded");
+
+        // Same order without a boundary, followed by the earlier history
+        let since = repo.get_first_parent_commits_since(None, &None)?;
+        let since_hashes: Vec<&str> = since.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(&since_hashes[..3], hashes.as_slice());
+        assert_eq!(since_hashes[3], initial_commit);
+
+        // Path filtering applies to the expanded commits
+        let docs = repo.get_first_parent_commits_since(
+            Some(initial_commit.clone()),
+            &Some("docs".to_string()),
+        )?;
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].hash, feature_commit);
+
+        // The lazy first-parent history yields the same commits
+        let query = HistoryQuery::new().since(initial_commit.as_str()).first_parent();
+        let lazy = repo.history(&query)?.collect::<Result<Vec<_>, _>>()?;
+        let lazy_hashes: Vec<&str> = lazy.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(lazy_hashes, hashes);
+
+        let lazy_docs = repo.history(&query.path("docs"))?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(lazy_docs.len(), 1);
+        assert_eq!(lazy_docs[0].hash, feature_commit);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_pages_lazily() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.to_str().unwrap())?;
+
+        create_dir(workspace_path.join("docs")).unwrap();
+        let mut created = Vec::new();
+        for index in 0..6 {
+            let dir = if index % 2 == 0 { "docs" } else { "." };
+            std::fs::write(workspace_path.join(dir).join(format!("file{index}.txt")), "content")
+                .unwrap();
+            repo.add_all()?;
+            created.push(repo.commit(&format!("chore: commit {index}"))?);
+        }
+        // Pages of two commits cover the range in walk order
+        let query = HistoryQuery::new().since(created[0].as_str());
+        let expected: Vec<&str> = created[1..].iter().rev().map(String::as_str).collect();
+        let mut pages = Vec::new();
+        for page in 0..3 {
+            let commits = repo.history(&query)?.skip(page * 2).take(2);
+            pages.push(commits.map(|c| c.map(|c| c.hash)).collect::<Result<Vec<_>, _>>()?);
+        }
+        let hashes: Vec<String> = pages.concat();
+        assert_eq!(hashes, expected);
+        assert!(repo.history(&query)?.nth(5).is_none());
+
+        // The limit stops the walk early
+        let limited = repo.history(&query.clone().limit(3))?.collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(limited.len(), 3);
+
+        // Path and until filters apply before the limit
+        let docs = HistoryQuery::new().until(created[4].as_str()).path("docs").limit(2);
+        let docs = repo.history(&docs)?.collect::<Result<Vec<_>, _>>()?;
+        let docs: Vec<&str> = docs.iter().map(|c| c.hash.as_str()).collect();
+        assert_eq!(docs, vec![created[4].as_str(), created[2].as_str()]);
+
+        assert!(repo.history(&HistoryQuery::new().since("missing-ref")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_local_tags() -> Result<(), RepoError> {
         let workspace = TestWorkspace::new().unwrap();
@@ -924,6 +1252,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_worktree_fast_forward_and_remove() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+        std::fs::write(workspace_path.join("file1.txt"), "main").expect("Failed to write file");
+        repo.add_all()?;
+        let base_sha = repo.commit("feat: add file1")?;
+
+        let sandbox_path = workspace_path.with_extension("sandbox");
+        let sandbox = repo.add_worktree("sandbox", &sandbox_path)?;
+        assert_eq!(repo.find_worktree("sandbox")?, Some(sandbox_path.clone()));
+        assert_eq!(sandbox.get_current_branch()?, "sandbox");
+
+        // Commit in the worktree without touching the main working directory
+        std::fs::write(sandbox_path.join("release.txt"), "1.0.0").expect("Failed to write file");
+        std::fs::remove_file(sandbox_path.join("file1.txt")).expect("Failed to remove file");
+        sandbox.add_all()?;
+        let release_sha = sandbox.commit("chore: release")?;
+        assert_eq!(repo.get_current_sha()?, base_sha);
+        assert!(!workspace_path.join("release.txt").exists());
+
+        assert_eq!(repo.fast_forward("sandbox")?, release_sha);
+        assert_eq!(repo.get_current_sha()?, release_sha);
+        assert!(workspace_path.join("release.txt").exists());
+        assert!(!workspace_path.join("file1.txt").exists());
+
+        repo.remove_worktree("sandbox")?;
+        assert_eq!(repo.find_worktree("sandbox")?, None);
+        assert!(!sandbox_path.exists());
+        assert!(!repo.branch_exists("sandbox")?);
+
+        // Removing again is a no-op
+        repo.remove_worktree("sandbox")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fast_forward_rejects_diverged_history() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+
+        repo.create_branch("feature-branch")?;
+        std::fs::write(workspace_path.join("file1.txt"), "main").expect("Failed to write file");
+        repo.add_all()?;
+        let main_sha = repo.commit("feat: add file1")?;
+
+        let result = repo.fast_forward("feature-branch");
+        assert!(matches!(result, Err(RepoError::MergeError(_))));
+        assert_eq!(repo.get_current_sha()?, main_sha);
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "Requires a remote repository"]
     fn test_fetch() -> Result<(), RepoError> {
@@ -947,4 +1333,87 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_changed_files_detect_lfs_pointers() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+
+        std::fs::write(workspace_path.join("README.md"), "Hello, world!").unwrap();
+        let first_commit_id = repo.add_all()?.commit("feat: add README.md")?;
+
+        std::fs::write(
+            workspace_path.join(".gitattributes"),
+            "*.bin filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+        std::fs::write(
+            workspace_path.join("model.bin"),
+            "version https://git-lfs.github.com/spec/v1\n\
+             oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+             size 12345\n",
+        )
+        .unwrap();
+        let second_commit_id = repo.add_all()?.commit("feat: add model")?;
+
+        let changed = repo.get_files_changed_between(&first_commit_id, &second_commit_id)?;
+        let model = changed.iter().find(|file| file.path == "model.bin").unwrap();
+        assert_eq!(model.kind, GitFileKind::LfsPointer);
+        assert!(model.is_lfs_pointer());
+        let attributes = changed.iter().find(|file| file.path == ".gitattributes").unwrap();
+        assert_eq!(attributes.kind, GitFileKind::Regular);
+
+        // Working copies of LFS files hold real content, so status relies on attributes
+        std::fs::write(workspace_path.join("model.bin"), [0u8, 159, 146, 150]).unwrap();
+        let status = repo.get_status_detailed()?;
+        let model = status.iter().find(|file| file.path == "model.bin").unwrap();
+        assert!(model.is_lfs_pointer());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_changed_files_detect_submodules() -> Result<(), RepoError> {
+        let workspace = TestWorkspace::new().unwrap();
+        let workspace_path = workspace.path();
+        let repo = Repo::create(workspace_path.display().to_string().as_str())?;
+        repo.config("Sublime Git Bot", "git-boot@websublime.com")?;
+
+        std::fs::write(workspace_path.join("README.md"), "Hello, world!").unwrap();
+        let first_commit_id = repo.add_all()?.commit("feat: add README.md")?;
+
+        // Record a gitlink, the tree entry Git uses for a submodule
+        let mut index = repo.repo.index().unwrap();
+        index
+            .add(&git2::IndexEntry {
+                ctime: git2::IndexTime::new(0, 0),
+                mtime: git2::IndexTime::new(0, 0),
+                dev: 0,
+                ino: 0,
+                mode: 0o160_000,
+                uid: 0,
+                gid: 0,
+                file_size: 0,
+                id: git2::Oid::from_str(&first_commit_id).unwrap(),
+                flags: 0,
+                flags_extended: 0,
+                path: b"packages/core/vendor/lib".to_vec(),
+            })
+            .unwrap();
+        index.write().unwrap();
+        let second_commit_id = repo.commit("feat: add submodule")?;
+
+        let changed = repo.get_files_changed_between(&first_commit_id, &second_commit_id)?;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].path, "packages/core/vendor/lib");
+        assert_eq!(changed[0].kind, GitFileKind::Submodule);
+        assert!(changed[0].is_submodule());
+
+        let in_commit = repo.get_files_changed_in_commit(&second_commit_id)?;
+        assert!(in_commit[0].is_submodule());
+
+        Ok(())
+    }
 }
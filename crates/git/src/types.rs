@@ -1,5 +1,7 @@
-use git2::{Error as Git2Error, Repository};
+use git2::{Commit, Error as Git2Error, Oid, Repository, Revwalk};
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
+use std::sync::Mutex;
 use thiserror::Error;
 
 /// Represents a Git repository with high-level operation methods
@@ -22,9 +24,134 @@ use thiserror::Error;
 /// repo.add_all().expect("Failed to stage changes");
 /// let commit_id = repo.commit("feat: add new feature").expect("Failed to commit");
 /// ```
+// Allow struct_field_names: `repo` is the wrapped libgit2 repository, named after what it holds
+#[allow(clippy::struct_field_names)]
 pub struct Repo {
     pub(crate) repo: Repository,
     pub(crate) local_path: PathBuf,
+    pub(crate) commit_cache: Mutex<CommitCache>,
+}
+
+/// History walks that can be answered from the commit cache
+///
+/// Each walk formats its commits differently, so their results are cached separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum CommitWalk {
+    /// `get_commits_since`: every commit, newest first, dated in RFC 2822
+    Since,
+    /// `get_commits_between`: every commit, newest first, dated in RFC 3339
+    Between,
+    /// First-parent history with merges expanded into the commits they merged
+    FirstParent,
+}
+
+/// Commits of a cached walk, with their ids, in walk order
+pub(crate) type CachedWalk = Vec<(Oid, RepoCommit)>;
+
+/// In-memory cache of commit history shared by the since and between queries
+///
+/// Walk results are keyed by walk, tip and boundary commit, so queries over the same range
+/// for different paths reuse one walk. The paths each commit changed are keyed by commit
+/// and computed once, however many path filters are checked against them. Walks are
+/// dropped when HEAD moves to a new commit.
+#[derive(Debug, Default)]
+pub(crate) struct CommitCache {
+    /// HEAD the cached walks were computed against
+    pub(crate) head: Option<Oid>,
+    /// Commits of each walk, in walk order
+    pub(crate) walks: HashMap<(CommitWalk, Oid, Option<Oid>), CachedWalk>,
+    /// Paths changed by each commit, compared with its first parent
+    pub(crate) paths: HashMap<Oid, Vec<String>>,
+}
+
+/// Describes a range of commit history to iterate with [`Repo::history`]
+///
+/// By default the whole history reachable from `HEAD` is walked, newest first.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_git_tools::HistoryQuery;
+///
+/// // The 50 most recent first-parent commits touching `packages/core` since v1.0.0
+/// let query = HistoryQuery::new()
+///     .since("v1.0.0")
+///     .path("packages/core")
+///     .first_parent()
+///     .limit(50);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryQuery {
+    /// Reference whose history is excluded, if any
+    pub since: Option<String>,
+    /// Reference the walk starts from, `HEAD` when not set
+    pub until: Option<String>,
+    /// Path the commits must touch, relative to the repository root
+    pub path: Option<String>,
+    /// Maximum number of commits to yield
+    pub limit: Option<usize>,
+    /// Whether to follow first parents, expanding merges into the commits they merged
+    pub first_parent: bool,
+}
+
+impl HistoryQuery {
+    /// Creates a query for the whole history reachable from `HEAD`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Excludes the history reachable from `since` (branch, tag, or commit SHA)
+    #[must_use]
+    pub fn since(mut self, since: impl Into<String>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Starts the walk from `until` instead of `HEAD`
+    #[must_use]
+    pub fn until(mut self, until: impl Into<String>) -> Self {
+        self.until = Some(until.into());
+        self
+    }
+
+    /// Only yields commits touching `path`
+    #[must_use]
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Stops after `limit` commits
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Follows first parents, replacing merge commits by the commits they merged
+    #[must_use]
+    pub fn first_parent(mut self) -> Self {
+        self.first_parent = true;
+        self
+    }
+}
+
+/// Lazy iterator over commit history, created by [`Repo::history`]
+///
+/// Commits are read from the object database one at a time as the iterator advances, so
+/// memory use does not grow with the length of the history. Author dates are RFC 3339.
+/// Iteration stops after the first error.
+pub struct CommitHistory<'a> {
+    pub(crate) repo: &'a Repo,
+    pub(crate) revwalk: Revwalk<'a>,
+    pub(crate) boundary: Option<Oid>,
+    pub(crate) first_parent: bool,
+    /// Commits merged by the last merge commit, not yet yielded
+    pub(crate) pending: VecDeque<Commit<'a>>,
+    pub(crate) path: Option<String>,
+    pub(crate) remaining: Option<usize>,
+    pub(crate) failed: bool,
 }
 
 /// Represents the status of a file in Git
@@ -53,18 +180,44 @@ pub enum GitFileStatus {
     Untracked,
 }
 
+/// Represents what kind of entry a changed path is
+///
+/// Most changed paths are regular files. Submodules are recorded by Git as a commit
+/// pointer, and Git LFS files are stored as small text pointers to the real content,
+/// so neither has meaningful line-level content to diff.
+///
+/// # Examples
+///
+/// ```
+/// use git::repo::GitFileKind;
+///
+/// let kind = GitFileKind::default();
+/// assert_eq!(kind, GitFileKind::Regular);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum GitFileKind {
+    /// A regular file, symlink, or directory entry
+    #[default]
+    Regular,
+    /// A submodule whose recorded commit changed
+    Submodule,
+    /// A file tracked by Git LFS, stored in the repository as a pointer
+    LfsPointer,
+}
+
 /// Represents a changed file in the Git repository with staging information
 ///
 /// # Examples
 ///
 /// ```
-/// use git::repo::{GitChangedFile, GitFileStatus};
+/// use git::repo::{GitChangedFile, GitFileKind, GitFileStatus};
 ///
 /// let file = GitChangedFile {
 ///     path: "src/main.rs".to_string(),
 ///     status: GitFileStatus::Modified,
 ///     staged: true,
 ///     workdir: false,
+///     kind: GitFileKind::Regular,
 /// };
 ///
 /// println!("Changed file: {} ({})", file.path,
@@ -90,6 +243,22 @@ pub struct GitChangedFile {
     pub staged: bool,
     /// Whether the file has changes in the working directory
     pub workdir: bool,
+    /// Whether the path is a regular file, a submodule, or a Git LFS pointer
+    pub kind: GitFileKind,
+}
+
+impl GitChangedFile {
+    /// Returns whether the path is a submodule
+    #[must_use]
+    pub fn is_submodule(&self) -> bool {
+        self.kind == GitFileKind::Submodule
+    }
+
+    /// Returns whether the path is a file tracked by Git LFS
+    #[must_use]
+    pub fn is_lfs_pointer(&self) -> bool {
+        self.kind == GitFileKind::LfsPointer
+    }
 }
 
 /// Represents diff statistics for a file
@@ -325,4 +494,31 @@ pub enum RepoError {
     /// Failed due to merge conflicts
     #[error("Failed on merge conflict: {0}")]
     MergeConflictError(#[source] Git2Error),
+
+    /// Failed on worktree operations
+    #[error("Failed on worktree: {0}")]
+    WorktreeError(#[source] Git2Error),
+
+    /// Attempted a mutating operation while read-only mode is active
+    #[error("Git operation '{operation}' on '{path}' blocked: repository is in read-only mode")]
+    ReadOnly {
+        /// The blocked operation
+        operation: String,
+        /// The repository (or target) path that would have been modified
+        path: PathBuf,
+    },
+
+    /// Attempted a remote operation while offline mode is active
+    #[error("Git operation '{operation}' blocked: offline mode is enabled")]
+    Offline {
+        /// The blocked operation
+        operation: String,
+    },
+
+    /// A remote operation was stopped, or not started, because the process deadline passed
+    #[error("Git operation '{operation}' exceeded its deadline")]
+    DeadlineExceeded {
+        /// The stopped operation
+        operation: String,
+    },
 }
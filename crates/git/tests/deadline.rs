@@ -0,0 +1,51 @@
+//! # Process-Wide Deadline
+//!
+//! Verifies that once the deadline set with `Repo::set_deadline` has passed, every remote
+//! operation fails with `RepoError::DeadlineExceeded` while local operations keep working.
+//! The deadline is global, so it is tested in its own test binary.
+
+#![allow(clippy::unwrap_used)]
+
+use std::time::{Duration, Instant};
+use sublime_git_tools::{Repo, RepoError};
+
+#[test]
+fn test_passed_deadline_blocks_remote_operations() {
+    let remote_dir = tempfile::tempdir().unwrap();
+    let remote = Repo::create(remote_dir.path().to_str().unwrap()).unwrap();
+    remote.config("Test User", "test@example.com").unwrap();
+    std::fs::write(remote_dir.path().join("README.md"), "# Test\n").unwrap();
+    remote.add_all().unwrap();
+    remote.commit("chore: initial commit").unwrap();
+
+    // A deadline in the future does not get in the way
+    Repo::set_deadline(Some(Instant::now() + Duration::from_secs(600)));
+    let clone_dir = tempfile::tempdir().unwrap();
+    let repo = Repo::clone(remote_dir.path().to_str().unwrap(), clone_dir.path().to_str().unwrap())
+        .unwrap();
+    repo.config("Test User", "test@example.com").unwrap();
+    assert!(repo.fetch("origin", None, false).is_ok());
+
+    Repo::set_deadline(Some(Instant::now()));
+
+    let fetch = repo.fetch("origin", None, false);
+    let pull = repo.pull("origin", None);
+    let push = repo.push("origin", None);
+    let tags = repo.get_remote_or_local_tags(Some(false));
+    let other_dir = tempfile::tempdir().unwrap();
+    let clone =
+        Repo::clone(remote_dir.path().to_str().unwrap(), other_dir.path().to_str().unwrap());
+    let branch = repo.create_branch("feature");
+
+    Repo::set_deadline(None);
+
+    assert!(
+        matches!(fetch, Err(RepoError::DeadlineExceeded { ref operation }) if operation == "fetch")
+    );
+    assert!(matches!(pull, Err(RepoError::DeadlineExceeded { .. })));
+    assert!(matches!(push, Err(RepoError::DeadlineExceeded { .. })));
+    assert!(matches!(tags, Err(RepoError::DeadlineExceeded { .. })));
+    assert!(matches!(clone, Err(RepoError::DeadlineExceeded { .. })));
+    assert!(branch.is_ok());
+    assert!(repo.fetch("origin", None, false).is_ok());
+}
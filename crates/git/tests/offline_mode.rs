@@ -0,0 +1,43 @@
+//! # Process-Wide Offline Mode
+//!
+//! Verifies that enabling `Repo::set_offline_mode` blocks every remote operation with
+//! `RepoError::Offline` while local operations keep working. The mode is global, so it is
+//! tested in its own test binary.
+
+#![allow(clippy::unwrap_used)]
+
+use sublime_git_tools::{Repo, RepoError};
+
+#[test]
+fn test_offline_mode_blocks_remote_operations() {
+    let remote_dir = tempfile::tempdir().unwrap();
+    let remote = Repo::create(remote_dir.path().to_str().unwrap()).unwrap();
+    remote.config("Test User", "test@example.com").unwrap();
+    std::fs::write(remote_dir.path().join("README.md"), "# Test\n").unwrap();
+    remote.add_all().unwrap();
+    remote.commit("chore: initial commit").unwrap();
+
+    let clone_dir = tempfile::tempdir().unwrap();
+    let repo = Repo::clone(remote_dir.path().to_str().unwrap(), clone_dir.path().to_str().unwrap())
+        .unwrap();
+    repo.config("Test User", "test@example.com").unwrap();
+
+    Repo::set_offline_mode(true);
+
+    let fetch = repo.fetch("origin", None, false);
+    let pull = repo.pull("origin", None);
+    let push = repo.push("origin", None);
+    let other_dir = tempfile::tempdir().unwrap();
+    let clone =
+        Repo::clone(remote_dir.path().to_str().unwrap(), other_dir.path().to_str().unwrap());
+    let branch = repo.create_branch("feature");
+
+    Repo::set_offline_mode(false);
+
+    assert!(matches!(fetch, Err(RepoError::Offline { ref operation }) if operation == "fetch"));
+    assert!(matches!(pull, Err(RepoError::Offline { .. })));
+    assert!(matches!(push, Err(RepoError::Offline { .. })));
+    assert!(matches!(clone, Err(RepoError::Offline { .. })));
+    assert!(branch.is_ok());
+    assert!(repo.fetch("origin", None, false).is_ok());
+}
@@ -0,0 +1,45 @@
+//! # Process-Wide Read-Only Mode
+//!
+//! Verifies that enabling `Repo::set_read_only_mode` blocks every mutating repository
+//! operation with `RepoError::ReadOnly` while reads keep working. The mode is global,
+//! so it is tested in its own test binary.
+
+#![allow(clippy::unwrap_used)]
+
+use sublime_git_tools::{Repo, RepoError};
+
+#[test]
+fn test_read_only_mode_blocks_mutations() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let path = temp_dir.path().to_str().unwrap();
+    let repo = Repo::create(path).unwrap();
+    repo.config("Test User", "test@example.com").unwrap();
+    std::fs::write(temp_dir.path().join("README.md"), "# Test\n").unwrap();
+    repo.add_all().unwrap();
+    repo.commit("chore: initial commit").unwrap();
+
+    Repo::set_read_only_mode(true);
+
+    let tag = repo.create_tag("v1.0.0", None);
+    let branch = repo.create_branch("feature");
+    let config = repo.set_config_value("core.hooksPath", ".githooks");
+    let commit = repo.commit("chore: blocked");
+    let sha = repo.get_current_sha();
+    let other_dir = tempfile::tempdir().unwrap();
+    let create = Repo::create(other_dir.path().to_str().unwrap());
+
+    Repo::set_read_only_mode(false);
+
+    assert!(
+        matches!(tag, Err(RepoError::ReadOnly { ref operation, .. }) if operation == "create tag")
+    );
+    assert!(matches!(branch, Err(RepoError::ReadOnly { .. })));
+    assert!(matches!(config, Err(RepoError::ReadOnly { .. })));
+    assert!(matches!(commit, Err(RepoError::ReadOnly { .. })));
+    assert!(matches!(create, Err(RepoError::ReadOnly { .. })));
+    assert!(sha.is_ok());
+
+    assert!(repo.get_config_value("core.hooksPath").unwrap().is_none());
+    assert!(!repo.branch_exists("feature").unwrap());
+    assert!(repo.create_tag("v1.0.0", None).is_ok());
+}
@@ -158,6 +158,7 @@ fn create_sample_report() -> AuditReport {
                     map.insert("upgrade_count".to_string(), "3".to_string());
                     map
                 },
+                fix: None,
             },
             AuditIssue {
                 severity: IssueSeverity::Critical,
@@ -169,6 +170,7 @@ fn create_sample_report() -> AuditReport {
                     "Replace deprecated packages with modern alternatives".to_string(),
                 ),
                 metadata: HashMap::new(),
+                fix: None,
             },
         ],
     };
@@ -177,6 +179,7 @@ fn create_sample_report() -> AuditReport {
     let dependencies = DependencyAuditSection {
         circular_dependencies: vec![],
         version_conflicts: vec![],
+        package_collisions: vec![],
         issues: vec![AuditIssue {
             severity: IssueSeverity::Warning,
             category: IssueCategory::Dependencies,
@@ -185,6 +188,7 @@ fn create_sample_report() -> AuditReport {
             affected_packages: vec!["pkg-a".to_string(), "pkg-b".to_string()],
             suggestion: Some("Align all packages to use the same version of lodash".to_string()),
             metadata: HashMap::new(),
+            fix: None,
         }],
     };
 
@@ -238,6 +242,7 @@ fn create_sample_report() -> AuditReport {
             affected_packages: vec!["pkg-a".to_string(), "pkg-b".to_string(), "pkg-c".to_string()],
             suggestion: Some("Use ^4.17.21 across all packages".to_string()),
             metadata: HashMap::new(),
+            fix: None,
         }],
     };
 
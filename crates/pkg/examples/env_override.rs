@@ -194,6 +194,7 @@ fn print_detailed_config(config: &PackageToolsConfig) {
     println!("    check_missing: {}", config.audit.dependencies.check_missing);
     println!("    check_unused: {}", config.audit.dependencies.check_unused);
     println!("    check_version_conflicts: {}", config.audit.dependencies.check_version_conflicts);
+    println!("    check_collisions: {}", config.audit.dependencies.check_collisions);
     println!("\n  [Breaking Changes]");
     println!(
         "    check_conventional_commits: {}",
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Err(violation) = sublime_pkg_tools::fuzz::version_bump(data) {
+        panic!("{violation}");
+    }
+});
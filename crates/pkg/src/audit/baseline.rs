@@ -0,0 +1,248 @@
+//! Baselines of known audit findings.
+//!
+//! **What**: Defines `AuditBaseline`, a file of audit findings that are known and accepted,
+//! and `BaselineComparison`, how the findings of a run compare with it.
+//!
+//! **How**: Each `BaselineEntry` records the fingerprint of a finding (see
+//! [`AuditIssue::fingerprint`]) with its severity, category, title and packages for review, and
+//! optionally a date after which the suppression expires and a reason. A finding is suppressed
+//! while a matching entry has not expired. Rewriting a baseline keeps the expiry and reason of
+//! entries whose findings are still present and drops the rest.
+//!
+//! **Why**: Turning audits on in CI for an existing workspace would fail every build on
+//! findings nobody is about to fix. A baseline lets CI fail only on new findings, while expiry
+//! dates keep accepted findings from being suppressed forever.
+
+use crate::audit::issue::{AuditIssue, IssueCategory, IssueSeverity};
+use crate::error::{AuditError, AuditResult};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Default file name of the audit baseline, relative to the workspace root.
+pub const DEFAULT_BASELINE_FILE: &str = ".audit-baseline.json";
+
+/// Known audit findings that do not fail an audit.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::{AuditBaseline, AuditIssue, IssueCategory, IssueSeverity};
+/// use chrono::NaiveDate;
+///
+/// let known = AuditIssue::new(
+///     IssueSeverity::Warning,
+///     IssueCategory::Dependencies,
+///     "Circular dependency".to_string(),
+///     "a -> b -> a".to_string(),
+/// );
+/// let baseline = AuditBaseline::from_issues([&known]);
+///
+/// let new = AuditIssue::new(
+///     IssueSeverity::Critical,
+///     IssueCategory::Security,
+///     "Vulnerable dependency".to_string(),
+///     "lodash is vulnerable".to_string(),
+/// );
+/// let today = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+/// let comparison = baseline.compare([&known, &new], today);
+///
+/// assert_eq!(comparison.suppressed, 1);
+/// assert_eq!(comparison.new_findings, 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditBaseline {
+    /// Known findings, sorted by fingerprint.
+    #[serde(default)]
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// A known audit finding recorded in a baseline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineEntry {
+    /// Fingerprint of the finding.
+    pub fingerprint: String,
+
+    /// Severity of the finding when it was recorded.
+    pub severity: IssueSeverity,
+
+    /// Category of the finding.
+    pub category: IssueCategory,
+
+    /// Title of the finding.
+    pub title: String,
+
+    /// Packages affected by the finding.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+
+    /// Last day the finding is suppressed, or `None` to suppress it indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<NaiveDate>,
+
+    /// Why the finding is accepted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// How the findings of an audit compare with a baseline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BaselineComparison {
+    /// Number of findings suppressed by the baseline.
+    pub suppressed: usize,
+
+    /// Number of findings not suppressed, including findings whose entry has expired.
+    pub new_findings: usize,
+
+    /// Entries that matched a finding but have expired.
+    pub expired: Vec<BaselineEntry>,
+
+    /// Entries that matched no finding and can be removed from the baseline.
+    pub stale: Vec<BaselineEntry>,
+}
+
+impl BaselineEntry {
+    /// Creates an entry for a finding, without expiry or reason.
+    #[must_use]
+    pub fn from_issue(issue: &AuditIssue) -> Self {
+        let mut packages = issue.affected_packages.clone();
+        packages.sort();
+        packages.dedup();
+
+        Self {
+            fingerprint: issue.fingerprint(),
+            severity: issue.severity,
+            category: issue.category,
+            title: issue.title.clone(),
+            packages,
+            expires: None,
+            reason: None,
+        }
+    }
+
+    /// Returns whether the suppression has expired on the given day.
+    #[must_use]
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires.is_some_and(|expires| expires < today)
+    }
+}
+
+impl AuditBaseline {
+    /// Creates a baseline recording the given findings.
+    #[must_use]
+    pub fn from_issues<'a>(issues: impl IntoIterator<Item = &'a AuditIssue>) -> Self {
+        Self::default().updated(issues, None)
+    }
+
+    /// Returns a baseline recording the given findings, keeping what is known about them.
+    ///
+    /// Findings already in this baseline keep their expiry date and reason; new findings get
+    /// `expires`. Entries whose finding is no longer present are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `issues` - The current findings
+    /// * `expires` - Expiry date of the entries added for new findings
+    #[must_use]
+    pub fn updated<'a>(
+        &self,
+        issues: impl IntoIterator<Item = &'a AuditIssue>,
+        expires: Option<NaiveDate>,
+    ) -> Self {
+        let mut entries = BTreeMap::new();
+        for issue in issues {
+            let fingerprint = issue.fingerprint();
+            if entries.contains_key(&fingerprint) {
+                continue;
+            }
+            let entry = self
+                .entry(&fingerprint)
+                .cloned()
+                .unwrap_or_else(|| BaselineEntry { expires, ..BaselineEntry::from_issue(issue) });
+            entries.insert(fingerprint, entry);
+        }
+
+        Self { entries: entries.into_values().collect() }
+    }
+
+    /// Returns the entry with the given fingerprint.
+    #[must_use]
+    pub fn entry(&self, fingerprint: &str) -> Option<&BaselineEntry> {
+        self.entries.iter().find(|entry| entry.fingerprint == fingerprint)
+    }
+
+    /// Returns whether a finding is suppressed on the given day.
+    #[must_use]
+    pub fn suppresses(&self, issue: &AuditIssue, today: NaiveDate) -> bool {
+        self.entry(&issue.fingerprint()).is_some_and(|entry| !entry.is_expired(today))
+    }
+
+    /// Compares the findings of an audit with this baseline.
+    ///
+    /// # Arguments
+    ///
+    /// * `issues` - Every finding of the audit
+    /// * `today` - Day used to decide which entries have expired
+    #[must_use]
+    pub fn compare<'a>(
+        &self,
+        issues: impl IntoIterator<Item = &'a AuditIssue>,
+        today: NaiveDate,
+    ) -> BaselineComparison {
+        let mut comparison = BaselineComparison::default();
+        let mut matched = HashSet::new();
+
+        for issue in issues {
+            let fingerprint = issue.fingerprint();
+            match self.entry(&fingerprint) {
+                Some(entry) if !entry.is_expired(today) => comparison.suppressed += 1,
+                Some(entry) => {
+                    comparison.new_findings += 1;
+                    if !matched.contains(&fingerprint) {
+                        comparison.expired.push(entry.clone());
+                    }
+                }
+                None => comparison.new_findings += 1,
+            }
+            matched.insert(fingerprint);
+        }
+
+        comparison.stale = self
+            .entries
+            .iter()
+            .filter(|entry| !matched.contains(&entry.fingerprint))
+            .cloned()
+            .collect();
+
+        comparison
+    }
+
+    /// Loads a baseline file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::InvalidBaseline` if the file cannot be read or parsed.
+    pub async fn load<F: AsyncFileSystem>(fs: &F, path: &Path) -> AuditResult<Self> {
+        let invalid =
+            |reason: String| AuditError::InvalidBaseline { path: path.to_path_buf(), reason };
+        let content = fs.read_file_string(path).await.map_err(|e| invalid(e.to_string()))?;
+        serde_json::from_str(&content).map_err(|e| invalid(e.to_string()))
+    }
+
+    /// Writes this baseline to a file, replacing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::FileSystemError` if the file cannot be written.
+    pub async fn save<F: AsyncFileSystem>(&self, fs: &F, path: &Path) -> AuditResult<()> {
+        let fs_error =
+            |reason: String| AuditError::FileSystemError { path: path.to_path_buf(), reason };
+        let content = serde_json::to_string_pretty(self).map_err(|e| fs_error(e.to_string()))?;
+        fs.write_file_string(path, &format!("{content}\n"))
+            .await
+            .map_err(|e| fs_error(e.to_string()))
+    }
+}
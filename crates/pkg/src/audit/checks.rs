@@ -0,0 +1,285 @@
+//! Custom audit checks and their registry.
+//!
+//! **What**: Defines the `AuditCheck` trait implemented by consumer-provided checks,
+//! `AuditCheckContext`, the workspace data a check runs against, `AuditFinding`, what a check
+//! reports, and `AuditCheckRegistry`, which holds the registered checks.
+//!
+//! **How**: Checks are registered on an `AuditManager` and run alongside the built-in checks
+//! by `AuditManager::check_stream`. Findings are turned into `AuditIssue`s with the check's
+//! category and severity, so they are filtered, scored, and reported like built-in issues.
+//! `audit.checks.<id>` in the configuration can disable a check or override its severity.
+//!
+//! **Why**: To let teams enforce their own policies (naming rules, internal registry rules)
+//! in the same audit run and report as the built-in checks.
+
+use crate::audit::fix::Fix;
+use crate::audit::issue::{AuditIssue, IssueCategory, IssueSeverity};
+use crate::audit::runner::BuiltinCheck;
+use crate::config::PackageToolsConfig;
+use crate::error::{AuditError, AuditResult};
+use crate::types::PackageInfo;
+use async_trait::async_trait;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A custom audit check.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use sublime_pkg_tools::audit::{
+///     AuditCheck, AuditCheckContext, AuditFinding, IssueCategory, IssueSeverity,
+/// };
+/// use sublime_pkg_tools::error::AuditResult;
+///
+/// struct ScopedNames;
+///
+/// #[async_trait]
+/// impl AuditCheck for ScopedNames {
+///     fn id(&self) -> &str {
+///         "scoped-names"
+///     }
+///
+///     fn category(&self) -> IssueCategory {
+///         IssueCategory::Other
+///     }
+///
+///     fn severity(&self) -> IssueSeverity {
+///         IssueSeverity::Warning
+///     }
+///
+///     async fn run(&self, context: &AuditCheckContext) -> AuditResult<Vec<AuditFinding>> {
+///         Ok(context
+///             .packages()
+///             .iter()
+///             .filter(|package| !package.name().starts_with("@myorg/"))
+///             .map(|package| {
+///                 AuditFinding::new("Unscoped package", "Packages must use the @myorg scope")
+///                     .with_package(package.name())
+///             })
+///             .collect())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait AuditCheck: Send + Sync {
+    /// Returns the unique id of this check, used in reports and as its configuration key.
+    fn id(&self) -> &str;
+
+    /// Returns the category of the issues this check reports.
+    fn category(&self) -> IssueCategory;
+
+    /// Returns the severity of the issues this check reports, unless overridden in
+    /// `audit.checks.<id>.severity`.
+    fn severity(&self) -> IssueSeverity;
+
+    /// Runs the check against the workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the check cannot run. A check that runs and finds problems
+    /// returns them as findings instead.
+    async fn run(&self, context: &AuditCheckContext) -> AuditResult<Vec<AuditFinding>>;
+}
+
+/// Workspace data shared by the custom checks of an audit run.
+#[derive(Debug)]
+pub struct AuditCheckContext {
+    workspace_root: PathBuf,
+    config: PackageToolsConfig,
+    packages: Vec<PackageInfo>,
+}
+
+impl AuditCheckContext {
+    /// Creates a context for the given workspace.
+    ///
+    /// `AuditManager` builds the context itself; this constructor lets checks be tested on
+    /// their own.
+    #[must_use]
+    pub fn new(
+        workspace_root: PathBuf,
+        config: PackageToolsConfig,
+        packages: Vec<PackageInfo>,
+    ) -> Self {
+        Self { workspace_root, config, packages }
+    }
+
+    /// Returns the root directory of the audited workspace.
+    #[must_use]
+    pub fn workspace_root(&self) -> &Path {
+        &self.workspace_root
+    }
+
+    /// Returns the configuration of the audit run.
+    #[must_use]
+    pub fn config(&self) -> &PackageToolsConfig {
+        &self.config
+    }
+
+    /// Returns the packages of the workspace.
+    #[must_use]
+    pub fn packages(&self) -> &[PackageInfo] {
+        &self.packages
+    }
+}
+
+/// A problem reported by a custom check.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::AuditFinding;
+///
+/// let finding = AuditFinding::new("Missing license", "Every package needs a license field")
+///     .with_package("@myorg/core")
+///     .with_suggestion("Add \"license\": \"MIT\" to package.json");
+///
+/// assert_eq!(finding.affected_packages, vec!["@myorg/core".to_string()]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditFinding {
+    /// Brief title describing the problem.
+    pub title: String,
+    /// Detailed description of the problem.
+    pub description: String,
+    /// Names of the packages affected by the problem.
+    pub affected_packages: Vec<String>,
+    /// Optional suggested action to resolve the problem.
+    pub suggestion: Option<String>,
+    /// Optional file edits that resolve the problem.
+    pub fix: Option<Fix>,
+}
+
+impl AuditFinding {
+    /// Creates a finding with no affected packages or suggestion.
+    #[must_use]
+    pub fn new(title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: description.into(),
+            affected_packages: Vec::new(),
+            suggestion: None,
+            fix: None,
+        }
+    }
+
+    /// Adds an affected package.
+    #[must_use]
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.affected_packages.push(package.into());
+        self
+    }
+
+    /// Sets the suggested action.
+    #[must_use]
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Sets the file edits that resolve the problem.
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix) -> Self {
+        self.fix = Some(fix);
+        self
+    }
+
+    /// Converts the finding into an issue reported by the given check.
+    ///
+    /// The issue records the check id in its `check` metadata.
+    pub(crate) fn into_issue(
+        self,
+        check_id: &str,
+        category: IssueCategory,
+        severity: IssueSeverity,
+    ) -> AuditIssue {
+        let mut issue = AuditIssue::new(severity, category, self.title, self.description);
+        issue.affected_packages = self.affected_packages;
+        issue.suggestion = self.suggestion;
+        issue.fix = self.fix;
+        issue.add_metadata("check".to_string(), check_id.to_string());
+        issue
+    }
+}
+
+/// The result of a custom check that ran successfully.
+#[derive(Debug, Clone)]
+pub struct CustomCheckSection {
+    /// Id of the check.
+    pub id: String,
+    /// Category of the check's issues.
+    pub category: IssueCategory,
+    /// Issues reported by the check.
+    pub issues: Vec<AuditIssue>,
+}
+
+/// Registry of custom audit checks.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::audit::AuditCheckRegistry;
+///
+/// let mut registry = AuditCheckRegistry::new();
+/// registry.register(ScopedNames)?;
+///
+/// assert_eq!(registry.ids(), vec!["scoped-names"]);
+/// ```
+#[derive(Default)]
+pub struct AuditCheckRegistry {
+    checks: Vec<Box<dyn AuditCheck>>,
+}
+
+impl AuditCheckRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom check.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::DuplicateCheck` if a check with the same id is already
+    /// registered or the id belongs to a built-in check.
+    pub fn register(&mut self, check: impl AuditCheck + 'static) -> AuditResult<&mut Self> {
+        if BuiltinCheck::from_id(check.id()).is_some() || self.get(check.id()).is_some() {
+            return Err(AuditError::DuplicateCheck { id: check.id().to_string() });
+        }
+
+        self.checks.push(Box::new(check));
+        Ok(self)
+    }
+
+    /// Returns the registered check with the given id.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&dyn AuditCheck> {
+        self.checks.iter().map(AsRef::as_ref).find(|check| check.id() == id)
+    }
+
+    /// Returns the ids of the registered checks, in registration order.
+    #[must_use]
+    pub fn ids(&self) -> Vec<&str> {
+        self.checks.iter().map(|check| check.id()).collect()
+    }
+
+    /// Returns the number of registered checks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Returns whether no check is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty()
+    }
+}
+
+impl fmt::Debug for AuditCheckRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuditCheckRegistry").field("checks", &self.ids()).finish()
+    }
+}
@@ -0,0 +1,165 @@
+//! Workspace health dashboard data.
+//!
+//! **What**: Defines `WorkspaceDashboard`, a single payload summarising the state of a
+//! workspace: package count, packages with uncommitted changes, pending changesets, outdated
+//! dependencies by severity, and dependency graph metrics.
+//!
+//! **How**: `AuditManager::dashboard` gathers the data from the subsystems it already owns
+//! (changes analyzer, changeset manager, upgrade manager, dependency graph) and assembles it
+//! with the constructors in this module.
+//!
+//! **Why**: Dashboards such as monitors and bindings need all of these numbers at once.
+//! Producing them in one call avoids N round trips and keeps the aggregation logic in one place.
+
+use crate::audit::sections::UpgradeAuditSection;
+use crate::types::Changeset;
+use crate::version::DependencyGraph;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Aggregated workspace health data for dashboards.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::audit::AuditManager;
+///
+/// let dashboard = audit_manager.dashboard().await?;
+///
+/// println!("{} packages, {} dirty", dashboard.package_count, dashboard.dirty_packages.len());
+/// if let Some(age) = dashboard.oldest_changeset_age() {
+///     println!("Oldest pending changeset: {} days", age.as_secs() / 86_400);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceDashboard {
+    /// When the dashboard data was collected.
+    pub generated_at: DateTime<Utc>,
+
+    /// Workspace root path.
+    pub workspace_root: PathBuf,
+
+    /// Whether this is a monorepo project.
+    pub is_monorepo: bool,
+
+    /// Number of packages in the workspace.
+    pub package_count: usize,
+
+    /// Names of packages with uncommitted changes, sorted alphabetically.
+    pub dirty_packages: Vec<String>,
+
+    /// Pending (unreleased) changeset statistics.
+    pub changesets: PendingChangesetStats,
+
+    /// Outdated dependency counts, or `None` when the upgrades audit section is disabled.
+    pub outdated: Option<OutdatedDependencyCounts>,
+
+    /// Internal dependency graph metrics.
+    pub graph: GraphMetrics,
+}
+
+impl WorkspaceDashboard {
+    /// Returns how long the oldest pending changeset has been waiting for a release.
+    ///
+    /// Returns `None` when there are no pending changesets.
+    #[must_use]
+    pub fn oldest_changeset_age(&self) -> Option<Duration> {
+        self.changesets.oldest_age_secs.map(Duration::from_secs)
+    }
+
+    /// Returns `true` if any package has uncommitted changes.
+    #[must_use]
+    pub fn has_dirty_packages(&self) -> bool {
+        !self.dirty_packages.is_empty()
+    }
+}
+
+/// Statistics about changesets waiting to be released.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingChangesetStats {
+    /// Number of pending changesets.
+    pub count: usize,
+
+    /// Creation time of the oldest pending changeset.
+    pub oldest_created_at: Option<DateTime<Utc>>,
+
+    /// Age of the oldest pending changeset in seconds, relative to `generated_at`.
+    pub oldest_age_secs: Option<u64>,
+}
+
+impl PendingChangesetStats {
+    /// Computes the statistics for a set of pending changesets.
+    ///
+    /// # Arguments
+    ///
+    /// * `changesets` - The pending changesets
+    /// * `now` - The reference time used to compute ages
+    #[must_use]
+    pub fn from_changesets(changesets: &[Changeset], now: DateTime<Utc>) -> Self {
+        let oldest_created_at = changesets.iter().map(|c| c.created_at).min();
+        let oldest_age_secs = oldest_created_at
+            .map(|created| u64::try_from((now - created).num_seconds()).unwrap_or(0));
+
+        Self { count: changesets.len(), oldest_created_at, oldest_age_secs }
+    }
+}
+
+/// Outdated external dependency counts by upgrade severity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutdatedDependencyCounts {
+    /// Dependencies with a major upgrade available.
+    pub major: usize,
+
+    /// Dependencies with a minor upgrade available.
+    pub minor: usize,
+
+    /// Dependencies with a patch upgrade available.
+    pub patch: usize,
+
+    /// Dependencies marked as deprecated in the registry.
+    pub deprecated: usize,
+}
+
+impl OutdatedDependencyCounts {
+    /// Returns the total number of outdated dependencies.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.major + self.minor + self.patch
+    }
+}
+
+impl From<&UpgradeAuditSection> for OutdatedDependencyCounts {
+    fn from(section: &UpgradeAuditSection) -> Self {
+        Self {
+            major: section.major_upgrades,
+            minor: section.minor_upgrades,
+            patch: section.patch_upgrades,
+            deprecated: section.deprecated_packages.len(),
+        }
+    }
+}
+
+/// Metrics describing the internal dependency graph.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphMetrics {
+    /// Number of internal dependency edges.
+    pub edge_count: usize,
+
+    /// Length of the longest dependency chain, with cycles collapsed.
+    pub max_depth: usize,
+
+    /// Number of circular dependency groups.
+    pub cycle_count: usize,
+}
+
+impl From<&DependencyGraph> for GraphMetrics {
+    fn from(graph: &DependencyGraph) -> Self {
+        Self {
+            edge_count: graph.edge_count(),
+            max_depth: graph.max_depth(),
+            cycle_count: graph.detect_cycles().len(),
+        }
+    }
+}
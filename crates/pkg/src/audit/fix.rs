@@ -0,0 +1,428 @@
+//! Fixes for audit issues.
+//!
+//! **What**: Defines `Fix`, the file edits that resolve an audit issue, `FixPlan`, the file
+//! changes of a set of fixes computed without writing anything, and `AuditFixer`, which plans
+//! fixes and applies a plan.
+//!
+//! **How**: Checks attach a `Fix` to the issues they can resolve. `AuditFixer::plan` reads each
+//! file once and applies the edits of every selected fix in order, so several fixes may edit
+//! the same file. The plan can be rendered as a unified diff for a dry run. `AuditFixer::apply`
+//! records an `audit-fix` transaction in the workspace transaction log (when backups are
+//! enabled), writes the files, and restores every file if any write fails.
+//!
+//! **Why**: To give every check one write path with previews and rollback, instead of each
+//! check rewriting files on its own.
+
+use crate::config::BackupConfig;
+use crate::error::{AuditError, AuditResult};
+use crate::upgrade::serialize_package_json;
+use crate::workspace::{OperationKind, TransactionLog};
+use package_json::PackageJson;
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// A change to one file, part of a [`Fix`].
+///
+/// Relative paths are resolved against the workspace root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum FileEdit {
+    /// Sets the version spec of a dependency in every dependency section of a `package.json`
+    /// that lists it.
+    SetDependency {
+        /// Path of the `package.json` file.
+        path: PathBuf,
+        /// Name of the dependency.
+        dependency: String,
+        /// Version spec to use, such as `^2.0.0` or `workspace:*`.
+        version_spec: String,
+    },
+
+    /// Replaces the first occurrence of `old` with `new`.
+    Replace {
+        /// Path of the file.
+        path: PathBuf,
+        /// Text expected in the file.
+        old: String,
+        /// Replacement text.
+        new: String,
+    },
+
+    /// Writes the whole file, creating it if it does not exist.
+    Write {
+        /// Path of the file.
+        path: PathBuf,
+        /// New content of the file.
+        content: String,
+    },
+}
+
+impl FileEdit {
+    /// Returns the path of the edited file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::SetDependency { path, .. }
+            | Self::Replace { path, .. }
+            | Self::Write { path, .. } => path,
+        }
+    }
+
+    /// Applies the edit to the current content of the file, `None` if it does not exist.
+    fn apply(&self, path: &Path, content: Option<&str>) -> AuditResult<String> {
+        let failed = |reason: String| AuditError::FixFailed { path: path.to_path_buf(), reason };
+
+        match self {
+            Self::Write { content, .. } => Ok(content.clone()),
+            Self::Replace { old, new, .. } => {
+                let content = content.ok_or_else(|| failed("file does not exist".to_string()))?;
+                if !content.contains(old.as_str()) {
+                    return Err(failed(format!("expected text not found: '{old}'")));
+                }
+                Ok(content.replacen(old.as_str(), new, 1))
+            }
+            Self::SetDependency { dependency, version_spec, .. } => {
+                let content = content.ok_or_else(|| failed("file does not exist".to_string()))?;
+                let mut manifest: PackageJson = serde_json::from_str(content)
+                    .map_err(|e| failed(format!("invalid package.json: {e}")))?;
+
+                let mut found = false;
+                for section in [
+                    &mut manifest.dependencies,
+                    &mut manifest.dev_dependencies,
+                    &mut manifest.peer_dependencies,
+                    &mut manifest.optional_dependencies,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    if let Some(spec) = section.get_mut(dependency) {
+                        spec.clone_from(version_spec);
+                        found = true;
+                    }
+                }
+                if !found {
+                    return Err(failed(format!("'{dependency}' is not a dependency")));
+                }
+
+                serialize_package_json(&manifest, content).map_err(|e| failed(e.to_string()))
+            }
+        }
+    }
+}
+
+/// The file edits that resolve an audit issue.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::{FileEdit, Fix};
+///
+/// let fix = Fix::new("Use workspace:* for @myorg/core").with_edit(FileEdit::SetDependency {
+///     path: "packages/app/package.json".into(),
+///     dependency: "@myorg/core".to_string(),
+///     version_spec: "workspace:*".to_string(),
+/// });
+///
+/// assert_eq!(fix.edits.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Fix {
+    /// What the fix changes, in one line.
+    pub description: String,
+
+    /// The edits, applied in order.
+    pub edits: Vec<FileEdit>,
+}
+
+impl Fix {
+    /// Creates a fix without edits.
+    #[must_use]
+    pub fn new(description: impl Into<String>) -> Self {
+        Self { description: description.into(), edits: Vec::new() }
+    }
+
+    /// Adds an edit.
+    #[must_use]
+    pub fn with_edit(mut self, edit: FileEdit) -> Self {
+        self.edits.push(edit);
+        self
+    }
+}
+
+/// The planned change of one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// Absolute path of the file.
+    pub path: PathBuf,
+
+    /// Content of the file when the plan was made, `None` if the fix creates it.
+    pub original: Option<String>,
+
+    /// Content of the file after the fixes.
+    pub updated: String,
+}
+
+impl FileChange {
+    /// Renders the change as a unified diff, with paths relative to `workspace_root`.
+    #[must_use]
+    pub fn diff(&self, workspace_root: &Path) -> String {
+        let relative = self.path.strip_prefix(workspace_root).unwrap_or(&self.path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let old_header =
+            if self.original.is_some() { format!("a/{relative}") } else { "/dev/null".to_string() };
+
+        TextDiff::from_lines(self.original.as_deref().unwrap_or_default(), self.updated.as_str())
+            .unified_diff()
+            .context_radius(3)
+            .header(&old_header, &format!("b/{relative}"))
+            .to_string()
+    }
+}
+
+/// The file changes of a set of fixes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixPlan {
+    /// Root of the workspace the plan was made for.
+    pub workspace_root: PathBuf,
+
+    /// Descriptions of the planned fixes, in order.
+    pub fixes: Vec<String>,
+
+    /// Files the fixes change, sorted by path. Fixes that leave a file as it is do not
+    /// list it.
+    pub files: Vec<FileChange>,
+}
+
+impl FixPlan {
+    /// Returns whether the plan changes no file.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Renders every file change as a unified diff.
+    #[must_use]
+    pub fn diff(&self) -> String {
+        self.files.iter().map(|file| file.diff(&self.workspace_root)).collect()
+    }
+}
+
+/// The result of applying a [`FixPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixOutcome {
+    /// Number of fixes applied.
+    pub fixes_applied: usize,
+
+    /// Absolute paths of the files written.
+    pub files: Vec<PathBuf>,
+
+    /// Transaction recorded for `workspace undo`, `None` when backups are disabled.
+    pub transaction_id: Option<String>,
+}
+
+/// Plans and applies fixes for audit issues.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::audit::{AuditFixer, AuditIssue};
+/// use sublime_pkg_tools::config::PackageToolsConfig;
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+/// use std::path::PathBuf;
+///
+/// # async fn example(issues: Vec<AuditIssue>) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = PackageToolsConfig::default();
+/// let fixer = AuditFixer::new(PathBuf::from("."), config.upgrade.backup, FileSystemManager::new());
+///
+/// let fixes: Vec<_> = issues.iter().filter_map(|issue| issue.fix.as_ref()).collect();
+/// let plan = fixer.plan(&fixes).await?;
+/// print!("{}", plan.diff());
+///
+/// let outcome = fixer.apply(&plan).await?;
+/// println!("Fixed {} file(s)", outcome.files.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AuditFixer<F: AsyncFileSystem + Clone + Send + Sync + 'static> {
+    workspace_root: PathBuf,
+    backup: BackupConfig,
+    fs: F,
+}
+
+impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> AuditFixer<F> {
+    /// Creates a fixer for the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_root` - Root of the workspace; relative edit paths are resolved against it
+    /// * `backup` - Backup settings of the transaction recorded by `apply`
+    /// * `fs` - Filesystem used to read and write files
+    #[must_use]
+    pub fn new(workspace_root: PathBuf, backup: BackupConfig, fs: F) -> Self {
+        Self { workspace_root, backup, fs }
+    }
+
+    /// Computes the file changes of the fixes without writing anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `fixes` - The fixes to plan, applied in order
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::FixFailed` if a file cannot be read or an edit does not apply
+    /// to its content.
+    pub async fn plan(&self, fixes: &[&Fix]) -> AuditResult<FixPlan> {
+        // Original and current content of every file, by path
+        let mut files: BTreeMap<PathBuf, (Option<String>, Option<String>)> = BTreeMap::new();
+
+        for fix in fixes {
+            for edit in &fix.edits {
+                let path = self.resolve(edit.path());
+                if !files.contains_key(&path) {
+                    let original = self.read(&path).await?;
+                    files.insert(path.clone(), (original.clone(), original));
+                }
+
+                if let Some((_, current)) = files.get_mut(&path) {
+                    *current = Some(edit.apply(&path, current.as_deref())?);
+                }
+            }
+        }
+
+        let files = files
+            .into_iter()
+            .filter_map(|(path, (original, updated))| {
+                let updated = updated?;
+                (original.as_ref() != Some(&updated)).then_some(FileChange {
+                    path,
+                    original,
+                    updated,
+                })
+            })
+            .collect();
+
+        Ok(FixPlan {
+            workspace_root: self.workspace_root.clone(),
+            fixes: fixes.iter().map(|fix| fix.description.clone()).collect(),
+            files,
+        })
+    }
+
+    /// Writes the files of a plan as one transaction.
+    ///
+    /// When backups are enabled the previous content is recorded as an `audit-fix`
+    /// transaction that `workspace undo` can revert. If a file changed since the plan was
+    /// made, nothing is written; if a write fails, every file is restored.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The plan to apply
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::FixFailed` if a file changed since the plan was made, the
+    /// transaction cannot be recorded, or a file cannot be written.
+    pub async fn apply(&self, plan: &FixPlan) -> AuditResult<FixOutcome> {
+        for file in &plan.files {
+            if self.read(&file.path).await? != file.original {
+                return Err(AuditError::FixFailed {
+                    path: file.path.clone(),
+                    reason: "file changed since the fix was planned".to_string(),
+                });
+            }
+        }
+
+        let paths: Vec<PathBuf> = plan.files.iter().map(|file| file.path.clone()).collect();
+        let transaction = if self.backup.enabled {
+            let log = TransactionLog::new(
+                self.workspace_root.clone(),
+                self.backup.clone(),
+                self.fs.clone(),
+            );
+            let id = log.begin(OperationKind::AuditFix, &paths).await.map_err(|e| {
+                AuditError::FixFailed {
+                    path: self.workspace_root.clone(),
+                    reason: format!("cannot record transaction: {e}"),
+                }
+            })?;
+            Some((log, id))
+        } else {
+            None
+        };
+
+        let mut written: Vec<&FileChange> = Vec::new();
+        for file in &plan.files {
+            if let Err(e) = self.write(&file.path, &file.updated).await {
+                match &transaction {
+                    Some((log, id)) => {
+                        if let Err(rollback_error) = log.rollback(id).await {
+                            log::warn!("Failed to roll back audit fixes: {}", rollback_error);
+                        }
+                    }
+                    None => self.restore(&written).await,
+                }
+                return Err(e);
+            }
+            written.push(file);
+        }
+
+        let transaction_id = match transaction {
+            Some((log, id)) => {
+                log.commit(&id).await.map_err(|e| AuditError::FixFailed {
+                    path: self.workspace_root.clone(),
+                    reason: format!("cannot commit transaction '{id}': {e}"),
+                })?;
+                Some(id)
+            }
+            None => None,
+        };
+
+        Ok(FixOutcome { fixes_applied: plan.fixes.len(), files: paths, transaction_id })
+    }
+
+    fn resolve(&self, path: &Path) -> PathBuf {
+        if path.is_absolute() { path.to_path_buf() } else { self.workspace_root.join(path) }
+    }
+
+    async fn read(&self, path: &Path) -> AuditResult<Option<String>> {
+        if !self.fs.exists(path).await {
+            return Ok(None);
+        }
+        self.fs.read_file_string(path).await.map(Some).map_err(|e| AuditError::FixFailed {
+            path: path.to_path_buf(),
+            reason: format!("cannot read file: {e}"),
+        })
+    }
+
+    async fn write(&self, path: &Path, content: &str) -> AuditResult<()> {
+        if let Some(parent) = path.parent() {
+            self.fs.create_dir_all(parent).await.map_err(|e| AuditError::FixFailed {
+                path: path.to_path_buf(),
+                reason: format!("cannot create directory: {e}"),
+            })?;
+        }
+        self.fs.write_file_string(path, content).await.map_err(|e| AuditError::FixFailed {
+            path: path.to_path_buf(),
+            reason: format!("cannot write file: {e}"),
+        })
+    }
+
+    /// Restores files written before a failure when no transaction was recorded.
+    async fn restore(&self, written: &[&FileChange]) {
+        for file in written {
+            let restored = match &file.original {
+                Some(original) => self.fs.write_file_string(&file.path, original).await,
+                None => self.fs.remove(&file.path).await,
+            };
+            if let Err(e) = restored {
+                log::warn!("Failed to restore '{}': {}", file.path.display(), e);
+            }
+        }
+    }
+}
@@ -266,6 +266,15 @@ fn format_dependencies_section_markdown(
         let _ = writeln!(output);
     }
 
+    if !sections.dependencies.package_collisions.is_empty() {
+        let _ = writeln!(output, "### Package Collisions");
+        let _ = writeln!(output);
+        for collision in &sections.dependencies.package_collisions {
+            let _ = writeln!(output, "- {}", collision.describe());
+        }
+        let _ = writeln!(output);
+    }
+
     if !sections.dependencies.version_conflicts.is_empty() {
         let _ = writeln!(output, "### Version Conflicts");
         let _ = writeln!(output);
@@ -0,0 +1,88 @@
+//! Workspace impact of the dependencies named by security issues.
+//!
+//! **What**: Provides `annotate_dependency_impact`, which adds to an issue about an external
+//! dependency (an advisory for `lodash`, say) the workspace packages that declare it and the
+//! workspace packages depending on those.
+//!
+//! **How**: Each affected package of the issue that is an external node of a
+//! `DependencyGraph` built with `from_packages_with_external` is looked up with
+//! `DependencyGraph::impact`. The impacted workspace packages are added to the issue's
+//! `affected_packages`, and the dependencies and both groups of packages are recorded in its
+//! metadata. `AuditManager` applies it to every issue of a `Security` check.
+//!
+//! **Why**: An advisory names a third-party package, but what a team has to act on is the
+//! list of its own packages shipping it, including the ones that only pull it in through
+//! another workspace package.
+
+use crate::audit::issue::AuditIssue;
+use crate::version::DependencyGraph;
+
+/// Adds the workspace packages impacted by the external dependencies an issue names.
+///
+/// Names in `affected_packages` that are external nodes of `graph` are replaced by the
+/// workspace packages declaring them, followed by the workspace packages depending on those.
+/// The metadata records them as `dependency`, `direct_dependents` and
+/// `transitive_dependents` (comma-separated). Issues naming no external dependency of the
+/// graph are left unchanged.
+///
+/// # Arguments
+///
+/// * `issue` - The issue to annotate
+/// * `graph` - A graph built with `DependencyGraph::from_packages_with_external`
+///
+/// # Returns
+///
+/// `true` if the issue names an external dependency of the graph.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::audit::{AuditIssue, IssueCategory, IssueSeverity, annotate_dependency_impact};
+/// use sublime_pkg_tools::version::DependencyGraph;
+///
+/// let graph = DependencyGraph::from_packages_with_external(&packages)?;
+/// let mut issue = AuditIssue::new(
+///     IssueSeverity::Critical,
+///     IssueCategory::Security,
+///     "Prototype pollution in lodash".to_string(),
+///     "lodash < 4.17.21 is vulnerable".to_string(),
+/// );
+/// issue.affected_packages.push("lodash".to_string());
+///
+/// annotate_dependency_impact(&mut issue, &graph);
+/// println!("Affected: {}", issue.affected_packages.join(", "));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn annotate_dependency_impact(issue: &mut AuditIssue, graph: &DependencyGraph) -> bool {
+    let (external, workspace): (Vec<String>, Vec<String>) =
+        issue.affected_packages.iter().cloned().partition(|name| graph.is_external(name));
+    if external.is_empty() {
+        return false;
+    }
+
+    let mut direct: Vec<String> = Vec::new();
+    let mut transitive: Vec<String> = Vec::new();
+    for impact in external.iter().filter_map(|dependency| graph.impact(dependency)) {
+        direct.extend(impact.direct);
+        transitive.extend(impact.transitive);
+    }
+    direct.sort();
+    direct.dedup();
+    transitive.sort();
+    transitive.dedup();
+    // A package declaring one dependency may reach another only transitively
+    transitive.retain(|package| !direct.contains(package));
+
+    let mut affected = workspace;
+    for package in direct.iter().chain(&transitive) {
+        if !affected.contains(package) {
+            affected.push(package.clone());
+        }
+    }
+    issue.affected_packages = affected;
+
+    issue.add_metadata("dependency".to_string(), external.join(","));
+    issue.add_metadata("direct_dependents".to_string(), direct.join(","));
+    issue.add_metadata("transitive_dependents".to_string(), transitive.join(","));
+    true
+}
@@ -11,7 +11,10 @@
 //! findings across different audit sections, enabling consistent reporting
 //! and filtering.
 
+use crate::audit::fix::Fix;
+use crate::error::{AuditError, AuditResult};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 
 /// A single audit issue found during analysis.
@@ -36,6 +39,7 @@ use std::collections::HashMap;
 ///     affected_packages: vec!["my-app".to_string()],
 ///     suggestion: Some("Consider migrating to 'new-lib'".to_string()),
 ///     metadata: HashMap::new(),
+///     fix: None,
 /// };
 ///
 /// assert_eq!(issue.severity, IssueSeverity::Critical);
@@ -60,6 +64,7 @@ use std::collections::HashMap;
 ///     affected_packages: vec!["my-package".to_string()],
 ///     suggestion: Some("Review breaking changes before upgrading".to_string()),
 ///     metadata,
+///     fix: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -99,6 +104,12 @@ pub struct AuditIssue {
     /// Can contain any key-value pairs relevant to the issue, such as
     /// version numbers, dependency names, commit hashes, etc.
     pub metadata: HashMap<String, String>,
+
+    /// Optional file edits that resolve the issue.
+    ///
+    /// Applied with `AuditFixer`, for example by `workspace audit --fix`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fix: Option<Fix>,
 }
 
 impl AuditIssue {
@@ -145,6 +156,7 @@ impl AuditIssue {
             affected_packages: Vec::new(),
             suggestion: None,
             metadata: HashMap::new(),
+            fix: None,
         }
     }
 
@@ -230,6 +242,41 @@ impl AuditIssue {
         self.metadata.insert(key, value);
     }
 
+    /// Sets the fix for this issue.
+    ///
+    /// # Arguments
+    ///
+    /// * `fix` - File edits that resolve the issue
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::audit::{AuditIssue, FileEdit, Fix, IssueCategory, IssueSeverity};
+    ///
+    /// let mut issue = AuditIssue::new(
+    ///     IssueSeverity::Warning,
+    ///     IssueCategory::Other,
+    ///     "Missing license".to_string(),
+    ///     "The package has no license file".to_string(),
+    /// );
+    ///
+    /// issue.set_fix(Fix::new("Add a LICENSE file").with_edit(FileEdit::Write {
+    ///     path: "LICENSE".into(),
+    ///     content: "MIT License\n".to_string(),
+    /// }));
+    ///
+    /// assert!(issue.is_fixable());
+    /// ```
+    pub fn set_fix(&mut self, fix: Fix) {
+        self.fix = Some(fix);
+    }
+
+    /// Returns whether the issue has a fix with at least one edit.
+    #[must_use]
+    pub fn is_fixable(&self) -> bool {
+        self.fix.as_ref().is_some_and(|fix| !fix.edits.is_empty())
+    }
+
     /// Returns whether this is a critical issue.
     ///
     /// # Examples
@@ -302,6 +349,50 @@ impl AuditIssue {
     pub fn is_info(&self) -> bool {
         self.severity == IssueSeverity::Info
     }
+
+    /// Returns a stable identifier of this finding.
+    ///
+    /// The fingerprint hashes the category, title and sorted affected packages, so the same
+    /// finding has the same fingerprint across runs while its description or suggestion may
+    /// change. Audit baselines use it to recognize known findings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::audit::{AuditIssue, IssueSeverity, IssueCategory};
+    ///
+    /// let mut first = AuditIssue::new(
+    ///     IssueSeverity::Warning,
+    ///     IssueCategory::Dependencies,
+    ///     "Circular dependency".to_string(),
+    ///     "a -> b -> a".to_string(),
+    /// );
+    /// first.add_affected_package("a".to_string());
+    /// first.add_affected_package("b".to_string());
+    ///
+    /// let mut second = first.clone();
+    /// second.description = "b -> a -> b".to_string();
+    /// second.affected_packages.reverse();
+    ///
+    /// assert_eq!(first.fingerprint(), second.fingerprint());
+    /// assert_eq!(first.fingerprint().len(), 16);
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> String {
+        let mut packages: Vec<&str> = self.affected_packages.iter().map(String::as_str).collect();
+        packages.sort_unstable();
+        packages.dedup();
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.category.as_str());
+        hasher.update([0]);
+        hasher.update(&self.title);
+        for package in packages {
+            hasher.update([0]);
+            hasher.update(package);
+        }
+        hasher.finalize().iter().take(8).map(|byte| format!("{byte:02x}")).collect()
+    }
 }
 
 /// Severity level of an audit issue.
@@ -396,6 +487,30 @@ impl IssueSeverity {
             IssueSeverity::Critical => "critical",
         }
     }
+
+    /// Parses a severity from its string representation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::InvalidSeverity` if the value is not `critical`, `warning`,
+    /// or `info`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::audit::IssueSeverity;
+    ///
+    /// assert_eq!(IssueSeverity::parse("warning").ok(), Some(IssueSeverity::Warning));
+    /// assert!(IssueSeverity::parse("high").is_err());
+    /// ```
+    pub fn parse(value: &str) -> AuditResult<Self> {
+        match value {
+            "info" => Ok(IssueSeverity::Info),
+            "warning" => Ok(IssueSeverity::Warning),
+            "critical" => Ok(IssueSeverity::Critical),
+            _ => Err(AuditError::InvalidSeverity { severity: value.to_string() }),
+        }
+    }
 }
 
 impl std::fmt::Display for IssueSeverity {
@@ -13,6 +13,15 @@
 //! the complexity of coordinating multiple subsystems while presenting a clean,
 //! simple API for users.
 
+use crate::audit::checks::{AuditCheck, AuditCheckContext, AuditCheckRegistry, CustomCheckSection};
+use crate::audit::dashboard::{
+    GraphMetrics, OutdatedDependencyCounts, PendingChangesetStats, WorkspaceDashboard,
+};
+use crate::audit::fix::AuditFixer;
+use crate::audit::impact::annotate_dependency_impact;
+use crate::audit::issue::{AuditIssue, IssueCategory, IssueSeverity};
+use crate::audit::ownership::RegistryOwnershipCheck;
+use crate::audit::runner::{AuditCheckOutcome, AuditCheckReport, AuditRun, BuiltinCheck};
 use crate::audit::sections::{
     BreakingChangesAuditSection, DependencyAuditSection, UpgradeAuditSection,
     VersionConsistencyAuditSection, audit_dependencies as audit_dependencies_impl,
@@ -23,13 +32,20 @@ use crate::changes::ChangesAnalyzer;
 use crate::changeset::{ChangesetManager, FileBasedChangesetStorage};
 use crate::config::PackageToolsConfig;
 use crate::error::{AuditError, AuditResult};
-use crate::types::PackageInfo;
-use crate::upgrade::UpgradeManager;
+use crate::types::{Changeset, PackageInfo};
+use crate::upgrade::{RegistryClient, UpgradeManager};
+use crate::version::DependencyGraph;
+use futures::stream::{self, Stream, StreamExt};
 use std::collections::HashSet;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use sublime_git_tools::Repo;
+use sublime_standard_tools::deadline::global_deadline;
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
-use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait};
+use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait, WorkspacePackage};
+use tokio::sync::OnceCell;
+use tokio::task::JoinSet;
 
 /// High-level manager for audit and health check operations.
 ///
@@ -133,6 +149,9 @@ pub struct AuditManager {
 
     /// Configuration for audit operations.
     config: PackageToolsConfig,
+
+    /// Custom checks run alongside the built-in checks.
+    checks: AuditCheckRegistry,
 }
 
 impl AuditManager {
@@ -229,11 +248,12 @@ impl AuditManager {
             })?;
 
         // Initialize upgrade manager
-        let upgrade_manager = UpgradeManager::new(workspace_root.clone(), config.upgrade.clone())
-            .await
-            .map_err(|e| AuditError::UpgradeDetectionFailed {
-                reason: format!("Failed to initialize upgrade manager: {}", e),
-            })?;
+        let upgrade_manager =
+            Box::pin(UpgradeManager::new(workspace_root.clone(), config.upgrade.clone()))
+                .await
+                .map_err(|e| AuditError::UpgradeDetectionFailed {
+                    reason: format!("Failed to initialize upgrade manager: {}", e),
+                })?;
 
         // Initialize changes analyzer
         let changes_analyzer =
@@ -252,6 +272,8 @@ impl AuditManager {
                     reason: format!("Failed to initialize changeset manager: {}", e),
                 })?;
 
+        let checks = Box::pin(default_checks(&workspace_root, &config)).await?;
+
         Ok(Self {
             workspace_root,
             upgrade_manager,
@@ -260,6 +282,7 @@ impl AuditManager {
             fs,
             monorepo_detector,
             config,
+            checks,
         })
     }
 
@@ -356,6 +379,32 @@ impl AuditManager {
         &self.changes_analyzer
     }
 
+    /// Returns a reference to the changeset manager.
+    ///
+    /// This provides access to the underlying changeset manager, for example to
+    /// list the pending changesets summarised by [`dashboard`](Self::dashboard).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::audit::AuditManager;
+    /// # use sublime_pkg_tools::config::PackageToolsConfig;
+    /// # use std::path::PathBuf;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let workspace_root = PathBuf::from(".");
+    /// # let config = PackageToolsConfig::default();
+    /// let manager = AuditManager::new(workspace_root, config).await?;
+    /// let pending = manager.changeset_manager().list_pending().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn changeset_manager(
+        &self,
+    ) -> &ChangesetManager<FileBasedChangesetStorage<FileSystemManager>> {
+        &self.changeset_manager
+    }
+
     /// Returns a reference to the monorepo detector.
     ///
     /// This provides access to the underlying monorepo detector for direct
@@ -406,6 +455,38 @@ impl AuditManager {
         &self.fs
     }
 
+    /// Returns a fixer for the fixes attached to this workspace's audit issues.
+    ///
+    /// Applied fixes are recorded in the transaction log configured by
+    /// `upgrade.backup`, so `workspace undo` can revert them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::audit::AuditManager;
+    /// # use sublime_pkg_tools::config::PackageToolsConfig;
+    /// # use std::path::PathBuf;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let workspace_root = PathBuf::from(".");
+    /// # let config = PackageToolsConfig::default();
+    /// let manager = AuditManager::new(workspace_root, config).await?;
+    /// let section = manager.audit_version_consistency().await?;
+    ///
+    /// let fixes: Vec<_> = section.issues.iter().filter_map(|issue| issue.fix.as_ref()).collect();
+    /// let plan = manager.fixer().plan(&fixes).await?;
+    /// print!("{}", plan.diff());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn fixer(&self) -> AuditFixer<FileSystemManager> {
+        AuditFixer::new(
+            self.workspace_root.clone(),
+            self.config.upgrade.backup.clone(),
+            self.fs.clone(),
+        )
+    }
+
     /// Audits available package upgrades.
     ///
     /// Performs a comprehensive analysis of available dependency upgrades, including:
@@ -692,34 +773,27 @@ impl AuditManager {
                 return Err(AuditError::PackageNotFound { package: "any package".to_string() });
             }
 
-            // Convert workspace packages to PackageInfo
-            let mut packages = Vec::with_capacity(workspace_packages.len());
-            for workspace_package in workspace_packages {
-                let package_json_path = workspace_package.absolute_path.join("package.json");
-
-                // Read package.json file
-                let content = self.fs.read_file_string(&package_json_path).await.map_err(|e| {
-                    AuditError::FileSystemError {
-                        path: package_json_path.clone(),
-                        reason: format!("Failed to read file: {}", e),
-                    }
-                })?;
+            // Read every package.json on its own task; large workspaces spend most of
+            // the audit setup here
+            let mut reads = JoinSet::new();
+            for (index, workspace_package) in workspace_packages.iter().cloned().enumerate() {
+                let fs = self.fs.clone();
+                reads.spawn(async move {
+                    read_workspace_package(&fs, workspace_package).await.map(|info| (index, info))
+                });
+            }
 
-                // Parse package.json
-                let package_json: package_json::PackageJson = serde_json::from_str(&content)
-                    .map_err(|e| AuditError::FileSystemError {
-                        path: package_json_path.clone(),
-                        reason: format!("Failed to parse JSON: {}", e),
-                    })?;
-
-                packages.push(PackageInfo::new(
-                    package_json,
-                    Some(workspace_package.clone()),
-                    workspace_package.absolute_path.clone(),
-                ));
+            let mut packages = Vec::with_capacity(workspace_packages.len());
+            while let Some(joined) = reads.join_next().await {
+                let package = joined.map_err(|e| AuditError::WorkspaceAnalysisFailed {
+                    reason: format!("Package read task failed: {}", e),
+                })??;
+                packages.push(package);
             }
 
-            Ok(packages)
+            // Keep the detector's package order regardless of completion order
+            packages.sort_by_key(|(index, _)| *index);
+            Ok(packages.into_iter().map(|(_, package)| package).collect())
         } else {
             // Single package project
             let package_json_path = self.workspace_root.join("package.json");
@@ -773,17 +847,6 @@ impl AuditManager {
     /// # }
     /// ```
     pub async fn audit_breaking_changes(&self) -> AuditResult<BreakingChangesAuditSection> {
-        use crate::audit::sections::audit_breaking_changes;
-
-        // Get HEAD commit
-        let head_commit = "HEAD";
-
-        // Determine the base commit using an intelligent strategy:
-        // 1. Try to use the last tag (compare against last release)
-        // 2. Try merge-base with main/master (compare against main branch)
-        // 3. Fallback to HEAD~10 (last 10 commits)
-        let base_commit = self.determine_base_commit()?;
-
         // Load pending changesets to detect breaking changes from them
         let pending_changesets = self.changeset_manager.list_pending().await.map_err(|e| {
             AuditError::WorkspaceAnalysisFailed {
@@ -793,56 +856,464 @@ impl AuditManager {
 
         // Use the first pending changeset if available
         // Multiple changesets will be fully integrated in a future enhancement
-        let changeset = pending_changesets.first();
-
-        // Call the breaking changes audit implementation
-        audit_breaking_changes(
-            &self.changes_analyzer,
-            &base_commit,
-            head_commit,
-            changeset,
-            &self.config.audit.breaking_changes,
-        )
+        let changeset = pending_changesets.into_iter().next();
+
+        // The history walk is synchronous git2 work. Running it on a blocking thread, with
+        // its own repository handle, keeps it off the audit task so the per-check timeout
+        // can fire while it is still going.
+        let workspace_root = self.workspace_root.clone();
+        let fs = self.fs.clone();
+        let config = self.config.clone();
+        let runtime = tokio::runtime::Handle::current();
+        tokio::task::spawn_blocking(move || {
+            runtime.block_on(breaking_changes_in_history(workspace_root, fs, config, changeset))
+        })
         .await
+        .map_err(|e| AuditError::BreakingChangesDetectionFailed {
+            reason: format!("Breaking changes analysis task failed: {}", e),
+        })?
     }
 
-    /// Determines the base commit for breaking changes analysis.
+    /// Collects the data for a workspace health dashboard in a single call.
     ///
-    /// Uses an intelligent strategy to find the most appropriate base commit:
-    /// 1. Last Git tag (if available) - compares against last release
-    /// 2. Merge-base with main or master branch - compares against main branch
-    /// 3. HEAD~10 - last 10 commits as fallback
+    /// Gathers:
+    /// - The number of workspace packages and the dependency graph metrics
+    /// - Packages with uncommitted changes in the working directory
+    /// - Pending changesets and the age of the oldest one
+    /// - Outdated dependency counts by severity (skipped if the upgrades section is disabled)
     ///
     /// # Returns
     ///
-    /// The Git reference string to use as the base commit.
+    /// A `WorkspaceDashboard` with all metrics.
     ///
     /// # Errors
     ///
-    /// Returns an error if Git operations fail critically.
-    fn determine_base_commit(&self) -> AuditResult<String> {
-        // Try to get the last tag
-        if let Ok(last_tag) = self.changes_analyzer.git_repo().get_last_tag() {
-            return Ok(last_tag);
-        }
+    /// Returns `AuditError` if:
+    /// - Package discovery or dependency graph construction fails
+    /// - The working directory cannot be analyzed
+    /// - Pending changesets cannot be loaded
+    /// - Upgrade detection fails while the upgrades section is enabled
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::audit::AuditManager;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = AuditManager::new(PathBuf::from("."), PackageToolsConfig::default()).await?;
+    /// let dashboard = manager.dashboard().await?;
+    ///
+    /// println!("Packages: {}", dashboard.package_count);
+    /// println!("Pending changesets: {}", dashboard.changesets.count);
+    /// println!("Max graph depth: {}", dashboard.graph.max_depth);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dashboard(&self) -> AuditResult<WorkspaceDashboard> {
+        let now = chrono::Utc::now();
+
+        let packages = self.discover_packages().await?;
+        let graph = DependencyGraph::from_packages(&packages).map_err(|e| {
+            AuditError::DependencyGraphFailed {
+                reason: format!("Failed to build dependency graph: {}", e),
+            }
+        })?;
+
+        let working_directory =
+            self.changes_analyzer.analyze_working_directory().await.map_err(|e| {
+                AuditError::AnalysisFailed {
+                    section: "changes".to_string(),
+                    reason: format!("Failed to analyze working directory: {}", e),
+                }
+            })?;
+        let mut dirty_packages: Vec<String> = working_directory
+            .packages_with_changes()
+            .into_iter()
+            .map(|package| package.package_name().to_string())
+            .collect();
+        dirty_packages.sort();
+
+        let pending_changesets = self.changeset_manager.list_pending().await.map_err(|e| {
+            AuditError::WorkspaceAnalysisFailed {
+                reason: format!("Failed to load pending changesets: {}", e),
+            }
+        })?;
+
+        let outdated = match self.audit_upgrades().await {
+            Ok(section) => Some(OutdatedDependencyCounts::from(&section)),
+            Err(AuditError::SectionDisabled { .. }) => None,
+            Err(e) => return Err(e),
+        };
+
+        Ok(WorkspaceDashboard {
+            generated_at: now,
+            workspace_root: self.workspace_root.clone(),
+            is_monorepo: self.changes_analyzer.is_monorepo(),
+            package_count: packages.len(),
+            dirty_packages,
+            changesets: PendingChangesetStats::from_changesets(&pending_changesets, now),
+            outdated,
+            graph: GraphMetrics::from(&graph),
+        })
+    }
+
+    /// Registers a custom check to run alongside the built-in checks.
+    ///
+    /// # Parameters
+    ///
+    /// * `check` - The check to register
+    ///
+    /// # Returns
+    ///
+    /// The manager, so several checks can be registered in a row.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AuditError::DuplicateCheck` if a check with the same id is already
+    /// registered or the id belongs to a built-in check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::audit::AuditManager;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut manager =
+    ///     AuditManager::new(PathBuf::from("."), PackageToolsConfig::default()).await?;
+    /// manager.register_check(ScopedNames)?;
+    ///
+    /// assert!(manager.check_ids().contains(&"scoped-names"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn register_check(&mut self, check: impl AuditCheck + 'static) -> AuditResult<&mut Self> {
+        self.checks.register(check)?;
+        Ok(self)
+    }
+
+    /// Returns the ids of every check, built-in checks first and then custom checks in
+    /// registration order.
+    ///
+    /// Checks disabled in `audit.checks` are included; `check_stream` skips them.
+    #[must_use]
+    pub fn check_ids(&self) -> Vec<&str> {
+        BuiltinCheck::ALL.iter().map(|check| check.id()).chain(self.checks.ids()).collect()
+    }
 
-        // Try to get merge-base with main branch
-        if let Ok(current_branch) = self.changes_analyzer.git_repo().get_current_branch() {
-            // Try main first, then master
-            for main_branch in &["main", "master"] {
-                if let Ok(merge_base) =
-                    self.changes_analyzer.git_repo().get_merge_base(&current_branch, main_branch)
-                {
-                    return Ok(merge_base);
+    /// Runs audit checks concurrently and streams each outcome as the check completes.
+    ///
+    /// Up to `audit.concurrency` checks run at once, and each one is bounded by
+    /// `audit.check_timeout_secs`. Package manifests are read once per run, each on its
+    /// own task, and the git history walk of the breaking changes check runs on a
+    /// blocking thread, so a slow check cannot hold up the others past its timeout. A
+    /// check that fails or times out yields an outcome with the error; the other checks
+    /// keep running. Checks still running when the global deadline passes yield
+    /// `AuditError::DeadlineExceeded`, so the outcomes of finished checks are kept. Checks disabled in `audit.checks` are skipped without an outcome,
+    /// and `audit.checks.<id>.severity` replaces the severity of every issue a check
+    /// reports.
+    ///
+    /// # Parameters
+    ///
+    /// * `checks` - Ids of the checks to run; use `check_ids` for a full audit
+    ///
+    /// # Returns
+    ///
+    /// A stream of `AuditCheckOutcome`, one per enabled check, in completion order. An id
+    /// that is neither built in nor registered yields `AuditError::UnknownCheck`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    /// use sublime_pkg_tools::audit::AuditManager;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = AuditManager::new(PathBuf::from("."), PackageToolsConfig::default()).await?;
+    ///
+    /// let checks = manager.check_ids();
+    /// let mut outcomes = manager.check_stream(&checks);
+    /// while let Some(outcome) = outcomes.next().await {
+    ///     println!("{} finished in {:?}", outcome.check, outcome.duration);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn check_stream<'a, S: AsRef<str> + Sync>(
+        &'a self,
+        checks: &'a [S],
+    ) -> impl Stream<Item = AuditCheckOutcome> + 'a {
+        let timeout_secs = self.config.audit.check_timeout_secs;
+        let concurrency = self.config.audit.concurrency.max(1);
+        let context = Arc::new(OnceCell::new());
+
+        stream::iter(checks.iter().map(AsRef::as_ref))
+            .filter(move |id| std::future::ready(self.config.audit.is_check_enabled(id)))
+            .map(move |id| {
+                let context = Arc::clone(&context);
+                async move {
+                    let started = Instant::now();
+                    let bounded = tokio::time::timeout(
+                        Duration::from_secs(timeout_secs),
+                        self.run_check(id, &context),
+                    );
+                    let result = match global_deadline().run(id, bounded).await {
+                        Ok(result) => result
+                            .unwrap_or(Err(AuditError::Timeout { duration_secs: timeout_secs })),
+                        Err(_) => Err(AuditError::DeadlineExceeded { check: id.to_string() }),
+                    };
+
+                    AuditCheckOutcome { check: id.to_string(), duration: started.elapsed(), result }
                 }
+            })
+            .buffer_unordered(concurrency)
+    }
+
+    /// Runs audit checks concurrently and collects their results.
+    ///
+    /// This is `check_stream` for callers that don't need partial results.
+    ///
+    /// # Parameters
+    ///
+    /// * `checks` - Ids of the checks to run; use `check_ids` for a full audit
+    ///
+    /// # Returns
+    ///
+    /// An `AuditRun` with the section of every successful check and the error of every
+    /// failed or timed-out one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::audit::AuditManager;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = AuditManager::new(PathBuf::from("."), PackageToolsConfig::default()).await?;
+    /// let run = manager.run_checks(&manager.check_ids()).await;
+    ///
+    /// for (check, error) in &run.failures {
+    ///     eprintln!("{check} failed: {error}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_checks<S: AsRef<str> + Sync>(&self, checks: &[S]) -> AuditRun {
+        self.check_stream(checks)
+            .fold(AuditRun::default(), |mut run, outcome| async move {
+                run.record(outcome);
+                run
+            })
+            .await
+    }
+
+    /// Runs a single check without a timeout and applies its severity override.
+    ///
+    /// The context, with the discovered packages, is built by the first check that needs
+    /// it and shared with the others, so manifests are read once per run.
+    async fn run_check(
+        &self,
+        id: &str,
+        context: &OnceCell<AuditCheckContext>,
+    ) -> AuditResult<AuditCheckReport> {
+        let severity_override =
+            self.config.audit.check_severity(id).map(IssueSeverity::parse).transpose()?;
+
+        let mut report = match BuiltinCheck::from_id(id) {
+            Some(builtin) => self.run_builtin_check(builtin, context).await?,
+            None => {
+                let check = self
+                    .checks
+                    .get(id)
+                    .ok_or_else(|| AuditError::UnknownCheck { id: id.to_string() })?;
+                let context = context.get_or_try_init(|| self.check_context()).await?;
+                let severity = severity_override.unwrap_or_else(|| check.severity());
+                let mut issues: Vec<AuditIssue> = check
+                    .run(context)
+                    .await?
+                    .into_iter()
+                    .map(|finding| finding.into_issue(id, check.category(), severity))
+                    .collect();
+
+                if check.category() == IssueCategory::Security && !issues.is_empty() {
+                    let graph = DependencyGraph::from_packages_with_external(context.packages())
+                        .map_err(|e| AuditError::DependencyGraphFailed {
+                            reason: format!("Failed to build dependency graph: {}", e),
+                        })?;
+                    for issue in &mut issues {
+                        annotate_dependency_impact(issue, &graph);
+                    }
+                }
+
+                AuditCheckReport::Custom(CustomCheckSection {
+                    id: id.to_string(),
+                    category: check.category(),
+                    issues,
+                })
+            }
+        };
+
+        if let Some(severity) = severity_override {
+            for issue in report.issues_mut() {
+                issue.severity = severity;
             }
         }
 
-        // Fallback to last 10 commits
-        Ok("HEAD~10".to_string())
+        Ok(report)
+    }
+
+    async fn run_builtin_check(
+        &self,
+        check: BuiltinCheck,
+        context: &OnceCell<AuditCheckContext>,
+    ) -> AuditResult<AuditCheckReport> {
+        match check {
+            BuiltinCheck::Upgrades => self.audit_upgrades().await.map(AuditCheckReport::Upgrades),
+            BuiltinCheck::Dependencies => {
+                let packages = context.get_or_try_init(|| self.check_context()).await?.packages();
+                audit_dependencies_impl(&self.workspace_root, packages, &self.config)
+                    .await
+                    .map(AuditCheckReport::Dependencies)
+            }
+            BuiltinCheck::VersionConsistency => {
+                let packages = context.get_or_try_init(|| self.check_context()).await?.packages();
+                let internal_package_names: HashSet<String> =
+                    packages.iter().map(|p| p.name().to_string()).collect();
+                audit_version_consistency_impl(packages, &internal_package_names, &self.config)
+                    .await
+                    .map(AuditCheckReport::VersionConsistency)
+            }
+            BuiltinCheck::BreakingChanges => {
+                self.audit_breaking_changes().await.map(AuditCheckReport::BreakingChanges)
+            }
+        }
+    }
+
+    async fn check_context(&self) -> AuditResult<AuditCheckContext> {
+        let packages = self.discover_packages().await?;
+        Ok(AuditCheckContext::new(self.workspace_root.clone(), self.config.clone(), packages))
     }
 
     // Future audit methods will be implemented in subsequent stories:
     // - Story 10.7: calculate_health_score() -> u8
     // - Story 10.8: run_audit() -> AuditReport
 }
+
+/// Creates the registry of checks shipped with the crate that the configuration enables.
+///
+/// The registry ownership check is registered when the organization's scopes are configured.
+async fn default_checks(
+    workspace_root: &Path,
+    config: &PackageToolsConfig,
+) -> AuditResult<AuditCheckRegistry> {
+    let mut checks = AuditCheckRegistry::new();
+    if !config.audit.registry_ownership.scopes.is_empty() {
+        let client = RegistryClient::new(workspace_root, config.upgrade.registry.clone())
+            .await
+            .map_err(|e| AuditError::RegistryError {
+                reason: format!("Failed to initialize registry client: {}", e),
+            })?;
+        checks.register(RegistryOwnershipCheck::new(client, &config.audit.registry_ownership))?;
+    }
+    Ok(checks)
+}
+
+/// Reads and parses the package.json of a workspace package.
+async fn read_workspace_package(
+    fs: &FileSystemManager,
+    workspace_package: WorkspacePackage,
+) -> AuditResult<PackageInfo> {
+    let package_json_path = workspace_package.absolute_path.join("package.json");
+
+    // Read package.json file
+    let content =
+        fs.read_file_string(&package_json_path).await.map_err(|e| AuditError::FileSystemError {
+            path: package_json_path.clone(),
+            reason: format!("Failed to read file: {}", e),
+        })?;
+
+    // Parse package.json
+    let package_json: package_json::PackageJson =
+        serde_json::from_str(&content).map_err(|e| AuditError::FileSystemError {
+            path: package_json_path.clone(),
+            reason: format!("Failed to parse JSON: {}", e),
+        })?;
+
+    let absolute_path = workspace_package.absolute_path.clone();
+    Ok(PackageInfo::new(package_json, Some(workspace_package), absolute_path))
+}
+
+/// Opens the repository at `workspace_root` and audits breaking changes since the
+/// base commit chosen by `determine_base_commit`.
+async fn breaking_changes_in_history(
+    workspace_root: PathBuf,
+    fs: FileSystemManager,
+    config: PackageToolsConfig,
+    changeset: Option<Changeset>,
+) -> AuditResult<BreakingChangesAuditSection> {
+    use crate::audit::sections::audit_breaking_changes;
+
+    let workspace_str =
+        workspace_root.to_str().ok_or_else(|| AuditError::InvalidWorkspaceRoot {
+            path: workspace_root.clone(),
+            reason: "Workspace path contains invalid UTF-8".to_string(),
+        })?;
+    let git_repo = Repo::open(workspace_str).map_err(|e| AuditError::GitError {
+        operation: "open repository".to_string(),
+        reason: e.to_string(),
+    })?;
+
+    // Determine the base commit using an intelligent strategy:
+    // 1. Try to use the last tag (compare against last release)
+    // 2. Try merge-base with main/master (compare against main branch)
+    // 3. Fallback to HEAD~10 (last 10 commits)
+    let base_commit = determine_base_commit(&git_repo);
+
+    let changes_analyzer = ChangesAnalyzer::new(workspace_root, git_repo, fs, config.clone())
+        .await
+        .map_err(|e| AuditError::AnalysisFailed {
+            section: "changes".to_string(),
+            reason: format!("Failed to initialize changes analyzer: {}", e),
+        })?;
+
+    audit_breaking_changes(
+        &changes_analyzer,
+        &base_commit,
+        "HEAD",
+        changeset.as_ref(),
+        &config.audit.breaking_changes,
+    )
+    .await
+}
+
+/// Determines the base commit for breaking changes analysis.
+///
+/// Uses an intelligent strategy to find the most appropriate base commit:
+/// 1. Last Git tag (if available) - compares against last release
+/// 2. Merge-base with main or master branch - compares against main branch
+/// 3. HEAD~10 - last 10 commits as fallback
+fn determine_base_commit(git_repo: &Repo) -> String {
+    // Try to get the last tag
+    if let Ok(last_tag) = git_repo.get_last_tag() {
+        return last_tag;
+    }
+
+    // Try to get merge-base with main branch
+    if let Ok(current_branch) = git_repo.get_current_branch() {
+        // Try main first, then master
+        for main_branch in &["main", "master"] {
+            if let Ok(merge_base) = git_repo.get_merge_base(&current_branch, main_branch) {
+                return merge_base;
+            }
+        }
+    }
+
+    // Fallback to last 10 commits
+    "HEAD~10".to_string()
+}
@@ -17,7 +17,12 @@
 //! - **Dependency Categorization**: Classify dependencies as internal, external, workspace, or local
 //! - **Version Consistency Checks**: Detect version inconsistencies across packages
 //! - **Health Scoring**: Calculate overall project health metrics
+//! - **Dashboard Data**: Collect package, changeset, upgrade and graph metrics in one call
 //! - **Report Generation**: Export audit results in multiple formats (Markdown, JSON)
+//! - **Fixes**: Preview and apply the fixes attached to issues, as one undoable transaction
+//! - **Dependency Impact**: Annotate security issues with the workspace packages shipping the
+//!   affected dependency
+//! - **Baselines**: Suppress known findings so CI fails only on new ones
 //!
 //! # Example
 //!
@@ -53,14 +58,28 @@
 //! - `report`: Report structures and aggregation
 //! - `formatter`: Report formatting and export capabilities (Markdown, JSON)
 //! - `health_score`: Health score calculation utilities
+//! - `checks`: Custom audit checks and their registry
+//! - `ownership`: Registry existence and ownership check for the organization's scopes
+//! - `dashboard`: Aggregated workspace health data for dashboards
+//! - `runner`: Concurrent execution of audit checks with per-check timeouts
+//! - `fix`: Fixes attached to issues, with dry-run diffs and transactional application
+//! - `impact`: Workspace packages impacted by the dependencies named by security issues
+//! - `baseline`: Known findings that are suppressed until they expire
 
 #![allow(clippy::todo)]
 
+mod baseline;
+mod checks;
+mod dashboard;
+mod fix;
 mod formatter;
 mod health_score;
+mod impact;
 mod issue;
 mod manager;
+mod ownership;
 mod report;
+mod runner;
 mod sections;
 
 #[cfg(test)]
@@ -69,12 +88,23 @@ mod tests;
 // Public exports
 pub use manager::AuditManager;
 
+// Dashboard data
+pub use dashboard::{
+    GraphMetrics, OutdatedDependencyCounts, PendingChangesetStats, WorkspaceDashboard,
+};
+
 // Health score calculation
 pub use health_score::{
     HealthScoreBreakdown, HealthScoreWeights, calculate_diminishing_factor, calculate_health_score,
     calculate_health_score_detailed,
 };
 
+// Fixes
+pub use fix::{AuditFixer, FileChange, FileEdit, Fix, FixOutcome, FixPlan};
+
+// Dependency impact of security issues
+pub use impact::annotate_dependency_impact;
+
 // Issue types
 pub use issue::{AuditIssue, IssueCategory, IssueSeverity};
 
@@ -82,12 +112,26 @@ pub use issue::{AuditIssue, IssueCategory, IssueSeverity};
 pub use sections::{
     BreakingChange, BreakingChangeSource, BreakingChangesAuditSection, CategorizationStats,
     DependencyAuditSection, DependencyCategorization, DeprecatedPackage, ExternalPackage,
-    InternalPackage, LocalLink, LocalLinkType, PackageBreakingChanges, UpgradeAuditSection,
-    VersionConflict, VersionConsistencyAuditSection, VersionInconsistency, VersionUsage,
-    WorkspaceLink, audit_breaking_changes, audit_dependencies, audit_upgrades,
+    InternalPackage, LocalLink, LocalLinkType, PackageBreakingChanges, PackageCollision,
+    UpgradeAuditSection, VersionConflict, VersionConsistencyAuditSection, VersionInconsistency,
+    VersionUsage, WorkspaceLink, audit_breaking_changes, audit_dependencies, audit_upgrades,
     audit_version_consistency, categorize_dependencies, generate_categorization_issues,
 };
 
+// Concurrent check execution
+pub use runner::{AuditCheckOutcome, AuditCheckReport, AuditRun, BuiltinCheck};
+
+// Custom checks
+pub use checks::{
+    AuditCheck, AuditCheckContext, AuditCheckRegistry, AuditFinding, CustomCheckSection,
+};
+
+// Registry ownership check
+pub use ownership::{REGISTRY_OWNERSHIP_CHECK_ID, RegistryOwnershipCheck};
+
+// Baselines of known findings
+pub use baseline::{AuditBaseline, BaselineComparison, BaselineEntry, DEFAULT_BASELINE_FILE};
+
 // Report types
 pub use report::{AuditReport, AuditSections, AuditSummary};
 
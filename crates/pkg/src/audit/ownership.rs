@@ -0,0 +1,268 @@
+//! Registry existence and ownership check for the organization's scopes.
+//!
+//! **What**: Provides `RegistryOwnershipCheck`, an `AuditCheck` verifying that every workspace
+//! package under one of the scopes in `audit.registry_ownership.scopes` is either marked
+//! `"private": true` or published on the private registry its scope resolves to, maintained by
+//! one of `audit.registry_ownership.owners`.
+//!
+//! **How**: For each non-private package under an organization scope, the check resolves the
+//! registry it would be published to (`publishConfig.registry`, then `.npmrc` and the scoped
+//! registries of `upgrade.registry`). A package resolving to the public npm registry is
+//! reported without a lookup; otherwise the package document is fetched and a missing package
+//! or one without a configured owner among its maintainers is reported. Packages whose scope
+//! is one edit away from an organization scope are reported as likely typos. `AuditManager`
+//! registers the check under `registry-ownership` when at least one scope is configured.
+//!
+//! **Why**: An unpublished name under the organization's scope can be claimed by anyone on a
+//! registry the scope falls back to, a mistyped scope publishes outside the organization, and
+//! a missing scoped registry mapping sends internal code to the public registry.
+
+use crate::audit::checks::{AuditCheck, AuditCheckContext, AuditFinding};
+use crate::audit::issue::{IssueCategory, IssueSeverity};
+use crate::config::RegistryOwnershipAuditConfig;
+use crate::error::{AuditError, AuditResult, UpgradeError};
+use crate::types::PackageInfo;
+use crate::upgrade::{MetadataDetail, RegistryClient};
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use sublime_standard_tools::network::is_offline_mode;
+
+/// Id under which `AuditManager` registers the check.
+pub const REGISTRY_OWNERSHIP_CHECK_ID: &str = "registry-ownership";
+
+/// Hosts of the public npm registry and its mirrors.
+const PUBLIC_REGISTRY_HOSTS: [&str; 2] = ["registry.npmjs.org", "registry.yarnpkg.com"];
+
+/// Audit check for the registry existence and ownership of the organization's packages.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::audit::RegistryOwnershipCheck;
+/// use sublime_pkg_tools::config::{RegistryConfig, RegistryOwnershipAuditConfig};
+/// use sublime_pkg_tools::upgrade::RegistryClient;
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = RegistryClient::new(&PathBuf::from("."), RegistryConfig::default()).await?;
+/// let config = RegistryOwnershipAuditConfig {
+///     scopes: vec!["@myorg".to_string()],
+///     owners: vec!["myorg".to_string()],
+/// };
+/// let check = RegistryOwnershipCheck::new(client, &config);
+/// # Ok(())
+/// # }
+/// ```
+pub struct RegistryOwnershipCheck {
+    client: RegistryClient,
+    scopes: Vec<String>,
+    owners: Vec<String>,
+}
+
+impl RegistryOwnershipCheck {
+    /// Creates the check for the scopes and owners of the given configuration.
+    ///
+    /// Scopes are accepted with or without the leading `@`.
+    #[must_use]
+    pub fn new(client: RegistryClient, config: &RegistryOwnershipAuditConfig) -> Self {
+        let scopes = config
+            .scopes
+            .iter()
+            .map(|scope| scope.trim_start_matches('@').to_lowercase())
+            .collect();
+        Self { client, scopes, owners: config.owners.clone() }
+    }
+
+    /// Returns whether the package is under one of the organization scopes.
+    fn is_organization_package(&self, package: &PackageInfo) -> bool {
+        package_scope(package.name())
+            .is_some_and(|scope| self.scopes.contains(&scope.to_lowercase()))
+    }
+
+    /// Checks one package under an organization scope.
+    async fn check_package(&self, package: &PackageInfo) -> AuditResult<Option<AuditFinding>> {
+        let name = package.name();
+        let manifest = package.package_json();
+        if manifest.private {
+            return Ok(None);
+        }
+
+        let registry = manifest
+            .publish_config
+            .as_ref()
+            .and_then(|publish_config| publish_config.get("registry"))
+            .cloned()
+            .unwrap_or_else(|| self.client.resolve_registry_url(name));
+
+        if is_public_registry(&registry) {
+            return Ok(Some(
+                AuditFinding::new(
+                    format!("{name} would be published to the public registry"),
+                    format!(
+                        "{name} is not marked private and its scope resolves to {registry}, so \
+                         publishing it would make it public."
+                    ),
+                )
+                .with_package(name)
+                .with_suggestion(
+                    "Map the scope to the private registry in .npmrc or \
+                     upgrade.registry.scoped_registries, or set \"private\": true",
+                ),
+            ));
+        }
+
+        if is_offline_mode() {
+            log::info!("Offline mode: registry ownership of '{}' not verified", name);
+            return Ok(None);
+        }
+
+        match self.client.fetch_package_metadata(name, &registry, MetadataDetail::Full).await {
+            Ok(metadata) => Ok(self.unowned_finding(name, &registry, &metadata.maintainers)),
+            Err(UpgradeError::PackageNotFound { .. }) => Ok(Some(
+                AuditFinding::new(
+                    format!("{name} is not published to {registry}"),
+                    format!(
+                        "{name} is not marked private and does not exist on {registry}. Until \
+                         the organization publishes it, the name can be claimed by anyone on a \
+                         registry the scope falls back to."
+                    ),
+                )
+                .with_package(name)
+                .with_suggestion(
+                    "Publish the package to the private registry or set \"private\": true",
+                ),
+            )),
+            Err(error) => Err(AuditError::RegistryError {
+                reason: format!("Failed to look up '{name}' on {registry}: {error}"),
+            }),
+        }
+    }
+
+    /// Returns a finding if none of the configured owners maintains the package.
+    fn unowned_finding(
+        &self,
+        name: &str,
+        registry: &str,
+        maintainers: &[String],
+    ) -> Option<AuditFinding> {
+        if self.owners.is_empty()
+            || maintainers.iter().any(|maintainer| {
+                self.owners.iter().any(|owner| owner.eq_ignore_ascii_case(maintainer))
+            })
+        {
+            return None;
+        }
+
+        let listed =
+            if maintainers.is_empty() { "none".to_string() } else { maintainers.join(", ") };
+        Some(
+            AuditFinding::new(
+                format!("{name} is not owned by the organization"),
+                format!(
+                    "{name} exists on {registry}, but none of the configured owners ({}) \
+                     maintains it. Maintainers: {listed}.",
+                    self.owners.join(", ")
+                ),
+            )
+            .with_package(name)
+            .with_suggestion(
+                "Verify who published the package and transfer it to the organization, or rename \
+                 the workspace package",
+            ),
+        )
+    }
+
+    /// Returns a finding if the package's scope looks like a typo of an organization scope.
+    fn near_miss_finding(&self, package: &PackageInfo) -> Option<AuditFinding> {
+        let name = package.name();
+        let scope = package_scope(name)?.to_lowercase();
+        let intended = self
+            .scopes
+            .iter()
+            .find(|candidate| candidate.len() >= 4 && edit_distance(candidate, &scope) == 1)?;
+
+        Some(
+            AuditFinding::new(
+                format!("{name} uses a scope resembling @{intended}"),
+                format!(
+                    "The scope @{scope} is one character away from the organization scope \
+                     @{intended}. Packages under it are not covered by the organization's \
+                     registry and are easily confused with its packages."
+                ),
+            )
+            .with_package(name)
+            .with_suggestion(format!("Rename the package to use the @{intended} scope")),
+        )
+    }
+}
+
+#[async_trait]
+impl AuditCheck for RegistryOwnershipCheck {
+    fn id(&self) -> &str {
+        REGISTRY_OWNERSHIP_CHECK_ID
+    }
+
+    fn category(&self) -> IssueCategory {
+        IssueCategory::Security
+    }
+
+    fn severity(&self) -> IssueSeverity {
+        IssueSeverity::Critical
+    }
+
+    async fn run(&self, context: &AuditCheckContext) -> AuditResult<Vec<AuditFinding>> {
+        let mut findings: Vec<AuditFinding> = context
+            .packages()
+            .iter()
+            .filter_map(|package| self.near_miss_finding(package))
+            .collect();
+
+        // Lookups are created up front so the stream holds no closures borrowing the packages
+        let lookups: Vec<_> = context
+            .packages()
+            .iter()
+            .filter(|package| self.is_organization_package(package))
+            .map(|package| self.check_package(package))
+            .collect();
+        let results: Vec<AuditResult<Option<AuditFinding>>> = stream::iter(lookups)
+            .buffered(context.config().audit.concurrency.max(1))
+            .collect()
+            .await;
+
+        for result in results {
+            findings.extend(result?);
+        }
+        Ok(findings)
+    }
+}
+
+/// Returns the scope of a package name, without the `@`.
+pub(crate) fn package_scope(name: &str) -> Option<&str> {
+    name.strip_prefix('@')?.split_once('/').map(|(scope, _)| scope)
+}
+
+/// Returns whether a registry URL points at the public npm registry.
+pub(crate) fn is_public_registry(registry: &str) -> bool {
+    let host = registry
+        .split_once("://")
+        .map_or(registry, |(_, rest)| rest)
+        .split(['/', ':'])
+        .next()
+        .unwrap_or_default();
+    PUBLIC_REGISTRY_HOSTS.iter().any(|public| host.eq_ignore_ascii_case(public))
+}
+
+/// Returns the Levenshtein distance between two strings.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
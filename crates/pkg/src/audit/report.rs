@@ -11,12 +11,13 @@
 //! **Why**: To provide a unified view of all audit findings that can be easily
 //! consumed by CLI tools, CI/CD pipelines, or other automation systems.
 
+use crate::audit::baseline::{AuditBaseline, BaselineComparison};
 use crate::audit::issue::{AuditIssue, IssueSeverity};
 use crate::audit::sections::{
     BreakingChangesAuditSection, DependencyAuditSection, DependencyCategorization,
     UpgradeAuditSection, VersionConsistencyAuditSection,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -221,6 +222,47 @@ impl AuditReport {
     pub fn total_issues(&self) -> usize {
         self.summary.total_issues
     }
+
+    /// Removes the findings suppressed by a baseline and recomputes the summary.
+    ///
+    /// # Arguments
+    ///
+    /// * `baseline` - Known findings
+    /// * `today` - Day used to decide which baseline entries have expired
+    ///
+    /// # Returns
+    ///
+    /// How the findings compared with the baseline, before suppressed ones were removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let baseline = AuditBaseline::load(&fs, Path::new(".audit-baseline.json")).await?;
+    /// let comparison = report.apply_baseline(&baseline, Utc::now().date_naive());
+    /// if comparison.new_findings > 0 {
+    ///     std::process::exit(1);
+    /// }
+    /// ```
+    pub fn apply_baseline(
+        &mut self,
+        baseline: &AuditBaseline,
+        today: NaiveDate,
+    ) -> BaselineComparison {
+        let comparison = baseline.compare(self.all_issues(), today);
+
+        let sections = &mut self.sections;
+        for issues in [
+            &mut sections.upgrades.issues,
+            &mut sections.dependencies.issues,
+            &mut sections.breaking_changes.issues,
+            &mut sections.version_consistency.issues,
+        ] {
+            issues.retain(|issue| !baseline.suppresses(issue, today));
+        }
+        self.summary = AuditSummary::from_sections(&self.sections);
+
+        comparison
+    }
 }
 
 /// All audit sections collected in one structure.
@@ -409,6 +451,13 @@ impl AuditSummary {
             ));
         }
 
+        if !sections.dependencies.package_collisions.is_empty() {
+            suggestions.push(format!(
+                "Resolve {} package collision(s)",
+                sections.dependencies.package_collisions.len()
+            ));
+        }
+
         if !sections.dependencies.version_conflicts.is_empty() {
             suggestions.push(format!(
                 "Fix {} version conflict(s)",
@@ -0,0 +1,193 @@
+//! Concurrent execution of audit checks.
+//!
+//! **What**: Provides `BuiltinCheck`, the checks built into `AuditManager`,
+//! `AuditCheckOutcome`, the result of one check as soon as it completes, and `AuditRun`, the
+//! collected results of a run.
+//!
+//! **How**: `AuditManager::check_stream` runs the requested checks, built-in or registered,
+//! concurrently, each bounded by `audit.check_timeout_secs`, and yields an outcome per check
+//! in completion order. A failed or timed-out check is recorded in its outcome instead of
+//! stopping the other checks.
+//!
+//! **Why**: On large workspaces the upgrade check spends most of its time waiting on the
+//! registry. Running checks side by side lets the local checks finish meanwhile, streaming
+//! outcomes lets front ends show results as they arrive, and the timeout keeps one slow
+//! network check from stalling the whole run.
+
+use crate::audit::checks::CustomCheckSection;
+use crate::audit::issue::AuditIssue;
+use crate::audit::sections::{
+    BreakingChangesAuditSection, DependencyAuditSection, UpgradeAuditSection,
+    VersionConsistencyAuditSection,
+};
+use crate::error::{AuditError, AuditResult};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// A check built into `AuditManager`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::BuiltinCheck;
+///
+/// assert_eq!(BuiltinCheck::ALL.len(), 4);
+/// assert_eq!(BuiltinCheck::VersionConsistency.id(), "version-consistency");
+/// assert_eq!(BuiltinCheck::from_id("upgrades"), Some(BuiltinCheck::Upgrades));
+/// assert_eq!(BuiltinCheck::VersionConsistency.to_string(), "version consistency");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BuiltinCheck {
+    /// Available dependency upgrades and deprecated packages.
+    Upgrades,
+    /// Circular dependencies and version conflicts.
+    Dependencies,
+    /// Inconsistent versions of internal dependencies.
+    VersionConsistency,
+    /// Breaking changes since the last release.
+    BreakingChanges,
+}
+
+impl BuiltinCheck {
+    /// Every built-in check, in report order.
+    pub const ALL: [Self; 4] =
+        [Self::Upgrades, Self::Dependencies, Self::VersionConsistency, Self::BreakingChanges];
+
+    /// Returns the id of the check, used in outcomes and as its `audit.checks` key.
+    #[must_use]
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Upgrades => "upgrades",
+            Self::Dependencies => "dependencies",
+            Self::VersionConsistency => "version-consistency",
+            Self::BreakingChanges => "breaking-changes",
+        }
+    }
+
+    /// Returns the built-in check with the given id.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|check| check.id() == id)
+    }
+}
+
+impl fmt::Display for BuiltinCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Upgrades => write!(f, "upgrades"),
+            Self::Dependencies => write!(f, "dependencies"),
+            Self::VersionConsistency => write!(f, "version consistency"),
+            Self::BreakingChanges => write!(f, "breaking changes"),
+        }
+    }
+}
+
+/// The section produced by a successful check.
+#[derive(Debug, Clone)]
+pub enum AuditCheckReport {
+    /// Result of [`BuiltinCheck::Upgrades`].
+    Upgrades(UpgradeAuditSection),
+    /// Result of [`BuiltinCheck::Dependencies`].
+    Dependencies(DependencyAuditSection),
+    /// Result of [`BuiltinCheck::VersionConsistency`].
+    VersionConsistency(VersionConsistencyAuditSection),
+    /// Result of [`BuiltinCheck::BreakingChanges`].
+    BreakingChanges(BreakingChangesAuditSection),
+    /// Result of a registered custom check.
+    Custom(CustomCheckSection),
+}
+
+impl AuditCheckReport {
+    /// Returns the issues found by the check.
+    #[must_use]
+    pub fn issues(&self) -> &[AuditIssue] {
+        match self {
+            Self::Upgrades(section) => &section.issues,
+            Self::Dependencies(section) => &section.issues,
+            Self::VersionConsistency(section) => &section.issues,
+            Self::BreakingChanges(section) => &section.issues,
+            Self::Custom(section) => &section.issues,
+        }
+    }
+
+    pub(crate) fn issues_mut(&mut self) -> &mut Vec<AuditIssue> {
+        match self {
+            Self::Upgrades(section) => &mut section.issues,
+            Self::Dependencies(section) => &mut section.issues,
+            Self::VersionConsistency(section) => &mut section.issues,
+            Self::BreakingChanges(section) => &mut section.issues,
+            Self::Custom(section) => &mut section.issues,
+        }
+    }
+}
+
+/// The result of one check, yielded as soon as the check completes.
+#[derive(Debug, Clone)]
+pub struct AuditCheckOutcome {
+    /// Id of the check that ran.
+    pub check: String,
+    /// How long the check ran, up to the timeout.
+    pub duration: Duration,
+    /// The section produced by the check, or why it failed.
+    ///
+    /// A check that exceeded `audit.check_timeout_secs` fails with `AuditError::Timeout`.
+    pub result: AuditResult<AuditCheckReport>,
+}
+
+/// The collected results of a run of audit checks.
+///
+/// Sections of built-in checks that did not run or failed are `None`; failures are listed
+/// in `failures` in completion order.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::{AuditCheckOutcome, AuditRun};
+/// use sublime_pkg_tools::error::AuditError;
+/// use std::time::Duration;
+///
+/// let mut run = AuditRun::default();
+/// run.record(AuditCheckOutcome {
+///     check: "upgrades".to_string(),
+///     duration: Duration::from_secs(30),
+///     result: Err(AuditError::Timeout { duration_secs: 30 }),
+/// });
+///
+/// assert!(run.upgrades.is_none());
+/// assert_eq!(run.failures.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AuditRun {
+    /// Result of the upgrades check.
+    pub upgrades: Option<UpgradeAuditSection>,
+    /// Result of the dependencies check.
+    pub dependencies: Option<DependencyAuditSection>,
+    /// Result of the version consistency check.
+    pub version_consistency: Option<VersionConsistencyAuditSection>,
+    /// Result of the breaking changes check.
+    pub breaking_changes: Option<BreakingChangesAuditSection>,
+    /// Results of custom checks, in completion order.
+    pub custom: Vec<CustomCheckSection>,
+    /// Ids of the checks that failed or timed out, with their errors.
+    pub failures: Vec<(String, AuditError)>,
+}
+
+impl AuditRun {
+    /// Stores the section or failure of a completed check.
+    pub fn record(&mut self, outcome: AuditCheckOutcome) {
+        match outcome.result {
+            Ok(AuditCheckReport::Upgrades(section)) => self.upgrades = Some(section),
+            Ok(AuditCheckReport::Dependencies(section)) => self.dependencies = Some(section),
+            Ok(AuditCheckReport::VersionConsistency(section)) => {
+                self.version_consistency = Some(section);
+            }
+            Ok(AuditCheckReport::BreakingChanges(section)) => {
+                self.breaking_changes = Some(section);
+            }
+            Ok(AuditCheckReport::Custom(section)) => self.custom.push(section),
+            Err(error) => self.failures.push((outcome.check, error)),
+        }
+    }
+}
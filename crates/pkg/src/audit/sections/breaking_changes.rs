@@ -778,6 +778,7 @@ fn generate_breaking_change_issues(
             affected_packages: vec![package_breaking.package_name.clone()],
             suggestion,
             metadata,
+            fix: None,
         });
     }
 
@@ -727,6 +727,7 @@ pub fn generate_categorization_issues(
                     meta.insert("used_by".to_string(), internal_pkg.used_by.join(", "));
                     meta
                 },
+                fix: None,
             });
         }
     }
@@ -762,6 +763,7 @@ pub fn generate_categorization_issues(
                 meta.insert("count".to_string(), categorization.workspace_links.len().to_string());
                 meta
             },
+            fix: None,
         });
     }
 
@@ -818,6 +820,7 @@ pub fn generate_categorization_issues(
                 meta.insert("total".to_string(), categorization.local_links.len().to_string());
                 meta
             },
+            fix: None,
         });
     }
 
@@ -848,6 +851,7 @@ pub fn generate_categorization_issues(
             meta.insert("external_percentage".to_string(), format!("{:.1}", categorization.external_percentage()));
             meta
         },
+        fix: None,
     });
 
     issues
@@ -1,7 +1,8 @@
 //! Dependency audit section for analyzing dependency graph health and conflicts.
 //!
 //! **What**: Provides functionality to audit internal package dependencies for circular
-//! dependencies, version conflicts, and other dependency graph issues.
+//! dependencies, version conflicts, package name and path collisions, workspace dependencies
+//! the lockfile resolves outside the workspace, and other dependency graph issues.
 //!
 //! **How**: Uses the `DependencyGraph` from the version module to detect circular dependencies
 //! using Tarjan's algorithm, and analyzes dependency version specifications across packages
@@ -12,12 +13,15 @@
 //! graph and resolve conflicts before they impact production.
 
 use crate::audit::issue::{AuditIssue, IssueCategory, IssueSeverity};
+use crate::audit::sections::lockfile::{Lockfile, LockfileResolution};
 use crate::config::PackageToolsConfig;
 use crate::error::{AuditError, AuditResult};
+use crate::types::dependency::spec_satisfied_by;
 use crate::types::{CircularDependency, DependencyType, PackageInfo};
 use crate::version::DependencyGraph;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Audit section containing dependency analysis results.
 ///
@@ -70,10 +74,18 @@ pub struct DependencyAuditSection {
     /// external package but with potentially incompatible version specifications.
     pub version_conflicts: Vec<VersionConflict>,
 
+    /// List of package name and path collisions detected in the workspace.
+    ///
+    /// Collisions make the dependency graph resolve a package name to the wrong
+    /// package without any error, so each one is reported as a Critical issue.
+    #[serde(default)]
+    pub package_collisions: Vec<PackageCollision>,
+
     /// List of audit issues generated from the dependency analysis.
     ///
     /// Issues are created based on detected problems:
     /// - Circular dependencies generate Critical issues
+    /// - Package collisions generate Critical issues
     /// - Version conflicts generate Warning issues
     pub issues: Vec<AuditIssue>,
 }
@@ -96,6 +108,7 @@ impl DependencyAuditSection {
         Self {
             circular_dependencies: Vec::new(),
             version_conflicts: Vec::new(),
+            package_collisions: Vec::new(),
             issues: Vec::new(),
         }
     }
@@ -130,6 +143,21 @@ impl DependencyAuditSection {
         !self.version_conflicts.is_empty()
     }
 
+    /// Returns whether any package name or path collisions were detected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::audit::DependencyAuditSection;
+    ///
+    /// let section = DependencyAuditSection::empty();
+    /// assert!(!section.has_package_collisions());
+    /// ```
+    #[must_use]
+    pub fn has_package_collisions(&self) -> bool {
+        !self.package_collisions.is_empty()
+    }
+
     /// Returns the number of critical issues.
     ///
     /// # Examples
@@ -344,7 +372,130 @@ pub struct VersionUsage {
     pub version_spec: String,
 }
 
-/// Audits dependency graph health for circular dependencies and version conflicts.
+/// A package name or directory claimed by more than one package.
+///
+/// The dependency graph keys packages by name and file mapping picks the first package
+/// directory containing a file, so collisions do not fail anything; they make changes
+/// and version bumps land on the wrong package.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::audit::PackageCollision;
+/// use std::path::PathBuf;
+///
+/// let collision = PackageCollision::DuplicateName {
+///     package_name: "@myorg/utils".to_string(),
+///     paths: vec![PathBuf::from("packages/utils"), PathBuf::from("legacy/utils")],
+/// };
+///
+/// assert_eq!(collision.package_name(), "@myorg/utils");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum PackageCollision {
+    /// Two or more directories declare the same package name.
+    DuplicateName {
+        /// The package name declared more than once.
+        package_name: String,
+        /// Directories declaring the name, relative to the workspace root.
+        paths: Vec<PathBuf>,
+    },
+
+    /// A package directory lies inside another package's directory, usually because
+    /// workspace patterns match overlapping directories.
+    NestedPath {
+        /// Name of the inner package.
+        package_name: String,
+        /// Directory of the inner package, relative to the workspace root.
+        path: PathBuf,
+        /// Name of the package whose directory contains it.
+        parent_name: String,
+        /// Directory of the outer package, relative to the workspace root.
+        parent_path: PathBuf,
+    },
+
+    /// An internal package is required with a range its workspace version does not
+    /// satisfy, so the package manager installs the registry package of the same name.
+    RegistryShadowing {
+        /// Name of the internal package.
+        package_name: String,
+        /// Version of the internal package in the workspace.
+        local_version: String,
+        /// Name of the package declaring the dependency.
+        dependent: String,
+        /// Version specification declared by the dependent.
+        version_spec: String,
+    },
+
+    /// The lockfile resolves a dependency on an internal package to something other than
+    /// the workspace package, usually a registry version.
+    LockfileShadowing {
+        /// Name of the internal package.
+        package_name: String,
+        /// Version of the internal package in the workspace.
+        local_version: String,
+        /// Name of the package declaring the dependency.
+        dependent: String,
+        /// Version specification declared by the dependent.
+        version_spec: String,
+        /// What the lockfile resolves the dependency to: a version or a `link:` target.
+        resolved: String,
+        /// File name of the lockfile, relative to the workspace root.
+        lockfile: String,
+    },
+}
+
+impl PackageCollision {
+    /// Returns the name of the package the collision is about.
+    #[must_use]
+    pub fn package_name(&self) -> &str {
+        match self {
+            Self::DuplicateName { package_name, .. }
+            | Self::NestedPath { package_name, .. }
+            | Self::RegistryShadowing { package_name, .. }
+            | Self::LockfileShadowing { package_name, .. } => package_name,
+        }
+    }
+
+    /// Returns a one-line description of the collision.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::DuplicateName { package_name, paths } => {
+                let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+                format!("'{}' is declared by {}", package_name, paths.join(", "))
+            }
+            Self::NestedPath { package_name, path, parent_name, parent_path } => format!(
+                "'{}' ({}) is inside '{}' ({})",
+                package_name,
+                path.display(),
+                parent_name,
+                parent_path.display()
+            ),
+            Self::RegistryShadowing { package_name, local_version, dependent, version_spec } => {
+                format!(
+                    "'{}' requires '{}@{}', which the workspace version {} does not satisfy",
+                    dependent, package_name, version_spec, local_version
+                )
+            }
+            Self::LockfileShadowing {
+                package_name,
+                local_version,
+                dependent,
+                version_spec,
+                resolved,
+                lockfile,
+            } => format!(
+                "{} resolves '{}@{}' of '{}' to {} instead of the workspace version {}",
+                lockfile, package_name, version_spec, dependent, resolved, local_version
+            ),
+        }
+    }
+}
+
+/// Audits dependency graph health for circular dependencies, version conflicts, and
+/// package collisions.
 ///
 /// This function performs comprehensive dependency analysis by building a dependency
 /// graph and checking for common issues that can cause problems in monorepo setups.
@@ -387,7 +538,7 @@ pub struct VersionUsage {
 /// # }
 /// ```
 pub async fn audit_dependencies(
-    _workspace_root: &std::path::Path,
+    workspace_root: &std::path::Path,
     packages: &[PackageInfo],
     config: &PackageToolsConfig,
 ) -> AuditResult<DependencyAuditSection> {
@@ -486,6 +637,37 @@ pub async fn audit_dependencies(
         }
     }
 
+    // Check for package name and path collisions if configured
+    if config.audit.dependencies.check_collisions {
+        section.package_collisions = detect_package_collisions(workspace_root, packages);
+    }
+
+    // Check the lockfile resolution of internal dependencies if configured
+    if config.audit.dependencies.check_lockfile
+        && let Some(lockfile) = Lockfile::read(workspace_root).await
+    {
+        let shadowed = detect_lockfile_shadowing(workspace_root, packages, &lockfile);
+
+        // The lockfile confirms predicted registry shadowing; report it once
+        let confirmed: HashSet<(&str, &str)> = shadowed
+            .iter()
+            .filter_map(|collision| match collision {
+                PackageCollision::LockfileShadowing { package_name, dependent, .. } => {
+                    Some((package_name.as_str(), dependent.as_str()))
+                }
+                _ => None,
+            })
+            .collect();
+        section.package_collisions.retain(|collision| match collision {
+            PackageCollision::RegistryShadowing { package_name, dependent, .. } => {
+                !confirmed.contains(&(package_name.as_str(), dependent.as_str()))
+            }
+            _ => true,
+        });
+        section.package_collisions.extend(shadowed);
+    }
+    section.issues.extend(section.package_collisions.iter().map(collision_issue));
+
     // TODO: will be implemented in story 10.4 (check_missing)
     // Missing dependencies require source code analysis to detect imports
     // that don't have corresponding package.json entries
@@ -497,6 +679,213 @@ pub async fn audit_dependencies(
     Ok(section)
 }
 
+/// Detects package names and directories claimed by more than one package.
+///
+/// Finds names declared by several directories, package directories nested inside
+/// another package, and internal packages required with a registry range their
+/// workspace version does not satisfy. Paths are reported relative to `workspace_root`.
+fn detect_package_collisions(
+    workspace_root: &Path,
+    packages: &[PackageInfo],
+) -> Vec<PackageCollision> {
+    let relative = |path: &Path| path.strip_prefix(workspace_root).unwrap_or(path).to_path_buf();
+    let mut collisions = Vec::new();
+
+    // Same name in several directories; the graph keeps whichever comes last
+    let mut by_name: BTreeMap<&str, Vec<&PackageInfo>> = BTreeMap::new();
+    for package in packages {
+        by_name.entry(package.name()).or_default().push(package);
+    }
+    for (name, declared) in &by_name {
+        if declared.len() > 1 {
+            let mut paths: Vec<PathBuf> = declared.iter().map(|p| relative(p.path())).collect();
+            paths.sort();
+            collisions
+                .push(PackageCollision::DuplicateName { package_name: name.to_string(), paths });
+        }
+    }
+
+    // Package directories inside other package directories
+    let mut nested = Vec::new();
+    for inner in packages {
+        for outer in packages {
+            if inner.path() != outer.path() && inner.path().starts_with(outer.path()) {
+                nested.push(PackageCollision::NestedPath {
+                    package_name: inner.name().to_string(),
+                    path: relative(inner.path()),
+                    parent_name: outer.name().to_string(),
+                    parent_path: relative(outer.path()),
+                });
+            }
+        }
+    }
+    nested.sort_by_key(PackageCollision::describe);
+    collisions.extend(nested);
+
+    // Internal names resolved from the registry instead of the workspace
+    for package in packages {
+        let json = package.package_json();
+        let declared = [
+            &json.dependencies,
+            &json.dev_dependencies,
+            &json.peer_dependencies,
+            &json.optional_dependencies,
+        ];
+        let mut shadowed: Vec<(&String, &String)> = declared
+            .into_iter()
+            .flatten()
+            .flat_map(|deps| deps.iter())
+            .filter(|(name, spec)| {
+                name.as_str() != package.name()
+                    && is_registry_spec(spec)
+                    && by_name
+                        .get(name.as_str())
+                        .is_some_and(|internal| !spec_satisfied_by(spec, &internal[0].version()))
+            })
+            .collect();
+        shadowed.sort();
+        shadowed.dedup();
+        for (name, spec) in shadowed {
+            if let Some(internal) = by_name.get(name.as_str()) {
+                collisions.push(PackageCollision::RegistryShadowing {
+                    package_name: name.clone(),
+                    local_version: internal[0].version().to_string(),
+                    dependent: package.name().to_string(),
+                    version_spec: spec.clone(),
+                });
+            }
+        }
+    }
+
+    collisions
+}
+
+/// Detects internal dependencies the lockfile resolves to something other than the
+/// workspace package.
+///
+/// Regular, dev, and optional dependencies are checked; peer dependencies are installed by
+/// the dependent's consumers. Dependencies the lockfile does not record are skipped.
+fn detect_lockfile_shadowing(
+    workspace_root: &Path,
+    packages: &[PackageInfo],
+    lockfile: &Lockfile,
+) -> Vec<PackageCollision> {
+    let relative = |path: &Path| {
+        let path = path.strip_prefix(workspace_root).unwrap_or(path);
+        path.components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+    let by_name: HashMap<&str, &PackageInfo> =
+        packages.iter().map(|package| (package.name(), package)).collect();
+
+    let mut collisions = Vec::new();
+    for package in packages {
+        let json = package.package_json();
+        let declared = [&json.dependencies, &json.dev_dependencies, &json.optional_dependencies];
+        let mut dependencies: Vec<(&String, &String)> =
+            declared.into_iter().flatten().flat_map(|deps| deps.iter()).collect();
+        dependencies.sort();
+        dependencies.dedup();
+
+        let dependent_dir = relative(package.path());
+        for (name, spec) in dependencies {
+            let Some(internal) = by_name.get(name.as_str()) else { continue };
+            if name.as_str() == package.name() {
+                continue;
+            }
+
+            let local_dir = relative(internal.path());
+            if let LockfileResolution::Elsewhere(resolved) =
+                lockfile.resolve(&dependent_dir, name, spec, &local_dir)
+            {
+                collisions.push(PackageCollision::LockfileShadowing {
+                    package_name: name.clone(),
+                    local_version: internal.version().to_string(),
+                    dependent: package.name().to_string(),
+                    version_spec: spec.clone(),
+                    resolved,
+                    lockfile: lockfile.file.to_string(),
+                });
+            }
+        }
+    }
+
+    collisions
+}
+
+/// Returns whether a version spec is resolved from the registry by name.
+///
+/// Protocol specs (`workspace:`, `file:`, `npm:` aliases, git and tarball URLs) and
+/// paths resolve elsewhere and cannot be shadowed.
+fn is_registry_spec(version_spec: &str) -> bool {
+    let spec = version_spec.trim();
+    !spec.is_empty() && !spec.contains(':') && !spec.contains('/')
+}
+
+/// Builds the critical audit issue reported for a package collision.
+fn collision_issue(collision: &PackageCollision) -> AuditIssue {
+    let (title, consequence, suggestion) = match collision {
+        PackageCollision::DuplicateName { package_name, .. } => (
+            format!("Duplicate package name '{}'", package_name),
+            "Only one of these packages ends up in the dependency graph, so dependents, \
+             changes, and version bumps of the others are silently attributed to it.",
+            "Rename one of the packages or exclude the stale directory from the workspace \
+             patterns.",
+        ),
+        PackageCollision::NestedPath { package_name, .. } => (
+            format!("Package '{}' is nested inside another package", package_name),
+            "Files of the inner package also belong to the outer one, so changes can be \
+             attributed to the wrong package.",
+            "Narrow the workspace patterns so they do not match directories inside other \
+             packages, or move the inner package out.",
+        ),
+        PackageCollision::RegistryShadowing { package_name, .. } => (
+            format!("Internal package '{}' is shadowed by the registry", package_name),
+            "The package manager installs the registry package of the same name instead of \
+             linking the workspace package, while the dependency graph still links them.",
+            "Use the workspace: protocol or a range the workspace version satisfies.",
+        ),
+        PackageCollision::LockfileShadowing { package_name, .. } => (
+            format!("Internal package '{}' is not linked by the lockfile", package_name),
+            "The package manager installs this copy instead of the workspace package, while \
+             the dependency graph still links them.",
+            "Use the workspace: protocol or a range the workspace version satisfies, then \
+             reinstall to update the lockfile.",
+        ),
+    };
+
+    let mut issue = AuditIssue::new(
+        IssueSeverity::Critical,
+        IssueCategory::Dependencies,
+        title,
+        format!("{}. {}", collision.describe(), consequence),
+    );
+    issue.add_affected_package(collision.package_name().to_string());
+    if let PackageCollision::RegistryShadowing { dependent, .. }
+    | PackageCollision::LockfileShadowing { dependent, .. } = collision
+    {
+        issue.add_affected_package(dependent.clone());
+    }
+    issue.set_suggestion(suggestion.to_string());
+    issue.add_metadata("collision".to_string(), collision_kind(collision).to_string());
+    if let PackageCollision::LockfileShadowing { resolved, lockfile, .. } = collision {
+        issue.add_metadata("lockfile".to_string(), lockfile.clone());
+        issue.add_metadata("resolved".to_string(), resolved.clone());
+    }
+    issue
+}
+
+fn collision_kind(collision: &PackageCollision) -> &'static str {
+    match collision {
+        PackageCollision::DuplicateName { .. } => "duplicate-name",
+        PackageCollision::NestedPath { .. } => "nested-path",
+        PackageCollision::RegistryShadowing { .. } => "registry-shadowing",
+        PackageCollision::LockfileShadowing { .. } => "lockfile-shadowing",
+    }
+}
+
 /// Detects version conflicts for external dependencies across packages.
 ///
 /// This function analyzes all external dependencies (non-workspace, non-local) used
@@ -0,0 +1,294 @@
+//! Workspace dependency resolution read from package manager lockfiles.
+//!
+//! **What**: Reads the lockfile at the workspace root and answers, for a workspace package
+//! declaring a dependency on another workspace package, whether the package manager linked
+//! the local package or installed something else in its place.
+//!
+//! **How**: The first lockfile found is parsed without installing anything:
+//! - `package-lock.json` / `npm-shrinkwrap.json` (lockfile v2 and v3): the `packages` map is
+//!   searched like Node resolves modules, from the dependent's `node_modules` up to the root,
+//!   and linked entries are compared with the local package directory
+//! - `pnpm-lock.yaml`: the dependent's importer lists each dependency as a `link:` path or a
+//!   resolved version
+//! - `yarn.lock` (classic and berry): the entry for the declared descriptor is either the
+//!   workspace or a registry resolution; classic lockfiles omit linked workspaces
+//!
+//! **Why**: A workspace dependency whose range the local version does not satisfy, or whose
+//! lockfile was never refreshed, silently installs a registry copy. The declared graph still
+//! links the packages, so only the lockfile shows what is actually used.
+
+use std::path::Path;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// Lockfiles read, in lookup order.
+const LOCKFILES: [&str; 4] =
+    ["pnpm-lock.yaml", "package-lock.json", "npm-shrinkwrap.json", "yarn.lock"];
+
+/// How a lockfile resolves a workspace dependency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum LockfileResolution {
+    /// The dependency links the local workspace package.
+    Linked,
+    /// The dependency resolves to something else, described by the version or link target.
+    Elsewhere(String),
+    /// The lockfile does not record the dependency.
+    Unknown,
+}
+
+/// A parsed lockfile.
+#[derive(Debug)]
+pub(crate) struct Lockfile {
+    /// File name of the lockfile, relative to the workspace root.
+    pub(crate) file: &'static str,
+    kind: LockfileKind,
+}
+
+#[derive(Debug)]
+enum LockfileKind {
+    Npm(serde_json::Map<String, serde_json::Value>),
+    Pnpm(serde_yaml::Mapping),
+    Yarn(Vec<YarnEntry>),
+}
+
+/// An entry of `yarn.lock`.
+#[derive(Debug, Default)]
+struct YarnEntry {
+    /// Descriptors resolved by the entry, e.g. `@scope/a@^1.0.0`.
+    descriptors: Vec<String>,
+    version: Option<String>,
+    /// `resolution` (berry) or `resolved` (classic).
+    resolution: Option<String>,
+}
+
+impl Lockfile {
+    /// Reads the lockfile of a workspace.
+    ///
+    /// Returns `None` when the workspace has no lockfile, or when it cannot be read or
+    /// parsed, which is logged as a warning.
+    pub(crate) async fn read(workspace_root: &Path) -> Option<Self> {
+        let fs = FileSystemManager::new();
+        for file in LOCKFILES {
+            let path = workspace_root.join(file);
+            if !fs.exists(&path).await {
+                continue;
+            }
+
+            let parsed = match fs.read_file_string(&path).await {
+                Ok(content) => Self::parse(file, &content),
+                Err(error) => Err(error.to_string()),
+            };
+            return match parsed {
+                Ok(lockfile) => Some(lockfile),
+                Err(error) => {
+                    log::warn!(
+                        "{}: {error}; skipping the lockfile resolution check",
+                        path.display()
+                    );
+                    None
+                }
+            };
+        }
+        None
+    }
+
+    /// Parses the content of a lockfile named `file`.
+    pub(crate) fn parse(file: &'static str, content: &str) -> Result<Self, String> {
+        let kind = match file {
+            "pnpm-lock.yaml" => {
+                let lockfile: serde_yaml::Value =
+                    serde_yaml::from_str(content).map_err(|e| e.to_string())?;
+                let importers = lockfile
+                    .get("importers")
+                    .and_then(serde_yaml::Value::as_mapping)
+                    .cloned()
+                    .unwrap_or_default();
+                LockfileKind::Pnpm(importers)
+            }
+            "yarn.lock" => LockfileKind::Yarn(parse_yarn_lock(content)),
+            _ => {
+                let lockfile: serde_json::Value =
+                    serde_json::from_str(content).map_err(|e| e.to_string())?;
+                let packages = lockfile
+                    .get("packages")
+                    .and_then(serde_json::Value::as_object)
+                    .cloned()
+                    .ok_or("lockfile version 1 is not supported; reinstall with npm 7 or later")?;
+                LockfileKind::Npm(packages)
+            }
+        };
+        Ok(Self { file, kind })
+    }
+
+    /// Resolves a dependency of a workspace package.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependent_dir` - Directory of the dependent, relative to the root (`""` for the root)
+    /// * `name` - Name of the dependency
+    /// * `version_spec` - Version specification declared by the dependent
+    /// * `local_dir` - Directory of the workspace package named `name`, relative to the root
+    pub(crate) fn resolve(
+        &self,
+        dependent_dir: &str,
+        name: &str,
+        version_spec: &str,
+        local_dir: &str,
+    ) -> LockfileResolution {
+        match &self.kind {
+            LockfileKind::Npm(packages) => resolve_npm(packages, dependent_dir, name, local_dir),
+            LockfileKind::Pnpm(importers) => {
+                resolve_pnpm(importers, dependent_dir, name, local_dir)
+            }
+            LockfileKind::Yarn(entries) => resolve_yarn(entries, name, version_spec),
+        }
+    }
+}
+
+/// Resolves a dependency like Node does, from the dependent's `node_modules` up to the root.
+fn resolve_npm(
+    packages: &serde_json::Map<String, serde_json::Value>,
+    dependent_dir: &str,
+    name: &str,
+    local_dir: &str,
+) -> LockfileResolution {
+    let mut dir = dependent_dir;
+    loop {
+        let key = if dir.is_empty() {
+            format!("node_modules/{name}")
+        } else {
+            format!("{dir}/node_modules/{name}")
+        };
+
+        if let Some(entry) = packages.get(&key) {
+            let field = |field: &str| entry.get(field).and_then(serde_json::Value::as_str);
+            if entry.get("link").and_then(serde_json::Value::as_bool) == Some(true) {
+                let target = field("resolved").unwrap_or_default();
+                return if target == local_dir {
+                    LockfileResolution::Linked
+                } else {
+                    LockfileResolution::Elsewhere(format!("link:{target}"))
+                };
+            }
+            return LockfileResolution::Elsewhere(
+                field("version").unwrap_or("unknown").to_string(),
+            );
+        }
+
+        if dir.is_empty() {
+            return LockfileResolution::Unknown;
+        }
+        dir = dir.rsplit_once('/').map_or("", |(parent, _)| parent);
+    }
+}
+
+/// Resolves a dependency from the dependent's pnpm importer.
+fn resolve_pnpm(
+    importers: &serde_yaml::Mapping,
+    dependent_dir: &str,
+    name: &str,
+    local_dir: &str,
+) -> LockfileResolution {
+    let importer = if dependent_dir.is_empty() { "." } else { dependent_dir };
+    let Some(importer) = importers.get(importer) else {
+        return LockfileResolution::Unknown;
+    };
+
+    let resolved = ["dependencies", "devDependencies", "optionalDependencies"]
+        .into_iter()
+        .filter_map(|section| importer.get(section)?.get(name))
+        .find_map(|dependency| {
+            // pnpm 6+ records `{ specifier, version }`, older lockfiles the version alone
+            dependency.get("version").unwrap_or(dependency).as_str()
+        });
+
+    match resolved {
+        Some(version) => match version.strip_prefix("link:") {
+            Some(target) if join_relative(dependent_dir, target) == local_dir => {
+                LockfileResolution::Linked
+            }
+            Some(_) => LockfileResolution::Elsewhere(version.to_string()),
+            None => {
+                // Drop the peer dependency suffix, e.g. `1.0.0(react@18.2.0)`
+                let version = version.split(['(', '_']).next().unwrap_or(version);
+                LockfileResolution::Elsewhere(version.to_string())
+            }
+        },
+        None => LockfileResolution::Unknown,
+    }
+}
+
+/// Resolves a dependency from the `yarn.lock` entry of its descriptor.
+fn resolve_yarn(entries: &[YarnEntry], name: &str, version_spec: &str) -> LockfileResolution {
+    let descriptors = [format!("{name}@{version_spec}"), format!("{name}@npm:{version_spec}")];
+    let Some(entry) =
+        entries.iter().find(|entry| entry.descriptors.iter().any(|d| descriptors.contains(d)))
+    else {
+        return LockfileResolution::Unknown;
+    };
+
+    let workspace = entry.resolution.as_deref().is_some_and(|r| r.contains("@workspace:"))
+        || entry.version.as_deref() == Some("0.0.0-use.local");
+    if workspace {
+        LockfileResolution::Linked
+    } else {
+        LockfileResolution::Elsewhere(
+            entry.version.clone().unwrap_or_else(|| "unknown".to_string()),
+        )
+    }
+}
+
+/// Parses the entries of a classic or berry `yarn.lock`.
+///
+/// Only the top-level keys and their `version` and `resolution`/`resolved` fields are read.
+fn parse_yarn_lock(content: &str) -> Vec<YarnEntry> {
+    let unquote = |value: &str| value.trim().trim_matches('"').to_string();
+    let mut entries = Vec::new();
+    let mut current: Option<YarnEntry> = None;
+
+    for line in content.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            entries.extend(current.take());
+            let key = line.trim_end().trim_end_matches(':');
+            let descriptors = key.split(", ").map(unquote).collect();
+            current = Some(YarnEntry { descriptors, ..YarnEntry::default() });
+            continue;
+        }
+
+        // Fields of the entry are indented by two spaces; deeper lines are nested maps
+        let Some(entry) = current.as_mut() else { continue };
+        let Some(field) = line.strip_prefix("  ").filter(|field| !field.starts_with(' ')) else {
+            continue;
+        };
+        let (key, value) = match field.split_once(": ") {
+            Some((key, value)) => (key, value),
+            None => field.split_once(' ').unwrap_or((field, "")),
+        };
+        match unquote(key).as_str() {
+            "version" => entry.version = Some(unquote(value)),
+            "resolution" | "resolved" => entry.resolution = Some(unquote(value)),
+            _ => {}
+        }
+    }
+
+    entries.extend(current);
+    entries
+}
+
+/// Joins a relative path to a directory, both using `/` separators.
+fn join_relative(dir: &str, relative: &str) -> String {
+    let mut components: Vec<&str> = dir.split('/').filter(|c| !c.is_empty()).collect();
+    for component in relative.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+    components.join("/")
+}
@@ -35,6 +35,9 @@ pub(crate) mod breaking_changes;
 // Version consistency section (Story 10.6 - IMPLEMENTED)
 pub(crate) mod version_consistency;
 
+// Lockfile resolution used by the dependencies section
+pub(crate) mod lockfile;
+
 // Public exports
 pub use breaking_changes::{
     BreakingChange, BreakingChangeSource, BreakingChangesAuditSection, PackageBreakingChanges,
@@ -44,7 +47,9 @@ pub use categorization::{
     CategorizationStats, DependencyCategorization, ExternalPackage, InternalPackage, LocalLink,
     LocalLinkType, WorkspaceLink, categorize_dependencies, generate_categorization_issues,
 };
-pub use dependencies::{DependencyAuditSection, VersionConflict, VersionUsage, audit_dependencies};
+pub use dependencies::{
+    DependencyAuditSection, PackageCollision, VersionConflict, VersionUsage, audit_dependencies,
+};
 pub use upgrades::{DeprecatedPackage, UpgradeAuditSection, audit_upgrades};
 pub use version_consistency::{
     VersionConsistencyAuditSection, VersionInconsistency, audit_version_consistency,
@@ -451,6 +451,24 @@ pub async fn audit_upgrades(
         }
     }
 
+    // Dependencies skipped in offline mode may hide upgrades
+    if !preview.offline_skipped.is_empty() {
+        let mut issue = AuditIssue::new(
+            IssueSeverity::Info,
+            IssueCategory::Upgrades,
+            "Upgrade check incomplete in offline mode".to_string(),
+            format!(
+                "Registry lookups were skipped for {} dependencies, so upgrades of these \
+                 dependencies are unknown: {}",
+                preview.offline_skipped.len(),
+                preview.offline_skipped.join(", ")
+            ),
+        );
+        issue.add_metadata("offline_skipped".to_string(), preview.offline_skipped.join(","));
+        issue.set_suggestion("Run the audit online to check these dependencies".to_string());
+        issues.push(issue);
+    }
+
     let total_upgrades = major_count + minor_count + patch_count;
 
     Ok(UpgradeAuditSection {
@@ -466,8 +484,9 @@ pub async fn audit_upgrades(
 
 /// Builds detection options from audit configuration.
 ///
-/// Configures which dependency types to check based on the audit configuration.
-fn build_detection_options(_config: &PackageToolsConfig) -> DetectionOptions {
+/// Configures which dependency types to check and how many registry lookups run at once
+/// based on the audit configuration.
+fn build_detection_options(config: &PackageToolsConfig) -> DetectionOptions {
     DetectionOptions {
         include_dependencies: true,
         include_dev_dependencies: true,
@@ -476,7 +495,9 @@ fn build_detection_options(_config: &PackageToolsConfig) -> DetectionOptions {
         package_filter: None,
         dependency_filter: None,
         include_prereleases: false,
-        concurrency: 10,
+        concurrency: config.audit.concurrency,
+        include_download_stats: false,
+        ..DetectionOptions::default()
     }
 }
 
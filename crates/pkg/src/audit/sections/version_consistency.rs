@@ -13,6 +13,7 @@
 //! which helps prevent version conflicts, simplifies dependency management, and ensures
 //! all packages work with compatible versions of internal dependencies.
 
+use crate::audit::fix::{FileEdit, Fix};
 use crate::audit::issue::{AuditIssue, IssueCategory, IssueSeverity};
 use crate::audit::sections::dependencies::VersionUsage;
 use crate::config::PackageToolsConfig;
@@ -20,6 +21,7 @@ use crate::error::{AuditError, AuditResult};
 use crate::types::PackageInfo;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Audit section containing version consistency analysis results.
 ///
@@ -450,8 +452,14 @@ pub async fn audit_version_consistency(
     // Detect inconsistencies
     let inconsistencies = detect_inconsistencies(internal_usage);
 
+    // Manifests the fixes edit, by package name
+    let manifests: HashMap<&str, PathBuf> = packages
+        .iter()
+        .map(|package| (package.name(), package.path().join("package.json")))
+        .collect();
+
     // Generate issues based on configuration
-    let issues = generate_issues(&inconsistencies, config);
+    let issues = generate_issues(&inconsistencies, config, &manifests);
 
     Ok(VersionConsistencyAuditSection { inconsistencies, issues })
 }
@@ -584,9 +592,12 @@ fn determine_recommended_version(
 /// - Critical if `fail_on_inconsistency` is true
 /// - Warning if `warn_on_inconsistency` is true
 /// - No issues if both are false
+///
+/// Each issue carries a fix that sets every reference to the recommended version.
 fn generate_issues(
     inconsistencies: &[VersionInconsistency],
     config: &PackageToolsConfig,
+    manifests: &HashMap<&str, PathBuf>,
 ) -> Vec<AuditIssue> {
     let mut issues = Vec::new();
 
@@ -641,6 +652,25 @@ fn generate_issues(
             issue.add_metadata(format!("version_{}_spec", idx), usage.version_spec.clone());
         }
 
+        // Fix: point every other reference at the recommended version
+        let mut fix = Fix::new(format!(
+            "Use '{}' for every reference to '{}'",
+            inconsistency.recommended_version, inconsistency.package_name
+        ));
+        for usage in &inconsistency.versions_used {
+            if usage.version_spec == inconsistency.recommended_version {
+                continue;
+            }
+            if let Some(manifest) = manifests.get(usage.package_name.as_str()) {
+                fix = fix.with_edit(FileEdit::SetDependency {
+                    path: manifest.clone(),
+                    dependency: inconsistency.package_name.clone(),
+                    version_spec: inconsistency.recommended_version.clone(),
+                });
+            }
+        }
+        issue.set_fix(fix);
+
         issues.push(issue);
     }
 
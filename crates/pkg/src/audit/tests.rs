@@ -144,11 +144,13 @@ mod tests {
                     affected_packages: vec!["foo".to_string()],
                     suggestion: Some("Review breaking changes before upgrading".to_string()),
                     metadata: HashMap::new(),
+                    fix: None,
                 }],
             },
             dependencies: DependencyAuditSection {
                 circular_dependencies: vec![],
                 version_conflicts: vec![],
+                package_collisions: vec![],
                 issues: vec![AuditIssue {
                     severity: IssueSeverity::Critical,
                     category: IssueCategory::Dependencies,
@@ -157,6 +159,7 @@ mod tests {
                     affected_packages: vec!["pkg-a".to_string(), "pkg-b".to_string()],
                     suggestion: Some("Refactor to remove circular dependency".to_string()),
                     metadata: HashMap::new(),
+                    fix: None,
                 }],
             },
             breaking_changes: BreakingChangesAuditSection {
@@ -985,6 +988,7 @@ mod tests {
             affected_packages: vec!["pkg-a".to_string()],
             suggestion: Some("Fix it".to_string()),
             metadata,
+            fix: None,
         };
 
         let json_result = serde_json::to_string(&issue);
@@ -1404,6 +1408,408 @@ mod tests {
         assert_eq!(section.warning_issue_count(), 0);
     }
 
+    #[tokio::test]
+    async fn test_audit_dependencies_detects_duplicate_names() {
+        use crate::audit::sections::dependencies::{PackageCollision, audit_dependencies};
+        use crate::config::PackageToolsConfig;
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+        use std::path::PathBuf;
+
+        let package_a_json = PackageJson {
+            name: "pkg-a".to_string(),
+            version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+        let package_a_copy_json = PackageJson {
+            name: "pkg-a".to_string(),
+            version: "2.0.0".to_string(),
+            ..Default::default()
+        };
+
+        let packages = vec![
+            PackageInfo::new(package_a_json, None, PathBuf::from("packages/a")),
+            PackageInfo::new(package_a_copy_json, None, PathBuf::from("legacy/a")),
+        ];
+
+        let config = PackageToolsConfig::default();
+        let workspace_root = PathBuf::from(".");
+
+        let section =
+            audit_dependencies(&workspace_root, &packages, &config).await.expect("Should succeed");
+
+        assert!(section.has_package_collisions());
+        assert_eq!(
+            section.package_collisions,
+            vec![PackageCollision::DuplicateName {
+                package_name: "pkg-a".to_string(),
+                paths: vec![PathBuf::from("legacy/a"), PathBuf::from("packages/a")],
+            }]
+        );
+        assert_eq!(section.critical_issue_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_detects_nested_packages() {
+        use crate::audit::sections::dependencies::{PackageCollision, audit_dependencies};
+        use crate::config::PackageToolsConfig;
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+        use std::path::PathBuf;
+
+        let outer_json = PackageJson {
+            name: "pkg-outer".to_string(),
+            version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+        let inner_json = PackageJson {
+            name: "pkg-inner".to_string(),
+            version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+
+        let packages = vec![
+            PackageInfo::new(outer_json, None, PathBuf::from("packages/outer")),
+            PackageInfo::new(inner_json, None, PathBuf::from("packages/outer/inner")),
+        ];
+
+        let config = PackageToolsConfig::default();
+        let workspace_root = PathBuf::from(".");
+
+        let section =
+            audit_dependencies(&workspace_root, &packages, &config).await.expect("Should succeed");
+
+        assert_eq!(
+            section.package_collisions,
+            vec![PackageCollision::NestedPath {
+                package_name: "pkg-inner".to_string(),
+                path: PathBuf::from("packages/outer/inner"),
+                parent_name: "pkg-outer".to_string(),
+                parent_path: PathBuf::from("packages/outer"),
+            }]
+        );
+        assert_eq!(section.critical_issue_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_detects_registry_shadowing() {
+        use crate::audit::sections::dependencies::{PackageCollision, audit_dependencies};
+        use crate::config::PackageToolsConfig;
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+        use std::path::PathBuf;
+
+        // pkg-a asks for a range the local pkg-b does not satisfy, so it installs from
+        // the registry; pkg-c uses the workspace protocol and is not reported
+        let package_a_json = PackageJson {
+            name: "pkg-a".to_string(),
+            version: "1.0.0".to_string(),
+            dependencies: Some([("pkg-b".to_string(), "^1.0.0".to_string())].into_iter().collect()),
+            ..Default::default()
+        };
+        let package_b_json = PackageJson {
+            name: "pkg-b".to_string(),
+            version: "2.0.0".to_string(),
+            ..Default::default()
+        };
+        let package_c_json = PackageJson {
+            name: "pkg-c".to_string(),
+            version: "1.0.0".to_string(),
+            dependencies: Some(
+                [("pkg-b".to_string(), "workspace:*".to_string())].into_iter().collect(),
+            ),
+            ..Default::default()
+        };
+
+        let packages = vec![
+            PackageInfo::new(package_a_json, None, PathBuf::from("packages/a")),
+            PackageInfo::new(package_b_json, None, PathBuf::from("packages/b")),
+            PackageInfo::new(package_c_json, None, PathBuf::from("packages/c")),
+        ];
+
+        let config = PackageToolsConfig::default();
+        let workspace_root = PathBuf::from(".");
+
+        let section =
+            audit_dependencies(&workspace_root, &packages, &config).await.expect("Should succeed");
+
+        assert_eq!(
+            section.package_collisions,
+            vec![PackageCollision::RegistryShadowing {
+                package_name: "pkg-b".to_string(),
+                local_version: "2.0.0".to_string(),
+                dependent: "pkg-a".to_string(),
+                version_spec: "^1.0.0".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_collisions_disabled() {
+        use crate::audit::sections::dependencies::audit_dependencies;
+        use crate::config::PackageToolsConfig;
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+        use std::path::PathBuf;
+
+        let package_a_json = PackageJson {
+            name: "pkg-a".to_string(),
+            version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+        let package_a_copy_json = PackageJson {
+            name: "pkg-a".to_string(),
+            version: "1.0.0".to_string(),
+            ..Default::default()
+        };
+
+        let packages = vec![
+            PackageInfo::new(package_a_json, None, PathBuf::from("packages/a")),
+            PackageInfo::new(package_a_copy_json, None, PathBuf::from("packages/a/nested")),
+        ];
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.dependencies.check_collisions = false;
+        let workspace_root = PathBuf::from(".");
+
+        let section =
+            audit_dependencies(&workspace_root, &packages, &config).await.expect("Should succeed");
+
+        assert!(!section.has_package_collisions());
+        assert_eq!(section.critical_issue_count(), 0);
+    }
+
+    /// Builds `app` depending on `lib@^1.0.0` and `ui@^1.0.0` in a temp workspace with the
+    /// given lockfile; `lib` and `ui` are both at 1.2.0 locally.
+    fn lockfile_workspace(
+        lockfile: &str,
+        content: &str,
+    ) -> (TempDir, Vec<crate::types::PackageInfo>) {
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+
+        let temp = TempDir::new().expect("Should create temp dir");
+        std::fs::write(temp.path().join(lockfile), content).expect("Should write lockfile");
+
+        let package = |name: &str, dependencies: &[&str]| {
+            let json = PackageJson {
+                name: name.to_string(),
+                version: "1.2.0".to_string(),
+                dependencies: (!dependencies.is_empty()).then(|| {
+                    dependencies.iter().map(|dep| (dep.to_string(), "^1.0.0".to_string())).collect()
+                }),
+                ..Default::default()
+            };
+            PackageInfo::new(json, None, temp.path().join("packages").join(name))
+        };
+        let packages =
+            vec![package("app", &["lib", "ui"]), package("lib", &[]), package("ui", &[])];
+        (temp, packages)
+    }
+
+    /// Returns the `(package, dependent, resolved)` of each lockfile shadowing collision.
+    fn lockfile_shadowing(
+        section: &crate::audit::sections::dependencies::DependencyAuditSection,
+    ) -> Vec<(String, String, String)> {
+        use crate::audit::sections::dependencies::PackageCollision;
+
+        section
+            .package_collisions
+            .iter()
+            .filter_map(|collision| match collision {
+                PackageCollision::LockfileShadowing {
+                    package_name, dependent, resolved, ..
+                } => Some((package_name.clone(), dependent.clone(), resolved.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_lockfile_npm() {
+        use crate::audit::sections::dependencies::audit_dependencies;
+
+        // lib is linked; ui was installed from the registry under app's node_modules
+        let lockfile = r#"{
+            "name": "root",
+            "lockfileVersion": 3,
+            "packages": {
+                "": { "workspaces": ["packages/*"] },
+                "node_modules/lib": { "resolved": "packages/lib", "link": true },
+                "node_modules/ui": { "resolved": "packages/ui", "link": true },
+                "packages/app/node_modules/ui": {
+                    "version": "1.1.0",
+                    "resolved": "https://registry.npmjs.org/ui/-/ui-1.1.0.tgz"
+                }
+            }
+        }"#;
+        let (temp, packages) = lockfile_workspace("package-lock.json", lockfile);
+
+        let section = audit_dependencies(temp.path(), &packages, &PackageToolsConfig::default())
+            .await
+            .expect("Should succeed");
+
+        assert_eq!(
+            lockfile_shadowing(&section),
+            vec![("ui".to_string(), "app".to_string(), "1.1.0".to_string())]
+        );
+        let issue = section
+            .issues
+            .iter()
+            .find(|issue| {
+                issue.metadata.get("collision").map(String::as_str) == Some("lockfile-shadowing")
+            })
+            .expect("Should report an issue");
+        assert!(issue.is_critical());
+        assert_eq!(issue.metadata.get("lockfile").map(String::as_str), Some("package-lock.json"));
+        assert!(issue.affected_packages.contains(&"app".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_lockfile_pnpm() {
+        use crate::audit::sections::dependencies::audit_dependencies;
+
+        let lockfile = r"lockfileVersion: '9.0'
+
+importers:
+
+  .: {}
+
+  packages/app:
+    dependencies:
+      lib:
+        specifier: ^1.0.0
+        version: link:../lib
+      ui:
+        specifier: ^1.0.0
+        version: 1.0.3(react@18.2.0)
+
+  packages/lib: {}
+
+  packages/ui: {}
+";
+        let (temp, packages) = lockfile_workspace("pnpm-lock.yaml", lockfile);
+
+        let section = audit_dependencies(temp.path(), &packages, &PackageToolsConfig::default())
+            .await
+            .expect("Should succeed");
+
+        assert_eq!(
+            lockfile_shadowing(&section),
+            vec![("ui".to_string(), "app".to_string(), "1.0.3".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_lockfile_yarn() {
+        use crate::audit::sections::dependencies::audit_dependencies;
+
+        let berry = r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 8
+  cacheKey: 10
+
+"app@workspace:packages/app":
+  version: 0.0.0-use.local
+  resolution: "app@workspace:packages/app"
+  dependencies:
+    lib: "npm:^1.0.0"
+  languageName: unknown
+  linkType: soft
+
+"lib@npm:^1.0.0, lib@workspace:packages/lib":
+  version: 0.0.0-use.local
+  resolution: "lib@workspace:packages/lib"
+  languageName: unknown
+  linkType: soft
+
+"ui@npm:^1.0.0":
+  version: 1.0.9
+  resolution: "ui@npm:1.0.9"
+  languageName: node
+  linkType: hard
+"#;
+        let (temp, packages) = lockfile_workspace("yarn.lock", berry);
+        let section = audit_dependencies(temp.path(), &packages, &PackageToolsConfig::default())
+            .await
+            .expect("Should succeed");
+        assert_eq!(
+            lockfile_shadowing(&section),
+            vec![("ui".to_string(), "app".to_string(), "1.0.9".to_string())]
+        );
+
+        // Classic lockfiles only list registry resolutions
+        let classic = r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"ui@^1.0.0":
+  version "1.0.4"
+  resolved "https://registry.yarnpkg.com/ui/-/ui-1.0.4.tgz#abc"
+  integrity sha512-abc
+"#;
+        let (temp, packages) = lockfile_workspace("yarn.lock", classic);
+        let section = audit_dependencies(temp.path(), &packages, &PackageToolsConfig::default())
+            .await
+            .expect("Should succeed");
+        assert_eq!(
+            lockfile_shadowing(&section),
+            vec![("ui".to_string(), "app".to_string(), "1.0.4".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_audit_dependencies_lockfile_replaces_predicted_shadowing() {
+        use crate::audit::sections::dependencies::{PackageCollision, audit_dependencies};
+        use crate::types::PackageInfo;
+        use package_json::PackageJson;
+
+        let lockfile = r#"{
+            "lockfileVersion": 3,
+            "packages": {
+                "node_modules/lib": { "version": "1.0.0" }
+            }
+        }"#;
+        let (temp, _) = lockfile_workspace("package-lock.json", lockfile);
+        let app = PackageJson {
+            name: "app".to_string(),
+            version: "1.0.0".to_string(),
+            dependencies: Some([("lib".to_string(), "^1.0.0".to_string())].into_iter().collect()),
+            ..Default::default()
+        };
+        let lib = PackageJson {
+            name: "lib".to_string(),
+            version: "2.0.0".to_string(),
+            ..Default::default()
+        };
+        let packages = vec![
+            PackageInfo::new(app, None, temp.path().join("packages/app")),
+            PackageInfo::new(lib, None, temp.path().join("packages/lib")),
+        ];
+
+        let section = audit_dependencies(temp.path(), &packages, &PackageToolsConfig::default())
+            .await
+            .expect("Should succeed");
+
+        // The range is unsatisfied and the lockfile confirms it; one collision is reported
+        assert_eq!(section.package_collisions.len(), 1);
+        assert!(matches!(
+            &section.package_collisions[0],
+            PackageCollision::LockfileShadowing { resolved, .. } if resolved == "1.0.0"
+        ));
+        assert_eq!(section.critical_issue_count(), 1);
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.dependencies.check_lockfile = false;
+        let section =
+            audit_dependencies(temp.path(), &packages, &config).await.expect("Should succeed");
+        assert!(matches!(
+            &section.package_collisions[..],
+            [PackageCollision::RegistryShadowing { .. }]
+        ));
+    }
+
     #[tokio::test]
     async fn test_audit_dependencies_ignores_workspace_protocol() {
         use crate::audit::sections::dependencies::audit_dependencies;
@@ -3367,4 +3773,829 @@ mod tests {
         assert_eq!(deserialized.critical_weight, weights.critical_weight);
         assert_eq!(deserialized.security_multiplier, weights.security_multiplier);
     }
+
+    // ==================== Dashboard Tests ====================
+
+    #[tokio::test]
+    async fn test_dashboard_reports_dirty_packages_and_counts() {
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        // Leave an uncommitted file in the package
+        let fs = FileSystemManager::new();
+        fs.write_file(&workspace_path.join("index.js"), b"module.exports = {};")
+            .await
+            .expect("Failed to write index.js");
+
+        let config = PackageToolsConfig::default();
+        let manager =
+            AuditManager::new(workspace_path, config).await.expect("Manager should be initialized");
+
+        let dashboard = manager.dashboard().await.expect("Dashboard should be collected");
+
+        assert_eq!(dashboard.package_count, 1);
+        assert!(!dashboard.is_monorepo);
+        assert_eq!(dashboard.dirty_packages, vec!["test-package".to_string()]);
+        assert!(dashboard.has_dirty_packages());
+        assert_eq!(dashboard.changesets.count, 0);
+        assert!(dashboard.oldest_changeset_age().is_none());
+        assert_eq!(dashboard.outdated.map(|o| o.total()), Some(0));
+        assert_eq!(dashboard.graph.max_depth, 0);
+        assert_eq!(dashboard.graph.cycle_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_dashboard_skips_outdated_when_upgrades_disabled() {
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.sections.upgrades = false;
+
+        let manager =
+            AuditManager::new(workspace_path, config).await.expect("Manager should be initialized");
+
+        let dashboard = manager.dashboard().await.expect("Dashboard should be collected");
+
+        assert!(dashboard.outdated.is_none());
+        assert!(!dashboard.has_dirty_packages());
+    }
+
+    #[test]
+    fn test_pending_changeset_stats_uses_oldest_changeset() {
+        use crate::audit::PendingChangesetStats;
+        use crate::types::{Changeset, VersionBump};
+
+        let now = chrono::Utc::now();
+        let mut older = Changeset::new("feature-a", VersionBump::Minor, vec![]);
+        older.created_at = now - chrono::Duration::days(3);
+        let mut newer = Changeset::new("feature-b", VersionBump::Patch, vec![]);
+        newer.created_at = now - chrono::Duration::hours(1);
+
+        let stats = PendingChangesetStats::from_changesets(&[newer, older.clone()], now);
+
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.oldest_created_at, Some(older.created_at));
+        assert_eq!(stats.oldest_age_secs, Some(3 * 86_400));
+
+        let empty = PendingChangesetStats::from_changesets(&[], now);
+        assert_eq!(empty, PendingChangesetStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_run_checks_records_sections_and_failures() {
+        use crate::error::AuditError;
+
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.sections.upgrades = false;
+
+        let manager =
+            AuditManager::new(workspace_path, config).await.expect("Manager should be initialized");
+
+        let run = manager.run_checks(&["upgrades", "dependencies", "version-consistency"]).await;
+
+        assert!(run.upgrades.is_none());
+        assert!(run.dependencies.is_some());
+        assert!(run.version_consistency.is_some());
+        assert!(run.breaking_changes.is_none());
+        assert_eq!(run.failures.len(), 1);
+        assert_eq!(run.failures[0].0, "upgrades");
+        assert!(matches!(run.failures[0].1, AuditError::SectionDisabled { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_check_stream_yields_one_outcome_per_check() {
+        use futures::StreamExt;
+
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.concurrency = 1;
+
+        let manager =
+            AuditManager::new(workspace_path, config).await.expect("Manager should be initialized");
+
+        let checks = ["dependencies", "version-consistency"];
+        let outcomes: Vec<_> = manager.check_stream(&checks).collect().await;
+
+        assert_eq!(outcomes.len(), 2);
+        for outcome in &outcomes {
+            assert!(checks.contains(&outcome.check.as_str()));
+            let report = outcome.result.as_ref().expect("Check should succeed");
+            assert!(report.issues().is_empty());
+        }
+    }
+
+    /// Custom check reporting every package of the workspace.
+    struct EveryPackage;
+
+    #[async_trait::async_trait]
+    impl crate::audit::AuditCheck for EveryPackage {
+        fn id(&self) -> &str {
+            "every-package"
+        }
+
+        fn category(&self) -> IssueCategory {
+            IssueCategory::Other
+        }
+
+        fn severity(&self) -> IssueSeverity {
+            IssueSeverity::Info
+        }
+
+        async fn run(
+            &self,
+            context: &crate::audit::AuditCheckContext,
+        ) -> crate::error::AuditResult<Vec<crate::audit::AuditFinding>> {
+            Ok(context
+                .packages()
+                .iter()
+                .map(|package| {
+                    crate::audit::AuditFinding::new("Package found", "Reported by a custom check")
+                        .with_package(package.name())
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_check_runs_alongside_builtin_checks() {
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let mut manager = AuditManager::new(workspace_path, PackageToolsConfig::default())
+            .await
+            .expect("Manager should be initialized");
+        manager.register_check(EveryPackage).expect("Check should register");
+
+        assert_eq!(manager.check_ids().last(), Some(&"every-package"));
+
+        let run = manager.run_checks(&["dependencies", "every-package"]).await;
+
+        assert!(run.failures.is_empty());
+        assert!(run.dependencies.is_some());
+        assert_eq!(run.custom.len(), 1);
+        let section = &run.custom[0];
+        assert_eq!(section.id, "every-package");
+        assert_eq!(section.issues.len(), 1);
+        assert_eq!(section.issues[0].severity, IssueSeverity::Info);
+        assert_eq!(section.issues[0].category, IssueCategory::Other);
+        assert_eq!(section.issues[0].affected_packages, vec!["test-package".to_string()]);
+        assert_eq!(
+            section.issues[0].metadata.get("check").map(String::as_str),
+            Some("every-package")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_check_overrides_disable_and_change_severity() {
+        use crate::config::AuditCheckConfig;
+
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.checks.insert(
+            "every-package".to_string(),
+            AuditCheckConfig { enabled: true, severity: Some("critical".to_string()) },
+        );
+        config.audit.checks.insert(
+            "dependencies".to_string(),
+            AuditCheckConfig { enabled: false, severity: None },
+        );
+
+        let mut manager =
+            AuditManager::new(workspace_path, config).await.expect("Manager should be initialized");
+        manager.register_check(EveryPackage).expect("Check should register");
+
+        let run = manager.run_checks(&manager.check_ids()).await;
+
+        assert!(run.dependencies.is_none());
+        assert!(!run.failures.iter().any(|(id, _)| id == "dependencies"));
+        assert_eq!(run.custom.len(), 1);
+        assert!(run.custom[0].issues.iter().all(|issue| issue.severity == IssueSeverity::Critical));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_check_id_fails_its_outcome() {
+        use crate::error::AuditError;
+
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let manager = AuditManager::new(workspace_path, PackageToolsConfig::default())
+            .await
+            .expect("Manager should be initialized");
+
+        let run = manager.run_checks(&["no-such-check"]).await;
+
+        assert_eq!(run.failures.len(), 1);
+        assert!(
+            matches!(&run.failures[0].1, AuditError::UnknownCheck { id } if id == "no-such-check")
+        );
+    }
+
+    #[test]
+    fn test_check_registry_rejects_duplicate_and_builtin_ids() {
+        use crate::audit::AuditCheckRegistry;
+        use crate::error::AuditError;
+
+        /// Custom check reusing a built-in id.
+        struct FakeUpgrades;
+
+        #[async_trait::async_trait]
+        impl crate::audit::AuditCheck for FakeUpgrades {
+            fn id(&self) -> &str {
+                "upgrades"
+            }
+
+            fn category(&self) -> IssueCategory {
+                IssueCategory::Upgrades
+            }
+
+            fn severity(&self) -> IssueSeverity {
+                IssueSeverity::Info
+            }
+
+            async fn run(
+                &self,
+                _context: &crate::audit::AuditCheckContext,
+            ) -> crate::error::AuditResult<Vec<crate::audit::AuditFinding>> {
+                Ok(Vec::new())
+            }
+        }
+
+        let mut registry = AuditCheckRegistry::new();
+        registry.register(EveryPackage).expect("Check should register");
+
+        assert!(matches!(
+            registry.register(EveryPackage),
+            Err(AuditError::DuplicateCheck { id }) if id == "every-package"
+        ));
+        assert!(matches!(
+            registry.register(FakeUpgrades),
+            Err(AuditError::DuplicateCheck { id }) if id == "upgrades"
+        ));
+        assert_eq!(registry.ids(), vec!["every-package"]);
+    }
+
+    // ==================== Fix Tests ====================
+
+    fn write_manifest(dir: &std::path::Path, name: &str, deps: &[(&str, &str)]) -> PathBuf {
+        let dependencies: serde_json::Map<String, serde_json::Value> = deps
+            .iter()
+            .map(|(dep, spec)| ((*dep).to_string(), serde_json::Value::String((*spec).to_string())))
+            .collect();
+        let manifest = serde_json::json!({
+            "name": name,
+            "version": "1.0.0",
+            "dependencies": dependencies,
+        });
+
+        std::fs::create_dir_all(dir).expect("Failed to create package dir");
+        let path = dir.join("package.json");
+        std::fs::write(&path, serde_json::to_string_pretty(&manifest).unwrap() + "\n")
+            .expect("Failed to write package.json");
+        path
+    }
+
+    fn fixer_for(root: &std::path::Path) -> crate::audit::AuditFixer<FileSystemManager> {
+        crate::audit::AuditFixer::new(
+            root.to_path_buf(),
+            crate::config::BackupConfig::default(),
+            FileSystemManager::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fix_plan_renders_diff_without_writing() {
+        use crate::audit::{FileEdit, Fix};
+
+        let temp = TempDir::new().unwrap();
+        let manifest =
+            write_manifest(&temp.path().join("packages/app"), "app", &[("@myorg/core", "^1.0.0")]);
+        let original = std::fs::read_to_string(&manifest).unwrap();
+
+        let fix = Fix::new("Use workspace:*").with_edit(FileEdit::SetDependency {
+            path: PathBuf::from("packages/app/package.json"),
+            dependency: "@myorg/core".to_string(),
+            version_spec: "workspace:*".to_string(),
+        });
+
+        let plan = fixer_for(temp.path()).plan(&[&fix]).await.expect("Plan should succeed");
+        assert_eq!(plan.fixes, vec!["Use workspace:*".to_string()]);
+        assert_eq!(plan.files.len(), 1);
+        assert!(plan.files[0].updated.contains("\"@myorg/core\": \"workspace:*\""));
+
+        let diff = plan.diff();
+        assert!(diff.contains("--- a/packages/app/package.json"));
+        assert!(diff.contains("+++ b/packages/app/package.json"));
+        assert!(diff.contains("-    \"@myorg/core\": \"^1.0.0\""));
+        assert!(diff.contains("+    \"@myorg/core\": \"workspace:*\""));
+
+        assert_eq!(std::fs::read_to_string(&manifest).unwrap(), original);
+    }
+
+    #[tokio::test]
+    async fn test_fix_plan_combines_edits_to_the_same_file() {
+        use crate::audit::{FileEdit, Fix};
+
+        let temp = TempDir::new().unwrap();
+        write_manifest(temp.path(), "app", &[("a", "^1.0.0"), ("b", "^1.0.0")]);
+
+        let first = Fix::new("Bump a").with_edit(FileEdit::SetDependency {
+            path: PathBuf::from("package.json"),
+            dependency: "a".to_string(),
+            version_spec: "^2.0.0".to_string(),
+        });
+        let second = Fix::new("Bump b").with_edit(FileEdit::SetDependency {
+            path: PathBuf::from("package.json"),
+            dependency: "b".to_string(),
+            version_spec: "^3.0.0".to_string(),
+        });
+        let noop = Fix::new("Keep a").with_edit(FileEdit::Replace {
+            path: PathBuf::from("package.json"),
+            old: "\"name\"".to_string(),
+            new: "\"name\"".to_string(),
+        });
+
+        let plan = fixer_for(temp.path())
+            .plan(&[&first, &second, &noop])
+            .await
+            .expect("Plan should succeed");
+        assert_eq!(plan.fixes.len(), 3);
+        assert_eq!(plan.files.len(), 1);
+        assert!(plan.files[0].updated.contains("\"a\": \"^2.0.0\""));
+        assert!(plan.files[0].updated.contains("\"b\": \"^3.0.0\""));
+    }
+
+    #[tokio::test]
+    async fn test_fix_plan_fails_when_edit_does_not_apply() {
+        use crate::audit::{FileEdit, Fix};
+        use crate::error::AuditError;
+
+        let temp = TempDir::new().unwrap();
+        write_manifest(temp.path(), "app", &[("a", "^1.0.0")]);
+        let fixer = fixer_for(temp.path());
+
+        let missing_text = Fix::new("Replace").with_edit(FileEdit::Replace {
+            path: PathBuf::from("package.json"),
+            old: "not there".to_string(),
+            new: "here".to_string(),
+        });
+        let result = fixer.plan(&[&missing_text]).await;
+        assert!(
+            matches!(result, Err(AuditError::FixFailed { reason, .. }) if reason.contains("not found"))
+        );
+
+        let missing_dependency = Fix::new("Set").with_edit(FileEdit::SetDependency {
+            path: PathBuf::from("package.json"),
+            dependency: "b".to_string(),
+            version_spec: "^2.0.0".to_string(),
+        });
+        let result = fixer.plan(&[&missing_dependency]).await;
+        assert!(
+            matches!(result, Err(AuditError::FixFailed { reason, .. }) if reason.contains("'b'"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fix_apply_records_undoable_transaction() {
+        use crate::audit::{FileEdit, Fix};
+        use crate::workspace::{OperationKind, TransactionLog};
+
+        let temp = TempDir::new().unwrap();
+        let manifest = write_manifest(temp.path(), "app", &[("a", "^1.0.0")]);
+        let original = std::fs::read_to_string(&manifest).unwrap();
+
+        let fix = Fix::new("Bump a")
+            .with_edit(FileEdit::SetDependency {
+                path: PathBuf::from("package.json"),
+                dependency: "a".to_string(),
+                version_spec: "^2.0.0".to_string(),
+            })
+            .with_edit(FileEdit::Write {
+                path: PathBuf::from(".nvmrc"),
+                content: "20\n".to_string(),
+            });
+
+        let fixer = fixer_for(temp.path());
+        let plan = fixer.plan(&[&fix]).await.unwrap();
+        let diff = plan.diff();
+        assert!(diff.contains("--- /dev/null"));
+        assert!(diff.contains("+++ b/.nvmrc"));
+
+        let outcome = fixer.apply(&plan).await.expect("Apply should succeed");
+        assert_eq!(outcome.fixes_applied, 1);
+        assert_eq!(outcome.files.len(), 2);
+        assert!(std::fs::read_to_string(&manifest).unwrap().contains("\"a\": \"^2.0.0\""));
+        assert_eq!(std::fs::read_to_string(temp.path().join(".nvmrc")).unwrap(), "20\n");
+
+        let id = outcome.transaction_id.expect("Backups are enabled by default");
+        let log = TransactionLog::new(
+            temp.path().to_path_buf(),
+            crate::config::BackupConfig::default(),
+            FileSystemManager::new(),
+        );
+        let entry = log.get(&id).await.unwrap();
+        assert_eq!(entry.kind, OperationKind::AuditFix);
+        assert!(entry.committed);
+
+        log.undo(&id).await.expect("Undo should succeed");
+        assert_eq!(std::fs::read_to_string(&manifest).unwrap(), original);
+        assert!(!temp.path().join(".nvmrc").exists());
+    }
+
+    #[tokio::test]
+    async fn test_fix_apply_rejects_file_changed_since_plan() {
+        use crate::audit::{FileEdit, Fix};
+        use crate::error::AuditError;
+
+        let temp = TempDir::new().unwrap();
+        let manifest = write_manifest(temp.path(), "app", &[("a", "^1.0.0")]);
+
+        let fix = Fix::new("Bump a").with_edit(FileEdit::SetDependency {
+            path: PathBuf::from("package.json"),
+            dependency: "a".to_string(),
+            version_spec: "^2.0.0".to_string(),
+        });
+
+        let fixer = fixer_for(temp.path());
+        let plan = fixer.plan(&[&fix]).await.unwrap();
+
+        write_manifest(temp.path(), "app", &[("a", "^1.5.0")]);
+        let result = fixer.apply(&plan).await;
+        assert!(
+            matches!(result, Err(AuditError::FixFailed { reason, .. }) if reason.contains("changed"))
+        );
+        assert!(std::fs::read_to_string(&manifest).unwrap().contains("\"a\": \"^1.5.0\""));
+    }
+
+    #[tokio::test]
+    async fn test_version_consistency_issue_carries_fix() {
+        use crate::audit::FileEdit;
+        use crate::audit::sections::audit_version_consistency;
+        use crate::types::PackageInfo;
+        use std::collections::HashSet;
+
+        let temp = TempDir::new().unwrap();
+        let mut packages = Vec::new();
+        for (dir, name, deps) in [
+            ("app-a", "@myorg/app-a", vec![("@myorg/core", "^1.0.0")]),
+            ("app-b", "@myorg/app-b", vec![("@myorg/core", "^1.1.0")]),
+            ("core", "@myorg/core", vec![]),
+        ] {
+            let path = temp.path().join(dir);
+            let manifest = write_manifest(&path, name, &deps);
+            let package_json: package_json::PackageJson =
+                serde_json::from_str(&std::fs::read_to_string(&manifest).unwrap()).unwrap();
+            packages.push(PackageInfo::new(package_json, None, path));
+        }
+        let internal_names: HashSet<String> =
+            packages.iter().map(|p| p.name().to_string()).collect();
+
+        let section =
+            audit_version_consistency(&packages, &internal_names, &PackageToolsConfig::default())
+                .await
+                .unwrap();
+        let issue = &section.issues[0];
+        assert!(issue.is_fixable());
+
+        let fix = issue.fix.as_ref().unwrap();
+        let recommended = &section.inconsistencies[0].recommended_version;
+        assert!(fix.edits.iter().all(|edit| matches!(
+            edit,
+            FileEdit::SetDependency { version_spec, .. } if version_spec == recommended
+        )));
+
+        let fixer = fixer_for(temp.path());
+        let plan = fixer.plan(&[fix]).await.unwrap();
+        fixer.apply(&plan).await.unwrap();
+
+        let packages: Vec<PackageInfo> = packages
+            .iter()
+            .map(|package| {
+                let content = std::fs::read_to_string(package.path().join("package.json")).unwrap();
+                PackageInfo::new(
+                    serde_json::from_str(&content).unwrap(),
+                    None,
+                    package.path().to_path_buf(),
+                )
+            })
+            .collect();
+        let section =
+            audit_version_consistency(&packages, &internal_names, &PackageToolsConfig::default())
+                .await
+                .unwrap();
+        assert!(!section.has_inconsistencies());
+    }
+
+    /// Creates a package for the registry ownership tests.
+    fn ownership_package(manifest: serde_json::Value) -> crate::types::PackageInfo {
+        let name = manifest["name"].as_str().unwrap().to_string();
+        crate::types::PackageInfo::new(
+            serde_json::from_value(manifest).unwrap(),
+            None,
+            PathBuf::from("packages").join(name.rsplit('/').next().unwrap()),
+        )
+    }
+
+    /// Creates a registry ownership check for `@acme` against the given registry.
+    async fn ownership_check(
+        registry_url: &str,
+        owners: &[&str],
+    ) -> crate::audit::RegistryOwnershipCheck {
+        let mut registry = crate::config::RegistryConfig {
+            read_npmrc: false,
+            retry_attempts: 0,
+            ..Default::default()
+        };
+        registry.scoped_registries.insert("acme".to_string(), registry_url.to_string());
+        let client =
+            crate::upgrade::RegistryClient::new(&PathBuf::from("."), registry).await.unwrap();
+        let config = crate::config::RegistryOwnershipAuditConfig {
+            scopes: vec!["@acme".to_string()],
+            owners: owners.iter().map(ToString::to_string).collect(),
+        };
+        crate::audit::RegistryOwnershipCheck::new(client, &config)
+    }
+
+    /// Runs a check against the given packages.
+    async fn run_ownership_check(
+        check: &crate::audit::RegistryOwnershipCheck,
+        packages: Vec<crate::types::PackageInfo>,
+    ) -> Vec<crate::audit::AuditFinding> {
+        use crate::audit::AuditCheck;
+
+        let context = crate::audit::AuditCheckContext::new(
+            PathBuf::from("."),
+            PackageToolsConfig::default(),
+            packages,
+        );
+        check.run(&context).await.unwrap()
+    }
+
+    /// Registry document of an `@acme` package with the given maintainers.
+    fn ownership_document(name: &str, maintainers: serde_json::Value) -> String {
+        serde_json::json!({
+            "name": name,
+            "dist-tags": { "latest": "1.0.0" },
+            "versions": { "1.0.0": {} },
+            "maintainers": maintainers,
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_registry_ownership_accepts_owned_and_private_packages() {
+        let mut server = mockito::Server::new_async().await;
+        let _owned = server
+            .mock("GET", "/@acme/core")
+            .with_status(200)
+            .with_body(ownership_document(
+                "@acme/core",
+                serde_json::json!([{ "name": "acme", "email": "npm@acme.dev" }]),
+            ))
+            .create_async()
+            .await;
+        let private = server.mock("GET", "/@acme/app").expect(0).create_async().await;
+
+        let check = ownership_check(&server.url(), &["acme"]).await;
+        let findings = run_ownership_check(
+            &check,
+            vec![
+                ownership_package(serde_json::json!({ "name": "@acme/core", "version": "1.0.0" })),
+                ownership_package(
+                    serde_json::json!({ "name": "@acme/app", "version": "1.0.0", "private": true }),
+                ),
+                ownership_package(serde_json::json!({ "name": "left-pad", "version": "1.0.0" })),
+            ],
+        )
+        .await;
+
+        assert!(findings.is_empty(), "unexpected findings: {findings:?}");
+        private.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_registry_ownership_reports_missing_and_unowned_packages() {
+        let mut server = mockito::Server::new_async().await;
+        let _missing = server.mock("GET", "/@acme/utils").with_status(404).create_async().await;
+        let _unowned = server
+            .mock("GET", "/@acme/core")
+            .with_status(200)
+            .with_body(ownership_document(
+                "@acme/core",
+                serde_json::json!(["mallory <mallory@example.com>"]),
+            ))
+            .create_async()
+            .await;
+
+        let check = ownership_check(&server.url(), &["acme"]).await;
+        let findings = run_ownership_check(
+            &check,
+            vec![
+                ownership_package(serde_json::json!({ "name": "@acme/core", "version": "1.0.0" })),
+                ownership_package(serde_json::json!({ "name": "@acme/utils", "version": "1.0.0" })),
+            ],
+        )
+        .await;
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].title, "@acme/core is not owned by the organization");
+        assert!(findings[0].description.contains("Maintainers: mallory."));
+        assert_eq!(findings[1].affected_packages, ["@acme/utils"]);
+        assert!(findings[1].title.starts_with("@acme/utils is not published to"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_ownership_reports_public_registry_and_mistyped_scopes() {
+        let check = ownership_check("https://npm.acme.dev", &[]).await;
+        let findings = run_ownership_check(
+            &check,
+            vec![
+                ownership_package(serde_json::json!({
+                    "name": "@acme/cli",
+                    "version": "1.0.0",
+                    "publishConfig": { "registry": "https://registry.npmjs.org/" },
+                })),
+                ownership_package(serde_json::json!({ "name": "@acmee/core", "version": "1.0.0" })),
+            ],
+        )
+        .await;
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].title, "@acmee/core uses a scope resembling @acme");
+        assert_eq!(findings[1].title, "@acme/cli would be published to the public registry");
+    }
+
+    #[tokio::test]
+    async fn test_registry_ownership_check_registered_for_configured_scopes() {
+        let (_temp_dir, workspace_path) = setup_test_workspace().await;
+
+        let manager =
+            AuditManager::new(workspace_path.clone(), PackageToolsConfig::default()).await.unwrap();
+        assert!(!manager.check_ids().contains(&crate::audit::REGISTRY_OWNERSHIP_CHECK_ID));
+
+        let mut config = PackageToolsConfig::default();
+        config.audit.registry_ownership.scopes = vec!["@acme".to_string()];
+        config.upgrade.registry.read_npmrc = false;
+        let manager = AuditManager::new(workspace_path, config).await.unwrap();
+        assert!(manager.check_ids().contains(&crate::audit::REGISTRY_OWNERSHIP_CHECK_ID));
+    }
+
+    #[test]
+    fn test_registry_ownership_helpers() {
+        use crate::audit::ownership::{edit_distance, is_public_registry, package_scope};
+
+        assert_eq!(package_scope("@acme/core"), Some("acme"));
+        assert_eq!(package_scope("left-pad"), None);
+        assert!(is_public_registry("https://registry.npmjs.org/"));
+        assert!(is_public_registry("https://REGISTRY.yarnpkg.com"));
+        assert!(!is_public_registry("https://npm.acme.dev/registry.npmjs.org"));
+        assert_eq!(edit_distance("acme", "acmee"), 1);
+        assert_eq!(edit_distance("acme", "amce"), 2);
+        assert_eq!(edit_distance("acme", "acme"), 0);
+    }
+
+    #[test]
+    fn test_annotate_dependency_impact_lists_workspace_packages() {
+        use crate::audit::annotate_dependency_impact;
+        use crate::version::DependencyGraph;
+
+        // app -> ui -> lodash
+        let packages = vec![
+            ownership_package(serde_json::json!({
+                "name": "app", "version": "1.0.0", "dependencies": { "ui": "^1.0.0" }
+            })),
+            ownership_package(serde_json::json!({
+                "name": "ui", "version": "1.0.0", "dependencies": { "lodash": "^4.17.0" }
+            })),
+        ];
+        let graph = DependencyGraph::from_packages_with_external(&packages).unwrap();
+
+        let mut issue = AuditIssue::new(
+            IssueSeverity::Critical,
+            IssueCategory::Security,
+            "Prototype pollution in lodash".to_string(),
+            "lodash < 4.17.21 is vulnerable".to_string(),
+        );
+        issue.affected_packages.push("lodash".to_string());
+
+        assert!(annotate_dependency_impact(&mut issue, &graph));
+        assert_eq!(issue.affected_packages, vec!["ui".to_string(), "app".to_string()]);
+        assert_eq!(issue.metadata.get("dependency").map(String::as_str), Some("lodash"));
+        assert_eq!(issue.metadata.get("direct_dependents").map(String::as_str), Some("ui"));
+        assert_eq!(issue.metadata.get("transitive_dependents").map(String::as_str), Some("app"));
+
+        let mut unrelated = AuditIssue::new(
+            IssueSeverity::Warning,
+            IssueCategory::Security,
+            "Unowned package".to_string(),
+            "ui is not owned by the organization".to_string(),
+        );
+        unrelated.affected_packages.push("ui".to_string());
+
+        assert!(!annotate_dependency_impact(&mut unrelated, &graph));
+        assert_eq!(unrelated.affected_packages, vec!["ui".to_string()]);
+        assert!(unrelated.metadata.is_empty());
+    }
+
+    // ==================== Baseline Tests ====================
+
+    fn day(date: &str) -> chrono::NaiveDate {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn test_baseline_suppresses_known_findings_until_expiry() {
+        use crate::audit::AuditBaseline;
+
+        let mut report = create_test_audit_report();
+        let circular = report.critical_issues()[0].clone();
+        let upgrade = report.warnings()[0].clone();
+        let removed = AuditIssue::new(
+            IssueSeverity::Info,
+            IssueCategory::Other,
+            "Fixed long ago".to_string(),
+            String::new(),
+        );
+
+        let mut baseline = AuditBaseline::from_issues([&circular, &upgrade, &removed]);
+        assert_eq!(baseline.entries.len(), 3);
+        let upgrade_fingerprint = upgrade.fingerprint();
+        for entry in &mut baseline.entries {
+            if entry.fingerprint == upgrade_fingerprint {
+                entry.expires = Some(day("2025-03-31"));
+            }
+        }
+
+        let comparison = report.apply_baseline(&baseline, day("2025-04-01"));
+        assert_eq!(comparison.suppressed, 1);
+        assert_eq!(comparison.new_findings, 1);
+        assert_eq!(comparison.expired.len(), 1);
+        assert_eq!(comparison.expired[0].title, "Major upgrade available");
+        assert_eq!(comparison.stale.len(), 1);
+        assert_eq!(comparison.stale[0].title, "Fixed long ago");
+
+        // The expired suppression no longer hides the upgrade warning
+        assert!(report.passed());
+        assert_eq!(report.total_issues(), 1);
+        assert_eq!(report.warnings()[0].title, "Major upgrade available");
+
+        // On its last day the suppression still applies
+        let mut report = create_test_audit_report();
+        let comparison = report.apply_baseline(&baseline, day("2025-03-31"));
+        assert_eq!(comparison.suppressed, 2);
+        assert_eq!(report.total_issues(), 0);
+    }
+
+    #[test]
+    fn test_baseline_update_keeps_expiry_and_reason() {
+        use crate::audit::AuditBaseline;
+
+        let report = create_test_audit_report();
+        let mut baseline = AuditBaseline::from_issues(report.critical_issues());
+        baseline.entries[0].reason = Some("Tracked in #42".to_string());
+        baseline.entries[0].expires = Some(day("2025-06-30"));
+
+        let updated = baseline.updated(report.all_issues(), Some(day("2025-12-31")));
+        assert_eq!(updated.entries.len(), 2);
+
+        let circular = updated.entry(&report.critical_issues()[0].fingerprint()).unwrap();
+        assert_eq!(circular.reason.as_deref(), Some("Tracked in #42"));
+        assert_eq!(circular.expires, Some(day("2025-06-30")));
+        assert_eq!(circular.packages, vec!["pkg-a".to_string(), "pkg-b".to_string()]);
+
+        let upgrade = updated.entry(&report.warnings()[0].fingerprint()).unwrap();
+        assert_eq!(upgrade.expires, Some(day("2025-12-31")));
+        assert!(upgrade.reason.is_none());
+
+        // Findings that are gone are dropped
+        let updated = updated.updated(report.warnings(), None);
+        assert_eq!(updated.entries.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_baseline_save_and_load() {
+        use crate::audit::AuditBaseline;
+        use crate::error::AuditError;
+
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".audit-baseline.json");
+        let fs = FileSystemManager::new();
+
+        let report = create_test_audit_report();
+        let baseline = AuditBaseline::from_issues(report.all_issues());
+        baseline.save(&fs, &path).await.unwrap();
+
+        let content = fs.read_file_string(&path).await.unwrap();
+        assert!(content.contains("\"fingerprint\""));
+        assert!(!content.contains("\"expires\""));
+        assert_eq!(AuditBaseline::load(&fs, &path).await.unwrap(), baseline);
+
+        fs.write_file_string(&path, "not json").await.unwrap();
+        let error = AuditBaseline::load(&fs, &path).await.unwrap_err();
+        assert!(matches!(error, AuditError::InvalidBaseline { .. }));
+
+        let missing = AuditBaseline::load(&fs, &temp.path().join("missing.json")).await;
+        assert!(matches!(missing, Err(AuditError::InvalidBaseline { .. })));
+    }
 }
@@ -0,0 +1,285 @@
+//! Retroactive release tags for versions released without one.
+//!
+//! **What**: Provides `ReleaseTagBackfill`, which finds the release tags missing for versions
+//! a workspace has already released and creates them at the commits that released them.
+//!
+//! **How**: For each package, the first-parent history of its `package.json` is read oldest
+//! first, and the first commit at which the manifest holds a version is taken as the release
+//! commit of that version. Versions recorded in archived changesets but never found in the
+//! manifest history are reported as unresolved instead of being guessed. Tag names follow the
+//! package's tag format, or `root_tag_format` for a single-package repository, and tags that
+//! already exist are left alone.
+//!
+//! **Why**: Release baselines are resolved from tags. A repository adopting the tool with
+//! releases made by hand has none, so every package looks unreleased and its first changelog
+//! covers the whole history. Backfilling the tags makes baseline detection work from day one.
+
+use crate::config::ChangelogConfig;
+use crate::error::{ChangelogError, ChangelogResult};
+use crate::types::{ArchivedChangeset, PackageInfo, Version};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use sublime_git_tools::{HistoryQuery, Repo};
+
+/// A release tag missing for an already-released version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingReleaseTag {
+    /// The released package, or `None` when the tag uses the root tag format.
+    pub package_name: Option<String>,
+
+    /// The released version.
+    pub version: String,
+
+    /// The tag to create.
+    pub tag_name: String,
+
+    /// The commit that first set the version in the package manifest.
+    pub commit: String,
+
+    /// Whether an archived changeset records the release.
+    pub recorded: bool,
+}
+
+/// A release recorded in an archived changeset whose commit could not be found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnresolvedRelease {
+    /// The released package.
+    pub package_name: String,
+
+    /// The released version.
+    pub version: String,
+}
+
+/// The tags a backfill would create.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagBackfillPlan {
+    /// Tags to create, per package in release order.
+    pub missing: Vec<MissingReleaseTag>,
+
+    /// Release tags that already exist.
+    pub existing: Vec<String>,
+
+    /// Archived releases no manifest commit could be found for.
+    pub unresolved: Vec<UnresolvedRelease>,
+}
+
+impl TagBackfillPlan {
+    /// Returns `true` if there is nothing to create or report.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+/// Finds and creates the release tags missing from a repository.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::changelog::ReleaseTagBackfill;
+///
+/// let backfill = ReleaseTagBackfill::new(&repo, &config.changelog);
+/// let plan = backfill.plan(&packages, &archived)?;
+/// for tag in &plan.missing {
+///     println!("{} -> {}", tag.tag_name, tag.commit);
+/// }
+/// let created = backfill.apply(&plan)?;
+/// ```
+#[derive(Debug)]
+pub struct ReleaseTagBackfill<'a> {
+    /// Repository whose history is read and tagged.
+    repo: &'a Repo,
+
+    /// Changelog configuration providing the tag formats.
+    config: ChangelogConfig,
+}
+
+impl<'a> ReleaseTagBackfill<'a> {
+    /// Creates a backfill for a repository, using the tag formats of the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository to read and tag
+    /// * `config` - Changelog configuration providing the tag formats
+    #[must_use]
+    pub fn new(repo: &'a Repo, config: &ChangelogConfig) -> Self {
+        Self { repo, config: config.clone() }
+    }
+
+    /// Finds the release tags missing for the given packages.
+    ///
+    /// A single package at the repository root is tagged with the root tag format, so its
+    /// tags are the ones baseline detection looks up for the workspace, unless it is scoped
+    /// or has its own `tag_format`; see [`ChangelogConfig::uses_root_tag`].
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - The workspace packages to check
+    /// * `archived` - Archived changesets, whose recorded releases are cross-checked
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if the tags or the history of a manifest cannot be
+    /// read.
+    pub fn plan(
+        &self,
+        packages: &[PackageInfo],
+        archived: &[ArchivedChangeset],
+    ) -> ChangelogResult<TagBackfillPlan> {
+        let tags: HashSet<String> = self
+            .repo
+            .get_remote_or_local_tags(Some(true))
+            .map_err(|e| git_error("get tags", e.as_ref()))?
+            .into_iter()
+            .map(|tag| tag.tag)
+            .collect();
+
+        let manifests: Vec<(&PackageInfo, String)> =
+            packages.iter().map(|package| (package, self.manifest_path(package.path()))).collect();
+        let unified = matches!(manifests.as_slice(), [(_, manifest)] if manifest == "package.json");
+
+        let mut plan = TagBackfillPlan::default();
+        for (package, manifest) in manifests {
+            let name = package.name();
+            let releases = self.manifest_releases(&manifest)?;
+            let recorded: Vec<&String> = archived
+                .iter()
+                .filter_map(|archive| archive.release_info.versions.get(name))
+                .collect();
+
+            let root_tag = self.config.uses_root_tag(name, unified);
+            let tag_name =
+                |version: &str| self.config.release_tag((!root_tag).then_some(name), version);
+
+            for (version, commit) in &releases {
+                let tag_name = tag_name(version);
+                if tags.contains(&tag_name) {
+                    plan.existing.push(tag_name);
+                    continue;
+                }
+                plan.missing.push(MissingReleaseTag {
+                    package_name: (!root_tag).then(|| name.to_string()),
+                    version: version.clone(),
+                    tag_name,
+                    commit: commit.clone(),
+                    recorded: recorded.contains(&version),
+                });
+            }
+
+            let mut unresolved: Vec<&String> = recorded
+                .into_iter()
+                .filter(|version| releases.iter().all(|(released, _)| released != *version))
+                .filter(|version| !tags.contains(&tag_name(version)))
+                .collect();
+            unresolved.sort();
+            unresolved.dedup();
+            plan.unresolved.extend(unresolved.into_iter().map(|version| UnresolvedRelease {
+                package_name: name.to_string(),
+                version: version.clone(),
+            }));
+        }
+
+        Ok(plan)
+    }
+
+    /// Creates the missing tags of a plan.
+    ///
+    /// Each tag is annotated with `Release {package} version {version}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The plan returned by [`ReleaseTagBackfill::plan`]
+    ///
+    /// # Returns
+    ///
+    /// The names of the created tags.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if a tag cannot be created. Tags created before the
+    /// failure are kept.
+    pub fn apply(&self, plan: &TagBackfillPlan) -> ChangelogResult<Vec<String>> {
+        let mut created = Vec::with_capacity(plan.missing.len());
+        for tag in &plan.missing {
+            let subject = tag.package_name.as_deref().unwrap_or("workspace");
+            let message = format!("Release {subject} version {}", tag.version);
+            self.repo
+                .create_tag_at(&tag.tag_name, &tag.commit, Some(message))
+                .map_err(|e| git_error(&format!("create tag {}", tag.tag_name), e.as_ref()))?;
+            created.push(tag.tag_name.clone());
+        }
+        Ok(created)
+    }
+
+    /// Returns the manifest of a package directory, relative to the repository root.
+    fn manifest_path(&self, package_path: &Path) -> String {
+        let root = self.repo.get_repo_path();
+        let relative = package_path
+            .strip_prefix(root)
+            .ok()
+            .map(Path::to_path_buf)
+            .or_else(|| {
+                let canonical = package_path.canonicalize().ok()?;
+                let root = root.canonicalize().ok()?;
+                canonical.strip_prefix(root).ok().map(Path::to_path_buf)
+            })
+            .unwrap_or_else(|| package_path.to_path_buf());
+
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let relative = relative.trim_matches('/');
+        if relative.is_empty() {
+            "package.json".to_string()
+        } else {
+            format!("{relative}/package.json")
+        }
+    }
+
+    /// Returns each version a manifest held with the commit that first set it, oldest first.
+    fn manifest_releases(&self, manifest: &str) -> ChangelogResult<Vec<(String, String)>> {
+        let mut commits = self
+            .repo
+            .history(&HistoryQuery::new().first_parent().path(manifest))
+            .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+            .map_err(|e| git_error("read manifest history", e.as_ref()))?;
+        commits.reverse();
+
+        let mut releases: BTreeMap<String, (usize, String)> = BTreeMap::new();
+        let mut previous: Option<String> = None;
+        for (position, commit) in commits.into_iter().enumerate() {
+            let content = self
+                .repo
+                .get_file_content_at_ref(&commit.hash, manifest)
+                .map_err(|e| git_error("read manifest", e.as_ref()))?;
+            let version = content.as_deref().and_then(manifest_version);
+
+            if let Some(version) = &version
+                && previous.as_ref() != Some(version)
+            {
+                releases.entry(version.clone()).or_insert((position, commit.hash));
+            }
+            previous = version;
+        }
+
+        let mut releases: Vec<(usize, String, String)> = releases
+            .into_iter()
+            .map(|(version, (position, commit))| (position, version, commit))
+            .collect();
+        releases.sort();
+        Ok(releases.into_iter().map(|(_, version, commit)| (version, commit)).collect())
+    }
+}
+
+/// Returns the semver version of a `package.json`, if it has one.
+fn manifest_version(content: &str) -> Option<String> {
+    let manifest: serde_json::Value = serde_json::from_str(content).ok()?;
+    let version = manifest.get("version")?.as_str()?;
+    Version::parse(version).ok().map(|version| version.to_string())
+}
+
+fn git_error(operation: &str, reason: &str) -> ChangelogError {
+    ChangelogError::GitError { operation: operation.to_string(), reason: reason.to_string() }
+}
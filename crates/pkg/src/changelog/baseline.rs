@@ -0,0 +1,269 @@
+//! Release baseline resolution from Git tags.
+//!
+//! **What**: Provides `ReleaseBaselineResolver`, which finds the point in history a package
+//! (or the whole workspace) was last released from, and `ReleaseBaseline`, which describes
+//! that point and collects the commits made since.
+//!
+//! **How**: Local tags are loaded once and parsed with the configured tag formats. For a
+//! package the newest `{name}@{version}` tag wins; when the package has never been tagged the
+//! newest unified `v{version}` tag is used instead. When neither exists the package has never
+//! been released and the baseline falls back to the first commit of the repository.
+//!
+//! **Why**: Changelog generation and change analysis both need to know "what changed since the
+//! last release". Resolving that from tags, instead of from the version being released next,
+//! makes first-time releases and packages that were never tagged work without manual refs.
+
+use crate::changelog::version_detection::{VersionTag, find_version_tags};
+use crate::config::ChangelogConfig;
+use crate::error::{ChangelogError, ChangelogResult};
+use crate::types::Version;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use sublime_git_tools::{CommitHistory, HistoryQuery, Repo, RepoTags};
+
+/// Where a release baseline was resolved from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BaselineSource {
+    /// A per-package tag such as `@myorg/core@1.2.0`.
+    PackageTag,
+
+    /// A unified workspace tag such as `v1.2.0`.
+    RootTag,
+
+    /// No release tag exists; the baseline is the first commit of the repository.
+    FirstCommit,
+}
+
+impl fmt::Display for BaselineSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PackageTag => write!(f, "package-tag"),
+            Self::RootTag => write!(f, "root-tag"),
+            Self::FirstCommit => write!(f, "first-commit"),
+        }
+    }
+}
+
+/// The point in history a package or workspace was last released from.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::changelog::ReleaseBaselineResolver;
+///
+/// let resolver = ReleaseBaselineResolver::new(&repo, &config.changelog)?;
+/// let baseline = resolver.resolve(Some("@myorg/core"))?;
+///
+/// if baseline.is_first_release() {
+///     println!("never released, starting at {}", baseline.commit());
+/// } else {
+///     println!("last released as {}", baseline.git_ref());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseBaseline {
+    /// The package the baseline was resolved for, or `None` for the workspace root.
+    package_name: Option<String>,
+
+    /// Where the baseline was resolved from.
+    source: BaselineSource,
+
+    /// The release tag, when one was found.
+    tag: Option<VersionTag>,
+
+    /// The commit the baseline points at.
+    commit: String,
+}
+
+impl ReleaseBaseline {
+    /// Returns the package the baseline was resolved for.
+    #[must_use]
+    pub fn package_name(&self) -> Option<&str> {
+        self.package_name.as_deref()
+    }
+
+    /// Returns where the baseline was resolved from.
+    #[must_use]
+    pub fn source(&self) -> BaselineSource {
+        self.source
+    }
+
+    /// Returns the release tag, if the baseline came from one.
+    #[must_use]
+    pub fn tag(&self) -> Option<&VersionTag> {
+        self.tag.as_ref()
+    }
+
+    /// Returns the commit hash the baseline points at.
+    #[must_use]
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    /// Returns the released version, if the baseline came from a tag.
+    #[must_use]
+    pub fn version(&self) -> Option<&Version> {
+        self.tag.as_ref().map(VersionTag::version)
+    }
+
+    /// Returns the reference to display for the baseline.
+    ///
+    /// This is the tag name when a tag was found, and the commit hash otherwise.
+    #[must_use]
+    pub fn git_ref(&self) -> &str {
+        self.tag.as_ref().map_or(self.commit.as_str(), VersionTag::tag_name)
+    }
+
+    /// Returns `true` if no release tag exists for the package or workspace.
+    #[must_use]
+    pub fn is_first_release(&self) -> bool {
+        self.source == BaselineSource::FirstCommit
+    }
+
+    /// Walks the commits made since the baseline.
+    ///
+    /// For a tagged baseline the tagged commit itself is excluded. For a first release the
+    /// whole history up to `HEAD` is walked, including the first commit. History is read
+    /// lazily along first parents, with merge commits expanded into the commits they merged,
+    /// so callers can process commits one at a time with bounded memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository to read commits from
+    /// * `relative_path` - Optional path filter, relative to the repository root
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if the walk cannot be started. Errors reading
+    /// individual commits are yielded by the iterator.
+    pub fn commits_since<'r>(
+        &self,
+        repo: &'r Repo,
+        relative_path: &Option<String>,
+    ) -> ChangelogResult<CommitHistory<'r>> {
+        let mut query = HistoryQuery::new().first_parent();
+        if !self.is_first_release() {
+            query = query.since(self.commit.as_str());
+        }
+        if let Some(path) = relative_path {
+            query = query.path(path.as_str());
+        }
+
+        repo.history(&query).map_err(|e| ChangelogError::GitError {
+            operation: "get commits since release".to_string(),
+            reason: format!("Failed to read commits since {}: {}", self.git_ref(), e.as_ref()),
+        })
+    }
+}
+
+/// Resolves release baselines from the tags of a repository.
+///
+/// Tags are read once on construction, so a single resolver can cheaply resolve baselines
+/// for every package in a workspace.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::changelog::ReleaseBaselineResolver;
+///
+/// let resolver = ReleaseBaselineResolver::new(&repo, &config.changelog)?;
+///
+/// let core = resolver.resolve(Some("@myorg/core"))?;
+/// let root = resolver.resolve(None)?;
+/// ```
+#[derive(Debug)]
+pub struct ReleaseBaselineResolver<'a> {
+    /// Repository used to find the first commit for untagged packages.
+    repo: &'a Repo,
+
+    /// Changelog configuration providing the tag formats.
+    config: ChangelogConfig,
+
+    /// Local tags, with annotated tags dereferenced to their commits.
+    tags: Vec<RepoTags>,
+}
+
+impl<'a> ReleaseBaselineResolver<'a> {
+    /// Creates a resolver, loading the local tags of the repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository to resolve baselines in
+    /// * `config` - Changelog configuration providing the tag formats
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if the tags cannot be listed.
+    pub fn new(repo: &'a Repo, config: &ChangelogConfig) -> ChangelogResult<Self> {
+        let tags =
+            repo.get_remote_or_local_tags(Some(true)).map_err(|e| ChangelogError::GitError {
+                operation: "get tags".to_string(),
+                reason: e.as_ref().to_string(),
+            })?;
+
+        Ok(Self { repo, config: config.clone(), tags })
+    }
+
+    /// Resolves the baseline for a package, or for the workspace root when `None`.
+    ///
+    /// Resolution order:
+    /// 1. The newest per-package tag (packages only)
+    /// 2. The newest unified root tag
+    /// 3. The first commit of the repository
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - The package to resolve, or `None` for the workspace root
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if no tag matches and the first commit cannot be
+    /// found (for example, in a repository without commits).
+    pub fn resolve(&self, package_name: Option<&str>) -> ChangelogResult<ReleaseBaseline> {
+        if let Some(name) = package_name
+            && let Some(baseline) = self.newest_tag(
+                package_name,
+                self.config.tag_format(package_name),
+                BaselineSource::PackageTag,
+            )
+        {
+            return Ok(ReleaseBaseline { package_name: Some(name.to_string()), ..baseline });
+        }
+
+        if let Some(baseline) =
+            self.newest_tag(None, self.config.tag_format(None), BaselineSource::RootTag)
+        {
+            return Ok(ReleaseBaseline {
+                package_name: package_name.map(String::from),
+                ..baseline
+            });
+        }
+
+        let commit = self.repo.get_first_sha().map_err(|e| ChangelogError::GitError {
+            operation: "get first commit".to_string(),
+            reason: e.as_ref().to_string(),
+        })?;
+
+        Ok(ReleaseBaseline {
+            package_name: package_name.map(String::from),
+            source: BaselineSource::FirstCommit,
+            tag: None,
+            commit,
+        })
+    }
+
+    /// Builds a baseline from the newest tag matching `format`, if any.
+    fn newest_tag(
+        &self,
+        package_name: Option<&str>,
+        format: &str,
+        source: BaselineSource,
+    ) -> Option<ReleaseBaseline> {
+        let names: Vec<String> = self.tags.iter().map(|t| t.tag.clone()).collect();
+        let newest = find_version_tags(&names, package_name, format).into_iter().next()?;
+        let commit = self.tags.iter().find(|t| t.tag == newest.tag_name())?.hash.clone();
+
+        Some(ReleaseBaseline { package_name: None, source, tag: Some(newest), commit })
+    }
+}
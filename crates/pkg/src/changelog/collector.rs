@@ -5,7 +5,9 @@
 //!
 //! **How**: This module integrates with Git to retrieve commits between versions,
 //! parses them using conventional commit format (with fallback to plain messages),
-//! groups them by section type, and collects all relevant metadata.
+//! groups them by section type, and collects all relevant metadata. Commits are filtered
+//! by message pattern, author, and commit type, and commits whose scope is mapped to a
+//! package in `scope_packages` are attributed to that package.
 //!
 //! **Why**: To automate the process of gathering and organizing commit information
 //! for changelog generation, ensuring consistent and comprehensive release documentation.
@@ -15,8 +17,8 @@ use crate::config::ChangelogConfig;
 use crate::error::{ChangelogError, ChangelogResult};
 use chrono::{DateTime, TimeZone, Utc};
 use regex::Regex;
-use std::collections::HashMap;
-use sublime_git_tools::{Repo, RepoCommit};
+use std::collections::{HashMap, HashSet};
+use sublime_git_tools::{CommitHistory, HistoryQuery, Repo, RepoCommit, RepoError};
 
 /// Collector for gathering changelog data from Git commits.
 ///
@@ -51,6 +53,9 @@ pub struct ChangelogCollector<'a> {
 
     /// Cached exclude patterns regex.
     exclude_patterns: Vec<Regex>,
+
+    /// Cached excluded author matchers.
+    exclude_authors: Vec<Regex>,
 }
 
 impl<'a> ChangelogCollector<'a> {
@@ -80,7 +85,18 @@ impl<'a> ChangelogCollector<'a> {
         let exclude_patterns =
             config.exclude.patterns.iter().filter_map(|pattern| Regex::new(pattern).ok()).collect();
 
-        Self { repo, config, exclude_patterns }
+        // Author patterns are literal except for `*`, so names like `renovate[bot]` match as-is
+        let exclude_authors = config
+            .exclude
+            .authors
+            .iter()
+            .filter_map(|author| {
+                let pattern = author.split('*').map(regex::escape).collect::<Vec<_>>().join(".*");
+                Regex::new(&format!("^{pattern}$")).ok()
+            })
+            .collect();
+
+        Self { repo, config, exclude_patterns, exclude_authors }
     }
 
     /// Collects commits between two Git references and organizes them into sections.
@@ -136,86 +152,147 @@ impl<'a> ChangelogCollector<'a> {
         to_ref: &str,
         relative_path: Option<&str>,
     ) -> ChangelogResult<Vec<ChangelogSection>> {
-        // Get commits from Git
-        let commits = self.get_commits_between(from_ref, to_ref, relative_path)?;
+        let range = HistoryQuery::new().since(from_ref).until(to_ref).first_parent();
+        let mut query = range.clone();
+        if let Some(path) = relative_path {
+            query = query.path(path);
+        }
 
-        // Parse and group commits
-        self.process_commits(commits)
+        let entries =
+            self.collect_entries(None, self.read_history(&query)?, || self.read_history(&range))?;
+        Ok(self.sections_from(entries))
     }
 
-    /// Retrieves commits between two Git references.
+    /// Opens a lazy walk over the commits of a history query.
+    ///
+    /// History is read along first parents with merge commits expanded into the commits they
+    /// merged, so rebase and merge strategies yield the same commits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::GitError` if the references of the query cannot be resolved.
+    pub(crate) fn read_history(&self, query: &HistoryQuery) -> ChangelogResult<CommitHistory<'a>> {
+        self.repo.history(query).map_err(|e| history_error(query, &e))
+    }
+
+    /// Turns commits into changelog entries as they are read.
+    ///
+    /// Commits are consumed one at a time: squash merges are split, excluded commits are
+    /// dropped, and the rest are parsed into entries, so only commits that end up in the
+    /// changelog are held in memory.
+    ///
+    /// Scope mappings attribute commits to packages. Commits found through the package's
+    /// paths are kept unless their scope maps to another package. Commits from anywhere in
+    /// the range whose scope maps to this package are added, so changes to generated or
+    /// root-level files still reach the package changelog.
     ///
     /// # Arguments
     ///
-    /// * `from_ref` - Starting Git reference
-    /// * `to_ref` - Ending Git reference
-    /// * `relative_path` - Optional path filter
+    /// * `package_name` - The package being collected for, or `None` for the workspace root
+    /// * `path_commits` - Commits that touched the package's files
+    /// * `range_commits` - Opens every commit in the same range; only called when scopes are
+    ///   mapped
     ///
     /// # Returns
     ///
-    /// A vector of `RepoCommit` instances.
+    /// The entries of the included commits, in the order they were read.
     ///
     /// # Errors
     ///
-    /// Returns an error if Git operations fail.
-    fn get_commits_between(
+    /// Returns `ChangelogError::GitError` if a commit cannot be read or `range_commits` fails.
+    pub(crate) fn collect_entries<P, R>(
         &self,
-        from_ref: &str,
-        to_ref: &str,
-        relative_path: Option<&str>,
-    ) -> ChangelogResult<Vec<RepoCommit>> {
-        let path_option = relative_path.map(String::from);
+        package_name: Option<&str>,
+        path_commits: P,
+        range_commits: impl FnOnce() -> ChangelogResult<R>,
+    ) -> ChangelogResult<Vec<ChangelogEntry>>
+    where
+        P: IntoIterator<Item = Result<RepoCommit, RepoError>>,
+        R: IntoIterator<Item = Result<RepoCommit, RepoError>>,
+    {
+        let scoped = package_name.filter(|_| !self.config.scope_packages.is_empty());
+        let mut entries = Vec::new();
+        // Split squash commits share a hash, so the message is part of the identity
+        let mut seen = HashSet::new();
+
+        self.push_entries(&mut entries, path_commits, |commit| match scoped {
+            Some(package) => {
+                self.scope_package(commit).is_none_or(|name| name == package)
+                    && seen.insert((commit.hash.clone(), commit.message.clone()))
+            }
+            None => true,
+        })?;
+
+        if let Some(package) = scoped {
+            self.push_entries(&mut entries, range_commits()?, |commit| {
+                self.scope_package(commit) == Some(package)
+                    && seen.insert((commit.hash.clone(), commit.message.clone()))
+            })?;
+        }
+
+        Ok(entries)
+    }
 
-        self.repo.get_commits_between(from_ref, to_ref, &path_option).map_err(|e| {
-            ChangelogError::GitError {
-                operation: format!("get commits between {} and {}", from_ref, to_ref),
+    /// Appends the entries of the commits that pass the exclusion rules and `keep`.
+    fn push_entries(
+        &self,
+        entries: &mut Vec<ChangelogEntry>,
+        commits: impl IntoIterator<Item = Result<RepoCommit, RepoError>>,
+        mut keep: impl FnMut(&RepoCommit) -> bool,
+    ) -> ChangelogResult<()> {
+        for commit in commits {
+            let commit = commit.map_err(|e| ChangelogError::GitError {
+                operation: "read commit history".to_string(),
                 reason: e.as_ref().to_string(),
+            })?;
+            for commit in self.expand_squashed(commit) {
+                if self.should_include_commit(&commit) && keep(&commit) {
+                    entries.push(self.parse_commit(&commit));
+                }
             }
-        })
+        }
+        Ok(())
     }
 
-    /// Processes a list of commits into organized changelog sections.
+    /// Splits a squash-merge commit into one commit per combined conventional commit.
     ///
-    /// This method:
-    /// 1. Filters commits based on exclusion rules
-    /// 2. Parses each commit into a changelog entry
-    /// 3. Groups entries by section type
-    /// 4. Sorts entries within sections
-    /// 5. Sorts sections by priority
+    /// Each split commit keeps the hash, author, and date of the squash commit. Commits that
+    /// are not squash merges, and all commits when conventional parsing is disabled, are
+    /// returned unchanged.
     ///
     /// # Arguments
     ///
-    /// * `commits` - Vector of Git commits to process
+    /// * `commit` - The commit to expand
     ///
     /// # Returns
     ///
-    /// A vector of `ChangelogSection` instances.
-    pub(crate) fn process_commits(
-        &self,
-        commits: Vec<RepoCommit>,
-    ) -> ChangelogResult<Vec<ChangelogSection>> {
-        // Filter commits
-        let filtered_commits: Vec<&RepoCommit> =
-            commits.iter().filter(|commit| self.should_include_commit(commit)).collect();
+    /// The commits the squash merge combined, in their original order.
+    pub(crate) fn expand_squashed(&self, commit: RepoCommit) -> Vec<RepoCommit> {
+        if !self.config.conventional.enabled {
+            return vec![commit];
+        }
 
-        // Parse commits into entries
-        let mut entries: Vec<ChangelogEntry> =
-            filtered_commits.iter().map(|commit| self.parse_commit(commit)).collect();
+        match ConventionalCommit::split_squashed(&commit.message) {
+            Some(messages) => messages
+                .into_iter()
+                .map(|message| RepoCommit { message, ..commit.clone() })
+                .collect(),
+            None => vec![commit],
+        }
+    }
 
-        // Sort entries by date (newest first)
+    /// Sorts entries newest first and groups them into sections ordered by priority.
+    pub(crate) fn sections_from(&self, mut entries: Vec<ChangelogEntry>) -> Vec<ChangelogSection> {
         entries.sort_by(|a, b| b.date.cmp(&a.date));
-
-        // Group entries by section
-        let sections = self.group_entries_by_section(entries);
-
-        Ok(sections)
+        self.group_entries_by_section(entries)
     }
 
     /// Checks if a commit should be included in the changelog.
     ///
     /// Applies exclusion rules based on:
     /// - Commit message patterns
-    /// - Author names
+    /// - Author names or emails
+    /// - Excluded and included commit types (breaking changes are always kept)
     ///
     /// # Arguments
     ///
@@ -233,11 +310,53 @@ impl<'a> ChangelogCollector<'a> {
         }
 
         // Check exclude authors
-        if self.config.exclude.authors.contains(&commit.author_name) {
+        if self.exclude_authors.iter().any(|author| {
+            author.is_match(&commit.author_name) || author.is_match(&commit.author_email)
+        }) {
             return false;
         }
 
-        true
+        // Check commit types
+        let exclude_types = &self.config.exclude.types;
+        let include_types = &self.config.include.types;
+        if exclude_types.is_empty() && include_types.is_empty() {
+            return true;
+        }
+
+        match self.parse_conventional(commit) {
+            Some(conventional) if conventional.is_breaking() => true,
+            Some(conventional) => {
+                let commit_type = conventional.commit_type();
+                !exclude_types.iter().any(|t| t == commit_type)
+                    && (include_types.is_empty() || include_types.iter().any(|t| t == commit_type))
+            }
+            None => include_types.is_empty(),
+        }
+    }
+
+    /// Returns the package a commit belongs to through `scope_packages`, if its scope is mapped.
+    ///
+    /// # Arguments
+    ///
+    /// * `commit` - The commit to check
+    ///
+    /// # Returns
+    ///
+    /// The mapped package name, or `None` for unscoped, unmapped, or non-conventional commits.
+    pub(crate) fn scope_package(&self, commit: &RepoCommit) -> Option<&str> {
+        if self.config.scope_packages.is_empty() {
+            return None;
+        }
+        let conventional = self.parse_conventional(commit)?;
+        self.config.scope_packages.get(conventional.scope()?).map(String::as_str)
+    }
+
+    /// Parses a commit message as a conventional commit, when conventional parsing is enabled.
+    fn parse_conventional(&self, commit: &RepoCommit) -> Option<ConventionalCommit> {
+        if !self.config.conventional.enabled {
+            return None;
+        }
+        ConventionalCommit::parse(&commit.message).ok()
     }
 
     /// Parses a Git commit into a changelog entry.
@@ -452,3 +571,15 @@ impl<'a> ChangelogCollector<'a> {
         SectionType::Other
     }
 }
+
+/// Describes a history walk that could not be started.
+fn history_error(query: &HistoryQuery, error: &RepoError) -> ChangelogError {
+    ChangelogError::GitError {
+        operation: format!(
+            "get commits between {} and {}",
+            query.since.as_deref().unwrap_or("the first commit"),
+            query.until.as_deref().unwrap_or("HEAD")
+        ),
+        reason: error.as_ref().to_string(),
+    }
+}
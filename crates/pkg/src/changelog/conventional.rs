@@ -21,6 +21,20 @@
 //! [optional footer(s)]
 //! ```
 //!
+//! # Squash-Merge Commits
+//!
+//! Squash merges collapse a pull request into one commit whose body lists the original
+//! commits. [`ConventionalCommit::split_squashed`] recovers those commits so they can be
+//! parsed individually:
+//!
+//! ```text
+//! Add OAuth2 support (#42)
+//!
+//! * feat(auth): add OAuth2 support
+//!
+//! * fix(auth): refresh expired tokens
+//! ```
+//!
 //! # Configuration Integration
 //!
 //! This module works with [`crate::config::ConventionalConfig`] for customizing:
@@ -62,6 +76,7 @@
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::OnceLock;
 
 use crate::error::{ChangelogError, ChangelogResult};
 
@@ -194,6 +209,66 @@ impl ConventionalCommit {
         Ok(Self { commit_type, scope, breaking, description, body, footers })
     }
 
+    /// Splits a squash-merge commit message into the commit messages it combines.
+    ///
+    /// Squash merges keep the pull request title as the subject and list the squashed
+    /// commits in the body, one `type(scope): subject` line per commit, optionally
+    /// prefixed with `* ` or `- `. Lines following an item, up to the next item, form that
+    /// item's body and footers.
+    ///
+    /// A message is treated as a squash when its body lists at least two items, or at least
+    /// one bulleted item. Item types must be lowercase, so footers such as `Refs: #12` are
+    /// never mistaken for items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let message = "Add login (#12)\n\n* feat(auth): add login\n\n* fix(auth): handle expiry";
+    /// let items = ConventionalCommit::split_squashed(message).unwrap_or_default();
+    /// assert_eq!(items, vec!["feat(auth): add login", "fix(auth): handle expiry"]);
+    ///
+    /// assert!(ConventionalCommit::split_squashed("feat: add login").is_none());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The combined commit messages, or `None` if the message is not a squash commit.
+    #[must_use]
+    pub fn split_squashed(message: &str) -> Option<Vec<String>> {
+        static ITEM_REGEX: OnceLock<Regex> = OnceLock::new();
+        let item_regex = ITEM_REGEX.get_or_init(|| {
+            Regex::new(r"^[a-z]+(\([^)]+\))?!?:\s*\S").unwrap_or_else(|_| {
+                // This should never fail with a valid regex pattern
+                unreachable!("Squash item regex pattern is invalid")
+            })
+        });
+
+        let mut items: Vec<Vec<&str>> = Vec::new();
+        let mut bulleted = false;
+        let mut previous_blank = true;
+
+        // The subject is the pull request title
+        for line in message.lines().skip(1) {
+            let trimmed = line.trim();
+            let bullet = trimmed.strip_prefix("* ").or_else(|| trimmed.strip_prefix("- "));
+            let candidate = bullet.map_or(trimmed, str::trim_start);
+
+            if (bullet.is_some() || previous_blank) && item_regex.is_match(candidate) {
+                bulleted |= bullet.is_some();
+                items.push(vec![candidate]);
+            } else if let Some(item) = items.last_mut() {
+                item.push(line);
+            }
+            previous_blank = trimmed.is_empty();
+        }
+
+        if items.len() < 2 && !(bulleted && items.len() == 1) {
+            return None;
+        }
+
+        Some(items.into_iter().map(|item| item.join("\n").trim_end().to_string()).collect())
+    }
+
     /// Returns the commit type (feat, fix, docs, etc.).
     ///
     /// # Examples
@@ -166,6 +166,8 @@ impl<'a> ConventionalCommitsFormatter<'a> {
             }
         }
 
+        output.push_str(&changelog.dependencies_to_markdown(self.config));
+
         output
     }
 
@@ -179,7 +181,7 @@ impl<'a> ConventionalCommitsFormatter<'a> {
     ///
     /// The formatted version header string.
     pub(crate) fn format_version_header(&self, changelog: &Changelog) -> String {
-        let date_str = changelog.date.format("%Y-%m-%d").to_string();
+        let date_str = self.config.date.format_date(&changelog.date);
 
         // Use template if provided, otherwise use standard format
         if self.config.template.version_header.contains("{version}")
@@ -169,6 +169,8 @@ impl<'a> CustomTemplateFormatter<'a> {
             }
         }
 
+        output.push_str(&changelog.dependencies_to_markdown(self.config));
+
         output
     }
 
@@ -194,7 +196,7 @@ impl<'a> CustomTemplateFormatter<'a> {
     /// // Output: "## [1.0.0] - 2024-01-15"
     /// ```
     pub(crate) fn format_version_header(&self, changelog: &Changelog) -> String {
-        let date_str = changelog.date.format("%Y-%m-%d").to_string();
+        let date_str = self.config.date.format_date(&changelog.date);
         let package_name = changelog.package_name.as_deref().unwrap_or("");
 
         self.config
@@ -291,7 +293,7 @@ impl<'a> CustomTemplateFormatter<'a> {
     /// // Output: "- Add new feature (abc123d)"
     /// ```
     pub(crate) fn format_entry(&self, entry: &ChangelogEntry) -> String {
-        let date_str = entry.date.format("%Y-%m-%d").to_string();
+        let date_str = self.config.date.format_date(&entry.date);
         let commit_type = entry.commit_type.as_deref().unwrap_or("");
         let scope = entry.scope.as_deref().unwrap_or("");
         let breaking_marker = if entry.breaking { "BREAKING: " } else { "" };
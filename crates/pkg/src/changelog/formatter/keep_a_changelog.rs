@@ -223,6 +223,8 @@ impl<'a> KeepAChangelogFormatter<'a> {
             }
         }
 
+        output.push_str(&changelog.dependencies_to_markdown(self.config));
+
         output
     }
 
@@ -236,7 +238,7 @@ impl<'a> KeepAChangelogFormatter<'a> {
     ///
     /// The formatted version header string.
     pub(crate) fn format_version_header(&self, changelog: &Changelog) -> String {
-        let date_str = changelog.date.format("%Y-%m-%d").to_string();
+        let date_str = self.config.date.format_date(&changelog.date);
 
         // Use template if provided, otherwise use Keep a Changelog standard format
         if self.config.template.version_header.contains("{version}")
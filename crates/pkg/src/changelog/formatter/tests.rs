@@ -91,6 +91,24 @@ fn test_format_version_header() {
     assert!(header.starts_with("##"));
 }
 
+#[test]
+fn test_format_version_header_with_date_config() {
+    let mut config = ChangelogConfig::default();
+    config.date.format = "%B %-d, %Y".to_string();
+    config.date.timezone = "-05:00".to_string();
+
+    let date = Utc.with_ymd_and_hms(2024, 3, 1, 2, 30, 0).unwrap();
+    let changelog = Changelog::new(Some("test-pkg"), "1.0.0", None, date);
+
+    let keep = KeepAChangelogFormatter::new(&config).format_version_header(&changelog);
+    let conventional = ConventionalCommitsFormatter::new(&config).format_version_header(&changelog);
+    let custom = CustomTemplateFormatter::new(&config).format_version_header(&changelog);
+
+    assert_eq!(keep, "## [1.0.0] - February 29, 2024");
+    assert_eq!(conventional, keep);
+    assert_eq!(custom, keep);
+}
+
 #[test]
 fn test_format_entry_without_links() {
     let config = ChangelogConfig {
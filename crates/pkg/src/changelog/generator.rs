@@ -41,14 +41,18 @@
 //! # }
 //! ```
 
+use crate::changelog::types::heading_anchor;
 use crate::changelog::version_detection::{VersionTag, find_previous_version, parse_version_tag};
 use crate::changelog::{Changelog, ChangelogCollector, ChangelogMetadata};
+use crate::changelog::{ReleaseBaseline, ReleaseBaselineResolver};
 use crate::config::ChangelogConfig;
 use crate::error::{ChangelogError, ChangelogResult};
+use crate::line_endings::LineEnding;
 use crate::types::VersionBump;
-use chrono::Utc;
+use futures::stream::{self, StreamExt};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use sublime_git_tools::Repo;
+use sublime_git_tools::{HistoryQuery, Repo};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 
 /// Main changelog generator for creating and managing changelogs.
@@ -560,11 +564,7 @@ impl ChangelogGenerator {
         let tag_names: Vec<String> = git_tags.iter().map(|t| t.tag.clone()).collect();
 
         // Determine which format to use based on package_name
-        let format = if package_name.is_some() {
-            &self.config.version_tag_format
-        } else {
-            &self.config.root_tag_format
-        };
+        let format = self.config.tag_format(package_name);
 
         // Find previous version
         find_previous_version(&tag_names, current_version, package_name, format)
@@ -626,11 +626,7 @@ impl ChangelogGenerator {
         package_name: Option<&str>,
     ) -> ChangelogResult<Option<VersionTag>> {
         // Determine which format to use
-        let format = if package_name.is_some() {
-            &self.config.version_tag_format
-        } else {
-            &self.config.root_tag_format
-        };
+        let format = self.config.tag_format(package_name);
 
         Ok(parse_version_tag(tag, package_name, format))
     }
@@ -702,11 +698,7 @@ impl ChangelogGenerator {
         let tag_names: Vec<String> = git_tags.iter().map(|t| t.tag.clone()).collect();
 
         // Determine which format to use
-        let format = if package_name.is_some() {
-            &self.config.version_tag_format
-        } else {
-            &self.config.root_tag_format
-        };
+        let format = self.config.tag_format(package_name);
 
         // Parse and filter tags
         let mut version_tags: Vec<VersionTag> = tag_names
@@ -809,8 +801,17 @@ impl ChangelogGenerator {
 
         // Collect commits using the collector
         let collector = ChangelogCollector::new(&self.git_repo, &self.config);
-        let sections =
-            collector.collect_between_versions(&from_ref, &to_ref, relative_path).await?;
+        let range =
+            HistoryQuery::new().since(from_ref.as_str()).until(to_ref.as_str()).first_parent();
+        let mut query = range.clone();
+        if let Some(path) = relative_path {
+            query = query.path(path);
+        }
+        let entries =
+            collector.collect_entries(package_name, collector.read_history(&query)?, || {
+                collector.read_history(&range)
+            })?;
+        let sections = collector.sections_from(entries);
 
         // Build changelog metadata
         let metadata = self.build_metadata(
@@ -823,8 +824,12 @@ impl ChangelogGenerator {
         )?;
 
         // Create changelog
-        let mut changelog =
-            Changelog::new(package_name, version, prev_version.as_deref(), Utc::now());
+        let mut changelog = Changelog::new(
+            package_name,
+            version,
+            prev_version.as_deref(),
+            self.config.date.release_date(),
+        );
 
         // Add sections
         for section in sections {
@@ -859,11 +864,7 @@ impl ChangelogGenerator {
         previous_version: Option<&str>,
         current_version: &str,
     ) -> ChangelogResult<(String, String)> {
-        let format = if package_name.is_some() {
-            &self.config.version_tag_format
-        } else {
-            &self.config.root_tag_format
-        };
+        let format = self.config.tag_format(package_name);
 
         // Build to_ref (current version tag)
         let to_ref = if let Some(pkg_name) = package_name {
@@ -978,6 +979,9 @@ impl ChangelogGenerator {
     /// one by prepending the new version section. The operation can be performed in dry-run
     /// mode, which returns the content without writing to the file system.
     ///
+    /// The file is resolved with `ChangelogConfig::package_filename` for the changelog's
+    /// package, or `filename` for the root changelog.
+    ///
     /// # Arguments
     ///
     /// * `package_path` - The path to the package directory
@@ -991,6 +995,7 @@ impl ChangelogGenerator {
     /// # Errors
     ///
     /// This method returns an error if:
+    /// - The changelog file is missing and creating it is disabled (`create_missing`)
     /// - File system operations fail
     /// - The existing changelog cannot be parsed
     /// - The path is invalid
@@ -1036,13 +1041,18 @@ impl ChangelogGenerator {
         changelog: &Changelog,
         dry_run: bool,
     ) -> ChangelogResult<String> {
-        let changelog_path = package_path.join(&self.config.filename);
+        let package_name = changelog.package_name.as_deref();
+        let changelog_path = package_path.join(self.changelog_filename(package_name));
+        let exists = self.fs.exists(&changelog_path).await;
+        if !exists && !self.config.creates_missing(package_name) {
+            return Err(ChangelogError::NotFound { path: changelog_path });
+        }
 
         // Render new content
         let new_section = changelog.to_markdown(&self.config);
 
         // Read existing changelog (if exists)
-        let existing_content = if self.fs.exists(&changelog_path).await {
+        let existing_content = if exists {
             self.fs.read_file_string(&changelog_path).await.map_err(|e| {
                 ChangelogError::FileSystemError {
                     path: changelog_path.clone(),
@@ -1059,8 +1069,10 @@ impl ChangelogGenerator {
             header
         };
 
-        // Prepend new section
-        let updated_content = self.prepend_changelog(&existing_content, &new_section);
+        // Prepend new section, keeping the line endings of the existing file
+        let line_ending = LineEnding::detect(&existing_content);
+        let updated_content =
+            line_ending.apply(&self.prepend_changelog(&existing_content, &new_section));
 
         // Write if not dry-run
         if !dry_run {
@@ -1074,7 +1086,7 @@ impl ChangelogGenerator {
                 })?;
             }
 
-            self.fs.write_file_string(&changelog_path, &updated_content).await.map_err(|e| {
+            self.fs.write_file_atomic(&changelog_path, &updated_content).await.map_err(|e| {
                 ChangelogError::UpdateFailed {
                     path: changelog_path.clone(),
                     reason: e.as_ref().to_string(),
@@ -1085,6 +1097,68 @@ impl ChangelogGenerator {
         Ok(updated_content)
     }
 
+    /// Writes generated changelogs to disk.
+    ///
+    /// Different files are written concurrently, at most `changelog.concurrency` at a time,
+    /// and each file is replaced atomically (see [`GeneratedChangelog::write`]). Changelogs
+    /// that resolve to the same file are written one after another, in the order given, each
+    /// prepended to the previous one, so none of them is lost. Every file is attempted; the
+    /// first failure, in file order, is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `changelogs` - The changelogs to write
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangelogError::FileSystemError` if a changelog cannot be read or written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let changelogs = generator.generate_from_changeset(&changeset, &resolution).await?;
+    /// generator.write_changelogs(&changelogs).await?;
+    /// ```
+    ///
+    /// [`GeneratedChangelog::write`]: crate::changelog::GeneratedChangelog::write
+    pub async fn write_changelogs(
+        &self,
+        changelogs: &[crate::changelog::GeneratedChangelog],
+    ) -> ChangelogResult<()> {
+        let mut files: Vec<Vec<&crate::changelog::GeneratedChangelog>> = Vec::new();
+        let mut file_index: HashMap<&Path, usize> = HashMap::new();
+        for changelog in changelogs {
+            let index =
+                *file_index.entry(changelog.changelog_path.as_path()).or_insert_with(|| {
+                    files.push(Vec::new());
+                    files.len() - 1
+                });
+            files[index].push(changelog);
+        }
+
+        let writes: Vec<_> = files
+            .into_iter()
+            .map(|file| async move {
+                for (position, changelog) in file.into_iter().enumerate() {
+                    if position == 0 || changelog.existing {
+                        changelog.write(&self.fs).await?;
+                    } else {
+                        // The file was created by the previous changelog for this path
+                        let changelog = crate::changelog::GeneratedChangelog {
+                            existing: true,
+                            ..changelog.clone()
+                        };
+                        changelog.write(&self.fs).await?;
+                    }
+                }
+                Ok(())
+            })
+            .collect();
+        let results: Vec<ChangelogResult<()>> =
+            stream::iter(writes).buffered(self.config.concurrency.max(1)).collect().await;
+        results.into_iter().collect()
+    }
+
     /// Parses an existing CHANGELOG.md file.
     ///
     /// This method reads and parses an existing changelog file, extracting version
@@ -1094,6 +1168,8 @@ impl ChangelogGenerator {
     /// # Arguments
     ///
     /// * `package_path` - The path to the package directory
+    /// * `package_name` - The package whose changelog file is read, or `None` for the root
+    ///   changelog
     ///
     /// # Returns
     ///
@@ -1129,7 +1205,7 @@ impl ChangelogGenerator {
     /// ).await?;
     ///
     /// let package_path = PathBuf::from("packages/my-package");
-    /// let parsed = generator.parse_changelog(&package_path).await?;
+    /// let parsed = generator.parse_changelog(&package_path, Some("my-package")).await?;
     ///
     /// println!("Found {} versions", parsed.versions.len());
     /// if let Some(latest) = parsed.latest_version() {
@@ -1141,10 +1217,11 @@ impl ChangelogGenerator {
     pub async fn parse_changelog(
         &self,
         package_path: &Path,
+        package_name: Option<&str>,
     ) -> ChangelogResult<crate::changelog::parser::ParsedChangelog> {
         use crate::changelog::parser::ChangelogParser;
 
-        let changelog_path = package_path.join(&self.config.filename);
+        let changelog_path = package_path.join(self.changelog_filename(package_name));
 
         // Check if file exists
         if !self.fs.exists(&changelog_path).await {
@@ -1164,6 +1241,11 @@ impl ChangelogGenerator {
         parser.parse(&content)
     }
 
+    /// Returns the changelog file of a package, or of the workspace root for `None`.
+    pub(crate) fn changelog_filename(&self, package_name: Option<&str>) -> &str {
+        package_name.map_or(&self.config.filename, |name| self.config.package_filename(name))
+    }
+
     /// Prepends a new changelog section to existing content.
     ///
     /// This method intelligently inserts the new version section after the header
@@ -1359,6 +1441,10 @@ impl ChangelogGenerator {
 
     /// Generates changelogs for each package in a monorepo.
     ///
+    /// Packages are generated concurrently, at most `changelog.concurrency` at a time. Each
+    /// package walks its history on a blocking thread with its own repository handle, since
+    /// git reads are synchronous. The changelogs are returned in resolution order.
+    ///
     /// # Arguments
     ///
     /// * `changeset` - The changeset with commits
@@ -1372,124 +1458,250 @@ impl ChangelogGenerator {
         _changeset: &crate::types::Changeset,
         version_resolution: &crate::version::VersionResolution,
     ) -> ChangelogResult<Vec<crate::changelog::GeneratedChangelog>> {
-        use crate::changelog::GeneratedChangelog;
-        use sublime_standard_tools::filesystem::AsyncFileSystem;
+        // Shared by all packages so cross-package changelog anchors line up.
+        let release_date = self.config.date.release_date();
+        // Tags are loaded once; an empty repository has no baselines at all.
+        let baselines = ReleaseBaselineResolver::new(&self.git_repo, &self.config).ok();
+
+        // Futures are created up front so the stream holds no closures borrowing the updates
+        let packages: Vec<_> = version_resolution
+            .updates
+            .iter()
+            .map(|update| {
+                self.generate_package_changelog(
+                    update,
+                    version_resolution,
+                    baselines.as_ref(),
+                    release_date,
+                )
+            })
+            .collect();
+        let results: Vec<ChangelogResult<Option<crate::changelog::GeneratedChangelog>>> =
+            stream::iter(packages).buffered(self.config.concurrency.max(1)).collect().await;
 
         let mut changelogs = Vec::new();
+        for result in results {
+            changelogs.extend(result?);
+        }
+        Ok(changelogs)
+    }
 
-        for update in &version_resolution.updates {
-            // Load package.json to get package name
-            let package_json_path = update.path.join("package.json");
-            let package_json_content =
-                self.fs.read_file_string(&package_json_path).await.map_err(|_e| {
-                    ChangelogError::PackageNotFound { package: update.name.clone() }
-                })?;
+    /// Generates the changelog of one package of a release.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The package update the changelog describes
+    /// * `version_resolution` - The full resolution, used for dependency entries
+    /// * `baselines` - Release baselines shared by every package of the release
+    /// * `release_date` - The release date used for every changelog in this run
+    ///
+    /// # Returns
+    ///
+    /// The generated changelog, or `None` when the package has no changelog file and is
+    /// configured not to get one.
+    async fn generate_package_changelog(
+        &self,
+        update: &crate::version::PackageUpdate,
+        version_resolution: &crate::version::VersionResolution,
+        baselines: Option<&ReleaseBaselineResolver<'_>>,
+        release_date: chrono::DateTime<chrono::Utc>,
+    ) -> ChangelogResult<Option<crate::changelog::GeneratedChangelog>> {
+        // Load package.json to get package name
+        let package_json_path = update.path.join("package.json");
+        let package_json_content = self
+            .fs
+            .read_file_string(&package_json_path)
+            .await
+            .map_err(|_e| ChangelogError::PackageNotFound { package: update.name.clone() })?;
 
-            let package_json: package_json::PackageJson =
-                serde_json::from_str(&package_json_content).map_err(|e| {
-                    ChangelogError::FileSystemError {
-                        path: package_json_path.clone(),
-                        reason: format!("Failed to parse package.json: {}", e),
-                    }
-                })?;
+        let package_json: package_json::PackageJson = serde_json::from_str(&package_json_content)
+            .map_err(|e| {
+            ChangelogError::FileSystemError {
+                path: package_json_path.clone(),
+                reason: format!("Failed to parse package.json: {}", e),
+            }
+        })?;
 
-            let package_name = package_json.name.clone();
+        let package_name = package_json.name.clone();
 
-            // Determine relative path for commit filtering
-            let relative_path = update
-                .path
-                .strip_prefix(&self.workspace_root)
-                .ok()
-                .and_then(|p| p.to_str())
-                .map(String::from);
+        // Packages without a changelog file may opt out of getting one
+        let changelog_path = update.path.join(self.config.package_filename(&package_name));
+        let existing = self.fs.exists(&changelog_path).await;
+        if !existing && !self.config.creates_missing(Some(&package_name)) {
+            return Ok(None);
+        }
 
-            // Determine previous version for Git refs
-            let previous_version = if let Ok(prev_tag) = self
-                .detect_previous_version(Some(&package_name), &update.next_version.to_string())
+        // Determine relative path for commit filtering
+        let relative_path = update
+            .path
+            .strip_prefix(&self.workspace_root)
+            .ok()
+            .and_then(|p| p.to_str())
+            .map(String::from);
+
+        // Collect commits since the package was last released. The walk is synchronous git
+        // work, so it runs on a blocking thread with its own repository handle.
+        let baseline = baselines.and_then(|b| b.resolve(Some(&package_name)).ok());
+        let (previous_version, from_ref) = baseline_refs(baseline.as_ref());
+        let sections = match baseline {
+            Some(baseline) => {
+                let repo_path = self.git_repo.get_repo_path().to_path_buf();
+                let config = self.config.clone();
+                let name = package_name.clone();
+                tokio::task::spawn_blocking(move || {
+                    let repo = Repo::open(&repo_path.to_string_lossy()).map_err(|e| {
+                        ChangelogError::GitError {
+                            operation: "open repository".to_string(),
+                            reason: e.as_ref().to_string(),
+                        }
+                    })?;
+                    collect_sections_since(&repo, &config, &baseline, Some(&name), &relative_path)
+                })
                 .await
-            {
-                prev_tag.map(|t| t.version().to_string())
-            } else {
-                None
-            };
-
-            // Build Git refs and collect commits
-            let (sections, from_ref, to_ref) = if let Some(ref prev_version) = previous_version {
-                let (from_ref, to_ref) = self.build_git_refs(
-                    Some(&package_name),
-                    Some(prev_version.as_str()),
-                    &update.next_version.to_string(),
-                )?;
-
-                // Collect commits for this package
-                let collector = ChangelogCollector::new(&self.git_repo, &self.config);
-                let sections = collector
-                    .collect_between_versions(&from_ref, &to_ref, relative_path.as_deref())
-                    .await?;
-
-                (sections, from_ref, to_ref)
-            } else {
-                // No previous version - try to collect all commits using git log
-                // If that fails (empty repo), return empty sections
-                let collector = ChangelogCollector::new(&self.git_repo, &self.config);
-
-                // Try to get commits since the beginning
-                // Use get_commits_since with None to get all commits
-                let commits_result = self.git_repo.get_commits_since(None, &relative_path);
-
-                let sections = if let Ok(commits) = commits_result {
-                    collector.process_commits(commits)?
-                } else {
-                    // Empty repo or no commits accessible
-                    Vec::new()
-                };
-
-                // Use empty refs for metadata when no previous version
-                (sections, String::new(), "HEAD".to_string())
-            };
-
-            // Build metadata
-            let metadata = self.build_metadata(
-                Some(&package_name),
-                &update.next_version.to_string(),
-                previous_version.as_deref(),
-                &from_ref,
-                &to_ref,
-                &sections,
-            )?;
-
-            // Create changelog
-            let mut changelog = crate::changelog::Changelog::new(
-                Some(&package_name),
-                &update.next_version.to_string(),
-                previous_version.as_deref(),
-                chrono::Utc::now(),
-            );
-
-            for section in sections {
-                changelog.add_section(section);
+                .map_err(|e| ChangelogError::GitError {
+                    operation: "get commits since release".to_string(),
+                    reason: format!("Changelog task for {package_name} failed: {e}"),
+                })??
             }
+            None => Vec::new(),
+        };
+        // The release tag this changelog describes; it is created once the release lands
+        let to_ref = self.config.release_tag(Some(&package_name), &update.next_version.to_string());
 
-            changelog.metadata = metadata;
+        // Build metadata
+        let metadata = self.build_metadata(
+            Some(&package_name),
+            &update.next_version.to_string(),
+            previous_version.as_deref(),
+            &from_ref,
+            &to_ref,
+            &sections,
+        )?;
 
-            // Render to markdown
-            let content = changelog.to_markdown(&self.config);
+        // Create changelog
+        let mut changelog = crate::changelog::Changelog::new(
+            Some(&package_name),
+            &update.next_version.to_string(),
+            previous_version.as_deref(),
+            release_date,
+        );
 
-            // Determine changelog path
-            let changelog_path = update.path.join(&self.config.filename);
-            let existing = self.fs.exists(&changelog_path).await;
+        for section in sections {
+            changelog.add_section(section);
+        }
 
-            changelogs.push(GeneratedChangelog::new(
-                Some(package_name),
-                update.path.clone(),
-                changelog,
-                content,
-                existing,
-                changelog_path,
-            ));
+        changelog.metadata = metadata;
+
+        if self.config.include_dependency_updates {
+            changelog.dependencies =
+                self.build_dependency_entries(update, version_resolution, release_date);
         }
 
-        Ok(changelogs)
+        // Render to markdown
+        let content = changelog.to_markdown(&self.config);
+
+        Ok(Some(crate::changelog::GeneratedChangelog::new(
+            Some(package_name),
+            update.path.clone(),
+            changelog,
+            content,
+            existing,
+            changelog_path,
+        )))
+    }
+
+    /// Collects changelog sections since the last release of a package or the workspace.
+    ///
+    /// The baseline is resolved from tags (see [`ReleaseBaselineResolver`]), so this works for
+    /// packages that were released before, packages only covered by unified tags, and packages
+    /// that were never released. When no baseline can be resolved at all (an empty repository),
+    /// no sections are collected.
+    ///
+    /// # Arguments
+    ///
+    /// * `baselines` - The baseline resolver, if tags could be loaded
+    /// * `package_name` - The package to collect for, or `None` for the workspace root
+    /// * `relative_path` - Optional path filter for the package
+    ///
+    /// # Returns
+    ///
+    /// A tuple of (sections, previous version, from_ref).
+    pub(crate) fn collect_since_baseline(
+        &self,
+        baselines: Option<&ReleaseBaselineResolver<'_>>,
+        package_name: Option<&str>,
+        relative_path: &Option<String>,
+    ) -> ChangelogResult<(Vec<crate::changelog::ChangelogSection>, Option<String>, String)> {
+        let baseline = baselines.and_then(|b| b.resolve(package_name).ok());
+        let (previous_version, from_ref) = baseline_refs(baseline.as_ref());
+        let sections = match &baseline {
+            Some(baseline) => collect_sections_since(
+                &self.git_repo,
+                &self.config,
+                baseline,
+                package_name,
+                relative_path,
+            )?,
+            None => Vec::new(),
+        };
+
+        Ok((sections, previous_version, from_ref))
+    }
+
+    /// Builds the dependency entries for a package changelog.
+    ///
+    /// Each internal dependency update recorded during version resolution becomes an entry.
+    /// When the dependency is released in the same resolution, the entry carries its new
+    /// version and a relative link to the matching heading in its changelog.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The package update whose dependencies are described
+    /// * `version_resolution` - The full resolution, used to look up dependency releases
+    /// * `date` - The release date used for every changelog in this run
+    ///
+    /// # Returns
+    ///
+    /// The dependency entries in resolution order.
+    pub(crate) fn build_dependency_entries(
+        &self,
+        update: &crate::version::PackageUpdate,
+        version_resolution: &crate::version::VersionResolution,
+        date: chrono::DateTime<chrono::Utc>,
+    ) -> Vec<crate::changelog::DependencyChangelogEntry> {
+        update
+            .dependency_updates
+            .iter()
+            .map(|dependency| {
+                let released = version_resolution
+                    .updates
+                    .iter()
+                    .find(|candidate| candidate.name == dependency.dependency_name);
+
+                let version = released.map(|r| r.next_version.to_string());
+                let link = released.and_then(|r| {
+                    let version = r.next_version.to_string();
+                    let heading = self
+                        .config
+                        .template
+                        .version_header
+                        .replace("{version}", &version)
+                        .replace("{date}", &self.config.date.format_date(&date));
+                    let changelog_path = relative_path(&update.path, &r.path)?
+                        .join(self.config.package_filename(&r.name));
+                    let changelog_path = changelog_path.to_str()?.replace('\\', "/");
+                    Some(format!("{}#{}", changelog_path, heading_anchor(&heading)))
+                });
+
+                crate::changelog::DependencyChangelogEntry {
+                    name: dependency.dependency_name.clone(),
+                    old_version_spec: dependency.old_version_spec.clone(),
+                    new_version_spec: dependency.new_version_spec.clone(),
+                    version,
+                    link,
+                }
+            })
+            .collect()
     }
 
     /// Generates a root changelog for the entire workspace.
@@ -1510,6 +1722,12 @@ impl ChangelogGenerator {
         use crate::changelog::GeneratedChangelog;
         use sublime_standard_tools::filesystem::AsyncFileSystem;
 
+        let changelog_path = self.workspace_root.join(&self.config.filename);
+        let existing = self.fs.exists(&changelog_path).await;
+        if !existing && !self.config.creates_missing(None) {
+            return Ok(Vec::new());
+        }
+
         // For root changelog, we need to determine a version
         // Use the highest version from the resolution, or construct one
         let version = if let Some(first_update) = version_resolution.updates.first() {
@@ -1519,35 +1737,11 @@ impl ChangelogGenerator {
             return Ok(Vec::new());
         };
 
-        // Determine previous version for Git refs
-        let previous_version =
-            if let Ok(prev_tag) = self.detect_previous_version(None, &version).await {
-                prev_tag.map(|t| t.version().to_string())
-            } else {
-                None
-            };
-
-        // Build Git refs and collect commits
-        let collector = ChangelogCollector::new(&self.git_repo, &self.config);
-        let (sections, from_ref, to_ref) = if let Some(ref prev_version) = previous_version {
-            let (from_ref, to_ref) =
-                self.build_git_refs(None, Some(prev_version.as_str()), &version)?;
-            let sections = collector.collect_between_versions(&from_ref, &to_ref, None).await?;
-            (sections, from_ref, to_ref)
-        } else {
-            // No previous version - try to collect all commits
-            let commits_result = self.git_repo.get_commits_since(None, &None);
-
-            let sections = if let Ok(commits) = commits_result {
-                collector.process_commits(commits)?
-            } else {
-                // Empty repo or no commits accessible
-                Vec::new()
-            };
-
-            // Use empty refs for metadata when no previous version
-            (sections, String::new(), "HEAD".to_string())
-        };
+        // Collect commits since the workspace was last released
+        let baselines = ReleaseBaselineResolver::new(&self.git_repo, &self.config).ok();
+        let (sections, previous_version, from_ref) =
+            self.collect_since_baseline(baselines.as_ref(), None, &None)?;
+        let to_ref = self.config.release_tag(None, &version);
 
         // Build metadata
         let metadata = self.build_metadata(
@@ -1564,7 +1758,7 @@ impl ChangelogGenerator {
             None,
             &version,
             previous_version.as_deref(),
-            chrono::Utc::now(),
+            self.config.date.release_date(),
         );
 
         for section in sections {
@@ -1576,10 +1770,6 @@ impl ChangelogGenerator {
         // Render to markdown
         let content = changelog.to_markdown(&self.config);
 
-        // Determine changelog path (at workspace root)
-        let changelog_path = self.workspace_root.join(&self.config.filename);
-        let existing = self.fs.exists(&changelog_path).await;
-
         Ok(vec![GeneratedChangelog::new(
             None,
             self.workspace_root.clone(),
@@ -1590,3 +1780,76 @@ impl ChangelogGenerator {
         )])
     }
 }
+
+/// Returns the previous version and the reference changelogs start from for a baseline.
+///
+/// Both are empty when no baseline could be resolved (an empty repository).
+fn baseline_refs(baseline: Option<&ReleaseBaseline>) -> (Option<String>, String) {
+    baseline.map_or((None, String::new()), |baseline| {
+        (baseline.version().map(ToString::to_string), baseline.git_ref().to_string())
+    })
+}
+
+/// Collects changelog sections from the commits made since a baseline.
+///
+/// Commits are read lazily and turned into entries as they arrive (see
+/// [`ChangelogCollector::collect_entries`]).
+///
+/// # Errors
+///
+/// Returns `ChangelogError::GitError` if the history cannot be read.
+fn collect_sections_since(
+    repo: &Repo,
+    config: &ChangelogConfig,
+    baseline: &ReleaseBaseline,
+    package_name: Option<&str>,
+    relative_path: &Option<String>,
+) -> ChangelogResult<Vec<crate::changelog::ChangelogSection>> {
+    let collector = ChangelogCollector::new(repo, config);
+    let entries = collector.collect_entries(
+        package_name,
+        baseline.commits_since(repo, relative_path)?,
+        || baseline.commits_since(repo, &None),
+    )?;
+    Ok(collector.sections_from(entries))
+}
+
+/// Computes the path of `to` relative to the directory `from`.
+///
+/// Both paths are compared component-wise; `..` segments are emitted for every component of
+/// `from` that is not shared with `to`. Returns `None` when the paths have different roots
+/// (e.g., different drive prefixes on Windows).
+///
+/// # Arguments
+///
+/// * `from` - The directory the relative path starts from
+/// * `to` - The target path
+///
+/// # Returns
+///
+/// The relative path, or `None` if no relative path exists.
+pub(crate) fn relative_path(from: &Path, to: &Path) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let from: Vec<Component<'_>> = from.components().collect();
+    let to: Vec<Component<'_>> = to.components().collect();
+
+    let common = from.iter().zip(to.iter()).take_while(|(a, b)| a == b).count();
+
+    let has_root = |components: &[Component<'_>]| {
+        components.iter().any(|c| matches!(c, Component::Prefix(_) | Component::RootDir))
+    };
+    if common == 0 && (has_root(&from) || has_root(&to)) {
+        return None;
+    }
+
+    let mut result = PathBuf::new();
+    for _ in common..from.len() {
+        result.push("..");
+    }
+    for component in &to[common..] {
+        result.push(component.as_os_str());
+    }
+
+    Some(result)
+}
@@ -16,6 +16,10 @@
 //! - **Multiple Formats**: Support for Keep a Changelog, Conventional Commits, and custom formats
 //! - **Monorepo Support**: Generate changelogs per package or at the root level
 //! - **Git Integration**: Detect versions from Git tags and analyze commit ranges
+//! - **Release Baselines**: Resolve the last release per package from tags, falling back to
+//!   unified root tags and then the first commit for packages that were never released
+//! - **Tag Backfill**: Create the release tags missing for versions released before tagging
+//!   was adopted, from the history of each `package.json`
 //! - **Template System**: Customizable templates for changelog sections and entries
 //! - **Breaking Changes**: Automatic detection and highlighting of breaking changes
 //! - **Issue Linking**: Automatic linking to issue trackers (GitHub, GitLab, etc.)
@@ -80,6 +84,8 @@
 #![allow(clippy::todo)]
 
 // Internal modules
+mod backfill;
+mod baseline;
 mod collector;
 mod conventional;
 mod formatter;
@@ -87,9 +93,12 @@ mod generator;
 mod merge_message;
 mod parser;
 mod types;
-mod version_detection;
+pub(crate) mod unreleased;
+pub(crate) mod version_detection;
 
 // Public re-exports
+pub use backfill::{MissingReleaseTag, ReleaseTagBackfill, TagBackfillPlan, UnresolvedRelease};
+pub use baseline::{BaselineSource, ReleaseBaseline, ReleaseBaselineResolver};
 pub use collector::ChangelogCollector;
 pub use conventional::{CommitFooter, ConventionalCommit, SectionType};
 pub use formatter::{
@@ -99,8 +108,10 @@ pub use generator::ChangelogGenerator;
 pub use merge_message::{MergeMessageContext, generate_merge_commit_message};
 pub use parser::{ChangelogParser, ParsedChangelog, ParsedVersion};
 pub use types::{
-    Changelog, ChangelogEntry, ChangelogMetadata, ChangelogSection, GeneratedChangelog,
+    Changelog, ChangelogEntry, ChangelogMetadata, ChangelogSection, DependencyChangelogEntry,
+    GeneratedChangelog,
 };
+pub use unreleased::UNRELEASED_HEADING;
 pub use version_detection::VersionTag;
 
 // Internal modules
@@ -17,7 +17,9 @@
 
 use crate::changelog::ChangelogGenerator;
 use crate::changelog::conventional::{ConventionalCommit, SectionType};
-use crate::config::{ChangelogConfig, ChangelogFormat, MonorepoMode, PackageToolsConfig};
+use crate::config::{
+    ChangelogConfig, ChangelogFormat, MonorepoMode, PackageChangelogConfig, PackageToolsConfig,
+};
 use proptest::prelude::*;
 use sublime_git_tools::Repo;
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
@@ -232,6 +234,49 @@ fn test_non_breaking_change() {
     assert!(!commit.is_breaking());
 }
 
+// ============================================================================
+// Squash Commit Tests
+// ============================================================================
+
+#[test]
+fn test_split_squashed_github_format() {
+    let message = "Add login (#12)\n\n* feat(auth): add login\n\nUses the new session API\n\n* fix(auth)!: expire tokens\n\nBREAKING CHANGE: sessions are shorter\n\n* chore: lint";
+    let items = ConventionalCommit::split_squashed(message).unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0], "feat(auth): add login\n\nUses the new session API");
+
+    let fix = ConventionalCommit::parse(&items[1]).unwrap();
+    assert_eq!(fix.commit_type(), "fix");
+    assert!(fix.is_breaking());
+    assert_eq!(ConventionalCommit::parse(&items[2]).unwrap().commit_type(), "chore");
+}
+
+#[test]
+fn test_split_squashed_single_bullet() {
+    let message = "Add login (#12)\n\n* feat(auth): add login";
+    let items = ConventionalCommit::split_squashed(message).unwrap();
+    assert_eq!(items, vec!["feat(auth): add login"]);
+}
+
+#[test]
+fn test_split_squashed_unbulleted_items() {
+    let message = "feat: add login (#12)\n\nfeat(auth): add login\n\nfix(auth): expire tokens";
+    let items = ConventionalCommit::split_squashed(message).unwrap();
+    assert_eq!(items, vec!["feat(auth): add login", "fix(auth): expire tokens"]);
+}
+
+#[test]
+fn test_split_squashed_regular_commits() {
+    assert!(ConventionalCommit::split_squashed("feat: add login").is_none());
+
+    // A single unbulleted line and footers are not squash items
+    let message = "feat: add login\n\nfix: typo found on the way\n\nRefs: #12";
+    assert!(ConventionalCommit::split_squashed(message).is_none());
+
+    let message = "feat: add login\n\nSome body text\nfix: not after a blank line\nfeat: neither";
+    assert!(ConventionalCommit::split_squashed(message).is_none());
+}
+
 // ============================================================================
 // Footer Parsing Tests
 // ============================================================================
@@ -1769,6 +1814,67 @@ mod data_collection_tests {
         assert!(!breaking.is_empty());
     }
 
+    async fn merge_strategy_descriptions(strategy: &str) -> Vec<String> {
+        let (temp_dir, repo) = create_test_repo();
+        repo.create_tag("v1.0.0", Some("Initial".to_string())).unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+
+        match strategy {
+            "merge" => {
+                repo.create_branch("feat/login").unwrap();
+                repo.checkout("feat/login").unwrap();
+                std::fs::write(temp_dir.path().join("login.txt"), "login").unwrap();
+                repo.commit_changes("feat(auth): add login").unwrap();
+                std::fs::write(temp_dir.path().join("expiry.txt"), "expiry").unwrap();
+                repo.commit_changes("fix(auth): expire tokens").unwrap();
+                repo.checkout(&main_branch).unwrap();
+                repo.merge("feat/login").unwrap();
+            }
+            "squash" => {
+                std::fs::write(temp_dir.path().join("login.txt"), "login").unwrap();
+                repo.commit_changes(
+                    "Add login (#12)\n\n* feat(auth): add login\n\n* fix(auth): expire tokens",
+                )
+                .unwrap();
+            }
+            _ => {
+                std::fs::write(temp_dir.path().join("login.txt"), "login").unwrap();
+                repo.commit_changes("feat(auth): add login").unwrap();
+                std::fs::write(temp_dir.path().join("expiry.txt"), "expiry").unwrap();
+                repo.commit_changes("fix(auth): expire tokens").unwrap();
+            }
+        }
+        repo.create_tag("v1.1.0", Some("Release".to_string())).unwrap();
+
+        let generator = ChangelogGenerator::new(
+            temp_dir.path().to_path_buf(),
+            repo,
+            FileSystemManager::new(),
+            ChangelogConfig::default(),
+        )
+        .await
+        .unwrap();
+        let changelog =
+            generator.generate_for_version(None, "1.1.0", Some("1.0.0"), None).await.unwrap();
+
+        let mut descriptions: Vec<String> = changelog
+            .sections
+            .iter()
+            .flat_map(|section| section.entries.iter().map(|entry| entry.description.clone()))
+            .collect();
+        descriptions.sort();
+        descriptions
+    }
+
+    #[tokio::test]
+    async fn test_generate_for_version_merge_strategies_are_equivalent() {
+        let rebased = merge_strategy_descriptions("rebase").await;
+        assert_eq!(rebased, vec!["add login", "expire tokens"]);
+
+        assert_eq!(merge_strategy_descriptions("merge").await, rebased);
+        assert_eq!(merge_strategy_descriptions("squash").await, rebased);
+    }
+
     #[tokio::test]
     async fn test_generate_for_version_auto_detect_previous() {
         let (temp_dir, repo) = create_test_repo();
@@ -2099,8 +2205,9 @@ mod data_collection_tests {
 mod collector_unit_tests {
     use super::*;
     use crate::changelog::{ChangelogCollector, ChangelogEntry, SectionType};
+    use crate::error::{ChangelogError, ChangelogResult};
     use chrono::Utc;
-    use sublime_git_tools::RepoCommit;
+    use sublime_git_tools::{RepoCommit, RepoError};
 
     fn create_test_commit(hash: &str, message: &str, author: &str, date: &str) -> RepoCommit {
         RepoCommit {
@@ -2235,6 +2342,232 @@ mod collector_unit_tests {
         drop(temp_dir);
     }
 
+    #[test]
+    fn test_expand_squashed() {
+        let (temp_dir, repo) = create_test_repo();
+        let config = ChangelogConfig::default();
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let squash = create_test_commit(
+            "s1",
+            "Add login (#12)\n\n* feat(auth): add login\n\n* fix(auth): expire tokens",
+            "John",
+            "2024-01-01",
+        );
+        let regular = create_test_commit("s2", "fix: typo", "John", "2024-01-01");
+
+        let commits: Vec<_> =
+            [squash, regular].into_iter().flat_map(|c| collector.expand_squashed(c)).collect();
+        let messages: Vec<&str> = commits.iter().map(|c| c.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["feat(auth): add login", "fix(auth): expire tokens", "fix: typo"]
+        );
+        assert!(commits[..2].iter().all(|c| c.hash == "s1" && c.author_name == "John"));
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_expand_squashed_without_conventional_parsing() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.conventional.enabled = false;
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let squash = create_test_commit(
+            "s1",
+            "Add login (#12)\n\n* feat(auth): add login\n\n* fix(auth): expire tokens",
+            "John",
+            "2024-01-01",
+        );
+
+        assert_eq!(collector.expand_squashed(squash).len(), 1);
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_should_include_commit_excluded_types() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.exclude.types = vec!["chore".to_string(), "ci".to_string()];
+
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let chore = create_test_commit("a1", "chore: update deps", "John", "2024-01-01");
+        assert!(!collector.should_include_commit(&chore));
+
+        let ci = create_test_commit("a2", "ci(release): tweak workflow", "John", "2024-01-01");
+        assert!(!collector.should_include_commit(&ci));
+
+        let breaking = create_test_commit("a3", "chore!: drop node 16", "John", "2024-01-01");
+        assert!(collector.should_include_commit(&breaking));
+
+        let feat = create_test_commit("a4", "feat: new feature", "John", "2024-01-01");
+        assert!(collector.should_include_commit(&feat));
+
+        let plain = create_test_commit("a5", "Update readme", "John", "2024-01-01");
+        assert!(collector.should_include_commit(&plain));
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_should_include_commit_included_types() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.include.types = vec!["feat".to_string(), "fix".to_string()];
+
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let feat = create_test_commit("b1", "feat: new feature", "John", "2024-01-01");
+        assert!(collector.should_include_commit(&feat));
+
+        let docs = create_test_commit("b2", "docs: update guide", "John", "2024-01-01");
+        assert!(!collector.should_include_commit(&docs));
+
+        let breaking = create_test_commit("b3", "refactor!: rename api", "John", "2024-01-01");
+        assert!(collector.should_include_commit(&breaking));
+
+        let plain = create_test_commit("b4", "Update readme", "John", "2024-01-01");
+        assert!(!collector.should_include_commit(&plain));
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_should_include_commit_author_wildcards() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.exclude.authors =
+            vec!["*[bot]".to_string(), "ci@example.com".to_string(), "release".to_string()];
+
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let renovate = create_test_commit("c1", "fix: bump dep", "renovate[bot]", "2024-01-01");
+        assert!(!collector.should_include_commit(&renovate));
+
+        let by_email = create_test_commit("c2", "fix: pipeline", "ci", "2024-01-01");
+        assert!(!collector.should_include_commit(&by_email));
+
+        let exact = create_test_commit("c3", "fix: release", "release", "2024-01-01");
+        assert!(!collector.should_include_commit(&exact));
+
+        // Patterns match the whole name, not a substring
+        let partial = create_test_commit("c4", "fix: notes", "release-team", "2024-01-01");
+        assert!(collector.should_include_commit(&partial));
+
+        let human = create_test_commit("c5", "fix: bug", "botanist", "2024-01-01");
+        assert!(collector.should_include_commit(&human));
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_scope_package() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.scope_packages.insert("core".to_string(), "@org/core".to_string());
+
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let scoped = create_test_commit("d1", "feat(core): add api", "John", "2024-01-01");
+        assert_eq!(collector.scope_package(&scoped), Some("@org/core"));
+
+        let unmapped = create_test_commit("d2", "feat(cli): add flag", "John", "2024-01-01");
+        assert_eq!(collector.scope_package(&unmapped), None);
+
+        let unscoped = create_test_commit("d3", "feat: add api", "John", "2024-01-01");
+        assert_eq!(collector.scope_package(&unscoped), None);
+
+        drop(temp_dir);
+    }
+
+    /// Commits as yielded by a lazy history walk.
+    type History = Vec<Result<RepoCommit, RepoError>>;
+
+    fn unused_range() -> ChangelogResult<History> {
+        panic!("range should not be loaded")
+    }
+
+    #[test]
+    fn test_collect_entries_attributes_by_scope() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.scope_packages.insert("core".to_string(), "@org/core".to_string());
+        config.scope_packages.insert("utils".to_string(), "@org/utils".to_string());
+
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let in_core = create_test_commit("e1", "feat(core): add api", "John", "2024-01-01");
+        let in_utils = create_test_commit("e2", "fix(utils): helper", "John", "2024-01-01");
+        let unscoped = create_test_commit("e3", "fix: shared bug", "John", "2024-01-01");
+        let root_file = create_test_commit("e4", "feat(core): regen types", "John", "2024-01-01");
+
+        let path_commits: History = vec![Ok(in_core.clone()), Ok(in_utils.clone()), Ok(unscoped)];
+        let mut all_commits = path_commits.clone();
+        all_commits.push(Ok(root_file));
+
+        let entries = collector
+            .collect_entries(Some("@org/core"), path_commits.clone(), || Ok(all_commits))
+            .unwrap();
+        let hashes: Vec<&str> = entries.iter().map(|e| e.commit_hash.as_str()).collect();
+        assert_eq!(hashes, vec!["e1", "e3", "e4"]);
+
+        // The workspace root keeps its path commits untouched
+        let entries = collector.collect_entries(None, path_commits, unused_range).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_collect_entries_without_mappings() {
+        let (temp_dir, repo) = create_test_repo();
+        let config = ChangelogConfig::default();
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        let commit = create_test_commit("f1", "feat(core): add api", "John", "2024-01-01");
+        let entries =
+            collector.collect_entries(Some("@org/core"), [Ok(commit)], unused_range).unwrap();
+        assert_eq!(entries.len(), 1);
+
+        drop(temp_dir);
+    }
+
+    #[test]
+    fn test_collect_entries_consumes_commits_as_they_arrive() {
+        let (temp_dir, repo) = create_test_repo();
+        let mut config = ChangelogConfig::default();
+        config.exclude.types = vec!["chore".to_string()];
+        let collector = ChangelogCollector::new(&repo, &config);
+
+        // Squash merges are split and excluded commits dropped one commit at a time
+        let squash = "Squash (#1)\n\n* feat: a\n\n* chore: b";
+        let commits =
+            (0..3).map(|i| Ok(create_test_commit(&format!("g{i}"), squash, "John", "2024-01-01")));
+        let entries = collector.collect_entries(None, commits, unused_range).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert!(entries.iter().all(|entry| entry.description == "a"));
+
+        // A failing read stops the walk without pulling the rest of the history
+        let pulled = std::cell::Cell::new(0);
+        let commits = (0..1000).map(|i| {
+            pulled.set(pulled.get() + 1);
+            if i == 2 {
+                Err(RepoError::Offline { operation: "read commit".to_string() })
+            } else {
+                Ok(create_test_commit(&format!("h{i}"), "feat: more", "John", "2024-01-01"))
+            }
+        });
+        let result = collector.collect_entries(None, commits, unused_range);
+        assert!(matches!(result, Err(ChangelogError::GitError { .. })));
+        assert_eq!(pulled.get(), 3);
+
+        drop(temp_dir);
+    }
+
     #[test]
     fn test_group_entries_by_section() {
         let (temp_dir, repo) = create_test_repo();
@@ -2590,6 +2923,37 @@ The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/).
         assert!(content.contains("## [1.0.0]"));
     }
 
+    #[tokio::test]
+    async fn test_update_changelog_preserves_crlf_line_endings() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let generator = create_test_generator(&temp_dir).await;
+
+        let package_path = temp_dir.path().join("packages/my-package");
+        generator.fs().create_dir_all(&package_path).await.expect("Failed to create package dir");
+
+        let changelog_path = package_path.join("CHANGELOG.md");
+        let initial_content =
+            "# Changelog\r\n\r\n## [1.0.0] - 2024-01-01\r\n\r\n- Initial release\r\n";
+        generator
+            .fs()
+            .write_file_string(&changelog_path, initial_content)
+            .await
+            .expect("Failed to write initial changelog");
+
+        let changelog = Changelog::new(Some("my-package"), "1.1.0", Some("1.0.0"), Utc::now());
+        generator
+            .update_changelog(&package_path, &changelog, false)
+            .await
+            .expect("Failed to update changelog");
+
+        let written =
+            generator.fs().read_file_string(&changelog_path).await.expect("Failed to read");
+        assert!(written.contains("## [1.1.0]"));
+        assert!(written.contains("- Initial release\r\n"));
+        assert_eq!(written.matches('\n').count(), written.matches("\r\n").count());
+        assert!(!written.contains("\r\r\n"));
+    }
+
     #[tokio::test]
     async fn test_parse_changelog_success() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
@@ -2619,8 +2983,10 @@ The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/).
             .expect("Failed to write changelog");
 
         // Parse it
-        let parsed =
-            generator.parse_changelog(&package_path).await.expect("Failed to parse changelog");
+        let parsed = generator
+            .parse_changelog(&package_path, None)
+            .await
+            .expect("Failed to parse changelog");
 
         assert_eq!(parsed.versions.len(), 2);
         assert_eq!(parsed.versions[0].version, "2.0.0");
@@ -2637,7 +3003,7 @@ The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.0.0/).
         generator.fs().create_dir_all(&package_path).await.expect("Failed to create package dir");
 
         // Try to parse non-existent changelog
-        let result = generator.parse_changelog(&package_path).await;
+        let result = generator.parse_changelog(&package_path, None).await;
 
         assert!(result.is_err());
         match result {
@@ -2870,8 +3236,10 @@ All notable changes will be documented here.
             .expect("Failed to update changelog");
 
         // Parse it back
-        let parsed =
-            generator.parse_changelog(&package_path).await.expect("Failed to parse changelog");
+        let parsed = generator
+            .parse_changelog(&package_path, None)
+            .await
+            .expect("Failed to parse changelog");
 
         assert_eq!(parsed.versions.len(), 1);
         assert_eq!(parsed.versions[0].version, "1.0.0");
@@ -3792,6 +4160,169 @@ mod generate_from_changeset_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_generate_from_changeset_package_changelog_overrides() {
+        let (temp_dir, repo) = create_test_repo();
+        let fs = FileSystemManager::new();
+        setup_test_monorepo(temp_dir.path(), &fs).await.unwrap();
+        // A lockfile makes the npm workspace detectable as a monorepo
+        fs.write_file_string(&temp_dir.path().join("package-lock.json"), "{}").await.unwrap();
+        add_test_commits(&repo, temp_dir.path()).unwrap();
+
+        let mut config = ChangelogConfig { create_missing: false, ..Default::default() };
+        config.packages.insert(
+            "@myorg/pkg-a".to_string(),
+            PackageChangelogConfig {
+                filename: Some("docs/HISTORY.md".to_string()),
+                create_missing: Some(true),
+                tag_format: None,
+            },
+        );
+
+        let generator =
+            ChangelogGenerator::new(temp_dir.path().to_path_buf(), repo, fs.clone(), config)
+                .await
+                .unwrap();
+
+        let changeset = create_test_changeset(
+            "feature-branch",
+            VersionBump::Minor,
+            vec!["@myorg/pkg-a", "@myorg/pkg-b"],
+        );
+        let resolution = create_test_resolution(vec![
+            ("@myorg/pkg-a", "1.0.0", "1.1.0", temp_dir.path().join("packages/pkg-a")),
+            ("@myorg/pkg-b", "2.0.0", "2.1.0", temp_dir.path().join("packages/pkg-b")),
+        ]);
+
+        let changelogs = generator.generate_from_changeset(&changeset, &resolution).await.unwrap();
+
+        // pkg-b has no changelog file and creating one is disabled
+        assert_eq!(changelogs.len(), 1);
+        assert_eq!(changelogs[0].package_name.as_deref(), Some("@myorg/pkg-a"));
+        assert_eq!(
+            changelogs[0].changelog_path,
+            temp_dir.path().join("packages/pkg-a/docs/HISTORY.md")
+        );
+        assert!(!changelogs[0].existing);
+    }
+
+    #[tokio::test]
+    async fn test_generate_and_write_many_package_changelogs_concurrently() {
+        let (temp_dir, repo) = create_test_repo();
+        let fs = FileSystemManager::new();
+        let root = temp_dir.path();
+        fs.write_file_string(
+            &root.join("package.json"),
+            r#"{"name": "root", "private": true, "workspaces": ["packages/*"]}"#,
+        )
+        .await
+        .unwrap();
+        fs.write_file_string(&root.join("package-lock.json"), "{}").await.unwrap();
+        std::fs::write(root.join("README.md"), "# Test").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: initial commit").unwrap();
+
+        let names: Vec<String> = (0..12).map(|i| format!("pkg-{i:02}")).collect();
+        for name in &names {
+            let dir = root.join("packages").join(name);
+            fs.write_file_string(
+                &dir.join("package.json"),
+                &format!(r#"{{"name": "@myorg/{name}", "version": "1.0.0"}}"#),
+            )
+            .await
+            .unwrap();
+            repo.add_all().unwrap();
+            repo.commit(&format!("feat: add {name}")).unwrap();
+        }
+
+        let config = ChangelogConfig {
+            monorepo_mode: MonorepoMode::PerPackage,
+            concurrency: 3,
+            ..Default::default()
+        };
+        let generator =
+            ChangelogGenerator::new(root.to_path_buf(), repo, fs.clone(), config).await.unwrap();
+
+        let packages: Vec<String> = names.iter().map(|name| format!("@myorg/{name}")).collect();
+        let changeset = create_test_changeset(
+            "feature-branch",
+            VersionBump::Minor,
+            packages.iter().map(String::as_str).collect(),
+        );
+        let resolution = create_test_resolution(
+            names
+                .iter()
+                .zip(&packages)
+                .map(|(name, package)| {
+                    (package.as_str(), "1.0.0", "1.1.0", root.join("packages").join(name))
+                })
+                .collect(),
+        );
+
+        let changelogs = generator.generate_from_changeset(&changeset, &resolution).await.unwrap();
+
+        // One changelog per package, in resolution order, each with only its own commit
+        let generated: Vec<&str> =
+            changelogs.iter().filter_map(|c| c.package_name.as_deref()).collect();
+        assert_eq!(generated, packages.iter().map(String::as_str).collect::<Vec<_>>());
+        for (changelog, name) in changelogs.iter().zip(&names) {
+            assert!(changelog.content.contains(&format!("add {name}")));
+            assert_eq!(
+                changelog.changelog.sections.iter().map(|s| s.entries.len()).sum::<usize>(),
+                1
+            );
+        }
+
+        generator.write_changelogs(&changelogs).await.unwrap();
+        for name in &names {
+            let dir = root.join("packages").join(name);
+            let written = fs.read_file_string(&dir.join("CHANGELOG.md")).await.unwrap();
+            assert!(written.contains(&format!("add {name}")));
+            // Atomic writes leave no temporary files behind
+            assert_eq!(fs.read_dir(&dir).await.unwrap().len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_write_changelogs_sharing_a_file_keeps_every_changelog() {
+        let (temp_dir, repo) = create_test_repo();
+        let fs = FileSystemManager::new();
+        let root = temp_dir.path().to_path_buf();
+        let generator =
+            ChangelogGenerator::new(root.clone(), repo, fs.clone(), ChangelogConfig::default())
+                .await
+                .unwrap();
+
+        let shared = root.join("CHANGELOG.md");
+        let other = root.join("packages/b/CHANGELOG.md");
+        let changelog = |name: &str, path: &std::path::Path| {
+            crate::changelog::GeneratedChangelog::new(
+                Some(name.to_string()),
+                root.clone(),
+                crate::changelog::Changelog::new(Some(name), "1.0.0", None, chrono::Utc::now()),
+                format!("## {name} 1.0.0\n"),
+                false,
+                path.to_path_buf(),
+            )
+        };
+        let changelogs = vec![
+            changelog("first", &shared),
+            changelog("b", &other),
+            changelog("second", &shared),
+            changelog("third", &shared),
+        ];
+
+        generator.write_changelogs(&changelogs).await.unwrap();
+
+        // Later changelogs for the same file are prepended, none overwrites another
+        let written = fs.read_file_string(&shared).await.unwrap();
+        let third = written.find("third").unwrap();
+        let second = written.find("second").unwrap();
+        let first = written.find("first").unwrap();
+        assert!(third < second && second < first, "{written}");
+        assert!(fs.read_file_string(&other).await.unwrap().contains("## b 1.0.0"));
+    }
+
     #[tokio::test]
     async fn test_generate_from_changeset_monorepo_root_mode() {
         let (temp_dir, repo) = create_test_repo();
@@ -4005,3 +4536,539 @@ mod generate_from_changeset_tests {
         assert!(content.contains("1.6.0"));
     }
 }
+
+mod dependency_section_tests {
+    use super::*;
+    use crate::changelog::generator::relative_path;
+    use crate::changelog::types::heading_anchor;
+    use crate::changelog::{Changelog, DependencyChangelogEntry};
+    use crate::changelog::{
+        ConventionalCommitsFormatter, CustomTemplateFormatter, KeepAChangelogFormatter,
+    };
+    use crate::types::{DependencyType, DependencyUpdate, UpdateReason, Version};
+    use crate::version::{PackageUpdate, VersionResolution};
+    use chrono::{TimeZone, Utc};
+    use std::path::{Path, PathBuf};
+
+    fn entry(version: Option<&str>, link: Option<&str>) -> DependencyChangelogEntry {
+        DependencyChangelogEntry {
+            name: "@myorg/core".to_string(),
+            old_version_spec: "^2.0.0".to_string(),
+            new_version_spec: "^2.1.0".to_string(),
+            version: version.map(String::from),
+            link: link.map(String::from),
+        }
+    }
+
+    fn propagated_changelog() -> Changelog {
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let mut changelog = Changelog::new(Some("@myorg/app"), "1.0.1", Some("1.0.0"), date);
+        changelog.dependencies.push(entry(Some("2.1.0"), Some("../core/CHANGELOG.md#210")));
+        changelog
+    }
+
+    #[test]
+    fn test_entry_markdown_variants() {
+        assert_eq!(
+            entry(Some("2.1.0"), Some("../core/CHANGELOG.md#210")).to_markdown(),
+            "- Updated `@myorg/core` to [2.1.0](../core/CHANGELOG.md#210) (`^2.0.0` → `^2.1.0`)"
+        );
+        assert_eq!(
+            entry(Some("2.1.0"), None).to_markdown(),
+            "- Updated `@myorg/core` to 2.1.0 (`^2.0.0` → `^2.1.0`)"
+        );
+        assert_eq!(
+            entry(None, None).to_markdown(),
+            "- Updated `@myorg/core` from `^2.0.0` to `^2.1.0`"
+        );
+    }
+
+    #[test]
+    fn test_dependency_only_changelog_is_not_empty() {
+        let changelog = propagated_changelog();
+
+        assert!(!changelog.is_empty());
+        assert_eq!(changelog.entry_count(), 0);
+    }
+
+    #[test]
+    fn test_to_markdown_renders_dependencies_section() {
+        let config = ChangelogConfig::default();
+        let markdown = propagated_changelog().to_markdown(&config);
+
+        assert!(markdown.contains("### Dependencies\n\n- Updated `@myorg/core` to [2.1.0]"));
+    }
+
+    #[test]
+    fn test_formatters_render_dependencies_section() {
+        let config = ChangelogConfig::default();
+        let changelog = propagated_changelog();
+
+        for output in [
+            KeepAChangelogFormatter::new(&config).format(&changelog),
+            ConventionalCommitsFormatter::new(&config).format(&changelog),
+            CustomTemplateFormatter::new(&config).format(&changelog),
+        ] {
+            assert!(output.contains("Dependencies"), "missing section in:\n{output}");
+            assert!(output.contains("`@myorg/core`"));
+        }
+    }
+
+    #[test]
+    fn test_no_dependencies_renders_no_section() {
+        let config = ChangelogConfig::default();
+        let changelog = Changelog::new(Some("@myorg/app"), "1.0.1", None, Utc::now());
+
+        assert!(changelog.dependencies_to_markdown(&config).is_empty());
+        assert!(!changelog.to_markdown(&config).contains("Dependencies"));
+    }
+
+    #[test]
+    fn test_heading_anchor() {
+        assert_eq!(heading_anchor("## [2.1.0] - 2024-01-15"), "210---2024-01-15");
+        assert_eq!(heading_anchor("## v1.0.0 (Beta_1)"), "v100-beta_1");
+    }
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            relative_path(Path::new("/repo/packages/app"), Path::new("/repo/packages/core")),
+            Some(PathBuf::from("../core"))
+        );
+        assert_eq!(
+            relative_path(Path::new("/repo/apps/web"), Path::new("/repo/libs/ui")),
+            Some(PathBuf::from("../../libs/ui"))
+        );
+        assert_eq!(
+            relative_path(Path::new("/repo/app"), Path::new("/repo/app")),
+            Some(PathBuf::new())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_dependency_entries_links_released_dependencies() {
+        let (temp_dir, repo) = create_test_repo();
+        let root = temp_dir.path().to_path_buf();
+        let generator = ChangelogGenerator::new(
+            root.clone(),
+            repo,
+            FileSystemManager::new(),
+            ChangelogConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let mut resolution = VersionResolution::new();
+        resolution.add_update(PackageUpdate::new(
+            "@myorg/core".to_string(),
+            root.join("packages/core"),
+            Version::parse("2.0.0").unwrap(),
+            Version::parse("2.1.0").unwrap(),
+            UpdateReason::DirectChange,
+        ));
+        let mut app = PackageUpdate::new(
+            "@myorg/app".to_string(),
+            root.join("packages/app"),
+            Version::parse("1.0.0").unwrap(),
+            Version::parse("1.0.1").unwrap(),
+            UpdateReason::DependencyPropagation {
+                triggered_by: "@myorg/core".to_string(),
+                depth: 1,
+            },
+        );
+        app.dependency_updates.push(DependencyUpdate::new(
+            "@myorg/core",
+            DependencyType::Regular,
+            "^2.0.0",
+            "^2.1.0",
+        ));
+        app.dependency_updates.push(DependencyUpdate::new(
+            "@myorg/external",
+            DependencyType::Dev,
+            "^1.0.0",
+            "^1.1.0",
+        ));
+        resolution.add_update(app.clone());
+
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let entries = generator.build_dependency_entries(&app, &resolution, date);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].version.as_deref(), Some("2.1.0"));
+        assert_eq!(entries[0].link.as_deref(), Some("../core/CHANGELOG.md#210---2024-01-15"));
+        assert_eq!(entries[1].version, None);
+        assert_eq!(entries[1].link, None);
+    }
+}
+
+// ============================================================================
+// Release Baseline Tests
+// ============================================================================
+
+mod release_baseline_tests {
+    use super::*;
+    use crate::changelog::{BaselineSource, ReleaseBaselineResolver};
+
+    #[test]
+    fn test_resolve_package_tag_prefers_newest() {
+        let (_temp_dir, repo) = create_test_repo();
+        repo.create_tag("@myorg/core@1.0.0", None).unwrap();
+        repo.commit_changes("feat: one").unwrap();
+        repo.create_tag("@myorg/core@1.1.0", None).unwrap();
+        repo.commit_changes("fix: two").unwrap();
+
+        let config = ChangelogConfig::default();
+        let resolver = ReleaseBaselineResolver::new(&repo, &config).unwrap();
+        let baseline = resolver.resolve(Some("@myorg/core")).unwrap();
+
+        assert_eq!(baseline.source(), BaselineSource::PackageTag);
+        assert_eq!(baseline.git_ref(), "@myorg/core@1.1.0");
+        assert_eq!(baseline.version().unwrap().to_string(), "1.1.0");
+        assert_eq!(baseline.package_name(), Some("@myorg/core"));
+        assert!(!baseline.is_first_release());
+
+        let commits: Vec<_> =
+            baseline.commits_since(&repo, &None).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message.trim(), "fix: two");
+    }
+
+    #[test]
+    fn test_resolve_falls_back_to_root_tag() {
+        let (_temp_dir, repo) = create_test_repo();
+        repo.create_tag("other@3.0.0", None).unwrap();
+        repo.commit_changes("feat: one").unwrap();
+        repo.create_tag("v2.0.0", None).unwrap();
+        repo.commit_changes("feat: two").unwrap();
+
+        let config = ChangelogConfig::default();
+        let resolver = ReleaseBaselineResolver::new(&repo, &config).unwrap();
+
+        let package = resolver.resolve(Some("@myorg/core")).unwrap();
+        assert_eq!(package.source(), BaselineSource::RootTag);
+        assert_eq!(package.git_ref(), "v2.0.0");
+        assert_eq!(package.package_name(), Some("@myorg/core"));
+
+        let root = resolver.resolve(None).unwrap();
+        assert_eq!(root.source(), BaselineSource::RootTag);
+        assert_eq!(root.commit(), package.commit());
+    }
+
+    #[test]
+    fn test_resolve_never_released_uses_first_commit() {
+        let (_temp_dir, repo) = create_test_repo();
+        repo.commit_changes("feat: one").unwrap();
+        repo.commit_changes("feat: two").unwrap();
+
+        let config = ChangelogConfig::default();
+        let resolver = ReleaseBaselineResolver::new(&repo, &config).unwrap();
+        let baseline = resolver.resolve(Some("@myorg/core")).unwrap();
+
+        assert_eq!(baseline.source(), BaselineSource::FirstCommit);
+        assert!(baseline.is_first_release());
+        assert!(baseline.tag().is_none());
+        assert_eq!(baseline.commit(), repo.get_first_sha().unwrap());
+        assert_eq!(baseline.git_ref(), baseline.commit());
+
+        // The whole history, including the first commit, belongs to the first release
+        let commits = baseline.commits_since(&repo, &None).unwrap();
+        assert_eq!(commits.count(), repo.get_commits_since(None, &None).unwrap().len());
+    }
+
+    #[test]
+    fn test_commits_since_filters_by_path() {
+        let (temp_dir, repo) = create_test_repo();
+        std::fs::create_dir_all(temp_dir.path().join("packages/core")).unwrap();
+        std::fs::write(temp_dir.path().join("packages/core/index.js"), "1").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("feat: core").unwrap();
+        repo.create_tag("@myorg/core@1.0.0", None).unwrap();
+        std::fs::write(temp_dir.path().join("packages/core/index.js"), "2").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("fix: core").unwrap();
+        std::fs::write(temp_dir.path().join("README.md"), "docs").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("docs: readme").unwrap();
+
+        let config = ChangelogConfig::default();
+        let resolver = ReleaseBaselineResolver::new(&repo, &config).unwrap();
+        let path = Some("packages/core".to_string());
+
+        let untagged = resolver.resolve(Some("@myorg/other")).unwrap();
+        assert_eq!(untagged.commits_since(&repo, &path).unwrap().count(), 2);
+        assert_eq!(untagged.commits_since(&repo, &None).unwrap().count(), 4);
+
+        let tagged = resolver.resolve(Some("@myorg/core")).unwrap();
+        let commits: Vec<_> =
+            tagged.commits_since(&repo, &path).unwrap().collect::<Result<_, _>>().unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].message.trim(), "fix: core");
+    }
+}
+
+// ============================================================================
+// Unreleased Section Tests
+// ============================================================================
+
+mod unreleased_tests {
+    use super::*;
+    use crate::changelog::UNRELEASED_HEADING;
+    use crate::changelog::unreleased::is_unreleased_heading;
+    use crate::types::{Changeset, VersionBump};
+    use std::path::Path;
+
+    async fn create_generator(temp_dir: &TempDir, repo: Repo) -> ChangelogGenerator {
+        ChangelogGenerator::new(
+            temp_dir.path().to_path_buf(),
+            repo,
+            FileSystemManager::new(),
+            ChangelogConfig::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    /// Creates an npm workspace with two packages and a commit touching each.
+    async fn setup_workspace(temp_dir: &Path, repo: &Repo) {
+        let fs = FileSystemManager::new();
+        for (dir, name) in [("pkg-a", "@myorg/pkg-a"), ("pkg-b", "@myorg/pkg-b")] {
+            fs.create_dir_all(&temp_dir.join("packages").join(dir)).await.unwrap();
+            fs.write_file_string(
+                &temp_dir.join("packages").join(dir).join("package.json"),
+                &format!(r#"{{ "name": "{name}", "version": "1.0.0" }}"#),
+            )
+            .await
+            .unwrap();
+        }
+        fs.write_file_string(
+            &temp_dir.join("package.json"),
+            r#"{ "name": "root", "private": true, "workspaces": ["packages/*"] }"#,
+        )
+        .await
+        .unwrap();
+        fs.write_file_string(&temp_dir.join("package-lock.json"), "{}").await.unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: initial commit").unwrap();
+
+        fs.write_file_string(&temp_dir.join("packages/pkg-a/index.js"), "a").await.unwrap();
+        repo.add_all().unwrap();
+        repo.commit("feat: add pkg-a entry point").unwrap();
+
+        fs.write_file_string(&temp_dir.join("packages/pkg-b/index.js"), "b").await.unwrap();
+        repo.add_all().unwrap();
+        repo.commit("fix: repair pkg-b").unwrap();
+    }
+
+    #[test]
+    fn test_is_unreleased_heading() {
+        assert!(is_unreleased_heading("## [Unreleased]"));
+        assert!(is_unreleased_heading("## Unreleased"));
+        assert!(is_unreleased_heading("## [unreleased] "));
+        assert!(!is_unreleased_heading("# [Unreleased]"));
+        assert!(!is_unreleased_heading("## [1.0.0] - 2024-01-01"));
+    }
+
+    #[tokio::test]
+    async fn test_replace_unreleased_inserts_above_latest_release() {
+        let (temp_dir, repo) = create_test_repo();
+        let generator = create_generator(&temp_dir, repo).await;
+        let existing = "# Changelog\n\n## [1.0.0] - 2024-01-01\n\n- Initial\n";
+
+        let updated =
+            generator.replace_unreleased(existing, Some("## [Unreleased]\n\n- Pending\n")).unwrap();
+
+        assert_eq!(
+            updated,
+            "# Changelog\n\n## [Unreleased]\n\n- Pending\n\n## [1.0.0] - 2024-01-01\n\n- Initial\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replace_unreleased_replaces_and_removes_section() {
+        let (temp_dir, repo) = create_test_repo();
+        let generator = create_generator(&temp_dir, repo).await;
+        let existing =
+            "# Changelog\n\n## [Unreleased]\n\n- Old\n\n## [1.0.0] - 2024-01-01\n\n- Initial\n";
+
+        let replaced =
+            generator.replace_unreleased(existing, Some("## [Unreleased]\n\n- New\n")).unwrap();
+        assert!(replaced.contains("- New"));
+        assert!(!replaced.contains("- Old"));
+        assert_eq!(replaced.matches(UNRELEASED_HEADING).count(), 1);
+
+        let removed = generator.replace_unreleased(existing, None).unwrap();
+        assert_eq!(removed, "# Changelog\n\n## [1.0.0] - 2024-01-01\n\n- Initial\n");
+
+        assert_eq!(generator.replace_unreleased(&removed, None), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_unreleased_follows_pending_changesets() {
+        let (temp_dir, repo) = create_test_repo();
+        setup_workspace(temp_dir.path(), &repo).await;
+        let generator = create_generator(&temp_dir, repo).await;
+        let pkg_a = temp_dir.path().join("packages/pkg-a/CHANGELOG.md");
+        let pkg_b = temp_dir.path().join("packages/pkg-b/CHANGELOG.md");
+
+        let mut changeset = Changeset::new("feature", VersionBump::Minor, vec![]);
+        changeset.add_package("@myorg/pkg-a");
+
+        let dry_run = generator.update_unreleased(&[changeset.clone()], true).await.unwrap();
+        assert_eq!(dry_run, vec![pkg_a.clone()]);
+        assert!(!pkg_a.exists());
+
+        let updated = generator.update_unreleased(&[changeset.clone()], false).await.unwrap();
+        assert_eq!(updated, vec![pkg_a.clone()]);
+        let content = std::fs::read_to_string(&pkg_a).unwrap();
+        assert!(content.contains(UNRELEASED_HEADING));
+        assert!(content.contains("add pkg-a entry point"));
+        assert!(!content.contains("repair pkg-b"));
+        assert!(!pkg_b.exists());
+
+        // Unchanged pending changes leave the file alone
+        assert!(generator.update_unreleased(&[changeset], false).await.unwrap().is_empty());
+
+        // Without pending changesets the section is rolled away
+        let updated = generator.update_unreleased(&[], false).await.unwrap();
+        assert_eq!(updated, vec![pkg_a.clone()]);
+        let content = std::fs::read_to_string(&pkg_a).unwrap();
+        assert!(!content.contains(UNRELEASED_HEADING));
+        assert!(!content.contains("add pkg-a entry point"));
+    }
+}
+
+// ============================================================================
+// Tag Backfill Tests
+// ============================================================================
+
+mod tag_backfill_tests {
+    use super::*;
+    use crate::changelog::{ReleaseTagBackfill, UnresolvedRelease};
+    use crate::types::{ArchivedChangeset, Changeset, PackageInfo, ReleaseInfo, VersionBump};
+    use package_json::PackageJson;
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    fn write_manifest(dir: &Path, name: &str, version: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(
+            dir.join("package.json"),
+            format!(r#"{{ "name": "{name}", "version": "{version}" }}"#),
+        )
+        .unwrap();
+    }
+
+    fn package(dir: &Path, name: &str) -> PackageInfo {
+        let package_json = PackageJson {
+            name: name.to_string(),
+            version: "0.0.0".to_string(),
+            ..Default::default()
+        };
+        PackageInfo::new(package_json, None, dir.to_path_buf())
+    }
+
+    fn archived(name: &str, versions: &[&str]) -> Vec<ArchivedChangeset> {
+        versions
+            .iter()
+            .map(|version| {
+                let mut changeset = Changeset::new("main", VersionBump::Minor, vec![]);
+                changeset.add_package(name);
+                let versions = HashMap::from([(name.to_string(), (*version).to_string())]);
+                ArchivedChangeset::new(changeset, ReleaseInfo::new("ci", "abc123", versions))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_backfill_plans_and_creates_missing_package_tags() {
+        let (temp_dir, repo) = create_test_repo();
+        let core = temp_dir.path().join("packages/core");
+        let utils = temp_dir.path().join("packages/utils");
+        write_manifest(&core, "@myorg/core", "1.0.0");
+        write_manifest(&utils, "@myorg/utils", "1.0.0");
+        repo.add_all().unwrap();
+        let initial = repo.commit("chore: initial commit").unwrap();
+        repo.create_tag("@myorg/utils@1.0.0", None).unwrap();
+
+        std::fs::write(core.join("index.js"), "core").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("feat: core entry point").unwrap();
+        write_manifest(&core, "@myorg/core", "1.1.0");
+        repo.add_all().unwrap();
+        let release = repo.commit("chore: release").unwrap();
+
+        let packages = vec![package(&core, "@myorg/core"), package(&utils, "@myorg/utils")];
+        let archived = archived("@myorg/core", &["1.1.0", "2.0.0"]);
+        let backfill = ReleaseTagBackfill::new(&repo, &ChangelogConfig::default());
+        let plan = backfill.plan(&packages, &archived).unwrap();
+
+        let missing: Vec<(&str, &str, bool)> = plan
+            .missing
+            .iter()
+            .map(|tag| (tag.tag_name.as_str(), tag.commit.as_str(), tag.recorded))
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                ("@myorg/core@1.0.0", initial.as_str(), false),
+                ("@myorg/core@1.1.0", release.as_str(), true),
+            ]
+        );
+        assert_eq!(plan.missing[0].package_name.as_deref(), Some("@myorg/core"));
+        assert_eq!(plan.existing, vec!["@myorg/utils@1.0.0".to_string()]);
+        assert_eq!(
+            plan.unresolved,
+            vec![UnresolvedRelease {
+                package_name: "@myorg/core".to_string(),
+                version: "2.0.0".to_string(),
+            }]
+        );
+
+        let created = backfill.apply(&plan).unwrap();
+        assert_eq!(created, vec!["@myorg/core@1.0.0", "@myorg/core@1.1.0"]);
+        let replanned = backfill.plan(&packages, &archived).unwrap();
+        assert!(replanned.missing.is_empty());
+        assert_eq!(replanned.existing.len(), 3);
+    }
+
+    #[test]
+    fn test_backfill_uses_root_tag_format_for_single_package() {
+        let (temp_dir, repo) = create_test_repo();
+        write_manifest(temp_dir.path(), "my-lib", "0.1.0");
+        repo.add_all().unwrap();
+        repo.commit("chore: initial commit").unwrap();
+        write_manifest(temp_dir.path(), "my-lib", "0.2.0");
+        repo.add_all().unwrap();
+        repo.commit("chore: release").unwrap();
+
+        let packages = vec![package(temp_dir.path(), "my-lib")];
+        let backfill = ReleaseTagBackfill::new(&repo, &ChangelogConfig::default());
+        let plan = backfill.plan(&packages, &[]).unwrap();
+
+        let tags: Vec<&str> = plan.missing.iter().map(|tag| tag.tag_name.as_str()).collect();
+        assert_eq!(tags, vec!["v0.1.0", "v0.2.0"]);
+        assert!(plan.missing.iter().all(|tag| tag.package_name.is_none()));
+    }
+
+    #[test]
+    fn test_backfill_uses_package_tag_format_for_scoped_single_package() {
+        let (temp_dir, repo) = create_test_repo();
+        write_manifest(temp_dir.path(), "@myorg/lib", "0.1.0");
+        repo.add_all().unwrap();
+        repo.commit("chore: initial commit").unwrap();
+        repo.create_tag("@myorg/lib@0.1.0", None).unwrap();
+        write_manifest(temp_dir.path(), "@myorg/lib", "0.2.0");
+        repo.add_all().unwrap();
+        repo.commit("chore: release").unwrap();
+
+        // Bump tags a scoped single package with the package format
+        let packages = vec![package(temp_dir.path(), "@myorg/lib")];
+        let backfill = ReleaseTagBackfill::new(&repo, &ChangelogConfig::default());
+        let plan = backfill.plan(&packages, &[]).unwrap();
+
+        assert_eq!(plan.existing, vec!["@myorg/lib@0.1.0".to_string()]);
+        let tags: Vec<&str> = plan.missing.iter().map(|tag| tag.tag_name.as_str()).collect();
+        assert_eq!(tags, vec!["@myorg/lib@0.2.0"]);
+        assert_eq!(plan.missing[0].package_name.as_deref(), Some("@myorg/lib"));
+    }
+}
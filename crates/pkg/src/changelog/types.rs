@@ -12,6 +12,7 @@
 
 use crate::changelog::SectionType;
 use crate::config::ChangelogConfig;
+use crate::line_endings::LineEnding;
 use crate::types::VersionBump;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -59,6 +60,14 @@ pub struct Changelog {
 
     /// Metadata about this changelog.
     pub metadata: ChangelogMetadata,
+
+    /// Internal dependency updates that caused or accompany this release.
+    ///
+    /// Populated from the dependency propagation data of the version resolution so that
+    /// packages bumped only because a workspace dependency changed still get a meaningful
+    /// entry. Rendered as a dedicated "Dependencies" section.
+    #[serde(default)]
+    pub dependencies: Vec<DependencyChangelogEntry>,
 }
 
 impl Changelog {
@@ -98,6 +107,7 @@ impl Changelog {
             date,
             sections: Vec::new(),
             metadata: ChangelogMetadata::default(),
+            dependencies: Vec::new(),
         }
     }
 
@@ -137,7 +147,7 @@ impl Changelog {
         let mut output = String::new();
 
         // Version header
-        let date_str = self.date.format("%Y-%m-%d").to_string();
+        let date_str = config.date.format_date(&self.date);
         let version_header = config
             .template
             .version_header
@@ -155,6 +165,91 @@ impl Changelog {
             }
         }
 
+        output.push_str(&self.dependencies_to_markdown(config));
+
+        output
+    }
+
+    /// Returns the anchor of this version's heading, as rendered by `to_markdown`.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Changelog configuration used to render the heading
+    ///
+    /// # Returns
+    ///
+    /// The anchor without the leading `#` (e.g., `"210---2024-01-15"`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changelog::Changelog;
+    /// use sublime_pkg_tools::config::ChangelogConfig;
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let date = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+    /// let changelog = Changelog::new(Some("my-package"), "2.1.0", None, date);
+    /// assert_eq!(changelog.anchor(&ChangelogConfig::default()), "210---2024-01-15");
+    /// ```
+    #[must_use]
+    pub fn anchor(&self, config: &ChangelogConfig) -> String {
+        let heading = config
+            .template
+            .version_header
+            .replace("{version}", &self.version)
+            .replace("{date}", &config.date.format_date(&self.date));
+        heading_anchor(&heading)
+    }
+
+    /// Renders the internal dependency updates as a "Dependencies" section.
+    ///
+    /// Returns an empty string when there are no dependency updates, so formatters can
+    /// append the result unconditionally.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Changelog configuration for formatting
+    ///
+    /// # Returns
+    ///
+    /// The markdown for the dependencies section, followed by a blank line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changelog::{Changelog, DependencyChangelogEntry};
+    /// use sublime_pkg_tools::config::ChangelogConfig;
+    /// use chrono::Utc;
+    ///
+    /// let mut changelog = Changelog::new(Some("@org/app"), "1.0.1", Some("1.0.0"), Utc::now());
+    /// changelog.dependencies.push(DependencyChangelogEntry {
+    ///     name: "@org/core".to_string(),
+    ///     old_version_spec: "^2.0.0".to_string(),
+    ///     new_version_spec: "^2.1.0".to_string(),
+    ///     version: Some("2.1.0".to_string()),
+    ///     link: None,
+    /// });
+    ///
+    /// let markdown = changelog.dependencies_to_markdown(&ChangelogConfig::default());
+    /// assert!(markdown.starts_with("### Dependencies"));
+    /// assert!(markdown.contains("`@org/core`"));
+    /// ```
+    #[must_use]
+    pub fn dependencies_to_markdown(&self, config: &ChangelogConfig) -> String {
+        if self.dependencies.is_empty() {
+            return String::new();
+        }
+
+        let mut output =
+            config.template.section_header.replace("{section}", DEPENDENCIES_SECTION_TITLE);
+        output.push_str("\n\n");
+
+        for dependency in &self.dependencies {
+            output.push_str(&dependency.to_markdown());
+            output.push('\n');
+        }
+
+        output.push_str("\n\n");
         output
     }
 
@@ -176,10 +271,11 @@ impl Changelog {
     ///
     /// # Returns
     ///
-    /// `true` if there are no entries in any section, `false` otherwise.
+    /// `true` if there are no entries in any section and no dependency updates,
+    /// `false` otherwise.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.sections.iter().all(|s| s.is_empty())
+        self.sections.iter().all(|s| s.is_empty()) && self.dependencies.is_empty()
     }
 
     /// Gets the total number of entries across all sections.
@@ -203,6 +299,101 @@ impl Changelog {
     }
 }
 
+/// Title of the section listing internal dependency updates.
+pub(crate) const DEPENDENCIES_SECTION_TITLE: &str = "Dependencies";
+
+/// An internal dependency update listed in a changelog.
+///
+/// Describes a workspace dependency whose version changed in the same release,
+/// optionally linking to the dependency's own changelog entry for that version.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changelog::DependencyChangelogEntry;
+///
+/// let entry = DependencyChangelogEntry {
+///     name: "@org/core".to_string(),
+///     old_version_spec: "^2.0.0".to_string(),
+///     new_version_spec: "^2.1.0".to_string(),
+///     version: Some("2.1.0".to_string()),
+///     link: Some("../core/CHANGELOG.md#210---2024-01-15".to_string()),
+/// };
+///
+/// assert_eq!(
+///     entry.to_markdown(),
+///     "- Updated `@org/core` to [2.1.0](../core/CHANGELOG.md#210---2024-01-15) (`^2.0.0` → `^2.1.0`)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyChangelogEntry {
+    /// Name of the internal dependency.
+    pub name: String,
+
+    /// Version spec before the update.
+    pub old_version_spec: String,
+
+    /// Version spec after the update.
+    pub new_version_spec: String,
+
+    /// Version the dependency is released at, when it is part of the same release.
+    pub version: Option<String>,
+
+    /// Relative link to the dependency's changelog entry for `version`.
+    pub link: Option<String>,
+}
+
+impl DependencyChangelogEntry {
+    /// Renders the entry as a markdown list item.
+    ///
+    /// # Returns
+    ///
+    /// A single markdown line without trailing newline.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        match (&self.version, &self.link) {
+            (Some(version), Some(link)) => format!(
+                "- Updated `{}` to [{}]({}) (`{}` → `{}`)",
+                self.name, version, link, self.old_version_spec, self.new_version_spec
+            ),
+            (Some(version), None) => format!(
+                "- Updated `{}` to {} (`{}` → `{}`)",
+                self.name, version, self.old_version_spec, self.new_version_spec
+            ),
+            _ => format!(
+                "- Updated `{}` from `{}` to `{}`",
+                self.name, self.old_version_spec, self.new_version_spec
+            ),
+        }
+    }
+}
+
+/// Converts a markdown heading into the anchor generated by GitHub-flavored renderers.
+///
+/// Leading `#` markers are dropped, the text is lowercased, punctuation other than `-`
+/// and `_` is removed, and spaces become `-`.
+///
+/// # Arguments
+///
+/// * `heading` - The heading line (e.g., `"## [2.1.0] - 2024-01-15"`)
+///
+/// # Returns
+///
+/// The anchor without the leading `#` (e.g., `"210---2024-01-15"`).
+pub(crate) fn heading_anchor(heading: &str) -> String {
+    heading
+        .trim_start_matches('#')
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Metadata about a changelog.
 ///
 /// Contains additional information about the changelog generation,
@@ -681,13 +872,15 @@ impl GeneratedChangelog {
                     }
                 })?;
 
-            format!("{}\n{}", self.content, existing_content)
+            // Keep the line endings of the existing file
+            LineEnding::detect(&existing_content)
+                .apply(&format!("{}\n{}", self.content, existing_content))
         } else {
             self.content.clone()
         };
 
-        // Write to file
-        fs.write_file_string(&self.changelog_path, &final_content).await.map_err(|e| {
+        // Replace the file atomically so an interrupted release never leaves it half written
+        fs.write_file_atomic(&self.changelog_path, &final_content).await.map_err(|e| {
             ChangelogError::FileSystemError {
                 path: self.changelog_path.clone(),
                 reason: e.as_ref().to_string(),
@@ -730,6 +923,7 @@ impl GeneratedChangelog {
             }
         })?;
 
-        Ok(format!("{}\n{}", self.content, existing_content))
+        Ok(LineEnding::detect(&existing_content)
+            .apply(&format!("{}\n{}", self.content, existing_content)))
     }
 }
@@ -0,0 +1,252 @@
+//! Unreleased section maintenance.
+//!
+//! **What**: Keeps an `## [Unreleased]` section at the top of each changelog, listing the
+//! changes queued by pending changesets.
+//!
+//! **How**: `ChangelogGenerator::update_unreleased` collects the commits since the last
+//! release of every package with a pending changeset, exactly as a release would, and
+//! renders them under the `## [Unreleased]` heading. An existing section is replaced in
+//! place; changelogs without pending changes lose theirs. Bumping calls it with no pending
+//! changesets, so the release section takes the place of the unreleased one.
+//!
+//! **Why**: Stakeholders get a live view of what the next release contains without running
+//! a release plan.
+
+use crate::changelog::{ChangelogGenerator, ChangelogSection, ReleaseBaselineResolver};
+use crate::error::{ChangelogError, ChangelogResult};
+use crate::line_endings::LineEnding;
+use crate::types::Changeset;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait};
+
+/// Heading of the section listing pending changes.
+pub const UNRELEASED_HEADING: &str = "## [Unreleased]";
+
+/// A changelog whose unreleased section is maintained.
+struct UnreleasedTarget {
+    /// Package owning the changelog, or `None` for the root changelog.
+    package_name: Option<String>,
+    /// Directory holding the changelog.
+    directory: PathBuf,
+    /// Package directory relative to the workspace root, used to filter commits.
+    relative_path: Option<String>,
+    /// Whether pending changesets affect the changelog.
+    pending: bool,
+}
+
+impl ChangelogGenerator {
+    /// Rewrites the `## [Unreleased]` sections from the pending changesets.
+    ///
+    /// Changelogs of packages in a pending changeset get a section with the commits since
+    /// the package's last release, following `monorepo_mode` like release changelogs.
+    /// Changelogs of other packages lose their section, so calling this with no changesets
+    /// removes every unreleased section.
+    ///
+    /// # Arguments
+    ///
+    /// * `changesets` - The pending changesets
+    /// * `dry_run` - If `true`, reports the changelogs that would change without writing them
+    ///
+    /// # Returns
+    ///
+    /// The paths of the changelogs whose content changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if commits cannot be collected or a changelog cannot be read or
+    /// written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::changelog::ChangelogGenerator;
+    ///
+    /// # async fn example(generator: ChangelogGenerator, pending: Vec<sublime_pkg_tools::types::Changeset>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let updated = generator.update_unreleased(&pending, false).await?;
+    /// println!("Updated {} changelog(s)", updated.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_unreleased(
+        &self,
+        changesets: &[Changeset],
+        dry_run: bool,
+    ) -> ChangelogResult<Vec<PathBuf>> {
+        let pending: BTreeSet<&str> = changesets
+            .iter()
+            .flat_map(|changeset| changeset.packages.iter().map(String::as_str))
+            .collect();
+        // Tags are loaded once; an empty repository has no baselines at all.
+        let baselines = ReleaseBaselineResolver::new(self.git_repo(), self.config()).ok();
+
+        let mut updated = Vec::new();
+        for target in self.unreleased_targets(&pending).await {
+            let package_name = target.package_name.as_deref();
+            let changelog_path = target.directory.join(self.changelog_filename(package_name));
+            let exists = self.fs().exists(&changelog_path).await;
+            if !exists && (!target.pending || !self.config().creates_missing(package_name)) {
+                continue;
+            }
+
+            let existing = if exists {
+                self.fs().read_file_string(&changelog_path).await.map_err(|e| {
+                    ChangelogError::FileSystemError {
+                        path: changelog_path.clone(),
+                        reason: e.as_ref().to_string(),
+                    }
+                })?
+            } else {
+                self.config().template.header.clone()
+            };
+
+            let section = if target.pending {
+                let (sections, _, _) = self.collect_since_baseline(
+                    baselines.as_ref(),
+                    package_name,
+                    &target.relative_path,
+                )?;
+                Some(self.render_unreleased(&sections))
+            } else {
+                None
+            };
+
+            let Some(content) = self.replace_unreleased(&existing, section.as_deref()) else {
+                continue;
+            };
+            let content = LineEnding::detect(&existing).apply(&content);
+            if exists && content == existing {
+                continue;
+            }
+
+            if !dry_run {
+                if let Some(parent) = changelog_path.parent() {
+                    self.fs().create_dir_all(parent).await.map_err(|e| {
+                        ChangelogError::FileSystemError {
+                            path: parent.to_path_buf(),
+                            reason: e.as_ref().to_string(),
+                        }
+                    })?;
+                }
+                self.fs().write_file_atomic(&changelog_path, &content).await.map_err(|e| {
+                    ChangelogError::UpdateFailed {
+                        path: changelog_path.clone(),
+                        reason: e.as_ref().to_string(),
+                    }
+                })?;
+            }
+            updated.push(changelog_path);
+        }
+
+        Ok(updated)
+    }
+
+    /// Lists the changelogs whose unreleased section is maintained.
+    ///
+    /// Every workspace package is listed, pending or not, so stale sections are removed.
+    async fn unreleased_targets(&self, pending: &BTreeSet<&str>) -> Vec<UnreleasedTarget> {
+        use crate::config::MonorepoMode;
+
+        let detector = MonorepoDetector::with_filesystem(self.fs().clone());
+        let monorepo = detector.detect_monorepo(self.workspace_root()).await.ok();
+
+        let mut targets = Vec::new();
+        if let Some(monorepo) = &monorepo
+            && self.config().monorepo_mode != MonorepoMode::Root
+        {
+            targets.extend(monorepo.packages().iter().map(|package| {
+                UnreleasedTarget {
+                    package_name: Some(package.name.clone()),
+                    directory: package.absolute_path.clone(),
+                    relative_path: package
+                        .absolute_path
+                        .strip_prefix(self.workspace_root())
+                        .ok()
+                        .and_then(|path| path.to_str())
+                        .map(String::from),
+                    pending: pending.contains(package.name.as_str()),
+                }
+            }));
+        }
+        if monorepo.is_none() || self.config().monorepo_mode != MonorepoMode::PerPackage {
+            targets.push(UnreleasedTarget {
+                package_name: None,
+                directory: self.workspace_root().clone(),
+                relative_path: None,
+                pending: !pending.is_empty(),
+            });
+        }
+        targets
+    }
+
+    /// Renders the unreleased section from the collected changelog sections.
+    fn render_unreleased(&self, sections: &[ChangelogSection]) -> String {
+        let mut output = format!("{UNRELEASED_HEADING}\n\n");
+        for section in sections.iter().filter(|section| !section.is_empty()) {
+            output.push_str(&section.to_markdown(self.config()));
+            output.push('\n');
+        }
+        output
+    }
+
+    /// Replaces the unreleased section of a changelog, or removes it when `section` is `None`.
+    ///
+    /// A new section is inserted above the latest release. Returns `None` when there is
+    /// nothing to remove.
+    pub(crate) fn replace_unreleased(
+        &self,
+        existing: &str,
+        section: Option<&str>,
+    ) -> Option<String> {
+        let lines: Vec<&str> = existing.lines().collect();
+        let start = lines.iter().position(|line| is_unreleased_heading(line));
+
+        let without = match start {
+            Some(start) => {
+                let end = lines[start + 1..]
+                    .iter()
+                    .position(|line| line.starts_with("## "))
+                    .map_or(lines.len(), |offset| start + 1 + offset);
+                let mut kept: Vec<&str> = lines[..start].to_vec();
+                kept.extend_from_slice(&lines[end..]);
+                let mut content = kept.join("\n");
+                content.push('\n');
+                content
+            }
+            None if section.is_none() => return None,
+            None => existing.to_string(),
+        };
+
+        let Some(section) = section else {
+            return Some(without);
+        };
+
+        // The section goes right after the title, above the latest release
+        let lines: Vec<&str> = without.lines().collect();
+        let insert = lines.iter().position(|line| line.starts_with("## ")).unwrap_or(lines.len());
+        let header = lines[..insert].join("\n");
+        let releases = lines[insert..].join("\n");
+
+        let mut content = String::new();
+        if !header.trim().is_empty() {
+            content.push_str(header.trim_end());
+            content.push_str("\n\n");
+        }
+        content.push_str(section.trim_end());
+        content.push('\n');
+        if !releases.is_empty() {
+            content.push('\n');
+            content.push_str(&releases);
+            content.push('\n');
+        }
+        Some(content)
+    }
+}
+
+/// Returns whether a line is an unreleased heading (`## [Unreleased]` or `## Unreleased`).
+pub(crate) fn is_unreleased_heading(line: &str) -> bool {
+    line.strip_prefix("## ")
+        .map(|title| title.trim().trim_start_matches('[').trim_end_matches(']'))
+        .is_some_and(|title| title.eq_ignore_ascii_case("unreleased"))
+}
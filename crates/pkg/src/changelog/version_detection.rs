@@ -220,7 +220,9 @@ impl Ord for VersionTag {
 /// # Arguments
 ///
 /// * `tag` - The Git tag string to parse
-/// * `expected_package` - The expected package name for monorepo tags, or None for root tags
+/// * `expected_package` - The expected package name for monorepo tags, or None for root tags.
+///   A format without `{name}`, such as a package tagged `v{version}`, cannot name the
+///   package, so any tag of that format is accepted
 /// * `format` - The tag format template with placeholders
 ///
 /// # Returns
@@ -269,8 +271,9 @@ pub(crate) fn parse_version_tag(
         None
     };
 
-    // Verify package matches if expected
+    // Verify package matches if expected and named by the format
     if let Some(expected) = expected_package
+        && format.contains("{name}")
         && package_name.as_deref() != Some(expected)
     {
         return None;
@@ -480,7 +483,6 @@ pub(crate) fn is_monorepo_format(format: &str) -> bool {
 /// assert_eq!(tag, "v1.0.0");
 /// ```
 #[must_use]
-#[allow(dead_code)]
 pub(crate) fn format_version_tag(
     package_name: Option<&str>,
     version: &str,
@@ -11,7 +11,10 @@
 //! complex monorepo structures, enabling accurate detection of which packages are affected by
 //! changes.
 
-use crate::changes::PackageMapper;
+use crate::changes::mapping::{canonical, relative_to};
+use crate::changes::workspace_diff::{PackageManifest, manifests_at_ref};
+use crate::changes::{ChangesQuery, PackageMapper};
+use crate::changes::{WorkspaceDiff, WorkspaceState};
 use crate::config::PackageToolsConfig;
 use crate::error::{ChangesError, ChangesResult};
 
@@ -300,22 +303,11 @@ where
             });
         }
 
-        // Check if it's a directory by checking metadata
-        match fs.metadata(workspace_root).await {
-            Ok(metadata) => {
-                if !metadata.is_dir() {
-                    return Err(ChangesError::InvalidWorkspaceRoot {
-                        path: workspace_root.to_path_buf(),
-                        reason: "Path is not a directory".to_string(),
-                    });
-                }
-            }
-            Err(e) => {
-                return Err(ChangesError::InvalidWorkspaceRoot {
-                    path: workspace_root.to_path_buf(),
-                    reason: format!("Cannot read metadata: {}", e),
-                });
-            }
+        if !fs.is_dir(workspace_root).await {
+            return Err(ChangesError::InvalidWorkspaceRoot {
+                path: workspace_root.to_path_buf(),
+                reason: "Path is not a directory".to_string(),
+            });
         }
 
         Ok(())
@@ -571,53 +563,52 @@ where
         // Map files to packages (not used directly but ensures cache is populated)
         let _files_by_package = package_mapper.map_files_to_packages(&changed_paths).await?;
 
+        // Get all packages to attribute files and include those without changes
+        let all_packages = self.get_all_packages().await?;
+
         // Build file changes grouped by package
         let mut package_file_changes: HashMap<String, Vec<FileChange>> = HashMap::new();
+        let mut unattributed_files = Vec::new();
 
         for git_file in &status {
             let file_path = PathBuf::from(&git_file.path);
-
-            // Find which package this file belongs to
-            if let Some(package_name) = package_mapper.find_package_for_file(&file_path).await? {
-                // Get the package info to calculate relative path
-                let all_pkgs = self.get_all_packages().await?;
-                let package_info = all_pkgs.iter().find(|p| p.name == package_name);
-
-                let package_relative_path = if let Some(pkg) = package_info {
-                    file_path.strip_prefix(&pkg.location).unwrap_or(&file_path).to_path_buf()
-                } else {
-                    file_path.clone()
-                };
-
-                // Create FileChange
-                let change_type = FileChangeType::from_git_status(&git_file.status);
-                let mut file_change =
-                    FileChange::new(file_path.clone(), package_relative_path, change_type);
-
-                // Calculate lines added/deleted using git diff for working directory changes
-                // Only attempt to get diff stats for files that aren't deleted
-                // If diff stats fail (e.g., binary file, permission issues), leave as None
-                if !matches!(change_type, FileChangeType::Deleted) {
-                    match self.git_repo.get_file_diff_stats(git_file.path.as_str()) {
-                        Ok(diff_stats) => {
-                            file_change.lines_added = Some(diff_stats.lines_added);
-                            file_change.lines_deleted = Some(diff_stats.lines_deleted);
-                        }
-                        Err(_) => {
-                            // Diff stats not available (binary file, permission issues, etc.)
-                        }
+            let change_type = FileChangeType::from_git_file(git_file);
+            let mut file_change =
+                FileChange::new(file_path.clone(), file_path.clone(), change_type);
+            file_change.class = self.config.changes.file_patterns.classify(&file_path);
+
+            // Calculate lines added/deleted using git diff for working directory changes
+            // Only attempt to get diff stats for files that aren't deleted, submodules, or
+            // LFS files, whose diff is a commit or pointer rather than their content
+            // If diff stats fail (e.g., binary file, permission issues), leave as None
+            if !matches!(change_type, FileChangeType::Deleted | FileChangeType::Submodule)
+                && !git_file.is_lfs_pointer()
+            {
+                match self.git_repo.get_file_diff_stats(git_file.path.as_str()) {
+                    Ok(diff_stats) => {
+                        file_change.lines_added = Some(diff_stats.lines_added);
+                        file_change.lines_deleted = Some(diff_stats.lines_deleted);
+                    }
+                    Err(_) => {
+                        // Diff stats not available (binary file, permission issues, etc.)
                     }
                 }
-
-                package_file_changes.entry(package_name).or_default().push(file_change);
             }
-        }
 
-        // Get all packages to include those without changes
-        let all_packages = self.get_all_packages().await?;
+            self.attribute_file_change(
+                &mut package_mapper,
+                file_change,
+                &all_packages,
+                &mut package_file_changes,
+                &mut unattributed_files,
+            )
+            .await?;
+        }
 
         // Create ChangesReport
         let mut report = ChangesReport::new(AnalysisMode::WorkingDirectory, self.is_monorepo());
+        report.unattributed_files = unattributed_files;
+        report.sync_unattributed_warnings();
 
         for package_info in all_packages {
             let mut package_changes = PackageChanges::new(package_info.clone());
@@ -749,39 +740,33 @@ where
         let mut package_mapper =
             PackageMapper::with_filesystem(self.workspace_root.clone(), self.fs.clone());
 
-        // Map files to packages
-        let files_by_package = package_mapper.map_files_to_packages(&changed_paths).await?;
+        // Map files to packages (ensures the mapper caches are populated)
+        let _files_by_package = package_mapper.map_files_to_packages(&changed_paths).await?;
+
+        // Get all packages to attribute files and include those without changes
+        let all_packages = self.get_all_packages().await?;
 
         // Build file changes grouped by package with detailed info
         let mut package_file_changes: HashMap<String, Vec<FileChange>> = HashMap::new();
+        let mut unattributed_files = Vec::new();
 
         for git_file in &changed_files {
             let file_path = PathBuf::from(&git_file.path);
-
-            // Find which package this file belongs to
-            if let Some(package_name) = package_mapper.find_package_for_file(&file_path).await? {
-                // Get the package info to calculate relative path
-                let all_pkgs = self.get_all_packages().await?;
-                let package_info = all_pkgs.iter().find(|p| p.name == package_name);
-
-                let package_relative_path = if let Some(pkg) = package_info {
-                    file_path.strip_prefix(&pkg.location).unwrap_or(&file_path).to_path_buf()
-                } else {
-                    file_path.clone()
-                };
-
-                // Create FileChange
-                let change_type = FileChangeType::from_git_status(&git_file.status);
-                let mut file_change =
-                    FileChange::new(file_path.clone(), package_relative_path, change_type);
-
-                // Note: Line statistics would require diff analysis which is not in scope for this story
-                // They will remain None for commit range analysis
-                file_change.lines_added = None;
-                file_change.lines_deleted = None;
-
-                package_file_changes.entry(package_name).or_default().push(file_change);
-            }
+            let change_type = FileChangeType::from_git_file(git_file);
+
+            // Note: Line statistics would require diff analysis which is not in scope for this story
+            // They will remain None for commit range analysis
+            let mut file_change = FileChange::new(file_path.clone(), file_path, change_type);
+            file_change.class = self.config.changes.file_patterns.classify(&file_change.path);
+
+            self.attribute_file_change(
+                &mut package_mapper,
+                file_change,
+                &all_packages,
+                &mut package_file_changes,
+                &mut unattributed_files,
+            )
+            .await?;
         }
 
         // For each commit, determine which packages it affects based on the files
@@ -794,7 +779,7 @@ where
             // Since we don't have a way to get files per commit easily,
             // we'll associate all commits with all packages that have changes.
             // This is conservative but correct for the commit range.
-            for package_name in files_by_package.keys() {
+            for package_name in package_file_changes.keys() {
                 commits_by_package
                     .entry(package_name.clone())
                     .or_default()
@@ -845,11 +830,10 @@ where
             }
         }
 
-        // Get all packages to include those without changes
-        let all_packages = self.get_all_packages().await?;
-
         // Create ChangesReport
         let mut report = ChangesReport::new_for_range(from_ref, to_ref, self.is_monorepo());
+        report.unattributed_files = unattributed_files;
+        report.sync_unattributed_warnings();
 
         for package_info in all_packages {
             let mut package_changes = PackageChanges::new(package_info.clone());
@@ -879,6 +863,210 @@ where
         Ok(report)
     }
 
+    /// Analyzes changes made since each package was last released.
+    ///
+    /// Each package's baseline is resolved from Git tags with
+    /// [`ReleaseBaselineResolver`](crate::changelog::ReleaseBaselineResolver): its newest
+    /// `{name}@{version}` tag, then the newest unified `v{version}` tag, then the first commit
+    /// of the repository for packages that were never released. Packages sharing a baseline are
+    /// analyzed together with [`analyze_commit_range`](Self::analyze_commit_range).
+    /// For a package that was never released the first commit is the starting point, so
+    /// only changes made after it are reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `to_ref` - Ending Git reference (commit, branch, tag)
+    ///
+    /// # Returns
+    ///
+    /// A `ChangesReport` covering every workspace package. The report's `base_ref` is the
+    /// shared baseline when all packages have the same one, and `"last-release"` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Tags cannot be read or no baseline can be resolved (e.g., a repository without commits)
+    /// - Commit range analysis fails for a reason other than an empty range
+    /// - Package information cannot be loaded
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let report = analyzer.analyze_since_last_release("HEAD").await?;
+    ///
+    /// for package in report.packages_with_changes() {
+    ///     println!("{} changed since its last release", package.package_name());
+    /// }
+    /// ```
+    pub async fn analyze_since_last_release(
+        &self,
+        to_ref: &str,
+    ) -> ChangesResult<crate::changes::ChangesReport> {
+        use crate::changelog::ReleaseBaselineResolver;
+        use crate::changes::{ChangesReport, PackageChanges};
+        use std::collections::{BTreeMap, HashMap};
+
+        let baseline_error = |e: crate::error::ChangelogError| ChangesError::GitError {
+            operation: "resolve_release_baseline".to_string(),
+            reason: e.to_string(),
+        };
+
+        let resolver = ReleaseBaselineResolver::new(&self.git_repo, &self.config.changelog)
+            .map_err(baseline_error)?;
+        let all_packages = self.get_all_packages().await?;
+
+        // Group packages by baseline commit so each distinct range is analyzed once
+        let mut packages_by_baseline: BTreeMap<String, (String, Vec<String>)> = BTreeMap::new();
+        for package in &all_packages {
+            let baseline = resolver.resolve(Some(&package.name)).map_err(baseline_error)?;
+            packages_by_baseline
+                .entry(baseline.commit().to_string())
+                .or_insert_with(|| (baseline.git_ref().to_string(), Vec::new()))
+                .1
+                .push(package.name.clone());
+        }
+
+        let mut changes_by_package: HashMap<String, PackageChanges> = HashMap::new();
+        let mut unattributed_files: Vec<crate::changes::FileChange> = Vec::new();
+        for (commit, (_, names)) in &packages_by_baseline {
+            let range_report = match self.analyze_commit_range(commit, to_ref).await {
+                Ok(report) => report,
+                // Nothing happened since this baseline: its packages are unchanged
+                Err(
+                    ChangesError::InvalidCommitRange { .. }
+                    | ChangesError::NoChangesDetected { .. },
+                ) => continue,
+                Err(e) => return Err(e),
+            };
+
+            for name in names {
+                if let Some(changes) = range_report.get_package(name) {
+                    changes_by_package.insert(name.clone(), changes.clone());
+                }
+            }
+
+            for file in range_report.unattributed_files {
+                if !unattributed_files.iter().any(|existing| existing.path == file.path) {
+                    unattributed_files.push(file);
+                }
+            }
+        }
+
+        let base_ref = match packages_by_baseline.values().next() {
+            Some((git_ref, _)) if packages_by_baseline.len() == 1 => git_ref.clone(),
+            _ => "last-release".to_string(),
+        };
+        let mut report = ChangesReport::new_for_range(&base_ref, to_ref, self.is_monorepo());
+        report.unattributed_files = unattributed_files;
+        report.sync_unattributed_warnings();
+
+        for package_info in all_packages {
+            let package_changes =
+                changes_by_package.remove(&package_info.name).unwrap_or_else(|| {
+                    let mut unchanged = PackageChanges::new(package_info.clone());
+                    if let Ok(version) = crate::types::Version::parse(&package_info.version) {
+                        unchanged.current_version = Some(version);
+                    }
+                    unchanged
+                });
+            report.add_package(package_changes);
+        }
+
+        Ok(report)
+    }
+
+    /// Analyzes the changes described by a query.
+    ///
+    /// With a starting ref the range is analyzed with
+    /// [`analyze_commit_range`](Self::analyze_commit_range); without one, each package starts
+    /// at its last release as in [`analyze_since_last_release`](Self::analyze_since_last_release).
+    /// When `include_uncommitted` is set, working directory changes are merged into the
+    /// report, so an empty range is not an error. Path filters are applied last: files outside
+    /// them are dropped, along with commits that no longer touch a reported file.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The range, path filters, and uncommitted-changes flag
+    ///
+    /// # Returns
+    ///
+    /// A `ChangesReport` covering every workspace package, with per-package statistics and
+    /// file lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Git references cannot be resolved
+    /// - The range is empty and uncommitted changes are not included
+    /// - Git status cannot be read when uncommitted changes are included
+    /// - Package information cannot be loaded
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::changes::ChangesQuery;
+    ///
+    /// let query = ChangesQuery::new()
+    ///     .from_ref("v1.0.0")
+    ///     .to_ref("main")
+    ///     .with_path("packages/core")
+    ///     .include_uncommitted(true);
+    /// let report = analyzer.analyze(&query).await?;
+    ///
+    /// for package in report.packages_with_changes() {
+    ///     println!("{}: {} files", package.package_name(), package.files.len());
+    /// }
+    /// ```
+    pub async fn analyze(
+        &self,
+        query: &ChangesQuery,
+    ) -> ChangesResult<crate::changes::ChangesReport> {
+        let to_ref = query.to();
+        let mut report = match &query.from_ref {
+            Some(from_ref) => match self.analyze_commit_range(from_ref, to_ref).await {
+                Ok(report) => report,
+                // Uncommitted work is still reported when nothing was committed in the range
+                Err(
+                    ChangesError::InvalidCommitRange { .. }
+                    | ChangesError::NoChangesDetected { .. },
+                ) if query.include_uncommitted => self.unchanged_report(from_ref, to_ref).await?,
+                Err(e) => return Err(e),
+            },
+            None => Box::pin(self.analyze_since_last_release(to_ref)).await?,
+        };
+
+        if query.include_uncommitted {
+            let uncommitted = self.analyze_working_directory().await?;
+            merge_uncommitted(&mut report, uncommitted);
+        }
+
+        if !query.paths.is_empty() {
+            report.retain_files(|file| query.matches_path(&file.path));
+        }
+
+        Ok(report)
+    }
+
+    /// Builds a range report in which every workspace package is unchanged.
+    async fn unchanged_report(
+        &self,
+        from_ref: &str,
+        to_ref: &str,
+    ) -> ChangesResult<crate::changes::ChangesReport> {
+        use crate::changes::{ChangesReport, PackageChanges};
+
+        let mut report = ChangesReport::new_for_range(from_ref, to_ref, self.is_monorepo());
+        for package_info in self.get_all_packages().await? {
+            let mut unchanged = PackageChanges::new(package_info.clone());
+            if let Ok(version) = crate::types::Version::parse(&package_info.version) {
+                unchanged.current_version = Some(version);
+            }
+            report.add_package(unchanged);
+        }
+
+        Ok(report)
+    }
+
     /// Analyzes changes with version preview calculation.
     ///
     /// This method performs commit range analysis and enhances the report with next version
@@ -971,6 +1159,105 @@ where
         Ok(report)
     }
 
+    /// Compares the packages of two workspace states.
+    ///
+    /// Each state is either a Git reference or the working directory. The diff reports
+    /// packages added and removed, version changes, and dependency additions, removals and
+    /// spec changes for every package present in both states.
+    ///
+    /// At a Git reference packages are discovered from the workspace patterns declared in the
+    /// root `package.json` at that reference (plus the configured workspace patterns), so
+    /// packages that were later moved or deleted are still found.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The state to compare from
+    /// * `to` - The state to compare to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A Git reference cannot be resolved or read
+    /// - A `package.json` cannot be read or parsed
+    /// - Packages cannot be detected in the working directory
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::changes::{ChangesAnalyzer, WorkspaceState};
+    ///
+    /// # async fn example(analyzer: &ChangesAnalyzer) -> Result<(), Box<dyn std::error::Error>> {
+    /// let diff = analyzer
+    ///     .diff_workspace(&WorkspaceState::git_ref("v1.0.0"), &WorkspaceState::git_ref("HEAD"))
+    ///     .await?;
+    ///
+    /// for removed in &diff.removed_packages {
+    ///     println!("- {}", removed.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn diff_workspace(
+        &self,
+        from: &WorkspaceState,
+        to: &WorkspaceState,
+    ) -> ChangesResult<WorkspaceDiff> {
+        let from_packages = self.load_manifests(from).await?;
+        let to_packages = self.load_manifests(to).await?;
+
+        Ok(WorkspaceDiff::compare(from.clone(), to.clone(), from_packages, to_packages))
+    }
+
+    /// Loads the package manifests of a workspace state.
+    async fn load_manifests(&self, state: &WorkspaceState) -> ChangesResult<Vec<PackageManifest>> {
+        match state {
+            WorkspaceState::GitRef(git_ref) => {
+                let repo_root = self.git_repo.get_repo_path();
+                let prefix = relative_to(&self.workspace_root, repo_root)
+                    .or_else(|| {
+                        relative_to(&canonical(&self.workspace_root), &canonical(repo_root))
+                    })
+                    .map(|relative| {
+                        relative
+                            .components()
+                            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                            .collect::<Vec<_>>()
+                            .join("/")
+                    })
+                    .unwrap_or_default();
+
+                manifests_at_ref(
+                    &self.git_repo,
+                    git_ref,
+                    &prefix,
+                    &self.config.get_standard_config().monorepo.workspace_patterns,
+                )
+            }
+            WorkspaceState::WorkingDirectory => {
+                let mut manifests = Vec::new();
+                for package in self.get_all_packages().await? {
+                    let package_json_path = package.absolute_path.join("package.json");
+                    let content =
+                        self.fs.read_file_string(&package_json_path).await.map_err(|e| {
+                            ChangesError::FileSystemError {
+                                path: package_json_path.clone(),
+                                reason: format!("Failed to read package.json: {}", e),
+                            }
+                        })?;
+                    if let Some(mut manifest) = PackageManifest::parse(
+                        &content,
+                        package.location.clone(),
+                        Some(&package.name),
+                    )? {
+                        manifest.name = package.name;
+                        manifests.push(manifest);
+                    }
+                }
+                Ok(manifests)
+            }
+        }
+    }
+
     /// Calculates the next version for a package based on the bump type.
     ///
     /// This is a helper method that applies the version bump to a current version.
@@ -1106,6 +1393,81 @@ where
         Ok(())
     }
 
+    /// Attributes a changed file to the packages it affects.
+    ///
+    /// A file inside a package belongs to it unless the package's ignore rules exclude it.
+    /// A file outside every package follows the first matching `changes.root_files` rule:
+    /// it is dropped, added to every package, or added to the listed packages. Files no
+    /// rule matches are collected in `unattributed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapper` - Package mapper used to find the owning package
+    /// * `file_change` - The change, with `package_relative_path` equal to `path`
+    /// * `all_packages` - All workspace packages
+    /// * `package_file_changes` - File changes grouped by package name
+    /// * `unattributed` - Changes attributed to no package by any rule
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if package mapping or loading ignore rules fails.
+    async fn attribute_file_change(
+        &self,
+        mapper: &mut PackageMapper<F>,
+        file_change: crate::changes::FileChange,
+        all_packages: &[WorkspacePackage],
+        package_file_changes: &mut std::collections::HashMap<
+            String,
+            Vec<crate::changes::FileChange>,
+        >,
+        unattributed: &mut Vec<crate::changes::FileChange>,
+    ) -> ChangesResult<()> {
+        use crate::config::RootFileAttribution;
+
+        let file_path = file_change.path.clone();
+        let package_names = if let Some(package_name) =
+            mapper.find_package_for_file(&file_path).await?
+        {
+            if mapper.is_ignored(&package_name, &file_path).await? {
+                return Ok(());
+            }
+            vec![package_name]
+        } else {
+            match self.config.changes.rule_for(&file_path) {
+                None => {
+                    unattributed.push(file_change);
+                    return Ok(());
+                }
+                Some(rule) => match rule.attribution {
+                    RootFileAttribution::Ignore => return Ok(()),
+                    RootFileAttribution::All => {
+                        all_packages.iter().map(|package| package.name.clone()).collect()
+                    }
+                    RootFileAttribution::Packages => rule
+                        .packages
+                        .iter()
+                        .filter(|name| all_packages.iter().any(|package| &package.name == *name))
+                        .cloned()
+                        .collect(),
+                },
+            }
+        };
+
+        for package_name in package_names {
+            let mut change = file_change.clone();
+            if let Some(relative) = all_packages
+                .iter()
+                .find(|package| package.name == package_name)
+                .and_then(|package| relative_to(&file_path, &package.location))
+            {
+                change.package_relative_path = relative;
+            }
+            package_file_changes.entry(package_name).or_default().push(change);
+        }
+
+        Ok(())
+    }
+
     /// Gets all packages in the workspace.
     ///
     /// Returns package information for all packages, regardless of whether they
@@ -1166,6 +1528,52 @@ where
     }
 }
 
+/// Merges working directory changes into a range report.
+///
+/// Files already changed in the range keep their commits; their line statistics are filled from
+/// the working directory when the range had none, and they become deletions when the file was
+/// deleted since. Other files are added to their package.
+fn merge_uncommitted(
+    report: &mut crate::changes::ChangesReport,
+    uncommitted: crate::changes::ChangesReport,
+) {
+    use crate::changes::FileChangeType;
+
+    for file in uncommitted.unattributed_files {
+        if !report.unattributed_files.iter().any(|existing| existing.path == file.path) {
+            report.unattributed_files.push(file);
+        }
+    }
+    report.sync_unattributed_warnings();
+
+    for changes in uncommitted.packages {
+        let Some(package) =
+            report.packages.iter_mut().find(|p| p.package_name == changes.package_name)
+        else {
+            continue;
+        };
+        if changes.files.is_empty() {
+            continue;
+        }
+
+        for file in changes.files {
+            match package.files.iter_mut().find(|existing| existing.path == file.path) {
+                Some(existing) => {
+                    if file.change_type == FileChangeType::Deleted {
+                        existing.change_type = FileChangeType::Deleted;
+                    }
+                    existing.lines_added = existing.lines_added.or(file.lines_added);
+                    existing.lines_deleted = existing.lines_deleted.or(file.lines_deleted);
+                }
+                None => package.files.push(file),
+            }
+        }
+        package.recalculate_stats();
+    }
+
+    report.recalculate_summary();
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -1,7 +1,8 @@
 //! File change data structures for tracking individual file modifications.
 //!
 //! **What**: Provides types for representing individual file changes, including the type
-//! of change (added, modified, deleted, renamed, copied), file paths, and associated statistics.
+//! of change (added, modified, deleted, renamed, copied, submodule), file paths, and associated
+//! statistics.
 //!
 //! **How**: Defines serializable structures that map Git file status changes to domain types,
 //! tracking both absolute and package-relative paths, change types, line statistics, and
@@ -16,7 +17,7 @@
 //! ## Creating a file change
 //!
 //! ```rust
-//! use sublime_pkg_tools::changes::{FileChange, FileChangeType};
+//! use sublime_pkg_tools::changes::{FileChange, FileChangeType, FileClass};
 //! use std::path::PathBuf;
 //!
 //! let change = FileChange {
@@ -26,6 +27,7 @@
 //!     lines_added: Some(15),
 //!     lines_deleted: Some(3),
 //!     commits: vec!["abc123".to_string()],
+//!     class: FileClass::Source,
 //! };
 //!
 //! assert_eq!(change.change_type, FileChangeType::Modified);
@@ -35,7 +37,7 @@
 //! ## Filtering by change type
 //!
 //! ```rust
-//! use sublime_pkg_tools::changes::{FileChange, FileChangeType};
+//! use sublime_pkg_tools::changes::{FileChange, FileChangeType, FileClass};
 //! use std::path::PathBuf;
 //!
 //! let changes = vec![
@@ -46,6 +48,7 @@
 //!         lines_added: Some(100),
 //!         lines_deleted: None,
 //!         commits: vec![],
+//!         class: FileClass::Source,
 //!     },
 //!     FileChange {
 //!         path: PathBuf::from("file2.ts"),
@@ -54,6 +57,7 @@
 //!         lines_added: Some(10),
 //!         lines_deleted: Some(5),
 //!         commits: vec![],
+//!         class: FileClass::Source,
 //!     },
 //! ];
 //!
@@ -65,7 +69,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use sublime_git_tools::GitFileStatus;
+use sublime_git_tools::{GitChangedFile, GitFileStatus};
 
 /// Type of change applied to a file.
 ///
@@ -99,6 +103,8 @@ pub enum FileChangeType {
     Copied,
     /// File is untracked (not yet added to Git).
     Untracked,
+    /// Submodule whose recorded commit changed.
+    Submodule,
 }
 
 impl FileChangeType {
@@ -127,11 +133,12 @@ impl FileChangeType {
     ///
     /// assert!(FileChangeType::Modified.is_modification());
     /// assert!(FileChangeType::Renamed.is_modification());
+    /// assert!(FileChangeType::Submodule.is_modification());
     /// assert!(!FileChangeType::Added.is_modification());
     /// ```
     #[must_use]
     pub fn is_modification(&self) -> bool {
-        matches!(self, Self::Modified | Self::Renamed)
+        matches!(self, Self::Modified | Self::Renamed | Self::Submodule)
     }
 
     /// Returns whether this change type represents a deletion.
@@ -169,6 +176,57 @@ impl FileChangeType {
             GitFileStatus::Untracked => Self::Untracked,
         }
     }
+
+    /// Converts a `sublime_git_tools::GitChangedFile` to `FileChangeType`.
+    ///
+    /// Submodule entries become `Submodule` whatever their status, so a submodule bump is
+    /// not mistaken for an edit of a regular file.
+    #[must_use]
+    pub fn from_git_file(file: &GitChangedFile) -> Self {
+        if file.is_submodule() { Self::Submodule } else { Self::from_git_status(&file.status) }
+    }
+}
+
+/// Kind of file a change touches.
+///
+/// Classes are assigned from the `changes.file_patterns` configuration. Changes to
+/// `Generated` and `Binary` files alone do not make a package meaningfully changed.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changes::FileClass;
+///
+/// assert!(FileClass::Source.is_meaningful());
+/// assert!(FileClass::Test.is_meaningful());
+/// assert!(!FileClass::Generated.is_meaningful());
+/// assert_eq!(FileClass::default(), FileClass::Source);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileClass {
+    /// Source code, or any file no pattern matches.
+    #[default]
+    Source,
+    /// Test files and fixtures.
+    Test,
+    /// Documentation.
+    Docs,
+    /// Tooling and package configuration.
+    Config,
+    /// Files produced by tools, such as lockfiles, snapshots and build output.
+    Generated,
+    /// Binary assets such as images, fonts and archives.
+    Binary,
+}
+
+impl FileClass {
+    /// Returns whether a change to a file of this class makes its package meaningfully
+    /// changed.
+    #[must_use]
+    pub fn is_meaningful(&self) -> bool {
+        !matches!(self, Self::Generated | Self::Binary)
+    }
 }
 
 /// Details of a single file change.
@@ -182,7 +240,7 @@ impl FileChangeType {
 /// ## Creating a file change
 ///
 /// ```rust
-/// use sublime_pkg_tools::changes::{FileChange, FileChangeType};
+/// use sublime_pkg_tools::changes::{FileChange, FileChangeType, FileClass};
 /// use std::path::PathBuf;
 ///
 /// let change = FileChange {
@@ -192,6 +250,7 @@ impl FileChangeType {
 ///     lines_added: Some(50),
 ///     lines_deleted: None,
 ///     commits: vec![],
+///     class: FileClass::Source,
 /// };
 ///
 /// assert!(change.is_addition());
@@ -201,7 +260,7 @@ impl FileChangeType {
 /// ## Checking for package.json changes
 ///
 /// ```rust
-/// use sublime_pkg_tools::changes::{FileChange, FileChangeType};
+/// use sublime_pkg_tools::changes::{FileChange, FileChangeType, FileClass};
 /// use std::path::PathBuf;
 ///
 /// let change = FileChange {
@@ -211,6 +270,7 @@ impl FileChangeType {
 ///     lines_added: Some(2),
 ///     lines_deleted: Some(1),
 ///     commits: vec![],
+///     class: FileClass::Source,
 /// };
 ///
 /// assert!(change.is_package_json());
@@ -251,6 +311,10 @@ pub struct FileChange {
     /// For working directory analysis, this will be empty. For commit range
     /// analysis, this contains all commits in the range that modified this file.
     pub commits: Vec<String>,
+
+    /// Kind of file changed.
+    #[serde(default)]
+    pub class: FileClass,
 }
 
 impl FileChange {
@@ -286,6 +350,7 @@ impl FileChange {
             lines_added: None,
             lines_deleted: None,
             commits: Vec::new(),
+            class: FileClass::Source,
         }
     }
 
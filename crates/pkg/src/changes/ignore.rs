@@ -0,0 +1,171 @@
+//! Package-level ignore rules for change detection.
+//!
+//! **What**: Provides `PackageIgnore`, the set of paths inside a package whose changes do not
+//! mark the package as changed (for example `**/*.test.ts` or `docs/`).
+//!
+//! **How**: Rules are read from a `.pkgignore` file in the package root and from the
+//! `packageTools.ignoreChanges` array in the package's `package.json`. `.pkgignore` holds one
+//! rule per line; blank lines and lines starting with `#` are skipped. Every rule is relative
+//! to the package root: rules containing `*`, `?`, or `[` are glob patterns (`*` does not cross
+//! `/`, `**` does), other rules match the path itself and everything below it.
+//!
+//! **Why**: Test-only or documentation-only edits should not force a release. Keeping the
+//! rules next to each package lets package owners decide what counts as a releasable change,
+//! and both `PackageMapper` and the changeset `PackageDetector` apply the same rules.
+
+use crate::error::{ChangesError, ChangesResult};
+use glob::{MatchOptions, Pattern};
+use std::path::Path;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Name of the per-package ignore file.
+pub const PKGIGNORE_FILE: &str = ".pkgignore";
+
+/// A single ignore rule.
+#[derive(Debug, Clone)]
+enum IgnoreRule {
+    /// Glob pattern matched against the package-relative path.
+    Glob(Pattern),
+    /// Path prefix: matches the path itself and everything below it.
+    Prefix(String),
+}
+
+/// Paths inside a package whose changes are ignored by change detection.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changes::PackageIgnore;
+/// use std::path::Path;
+///
+/// let ignore = PackageIgnore::from_rules(["**/*.test.ts", "docs/"])?;
+///
+/// assert!(ignore.is_ignored(Path::new("src/utils/index.test.ts")));
+/// assert!(ignore.is_ignored(Path::new("docs/guide.md")));
+/// assert!(!ignore.is_ignored(Path::new("src/index.ts")));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PackageIgnore {
+    rules: Vec<IgnoreRule>,
+}
+
+impl PackageIgnore {
+    /// Builds ignore rules from a list of rule strings.
+    ///
+    /// Blank rules and rules starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesError::PatternError` if a glob rule is invalid.
+    pub fn from_rules<I, S>(rules: I) -> ChangesResult<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut ignore = Self::default();
+
+        for rule in rules {
+            let rule = rule.as_ref().trim();
+            if rule.is_empty() || rule.starts_with('#') {
+                continue;
+            }
+
+            let rule = rule.trim_start_matches("./").trim_end_matches('/');
+            if rule.contains(['*', '?', '[']) {
+                let pattern = Pattern::new(rule).map_err(|e| ChangesError::PatternError {
+                    pattern: rule.to_string(),
+                    reason: e.to_string(),
+                })?;
+                ignore.rules.push(IgnoreRule::Glob(pattern));
+            } else {
+                ignore.rules.push(IgnoreRule::Prefix(rule.to_string()));
+            }
+        }
+
+        Ok(ignore)
+    }
+
+    /// Loads the ignore rules of the package at `package_root`.
+    ///
+    /// Combines the rules from `<package_root>/.pkgignore` and from
+    /// `packageTools.ignoreChanges` in `<package_root>/package.json`. Missing files yield no
+    /// rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `fs` - Filesystem used to read the files
+    /// * `package_root` - Absolute path of the package directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an existing file cannot be read, if `package.json` cannot be
+    /// parsed, or if a glob rule is invalid.
+    pub async fn load<F>(fs: &F, package_root: &Path) -> ChangesResult<Self>
+    where
+        F: AsyncFileSystem + Send + Sync,
+    {
+        let mut rules = Vec::new();
+
+        let ignore_path = package_root.join(PKGIGNORE_FILE);
+        if fs.exists(&ignore_path).await {
+            let content = fs.read_file_string(&ignore_path).await.map_err(|e| {
+                ChangesError::FileSystemError {
+                    path: ignore_path.clone(),
+                    reason: format!("Failed to read {PKGIGNORE_FILE}: {e}"),
+                }
+            })?;
+            rules.extend(content.lines().map(str::to_string));
+        }
+
+        let package_json_path = package_root.join("package.json");
+        if fs.exists(&package_json_path).await {
+            let content = fs.read_file_string(&package_json_path).await.map_err(|e| {
+                ChangesError::FileSystemError {
+                    path: package_json_path.clone(),
+                    reason: format!("Failed to read package.json: {e}"),
+                }
+            })?;
+            let package_json: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
+                ChangesError::PackageJsonParseError {
+                    path: package_json_path.clone(),
+                    reason: e.to_string(),
+                }
+            })?;
+
+            if let Some(globs) = package_json
+                .pointer("/packageTools/ignoreChanges")
+                .and_then(serde_json::Value::as_array)
+            {
+                rules
+                    .extend(globs.iter().filter_map(serde_json::Value::as_str).map(str::to_string));
+            }
+        }
+
+        Self::from_rules(rules)
+    }
+
+    /// Returns `true` if there are no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Checks whether a changed file should be ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_relative_path` - Path of the file relative to the package root
+    ///
+    /// # Returns
+    ///
+    /// `true` if any rule matches the path.
+    #[must_use]
+    pub fn is_ignored(&self, package_relative_path: &Path) -> bool {
+        let options = MatchOptions { require_literal_separator: true, ..MatchOptions::new() };
+        self.rules.iter().any(|rule| match rule {
+            IgnoreRule::Glob(pattern) => pattern.matches_path_with(package_relative_path, options),
+            IgnoreRule::Prefix(prefix) => package_relative_path.starts_with(prefix),
+        })
+    }
+}
@@ -16,7 +16,8 @@
 //! - **Single Package**: Handles standard single-package projects
 //! - **Caching**: Caches monorepo structure and file mappings for performance
 //! - **Root Files**: Handles files in the workspace root that don't belong to any package
-//! - **Path Normalization**: Handles relative and absolute paths correctly
+//! - **Path Normalization**: Handles relative and absolute paths correctly, including `\`
+//!   separators and Windows verbatim (`\\?\`) and drive-letter case differences
 //!
 //! # Examples
 //!
@@ -68,11 +69,13 @@
 //! # }
 //! ```
 
+use crate::changes::PackageIgnore;
 use crate::error::{ChangesError, ChangesResult};
 use crate::types::PackageInfo;
 use package_json::PackageJson;
+use std::cell::OnceCell;
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use sublime_standard_tools::monorepo::{
     MonorepoDescriptor, MonorepoDetector, MonorepoDetectorTrait,
@@ -89,6 +92,8 @@ use sublime_standard_tools::monorepo::{
 /// - **Lazy Detection**: Monorepo structure is detected on first use
 /// - **Two-Level Cache**: Caches both the monorepo descriptor and file-to-package mappings
 /// - **Absolute Path Handling**: Normalizes all paths relative to workspace root
+/// - **Ignore Rules**: Files matched by a package's `.pkgignore` or
+///   `packageTools.ignoreChanges` rules do not mark the package as changed
 ///
 /// # Cache Strategy
 ///
@@ -136,9 +141,16 @@ where
     /// Cached monorepo descriptor (None means not yet detected or single-package).
     pub(crate) cached_monorepo: Option<Option<MonorepoDescriptor>>,
 
+    /// Package roots in the order of the cached monorepo's packages, each canonicalized
+    /// at most once per detection.
+    pub(crate) package_roots: Vec<ResolvedPath>,
+
     /// Cache mapping file paths to package names.
     /// Value is `Option<String>` where `None` means file doesn't belong to any package.
     pub(crate) file_cache: HashMap<PathBuf, Option<String>>,
+
+    /// Cache of loaded ignore rules by package name.
+    pub(crate) ignore_cache: HashMap<String, PackageIgnore>,
 }
 
 impl PackageMapper<FileSystemManager> {
@@ -169,7 +181,9 @@ impl PackageMapper<FileSystemManager> {
             fs,
             monorepo_detector,
             cached_monorepo: None,
+            package_roots: Vec::new(),
             file_cache: HashMap::new(),
+            ignore_cache: HashMap::new(),
         }
     }
 }
@@ -210,14 +224,17 @@ where
             fs,
             monorepo_detector,
             cached_monorepo: None,
+            package_roots: Vec::new(),
             file_cache: HashMap::new(),
+            ignore_cache: HashMap::new(),
         }
     }
 
     /// Maps a list of files to their containing packages.
     ///
     /// Returns a HashMap where keys are package names and values are lists of files
-    /// belonging to that package. Files that don't belong to any package are omitted.
+    /// belonging to that package. Files that don't belong to any package, or that match
+    /// their package's ignore rules, are omitted.
     ///
     /// # Arguments
     ///
@@ -277,10 +294,12 @@ where
             let normalized_path = self.normalize_path(file)?;
 
             // Find which package owns this file
-            if let Some(package_name) = self.find_package_for_file(&normalized_path).await? {
+            if let Some(package_name) = self.find_package_for_file(&normalized_path).await?
+                && !self.is_ignored(&package_name, &normalized_path).await?
+            {
                 package_files.entry(package_name).or_default().push(normalized_path);
             }
-            // Files not belonging to any package are silently omitted
+            // Files not belonging to any package or ignored by it are silently omitted
         }
 
         Ok(package_files)
@@ -445,7 +464,71 @@ where
     /// ```
     pub fn clear_cache(&mut self) {
         self.cached_monorepo = None;
+        self.package_roots.clear();
         self.file_cache.clear();
+        self.ignore_cache.clear();
+    }
+
+    /// Checks whether a change to `file` is ignored by the ignore rules of `package_name`.
+    ///
+    /// Rules come from the package's `.pkgignore` file and its
+    /// `packageTools.ignoreChanges` globs (see `PackageIgnore`), and are loaded once per
+    /// package.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - Name of the package that owns the file
+    /// * `file` - Path to the file (can be relative or absolute)
+    ///
+    /// # Returns
+    ///
+    /// `true` if the change should not mark the package as changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is outside the workspace, monorepo detection fails, or
+    /// the ignore rules cannot be read or parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::changes::mapping::PackageMapper;
+    /// use sublime_standard_tools::filesystem::FileSystemManager;
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut mapper = PackageMapper::new(PathBuf::from("."), FileSystemManager::new());
+    ///
+    /// let file = Path::new("packages/core/src/index.test.ts");
+    /// if mapper.is_ignored("@myorg/core", file).await? {
+    ///     println!("Test-only change");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_ignored(&mut self, package_name: &str, file: &Path) -> ChangesResult<bool> {
+        self.ensure_monorepo_detected().await?;
+
+        let package_root = match &self.cached_monorepo {
+            Some(Some(monorepo)) => match monorepo.get_package(package_name) {
+                Some(package) => package.absolute_path.clone(),
+                None => return Ok(false),
+            },
+            _ => self.workspace_root.clone(),
+        };
+
+        if !self.ignore_cache.contains_key(package_name) {
+            let ignore = PackageIgnore::load(&self.fs, &package_root).await?;
+            self.ignore_cache.insert(package_name.to_string(), ignore);
+        }
+
+        let Some(ignore) = self.ignore_cache.get(package_name).filter(|i| !i.is_empty()) else {
+            return Ok(false);
+        };
+
+        let absolute_file = self.workspace_root.join(self.normalize_path(file)?);
+        Ok(relative_to(&absolute_file, &package_root)
+            .is_some_and(|relative| ignore.is_ignored(&relative)))
     }
 
     /// Returns whether this workspace is a monorepo.
@@ -497,6 +580,11 @@ where
             match monorepo_result {
                 Ok(descriptor) => {
                     // Successfully detected as monorepo
+                    self.package_roots = descriptor
+                        .packages()
+                        .iter()
+                        .map(|package| ResolvedPath::new(package.absolute_path.clone()))
+                        .collect();
                     self.cached_monorepo = Some(Some(descriptor));
                 }
                 Err(_) => {
@@ -515,18 +603,20 @@ where
     /// This assumes the path is already normalized and monorepo is detected.
     async fn find_package_for_file_impl(&self, file: &Path) -> ChangesResult<Option<String>> {
         if let Some(Some(monorepo)) = &self.cached_monorepo {
-            // Monorepo: use find_package_for_path
-            // Convert relative path to absolute and canonicalize to handle symlinks
+            // Monorepo: find the package whose directory contains the file
             let absolute_file = if file.is_absolute() {
                 file.to_path_buf()
             } else {
                 self.workspace_root.join(file)
             };
 
-            // Canonicalize to handle symlinks (e.g., /var -> /private/var on macOS)
-            let canonical_file = absolute_file.canonicalize().unwrap_or(absolute_file);
-
-            if let Some(workspace_package) = monorepo.find_package_for_path(&canonical_file) {
+            let absolute_file = ResolvedPath::new(absolute_file);
+            if let Some((workspace_package, _)) = monorepo
+                .packages()
+                .iter()
+                .zip(&self.package_roots)
+                .find(|(_, root)| is_within(&absolute_file, root))
+            {
                 return Ok(Some(workspace_package.name.clone()));
             }
 
@@ -542,19 +632,19 @@ where
     /// Normalizes a file path relative to the workspace root.
     ///
     /// Handles both relative and absolute paths, ensuring the returned path
-    /// is relative to the workspace root.
+    /// is relative to the workspace root and uses the platform separator.
     pub(crate) fn normalize_path(&self, path: &Path) -> ChangesResult<PathBuf> {
         if path.is_absolute() {
-            // Strip workspace root prefix
-            path.strip_prefix(&self.workspace_root).map(|p| p.to_path_buf()).map_err(|_| {
-                ChangesError::FileOutsideWorkspace {
+            // Strip workspace root prefix, retrying with canonical paths to resolve symlinks
+            relative_to(path, &self.workspace_root)
+                .or_else(|| relative_to(&canonical(path), &canonical(&self.workspace_root)))
+                .ok_or_else(|| ChangesError::FileOutsideWorkspace {
                     path: path.to_path_buf(),
                     workspace_root: self.workspace_root.clone(),
-                }
-            })
+                })
         } else {
             // Already relative
-            Ok(path.to_path_buf())
+            Ok(normalize_separators(path))
         }
     }
 
@@ -612,3 +702,91 @@ where
         ))
     }
 }
+
+/// Rewrites `/` and `\` separators to the platform separator.
+///
+/// Git always reports paths with `/`, while paths built on Windows use `\`. Normalizing both
+/// lets paths from either source be compared component by component.
+pub(crate) fn normalize_separators(path: &Path) -> PathBuf {
+    let raw = path.to_string_lossy();
+    let foreign = if cfg!(windows) { '/' } else { '\\' };
+    if !raw.contains(foreign) {
+        return path.to_path_buf();
+    }
+    PathBuf::from(raw.replace(foreign, std::path::MAIN_SEPARATOR_STR))
+}
+
+/// Removes the verbatim prefix (`\\?\` or `\\?\UNC\`) that `canonicalize` adds on Windows.
+pub(crate) fn strip_verbatim_prefix(path: &Path) -> PathBuf {
+    let raw = path.to_string_lossy();
+    if let Some(rest) = raw.strip_prefix(r"\\?\UNC\") {
+        return PathBuf::from(format!(r"\\{rest}"));
+    }
+    if let Some(rest) = raw.strip_prefix(r"\\?\") {
+        return PathBuf::from(rest);
+    }
+    path.to_path_buf()
+}
+
+/// Returns `path` relative to `base` when `path` is inside `base`.
+///
+/// Both paths have their separators and verbatim prefixes normalized first. On Windows,
+/// components are compared ignoring ASCII case, matching the filesystem.
+pub(crate) fn relative_to(path: &Path, base: &Path) -> Option<PathBuf> {
+    let path = strip_verbatim_prefix(&normalize_separators(path));
+    let base = strip_verbatim_prefix(&normalize_separators(base));
+
+    let mut remaining = path.components();
+    for base_component in base.components() {
+        if !components_equal(remaining.next()?, base_component) {
+            return None;
+        }
+    }
+    Some(remaining.as_path().to_path_buf())
+}
+
+/// A path paired with its canonical form, resolved on first use.
+///
+/// Matching many files against many package roots would otherwise canonicalize the same
+/// paths over and over; each `ResolvedPath` hits the filesystem at most once.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedPath {
+    path: PathBuf,
+    canonical: OnceCell<PathBuf>,
+}
+
+impl ResolvedPath {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self { path, canonical: OnceCell::new() }
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn canonical(&self) -> &Path {
+        self.canonical.get_or_init(|| canonical(&self.path))
+    }
+}
+
+/// Returns whether `path` is inside `dir`, also comparing canonical forms to resolve
+/// symlinks (e.g., `/var` -> `/private/var` on macOS).
+///
+/// Canonical forms are only resolved when the paths as given do not match.
+pub(crate) fn is_within(path: &ResolvedPath, dir: &ResolvedPath) -> bool {
+    relative_to(path.path(), dir.path()).is_some()
+        || relative_to(path.canonical(), dir.canonical()).is_some()
+}
+
+/// Canonicalizes `path`, falling back to the path itself when it cannot be resolved.
+pub(crate) fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn components_equal(a: Component<'_>, b: Component<'_>) -> bool {
+    if cfg!(windows) {
+        a.as_os_str().to_string_lossy().eq_ignore_ascii_case(&b.as_os_str().to_string_lossy())
+    } else {
+        a == b
+    }
+}
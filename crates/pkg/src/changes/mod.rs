@@ -20,6 +20,7 @@
 //! - **Change Statistics**: Provide detailed statistics about changes (files, lines, commits)
 //! - **Multi-Package Support**: Handle both single-package and monorepo structures
 //! - **Change Filtering**: Filter changes by type, package, or directory
+//! - **Workspace Diff**: Compare packages, versions and dependencies between two states
 //!
 //! # Example
 //!
@@ -103,6 +104,7 @@
 //! - `file_change`: Individual file change details
 //! - `commit_info`: Commit information and metadata
 //! - `stats`: Change statistics and summaries
+//! - `workspace_diff`: Package-level diff between two workspace states
 
 // Analyzer module - Story 7.1
 mod analyzer;
@@ -112,6 +114,15 @@ pub use analyzer::ChangesAnalyzer;
 pub mod mapping;
 pub use mapping::PackageMapper;
 
+// Package-level ignore rules
+mod ignore;
+pub use ignore::{PKGIGNORE_FILE, PackageIgnore};
+
+// Analysis requests
+mod query;
+pub use query::ChangesQuery;
+pub(crate) use query::path_matches;
+
 // Report types - Story 7.3
 mod report;
 pub use report::{AnalysisMode, ChangesReport};
@@ -122,7 +133,7 @@ pub use package_changes::PackageChanges;
 
 // File changes - Story 7.3
 mod file_change;
-pub use file_change::{FileChange, FileChangeType};
+pub use file_change::{FileChange, FileChangeType, FileClass};
 
 // Commit info - Story 7.3 (minimal implementation)
 mod commit_info;
@@ -130,7 +141,14 @@ pub use commit_info::CommitInfo;
 
 // Statistics - Story 7.3
 mod stats;
-pub use stats::{ChangesSummary, PackageChangeStats};
+pub use stats::{ChangesSummary, FileClassCounts, PackageChangeStats};
+
+// Workspace diff
+mod workspace_diff;
+pub use workspace_diff::{
+    DependencyChange, DependencyChangeKind, PackageDiff, PackageSummary, VersionChange,
+    WorkspaceDiff, WorkspaceState,
+};
 
 // Tests module
 #[cfg(test)]
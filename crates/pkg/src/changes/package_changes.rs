@@ -40,7 +40,7 @@
 //! assert_eq!(changes.files.len(), 1);
 //! ```
 
-use crate::changes::{CommitInfo, FileChange, FileChangeType, PackageChangeStats};
+use crate::changes::{CommitInfo, FileChange, FileChangeType, FileClass, PackageChangeStats};
 use crate::types::{Version, VersionBump};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -249,7 +249,7 @@ impl PackageChanges {
             FileChangeType::Added | FileChangeType::Untracked | FileChangeType::Copied => {
                 self.stats.files_added += 1;
             }
-            FileChangeType::Modified | FileChangeType::Renamed => {
+            FileChangeType::Modified | FileChangeType::Renamed | FileChangeType::Submodule => {
                 self.stats.files_modified += 1;
             }
             FileChangeType::Deleted => {
@@ -263,6 +263,7 @@ impl PackageChanges {
         if let Some(deleted) = file_change.lines_deleted {
             self.stats.lines_deleted += deleted;
         }
+        self.stats.classes.add(file_change.class);
 
         self.files.push(file_change);
         self.has_changes = true;
@@ -280,6 +281,20 @@ impl PackageChanges {
         self.stats.commits = self.commits.len();
     }
 
+    /// Recalculates the statistics and `has_changes` from the current files and commits.
+    ///
+    /// This should be called after manually modifying `files` or `commits`.
+    pub fn recalculate_stats(&mut self) {
+        let files = std::mem::take(&mut self.files);
+        self.stats = PackageChangeStats::new();
+        self.has_changes = false;
+
+        for file in files {
+            self.add_file(file);
+        }
+        self.stats.commits = self.commits.len();
+    }
+
     /// Gets files by change type.
     ///
     /// # Arguments
@@ -452,6 +467,21 @@ impl PackageChanges {
     pub fn deleted_files(&self) -> Vec<&FileChange> {
         self.files.iter().filter(|f| f.is_deletion()).collect()
     }
+
+    /// Returns all files of the given class.
+    #[must_use]
+    pub fn files_by_class(&self, class: FileClass) -> Vec<&FileChange> {
+        self.files.iter().filter(|f| f.class == class).collect()
+    }
+
+    /// Returns whether any changed file is something other than generated or binary output.
+    ///
+    /// Packages whose only changes are lockfiles, build artifacts or binaries still appear in
+    /// reports but do not warrant a version bump.
+    #[must_use]
+    pub fn has_meaningful_changes(&self) -> bool {
+        self.files.iter().any(|f| f.class.is_meaningful())
+    }
 }
 
 /// Default WorkspacePackage for deserialization.
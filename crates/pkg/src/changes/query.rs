@@ -0,0 +1,145 @@
+//! Change analysis requests over arbitrary refs and paths.
+//!
+//! **What**: Provides `ChangesQuery`, a description of which changes `ChangesAnalyzer::analyze`
+//! should report: the Git range, path filters, and whether uncommitted work is included.
+//!
+//! **How**: A query holds an optional starting ref (defaulting to each package's last release),
+//! an ending ref (defaulting to `HEAD`), path filters matched against repository-relative file
+//! paths, and an `include_uncommitted` flag. The analyzer runs the range analysis, merges the
+//! working directory changes when requested, and then drops files outside the path filters.
+//!
+//! **Why**: Reviewing a pull request or preparing a release needs changes between any two refs
+//! (tags, SHAs, branches), often narrowed to part of the tree. A single request type lets the
+//! CLI and bindings share those semantics instead of combining fixed analysis modes.
+
+use glob::{MatchOptions, Pattern};
+use std::path::Path;
+
+/// Ref used when a query has no ending ref.
+const DEFAULT_TO_REF: &str = "HEAD";
+
+/// A change analysis request over a Git range with optional path filters.
+///
+/// A default query reports every change since each package's last release up to `HEAD`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changes::ChangesQuery;
+/// use std::path::Path;
+///
+/// let query = ChangesQuery::new()
+///     .from_ref("v1.0.0")
+///     .to_ref("feature/login")
+///     .with_path("packages/core")
+///     .with_path("**/*.ts")
+///     .include_uncommitted(true);
+///
+/// assert_eq!(query.to(), "feature/login");
+/// assert!(query.matches_path(Path::new("packages/core/package.json")));
+/// assert!(query.matches_path(Path::new("packages/cli/src/index.ts")));
+/// assert!(!query.matches_path(Path::new("packages/cli/README.md")));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangesQuery {
+    /// Starting ref (exclusive): a tag, SHA, or branch. `None` starts each package at its
+    /// last release.
+    pub from_ref: Option<String>,
+
+    /// Ending ref (inclusive). `None` means `HEAD`.
+    pub to_ref: Option<String>,
+
+    /// Path filters, relative to the repository root. Entries containing `*`, `?`, or `[` are
+    /// glob patterns; other entries match the path itself and everything below it. An empty
+    /// list matches every file.
+    pub paths: Vec<String>,
+
+    /// Whether staged, unstaged, and untracked changes are added to the committed range.
+    pub include_uncommitted: bool,
+}
+
+impl ChangesQuery {
+    /// Creates a query that reports every change since the last release.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the starting ref.
+    #[must_use]
+    pub fn from_ref(mut self, from_ref: impl Into<String>) -> Self {
+        self.from_ref = Some(from_ref.into());
+        self
+    }
+
+    /// Sets the ending ref.
+    #[must_use]
+    pub fn to_ref(mut self, to_ref: impl Into<String>) -> Self {
+        self.to_ref = Some(to_ref.into());
+        self
+    }
+
+    /// Adds a path filter.
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    /// Adds several path filters.
+    #[must_use]
+    pub fn with_paths<I, S>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets whether uncommitted changes are included.
+    #[must_use]
+    pub fn include_uncommitted(mut self, include: bool) -> Self {
+        self.include_uncommitted = include;
+        self
+    }
+
+    /// Returns the ending ref, defaulting to `HEAD`.
+    #[must_use]
+    pub fn to(&self) -> &str {
+        self.to_ref.as_deref().unwrap_or(DEFAULT_TO_REF)
+    }
+
+    /// Checks whether a repository-relative file path passes the path filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path relative to the repository root
+    ///
+    /// # Returns
+    ///
+    /// `true` if there are no filters or any filter matches.
+    #[must_use]
+    pub fn matches_path(&self, path: &Path) -> bool {
+        if self.paths.is_empty() {
+            return true;
+        }
+
+        self.paths.iter().any(|filter| path_matches(filter, path))
+    }
+}
+
+/// Checks a path against a single path filter.
+///
+/// Filters containing `*`, `?`, or `[` are glob patterns in which `*` does not cross `/`;
+/// other filters match the path itself and everything below it. A leading `./` and a
+/// trailing `/` are ignored. Invalid globs match nothing.
+pub(crate) fn path_matches(filter: &str, path: &Path) -> bool {
+    let filter = filter.trim_start_matches("./").trim_end_matches('/');
+    if filter.contains(['*', '?', '[']) {
+        let options = MatchOptions { require_literal_separator: true, ..MatchOptions::new() };
+        Pattern::new(filter).is_ok_and(|pattern| pattern.matches_path_with(path, options))
+    } else {
+        path.starts_with(filter)
+    }
+}
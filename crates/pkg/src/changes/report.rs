@@ -25,6 +25,8 @@
 //!     packages: vec![],
 //!     summary: ChangesSummary::new(),
 //!     is_monorepo: false,
+//!     unattributed_files: vec![],
+//!     warnings: vec![],
 //! };
 //!
 //! assert_eq!(report.analysis_mode, AnalysisMode::WorkingDirectory);
@@ -38,9 +40,11 @@
 //! let packages_without_changes = report.packages_without_changes();
 //! ```
 
-use crate::changes::{ChangesSummary, FileChangeType, PackageChanges};
+use crate::changes::{ChangesSummary, FileChange, FileChangeType, PackageChanges};
+use crate::types::{Warning, WarningKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Analysis mode indicating how changes were detected.
 ///
@@ -206,6 +210,19 @@ pub struct ChangesReport {
 
     /// Whether this workspace is a monorepo.
     pub is_monorepo: bool,
+
+    /// Changed files outside every package that no `changes.root_files` rule attributes.
+    ///
+    /// These changes affect no package; add a rule to ignore them or attribute them.
+    #[serde(default)]
+    pub unattributed_files: Vec<FileChange>,
+
+    /// Non-fatal issues found during the analysis.
+    ///
+    /// Lists each package without a valid version, for which no next version can be
+    /// calculated, and each file in `unattributed_files`.
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
 }
 
 impl ChangesReport {
@@ -236,6 +253,8 @@ impl ChangesReport {
             packages: Vec::new(),
             summary: ChangesSummary::new(),
             is_monorepo,
+            unattributed_files: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -266,6 +285,8 @@ impl ChangesReport {
             packages: Vec::new(),
             summary: ChangesSummary::new(),
             is_monorepo,
+            unattributed_files: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -396,12 +417,27 @@ impl ChangesReport {
 
     /// Adds a package to the report.
     ///
-    /// Updates the summary statistics automatically.
+    /// Updates the summary statistics automatically, and adds an `InvalidVersion` warning
+    /// when the package has no valid current version.
     ///
     /// # Arguments
     ///
     /// * `package` - The package changes to add
     pub fn add_package(&mut self, package: PackageChanges) {
+        if package.current_version.is_none() {
+            self.warnings.push(
+                Warning::new(
+                    WarningKind::InvalidVersion,
+                    format!(
+                        "{} has no valid version ('{}'); its next version cannot be calculated",
+                        package.package_name, package.package_version
+                    ),
+                )
+                .with_package(package.package_name.clone())
+                .with_path(package.package_location.join("package.json")),
+            );
+        }
+
         // Update summary
         self.summary.total_packages += 1;
         if package.has_changes {
@@ -422,6 +458,54 @@ impl ChangesReport {
         self.packages.push(package);
     }
 
+    /// Keeps only the files matching a predicate, in every package and in
+    /// `unattributed_files`.
+    ///
+    /// Commits that no longer touch any kept file are dropped, and package statistics and the
+    /// summary are recalculated.
+    ///
+    /// # Arguments
+    ///
+    /// * `keep` - Returns `true` for files to keep
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // Only report TypeScript sources
+    /// report.retain_files(|file| file.extension() == Some("ts"));
+    /// ```
+    pub fn retain_files(&mut self, mut keep: impl FnMut(&FileChange) -> bool) {
+        for package in &mut self.packages {
+            package.files.retain(&mut keep);
+            let touched: HashSet<&str> = package
+                .files
+                .iter()
+                .flat_map(|file| file.commits.iter().map(String::as_str))
+                .collect();
+            package.commits.retain(|commit| touched.contains(commit.hash.as_str()));
+            package.recalculate_stats();
+        }
+        self.unattributed_files.retain(&mut keep);
+        self.sync_unattributed_warnings();
+
+        self.recalculate_summary();
+    }
+
+    /// Replaces the `UnattributedFile` warnings with one per file in `unattributed_files`.
+    pub(crate) fn sync_unattributed_warnings(&mut self) {
+        self.warnings.retain(|warning| warning.kind != WarningKind::UnattributedFile);
+        self.warnings.extend(self.unattributed_files.iter().map(|file| {
+            Warning::new(
+                WarningKind::UnattributedFile,
+                format!(
+                    "{} changed outside every package and no changes.root_files rule attributes it",
+                    file.path.display()
+                ),
+            )
+            .with_path(file.path.clone())
+        }));
+    }
+
     /// Recalculates the summary statistics from current packages.
     ///
     /// This should be called after manually modifying packages.
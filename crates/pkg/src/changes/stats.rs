@@ -14,7 +14,7 @@
 //! ## Creating package statistics
 //!
 //! ```rust
-//! use sublime_pkg_tools::changes::PackageChangeStats;
+//! use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
 //!
 //! let stats = PackageChangeStats {
 //!     files_changed: 5,
@@ -24,6 +24,7 @@
 //!     commits: 3,
 //!     lines_added: 150,
 //!     lines_deleted: 30,
+//!     classes: FileClassCounts::default(),
 //! };
 //!
 //! assert_eq!(stats.net_lines_changed(), 120);
@@ -49,6 +50,7 @@
 //! assert_eq!(summary.net_lines_changed(), 200);
 //! ```
 
+use crate::changes::FileClass;
 use serde::{Deserialize, Serialize};
 
 /// Summary statistics for changes across all packages.
@@ -264,7 +266,7 @@ impl Default for ChangesSummary {
 /// # Examples
 ///
 /// ```rust
-/// use sublime_pkg_tools::changes::PackageChangeStats;
+/// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
 ///
 /// let stats = PackageChangeStats {
 ///     files_changed: 5,
@@ -274,6 +276,7 @@ impl Default for ChangesSummary {
 ///     commits: 3,
 ///     lines_added: 150,
 ///     lines_deleted: 30,
+///     classes: FileClassCounts::default(),
 /// };
 ///
 /// assert_eq!(stats.net_lines_changed(), 120);
@@ -301,6 +304,10 @@ pub struct PackageChangeStats {
 
     /// Total lines deleted in this package.
     pub lines_deleted: usize,
+
+    /// Number of changed files of each class.
+    #[serde(default)]
+    pub classes: FileClassCounts,
 }
 
 impl PackageChangeStats {
@@ -325,6 +332,7 @@ impl PackageChangeStats {
             commits: 0,
             lines_added: 0,
             lines_deleted: 0,
+            classes: FileClassCounts::default(),
         }
     }
 
@@ -346,12 +354,34 @@ impl PackageChangeStats {
         self.files_changed > 0
     }
 
+    /// Returns whether this package has changes other than to generated or binary files.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changes::{FileClass, PackageChangeStats};
+    ///
+    /// let mut stats = PackageChangeStats::new();
+    /// stats.files_changed = 1;
+    /// stats.classes.add(FileClass::Generated);
+    /// assert!(stats.has_changes());
+    /// assert!(!stats.has_meaningful_changes());
+    ///
+    /// stats.files_changed = 2;
+    /// stats.classes.add(FileClass::Source);
+    /// assert!(stats.has_meaningful_changes());
+    /// ```
+    #[must_use]
+    pub fn has_meaningful_changes(&self) -> bool {
+        self.files_changed > self.classes.generated + self.classes.binary
+    }
+
     /// Calculates the net line change (added - deleted).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -361,6 +391,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.net_lines_changed(), 120);
@@ -375,7 +406,7 @@ impl PackageChangeStats {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -385,6 +416,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.total_lines_changed(), 180);
@@ -401,7 +433,7 @@ impl PackageChangeStats {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -411,6 +443,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.average_lines_per_file(), 36.0);
@@ -431,7 +464,7 @@ impl PackageChangeStats {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -441,6 +474,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.added_percentage(), 40.0);
@@ -461,7 +495,7 @@ impl PackageChangeStats {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -471,6 +505,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.modified_percentage(), 60.0);
@@ -491,7 +526,7 @@ impl PackageChangeStats {
     /// # Examples
     ///
     /// ```rust
-    /// use sublime_pkg_tools::changes::PackageChangeStats;
+    /// use sublime_pkg_tools::changes::{FileClassCounts, PackageChangeStats};
     ///
     /// let stats = PackageChangeStats {
     ///     files_changed: 5,
@@ -501,6 +536,7 @@ impl PackageChangeStats {
     ///     commits: 3,
     ///     lines_added: 150,
     ///     lines_deleted: 30,
+    ///     classes: FileClassCounts::default(),
     /// };
     ///
     /// assert_eq!(stats.deleted_percentage(), 20.0);
@@ -520,3 +556,70 @@ impl Default for PackageChangeStats {
         Self::new()
     }
 }
+
+/// Number of changed files of each class.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changes::{FileClass, FileClassCounts};
+///
+/// let mut counts = FileClassCounts::default();
+/// counts.add(FileClass::Source);
+/// counts.add(FileClass::Binary);
+///
+/// assert_eq!(counts.source, 1);
+/// assert_eq!(counts.binary, 1);
+/// assert_eq!(counts.get(FileClass::Test), 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileClassCounts {
+    /// Source files changed.
+    pub source: usize,
+
+    /// Test files changed.
+    pub test: usize,
+
+    /// Documentation files changed.
+    pub docs: usize,
+
+    /// Configuration files changed.
+    pub config: usize,
+
+    /// Generated files changed.
+    pub generated: usize,
+
+    /// Binary files changed.
+    pub binary: usize,
+}
+
+impl FileClassCounts {
+    /// Counts one more changed file of a class.
+    pub fn add(&mut self, class: FileClass) {
+        *self.slot(class) += 1;
+    }
+
+    /// Returns the number of changed files of a class.
+    #[must_use]
+    pub fn get(&self, class: FileClass) -> usize {
+        match class {
+            FileClass::Source => self.source,
+            FileClass::Test => self.test,
+            FileClass::Docs => self.docs,
+            FileClass::Config => self.config,
+            FileClass::Generated => self.generated,
+            FileClass::Binary => self.binary,
+        }
+    }
+
+    fn slot(&mut self, class: FileClass) -> &mut usize {
+        match class {
+            FileClass::Source => &mut self.source,
+            FileClass::Test => &mut self.test,
+            FileClass::Docs => &mut self.docs,
+            FileClass::Config => &mut self.config,
+            FileClass::Generated => &mut self.generated,
+            FileClass::Binary => &mut self.binary,
+        }
+    }
+}
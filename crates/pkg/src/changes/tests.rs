@@ -513,6 +513,324 @@ mod commit_range_tests {
             assert!(package.stats.files_changed > 0, "Package should have file count");
         }
     }
+
+    #[tokio::test]
+    async fn test_analyze_since_last_release_uses_per_package_baselines() {
+        let (_temp, workspace_path, repo) = create_monorepo_with_commits().await;
+
+        // pkg-b is released at the current HEAD; pkg-a has never been released
+        repo.create_tag("@test/pkg-b@1.0.0", None).unwrap();
+        fs::write(workspace_path.join("packages/pkg-a/src/index.js"), "export const a = 100;")
+            .unwrap();
+        repo.add_all().unwrap();
+        repo.commit("fix: tweak pkg-a").unwrap();
+
+        let git_repo = Repo::open(workspace_path.to_str().unwrap()).unwrap();
+        let fs = FileSystemManager::new();
+        let config = PackageToolsConfig::default();
+
+        let analyzer = ChangesAnalyzer::new(workspace_path, git_repo, fs, config).await.unwrap();
+
+        let report = analyzer.analyze_since_last_release("HEAD").await.unwrap();
+
+        assert_eq!(report.analysis_mode, AnalysisMode::CommitRange);
+        assert_eq!(report.base_ref.as_deref(), Some("last-release"));
+        assert!(report.get_package("@test/pkg-a").unwrap().has_changes);
+        assert!(!report.get_package("@test/pkg-b").unwrap().has_changes);
+    }
+}
+
+/// Tests for query-based change analysis.
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::panic)]
+mod query_tests {
+    use crate::changes::{ChangesAnalyzer, ChangesQuery, FileChangeType};
+    use crate::config::PackageToolsConfig;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use sublime_git_tools::Repo;
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    /// Creates a monorepo with `v1.0.0` tagged before changes to both packages.
+    fn create_monorepo() -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "version": "1.0.0", "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+        for name in ["pkg-a", "pkg-b"] {
+            fs::create_dir_all(root.join(format!("packages/{name}/src"))).unwrap();
+            fs::write(
+                root.join(format!("packages/{name}/package.json")),
+                format!(r#"{{"name": "@test/{name}", "version": "1.0.0"}}"#),
+            )
+            .unwrap();
+        }
+
+        let repo = Repo::create(root.to_str().unwrap()).unwrap();
+        repo.config("Test User", "test@example.com").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: initial").unwrap();
+        repo.create_tag("v1.0.0", None).unwrap();
+
+        fs::write(root.join("packages/pkg-a/src/index.ts"), "export const a = 1;").unwrap();
+        fs::write(root.join("packages/pkg-a/README.md"), "# pkg-a").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("feat: add pkg-a").unwrap();
+
+        fs::write(root.join("packages/pkg-b/src/index.ts"), "export const b = 1;").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("feat: add pkg-b").unwrap();
+
+        (temp_dir, root)
+    }
+
+    async fn create_analyzer(root: &Path) -> ChangesAnalyzer {
+        let git_repo = Repo::open(root.to_str().unwrap()).unwrap();
+        ChangesAnalyzer::new(
+            root.to_path_buf(),
+            git_repo,
+            FileSystemManager::new(),
+            PackageToolsConfig::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_matches_path() {
+        let query = ChangesQuery::new();
+        assert!(query.matches_path(Path::new("anything/at/all.rs")));
+
+        let query = ChangesQuery::new().with_paths(["./packages/core/", "**/*.ts"]);
+        assert!(query.matches_path(Path::new("packages/core/package.json")));
+        assert!(query.matches_path(Path::new("packages/cli/src/index.ts")));
+        assert!(!query.matches_path(Path::new("packages/core-utils/package.json")));
+        assert!(!query.matches_path(Path::new("packages/cli/README.md")));
+
+        let query = ChangesQuery::new().with_path("packages/*/README.md");
+        assert!(query.matches_path(Path::new("packages/cli/README.md")));
+        assert!(!query.matches_path(Path::new("packages/cli/docs/README.md")));
+    }
+
+    #[test]
+    fn test_query_defaults() {
+        let query = ChangesQuery::new();
+        assert_eq!(query.from_ref, None);
+        assert_eq!(query.to(), "HEAD");
+        assert!(!query.include_uncommitted);
+
+        let query = ChangesQuery::new().from_ref("v1.0.0").to_ref("main");
+        assert_eq!(query.from_ref.as_deref(), Some("v1.0.0"));
+        assert_eq!(query.to(), "main");
+    }
+
+    #[tokio::test]
+    async fn test_analyze_between_arbitrary_refs() {
+        let (_temp, root) = create_monorepo();
+        let analyzer = create_analyzer(&root).await;
+
+        let query = ChangesQuery::new().from_ref("v1.0.0").to_ref("HEAD~1");
+        let report = analyzer.analyze(&query).await.unwrap();
+
+        assert_eq!(report.base_ref.as_deref(), Some("v1.0.0"));
+        assert_eq!(report.head_ref.as_deref(), Some("HEAD~1"));
+        assert_eq!(report.get_package("@test/pkg-a").unwrap().files.len(), 2);
+        assert!(!report.get_package("@test/pkg-b").unwrap().has_changes);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_with_path_filters() {
+        let (_temp, root) = create_monorepo();
+        let analyzer = create_analyzer(&root).await;
+
+        let query = ChangesQuery::new().from_ref("v1.0.0").with_path("**/*.ts");
+        let report = analyzer.analyze(&query).await.unwrap();
+
+        let pkg_a = report.get_package("@test/pkg-a").unwrap();
+        assert_eq!(pkg_a.files.len(), 1);
+        assert_eq!(pkg_a.stats.files_changed, 1);
+        assert!(!pkg_a.commits.is_empty());
+        assert!(report.get_package("@test/pkg-b").unwrap().has_changes);
+        assert_eq!(report.summary.total_files_changed, 2);
+
+        let query = ChangesQuery::new().from_ref("v1.0.0").with_path("packages/pkg-b");
+        let report = analyzer.analyze(&query).await.unwrap();
+
+        let pkg_a = report.get_package("@test/pkg-a").unwrap();
+        assert!(!pkg_a.has_changes);
+        assert!(pkg_a.commits.is_empty(), "Commits without reported files are dropped");
+        assert_eq!(report.summary.packages_with_changes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_including_uncommitted() {
+        let (_temp, root) = create_monorepo();
+        let analyzer = create_analyzer(&root).await;
+
+        fs::write(root.join("packages/pkg-b/src/extra.ts"), "export const extra = 1;").unwrap();
+        fs::remove_file(root.join("packages/pkg-a/README.md")).unwrap();
+
+        let query = ChangesQuery::new().from_ref("v1.0.0").include_uncommitted(true);
+        let report = analyzer.analyze(&query).await.unwrap();
+
+        let pkg_b = report.get_package("@test/pkg-b").unwrap();
+        assert_eq!(pkg_b.files.len(), 2);
+        assert!(pkg_b.files.iter().any(|file| file.path.ends_with("extra.ts")));
+
+        let pkg_a = report.get_package("@test/pkg-a").unwrap();
+        let readme = pkg_a.files.iter().find(|file| file.path.ends_with("README.md")).unwrap();
+        assert_eq!(readme.change_type, FileChangeType::Deleted);
+        assert!(!readme.commits.is_empty(), "Committed files keep their commits");
+        assert_eq!(pkg_a.stats.files_deleted, 1);
+    }
+
+    #[tokio::test]
+    async fn test_analyze_empty_range() {
+        let (_temp, root) = create_monorepo();
+        let analyzer = create_analyzer(&root).await;
+
+        let query = ChangesQuery::new().from_ref("HEAD");
+        assert!(analyzer.analyze(&query).await.is_err());
+
+        fs::write(root.join("packages/pkg-b/src/extra.ts"), "export const extra = 1;").unwrap();
+        let report = analyzer.analyze(&query.include_uncommitted(true)).await.unwrap();
+
+        assert_eq!(report.summary.packages_with_changes, 1);
+        assert_eq!(report.get_package("@test/pkg-b").unwrap().files.len(), 1);
+    }
+}
+
+/// Tests for attribution of root and shared file changes.
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod root_attribution_tests {
+    use crate::changes::ChangesAnalyzer;
+    use crate::config::{PackageToolsConfig, RootFileRule};
+    use crate::types::WarningKind;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use sublime_git_tools::Repo;
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    /// Creates a monorepo and commits changes to root and shared files after `v1.0.0`.
+    fn create_monorepo() -> (TempDir, PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "version": "1.0.0", "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+        for name in ["pkg-a", "pkg-b"] {
+            fs::create_dir_all(root.join(format!("packages/{name}"))).unwrap();
+            fs::write(
+                root.join(format!("packages/{name}/package.json")),
+                format!(r#"{{"name": "@test/{name}", "version": "1.0.0"}}"#),
+            )
+            .unwrap();
+        }
+
+        let repo = Repo::create(root.to_str().unwrap()).unwrap();
+        repo.config("Test User", "test@example.com").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: initial").unwrap();
+        repo.create_tag("v1.0.0", None).unwrap();
+
+        fs::write(root.join("pnpm-lock.yaml"), "lockfileVersion: '9.0'").unwrap();
+        fs::write(root.join("tsconfig.base.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("shared")).unwrap();
+        fs::write(root.join("shared/types.ts"), "export type Id = string;").unwrap();
+        fs::create_dir_all(root.join("scripts")).unwrap();
+        fs::write(root.join("scripts/build.sh"), "echo build").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: shared setup").unwrap();
+
+        (temp_dir, root)
+    }
+
+    async fn create_analyzer(root: &Path, config: PackageToolsConfig) -> ChangesAnalyzer {
+        let git_repo = Repo::open(root.to_str().unwrap()).unwrap();
+        ChangesAnalyzer::new(root.to_path_buf(), git_repo, FileSystemManager::new(), config)
+            .await
+            .unwrap()
+    }
+
+    fn file_paths(report: &crate::changes::ChangesReport, package: &str) -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> =
+            report.get_package(package).unwrap().files.iter().map(|f| f.path.clone()).collect();
+        paths.sort();
+        paths
+    }
+
+    #[tokio::test]
+    async fn test_default_rules() {
+        let (_temp, root) = create_monorepo();
+        let analyzer = create_analyzer(&root, PackageToolsConfig::default()).await;
+
+        let mut report = analyzer.analyze_commit_range("v1.0.0", "HEAD").await.unwrap();
+
+        // The lockfile is ignored and tsconfig.base.json goes to every package
+        for package in ["@test/pkg-a", "@test/pkg-b"] {
+            assert_eq!(file_paths(&report, package), vec![PathBuf::from("tsconfig.base.json")]);
+            assert_eq!(report.get_package(package).unwrap().commits.len(), 1);
+        }
+
+        let mut unattributed: Vec<PathBuf> =
+            report.unattributed_files.iter().map(|f| f.path.clone()).collect();
+        unattributed.sort();
+        assert_eq!(
+            unattributed,
+            vec![PathBuf::from("scripts/build.sh"), PathBuf::from("shared/types.ts")]
+        );
+
+        // Each unattributed file is reported as a warning, kept in sync when files are dropped
+        let mut warned: Vec<PathBuf> = report
+            .warnings
+            .iter()
+            .filter(|warning| warning.kind == WarningKind::UnattributedFile)
+            .filter_map(|warning| warning.path.clone())
+            .collect();
+        warned.sort();
+        assert_eq!(warned, unattributed);
+
+        report.retain_files(|file| file.path != Path::new("scripts/build.sh"));
+        let warned: Vec<PathBuf> =
+            report.warnings.iter().filter_map(|warning| warning.path.clone()).collect();
+        assert_eq!(warned, vec![PathBuf::from("shared/types.ts")]);
+    }
+
+    #[tokio::test]
+    async fn test_configured_rules() {
+        let (_temp, root) = create_monorepo();
+        let mut config = PackageToolsConfig::default();
+        config.changes.root_files = vec![
+            RootFileRule::packages("shared", ["@test/pkg-a", "@test/missing"]),
+            RootFileRule::ignore("scripts/**"),
+            RootFileRule::all("*.yaml"),
+        ];
+        let analyzer = create_analyzer(&root, config).await;
+
+        let report = analyzer.analyze_commit_range("v1.0.0", "HEAD").await.unwrap();
+
+        assert_eq!(
+            file_paths(&report, "@test/pkg-a"),
+            vec![PathBuf::from("pnpm-lock.yaml"), PathBuf::from("shared/types.ts")]
+        );
+        assert_eq!(file_paths(&report, "@test/pkg-b"), vec![PathBuf::from("pnpm-lock.yaml")]);
+        assert_eq!(report.unattributed_files.len(), 1);
+        assert_eq!(report.unattributed_files[0].path, PathBuf::from("tsconfig.base.json"));
+    }
 }
 
 /// Tests for the PackageMapper functionality.
@@ -521,9 +839,11 @@ mod commit_range_tests {
 #[allow(clippy::expect_used)]
 #[allow(clippy::panic)]
 mod mapping_tests {
-    use crate::changes::mapping::PackageMapper;
+    use crate::changes::mapping::{
+        PackageMapper, ResolvedPath, is_within, relative_to, strip_verbatim_prefix,
+    };
     use crate::error::ChangesError;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
     use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
     use tempfile::TempDir;
     use tokio;
@@ -832,6 +1152,94 @@ mod mapping_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_normalize_path_foreign_separators() {
+        let (_temp, workspace_root) = create_single_package_workspace().await;
+        let fs = FileSystemManager::new();
+        let mapper = PackageMapper::new(workspace_root.clone(), fs);
+
+        let foreign = if cfg!(windows) { "src/index.ts" } else { "src\\index.ts" };
+        let result = mapper.normalize_path(&PathBuf::from(foreign));
+
+        assert_eq!(result.expect("Expected Ok"), PathBuf::from("src").join("index.ts"));
+    }
+
+    #[tokio::test]
+    async fn test_map_file_with_foreign_separators_in_monorepo() {
+        let (_temp, workspace_root) = create_monorepo_workspace().await;
+        let fs = FileSystemManager::new();
+        let mut mapper = PackageMapper::new(workspace_root.clone(), fs);
+
+        let foreign = if cfg!(windows) {
+            "packages/core/src/index.ts"
+        } else {
+            "packages\\core\\src\\index.ts"
+        };
+        let result = mapper.find_package_for_file(&PathBuf::from(foreign)).await;
+
+        assert_eq!(result.expect("Expected Ok"), Some("@test/core".to_string()));
+    }
+
+    #[test]
+    fn test_relative_to_normalizes_separators() {
+        let base = PathBuf::from("repo").join("packages");
+        let path = if cfg!(windows) {
+            PathBuf::from("repo/packages/core/index.ts")
+        } else {
+            PathBuf::from("repo\\packages\\core\\index.ts")
+        };
+
+        assert_eq!(relative_to(&path, &base), Some(PathBuf::from("core").join("index.ts")));
+        assert_eq!(relative_to(&PathBuf::from("repo").join("other"), &base), None);
+    }
+
+    #[test]
+    fn test_relative_to_requires_whole_components() {
+        let base = PathBuf::from("packages").join("core");
+        let sibling = PathBuf::from("packages").join("core-utils").join("index.ts");
+
+        assert_eq!(relative_to(&sibling, &base), None);
+        assert!(!is_within(&ResolvedPath::new(sibling), &ResolvedPath::new(base)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_within_resolves_symlinked_roots() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let real_root = temp_dir.path().join("real");
+        std::fs::create_dir_all(real_root.join("src")).expect("Failed to create package dir");
+        std::fs::write(real_root.join("src").join("index.ts"), "").expect("Failed to write file");
+        let linked_root = temp_dir.path().join("linked");
+        std::os::unix::fs::symlink(&real_root, &linked_root).expect("Failed to create symlink");
+
+        let root = ResolvedPath::new(real_root.clone());
+        let file = ResolvedPath::new(linked_root.join("src").join("index.ts"));
+        let outside = ResolvedPath::new(temp_dir.path().join("other.ts"));
+
+        assert!(is_within(&file, &root));
+        assert!(!is_within(&outside, &root));
+        assert_eq!(file.path(), linked_root.join("src").join("index.ts"));
+    }
+
+    #[test]
+    fn test_strip_verbatim_prefix() {
+        assert_eq!(strip_verbatim_prefix(Path::new(r"\\?\C:\repo")), PathBuf::from(r"C:\repo"));
+        assert_eq!(
+            strip_verbatim_prefix(Path::new(r"\\?\UNC\server\share")),
+            PathBuf::from(r"\\server\share")
+        );
+        assert_eq!(strip_verbatim_prefix(Path::new("plain/path")), PathBuf::from("plain/path"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_relative_to_windows_drive_case_and_verbatim_root() {
+        let base = PathBuf::from(r"\\?\C:\Repo\packages\core");
+        let path = PathBuf::from(r"c:\repo\Packages\core\src\index.ts");
+
+        assert_eq!(relative_to(&path, &base), Some(PathBuf::from(r"src\index.ts")));
+    }
+
     #[tokio::test]
     async fn test_empty_file_list() {
         let (_temp, workspace_root) = create_single_package_workspace().await;
@@ -845,6 +1253,108 @@ mod mapping_tests {
         let package_files = result.expect("Expected Ok result");
         assert!(package_files.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_monorepo_map_files_skips_pkgignore_matches() {
+        let (_temp, workspace_root) = create_monorepo_workspace().await;
+        let fs = FileSystemManager::new();
+        fs.write_file_string(
+            &workspace_root.join("packages/core/.pkgignore"),
+            "# test-only changes\n**/*.test.ts\n\ndocs/\n",
+        )
+        .await
+        .expect("Failed to write .pkgignore");
+        let mut mapper = PackageMapper::new(workspace_root.clone(), fs);
+
+        let files = vec![
+            PathBuf::from("packages/core/src/index.test.ts"),
+            PathBuf::from("packages/core/docs/guide.md"),
+            PathBuf::from("packages/utils/src/helper.test.ts"),
+        ];
+
+        let package_files =
+            mapper.map_files_to_packages(&files).await.expect("Failed to map files");
+        assert!(!package_files.contains_key("@test/core"));
+        assert_eq!(package_files["@test/utils"].len(), 1);
+
+        assert!(
+            !mapper
+                .is_ignored("@test/core", Path::new("packages/core/src/index.ts"))
+                .await
+                .expect("Failed to check ignore rules")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_single_package_ignore_changes_from_package_json() {
+        let (_temp, workspace_root) = create_single_package_workspace().await;
+        let fs = FileSystemManager::new();
+        let package_json = serde_json::json!({
+            "name": "test-package",
+            "version": "1.0.0",
+            "packageTools": { "ignoreChanges": ["**/*.spec.ts", "README.md"] }
+        });
+        fs.write_file_string(
+            &workspace_root.join("package.json"),
+            &serde_json::to_string_pretty(&package_json).expect("Failed to serialize"),
+        )
+        .await
+        .expect("Failed to write package.json");
+        let mut mapper = PackageMapper::new(workspace_root.clone(), fs);
+
+        let files = vec![
+            PathBuf::from("src/index.spec.ts"),
+            PathBuf::from("README.md"),
+            PathBuf::from("src/index.ts"),
+        ];
+
+        let package_files =
+            mapper.map_files_to_packages(&files).await.expect("Failed to map files");
+        assert_eq!(package_files["test-package"], vec![PathBuf::from("src/index.ts")]);
+    }
+}
+
+// ============================================================================
+// Package Ignore Rules Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod ignore_tests {
+    use crate::changes::PackageIgnore;
+    use crate::error::ChangesError;
+    use std::path::Path;
+
+    #[test]
+    fn test_from_rules_globs_and_prefixes() {
+        let ignore =
+            PackageIgnore::from_rules(["# comment", "", "**/*.test.ts", "./docs/", "*.md"])
+                .expect("Failed to build rules");
+
+        assert!(ignore.is_ignored(Path::new("index.test.ts")));
+        assert!(ignore.is_ignored(Path::new("src/deep/util.test.ts")));
+        assert!(ignore.is_ignored(Path::new("docs")));
+        assert!(ignore.is_ignored(Path::new("docs/api/index.md")));
+        assert!(ignore.is_ignored(Path::new("README.md")));
+        assert!(!ignore.is_ignored(Path::new("src/README.md")));
+        assert!(!ignore.is_ignored(Path::new("docsite/index.html")));
+        assert!(!ignore.is_ignored(Path::new("src/index.ts")));
+    }
+
+    #[test]
+    fn test_empty_rules_ignore_nothing() {
+        let ignore = PackageIgnore::from_rules(["", "# only comments"]).expect("Failed to build");
+
+        assert!(ignore.is_empty());
+        assert!(!ignore.is_ignored(Path::new("src/index.ts")));
+    }
+
+    #[test]
+    fn test_invalid_glob_is_rejected() {
+        let result = PackageIgnore::from_rules(["src/[.ts"]);
+
+        assert!(matches!(result, Err(ChangesError::PatternError { .. })));
+    }
 }
 
 // ============================================================================
@@ -1713,7 +2223,8 @@ mod version_preview_tests {
 #[allow(clippy::unwrap_used)]
 mod stats_tests {
     use crate::changes::{
-        ChangesSummary, FileChange, FileChangeType, PackageChangeStats, PackageChanges,
+        ChangesSummary, FileChange, FileChangeType, FileClass, FileClassCounts, PackageChangeStats,
+        PackageChanges,
     };
     use std::path::PathBuf;
     use sublime_standard_tools::monorepo::WorkspacePackage;
@@ -1762,6 +2273,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 150,
             lines_deleted: 30,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.net_lines_changed(), 120);
@@ -1777,6 +2289,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 30,
             lines_deleted: 150,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.net_lines_changed(), -120);
@@ -1792,6 +2305,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 150,
             lines_deleted: 30,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.total_lines_changed(), 180);
@@ -1807,6 +2321,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 150,
             lines_deleted: 30,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.average_lines_per_file(), 36.0);
@@ -1828,6 +2343,7 @@ mod stats_tests {
             commits: 5,
             lines_added: 100,
             lines_deleted: 20,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.added_percentage(), 20.0);
@@ -1959,6 +2475,7 @@ mod stats_tests {
             lines_added: Some(50),
             lines_deleted: Some(0),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 1);
@@ -1977,6 +2494,7 @@ mod stats_tests {
             lines_added: Some(30),
             lines_deleted: Some(10),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 2);
@@ -1994,6 +2512,7 @@ mod stats_tests {
             lines_added: Some(0),
             lines_deleted: Some(20),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 3);
@@ -2016,6 +2535,7 @@ mod stats_tests {
             lines_added: None,
             lines_deleted: None,
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 1);
@@ -2024,6 +2544,49 @@ mod stats_tests {
         assert_eq!(changes.stats.lines_deleted, 0);
     }
 
+    #[test]
+    fn test_package_changes_counts_file_classes() {
+        let workspace_pkg = create_test_workspace_package("core");
+        let mut changes = PackageChanges::new(workspace_pkg);
+
+        for (path, class) in
+            [("package-lock.json", FileClass::Generated), ("assets/logo.png", FileClass::Binary)]
+        {
+            let mut file = FileChange::new(
+                PathBuf::from("packages/core").join(path),
+                PathBuf::from(path),
+                FileChangeType::Modified,
+            );
+            file.class = class;
+            changes.add_file(file);
+        }
+
+        assert_eq!(changes.stats.classes.generated, 1);
+        assert_eq!(changes.stats.classes.binary, 1);
+        assert!(changes.has_changes);
+        assert!(!changes.has_meaningful_changes());
+        assert!(!changes.stats.has_meaningful_changes());
+
+        let mut file = FileChange::new(
+            PathBuf::from("packages/core/src/index.test.ts"),
+            PathBuf::from("src/index.test.ts"),
+            FileChangeType::Added,
+        );
+        file.class = FileClass::Test;
+        changes.add_file(file);
+
+        assert_eq!(changes.stats.classes.get(FileClass::Test), 1);
+        assert_eq!(changes.files_by_class(FileClass::Test).len(), 1);
+        assert!(changes.has_meaningful_changes());
+        assert!(changes.stats.has_meaningful_changes());
+
+        changes.recalculate_stats();
+        assert_eq!(
+            changes.stats.classes,
+            FileClassCounts { test: 1, generated: 1, binary: 1, ..FileClassCounts::default() }
+        );
+    }
+
     #[test]
     fn test_package_changes_add_file_renamed() {
         let workspace_pkg = create_test_workspace_package("core");
@@ -2036,6 +2599,7 @@ mod stats_tests {
             lines_added: Some(5),
             lines_deleted: Some(3),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 1);
@@ -2058,6 +2622,7 @@ mod stats_tests {
             lines_added: Some(50),
             lines_deleted: Some(0),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 1);
@@ -2078,6 +2643,7 @@ mod stats_tests {
             lines_added: Some(25),
             lines_deleted: Some(0),
             commits: Vec::new(),
+            class: FileClass::Source,
         });
 
         assert_eq!(changes.stats.files_changed, 1);
@@ -2141,6 +2707,7 @@ mod stats_tests {
             commits: 10,
             lines_added: 500,
             lines_deleted: 200,
+            classes: FileClassCounts::default(),
         };
 
         // Test all calculations
@@ -2172,6 +2739,7 @@ mod stats_tests {
             commits: 2,
             lines_added: 0,
             lines_deleted: 150,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.deleted_percentage(), 100.0);
@@ -2190,6 +2758,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 200,
             lines_deleted: 0,
+            classes: FileClassCounts::default(),
         };
 
         assert_eq!(stats.added_percentage(), 100.0);
@@ -2248,6 +2817,7 @@ mod stats_tests {
             commits: 3,
             lines_added: 150,
             lines_deleted: 30,
+            classes: FileClassCounts::default(),
         };
 
         // Test serialization
@@ -2261,6 +2831,12 @@ mod stats_tests {
         assert_eq!(deserialized.files_changed, 5);
         assert_eq!(deserialized.files_added, 2);
         assert_eq!(deserialized.lines_added, 150);
+
+        // Stats written before file classes existed still load
+        let legacy = r#"{"files_changed":1,"files_added":1,"files_modified":0,"files_deleted":0,
+            "commits":1,"lines_added":3,"lines_deleted":0}"#;
+        let deserialized: PackageChangeStats = serde_json::from_str(legacy).unwrap();
+        assert_eq!(deserialized.classes, FileClassCounts::default());
     }
 
     #[test]
@@ -2288,3 +2864,362 @@ mod stats_tests {
         assert_eq!(deserialized.packages_with_changes, 3);
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod workspace_diff_tests {
+    use crate::changes::workspace_diff::PackageManifest;
+    use crate::changes::{ChangesAnalyzer, DependencyChangeKind, WorkspaceDiff, WorkspaceState};
+    use crate::config::PackageToolsConfig;
+    use crate::types::DependencyType;
+    use std::fs;
+    use std::path::PathBuf;
+    use sublime_git_tools::Repo;
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    fn manifest(content: &str, path: &str) -> PackageManifest {
+        PackageManifest::parse(content, PathBuf::from(path), None).unwrap().unwrap()
+    }
+
+    /// Creates a monorepo with `core` and `utils`, committed and tagged as `v1.0.0`.
+    fn create_monorepo() -> (TempDir, PathBuf, Repo) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "version": "1.0.0", "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages/core")).unwrap();
+        fs::write(
+            root.join("packages/core/package.json"),
+            r#"{"name": "@test/core", "version": "1.0.0", "dependencies": {"lodash": "^4.0.0"}}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("packages/utils")).unwrap();
+        fs::write(
+            root.join("packages/utils/package.json"),
+            r#"{"name": "@test/utils", "version": "1.0.0"}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("fixtures/sample")).unwrap();
+        fs::write(
+            root.join("fixtures/sample/package.json"),
+            r#"{"name": "fixture", "version": "0.0.1"}"#,
+        )
+        .unwrap();
+        fs::write(root.join("package-lock.json"), "{}").unwrap();
+
+        let repo = Repo::create(root.to_str().unwrap()).unwrap();
+        repo.config("Test User", "test@example.com").unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: initial").unwrap();
+        repo.create_tag("v1.0.0", None).unwrap();
+
+        (temp_dir, root, repo)
+    }
+
+    /// Bumps `core`, changes its dependencies, removes `utils` and adds `cli`.
+    fn evolve_monorepo(root: &std::path::Path) {
+        fs::write(
+            root.join("packages/core/package.json"),
+            r#"{"name": "@test/core", "version": "1.1.0",
+                "dependencies": {"lodash": "^4.17.0"},
+                "devDependencies": {"vitest": "^1.0.0"}}"#,
+        )
+        .unwrap();
+        fs::remove_dir_all(root.join("packages/utils")).unwrap();
+        fs::create_dir_all(root.join("packages/cli")).unwrap();
+        fs::write(
+            root.join("packages/cli/package.json"),
+            r#"{"name": "@test/cli", "version": "0.1.0", "dependencies": {"@test/core": "workspace:*"}}"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_compare_reports_added_removed_and_changed_packages() {
+        let from = vec![
+            manifest(r#"{"name": "a", "version": "1.0.0", "dependencies": {"x": "^1.0.0"}}"#, "a"),
+            manifest(r#"{"name": "b", "version": "1.0.0"}"#, "b"),
+            manifest(r#"{"name": "same", "version": "1.0.0"}"#, "same"),
+        ];
+        let to = vec![
+            manifest(
+                r#"{"name": "a", "version": "2.0.0", "dependencies": {"y": "^1.0.0"},
+                    "peerDependencies": {"x": "^1.0.0"}}"#,
+                "a",
+            ),
+            manifest(r#"{"name": "c", "version": "0.1.0"}"#, "c"),
+            manifest(r#"{"name": "same", "version": "1.0.0"}"#, "same"),
+        ];
+
+        let diff = WorkspaceDiff::compare(
+            WorkspaceState::git_ref("main"),
+            WorkspaceState::WorkingDirectory,
+            from,
+            to,
+        );
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.added_packages.len(), 1);
+        assert_eq!(diff.added_packages[0].name, "c");
+        assert_eq!(diff.removed_packages.len(), 1);
+        assert_eq!(diff.removed_packages[0].name, "b");
+        assert_eq!(diff.changed_packages.len(), 1);
+        assert!(diff.changed_package("same").is_none());
+
+        let a = diff.changed_package("a").unwrap();
+        let version = a.version_change.as_ref().unwrap();
+        assert_eq!((version.from.as_str(), version.to.as_str()), ("1.0.0", "2.0.0"));
+
+        let changes: Vec<_> = a
+            .dependency_changes
+            .iter()
+            .map(|c| (c.name.as_str(), c.dependency_type, c.kind))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("x", DependencyType::Regular, DependencyChangeKind::Removed),
+                ("y", DependencyType::Regular, DependencyChangeKind::Added),
+                ("x", DependencyType::Peer, DependencyChangeKind::Added),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_reports_spec_changes() {
+        let from = vec![manifest(r#"{"name": "a", "devDependencies": {"x": "^1.0.0"}}"#, "a")];
+        let to = vec![manifest(r#"{"name": "a", "devDependencies": {"x": "^2.0.0"}}"#, "a")];
+
+        let diff = WorkspaceDiff::compare(
+            WorkspaceState::git_ref("HEAD~1"),
+            WorkspaceState::git_ref("HEAD"),
+            from,
+            to,
+        );
+
+        let change = &diff.changed_package("a").unwrap().dependency_changes[0];
+        assert_eq!(change.kind, DependencyChangeKind::Updated);
+        assert_eq!(change.dependency_type, DependencyType::Dev);
+        assert_eq!(change.old_spec.as_deref(), Some("^1.0.0"));
+        assert_eq!(change.new_spec.as_deref(), Some("^2.0.0"));
+        assert!(diff.changed_package("a").unwrap().version_change.is_none());
+    }
+
+    #[test]
+    fn test_workspace_diff_serializes_states_and_kinds() {
+        let from = vec![manifest(r#"{"name": "a", "version": "1.0.0"}"#, "a")];
+        let to =
+            vec![manifest(r#"{"name": "a", "version": "1.0.0", "dependencies": {"x": "1"}}"#, "a")];
+        let diff = WorkspaceDiff::compare(
+            WorkspaceState::git_ref("v1.0.0"),
+            WorkspaceState::WorkingDirectory,
+            from,
+            to,
+        );
+
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["from"]["git_ref"], "v1.0.0");
+        assert_eq!(json["to"], "working_directory");
+        assert_eq!(json["changed_packages"][0]["dependency_changes"][0]["kind"], "added");
+    }
+
+    #[tokio::test]
+    async fn test_diff_workspace_between_refs() {
+        let (_temp, root, repo) = create_monorepo();
+        evolve_monorepo(&root);
+        repo.add_all().unwrap();
+        repo.commit("feat: evolve packages").unwrap();
+
+        let analyzer = ChangesAnalyzer::new(
+            root,
+            repo,
+            FileSystemManager::new(),
+            PackageToolsConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let diff = analyzer
+            .diff_workspace(&WorkspaceState::git_ref("v1.0.0"), &WorkspaceState::git_ref("HEAD"))
+            .await
+            .unwrap();
+
+        let added: Vec<_> = diff.added_packages.iter().map(|p| p.name.as_str()).collect();
+        let removed: Vec<_> = diff.removed_packages.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(added, vec!["@test/cli"]);
+        assert_eq!(removed, vec!["@test/utils"]);
+        assert_eq!(diff.removed_packages[0].path, PathBuf::from("packages/utils"));
+
+        let core = diff.changed_package("@test/core").unwrap();
+        assert_eq!(core.version_change.as_ref().unwrap().to, "1.1.0");
+        assert_eq!(core.dependency_changes.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_diff_workspace_against_working_directory() {
+        let (_temp, root, repo) = create_monorepo();
+        evolve_monorepo(&root);
+
+        let analyzer = ChangesAnalyzer::new(
+            root,
+            repo,
+            FileSystemManager::new(),
+            PackageToolsConfig::default(),
+        )
+        .await
+        .unwrap();
+
+        let diff = analyzer
+            .diff_workspace(&WorkspaceState::git_ref("v1.0.0"), &WorkspaceState::WorkingDirectory)
+            .await
+            .unwrap();
+
+        assert_eq!(diff.added_packages.len(), 1);
+        assert_eq!(diff.removed_packages.len(), 1);
+        assert!(diff.changed_package("@test/core").is_some());
+
+        let unchanged = analyzer
+            .diff_workspace(&WorkspaceState::git_ref("HEAD"), &WorkspaceState::git_ref("v1.0.0"))
+            .await
+            .unwrap();
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_manifests_at_ref_expand_brace_and_negated_patterns() {
+        use crate::changes::workspace_diff::manifests_at_ref;
+
+        let (_temp, root, repo) = create_monorepo();
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "workspaces": ["{packages,fixtures}/*", "!packages/utils"]}"#,
+        )
+        .unwrap();
+        repo.add_all().unwrap();
+        repo.commit("chore: brace workspaces").unwrap();
+
+        let mut names: Vec<_> = manifests_at_ref(&repo, "HEAD", "", &[])
+            .unwrap()
+            .into_iter()
+            .map(|manifest| manifest.name)
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["@test/core", "fixture"]);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod submodule_lfs_tests {
+    use crate::changes::{ChangesAnalyzer, FileChangeType};
+    use crate::config::PackageToolsConfig;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+    use sublime_git_tools::Repo;
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    const LFS_POINTER: &str = "version https://git-lfs.github.com/spec/v1\n\
+        oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+        size 12345\n";
+
+    /// Creates a monorepo with two packages and returns its first commit.
+    fn create_monorepo() -> (TempDir, PathBuf, Repo, String) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path().to_path_buf();
+
+        fs::write(
+            root.join("package.json"),
+            r#"{"name": "root", "version": "1.0.0", "workspaces": ["packages/*"]}"#,
+        )
+        .unwrap();
+        fs::write(root.join("package-lock.json"), "{}").unwrap();
+        for name in ["pkg-a", "pkg-b"] {
+            fs::create_dir_all(root.join("packages").join(name)).unwrap();
+            fs::write(
+                root.join("packages").join(name).join("package.json"),
+                format!(r#"{{"name": "@test/{name}", "version": "1.0.0"}}"#),
+            )
+            .unwrap();
+        }
+        fs::write(root.join(".gitattributes"), "*.png filter=lfs diff=lfs merge=lfs -text\n")
+            .unwrap();
+
+        let repo = Repo::create(root.to_str().unwrap()).unwrap();
+        repo.config("Test User", "test@example.com").unwrap();
+        let first = repo.add_all().unwrap().commit("chore: initial").unwrap();
+
+        (temp_dir, root, repo, first)
+    }
+
+    fn git(root: &Path, args: &[&str]) {
+        let output = Command::new("git").args(args).current_dir(root).output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    async fn analyzer(root: &Path) -> ChangesAnalyzer {
+        let git_repo = Repo::open(root.to_str().unwrap()).unwrap();
+        ChangesAnalyzer::new(
+            root.to_path_buf(),
+            git_repo,
+            FileSystemManager::new(),
+            PackageToolsConfig::default(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_submodule_bump_is_attributed_to_containing_package() {
+        let (_temp, root, _repo, first) = create_monorepo();
+
+        // Record a gitlink inside pkg-a, as `git submodule add` would
+        git(
+            &root,
+            &[
+                "update-index",
+                "--add",
+                "--cacheinfo",
+                &format!("160000,{first},packages/pkg-a/vendor/lib"),
+            ],
+        );
+        git(&root, &["commit", "-m", "chore: add vendored lib"]);
+
+        let report = analyzer(&root).await.analyze_commit_range(&first, "HEAD").await.unwrap();
+
+        let pkg_a = report.get_package("@test/pkg-a").unwrap();
+        assert_eq!(pkg_a.files.len(), 1);
+        assert_eq!(pkg_a.files[0].change_type, FileChangeType::Submodule);
+        assert_eq!(pkg_a.stats.files_modified, 1);
+        assert!(!report.get_package("@test/pkg-b").unwrap().has_changes);
+    }
+
+    #[tokio::test]
+    async fn test_lfs_files_skip_line_statistics() {
+        let (_temp, root, repo, _first) = create_monorepo();
+
+        fs::write(root.join("packages/pkg-b/logo.png"), LFS_POINTER).unwrap();
+        fs::write(root.join("packages/pkg-b/index.js"), "export const b = 1;\n").unwrap();
+        repo.add_all().unwrap().commit("feat: add logo").unwrap();
+
+        fs::write(root.join("packages/pkg-b/logo.png"), "not really a png\nbut text\n").unwrap();
+        fs::write(root.join("packages/pkg-b/index.js"), "export const b = 2;\n").unwrap();
+
+        let report = analyzer(&root).await.analyze_working_directory().await.unwrap();
+
+        let pkg_b = report.get_package("@test/pkg-b").unwrap();
+        let logo = pkg_b.files.iter().find(|f| f.path.ends_with("logo.png")).unwrap();
+        assert_eq!(logo.change_type, FileChangeType::Modified);
+        assert_eq!(logo.lines_added, None);
+        assert_eq!(logo.lines_deleted, None);
+
+        let index = pkg_b.files.iter().find(|f| f.path.ends_with("index.js")).unwrap();
+        assert_eq!(index.lines_added, Some(1));
+    }
+}
@@ -0,0 +1,431 @@
+//! Package-level diff between two states of a workspace.
+//!
+//! **What**: Provides `WorkspaceDiff`, which describes how the packages of a workspace differ
+//! between two states: packages added or removed, version changes, and dependency additions,
+//! removals and spec changes per package.
+//!
+//! **How**: Each state (a Git reference or the working directory) is loaded into a list of
+//! package manifests. At a Git reference the manifests are read from the commit tree, using the
+//! workspace patterns declared at that reference; the working directory uses the monorepo
+//! detector. The two lists are then compared by package name.
+//!
+//! **Why**: Release tooling, reviews and bindings need to know what a range of commits did to
+//! the workspace structure, not just which files changed. A typed, serializable diff avoids
+//! every consumer re-parsing `package.json` files at two points in history.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::changes::{ChangesAnalyzer, WorkspaceState};
+//!
+//! let diff = analyzer
+//!     .diff_workspace(&WorkspaceState::git_ref("v1.0.0"), &WorkspaceState::WorkingDirectory)
+//!     .await?;
+//!
+//! for package in &diff.added_packages {
+//!     println!("+ {}@{}", package.name, package.version);
+//! }
+//! for package in &diff.changed_packages {
+//!     if let Some(change) = &package.version_change {
+//!         println!("~ {}: {} -> {}", package.name, change.from, change.to);
+//!     }
+//! }
+//! ```
+
+use crate::error::{ChangesError, ChangesResult};
+use crate::types::DependencyType;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use sublime_git_tools::Repo;
+use sublime_standard_tools::monorepo::WorkspacePatterns;
+
+/// Dependency sections compared by the diff, in report order.
+const DEPENDENCY_SECTIONS: [DependencyType; 4] =
+    [DependencyType::Regular, DependencyType::Dev, DependencyType::Peer, DependencyType::Optional];
+
+/// A state of the workspace that can be compared.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceState {
+    /// The workspace as committed at a Git reference (branch, tag or commit SHA).
+    GitRef(String),
+
+    /// The workspace as it is on disk, including uncommitted changes.
+    WorkingDirectory,
+}
+
+impl WorkspaceState {
+    /// Creates a state for a Git reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changes::WorkspaceState;
+    ///
+    /// let state = WorkspaceState::git_ref("main");
+    /// assert_eq!(state.to_string(), "main");
+    /// ```
+    #[must_use]
+    pub fn git_ref(git_ref: impl Into<String>) -> Self {
+        Self::GitRef(git_ref.into())
+    }
+}
+
+impl fmt::Display for WorkspaceState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::GitRef(git_ref) => write!(f, "{git_ref}"),
+            Self::WorkingDirectory => write!(f, "working-directory"),
+        }
+    }
+}
+
+/// Package-level differences between two workspace states.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceDiff {
+    /// The state the diff starts from.
+    pub from: WorkspaceState,
+
+    /// The state the diff ends at.
+    pub to: WorkspaceState,
+
+    /// Packages present in `to` but not in `from`, sorted by name.
+    pub added_packages: Vec<PackageSummary>,
+
+    /// Packages present in `from` but not in `to`, sorted by name.
+    pub removed_packages: Vec<PackageSummary>,
+
+    /// Packages present in both states whose version or dependencies changed, sorted by name.
+    pub changed_packages: Vec<PackageDiff>,
+}
+
+impl WorkspaceDiff {
+    /// Compares two lists of package manifests.
+    pub(crate) fn compare(
+        from: WorkspaceState,
+        to: WorkspaceState,
+        from_packages: Vec<PackageManifest>,
+        to_packages: Vec<PackageManifest>,
+    ) -> Self {
+        let mut before: BTreeMap<String, PackageManifest> =
+            from_packages.into_iter().map(|p| (p.name.clone(), p)).collect();
+        let mut added_packages = Vec::new();
+        let mut changed_packages = Vec::new();
+
+        let after: BTreeMap<String, PackageManifest> =
+            to_packages.into_iter().map(|p| (p.name.clone(), p)).collect();
+        for (name, new) in after {
+            match before.remove(&name) {
+                Some(old) => {
+                    let diff = PackageDiff::between(&old, new);
+                    if diff.has_changes() {
+                        changed_packages.push(diff);
+                    }
+                }
+                None => added_packages.push(PackageSummary::from(new)),
+            }
+        }
+        let removed_packages = before.into_values().map(PackageSummary::from).collect();
+
+        Self { from, to, added_packages, removed_packages, changed_packages }
+    }
+
+    /// Returns `true` if the two states have the same packages, versions and dependencies.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_packages.is_empty()
+            && self.removed_packages.is_empty()
+            && self.changed_packages.is_empty()
+    }
+
+    /// Returns the changes of a package present in both states, if it changed.
+    #[must_use]
+    pub fn changed_package(&self, name: &str) -> Option<&PackageDiff> {
+        self.changed_packages.iter().find(|p| p.name == name)
+    }
+}
+
+/// A package added to or removed from the workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageSummary {
+    /// Package name.
+    pub name: String,
+
+    /// Package version in the state the package exists in.
+    pub version: String,
+
+    /// Package directory relative to the workspace root.
+    pub path: PathBuf,
+}
+
+impl From<PackageManifest> for PackageSummary {
+    fn from(manifest: PackageManifest) -> Self {
+        Self { name: manifest.name, version: manifest.version, path: manifest.path }
+    }
+}
+
+/// Changes to a package present in both states.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageDiff {
+    /// Package name.
+    pub name: String,
+
+    /// Package directory relative to the workspace root, in the `to` state.
+    pub path: PathBuf,
+
+    /// The version change, if the version differs.
+    pub version_change: Option<VersionChange>,
+
+    /// Dependency changes, grouped by dependency type and sorted by name.
+    pub dependency_changes: Vec<DependencyChange>,
+}
+
+impl PackageDiff {
+    /// Compares two manifests of the same package.
+    fn between(old: &PackageManifest, new: PackageManifest) -> Self {
+        let version_change = (old.version != new.version)
+            .then(|| VersionChange { from: old.version.clone(), to: new.version.clone() });
+
+        let mut dependency_changes = Vec::new();
+        for dependency_type in DEPENDENCY_SECTIONS {
+            let empty = BTreeMap::new();
+            let before = old.dependencies.get(&dependency_type).unwrap_or(&empty);
+            let after = new.dependencies.get(&dependency_type).unwrap_or(&empty);
+
+            let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+            names.sort();
+            names.dedup();
+
+            for name in names {
+                let change = match (before.get(name), after.get(name)) {
+                    (None, Some(spec)) => DependencyChange {
+                        name: name.clone(),
+                        dependency_type,
+                        kind: DependencyChangeKind::Added,
+                        old_spec: None,
+                        new_spec: Some(spec.clone()),
+                    },
+                    (Some(spec), None) => DependencyChange {
+                        name: name.clone(),
+                        dependency_type,
+                        kind: DependencyChangeKind::Removed,
+                        old_spec: Some(spec.clone()),
+                        new_spec: None,
+                    },
+                    (Some(old_spec), Some(new_spec)) if old_spec != new_spec => DependencyChange {
+                        name: name.clone(),
+                        dependency_type,
+                        kind: DependencyChangeKind::Updated,
+                        old_spec: Some(old_spec.clone()),
+                        new_spec: Some(new_spec.clone()),
+                    },
+                    _ => continue,
+                };
+                dependency_changes.push(change);
+            }
+        }
+
+        Self { name: new.name, path: new.path, version_change, dependency_changes }
+    }
+
+    /// Returns `true` if the version or any dependency changed.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.version_change.is_some() || !self.dependency_changes.is_empty()
+    }
+}
+
+/// A package version change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionChange {
+    /// Version in the `from` state.
+    pub from: String,
+
+    /// Version in the `to` state.
+    pub to: String,
+}
+
+/// How a dependency changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyChangeKind {
+    /// The dependency was added.
+    Added,
+
+    /// The dependency was removed.
+    Removed,
+
+    /// The version spec of the dependency changed.
+    Updated,
+}
+
+/// A change to a single dependency of a package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyChange {
+    /// Dependency name.
+    pub name: String,
+
+    /// The `package.json` section the dependency is declared in.
+    pub dependency_type: DependencyType,
+
+    /// How the dependency changed.
+    pub kind: DependencyChangeKind,
+
+    /// Version spec in the `from` state, `None` when added.
+    pub old_spec: Option<String>,
+
+    /// Version spec in the `to` state, `None` when removed.
+    pub new_spec: Option<String>,
+}
+
+/// The parts of a `package.json` the diff compares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackageManifest {
+    /// Package name.
+    pub(crate) name: String,
+
+    /// Package version, `0.0.0` when missing.
+    pub(crate) version: String,
+
+    /// Package directory relative to the workspace root.
+    pub(crate) path: PathBuf,
+
+    /// Dependency specs by section and name.
+    pub(crate) dependencies: BTreeMap<DependencyType, BTreeMap<String, String>>,
+}
+
+impl PackageManifest {
+    /// Parses a manifest from `package.json` content.
+    ///
+    /// Returns `Ok(None)` when the manifest has no name and `default_name` is `None`.
+    pub(crate) fn parse(
+        content: &str,
+        path: PathBuf,
+        default_name: Option<&str>,
+    ) -> ChangesResult<Option<Self>> {
+        let json: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| ChangesError::PackageJsonParseError {
+                path: path.join("package.json"),
+                reason: e.to_string(),
+            })?;
+
+        let Some(name) = json["name"].as_str().or(default_name) else {
+            return Ok(None);
+        };
+        let version = json["version"].as_str().unwrap_or("0.0.0").to_string();
+
+        let dependencies = DEPENDENCY_SECTIONS
+            .into_iter()
+            .filter_map(|dependency_type| {
+                let section = json.get(dependency_type.as_str())?.as_object()?;
+                let specs = section
+                    .iter()
+                    .filter_map(|(name, spec)| Some((name.clone(), spec.as_str()?.to_string())))
+                    .collect();
+                Some((dependency_type, specs))
+            })
+            .collect();
+
+        Ok(Some(Self { name: name.to_string(), version, path, dependencies }))
+    }
+}
+
+/// Loads the package manifests of a workspace as committed at a Git reference.
+///
+/// `prefix` is the workspace root relative to the repository root, using `/` and without a
+/// trailing separator (empty when they are the same). Monorepos are recognised from the same
+/// files as the monorepo detector (workspace manifests, or `workspaces` plus a lock file), and
+/// their packages are the directories matching the declared patterns or `config_patterns`. Otherwise
+/// the root package is the only package. A reference where the workspace root has no
+/// `package.json` has no packages.
+///
+/// # Errors
+///
+/// Returns `ChangesError::GitError` if the reference cannot be read and
+/// `ChangesError::PackageJsonParseError` if a manifest is not valid JSON.
+pub(crate) fn manifests_at_ref(
+    repo: &Repo,
+    git_ref: &str,
+    prefix: &str,
+    config_patterns: &[String],
+) -> ChangesResult<Vec<PackageManifest>> {
+    let git_error = |operation: &str, e: &sublime_git_tools::RepoError| ChangesError::GitError {
+        operation: operation.to_string(),
+        reason: format!("Failed to read '{git_ref}': {}", e.as_ref()),
+    };
+    let repo_path = |relative: &str| {
+        if prefix.is_empty() { relative.to_string() } else { format!("{prefix}/{relative}") }
+    };
+
+    let Some(root_content) = repo
+        .get_file_content_at_ref(git_ref, &repo_path("package.json"))
+        .map_err(|e| git_error("get_file_content_at_ref", &e))?
+    else {
+        return Ok(Vec::new());
+    };
+    let root_json: serde_json::Value =
+        serde_json::from_str(&root_content).map_err(|e| ChangesError::PackageJsonParseError {
+            path: PathBuf::from("package.json"),
+            reason: e.to_string(),
+        })?;
+
+    let files = repo.get_files_at_ref(git_ref).map_err(|e| git_error("get_files_at_ref", &e))?;
+    let declared = workspace_patterns(&root_json);
+    let has_file = |name: &str| files.iter().any(|f| *f == repo_path(name));
+    let is_monorepo = ["pnpm-workspace.yaml", "bun.lockb", "deno.json"].iter().any(|f| has_file(f))
+        || (declared.is_some()
+            && ["yarn.lock", "pnpm-lock.yaml", "package-lock.json"].iter().any(|f| has_file(f)));
+
+    if !is_monorepo {
+        return Ok(PackageManifest::parse(&root_content, PathBuf::from("."), Some("root"))?
+            .into_iter()
+            .collect());
+    }
+
+    let mut patterns = declared.unwrap_or_default();
+    patterns.extend(config_patterns.iter().cloned());
+    let patterns = WorkspacePatterns::new(&patterns);
+
+    let mut manifests = Vec::new();
+    for file in &files {
+        let relative = if prefix.is_empty() {
+            file.as_str()
+        } else {
+            let Some(relative) = file.strip_prefix(prefix).and_then(|f| f.strip_prefix('/')) else {
+                continue;
+            };
+            relative
+        };
+        let Some(dir) = relative.strip_suffix("/package.json") else {
+            continue;
+        };
+        if dir.split('/').any(|segment| segment == "node_modules") || !patterns.matches(dir) {
+            continue;
+        }
+
+        let content = repo
+            .get_file_content_at_ref(git_ref, file)
+            .map_err(|e| git_error("get_file_content_at_ref", &e))?
+            .unwrap_or_default();
+        if let Some(manifest) = PackageManifest::parse(&content, PathBuf::from(dir), None)? {
+            manifests.push(manifest);
+        }
+    }
+
+    Ok(manifests)
+}
+
+/// Reads the `workspaces` field of a root `package.json`, as an array or `{ packages }`.
+fn workspace_patterns(root_json: &serde_json::Value) -> Option<Vec<String>> {
+    let workspaces = root_json.get("workspaces")?;
+    let patterns = workspaces.as_array().or_else(|| workspaces.get("packages")?.as_array())?;
+    Some(
+        patterns
+            .iter()
+            .filter_map(|p| p.as_str())
+            .map(|p| p.trim_start_matches("./").to_string())
+            .collect(),
+    )
+}
@@ -0,0 +1,54 @@
+//! Author identity resolution for changeset metadata.
+//!
+//! **What**: Determines who is creating or modifying a changeset, producing a
+//! `ChangesetAuthor` from the CI environment or the git user configuration.
+//!
+//! **How**: Known CI actor variables are checked first, because on CI the git identity is
+//! usually a bot account. Outside CI, `user.name` and `user.email` are read from the
+//! repository configuration. Environment lookups go through a closure so tests do not depend
+//! on the process environment.
+//!
+//! **Why**: Approval workflows and audit trails need to know who created a changeset and who
+//! changed it afterwards, without asking users to type their identity.
+
+use sublime_git_tools::Repo;
+
+use crate::types::ChangesetAuthor;
+
+/// CI actor variables: provider, login variable, and optional email variable.
+const CI_ACTORS: [(&str, &str, Option<&str>); 4] = [
+    ("github", "GITHUB_ACTOR", None),
+    ("gitlab", "GITLAB_USER_LOGIN", Some("GITLAB_USER_EMAIL")),
+    ("circleci", "CIRCLE_USERNAME", None),
+    ("buildkite", "BUILDKITE_BUILD_CREATOR", Some("BUILDKITE_BUILD_CREATOR_EMAIL")),
+];
+
+/// Resolves the current author from the process environment and `repo`.
+pub(crate) fn current_author(repo: Option<&Repo>) -> Option<ChangesetAuthor> {
+    resolve_author(repo, &|name| std::env::var(name).ok())
+}
+
+/// Resolves an author, preferring a CI actor over the git user configuration.
+///
+/// Returns `None` when neither source provides a non-empty name.
+pub(crate) fn resolve_author(
+    repo: Option<&Repo>,
+    env: &dyn Fn(&str) -> Option<String>,
+) -> Option<ChangesetAuthor> {
+    let non_empty = |value: Option<String>| value.filter(|v| !v.trim().is_empty());
+
+    for (provider, login_var, email_var) in CI_ACTORS {
+        if let Some(name) = non_empty(env(login_var)) {
+            return Some(ChangesetAuthor {
+                name,
+                email: email_var.and_then(|var| non_empty(env(var))),
+                source: provider.to_string(),
+            });
+        }
+    }
+
+    let repo = repo?;
+    let name = non_empty(repo.get_config_value("user.name").ok().flatten())?;
+    let email = non_empty(repo.get_config_value("user.email").ok().flatten());
+    Some(ChangesetAuthor { name, email, source: "git".to_string() })
+}
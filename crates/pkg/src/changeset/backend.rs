@@ -0,0 +1,183 @@
+//! Changeset storage backends and their registry.
+//!
+//! **What**: Provides `ChangesetBackendRegistry`, which maps backend names to factories that
+//! open a `ChangesetStorage`, and `ChangesetStorageLocation`, where a backend keeps its
+//! pending and archived changesets.
+//!
+//! **How**: Each backend is registered under a unique name with a factory receiving the
+//! location. `ChangesetBackendRegistry::default` registers the built-in `file` backend,
+//! backed by `FileBasedChangesetStorage`; consumers register their own backends on top.
+//!
+//! **Why**: Commands that pick a storage by name, such as migrating changesets, resolve it
+//! in one place instead of hard-coding the file backend, so new backends only need to be
+//! registered.
+
+use crate::changeset::{ChangesetStorage, FileBasedChangesetStorage};
+use crate::error::{ChangesetError, ChangesetResult};
+use std::fmt;
+use std::path::PathBuf;
+use sublime_standard_tools::filesystem::{FileSystemManager, PathExt};
+
+/// Name of the built-in backend storing changesets as JSON files in the workspace.
+pub const FILE_BACKEND: &str = "file";
+
+/// Opens a changeset storage at a location.
+pub type ChangesetBackendFactory = fn(&ChangesetStorageLocation) -> Box<dyn ChangesetStorage>;
+
+/// Where a backend keeps pending and archived changesets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangesetStorageLocation {
+    /// Root path of the workspace.
+    pub root: PathBuf,
+
+    /// Location of pending changesets, relative to the workspace root.
+    pub path: String,
+
+    /// Location of archived changesets, relative to the workspace root.
+    pub history_path: String,
+}
+
+impl ChangesetStorageLocation {
+    /// Creates a storage location.
+    #[must_use]
+    pub fn new(root: PathBuf, path: impl Into<String>, history_path: impl Into<String>) -> Self {
+        Self { root, path: path.into(), history_path: history_path.into() }
+    }
+
+    /// Returns whether both locations keep pending or archived changesets in the same place.
+    ///
+    /// Paths are compared after resolving `.` and `..` against the workspace root, so
+    /// `./.changesets` and `.changesets` are the same place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changeset::ChangesetStorageLocation;
+    /// use std::path::PathBuf;
+    ///
+    /// let configured = ChangesetStorageLocation::new(
+    ///     PathBuf::from("/repo"),
+    ///     ".changesets",
+    ///     ".changesets/history",
+    /// );
+    /// let target = ChangesetStorageLocation::new(
+    ///     PathBuf::from("/repo"),
+    ///     "./.changesets",
+    ///     "changes/history",
+    /// );
+    /// assert!(configured.overlaps(&target));
+    /// ```
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.resolve(&self.path) == other.resolve(&other.path)
+            || self.resolve(&self.history_path) == other.resolve(&other.history_path)
+    }
+
+    fn resolve(&self, path: &str) -> PathBuf {
+        self.root.join(path).normalize()
+    }
+}
+
+/// Registry of changeset storage backends.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changeset::{
+///     ChangesetBackendRegistry, ChangesetStorageLocation, FILE_BACKEND,
+/// };
+/// use std::path::PathBuf;
+///
+/// let registry = ChangesetBackendRegistry::default();
+/// assert_eq!(registry.names(), vec![FILE_BACKEND]);
+///
+/// let location =
+///     ChangesetStorageLocation::new(PathBuf::from("."), "changes", "changes/history");
+/// let storage = registry.open("file", &location);
+/// assert!(storage.is_ok());
+/// ```
+pub struct ChangesetBackendRegistry {
+    backends: Vec<(String, ChangesetBackendFactory)>,
+}
+
+impl ChangesetBackendRegistry {
+    /// Creates a registry without any backend, not even the built-in ones.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { backends: Vec::new() }
+    }
+
+    /// Registers a backend under a name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::DuplicateBackend` if a backend with the same name is
+    /// already registered.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: ChangesetBackendFactory,
+    ) -> ChangesetResult<&mut Self> {
+        let name = name.into();
+        if self.contains(&name) {
+            return Err(ChangesetError::DuplicateBackend { name });
+        }
+
+        self.backends.push((name, factory));
+        Ok(self)
+    }
+
+    /// Returns whether a backend is registered under the name.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.backends.iter().any(|(registered, _)| registered == name)
+    }
+
+    /// Returns the registered backend names in registration order.
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.backends.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Opens the storage of the named backend at a location.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::UnknownBackend` if no backend is registered under the name.
+    pub fn open(
+        &self,
+        name: &str,
+        location: &ChangesetStorageLocation,
+    ) -> ChangesetResult<Box<dyn ChangesetStorage>> {
+        self.backends
+            .iter()
+            .find(|(registered, _)| registered == name)
+            .map(|(_, factory)| factory(location))
+            .ok_or_else(|| ChangesetError::UnknownBackend {
+                name: name.to_string(),
+                available: self.names().into_iter().map(str::to_string).collect(),
+            })
+    }
+}
+
+impl Default for ChangesetBackendRegistry {
+    /// Creates a registry holding the built-in `file` backend.
+    fn default() -> Self {
+        Self { backends: vec![(FILE_BACKEND.to_string(), open_file_storage)] }
+    }
+}
+
+impl fmt::Debug for ChangesetBackendRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChangesetBackendRegistry").field("backends", &self.names()).finish()
+    }
+}
+
+fn open_file_storage(location: &ChangesetStorageLocation) -> Box<dyn ChangesetStorage> {
+    Box::new(FileBasedChangesetStorage::new(
+        location.root.clone(),
+        location.path.clone(),
+        location.history_path.clone(),
+        FileSystemManager::new(),
+    ))
+}
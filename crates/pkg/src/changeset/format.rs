@@ -0,0 +1,274 @@
+//! Canonical serialization of changeset files and a three-way merge for them.
+//!
+//! **What**: Provides `to_canonical_json`, which renders pending and archived changesets
+//! the same way regardless of struct field order or map iteration order, and
+//! `merge_changeset_files`, which resolves a git merge between two edits of the same
+//! changeset file.
+//!
+//! **How**: Values are serialized to JSON, object keys are sorted recursively, and the
+//! result is pretty-printed with two-space indentation, one list item per line, and a
+//! trailing newline. The merge parses the ancestor and both sides, merges each field
+//! three ways, and renders the result canonically. Lists take the additions and removals
+//! of both sides, the bump takes the largest of conflicting bumps, and the history of
+//! revisions, operations, and promotions is the union of both sides.
+//!
+//! **Why**: Changeset files are committed alongside code. Reordered fields and shifting
+//! whitespace between versions produce noisy diffs, and two branches adding a package to
+//! the same changeset conflict on lines that merge cleanly field by field.
+//!
+//! # Git Merge Driver
+//!
+//! `merge_changeset_files` backs `workspace changeset merge-driver`, registered with:
+//!
+//! ```text
+//! git config merge.workspace-changeset.driver "workspace changeset merge-driver %O %A %B"
+//! echo ".changesets/*.json merge=workspace-changeset" >> .gitattributes
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::changeset::{merge_changeset_files, to_canonical_json};
+//! use sublime_pkg_tools::types::{Changeset, VersionBump};
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let base = Changeset::new("feature/sso", VersionBump::Patch, vec!["production".to_string()]);
+//! let mut ours = base.clone();
+//! ours.add_package("@myorg/auth");
+//! let mut theirs = base.clone();
+//! theirs.add_package("@myorg/core");
+//!
+//! let merged = merge_changeset_files(
+//!     &to_canonical_json(&base)?,
+//!     &to_canonical_json(&ours)?,
+//!     &to_canonical_json(&theirs)?,
+//! )?;
+//!
+//! let merged: Changeset = serde_json::from_str(&merged)?;
+//! assert_eq!(merged.packages, vec!["@myorg/auth".to_string(), "@myorg/core".to_string()]);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{ChangesetError, ChangesetResult};
+use crate::types::Changeset;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Serializes a changeset file in canonical form.
+///
+/// Object keys are sorted, lists have one item per line, indentation is two spaces, and
+/// the output ends with a newline, so equal values always produce identical files.
+///
+/// # Arguments
+///
+/// * `value` - The changeset, archived changeset, or other value to serialize
+///
+/// # Errors
+///
+/// Returns `ChangesetError::SerializationError` if the value cannot be serialized.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changeset::to_canonical_json;
+/// use sublime_pkg_tools::types::{Changeset, VersionBump};
+///
+/// let changeset = Changeset::new("fix/login", VersionBump::Patch, vec![]);
+/// let json = to_canonical_json(&changeset)?;
+///
+/// assert!(json.starts_with("{\n  \"branch\": \"fix/login\",\n"));
+/// assert!(json.ends_with("}\n"));
+/// # Ok::<(), sublime_pkg_tools::error::ChangesetError>(())
+/// ```
+pub fn to_canonical_json<T: Serialize>(value: &T) -> ChangesetResult<String> {
+    let serialization_error = |e: serde_json::Error| ChangesetError::SerializationError {
+        operation: "serialize".to_string(),
+        reason: format!("Failed to serialize changeset: {}", e),
+    };
+
+    let value = serde_json::to_value(value).map_err(serialization_error)?;
+    let mut json = serde_json::to_string_pretty(&sort_keys(value)).map_err(serialization_error)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Merges two edits of a changeset file against their common ancestor.
+///
+/// The arguments are file contents as git hands them to a merge driver. An empty
+/// ancestor, as when both sides added the file, merges the two sides as a union.
+///
+/// # Arguments
+///
+/// * `base` - Content of the common ancestor, empty if there is none
+/// * `ours` - Content of the current branch's version
+/// * `theirs` - Content of the version being merged in
+///
+/// # Returns
+///
+/// The merged changeset, serialized with `to_canonical_json`.
+///
+/// # Errors
+///
+/// Returns `ChangesetError::SerializationError` if a version is not a changeset, and
+/// `ChangesetError::MergeConflict` if both sides changed the same field differently
+/// in a way that cannot be merged.
+pub fn merge_changeset_files(base: &str, ours: &str, theirs: &str) -> ChangesetResult<String> {
+    let parse = |content: &str, side: &str| {
+        serde_json::from_str::<Changeset>(content)
+            .map(|mut changeset| {
+                changeset.ensure_id();
+                changeset
+            })
+            .map_err(|e| ChangesetError::SerializationError {
+                operation: "deserialize".to_string(),
+                reason: format!("Failed to deserialize the {} changeset: {}", side, e),
+            })
+    };
+
+    let base = if base.trim().is_empty() { None } else { Some(parse(base, "ancestor")?) };
+    let ours = parse(ours, "current")?;
+    let theirs = parse(theirs, "other")?;
+
+    to_canonical_json(&merge_changeset_edits(base.as_ref(), &ours, &theirs)?)
+}
+
+/// Merges two edits of a changeset against their common ancestor.
+///
+/// A field changed on one side only takes that side's value. When both sides changed a
+/// field, lists combine both sides' additions and removals, the bump becomes the largest
+/// of the two, `created_at` the earliest, `updated_at` the latest, and revisions,
+/// operations, and promotions the union of both histories. Conflicting ids, branches,
+/// names, authors, or creation branches cannot be merged.
+///
+/// # Errors
+///
+/// Returns `ChangesetError::MergeConflict` listing the fields both sides changed
+/// differently.
+pub(crate) fn merge_changeset_edits(
+    base: Option<&Changeset>,
+    ours: &Changeset,
+    theirs: &Changeset,
+) -> ChangesetResult<Changeset> {
+    let mut conflicts = Vec::new();
+    let mut merged = ours.clone();
+
+    merged.id = merge_field("id", base.map(|b| &b.id), &ours.id, &theirs.id, &mut conflicts);
+    merged.name =
+        merge_field("name", base.map(|b| &b.name), &ours.name, &theirs.name, &mut conflicts);
+    merged.branch = merge_field(
+        "branch",
+        base.map(|b| &b.branch),
+        &ours.branch,
+        &theirs.branch,
+        &mut conflicts,
+    );
+    merged.author = merge_field(
+        "author",
+        base.map(|b| &b.author),
+        &ours.author,
+        &theirs.author,
+        &mut conflicts,
+    );
+    merged.created_on_branch = merge_field(
+        "created_on_branch",
+        base.map(|b| &b.created_on_branch),
+        &ours.created_on_branch,
+        &theirs.created_on_branch,
+        &mut conflicts,
+    );
+    if !conflicts.is_empty() {
+        return Err(ChangesetError::MergeConflict {
+            branch: ours.branch.clone(),
+            fields: conflicts,
+        });
+    }
+
+    let mut bump_conflict = Vec::new();
+    merged.bump =
+        merge_field("bump", base.map(|b| &b.bump), &ours.bump, &theirs.bump, &mut bump_conflict);
+    if !bump_conflict.is_empty() && theirs.bump.rank() > ours.bump.rank() {
+        merged.bump = theirs.bump;
+    }
+
+    let empty = Vec::new();
+    merged.environments = merge_list(
+        base.map_or(&empty, |b| &b.environments),
+        &ours.environments,
+        &theirs.environments,
+    );
+    merged.packages =
+        merge_list(base.map_or(&empty, |b| &b.packages), &ours.packages, &theirs.packages);
+    merged.changes =
+        merge_list(base.map_or(&empty, |b| &b.changes), &ours.changes, &theirs.changes);
+
+    merged.created_at = ours.created_at.min(theirs.created_at);
+    merged.updated_at = ours.updated_at.max(theirs.updated_at);
+
+    for revision in &theirs.revisions {
+        if !merged.revisions.contains(revision) {
+            merged.revisions.push(revision.clone());
+        }
+    }
+    merged.revisions.sort_by_key(|revision| revision.timestamp);
+    for operation in &theirs.operations {
+        if !merged.operations.contains(operation) {
+            merged.operations.push(operation.clone());
+        }
+    }
+    merged.operations.sort_by_key(|operation| operation.timestamp);
+    for promotion in &theirs.promotions {
+        if !merged.is_promoted_to(&promotion.environment) {
+            merged.promotions.push(promotion.clone());
+        }
+    }
+    merged.promotions.sort_by_key(|promotion| promotion.timestamp);
+
+    Ok(merged)
+}
+
+/// Picks the side that changed a field, recording a conflict when both changed it
+/// differently.
+fn merge_field<T: PartialEq + Clone>(
+    field: &str,
+    base: Option<&T>,
+    ours: &T,
+    theirs: &T,
+    conflicts: &mut Vec<String>,
+) -> T {
+    if ours == theirs || base == Some(theirs) {
+        ours.clone()
+    } else if base == Some(ours) {
+        theirs.clone()
+    } else {
+        conflicts.push(field.to_string());
+        ours.clone()
+    }
+}
+
+/// Merges a list three ways, keeping our order and appending their additions.
+///
+/// Items either side removed from the ancestor are dropped.
+fn merge_list(base: &[String], ours: &[String], theirs: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> =
+        ours.iter().filter(|item| theirs.contains(item) || !base.contains(item)).cloned().collect();
+    for item in theirs {
+        if !base.contains(item) && !merged.contains(item) {
+            merged.push(item.clone());
+        }
+    }
+    merged
+}
+
+/// Sorts the keys of every object in a JSON value.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(entries.into_iter().map(|(key, value)| (key, sort_keys(value))).collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        other => other,
+    }
+}
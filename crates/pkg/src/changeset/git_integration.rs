@@ -7,13 +7,20 @@
 //! **How**: The `PackageDetector` uses `sublime_git_tools` to retrieve commit information
 //! and file changes, then uses `sublime_standard_tools` to detect the workspace structure
 //! (monorepo vs single package) and map changed files to their corresponding packages.
+//! Changes to generated or binary files, as classified by `FilePatternsConfig`, are skipped
+//! so a regenerated lockfile or rebuilt asset alone does not propose a release.
 //!
 //! **Why**: Automating the detection of affected packages from Git commits enables
 //! developers to quickly identify which packages need version bumps and should be included
 //! in a changeset, reducing manual work and potential errors in the release process.
 
+use crate::changes::PackageIgnore;
+use crate::changes::mapping::{ResolvedPath, is_within, relative_to};
+use crate::changeset::proposal::{ChangesetProposal, PackageProposal, bump_for_commit_message};
+use crate::config::FilePatternsConfig;
 use crate::error::{ChangesetError, ChangesetResult};
-use std::collections::HashSet;
+use crate::types::VersionBump;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use sublime_git_tools::{Repo, RepoCommit};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
@@ -58,6 +65,8 @@ pub struct PackageDetector<'a> {
     fs: FileSystemManager,
     /// Monorepo detector for workspace analysis.
     monorepo_detector: MonorepoDetector<FileSystemManager>,
+    /// Patterns classifying changed files.
+    file_patterns: FilePatternsConfig,
 }
 
 impl<'a> PackageDetector<'a> {
@@ -90,13 +99,40 @@ impl<'a> PackageDetector<'a> {
     pub fn new(workspace_root: impl Into<PathBuf>, repo: &'a Repo, fs: FileSystemManager) -> Self {
         let workspace_root = workspace_root.into();
         let monorepo_detector = MonorepoDetector::with_filesystem(fs.clone());
-        Self { workspace_root, repo, fs, monorepo_detector }
+        Self {
+            workspace_root,
+            repo,
+            fs,
+            monorepo_detector,
+            file_patterns: FilePatternsConfig::default(),
+        }
+    }
+
+    /// Sets the patterns used to classify changed files.
+    ///
+    /// Files classified as generated or binary do not affect any package.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::changeset::PackageDetector;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    ///
+    /// # fn example(detector: PackageDetector<'_>, config: &PackageToolsConfig) {
+    /// let detector = detector.with_file_patterns(config.changes.file_patterns.clone());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_file_patterns(mut self, file_patterns: FilePatternsConfig) -> Self {
+        self.file_patterns = file_patterns;
+        self
     }
 
     /// Detects packages affected by the given commits.
     ///
     /// This method analyzes each commit to determine which files were changed, then maps
     /// those files to packages. It handles both monorepo and single-package repositories.
+    /// Generated and binary files are skipped.
     ///
     /// # Parameters
     ///
@@ -139,8 +175,9 @@ impl<'a> PackageDetector<'a> {
         // Check if this is a monorepo
         let is_monorepo = self.is_monorepo().await?;
 
-        // Get all changed files from commits
-        let changed_files = self.get_changed_files_from_commits(commit_ids)?;
+        // Get all changed files from commits, skipping generated and binary files
+        let mut changed_files = self.get_changed_files_from_commits(commit_ids)?;
+        changed_files.retain(|file| self.file_patterns.classify(file).is_meaningful());
 
         if changed_files.is_empty() {
             return Ok(Vec::new());
@@ -254,6 +291,9 @@ impl<'a> PackageDetector<'a> {
 
     /// Gets the list of commits between two references.
     ///
+    /// History is read along first parents, and merge commits are replaced by the commits
+    /// they merged, so merged and rebased branches record the same commits.
+    ///
     /// # Parameters
     ///
     /// * `from_ref` - Starting reference (commits after this)
@@ -282,7 +322,7 @@ impl<'a> PackageDetector<'a> {
         from_ref: &str,
         to_ref: &str,
     ) -> ChangesetResult<Vec<RepoCommit>> {
-        self.repo.get_commits_between(from_ref, to_ref, &None).map_err(|e| {
+        self.repo.get_first_parent_commits_between(from_ref, to_ref, &None).map_err(|e| {
             ChangesetError::GitIntegration {
                 operation: format!("get commits between {} and {}", from_ref, to_ref),
                 reason: format!("Failed to get commits: {}", e),
@@ -290,8 +330,84 @@ impl<'a> PackageDetector<'a> {
         })
     }
 
+    /// Proposes the packages and bump type of a changeset for a branch.
+    ///
+    /// Compares `head` with its merge-base with `base`. Every commit since the merge-base
+    /// is mapped to the packages whose files it changes (honoring their ignore rules), and
+    /// each package gets the highest bump its commits call for, as decided by
+    /// [`bump_for_commit_message`](crate::changeset::bump_for_commit_message).
+    ///
+    /// # Parameters
+    ///
+    /// * `base` - Base branch or reference, e.g. `main`
+    /// * `head` - Branch or reference to propose a changeset for, e.g. `HEAD`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the merge-base cannot be found, or if Git operations or package
+    /// detection fail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::PackageDetector;
+    /// # async fn example(detector: PackageDetector<'_>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let proposal = detector.propose_changeset("main", "HEAD").await?;
+    /// for package in &proposal.packages {
+    ///     println!("{}: {}", package.name, package.bump);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn propose_changeset(
+        &self,
+        base: &str,
+        head: &str,
+    ) -> ChangesetResult<ChangesetProposal> {
+        let merge_base =
+            self.repo.get_merge_base(head, base).map_err(|e| ChangesetError::GitIntegration {
+                operation: format!("find merge-base of {} and {}", head, base),
+                reason: e.to_string(),
+            })?;
+        let commits = self.get_commits_between(&merge_base, head)?;
+
+        let mut packages: BTreeMap<String, PackageProposal> = BTreeMap::new();
+        for commit in &commits {
+            let bump = bump_for_commit_message(&commit.message);
+            for name in self.detect_affected_packages(std::slice::from_ref(&commit.hash)).await? {
+                let package = packages.entry(name.clone()).or_insert_with(|| PackageProposal {
+                    name,
+                    bump: VersionBump::None,
+                    commits: Vec::new(),
+                });
+                if bump.rank() > package.bump.rank() {
+                    package.bump = bump;
+                }
+                package.commits.push(commit.hash.clone());
+            }
+        }
+
+        let packages: Vec<PackageProposal> = packages.into_values().collect();
+        let bump = packages
+            .iter()
+            .map(|package| package.bump)
+            .max_by_key(|bump| bump.rank())
+            .unwrap_or(VersionBump::None);
+
+        Ok(ChangesetProposal {
+            base: base.to_string(),
+            merge_base,
+            bump,
+            packages,
+            commit_count: commits.len(),
+        })
+    }
+
     /// Gets commits since a specific reference.
     ///
+    /// Like [`Self::get_commits_between`], merge commits are expanded into the commits they
+    /// merged.
+    ///
     /// # Parameters
     ///
     /// * `since` - Optional reference to start from. If `None`, gets all commits.
@@ -318,9 +434,11 @@ impl<'a> PackageDetector<'a> {
     /// # }
     /// ```
     pub fn get_commits_since(&self, since: Option<String>) -> ChangesetResult<Vec<RepoCommit>> {
-        self.repo.get_commits_since(since, &None).map_err(|e| ChangesetError::GitIntegration {
-            operation: "get commits since reference".to_string(),
-            reason: format!("Failed to get commits: {}", e),
+        self.repo.get_first_parent_commits_since(since, &None).map_err(|e| {
+            ChangesetError::GitIntegration {
+                operation: "get commits since reference".to_string(),
+                reason: format!("Failed to get commits: {}", e),
+            }
         })
     }
 
@@ -378,36 +496,49 @@ impl<'a> PackageDetector<'a> {
     /// Maps changed files to packages in a monorepo.
     ///
     /// This method determines which packages contain the changed files by checking
-    /// if each file path is within a package directory.
+    /// if each file path is within a package directory. Files matched by the owning
+    /// package's ignore rules do not affect it.
     async fn map_files_to_packages_monorepo(
         &self,
         changed_files: &[PathBuf],
     ) -> ChangesetResult<Vec<String>> {
         let packages = self.get_workspace_packages().await?;
         let mut affected_packages = HashSet::new();
+        let mut ignores: HashMap<&str, PackageIgnore> = HashMap::new();
+        let package_roots: Vec<ResolvedPath> = packages
+            .iter()
+            .map(|package| ResolvedPath::new(package.absolute_path.clone()))
+            .collect();
 
         for file in changed_files {
             // Convert file to absolute path
-            let file_absolute =
-                if file.is_absolute() { file.clone() } else { self.workspace_root.join(file) };
-
-            // Canonicalize the file path if possible, otherwise use as-is
-            let file_canonical =
-                file_absolute.canonicalize().unwrap_or_else(|_| file_absolute.clone());
-
-            // Check which package contains this file
-            for package in &packages {
-                // Use the absolute_path field from WorkspacePackage
-                let package_canonical = package
-                    .absolute_path
-                    .canonicalize()
-                    .unwrap_or_else(|_| package.absolute_path.clone());
-
-                // Check if file is within this package's directory
-                if file_canonical.starts_with(&package_canonical) {
-                    affected_packages.insert(package.name.clone());
-                    break;
-                }
+            let file_absolute = ResolvedPath::new(if file.is_absolute() {
+                file.clone()
+            } else {
+                self.workspace_root.join(file)
+            });
+
+            // Find the package whose directory contains this file, tolerating separator,
+            // symlink, and Windows path prefix differences
+            let Some((package, _)) = packages
+                .iter()
+                .zip(&package_roots)
+                .find(|(_, root)| is_within(&file_absolute, root))
+            else {
+                continue;
+            };
+
+            if !ignores.contains_key(package.name.as_str()) {
+                let ignore = self.load_ignore(&package.absolute_path).await?;
+                ignores.insert(&package.name, ignore);
+            }
+            let ignored = ignores.get(package.name.as_str()).is_some_and(|ignore| {
+                relative_to(file_absolute.path(), &package.absolute_path)
+                    .is_some_and(|relative| ignore.is_ignored(&relative))
+            });
+
+            if !ignored {
+                affected_packages.insert(package.name.clone());
             }
         }
 
@@ -416,13 +547,14 @@ impl<'a> PackageDetector<'a> {
 
     /// Maps changed files to the single package.
     ///
-    /// For single-package repositories, any file change (except certain ignored files)
-    /// affects the single package.
+    /// For single-package repositories, any file change not matched by the package's
+    /// ignore rules affects the single package.
     async fn map_files_to_packages_single(
         &self,
         changed_files: &[PathBuf],
     ) -> ChangesetResult<Vec<String>> {
-        if changed_files.is_empty() {
+        let ignore = self.load_ignore(&self.workspace_root).await?;
+        if changed_files.iter().all(|file| ignore.is_ignored(file)) {
             return Ok(Vec::new());
         }
 
@@ -437,6 +569,16 @@ impl<'a> PackageDetector<'a> {
         Ok(packages)
     }
 
+    /// Loads the `.pkgignore` and `packageTools.ignoreChanges` rules of a package.
+    async fn load_ignore(&self, package_root: &Path) -> ChangesetResult<PackageIgnore> {
+        PackageIgnore::load(&self.fs, package_root).await.map_err(|e| {
+            ChangesetError::GitIntegration {
+                operation: format!("load ignore rules for {}", package_root.display()),
+                reason: e.to_string(),
+            }
+        })
+    }
+
     /// Gets all workspace packages in a monorepo.
     async fn get_workspace_packages(&self) -> ChangesetResult<Vec<WorkspacePackage>> {
         self.monorepo_detector.detect_packages(&self.workspace_root).await.map_err(|e| {
@@ -479,3 +621,16 @@ impl<'a> PackageDetector<'a> {
         self.repo
     }
 }
+
+/// Returns the values of the trailers of a commit message with the given key.
+///
+/// Trailers are `Key: value` lines of the last paragraph of the message, after its
+/// subject. Keys are compared case-insensitively.
+pub(crate) fn commit_trailers<'m>(message: &'m str, key: &str) -> impl Iterator<Item = &'m str> {
+    let body = message.trim_end().split_once('\n').map_or("", |(_, body)| body);
+    let last_paragraph = body.rsplit("\n\n").next().unwrap_or_default();
+    last_paragraph.lines().filter_map(move |line| {
+        let (name, value) = line.split_once(':')?;
+        name.trim().eq_ignore_ascii_case(key).then(|| value.trim())
+    })
+}
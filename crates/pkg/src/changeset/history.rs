@@ -222,16 +222,18 @@ impl ChangesetHistory {
         Ok(archives)
     }
 
-    /// Gets a specific archived changeset by branch name.
+    /// Gets a specific archived changeset by id, name, or branch name.
+    ///
+    /// When a branch or name was released more than once, the latest release is returned.
     ///
     /// # Arguments
     ///
-    /// * `branch` - The branch name of the changeset to retrieve
+    /// * `reference` - The id, name, or branch name of the changeset to retrieve
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - No archived changeset exists for the specified branch
+    /// - No archived changeset matches the reference
     /// - The archived changeset file is corrupted or cannot be deserialized
     ///
     /// # Examples
@@ -259,8 +261,8 @@ impl ChangesetHistory {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, branch: &str) -> ChangesetResult<ArchivedChangeset> {
-        self.storage.load_archived(branch).await
+    pub async fn get(&self, reference: &str) -> ChangesetResult<ArchivedChangeset> {
+        self.storage.load_archived(reference).await
     }
 
     /// Queries changesets by date range.
@@ -13,14 +13,26 @@
 
 use crate::config::ChangesetConfig;
 use crate::error::{ChangesetError, ChangesetResult};
-use crate::types::{Changeset, UpdateSummary, VersionBump};
+use crate::types::{
+    ArchivedChangeset, Changeset, ChangesetRevision, CommitAssociation, UpdateSummary, VersionBump,
+    generate_id,
+};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::path::PathBuf;
-use sublime_git_tools::Repo;
+use sublime_git_tools::{Repo, RepoCommit};
 use sublime_standard_tools::filesystem::FileSystemManager;
 
-use super::git_integration::PackageDetector;
+use super::author::current_author;
+use super::git_integration::{PackageDetector, commit_trailers};
+use super::names::generate_name;
+use super::promotion::{ChangesetPromotion, check_promotion};
+use super::query::{ChangesetPage, ChangesetQuery};
+use super::stale::{StaleCleanupReport, StalePackage, find_stale_packages};
 use super::storage::{ChangesetStorage, FileBasedChangesetStorage};
 
+/// How many ids are tried before giving up on a name no pending changeset uses.
+const MAX_NAME_ATTEMPTS: usize = 8;
+
 /// Manager for high-level changeset operations.
 ///
 /// The `ChangesetManager` provides a comprehensive API for creating, reading, updating,
@@ -195,7 +207,8 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
     ///
     /// This method creates a new changeset with the specified branch name, version bump type,
     /// and target environments. It validates the branch name, checks for existing changesets,
-    /// validates the environments, and saves the changeset to storage.
+    /// validates the environments, and saves the changeset to storage. The changeset gets a
+    /// new id and, when `changeset.human_names` is enabled, a generated name.
     ///
     /// # Parameters
     ///
@@ -249,19 +262,19 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         }
 
         // Check if changeset already exists
-        if self.storage.exists(&branch_name).await? {
-            let path = std::env::current_dir()
-                .unwrap_or_default()
-                .join(&self.config.path)
-                .join(&branch_name);
-            return Err(ChangesetError::AlreadyExists { branch: branch_name, path });
-        }
+        self.ensure_absent(&branch_name).await?;
 
         // Validate environments
         self.validate_environments(&environments)?;
 
         // Create new changeset
-        let changeset = Changeset::new(branch_name, bump, environments);
+        let mut changeset = Changeset::new(branch_name, bump, environments);
+        self.assign_name(&mut changeset).await?;
+        if self.config.track_authors {
+            changeset.author = current_author(self.git_repo.as_ref());
+            changeset.created_on_branch =
+                self.git_repo.as_ref().and_then(|repo| repo.get_current_branch().ok());
+        }
 
         // Note: We don't validate for packages here because changesets start empty
         // and packages are added later through update operations
@@ -303,6 +316,47 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         self.storage.load(branch).await
     }
 
+    /// Loads a pending changeset by branch name, id, or name.
+    ///
+    /// The branch name is tried first, then the ids and names of all pending changesets.
+    ///
+    /// # Parameters
+    ///
+    /// * `reference` - The branch name, id, or name of the changeset
+    ///
+    /// # Returns
+    ///
+    /// The matching `Changeset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No pending changeset matches the reference
+    /// - Storage operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager<impl ChangesetStorage>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let changeset = manager.resolve("brave-lions-smile").await?;
+    /// println!("{} is on branch {}", changeset.id, changeset.branch);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve(&self, reference: &str) -> ChangesetResult<Changeset> {
+        if self.storage.exists(reference).await? {
+            return self.storage.load(reference).await;
+        }
+
+        self.storage
+            .list_pending()
+            .await?
+            .into_iter()
+            .find(|changeset| changeset.matches_reference(reference))
+            .ok_or_else(|| ChangesetError::NotFound { branch: reference.to_string() })
+    }
+
     /// Updates an existing changeset.
     ///
     /// This method validates the changeset, updates its `updated_at` timestamp,
@@ -345,12 +399,87 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         let mut updated_changeset = changeset.clone();
         updated_changeset.touch();
 
+        // Record who changed what
+        if self.config.track_authors
+            && let Ok(previous) = self.storage.load(&changeset.branch).await
+        {
+            let changes = updated_changeset.describe_changes_since(&previous);
+            self.record_revision(&mut updated_changeset, changes);
+        }
+
         // Save to storage
         self.storage.save(&updated_changeset).await?;
 
         Ok(())
     }
 
+    /// Fails with `ChangesetError::AlreadyExists` if a changeset exists for `branch`.
+    async fn ensure_absent(&self, branch: &str) -> ChangesetResult<()> {
+        if self.storage.exists(branch).await? {
+            let path =
+                std::env::current_dir().unwrap_or_default().join(&self.config.path).join(branch);
+            return Err(ChangesetError::AlreadyExists { branch: branch.to_string(), path });
+        }
+        Ok(())
+    }
+
+    /// Gives `changeset` a human-friendly name generated from its id.
+    ///
+    /// When the name is already used by another pending changeset, a new id is picked and
+    /// the name generated again. Does nothing when `changeset.human_names` is disabled.
+    async fn assign_name(&self, changeset: &mut Changeset) -> ChangesetResult<()> {
+        if !self.config.human_names {
+            return Ok(());
+        }
+
+        let taken: HashSet<String> = self
+            .storage
+            .list_pending()
+            .await?
+            .into_iter()
+            .filter(|pending| pending.branch != changeset.branch)
+            .filter_map(|pending| pending.name)
+            .collect();
+
+        for _ in 0..MAX_NAME_ATTEMPTS {
+            let name = generate_name(&changeset.id);
+            if !taken.contains(&name) {
+                changeset.name = Some(name);
+                return Ok(());
+            }
+            changeset.id = generate_id();
+        }
+
+        // Every name collided; the changeset is still referenced by id and branch
+        Ok(())
+    }
+
+    /// Sets the author of the last recorded operation of `changeset`.
+    ///
+    /// Does nothing when author tracking is disabled.
+    fn stamp_operation(&self, changeset: &mut Changeset) {
+        if !self.config.track_authors {
+            return;
+        }
+        if let Some(operation) = changeset.operations.last_mut() {
+            operation.author = current_author(self.git_repo.as_ref());
+        }
+    }
+
+    /// Appends a revision describing `changes` to `changeset`.
+    ///
+    /// Does nothing when `changes` is empty or author tracking is disabled.
+    pub(crate) fn record_revision(&self, changeset: &mut Changeset, changes: Vec<String>) {
+        if !self.config.track_authors || changes.is_empty() {
+            return;
+        }
+        changeset.revisions.push(ChangesetRevision {
+            timestamp: changeset.updated_at,
+            author: current_author(self.git_repo.as_ref()),
+            changes,
+        });
+    }
+
     /// Deletes a pending changeset.
     ///
     /// This permanently removes the changeset from storage. Use with caution as
@@ -410,6 +539,89 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         self.storage.list_pending().await
     }
 
+    /// Lists pending changesets matching a query, sorted and paginated.
+    ///
+    /// Filters other than `author` are evaluated on the stored changesets. The `author` filter
+    /// matches changesets containing at least one commit whose author name or email contains
+    /// the given text, ignoring case, and therefore requires a Git repository.
+    ///
+    /// # Parameters
+    ///
+    /// * `query` - Filters, sort order, and pagination window
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Storage operation fails
+    /// - An author filter is given and no Git repository is available, or commits cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::{ChangesetManager, ChangesetQuery};
+    /// # async fn example(manager: ChangesetManager<impl ChangesetStorage>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let page = manager.query(&ChangesetQuery::new().with_environment("production").with_limit(20)).await?;
+    /// println!("Showing {} of {}", page.changesets.len(), page.total);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query(&self, query: &ChangesetQuery) -> ChangesetResult<ChangesetPage> {
+        let mut changesets = self.storage.list_pending().await?;
+
+        if let Some(author) = &query.author {
+            let repo = self.git_repo.as_ref().ok_or_else(|| ChangesetError::GitIntegration {
+                operation: "filter changesets by author".to_string(),
+                reason: "Git repository not available".to_string(),
+            })?;
+            let detector =
+                PackageDetector::new(self.workspace_root.clone(), repo, FileSystemManager::new());
+
+            let needle = author.to_lowercase();
+            let authored: HashSet<String> = detector
+                .get_commits_since(None)?
+                .into_iter()
+                .filter(|commit| {
+                    commit.author_name.to_lowercase().contains(&needle)
+                        || commit.author_email.to_lowercase().contains(&needle)
+                })
+                .map(|commit| commit.hash)
+                .collect();
+
+            changesets.retain(|changeset| {
+                changeset.changes.iter().any(|commit| authored.contains(commit))
+            });
+        }
+
+        Ok(query.apply(changesets))
+    }
+
+    /// Checks that every pending changeset only targets configured environments.
+    ///
+    /// Changesets are validated when they are created or updated, but the configured
+    /// environments may change afterwards. Run this before promoting or releasing to catch
+    /// changesets that reference environments which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::InvalidEnvironment` for the first unknown environment found,
+    /// or a storage error if pending changesets cannot be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager<impl ChangesetStorage>) -> Result<(), Box<dyn std::error::Error>> {
+    /// manager.validate_pending_environments().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_pending_environments(&self) -> ChangesetResult<()> {
+        for changeset in self.storage.list_pending().await? {
+            self.validate_environments(&changeset.environments)?;
+        }
+        Ok(())
+    }
+
     /// Validates that all environments are in the available environments list.
     ///
     /// # Parameters
@@ -581,11 +793,17 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
     /// method for populating changesets from Git history.
     ///
     /// The method will:
-    /// 1. Get commits since the last commit in the changeset (or all commits if empty)
-    /// 2. Detect which packages are affected by the commits
-    /// 3. Add new packages to the changeset
-    /// 4. Add commit IDs to the changeset
-    /// 5. Save the updated changeset
+    /// 1. Get the commits since the merge-base of `HEAD` with `changeset.base_branch`
+    ///    (`main`, then `master`, when unset), or since the last commit in the changeset
+    ///    when no base branch is found
+    /// 2. Skip commits the changeset already lists
+    /// 3. Keep the commits selected by `changeset.commit_association`:
+    ///    - `branch`: all of them
+    ///    - `packages`: those touching a package the changeset lists
+    ///    - `trailer`: those with a `Changeset: <id>` trailer naming the changeset
+    /// 4. Detect which packages are affected by the kept commits and add new ones to the
+    ///    changeset, except with the `packages` strategy
+    /// 5. Add the commit IDs and the strategy to the changeset and save it
     ///
     /// # Parameters
     ///
@@ -604,6 +822,7 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
     /// Returns an error if:
     /// - Git repository is not available
     /// - The changeset does not exist
+    /// - The configured base branch cannot be found
     /// - Git operations fail
     /// - Package detection fails
     /// - Storage operation fails
@@ -640,38 +859,49 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
 
         // Load the changeset
         let mut changeset = self.load(branch).await?;
+        let strategy = self.config.commit_association;
 
-        // Get the last commit in the changeset to use as a starting point
-        let since_commit = changeset.changes.last().cloned();
-
-        // Get new commits from Git
         let detector =
             PackageDetector::new(self.workspace_root.clone(), repo, FileSystemManager::new());
 
-        let new_commits = detector.get_commits_since(since_commit)?;
+        // Candidate commits, oldest first, without the ones already recorded
+        let mut candidates = self.branch_commits(&detector, &changeset)?;
+        candidates.reverse();
+        candidates.retain(|commit| !changeset.has_commit(&commit.hash));
 
-        if new_commits.is_empty() {
-            return Ok(UpdateSummary::empty());
-        }
-
-        // Extract commit IDs
-        let commit_ids: Vec<String> = new_commits.iter().map(|c| c.hash.clone()).collect();
-
-        // Detect affected packages
-        let affected_packages = detector.detect_affected_packages(&commit_ids).await?;
-
-        // Determine which packages are new and which already existed
-        let mut new_packages: Vec<String> = Vec::new();
-        let mut existing_packages: Vec<String> = Vec::new();
+        let mut commit_ids: Vec<String> = Vec::new();
+        let mut affected_packages: BTreeSet<String> = BTreeSet::new();
+        for commit in candidates {
+            if strategy == CommitAssociation::Trailer
+                && !commit_trailers(&commit.message, "Changeset")
+                    .any(|value| value == changeset.id || changeset.name.as_deref() == Some(value))
+            {
+                continue;
+            }
 
-        for pkg in affected_packages {
-            if changeset.has_package(&pkg) {
-                existing_packages.push(pkg);
+            let packages =
+                detector.detect_affected_packages(std::slice::from_ref(&commit.hash)).await?;
+            if strategy == CommitAssociation::Packages {
+                let listed: Vec<String> =
+                    packages.into_iter().filter(|pkg| changeset.has_package(pkg)).collect();
+                if listed.is_empty() {
+                    continue;
+                }
+                affected_packages.extend(listed);
             } else {
-                new_packages.push(pkg);
+                affected_packages.extend(packages);
             }
+            commit_ids.push(commit.hash);
         }
 
+        if commit_ids.is_empty() {
+            return Ok(UpdateSummary::empty());
+        }
+
+        // Determine which packages are new and which already existed
+        let (existing_packages, new_packages): (Vec<String>, Vec<String>) =
+            affected_packages.into_iter().partition(|pkg| changeset.has_package(pkg));
+
         // Update changeset with new packages and commits
         for package in &new_packages {
             changeset.add_package(package);
@@ -680,6 +910,7 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         for commit_id in &commit_ids {
             changeset.add_commit(commit_id);
         }
+        changeset.association = Some(strategy);
 
         // Save the updated changeset
         self.update(&changeset).await?;
@@ -687,6 +918,44 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         Ok(UpdateSummary::new(commit_ids.len(), commit_ids, new_packages, existing_packages))
     }
 
+    /// Returns the commits `add_commits_from_git` considers for a changeset, newest first.
+    ///
+    /// Uses the commits since the merge-base of `HEAD` with the configured base branch, or
+    /// with `main` or `master` when none is configured. A base branch that is checked out
+    /// is skipped. Without a usable default base branch, the commits since the last commit
+    /// in the changeset are used.
+    fn branch_commits(
+        &self,
+        detector: &PackageDetector<'_>,
+        changeset: &Changeset,
+    ) -> ChangesetResult<Vec<RepoCommit>> {
+        let current = detector.repo().get_current_branch().ok();
+
+        if let Some(base) = &self.config.base_branch {
+            if current.as_deref() != Some(base.as_str()) {
+                let merge_base = detector.repo().get_merge_base("HEAD", base).map_err(|e| {
+                    ChangesetError::GitIntegration {
+                        operation: format!("find merge-base of HEAD and {}", base),
+                        reason: e.to_string(),
+                    }
+                })?;
+                return detector.get_commits_between(&merge_base, "HEAD");
+            }
+        } else {
+            for base in ["main", "master"] {
+                if current.as_deref() == Some(base) {
+                    continue;
+                }
+                match detector.repo().get_merge_base("HEAD", base) {
+                    Ok(merge_base) => return detector.get_commits_between(&merge_base, "HEAD"),
+                    Err(e) => log::debug!("Cannot compare HEAD with '{}': {}", base, e),
+                }
+            }
+        }
+
+        detector.get_commits_since(changeset.changes.last().cloned())
+    }
+
     /// Archives a changeset with release information.
     ///
     /// Moves a changeset from the pending state to the history archive, adding
@@ -754,4 +1023,402 @@ impl<S: ChangesetStorage> ChangesetManager<S> {
         // Archive using storage
         self.storage.archive(&changeset, release_info).await
     }
+
+    /// Archives the part of a changeset covering the released packages.
+    ///
+    /// Used by partial releases. When every package of the changeset was released this
+    /// behaves like [`archive`](Self::archive). Otherwise the changeset is split with
+    /// [`Changeset::split_packages`]: the released part is archived under
+    /// `<branch>@<packages>` and the pending changeset keeps the remaining packages.
+    ///
+    /// # Parameters
+    ///
+    /// * `branch` - The branch name of the changeset
+    /// * `released` - Names of the packages that were released
+    /// * `release_info` - Release metadata for the archived part
+    ///
+    /// # Returns
+    ///
+    /// The changeset that stays pending, or `None` if the whole changeset was archived.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The changeset doesn't exist
+    /// - The released part was already archived
+    /// - Archiving or saving the pending changeset fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # use sublime_pkg_tools::types::ReleaseInfo;
+    /// # async fn example(manager: ChangesetManager, release_info: ReleaseInfo) -> Result<(), Box<dyn std::error::Error>> {
+    /// let released = vec!["@myorg/core".to_string()];
+    /// if let Some(pending) = manager.archive_packages("fix/auth", &released, release_info).await? {
+    ///     println!("Still pending: {:?}", pending.packages);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn archive_packages(
+        &self,
+        branch: &str,
+        released: &[String],
+        release_info: crate::types::ReleaseInfo,
+    ) -> ChangesetResult<Option<Changeset>> {
+        let changeset = self.load(branch).await?;
+        let (released, pending) = changeset.split_packages(released);
+
+        self.storage.archive(&released, release_info).await?;
+        if let Some(pending) = &pending {
+            self.update(pending).await?;
+        }
+
+        Ok(pending)
+    }
+
+    /// Moves packages of a pending changeset into a new pending changeset.
+    ///
+    /// The new changeset keeps the bump, environments, and commits of the original. Both
+    /// changesets record the split in their operation history, with the current author
+    /// when author tracking is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// * `branch` - The branch name of the changeset to split
+    /// * `packages` - Names of the packages to move
+    /// * `into` - Branch name of the new changeset
+    ///
+    /// # Returns
+    ///
+    /// The updated original changeset and the new changeset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The changeset doesn't exist
+    /// - A changeset already exists for `into`
+    /// - A package is not in the changeset, or no package would stay
+    /// - Storage operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let (auth, docs) = manager
+    ///     .split("feature/auth", &["@myorg/docs".to_string()], "feature/auth-docs")
+    ///     .await?;
+    /// println!("{} keeps {:?}, {} has {:?}", auth.branch, auth.packages, docs.branch, docs.packages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn split(
+        &self,
+        branch: &str,
+        packages: &[String],
+        into: &str,
+    ) -> ChangesetResult<(Changeset, Changeset)> {
+        let mut changeset = self.load(branch).await?;
+        self.ensure_absent(into).await?;
+
+        let mut split = changeset.split_off(packages, into)?;
+        self.assign_name(&mut split).await?;
+        self.stamp_operation(&mut changeset);
+        self.stamp_operation(&mut split);
+
+        self.storage.save(&split).await?;
+        self.storage.save(&changeset).await?;
+
+        Ok((changeset, split))
+    }
+
+    /// Merges pending changesets into one pending changeset.
+    ///
+    /// See [`Changeset::merge`] for how the sources are combined. The merged changeset is
+    /// saved under `into` and the sources are deleted. `into` may name one of the sources,
+    /// in which case that changeset is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// * `branches` - Branch names of the changesets to merge
+    /// * `into` - Branch name of the merged changeset
+    ///
+    /// # Returns
+    ///
+    /// The merged changeset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Fewer than two distinct changesets are given
+    /// - A changeset doesn't exist
+    /// - `into` names an existing changeset that is not a source
+    /// - The merged environments are invalid
+    /// - Storage operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let branches = vec!["fix/login".to_string(), "feature/sso".to_string()];
+    /// let merged = manager.merge(&branches, "release/auth").await?;
+    /// println!("Merged bump: {}", merged.bump);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn merge(&self, branches: &[String], into: &str) -> ChangesetResult<Changeset> {
+        let mut sources = Vec::with_capacity(branches.len());
+        for branch in branches {
+            if !sources.iter().any(|source: &Changeset| &source.branch == branch) {
+                sources.push(self.load(branch).await?);
+            }
+        }
+        if !branches.iter().any(|branch| branch == into) {
+            self.ensure_absent(into).await?;
+        }
+
+        let mut merged = Changeset::merge(into, &sources)?;
+        self.validate_environments(&merged.environments)?;
+        self.assign_name(&mut merged).await?;
+        self.stamp_operation(&mut merged);
+
+        self.storage.save(&merged).await?;
+        for source in sources.iter().filter(|source| source.branch != into) {
+            self.storage.delete(&source.branch).await?;
+        }
+
+        Ok(merged)
+    }
+
+    /// Checks that a changeset may be promoted into an environment.
+    ///
+    /// The changeset is looked up among the pending changesets first, then in history,
+    /// by id, name, or branch. Nothing is written; pass the returned promotion to
+    /// [`apply_promotion`](Self::apply_promotion) to record it.
+    ///
+    /// # Parameters
+    ///
+    /// * `reference` - Id, name, or branch of the changeset
+    /// * `environment` - Environment to promote the changeset into
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No pending or archived changeset matches the reference
+    /// - The environment is not available
+    /// - The changeset does not target the environment, was already promoted into it, or
+    ///   was not promoted into the environment before it in the promotion order
+    /// - Storage operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let promotion = manager.plan_promotion("brave-lions-smile", "production").await?;
+    /// println!("Promoting from {:?}", promotion.previous);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn plan_promotion(
+        &self,
+        reference: &str,
+        environment: &str,
+    ) -> ChangesetResult<ChangesetPromotion> {
+        let (changeset, release) = match self.resolve(reference).await {
+            Ok(changeset) => (changeset, None),
+            Err(ChangesetError::NotFound { .. }) => {
+                let archived = self.storage.load_archived(reference).await?;
+                (archived.changeset, Some(archived.release_info))
+            }
+            Err(error) => return Err(error),
+        };
+
+        let previous = check_promotion(&changeset, environment, &self.config)?;
+
+        Ok(ChangesetPromotion {
+            changeset,
+            release,
+            environment: environment.to_string(),
+            previous,
+        })
+    }
+
+    /// Records a planned promotion in the changeset.
+    ///
+    /// Pending changesets are saved in place and archived ones are rewritten in history.
+    /// The promotion is attributed to the current git user when author tracking is enabled.
+    ///
+    /// # Parameters
+    ///
+    /// * `promotion` - A promotion returned by [`plan_promotion`](Self::plan_promotion)
+    ///
+    /// # Returns
+    ///
+    /// The changeset with the promotion recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if storage operation fails.
+    pub async fn apply_promotion(
+        &self,
+        promotion: &ChangesetPromotion,
+    ) -> ChangesetResult<Changeset> {
+        let mut changeset = promotion.changeset.clone();
+        let author =
+            if self.config.track_authors { current_author(self.git_repo.as_ref()) } else { None };
+        changeset.record_promotion(promotion.environment.clone(), author);
+
+        match &promotion.release {
+            Some(release) => {
+                let archived = ArchivedChangeset::new(changeset.clone(), release.clone());
+                self.storage.save_archived(&archived).await?;
+            }
+            None => self.storage.save(&changeset).await?,
+        }
+
+        Ok(changeset)
+    }
+
+    /// Promotes a changeset into an environment.
+    ///
+    /// Shorthand for [`plan_promotion`](Self::plan_promotion) followed by
+    /// [`apply_promotion`](Self::apply_promotion).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `plan_promotion` and `apply_promotion`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # async fn example(manager: ChangesetManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// manager.promote("brave-lions-smile", "staging").await?;
+    /// manager.promote("brave-lions-smile", "production").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn promote(&self, reference: &str, environment: &str) -> ChangesetResult<Changeset> {
+        let promotion = self.plan_promotion(reference, environment).await?;
+        self.apply_promotion(&promotion).await
+    }
+
+    /// Lists the packages of pending changesets that are not in the workspace.
+    ///
+    /// See [`find_stale_packages`](super::find_stale_packages) for how renames are resolved.
+    ///
+    /// # Parameters
+    ///
+    /// * `workspace_packages` - Names of the packages in the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if pending changesets cannot be loaded.
+    pub async fn find_stale_packages(
+        &self,
+        workspace_packages: &HashSet<String>,
+    ) -> ChangesetResult<Vec<StalePackage>> {
+        let pending = self.storage.list_pending().await?;
+        Ok(find_stale_packages(&pending, workspace_packages, &self.config))
+    }
+
+    /// Renames or removes the packages of pending changesets that are not in the workspace.
+    ///
+    /// A stale package is renamed when `renames` or `changeset.package_renames` maps it to a
+    /// workspace package; `renames` takes precedence. Otherwise it is removed when `prune`
+    /// is set and kept when not. Modified changesets are saved through
+    /// [`update`](Self::update), so the change is recorded in their revisions. Changesets
+    /// left without packages are deleted.
+    ///
+    /// # Parameters
+    ///
+    /// * `workspace_packages` - Names of the packages in the workspace
+    /// * `renames` - Renames to apply on top of the configured ones, old name to new name
+    /// * `prune` - Whether to remove stale packages that have no rename
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if pending changesets cannot be loaded, saved, or deleted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::changeset::ChangesetManager;
+    /// # use std::collections::{BTreeMap, HashSet};
+    /// # async fn example(manager: ChangesetManager, workspace: HashSet<String>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let renames = BTreeMap::from([("@myorg/legacy".to_string(), "@myorg/compat".to_string())]);
+    /// let report = manager.clean_stale_packages(&workspace, &renames, true).await?;
+    /// println!("Renamed {}, removed {}", report.renamed.len(), report.removed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clean_stale_packages(
+        &self,
+        workspace_packages: &HashSet<String>,
+        renames: &BTreeMap<String, String>,
+        prune: bool,
+    ) -> ChangesetResult<StaleCleanupReport> {
+        let mut report = StaleCleanupReport::default();
+
+        for mut changeset in self.storage.list_pending().await? {
+            let stale = find_stale_packages(
+                std::slice::from_ref(&changeset),
+                workspace_packages,
+                &self.config,
+            );
+            if stale.is_empty() {
+                continue;
+            }
+
+            let mut modified = false;
+            for mut entry in stale {
+                if let Some(target) = renames.get(&entry.package)
+                    && workspace_packages.contains(target)
+                {
+                    entry.renamed_to = Some(target.clone());
+                }
+
+                match &entry.renamed_to {
+                    Some(target) => {
+                        rename_package(&mut changeset, &entry.package, target);
+                        modified = true;
+                        report.renamed.push(entry);
+                    }
+                    None if prune => {
+                        changeset.remove_package(&entry.package);
+                        modified = true;
+                        report.removed.push(entry);
+                    }
+                    None => report.kept.push(entry),
+                }
+            }
+
+            if !modified {
+                continue;
+            }
+            if changeset.packages.is_empty() {
+                self.delete(&changeset.branch).await?;
+                report.deleted.push(changeset.branch);
+            } else {
+                self.update(&changeset).await?;
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Replaces `from` with `to` in the packages of `changeset`, keeping its position.
+///
+/// When the changeset already lists `to`, `from` is removed instead.
+fn rename_package(changeset: &mut Changeset, from: &str, to: &str) {
+    if changeset.has_package(to) {
+        changeset.remove_package(from);
+    } else if let Some(package) = changeset.packages.iter_mut().find(|package| *package == from) {
+        *package = to.to_string();
+    }
 }
@@ -0,0 +1,199 @@
+//! Changeset migration between storage backends.
+//!
+//! **What**: Provides `migrate_changesets`, which copies every pending and archived
+//! changeset from one `ChangesetStorage` to another and reports what was copied.
+//!
+//! **How**: Archived changesets are copied first, then pending ones, so archiving into
+//! the target never removes a pending changeset the migration just wrote. Every copy is
+//! read back from the target and compared with the source. Changesets the target already
+//! holds unchanged are skipped, which makes an interrupted migration safe to re-run.
+//!
+//! **Why**: Moving to another backend or location must not lose changesets or release
+//! history, and a partially failed move must be resumable without duplicating entries.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::changeset::{FileBasedChangesetStorage, migrate_changesets};
+//! use sublime_standard_tools::filesystem::FileSystemManager;
+//! use std::path::PathBuf;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let fs = FileSystemManager::new();
+//! let root = PathBuf::from(".");
+//! let source = FileBasedChangesetStorage::new(
+//!     root.clone(),
+//!     ".changesets".to_string(),
+//!     ".changesets/history".to_string(),
+//!     fs.clone(),
+//! );
+//! let target = FileBasedChangesetStorage::new(
+//!     root,
+//!     "changes".to_string(),
+//!     "changes/history".to_string(),
+//!     fs,
+//! );
+//!
+//! let report = migrate_changesets(&source, &target).await?;
+//! println!("Copied {} changesets", report.copied_count());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::changeset::ChangesetStorage;
+use crate::error::{ChangesetError, ChangesetResult};
+use crate::types::{ArchivedChangeset, Changeset};
+use serde::Serialize;
+
+/// Outcome of a changeset migration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangesetMigrationReport {
+    /// Branches of pending changesets copied to the target.
+    pub pending_copied: Vec<String>,
+    /// Branches of pending changesets the target already held unchanged.
+    pub pending_skipped: Vec<String>,
+    /// Ids of archived changesets copied to the target.
+    pub archived_copied: Vec<String>,
+    /// Ids of archived changesets the target already held unchanged.
+    pub archived_skipped: Vec<String>,
+}
+
+impl ChangesetMigrationReport {
+    /// Returns the number of changesets written to the target.
+    #[must_use]
+    pub fn copied_count(&self) -> usize {
+        self.pending_copied.len() + self.archived_copied.len()
+    }
+
+    /// Returns the number of changesets the target already held.
+    #[must_use]
+    pub fn skipped_count(&self) -> usize {
+        self.pending_skipped.len() + self.archived_skipped.len()
+    }
+}
+
+/// Copies every pending and archived changeset from `source` to `target`.
+///
+/// The source is never modified. A changeset the target already holds unchanged is
+/// skipped, so re-running an interrupted migration resumes where it stopped.
+///
+/// # Arguments
+///
+/// * `source` - Storage to read changesets from
+/// * `target` - Storage to write changesets to
+///
+/// # Returns
+///
+/// A report listing the copied and skipped changesets.
+///
+/// # Errors
+///
+/// Returns `ChangesetError::MigrationFailed` if the target holds a different changeset
+/// under the same branch or id, or if a copied changeset does not read back unchanged.
+/// Storage errors from either side are returned as they are.
+pub async fn migrate_changesets(
+    source: &dyn ChangesetStorage,
+    target: &dyn ChangesetStorage,
+) -> ChangesetResult<ChangesetMigrationReport> {
+    let mut report = ChangesetMigrationReport::default();
+
+    // Archiving removes the target's pending changeset for the same branch, so history
+    // goes first
+    let mut archived = source.list_archived().await?;
+    archived.sort_by(|a, b| {
+        a.release_info
+            .applied_at
+            .cmp(&b.release_info.applied_at)
+            .then(a.changeset.id.cmp(&b.changeset.id))
+    });
+    for entry in archived {
+        let id = entry.changeset.id.clone();
+        if migrate_archived(target, entry).await? {
+            report.archived_copied.push(id);
+        } else {
+            report.archived_skipped.push(id);
+        }
+    }
+
+    let mut pending = source.list_pending().await?;
+    pending.sort_by(|a, b| a.branch.cmp(&b.branch));
+    for changeset in pending {
+        let branch = changeset.branch.clone();
+        if migrate_pending(target, &changeset).await? {
+            report.pending_copied.push(branch);
+        } else {
+            report.pending_skipped.push(branch);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Copies one archived changeset, returning `false` when the target already holds it.
+async fn migrate_archived(
+    target: &dyn ChangesetStorage,
+    entry: ArchivedChangeset,
+) -> ChangesetResult<bool> {
+    let id = entry.changeset.id.clone();
+    if let Some(existing) = load_archived_by_id(target, &id).await? {
+        if existing == entry {
+            return Ok(false);
+        }
+        return Err(ChangesetError::MigrationFailed {
+            reference: id,
+            reason: "the target already holds a different archived changeset with this id"
+                .to_string(),
+        });
+    }
+
+    target.archive(&entry.changeset, entry.release_info.clone()).await?;
+
+    match load_archived_by_id(target, &id).await? {
+        Some(copied) if copied == entry => Ok(true),
+        _ => Err(ChangesetError::MigrationFailed {
+            reference: id,
+            reason: "the archived changeset did not read back unchanged from the target"
+                .to_string(),
+        }),
+    }
+}
+
+/// Copies one pending changeset, returning `false` when the target already holds it.
+async fn migrate_pending(
+    target: &dyn ChangesetStorage,
+    changeset: &Changeset,
+) -> ChangesetResult<bool> {
+    if target.exists(&changeset.branch).await? {
+        if target.load(&changeset.branch).await? == *changeset {
+            return Ok(false);
+        }
+        return Err(ChangesetError::MigrationFailed {
+            reference: changeset.branch.clone(),
+            reason: "the target already holds a different changeset for this branch".to_string(),
+        });
+    }
+
+    target.save(changeset).await?;
+
+    if target.load(&changeset.branch).await? == *changeset {
+        Ok(true)
+    } else {
+        Err(ChangesetError::MigrationFailed {
+            reference: changeset.branch.clone(),
+            reason: "the changeset did not read back unchanged from the target".to_string(),
+        })
+    }
+}
+
+/// Loads the archived changeset with exactly `id`, ignoring name and branch matches.
+async fn load_archived_by_id(
+    storage: &dyn ChangesetStorage,
+    id: &str,
+) -> ChangesetResult<Option<ArchivedChangeset>> {
+    match storage.load_archived(id).await {
+        Ok(archived) if archived.changeset.id == id => Ok(Some(archived)),
+        Ok(_) | Err(ChangesetError::NotFound { .. }) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
@@ -21,22 +21,34 @@
 //! - What commits are associated with the changes
 //! - When the changeset was created and last updated
 //!
+//! ## Identifying Changesets
+//!
+//! Every changeset has a unique `id` and, when `changeset.human_names` is enabled, a
+//! generated `name` such as `brave-lions-smile`. Pending changesets are stored under their
+//! branch name, one per branch; archived changesets are stored under their id, so a branch
+//! can be released more than once. An id, a name, or a branch can be used to reference a
+//! changeset with `ChangesetManager::resolve` and `ChangesetHistory::get`.
+//!
 //! ## Changeset Lifecycle
 //!
 //! 1. **Create**: Initialize a new changeset for a branch with specific version bump and environments
 //! 2. **Update**: Modify changeset properties (packages, bump type, environments)
 //! 3. **Add Commits**: Associate Git commits with the changeset
 //! 4. **Archive**: Move the changeset to history when released
-//! 5. **Query**: Search through changeset history
+//! 5. **Promote**: Record the environments the release has reached
+//! 6. **Query**: Search through changeset history
 //!
 //! # Features
 //!
 //! - **Changeset Creation**: Create new changesets with branch, bump type, and environment targeting
-//! - **Changeset Storage**: Pluggable storage system (file-based by default)
+//! - **Changeset Storage**: Pluggable storage system (file-based by default), selectable by
+//!   name through `ChangesetBackendRegistry`
 //! - **History Management**: Archive changesets and maintain searchable history
 //! - **Git Integration**: Automatically add commits from Git and detect affected packages
 //! - **Update Tracking**: Track when changesets are created and modified
 //! - **Query API**: Search history by date, package, environment, or bump type
+//! - **Promotion**: Record promotions through environments in the configured order
+//! - **Migration**: Copy pending and archived changesets between storage backends
 //! - **Validation**: Ensure changesets are valid before saving
 //!
 //! # Example
@@ -124,6 +136,7 @@
 //! #   async fn list_pending(&self) -> ChangesetResult<Vec<String>> { todo!() }
 //! #   async fn archive(&self, changeset: &Changeset, release_info: crate::types::ReleaseInfo) -> ChangesetResult<()> { todo!() }
 //! #   async fn load_archived(&self, id: &str) -> ChangesetResult<ArchivedChangeset> { todo!() }
+//! #   async fn save_archived(&self, archived: &ArchivedChangeset) -> ChangesetResult<()> { todo!() }
 //! #   async fn list_archived(&self) -> ChangesetResult<Vec<String>> { todo!() }
 //! }
 //! ```
@@ -195,22 +208,43 @@
 //! This module contains:
 //! - `manager`: The main `ChangesetManager` for orchestrating changeset operations
 //! - `storage`: Storage trait and implementations (file-based)
+//! - `format`: Canonical changeset file serialization and the merge driver
 //! - `history`: History query API and archived changeset management
+//! - `stale`: Pending changeset entries for packages the workspace no longer has
 //! - `git_integration`: Git integration for detecting affected packages and commits
+//! - `proposal`: Packages and bump type proposed for a branch from its commits
 
 #![allow(clippy::todo)]
 
 // Internal modules
+mod author;
+mod backend;
+mod format;
 mod git_integration;
 mod history;
 mod manager;
+mod migration;
+mod names;
+mod promotion;
+mod proposal;
+mod query;
+mod stale;
 mod storage;
 
 #[cfg(test)]
 mod tests;
 
 // Public API - re-exports
+pub use backend::{
+    ChangesetBackendFactory, ChangesetBackendRegistry, ChangesetStorageLocation, FILE_BACKEND,
+};
+pub use format::{merge_changeset_files, to_canonical_json};
 pub use git_integration::PackageDetector;
 pub use history::ChangesetHistory;
 pub use manager::ChangesetManager;
+pub use migration::{ChangesetMigrationReport, migrate_changesets};
+pub use promotion::ChangesetPromotion;
+pub use proposal::{ChangesetProposal, PackageProposal, bump_for_commit_message};
+pub use query::{ChangesetPage, ChangesetQuery, ChangesetSortField};
+pub use stale::{StaleCleanupReport, StalePackage, find_stale_packages};
 pub use storage::{ChangesetStorage, FileBasedChangesetStorage};
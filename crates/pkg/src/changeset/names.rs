@@ -0,0 +1,211 @@
+//! Human-friendly changeset names.
+//!
+//! **What**: Generates names such as `brave-lions-smile` for changesets.
+//!
+//! **How**: An adjective, a plural noun, and a verb are picked from fixed word lists using
+//! bytes of a SHA-256 hash of the changeset id, so the same id always gets the same name.
+//! `ChangesetManager` assigns a name when `changeset.human_names` is enabled and picks a new
+//! id when the name is already used by a pending changeset.
+//!
+//! **Why**: Ids are unique but hard to remember and type. A short generated name can be
+//! read out, searched for, and passed to `changeset show` like a branch name.
+
+use sha2::{Digest, Sha256};
+
+const ADJECTIVES: &[&str] = &[
+    "brave", "calm", "clever", "cool", "cuddly", "curly", "eager", "early", "fair", "fancy",
+    "fast", "fluffy", "fresh", "funny", "gentle", "giant", "happy", "honest", "kind", "lazy",
+    "lemon", "lucky", "mighty", "modern", "neat", "nice", "odd", "polite", "proud", "quick",
+    "quiet", "rare", "red", "rich", "shaggy", "sharp", "shy", "silent", "silly", "slimy", "slow",
+    "smart", "smooth", "soft", "sour", "spicy", "strong", "sweet", "swift", "tall", "tame",
+    "tasty", "tender", "thick", "tidy", "tiny", "tough", "warm", "wet", "wicked", "wild", "wise",
+    "yellow", "young",
+];
+
+const NOUNS: &[&str] = &[
+    "actors",
+    "ants",
+    "apes",
+    "apples",
+    "bags",
+    "bananas",
+    "beans",
+    "bears",
+    "bees",
+    "birds",
+    "boats",
+    "books",
+    "bottles",
+    "buckets",
+    "bugs",
+    "buses",
+    "cameras",
+    "cars",
+    "cats",
+    "chairs",
+    "cherries",
+    "clocks",
+    "clouds",
+    "coats",
+    "cooks",
+    "cows",
+    "crabs",
+    "cups",
+    "deer",
+    "dingos",
+    "dodos",
+    "dogs",
+    "dolls",
+    "donkeys",
+    "doors",
+    "dots",
+    "dragons",
+    "drinks",
+    "ducks",
+    "eagles",
+    "eels",
+    "eggs",
+    "falcons",
+    "fans",
+    "feet",
+    "files",
+    "fireants",
+    "fishes",
+    "flies",
+    "foxes",
+    "frogs",
+    "geese",
+    "ghosts",
+    "goats",
+    "grapes",
+    "hairs",
+    "hats",
+    "hornets",
+    "horses",
+    "islands",
+    "jars",
+    "jokes",
+    "kids",
+    "kings",
+    "kiwis",
+    "knives",
+    "lamps",
+    "lemons",
+    "lions",
+    "lizards",
+    "llamas",
+    "maps",
+    "mice",
+    "monkeys",
+    "moles",
+    "moons",
+    "moose",
+    "mugs",
+    "numbers",
+    "olives",
+    "otters",
+    "owls",
+    "pandas",
+    "pans",
+    "pants",
+    "papayas",
+    "pears",
+    "pens",
+    "pets",
+    "pianos",
+    "pigs",
+    "planes",
+    "plants",
+    "poems",
+    "pumas",
+    "queens",
+    "rabbits",
+    "radios",
+    "rats",
+    "ravens",
+    "rings",
+    "rivers",
+    "rockets",
+    "rocks",
+    "roses",
+    "schools",
+    "seahorses",
+    "seals",
+    "sheep",
+    "shirts",
+    "shoes",
+    "singers",
+    "snails",
+    "snakes",
+    "socks",
+    "spiders",
+    "spies",
+    "squids",
+    "stars",
+    "swans",
+    "tables",
+    "taxis",
+    "teachers",
+    "tigers",
+    "toes",
+    "tomatoes",
+    "tools",
+    "toys",
+    "trains",
+    "trees",
+    "turkeys",
+    "turtles",
+    "waves",
+    "wasps",
+    "weeks",
+    "windows",
+    "wolves",
+    "worms",
+    "zebras",
+];
+
+const VERBS: &[&str] = &[
+    "accept", "add", "admire", "agree", "allow", "appear", "applaud", "approve", "argue", "arrive",
+    "attack", "attend", "bake", "battle", "beam", "beg", "behave", "bathe", "begin", "bet", "boil",
+    "bow", "brake", "breathe", "brush", "build", "burn", "buy", "call", "camp", "care", "carry",
+    "change", "cheat", "check", "cheer", "chew", "clap", "clean", "collect", "compare", "complain",
+    "confess", "cough", "count", "cover", "crash", "cross", "cry", "dance", "decide", "deliver",
+    "deny", "design", "destroy", "divide", "do", "double", "doubt", "draw", "dream", "dress",
+    "drive", "drop", "eat", "enjoy", "enter", "explain", "exist", "fail", "fetch", "film", "fix",
+    "flash", "float", "fly", "fold", "fry", "give", "glow", "grab", "greet", "grin", "grow",
+    "guess", "hammer", "hang", "happen", "heal", "hear", "help", "hide", "hope", "hug", "hunt",
+    "invent", "itch", "jam", "join", "joke", "judge", "juggle", "jump", "kick", "kiss", "kneel",
+    "knock", "know", "laugh", "lay", "lead", "learn", "leave", "lick", "lie", "listen", "live",
+    "look", "love", "make", "marry", "march", "matter", "melt", "mix", "move", "nail", "notice",
+    "obey", "occur", "open", "own", "pay", "peel", "play", "poke", "pretend", "promise", "pull",
+    "punch", "push", "raise", "reflect", "refuse", "relate", "relax", "remain", "repair", "repeat",
+    "reply", "report", "rescue", "rest", "retire", "return", "rhyme", "ring", "roll", "rule",
+    "run", "rush", "say", "scream", "search", "sell", "serve", "shake", "share", "shave", "shine",
+    "shop", "shout", "sin", "sing", "sink", "sip", "sit", "sleep", "slide", "smash", "smell",
+    "smile", "smoke", "sneeze", "sniff", "sort", "speak", "spend", "stare", "start", "study",
+    "swim", "switch", "talk", "taste", "teach", "tease", "tell", "thank", "think", "throw",
+    "tickle", "tie", "trade", "train", "travel", "try", "turn", "type", "unite", "vanish", "visit",
+    "wait", "walk", "warn", "wash", "watch", "wave", "whisper", "win", "wink", "wish", "wonder",
+    "work", "worry", "yawn", "yell",
+];
+
+/// Generates the human-friendly name for a changeset id.
+///
+/// The same id always yields the same name.
+///
+/// # Arguments
+///
+/// * `id` - The changeset id
+///
+/// # Returns
+///
+/// A name of the form `<adjective>-<noun>-<verb>`, such as `brave-lions-smile`.
+pub(crate) fn generate_name(id: &str) -> String {
+    let hash = Sha256::digest(id.as_bytes());
+    let pick = |words: &[&'static str], offset: usize| {
+        let index = usize::from(u16::from_be_bytes([hash[offset], hash[offset + 1]]));
+        words[index % words.len()]
+    };
+
+    format!("{}-{}-{}", pick(ADJECTIVES, 0), pick(NOUNS, 2), pick(VERBS, 4))
+}
@@ -0,0 +1,142 @@
+//! Environment promotion of pending and archived changesets.
+//!
+//! **What**: Provides `ChangesetPromotion`, the validated promotion of a changeset into a
+//! deployment environment, and the rules deciding whether a promotion is allowed.
+//!
+//! **How**: A promotion is planned by `ChangesetManager::plan_promotion`, which finds the
+//! changeset among the pending ones or in history and checks it against the configured
+//! promotion order: the environment must be available and targeted by the changeset, must
+//! not have been promoted into already, and every targeted environment before it in the
+//! order must have been promoted into first. `ChangesetManager::apply_promotion` then records
+//! the promotion in the changeset file, so callers can run their own release steps (moving
+//! tags or dist-tags) in between and skip recording when those fail.
+//!
+//! **Why**: Environments declared in configuration say where a release goes, but not where
+//! it has been. Recording promotions on the changeset keeps that state in the repository
+//! next to the release, and enforcing the order keeps a release from reaching `production`
+//! without passing `staging`.
+
+use crate::config::ChangesetConfig;
+use crate::error::{ChangesetError, ChangesetResult};
+use crate::types::{Changeset, ReleaseInfo};
+
+/// A promotion of a changeset into an environment that passed the promotion rules.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// # use sublime_pkg_tools::changeset::ChangesetManager;
+/// # async fn example(manager: ChangesetManager) -> Result<(), Box<dyn std::error::Error>> {
+/// let promotion = manager.plan_promotion("brave-lions-smile", "production").await?;
+/// for (package, version) in promotion.released_versions() {
+///     println!("{package}@{version} -> production");
+/// }
+/// manager.apply_promotion(&promotion).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangesetPromotion {
+    /// The changeset, without the promotion recorded.
+    pub changeset: Changeset,
+
+    /// Release metadata, when the changeset was archived.
+    pub release: Option<ReleaseInfo>,
+
+    /// Environment the changeset is promoted into.
+    pub environment: String,
+
+    /// Environment the changeset reached before, when one precedes `environment`.
+    pub previous: Option<String>,
+}
+
+impl ChangesetPromotion {
+    /// Returns whether the promoted changeset was already released and archived.
+    #[must_use]
+    pub fn is_archived(&self) -> bool {
+        self.release.is_some()
+    }
+
+    /// Returns the released versions of the changeset's packages, sorted by package name.
+    ///
+    /// The release may hold other packages, released together from other changesets or
+    /// bumped as dependents; those are left out so re-releasing stays scoped to the
+    /// changeset. Empty for pending changesets, which have not been released.
+    #[must_use]
+    pub fn released_versions(&self) -> Vec<(&str, &str)> {
+        let mut versions: Vec<(&str, &str)> = self
+            .release
+            .iter()
+            .flat_map(|release| release.versions.iter())
+            .filter(|(package, _)| self.changeset.has_package(package))
+            .map(|(package, version)| (package.as_str(), version.as_str()))
+            .collect();
+        versions.sort_unstable();
+        versions
+    }
+}
+
+/// Checks that `changeset` may be promoted into `environment`.
+///
+/// Returns the environment that precedes `environment` among those the changeset targets,
+/// if any.
+///
+/// # Errors
+///
+/// Returns `ChangesetError::InvalidEnvironment` if the environment is not available and
+/// `ChangesetError::PromotionRejected` if the changeset does not target it, was already
+/// promoted into it, or was not promoted into the preceding environment.
+pub(crate) fn check_promotion(
+    changeset: &Changeset,
+    environment: &str,
+    config: &ChangesetConfig,
+) -> ChangesetResult<Option<String>> {
+    if !config.available_environments.iter().any(|env| env == environment) {
+        return Err(ChangesetError::InvalidEnvironment {
+            environment: environment.to_string(),
+            available: config.available_environments.clone(),
+        });
+    }
+
+    let reject = |reason: String| ChangesetError::PromotionRejected {
+        reference: changeset.branch.clone(),
+        environment: environment.to_string(),
+        reason,
+    };
+
+    if !changeset.environments.iter().any(|env| env == environment) {
+        return Err(reject(format!(
+            "the changeset targets [{}] only",
+            changeset.environments.join(", ")
+        )));
+    }
+    if let Some(promotion) =
+        changeset.promotions.iter().find(|promotion| promotion.environment == environment)
+    {
+        return Err(reject(format!(
+            "already promoted on {}",
+            promotion.timestamp.format("%Y-%m-%d %H:%M UTC")
+        )));
+    }
+
+    // Environments the changeset does not target are skipped
+    let order = config.promotion_order();
+    let previous = order
+        .iter()
+        .position(|env| *env == environment)
+        .and_then(|position| {
+            order[..position]
+                .iter()
+                .rev()
+                .find(|env| changeset.environments.iter().any(|target| target == *env))
+        })
+        .map(|env| (*env).to_string());
+
+    if let Some(previous) = &previous
+        && !changeset.is_promoted_to(previous)
+    {
+        return Err(reject(format!("it must be promoted to '{previous}' first")));
+    }
+
+    Ok(previous)
+}
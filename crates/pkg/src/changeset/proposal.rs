@@ -0,0 +1,97 @@
+//! Changeset proposals derived from a branch's commits.
+//!
+//! **What**: Provides `ChangesetProposal`, the packages and bump type suggested for a branch,
+//! and `bump_for_commit_message`, which maps one commit message to a bump type.
+//!
+//! **How**: `PackageDetector::propose_changeset` walks the commits between the branch and
+//! its merge-base with the base branch, maps each commit's files to packages, and raises each
+//! package's bump to the highest one its commits call for. Conventional commits decide the
+//! bump: breaking changes are major, `feat` is minor, and everything else, including
+//! non-conventional messages, is patch. Squash commits are split into the commits they
+//! combine.
+//!
+//! **Why**: Contributors often know what they changed but not which packages it touched or
+//! which bump it warrants. Proposing both from the diff leaves them to confirm or adjust.
+
+use crate::changelog::ConventionalCommit;
+use crate::types::VersionBump;
+use serde::Serialize;
+
+/// Packages and bump type proposed for a changeset from a branch's commits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangesetProposal {
+    /// Base branch the branch was compared with.
+    pub base: String,
+
+    /// Merge-base commit of the branch and the base branch.
+    pub merge_base: String,
+
+    /// Highest bump proposed for any package, or `None` if no package is affected.
+    pub bump: VersionBump,
+
+    /// Affected packages, sorted by name.
+    pub packages: Vec<PackageProposal>,
+
+    /// Number of commits on the branch since the merge-base.
+    pub commit_count: usize,
+}
+
+impl ChangesetProposal {
+    /// Returns the names of the affected packages.
+    #[must_use]
+    pub fn package_names(&self) -> Vec<String> {
+        self.packages.iter().map(|package| package.name.clone()).collect()
+    }
+}
+
+/// A package affected by a branch, with the bump its commits call for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackageProposal {
+    /// Package name.
+    pub name: String,
+
+    /// Highest bump called for by the commits touching the package.
+    pub bump: VersionBump,
+
+    /// Hashes of the commits touching the package, newest first.
+    pub commits: Vec<String>,
+}
+
+/// Returns the bump a commit message calls for.
+///
+/// Breaking changes (`!` or a `BREAKING CHANGE` footer) are major, `feat` commits minor,
+/// and any other commit patch. Squash commits take the highest bump of the commits they
+/// combine.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changeset::bump_for_commit_message;
+/// use sublime_pkg_tools::types::VersionBump;
+///
+/// assert_eq!(bump_for_commit_message("feat(auth)!: drop sessions"), VersionBump::Major);
+/// assert_eq!(bump_for_commit_message("feat: add login"), VersionBump::Minor);
+/// assert_eq!(bump_for_commit_message("Update README"), VersionBump::Patch);
+/// ```
+#[must_use]
+pub fn bump_for_commit_message(message: &str) -> VersionBump {
+    let Some(messages) = ConventionalCommit::split_squashed(message) else {
+        return conventional_bump(message);
+    };
+    messages
+        .iter()
+        .map(|message| conventional_bump(message))
+        .max_by_key(|bump| bump.rank())
+        .unwrap_or(VersionBump::Patch)
+}
+
+/// Maps a single commit message to a bump.
+fn conventional_bump(message: &str) -> VersionBump {
+    match ConventionalCommit::parse(message) {
+        Ok(commit) if commit.is_breaking() => VersionBump::Major,
+        Ok(commit) if commit.commit_type() == "feat" => VersionBump::Minor,
+        _ => VersionBump::Patch,
+    }
+}
@@ -0,0 +1,257 @@
+//! Filtering, sorting, and pagination of pending changesets.
+//!
+//! **What**: Provides `ChangesetQuery`, a description of which pending changesets to return and
+//! in what order, and `ChangesetPage`, the slice of results it produces.
+//!
+//! **How**: A query holds optional filters (package, bump, environment, author, creation age),
+//! a sort field, and an offset/limit window. `ChangesetManager::query` loads pending changesets,
+//! resolves author filters through git, and hands the rest to `ChangesetQuery::apply`, which
+//! filters, sorts, and slices in memory.
+//!
+//! **Why**: Workspaces with dozens of pending changesets need a way to narrow the list. Keeping
+//! the logic in the library lets the CLI and bindings share the same semantics.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Changeset, VersionBump};
+
+/// Field used to order query results.
+///
+/// Each field has a natural direction: timestamps sort newest first, branches alphabetically,
+/// and bumps from major to none. [`ChangesetQuery::reverse`] flips it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangesetSortField {
+    /// Last update time, most recent first.
+    #[default]
+    UpdatedAt,
+    /// Creation time, most recent first.
+    CreatedAt,
+    /// Branch name, alphabetical.
+    Branch,
+    /// Bump type, major first.
+    Bump,
+}
+
+/// A filter, sort, and pagination request over pending changesets.
+///
+/// All filters are combined with AND. A default query matches every changeset, sorted by last
+/// update with no pagination.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changeset::{ChangesetQuery, ChangesetSortField};
+/// use sublime_pkg_tools::types::VersionBump;
+///
+/// let query = ChangesetQuery::new()
+///     .with_package("@myorg/core")
+///     .with_bump(VersionBump::Minor)
+///     .sort_by(ChangesetSortField::Branch)
+///     .with_limit(10);
+///
+/// assert_eq!(query.limit, Some(10));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangesetQuery {
+    /// Only changesets that affect this package.
+    pub package: Option<String>,
+
+    /// Only changesets with this bump type.
+    pub bump: Option<VersionBump>,
+
+    /// Only changesets targeting this environment.
+    pub environment: Option<String>,
+
+    /// Only changesets containing a commit whose author name or email contains this text
+    /// (case-insensitive). Requires a git repository.
+    pub author: Option<String>,
+
+    /// Only changesets created at or after this instant.
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// Only changesets created at or before this instant.
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// Field used to order results.
+    pub sort: ChangesetSortField,
+
+    /// Whether to reverse the natural order of the sort field.
+    pub reverse: bool,
+
+    /// Number of matching changesets to skip.
+    pub offset: usize,
+
+    /// Maximum number of changesets to return.
+    pub limit: Option<usize>,
+}
+
+impl ChangesetQuery {
+    /// Creates a query that matches every changeset.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts results to changesets affecting `package`.
+    #[must_use]
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// Restricts results to changesets with the given bump.
+    #[must_use]
+    pub fn with_bump(mut self, bump: VersionBump) -> Self {
+        self.bump = Some(bump);
+        self
+    }
+
+    /// Restricts results to changesets targeting `environment`.
+    #[must_use]
+    pub fn with_environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// Restricts results to changesets with a commit by a matching author.
+    #[must_use]
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Restricts results to changesets created at or after `instant`.
+    #[must_use]
+    pub fn created_after(mut self, instant: DateTime<Utc>) -> Self {
+        self.created_after = Some(instant);
+        self
+    }
+
+    /// Restricts results to changesets created at or before `instant`.
+    #[must_use]
+    pub fn created_before(mut self, instant: DateTime<Utc>) -> Self {
+        self.created_before = Some(instant);
+        self
+    }
+
+    /// Sets the sort field.
+    #[must_use]
+    pub fn sort_by(mut self, sort: ChangesetSortField) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Reverses the natural order of the sort field.
+    #[must_use]
+    pub fn reversed(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Skips the first `offset` matching changesets.
+    #[must_use]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns at most `limit` changesets.
+    #[must_use]
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns `true` if `changeset` passes every filter except `author`.
+    ///
+    /// Author filtering needs commit metadata and is resolved by
+    /// `ChangesetManager::query`.
+    #[must_use]
+    pub fn matches(&self, changeset: &Changeset) -> bool {
+        self.package.as_deref().is_none_or(|package| changeset.has_package(package))
+            && self.bump.is_none_or(|bump| changeset.bump == bump)
+            && self
+                .environment
+                .as_ref()
+                .is_none_or(|environment| changeset.environments.contains(environment))
+            && self.created_after.is_none_or(|after| changeset.created_at >= after)
+            && self.created_before.is_none_or(|before| changeset.created_at <= before)
+    }
+
+    /// Filters, sorts, and paginates `changesets`.
+    ///
+    /// The `author` filter is not applied here.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::changeset::{ChangesetQuery, ChangesetSortField};
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let changesets = vec![
+    ///     Changeset::new("feature/b", VersionBump::Patch, vec![]),
+    ///     Changeset::new("feature/a", VersionBump::Major, vec![]),
+    ///     Changeset::new("feature/c", VersionBump::Minor, vec![]),
+    /// ];
+    ///
+    /// let page = ChangesetQuery::new()
+    ///     .sort_by(ChangesetSortField::Branch)
+    ///     .with_offset(1)
+    ///     .with_limit(1)
+    ///     .apply(changesets);
+    ///
+    /// assert_eq!(page.total, 3);
+    /// assert_eq!(page.changesets[0].branch, "feature/b");
+    /// assert!(page.has_more());
+    /// ```
+    #[must_use]
+    pub fn apply(&self, changesets: Vec<Changeset>) -> ChangesetPage {
+        let mut matching: Vec<Changeset> =
+            changesets.into_iter().filter(|changeset| self.matches(changeset)).collect();
+
+        match self.sort {
+            ChangesetSortField::UpdatedAt => {
+                matching.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            }
+            ChangesetSortField::CreatedAt => {
+                matching.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            }
+            ChangesetSortField::Branch => matching.sort_by(|a, b| a.branch.cmp(&b.branch)),
+            ChangesetSortField::Bump => {
+                matching.sort_by(|a, b| b.bump.rank().cmp(&a.bump.rank()));
+            }
+        }
+        if self.reverse {
+            matching.reverse();
+        }
+
+        let total = matching.len();
+        let changesets: Vec<Changeset> =
+            matching.into_iter().skip(self.offset).take(self.limit.unwrap_or(usize::MAX)).collect();
+
+        ChangesetPage { changesets, total, offset: self.offset }
+    }
+}
+
+/// A window of query results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangesetPage {
+    /// Changesets in the requested window, in sort order.
+    pub changesets: Vec<Changeset>,
+
+    /// Number of changesets that matched the filters, before pagination.
+    pub total: usize,
+
+    /// Number of matching changesets skipped before this window.
+    pub offset: usize,
+}
+
+impl ChangesetPage {
+    /// Returns `true` if more matching changesets follow this window.
+    #[must_use]
+    pub fn has_more(&self) -> bool {
+        self.offset + self.changesets.len() < self.total
+    }
+}
@@ -0,0 +1,102 @@
+//! Pending changeset entries for packages the workspace no longer has.
+//!
+//! **What**: Provides `find_stale_packages`, which lists the packages of pending changesets
+//! that are not in the workspace, and the `StaleCleanupReport` of rewriting them.
+//!
+//! **How**: Each stale entry carries the package it was renamed to, when
+//! `changeset.package_renames` maps it to a workspace package. `ChangesetManager` uses these
+//! entries to rename or prune packages and to delete changesets left without any.
+//!
+//! **Why**: A changeset written before a package was removed or renamed can no longer be
+//! applied. Listing and rewriting those entries lets the release go ahead without editing
+//! changeset files by hand.
+
+use crate::config::ChangesetConfig;
+use crate::types::Changeset;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// A package listed by a pending changeset that is not in the workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StalePackage {
+    /// Branch of the changeset that lists the package.
+    pub branch: String,
+
+    /// Name of the package as listed by the changeset.
+    pub package: String,
+
+    /// Workspace package it was renamed to, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub renamed_to: Option<String>,
+}
+
+/// Outcome of cleaning stale packages out of pending changesets.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaleCleanupReport {
+    /// Entries rewritten to the package they were renamed to.
+    pub renamed: Vec<StalePackage>,
+    /// Entries removed from their changeset.
+    pub removed: Vec<StalePackage>,
+    /// Entries left untouched, because they have no rename and pruning was not requested.
+    pub kept: Vec<StalePackage>,
+    /// Branches of changesets deleted because no package was left.
+    pub deleted: Vec<String>,
+}
+
+impl StaleCleanupReport {
+    /// Returns whether any changeset was modified or deleted.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        !self.renamed.is_empty() || !self.removed.is_empty() || !self.deleted.is_empty()
+    }
+}
+
+/// Lists the packages of `changesets` that are not in `workspace_packages`.
+///
+/// Entries are returned in changeset order. `renamed_to` is set when
+/// `config.package_renames` leads to a package of the workspace.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::changeset::find_stale_packages;
+/// use sublime_pkg_tools::config::ChangesetConfig;
+/// use sublime_pkg_tools::types::{Changeset, VersionBump};
+/// use std::collections::HashSet;
+///
+/// let mut changeset = Changeset::new("feat/api", VersionBump::Minor, vec!["production".into()]);
+/// changeset.add_package("@myorg/core");
+/// changeset.add_package("@myorg/legacy");
+///
+/// let mut config = ChangesetConfig::default();
+/// config.package_renames.insert("@myorg/legacy".into(), "@myorg/compat".into());
+/// let workspace: HashSet<String> = ["@myorg/core".into(), "@myorg/compat".into()].into();
+///
+/// let stale = find_stale_packages(&[changeset], &workspace, &config);
+/// assert_eq!(stale.len(), 1);
+/// assert_eq!(stale[0].renamed_to.as_deref(), Some("@myorg/compat"));
+/// ```
+#[must_use]
+pub fn find_stale_packages(
+    changesets: &[Changeset],
+    workspace_packages: &HashSet<String>,
+    config: &ChangesetConfig,
+) -> Vec<StalePackage> {
+    changesets
+        .iter()
+        .flat_map(|changeset| {
+            changeset.packages.iter().filter(|package| !workspace_packages.contains(*package)).map(
+                |package| StalePackage {
+                    branch: changeset.branch.clone(),
+                    package: package.clone(),
+                    renamed_to: config
+                        .renamed_package(package)
+                        .filter(|name| workspace_packages.contains(*name))
+                        .map(str::to_string),
+                },
+            )
+        })
+        .collect()
+}
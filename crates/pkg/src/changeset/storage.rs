@@ -28,7 +28,7 @@
 //! ## Archive Operations
 //!
 //! - **archive**: Move a changeset to history with release information
-//! - **load_archived**: Retrieve an archived changeset by branch name
+//! - **load_archived**: Retrieve an archived changeset by id, name, or branch name
 //!
 //! # Examples
 //!
@@ -104,17 +104,26 @@
 //!             changeset.clone(),
 //!             release_info,
 //!         );
-//!         archived.insert(changeset.branch.clone(), archived_changeset);
+//!         archived.insert(changeset.id.clone(), archived_changeset);
 //!         Ok(())
 //!     }
 //!
-//!     async fn load_archived(&self, branch: &str) -> ChangesetResult<ArchivedChangeset> {
+//!     async fn load_archived(&self, reference: &str) -> ChangesetResult<ArchivedChangeset> {
 //!         let archived = self.archived.read().await;
-//!         archived.get(branch).cloned().ok_or_else(|| {
-//!             sublime_pkg_tools::error::ChangesetError::NotFound {
-//!                 branch: branch.to_string(),
-//!             }
-//!         })
+//!         archived
+//!             .values()
+//!             .filter(|archived| archived.changeset.matches_reference(reference))
+//!             .max_by_key(|archived| archived.release_info.applied_at)
+//!             .cloned()
+//!             .ok_or_else(|| sublime_pkg_tools::error::ChangesetError::NotFound {
+//!                 branch: reference.to_string(),
+//!             })
+//!     }
+//!
+//!     async fn save_archived(&self, changeset: &ArchivedChangeset) -> ChangesetResult<()> {
+//!         let mut archived = self.archived.write().await;
+//!         archived.insert(changeset.changeset.id.clone(), changeset.clone());
+//!         Ok(())
 //!     }
 //!
 //!     async fn list_archived(&self) -> ChangesetResult<Vec<ArchivedChangeset>> {
@@ -186,9 +195,13 @@
 //! Implementations should use appropriate error variants from `ChangesetError` to provide
 //! clear error messages with context.
 
+use crate::changeset::format::to_canonical_json;
 use crate::error::{ChangesetError, ChangesetResult};
 use crate::types::{ArchivedChangeset, Changeset, ReleaseInfo};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 
 /// Trait for changeset storage operations.
 ///
@@ -419,7 +432,7 @@ pub trait ChangesetStorage: Send + Sync {
     /// * `ChangesetError::StorageError` - If storage operations fail
     /// * `ChangesetError::SerializationError` - If serialization fails
     /// * `ChangesetError::PermissionDenied` - If lacking necessary permissions
-    /// * `ChangesetError::AlreadyExists` - If an archived changeset with this branch already exists
+    /// * `ChangesetError::AlreadyExists` - If an archived changeset with this id already exists
     ///
     /// # Examples
     ///
@@ -445,14 +458,16 @@ pub trait ChangesetStorage: Send + Sync {
         release_info: ReleaseInfo,
     ) -> ChangesetResult<()>;
 
-    /// Loads an archived changeset by branch name.
+    /// Loads an archived changeset by id, name, or branch name.
     ///
     /// Retrieves an archived changeset from history storage. This includes both the
-    /// original changeset data and the release metadata added during archiving.
+    /// original changeset data and the release metadata added during archiving. When
+    /// several archived changesets match a branch or name, the most recently released one
+    /// is returned.
     ///
     /// # Arguments
     ///
-    /// * `branch` - The branch name identifying the archived changeset
+    /// * `reference` - The id, name, or branch name of the archived changeset
     ///
     /// # Returns
     ///
@@ -461,7 +476,7 @@ pub trait ChangesetStorage: Send + Sync {
     ///
     /// # Errors
     ///
-    /// * `ChangesetError::NotFound` - If no archived changeset exists for the branch
+    /// * `ChangesetError::NotFound` - If no archived changeset matches the reference
     /// * `ChangesetError::StorageError` - If reading from storage fails
     /// * `ChangesetError::SerializationError` - If deserialization fails
     /// * `ChangesetError::PermissionDenied` - If lacking read permissions
@@ -476,7 +491,32 @@ pub trait ChangesetStorage: Send + Sync {
     ///     println!("  {} -> {}", pkg, version);
     /// }
     /// ```
-    async fn load_archived(&self, branch: &str) -> ChangesetResult<ArchivedChangeset>;
+    async fn load_archived(&self, reference: &str) -> ChangesetResult<ArchivedChangeset>;
+
+    /// Overwrites an archived changeset.
+    ///
+    /// Used to record what happened to a release after it was archived, such as its
+    /// promotion into further environments. The archive is matched by changeset id and
+    /// keeps its location.
+    ///
+    /// # Arguments
+    ///
+    /// * `archived` - The updated archived changeset
+    ///
+    /// # Errors
+    ///
+    /// * `ChangesetError::NotFound` - If no archived changeset has this id
+    /// * `ChangesetError::StorageError` - If writing to storage fails
+    /// * `ChangesetError::SerializationError` - If serialization fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut archived = storage.load_archived("feature/oauth").await?;
+    /// archived.changeset.record_promotion("production", None);
+    /// storage.save_archived(&archived).await?;
+    /// ```
+    async fn save_archived(&self, archived: &ArchivedChangeset) -> ChangesetResult<()>;
 
     /// Lists all archived changesets.
     ///
@@ -520,11 +560,12 @@ pub trait ChangesetStorage: Send + Sync {
 /// ```text
 /// <root_path>/
 /// ├── <changeset_dir>/        # Pending changesets
-/// │   ├── feature-branch.json
-/// │   └── fix-bug.json
+/// │   ├── 5b0e7d13c9a2.json
+/// │   └── c41f0a8e62d7.json
 /// └── <history_dir>/          # Archived changesets
-///     ├── feature-branch.json
-///     └── release-v1.json
+///     ├── index.json          # Id, name and branch of each archive
+///     ├── 3f9a1c0b7d2e.json
+///     └── 8e41d05a9b6c.json
 /// ```
 ///
 /// # Thread Safety
@@ -536,9 +577,19 @@ pub trait ChangesetStorage: Send + Sync {
 ///
 /// # File Format
 ///
-/// Changesets are stored as JSON files with the branch name as the filename
-/// (sanitized for filesystem compatibility). Each file contains the complete
-/// serialized `Changeset` or `ArchivedChangeset` structure.
+/// Pending and archived changesets are stored as JSON files with their id as the filename,
+/// so branch names never collide and a branch can be archived more than once. Pending
+/// changesets are found by branch by reading the pending files, of which there is one per
+/// active branch. Archives are found by id from their filename, and by name or branch
+/// through `index.json` in the history directory, which records the id, name, branch and
+/// release time of every archive and is brought up to date from the archive files it does
+/// not list yet. Files written before ids existed keep their branch filename and are
+/// still found. Each file contains the complete serialized `Changeset` or
+/// `ArchivedChangeset` structure, written in the canonical form of `to_canonical_json` so
+/// saving an unchanged changeset never changes its file.
+///
+/// Changesets loaded without an id get one derived from their branch and creation time,
+/// see `Changeset::ensure_id`.
 ///
 /// # Examples
 ///
@@ -635,27 +686,53 @@ where
     ///
     /// # Arguments
     ///
-    /// * `branch` - The branch name to convert to a file path
+    /// * `id` - The id of the pending changeset
     ///
     /// # Returns
     ///
     /// Returns the absolute path to where the changeset file should be stored.
-    fn changeset_path(&self, branch: &str) -> std::path::PathBuf {
-        let filename = Self::sanitize_branch_name(branch);
+    pub fn changeset_path(&self, id: &str) -> std::path::PathBuf {
+        let filename = Self::sanitize_branch_name(id);
         self.root_path.join(&self.changeset_dir).join(format!("{}.json", filename))
     }
 
+    /// Finds the file of the pending changeset for a branch.
+    ///
+    /// Pending files are named by id, so the pending files are read until one belongs to
+    /// the branch. A file named by the branch, as written before ids existed, is checked
+    /// first.
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - The branch name
+    ///
+    /// # Returns
+    ///
+    /// Returns the path of the changeset file, or `None` if the branch has no pending
+    /// changeset.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::StorageError` if the pending directory cannot be read.
+    pub async fn find_changeset_path(
+        &self,
+        branch: &str,
+    ) -> ChangesetResult<Option<std::path::PathBuf>> {
+        let (found, _) = self.find_pending(branch).await?;
+        Ok(found.map(|(path, _)| path))
+    }
+
     /// Returns the full path to an archived changeset file.
     ///
     /// # Arguments
     ///
-    /// * `branch` - The branch name to convert to a file path
+    /// * `id` - The id of the archived changeset
     ///
     /// # Returns
     ///
     /// Returns the absolute path to where the archived changeset file should be stored.
-    fn archive_path(&self, branch: &str) -> std::path::PathBuf {
-        let filename = Self::sanitize_branch_name(branch);
+    pub fn archive_path(&self, id: &str) -> std::path::PathBuf {
+        let filename = Self::sanitize_branch_name(id);
         self.root_path.join(&self.history_dir).join(format!("{}.json", filename))
     }
 
@@ -729,7 +806,10 @@ where
     F: sublime_standard_tools::filesystem::AsyncFileSystem,
 {
     async fn save(&self, changeset: &Changeset) -> ChangesetResult<()> {
-        let path = self.changeset_path(&changeset.branch);
+        let mut changeset = changeset.clone();
+        changeset.ensure_id();
+        let path = self.changeset_path(&changeset.id);
+        let (previous, _) = self.find_pending(&changeset.branch).await?;
 
         // Ensure the parent directory exists
         if let Some(parent) = path.parent() {
@@ -739,68 +819,48 @@ where
             })?;
         }
 
-        // Serialize the changeset to JSON
-        let json = serde_json::to_string_pretty(changeset).map_err(|e| {
-            ChangesetError::SerializationError {
-                operation: "serialize".to_string(),
-                reason: format!("Failed to serialize changeset: {}", e),
-            }
-        })?;
+        // Serialize the changeset to canonical JSON
+        let json = to_canonical_json(&changeset)?;
 
         // Write to file atomically
-        self.fs.write_file_string(&path, &json).await.map_err(|e| {
+        self.fs.write_file_atomic(&path, &json).await.map_err(|e| {
             ChangesetError::StorageError {
                 path: path.clone(),
                 reason: format!("Failed to write changeset file: {}", e),
             }
         })?;
 
+        // A branch keeps one pending file, so the one it replaces is removed
+        if let Some((previous, _)) = previous
+            && previous != path
+        {
+            self.fs.remove(&previous).await.map_err(|e| ChangesetError::StorageError {
+                path: previous.clone(),
+                reason: format!("Failed to delete replaced changeset file: {}", e),
+            })?;
+        }
+
         Ok(())
     }
 
     async fn load(&self, branch: &str) -> ChangesetResult<Changeset> {
-        let path = self.changeset_path(branch);
-
-        // Check if file exists
-        let exists = self.fs.exists(&path).await;
-
-        if !exists {
-            return Err(ChangesetError::NotFound { branch: branch.to_string() });
+        match self.find_pending(branch).await? {
+            (Some((_, changeset)), _) => Ok(changeset),
+            // An unreadable file may be the branch's changeset, so its error is reported
+            (None, Some(unreadable)) => Err(unreadable),
+            (None, None) => Err(ChangesetError::NotFound { branch: branch.to_string() }),
         }
-
-        // Read file contents
-        let contents =
-            self.fs.read_file_string(&path).await.map_err(|e| ChangesetError::StorageError {
-                path: path.clone(),
-                reason: format!("Failed to read changeset file: {}", e),
-            })?;
-
-        // Deserialize JSON
-        let changeset =
-            serde_json::from_str(&contents).map_err(|e| ChangesetError::SerializationError {
-                operation: "deserialize".to_string(),
-                reason: format!("Failed to deserialize changeset: {}", e),
-            })?;
-
-        Ok(changeset)
     }
 
     async fn exists(&self, branch: &str) -> ChangesetResult<bool> {
-        let path = self.changeset_path(branch);
-
-        Ok(self.fs.exists(&path).await)
+        Ok(self.find_changeset_path(branch).await?.is_some())
     }
 
     async fn delete(&self, branch: &str) -> ChangesetResult<()> {
-        let path = self.changeset_path(branch);
-
-        // Check if file exists before attempting to delete
-        let exists = self.fs.exists(&path).await;
-
-        // If the file doesn't exist, return success (idempotent operation)
-        if !exists {
+        // If no file belongs to the branch, return success (idempotent operation)
+        let Some(path) = self.find_changeset_path(branch).await? else {
             return Ok(());
-        }
+        };
 
         // Delete the file
         self.fs.remove(&path).await.map_err(|e| ChangesetError::StorageError {
@@ -858,8 +918,10 @@ where
         changeset: &Changeset,
         release_info: ReleaseInfo,
     ) -> ChangesetResult<()> {
-        let pending_path = self.changeset_path(&changeset.branch);
-        let archive_path = self.archive_path(&changeset.branch);
+        let mut changeset = changeset.clone();
+        changeset.ensure_id();
+
+        let archive_path = self.archive_path(&changeset.id);
 
         // Check if archived changeset already exists
         let archive_exists = self.fs.exists(&archive_path).await;
@@ -871,8 +933,15 @@ where
             });
         }
 
+        // Only the pending file of this changeset is removed, not a newer one for the branch
+        let pending_path = match self.find_pending(&changeset.branch).await? {
+            (Some((path, pending)), _) if pending.id == changeset.id => Some(path),
+            _ => None,
+        };
+
         // Create archived changeset
-        let archived = ArchivedChangeset::new(changeset.clone(), release_info);
+        let branch = changeset.branch.clone();
+        let archived = ArchivedChangeset::new(changeset, release_info);
 
         // Ensure history directory exists
         if let Some(parent) = archive_path.parent() {
@@ -883,27 +952,22 @@ where
         }
 
         // Serialize archived changeset
-        let json = serde_json::to_string_pretty(&archived).map_err(|e| {
-            ChangesetError::SerializationError {
-                operation: "serialize".to_string(),
-                reason: format!("Failed to serialize archived changeset: {}", e),
-            }
-        })?;
+        let json = to_canonical_json(&archived)?;
 
         // Write archived changeset
         self.fs.write_file_string(&archive_path, &json).await.map_err(|e| {
             ChangesetError::ArchiveError {
-                branch: changeset.branch.clone(),
+                branch: branch.clone(),
                 reason: format!("Failed to write archived changeset: {}", e),
             }
         })?;
 
-        // Delete from pending storage
-        let exists = self.fs.exists(&pending_path).await;
+        self.index_archive(&archive_path, &archived).await;
 
-        if exists {
+        // Delete from pending storage
+        if let Some(pending_path) = pending_path {
             self.fs.remove(&pending_path).await.map_err(|e| ChangesetError::ArchiveError {
-                branch: changeset.branch.clone(),
+                branch: branch.clone(),
                 reason: format!("Failed to delete pending changeset: {}", e),
             })?;
         }
@@ -911,59 +975,54 @@ where
         Ok(())
     }
 
-    async fn load_archived(&self, branch: &str) -> ChangesetResult<ArchivedChangeset> {
-        let path = self.archive_path(branch);
-
-        // Check if file exists
-        let exists = self.fs.exists(&path).await;
+    async fn load_archived(&self, reference: &str) -> ChangesetResult<ArchivedChangeset> {
+        // Archives are named by id
+        let path = self.archive_path(reference);
+        if !reference.is_empty() && self.fs.exists(&path).await {
+            let archived = self.load_archived_from_path(&path).await?;
+            if archived.changeset.id == reference {
+                return Ok(archived);
+            }
+        }
 
-        if !exists {
-            return Err(ChangesetError::NotFound { branch: branch.to_string() });
+        // Otherwise look the reference up by name or branch, latest release first
+        let index = self.archive_index().await?;
+        let latest = index
+            .archives
+            .iter()
+            .filter(|(_, entry)| entry.matches_reference(reference))
+            .max_by_key(|(_, entry)| entry.applied_at);
+        match latest {
+            Some((file, _)) => {
+                self.load_archived_from_path(&self.root_path.join(&self.history_dir).join(file))
+                    .await
+            }
+            None => Err(ChangesetError::NotFound { branch: reference.to_string() }),
         }
+    }
 
-        // Read file contents
-        let contents =
-            self.fs.read_file_string(&path).await.map_err(|e| ChangesetError::StorageError {
-                path: path.clone(),
-                reason: format!("Failed to read archived changeset file: {}", e),
-            })?;
+    async fn save_archived(&self, archived: &ArchivedChangeset) -> ChangesetResult<()> {
+        let path = self
+            .find_archive_path(&archived.changeset.id)
+            .await?
+            .ok_or_else(|| ChangesetError::NotFound { branch: archived.changeset.id.clone() })?;
 
-        // Deserialize JSON
-        let archived =
-            serde_json::from_str(&contents).map_err(|e| ChangesetError::SerializationError {
-                operation: "deserialize".to_string(),
-                reason: format!("Failed to deserialize archived changeset: {}", e),
-            })?;
+        let json = to_canonical_json(archived)?;
+        self.fs.write_file_string(&path, &json).await.map_err(|e| {
+            ChangesetError::StorageError {
+                path: path.clone(),
+                reason: format!("Failed to write archived changeset file: {}", e),
+            }
+        })?;
+        self.index_archive(&path, archived).await;
 
-        Ok(archived)
+        Ok(())
     }
 
     async fn list_archived(&self) -> ChangesetResult<Vec<ArchivedChangeset>> {
-        let dir_path = self.root_path.join(&self.history_dir);
-
-        // Check if directory exists
-        let exists = self.fs.exists(&dir_path).await;
-
-        // If directory doesn't exist, return empty list
-        if !exists {
-            return Ok(Vec::new());
-        }
-
-        // Read directory entries
-        let entries =
-            self.fs.read_dir(&dir_path).await.map_err(|e| ChangesetError::StorageError {
-                path: dir_path.clone(),
-                reason: format!("Failed to read history directory: {}", e),
-            })?;
-
         // Load all archived changeset files
         let mut archived_changesets = Vec::new();
-        for entry in entries {
-            // Only process .json files
-            if entry.extension().and_then(|e| e.to_str()) != Some("json") {
-                continue;
-            }
-
+        for entry in self.archive_files().await? {
             // Read and deserialize the file
             match self.load_archived_from_path(&entry).await {
                 Ok(archived) => archived_changesets.push(archived),
@@ -1003,11 +1062,12 @@ where
             })?;
 
         // Deserialize JSON
-        let changeset =
+        let mut changeset: Changeset =
             serde_json::from_str(&contents).map_err(|e| ChangesetError::SerializationError {
                 operation: "deserialize".to_string(),
                 reason: format!("Failed to deserialize changeset: {}", e),
             })?;
+        changeset.ensure_id();
 
         Ok(changeset)
     }
@@ -1035,12 +1095,213 @@ where
             })?;
 
         // Deserialize JSON
-        let archived =
+        let mut archived: ArchivedChangeset =
             serde_json::from_str(&contents).map_err(|e| ChangesetError::SerializationError {
                 operation: "deserialize".to_string(),
                 reason: format!("Failed to deserialize archived changeset: {}", e),
             })?;
+        archived.changeset.ensure_id();
 
         Ok(archived)
     }
+
+    /// Finds the pending changeset of a branch and the file it is stored in.
+    ///
+    /// Files that cannot be read are skipped; the error of the first one is returned next
+    /// to the result, since it may have been the branch's changeset.
+    async fn find_pending(
+        &self,
+        branch: &str,
+    ) -> ChangesetResult<(Option<(std::path::PathBuf, Changeset)>, Option<ChangesetError>)> {
+        let dir_path = self.root_path.join(&self.changeset_dir);
+        if !self.fs.exists(&dir_path).await {
+            return Ok((None, None));
+        }
+
+        let entries =
+            self.fs.read_dir(&dir_path).await.map_err(|e| ChangesetError::StorageError {
+                path: dir_path.clone(),
+                reason: format!("Failed to read changeset directory: {}", e),
+            })?;
+
+        // Files written before ids existed are named by branch, so that one is read first
+        let legacy = dir_path.join(format!("{}.json", Self::sanitize_branch_name(branch)));
+        let (legacy, others): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .filter(|entry| entry.extension().and_then(|e| e.to_str()) == Some("json"))
+            .partition(|entry| *entry == legacy);
+
+        let mut unreadable = None;
+        for entry in legacy.into_iter().chain(others) {
+            match self.load_from_path(&entry).await {
+                Ok(changeset) if changeset.branch == branch => {
+                    return Ok((Some((entry, changeset)), None));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    log::warn!("Failed to load changeset from {:?}: {}", entry, e);
+                    unreadable.get_or_insert(e);
+                }
+            }
+        }
+
+        Ok((None, unreadable))
+    }
+
+    /// Returns the archive files of the history directory, without the archive index.
+    async fn archive_files(&self) -> ChangesetResult<Vec<std::path::PathBuf>> {
+        let dir_path = self.root_path.join(&self.history_dir);
+        if !self.fs.exists(&dir_path).await {
+            return Ok(Vec::new());
+        }
+
+        let entries =
+            self.fs.read_dir(&dir_path).await.map_err(|e| ChangesetError::StorageError {
+                path: dir_path.clone(),
+                reason: format!("Failed to read history directory: {}", e),
+            })?;
+        Ok(entries
+            .into_iter()
+            .filter(|entry| {
+                entry.extension().and_then(|e| e.to_str()) == Some("json")
+                    && entry.file_name().and_then(|name| name.to_str()) != Some(ARCHIVE_INDEX_FILE)
+            })
+            .collect())
+    }
+
+    /// Returns the archive index, brought up to date with the history directory.
+    ///
+    /// Only the archive files the index does not list are read, and entries of removed
+    /// files are dropped. The index is rewritten when it changed.
+    async fn archive_index(&self) -> ChangesetResult<ArchiveIndex> {
+        let files = self.archive_files().await?;
+        let mut index = self.read_archive_index().await;
+
+        let names: HashSet<&str> = files
+            .iter()
+            .filter_map(|file| file.file_name().and_then(|name| name.to_str()))
+            .collect();
+        let listed = index.archives.len();
+        index.archives.retain(|file, _| names.contains(file.as_str()));
+        let mut changed = index.archives.len() != listed;
+
+        for file in &files {
+            let Some(name) = file.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if index.archives.contains_key(name) {
+                continue;
+            }
+            match self.load_archived_from_path(file).await {
+                Ok(archived) => {
+                    index.archives.insert(name.to_string(), ArchiveIndexEntry::of(&archived));
+                    changed = true;
+                }
+                Err(e) => log::warn!("Failed to load archived changeset from {:?}: {}", file, e),
+            }
+        }
+
+        if changed {
+            self.write_archive_index(&index).await;
+        }
+        Ok(index)
+    }
+
+    /// Records an archive written to `path` in the archive index.
+    async fn index_archive(&self, path: &std::path::Path, archived: &ArchivedChangeset) {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return;
+        };
+        let mut index = self.read_archive_index().await;
+        index.archives.insert(name.to_string(), ArchiveIndexEntry::of(archived));
+        self.write_archive_index(&index).await;
+    }
+
+    /// Reads the archive index, or an empty one when it is missing or unreadable.
+    async fn read_archive_index(&self) -> ArchiveIndex {
+        let path = self.root_path.join(&self.history_dir).join(ARCHIVE_INDEX_FILE);
+        if !self.fs.exists(&path).await {
+            return ArchiveIndex::default();
+        }
+
+        let index = match self.fs.read_file_string(&path).await {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        index.unwrap_or_else(|e| {
+            log::warn!("Rebuilding unreadable archive index {:?}: {}", path, e);
+            ArchiveIndex::default()
+        })
+    }
+
+    /// Writes the archive index.
+    ///
+    /// Failures are logged: the index is rebuilt from the archive files when it is missing.
+    async fn write_archive_index(&self, index: &ArchiveIndex) {
+        let path = self.root_path.join(&self.history_dir).join(ARCHIVE_INDEX_FILE);
+        let written = match serde_json::to_string_pretty(index) {
+            Ok(json) => self.fs.write_file_atomic(&path, &json).await.map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = written {
+            log::warn!("Failed to write archive index {:?}: {}", path, e);
+        }
+    }
+
+    /// Finds the file of the archived changeset with the given id.
+    ///
+    /// Archives are named by id, except those written before ids existed, which are
+    /// found through the archive index.
+    async fn find_archive_path(&self, id: &str) -> ChangesetResult<Option<std::path::PathBuf>> {
+        let path = self.archive_path(id);
+        if !id.is_empty() && self.fs.exists(&path).await {
+            return Ok(Some(path));
+        }
+
+        let history = self.root_path.join(&self.history_dir);
+        Ok(self
+            .archive_index()
+            .await?
+            .archives
+            .into_iter()
+            .find(|(_, entry)| entry.id == id)
+            .map(|(file, _)| history.join(file)))
+    }
+}
+
+/// Name of the file in the history directory indexing the archives.
+const ARCHIVE_INDEX_FILE: &str = "index.json";
+
+/// What each archive of the history directory is looked up by.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ArchiveIndex {
+    /// Archives by file name.
+    archives: BTreeMap<String, ArchiveIndexEntry>,
+}
+
+/// Id, name, branch and release time of an archive.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveIndexEntry {
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    branch: String,
+    applied_at: DateTime<Utc>,
+}
+
+impl ArchiveIndexEntry {
+    fn of(archived: &ArchivedChangeset) -> Self {
+        Self {
+            id: archived.changeset.id.clone(),
+            name: archived.changeset.name.clone(),
+            branch: archived.changeset.branch.clone(),
+            applied_at: archived.release_info.applied_at,
+        }
+    }
+
+    /// Checks whether `reference` names the archive by id, name, or branch.
+    fn matches_reference(&self, reference: &str) -> bool {
+        self.id == reference || self.branch == reference || self.name.as_deref() == Some(reference)
+    }
 }
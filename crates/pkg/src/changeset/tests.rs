@@ -105,6 +105,16 @@ impl ChangesetStorage for MockStorage {
             .ok_or_else(|| ChangesetError::NotFound { branch: branch.to_string() })
     }
 
+    async fn save_archived(&self, archived: &ArchivedChangeset) -> ChangesetResult<()> {
+        let mut stored = self.archived.write().await;
+        let branch = archived.changeset.branch.clone();
+        if !stored.contains_key(&branch) {
+            return Err(ChangesetError::NotFound { branch });
+        }
+        stored.insert(branch, archived.clone());
+        Ok(())
+    }
+
     async fn list_archived(&self) -> ChangesetResult<Vec<ArchivedChangeset>> {
         let archived = self.archived.read().await;
         Ok(archived.values().cloned().collect())
@@ -692,6 +702,162 @@ mod file_based_storage_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_file_archive_same_branch_twice() {
+        let (temp_dir, storage) = setup_file_storage().await;
+        let release_info = ReleaseInfo::new(
+            "user@example.com".to_string(),
+            "commit1".to_string(),
+            versions_map(vec![]),
+        );
+
+        let first = Changeset::new("fix/repeat", VersionBump::Patch, vec![]);
+        storage.archive(&first, release_info.clone()).await.unwrap();
+        let mut later = release_info;
+        later.applied_at += chrono::Duration::minutes(5);
+        let second = Changeset::new("fix/repeat", VersionBump::Minor, vec![]);
+        storage.archive(&second, later).await.unwrap();
+
+        // Archives are named by id, so both releases are kept
+        let history = temp_dir.path().join(".changesets/history");
+        assert!(history.join(format!("{}.json", first.id)).exists());
+        assert!(history.join(format!("{}.json", second.id)).exists());
+
+        // The branch resolves to the latest release, the id to the exact one
+        assert_eq!(storage.load_archived("fix/repeat").await.unwrap().changeset.id, second.id);
+        assert_eq!(storage.load_archived(&first.id).await.unwrap().changeset.id, first.id);
+    }
+
+    #[tokio::test]
+    async fn test_file_pending_changesets_are_named_by_id() {
+        let (temp_dir, storage) = setup_file_storage().await;
+        let mut changeset = Changeset::new("feature/by-id", VersionBump::Minor, vec![]);
+        storage.save(&changeset).await.unwrap();
+
+        let pending = temp_dir.path().join(".changesets");
+        assert!(pending.join(format!("{}.json", changeset.id)).exists());
+        assert!(!pending.join("feature-by-id.json").exists());
+        assert_eq!(
+            storage.find_changeset_path("feature/by-id").await.unwrap(),
+            Some(storage.changeset_path(&changeset.id))
+        );
+
+        // Branches that sanitize to the same filename no longer collide
+        let colliding = Changeset::new("feature-by-id", VersionBump::Patch, vec![]);
+        storage.save(&colliding).await.unwrap();
+        assert_eq!(storage.load("feature/by-id").await.unwrap().id, changeset.id);
+        assert_eq!(storage.load("feature-by-id").await.unwrap().id, colliding.id);
+
+        // Saving the branch again keeps a single file for it
+        changeset.add_package("@org/core");
+        storage.save(&changeset).await.unwrap();
+        assert_eq!(storage.list_pending().await.unwrap().len(), 2);
+
+        storage.delete("feature/by-id").await.unwrap();
+        assert!(!storage.exists("feature/by-id").await.unwrap());
+        assert!(storage.exists("feature-by-id").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_file_archives_are_found_through_the_index() {
+        let (temp_dir, storage) = setup_file_storage().await;
+        let release_info = ReleaseInfo::new(
+            "user@example.com".to_string(),
+            "commit1".to_string(),
+            versions_map(vec![]),
+        );
+        let mut changeset = Changeset::new("fix/indexed", VersionBump::Patch, vec![]);
+        changeset.name = Some("brave-lions-smile".to_string());
+        storage.archive(&changeset, release_info).await.unwrap();
+
+        let index = temp_dir.path().join(".changesets/history/index.json");
+        let recorded: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&index).unwrap()).unwrap();
+        let entry = &recorded["archives"][format!("{}.json", changeset.id)];
+        assert_eq!(entry["branch"], "fix/indexed");
+        assert_eq!(entry["name"], "brave-lions-smile");
+
+        // A missing index is rebuilt from the archives, skipping unreadable files
+        std::fs::write(temp_dir.path().join(".changesets/history/unrelated.json"), "not json")
+            .unwrap();
+        std::fs::remove_file(&index).unwrap();
+        assert_eq!(
+            storage.load_archived("brave-lions-smile").await.unwrap().changeset.id,
+            changeset.id
+        );
+        assert!(index.exists());
+        assert_eq!(storage.load_archived("fix/indexed").await.unwrap().changeset.id, changeset.id);
+        assert_eq!(storage.list_archived().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_file_save_archived_rewrites_archive() {
+        let (temp_dir, storage) = setup_file_storage().await;
+        let changeset = Changeset::new("fix/promote", VersionBump::Patch, vec![]);
+        let release_info = ReleaseInfo::new(
+            "user@example.com".to_string(),
+            "commit1".to_string(),
+            versions_map(vec![]),
+        );
+        storage.archive(&changeset, release_info).await.unwrap();
+
+        let mut archived = storage.load_archived("fix/promote").await.unwrap();
+        archived.changeset.record_promotion("production", None);
+        storage.save_archived(&archived).await.unwrap();
+
+        let history = temp_dir.path().join(".changesets/history");
+        let files: Vec<_> = std::fs::read_dir(&history)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name != "index.json")
+            .collect();
+        assert_eq!(files, vec![format!("{}.json", changeset.id)]);
+        let reloaded = storage.load_archived(&changeset.id).await.unwrap();
+        assert!(reloaded.changeset.is_promoted_to("production"));
+
+        // Only existing archives can be rewritten
+        let unknown = ArchivedChangeset::new(
+            Changeset::new("fix/unknown", VersionBump::Patch, vec![]),
+            archived.release_info,
+        );
+        let result = storage.save_archived(&unknown).await;
+        assert!(matches!(result, Err(ChangesetError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_file_load_legacy_changeset_without_id() {
+        let (temp_dir, storage) = setup_file_storage().await;
+        let mut legacy = Changeset::new("feature/legacy", VersionBump::Minor, vec![]);
+        legacy.id.clear();
+        let dir = temp_dir.path().join(".changesets");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("feature-legacy.json"), serde_json::to_string(&legacy).unwrap())
+            .unwrap();
+
+        let first = storage.load("feature/legacy").await.unwrap();
+        let second = storage.load("feature/legacy").await.unwrap();
+        assert_eq!(first.id.len(), 12);
+        assert_eq!(first.id, second.id, "Derived ids must be stable");
+
+        // Legacy archives named by branch are still found
+        let fs = FileSystemManager::new();
+        let history = temp_dir.path().join(".changesets/history");
+        fs.create_dir_all(&history).await.unwrap();
+        let archived = ArchivedChangeset::new(
+            legacy,
+            ReleaseInfo::new("user".to_string(), "commit".to_string(), versions_map(vec![])),
+        );
+        fs.write_file_string(
+            &history.join("feature-legacy.json"),
+            &serde_json::to_string(&archived).unwrap(),
+        )
+        .await
+        .unwrap();
+        let loaded = storage.load_archived("feature/legacy").await.unwrap();
+        assert_eq!(loaded.changeset.id, first.id);
+        assert_eq!(storage.load_archived(&first.id).await.unwrap().changeset.id, first.id);
+    }
+
     #[tokio::test]
     async fn test_file_load_archived_nonexistent() {
         let (_temp_dir, storage) = setup_file_storage().await;
@@ -930,7 +1096,7 @@ mod file_based_storage_tests {
         storage.save(&changeset).await.unwrap();
 
         // Read the file directly and verify it's valid JSON
-        let path = temp_dir.path().join(".changesets").join("feature-readable.json");
+        let path = temp_dir.path().join(".changesets").join(format!("{}.json", changeset.id));
         let contents = fs.read_file_string(&path).await.unwrap();
 
         // Verify it's valid JSON and contains expected fields
@@ -1064,7 +1230,7 @@ mod manager_tests {
     }
 
     impl MockManagerStorage {
-        fn new() -> Self {
+        pub(super) fn new() -> Self {
             Self {
                 changesets: Arc::new(Mutex::new(HashMap::new())),
                 archived: Arc::new(Mutex::new(HashMap::new())),
@@ -1133,12 +1299,22 @@ mod manager_tests {
                 .ok_or_else(|| ChangesetError::NotFound { branch: branch.to_string() })
         }
 
+        async fn save_archived(&self, archived: &ArchivedChangeset) -> ChangesetResult<()> {
+            let mut stored = self.archived.lock().unwrap();
+            let branch = archived.changeset.branch.clone();
+            if !stored.contains_key(&branch) {
+                return Err(ChangesetError::NotFound { branch });
+            }
+            stored.insert(branch, archived.clone());
+            Ok(())
+        }
+
         async fn list_archived(&self) -> ChangesetResult<Vec<ArchivedChangeset>> {
             Ok(self.archived.lock().unwrap().values().cloned().collect())
         }
     }
 
-    fn create_test_config() -> ChangesetConfig {
+    pub(super) fn create_test_config() -> ChangesetConfig {
         ChangesetConfig {
             path: ".changesets".into(),
             history_path: ".changesets/history".into(),
@@ -1148,6 +1324,12 @@ mod manager_tests {
                 "production".to_string(),
             ],
             default_environments: vec!["production".to_string()],
+            environments: Vec::new(),
+            track_authors: true,
+            human_names: true,
+            package_renames: Default::default(),
+            commit_association: Default::default(),
+            base_branch: None,
         }
     }
 
@@ -1173,6 +1355,53 @@ mod manager_tests {
         assert_eq!(changeset.environments, vec!["production".to_string()]);
     }
 
+    #[tokio::test]
+    async fn test_create_assigns_id_and_name() {
+        let manager = create_test_manager();
+
+        let changeset = manager.create("feature/named", VersionBump::Minor, vec![]).await.unwrap();
+
+        assert_eq!(changeset.id.len(), 12);
+        let name = changeset.name.clone().expect("human names are enabled");
+        assert_eq!(name, super::super::names::generate_name(&changeset.id));
+        assert_eq!(name.split('-').count(), 3);
+
+        // The changeset resolves by branch, id, and name
+        for reference in ["feature/named", changeset.id.as_str(), name.as_str()] {
+            let resolved = manager.resolve(reference).await.unwrap();
+            assert_eq!(resolved.id, changeset.id);
+        }
+        assert!(matches!(
+            manager.resolve("missing-name-here").await,
+            Err(ChangesetError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_without_human_names() {
+        let mut config = create_test_config();
+        config.human_names = false;
+        let manager = ChangesetManager::with_storage(
+            MockManagerStorage::new(),
+            PathBuf::from("."),
+            None,
+            config,
+        );
+
+        let changeset = manager.create("feature/plain", VersionBump::Patch, vec![]).await.unwrap();
+
+        assert!(changeset.name.is_none());
+        assert!(!changeset.id.is_empty());
+    }
+
+    #[test]
+    fn test_generated_names_are_stable() {
+        use super::super::names::generate_name;
+
+        assert_eq!(generate_name("3f9a1c0b7d2e"), generate_name("3f9a1c0b7d2e"));
+        assert_ne!(generate_name("3f9a1c0b7d2e"), generate_name("8e41d05a9b6c"));
+    }
+
     #[tokio::test]
     async fn test_create_changeset_empty_branch() {
         let manager = create_test_manager();
@@ -1231,6 +1460,28 @@ mod manager_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_validate_pending_environments() {
+        let manager = create_test_manager();
+
+        manager
+            .create("feature/valid", VersionBump::Minor, vec!["staging".to_string()])
+            .await
+            .unwrap();
+        assert!(manager.validate_pending_environments().await.is_ok());
+
+        // Simulate a changeset written before "qa" was removed from the configuration
+        let stale = Changeset::new("feature/stale", VersionBump::Patch, vec!["qa".to_string()]);
+        manager.storage().save(&stale).await.unwrap();
+
+        match manager.validate_pending_environments().await.unwrap_err() {
+            ChangesetError::InvalidEnvironment { environment, .. } => {
+                assert_eq!(environment, "qa");
+            }
+            other => panic!("Expected InvalidEnvironment error, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_load_changeset_success() {
         let manager = create_test_manager();
@@ -1689,6 +1940,241 @@ mod manager_tests {
         assert_eq!(archived.release_info.get_version("@myorg/utils"), Some("1.5.0"));
     }
 
+    #[tokio::test]
+    async fn test_manager_archive_packages_splits_changeset() {
+        let manager = create_test_manager();
+        let mut changeset = manager
+            .create("fix/hotfix", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+        changeset.add_package("@myorg/core");
+        changeset.add_package("@myorg/utils");
+        manager.update(&changeset).await.unwrap();
+
+        let mut versions = HashMap::new();
+        versions.insert("@myorg/core".to_string(), "1.0.1".to_string());
+        let release_info = crate::types::ReleaseInfo::new(
+            "ci-bot@example.com".to_string(),
+            "abc123".to_string(),
+            versions,
+        );
+
+        let pending = manager
+            .archive_packages("fix/hotfix", &["@myorg/core".to_string()], release_info.clone())
+            .await
+            .unwrap()
+            .expect("utils stays pending");
+        assert_eq!(pending.packages, vec!["@myorg/utils".to_string()]);
+
+        let stored = manager.load("fix/hotfix").await.unwrap();
+        assert_eq!(stored.packages, vec!["@myorg/utils".to_string()]);
+        let archived = manager.storage().load_archived("fix/hotfix@@myorg/core").await.unwrap();
+        assert_eq!(archived.changeset.packages, vec!["@myorg/core".to_string()]);
+
+        // Releasing the rest archives the changeset under its own branch
+        let remaining = manager
+            .archive_packages("fix/hotfix", &["@myorg/utils".to_string()], release_info)
+            .await
+            .unwrap();
+        assert!(remaining.is_none());
+        assert!(!manager.storage().exists("fix/hotfix").await.unwrap());
+        assert!(manager.storage().load_archived("fix/hotfix").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_manager_split_changeset() {
+        let manager = create_test_manager();
+        let mut changeset = manager
+            .create("feature/auth", VersionBump::Minor, vec!["production".to_string()])
+            .await
+            .unwrap();
+        changeset.add_package("@myorg/auth");
+        changeset.add_package("@myorg/docs");
+        manager.update(&changeset).await.unwrap();
+
+        let (original, split) = manager
+            .split("feature/auth", &["@myorg/docs".to_string()], "feature/docs")
+            .await
+            .unwrap();
+        assert_eq!(original.packages, vec!["@myorg/auth".to_string()]);
+        assert_eq!(split.packages, vec!["@myorg/docs".to_string()]);
+
+        let stored = manager.load("feature/auth").await.unwrap();
+        assert_eq!(stored.packages, vec!["@myorg/auth".to_string()]);
+        assert_eq!(stored.operations.len(), 1);
+        let stored_split = manager.load("feature/docs").await.unwrap();
+        assert_eq!(stored_split.bump, VersionBump::Minor);
+        assert_eq!(stored_split.operations, stored.operations);
+    }
+
+    #[tokio::test]
+    async fn test_manager_split_into_existing_changeset_fails() {
+        let manager = create_test_manager();
+        let mut changeset = manager
+            .create("feature/auth", VersionBump::Minor, vec!["production".to_string()])
+            .await
+            .unwrap();
+        changeset.add_package("@myorg/auth");
+        changeset.add_package("@myorg/docs");
+        manager.update(&changeset).await.unwrap();
+        manager.create("feature/docs", VersionBump::Patch, vec![]).await.unwrap();
+
+        let result =
+            manager.split("feature/auth", &["@myorg/docs".to_string()], "feature/docs").await;
+        assert!(matches!(result, Err(ChangesetError::AlreadyExists { .. })));
+        assert_eq!(manager.load("feature/auth").await.unwrap().packages.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_manager_merge_changesets() {
+        let manager = create_test_manager();
+        let mut fix = manager
+            .create("fix/login", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+        fix.add_package("@myorg/auth");
+        manager.update(&fix).await.unwrap();
+        let mut feature = manager
+            .create("feature/sso", VersionBump::Minor, vec!["staging".to_string()])
+            .await
+            .unwrap();
+        feature.add_package("@myorg/core");
+        manager.update(&feature).await.unwrap();
+
+        let branches = vec!["fix/login".to_string(), "feature/sso".to_string()];
+        let merged = manager.merge(&branches, "release/auth").await.unwrap();
+        assert_eq!(merged.bump, VersionBump::Minor);
+        assert_eq!(merged.packages, vec!["@myorg/auth".to_string(), "@myorg/core".to_string()]);
+
+        assert!(manager.storage().exists("release/auth").await.unwrap());
+        assert!(!manager.storage().exists("fix/login").await.unwrap());
+        assert!(!manager.storage().exists("feature/sso").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_manager_merge_into_source_branch() {
+        let manager = create_test_manager();
+        let mut fix = manager
+            .create("fix/login", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+        fix.add_package("@myorg/auth");
+        manager.update(&fix).await.unwrap();
+        let mut feature = manager
+            .create("feature/sso", VersionBump::Major, vec!["production".to_string()])
+            .await
+            .unwrap();
+        feature.add_package("@myorg/core");
+        manager.update(&feature).await.unwrap();
+
+        let branches = vec!["fix/login".to_string(), "feature/sso".to_string()];
+        let merged = manager.merge(&branches, "fix/login").await.unwrap();
+        assert_eq!(merged.bump, VersionBump::Major);
+
+        let stored = manager.load("fix/login").await.unwrap();
+        assert_eq!(stored.packages.len(), 2);
+        assert!(!manager.storage().exists("feature/sso").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_manager_merge_requires_two_changesets() {
+        let manager = create_test_manager();
+        manager.create("fix/login", VersionBump::Patch, vec![]).await.unwrap();
+
+        let branches = vec!["fix/login".to_string(), "fix/login".to_string()];
+        let result = manager.merge(&branches, "release/auth").await;
+        assert!(matches!(result, Err(ChangesetError::ValidationFailed { .. })));
+        assert!(manager.storage().exists("fix/login").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_manager_promote_follows_promotion_order() {
+        let manager = create_test_manager();
+        let environments = vec!["staging".to_string(), "production".to_string()];
+        manager.create("feature/sso", VersionBump::Minor, environments).await.unwrap();
+
+        let result = manager.promote("feature/sso", "production").await;
+        match result {
+            Err(ChangesetError::PromotionRejected { environment, reason, .. }) => {
+                assert_eq!(environment, "production");
+                assert!(reason.contains("'staging'"), "unexpected reason: {reason}");
+            }
+            other => panic!("Expected PromotionRejected, got {other:?}"),
+        }
+
+        // Development is not targeted, so staging comes first
+        let promotion = manager.plan_promotion("feature/sso", "staging").await.unwrap();
+        assert_eq!(promotion.previous, None);
+        assert!(!promotion.is_archived());
+        manager.apply_promotion(&promotion).await.unwrap();
+
+        let promoted = manager.promote("feature/sso", "production").await.unwrap();
+        let environments: Vec<&str> =
+            promoted.promotions.iter().map(|promotion| promotion.environment.as_str()).collect();
+        assert_eq!(environments, vec!["staging", "production"]);
+        assert_eq!(manager.load("feature/sso").await.unwrap().promotions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_manager_promote_rejects_invalid_targets() {
+        let manager = create_test_manager();
+        manager
+            .create("feature/sso", VersionBump::Minor, vec!["staging".to_string()])
+            .await
+            .unwrap();
+
+        let result = manager.promote("feature/sso", "qa").await;
+        assert!(matches!(result, Err(ChangesetError::InvalidEnvironment { .. })));
+        let result = manager.promote("feature/sso", "production").await;
+        assert!(matches!(result, Err(ChangesetError::PromotionRejected { .. })));
+
+        manager.promote("feature/sso", "staging").await.unwrap();
+        let result = manager.promote("feature/sso", "staging").await;
+        match result {
+            Err(ChangesetError::PromotionRejected { reason, .. }) => {
+                assert!(reason.starts_with("already promoted"), "unexpected reason: {reason}");
+            }
+            other => panic!("Expected PromotionRejected, got {other:?}"),
+        }
+        assert!(matches!(
+            manager.promote("feature/unknown", "staging").await,
+            Err(ChangesetError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_manager_promote_archived_changeset() {
+        let manager = create_test_manager();
+        let environments = vec!["staging".to_string(), "production".to_string()];
+        let mut changeset =
+            manager.create("feature/sso", VersionBump::Minor, environments).await.unwrap();
+        changeset.add_package("@myorg/core");
+        manager.update(&changeset).await.unwrap();
+        manager.promote("feature/sso", "staging").await.unwrap();
+
+        let mut versions = HashMap::new();
+        versions.insert("@myorg/core".to_string(), "1.1.0".to_string());
+        versions.insert("@myorg/utils".to_string(), "2.0.1".to_string());
+        let release_info = crate::types::ReleaseInfo::new(
+            "ci-bot@example.com".to_string(),
+            "abc123".to_string(),
+            versions,
+        );
+        manager.archive("feature/sso", release_info).await.unwrap();
+
+        let promotion = manager.plan_promotion("feature/sso", "production").await.unwrap();
+        assert!(promotion.is_archived());
+        assert_eq!(promotion.previous.as_deref(), Some("staging"));
+        // Packages released alongside the changeset are not re-released with it
+        assert_eq!(promotion.released_versions(), vec![("@myorg/core", "1.1.0")]);
+        manager.apply_promotion(&promotion).await.unwrap();
+
+        let archived = manager.storage().load_archived("feature/sso").await.unwrap();
+        assert!(archived.changeset.is_promoted_to("staging"));
+        assert!(archived.changeset.is_promoted_to("production"));
+        assert_eq!(archived.release_info.git_commit, "abc123");
+    }
+
     #[tokio::test]
     async fn test_manager_archive_nonexistent_changeset() {
         let manager = create_test_manager();
@@ -1718,9 +2204,9 @@ mod manager_tests {
 
 mod git_integration_tests {
     use crate::changeset::{ChangesetManager, FileBasedChangesetStorage, PackageDetector};
-    use crate::config::ChangesetConfig;
+    use crate::config::{ChangesetConfig, FilePatternsConfig};
     use crate::error::ChangesetError;
-    use crate::types::VersionBump;
+    use crate::types::{CommitAssociation, VersionBump};
     use std::fs;
     use sublime_git_tools::Repo;
     use sublime_standard_tools::filesystem::FileSystemManager;
@@ -1935,6 +2421,63 @@ mod git_integration_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_package_detector_skips_ignored_changes() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        fs::write(temp_dir.path().join("packages/package1/.pkgignore"), "**/*.test.js\n").unwrap();
+
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+
+        // Test-only change in package1, source change in package2
+        fs::write(temp_dir.path().join("packages/package1/src/index.test.js"), "test();").unwrap();
+        fs::write(temp_dir.path().join("packages/package2/src/index.js"), "console.log(2);")
+            .unwrap();
+        repo.add_all().unwrap();
+        let commit = repo.commit("test: cover package1, fix package2").unwrap();
+
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+
+        let affected =
+            detector.detect_affected_packages(std::slice::from_ref(&commit)).await.unwrap();
+        assert_eq!(affected, vec!["@test/package2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_package_detector_skips_generated_and_binary_changes() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+
+        // Lockfile and binary changes in package1, source change in package2
+        fs::write(temp_dir.path().join("packages/package1/package-lock.json"), "{}").unwrap();
+        fs::write(temp_dir.path().join("packages/package1/logo.png"), [0x89, 0x50]).unwrap();
+        fs::write(temp_dir.path().join("packages/package2/src/index.js"), "console.log(2);")
+            .unwrap();
+        repo.add_all().unwrap();
+        let commit = repo.commit("chore: refresh lockfile, fix package2").unwrap();
+
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+        let affected =
+            detector.detect_affected_packages(std::slice::from_ref(&commit)).await.unwrap();
+        assert_eq!(affected, vec!["@test/package2".to_string()]);
+
+        // With no generated patterns the lockfile counts again
+        let patterns = FilePatternsConfig { generated: vec![], ..FilePatternsConfig::default() };
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new())
+                .with_file_patterns(patterns);
+        let mut affected =
+            detector.detect_affected_packages(std::slice::from_ref(&commit)).await.unwrap();
+        affected.sort();
+        assert_eq!(affected, vec!["@test/package1".to_string(), "@test/package2".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_package_detector_detect_affected_packages_multiple() {
         let (temp_dir, repo) = setup_git_repo();
@@ -2034,6 +2577,42 @@ mod git_integration_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_package_detector_expands_merge_commits() {
+        let (temp_dir, repo) = setup_git_repo();
+        repo.create_tag("v1.0.0", Some("Version 1.0.0".to_string())).unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+
+        repo.create_branch("feat/merged").unwrap();
+        repo.checkout("feat/merged").unwrap();
+        fs::write(temp_dir.path().join("feature.txt"), "feature").unwrap();
+        repo.add("feature.txt").unwrap();
+        let feature_commit = repo.commit("feat: add feature").unwrap();
+        repo.checkout(&main_branch).unwrap();
+        repo.merge("feat/merged").unwrap();
+        let merge_commit = repo.get_current_sha().unwrap();
+        assert_ne!(merge_commit, feature_commit, "Merge should create a merge commit");
+
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+
+        let since: Vec<String> = detector
+            .get_commits_since(Some("v1.0.0".to_string()))
+            .unwrap()
+            .into_iter()
+            .map(|commit| commit.hash)
+            .collect();
+        assert_eq!(since, vec![feature_commit.clone()]);
+
+        let between: Vec<String> = detector
+            .get_commits_between("v1.0.0", "HEAD")
+            .unwrap()
+            .into_iter()
+            .map(|commit| commit.hash)
+            .collect();
+        assert_eq!(between, vec![feature_commit]);
+    }
+
     #[allow(clippy::len_zero)]
     #[tokio::test]
     async fn test_package_detector_get_commits_between() {
@@ -2063,30 +2642,96 @@ mod git_integration_tests {
     }
 
     #[tokio::test]
-    async fn test_package_detector_workspace_root() {
+    async fn test_package_detector_propose_changeset() {
         let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        repo.add_all().unwrap();
+        repo.commit("chore: setup monorepo").unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+
+        repo.create_branch("feat/login").unwrap();
+        repo.checkout("feat/login").unwrap();
+        fs::write(temp_dir.path().join("packages/package1/src/index.js"), "// fix\n").unwrap();
+        repo.add_all().unwrap();
+        let fix_commit = repo.commit("fix(package1): handle empty input").unwrap();
+        fs::write(temp_dir.path().join("packages/package2/src/login.js"), "// login\n").unwrap();
+        repo.add_all().unwrap();
+        let feat_commit = repo.commit("feat(package2): add login").unwrap();
+        fs::write(temp_dir.path().join("packages/package1/src/api.js"), "// api\n").unwrap();
+        repo.add_all().unwrap();
+        let breaking_commit = repo.commit("feat(package1)!: rename api").unwrap();
 
         let detector =
             PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
-
-        assert_eq!(detector.workspace_root(), temp_dir.path());
+        let proposal = detector.propose_changeset(&main_branch, "HEAD").await.unwrap();
+
+        assert_eq!(proposal.base, main_branch);
+        assert_eq!(proposal.commit_count, 3);
+        assert_eq!(proposal.bump, VersionBump::Major);
+        assert_eq!(proposal.package_names(), vec!["@test/package1", "@test/package2"]);
+        assert_eq!(proposal.packages[0].bump, VersionBump::Major);
+        assert_eq!(proposal.packages[0].commits, vec![breaking_commit, fix_commit]);
+        assert_eq!(proposal.packages[1].bump, VersionBump::Minor);
+        assert_eq!(proposal.packages[1].commits, vec![feat_commit]);
     }
 
     #[tokio::test]
-    async fn test_package_detector_repo_reference() {
+    async fn test_package_detector_propose_changeset_unknown_base() {
         let (temp_dir, repo) = setup_git_repo();
 
         let detector =
             PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+        let result = detector.propose_changeset("does-not-exist", "HEAD").await;
 
-        let repo_ref = detector.repo();
-        // Just verify we can access the repo reference
-        let repo_path = repo_ref.get_repo_path();
-        // get_repo_path returns &Path directly
-        assert!(!repo_path.as_os_str().is_empty());
+        assert!(matches!(result, Err(ChangesetError::GitIntegration { .. })));
     }
 
-    #[tokio::test]
+    #[test]
+    fn test_bump_for_commit_message() {
+        use crate::changeset::bump_for_commit_message;
+
+        assert_eq!(bump_for_commit_message("feat!: drop node 16"), VersionBump::Major);
+        assert_eq!(
+            bump_for_commit_message("fix: parse\n\nBREAKING CHANGE: new format"),
+            VersionBump::Major
+        );
+        assert_eq!(bump_for_commit_message("feat(auth): add login"), VersionBump::Minor);
+        assert_eq!(bump_for_commit_message("fix: handle expiry"), VersionBump::Patch);
+        assert_eq!(bump_for_commit_message("docs: typo"), VersionBump::Patch);
+        assert_eq!(bump_for_commit_message("Update dependencies"), VersionBump::Patch);
+        assert_eq!(
+            bump_for_commit_message(
+                "Add login (#12)\n\n* fix(auth): expiry\n\n* feat(auth): login"
+            ),
+            VersionBump::Minor
+        );
+    }
+
+    #[tokio::test]
+    async fn test_package_detector_workspace_root() {
+        let (temp_dir, repo) = setup_git_repo();
+
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+
+        assert_eq!(detector.workspace_root(), temp_dir.path());
+    }
+
+    #[tokio::test]
+    async fn test_package_detector_repo_reference() {
+        let (temp_dir, repo) = setup_git_repo();
+
+        let detector =
+            PackageDetector::new(temp_dir.path().to_path_buf(), &repo, FileSystemManager::new());
+
+        let repo_ref = detector.repo();
+        // Just verify we can access the repo reference
+        let repo_path = repo_ref.get_repo_path();
+        // get_repo_path returns &Path directly
+        assert!(!repo_path.as_os_str().is_empty());
+    }
+
+    #[tokio::test]
     async fn test_manager_add_commits_from_git_monorepo() {
         let (temp_dir, repo) = setup_git_repo();
         setup_monorepo(temp_dir.path());
@@ -2128,6 +2773,12 @@ mod git_integration_tests {
             history_path: ".changesets/history".into(),
             available_environments: vec!["production".to_string()],
             default_environments: vec!["production".to_string()],
+            environments: Vec::new(),
+            track_authors: true,
+            human_names: true,
+            package_renames: Default::default(),
+            commit_association: Default::default(),
+            base_branch: None,
         };
 
         let manager = ChangesetManager::with_storage(
@@ -2210,6 +2861,12 @@ mod git_integration_tests {
             history_path: ".changesets/history".into(),
             available_environments: vec!["production".to_string()],
             default_environments: vec!["production".to_string()],
+            environments: Vec::new(),
+            track_authors: true,
+            human_names: true,
+            package_renames: Default::default(),
+            commit_association: Default::default(),
+            base_branch: None,
         };
 
         let manager = ChangesetManager::with_storage(
@@ -2276,6 +2933,12 @@ mod git_integration_tests {
             history_path: ".changesets/history".into(),
             available_environments: vec!["production".to_string()],
             default_environments: vec!["production".to_string()],
+            environments: Vec::new(),
+            track_authors: true,
+            human_names: true,
+            package_renames: Default::default(),
+            commit_association: Default::default(),
+            base_branch: None,
         };
 
         // Create manager without Git repo
@@ -2298,6 +2961,316 @@ mod git_integration_tests {
             panic!("Expected GitIntegration error");
         }
     }
+
+    /// Helper to create a manager associating commits with the given strategy.
+    fn association_manager(
+        temp_dir: &TempDir,
+        repo: Repo,
+        commit_association: CommitAssociation,
+        base_branch: &str,
+    ) -> ChangesetManager<FileBasedChangesetStorage<FileSystemManager>> {
+        let storage = FileBasedChangesetStorage::new(
+            temp_dir.path().to_path_buf(),
+            ".changesets".into(),
+            ".changesets/history".into(),
+            FileSystemManager::new(),
+        );
+        let config = ChangesetConfig {
+            commit_association,
+            base_branch: Some(base_branch.to_string()),
+            ..Default::default()
+        };
+        ChangesetManager::with_storage(storage, temp_dir.path().to_path_buf(), Some(repo), config)
+    }
+
+    /// Helper to commit a change to a file of the repository.
+    fn commit_file(temp_dir: &TempDir, repo: &Repo, path: &str, message: &str) -> String {
+        fs::write(temp_dir.path().join(path), message).unwrap();
+        repo.add_all().unwrap();
+        repo.commit(message).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_manager_add_commits_from_git_branch_since_base() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+        commit_file(&temp_dir, &repo, "packages/package2/src/index.js", "Update package2 on main");
+
+        repo.create_branch("feature/test").unwrap();
+        repo.checkout("feature/test").unwrap();
+        let commit = commit_file(&temp_dir, &repo, "packages/package1/src/index.js", "Update 1");
+
+        let manager = association_manager(&temp_dir, repo, CommitAssociation::Branch, &main_branch);
+        manager
+            .create("feature/test", VersionBump::Minor, vec!["production".into()])
+            .await
+            .unwrap();
+
+        let summary = manager.add_commits_from_git("feature/test").await.unwrap();
+        assert_eq!(summary.commit_ids, vec![commit.clone()]);
+        assert_eq!(summary.new_packages, vec!["@test/package1".to_string()]);
+
+        let changeset = manager.load("feature/test").await.unwrap();
+        assert_eq!(changeset.changes, vec![commit]);
+        assert_eq!(changeset.association, Some(CommitAssociation::Branch));
+    }
+
+    #[tokio::test]
+    async fn test_manager_add_commits_from_git_skips_recorded_commits() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+
+        repo.create_branch("feature/test").unwrap();
+        repo.checkout("feature/test").unwrap();
+        let first = commit_file(&temp_dir, &repo, "packages/package1/src/index.js", "Update 1");
+        let second = commit_file(&temp_dir, &repo, "packages/package2/src/index.js", "Update 2");
+
+        let manager = association_manager(&temp_dir, repo, CommitAssociation::Branch, &main_branch);
+        manager
+            .create("feature/test", VersionBump::Minor, vec!["production".into()])
+            .await
+            .unwrap();
+        let mut changeset = manager.load("feature/test").await.unwrap();
+        changeset.add_package("@test/package2");
+        changeset.add_commit(&second);
+        manager.update(&changeset).await.unwrap();
+
+        let summary = manager.add_commits_from_git("feature/test").await.unwrap();
+        assert_eq!(summary.commit_ids, vec![first.clone()]);
+        assert_eq!(summary.new_packages, vec!["@test/package1".to_string()]);
+
+        let summary = manager.add_commits_from_git("feature/test").await.unwrap();
+        assert_eq!(summary.commits_added, 0);
+
+        let changeset = manager.load("feature/test").await.unwrap();
+        assert_eq!(changeset.changes, vec![second, first]);
+    }
+
+    #[tokio::test]
+    async fn test_manager_add_commits_from_git_packages_strategy() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+
+        repo.create_branch("feature/test").unwrap();
+        repo.checkout("feature/test").unwrap();
+        let listed = commit_file(&temp_dir, &repo, "packages/package1/src/index.js", "Update 1");
+        commit_file(&temp_dir, &repo, "packages/package2/src/index.js", "Update 2");
+
+        let manager =
+            association_manager(&temp_dir, repo, CommitAssociation::Packages, &main_branch);
+        manager
+            .create("feature/test", VersionBump::Minor, vec!["production".into()])
+            .await
+            .unwrap();
+        let mut changeset = manager.load("feature/test").await.unwrap();
+        changeset.add_package("@test/package1");
+        manager.update(&changeset).await.unwrap();
+
+        let summary = manager.add_commits_from_git("feature/test").await.unwrap();
+        assert_eq!(summary.commit_ids, vec![listed.clone()]);
+        assert!(summary.new_packages.is_empty());
+        assert_eq!(summary.existing_packages, vec!["@test/package1".to_string()]);
+
+        let changeset = manager.load("feature/test").await.unwrap();
+        assert_eq!(changeset.packages, vec!["@test/package1".to_string()]);
+        assert_eq!(changeset.changes, vec![listed]);
+        assert_eq!(changeset.association, Some(CommitAssociation::Packages));
+    }
+
+    #[tokio::test]
+    async fn test_manager_add_commits_from_git_trailer_strategy() {
+        let (temp_dir, repo) = setup_git_repo();
+        setup_monorepo(temp_dir.path());
+        repo.add_all().unwrap();
+        repo.commit("Setup monorepo").unwrap();
+        let main_branch = repo.get_current_branch().unwrap();
+        repo.create_branch("feature/test").unwrap();
+        repo.checkout("feature/test").unwrap();
+
+        let manager =
+            association_manager(&temp_dir, repo, CommitAssociation::Trailer, &main_branch);
+        let id = manager
+            .create("feature/test", VersionBump::Minor, vec!["production".into()])
+            .await
+            .unwrap()
+            .id;
+        let git_repo = manager.git_repo();
+        let repo = git_repo.as_ref().unwrap();
+
+        let tagged = commit_file(
+            &temp_dir,
+            repo,
+            "packages/package2/src/index.js",
+            &format!("Update 2\n\nRefs: #12\nchangeset: {id}"),
+        );
+        commit_file(&temp_dir, repo, "packages/package1/src/index.js", "Update 1");
+        commit_file(
+            &temp_dir,
+            repo,
+            "packages/package1/src/api.js",
+            "Update 1 again\n\nChangeset: 000000000000",
+        );
+
+        let summary = manager.add_commits_from_git("feature/test").await.unwrap();
+        assert_eq!(summary.commit_ids, vec![tagged]);
+        assert_eq!(summary.new_packages, vec!["@test/package2".to_string()]);
+
+        let changeset = manager.load("feature/test").await.unwrap();
+        assert_eq!(changeset.association, Some(CommitAssociation::Trailer));
+    }
+
+    #[tokio::test]
+    async fn test_manager_add_commits_from_git_unknown_base_branch() {
+        let (temp_dir, repo) = setup_git_repo();
+        repo.create_branch("feature/test").unwrap();
+        repo.checkout("feature/test").unwrap();
+
+        let manager =
+            association_manager(&temp_dir, repo, CommitAssociation::Branch, "does-not-exist");
+        manager
+            .create("feature/test", VersionBump::Minor, vec!["production".into()])
+            .await
+            .unwrap();
+
+        let result = manager.add_commits_from_git("feature/test").await;
+        assert!(matches!(result, Err(ChangesetError::GitIntegration { .. })));
+    }
+
+    #[test]
+    fn test_commit_trailers() {
+        use crate::changeset::git_integration::commit_trailers;
+
+        let message = "feat: login\n\nChangeset: ignored body line\n\nRefs: #1\nChangeset: abc";
+        assert_eq!(commit_trailers(message, "changeset").collect::<Vec<_>>(), vec!["abc"]);
+        assert_eq!(commit_trailers("Changeset: abc", "Changeset").count(), 0);
+    }
+}
+
+// ============================================================================
+// Stale Package Tests
+// ============================================================================
+
+mod stale_tests {
+    use super::manager_tests::{MockManagerStorage, create_test_config};
+    use crate::changeset::{ChangesetManager, ChangesetStorage, find_stale_packages};
+    use crate::types::{Changeset, VersionBump};
+    use std::collections::{BTreeMap, HashSet};
+    use std::path::PathBuf;
+
+    fn workspace(packages: &[&str]) -> HashSet<String> {
+        packages.iter().map(|package| (*package).to_string()).collect()
+    }
+
+    fn changeset(branch: &str, packages: &[&str]) -> Changeset {
+        let mut changeset =
+            Changeset::new(branch, VersionBump::Minor, vec!["production".to_string()]);
+        for package in packages {
+            changeset.add_package(*package);
+        }
+        changeset
+    }
+
+    async fn create_manager(
+        renames: &[(&str, &str)],
+        changesets: &[Changeset],
+    ) -> ChangesetManager<MockManagerStorage> {
+        let mut config = create_test_config();
+        config.package_renames =
+            renames.iter().map(|(from, to)| ((*from).to_string(), (*to).to_string())).collect();
+        let manager = ChangesetManager::with_storage(
+            MockManagerStorage::new(),
+            PathBuf::from("."),
+            None,
+            config,
+        );
+        for changeset in changesets {
+            manager.storage().save(changeset).await.unwrap();
+        }
+        manager
+    }
+
+    #[test]
+    fn test_find_stale_packages_resolves_renames_in_workspace() {
+        let mut config = create_test_config();
+        config.package_renames.insert("@org/old".to_string(), "@org/new".to_string());
+        config.package_renames.insert("@org/gone".to_string(), "@org/missing".to_string());
+        let changesets = [changeset("feature/a", &["@org/core", "@org/old", "@org/gone"])];
+
+        let stale =
+            find_stale_packages(&changesets, &workspace(&["@org/core", "@org/new"]), &config);
+
+        assert_eq!(stale.len(), 2);
+        assert_eq!(stale[0].package, "@org/old");
+        assert_eq!(stale[0].renamed_to.as_deref(), Some("@org/new"));
+        assert_eq!(stale[1].package, "@org/gone");
+        assert_eq!(stale[1].renamed_to, None);
+    }
+
+    #[tokio::test]
+    async fn test_clean_renames_and_keeps_without_prune() {
+        let manager = create_manager(
+            &[("@org/old", "@org/new")],
+            &[changeset("feature/a", &["@org/old", "@org/core", "@org/gone"])],
+        )
+        .await;
+
+        let report = manager
+            .clean_stale_packages(&workspace(&["@org/core", "@org/new"]), &BTreeMap::new(), false)
+            .await
+            .unwrap();
+
+        assert_eq!(report.renamed.len(), 1);
+        assert_eq!(report.kept.len(), 1);
+        assert!(report.removed.is_empty());
+        let stored = manager.load("feature/a").await.unwrap();
+        assert_eq!(stored.packages, ["@org/new", "@org/core", "@org/gone"]);
+        assert_eq!(stored.revisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_clean_applies_explicit_renames_and_prunes() {
+        let manager = create_manager(
+            &[],
+            &[
+                changeset("feature/a", &["@org/old", "@org/core"]),
+                changeset("feature/b", &["@org/gone"]),
+            ],
+        )
+        .await;
+        let renames = BTreeMap::from([("@org/old".to_string(), "@org/core".to_string())]);
+
+        let report =
+            manager.clean_stale_packages(&workspace(&["@org/core"]), &renames, true).await.unwrap();
+
+        assert_eq!(report.renamed.len(), 1);
+        assert_eq!(report.removed.len(), 1);
+        assert_eq!(report.deleted, ["feature/b"]);
+        assert!(report.has_changes());
+        assert_eq!(manager.load("feature/a").await.unwrap().packages, ["@org/core"]);
+        assert!(!manager.storage().exists("feature/b").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_clean_without_stale_packages_changes_nothing() {
+        let manager = create_manager(&[], &[changeset("feature/a", &["@org/core"])]).await;
+
+        let report = manager
+            .clean_stale_packages(&workspace(&["@org/core"]), &BTreeMap::new(), true)
+            .await
+            .unwrap();
+
+        assert!(!report.has_changes());
+        assert!(manager.find_stale_packages(&workspace(&["@org/core"])).await.unwrap().is_empty());
+    }
 }
 
 // ============================================================================
@@ -2880,3 +3853,569 @@ mod history_tests {
         assert_eq!(archived.release_info.package_count(), 2);
     }
 }
+
+#[cfg(test)]
+mod query_tests {
+    use super::manager_tests::create_test_manager;
+    use crate::changeset::{ChangesetQuery, ChangesetSortField};
+    use crate::error::ChangesetError;
+    use crate::types::{Changeset, VersionBump};
+    use chrono::{Duration, Utc};
+
+    fn changeset(
+        branch: &str,
+        bump: VersionBump,
+        env: &str,
+        package: &str,
+        age_days: i64,
+    ) -> Changeset {
+        let mut changeset = Changeset::new(branch, bump, vec![env.to_string()]);
+        changeset.add_package(package);
+        changeset.created_at = Utc::now() - Duration::days(age_days);
+        changeset.updated_at = changeset.created_at;
+        changeset
+    }
+
+    fn sample() -> Vec<Changeset> {
+        vec![
+            changeset("feature/b", VersionBump::Patch, "staging", "@org/core", 1),
+            changeset("feature/a", VersionBump::Major, "production", "@org/core", 10),
+            changeset("feature/c", VersionBump::Minor, "production", "@org/utils", 5),
+        ]
+    }
+
+    fn branches(changesets: &[Changeset]) -> Vec<&str> {
+        changesets.iter().map(|c| c.branch.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_query_sorts_by_update_time() {
+        let page = ChangesetQuery::new().apply(sample());
+        assert_eq!(page.total, 3);
+        assert_eq!(branches(&page.changesets), vec!["feature/b", "feature/c", "feature/a"]);
+        assert!(!page.has_more());
+    }
+
+    #[test]
+    fn test_filters_are_combined() {
+        let page = ChangesetQuery::new()
+            .with_package("@org/core")
+            .with_environment("production")
+            .apply(sample());
+        assert_eq!(branches(&page.changesets), vec!["feature/a"]);
+
+        let page = ChangesetQuery::new().with_bump(VersionBump::Minor).apply(sample());
+        assert_eq!(branches(&page.changesets), vec!["feature/c"]);
+    }
+
+    #[test]
+    fn test_age_filters() {
+        let now = Utc::now();
+        let page = ChangesetQuery::new().created_after(now - Duration::days(7)).apply(sample());
+        assert_eq!(page.total, 2);
+
+        let page = ChangesetQuery::new().created_before(now - Duration::days(7)).apply(sample());
+        assert_eq!(branches(&page.changesets), vec!["feature/a"]);
+    }
+
+    #[test]
+    fn test_sort_fields_and_reverse() {
+        let page = ChangesetQuery::new().sort_by(ChangesetSortField::Bump).apply(sample());
+        assert_eq!(branches(&page.changesets), vec!["feature/a", "feature/c", "feature/b"]);
+
+        let page = ChangesetQuery::new()
+            .sort_by(ChangesetSortField::Branch)
+            .reversed(true)
+            .apply(sample());
+        assert_eq!(branches(&page.changesets), vec!["feature/c", "feature/b", "feature/a"]);
+    }
+
+    #[test]
+    fn test_pagination() {
+        let query = ChangesetQuery::new().sort_by(ChangesetSortField::Branch).with_limit(2);
+
+        let first = query.clone().apply(sample());
+        assert_eq!(branches(&first.changesets), vec!["feature/a", "feature/b"]);
+        assert_eq!(first.total, 3);
+        assert!(first.has_more());
+
+        let second = query.with_offset(2).apply(sample());
+        assert_eq!(branches(&second.changesets), vec!["feature/c"]);
+        assert!(!second.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_manager_query() {
+        let manager = create_test_manager();
+        manager
+            .create("feature/one", VersionBump::Minor, vec!["staging".to_string()])
+            .await
+            .unwrap();
+        manager
+            .create("feature/two", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+
+        let page = manager.query(&ChangesetQuery::new().with_environment("staging")).await.unwrap();
+        assert_eq!(branches(&page.changesets), vec!["feature/one"]);
+    }
+
+    #[tokio::test]
+    async fn test_manager_query_author_requires_git() {
+        let manager = create_test_manager();
+        let result = manager.query(&ChangesetQuery::new().with_author("alice")).await;
+        assert!(matches!(result, Err(ChangesetError::GitIntegration { .. })));
+    }
+}
+
+mod author_tests {
+    use super::manager_tests::{MockManagerStorage, create_test_config, create_test_manager};
+    use crate::changeset::ChangesetManager;
+    use crate::changeset::author::resolve_author;
+    use crate::types::{Changeset, VersionBump};
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn env_from(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> =
+            vars.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_resolve_author_prefers_ci_actor() {
+        let env =
+            env_from(&[("GITLAB_USER_LOGIN", "jdoe"), ("GITLAB_USER_EMAIL", "jdoe@example.com")]);
+        let author = resolve_author(None, &env).unwrap();
+
+        assert_eq!(author.name, "jdoe");
+        assert_eq!(author.email.as_deref(), Some("jdoe@example.com"));
+        assert_eq!(author.source, "gitlab");
+    }
+
+    #[test]
+    fn test_resolve_author_ignores_empty_values() {
+        let env = env_from(&[("GITHUB_ACTOR", "  "), ("CIRCLE_USERNAME", "circle-user")]);
+        let author = resolve_author(None, &env).unwrap();
+
+        assert_eq!(author.name, "circle-user");
+        assert_eq!(author.source, "circleci");
+        assert!(author.email.is_none());
+    }
+
+    #[test]
+    fn test_resolve_author_without_sources() {
+        assert!(resolve_author(None, &env_from(&[])).is_none());
+    }
+
+    #[test]
+    fn test_old_changeset_files_still_deserialize() {
+        let json = r#"{
+            "branch": "feature/old",
+            "bump": "minor",
+            "environments": ["production"],
+            "packages": ["@org/core"],
+            "changes": [],
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }"#;
+        let changeset: Changeset = serde_json::from_str(json).unwrap();
+
+        assert!(changeset.author.is_none());
+        assert!(changeset.created_on_branch.is_none());
+        assert!(changeset.revisions.is_empty());
+
+        let serialized = serde_json::to_string(&changeset).unwrap();
+        assert!(!serialized.contains("author"));
+        assert!(!serialized.contains("revisions"));
+    }
+
+    #[test]
+    fn test_describe_changes_since() {
+        let previous = Changeset::new("feature/x", VersionBump::Minor, vec!["staging".to_string()]);
+        let mut current = previous.clone();
+        current.bump = VersionBump::Major;
+        current.add_package("@org/core");
+        current.add_commit("abc123");
+
+        let changes = current.describe_changes_since(&previous);
+        assert!(changes.contains(&"bump: minor -> major".to_string()));
+        assert!(changes.contains(&"packages added: @org/core".to_string()));
+        assert!(changes.contains(&"commits added: 1".to_string()));
+        assert!(previous.describe_changes_since(&previous).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_records_revision() {
+        let manager = create_test_manager();
+        let mut changeset = manager
+            .create("feature/history", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+        changeset.add_package("@org/core");
+        manager.update(&changeset).await.unwrap();
+
+        changeset = manager.load("feature/history").await.unwrap();
+        assert_eq!(changeset.revisions.len(), 1);
+        assert_eq!(changeset.revisions[0].changes, vec!["packages added: @org/core".to_string()]);
+
+        // An update without changes does not add a revision
+        manager.update(&changeset).await.unwrap();
+        let reloaded = manager.load("feature/history").await.unwrap();
+        assert_eq!(reloaded.revisions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_tracking_disabled_records_nothing() {
+        let mut config = create_test_config();
+        config.track_authors = false;
+        let manager = ChangesetManager::with_storage(
+            MockManagerStorage::new(),
+            PathBuf::from("."),
+            None,
+            config,
+        );
+
+        let mut changeset = manager
+            .create("feature/private", VersionBump::Patch, vec!["production".to_string()])
+            .await
+            .unwrap();
+        changeset.add_package("@org/core");
+        manager.update(&changeset).await.unwrap();
+
+        let reloaded = manager.load("feature/private").await.unwrap();
+        assert!(reloaded.author.is_none());
+        assert!(reloaded.created_on_branch.is_none());
+        assert!(reloaded.revisions.is_empty());
+    }
+}
+
+// ============================================================================
+// Migration Tests
+// ============================================================================
+
+mod migration_tests {
+    use super::*;
+    use crate::changeset::{FileBasedChangesetStorage, migrate_changesets};
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    fn file_storage(root: &TempDir, dir: &str) -> FileBasedChangesetStorage<FileSystemManager> {
+        FileBasedChangesetStorage::new(
+            root.path().to_path_buf(),
+            dir.to_string(),
+            format!("{dir}/history"),
+            FileSystemManager::new(),
+        )
+    }
+
+    async fn seed(storage: &FileBasedChangesetStorage<FileSystemManager>) {
+        let mut released =
+            Changeset::new("feature/released", VersionBump::Minor, vec!["production".to_string()]);
+        released.add_package("@org/core");
+        storage.save(&released).await.unwrap();
+        let release_info = ReleaseInfo::new(
+            "dev@example.com".to_string(),
+            "abc123".to_string(),
+            versions_map(vec![("@org/core".to_string(), "1.1.0".to_string())]),
+        );
+        storage.archive(&released, release_info).await.unwrap();
+
+        // A later changeset on the same branch stays pending
+        let mut pending =
+            Changeset::new("feature/released", VersionBump::Patch, vec!["production".to_string()]);
+        pending.add_package("@org/core");
+        storage.save(&pending).await.unwrap();
+
+        let mut other =
+            Changeset::new("feature/other", VersionBump::Major, vec!["staging".to_string()]);
+        other.add_package("@org/utils");
+        storage.save(&other).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_migrate_copies_pending_and_archived() {
+        let root = tempfile::tempdir().unwrap();
+        let source = file_storage(&root, ".changesets");
+        let target = file_storage(&root, "changes");
+        seed(&source).await;
+
+        let report = migrate_changesets(&source, &target).await.unwrap();
+
+        assert_eq!(report.pending_copied, vec!["feature/other", "feature/released"]);
+        assert_eq!(report.archived_copied.len(), 1);
+        assert_eq!(report.skipped_count(), 0);
+
+        // The pending changeset survives archiving its predecessor on the same branch
+        let pending = target.load("feature/released").await.unwrap();
+        assert_eq!(pending.bump, VersionBump::Patch);
+        assert_eq!(target.list_archived().await.unwrap(), source.list_archived().await.unwrap());
+
+        // The source is left untouched
+        assert_eq!(source.list_pending().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_resumes_without_duplicating() {
+        let root = tempfile::tempdir().unwrap();
+        let source = file_storage(&root, ".changesets");
+        let target = file_storage(&root, "changes");
+        seed(&source).await;
+
+        // Simulate an interrupted run that copied only one pending changeset
+        target.save(&source.load("feature/other").await.unwrap()).await.unwrap();
+
+        let report = migrate_changesets(&source, &target).await.unwrap();
+        assert_eq!(report.pending_skipped, vec!["feature/other"]);
+        assert_eq!(report.copied_count(), 2);
+
+        let rerun = migrate_changesets(&source, &target).await.unwrap();
+        assert_eq!(rerun.copied_count(), 0);
+        assert_eq!(rerun.skipped_count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_refuses_to_overwrite_different_changeset() {
+        let root = tempfile::tempdir().unwrap();
+        let source = file_storage(&root, ".changesets");
+        let target = file_storage(&root, "changes");
+        seed(&source).await;
+
+        let conflicting =
+            Changeset::new("feature/other", VersionBump::Patch, vec!["production".to_string()]);
+        target.save(&conflicting).await.unwrap();
+
+        let result = migrate_changesets(&source, &target).await;
+        assert!(matches!(
+            result,
+            Err(ChangesetError::MigrationFailed { reference, .. }) if reference == "feature/other"
+        ));
+        assert_eq!(target.load("feature/other").await.unwrap(), conflicting);
+    }
+}
+
+// ============================================================================
+// Storage Backend Tests
+// ============================================================================
+
+mod backend_tests {
+    use super::*;
+    use crate::changeset::{
+        ChangesetBackendRegistry, ChangesetStorageLocation, FILE_BACKEND, FileBasedChangesetStorage,
+    };
+    use std::path::PathBuf;
+
+    fn location(root: &tempfile::TempDir, dir: &str) -> ChangesetStorageLocation {
+        ChangesetStorageLocation::new(root.path().to_path_buf(), dir, format!("{dir}/history"))
+    }
+
+    #[tokio::test]
+    async fn test_registry_opens_file_backend() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = ChangesetBackendRegistry::default()
+            .open(FILE_BACKEND, &location(&root, "changes"))
+            .unwrap();
+
+        let mut changeset =
+            Changeset::new("feature/x", VersionBump::Minor, vec!["production".to_string()]);
+        changeset.add_package("@org/core");
+        storage.save(&changeset).await.unwrap();
+
+        assert!(storage.exists("feature/x").await.unwrap());
+        assert!(root.path().join("changes").is_dir());
+    }
+
+    #[test]
+    fn test_registry_rejects_unknown_backend() {
+        let root = tempfile::tempdir().unwrap();
+        let result = ChangesetBackendRegistry::default().open("s3", &location(&root, "changes"));
+
+        assert!(matches!(
+            result,
+            Err(ChangesetError::UnknownBackend { name, available })
+                if name == "s3" && available == vec![FILE_BACKEND]
+        ));
+    }
+
+    #[test]
+    fn test_registry_rejects_duplicate_backend() {
+        let mut registry = ChangesetBackendRegistry::empty();
+        let factory = |_: &ChangesetStorageLocation| -> Box<dyn ChangesetStorage> {
+            Box::new(FileBasedChangesetStorage::new(
+                PathBuf::from("."),
+                ".changesets".to_string(),
+                ".changesets/history".to_string(),
+                sublime_standard_tools::filesystem::FileSystemManager::new(),
+            ))
+        };
+
+        registry.register("memory", factory).unwrap();
+        assert!(matches!(
+            registry.register("memory", factory),
+            Err(ChangesetError::DuplicateBackend { name }) if name == "memory"
+        ));
+        assert_eq!(registry.names(), vec!["memory"]);
+    }
+
+    #[test]
+    fn test_location_overlap_normalizes_paths() {
+        let root = tempfile::tempdir().unwrap();
+        let configured = location(&root, ".changesets");
+
+        assert!(configured.overlaps(&location(&root, "./.changesets")));
+        assert!(configured.overlaps(&location(&root, "changes/../.changesets")));
+        assert!(configured.overlaps(&ChangesetStorageLocation::new(
+            root.path().to_path_buf(),
+            "changes",
+            ".changesets/./history",
+        )));
+        assert!(!configured.overlaps(&location(&root, "changes")));
+    }
+}
+
+// ============================================================================
+// Canonical Format Tests
+// ============================================================================
+
+mod format_tests {
+    use super::*;
+    use crate::changeset::{FileBasedChangesetStorage, merge_changeset_files, to_canonical_json};
+    use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+    fn changeset() -> Changeset {
+        let mut changeset =
+            Changeset::new("feature/sso", VersionBump::Patch, vec!["production".to_string()]);
+        changeset.add_package("@org/auth");
+        changeset.add_commit("abc123");
+        changeset
+    }
+
+    fn merge(base: &Changeset, ours: &Changeset, theirs: &Changeset) -> ChangesetResult<Changeset> {
+        let merged = merge_changeset_files(
+            &to_canonical_json(base)?,
+            &to_canonical_json(ours)?,
+            &to_canonical_json(theirs)?,
+        )?;
+        Ok(serde_json::from_str(&merged).unwrap())
+    }
+
+    #[test]
+    fn test_canonical_json_layout() {
+        let release_info = ReleaseInfo::new(
+            "ci".to_string(),
+            "abc123".to_string(),
+            versions_map(vec![
+                ("@org/web".to_string(), "2.0.0".to_string()),
+                ("@org/auth".to_string(), "1.1.0".to_string()),
+                ("@org/core".to_string(), "1.0.1".to_string()),
+            ]),
+        );
+        let archived = ArchivedChangeset::new(changeset(), release_info);
+
+        let json = to_canonical_json(&archived).unwrap();
+
+        assert!(json.ends_with("}\n"));
+        assert!(!json.ends_with("\n\n"));
+        assert!(json.contains("  \"packages\": [\n      \"@org/auth\"\n    ],"));
+        let auth = json.find("\"@org/auth\": \"1.1.0\"").unwrap();
+        let core = json.find("\"@org/core\": \"1.0.1\"").unwrap();
+        let web = json.find("\"@org/web\": \"2.0.0\"").unwrap();
+        assert!(auth < core && core < web);
+
+        // Equal values always produce identical files
+        let reparsed: ArchivedChangeset = serde_json::from_str(&json).unwrap();
+        assert_eq!(to_canonical_json(&reparsed).unwrap(), json);
+    }
+
+    #[tokio::test]
+    async fn test_file_storage_writes_canonical_json() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let fs = FileSystemManager::new();
+        let storage = FileBasedChangesetStorage::new(
+            temp_dir.path().to_path_buf(),
+            ".changesets".to_string(),
+            ".changesets/history".to_string(),
+            fs.clone(),
+        );
+        let changeset = changeset();
+
+        storage.save(&changeset).await.unwrap();
+
+        let path = storage.changeset_path(&changeset.id);
+        let contents = fs.read_file_string(&path).await.unwrap();
+        assert_eq!(contents, to_canonical_json(&changeset).unwrap());
+    }
+
+    #[test]
+    fn test_merge_combines_list_edits() {
+        let base = changeset();
+        let mut ours = base.clone();
+        ours.add_package("@org/core");
+        ours.add_commit("def456");
+        let mut theirs = base.clone();
+        theirs.remove_package("@org/auth");
+        theirs.add_package("@org/web");
+        theirs.environments.push("staging".to_string());
+
+        let merged = merge(&base, &ours, &theirs).unwrap();
+
+        assert_eq!(merged.packages, vec!["@org/core".to_string(), "@org/web".to_string()]);
+        assert_eq!(merged.environments, vec!["production".to_string(), "staging".to_string()]);
+        assert_eq!(merged.changes, vec!["abc123".to_string(), "def456".to_string()]);
+        assert_eq!(merged.id, base.id);
+    }
+
+    #[test]
+    fn test_merge_keeps_largest_conflicting_bump() {
+        let base = changeset();
+        let mut ours = base.clone();
+        ours.set_bump(VersionBump::Minor);
+        let mut theirs = base.clone();
+        theirs.set_bump(VersionBump::Major);
+
+        assert_eq!(merge(&base, &ours, &theirs).unwrap().bump, VersionBump::Major);
+        assert_eq!(merge(&base, &theirs, &ours).unwrap().bump, VersionBump::Major);
+
+        // A bump changed on one side only is taken as is, even when smaller
+        let mut lowered = base.clone();
+        lowered.set_bump(VersionBump::None);
+        assert_eq!(merge(&base, &lowered, &base).unwrap().bump, VersionBump::None);
+    }
+
+    #[test]
+    fn test_merge_without_ancestor_unions_both_sides() {
+        let ours = changeset();
+        let mut theirs = ours.clone();
+        theirs.add_package("@org/core");
+
+        let merged = merge_changeset_files(
+            "",
+            &to_canonical_json(&ours).unwrap(),
+            &to_canonical_json(&theirs).unwrap(),
+        )
+        .unwrap();
+        let merged: Changeset = serde_json::from_str(&merged).unwrap();
+
+        assert_eq!(merged.packages, vec!["@org/auth".to_string(), "@org/core".to_string()]);
+    }
+
+    #[test]
+    fn test_merge_reports_conflicting_fields() {
+        let base = changeset();
+        let mut ours = base.clone();
+        ours.name = Some("brave-lions-smile".to_string());
+        let mut theirs = base.clone();
+        theirs.name = Some("quiet-owls-nap".to_string());
+
+        let result = merge(&base, &ours, &theirs);
+
+        assert!(matches!(
+            result,
+            Err(ChangesetError::MergeConflict { ref fields, .. }) if fields == &["name".to_string()]
+        ));
+        assert!(matches!(
+            merge_changeset_files("", "{}", "{}"),
+            Err(ChangesetError::SerializationError { .. })
+        ));
+    }
+}
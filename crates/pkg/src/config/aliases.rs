@@ -0,0 +1,149 @@
+//! Import alias configuration for workspace packages.
+//!
+//! **What**: Defines configuration for the import aliases generated from the workspace
+//! dependency graph: the `compilerOptions.paths` of a TypeScript project file and, optionally,
+//! the `imports` map of each package's `package.json`.
+//!
+//! **How**: This module provides the `AliasesConfig` structure. `tsconfig` names the project
+//! file that receives a `paths` entry for every workspace package another package depends on,
+//! pointing at `source_dir`/`entry` inside that package. With `package_imports`, each package
+//! also gets a `#`-prefixed `imports` entry for every workspace package it depends on.
+//!
+//! **Why**: So internal packages resolve to their sources during development without being
+//! built or published, while the maps stay derived from the declared dependencies instead of
+//! being maintained by hand.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use sublime_standard_tools::config::{ConfigError, ConfigResult, Configurable};
+
+/// Configuration for generated import aliases.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::AliasesConfig;
+///
+/// let config = AliasesConfig::default();
+/// assert_eq!(config.tsconfig, "tsconfig.json");
+/// assert_eq!(config.source_dir, "src");
+/// assert!(!config.package_imports);
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [package_tools.aliases]
+/// tsconfig = "tsconfig.base.json"
+/// source_dir = "src"
+/// entry = "index.ts"
+/// package_imports = true
+/// imports_prefix = "#"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AliasesConfig {
+    /// TypeScript project file, relative to the workspace root, whose
+    /// `compilerOptions.paths` is maintained.
+    ///
+    /// # Default: `"tsconfig.json"`
+    #[serde(default = "default_tsconfig")]
+    pub tsconfig: String,
+
+    /// Directory inside each package that aliases point at.
+    ///
+    /// # Default: `"src"`
+    #[serde(default = "default_source_dir")]
+    pub source_dir: String,
+
+    /// File inside `source_dir` that the bare package name resolves to.
+    ///
+    /// # Default: `"index.ts"`
+    #[serde(default = "default_entry")]
+    pub entry: String,
+
+    /// Whether to also maintain the `imports` map of each package's `package.json`.
+    ///
+    /// # Default: `false`
+    #[serde(default)]
+    pub package_imports: bool,
+
+    /// Prefix of the generated `imports` keys. Node requires subpath imports to start
+    /// with `#`.
+    ///
+    /// # Default: `"#"`
+    #[serde(default = "default_imports_prefix")]
+    pub imports_prefix: String,
+}
+
+impl Default for AliasesConfig {
+    fn default() -> Self {
+        Self {
+            tsconfig: default_tsconfig(),
+            source_dir: default_source_dir(),
+            entry: default_entry(),
+            package_imports: false,
+            imports_prefix: default_imports_prefix(),
+        }
+    }
+}
+
+fn default_tsconfig() -> String {
+    "tsconfig.json".to_string()
+}
+
+fn default_source_dir() -> String {
+    "src".to_string()
+}
+
+fn default_entry() -> String {
+    "index.ts".to_string()
+}
+
+fn default_imports_prefix() -> String {
+    "#".to_string()
+}
+
+impl Configurable for AliasesConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.tsconfig.trim().is_empty() || Path::new(&self.tsconfig).is_absolute() {
+            return Err(ConfigError::ValidationError {
+                message: "aliases.tsconfig: Must be a non-empty path relative to the workspace \
+                          root"
+                    .to_string(),
+            });
+        }
+
+        if Path::new(&self.source_dir).is_absolute() {
+            return Err(ConfigError::ValidationError {
+                message: "aliases.source_dir: Must be relative to the package directory"
+                    .to_string(),
+            });
+        }
+
+        if self.entry.trim().is_empty() {
+            return Err(ConfigError::ValidationError {
+                message: "aliases.entry: Cannot be empty".to_string(),
+            });
+        }
+
+        if !self.imports_prefix.starts_with('#') || self.imports_prefix.contains('*') {
+            return Err(ConfigError::ValidationError {
+                message: format!(
+                    "aliases.imports_prefix: '{}' must start with '#' and cannot contain '*'",
+                    self.imports_prefix
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.tsconfig = other.tsconfig;
+        self.source_dir = other.source_dir;
+        self.entry = other.entry;
+        self.package_imports = other.package_imports;
+        self.imports_prefix = other.imports_prefix;
+        Ok(())
+    }
+}
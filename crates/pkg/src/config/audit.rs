@@ -12,6 +12,7 @@
 //! environments.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use sublime_standard_tools::config::{ConfigResult, Configurable};
 
 /// Configuration for audit and health check operations.
@@ -27,6 +28,7 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// let config = AuditConfig::default();
 /// assert!(config.enabled);
 /// assert_eq!(config.min_severity, "warning");
+/// assert_eq!(config.concurrency, 16);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AuditConfig {
@@ -59,6 +61,99 @@ pub struct AuditConfig {
 
     /// Configuration for health score calculation weights.
     pub health_score_weights: HealthScoreWeightsConfig,
+
+    /// Configuration for the registry ownership check of the organization's scopes.
+    #[serde(default)]
+    pub registry_ownership: RegistryOwnershipAuditConfig,
+
+    /// Maximum number of registry lookups in flight at once.
+    ///
+    /// Lookups from all packages share this limit, so a package with many dependencies
+    /// does not hold back the others.
+    ///
+    /// # Default: `16`
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+
+    /// Maximum time in seconds a single audit check may run.
+    ///
+    /// A check that exceeds it is reported as timed out and the remaining checks still
+    /// complete.
+    ///
+    /// # Default: `300`
+    #[serde(default = "default_check_timeout_secs")]
+    pub check_timeout_secs: u64,
+
+    /// Per-check overrides keyed by check id.
+    ///
+    /// Keys are built-in check ids (`upgrades`, `dependencies`, `version-consistency`,
+    /// `breaking-changes`) or ids of custom checks registered on the `AuditManager`.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub checks: BTreeMap<String, AuditCheckConfig>,
+}
+
+impl AuditConfig {
+    /// Returns whether the check with the given id runs.
+    ///
+    /// Checks without an entry in `checks` run.
+    #[must_use]
+    pub fn is_check_enabled(&self, id: &str) -> bool {
+        self.checks.get(id).is_none_or(|check| check.enabled)
+    }
+
+    /// Returns the severity override for the check with the given id, if any.
+    #[must_use]
+    pub fn check_severity(&self, id: &str) -> Option<&str> {
+        self.checks.get(id).and_then(|check| check.severity.as_deref())
+    }
+}
+
+fn default_concurrency() -> usize {
+    16
+}
+
+fn default_check_timeout_secs() -> u64 {
+    300
+}
+
+/// Overrides for a single audit check.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::AuditCheckConfig;
+///
+/// let config = AuditCheckConfig::default();
+/// assert!(config.enabled);
+/// assert!(config.severity.is_none());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditCheckConfig {
+    /// Whether the check runs.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_check_enabled")]
+    pub enabled: bool,
+
+    /// Severity given to every issue the check reports, replacing the check's own.
+    ///
+    /// Valid values: "critical", "warning", "info"
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+}
+
+impl Default for AuditCheckConfig {
+    fn default() -> Self {
+        Self { enabled: true, severity: None }
+    }
+}
+
+fn default_check_enabled() -> bool {
+    true
 }
 
 /// Configuration for which audit sections to execute.
@@ -150,6 +245,8 @@ pub struct UpgradeAuditConfig {
 /// let config = DependencyAuditConfig::default();
 /// assert!(config.check_circular);
 /// assert!(config.check_version_conflicts);
+/// assert!(config.check_collisions);
+/// assert!(config.check_lockfile);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DependencyAuditConfig {
@@ -172,6 +269,22 @@ pub struct DependencyAuditConfig {
     ///
     /// # Default: `true`
     pub check_version_conflicts: bool,
+
+    /// Whether to detect duplicate package names, nested package directories, and
+    /// internal packages shadowed by the registry.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_check_enabled")]
+    pub check_collisions: bool,
+
+    /// Whether to check how the lockfile resolves dependencies on internal packages.
+    ///
+    /// Reports internal dependencies that `package-lock.json`, `pnpm-lock.yaml`, or
+    /// `yarn.lock` resolves to a registry version instead of the workspace package.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_check_enabled")]
+    pub check_lockfile: bool,
 }
 
 /// Configuration for breaking changes audits.
@@ -226,6 +339,37 @@ pub struct VersionConsistencyAuditConfig {
     pub warn_on_inconsistency: bool,
 }
 
+/// Configuration for the registry ownership check.
+///
+/// The check runs when at least one scope is listed. Every non-private workspace package
+/// under these scopes must exist on the registry it resolves to, and that registry must not
+/// be the public npm registry.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::RegistryOwnershipAuditConfig;
+///
+/// let config = RegistryOwnershipAuditConfig::default();
+/// assert!(config.scopes.is_empty());
+/// assert!(config.owners.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RegistryOwnershipAuditConfig {
+    /// Scopes owned by the organization, with or without the leading `@`.
+    ///
+    /// # Default: empty (check disabled)
+    pub scopes: Vec<String>,
+
+    /// Registry users or organizations, one of which must maintain each published package.
+    ///
+    /// Ownership is not checked when empty.
+    ///
+    /// # Default: empty
+    pub owners: Vec<String>,
+}
+
 impl Default for AuditConfig {
     fn default() -> Self {
         Self {
@@ -237,6 +381,10 @@ impl Default for AuditConfig {
             breaking_changes: BreakingChangesAuditConfig::default(),
             version_consistency: VersionConsistencyAuditConfig::default(),
             health_score_weights: HealthScoreWeightsConfig::default(),
+            registry_ownership: RegistryOwnershipAuditConfig::default(),
+            concurrency: default_concurrency(),
+            check_timeout_secs: default_check_timeout_secs(),
+            checks: BTreeMap::new(),
         }
     }
 }
@@ -271,6 +419,8 @@ impl Default for DependencyAuditConfig {
             check_missing: false,
             check_unused: false,
             check_version_conflicts: true,
+            check_collisions: true,
+            check_lockfile: true,
         }
     }
 }
@@ -380,12 +530,38 @@ impl Configurable for AuditConfig {
             }
         }
 
+        if self.concurrency == 0 {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "audit.concurrency: Concurrency must be greater than 0".to_string(),
+            });
+        }
+
+        if self.check_timeout_secs == 0 {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "audit.check_timeout_secs: Timeout must be greater than 0".to_string(),
+            });
+        }
+
+        for (id, check) in &self.checks {
+            if let Some(severity) = &check.severity
+                && !matches!(severity.as_str(), "critical" | "warning" | "info")
+            {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "audit.checks.{}.severity: Invalid severity '{}'. Must be one of: critical, warning, info",
+                        id, severity
+                    ),
+                });
+            }
+        }
+
         self.sections.validate()?;
         self.upgrades.validate()?;
         self.dependencies.validate()?;
         self.breaking_changes.validate()?;
         self.version_consistency.validate()?;
         self.health_score_weights.validate()?;
+        self.registry_ownership.validate()?;
 
         Ok(())
     }
@@ -399,6 +575,10 @@ impl Configurable for AuditConfig {
         self.breaking_changes.merge_with(other.breaking_changes)?;
         self.version_consistency.merge_with(other.version_consistency)?;
         self.health_score_weights.merge_with(other.health_score_weights)?;
+        self.registry_ownership.merge_with(other.registry_ownership)?;
+        self.concurrency = other.concurrency;
+        self.check_timeout_secs = other.check_timeout_secs;
+        self.checks.extend(other.checks);
         Ok(())
     }
 }
@@ -442,6 +622,8 @@ impl Configurable for DependencyAuditConfig {
         self.check_missing = other.check_missing;
         self.check_unused = other.check_unused;
         self.check_version_conflicts = other.check_version_conflicts;
+        self.check_collisions = other.check_collisions;
+        self.check_lockfile = other.check_lockfile;
         Ok(())
     }
 }
@@ -470,6 +652,29 @@ impl Configurable for VersionConsistencyAuditConfig {
     }
 }
 
+impl Configurable for RegistryOwnershipAuditConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        for scope in &self.scopes {
+            let name = scope.strip_prefix('@').unwrap_or(scope);
+            if name.is_empty() || name.contains('/') {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "audit.registry_ownership.scopes: Invalid scope '{}'. Expected a scope such as '@myorg'",
+                        scope
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.scopes = other.scopes;
+        self.owners = other.owners;
+        Ok(())
+    }
+}
+
 impl Configurable for HealthScoreWeightsConfig {
     fn validate(&self) -> ConfigResult<()> {
         // Validate that all weights are positive
@@ -9,8 +9,11 @@
 //! **Why**: To enable flexible changelog generation that supports multiple formats and
 //! conventions while maintaining consistency and clarity.
 
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Local, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
 use sublime_standard_tools::config::{ConfigResult, Configurable};
 
 /// Configuration for changelog generation.
@@ -42,9 +45,27 @@ pub struct ChangelogConfig {
 
     /// The filename for the changelog.
     ///
+    /// Relative to the package directory (or the workspace root for the root changelog),
+    /// so it may name a subdirectory, e.g. `docs/CHANGELOG.md`.
+    ///
     /// # Default: `"CHANGELOG.md"`
     pub filename: String,
 
+    /// Whether to create the changelog file when it does not exist yet.
+    ///
+    /// When disabled, releases of packages without a changelog file leave them without one.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_create_missing")]
+    pub create_missing: bool,
+
+    /// Per-package overrides of the changelog file and release tag format, keyed by package
+    /// name.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageChangelogConfig>,
+
     /// Whether to include links to commits in the repository.
     ///
     /// # Default: `true`
@@ -60,6 +81,23 @@ pub struct ChangelogConfig {
     /// # Default: `false`
     pub include_authors: bool,
 
+    /// Whether to add a "Dependencies" section listing internal dependency updates.
+    ///
+    /// Packages bumped only through dependency propagation otherwise get an empty entry.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_include_dependency_updates")]
+    pub include_dependency_updates: bool,
+
+    /// Whether to keep an `## [Unreleased]` section listing the pending changes.
+    ///
+    /// The section is rewritten whenever a changeset is added, updated, or removed, and is
+    /// replaced by the release section at bump time.
+    ///
+    /// # Default: `false`
+    #[serde(default)]
+    pub unreleased_section: bool,
+
     /// Repository URL for generating links.
     ///
     /// If not set, will attempt to auto-detect from git remote.
@@ -74,7 +112,8 @@ pub struct ChangelogConfig {
 
     /// Format for version tags in monorepo packages.
     ///
-    /// Supports placeholders: {name}, {version}
+    /// Supports placeholders: {name}, {version}. Packages with a `tag_format` override use
+    /// theirs instead.
     ///
     /// # Default: `"{name}@{version}"`
     pub version_tag_format: String,
@@ -92,8 +131,39 @@ pub struct ChangelogConfig {
     /// Exclusion rules for commits.
     pub exclude: ExcludeConfig,
 
+    /// Inclusion rules for commits.
+    ///
+    /// # Default: all commit types included
+    #[serde(default)]
+    pub include: IncludeConfig,
+
+    /// Maps conventional commit scopes to package names.
+    ///
+    /// A commit such as `feat(core): ...` whose scope is mapped belongs to the mapped package's
+    /// changelog, whichever files it touches. Commits with unmapped scopes are attributed by
+    /// the files they change.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub scope_packages: HashMap<String, String>,
+
     /// Custom template configuration.
     pub template: TemplateConfig,
+
+    /// Release date settings used in version headings.
+    ///
+    /// # Default: `"%Y-%m-%d"` in UTC, honoring `SOURCE_DATE_EPOCH`
+    #[serde(default)]
+    pub date: ChangelogDateConfig,
+
+    /// Maximum number of package changelogs generated or written at once.
+    ///
+    /// Each package walks its history on its own blocking thread and repository handle, so
+    /// the git work of different packages runs in parallel.
+    ///
+    /// # Default: `8`
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
 }
 
 /// Changelog format type.
@@ -165,8 +235,34 @@ pub struct ExcludeConfig {
 
     /// Authors whose commits should be excluded.
     ///
+    /// Matched against the author name or email. A `*` matches any sequence of characters,
+    /// so `*[bot]` excludes every bot account.
+    ///
     /// # Default: empty
     pub authors: Vec<String>,
+
+    /// Conventional commit types to exclude, such as `chore` or `ci`.
+    ///
+    /// Breaking changes are always included.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub types: Vec<String>,
+}
+
+/// Inclusion rules for changelog generation.
+///
+/// Restricts changelogs to specific commits. Exclusion rules still apply to included commits.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct IncludeConfig {
+    /// Conventional commit types to include, such as `feat` and `fix`.
+    ///
+    /// When set, commits of other types and non-conventional commits are left out.
+    /// Breaking changes are always included.
+    ///
+    /// # Default: empty (all types)
+    #[serde(default)]
+    pub types: Vec<String>,
 }
 
 /// Custom template configuration for changelog generation.
@@ -207,18 +303,228 @@ impl Default for ChangelogConfig {
             enabled: true,
             format: ChangelogFormat::KeepAChangelog,
             filename: "CHANGELOG.md".to_string(),
+            create_missing: true,
+            packages: BTreeMap::new(),
             include_commit_links: true,
             include_issue_links: true,
             include_authors: false,
+            include_dependency_updates: true,
+            unreleased_section: false,
             repository_url: None,
             monorepo_mode: MonorepoMode::PerPackage,
             version_tag_format: "{name}@{version}".to_string(),
             root_tag_format: "v{version}".to_string(),
             conventional: ConventionalConfig::default(),
             exclude: ExcludeConfig::default(),
+            include: IncludeConfig::default(),
+            scope_packages: HashMap::new(),
             template: TemplateConfig::default(),
+            date: ChangelogDateConfig::default(),
+            concurrency: default_concurrency(),
+        }
+    }
+}
+
+/// Returns the default for `ChangelogConfig::include_dependency_updates`.
+fn default_include_dependency_updates() -> bool {
+    true
+}
+
+fn default_create_missing() -> bool {
+    true
+}
+
+fn default_concurrency() -> usize {
+    8
+}
+
+impl ChangelogConfig {
+    /// Returns the changelog file of a package, relative to the package directory.
+    ///
+    /// Packages without a `filename` override use `filename`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{ChangelogConfig, PackageChangelogConfig};
+    ///
+    /// let mut config = ChangelogConfig::default();
+    /// config.packages.insert(
+    ///     "@org/docs".to_string(),
+    ///     PackageChangelogConfig { filename: Some("docs/HISTORY.md".to_string()), ..Default::default() },
+    /// );
+    ///
+    /// assert_eq!(config.package_filename("@org/docs"), "docs/HISTORY.md");
+    /// assert_eq!(config.package_filename("@org/core"), "CHANGELOG.md");
+    /// ```
+    #[must_use]
+    pub fn package_filename(&self, package: &str) -> &str {
+        self.packages
+            .get(package)
+            .and_then(|overrides| overrides.filename.as_deref())
+            .unwrap_or(&self.filename)
+    }
+
+    /// Returns the release tag format of a package, or of the workspace root when `None`.
+    ///
+    /// Packages without a `tag_format` override use `version_tag_format`; the workspace
+    /// root uses `root_tag_format`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{ChangelogConfig, PackageChangelogConfig};
+    ///
+    /// let mut config = ChangelogConfig::default();
+    /// config.packages.insert(
+    ///     "@org/app".to_string(),
+    ///     PackageChangelogConfig { tag_format: Some("app/v{version}".to_string()), ..Default::default() },
+    /// );
+    ///
+    /// assert_eq!(config.tag_format(Some("@org/app")), "app/v{version}");
+    /// assert_eq!(config.tag_format(Some("@org/core")), "{name}@{version}");
+    /// assert_eq!(config.tag_format(None), "v{version}");
+    /// ```
+    #[must_use]
+    pub fn tag_format(&self, package: Option<&str>) -> &str {
+        match package {
+            Some(name) => self
+                .packages
+                .get(name)
+                .and_then(|overrides| overrides.tag_format.as_deref())
+                .unwrap_or(&self.version_tag_format),
+            None => &self.root_tag_format,
         }
     }
+
+    /// Returns the release tag of a package version, or of a workspace version when `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{ChangelogConfig, PackageChangelogConfig};
+    ///
+    /// let mut config = ChangelogConfig::default();
+    /// config.packages.insert(
+    ///     "@org/app".to_string(),
+    ///     PackageChangelogConfig { tag_format: Some("{name}/v{version}".to_string()), ..Default::default() },
+    /// );
+    ///
+    /// assert_eq!(config.release_tag(Some("@org/app"), "2024.6.0"), "@org/app/v2024.6.0");
+    /// assert_eq!(config.release_tag(Some("@org/core"), "1.2.0"), "@org/core@1.2.0");
+    /// assert_eq!(config.release_tag(None, "1.2.0"), "v1.2.0");
+    /// ```
+    #[must_use]
+    pub fn release_tag(&self, package: Option<&str>, version: &str) -> String {
+        crate::changelog::version_detection::format_version_tag(
+            package,
+            version,
+            self.tag_format(package),
+        )
+    }
+
+    /// Returns whether a package released on its own is tagged with the root tag format.
+    ///
+    /// Single-package releases use the root format (`v{version}`), unless the package is
+    /// scoped or has its own `tag_format`, in which case the package format is kept so its
+    /// tags stay distinguishable.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - Name of the released package
+    /// * `single_package` - Whether the package is the only one released or in the repository
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::ChangelogConfig;
+    ///
+    /// let config = ChangelogConfig::default();
+    /// assert!(config.uses_root_tag("my-lib", true));
+    /// assert!(!config.uses_root_tag("@org/lib", true));
+    /// assert!(!config.uses_root_tag("my-lib", false));
+    /// ```
+    #[must_use]
+    pub fn uses_root_tag(&self, package: &str, single_package: bool) -> bool {
+        single_package
+            && !package.starts_with('@')
+            && self.packages.get(package).is_none_or(|o| o.tag_format.is_none())
+    }
+
+    /// Returns the release tag of a package version, applying the single-package rule.
+    ///
+    /// See [`uses_root_tag`](Self::uses_root_tag) for when the root format is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::ChangelogConfig;
+    ///
+    /// let config = ChangelogConfig::default();
+    /// assert_eq!(config.package_release_tag("my-lib", "1.2.0", true), "v1.2.0");
+    /// assert_eq!(config.package_release_tag("@org/lib", "1.2.0", true), "@org/lib@1.2.0");
+    /// assert_eq!(config.package_release_tag("my-lib", "1.2.0", false), "my-lib@1.2.0");
+    /// ```
+    #[must_use]
+    pub fn package_release_tag(
+        &self,
+        package: &str,
+        version: &str,
+        single_package: bool,
+    ) -> String {
+        let root = self.uses_root_tag(package, single_package);
+        self.release_tag((!root).then_some(package), version)
+    }
+
+    /// Returns whether a missing changelog file is created for a package.
+    ///
+    /// `None` stands for the root changelog, which always follows `create_missing`.
+    #[must_use]
+    pub fn creates_missing(&self, package: Option<&str>) -> bool {
+        package
+            .and_then(|name| self.packages.get(name))
+            .and_then(|overrides| overrides.create_missing)
+            .unwrap_or(self.create_missing)
+    }
+}
+
+/// Overrides of the changelog file and release tags for a single package.
+///
+/// Unset fields fall back to the `[package_tools.changelog]` settings.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::PackageChangelogConfig;
+///
+/// let config = PackageChangelogConfig::default();
+/// assert!(config.filename.is_none());
+/// assert!(config.create_missing.is_none());
+/// assert!(config.tag_format.is_none());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PackageChangelogConfig {
+    /// Changelog file relative to the package directory, e.g. `docs/CHANGELOG.md`.
+    ///
+    /// # Default: `None` (uses `changelog.filename`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+
+    /// Whether to create the changelog file when it does not exist yet.
+    ///
+    /// # Default: `None` (uses `changelog.create_missing`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_missing: Option<bool>,
+
+    /// Format of the package's release tags, e.g. `{name}/v{version}`, or `v{version}` for
+    /// an app released under unified tags.
+    ///
+    /// Used to create release tags, detect release baselines, and build changelog compare
+    /// links.
+    ///
+    /// # Default: `None` (uses `changelog.version_tag_format`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_format: Option<String>,
 }
 
 impl Default for ConventionalConfig {
@@ -270,9 +576,48 @@ impl Configurable for ChangelogConfig {
             });
         }
 
+        if self.concurrency == 0 {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "changelog.concurrency: Concurrency must be greater than 0".to_string(),
+            });
+        }
+
         self.conventional.validate()?;
         self.exclude.validate()?;
+        self.include.validate()?;
         self.template.validate()?;
+        self.date.validate()?;
+
+        for (package, overrides) in &self.packages {
+            if let Some(filename) = &overrides.filename
+                && (filename.trim().is_empty() || std::path::Path::new(filename).is_absolute())
+            {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "changelog.packages.{package}.filename: Filename must be a non-empty path \
+                         relative to the package directory"
+                    ),
+                });
+            }
+            if let Some(format) = &overrides.tag_format
+                && !format.contains("{version}")
+            {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "changelog.packages.{package}.tag_format: Format must contain '{{version}}' \
+                         placeholder"
+                    ),
+                });
+            }
+        }
+
+        if let Some((scope, _)) =
+            self.scope_packages.iter().find(|(_, name)| name.trim().is_empty())
+        {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!("changelog.scope_packages.{scope}: Package name cannot be empty"),
+            });
+        }
 
         Ok(())
     }
@@ -281,16 +626,24 @@ impl Configurable for ChangelogConfig {
         self.enabled = other.enabled;
         self.format = other.format;
         self.filename = other.filename;
+        self.create_missing = other.create_missing;
+        self.packages = other.packages;
         self.include_commit_links = other.include_commit_links;
         self.include_issue_links = other.include_issue_links;
         self.include_authors = other.include_authors;
+        self.include_dependency_updates = other.include_dependency_updates;
+        self.unreleased_section = other.unreleased_section;
         self.repository_url = other.repository_url;
         self.monorepo_mode = other.monorepo_mode;
         self.version_tag_format = other.version_tag_format;
         self.root_tag_format = other.root_tag_format;
         self.conventional.merge_with(other.conventional)?;
         self.exclude.merge_with(other.exclude)?;
+        self.include.merge_with(other.include)?;
+        self.scope_packages = other.scope_packages;
         self.template.merge_with(other.template)?;
+        self.date.merge_with(other.date)?;
+        self.concurrency = other.concurrency;
         Ok(())
     }
 }
@@ -321,6 +674,18 @@ impl Configurable for ExcludeConfig {
     fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
         self.patterns = other.patterns;
         self.authors = other.authors;
+        self.types = other.types;
+        Ok(())
+    }
+}
+
+impl Configurable for IncludeConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.types = other.types;
         Ok(())
     }
 }
@@ -367,3 +732,160 @@ impl Configurable for TemplateConfig {
         Ok(())
     }
 }
+
+/// Environment variable that overrides the release date, in seconds since the Unix epoch.
+///
+/// Follows the [reproducible builds](https://reproducible-builds.org/specs/source-date-epoch/)
+/// convention, so CI can pin the date to the release commit.
+pub const SOURCE_DATE_EPOCH_ENV: &str = "SOURCE_DATE_EPOCH";
+
+/// Release date settings for changelog generation.
+///
+/// Controls the date shown in version headings and where it comes from.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use sublime_pkg_tools::config::ChangelogDateConfig;
+///
+/// let config = ChangelogDateConfig {
+///     format: "%d %B %Y".to_string(),
+///     timezone: "+09:00".to_string(),
+///     ..Default::default()
+/// };
+///
+/// let date = Utc.with_ymd_and_hms(2024, 1, 15, 20, 0, 0).unwrap();
+/// assert_eq!(config.format_date(&date), "16 January 2024");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ChangelogDateConfig {
+    /// `strftime`-style format of the `{date}` placeholder.
+    ///
+    /// # Default: `"%Y-%m-%d"`
+    pub format: String,
+
+    /// Timezone the date is shown in.
+    ///
+    /// `"UTC"`, `"local"` for the timezone of the machine (honoring `TZ`), or a fixed offset
+    /// such as `"+02:00"`.
+    ///
+    /// # Default: `"UTC"`
+    pub timezone: String,
+
+    /// Whether `SOURCE_DATE_EPOCH` overrides the current time as the release date.
+    ///
+    /// # Default: `true`
+    pub source_date_epoch: bool,
+}
+
+/// A parsed `ChangelogDateConfig::timezone`.
+enum DateTimezone {
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl Default for ChangelogDateConfig {
+    fn default() -> Self {
+        Self {
+            format: "%Y-%m-%d".to_string(),
+            timezone: "UTC".to_string(),
+            source_date_epoch: true,
+        }
+    }
+}
+
+impl ChangelogDateConfig {
+    /// Returns the release date for changelogs generated now.
+    ///
+    /// This is the time in `SOURCE_DATE_EPOCH` when it is set to a valid timestamp and
+    /// `source_date_epoch` is enabled, and the current time otherwise.
+    #[must_use]
+    pub fn release_date(&self) -> DateTime<Utc> {
+        self.release_date_from(std::env::var(SOURCE_DATE_EPOCH_ENV).ok().as_deref())
+    }
+
+    /// Returns the release date given the value of `SOURCE_DATE_EPOCH`.
+    pub(crate) fn release_date_from(&self, source_date_epoch: Option<&str>) -> DateTime<Utc> {
+        if self.source_date_epoch
+            && let Some(seconds) = source_date_epoch.and_then(|v| v.trim().parse::<i64>().ok())
+            && let Some(date) = DateTime::from_timestamp(seconds, 0)
+        {
+            return date;
+        }
+
+        Utc::now()
+    }
+
+    /// Formats a date with the configured format and timezone.
+    ///
+    /// Falls back to `%Y-%m-%d` in UTC when the settings are invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The date to format
+    ///
+    /// # Returns
+    ///
+    /// The text substituted for `{date}` in templates.
+    #[must_use]
+    pub fn format_date(&self, date: &DateTime<Utc>) -> String {
+        let local = match self.parse_timezone() {
+            Some(DateTimezone::Utc) => date.fixed_offset(),
+            Some(DateTimezone::Local) => date.with_timezone(&Local).fixed_offset(),
+            Some(DateTimezone::Fixed(offset)) => date.with_timezone(&offset),
+            None => return date.format("%Y-%m-%d").to_string(),
+        };
+
+        let mut output = String::new();
+        if write!(output, "{}", local.format(&self.format)).is_err() {
+            return date.format("%Y-%m-%d").to_string();
+        }
+        output
+    }
+
+    fn parse_timezone(&self) -> Option<DateTimezone> {
+        match self.timezone.trim() {
+            tz if tz.eq_ignore_ascii_case("utc") || tz.eq_ignore_ascii_case("z") => {
+                Some(DateTimezone::Utc)
+            }
+            tz if tz.eq_ignore_ascii_case("local") => Some(DateTimezone::Local),
+            tz => tz.parse::<FixedOffset>().ok().map(DateTimezone::Fixed),
+        }
+    }
+}
+
+impl Configurable for ChangelogDateConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.format.is_empty()
+            || StrftimeItems::new(&self.format).any(|item| matches!(item, Item::Error))
+        {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!(
+                    "changelog.date.format: '{}' is not a valid strftime format",
+                    self.format
+                ),
+            });
+        }
+
+        if self.parse_timezone().is_none() {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!(
+                    "changelog.date.timezone: '{}' must be 'UTC', 'local', or an offset such as '+02:00'",
+                    self.timezone
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.format = other.format;
+        self.timezone = other.timezone;
+        self.source_date_epoch = other.source_date_epoch;
+        Ok(())
+    }
+}
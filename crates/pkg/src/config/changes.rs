@@ -0,0 +1,359 @@
+//! Changes analysis configuration for root and shared files.
+//!
+//! **What**: Defines how changes to files outside every package (lockfiles,
+//! `tsconfig.base.json`, `.github/`, shared directories) are attributed to packages, and how
+//! changed files are classified.
+//!
+//! **How**: This module provides `ChangesConfig`, an ordered list of `RootFileRule`s. Each
+//! rule matches repository-relative paths and either ignores the change, attributes it to
+//! every package, or attributes it to a listed set of packages. The first matching rule wins;
+//! files no rule matches are reported as unattributed. `FilePatternsConfig` maps paths to a
+//! `FileClass` (source, test, docs, config, generated or binary).
+//!
+//! **Why**: Without rules, root files map to no package, so a change to a shared compiler
+//! configuration silently releases nothing. Explicit rules make attribution predictable and
+//! let each workspace decide which shared files affect which packages. Classifying files
+//! keeps regenerated lockfiles and build output from proposing releases.
+
+use crate::changes::{FileClass, path_matches};
+use glob::Pattern;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use sublime_standard_tools::config::{ConfigResult, Configurable};
+
+/// Configuration for changes analysis.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::{ChangesConfig, RootFileAttribution};
+/// use std::path::Path;
+///
+/// let config = ChangesConfig::default();
+/// let rule = config.rule_for(Path::new("pnpm-lock.yaml"));
+/// assert_eq!(rule.map(|r| r.attribution), Some(RootFileAttribution::Ignore));
+/// assert!(config.rule_for(Path::new("scripts/build.sh")).is_none());
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [[package_tools.changes.root_files]]
+/// pattern = "tsconfig.base.json"
+/// attribution = "all"
+///
+/// [[package_tools.changes.root_files]]
+/// pattern = ".github"
+/// attribution = "ignore"
+///
+/// [[package_tools.changes.root_files]]
+/// pattern = "shared/**"
+/// attribution = "packages"
+/// packages = ["@myorg/core", "@myorg/cli"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangesConfig {
+    /// Attribution rules for files outside every package, checked in order.
+    ///
+    /// Rules only apply to monorepos; in a single-package repository every file belongs to
+    /// the root package.
+    ///
+    /// # Default
+    ///
+    /// Lockfiles, `.github`, `.vscode` and root Markdown files are ignored;
+    /// `tsconfig.base.json` is attributed to all packages.
+    #[serde(default = "default_root_files")]
+    pub root_files: Vec<RootFileRule>,
+
+    /// Patterns used to classify changed files.
+    #[serde(default)]
+    pub file_patterns: FilePatternsConfig,
+}
+
+/// Glob patterns classifying changed files.
+///
+/// Patterns match paths relative to the repository root. A file is classified by the first
+/// matching class in the order generated, binary, test, docs, config; files matching no
+/// pattern are source files. Generated and binary changes do not call for a version bump.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::changes::FileClass;
+/// use sublime_pkg_tools::config::FilePatternsConfig;
+/// use std::path::Path;
+///
+/// let patterns = FilePatternsConfig::default();
+/// assert_eq!(patterns.classify(Path::new("packages/core/src/index.ts")), FileClass::Source);
+/// assert_eq!(patterns.classify(Path::new("packages/core/src/index.test.ts")), FileClass::Test);
+/// assert_eq!(patterns.classify(Path::new("pnpm-lock.yaml")), FileClass::Generated);
+/// assert_eq!(patterns.classify(Path::new("assets/logo.png")), FileClass::Binary);
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [package_tools.changes.file_patterns]
+/// test = ["**/*.test.ts", "**/__tests__/**"]
+/// generated = ["**/dist/**", "**/pnpm-lock.yaml"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct FilePatternsConfig {
+    /// Patterns of test files.
+    pub test: Vec<String>,
+
+    /// Patterns of documentation files.
+    pub docs: Vec<String>,
+
+    /// Patterns of configuration files.
+    pub config: Vec<String>,
+
+    /// Patterns of generated files, such as lockfiles and build output.
+    pub generated: Vec<String>,
+
+    /// Patterns of binary files.
+    pub binary: Vec<String>,
+}
+
+impl FilePatternsConfig {
+    /// Classifies a repository-relative path.
+    #[must_use]
+    pub fn classify(&self, path: &Path) -> FileClass {
+        let matches = |patterns: &[String]| patterns.iter().any(|p| path_matches(p, path));
+        if matches(&self.generated) {
+            FileClass::Generated
+        } else if matches(&self.binary) {
+            FileClass::Binary
+        } else if matches(&self.test) {
+            FileClass::Test
+        } else if matches(&self.docs) {
+            FileClass::Docs
+        } else if matches(&self.config) {
+            FileClass::Config
+        } else {
+            FileClass::Source
+        }
+    }
+
+    /// Returns each class with its patterns, in classification order.
+    fn classes(&self) -> [(&'static str, &[String]); 5] {
+        [
+            ("generated", &self.generated),
+            ("binary", &self.binary),
+            ("test", &self.test),
+            ("docs", &self.docs),
+            ("config", &self.config),
+        ]
+    }
+}
+
+impl Default for FilePatternsConfig {
+    fn default() -> Self {
+        let patterns = |patterns: &[&str]| patterns.iter().map(|p| (*p).to_string()).collect();
+        Self {
+            test: patterns(&[
+                "**/*.test.*",
+                "**/*.spec.*",
+                "**/__tests__/**",
+                "**/test/**",
+                "**/tests/**",
+            ]),
+            docs: patterns(&["**/*.md", "**/*.mdx", "**/docs/**"]),
+            config: patterns(&[
+                "**/package.json",
+                "**/tsconfig*.json",
+                "**/*.config.*",
+                "**/.*rc",
+                "**/.*rc.*",
+            ]),
+            generated: patterns(&[
+                "**/package-lock.json",
+                "**/npm-shrinkwrap.json",
+                "**/pnpm-lock.yaml",
+                "**/yarn.lock",
+                "**/bun.lock",
+                "**/bun.lockb",
+                "**/CHANGELOG.md",
+                "**/dist/**",
+                "**/build/**",
+                "**/*.min.js",
+                "**/*.map",
+            ]),
+            binary: patterns(&[
+                "**/*.png",
+                "**/*.jpg",
+                "**/*.jpeg",
+                "**/*.gif",
+                "**/*.ico",
+                "**/*.webp",
+                "**/*.woff",
+                "**/*.woff2",
+                "**/*.ttf",
+                "**/*.eot",
+                "**/*.pdf",
+                "**/*.zip",
+                "**/*.tgz",
+                "**/*.gz",
+                "**/*.wasm",
+            ]),
+        }
+    }
+}
+
+/// An attribution rule for root and shared files.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RootFileRule {
+    /// Repository-relative path or glob pattern.
+    ///
+    /// Patterns containing `*`, `?`, or `[` are globs (`*` does not cross `/`, `**` does);
+    /// other patterns match the path itself and everything below it.
+    pub pattern: String,
+
+    /// How matching files are attributed.
+    pub attribution: RootFileAttribution,
+
+    /// Packages matching files are attributed to, for `RootFileAttribution::Packages`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+}
+
+/// How a root or shared file change is attributed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RootFileAttribution {
+    /// The change affects no package.
+    Ignore,
+    /// The change affects every package.
+    All,
+    /// The change affects the packages listed in the rule.
+    Packages,
+}
+
+impl RootFileRule {
+    /// Creates a rule that ignores matching files.
+    #[must_use]
+    pub fn ignore(pattern: impl Into<String>) -> Self {
+        Self { pattern: pattern.into(), attribution: RootFileAttribution::Ignore, packages: vec![] }
+    }
+
+    /// Creates a rule that attributes matching files to every package.
+    #[must_use]
+    pub fn all(pattern: impl Into<String>) -> Self {
+        Self { pattern: pattern.into(), attribution: RootFileAttribution::All, packages: vec![] }
+    }
+
+    /// Creates a rule that attributes matching files to the given packages.
+    #[must_use]
+    pub fn packages<I, S>(pattern: impl Into<String>, packages: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            pattern: pattern.into(),
+            attribution: RootFileAttribution::Packages,
+            packages: packages.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Checks whether a repository-relative path matches this rule.
+    #[must_use]
+    pub fn matches(&self, path: &Path) -> bool {
+        path_matches(&self.pattern, path)
+    }
+}
+
+impl ChangesConfig {
+    /// Returns the first rule matching a repository-relative path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File path relative to the repository root
+    ///
+    /// # Returns
+    ///
+    /// The matching rule, or `None` if the file is unattributed.
+    #[must_use]
+    pub fn rule_for(&self, path: &Path) -> Option<&RootFileRule> {
+        self.root_files.iter().find(|rule| rule.matches(path))
+    }
+}
+
+impl Default for ChangesConfig {
+    fn default() -> Self {
+        Self { root_files: default_root_files(), file_patterns: FilePatternsConfig::default() }
+    }
+}
+
+/// Returns the default root file rules.
+fn default_root_files() -> Vec<RootFileRule> {
+    vec![
+        RootFileRule::ignore("package-lock.json"),
+        RootFileRule::ignore("npm-shrinkwrap.json"),
+        RootFileRule::ignore("pnpm-lock.yaml"),
+        RootFileRule::ignore("yarn.lock"),
+        RootFileRule::ignore("bun.lock"),
+        RootFileRule::ignore("bun.lockb"),
+        RootFileRule::ignore(".github"),
+        RootFileRule::ignore(".vscode"),
+        RootFileRule::ignore("*.md"),
+        RootFileRule::all("tsconfig.base.json"),
+    ]
+}
+
+impl Configurable for ChangesConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        for (index, rule) in self.root_files.iter().enumerate() {
+            let invalid = |reason: &str| {
+                Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!("changes.root_files[{index}]: {reason}"),
+                })
+            };
+
+            let pattern = rule.pattern.trim();
+            if pattern.is_empty() {
+                return invalid("Pattern cannot be empty");
+            }
+            if pattern.contains(['*', '?', '[']) && Pattern::new(pattern).is_err() {
+                return invalid(&format!("Invalid glob pattern '{pattern}'"));
+            }
+
+            match rule.attribution {
+                RootFileAttribution::Packages
+                    if rule.packages.is_empty()
+                        || rule.packages.iter().any(|name| name.trim().is_empty()) =>
+                {
+                    return invalid("Attribution 'packages' requires a list of package names");
+                }
+                RootFileAttribution::Ignore | RootFileAttribution::All
+                    if !rule.packages.is_empty() =>
+                {
+                    return invalid("Packages can only be listed with attribution 'packages'");
+                }
+                _ => {}
+            }
+        }
+
+        for (class, patterns) in self.file_patterns.classes() {
+            for pattern in patterns {
+                let pattern = pattern.trim();
+                if pattern.is_empty() || Pattern::new(pattern).is_err() {
+                    return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                        message: format!(
+                            "changes.file_patterns.{class}: Invalid glob pattern '{pattern}'"
+                        ),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.root_files = other.root_files;
+        self.file_patterns = other.file_patterns;
+        Ok(())
+    }
+}
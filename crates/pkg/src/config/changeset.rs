@@ -1,7 +1,7 @@
 //! Changeset configuration for storage and management settings.
 //!
 //! **What**: Defines configuration for changeset storage paths, history location,
-//! and available deployment environments.
+//! available deployment environments, and their promotion order.
 //!
 //! **How**: This module provides the `ChangesetConfig` structure that controls where
 //! changesets are stored, archived, and what environments are available for targeting.
@@ -9,9 +9,14 @@
 //! **Why**: To enable flexible changeset management that supports different project
 //! structures and deployment workflows while maintaining sensible defaults.
 
+use std::collections::{BTreeMap, HashSet};
+
 use serde::{Deserialize, Serialize};
 use sublime_standard_tools::config::{ConfigResult, Configurable};
 
+use super::EnvironmentDefinition;
+use crate::types::CommitAssociation;
+
 /// Configuration for changeset management.
 ///
 /// This structure controls where changesets are stored, where their history is archived,
@@ -23,6 +28,12 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// - `history_path`: Directory where archived changesets are stored
 /// - `available_environments`: List of valid environment names
 /// - `default_environments`: Environments to use when none are specified
+/// - `environments`: Optional promotion metadata for the available environments
+/// - `track_authors`: Whether to record who creates and modifies changesets
+/// - `human_names`: Whether to give new changesets a generated human-friendly name
+/// - `package_renames`: Old package names mapped to their new names
+/// - `commit_association`: Which git commits are associated with a changeset
+/// - `base_branch`: Branch whose merge-base bounds the commits associated with a changeset
 ///
 /// # Example
 ///
@@ -44,6 +55,22 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// history_path = ".changesets/history"
 /// available_environments = ["development", "staging", "production"]
 /// default_environments = ["production"]
+/// track_authors = true
+/// human_names = true
+/// commit_association = "packages"
+/// base_branch = "develop"
+///
+/// [package_tools.changeset.package_renames]
+/// "@myorg/old-utils" = "@myorg/utils"
+///
+/// [[package_tools.changeset.environments]]
+/// name = "staging"
+/// order = 1
+///
+/// [[package_tools.changeset.environments]]
+/// name = "production"
+/// order = 2
+/// required_approvals = 1
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChangesetConfig {
@@ -138,6 +165,113 @@ pub struct ChangesetConfig {
     /// assert_eq!(config.default_environments, vec!["staging"]);
     /// ```
     pub default_environments: Vec<String>,
+
+    /// Promotion metadata for available environments.
+    ///
+    /// Each definition names an entry of `available_environments` and gives it a position
+    /// in the promotion sequence. When empty, environments are promoted in the order of
+    /// `available_environments`.
+    ///
+    /// # Default
+    ///
+    /// `[]`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{ChangesetConfig, EnvironmentDefinition};
+    ///
+    /// let config = ChangesetConfig {
+    ///     available_environments: vec!["staging".to_string(), "production".to_string()],
+    ///     environments: vec![
+    ///         EnvironmentDefinition::new("production", 2),
+    ///         EnvironmentDefinition::new("staging", 1),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(config.promotion_order(), vec!["staging", "production"]);
+    /// ```
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub environments: Vec<EnvironmentDefinition>,
+
+    /// Whether to record author metadata on changesets.
+    ///
+    /// When enabled, the creator identity (CI actor or git `user.name`/`user.email`), the
+    /// branch checked out at creation, and a revision entry for every update are stored in
+    /// the changeset file. Disable it to keep personal data out of the repository.
+    ///
+    /// # Default
+    ///
+    /// `true`
+    #[serde(default = "default_track_authors")]
+    pub track_authors: bool,
+
+    /// Whether to give new changesets a human-friendly name.
+    ///
+    /// When enabled, changesets created, split, or merged get a name such as
+    /// `brave-lions-smile` generated from their id. The name, like the id and the branch,
+    /// can be used to reference the changeset.
+    ///
+    /// # Default
+    ///
+    /// `true`
+    #[serde(default = "default_human_names")]
+    pub human_names: bool,
+
+    /// Packages renamed since changesets were written, from old name to new name.
+    ///
+    /// Version resolution reads pending changesets that list an old name as listing the
+    /// new one, and changeset cleanup rewrites them. Chains such as `a -> b -> c` resolve
+    /// to the last name.
+    ///
+    /// # Default
+    ///
+    /// `{}`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::ChangesetConfig;
+    ///
+    /// let mut config = ChangesetConfig::default();
+    /// config.package_renames.insert("old-utils".to_string(), "utils".to_string());
+    /// assert_eq!(config.renamed_package("old-utils"), Some("utils"));
+    /// assert_eq!(config.renamed_package("utils"), None);
+    /// ```
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub package_renames: BTreeMap<String, String>,
+
+    /// Strategy deciding which git commits are associated with a changeset.
+    ///
+    /// - `branch`: every commit on the branch, adding the packages they touch
+    /// - `packages`: only commits touching a package the changeset lists
+    /// - `trailer`: only commits with a `Changeset: <id>` trailer naming the changeset
+    ///
+    /// # Default
+    ///
+    /// `"branch"`
+    #[serde(default)]
+    pub commit_association: CommitAssociation,
+
+    /// Branch the current branch is compared with when associating commits.
+    ///
+    /// Commits since the merge-base with this branch are considered. When unset, `main`
+    /// and then `master` are tried; without either, commits since the last commit the
+    /// changeset lists are considered.
+    ///
+    /// # Default
+    ///
+    /// `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_branch: Option<String>,
+}
+
+fn default_track_authors() -> bool {
+    true
+}
+
+fn default_human_names() -> bool {
+    true
 }
 
 impl Default for ChangesetConfig {
@@ -163,7 +297,88 @@ impl Default for ChangesetConfig {
             history_path: ".changesets/history".to_string(),
             available_environments: vec!["production".to_string()],
             default_environments: vec!["production".to_string()],
+            environments: Vec::new(),
+            track_authors: default_track_authors(),
+            human_names: default_human_names(),
+            package_renames: BTreeMap::new(),
+            commit_association: CommitAssociation::default(),
+            base_branch: None,
+        }
+    }
+}
+
+impl ChangesetConfig {
+    /// Returns the promotion metadata for an environment, if defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Environment name
+    #[must_use]
+    pub fn environment(&self, name: &str) -> Option<&EnvironmentDefinition> {
+        self.environments.iter().find(|env| env.name == name)
+    }
+
+    /// Returns environment names in promotion order.
+    ///
+    /// Defined environments are ordered by their `order` value. Without definitions, the
+    /// order of `available_environments` is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::ChangesetConfig;
+    ///
+    /// let config = ChangesetConfig {
+    ///     available_environments: vec!["staging".to_string(), "production".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(config.promotion_order(), vec!["staging", "production"]);
+    /// ```
+    #[must_use]
+    pub fn promotion_order(&self) -> Vec<&str> {
+        if self.environments.is_empty() {
+            return self.available_environments.iter().map(String::as_str).collect();
+        }
+
+        let mut defined: Vec<&EnvironmentDefinition> = self.environments.iter().collect();
+        defined.sort_by(|a, b| a.order.cmp(&b.order).then_with(|| a.name.cmp(&b.name)));
+        defined.into_iter().map(|env| env.name.as_str()).collect()
+    }
+
+    /// Returns the environment a release is promoted to after `name`.
+    ///
+    /// Returns `None` if `name` is last in the promotion order or not part of it.
+    #[must_use]
+    pub fn next_environment(&self, name: &str) -> Option<&str> {
+        let order = self.promotion_order();
+        let position = order.iter().position(|env| *env == name)?;
+        order.get(position + 1).copied()
+    }
+
+    /// Returns the current name of a package renamed in `package_renames`.
+    ///
+    /// Follows chains of renames; returns `None` if `name` was not renamed.
+    #[must_use]
+    pub fn renamed_package(&self, name: &str) -> Option<&str> {
+        let mut current = self.package_renames.get(name)?;
+        // Validation rejects cycles; the bound keeps an unvalidated config from looping
+        for _ in 0..self.package_renames.len() {
+            match self.package_renames.get(current) {
+                Some(next) => current = next,
+                None => break,
+            }
         }
+        Some(current)
+    }
+
+    /// Returns the environment a release must reach before being promoted to `name`.
+    ///
+    /// Returns `None` if `name` is first in the promotion order or not part of it.
+    #[must_use]
+    pub fn previous_environment(&self, name: &str) -> Option<&str> {
+        let order = self.promotion_order();
+        let position = order.iter().position(|env| *env == name)?;
+        position.checked_sub(1).and_then(|previous| order.get(previous).copied())
     }
 }
 
@@ -175,6 +390,9 @@ impl Configurable for ChangesetConfig {
     /// - History path is not empty
     /// - At least one environment is available
     /// - Default environments are all in available environments
+    /// - Environment definitions name available environments, once each, with distinct orders
+    /// - Package renames do not rename a package to itself or form a cycle
+    /// - The base branch, when set, is not empty
     ///
     /// # Errors
     ///
@@ -222,6 +440,56 @@ impl Configurable for ChangesetConfig {
             }
         }
 
+        let mut names = HashSet::new();
+        let mut orders = HashSet::new();
+        for env in &self.environments {
+            if !self.available_environments.contains(&env.name) {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "changeset.environments: Environment '{}' is not in available environments",
+                        env.name
+                    ),
+                });
+            }
+            if !names.insert(env.name.as_str()) {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "changeset.environments: Environment '{}' is defined more than once",
+                        env.name
+                    ),
+                });
+            }
+            if !orders.insert(env.order) {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "changeset.environments: Order {} is used by more than one environment",
+                        env.order
+                    ),
+                });
+            }
+        }
+
+        for old in self.package_renames.keys() {
+            let mut seen = HashSet::from([old.as_str()]);
+            let mut current = old.as_str();
+            while let Some(next) = self.package_renames.get(current) {
+                if !seen.insert(next.as_str()) {
+                    return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                        message: format!(
+                            "changeset.package_renames: Renaming '{old}' leads back to '{next}'"
+                        ),
+                    });
+                }
+                current = next;
+            }
+        }
+
+        if self.base_branch.as_deref().is_some_and(|branch| branch.trim().is_empty()) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "changeset.base_branch: Base branch cannot be empty".to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -257,6 +525,12 @@ impl Configurable for ChangesetConfig {
         self.history_path = other.history_path;
         self.available_environments = other.available_environments;
         self.default_environments = other.default_environments;
+        self.environments = other.environments;
+        self.track_authors = other.track_authors;
+        self.human_names = other.human_names;
+        self.package_renames = other.package_renames;
+        self.commit_association = other.commit_association;
+        self.base_branch = other.base_branch;
         Ok(())
     }
 }
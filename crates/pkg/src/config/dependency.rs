@@ -30,6 +30,7 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// - `skip_file_protocol`: Skip dependencies using file protocol
 /// - `skip_link_protocol`: Skip dependencies using link protocol
 /// - `skip_portal_protocol`: Skip dependencies using portal protocol
+/// - `range_policy`: Whether propagation rewrites ranges the new version already satisfies
 ///
 /// # Example
 ///
@@ -55,6 +56,7 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// skip_file_protocol = true
 /// skip_link_protocol = true
 /// skip_portal_protocol = true
+/// range_policy = "update"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DependencyConfig {
@@ -280,6 +282,46 @@ pub struct DependencyConfig {
     /// assert!(!config.skip_portal_protocol);
     /// ```
     pub skip_portal_protocol: bool,
+
+    /// How propagation rewrites dependency specs of updated workspace packages.
+    ///
+    /// - `"update"`: every spec is moved to the new version, keeping its operator
+    ///   (`^1.0.0` becomes `^1.0.1`)
+    /// - `"keep-range"`: specs the new version already satisfies are left as they are, so
+    ///   `^1.0.0` stays while `~1.0.0` still becomes `~1.1.0` on a minor bump
+    ///
+    /// Specs whose rewrite would not change them are never written under either policy;
+    /// they are reported in `PackageUpdate::skipped_dependency_updates`.
+    ///
+    /// # Default
+    ///
+    /// `"update"`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::DependencyConfig;
+    ///
+    /// let config = DependencyConfig {
+    ///     range_policy: "keep-range".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert!(config.keeps_satisfied_ranges());
+    /// ```
+    #[serde(default = "default_range_policy")]
+    pub range_policy: String,
+}
+
+fn default_range_policy() -> String {
+    "update".to_string()
+}
+
+impl DependencyConfig {
+    /// Returns whether specs the new version already satisfies are left unchanged.
+    #[must_use]
+    pub fn keeps_satisfied_ranges(&self) -> bool {
+        self.range_policy == "keep-range"
+    }
 }
 
 impl Default for DependencyConfig {
@@ -304,6 +346,7 @@ impl Default for DependencyConfig {
     /// assert!(config.skip_file_protocol);
     /// assert!(config.skip_link_protocol);
     /// assert!(config.skip_portal_protocol);
+    /// assert_eq!(config.range_policy, "update");
     /// ```
     fn default() -> Self {
         Self {
@@ -317,6 +360,7 @@ impl Default for DependencyConfig {
             skip_file_protocol: true,
             skip_link_protocol: true,
             skip_portal_protocol: true,
+            range_policy: default_range_policy(),
         }
     }
 }
@@ -327,6 +371,7 @@ impl Configurable for DependencyConfig {
     /// This method ensures that:
     /// - Propagation bump is one of: "major", "minor", "patch", "none"
     /// - At least one dependency type is enabled for propagation
+    /// - Range policy is one of: "update", "keep-range"
     ///
     /// # Errors
     ///
@@ -366,6 +411,15 @@ impl Configurable for DependencyConfig {
             });
         }
 
+        if !matches!(self.range_policy.as_str(), "update" | "keep-range") {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!(
+                    "dependency.range_policy: Invalid policy '{}'. Must be one of: update, keep-range",
+                    self.range_policy
+                ),
+            });
+        }
+
         Ok(())
     }
 
@@ -407,6 +461,7 @@ impl Configurable for DependencyConfig {
         self.skip_file_protocol = other.skip_file_protocol;
         self.skip_link_protocol = other.skip_link_protocol;
         self.skip_portal_protocol = other.skip_portal_protocol;
+        self.range_policy = other.range_policy;
         Ok(())
     }
 }
@@ -0,0 +1,579 @@
+//! Documentation registry for configuration keys.
+//!
+//! **What**: Provides `ConfigDoc`, the documentation of a single configuration key (summary,
+//! default, allowed values and a longer explanation), and a static registry of the keys users
+//! set most often and the ones validation reports on.
+//!
+//! **How**: Entries are keyed by their dotted path inside `[package_tools]`, such as
+//! `version.default_bump`. Map entries use `*` for the user-chosen segment
+//! (`audit.checks.*.severity`), and `ConfigDoc::lookup` matches concrete paths against them.
+//!
+//! **Why**: Validation errors are only actionable if the user can see what the key does and
+//! which values it takes. Keeping that text next to the configuration types lets the CLI print
+//! it with each error and for `config validate --explain`.
+
+use serde::Serialize;
+
+/// Documentation of a configuration key.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::ConfigDoc;
+///
+/// let doc = ConfigDoc::lookup("version.default_bump").expect("documented key");
+/// assert_eq!(doc.default, "\"patch\"");
+/// assert!(doc.allowed.contains(&"minor"));
+///
+/// // Map entries match any user-chosen segment
+/// let doc = ConfigDoc::lookup("audit.checks.registry-ownership.severity");
+/// assert_eq!(doc.map(|doc| doc.key), Some("audit.checks.*.severity"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigDoc {
+    /// Dotted path of the key inside `[package_tools]`; `*` stands for a map key.
+    pub key: &'static str,
+
+    /// One-line description.
+    pub summary: &'static str,
+
+    /// Default value, as written in TOML, or empty if the key has none.
+    pub default: &'static str,
+
+    /// Accepted values, or empty if the key is not an enumeration.
+    pub allowed: &'static [&'static str],
+
+    /// Longer explanation of how the key is used.
+    pub details: &'static str,
+}
+
+impl ConfigDoc {
+    /// Returns every documented key, grouped by section.
+    #[must_use]
+    pub fn all() -> &'static [ConfigDoc] {
+        CONFIG_DOCS
+    }
+
+    /// Returns the documentation of a key.
+    ///
+    /// A leading `package_tools.` is ignored, and `*` segments of the registry match any
+    /// single segment of `key`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Dotted path of the key, e.g. `upgrade.registry.timeout_secs`
+    #[must_use]
+    pub fn lookup(key: &str) -> Option<&'static ConfigDoc> {
+        let key = key.strip_prefix("package_tools.").unwrap_or(key);
+        CONFIG_DOCS.iter().find(|doc| doc.key == key).or_else(|| {
+            let segments: Vec<&str> = key.split('.').collect();
+            CONFIG_DOCS.iter().find(|doc| {
+                let pattern: Vec<&str> = doc.key.split('.').collect();
+                pattern.len() == segments.len()
+                    && pattern.iter().zip(&segments).all(|(p, s)| *p == "*" || p == s)
+            })
+        })
+    }
+
+    /// Returns the documented keys starting with a section prefix, e.g. `upgrade.registry`.
+    #[must_use]
+    pub fn section(prefix: &str) -> Vec<&'static ConfigDoc> {
+        let prefix = prefix.strip_prefix("package_tools.").unwrap_or(prefix);
+        CONFIG_DOCS
+            .iter()
+            .filter(|doc| {
+                doc.key
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+            })
+            .collect()
+    }
+}
+
+const BUMPS: &[&str] = &["major", "minor", "patch", "none"];
+const SEVERITIES: &[&str] = &["critical", "warning", "info"];
+const FAILURE_POLICIES: &[&str] = &["rollback", "keep"];
+
+static CONFIG_DOCS: &[ConfigDoc] = &[
+    // changeset
+    ConfigDoc {
+        key: "changeset.path",
+        summary: "Directory where pending changesets are stored",
+        default: "\".changesets\"",
+        allowed: &[],
+        details: "Relative to the workspace root. Must not contain `..` and must differ from \
+                  `changeset.history_path`.",
+    },
+    ConfigDoc {
+        key: "changeset.history_path",
+        summary: "Directory where changesets are archived after a release",
+        default: "\".changesets/history\"",
+        allowed: &[],
+        details: "Relative to the workspace root. Archived changesets record the released \
+                  versions and are read by `changeset history` and `tag`.",
+    },
+    ConfigDoc {
+        key: "changeset.available_environments",
+        summary: "Environments a changeset can target",
+        default: "[\"production\"]",
+        allowed: &[],
+        details: "At least one environment is required. Names must be unique and contain no \
+                  whitespace.",
+    },
+    ConfigDoc {
+        key: "changeset.default_environments",
+        summary: "Environments new changesets target when none are given",
+        default: "[\"production\"]",
+        allowed: &[],
+        details: "Every entry must also be listed in `changeset.available_environments`.",
+    },
+    ConfigDoc {
+        key: "changeset.environments",
+        summary: "Promotion order of the environments",
+        default: "[]",
+        allowed: &[],
+        details: "Each `[[package_tools.changeset.environments]]` entry names an available \
+                  environment and gives it a unique `order`.",
+    },
+    ConfigDoc {
+        key: "changeset.package_renames",
+        summary: "Old package names mapped to their new names",
+        default: "{}",
+        allowed: &[],
+        details: "Changesets referring to an old name are applied to the new one. A chain of \
+                  renames must not lead back to a name it started from.",
+    },
+    ConfigDoc {
+        key: "changeset.base_branch",
+        summary: "Branch changesets are compared against",
+        default: "",
+        allowed: &[],
+        details: "When unset, the repository's default branch is used. Must not be blank.",
+    },
+    // version
+    ConfigDoc {
+        key: "version.strategy",
+        summary: "How package versions move",
+        default: "\"independent\"",
+        allowed: &["independent", "unified"],
+        details: "`independent` bumps each package on its own; `unified` keeps every package on \
+                  the same version.",
+    },
+    ConfigDoc {
+        key: "version.default_bump",
+        summary: "Bump used when a changeset does not specify one",
+        default: "\"patch\"",
+        allowed: BUMPS,
+        details: "`none` records the change without bumping the version.",
+    },
+    ConfigDoc {
+        key: "version.snapshot_format",
+        summary: "Format of snapshot versions",
+        default: "\"{version}-{branch}.{timestamp}\"",
+        allowed: &[],
+        details: "Must contain the `{version}` placeholder. `{branch}`, `{timestamp}` and \
+                  `{commit}` are also available.",
+    },
+    ConfigDoc {
+        key: "version.packages.*.scheme",
+        summary: "Version scheme of a package",
+        default: "\"semver\"",
+        allowed: &["semver", "calver"],
+        details: "`calver` versions a package from the release date following \
+                  `version.calver_format`. Calver packages are versioned on their own under the \
+                  unified strategy. Takes precedence over `version.calver_packages`.",
+    },
+    ConfigDoc {
+        key: "version.calver_packages",
+        summary: "Packages using calendar versioning",
+        default: "[]",
+        allowed: &[],
+        details: "Package names or globs such as `@myorg/app-*`; a term prefixed with `!` \
+                  excludes the packages it matches.",
+    },
+    ConfigDoc {
+        key: "version.calver_format",
+        summary: "Layout of calendar versions",
+        default: "\"YYYY.MM.MICRO\"",
+        allowed: &["YYYY.MM.MICRO", "YYYY.MM.DD"],
+        details: "With `YYYY.MM.MICRO` any bump moves a package to the current year and month, \
+                  or increments `MICRO` within the same month. With `YYYY.MM.DD` it moves to \
+                  the release date. Months and days have no leading zeros, e.g. `2024.6.0`.",
+    },
+    ConfigDoc {
+        key: "version.calver_range",
+        summary: "How dependents reference calendar-versioned packages",
+        default: "\"pin\"",
+        allowed: &["pin", "at-least"],
+        details: "`pin` writes the exact version into dependents; `at-least` writes `>=` the \
+                  version. Ranges are replaced rather than rewritten with their operator, as a \
+                  caret range would stop matching at the next year.",
+    },
+    // dependency
+    ConfigDoc {
+        key: "dependency.propagation_bump",
+        summary: "Bump applied to dependents of an updated package",
+        default: "\"patch\"",
+        allowed: BUMPS,
+        details: "Applied to every workspace package depending on a bumped package, up to \
+                  `dependency.max_depth` levels.",
+    },
+    ConfigDoc {
+        key: "dependency.max_depth",
+        summary: "How many levels of dependents propagation reaches",
+        default: "10",
+        allowed: &[],
+        details: "Must be between 1 and 100.",
+    },
+    ConfigDoc {
+        key: "dependency.propagate_dependencies",
+        summary: "Propagate bumps through `dependencies`",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "At least one of `propagate_dependencies`, `propagate_dev_dependencies` and \
+                  `propagate_peer_dependencies` must be true.",
+    },
+    ConfigDoc {
+        key: "dependency.propagate_dev_dependencies",
+        summary: "Propagate bumps through `devDependencies`",
+        default: "false",
+        allowed: &["true", "false"],
+        details: "At least one of the three propagation flags must be true.",
+    },
+    ConfigDoc {
+        key: "dependency.propagate_peer_dependencies",
+        summary: "Propagate bumps through `peerDependencies`",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "At least one of the three propagation flags must be true.",
+    },
+    // upgrade
+    ConfigDoc {
+        key: "upgrade.auto_changeset",
+        summary: "Create a changeset when upgrades are applied",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "The changeset uses `upgrade.changeset_bump`.",
+    },
+    ConfigDoc {
+        key: "upgrade.changeset_bump",
+        summary: "Bump of the changeset created for applied upgrades",
+        default: "\"patch\"",
+        allowed: BUMPS,
+        details: "Only used when `upgrade.auto_changeset` is true.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.default_registry",
+        summary: "Registry used for packages without a scoped registry",
+        default: "\"https://registry.npmjs.org\"",
+        allowed: &[],
+        details: "Must be an http:// or https:// URL. Scoped registries from `.npmrc` and \
+                  `upgrade.registry.scoped_registries` take precedence.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.timeout_secs",
+        summary: "Timeout of a registry request, in seconds",
+        default: "30",
+        allowed: &[],
+        details: "Must be between 1 and 300.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.retry_attempts",
+        summary: "Retries of a failed registry request",
+        default: "3",
+        allowed: &[],
+        details: "At most 10.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.retry_delay_ms",
+        summary: "Initial delay between retries, in milliseconds",
+        default: "1000",
+        allowed: &[],
+        details: "Must be greater than 0. The delay doubles after each attempt.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.max_retry_after_secs",
+        summary: "Longest `Retry-After` wait honoured, in seconds",
+        default: "60",
+        allowed: &[],
+        details: "At most 3600. Longer waits fail the request instead.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.requests_per_second",
+        summary: "Registry requests started per second",
+        default: "",
+        allowed: &[],
+        details: "Unset sends requests without a limit. Must be greater than 0 when set.",
+    },
+    ConfigDoc {
+        key: "upgrade.registry.scope_fallback.*",
+        summary: "Registry order for a scope",
+        default: "",
+        allowed: &["scope-first", "default-first", "none"],
+        details: "Whether a scoped package missing from its scoped registry is looked up on the \
+                  default registry, and in which order.",
+    },
+    ConfigDoc {
+        key: "upgrade.backup.backup_dir",
+        summary: "Directory where backups are written",
+        default: "\".workspace-backups\"",
+        allowed: &[],
+        details: "Must not be empty when backups are enabled.",
+    },
+    ConfigDoc {
+        key: "upgrade.backup.max_backups",
+        summary: "Number of backups kept",
+        default: "5",
+        allowed: &[],
+        details: "Must be between 1 and 100 when backups are enabled.",
+    },
+    ConfigDoc {
+        key: "upgrade.backup.max_age_days",
+        summary: "Age after which backups are pruned, in days",
+        default: "",
+        allowed: &[],
+        details: "Unset keeps backups regardless of age. Must be greater than 0 when set.",
+    },
+    ConfigDoc {
+        key: "upgrade.backup.max_total_size_mb",
+        summary: "Total size of backups kept, in megabytes",
+        default: "",
+        allowed: &[],
+        details: "Unset keeps backups regardless of size. Must be greater than 0 when set.",
+    },
+    ConfigDoc {
+        key: "upgrade.verify.on_failure",
+        summary: "What to do when the verification command fails after upgrading",
+        default: "\"rollback\"",
+        allowed: FAILURE_POLICIES,
+        details: "`rollback` restores the backup taken before the upgrade; `keep` leaves the \
+                  upgraded files in place.",
+    },
+    ConfigDoc {
+        key: "upgrade.verify.by_upgrade.*.on_failure",
+        summary: "Verification failure policy for one upgrade type",
+        default: "",
+        allowed: FAILURE_POLICIES,
+        details: "The map key must be `major`, `minor` or `patch`. Falls back to \
+                  `upgrade.verify.on_failure`.",
+    },
+    ConfigDoc {
+        key: "upgrade.verify.timeout_secs",
+        summary: "Timeout of the verification command, in seconds",
+        default: "1800",
+        allowed: &[],
+        details: "Must be greater than 0.",
+    },
+    // changelog
+    ConfigDoc {
+        key: "changelog.enabled",
+        summary: "Generate changelogs when bumping versions",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "The other changelog keys are only validated when this is true.",
+    },
+    ConfigDoc {
+        key: "changelog.format",
+        summary: "Changelog layout",
+        default: "\"keep-a-changelog\"",
+        allowed: &["keep-a-changelog", "conventional", "custom"],
+        details: "`custom` renders entries with `changelog.template`.",
+    },
+    ConfigDoc {
+        key: "changelog.filename",
+        summary: "Name of the changelog file in each package",
+        default: "\"CHANGELOG.md\"",
+        allowed: &[],
+        details: "Must not be empty.",
+    },
+    ConfigDoc {
+        key: "changelog.repository_url",
+        summary: "Repository URL used for commit and compare links",
+        default: "",
+        allowed: &[],
+        details: "Detected from the `origin` remote when unset. Must be an http:// or https:// \
+                  URL when set.",
+    },
+    ConfigDoc {
+        key: "changelog.version_tag_format",
+        summary: "Tag name of a package release",
+        default: "\"{name}@{version}\"",
+        allowed: &[],
+        details: "Must contain `{version}`. Release baselines are found by looking these tags \
+                  up, and `tag` creates the missing ones.",
+    },
+    ConfigDoc {
+        key: "changelog.root_tag_format",
+        summary: "Tag name of a unified or single-package release",
+        default: "\"v{version}\"",
+        allowed: &[],
+        details: "Must contain `{version}`.",
+    },
+    ConfigDoc {
+        key: "changelog.packages.*.tag_format",
+        summary: "Tag name of a release of one package",
+        default: "",
+        allowed: &[],
+        details: "Overrides `changelog.version_tag_format` for the package, e.g. \
+                  `{name}/v{version}`, or `v{version}` for an app tagged like a unified release. \
+                  Must contain `{version}`.",
+    },
+    ConfigDoc {
+        key: "changelog.concurrency",
+        summary: "Package changelogs generated at once",
+        default: "8",
+        allowed: &[],
+        details: "Must be greater than 0.",
+    },
+    // changes
+    ConfigDoc {
+        key: "changes.file_patterns.test",
+        summary: "Glob patterns of test files",
+        default: "[\"**/*.test.*\", ...]",
+        allowed: &[],
+        details: "Checked after generated and binary patterns. Patterns match \
+                  repository-relative paths.",
+    },
+    ConfigDoc {
+        key: "changes.file_patterns.docs",
+        summary: "Glob patterns of documentation files",
+        default: "[\"**/*.md\", ...]",
+        allowed: &[],
+        details: "Checked after test patterns.",
+    },
+    ConfigDoc {
+        key: "changes.file_patterns.config",
+        summary: "Glob patterns of configuration files",
+        default: "[\"**/package.json\", ...]",
+        allowed: &[],
+        details: "Checked after docs patterns.",
+    },
+    ConfigDoc {
+        key: "changes.file_patterns.generated",
+        summary: "Glob patterns of generated files",
+        default: "[\"**/pnpm-lock.yaml\", ...]",
+        allowed: &[],
+        details: "Checked first. Packages whose only changes are generated or binary files \
+                  are not proposed for a release.",
+    },
+    ConfigDoc {
+        key: "changes.file_patterns.binary",
+        summary: "Glob patterns of binary files",
+        default: "[\"**/*.png\", ...]",
+        allowed: &[],
+        details: "Checked after generated patterns. Binary changes do not call for a release \
+                  on their own.",
+    },
+    // aliases
+    ConfigDoc {
+        key: "aliases.tsconfig",
+        summary: "Project file whose `compilerOptions.paths` is generated",
+        default: "\"tsconfig.json\"",
+        allowed: &[],
+        details: "Relative to the workspace root. Paths are written relative to the file's \
+                  `baseUrl`, or to its directory when it has none.",
+    },
+    ConfigDoc {
+        key: "aliases.source_dir",
+        summary: "Directory inside each package that aliases point at",
+        default: "\"src\"",
+        allowed: &[],
+        details: "Must be relative. Use an empty string to point at the package directory.",
+    },
+    ConfigDoc {
+        key: "aliases.entry",
+        summary: "File the bare package name resolves to",
+        default: "\"index.ts\"",
+        allowed: &[],
+        details: "Relative to `aliases.source_dir`. Must not be empty.",
+    },
+    ConfigDoc {
+        key: "aliases.package_imports",
+        summary: "Also generate the `imports` map of each package.json",
+        default: "false",
+        allowed: &["true", "false"],
+        details: "Each package gets an entry for every workspace package it depends on.",
+    },
+    ConfigDoc {
+        key: "aliases.imports_prefix",
+        summary: "Prefix of generated `imports` keys",
+        default: "\"#\"",
+        allowed: &[],
+        details: "Must start with `#`, as Node requires for subpath imports.",
+    },
+    // git
+    ConfigDoc {
+        key: "git.merge_commit_template",
+        summary: "Release commit message for a unified release",
+        default: "\"chore(release): {version}...\"",
+        allowed: &[],
+        details: "Must not be empty. Placeholders: `{version}`, `{changelog_summary}`.",
+    },
+    ConfigDoc {
+        key: "git.monorepo_merge_commit_template",
+        summary: "Release commit message for a package release",
+        default: "\"chore(release): {package_name}@{version}...\"",
+        allowed: &[],
+        details: "Must not be empty. Placeholders: `{package_name}`, `{version}`, \
+                  `{changelog_summary}`.",
+    },
+    ConfigDoc {
+        key: "git.breaking_warning_template",
+        summary: "Warning appended to release commits with breaking changes",
+        default: "\"\\n⚠️  BREAKING CHANGES: {breaking_changes_count}\\n\"",
+        allowed: &[],
+        details: "Must not be empty when `git.include_breaking_warning` is true.",
+    },
+    // audit
+    ConfigDoc {
+        key: "audit.enabled",
+        summary: "Run audits",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "The other audit keys are only validated when this is true.",
+    },
+    ConfigDoc {
+        key: "audit.min_severity",
+        summary: "Lowest severity reported",
+        default: "\"warning\"",
+        allowed: SEVERITIES,
+        details: "Issues below this severity are left out of reports.",
+    },
+    ConfigDoc {
+        key: "audit.sections",
+        summary: "Built-in audit sections to run",
+        default: "all enabled",
+        allowed: &[],
+        details: "At least one of `upgrades`, `dependencies`, `breaking_changes`, \
+                  `categorization` and `version_consistency` must be true.",
+    },
+    ConfigDoc {
+        key: "audit.concurrency",
+        summary: "Audit checks run at once",
+        default: "16",
+        allowed: &[],
+        details: "Must be greater than 0.",
+    },
+    ConfigDoc {
+        key: "audit.check_timeout_secs",
+        summary: "Timeout of a single audit check, in seconds",
+        default: "300",
+        allowed: &[],
+        details: "Must be greater than 0. A check that times out is reported as failed.",
+    },
+    ConfigDoc {
+        key: "audit.checks.*.severity",
+        summary: "Severity override for every issue of a check",
+        default: "",
+        allowed: SEVERITIES,
+        details: "The map key is a check id, built in or registered.",
+    },
+    ConfigDoc {
+        key: "audit.checks.*.enabled",
+        summary: "Whether a check runs",
+        default: "true",
+        allowed: &["true", "false"],
+        details: "The map key is a check id, built in or registered.",
+    },
+];
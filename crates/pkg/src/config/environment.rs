@@ -0,0 +1,88 @@
+//! Typed environment definitions for promotion workflows.
+//!
+//! **What**: Defines `EnvironmentDefinition`, which attaches a promotion order, a required
+//! approval count, and an auto-promote flag to an environment name listed in
+//! `changeset.available_environments`.
+//!
+//! **How**: Definitions are declared as `[[changeset.environments]]` entries. `ChangesetConfig`
+//! validates them against the available environments and exposes the resulting promotion order,
+//! so consumers can check that a release reaches `staging` before `production`.
+//!
+//! **Why**: A plain list of names says which environments exist but not in which sequence they
+//! are released to, nor what a promotion requires. Gates that enforce sequencing need that
+//! information from configuration rather than hard-coded names.
+
+use serde::{Deserialize, Serialize};
+
+/// Promotion metadata for a deployment environment.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::EnvironmentDefinition;
+///
+/// let staging = EnvironmentDefinition::new("staging", 1).with_auto_promote(true);
+/// let production = EnvironmentDefinition::new("production", 2).with_required_approvals(2);
+///
+/// assert!(staging.order < production.order);
+/// assert!(staging.auto_promote);
+/// assert_eq!(production.required_approvals, 2);
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [[package_tools.changeset.environments]]
+/// name = "staging"
+/// order = 1
+/// auto_promote = true
+///
+/// [[package_tools.changeset.environments]]
+/// name = "production"
+/// order = 2
+/// required_approvals = 2
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnvironmentDefinition {
+    /// Environment name. Must be listed in `available_environments`.
+    pub name: String,
+
+    /// Position in the promotion sequence. Lower values are promoted first.
+    pub order: u32,
+
+    /// Number of approvals required before a release is promoted into this environment.
+    #[serde(default)]
+    pub required_approvals: u32,
+
+    /// Whether a release is promoted into this environment automatically once the
+    /// previous environment succeeds.
+    #[serde(default)]
+    pub auto_promote: bool,
+}
+
+impl EnvironmentDefinition {
+    /// Creates a definition with no required approvals and manual promotion.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Environment name
+    /// * `order` - Position in the promotion sequence
+    #[must_use]
+    pub fn new(name: impl Into<String>, order: u32) -> Self {
+        Self { name: name.into(), order, required_approvals: 0, auto_promote: false }
+    }
+
+    /// Sets the number of approvals required to promote into this environment.
+    #[must_use]
+    pub fn with_required_approvals(mut self, required_approvals: u32) -> Self {
+        self.required_approvals = required_approvals;
+        self
+    }
+
+    /// Sets whether promotion into this environment happens automatically.
+    #[must_use]
+    pub fn with_auto_promote(mut self, auto_promote: bool) -> Self {
+        self.auto_promote = auto_promote;
+        self
+    }
+}
@@ -0,0 +1,147 @@
+//! Git hook configuration for managed hook installation.
+//!
+//! **What**: Defines configuration for the git hooks that package tools installs into a
+//! repository, including where they are installed and the script each hook runs.
+//!
+//! **How**: This module provides the `HooksConfig` structure. Each entry in `hooks` maps a git
+//! hook name (e.g., `pre-commit`) to the shell script body the hook should run. Hooks are
+//! installed into `.git/hooks` by default, or into a versioned directory registered through
+//! `core.hooksPath` when `hooks_path` is set (the approach popularized by husky).
+//!
+//! **Why**: To keep hooks declared alongside the rest of the workspace configuration so that
+//! every clone gets the same hooks and drift can be detected.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use sublime_standard_tools::config::{ConfigResult, Configurable};
+
+/// Git hooks git itself will execute.
+///
+/// Hooks declared in configuration must use one of these names.
+pub(crate) const KNOWN_GIT_HOOKS: &[&str] = &[
+    "applypatch-msg",
+    "pre-applypatch",
+    "post-applypatch",
+    "pre-commit",
+    "pre-merge-commit",
+    "prepare-commit-msg",
+    "commit-msg",
+    "post-commit",
+    "pre-rebase",
+    "post-checkout",
+    "post-merge",
+    "pre-push",
+    "pre-receive",
+    "update",
+    "post-receive",
+    "post-update",
+    "reference-transaction",
+    "push-to-checkout",
+    "pre-auto-gc",
+    "post-rewrite",
+    "sendemail-validate",
+    "fsmonitor-watchman",
+    "post-index-change",
+];
+
+/// Configuration for managed git hooks.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::HooksConfig;
+///
+/// let config = HooksConfig::default();
+/// assert!(config.enabled);
+/// assert!(config.hooks_path.is_none());
+/// assert!(config.hooks.is_empty());
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [package_tools.hooks]
+/// enabled = true
+/// hooks_path = ".husky"
+///
+/// [package_tools.hooks.hooks]
+/// pre-commit = "workspace changeset check"
+/// pre-push = "workspace audit --sections upgrades"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HooksConfig {
+    /// Whether hook management is enabled.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Directory, relative to the repository root, to install hooks into.
+    ///
+    /// When set, the directory is registered as git's `core.hooksPath` so hooks can be
+    /// committed with the repository. When unset, hooks go to `.git/hooks`.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks_path: Option<String>,
+
+    /// Hook scripts keyed by git hook name.
+    ///
+    /// The value is the body of a POSIX shell script; a shebang and management header are
+    /// added on installation.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub hooks: BTreeMap<String, String>,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self { enabled: true, hooks_path: None, hooks: BTreeMap::new() }
+    }
+}
+
+/// Returns the default for `HooksConfig::enabled`.
+fn default_enabled() -> bool {
+    true
+}
+
+impl Configurable for HooksConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if let Some(hooks_path) = &self.hooks_path
+            && (hooks_path.trim().is_empty() || Path::new(hooks_path).is_absolute())
+        {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message:
+                    "hooks.hooks_path: Must be a non-empty path relative to the repository root"
+                        .to_string(),
+            });
+        }
+
+        for (name, script) in &self.hooks {
+            if !KNOWN_GIT_HOOKS.contains(&name.as_str()) {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!("hooks.hooks: Unknown git hook '{}'", name),
+                });
+            }
+
+            if script.trim().is_empty() {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!("hooks.hooks.{}: Script cannot be empty", name),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.enabled = other.enabled;
+        if other.hooks_path.is_some() {
+            self.hooks_path = other.hooks_path;
+        }
+        self.hooks.extend(other.hooks);
+        Ok(())
+    }
+}
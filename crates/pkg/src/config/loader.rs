@@ -5,17 +5,23 @@
 //!
 //! **How**: This module provides a `ConfigLoader` that integrates with ConfigManager to load
 //! configuration from multiple sources (TOML, YAML, JSON files and environment variables),
-//! merge them according to priority, and validate the result.
+//! merge them according to priority, and validate the result. The remote configuration a file
+//! declares in `[remote]` is merged under the files. The active configuration profile, if one
+//! is selected, is merged over the files before environment variables are applied.
 //!
 //! **Why**: To provide a simple, consistent API for loading package tools configuration
 //! across different environments and use cases, with proper error handling and validation.
 
 use std::path::Path;
 
-use sublime_standard_tools::config::{ConfigManager, ConfigResult, Configurable};
+use sublime_standard_tools::config::{
+    ConfigManager, ConfigResult, ConfigSource, ConfigSourcePriority, Configurable,
+};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 
-use super::PackageToolsConfig;
+use super::profile::{active_profile, read_profile};
+use super::remote::read_remote;
+use super::{PackageToolsConfig, UnifiedConfig, unified};
 
 /// Configuration loader for package tools.
 ///
@@ -27,6 +33,8 @@ use super::PackageToolsConfig;
 /// - Load from TOML, YAML, or JSON files (auto-detected by extension)
 /// - Environment variable overrides with `SUBLIME_PKG_` prefix
 /// - Multiple configuration file support with priority ordering
+/// - Named profiles (`[profile.<name>]`) overlaying the file configuration
+/// - A remote base configuration (`[remote]`) fetched over HTTP(S) or from S3
 /// - Validation of loaded configuration
 /// - Sensible defaults
 ///
@@ -85,6 +93,7 @@ impl ConfigLoader {
     /// Loads configuration from a specific file with defaults and environment variables.
     ///
     /// This function loads configuration with default values, the specified file,
+    /// the active profile (see [`set_active_profile`](super::set_active_profile)),
     /// and environment variable overrides with "SUBLIME_PKG" prefix.
     ///
     /// # Arguments
@@ -100,6 +109,7 @@ impl ConfigLoader {
     /// Returns an error if:
     /// - The file does not exist
     /// - The file cannot be parsed
+    /// - The file does not declare the active profile
     /// - The configuration is invalid
     ///
     /// # Example
@@ -114,22 +124,103 @@ impl ConfigLoader {
     /// # }
     /// ```
     pub async fn load_from_file(path: impl AsRef<Path>) -> ConfigResult<PackageToolsConfig> {
-        let fs = FileSystemManager::new();
-        let manager = ConfigManager::<PackageToolsConfig>::builder()
-            .with_defaults()
-            .with_file(path)
-            .with_env_prefix("SUBLIME_PKG")
-            .build(fs)?;
+        Self::load_from_file_with_profile(path, active_profile().as_deref()).await
+    }
+
+    /// Loads configuration from a specific file with a named profile applied on top.
+    ///
+    /// The `[profile.<name>]` table of the file is merged over its base sections: tables
+    /// merge key by key and every other value replaces the base value. Environment
+    /// variables still take precedence. Conflicts within the profile are logged as
+    /// warnings. `None` loads the base configuration only.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the configuration file
+    /// * `profile` - Name of the profile to apply
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file does not exist
+    /// - The file cannot be parsed
+    /// - The file does not declare the profile
+    /// - The configuration is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::config::ConfigLoader;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ConfigLoader::load_from_file_with_profile("repo.config.toml", Some("ci")).await?;
+    /// println!("Audit enabled in CI: {}", config.audit.enabled);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_from_file_with_profile(
+        path: impl AsRef<Path>,
+        profile: Option<&str>,
+    ) -> ConfigResult<PackageToolsConfig> {
+        let manager = file_manager(path.as_ref(), profile).await?;
 
         let config = manager.load().await?;
         config.validate()?;
         Ok(config)
     }
 
+    /// Loads configuration from a specific file like [`ConfigLoader::load_from_file`], but
+    /// without validating it.
+    ///
+    /// Use this to report every problem of a configuration with
+    /// [`validate_config_all`](crate::config::validate_config_all) instead of failing on the
+    /// first one.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the configuration file
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration, which may be invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file does not exist
+    /// - The file cannot be parsed
+    /// - The file does not declare the active profile
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::config::{ConfigLoader, validate_config_all};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = ConfigLoader::load_from_file_unvalidated("repo.config.toml").await?;
+    /// for issue in validate_config_all(&config) {
+    ///     eprintln!("{issue}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_from_file_unvalidated(
+        path: impl AsRef<Path>,
+    ) -> ConfigResult<PackageToolsConfig> {
+        let manager = file_manager(path.as_ref(), active_profile().as_deref()).await?;
+
+        manager.load_unvalidated().await
+    }
+
     /// Loads configuration from multiple files with defaults and environment variables.
     ///
     /// Files are loaded in order, with later files taking precedence.
-    /// Files that don't exist are silently skipped.
+    /// Files that don't exist are silently skipped. The active profile is read from
+    /// every file that declares it and applied over all of them.
     ///
     /// # Arguments
     ///
@@ -143,6 +234,7 @@ impl ConfigLoader {
     ///
     /// Returns an error if:
     /// - A file exists but cannot be parsed
+    /// - A profile is active and no file declares it
     /// - The merged configuration is invalid
     ///
     /// # Example
@@ -166,13 +258,30 @@ impl ConfigLoader {
         // Start with defaults
         let mut builder = ConfigManager::<PackageToolsConfig>::builder().with_defaults();
 
+        // Add the remote base configuration under the files
+        let files: Vec<&Path> = paths.iter().map(AsRef::as_ref).collect();
+        let remote = read_remote(&fs, &files).await?;
+        if !remote.is_empty() {
+            builder = builder.with_source(ConfigSource::memory(
+                remote.sections_from(),
+                ConfigSourcePriority::Global,
+            ));
+        }
+
         // Add files that exist
-        for path in paths {
+        for path in &paths {
             if fs.exists(path.as_ref()).await {
-                builder = builder.with_file(path);
+                builder = builder.with_file(path.as_ref());
             }
         }
 
+        // Overlay the active profile
+        if let Some(name) = active_profile() {
+            let overlay = read_profile(&fs, &files, &name).await?;
+            builder =
+                builder.with_source(ConfigSource::memory(overlay, ConfigSourcePriority::Project));
+        }
+
         // Add environment variables
         builder = builder.with_env_prefix("SUBLIME_PKG");
 
@@ -181,6 +290,107 @@ impl ConfigLoader {
         config.validate()?;
         Ok(config)
     }
+
+    /// Loads a single workspace configuration file into both the package tools and the
+    /// standard tools configuration.
+    ///
+    /// Standard sections (`package_managers`, `monorepo`, `commands`, `filesystem`,
+    /// `validation`) are projected into the attached `StandardConfig`; all other sections feed
+    /// `PackageToolsConfig`. The active profile is merged over the file. Settings declared twice
+    /// with different values, and profile settings that replace a table with a plain value or
+    /// the reverse, are reported in [`UnifiedConfig::conflicts`] instead of failing the load.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the workspace configuration file
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration together with the detected conflicts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file does not exist or cannot be read
+    /// - The file cannot be parsed
+    /// - The file does not declare the active profile
+    /// - Either projected configuration is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::config::ConfigLoader;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let unified = ConfigLoader::load_unified("repo.config.toml").await?;
+    /// unified.ensure_no_conflicts()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_unified(path: impl AsRef<Path>) -> ConfigResult<UnifiedConfig> {
+        unified::load_unified(path.as_ref(), active_profile().as_deref()).await
+    }
+
+    /// Loads a single workspace configuration file, like [`ConfigLoader::load_unified`], with
+    /// a named profile applied on top instead of the active one.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the workspace configuration file
+    /// * `profile` - Name of the profile to apply, `None` for the base configuration
+    ///
+    /// # Returns
+    ///
+    /// The loaded configuration together with the detected conflicts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, does not declare the profile,
+    /// or either projected configuration is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::config::ConfigLoader;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let unified = ConfigLoader::load_unified_with_profile("repo.config.toml", Some("release")).await?;
+    /// for conflict in &unified.conflicts {
+    ///     eprintln!("warning: {conflict}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_unified_with_profile(
+        path: impl AsRef<Path>,
+        profile: Option<&str>,
+    ) -> ConfigResult<UnifiedConfig> {
+        unified::load_unified(path.as_ref(), profile).await
+    }
+}
+
+/// Builds the configuration manager for a single file: defaults, the file's remote
+/// configuration, the file itself, the profile overlay and environment variables.
+async fn file_manager(
+    path: &Path,
+    profile: Option<&str>,
+) -> ConfigResult<ConfigManager<PackageToolsConfig>> {
+    let fs = FileSystemManager::new();
+
+    let mut builder = ConfigManager::<PackageToolsConfig>::builder().with_defaults();
+    let remote = read_remote(&fs, &[path]).await?;
+    if !remote.is_empty() {
+        builder = builder.with_source(ConfigSource::memory(
+            remote.sections_from(),
+            ConfigSourcePriority::Global,
+        ));
+    }
+    builder = builder.with_file(path);
+    if let Some(name) = profile {
+        let overlay = read_profile(&fs, &[path], name).await?;
+        builder = builder.with_source(ConfigSource::memory(overlay, ConfigSourcePriority::Project));
+    }
+    builder.with_env_prefix("SUBLIME_PKG").build(fs)
 }
 
 /// Convenience function to load configuration with defaults.
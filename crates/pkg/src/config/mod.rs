@@ -15,7 +15,11 @@
 //!
 //! - **Hierarchical Configuration**: Load from multiple sources with priority ordering
 //! - **Environment Overrides**: Override settings via environment variables
-//! - **Validation**: Validate configuration before use
+//! - **Profiles**: Named overlays (`[profile.ci]`) selected at load time
+//! - **Remote Configuration**: A centrally managed base (`[remote]`) fetched over HTTP(S) or
+//!   from S3, cached, and optionally signature-checked
+//! - **Validation**: Validate configuration before use, or collect every issue at once
+//! - **Documentation Registry**: `ConfigDoc` describes each key, its default and its values
 //! - **Merging**: Merge configurations from different sources
 //! - **Type Safety**: Strongly-typed configuration structures
 //! - **Documentation**: Comprehensive inline documentation for all settings
@@ -87,6 +91,8 @@
 //! [package_tools.audit]
 //! enabled = true
 //! min_severity = "warning"
+//! concurrency = 16
+//! check_timeout_secs = 300
 //! ```
 //!
 //! # Environment Variables
@@ -123,21 +129,34 @@
 //! This module will contain:
 //! - `package_tools`: Main `PackageToolsConfig` structure
 //! - `changeset`: Changeset-specific configuration
+//! - `changes`: Attribution of root and shared file changes
+//! - `aliases`: Import aliases generated from the workspace dependency graph
 //! - `version`: Versioning strategy and options
 //! - `dependency`: Dependency propagation settings
 //! - `upgrade`: Upgrade detection and application settings
 //! - `changelog`: Changelog generation configuration
 //! - `audit`: Audit and health check settings
 //! - `git`: Git integration settings
+//! - `hooks`: Managed git hook installation
+//! - `notifications`: Release and failure notification channels
 
 // Configuration modules
+mod aliases;
 mod audit;
 mod changelog;
+mod changes;
 mod changeset;
 mod dependency;
+mod doc;
+mod environment;
 mod git;
+mod hooks;
 mod loader;
+mod notifications;
+mod profile;
+mod remote;
 mod types;
+mod unified;
 mod upgrade;
 mod validation;
 mod version;
@@ -148,23 +167,42 @@ mod workspace;
 mod tests;
 
 // Re-export all configuration types
+pub use aliases::AliasesConfig;
 pub use audit::{
-    AuditConfig, AuditSectionsConfig, BreakingChangesAuditConfig, DependencyAuditConfig,
-    HealthScoreWeightsConfig, UpgradeAuditConfig, VersionConsistencyAuditConfig,
+    AuditCheckConfig, AuditConfig, AuditSectionsConfig, BreakingChangesAuditConfig,
+    DependencyAuditConfig, HealthScoreWeightsConfig, RegistryOwnershipAuditConfig,
+    UpgradeAuditConfig, VersionConsistencyAuditConfig,
 };
 pub use changelog::{
-    ChangelogConfig, ChangelogFormat, ConventionalConfig, ExcludeConfig, MonorepoMode,
-    TemplateConfig,
+    ChangelogConfig, ChangelogDateConfig, ChangelogFormat, ConventionalConfig, ExcludeConfig,
+    IncludeConfig, MonorepoMode, PackageChangelogConfig, SOURCE_DATE_EPOCH_ENV, TemplateConfig,
 };
+pub use changes::{ChangesConfig, FilePatternsConfig, RootFileAttribution, RootFileRule};
 pub use changeset::ChangesetConfig;
 pub use dependency::DependencyConfig;
+pub use doc::ConfigDoc;
+pub use environment::EnvironmentDefinition;
 pub use git::GitConfig;
+pub use hooks::HooksConfig;
+pub(crate) use hooks::KNOWN_GIT_HOOKS;
 pub use loader::{ConfigLoader, load_config, load_config_from_file};
+pub use notifications::{
+    EmailChannelConfig, NotificationChannelConfig, NotificationsConfig, SmtpSecurity,
+    WebhookChannelConfig,
+};
+pub use profile::{active_profile, set_active_profile};
 pub use types::PackageToolsConfig;
-pub use upgrade::{BackupConfig, RegistryConfig, UpgradeConfig};
-pub use validation::{path_exists, validate_config, validate_path_format, validate_url_format};
-pub use version::VersionConfig;
-pub use workspace::WorkspaceConfig;
+pub use unified::{ConfigConflict, UnifiedConfig};
+pub use upgrade::{
+    BackupConfig, RegistryConfig, RegistryTlsConfig, UpgradeConfig, UpgradeVerifyConfig,
+    UpgradeVerifyRule,
+};
+pub use validation::{
+    ConfigValidationIssue, path_exists, validate_config, validate_config_all, validate_path_format,
+    validate_url_format,
+};
+pub use version::{CalverRangePolicy, PackageVersionConfig, VersionConfig};
+pub use workspace::{WorkspaceConfig, WorkspaceRootConfig};
 
-// Re-export VersioningStrategy from types module for convenience
-pub use crate::types::VersioningStrategy;
+// Re-export strategies from types module for convenience
+pub use crate::types::{CalverFormat, CommitAssociation, VersionScheme, VersioningStrategy};
@@ -0,0 +1,370 @@
+//! Notification configuration for release and failure announcements.
+//!
+//! **What**: Defines the channels that receive a notification after a release or a failed
+//! operation, and the templates used to render the message.
+//!
+//! **How**: This module provides the `NotificationsConfig` structure. Each entry in `channels`
+//! is a Slack incoming webhook, a generic webhook, or an email sent through an SMTP server,
+//! selected by its `type` key. Channels pick the events they receive; templates use
+//! placeholders such as `{packages}` and `{changelog}`. Values of URLs, headers, and SMTP
+//! credentials may reference environment variables as `${VAR}` so secrets stay out of the file.
+//!
+//! **Why**: To announce releases to the people who depend on them, and failures to the people
+//! who can fix them, without wrapping every release job in custom scripts.
+
+use crate::notifications::NotificationEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use sublime_standard_tools::config::{ConfigError, ConfigResult, Configurable};
+
+/// Configuration for release and failure notifications.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::NotificationsConfig;
+///
+/// let config = NotificationsConfig::default();
+/// assert!(config.enabled);
+/// assert!(config.channels.is_empty());
+/// assert_eq!(config.timeout_secs, 10);
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [package_tools.notifications]
+/// enabled = true
+/// timeout_secs = 10
+/// release_template = "Released {packages}\n\n{changelog}"
+/// failure_template = "{operation} failed: {error}"
+///
+/// [[package_tools.notifications.channels]]
+/// type = "slack"
+/// url = "${SLACK_RELEASES_WEBHOOK}"
+/// events = ["release"]
+///
+/// [[package_tools.notifications.channels]]
+/// type = "webhook"
+/// url = "https://deploy.example.com/hooks/release"
+/// headers = { Authorization = "Bearer ${DEPLOY_TOKEN}" }
+///
+/// [[package_tools.notifications.channels]]
+/// type = "email"
+/// host = "smtp.example.com"
+/// port = 587
+/// security = "starttls"
+/// username = "releases@example.com"
+/// password = "${SMTP_PASSWORD}"
+/// from = "releases@example.com"
+/// to = ["team@example.com"]
+/// events = ["failure"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NotificationsConfig {
+    /// Whether notifications are sent.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// How long each channel may take to accept a notification, in seconds.
+    ///
+    /// # Default: `10`
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// Message template for releases.
+    ///
+    /// Supports placeholders: {title}, {packages}, {package_count}, {changelog}
+    ///
+    /// # Default: `"Released {packages}\n\n{changelog}"`
+    #[serde(default = "default_release_template")]
+    pub release_template: String,
+
+    /// Message template for failed operations.
+    ///
+    /// Supports placeholders: {title}, {operation}, {error}
+    ///
+    /// # Default: `"{operation} failed: {error}"`
+    #[serde(default = "default_failure_template")]
+    pub failure_template: String,
+
+    /// Channels notifications are delivered to.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub channels: Vec<NotificationChannelConfig>,
+}
+
+/// A channel that receives notifications.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NotificationChannelConfig {
+    /// A Slack incoming webhook, posted `{"text": <message>}`.
+    Slack(WebhookChannelConfig),
+
+    /// A generic webhook, posted the notification as JSON, or the rendered `template`.
+    Webhook(WebhookChannelConfig),
+
+    /// An email sent through an SMTP server.
+    Email(EmailChannelConfig),
+}
+
+impl NotificationChannelConfig {
+    /// Returns the `type` key of the channel.
+    #[must_use]
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Slack(_) => "slack",
+            Self::Webhook(_) => "webhook",
+            Self::Email(_) => "email",
+        }
+    }
+
+    /// Returns the events the channel receives.
+    #[must_use]
+    pub fn events(&self) -> &[NotificationEvent] {
+        match self {
+            Self::Slack(channel) | Self::Webhook(channel) => &channel.events,
+            Self::Email(channel) => &channel.events,
+        }
+    }
+
+    /// Returns the template overriding the notification templates for this channel, if any.
+    #[must_use]
+    pub fn template(&self) -> Option<&str> {
+        match self {
+            Self::Slack(channel) | Self::Webhook(channel) => channel.template.as_deref(),
+            Self::Email(channel) => channel.template.as_deref(),
+        }
+    }
+}
+
+/// Settings of a Slack or generic webhook channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WebhookChannelConfig {
+    /// URL the notification is posted to. May reference `${VAR}` environment variables.
+    pub url: String,
+
+    /// Extra HTTP headers. Values may reference `${VAR}` environment variables.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Events the channel receives.
+    ///
+    /// # Default: `["release", "failure"]`
+    #[serde(default = "default_events")]
+    pub events: Vec<NotificationEvent>,
+
+    /// Message template used for every event instead of the notification templates.
+    ///
+    /// For generic webhooks the rendered template is the request body, and placeholder values
+    /// are JSON-escaped so they can be placed inside JSON strings. Without a template, generic
+    /// webhooks receive the notification as a JSON document.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Settings of an email channel.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmailChannelConfig {
+    /// Host name of the SMTP server.
+    pub host: String,
+
+    /// Port of the SMTP server.
+    ///
+    /// # Default: `587`
+    #[serde(default = "default_smtp_port")]
+    pub port: u16,
+
+    /// How the connection to the SMTP server is secured.
+    ///
+    /// # Default: `SmtpSecurity::StartTls`
+    #[serde(default)]
+    pub security: SmtpSecurity,
+
+    /// User name for `AUTH PLAIN`. May reference `${VAR}` environment variables.
+    ///
+    /// # Default: `None` (no authentication)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Password for `AUTH PLAIN`. May reference `${VAR}` environment variables.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// Sender address.
+    pub from: String,
+
+    /// Recipient addresses.
+    pub to: Vec<String>,
+
+    /// Subject template, with the same placeholders as the message templates.
+    ///
+    /// # Default: `"{title}"`
+    #[serde(default = "default_subject")]
+    pub subject: String,
+
+    /// Events the channel receives.
+    ///
+    /// # Default: `["release", "failure"]`
+    #[serde(default = "default_events")]
+    pub events: Vec<NotificationEvent>,
+
+    /// Message template used for every event instead of the notification templates.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl EmailChannelConfig {
+    /// Checks the sender and recipient addresses.
+    ///
+    /// Addresses are placed inside `<...>` in SMTP commands and headers, so an address must be
+    /// a single `local@domain` without whitespace, control characters, or angle brackets.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first invalid address.
+    pub(crate) fn validate_addresses(&self) -> Result<(), String> {
+        std::iter::once(&self.from).chain(&self.to).try_for_each(|address| {
+            let valid = address.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty() && !domain.is_empty() && !domain.contains('@')
+            }) && !address
+                .chars()
+                .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '<' | '>'));
+
+            if valid { Ok(()) } else { Err(format!("Invalid email address {address:?}")) }
+        })
+    }
+}
+
+/// How the connection to an SMTP server is secured.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpSecurity {
+    /// Connect in plain text and upgrade with `STARTTLS` (usually port 587).
+    #[default]
+    StartTls,
+
+    /// Connect over TLS from the start (usually port 465).
+    Tls,
+
+    /// No encryption. Only suitable for local relays.
+    None,
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            timeout_secs: default_timeout_secs(),
+            release_template: default_release_template(),
+            failure_template: default_failure_template(),
+            channels: Vec::new(),
+        }
+    }
+}
+
+/// Returns the default for `NotificationsConfig::enabled`.
+fn default_enabled() -> bool {
+    true
+}
+
+/// Returns the default for `NotificationsConfig::timeout_secs`.
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+/// Returns the default for `NotificationsConfig::release_template`.
+fn default_release_template() -> String {
+    "Released {packages}\n\n{changelog}".to_string()
+}
+
+/// Returns the default for `NotificationsConfig::failure_template`.
+fn default_failure_template() -> String {
+    "{operation} failed: {error}".to_string()
+}
+
+/// Returns the default events of a channel.
+fn default_events() -> Vec<NotificationEvent> {
+    vec![NotificationEvent::Release, NotificationEvent::Failure]
+}
+
+/// Returns the default for `EmailChannelConfig::port`.
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// Returns the default for `EmailChannelConfig::subject`.
+fn default_subject() -> String {
+    "{title}".to_string()
+}
+
+impl Configurable for NotificationsConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        if self.timeout_secs == 0 {
+            return Err(ConfigError::ValidationError {
+                message: "notifications.timeout_secs: Timeout must be greater than 0".to_string(),
+            });
+        }
+
+        for (index, channel) in self.channels.iter().enumerate() {
+            let invalid = |message: &str| ConfigError::ValidationError {
+                message: format!("notifications.channels[{index}]: {message}"),
+            };
+
+            if channel.events().is_empty() {
+                return Err(invalid("At least one event is required"));
+            }
+
+            match channel {
+                NotificationChannelConfig::Slack(webhook)
+                | NotificationChannelConfig::Webhook(webhook) => {
+                    if webhook.url.trim().is_empty() {
+                        return Err(invalid("Webhook URL cannot be empty"));
+                    }
+                }
+                NotificationChannelConfig::Email(email) => {
+                    if email.host.trim().is_empty() {
+                        return Err(invalid("SMTP host cannot be empty"));
+                    }
+                    if email.from.trim().is_empty() {
+                        return Err(invalid("Sender address cannot be empty"));
+                    }
+                    if email.to.is_empty() || email.to.iter().any(|to| to.trim().is_empty()) {
+                        return Err(invalid("At least one non-empty recipient is required"));
+                    }
+                    if email.password.is_some() && email.username.is_none() {
+                        return Err(invalid("A password requires a username"));
+                    }
+                    if email.username.is_some() && email.security == SmtpSecurity::None {
+                        return Err(invalid(
+                            "Credentials require TLS or STARTTLS; they are never sent in cleartext",
+                        ));
+                    }
+                    email.validate_addresses().map_err(|reason| invalid(&reason))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.enabled = other.enabled;
+        self.timeout_secs = other.timeout_secs;
+        self.release_template = other.release_template;
+        self.failure_template = other.failure_template;
+        self.channels = other.channels;
+        Ok(())
+    }
+}
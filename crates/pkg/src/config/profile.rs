@@ -0,0 +1,185 @@
+//! Named configuration profiles.
+//!
+//! **What**: Lets a configuration file declare named overlays under `[profile.<name>]`, such
+//! as `[profile.ci.package_tools.audit]`, and selects which one is applied on top of the base
+//! configuration.
+//!
+//! **How**: A profile table has the same layout as the root of the file: top-level sections,
+//! the legacy `package_tools` table, or both. It is merged over the base sections before the
+//! configuration is deserialized: tables merge key by key, every other value, including
+//! arrays, replaces the base value. Environment variables still override the result. A profile
+//! that declares a setting twice with different values, or replaces a table with a plain value
+//! (or the reverse), is reported as a `ConfigConflict`. The profile used by the loaders is
+//! chosen with `set_active_profile`.
+//!
+//! **Why**: CI and release pipelines need stricter validation or different registries than
+//! local development, and a second configuration file for each of them drifts from the first.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{PoisonError, RwLock};
+
+use sublime_standard_tools::config::{ConfigError, ConfigResult, ConfigValue};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+use super::unified::{
+    ConfigConflict, PROFILES_SECTION, merge_sections, read_config_file, render, split_sections,
+};
+
+/// Profile applied by the configuration loaders, set once by the application.
+static ACTIVE_PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Selects the profile applied by the configuration loaders for the rest of the process.
+///
+/// `None` loads the base configuration only.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_pkg_tools::config::{active_profile, set_active_profile};
+///
+/// set_active_profile(Some("ci".to_string()));
+/// assert_eq!(active_profile().as_deref(), Some("ci"));
+/// set_active_profile(None);
+/// ```
+pub fn set_active_profile(profile: Option<String>) {
+    *ACTIVE_PROFILE.write().unwrap_or_else(PoisonError::into_inner) = profile;
+}
+
+/// Returns the profile applied by the configuration loaders, if one is selected.
+#[must_use]
+pub fn active_profile() -> Option<String> {
+    ACTIVE_PROFILE.read().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// The sections a profile overlays, split like the root of a file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProfileOverlay {
+    /// Package tools sections of the profile.
+    pub(crate) package_tools: HashMap<String, ConfigValue>,
+
+    /// Standard tools sections of the profile.
+    pub(crate) standard: HashMap<String, ConfigValue>,
+
+    /// Settings the profile declares twice, or whose shape differs from the base.
+    pub(crate) conflicts: Vec<ConfigConflict>,
+}
+
+impl ProfileOverlay {
+    /// Reads profile `name` from a parsed configuration file.
+    ///
+    /// Returns `None` if the file does not declare the profile.
+    pub(crate) fn from_file(raw: &ConfigValue, name: &str) -> Option<Self> {
+        let profile = raw.get(PROFILES_SECTION)?.get(name)?.clone();
+        let prefix = format!("{PROFILES_SECTION}.{name}.");
+
+        let (package_tools, standard, mut conflicts) = split_sections(profile, &prefix);
+        let (base_package_tools, base_standard, _) = split_sections(raw.clone(), "");
+        shape_conflicts(&base_package_tools, &package_tools, "", &prefix, &mut conflicts);
+        shape_conflicts(&base_standard, &standard, "", &prefix, &mut conflicts);
+        conflicts.sort_by(|a, b| a.key.cmp(&b.key));
+
+        Some(Self { package_tools, standard, conflicts })
+    }
+
+    /// Returns every section of the profile as one map, for loaders that do not split the
+    /// standard sections.
+    pub(crate) fn sections_from(self) -> HashMap<String, ConfigValue> {
+        let mut sections = self.package_tools;
+        sections.extend(self.standard);
+        sections
+    }
+}
+
+/// Reads profile `name` from the existing files among `files` and merges them in order.
+///
+/// Conflicts are logged as warnings; use `ConfigLoader::load_unified` to receive them.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or parsed, or if no file declares the profile.
+pub(crate) async fn read_profile<F: AsyncFileSystem>(
+    fs: &F,
+    files: &[&Path],
+    name: &str,
+) -> ConfigResult<HashMap<String, ConfigValue>> {
+    let mut overlay: Option<HashMap<String, ConfigValue>> = None;
+    let mut declared = Vec::new();
+
+    for path in files {
+        if !fs.exists(path).await {
+            continue;
+        }
+        let raw = read_config_file(fs, path).await?;
+        declared.extend(profile_names(&raw));
+
+        let Some(profile) = ProfileOverlay::from_file(&raw, name) else {
+            continue;
+        };
+        for conflict in &profile.conflicts {
+            log::warn!("{}: {}", path.display(), conflict);
+        }
+        match overlay.as_mut() {
+            Some(overlay) => merge_sections(overlay, profile.sections_from()),
+            None => overlay = Some(profile.sections_from()),
+        }
+    }
+
+    overlay.ok_or_else(|| undefined_profile(name, files, &declared))
+}
+
+/// Returns the names of the profiles declared in a parsed configuration file.
+pub(crate) fn profile_names(raw: &ConfigValue) -> Vec<String> {
+    raw.get(PROFILES_SECTION)
+        .and_then(ConfigValue::as_map)
+        .map(|profiles| profiles.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Error for a profile that none of the given files declares.
+pub(crate) fn undefined_profile(name: &str, files: &[&Path], declared: &[String]) -> ConfigError {
+    let files = files.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ");
+    let mut declared = declared.to_vec();
+    declared.sort();
+    declared.dedup();
+
+    let available = if declared.is_empty() { "none".to_string() } else { declared.join(", ") };
+    ConfigError::validation(format!(
+        "profile '{name}' is not declared in {files} (available profiles: {available})"
+    ))
+}
+
+/// Records the settings of `overlay` that replace a table with a plain value or the reverse.
+fn shape_conflicts(
+    base: &HashMap<String, ConfigValue>,
+    overlay: &HashMap<String, ConfigValue>,
+    path: &str,
+    prefix: &str,
+    conflicts: &mut Vec<ConfigConflict>,
+) {
+    for (key, value) in overlay {
+        let Some(existing) = base.get(key) else {
+            continue;
+        };
+        let key_path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+
+        match (existing, value) {
+            (ConfigValue::Map(existing), ConfigValue::Map(value)) => {
+                shape_conflicts(existing, value, &key_path, prefix, conflicts);
+            }
+            (existing, value)
+                if existing.is_map() != value.is_map()
+                    || existing.is_array() != value.is_array() =>
+            {
+                conflicts.push(ConfigConflict {
+                    key: key_path.clone(),
+                    used_from: format!("{prefix}{key_path}"),
+                    used_value: render(value),
+                    ignored_from: key_path,
+                    ignored_value: render(existing),
+                });
+            }
+            _ => {}
+        }
+    }
+}
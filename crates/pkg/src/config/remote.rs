@@ -0,0 +1,168 @@
+//! Remote base configuration.
+//!
+//! **What**: Lets a workspace configuration file point at a centrally managed document with a
+//! `[remote]` table, such as `url = "https://config.acme.dev/workspace.toml"`, which is merged
+//! under the file.
+//!
+//! **How**: The loaders read the `[remote]` table of each file and fetch the document with the
+//! standard tools `RemoteProvider`, which caches it and checks its signature when `public_key`
+//! is set. The document has the layout of a configuration file and is split the same way. Its
+//! sections are added below the local files, so local settings, profiles, and environment
+//! variables all override it. A remote that cannot be reached and has no cached copy is logged
+//! and skipped; a document that fails signature verification fails the load. The fetch uses the
+//! proxy and TLS settings of registry requests, read from `[upgrade.registry.tls]` of the same
+//! file, `.npmrc` next to it, and the environment.
+//!
+//! **Why**: Organizations with many repositories manage workspace policy (audit thresholds,
+//! registries, upgrade rules) in one place instead of copying it into every workspace.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use sublime_standard_tools::config::{
+    ConfigError, ConfigProvider, ConfigResult, ConfigSourcePriority, ConfigValue, RemoteConfig,
+    RemoteProvider,
+};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+use sublime_standard_tools::network::TransportSettings;
+
+use super::RegistryTlsConfig;
+use super::unified::{merge_sections, read_config_file, split_sections};
+use crate::upgrade::{NpmrcConfig, resolve_transport};
+
+/// Table of a configuration file that points at the remote base configuration.
+pub(crate) const REMOTE_SECTION: &str = "remote";
+
+/// Sections of the remote base configuration, split like a file.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RemoteBase {
+    /// Package tools sections of the remote documents.
+    pub(crate) package_tools: HashMap<String, ConfigValue>,
+
+    /// Standard tools sections of the remote documents.
+    pub(crate) standard: HashMap<String, ConfigValue>,
+}
+
+impl RemoteBase {
+    /// Returns whether no remote document contributed any section.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.package_tools.is_empty() && self.standard.is_empty()
+    }
+
+    /// Returns every section as one map, for loaders that do not split the standard sections.
+    pub(crate) fn sections_from(self) -> HashMap<String, ConfigValue> {
+        let mut sections = self.package_tools;
+        sections.extend(self.standard);
+        sections
+    }
+}
+
+/// Fetches the remote configuration declared by the existing files among `files`, merged in
+/// file order.
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or parsed, its `[remote]` table is invalid, or a
+/// remote document cannot be parsed or fails signature verification.
+pub(crate) async fn read_remote<F: AsyncFileSystem + Clone + 'static>(
+    fs: &F,
+    files: &[&Path],
+) -> ConfigResult<RemoteBase> {
+    let mut base = RemoteBase::default();
+    for path in files {
+        if !fs.exists(path).await {
+            continue;
+        }
+        let raw = read_config_file(fs, path).await?;
+        let remote = remote_from_file(fs, &raw, path).await?;
+        merge_sections(&mut base.package_tools, remote.package_tools);
+        merge_sections(&mut base.standard, remote.standard);
+    }
+    Ok(base)
+}
+
+/// Fetches the remote configuration declared by a parsed configuration file.
+///
+/// A relative `cache_dir` is resolved against the directory of the file.
+pub(crate) async fn remote_from_file<F: AsyncFileSystem + Clone + 'static>(
+    fs: &F,
+    raw: &ConfigValue,
+    path: &Path,
+) -> ConfigResult<RemoteBase> {
+    let Some(section) = raw.get(REMOTE_SECTION) else {
+        return Ok(RemoteBase::default());
+    };
+
+    let mut config: RemoteConfig = serde_json::from_value(serde_json::to_value(section)?)
+        .map_err(|e| ConfigError::parse(REMOTE_SECTION, e.to_string()))?;
+    if config.url.trim().is_empty() {
+        return Err(ConfigError::validation(format!(
+            "{}: {REMOTE_SECTION}.url must not be empty",
+            path.display()
+        )));
+    }
+    if let (Some(cache_dir), Some(parent)) = (&config.cache_dir, path.parent())
+        && cache_dir.is_relative()
+    {
+        config.cache_dir = Some(parent.join(cache_dir));
+    }
+
+    let url = config.url.clone();
+    let transport = remote_transport(fs, raw, path).await?;
+    let provider = RemoteProvider::new(config, ConfigSourcePriority::Global, fs.clone())
+        .with_transport(transport);
+    let mut document = match provider.load().await {
+        Ok(document) => document,
+        Err(error @ ConfigError::RemoteFetchError { .. }) => {
+            log::warn!("{error}; continuing without the remote configuration");
+            return Ok(RemoteBase::default());
+        }
+        Err(error) => return Err(error),
+    };
+
+    // A remote document cannot chain to another remote
+    if let ConfigValue::Map(sections) = &mut document {
+        sections.remove(REMOTE_SECTION);
+    }
+    let (package_tools, standard, conflicts) = split_sections(document, "");
+    for conflict in &conflicts {
+        log::warn!("{url}: {conflict}");
+    }
+
+    Ok(RemoteBase { package_tools, standard })
+}
+
+/// Resolves the proxy and TLS settings of the remote fetch like those of registry requests.
+///
+/// The `[upgrade.registry.tls]` table and `read_npmrc` setting are read from the file itself,
+/// since the remote document is fetched before the configuration is loaded. Relative paths
+/// resolve against the directory of the file.
+async fn remote_transport<F: AsyncFileSystem>(
+    fs: &F,
+    raw: &ConfigValue,
+    path: &Path,
+) -> ConfigResult<TransportSettings> {
+    let (package_tools, _, _) = split_sections(raw.clone(), "");
+    let registry = package_tools.get("upgrade").and_then(|upgrade| upgrade.get("registry"));
+    let tls: RegistryTlsConfig = match registry.and_then(|registry| registry.get("tls")) {
+        Some(tls) => serde_json::from_value(serde_json::to_value(tls)?)
+            .map_err(|e| ConfigError::parse("upgrade.registry.tls", e.to_string()))?,
+        None => RegistryTlsConfig::default(),
+    };
+    let read_npmrc = registry
+        .and_then(|registry| registry.get("read_npmrc"))
+        .and_then(ConfigValue::as_boolean)
+        .unwrap_or(true);
+
+    let root = path.parent().unwrap_or(Path::new("."));
+    let npmrc = if read_npmrc {
+        NpmrcConfig::from_workspace(root, fs)
+            .await
+            .inspect_err(|e| log::warn!("Failed to load .npmrc: {e}"))
+            .ok()
+    } else {
+        None
+    };
+
+    Ok(resolve_transport(&tls, npmrc.as_ref(), root, |name| std::env::var(name).ok()))
+}
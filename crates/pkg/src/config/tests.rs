@@ -13,11 +13,19 @@
 use sublime_standard_tools::config::Configurable;
 
 use crate::config::{
-    AuditConfig, AuditSectionsConfig, BackupConfig, BreakingChangesAuditConfig, ChangelogConfig,
-    ChangelogFormat, ChangesetConfig, ConventionalConfig, DependencyAuditConfig, DependencyConfig,
-    GitConfig, MonorepoMode, PackageToolsConfig, RegistryConfig, UpgradeAuditConfig, UpgradeConfig,
+    AliasesConfig, AuditConfig, AuditSectionsConfig, BackupConfig, BreakingChangesAuditConfig,
+    ChangelogConfig, ChangelogFormat, ChangesConfig, ChangesetConfig, ConventionalConfig,
+    DependencyAuditConfig, DependencyConfig, EnvironmentDefinition, GitConfig, HooksConfig,
+    MonorepoMode, PackageToolsConfig, RegistryConfig, UpgradeAuditConfig, UpgradeConfig,
     VersionConfig, VersionConsistencyAuditConfig, VersioningStrategy,
 };
+use crate::config::{
+    CalverFormat, CalverRangePolicy, ChangelogDateConfig, CommitAssociation, EmailChannelConfig,
+    NotificationChannelConfig, NotificationsConfig, PackageChangelogConfig, PackageVersionConfig,
+    RootFileAttribution, RootFileRule, UpgradeVerifyConfig, UpgradeVerifyRule, VersionScheme,
+};
+use chrono::{TimeZone, Utc};
+use std::collections::BTreeMap;
 
 // =============================================================================
 // PackageToolsConfig Tests
@@ -173,6 +181,12 @@ mod changeset_config {
             history_path: ".custom-history".to_string(),
             available_environments: vec!["dev".to_string(), "prod".to_string()],
             default_environments: vec!["prod".to_string()],
+            environments: vec![EnvironmentDefinition::new("prod", 1)],
+            track_authors: false,
+            human_names: false,
+            package_renames: Default::default(),
+            commit_association: CommitAssociation::Trailer,
+            base_branch: Some("develop".to_string()),
         };
 
         assert!(base.merge_with(override_config.clone()).is_ok());
@@ -180,6 +194,123 @@ mod changeset_config {
         assert_eq!(base.history_path, ".custom-history");
         assert_eq!(base.available_environments, override_config.available_environments);
         assert_eq!(base.default_environments, override_config.default_environments);
+        assert_eq!(base.environments, override_config.environments);
+        assert!(!base.track_authors);
+        assert!(!base.human_names);
+        assert_eq!(base.commit_association, CommitAssociation::Trailer);
+        assert_eq!(base.base_branch.as_deref(), Some("develop"));
+    }
+
+    #[test]
+    fn test_commit_association_deserialize_and_validate() {
+        let json = r#"{"path": ".changesets", "history_path": ".changesets/history",
+            "available_environments": ["production"], "default_environments": ["production"],
+            "commit_association": "packages", "base_branch": "develop"}"#;
+        let config: ChangesetConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.commit_association, CommitAssociation::Packages);
+        assert!(config.validate().is_ok());
+
+        let config = ChangesetConfig { base_branch: Some(" ".to_string()), ..config };
+        let error = config.validate().unwrap_err();
+        assert!(error.to_string().contains("changeset.base_branch"));
+    }
+
+    #[test]
+    fn test_package_renames_follow_chains_and_reject_cycles() {
+        let mut config = ChangesetConfig::default();
+        config.package_renames.insert("a".to_string(), "b".to_string());
+        config.package_renames.insert("b".to_string(), "c".to_string());
+
+        assert!(config.validate().is_ok());
+        assert_eq!(config.renamed_package("a"), Some("c"));
+        assert_eq!(config.renamed_package("b"), Some("c"));
+        assert_eq!(config.renamed_package("c"), None);
+
+        config.package_renames.insert("c".to_string(), "a".to_string());
+        let error = config.validate().unwrap_err();
+        assert!(error.to_string().contains("changeset.package_renames"));
+
+        config.package_renames.clear();
+        config.package_renames.insert("a".to_string(), "a".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    fn staged_config() -> ChangesetConfig {
+        ChangesetConfig {
+            available_environments: vec![
+                "production".to_string(),
+                "development".to_string(),
+                "staging".to_string(),
+            ],
+            default_environments: vec!["production".to_string()],
+            environments: vec![
+                EnvironmentDefinition::new("production", 3).with_required_approvals(2),
+                EnvironmentDefinition::new("development", 1).with_auto_promote(true),
+                EnvironmentDefinition::new("staging", 2),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_promotion_order_uses_definitions() {
+        let config = staged_config();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.promotion_order(), vec!["development", "staging", "production"]);
+        assert_eq!(config.next_environment("staging"), Some("production"));
+        assert_eq!(config.next_environment("production"), None);
+        assert_eq!(config.previous_environment("production"), Some("staging"));
+        assert_eq!(config.previous_environment("development"), None);
+        assert_eq!(config.environment("production").map(|e| e.required_approvals), Some(2));
+        assert!(config.environment("development").is_some_and(|e| e.auto_promote));
+    }
+
+    #[test]
+    fn test_promotion_order_defaults_to_available_environments() {
+        let config = ChangesetConfig {
+            available_environments: vec!["staging".to_string(), "production".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(config.promotion_order(), vec!["staging", "production"]);
+        assert_eq!(config.previous_environment("production"), Some("staging"));
+        assert!(config.environment("staging").is_none());
+    }
+
+    #[test]
+    fn test_environment_definition_must_be_available() {
+        let mut config = staged_config();
+        config.environments.push(EnvironmentDefinition::new("qa", 4));
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_environment_definition_duplicates_rejected() {
+        let mut config = staged_config();
+        config.environments.push(EnvironmentDefinition::new("staging", 4));
+        assert!(config.validate().is_err());
+
+        let mut config = staged_config();
+        config.environments[2].order = 1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_environment_definitions_deserialize() {
+        let json = r#"{
+            "path": ".changesets",
+            "history_path": ".changesets/history",
+            "available_environments": ["staging", "production"],
+            "default_environments": ["production"],
+            "environments": [
+                { "name": "staging", "order": 1, "auto_promote": true },
+                { "name": "production", "order": 2, "required_approvals": 1 }
+            ]
+        }"#;
+        let config: ChangesetConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.environments.len(), 2);
+        assert!(config.environments[0].auto_promote);
+        assert_eq!(config.environments[1].required_approvals, 1);
+        assert!(config.validate().is_ok());
     }
 }
 
@@ -275,12 +406,104 @@ mod version_config {
             strategy: VersioningStrategy::Unified,
             default_bump: "minor".to_string(),
             snapshot_format: "{version}-snapshot".to_string(),
+            resolution_cache: false,
+            independent_packages: vec!["@myorg/plugin-*".to_string()],
+            packages: BTreeMap::from([(
+                "@myorg/web".to_string(),
+                PackageVersionConfig { scheme: Some(VersionScheme::Calver) },
+            )]),
+            calver_packages: vec!["@myorg/app-*".to_string()],
+            calver_format: CalverFormat::YearMonthDay,
+            calver_range: CalverRangePolicy::AtLeast,
         };
 
         assert!(base.merge_with(override_config.clone()).is_ok());
         assert_eq!(base.strategy, VersioningStrategy::Unified);
         assert_eq!(base.default_bump, "minor");
         assert_eq!(base.snapshot_format, "{version}-snapshot");
+        assert!(!base.resolution_cache);
+        assert_eq!(base.independent_packages, vec!["@myorg/plugin-*"]);
+        assert_eq!(base.scheme_for("@myorg/web"), VersionScheme::Calver);
+        assert_eq!(base.scheme_for("@myorg/app-admin"), VersionScheme::Calver);
+        assert_eq!(base.calver_format, CalverFormat::YearMonthDay);
+        assert_eq!(base.calver_range, CalverRangePolicy::AtLeast);
+    }
+
+    #[test]
+    fn test_independent_packages_validation() {
+        let config = VersionConfig {
+            strategy: VersioningStrategy::Unified,
+            independent_packages: vec![
+                "@myorg/plugin-*".to_string(),
+                "!@myorg/plugin-core".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let independent = VersionConfig {
+            independent_packages: vec!["@myorg/plugin-*".to_string()],
+            ..Default::default()
+        };
+        assert!(independent.validate().unwrap_err().to_string().contains("unified"));
+
+        let invalid = VersionConfig {
+            strategy: VersioningStrategy::Unified,
+            independent_packages: vec!["@myorg/[plugin".to_string()],
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_package_version_schemes() {
+        let mut value = serde_json::to_value(VersionConfig::default()).unwrap();
+        value["packages"] = serde_json::json!({
+            "@myorg/web": { "scheme": "calver" },
+            "@myorg/core": {}
+        });
+
+        let config: VersionConfig = serde_json::from_value(value).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.scheme_for("@myorg/web"), VersionScheme::Calver);
+        assert_eq!(config.scheme_for("@myorg/core"), VersionScheme::Semver);
+        assert_eq!(config.scheme_for("@myorg/other"), VersionScheme::Semver);
+
+        let mut invalid = serde_json::to_value(VersionConfig::default()).unwrap();
+        invalid["packages"] = serde_json::json!({ "@myorg/web": { "scheme": "romver" } });
+        assert!(serde_json::from_value::<VersionConfig>(invalid).is_err());
+    }
+
+    #[test]
+    fn test_calver_settings() {
+        let mut value = serde_json::to_value(VersionConfig::default()).unwrap();
+        assert_eq!(value["calver_format"], "YYYY.MM.MICRO");
+        assert_eq!(value["calver_range"], "pin");
+
+        value["calver_packages"] = serde_json::json!(["@myorg/app-*", "!@myorg/app-legacy"]);
+        value["calver_format"] = serde_json::json!("YYYY.MM.DD");
+        value["calver_range"] = serde_json::json!("at-least");
+        value["packages"] = serde_json::json!({ "@myorg/app-admin": { "scheme": "semver" } });
+
+        let config: VersionConfig = serde_json::from_value(value).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.calver_format, CalverFormat::YearMonthDay);
+        assert_eq!(config.calver_range, CalverRangePolicy::AtLeast);
+        assert_eq!(config.scheme_for("@myorg/app-web"), VersionScheme::Calver);
+        assert_eq!(config.scheme_for("@myorg/app-legacy"), VersionScheme::Semver);
+        // An override takes precedence over calver_packages
+        assert_eq!(config.scheme_for("@myorg/app-admin"), VersionScheme::Semver);
+        assert_eq!(config.scheme_for("@myorg/core"), VersionScheme::Semver);
+
+        let invalid = VersionConfig {
+            calver_packages: vec!["@myorg/[app".to_string()],
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = serde_json::to_value(VersionConfig::default()).unwrap();
+        invalid["calver_format"] = serde_json::json!("YY.MM");
+        assert!(serde_json::from_value::<VersionConfig>(invalid).is_err());
     }
 
     #[test]
@@ -400,6 +623,7 @@ mod dependency_config {
             skip_file_protocol: false,
             skip_link_protocol: false,
             skip_portal_protocol: false,
+            range_policy: "keep-range".to_string(),
         };
 
         assert!(base.merge_with(override_config.clone()).is_ok());
@@ -413,6 +637,13 @@ mod dependency_config {
         assert!(!base.skip_file_protocol);
         assert!(!base.skip_link_protocol);
         assert!(!base.skip_portal_protocol);
+        assert!(base.keeps_satisfied_ranges());
+    }
+
+    #[test]
+    fn test_invalid_range_policy() {
+        let config = DependencyConfig { range_policy: "pin".to_string(), ..Default::default() };
+        assert!(config.validate().unwrap_err().to_string().contains("range_policy"));
     }
 
     #[test]
@@ -566,214 +797,965 @@ mod git_config {
 }
 
 // =============================================================================
-// ChangelogConfig Tests
+// HooksConfig Tests
 // =============================================================================
 
-mod changelog_config {
+mod hooks_config {
     use super::*;
 
+    fn with_hook(name: &str, script: &str) -> HooksConfig {
+        let mut config = HooksConfig::default();
+        config.hooks.insert(name.to_string(), script.to_string());
+        config
+    }
+
     #[test]
     fn test_default_config_is_valid() {
-        let config = ChangelogConfig::default();
+        let config = HooksConfig::default();
         assert!(config.validate().is_ok());
+        assert!(config.enabled);
     }
 
     #[test]
-    fn test_default_values() {
-        let config = ChangelogConfig::default();
-        assert!(config.enabled);
-        assert_eq!(config.format, ChangelogFormat::KeepAChangelog);
-        assert_eq!(config.filename, "CHANGELOG.md");
-        assert!(config.include_commit_links);
-        assert!(config.include_issue_links);
-        assert!(!config.include_authors);
+    fn test_known_hook_is_valid() {
+        assert!(with_hook("pre-commit", "workspace changeset check").validate().is_ok());
     }
 
     #[test]
-    fn test_empty_filename_validation() {
-        let config = ChangelogConfig { filename: String::new(), ..Default::default() };
-        assert!(config.validate().is_err());
+    fn test_unknown_hook_is_rejected() {
+        assert!(with_hook("pre-deploy", "echo").validate().is_err());
     }
 
     #[test]
-    fn test_conventional_config_default() {
-        let config = ConventionalConfig::default();
-        assert!(config.enabled);
-        assert!(config.types.contains_key("feat"));
-        assert!(config.types.contains_key("fix"));
-        assert_eq!(config.breaking_section, "Breaking Changes");
+    fn test_empty_script_is_rejected() {
+        assert!(with_hook("pre-push", "  ").validate().is_err());
     }
 
     #[test]
-    fn test_format_serialization() {
-        let format = ChangelogFormat::KeepAChangelog;
-        let serialized = serde_json::to_string(&format).unwrap();
-        assert_eq!(serialized, r#""keep-a-changelog""#);
+    fn test_absolute_hooks_path_is_rejected() {
+        let config =
+            HooksConfig { hooks_path: Some("/tmp/hooks".to_string()), ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let config = HooksConfig { hooks_path: Some(".husky".to_string()), ..Default::default() };
+        assert!(config.validate().is_ok());
     }
 
     #[test]
-    fn test_monorepo_mode_serialization() {
-        let mode = MonorepoMode::PerPackage;
-        let serialized = serde_json::to_string(&mode).unwrap();
-        assert_eq!(serialized, r#""per-package""#);
+    fn test_merge_extends_hooks() {
+        let mut base = with_hook("pre-commit", "one");
+        let other =
+            HooksConfig { hooks_path: Some(".husky".to_string()), ..with_hook("pre-push", "two") };
+
+        base.merge_with(other).unwrap();
+
+        assert_eq!(base.hooks.len(), 2);
+        assert_eq!(base.hooks_path.as_deref(), Some(".husky"));
+    }
+
+    #[test]
+    fn test_missing_section_deserializes_to_default() {
+        let json = serde_json::to_value(PackageToolsConfig::default()).unwrap();
+        let mut json = json.as_object().unwrap().clone();
+        json.remove("hooks");
+
+        let config: PackageToolsConfig =
+            serde_json::from_value(serde_json::Value::Object(json)).unwrap();
+        assert_eq!(config.hooks, HooksConfig::default());
     }
 }
 
 // =============================================================================
-// UpgradeConfig Tests
+// AliasesConfig Tests
 // =============================================================================
 
-mod upgrade_config {
+mod aliases_config {
     use super::*;
-    use std::collections::HashMap;
 
     #[test]
-    fn test_default_upgrade_config_is_valid() {
-        let config = UpgradeConfig::default();
+    fn test_default_config_is_valid() {
+        let config = AliasesConfig::default();
         assert!(config.validate().is_ok());
+        assert_eq!(config.imports_prefix, "#");
     }
 
     #[test]
-    fn test_default_registry_config_is_valid() {
-        let config = RegistryConfig::default();
-        assert!(config.validate().is_ok());
+    fn test_absolute_tsconfig_is_rejected() {
+        let config =
+            AliasesConfig { tsconfig: "/repo/tsconfig.json".to_string(), ..Default::default() };
+        assert!(config.validate().is_err());
     }
 
     #[test]
-    fn test_default_backup_config_is_valid() {
-        let config = BackupConfig::default();
+    fn test_imports_prefix_must_start_with_hash() {
+        let config = AliasesConfig { imports_prefix: "~".to_string(), ..Default::default() };
+        assert!(config.validate().is_err());
+
+        let config =
+            AliasesConfig { imports_prefix: "#internal/".to_string(), ..Default::default() };
         assert!(config.validate().is_ok());
     }
 
     #[test]
-    fn test_default_values() {
-        let config = UpgradeConfig::default();
-        assert!(config.auto_changeset);
-        assert_eq!(config.changeset_bump, "patch");
-        assert_eq!(config.registry.default_registry, "https://registry.npmjs.org");
-        assert!(config.backup.enabled);
+    fn test_missing_section_deserializes_to_default() {
+        let json = serde_json::to_value(PackageToolsConfig::default()).unwrap();
+        let mut json = json.as_object().unwrap().clone();
+        json.remove("aliases");
+
+        let config: PackageToolsConfig =
+            serde_json::from_value(serde_json::Value::Object(json)).unwrap();
+        assert_eq!(config.aliases, AliasesConfig::default());
     }
+}
 
-    #[test]
-    fn test_invalid_changeset_bump() {
-        let config = UpgradeConfig { changeset_bump: "invalid".to_string(), ..Default::default() };
-        assert!(config.validate().is_err());
+// =============================================================================
+// NotificationsConfig Tests
+// =============================================================================
+
+mod notifications_config {
+    use super::*;
+    use crate::config::{SmtpSecurity, WebhookChannelConfig};
+    use crate::notifications::NotificationEvent;
+
+    fn email_channel() -> EmailChannelConfig {
+        EmailChannelConfig {
+            host: "smtp.example.com".to_string(),
+            port: 587,
+            security: SmtpSecurity::StartTls,
+            username: None,
+            password: None,
+            from: "releases@example.com".to_string(),
+            to: vec!["team@example.com".to_string()],
+            subject: "{title}".to_string(),
+            events: vec![NotificationEvent::Release],
+            template: None,
+        }
     }
 
-    #[test]
-    fn test_empty_registry_url() {
-        let config = UpgradeConfig {
-            registry: RegistryConfig { default_registry: String::new(), ..Default::default() },
-            ..Default::default()
-        };
-        assert!(config.validate().is_err());
+    fn with_channel(channel: NotificationChannelConfig) -> NotificationsConfig {
+        NotificationsConfig { channels: vec![channel], ..Default::default() }
     }
 
     #[test]
-    fn test_zero_timeout() {
-        let config = UpgradeConfig {
-            registry: RegistryConfig { timeout_secs: 0, ..Default::default() },
-            ..Default::default()
-        };
-        assert!(config.validate().is_err());
+    fn test_default_config_is_valid() {
+        let config = NotificationsConfig::default();
+        assert!(config.validate().is_ok());
+        assert!(config.enabled);
+        assert!(config.channels.is_empty());
+        assert_eq!(config.timeout_secs, 10);
     }
 
     #[test]
-    fn test_empty_backup_dir() {
-        let config = UpgradeConfig {
-            backup: BackupConfig { backup_dir: String::new(), ..Default::default() },
-            ..Default::default()
-        };
+    fn test_zero_timeout_is_rejected() {
+        let config = NotificationsConfig { timeout_secs: 0, ..Default::default() };
         assert!(config.validate().is_err());
     }
 
     #[test]
-    fn test_scoped_registries() {
-        let mut scoped = HashMap::new();
-        scoped.insert("myorg".to_string(), "https://npm.myorg.com".to_string());
+    fn test_channel_validation() {
+        let webhook = WebhookChannelConfig {
+            url: " ".to_string(),
+            headers: Default::default(),
+            events: vec![NotificationEvent::Release],
+            template: None,
+        };
+        assert!(
+            with_channel(NotificationChannelConfig::Slack(webhook.clone())).validate().is_err()
+        );
 
-        let config = UpgradeConfig {
-            registry: RegistryConfig { scoped_registries: scoped.clone(), ..Default::default() },
-            ..Default::default()
+        let no_events = WebhookChannelConfig {
+            url: "https://hooks.example.com".to_string(),
+            events: Vec::new(),
+            ..webhook
         };
+        assert!(with_channel(NotificationChannelConfig::Webhook(no_events)).validate().is_err());
 
-        assert!(config.validate().is_ok());
-        assert_eq!(
-            config.registry.scoped_registries.get("myorg").unwrap(),
-            "https://npm.myorg.com"
+        assert!(with_channel(NotificationChannelConfig::Email(email_channel())).validate().is_ok());
+
+        let no_recipients = EmailChannelConfig { to: Vec::new(), ..email_channel() };
+        assert!(with_channel(NotificationChannelConfig::Email(no_recipients)).validate().is_err());
+
+        let password_only = EmailChannelConfig {
+            password: Some("${SMTP_PASSWORD}".to_string()),
+            ..email_channel()
+        };
+        assert!(with_channel(NotificationChannelConfig::Email(password_only)).validate().is_err());
+
+        let cleartext_credentials = EmailChannelConfig {
+            security: SmtpSecurity::None,
+            username: Some("releases".to_string()),
+            ..email_channel()
+        };
+        assert!(
+            with_channel(NotificationChannelConfig::Email(cleartext_credentials))
+                .validate()
+                .is_err()
         );
-    }
 
-    #[test]
-    fn test_serialization() {
-        let config = UpgradeConfig::default();
-        let serialized = serde_json::to_string(&config);
-        assert!(serialized.is_ok());
-    }
+        let local_relay = EmailChannelConfig { security: SmtpSecurity::None, ..email_channel() };
+        assert!(with_channel(NotificationChannelConfig::Email(local_relay)).validate().is_ok());
 
-    #[test]
-    fn test_deserialization() {
-        let json = r#"{
-            "registry": {
-                "default_registry": "https://registry.npmjs.org",
-                "scoped_registries": {},
-                "auth_tokens": {},
-                "timeout_secs": 30,
-                "retry_attempts": 3,
-                "retry_delay_ms": 1000,
-                "read_npmrc": true
-            },
-            "auto_changeset": true,
-            "changeset_bump": "patch",
-            "backup": {
-                "enabled": true,
-                "backup_dir": ".workspace-backups",
-                "keep_after_success": false,
-                "max_backups": 5
-            }
-        }"#;
+        let injected = EmailChannelConfig {
+            to: vec!["team@example.com\r\nBcc: spam@example.com".to_string()],
+            ..email_channel()
+        };
+        assert!(with_channel(NotificationChannelConfig::Email(injected)).validate().is_err());
+
+        let bracketed =
+            EmailChannelConfig { from: "<releases@example.com>".to_string(), ..email_channel() };
+        assert!(with_channel(NotificationChannelConfig::Email(bracketed)).validate().is_err());
+    }
+
+    #[test]
+    fn test_channels_deserialize_by_type() {
+        let json = serde_json::json!({
+            "timeout_secs": 5,
+            "channels": [
+                { "type": "slack", "url": "${SLACK_WEBHOOK}", "events": ["release"] },
+                {
+                    "type": "webhook",
+                    "url": "https://deploy.example.com/hooks",
+                    "headers": { "Authorization": "Bearer ${TOKEN}" }
+                },
+                {
+                    "type": "email",
+                    "host": "smtp.example.com",
+                    "port": 465,
+                    "security": "tls",
+                    "from": "releases@example.com",
+                    "to": ["team@example.com"],
+                    "events": ["failure"]
+                }
+            ]
+        });
+
+        let config: NotificationsConfig = serde_json::from_value(json).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.timeout_secs, 5);
+        assert_eq!(config.channels.len(), 3);
+
+        assert!(matches!(
+            &config.channels[0],
+            NotificationChannelConfig::Slack(slack) if slack.events == vec![NotificationEvent::Release]
+        ));
+        assert!(matches!(
+            &config.channels[1],
+            NotificationChannelConfig::Webhook(webhook)
+                if webhook.headers["Authorization"] == "Bearer ${TOKEN}"
+                    && webhook.events.len() == 2
+        ));
+        assert!(matches!(
+            &config.channels[2],
+            NotificationChannelConfig::Email(email)
+                if email.security == SmtpSecurity::Tls
+                    && email.port == 465
+                    && email.subject == "{title}"
+        ));
+    }
+
+    #[test]
+    fn test_merge_replaces_channels() {
+        let mut base = with_channel(NotificationChannelConfig::Email(email_channel()));
+        let other = NotificationsConfig { enabled: false, ..Default::default() };
+
+        base.merge_with(other).unwrap();
 
-        let result: Result<UpgradeConfig, _> = serde_json::from_str(json);
-        assert!(result.is_ok());
+        assert!(!base.enabled);
+        assert!(base.channels.is_empty());
     }
 
     #[test]
-    fn test_merge() {
-        let mut base = UpgradeConfig::default();
-        let override_config = UpgradeConfig {
-            registry: RegistryConfig {
-                default_registry: "https://custom.registry.com".to_string(),
-                ..Default::default()
-            },
-            auto_changeset: false,
-            changeset_bump: "minor".to_string(),
-            backup: BackupConfig { max_backups: 10, ..Default::default() },
-        };
+    fn test_missing_section_deserializes_to_default() {
+        let json = serde_json::to_value(PackageToolsConfig::default()).unwrap();
+        let mut json = json.as_object().unwrap().clone();
+        json.remove("notifications");
 
-        assert!(base.merge_with(override_config).is_ok());
-        assert_eq!(base.registry.default_registry, "https://custom.registry.com");
-        assert!(!base.auto_changeset);
-        assert_eq!(base.changeset_bump, "minor");
-        assert_eq!(base.backup.max_backups, 10);
+        let config: PackageToolsConfig =
+            serde_json::from_value(serde_json::Value::Object(json)).unwrap();
+        assert_eq!(config.notifications, NotificationsConfig::default());
     }
 }
 
 // =============================================================================
-// AuditConfig Tests
+// ChangesConfig Tests
 // =============================================================================
 
-mod audit_config {
+mod changes_config {
     use super::*;
-    use crate::config::HealthScoreWeightsConfig;
+    use crate::changes::FileClass;
+    use crate::config::FilePatternsConfig;
+    use std::path::Path;
 
-    #[test]
-    fn test_default_audit_config_is_valid() {
-        let config = AuditConfig::default();
-        assert!(config.validate().is_ok());
+    fn attribution(config: &ChangesConfig, path: &str) -> Option<RootFileAttribution> {
+        config.rule_for(Path::new(path)).map(|rule| rule.attribution)
     }
 
     #[test]
-    fn test_default_values() {
+    fn test_default_config_is_valid() {
+        assert!(ChangesConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let config = ChangesConfig::default();
+        assert_eq!(attribution(&config, "yarn.lock"), Some(RootFileAttribution::Ignore));
+        assert_eq!(
+            attribution(&config, ".github/workflows/ci.yml"),
+            Some(RootFileAttribution::Ignore)
+        );
+        assert_eq!(attribution(&config, "CONTRIBUTING.md"), Some(RootFileAttribution::Ignore));
+        assert_eq!(attribution(&config, "tsconfig.base.json"), Some(RootFileAttribution::All));
+        assert_eq!(attribution(&config, "docs/guide.md"), None);
+        assert_eq!(attribution(&config, "scripts/release.sh"), None);
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let config = ChangesConfig {
+            root_files: vec![RootFileRule::all("shared/**"), RootFileRule::ignore("shared")],
+            ..ChangesConfig::default()
+        };
+        assert_eq!(attribution(&config, "shared/a/b.ts"), Some(RootFileAttribution::All));
+        assert_eq!(attribution(&config, "shared/b.ts"), Some(RootFileAttribution::All));
+        assert_eq!(attribution(&config, "shared"), Some(RootFileAttribution::Ignore));
+    }
+
+    #[test]
+    fn test_packages_attribution_validation() {
+        let valid = ChangesConfig {
+            root_files: vec![RootFileRule::packages("shared", ["a"])],
+            ..ChangesConfig::default()
+        };
+        assert!(valid.validate().is_ok());
+
+        let empty = ChangesConfig {
+            root_files: vec![RootFileRule::packages("shared", Vec::<String>::new())],
+            ..ChangesConfig::default()
+        };
+        assert!(empty.validate().is_err());
+
+        let mut listed = RootFileRule::ignore("shared");
+        listed.packages = vec!["a".to_string()];
+        assert!(
+            ChangesConfig { root_files: vec![listed], ..ChangesConfig::default() }
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_invalid_pattern_validation() {
+        for pattern in ["", "  ", "src/[.ts"] {
+            let config = ChangesConfig {
+                root_files: vec![RootFileRule::ignore(pattern)],
+                ..ChangesConfig::default()
+            };
+            assert!(config.validate().is_err(), "pattern '{pattern}' should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_default_file_patterns() {
+        let patterns = FilePatternsConfig::default();
+        let class = |path: &str| patterns.classify(Path::new(path));
+
+        assert_eq!(class("packages/core/src/index.ts"), FileClass::Source);
+        assert_eq!(class("packages/core/src/index.spec.ts"), FileClass::Test);
+        assert_eq!(class("packages/core/__tests__/api.ts"), FileClass::Test);
+        assert_eq!(class("packages/core/README.md"), FileClass::Docs);
+        assert_eq!(class("packages/core/package.json"), FileClass::Config);
+        assert_eq!(class("packages/core/tsconfig.build.json"), FileClass::Config);
+        assert_eq!(class("yarn.lock"), FileClass::Generated);
+        assert_eq!(class("packages/core/CHANGELOG.md"), FileClass::Generated);
+        assert_eq!(class("packages/core/dist/index.js"), FileClass::Generated);
+        assert_eq!(class("packages/core/assets/logo.png"), FileClass::Binary);
+    }
+
+    #[test]
+    fn test_custom_file_patterns() {
+        let json = r#"{"file_patterns": {"generated": ["**/src/generated/**"], "test": []}}"#;
+        let config: ChangesConfig = serde_json::from_str(json).unwrap();
+        let patterns = &config.file_patterns;
+
+        assert_eq!(patterns.classify(Path::new("a/src/generated/api.ts")), FileClass::Generated);
+        assert_eq!(patterns.classify(Path::new("a/src/api.test.ts")), FileClass::Source);
+        assert_eq!(patterns.classify(Path::new("yarn.lock")), FileClass::Source);
+        assert_eq!(patterns.docs, FilePatternsConfig::default().docs);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_file_pattern_validation() {
+        for pattern in ["", "src/[.ts"] {
+            let mut config = ChangesConfig::default();
+            config.file_patterns.binary.push(pattern.to_string());
+            assert!(config.validate().is_err(), "pattern '{pattern}' should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_deserialization() {
+        let json = r#"{
+            "root_files": [
+                {"pattern": "shared", "attribution": "packages", "packages": ["@myorg/core"]},
+                {"pattern": "turbo.json", "attribution": "all"}
+            ]
+        }"#;
+        let config: ChangesConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config.root_files,
+            vec![
+                RootFileRule::packages("shared", ["@myorg/core"]),
+                RootFileRule::all("turbo.json")
+            ]
+        );
+
+        let config: ChangesConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config, ChangesConfig::default());
+    }
+
+    #[test]
+    fn test_missing_section_deserializes_to_default() {
+        let json = serde_json::to_value(PackageToolsConfig::default()).unwrap();
+        let mut json = json.as_object().unwrap().clone();
+        json.remove("changes");
+
+        let config: PackageToolsConfig =
+            serde_json::from_value(serde_json::Value::Object(json)).unwrap();
+        assert_eq!(config.changes, ChangesConfig::default());
+    }
+}
+
+// =============================================================================
+// ChangelogConfig Tests
+// =============================================================================
+
+mod changelog_config {
+    use super::*;
+
+    #[test]
+    fn test_default_config_is_valid() {
+        let config = ChangelogConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_values() {
+        let config = ChangelogConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.format, ChangelogFormat::KeepAChangelog);
+        assert_eq!(config.filename, "CHANGELOG.md");
+        assert!(config.include_commit_links);
+        assert!(config.include_issue_links);
+        assert!(!config.include_authors);
+        assert!(!config.unreleased_section);
+    }
+
+    #[test]
+    fn test_empty_filename_validation() {
+        let config = ChangelogConfig { filename: String::new(), ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_changelog_concurrency_validation() {
+        assert_eq!(ChangelogConfig::default().concurrency, 8);
+        let config = ChangelogConfig { concurrency: 0, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_commit_filter_defaults() {
+        let config = ChangelogConfig::default();
+        assert!(config.exclude.types.is_empty());
+        assert!(config.include.types.is_empty());
+        assert!(config.scope_packages.is_empty());
+    }
+
+    #[test]
+    fn test_empty_scope_package_validation() {
+        let mut config = ChangelogConfig::default();
+        config.scope_packages.insert("core".to_string(), "@org/core".to_string());
+        assert!(config.validate().is_ok());
+
+        config.scope_packages.insert("docs".to_string(), "  ".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_package_changelog_overrides() {
+        let mut value = serde_json::to_value(ChangelogConfig::default()).unwrap();
+        value["create_missing"] = serde_json::json!(false);
+        value["packages"] = serde_json::json!({
+            "@org/docs": { "filename": "docs/CHANGELOG.md", "create_missing": true }
+        });
+
+        let config: ChangelogConfig = serde_json::from_value(value).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.package_filename("@org/docs"), "docs/CHANGELOG.md");
+        assert_eq!(config.package_filename("@org/core"), "CHANGELOG.md");
+        assert!(config.creates_missing(Some("@org/docs")));
+        assert!(!config.creates_missing(Some("@org/core")));
+        assert!(!config.creates_missing(None));
+    }
+
+    #[test]
+    fn test_package_changelog_filename_validation() {
+        let mut config = ChangelogConfig::default();
+        config.packages.insert(
+            "@org/docs".to_string(),
+            PackageChangelogConfig {
+                filename: Some(" ".to_string()),
+                create_missing: None,
+                tag_format: None,
+            },
+        );
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_package_tag_formats() {
+        let mut config = ChangelogConfig::default();
+        config.packages.insert(
+            "@org/app".to_string(),
+            PackageChangelogConfig {
+                tag_format: Some("{name}/v{version}".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(config.validate().is_ok());
+        assert_eq!(config.tag_format(Some("@org/app")), "{name}/v{version}");
+        assert_eq!(config.tag_format(Some("@org/core")), "{name}@{version}");
+        assert_eq!(config.tag_format(None), "v{version}");
+        assert_eq!(config.release_tag(Some("@org/app"), "1.2.0"), "@org/app/v1.2.0");
+        assert_eq!(config.release_tag(Some("@org/core"), "1.2.0"), "@org/core@1.2.0");
+        assert_eq!(config.release_tag(None, "1.2.0"), "v1.2.0");
+
+        config.packages.insert(
+            "@org/site".to_string(),
+            PackageChangelogConfig { tag_format: Some("{name}".to_string()), ..Default::default() },
+        );
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_commit_filter_deserialization() {
+        let mut value = serde_json::to_value(ChangelogConfig::default()).unwrap();
+        value["exclude"]["types"] = serde_json::json!(["chore", "ci"]);
+        value["include"] = serde_json::json!({ "types": ["feat", "fix"] });
+        value["scope_packages"] = serde_json::json!({ "core": "@org/core" });
+
+        let config: ChangelogConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(config.exclude.types, vec!["chore", "ci"]);
+        assert_eq!(config.include.types, vec!["feat", "fix"]);
+        assert_eq!(config.scope_packages.get("core").map(String::as_str), Some("@org/core"));
+    }
+
+    #[test]
+    fn test_conventional_config_default() {
+        let config = ConventionalConfig::default();
+        assert!(config.enabled);
+        assert!(config.types.contains_key("feat"));
+        assert!(config.types.contains_key("fix"));
+        assert_eq!(config.breaking_section, "Breaking Changes");
+    }
+
+    #[test]
+    fn test_format_serialization() {
+        let format = ChangelogFormat::KeepAChangelog;
+        let serialized = serde_json::to_string(&format).unwrap();
+        assert_eq!(serialized, r#""keep-a-changelog""#);
+    }
+
+    #[test]
+    fn test_monorepo_mode_serialization() {
+        let mode = MonorepoMode::PerPackage;
+        let serialized = serde_json::to_string(&mode).unwrap();
+        assert_eq!(serialized, r#""per-package""#);
+    }
+
+    #[test]
+    fn test_date_config_defaults() {
+        let config = ChangelogDateConfig::default();
+        assert_eq!(config.format, "%Y-%m-%d");
+        assert_eq!(config.timezone, "UTC");
+        assert!(config.source_date_epoch);
+
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap();
+        assert_eq!(config.format_date(&date), "2024-01-15");
+    }
+
+    #[test]
+    fn test_date_config_timezone_offset() {
+        let config = ChangelogDateConfig {
+            timezone: "+02:00".to_string(),
+            ..ChangelogDateConfig::default()
+        };
+
+        let date = Utc.with_ymd_and_hms(2024, 1, 15, 23, 30, 0).unwrap();
+        assert_eq!(config.format_date(&date), "2024-01-16");
+    }
+
+    #[test]
+    fn test_date_config_source_date_epoch() {
+        let config = ChangelogDateConfig::default();
+        let pinned = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+
+        assert_eq!(config.release_date_from(Some("1705276800")), pinned);
+        assert_ne!(config.release_date_from(Some("not-a-number")), pinned);
+
+        let ignored = ChangelogDateConfig { source_date_epoch: false, ..config };
+        assert_ne!(ignored.release_date_from(Some("1705276800")), pinned);
+    }
+
+    #[test]
+    fn test_date_config_validation() {
+        assert!(ChangelogDateConfig::default().validate().is_ok());
+
+        for timezone in ["local", "utc", "-05:30"] {
+            let config = ChangelogDateConfig {
+                timezone: timezone.to_string(),
+                ..ChangelogDateConfig::default()
+            };
+            assert!(config.validate().is_ok(), "{timezone} should be valid");
+        }
+
+        let bad_timezone = ChangelogDateConfig {
+            timezone: "Europe/Lisbon".to_string(),
+            ..ChangelogDateConfig::default()
+        };
+        assert!(bad_timezone.validate().is_err());
+
+        let bad_format =
+            ChangelogDateConfig { format: "%Y-%Q".to_string(), ..ChangelogDateConfig::default() };
+        assert!(bad_format.validate().is_err());
+    }
+
+    #[test]
+    fn test_date_config_missing_from_file() {
+        let mut value = serde_json::to_value(ChangelogConfig::default()).unwrap();
+        value.as_object_mut().unwrap().remove("date");
+
+        let config: ChangelogConfig = serde_json::from_value(value).unwrap();
+        assert_eq!(config.date, ChangelogDateConfig::default());
+    }
+}
+
+// =============================================================================
+// UpgradeConfig Tests
+// =============================================================================
+
+mod upgrade_config {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_default_upgrade_config_is_valid() {
+        let config = UpgradeConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_registry_config_is_valid() {
+        let config = RegistryConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_backup_config_is_valid() {
+        let config = BackupConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_values() {
+        let config = UpgradeConfig::default();
+        assert!(config.auto_changeset);
+        assert_eq!(config.changeset_bump, "patch");
+        assert_eq!(config.registry.default_registry, "https://registry.npmjs.org");
+        assert!(config.backup.enabled);
+    }
+
+    #[test]
+    fn test_invalid_changeset_bump() {
+        let config = UpgradeConfig { changeset_bump: "invalid".to_string(), ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_changeset_bump_by_upgrade_validation() {
+        let mut config = UpgradeConfig::default();
+        config.changeset_bump_by_upgrade.insert("major".to_string(), "minor".to_string());
+        assert!(config.validate().is_ok());
+
+        config.changeset_bump_by_upgrade.insert("prerelease".to_string(), "patch".to_string());
+        assert!(config.validate().is_err());
+
+        let mut config = UpgradeConfig::default();
+        config.changeset_bump_by_upgrade.insert("minor".to_string(), "huge".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_empty_registry_url() {
+        let config = UpgradeConfig {
+            registry: RegistryConfig { default_registry: String::new(), ..Default::default() },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_timeout() {
+        let config = UpgradeConfig {
+            registry: RegistryConfig { timeout_secs: 0, ..Default::default() },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_empty_backup_dir() {
+        let config = UpgradeConfig {
+            backup: BackupConfig { backup_dir: String::new(), ..Default::default() },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_backup_retention_limits() {
+        let config = UpgradeConfig {
+            backup: BackupConfig { max_age_days: Some(0), ..Default::default() },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = UpgradeConfig {
+            backup: BackupConfig { max_total_size_mb: Some(0), ..Default::default() },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = UpgradeConfig {
+            backup: BackupConfig {
+                max_age_days: Some(30),
+                max_total_size_mb: Some(50),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_scope_fallback() {
+        let mut config = UpgradeConfig::default();
+        config.registry.scope_fallback.insert("myorg".to_string(), "scope-first".to_string());
+        assert!(config.validate().is_ok());
+
+        config.registry.scope_fallback.insert("legacy".to_string(), "both".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_scoped_registries() {
+        let mut scoped = HashMap::new();
+        scoped.insert("myorg".to_string(), "https://npm.myorg.com".to_string());
+
+        let config = UpgradeConfig {
+            registry: RegistryConfig { scoped_registries: scoped.clone(), ..Default::default() },
+            ..Default::default()
+        };
+
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.registry.scoped_registries.get("myorg").unwrap(),
+            "https://npm.myorg.com"
+        );
+    }
+
+    #[test]
+    fn test_serialization() {
+        let config = UpgradeConfig::default();
+        let serialized = serde_json::to_string(&config);
+        assert!(serialized.is_ok());
+    }
+
+    #[test]
+    fn test_deserialization() {
+        let json = r#"{
+            "registry": {
+                "default_registry": "https://registry.npmjs.org",
+                "scoped_registries": {},
+                "auth_tokens": {},
+                "timeout_secs": 30,
+                "retry_attempts": 3,
+                "retry_delay_ms": 1000,
+                "read_npmrc": true
+            },
+            "auto_changeset": true,
+            "changeset_bump": "patch",
+            "backup": {
+                "enabled": true,
+                "backup_dir": ".workspace-backups",
+                "keep_after_success": false,
+                "max_backups": 5
+            }
+        }"#;
+
+        let result: Result<UpgradeConfig, _> = serde_json::from_str(json);
+        assert!(result.is_ok());
+        // Configurations written before abbreviated metadata existed keep using it
+        assert!(result.is_ok_and(|config| config.registry.abbreviated_metadata));
+    }
+
+    #[test]
+    fn test_registry_node_dist_url() {
+        let mut config = UpgradeConfig::default();
+        assert_eq!(config.registry.node_dist_url, "https://nodejs.org/dist");
+
+        config.registry.node_dist_url = String::new();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_registry_tls_config() {
+        let mut json = serde_json::to_value(UpgradeConfig::default()).expect("Should serialize");
+        json["registry"]["tls"] = serde_json::json!({
+            "proxy": "http://proxy.corp:8080",
+            "no_proxy": "localhost",
+            "ca_file": "certs/corp.pem",
+            "strict_ssl": false
+        });
+
+        let config: UpgradeConfig = serde_json::from_value(json).expect("Should parse tls section");
+
+        assert_eq!(config.registry.tls.proxy.as_deref(), Some("http://proxy.corp:8080"));
+        assert_eq!(config.registry.tls.no_proxy.as_deref(), Some("localhost"));
+        assert_eq!(config.registry.tls.ca_file.as_deref(), Some("certs/corp.pem"));
+        assert_eq!(config.registry.tls.strict_ssl, Some(false));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_patched_dependencies_policy_validation() {
+        let mut config = UpgradeConfig::default();
+        assert_eq!(config.patched_dependencies, "warn");
+
+        config.patched_dependencies = "confirm".to_string();
+        assert!(config.validate().is_ok());
+
+        config.patched_dependencies = "ignore".to_string();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_registry_tls_config_rejects_empty_values() {
+        let mut config = UpgradeConfig::default();
+        config.registry.tls.proxy = Some(String::new());
+        assert!(config.validate().is_err());
+
+        let mut config = UpgradeConfig::default();
+        config.registry.tls.ca_file = Some(" ".to_string());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_registry_requests_per_second_must_be_positive() {
+        let mut config = UpgradeConfig::default();
+        config.registry.requests_per_second = Some(0);
+        assert!(config.validate().is_err());
+
+        config.registry.requests_per_second = Some(20);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut base = UpgradeConfig::default();
+        let override_config = UpgradeConfig {
+            registry: RegistryConfig {
+                default_registry: "https://custom.registry.com".to_string(),
+                ..Default::default()
+            },
+            auto_changeset: false,
+            changeset_bump: "minor".to_string(),
+            changeset_bump_by_upgrade: HashMap::from([("major".to_string(), "minor".to_string())]),
+            backup: BackupConfig { max_backups: 10, ..Default::default() },
+            verify: UpgradeVerifyConfig {
+                command: Some("pnpm -r test".to_string()),
+                ..Default::default()
+            },
+            patched_dependencies: "block".to_string(),
+            detection_checkpoint_max_age_secs: 0,
+        };
+
+        assert!(base.merge_with(override_config).is_ok());
+        assert_eq!(base.patched_dependencies, "block");
+        assert_eq!(base.detection_checkpoint_max_age_secs, 0);
+        assert_eq!(base.verify.command_for("minor"), Some("pnpm -r test"));
+        assert_eq!(base.registry.default_registry, "https://custom.registry.com");
+        assert!(!base.auto_changeset);
+        assert_eq!(base.changeset_bump, "minor");
+        assert_eq!(base.bump_for_upgrade("major"), "minor");
+        assert_eq!(base.backup.max_backups, 10);
+    }
+
+    #[test]
+    fn test_verify_rule_for_upgrade_type() {
+        let mut verify = UpgradeVerifyConfig::default();
+        assert_eq!(verify.command_for("major"), None);
+
+        verify.command = Some("pnpm -r test".to_string());
+        verify.by_upgrade.insert(
+            "major".to_string(),
+            UpgradeVerifyRule {
+                command: Some("pnpm -r test && pnpm -r build".to_string()),
+                on_failure: None,
+            },
+        );
+        verify.by_upgrade.insert(
+            "patch".to_string(),
+            UpgradeVerifyRule { command: None, on_failure: Some("keep".to_string()) },
+        );
+
+        assert_eq!(verify.command_for("major"), Some("pnpm -r test && pnpm -r build"));
+        assert_eq!(verify.command_for("patch"), Some("pnpm -r test"));
+        assert_eq!(verify.on_failure_for("major"), "rollback");
+        assert_eq!(verify.on_failure_for("patch"), "keep");
+        assert!(verify.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_verify_policy_rejected() {
+        let mut verify =
+            UpgradeVerifyConfig { on_failure: "ignore".to_string(), ..Default::default() };
+        assert!(verify.validate().is_err());
+
+        verify.on_failure = "keep".to_string();
+        verify.by_upgrade.insert("breaking".to_string(), UpgradeVerifyRule::default());
+        assert!(verify.validate().is_err());
+    }
+}
+
+// =============================================================================
+// AuditConfig Tests
+// =============================================================================
+
+mod audit_config {
+    use super::*;
+    use crate::config::HealthScoreWeightsConfig;
+
+    #[test]
+    fn test_default_audit_config_is_valid() {
+        let config = AuditConfig::default();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_values() {
         let config = AuditConfig::default();
         assert!(config.enabled);
         assert_eq!(config.min_severity, "warning");
@@ -880,6 +1862,8 @@ mod audit_config {
                 check_missing: true,
                 check_unused: true,
                 check_version_conflicts: false,
+                check_collisions: false,
+                check_lockfile: false,
             },
             breaking_changes: BreakingChangesAuditConfig {
                 check_conventional_commits: false,
@@ -890,16 +1874,77 @@ mod audit_config {
                 warn_on_inconsistency: false,
             },
             health_score_weights: HealthScoreWeightsConfig::default(),
+            registry_ownership: crate::config::RegistryOwnershipAuditConfig {
+                scopes: vec!["@acme".to_string()],
+                owners: vec!["acme".to_string()],
+            },
+            concurrency: 4,
+            check_timeout_secs: 30,
+            checks: [("upgrades".to_string(), crate::config::AuditCheckConfig::default())]
+                .into_iter()
+                .collect(),
         };
 
         assert!(base.merge_with(override_config.clone()).is_ok());
         assert!(!base.enabled);
         assert_eq!(base.min_severity, "critical");
+        assert_eq!(base.concurrency, 4);
+        assert_eq!(base.check_timeout_secs, 30);
+        assert!(base.checks.contains_key("upgrades"));
         assert!(!base.sections.upgrades);
         assert!(!base.upgrades.include_major);
         assert!(base.dependencies.check_missing);
         assert!(!base.breaking_changes.check_conventional_commits);
         assert!(base.version_consistency.fail_on_inconsistency);
+        assert_eq!(base.registry_ownership.scopes, ["@acme"]);
+        assert_eq!(base.registry_ownership.owners, ["acme"]);
+    }
+
+    #[test]
+    fn test_registry_ownership_scopes_validated() {
+        let mut config = AuditConfig::default();
+        config.registry_ownership.scopes = vec!["@acme".to_string(), "tools".to_string()];
+        assert!(config.validate().is_ok());
+
+        for invalid in ["@", "@acme/core"] {
+            config.registry_ownership.scopes = vec![invalid.to_string()];
+            assert!(config.validate().is_err(), "{invalid} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_zero_concurrency_rejected() {
+        let config = AuditConfig { concurrency: 0, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_zero_check_timeout_rejected() {
+        let config = AuditConfig { check_timeout_secs: 0, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_check_overrides() {
+        use crate::config::AuditCheckConfig;
+
+        let mut config = AuditConfig::default();
+        assert!(config.is_check_enabled("upgrades"));
+
+        config.checks.insert(
+            "upgrades".to_string(),
+            AuditCheckConfig { enabled: false, severity: Some("info".to_string()) },
+        );
+        assert!(config.validate().is_ok());
+        assert!(!config.is_check_enabled("upgrades"));
+        assert_eq!(config.check_severity("upgrades"), Some("info"));
+        assert_eq!(config.check_severity("dependencies"), None);
+
+        config.checks.insert(
+            "naming".to_string(),
+            AuditCheckConfig { enabled: true, severity: Some("high".to_string()) },
+        );
+        assert!(config.validate().is_err());
     }
 
     #[test]
@@ -925,6 +1970,8 @@ mod audit_config {
                 check_missing: false,
                 check_unused: false,
                 check_version_conflicts: false,
+                check_collisions: false,
+                check_lockfile: false,
             },
             ..Default::default()
         };
@@ -958,147 +2005,599 @@ mod audit_config {
         assert!(config.validate().is_err());
     }
 
-    #[test]
-    fn test_health_score_weights_validation_negative_multiplier() {
-        use sublime_standard_tools::config::Configurable;
-        let config = HealthScoreWeightsConfig { security_multiplier: -0.5, ..Default::default() };
-        assert!(config.validate().is_err());
+    #[test]
+    fn test_health_score_weights_validation_negative_multiplier() {
+        use sublime_standard_tools::config::Configurable;
+        let config = HealthScoreWeightsConfig { security_multiplier: -0.5, ..Default::default() };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_health_score_weights_serialization() {
+        let config = HealthScoreWeightsConfig::default();
+        let serialized = serde_json::to_string(&config);
+        assert!(serialized.is_ok());
+        let json = serialized.unwrap();
+        assert!(json.contains("\"critical_weight\""));
+        assert!(json.contains("\"security_multiplier\""));
+    }
+
+    #[test]
+    fn test_health_score_weights_deserialization() {
+        let json = r#"{
+            "critical_weight": 20.0,
+            "warning_weight": 8.0,
+            "info_weight": 2.0,
+            "security_multiplier": 2.0,
+            "breaking_changes_multiplier": 1.5,
+            "dependencies_multiplier": 1.3,
+            "version_consistency_multiplier": 1.2,
+            "upgrades_multiplier": 1.0,
+            "other_multiplier": 1.1
+        }"#;
+
+        let result: Result<HealthScoreWeightsConfig, _> = serde_json::from_str(json);
+        assert!(result.is_ok());
+        let config = result.unwrap();
+        assert_eq!(config.critical_weight, 20.0);
+        assert_eq!(config.security_multiplier, 2.0);
+    }
+
+    #[test]
+    fn test_health_score_weights_merge() {
+        use sublime_standard_tools::config::Configurable;
+        let mut base = HealthScoreWeightsConfig::default();
+        let override_config = HealthScoreWeightsConfig {
+            critical_weight: 20.0,
+            warning_weight: 8.0,
+            info_weight: 2.0,
+            security_multiplier: 2.0,
+            breaking_changes_multiplier: 1.5,
+            dependencies_multiplier: 1.3,
+            version_consistency_multiplier: 1.2,
+            upgrades_multiplier: 1.0,
+            other_multiplier: 1.1,
+        };
+
+        assert!(base.merge_with(override_config.clone()).is_ok());
+        assert_eq!(base.critical_weight, 20.0);
+        assert_eq!(base.warning_weight, 8.0);
+        assert_eq!(base.security_multiplier, 2.0);
+    }
+
+    #[test]
+    fn test_health_score_weights_zero_values() {
+        use sublime_standard_tools::config::Configurable;
+        let config = HealthScoreWeightsConfig {
+            critical_weight: 0.0,
+            warning_weight: 0.0,
+            info_weight: 0.0,
+            security_multiplier: 0.0,
+            breaking_changes_multiplier: 0.0,
+            dependencies_multiplier: 0.0,
+            version_consistency_multiplier: 0.0,
+            upgrades_multiplier: 0.0,
+            other_multiplier: 0.0,
+        };
+        // Zero values should be valid (non-negative)
+        assert!(config.validate().is_ok());
+    }
+}
+
+// =============================================================================
+// Configuration Loading Tests
+// =============================================================================
+
+#[cfg(test)]
+mod loader_tests {
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::config::ConfigLoader;
+
+    #[tokio::test]
+    async fn test_load_defaults() {
+        let result = ConfigLoader::load_defaults().await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_load_from_nonexistent_files() {
+        // Should succeed with just defaults
+        let result = ConfigLoader::load_from_files(vec!["nonexistent.toml"]).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_load_from_toml_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("test-config.toml");
+
+        let toml_content = r#"
+[changeset]
+path = ".custom-changesets"
+history_path = ".custom-history"
+available_environments = ["dev", "prod"]
+default_environments = ["prod"]
+
+[version]
+strategy = "unified"
+default_bump = "minor"
+"#;
+
+        fs::write(&config_path, toml_content).unwrap();
+
+        let result = ConfigLoader::load_from_file(&config_path).await;
+        assert!(result.is_ok());
+
+        if let Ok(config) = result {
+            assert_eq!(config.changeset.path, ".custom-changesets");
+            assert_eq!(config.changeset.history_path, ".custom-history");
+            assert_eq!(config.version.default_bump, "minor");
+        }
+    }
+}
+
+// =============================================================================
+// Unified Loader Tests
+// =============================================================================
+
+#[cfg(test)]
+mod unified_loader_tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    use crate::config::ConfigLoader;
+
+    fn write_config(temp_dir: &TempDir, content: &str) -> PathBuf {
+        let path = temp_dir.path().join("repo.config.toml");
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_projects_both_shapes() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            r#"
+[changeset]
+available_environments = ["staging", "production"]
+default_environments = ["production"]
+
+[workspace]
+patterns = ["packages/*", "apps/*"]
+
+[monorepo]
+max_search_depth = 3
+"#,
+        );
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        assert!(!unified.has_conflicts());
+        assert!(unified.ensure_no_conflicts().is_ok());
+        assert_eq!(unified.package_tools.changeset.available_environments.len(), 2);
+        assert_eq!(unified.standard().monorepo.max_search_depth, 3);
+        assert_eq!(
+            unified.standard().monorepo.workspace_patterns,
+            vec!["packages/*".to_string(), "apps/*".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_projects_monorepo_patterns_into_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            r#"
+[monorepo]
+workspace_patterns = ["libs/*"]
+"#,
+        );
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        let workspace = unified.package_tools.workspace.unwrap();
+        assert_eq!(workspace.patterns, vec!["libs/*".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_reports_workspace_pattern_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            r#"
+[workspace]
+patterns = ["packages/*"]
+
+[monorepo]
+workspace_patterns = ["libs/*"]
+"#,
+        );
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        assert_eq!(unified.conflicts.len(), 1);
+        assert_eq!(unified.conflicts[0].key, "workspace.patterns");
+        assert_eq!(unified.conflicts[0].ignored_from, "monorepo.workspace_patterns");
+        assert_eq!(unified.standard().monorepo.workspace_patterns, vec!["packages/*".to_string()]);
+        assert!(unified.ensure_no_conflicts().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_ignores_pattern_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            r#"
+[workspace]
+patterns = ["packages/*", "apps/*"]
+
+[monorepo]
+workspace_patterns = ["apps/*", "packages/*"]
+"#,
+        );
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        assert!(!unified.has_conflicts());
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_merges_legacy_section() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            r#"
+[package_tools.changeset]
+path = ".legacy-changesets"
+available_environments = ["dev", "prod"]
+default_environments = ["prod"]
+
+[changeset]
+available_environments = ["qa", "prod"]
+"#,
+        );
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        assert_eq!(unified.package_tools.changeset.path, ".legacy-changesets");
+        assert_eq!(
+            unified.package_tools.changeset.available_environments,
+            vec!["qa".to_string(), "prod".to_string()]
+        );
+        assert_eq!(unified.conflicts.len(), 1);
+        assert_eq!(unified.conflicts[0].key, "changeset.available_environments");
+        assert_eq!(
+            unified.conflicts[0].ignored_from,
+            "package_tools.changeset.available_environments"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_load_unified_missing_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let result = ConfigLoader::load_unified(temp_dir.path().join("missing.toml")).await;
+        assert!(result.is_err());
+    }
+}
+
+// =============================================================================
+// Profile Tests
+// =============================================================================
+
+#[cfg(test)]
+mod profile_tests {
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    use crate::config::ConfigLoader;
+
+    const PROFILED_CONFIG: &str = r#"
+[audit]
+min_severity = "info"
+
+[upgrade.registry]
+default_registry = "https://registry.npmjs.org"
+timeout_secs = 30
+
+[changeset]
+available_environments = ["dev", "prod"]
+default_environments = ["prod"]
+
+[monorepo]
+max_search_depth = 5
+
+[profile.ci.package_tools.audit]
+min_severity = "critical"
+
+[profile.ci.upgrade.registry]
+default_registry = "https://npm.internal.example.com"
+
+[profile.ci.changeset]
+available_environments = ["ci"]
+default_environments = ["ci"]
+
+[profile.ci.monorepo]
+max_search_depth = 2
+
+[profile.release.audit]
+min_severity = "warning"
+"#;
+
+    fn write_config(temp_dir: &TempDir, name: &str, content: &str) -> PathBuf {
+        let path = temp_dir.path().join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_load_from_file_without_profile_ignores_profiles() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, "repo.config.toml", PROFILED_CONFIG);
+
+        let config = ConfigLoader::load_from_file_with_profile(&path, None).await.unwrap();
+
+        assert_eq!(config.audit.min_severity, "info");
+        assert_eq!(config.upgrade.registry.default_registry, "https://registry.npmjs.org");
+    }
+
+    #[tokio::test]
+    async fn test_load_from_file_with_profile_overlays_base() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, "repo.config.toml", PROFILED_CONFIG);
+
+        let config = ConfigLoader::load_from_file_with_profile(&path, Some("ci")).await.unwrap();
+
+        // Declared through the legacy table of the profile
+        assert_eq!(config.audit.min_severity, "critical");
+        // Tables merge key by key
+        assert_eq!(config.upgrade.registry.default_registry, "https://npm.internal.example.com");
+        assert_eq!(config.upgrade.registry.timeout_secs, 30);
+        // Arrays replace the base value
+        assert_eq!(config.changeset.available_environments, vec!["ci".to_string()]);
+
+        let release =
+            ConfigLoader::load_from_file_with_profile(&path, Some("release")).await.unwrap();
+        assert_eq!(release.audit.min_severity, "warning");
+        assert_eq!(release.changeset.available_environments.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_load_from_file_with_undeclared_profile_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, "repo.config.toml", PROFILED_CONFIG);
+
+        let error = ConfigLoader::load_from_file_with_profile(&path, Some("nightly"))
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("'nightly'"));
+        assert!(error.contains("ci, release"));
     }
 
-    #[test]
-    fn test_health_score_weights_serialization() {
-        let config = HealthScoreWeightsConfig::default();
-        let serialized = serde_json::to_string(&config);
-        assert!(serialized.is_ok());
-        let json = serialized.unwrap();
-        assert!(json.contains("\"critical_weight\""));
-        assert!(json.contains("\"security_multiplier\""));
+    #[tokio::test]
+    async fn test_load_unified_with_profile_applies_standard_sections() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, "repo.config.toml", PROFILED_CONFIG);
+
+        let unified = ConfigLoader::load_unified_with_profile(&path, Some("ci")).await.unwrap();
+
+        assert!(!unified.has_conflicts());
+        assert_eq!(unified.standard().monorepo.max_search_depth, 2);
+        assert_eq!(unified.package_tools.audit.min_severity, "critical");
+
+        let base = ConfigLoader::load_unified_with_profile(&path, None).await.unwrap();
+        assert_eq!(base.standard().monorepo.max_search_depth, 5);
     }
 
-    #[test]
-    fn test_health_score_weights_deserialization() {
-        let json = r#"{
-            "critical_weight": 20.0,
-            "warning_weight": 8.0,
-            "info_weight": 2.0,
-            "security_multiplier": 2.0,
-            "breaking_changes_multiplier": 1.5,
-            "dependencies_multiplier": 1.3,
-            "version_consistency_multiplier": 1.2,
-            "upgrades_multiplier": 1.0,
-            "other_multiplier": 1.1
-        }"#;
+    #[tokio::test]
+    async fn test_load_unified_with_profile_reports_conflicts() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            "repo.config.toml",
+            r#"
+[profile.ci.audit]
+min_severity = "critical"
+
+[profile.ci.package_tools.audit]
+min_severity = "warning"
+"#,
+        );
+        let unified = ConfigLoader::load_unified_with_profile(&path, Some("ci")).await.unwrap();
 
-        let result: Result<HealthScoreWeightsConfig, _> = serde_json::from_str(json);
-        assert!(result.is_ok());
-        let config = result.unwrap();
-        assert_eq!(config.critical_weight, 20.0);
-        assert_eq!(config.security_multiplier, 2.0);
+        assert_eq!(unified.package_tools.audit.min_severity, "critical");
+        assert_eq!(unified.conflicts.len(), 1);
+        assert_eq!(unified.conflicts[0].key, "audit.min_severity");
+        assert_eq!(unified.conflicts[0].used_from, "profile.ci.audit.min_severity");
+        assert_eq!(
+            unified.conflicts[0].ignored_from,
+            "profile.ci.package_tools.audit.min_severity"
+        );
     }
 
-    #[test]
-    fn test_health_score_weights_merge() {
-        use sublime_standard_tools::config::Configurable;
-        let mut base = HealthScoreWeightsConfig::default();
-        let override_config = HealthScoreWeightsConfig {
-            critical_weight: 20.0,
-            warning_weight: 8.0,
-            info_weight: 2.0,
-            security_multiplier: 2.0,
-            breaking_changes_multiplier: 1.5,
-            dependencies_multiplier: 1.3,
-            version_consistency_multiplier: 1.2,
-            upgrades_multiplier: 1.0,
-            other_multiplier: 1.1,
-        };
+    #[tokio::test]
+    async fn test_profile_overlay_reports_shape_conflicts() {
+        use crate::config::profile::ProfileOverlay;
+        use sublime_standard_tools::config::ConfigFormat;
 
-        assert!(base.merge_with(override_config.clone()).is_ok());
-        assert_eq!(base.critical_weight, 20.0);
-        assert_eq!(base.warning_weight, 8.0);
-        assert_eq!(base.security_multiplier, 2.0);
+        let raw = ConfigFormat::Toml
+            .parse(
+                r#"
+[changeset]
+available_environments = ["dev", "prod"]
+
+[profile.ci.changeset]
+available_environments = "ci"
+"#,
+            )
+            .unwrap();
+
+        let overlay = ProfileOverlay::from_file(&raw, "ci").unwrap();
+
+        assert_eq!(overlay.conflicts.len(), 1);
+        assert_eq!(overlay.conflicts[0].key, "changeset.available_environments");
+        assert_eq!(overlay.conflicts[0].used_from, "profile.ci.changeset.available_environments");
+        assert_eq!(overlay.conflicts[0].ignored_from, "changeset.available_environments");
+        assert!(ProfileOverlay::from_file(&raw, "release").is_none());
     }
 
-    #[test]
-    fn test_health_score_weights_zero_values() {
-        use sublime_standard_tools::config::Configurable;
-        let config = HealthScoreWeightsConfig {
-            critical_weight: 0.0,
-            warning_weight: 0.0,
-            info_weight: 0.0,
-            security_multiplier: 0.0,
-            breaking_changes_multiplier: 0.0,
-            dependencies_multiplier: 0.0,
-            version_consistency_multiplier: 0.0,
-            upgrades_multiplier: 0.0,
-            other_multiplier: 0.0,
-        };
-        // Zero values should be valid (non-negative)
-        assert!(config.validate().is_ok());
+    #[tokio::test]
+    async fn test_load_from_files_merges_profile_across_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let base = write_config(
+            &temp_dir,
+            "base.toml",
+            r#"
+[profile.ci.audit]
+min_severity = "critical"
+"#,
+        );
+        let local = write_config(
+            &temp_dir,
+            "local.toml",
+            r#"
+[profile.ci.upgrade.registry]
+default_registry = "https://npm.internal.example.com"
+"#,
+        );
+
+        let files = [base.as_path(), local.as_path()];
+        let fs = sublime_standard_tools::filesystem::FileSystemManager::new();
+        let overlay = crate::config::profile::read_profile(&fs, &files, "ci").await.unwrap();
+
+        assert!(overlay.contains_key("audit"));
+        assert!(overlay.contains_key("upgrade"));
+        assert!(crate::config::profile::read_profile(&fs, &files, "release").await.is_err());
     }
 }
 
 // =============================================================================
-// Configuration Loading Tests
+// Enhanced Validation Tests
 // =============================================================================
 
-#[cfg(test)]
-mod loader_tests {
+mod remote_config_tests {
     use std::fs;
+    use std::path::PathBuf;
     use tempfile::TempDir;
 
     use crate::config::ConfigLoader;
 
+    const REMOTE_DOCUMENT: &str = r#"
+[changeset]
+path = ".org-changesets"
+
+[version]
+default_bump = "minor"
+
+[monorepo]
+max_search_depth = 4
+"#;
+
+    fn write_config(temp_dir: &TempDir, server: &mockito::Server, remote: &str) -> PathBuf {
+        let path = temp_dir.path().join("repo.config.toml");
+        let content = format!(
+            "[remote]\nurl = \"{}/workspace.toml\"\ncache_dir = \".cache\"\n{remote}\n\
+             [changeset]\npath = \".changesets\"\n",
+            server.url()
+        );
+        fs::write(&path, content).unwrap();
+        path
+    }
+
     #[tokio::test]
-    async fn test_load_defaults() {
-        let result = ConfigLoader::load_defaults().await;
-        assert!(result.is_ok());
+    async fn test_load_from_file_merges_remote_under_file() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/workspace.toml").with_body(REMOTE_DOCUMENT).create_async().await;
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, &server, "");
+
+        let config = ConfigLoader::load_from_file(&path).await.unwrap();
+
+        assert_eq!(config.changeset.path, ".changesets");
+        assert_eq!(config.version.default_bump, "minor");
+        assert!(temp_dir.path().join(".cache").exists());
     }
 
     #[tokio::test]
-    async fn test_load_from_nonexistent_files() {
-        // Should succeed with just defaults
-        let result = ConfigLoader::load_from_files(vec!["nonexistent.toml"]).await;
-        assert!(result.is_ok());
+    async fn test_load_unified_merges_remote_standard_sections() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/workspace.toml").with_body(REMOTE_DOCUMENT).create_async().await;
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, &server, "");
+
+        let unified = ConfigLoader::load_unified(&path).await.unwrap();
+
+        assert_eq!(unified.package_tools.changeset.path, ".changesets");
+        assert_eq!(unified.package_tools.version.default_bump, "minor");
+        assert_eq!(unified.standard().monorepo.max_search_depth, 4);
     }
 
     #[tokio::test]
-    async fn test_load_from_toml_file() {
+    async fn test_remote_is_fetched_through_registry_proxy() {
+        let mut proxy = mockito::Server::new_async().await;
+        let mock = proxy
+            .mock("GET", mockito::Matcher::Any)
+            .with_body(REMOTE_DOCUMENT)
+            .create_async()
+            .await;
         let temp_dir = TempDir::new().unwrap();
-        let config_path = temp_dir.path().join("test-config.toml");
+        let path = temp_dir.path().join("repo.config.toml");
+        let content = format!(
+            "[remote]\nurl = \"http://config.invalid/workspace.toml\"\ncache_dir = \".cache\"\n\n\
+             [upgrade.registry.tls]\nproxy = \"{}\"\n",
+            proxy.url()
+        );
+        fs::write(&path, content).unwrap();
 
-        let toml_content = r#"
-[changeset]
-path = ".custom-changesets"
-history_path = ".custom-history"
-available_environments = ["dev", "prod"]
-default_environments = ["prod"]
+        let config = ConfigLoader::load_from_file(&path).await.unwrap();
 
-[version]
-strategy = "unified"
-default_bump = "minor"
-"#;
+        mock.assert_async().await;
+        assert_eq!(config.version.default_bump, "minor");
+    }
 
-        fs::write(&config_path, toml_content).unwrap();
+    #[tokio::test]
+    async fn test_unreachable_remote_is_skipped() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/workspace.toml").with_status(500).create_async().await;
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(&temp_dir, &server, "");
 
-        let result = ConfigLoader::load_from_file(&config_path).await;
-        assert!(result.is_ok());
+        let config = ConfigLoader::load_from_file(&path).await.unwrap();
 
-        if let Ok(config) = result {
-            assert_eq!(config.changeset.path, ".custom-changesets");
-            assert_eq!(config.changeset.history_path, ".custom-history");
-            assert_eq!(config.version.default_bump, "minor");
-        }
+        assert_eq!(config.changeset.path, ".changesets");
+        assert_eq!(config.version.default_bump, "patch");
     }
-}
 
-// =============================================================================
-// Enhanced Validation Tests
-// =============================================================================
+    #[tokio::test]
+    async fn test_remote_with_invalid_signature_fails_the_load() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/workspace.toml").with_body(REMOTE_DOCUMENT).create_async().await;
+        server.mock("GET", "/workspace.toml.sig").with_body("AAAA").create_async().await;
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_config(
+            &temp_dir,
+            &server,
+            "public_key = \"11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=\"",
+        );
+
+        let result = ConfigLoader::load_from_file(&path).await;
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("Signature verification failed"));
+    }
+}
 
 #[cfg(test)]
 mod validation_tests {
     use crate::config::{
-        PackageToolsConfig, validate_config, validate_path_format, validate_url_format,
+        ConfigDoc, PackageToolsConfig, validate_config, validate_config_all, validate_path_format,
+        validate_url_format,
     };
 
     #[test]
@@ -1246,6 +2745,89 @@ mod validation_tests {
         let result = validate_config(&config);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_high_max_retry_after() {
+        let mut config = PackageToolsConfig::default();
+        config.upgrade.registry.max_retry_after_secs = 7200;
+
+        let result = validate_config(&config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_zero_requests_per_second() {
+        let mut config = PackageToolsConfig::default();
+        config.upgrade.registry.requests_per_second = Some(0);
+
+        let result = validate_config(&config);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_validate_all_default_config() {
+        let config = PackageToolsConfig::default();
+        assert!(validate_config_all(&config).is_empty());
+    }
+
+    #[test]
+    fn test_validate_all_collects_every_issue() {
+        let mut config = PackageToolsConfig::default();
+        config.version.default_bump = "huge".to_string();
+        config.upgrade.registry.retry_attempts = 20;
+        config.changelog.version_tag_format = String::new();
+        config.changelog.root_tag_format = "release".to_string();
+
+        let issues = validate_config_all(&config);
+        let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+
+        assert!(paths.contains(&"version.default_bump"), "{paths:?}");
+        assert!(paths.contains(&"upgrade.registry.retry_attempts"), "{paths:?}");
+        assert!(paths.contains(&"changelog.version_tag_format"), "{paths:?}");
+        assert!(paths.contains(&"changelog.root_tag_format"), "{paths:?}");
+
+        let retries =
+            issues.iter().find(|issue| issue.path == "upgrade.registry.retry_attempts").unwrap();
+        assert_eq!(retries.value.as_deref(), Some("20"));
+        assert!(retries.doc().is_some());
+
+        // The first-error API still reports only one of them
+        assert!(validate_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_validate_all_reports_allowed_values() {
+        let mut config = PackageToolsConfig::default();
+        config.version.default_bump = "huge".to_string();
+
+        let issues = validate_config_all(&config);
+        let issue = issues.iter().find(|issue| issue.path == "version.default_bump").unwrap();
+
+        assert_eq!(issue.value.as_deref(), Some("\"huge\""));
+        assert_eq!(issue.allowed, vec!["major", "minor", "patch", "none"]);
+        assert!(issue.to_string().starts_with("version.default_bump: "));
+    }
+
+    #[test]
+    fn test_config_doc_lookup() {
+        let doc = ConfigDoc::lookup("package_tools.version.default_bump").unwrap();
+        assert_eq!(doc.key, "version.default_bump");
+
+        let doc = ConfigDoc::lookup("audit.checks.license-policy.severity").unwrap();
+        assert_eq!(doc.key, "audit.checks.*.severity");
+
+        assert!(ConfigDoc::lookup("version.unknown_key").is_none());
+    }
+
+    #[test]
+    fn test_config_doc_section() {
+        let docs = ConfigDoc::section("upgrade.registry");
+        assert!(!docs.is_empty());
+        assert!(docs.iter().all(|doc| doc.key.starts_with("upgrade.registry.")));
+
+        // Prefixes only match whole segments
+        assert!(ConfigDoc::section("upgrade.reg").is_empty());
+    }
 }
 
 // =============================================================================
@@ -1381,3 +2963,101 @@ mod workspace_config {
         assert_eq!(config1.patterns, config2.patterns);
     }
 }
+
+// =============================================================================
+// Workspace Roots Tests
+// =============================================================================
+
+mod workspace_roots {
+    use super::*;
+    use crate::config::WorkspaceRootConfig;
+
+    fn named(path: &str, name: &str) -> WorkspaceRootConfig {
+        WorkspaceRootConfig { path: path.to_string(), name: Some(name.to_string()) }
+    }
+
+    #[test]
+    fn test_roots_default_empty() {
+        let config = PackageToolsConfig::default();
+        assert!(config.roots.is_empty());
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json.get("roots").is_none());
+    }
+
+    #[test]
+    fn test_roots_deserialize() {
+        let mut json = serde_json::to_value(PackageToolsConfig::default()).unwrap();
+        json["roots"] = serde_json::json!([
+            { "path": "frontend" },
+            { "path": "services/api", "name": "api" }
+        ]);
+        let config: PackageToolsConfig = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            config.roots,
+            vec![WorkspaceRootConfig::new("frontend"), named("services/api", "api")]
+        );
+        assert_eq!(config.roots[0].display_name(), "frontend");
+        assert_eq!(config.roots[1].display_name(), "api");
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_roots_validate_empty_path() {
+        let config = PackageToolsConfig {
+            roots: vec![WorkspaceRootConfig::new("frontend"), WorkspaceRootConfig::new(" ")],
+            ..Default::default()
+        };
+
+        let error = config.validate().unwrap_err().to_string();
+        assert!(error.contains("roots[1]"));
+        assert!(error.contains("Path cannot be empty"));
+    }
+
+    #[test]
+    fn test_roots_validate_empty_name() {
+        let config =
+            PackageToolsConfig { roots: vec![named("frontend", "")], ..Default::default() };
+
+        assert!(config.validate().unwrap_err().to_string().contains("Name cannot be empty"));
+    }
+
+    #[test]
+    fn test_roots_validate_duplicate_path() {
+        let config = PackageToolsConfig {
+            roots: vec![named("frontend", "web"), named("frontend/", "app")],
+            ..Default::default()
+        };
+
+        assert!(config.validate().unwrap_err().to_string().contains("listed more than once"));
+    }
+
+    #[test]
+    fn test_roots_validate_duplicate_name() {
+        let config = PackageToolsConfig {
+            roots: vec![named("frontend", "web"), named("apps/web", "web")],
+            ..Default::default()
+        };
+
+        assert!(config.validate().unwrap_err().to_string().contains("used by another root"));
+    }
+
+    #[test]
+    fn test_roots_merge_replaces_when_set() {
+        let mut base = PackageToolsConfig {
+            roots: vec![WorkspaceRootConfig::new("frontend")],
+            ..Default::default()
+        };
+
+        base.merge_with(PackageToolsConfig::default()).unwrap();
+        assert_eq!(base.roots, vec![WorkspaceRootConfig::new("frontend")]);
+
+        let other = PackageToolsConfig {
+            roots: vec![WorkspaceRootConfig::new("backend")],
+            ..Default::default()
+        };
+        base.merge_with(other).unwrap();
+        assert_eq!(base.roots, vec![WorkspaceRootConfig::new("backend")]);
+    }
+}
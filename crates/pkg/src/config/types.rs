@@ -13,8 +13,9 @@ use serde::{Deserialize, Serialize};
 use sublime_standard_tools::config::{ConfigResult, Configurable, StandardConfig};
 
 use super::{
-    audit::AuditConfig, changelog::ChangelogConfig, changeset::ChangesetConfig,
-    dependency::DependencyConfig, git::GitConfig, upgrade::UpgradeConfig, version::VersionConfig,
+    aliases::AliasesConfig, audit::AuditConfig, changelog::ChangelogConfig, changes::ChangesConfig,
+    changeset::ChangesetConfig, dependency::DependencyConfig, git::GitConfig, hooks::HooksConfig,
+    notifications::NotificationsConfig, upgrade::UpgradeConfig, version::VersionConfig,
 };
 
 /// Main configuration structure for package tools.
@@ -78,6 +79,13 @@ use super::{
 ///
 /// [package_tools.audit]
 /// enabled = true
+///
+/// [package_tools.hooks]
+/// hooks_path = ".husky"
+///
+/// [[package_tools.changes.root_files]]
+/// pattern = "tsconfig.base.json"
+/// attribution = "all"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename = "package_tools")]
@@ -117,6 +125,31 @@ pub struct PackageToolsConfig {
     /// Settings for dependency audits and health score calculation.
     pub audit: AuditConfig,
 
+    /// Git hook management configuration.
+    ///
+    /// Hooks to install into the repository and where to install them.
+    #[serde(default)]
+    pub hooks: HooksConfig,
+
+    /// Changes analysis configuration.
+    ///
+    /// Attribution rules for changes to root and shared files outside every package.
+    #[serde(default)]
+    pub changes: ChangesConfig,
+
+    /// Import alias configuration.
+    ///
+    /// Where the tsconfig `paths` and package `imports` maps generated from the workspace
+    /// dependency graph are written.
+    #[serde(default)]
+    pub aliases: AliasesConfig,
+
+    /// Notification configuration.
+    ///
+    /// Channels that are told about releases and failed operations.
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+
     /// Workspace configuration for monorepo projects.
     ///
     /// Contains project-specific workspace patterns extracted from package.json.
@@ -128,6 +161,13 @@ pub struct PackageToolsConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workspace: Option<super::WorkspaceConfig>,
 
+    /// Workspace roots aggregated by a meta-repository.
+    ///
+    /// Empty for a regular workspace. When set, commands run with `--all-roots` operate on
+    /// each listed workspace and group their results per root.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roots: Vec<super::WorkspaceRootConfig>,
+
     /// Standard tools configuration.
     ///
     /// Configuration from sublime_standard_tools for filesystem, package managers, etc.
@@ -159,7 +199,12 @@ impl Default for PackageToolsConfig {
             changelog: ChangelogConfig::default(),
             git: GitConfig::default(),
             audit: AuditConfig::default(),
+            hooks: HooksConfig::default(),
+            changes: ChangesConfig::default(),
+            aliases: AliasesConfig::default(),
+            notifications: NotificationsConfig::default(),
             workspace: None,
+            roots: Vec::new(),
             standard_config: StandardConfig::default(),
         }
     }
@@ -194,6 +239,11 @@ impl Configurable for PackageToolsConfig {
         self.changelog.validate()?;
         self.git.validate()?;
         self.audit.validate()?;
+        self.hooks.validate()?;
+        self.changes.validate()?;
+        self.aliases.validate()?;
+        self.notifications.validate()?;
+        super::workspace::validate_roots(&self.roots)?;
 
         Ok(())
     }
@@ -232,6 +282,10 @@ impl Configurable for PackageToolsConfig {
         self.changelog.merge_with(other.changelog)?;
         self.git.merge_with(other.git)?;
         self.audit.merge_with(other.audit)?;
+        self.hooks.merge_with(other.hooks)?;
+        self.changes.merge_with(other.changes)?;
+        self.aliases.merge_with(other.aliases)?;
+        self.notifications.merge_with(other.notifications)?;
 
         // Merge workspace configuration
         if let Some(other_workspace) = other.workspace {
@@ -242,6 +296,11 @@ impl Configurable for PackageToolsConfig {
             }
         }
 
+        // Roots are replaced as a whole, like workspace patterns
+        if !other.roots.is_empty() {
+            self.roots = other.roots;
+        }
+
         self.standard_config.merge_with(other.standard_config)?;
 
         Ok(())
@@ -0,0 +1,375 @@
+//! Unified workspace configuration loading.
+//!
+//! **What**: Reads a single workspace configuration file and projects it into both the
+//! package tools configuration (`PackageToolsConfig`) and the standard tools configuration
+//! (`StandardConfig`), reporting every setting that is declared more than once with
+//! different values as a `ConfigConflict`.
+//!
+//! **How**: The file is parsed once. Standard sections (`package_managers`, `monorepo`,
+//! `commands`, `filesystem`, `validation`) feed `StandardConfig`; every other top-level section
+//! feeds `PackageToolsConfig`. A legacy `[package_tools]` table is still honoured, with
+//! top-level sections taking precedence. Workspace patterns are shared: whichever of
+//! `workspace.patterns` and `monorepo.workspace_patterns` is declared is projected into the
+//! other, and `workspace.patterns` wins when both are declared and disagree. When a profile is
+//! selected, its `[profile.<name>]` table is merged over the file's sections first. The remote
+//! configuration declared in `[remote]`, if any, is merged under the file.
+//!
+//! **Why**: Without a bridge, users maintain the same settings (workspace patterns,
+//! changeset environments, versioning) in two places and the two silently drift apart.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sublime_standard_tools::config::{
+    ConfigError, ConfigFormat, ConfigManager, ConfigResult, ConfigSource, ConfigSourcePriority,
+    ConfigValue, StandardConfig,
+};
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+use super::PackageToolsConfig;
+use super::profile::{ProfileOverlay, profile_names, undefined_profile};
+use super::remote::{REMOTE_SECTION, remote_from_file};
+
+/// Top-level sections that belong to the standard tools configuration.
+const STANDARD_SECTIONS: [&str; 5] =
+    ["package_managers", "monorepo", "commands", "filesystem", "validation"];
+
+/// Legacy table that wraps the package tools sections.
+const LEGACY_SECTION: &str = "package_tools";
+
+/// Table that holds the named configuration profiles.
+pub(crate) const PROFILES_SECTION: &str = "profile";
+
+/// A setting declared more than once with different values.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::ConfigConflict;
+///
+/// let conflict = ConfigConflict {
+///     key: "workspace.patterns".to_string(),
+///     used_from: "workspace.patterns".to_string(),
+///     used_value: r#"["packages/*"]"#.to_string(),
+///     ignored_from: "monorepo.workspace_patterns".to_string(),
+///     ignored_value: r#"["libs/*"]"#.to_string(),
+/// };
+///
+/// assert!(conflict.to_string().contains("monorepo.workspace_patterns"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfigConflict {
+    /// The setting in conflict, as a dotted key of the package tools configuration.
+    pub key: String,
+
+    /// The dotted key whose value was used.
+    pub used_from: String,
+
+    /// The value that was used, rendered as JSON.
+    pub used_value: String,
+
+    /// The dotted key whose value was ignored.
+    pub ignored_from: String,
+
+    /// The value that was ignored, rendered as JSON.
+    pub ignored_value: String,
+}
+
+impl fmt::Display for ConfigConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: using {} from '{}', ignoring {} from '{}'",
+            self.key, self.used_value, self.used_from, self.ignored_value, self.ignored_from
+        )
+    }
+}
+
+/// Package tools and standard tools configuration loaded from one workspace file.
+///
+/// The standard configuration is attached to `package_tools` and is available through
+/// [`UnifiedConfig::standard`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::config::ConfigLoader;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let unified = ConfigLoader::load_unified("repo.config.toml").await?;
+///
+/// for conflict in &unified.conflicts {
+///     eprintln!("warning: {conflict}");
+/// }
+///
+/// println!("Search patterns: {:?}", unified.standard().monorepo.workspace_patterns);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnifiedConfig {
+    /// The package tools configuration, with the standard configuration attached.
+    pub package_tools: PackageToolsConfig,
+
+    /// Settings that were declared more than once with different values.
+    pub conflicts: Vec<ConfigConflict>,
+}
+
+impl UnifiedConfig {
+    /// Returns the standard tools configuration projected from the workspace file.
+    #[must_use]
+    pub fn standard(&self) -> &StandardConfig {
+        self.package_tools.get_standard_config()
+    }
+
+    /// Returns `true` if any setting was declared more than once with different values.
+    #[must_use]
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+
+    /// Fails if any conflict was detected.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error listing every conflict.
+    pub fn ensure_no_conflicts(&self) -> ConfigResult<()> {
+        if self.conflicts.is_empty() {
+            return Ok(());
+        }
+
+        let details = self.conflicts.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ");
+        Err(ConfigError::validation(format!(
+            "{} conflicting configuration setting(s): {details}",
+            self.conflicts.len()
+        )))
+    }
+}
+
+/// Loads a workspace configuration file into both configuration shapes, with the named
+/// profile applied on top.
+pub(crate) async fn load_unified(
+    path: &Path,
+    profile: Option<&str>,
+) -> ConfigResult<UnifiedConfig> {
+    let fs = FileSystemManager::new();
+    if !fs.exists(path).await {
+        return Err(ConfigError::FileNotFound { path: path.to_path_buf() });
+    }
+    let raw = read_config_file(&fs, path).await?;
+
+    let overlay = match profile {
+        Some(name) => Some(
+            ProfileOverlay::from_file(&raw, name)
+                .ok_or_else(|| undefined_profile(name, &[path], &profile_names(&raw)))?,
+        ),
+        None => None,
+    };
+
+    let remote = remote_from_file(&fs, &raw, path).await?;
+    let (file_package_tools, file_standard, mut conflicts) = split_sections(raw, "");
+    let mut package_tools = remote.package_tools;
+    let mut standard = remote.standard;
+    merge_sections(&mut package_tools, file_package_tools);
+    merge_sections(&mut standard, file_standard);
+    if let Some(overlay) = overlay {
+        conflicts.extend(overlay.conflicts);
+        merge_sections(&mut package_tools, overlay.package_tools);
+        merge_sections(&mut standard, overlay.standard);
+    }
+    conflicts.extend(reconcile_workspace_patterns(&mut package_tools, &mut standard));
+
+    let standard_config = ConfigManager::<StandardConfig>::builder()
+        .with_defaults()
+        .with_source(ConfigSource::memory(standard, ConfigSourcePriority::Project))
+        .build(fs.clone())?
+        .load()
+        .await?;
+
+    let mut config = ConfigManager::<PackageToolsConfig>::builder()
+        .with_defaults()
+        .with_source(ConfigSource::memory(package_tools, ConfigSourcePriority::Project))
+        .with_env_prefix("SUBLIME_PKG")
+        .build(fs)?
+        .load()
+        .await?;
+    config.set_standard_config(standard_config);
+
+    Ok(UnifiedConfig { package_tools: config, conflicts })
+}
+
+/// Reads and parses a configuration file, detecting the format from its extension.
+pub(crate) async fn read_config_file<F: AsyncFileSystem>(
+    fs: &F,
+    path: &Path,
+) -> ConfigResult<ConfigValue> {
+    let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Toml);
+    let content = fs.read_file_string(path).await.map_err(|e| ConfigError::FileReadError {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    format.parse(&content)
+}
+
+/// Splits the parsed file into package tools and standard sections.
+///
+/// Sections of the legacy `[package_tools]` table are merged under the top-level sections.
+/// The `[profile]` and `[remote]` tables are left out. `prefix` is prepended to the keys of reported conflicts,
+/// for tables that are not at the root of the file.
+pub(crate) fn split_sections(
+    raw: ConfigValue,
+    prefix: &str,
+) -> (HashMap<String, ConfigValue>, HashMap<String, ConfigValue>, Vec<ConfigConflict>) {
+    let ConfigValue::Map(sections) = raw else {
+        return (HashMap::new(), HashMap::new(), Vec::new());
+    };
+
+    let mut package_tools = HashMap::new();
+    let mut standard = HashMap::new();
+    let mut legacy = None;
+
+    for (key, value) in sections {
+        if key == LEGACY_SECTION {
+            legacy = Some(value);
+        } else if key == PROFILES_SECTION || key == REMOTE_SECTION {
+            continue;
+        } else if STANDARD_SECTIONS.contains(&key.as_str()) {
+            standard.insert(key, value);
+        } else {
+            package_tools.insert(key, value);
+        }
+    }
+
+    let mut conflicts = Vec::new();
+    if let Some(ConfigValue::Map(legacy)) = legacy {
+        let mut merged = legacy;
+        for (key, value) in package_tools {
+            merge_reporting(&mut merged, key.clone(), value, &key, prefix, &mut conflicts);
+        }
+        package_tools = merged;
+    }
+    conflicts.sort_by(|a, b| a.key.cmp(&b.key));
+
+    (package_tools, standard, conflicts)
+}
+
+/// Inserts `value` under `key`, recursing into maps and recording differing leaves.
+fn merge_reporting(
+    target: &mut HashMap<String, ConfigValue>,
+    key: String,
+    value: ConfigValue,
+    path: &str,
+    prefix: &str,
+    conflicts: &mut Vec<ConfigConflict>,
+) {
+    match (target.get_mut(&key), value) {
+        (Some(ConfigValue::Map(existing)), ConfigValue::Map(incoming)) => {
+            for (child_key, child_value) in incoming {
+                let child_path = format!("{path}.{child_key}");
+                merge_reporting(existing, child_key, child_value, &child_path, prefix, conflicts);
+            }
+        }
+        (Some(existing), incoming) => {
+            if *existing != incoming {
+                conflicts.push(ConfigConflict {
+                    key: path.to_string(),
+                    used_from: format!("{prefix}{path}"),
+                    used_value: render(&incoming),
+                    ignored_from: format!("{prefix}{LEGACY_SECTION}.{path}"),
+                    ignored_value: render(existing),
+                });
+            }
+            *existing = incoming;
+        }
+        (None, incoming) => {
+            target.insert(key, incoming);
+        }
+    }
+}
+
+/// Shares workspace patterns between `workspace.patterns` and `monorepo.workspace_patterns`.
+fn reconcile_workspace_patterns(
+    package_tools: &mut HashMap<String, ConfigValue>,
+    standard: &mut HashMap<String, ConfigValue>,
+) -> Option<ConfigConflict> {
+    let declared = package_tools.get("workspace").and_then(|w| w.get("patterns")).cloned();
+    let searched = standard.get("monorepo").and_then(|m| m.get("workspace_patterns")).cloned();
+
+    match (declared, searched) {
+        (Some(declared), Some(searched)) => {
+            if same_patterns(&declared, &searched) {
+                return None;
+            }
+            let conflict = ConfigConflict {
+                key: "workspace.patterns".to_string(),
+                used_from: "workspace.patterns".to_string(),
+                used_value: render(&declared),
+                ignored_from: "monorepo.workspace_patterns".to_string(),
+                ignored_value: render(&searched),
+            };
+            set_nested(standard, "monorepo", "workspace_patterns", declared);
+            Some(conflict)
+        }
+        (Some(declared), None) => {
+            set_nested(standard, "monorepo", "workspace_patterns", declared);
+            None
+        }
+        (None, Some(searched)) => {
+            set_nested(package_tools, "workspace", "patterns", searched);
+            None
+        }
+        (None, None) => None,
+    }
+}
+
+/// Compares two pattern lists, ignoring order and duplicates.
+fn same_patterns(a: &ConfigValue, b: &ConfigValue) -> bool {
+    fn collect(value: &ConfigValue) -> Option<Vec<&str>> {
+        let mut patterns =
+            value.as_array()?.iter().map(ConfigValue::as_string).collect::<Option<Vec<_>>>()?;
+        patterns.sort_unstable();
+        patterns.dedup();
+        Some(patterns)
+    }
+
+    match (collect(a), collect(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Sets `section.key` in `sections`, creating the section when missing.
+fn set_nested(
+    sections: &mut HashMap<String, ConfigValue>,
+    section: &str,
+    key: &str,
+    value: ConfigValue,
+) {
+    let entry =
+        sections.entry(section.to_string()).or_insert_with(|| ConfigValue::Map(HashMap::new()));
+    if let ConfigValue::Map(map) = entry {
+        map.insert(key.to_string(), value);
+    }
+}
+
+/// Merges `overlay` into `sections`: tables merge key by key, other values replace.
+pub(crate) fn merge_sections(
+    sections: &mut HashMap<String, ConfigValue>,
+    overlay: HashMap<String, ConfigValue>,
+) {
+    for (key, value) in overlay {
+        match sections.get_mut(&key) {
+            Some(existing) => existing.merge(value),
+            None => {
+                sections.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Renders a configuration value as compact JSON for conflict reports.
+pub(crate) fn render(value: &ConfigValue) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{value:?}"))
+}
@@ -47,8 +47,172 @@ pub struct UpgradeConfig {
     /// # Default: `"patch"`
     pub changeset_bump: String,
 
+    /// Version bump per dependency upgrade type, overriding `changeset_bump`.
+    ///
+    /// Maps an upgrade type ("major", "minor", "patch") to a bump type. The changeset
+    /// receives the largest bump among the applied upgrades; upgrade types without an
+    /// entry use `changeset_bump`.
+    ///
+    /// # Default: empty
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub changeset_bump_by_upgrade: HashMap<String, String>,
+
     /// Backup configuration for upgrade operations.
     pub backup: BackupConfig,
+
+    /// Verification run after upgrades are applied.
+    ///
+    /// # Default: no verification
+    #[serde(default)]
+    pub verify: UpgradeVerifyConfig,
+
+    /// How to treat upgrades of dependencies with a local patch (`patches/*.patch` or pnpm
+    /// `patchedDependencies`).
+    ///
+    /// Valid values: "warn" (apply and flag them), "confirm" (apply them only when the
+    /// selection confirms patched upgrades), "block" (never apply them)
+    ///
+    /// # Default: `"warn"`
+    #[serde(default = "default_patched_dependencies")]
+    pub patched_dependencies: String,
+
+    /// How long lookups recorded by an interrupted upgrade detection are reused, in seconds.
+    ///
+    /// When a detection is run with `DetectionOptions::resume` (`upgrade check --resume`),
+    /// each registry lookup result is recorded with its time in `detection-checkpoint.json`
+    /// in the backup directory. A later detection with `resume` reuses results younger than
+    /// this instead of querying the registry again, so an interrupted scan resumes where it
+    /// stopped. The file is removed once a detection completes. `0` disables the checkpoint
+    /// even with `resume`.
+    ///
+    /// # Default: `86400` (one day)
+    #[serde(default = "default_detection_checkpoint_max_age_secs")]
+    pub detection_checkpoint_max_age_secs: u64,
+}
+
+/// Configuration for verifying applied upgrades.
+///
+/// After upgrades are written, the verification command runs in the workspace root. When it
+/// fails, the upgrades are rolled back from the backup or kept, depending on `on_failure`.
+/// The command and policy can be set per upgrade type; the entry for the largest applied
+/// upgrade type is used.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::{UpgradeVerifyConfig, UpgradeVerifyRule};
+///
+/// let mut config = UpgradeVerifyConfig {
+///     command: Some("pnpm -r test".to_string()),
+///     ..Default::default()
+/// };
+/// config.by_upgrade.insert(
+///     "patch".to_string(),
+///     UpgradeVerifyRule { command: Some(String::new()), on_failure: None },
+/// );
+///
+/// assert_eq!(config.command_for("major"), Some("pnpm -r test"));
+/// assert_eq!(config.command_for("patch"), None);
+/// assert_eq!(config.on_failure_for("major"), "rollback");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpgradeVerifyConfig {
+    /// Command run with `sh -c` after upgrades are applied.
+    ///
+    /// The names of the upgraded packages are passed, comma-separated, in the
+    /// `WORKSPACE_UPGRADED_PACKAGES` environment variable.
+    ///
+    /// # Default: `None` (no verification)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// What to do when the command fails.
+    ///
+    /// Valid values: "rollback" (restore the backup and fail), "keep" (keep the upgrades and
+    /// report the failure)
+    ///
+    /// # Default: `"rollback"`
+    #[serde(default = "default_verify_on_failure")]
+    pub on_failure: String,
+
+    /// Maximum time in seconds the command may run before it counts as failed.
+    ///
+    /// # Default: `1800`
+    #[serde(default = "default_verify_timeout_secs")]
+    pub timeout_secs: u64,
+
+    /// Command and policy per upgrade type ("major", "minor", "patch").
+    ///
+    /// Unset fields fall back to `command` and `on_failure`. An empty command disables
+    /// verification for that upgrade type.
+    ///
+    /// # Default: empty
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub by_upgrade: HashMap<String, UpgradeVerifyRule>,
+}
+
+/// Verification command and failure policy for one upgrade type.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpgradeVerifyRule {
+    /// Command replacing `verify.command`; empty to skip verification.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+
+    /// Policy replacing `verify.on_failure`.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<String>,
+}
+
+impl Default for UpgradeVerifyConfig {
+    fn default() -> Self {
+        Self {
+            command: None,
+            on_failure: default_verify_on_failure(),
+            timeout_secs: default_verify_timeout_secs(),
+            by_upgrade: HashMap::new(),
+        }
+    }
+}
+
+impl UpgradeVerifyConfig {
+    /// Returns the verification command for an upgrade type, or `None` to skip verification.
+    #[must_use]
+    pub fn command_for(&self, upgrade_type: &str) -> Option<&str> {
+        self.by_upgrade
+            .get(upgrade_type)
+            .and_then(|rule| rule.command.as_deref())
+            .or(self.command.as_deref())
+            .filter(|command| !command.trim().is_empty())
+    }
+
+    /// Returns the failure policy for an upgrade type.
+    #[must_use]
+    pub fn on_failure_for(&self, upgrade_type: &str) -> &str {
+        self.by_upgrade
+            .get(upgrade_type)
+            .and_then(|rule| rule.on_failure.as_deref())
+            .unwrap_or(&self.on_failure)
+    }
+}
+
+fn default_patched_dependencies() -> String {
+    "warn".to_string()
+}
+
+fn default_detection_checkpoint_max_age_secs() -> u64 {
+    86_400
+}
+
+fn default_verify_on_failure() -> String {
+    "rollback".to_string()
+}
+
+fn default_verify_timeout_secs() -> u64 {
+    1800
 }
 
 /// Configuration for NPM registry communication.
@@ -103,12 +267,131 @@ pub struct RegistryConfig {
     /// # Default: `1000` (1 second)
     pub retry_delay_ms: u64,
 
+    /// Longest `Retry-After` wait, in seconds, honored on HTTP 429 and 503 responses.
+    ///
+    /// Rate limited requests are retried after the wait the registry asks for, up to
+    /// `retry_attempts` times. A longer requested wait fails the request with
+    /// `RateLimited` or `ServiceUnavailable` instead.
+    ///
+    /// # Default: `60`
+    #[serde(default = "default_max_retry_after_secs")]
+    pub max_retry_after_secs: u64,
+
     /// Whether to read configuration from .npmrc files.
     ///
     /// When enabled, will merge settings from .npmrc files in the workspace.
     ///
     /// # Default: `true`
     pub read_npmrc: bool,
+
+    /// Base URL of the npm downloads API used for download statistics.
+    ///
+    /// Download statistics are only requested for packages resolved against the
+    /// default registry, so private package names are never sent to this endpoint.
+    ///
+    /// # Default: `"https://api.npmjs.org"`
+    #[serde(default = "default_downloads_api_url")]
+    pub downloads_api_url: String,
+
+    /// Base URL of the Node.js distribution site used for toolchain upgrades.
+    ///
+    /// Its `index.json` lists Node.js releases and their LTS status, which
+    /// `engines.node` and Volta `node` pins are compared against.
+    ///
+    /// # Default: `"https://nodejs.org/dist"`
+    #[serde(default = "default_node_dist_url")]
+    pub node_dist_url: String,
+
+    /// Whether to request abbreviated package documents during upgrade detection.
+    ///
+    /// Abbreviated documents (`application/vnd.npm.install-v1+json`) omit publish times and
+    /// repository information, so the full document is only fetched for dependencies that
+    /// have an upgrade available.
+    ///
+    /// # Default: `true`
+    #[serde(default = "default_abbreviated_metadata")]
+    pub abbreviated_metadata: bool,
+
+    /// Maximum number of registry requests started per second.
+    ///
+    /// Requests beyond the rate wait for their turn instead of being sent in a burst, which
+    /// keeps large scans under the request limits of corporate proxies and private
+    /// registries. Retries count towards the rate.
+    ///
+    /// # Default: `None` (no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requests_per_second: Option<u32>,
+
+    /// Proxy and TLS settings for registry requests.
+    ///
+    /// Values set here override `.npmrc` and the `HTTPS_PROXY`/`NO_PROXY` environment
+    /// variables.
+    ///
+    /// # Default: all unset
+    #[serde(default)]
+    pub tls: RegistryTlsConfig,
+
+    /// Registry fallback per scope.
+    ///
+    /// Maps scope names (without @) to the order in which registries are queried when a
+    /// package of the scope is not found:
+    /// - `"scope-first"`: the scope's registry, then the default registry
+    /// - `"default-first"`: the default registry, then the scope's registry
+    /// - `"none"`: only the scope's registry
+    ///
+    /// Useful while a scope moves between registries. Scopes without an entry use no
+    /// fallback.
+    ///
+    /// # Default: empty
+    #[serde(default)]
+    pub scope_fallback: HashMap<String, String>,
+}
+
+/// Proxy and TLS settings for registry requests.
+///
+/// Each setting is optional. Unset settings fall back to `.npmrc` (`https-proxy`, `proxy`,
+/// `noproxy`, `cafile`, `strict-ssl`) when `read_npmrc` is enabled, then to the
+/// `HTTPS_PROXY`/`HTTP_PROXY` and `NO_PROXY` environment variables.
+///
+/// # Example
+///
+/// ```toml
+/// [package_tools.upgrade.registry.tls]
+/// proxy = "http://proxy.corp.example:8080"
+/// no_proxy = "localhost,.corp.example"
+/// ca_file = "certs/corp-root.pem"
+/// strict_ssl = true
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegistryTlsConfig {
+    /// Proxy URL used for registry requests.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+
+    /// Comma-separated hosts or domains that bypass the proxy.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
+
+    /// Path to a PEM file with additional trusted CA certificates.
+    ///
+    /// Relative paths are resolved against the workspace root.
+    ///
+    /// # Default: `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_file: Option<String>,
+
+    /// Whether TLS certificates are verified.
+    ///
+    /// Disabling verification exposes registry traffic to interception and should only be
+    /// used as a last resort behind a trusted proxy.
+    ///
+    /// # Default: `None` (verify)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict_ssl: Option<bool>,
 }
 
 /// Configuration for backup and rollback operations.
@@ -153,6 +436,23 @@ pub struct BackupConfig {
     ///
     /// # Default: `5`
     pub max_backups: usize,
+
+    /// Maximum age of a backup in days.
+    ///
+    /// Older backups are deleted during cleanup. The most recent backup is always kept.
+    ///
+    /// # Default: `None` (no age limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+
+    /// Maximum total size of all backups in megabytes.
+    ///
+    /// The oldest backups are deleted during cleanup until the total fits. The most recent
+    /// backup is always kept.
+    ///
+    /// # Default: `None` (no size limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_size_mb: Option<u64>,
 }
 
 impl Default for UpgradeConfig {
@@ -161,11 +461,35 @@ impl Default for UpgradeConfig {
             registry: RegistryConfig::default(),
             auto_changeset: true,
             changeset_bump: "patch".to_string(),
+            changeset_bump_by_upgrade: HashMap::new(),
             backup: BackupConfig::default(),
+            verify: UpgradeVerifyConfig::default(),
+            patched_dependencies: default_patched_dependencies(),
+            detection_checkpoint_max_age_secs: default_detection_checkpoint_max_age_secs(),
         }
     }
 }
 
+impl UpgradeConfig {
+    /// Returns the changeset bump type for a dependency upgrade type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::UpgradeConfig;
+    ///
+    /// let mut config = UpgradeConfig::default();
+    /// config.changeset_bump_by_upgrade.insert("major".to_string(), "minor".to_string());
+    ///
+    /// assert_eq!(config.bump_for_upgrade("major"), "minor");
+    /// assert_eq!(config.bump_for_upgrade("patch"), "patch");
+    /// ```
+    #[must_use]
+    pub fn bump_for_upgrade(&self, upgrade_type: &str) -> &str {
+        self.changeset_bump_by_upgrade.get(upgrade_type).unwrap_or(&self.changeset_bump)
+    }
+}
+
 impl Default for RegistryConfig {
     fn default() -> Self {
         Self {
@@ -175,11 +499,62 @@ impl Default for RegistryConfig {
             timeout_secs: 30,
             retry_attempts: 3,
             retry_delay_ms: 1000,
+            max_retry_after_secs: default_max_retry_after_secs(),
             read_npmrc: true,
+            downloads_api_url: default_downloads_api_url(),
+            node_dist_url: default_node_dist_url(),
+            abbreviated_metadata: default_abbreviated_metadata(),
+            requests_per_second: None,
+            tls: RegistryTlsConfig::default(),
+            scope_fallback: HashMap::new(),
         }
     }
 }
 
+impl RegistryConfig {
+    /// Returns the fallback order configured for the scope of a package.
+    ///
+    /// Returns `None` for unscoped packages and scopes without a fallback entry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::RegistryConfig;
+    ///
+    /// let mut config = RegistryConfig::default();
+    /// config.scope_fallback.insert("myorg".to_string(), "scope-first".to_string());
+    ///
+    /// assert_eq!(config.fallback_for("@myorg/utils"), Some("scope-first"));
+    /// assert_eq!(config.fallback_for("@other/utils"), None);
+    /// assert_eq!(config.fallback_for("lodash"), None);
+    /// ```
+    #[must_use]
+    pub fn fallback_for(&self, package_name: &str) -> Option<&str> {
+        let scope = package_name.strip_prefix('@')?.split_once('/')?.0;
+        self.scope_fallback.get(scope).map(String::as_str)
+    }
+}
+
+/// Returns the default longest `Retry-After` wait honored, in seconds.
+fn default_max_retry_after_secs() -> u64 {
+    60
+}
+
+/// Returns the default npm downloads API URL.
+fn default_downloads_api_url() -> String {
+    "https://api.npmjs.org".to_string()
+}
+
+/// Returns the default Node.js distribution URL.
+fn default_node_dist_url() -> String {
+    "https://nodejs.org/dist".to_string()
+}
+
+/// Returns whether abbreviated package documents are requested by default.
+fn default_abbreviated_metadata() -> bool {
+    true
+}
+
 impl Default for BackupConfig {
     fn default() -> Self {
         Self {
@@ -187,6 +562,8 @@ impl Default for BackupConfig {
             backup_dir: ".workspace-backups".to_string(),
             keep_after_success: false,
             max_backups: 5,
+            max_age_days: None,
+            max_total_size_mb: None,
         }
     }
 }
@@ -206,8 +583,35 @@ impl Configurable for UpgradeConfig {
             }
         }
 
+        for (upgrade_type, bump) in &self.changeset_bump_by_upgrade {
+            if !matches!(upgrade_type.as_str(), "major" | "minor" | "patch") {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "upgrade.changeset_bump_by_upgrade: Invalid upgrade type '{upgrade_type}'. Must be one of: major, minor, patch"
+                    ),
+                });
+            }
+            if !matches!(bump.as_str(), "major" | "minor" | "patch" | "none") {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "upgrade.changeset_bump_by_upgrade.{upgrade_type}: Invalid bump type '{bump}'. Must be one of: major, minor, patch, none"
+                    ),
+                });
+            }
+        }
+
+        if !matches!(self.patched_dependencies.as_str(), "warn" | "confirm" | "block") {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!(
+                    "upgrade.patched_dependencies: Invalid policy '{}'. Must be one of: warn, confirm, block",
+                    self.patched_dependencies
+                ),
+            });
+        }
+
         self.registry.validate()?;
         self.backup.validate()?;
+        self.verify.validate()?;
         Ok(())
     }
 
@@ -215,7 +619,57 @@ impl Configurable for UpgradeConfig {
         self.registry.merge_with(other.registry)?;
         self.auto_changeset = other.auto_changeset;
         self.changeset_bump = other.changeset_bump;
+        self.changeset_bump_by_upgrade = other.changeset_bump_by_upgrade;
         self.backup.merge_with(other.backup)?;
+        self.verify.merge_with(other.verify)?;
+        self.patched_dependencies = other.patched_dependencies;
+        self.detection_checkpoint_max_age_secs = other.detection_checkpoint_max_age_secs;
+        Ok(())
+    }
+}
+
+impl Configurable for UpgradeVerifyConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        let policies = std::iter::once(("upgrade.verify.on_failure".to_string(), &self.on_failure))
+            .chain(self.by_upgrade.iter().filter_map(|(upgrade_type, rule)| {
+                rule.on_failure.as_ref().map(|policy| {
+                    (format!("upgrade.verify.by_upgrade.{upgrade_type}.on_failure"), policy)
+                })
+            }));
+        for (key, policy) in policies {
+            if !matches!(policy.as_str(), "rollback" | "keep") {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "{key}: Invalid policy '{policy}'. Must be one of: rollback, keep"
+                    ),
+                });
+            }
+        }
+
+        if let Some(upgrade_type) =
+            self.by_upgrade.keys().find(|key| !matches!(key.as_str(), "major" | "minor" | "patch"))
+        {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!(
+                    "upgrade.verify.by_upgrade: Invalid upgrade type '{upgrade_type}'. Must be one of: major, minor, patch"
+                ),
+            });
+        }
+
+        if self.timeout_secs == 0 {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.verify.timeout_secs: Timeout must be greater than 0".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        self.command = other.command;
+        self.on_failure = other.on_failure;
+        self.timeout_secs = other.timeout_secs;
+        self.by_upgrade = other.by_upgrade;
         Ok(())
     }
 }
@@ -236,6 +690,49 @@ impl Configurable for RegistryConfig {
             });
         }
 
+        if self.downloads_api_url.is_empty() {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.registry.downloads_api_url: Downloads API URL cannot be empty"
+                    .to_string(),
+            });
+        }
+
+        if self.node_dist_url.is_empty() {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.registry.node_dist_url: Node.js distribution URL cannot be empty"
+                    .to_string(),
+            });
+        }
+
+        if self.requests_per_second == Some(0) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.registry.requests_per_second: Rate must be greater than 0"
+                    .to_string(),
+            });
+        }
+
+        if self.tls.proxy.as_ref().is_some_and(|proxy| proxy.trim().is_empty()) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.registry.tls.proxy: Proxy URL cannot be empty".to_string(),
+            });
+        }
+
+        if self.tls.ca_file.as_ref().is_some_and(|path| path.trim().is_empty()) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.registry.tls.ca_file: CA file path cannot be empty".to_string(),
+            });
+        }
+
+        for (scope, order) in &self.scope_fallback {
+            if !["scope-first", "default-first", "none"].contains(&order.as_str()) {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!(
+                        "upgrade.registry.scope_fallback.{scope}: Invalid order '{order}'. Must be one of: scope-first, default-first, none"
+                    ),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -246,7 +743,14 @@ impl Configurable for RegistryConfig {
         self.timeout_secs = other.timeout_secs;
         self.retry_attempts = other.retry_attempts;
         self.retry_delay_ms = other.retry_delay_ms;
+        self.max_retry_after_secs = other.max_retry_after_secs;
         self.read_npmrc = other.read_npmrc;
+        self.downloads_api_url = other.downloads_api_url;
+        self.node_dist_url = other.node_dist_url;
+        self.abbreviated_metadata = other.abbreviated_metadata;
+        self.requests_per_second = other.requests_per_second;
+        self.tls = other.tls;
+        self.scope_fallback = other.scope_fallback;
         Ok(())
     }
 }
@@ -259,6 +763,18 @@ impl Configurable for BackupConfig {
             });
         }
 
+        if self.max_age_days == Some(0) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.backup.max_age_days: Must be greater than 0".to_string(),
+            });
+        }
+
+        if self.max_total_size_mb == Some(0) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "upgrade.backup.max_total_size_mb: Must be greater than 0".to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -267,6 +783,8 @@ impl Configurable for BackupConfig {
         self.backup_dir = other.backup_dir;
         self.keep_after_success = other.keep_after_success;
         self.max_backups = other.max_backups;
+        self.max_age_days = other.max_age_days;
+        self.max_total_size_mb = other.max_total_size_mb;
         Ok(())
     }
 }
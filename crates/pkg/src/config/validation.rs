@@ -9,13 +9,101 @@
 //!
 //! **Why**: To ensure configuration is valid before use, preventing runtime errors and
 //! providing clear, actionable error messages when configuration is invalid.
+//!
+//! `validate_config` stops at the first error. `validate_config_all` runs every rule and
+//! returns each failure as a `ConfigValidationIssue` with its path, the received value and the
+//! values the key accepts, so a user can fix every problem in one pass.
 
 use std::collections::HashSet;
 use std::path::Path;
 
+use serde::Serialize;
 use sublime_standard_tools::config::{ConfigError, ConfigResult, Configurable};
 
-use super::PackageToolsConfig;
+use super::{ConfigDoc, PackageToolsConfig};
+
+/// A validation rule the configuration does not satisfy.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::{PackageToolsConfig, validate_config_all};
+///
+/// let mut config = PackageToolsConfig::default();
+/// config.version.default_bump = "huge".to_string();
+///
+/// let issues = validate_config_all(&config);
+/// assert_eq!(issues[0].path, "version.default_bump");
+/// assert_eq!(issues[0].value.as_deref(), Some("\"huge\""));
+/// assert!(issues[0].allowed.contains(&"minor".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigValidationIssue {
+    /// Dotted path of the offending key inside `[package_tools]`, or the section when the rule
+    /// spans several keys. Empty if the rule names no key.
+    pub path: String,
+
+    /// What is wrong, without the path.
+    pub message: String,
+
+    /// The received value, as TOML-like JSON, if the path names a single value.
+    pub value: Option<String>,
+
+    /// Values the key accepts, from its `ConfigDoc`.
+    pub allowed: Vec<String>,
+}
+
+impl ConfigValidationIssue {
+    /// Returns the documentation of the offending key, if it is documented.
+    #[must_use]
+    pub fn doc(&self) -> Option<&'static ConfigDoc> {
+        ConfigDoc::lookup(&self.path)
+    }
+
+    /// Builds an issue from a `path: message` validation message.
+    fn from_message(message: &str, values: &serde_json::Value) -> Self {
+        let (path, message) = match message.split_once(": ") {
+            Some((path, rest)) if !path.is_empty() && !path.contains(char::is_whitespace) => {
+                (path.to_string(), rest.to_string())
+            }
+            _ => (String::new(), message.to_string()),
+        };
+
+        let value = (!path.is_empty())
+            .then(|| path.split('.').try_fold(values, |value, segment| value.get(segment)))
+            .flatten()
+            .filter(|value| !value.is_object())
+            .map(ToString::to_string);
+        let allowed = ConfigDoc::lookup(&path)
+            .map(|doc| doc.allowed.iter().map(ToString::to_string).collect())
+            .unwrap_or_default();
+
+        Self { path, message, value, allowed }
+    }
+}
+
+impl std::fmt::Display for ConfigValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/// Validation messages collected by the rules, each of the form `path: message`.
+#[derive(Debug, Default)]
+struct Issues {
+    messages: Vec<String>,
+}
+
+impl Issues {
+    fn push(&mut self, message: impl Into<String>) {
+        self.messages.push(message.into());
+    }
+}
 
 /// Validates a configuration structure with enhanced error messages.
 ///
@@ -58,54 +146,118 @@ pub fn validate_config(config: &PackageToolsConfig) -> ConfigResult<()> {
     config.validate()?;
 
     // Additional validation
-    validate_changeset_config(config)?;
-    validate_version_config(config)?;
-    validate_dependency_config(config)?;
-    validate_upgrade_config(config)?;
-    validate_changelog_config(config)?;
-    validate_git_config(config)?;
-    validate_audit_config(config)?;
+    match extended_issues(config).messages.into_iter().next() {
+        Some(message) => Err(ConfigError::validation(message)),
+        None => Ok(()),
+    }
+}
 
-    Ok(())
+/// Runs every validation rule and returns all the failures.
+///
+/// Unlike `validate_config`, this does not stop at the first error. The basic validation of
+/// each section reports at most one issue per section; the additional rules report every
+/// failure. Identical issues are reported once.
+///
+/// # Arguments
+///
+/// * `config` - The configuration to validate
+///
+/// # Returns
+///
+/// The issues in section order; empty if the configuration is valid.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::{PackageToolsConfig, validate_config_all};
+///
+/// let config = PackageToolsConfig::default();
+/// assert!(validate_config_all(&config).is_empty());
+/// ```
+#[must_use]
+pub fn validate_config_all(config: &PackageToolsConfig) -> Vec<ConfigValidationIssue> {
+    let basic = [
+        config.changeset.validate(),
+        config.version.validate(),
+        config.dependency.validate(),
+        config.upgrade.validate(),
+        config.changelog.validate(),
+        config.git.validate(),
+        config.audit.validate(),
+        config.hooks.validate(),
+        config.changes.validate(),
+        config.aliases.validate(),
+        config.notifications.validate(),
+        super::workspace::validate_roots(&config.roots),
+    ];
+
+    let mut messages: Vec<String> = basic
+        .into_iter()
+        .filter_map(Result::err)
+        .map(|error| match error {
+            ConfigError::ValidationError { message } => message,
+            other => other.to_string(),
+        })
+        .collect();
+    messages.extend(extended_issues(config).messages);
+
+    let values = serde_json::to_value(config).unwrap_or_default();
+    let mut seen = HashSet::new();
+    messages
+        .iter()
+        .filter(|message| seen.insert(message.as_str()))
+        .map(|message| ConfigValidationIssue::from_message(message, &values))
+        .collect()
+}
+
+/// Runs the rules beyond the basic `Configurable::validate()` of each section.
+fn extended_issues(config: &PackageToolsConfig) -> Issues {
+    let mut issues = Issues::default();
+    validate_changeset_config(config, &mut issues);
+    validate_version_config(config, &mut issues);
+    validate_dependency_config(config, &mut issues);
+    validate_upgrade_config(config, &mut issues);
+    validate_changelog_config(config, &mut issues);
+    validate_git_config(config, &mut issues);
+    validate_audit_config(config, &mut issues);
+    issues
 }
 
 /// Validates changeset configuration.
-fn validate_changeset_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_changeset_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let changeset = &config.changeset;
 
     // Validate path format
     if changeset.path.contains("..") {
-        return Err(ConfigError::validation(
+        issues.push(
             "changeset.path: Path should not contain '..' (parent directory references). Use absolute or simple relative paths.",
-        ));
+        );
     }
 
     if changeset.history_path.contains("..") {
-        return Err(ConfigError::validation(
+        issues.push(
             "changeset.history_path: Path should not contain '..' (parent directory references). Use absolute or simple relative paths.",
-        ));
+        );
     }
 
     // Validate that history path is different from changeset path
     if changeset.path == changeset.history_path {
-        return Err(ConfigError::validation(
+        issues.push(
             "changeset.history_path: History path must be different from changeset path to avoid conflicts.",
-        ));
+        );
     }
 
     // Validate environment names
     for env in &changeset.available_environments {
         if env.is_empty() {
-            return Err(ConfigError::validation(
-                "changeset.available_environments: Environment names cannot be empty.",
-            ));
+            issues.push("changeset.available_environments: Environment names cannot be empty.");
         }
 
         if env.contains(char::is_whitespace) {
-            return Err(ConfigError::validation(format!(
+            issues.push(format!(
                 "changeset.available_environments: Environment name '{}' contains whitespace. Use kebab-case or underscores instead.",
                 env
-            )));
+            ));
         }
     }
 
@@ -113,28 +265,26 @@ fn validate_changeset_config(config: &PackageToolsConfig) -> ConfigResult<()> {
     let mut seen = HashSet::new();
     for env in &changeset.available_environments {
         if !seen.insert(env) {
-            return Err(ConfigError::validation(format!(
+            issues.push(format!(
                 "changeset.available_environments: Duplicate environment name '{}'. Each environment must be unique.",
                 env
-            )));
+            ));
         }
     }
-
-    Ok(())
 }
 
 /// Validates version configuration.
-fn validate_version_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_version_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let version = &config.version;
 
     // Validate default_bump
     let valid_bumps = ["major", "minor", "patch", "none"];
     if !valid_bumps.contains(&version.default_bump.as_str()) {
-        return Err(ConfigError::validation(format!(
+        issues.push(format!(
             "version.default_bump: Invalid bump type '{}'. Must be one of: {}",
             version.default_bump,
             valid_bumps.join(", ")
-        )));
+        ));
     }
 
     // Validate strategy - VersioningStrategy is an enum, so we check it directly
@@ -142,39 +292,42 @@ fn validate_version_config(config: &PackageToolsConfig) -> ConfigResult<()> {
 
     // Validate snapshot format if present
     if !version.snapshot_format.contains("{version}") {
-        return Err(ConfigError::validation(
-            "version.snapshot_format: Format must contain '{version}' placeholder.",
-        ));
+        issues.push("version.snapshot_format: Format must contain '{version}' placeholder.");
     }
 
-    Ok(())
+    if version.packages.keys().any(|name| name.trim().is_empty()) {
+        issues.push("version.packages: Package name cannot be empty.");
+    }
+
+    if let Err(e) = version.calver_filter() {
+        issues.push(format!("version.calver_packages: {e}"));
+    }
 }
 
 /// Validates dependency configuration.
-fn validate_dependency_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_dependency_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let dependency = &config.dependency;
 
     // Validate propagation_bump
     let valid_bumps = ["major", "minor", "patch", "none"];
     if !valid_bumps.contains(&dependency.propagation_bump.as_str()) {
-        return Err(ConfigError::validation(format!(
+        issues.push(format!(
             "dependency.propagation_bump: Invalid bump type '{}'. Must be one of: {}",
             dependency.propagation_bump,
             valid_bumps.join(", ")
-        )));
+        ));
     }
 
     // Validate max_depth
     if dependency.max_depth == 0 {
-        return Err(ConfigError::validation(
-            "dependency.max_depth: Must be greater than 0. Use a reasonable value like 10.",
-        ));
+        issues
+            .push("dependency.max_depth: Must be greater than 0. Use a reasonable value like 10.");
     }
 
     if dependency.max_depth > 100 {
-        return Err(ConfigError::validation(
+        issues.push(
             "dependency.max_depth: Value is very high (>100), which may cause performance issues. Consider a lower value like 10.",
-        ));
+        );
     }
 
     // Validate that at least one propagation is enabled
@@ -182,27 +335,25 @@ fn validate_dependency_config(config: &PackageToolsConfig) -> ConfigResult<()> {
         && !dependency.propagate_dev_dependencies
         && !dependency.propagate_peer_dependencies
     {
-        return Err(ConfigError::validation(
+        issues.push(
             "dependency: At least one of propagate_dependencies, propagate_dev_dependencies, or propagate_peer_dependencies must be true.",
-        ));
+        );
     }
-
-    Ok(())
 }
 
 /// Validates upgrade configuration.
-fn validate_upgrade_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_upgrade_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let upgrade = &config.upgrade;
 
     // Validate changeset_bump if auto_changeset is enabled
     if upgrade.auto_changeset {
         let valid_bumps = ["major", "minor", "patch", "none"];
         if !valid_bumps.contains(&upgrade.changeset_bump.as_str()) {
-            return Err(ConfigError::validation(format!(
+            issues.push(format!(
                 "upgrade.changeset_bump: Invalid bump type '{}'. Must be one of: {}",
                 upgrade.changeset_bump,
                 valid_bumps.join(", ")
-            )));
+            ));
         }
     }
 
@@ -210,41 +361,52 @@ fn validate_upgrade_config(config: &PackageToolsConfig) -> ConfigResult<()> {
     let registry = &upgrade.registry;
 
     if registry.default_registry.is_empty() {
-        return Err(ConfigError::validation(
-            "upgrade.registry.default_registry: Registry URL cannot be empty.",
-        ));
-    }
-
-    if !registry.default_registry.starts_with("http://")
+        issues.push("upgrade.registry.default_registry: Registry URL cannot be empty.");
+    } else if !registry.default_registry.starts_with("http://")
         && !registry.default_registry.starts_with("https://")
     {
-        return Err(ConfigError::validation(
+        issues.push(
             "upgrade.registry.default_registry: Registry URL must start with http:// or https://",
-        ));
+        );
     }
 
     if registry.timeout_secs == 0 {
-        return Err(ConfigError::validation(
-            "upgrade.registry.timeout_secs: Timeout must be greater than 0.",
-        ));
+        issues.push("upgrade.registry.timeout_secs: Timeout must be greater than 0.");
     }
 
     if registry.timeout_secs > 300 {
-        return Err(ConfigError::validation(
+        issues.push(
             "upgrade.registry.timeout_secs: Timeout is very high (>300s). Consider a lower value like 30.",
-        ));
+        );
     }
 
     if registry.retry_attempts > 10 {
-        return Err(ConfigError::validation(
+        issues.push(
             "upgrade.registry.retry_attempts: Too many retry attempts (>10). Consider a lower value like 3.",
-        ));
+        );
+    }
+
+    if registry.max_retry_after_secs > 3600 {
+        issues.push(
+            "upgrade.registry.max_retry_after_secs: Wait is very high (>3600s). Consider a lower value like 60.",
+        );
     }
 
     if registry.retry_delay_ms == 0 {
-        return Err(ConfigError::validation(
-            "upgrade.registry.retry_delay_ms: Retry delay must be greater than 0.",
-        ));
+        issues.push("upgrade.registry.retry_delay_ms: Retry delay must be greater than 0.");
+    }
+
+    if registry.requests_per_second == Some(0) {
+        issues.push("upgrade.registry.requests_per_second: Rate must be greater than 0.");
+    }
+
+    for (scope, order) in &registry.scope_fallback {
+        if !["scope-first", "default-first", "none"].contains(&order.as_str()) {
+            issues.push(format!(
+                "upgrade.registry.scope_fallback.{}: Invalid order '{}'. Must be one of: scope-first, default-first, none",
+                scope, order
+            ));
+        }
     }
 
     // Validate backup configuration
@@ -252,36 +414,75 @@ fn validate_upgrade_config(config: &PackageToolsConfig) -> ConfigResult<()> {
 
     if backup.enabled {
         if backup.backup_dir.is_empty() {
-            return Err(ConfigError::validation(
+            issues.push(
                 "upgrade.backup.backup_dir: Backup directory cannot be empty when backup is enabled.",
-            ));
+            );
         }
 
         if backup.max_backups == 0 {
-            return Err(ConfigError::validation(
-                "upgrade.backup.max_backups: Must be greater than 0 when backup is enabled.",
-            ));
+            issues
+                .push("upgrade.backup.max_backups: Must be greater than 0 when backup is enabled.");
         }
 
         if backup.max_backups > 100 {
-            return Err(ConfigError::validation(
+            issues.push(
                 "upgrade.backup.max_backups: Too many backups (>100). Consider a lower value like 10.",
+            );
+        }
+
+        if backup.max_age_days == Some(0) {
+            issues.push("upgrade.backup.max_age_days: Must be greater than 0 when set.");
+        }
+
+        if backup.max_total_size_mb == Some(0) {
+            issues.push("upgrade.backup.max_total_size_mb: Must be greater than 0 when set.");
+        }
+    }
+
+    // Validate verification configuration
+    let verify = &upgrade.verify;
+    let valid_policies = ["rollback", "keep"];
+
+    if !valid_policies.contains(&verify.on_failure.as_str()) {
+        issues.push(format!(
+            "upgrade.verify.on_failure: Invalid policy '{}'. Must be one of: {}",
+            verify.on_failure,
+            valid_policies.join(", ")
+        ));
+    }
+
+    for (upgrade_type, rule) in &verify.by_upgrade {
+        if !["major", "minor", "patch"].contains(&upgrade_type.as_str()) {
+            issues.push(format!(
+                "upgrade.verify.by_upgrade: Invalid upgrade type '{}'. Must be one of: major, minor, patch",
+                upgrade_type
+            ));
+        }
+
+        if let Some(policy) = &rule.on_failure
+            && !valid_policies.contains(&policy.as_str())
+        {
+            issues.push(format!(
+                "upgrade.verify.by_upgrade.{}.on_failure: Invalid policy '{}'. Must be one of: {}",
+                upgrade_type,
+                policy,
+                valid_policies.join(", ")
             ));
         }
     }
 
-    Ok(())
+    if verify.timeout_secs == 0 {
+        issues.push("upgrade.verify.timeout_secs: Timeout must be greater than 0.");
+    }
 }
 
 /// Validates changelog configuration.
-fn validate_changelog_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_changelog_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let changelog = &config.changelog;
 
     if changelog.enabled {
         if changelog.filename.is_empty() {
-            return Err(ConfigError::validation(
-                "changelog.filename: Filename cannot be empty when changelog is enabled.",
-            ));
+            issues.push("changelog.filename: Filename cannot be empty when changelog is enabled.");
         }
 
         // Validate repository URL if commit links are enabled
@@ -289,78 +490,70 @@ fn validate_changelog_config(config: &PackageToolsConfig) -> ConfigResult<()> {
             && let Some(ref url) = changelog.repository_url
         {
             if url.is_empty() {
-                return Err(ConfigError::validation(
+                issues.push(
                     "changelog.repository_url: URL cannot be empty when include_commit_links is true.",
-                ));
-            }
-
-            if !url.starts_with("http://") && !url.starts_with("https://") {
-                return Err(ConfigError::validation(
-                    "changelog.repository_url: URL must start with http:// or https://",
-                ));
+                );
+            } else if !url.starts_with("http://") && !url.starts_with("https://") {
+                issues.push("changelog.repository_url: URL must start with http:// or https://");
             }
         }
         // Note: repository_url being None is allowed - it will be detected at runtime when actually generating changelogs
 
         // Validate tag formats
         if changelog.version_tag_format.is_empty() {
-            return Err(ConfigError::validation(
-                "changelog.version_tag_format: Tag format cannot be empty.",
-            ));
+            issues.push("changelog.version_tag_format: Tag format cannot be empty.");
+        } else if !changelog.version_tag_format.contains("{version}") {
+            issues
+                .push("changelog.version_tag_format: Format must contain '{version}' placeholder.");
         }
 
-        if !changelog.version_tag_format.contains("{version}") {
-            return Err(ConfigError::validation(
-                "changelog.version_tag_format: Format must contain '{version}' placeholder.",
-            ));
+        if changelog.root_tag_format.is_empty() {
+            issues.push("changelog.root_tag_format: Tag format cannot be empty.");
+        } else if !changelog.root_tag_format.contains("{version}") {
+            issues.push("changelog.root_tag_format: Format must contain '{version}' placeholder.");
         }
 
-        if changelog.root_tag_format.is_empty() {
-            return Err(ConfigError::validation(
-                "changelog.root_tag_format: Tag format cannot be empty.",
-            ));
+        for (package, overrides) in &changelog.packages {
+            if let Some(format) = &overrides.tag_format
+                && !format.contains("{version}")
+            {
+                issues.push(format!(
+                    "changelog.packages.{package}.tag_format: Format must contain '{{version}}' \
+                     placeholder."
+                ));
+            }
         }
 
-        if !changelog.root_tag_format.contains("{version}") {
-            return Err(ConfigError::validation(
-                "changelog.root_tag_format: Format must contain '{version}' placeholder.",
-            ));
+        if changelog.concurrency == 0 {
+            issues.push("changelog.concurrency: Concurrency must be greater than 0.");
         }
     }
-
-    Ok(())
 }
 
 /// Validates git configuration.
-fn validate_git_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_git_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let git = &config.git;
 
     // Validate merge commit template
     if git.merge_commit_template.is_empty() {
-        return Err(ConfigError::validation(
-            "git.merge_commit_template: Template cannot be empty.",
-        ));
+        issues.push("git.merge_commit_template: Template cannot be empty.");
     }
 
     // Validate monorepo merge commit template
     if git.monorepo_merge_commit_template.is_empty() {
-        return Err(ConfigError::validation(
-            "git.monorepo_merge_commit_template: Template cannot be empty.",
-        ));
+        issues.push("git.monorepo_merge_commit_template: Template cannot be empty.");
     }
 
     // Validate breaking warning template if enabled
     if git.include_breaking_warning && git.breaking_warning_template.is_empty() {
-        return Err(ConfigError::validation(
+        issues.push(
             "git.breaking_warning_template: Template cannot be empty when include_breaking_warning is true.",
-        ));
+        );
     }
-
-    Ok(())
 }
 
 /// Validates audit configuration.
-fn validate_audit_config(config: &PackageToolsConfig) -> ConfigResult<()> {
+fn validate_audit_config(config: &PackageToolsConfig, issues: &mut Issues) {
     let audit = &config.audit;
 
     if audit.enabled {
@@ -368,11 +561,11 @@ fn validate_audit_config(config: &PackageToolsConfig) -> ConfigResult<()> {
         let severity_str = audit.min_severity.to_lowercase();
         let valid_severities = ["critical", "warning", "info"];
         if !valid_severities.contains(&severity_str.as_str()) {
-            return Err(ConfigError::validation(format!(
+            issues.push(format!(
                 "audit.min_severity: Invalid severity '{}'. Must be one of: {}",
                 severity_str,
                 valid_severities.join(", ")
-            )));
+            ));
         }
 
         // Validate that at least one section is enabled
@@ -383,13 +576,32 @@ fn validate_audit_config(config: &PackageToolsConfig) -> ConfigResult<()> {
             && !sections.categorization
             && !sections.version_consistency
         {
-            return Err(ConfigError::validation(
+            issues.push(
                 "audit.sections: At least one audit section must be enabled when audit is enabled.",
-            ));
+            );
         }
-    }
 
-    Ok(())
+        if audit.concurrency == 0 {
+            issues.push("audit.concurrency: Concurrency must be greater than 0.");
+        }
+
+        if audit.check_timeout_secs == 0 {
+            issues.push("audit.check_timeout_secs: Timeout must be greater than 0.");
+        }
+
+        for (id, check) in &audit.checks {
+            if let Some(severity) = &check.severity
+                && !valid_severities.contains(&severity.as_str())
+            {
+                issues.push(format!(
+                    "audit.checks.{}.severity: Invalid severity '{}'. Must be one of: {}",
+                    id,
+                    severity,
+                    valid_severities.join(", ")
+                ));
+            }
+        }
+    }
 }
 
 /// Validates that a path is in a valid format.
@@ -9,8 +9,11 @@
 //! **Why**: To enable flexible versioning that supports both monorepo and single-package
 //! projects, with clear control over version resolution behavior.
 
-use crate::types::VersioningStrategy;
+use crate::error::VersionResult;
+use crate::types::{CalverFormat, Version, VersionScheme, VersioningStrategy};
+use crate::version::PackageFilter;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use sublime_standard_tools::config::{ConfigResult, Configurable};
 
 /// Configuration for version management.
@@ -23,6 +26,12 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// - `strategy`: The versioning strategy to use (independent or unified)
 /// - `default_bump`: Default version bump when none is specified in changeset
 /// - `snapshot_format`: Format template for snapshot versions
+/// - `resolution_cache`: Whether to cache version resolutions between runs
+/// - `independent_packages`: Packages versioned independently under the unified strategy
+/// - `packages`: Per-package overrides of the version scheme
+/// - `calver_packages`: Packages using calendar versioning
+/// - `calver_format`: Layout of calendar versions
+/// - `calver_range`: How dependents reference calendar-versioned packages
 ///
 /// # Example
 ///
@@ -41,6 +50,14 @@ use sublime_standard_tools::config::{ConfigResult, Configurable};
 /// strategy = "independent"
 /// default_bump = "patch"
 /// snapshot_format = "{version}-{branch}.{timestamp}"
+/// resolution_cache = true
+/// independent_packages = []
+/// calver_packages = ["@myorg/app-*"]
+/// calver_format = "YYYY.MM.MICRO"
+/// calver_range = "pin"
+///
+/// [package_tools.version.packages."@myorg/web-app"]
+/// scheme = "calver"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct VersionConfig {
@@ -110,6 +127,231 @@ pub struct VersionConfig {
     /// assert_eq!(config.snapshot_format, "{version}-snapshot.{short_hash}");
     /// ```
     pub snapshot_format: String,
+
+    /// Whether to cache version resolutions between runs.
+    ///
+    /// Resolutions are stored in the `resolution-cache` directory of the backup directory
+    /// (`upgrade.backup.backup_dir`), keyed by a hash of the changeset and a hash of the
+    /// workspace manifests. Any change to either produces a new key, so stale entries are
+    /// never used.
+    ///
+    /// # Default
+    ///
+    /// `true`
+    #[serde(default = "default_resolution_cache")]
+    pub resolution_cache: bool,
+
+    /// Packages versioned independently under the unified strategy.
+    ///
+    /// Each entry is a package name or a glob over package names (`@myorg/plugin-*`); an
+    /// entry prefixed with `!` excludes the packages it matches, as in `--filter`. Matching
+    /// packages keep their own versions and are bumped only when a changeset lists them or a
+    /// dependency propagates to them. Every other package forms the unified group. Only valid
+    /// with the unified strategy.
+    ///
+    /// # Default
+    ///
+    /// Empty: every package is in the unified group.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{VersionConfig, VersioningStrategy};
+    ///
+    /// let config = VersionConfig {
+    ///     strategy: VersioningStrategy::Unified,
+    ///     independent_packages: vec!["@myorg/plugin-*".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let filter = config.independent_filter().unwrap().unwrap();
+    /// assert!(filter.matches("@myorg/plugin-auth"));
+    /// assert!(!filter.matches("@myorg/core"));
+    /// ```
+    #[serde(default)]
+    pub independent_packages: Vec<String>,
+
+    /// Per-package overrides, keyed by package name.
+    ///
+    /// A package with the `calver` scheme is versioned from the release date following
+    /// `calver_format` and, under the unified strategy, keeps its own version like the
+    /// packages listed in `independent_packages`. An override takes precedence over
+    /// `calver_packages`.
+    ///
+    /// # Default
+    ///
+    /// Empty: the scheme of every package follows `calver_packages`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{PackageVersionConfig, VersionConfig, VersionScheme};
+    ///
+    /// let mut config = VersionConfig::default();
+    /// config.packages.insert(
+    ///     "@myorg/web-app".to_string(),
+    ///     PackageVersionConfig { scheme: Some(VersionScheme::Calver) },
+    /// );
+    ///
+    /// assert_eq!(config.scheme_for("@myorg/web-app"), VersionScheme::Calver);
+    /// assert_eq!(config.scheme_for("@myorg/core"), VersionScheme::Semver);
+    /// ```
+    #[serde(default)]
+    pub packages: BTreeMap<String, PackageVersionConfig>,
+
+    /// Packages using calendar versioning.
+    ///
+    /// Each entry is a package name or a glob over package names (`@myorg/app-*`); an entry
+    /// prefixed with `!` excludes the packages it matches, as in `--filter`. Matching packages
+    /// are bumped from the release date whenever a changeset lists them or a dependency
+    /// propagates to them, whatever the bump type other than `none`.
+    ///
+    /// # Default
+    ///
+    /// Empty: only packages whose override sets `scheme = "calver"` use calendar versioning.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::config::{VersionConfig, VersionScheme};
+    ///
+    /// let config = VersionConfig {
+    ///     calver_packages: vec!["@myorg/app-*".to_string()],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(config.scheme_for("@myorg/app-web"), VersionScheme::Calver);
+    /// assert_eq!(config.scheme_for("@myorg/core"), VersionScheme::Semver);
+    /// ```
+    #[serde(default)]
+    pub calver_packages: Vec<String>,
+
+    /// Layout of calendar versions: `YYYY.MM.MICRO` or `YYYY.MM.DD`.
+    ///
+    /// Months and days are written without leading zeros, as npm requires.
+    ///
+    /// # Default
+    ///
+    /// `YYYY.MM.MICRO`
+    #[serde(default)]
+    pub calver_format: CalverFormat,
+
+    /// How dependents reference a calendar-versioned package when its version changes.
+    ///
+    /// A caret range such as `^2024.6.0` would stop matching at the next year, so the range
+    /// of a dependent is replaced rather than rewritten with its operator: `pin` writes the
+    /// exact version and `at-least` writes `>=` the version.
+    ///
+    /// # Default
+    ///
+    /// `pin`
+    #[serde(default)]
+    pub calver_range: CalverRangePolicy,
+}
+
+/// How dependents reference a calendar-versioned package.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::CalverRangePolicy;
+/// use sublime_pkg_tools::types::Version;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let version = Version::parse("2024.6.1")?;
+///
+/// assert_eq!(CalverRangePolicy::Pin.range(&version), "2024.6.1");
+/// assert_eq!(CalverRangePolicy::AtLeast.range(&version), ">=2024.6.1");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CalverRangePolicy {
+    /// Dependents require the exact version.
+    #[default]
+    Pin,
+    /// Dependents require the version or any later one.
+    AtLeast,
+}
+
+impl CalverRangePolicy {
+    /// Returns the range dependents use to reference `version`.
+    #[must_use]
+    pub fn range(&self, version: &Version) -> String {
+        match self {
+            Self::Pin => version.to_string(),
+            Self::AtLeast => format!(">={version}"),
+        }
+    }
+}
+
+/// Overrides of the version settings for a single package.
+///
+/// Unset fields fall back to the `[package_tools.version]` settings.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::config::PackageVersionConfig;
+///
+/// let config = PackageVersionConfig::default();
+/// assert!(config.scheme.is_none());
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PackageVersionConfig {
+    /// Version scheme of the package: `semver` or `calver`.
+    ///
+    /// # Default: `None` (semantic versioning)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<VersionScheme>,
+}
+
+fn default_resolution_cache() -> bool {
+    true
+}
+
+impl VersionConfig {
+    /// Returns the filter selecting the packages versioned independently under the unified
+    /// strategy, or `None` when `independent_packages` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidFilter` if an entry is not a valid name or glob.
+    pub fn independent_filter(&self) -> VersionResult<Option<PackageFilter>> {
+        if self.independent_packages.is_empty() {
+            return Ok(None);
+        }
+        PackageFilter::parse(&self.independent_packages.join(",")).map(Some)
+    }
+
+    /// Returns the filter selecting the packages using calendar versioning, or `None` when
+    /// `calver_packages` is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidFilter` if an entry is not a valid name or glob.
+    pub fn calver_filter(&self) -> VersionResult<Option<PackageFilter>> {
+        if self.calver_packages.is_empty() {
+            return Ok(None);
+        }
+        PackageFilter::parse(&self.calver_packages.join(",")).map(Some)
+    }
+
+    /// Returns the version scheme of a package.
+    ///
+    /// The package's override is used when it sets a scheme; otherwise packages matching
+    /// `calver_packages` use calendar versioning.
+    #[must_use]
+    pub fn scheme_for(&self, package: &str) -> VersionScheme {
+        if let Some(scheme) = self.packages.get(package).and_then(|overrides| overrides.scheme) {
+            return scheme;
+        }
+        match self.calver_filter() {
+            Ok(Some(filter)) if filter.matches(package) => VersionScheme::Calver,
+            _ => VersionScheme::Semver,
+        }
+    }
 }
 
 /// Versioning strategy for packages.
@@ -142,6 +384,12 @@ impl Default for VersionConfig {
             strategy: VersioningStrategy::Independent,
             default_bump: "patch".to_string(),
             snapshot_format: "{version}-{branch}.{timestamp}".to_string(),
+            resolution_cache: default_resolution_cache(),
+            independent_packages: Vec::new(),
+            packages: BTreeMap::new(),
+            calver_packages: Vec::new(),
+            calver_format: CalverFormat::default(),
+            calver_range: CalverRangePolicy::default(),
         }
     }
 }
@@ -152,6 +400,9 @@ impl Configurable for VersionConfig {
     /// This method ensures that:
     /// - Default bump is one of: "major", "minor", "patch", "none"
     /// - Snapshot format is not empty
+    /// - Independent packages are valid filter terms and are only set with the unified strategy
+    /// - Package overrides are keyed by a non-empty package name
+    /// - Calver packages are valid filter terms
     ///
     /// # Errors
     ///
@@ -187,6 +438,32 @@ impl Configurable for VersionConfig {
             });
         }
 
+        if !self.independent_packages.is_empty() {
+            if self.strategy != VersioningStrategy::Unified {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: "version.independent_packages: Only applies to the unified strategy"
+                        .to_string(),
+                });
+            }
+            if let Err(e) = self.independent_filter() {
+                return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                    message: format!("version.independent_packages: {e}"),
+                });
+            }
+        }
+
+        if self.packages.keys().any(|name| name.trim().is_empty()) {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: "version.packages: Package name cannot be empty".to_string(),
+            });
+        }
+
+        if let Err(e) = self.calver_filter() {
+            return Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!("version.calver_packages: {e}"),
+            });
+        }
+
         Ok(())
     }
 
@@ -221,6 +498,12 @@ impl Configurable for VersionConfig {
         self.strategy = other.strategy;
         self.default_bump = other.default_bump;
         self.snapshot_format = other.snapshot_format;
+        self.resolution_cache = other.resolution_cache;
+        self.independent_packages = other.independent_packages;
+        self.packages = other.packages;
+        self.calver_packages = other.calver_packages;
+        self.calver_format = other.calver_format;
+        self.calver_range = other.calver_range;
         Ok(())
     }
 }
@@ -271,3 +271,91 @@ impl WorkspaceConfig {
         Ok(())
     }
 }
+
+/// A workspace root aggregated by a meta-repository.
+///
+/// A meta-repository holding several independent workspaces lists each of them as a root,
+/// so changes analysis, audits, and upgrade detection can run across all of them at once.
+/// Each root is its own git repository and may have its own configuration file.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::WorkspaceRootConfig;
+///
+/// let root = WorkspaceRootConfig::new("services/api");
+/// assert_eq!(root.display_name(), "services/api");
+///
+/// let root = WorkspaceRootConfig { name: Some("api".to_string()), ..root };
+/// assert_eq!(root.display_name(), "api");
+/// ```
+///
+/// # TOML Representation
+///
+/// ```toml
+/// [[roots]]
+/// path = "frontend"
+///
+/// [[roots]]
+/// path = "services/api"
+/// name = "api"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkspaceRootConfig {
+    /// Directory of the workspace, relative to the meta-repository root.
+    pub path: String,
+
+    /// Name used to group results; defaults to the path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl WorkspaceRootConfig {
+    /// Creates a root for the given directory, named after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Directory of the workspace, relative to the meta-repository root
+    #[must_use]
+    pub fn new(path: impl Into<String>) -> Self {
+        Self { path: path.into(), name: None }
+    }
+
+    /// Returns the name results are grouped under.
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.path)
+    }
+}
+
+/// Validates the workspace roots of a meta-repository.
+///
+/// # Errors
+///
+/// Returns an error if a root has an empty path or name, or if two roots share a name
+/// or a path.
+pub(crate) fn validate_roots(roots: &[WorkspaceRootConfig]) -> ConfigResult<()> {
+    let mut names = std::collections::HashSet::new();
+    let mut paths = std::collections::HashSet::new();
+    for (index, root) in roots.iter().enumerate() {
+        let invalid = |reason: String| {
+            Err(sublime_standard_tools::config::ConfigError::ValidationError {
+                message: format!("roots[{index}]: {reason}"),
+            })
+        };
+
+        if root.path.trim().is_empty() {
+            return invalid("Path cannot be empty".to_string());
+        }
+        if root.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return invalid("Name cannot be empty".to_string());
+        }
+        if !paths.insert(root.path.trim_end_matches('/')) {
+            return invalid(format!("Path '{}' is listed more than once", root.path));
+        }
+        if !names.insert(root.display_name()) {
+            return invalid(format!("Name '{}' is used by another root", root.display_name()));
+        }
+    }
+    Ok(())
+}
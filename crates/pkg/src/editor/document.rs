@@ -0,0 +1,498 @@
+//! Editable JSON documents that keep their formatting and comments.
+//!
+//! **What**: `JsonDocument` reads a JSON or JSONC file, exposes its value, and sets or removes
+//! values addressed by JSON Pointer (`/compilerOptions/strict`) while writing everything else
+//! back exactly as it was.
+//!
+//! **How**: Each edit is a single splice of the source text at the ranges recorded by the
+//! parser. New values are rendered with the document's indentation and line endings, and
+//! commas are added or removed around the edited entry. The document is parsed again after
+//! every edit, so its value always reflects its text.
+//!
+//! **Why**: Scaffolding and upgrades edit files that people maintain by hand. Rewriting them
+//! through `serde_json` would drop comments, reorder keys, and reformat the whole file.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+use super::JsonFlavor;
+use super::parser::{self, Entry, Node, NodeKind};
+use crate::error::{EditorError, EditorResult};
+use crate::line_endings::LineEnding;
+
+/// Indentation used when a document has no indented entry to copy.
+const DEFAULT_INDENT: &str = "  ";
+
+/// A JSON or JSONC document that can be edited without losing its formatting.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::editor::JsonDocument;
+/// use serde_json::json;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let source = "{\n  // Shared settings\n  \"extends\": \"./tsconfig.base.json\",\n}\n";
+/// let mut document = JsonDocument::parse("tsconfig.json", source)?;
+///
+/// document.set("/compilerOptions/strict", json!(true))?;
+///
+/// assert_eq!(document.get("/compilerOptions/strict"), Some(&json!(true)));
+/// assert!(document.content().contains("// Shared settings"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonDocument {
+    path: PathBuf,
+    flavor: JsonFlavor,
+    source: String,
+    root: Node,
+    value: Value,
+}
+
+/// A replacement of `start..end` of the source with `text`.
+struct Splice {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl JsonDocument {
+    /// Parses `source` as the content of the file at `path`, with the flavor of that file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::Syntax` if the content is not valid for the flavor of the file.
+    pub fn parse(path: impl Into<PathBuf>, source: impl Into<String>) -> EditorResult<Self> {
+        let path = path.into();
+        let flavor = JsonFlavor::for_path(&path);
+        Self::parse_as(path, source, flavor)
+    }
+
+    /// Parses `source` as the content of the file at `path` with an explicit flavor.
+    ///
+    /// `package.json` is parsed as strict JSON whatever flavor is requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::Syntax` if the content is not valid for the flavor.
+    pub fn parse_as(
+        path: impl Into<PathBuf>,
+        source: impl Into<String>,
+        flavor: JsonFlavor,
+    ) -> EditorResult<Self> {
+        let path = path.into();
+        let flavor = flavor.for_file(&path);
+        let source = source.into();
+        let (root, value) = parse_source(&path, &source, flavor)?;
+        Ok(Self { path, flavor, source, root, value })
+    }
+
+    /// Returns the path of the document.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the flavor the document is parsed with.
+    #[must_use]
+    pub fn flavor(&self) -> JsonFlavor {
+        self.flavor
+    }
+
+    /// Returns the value of the document, without comments.
+    #[must_use]
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    /// Returns the value at a JSON Pointer, if present.
+    #[must_use]
+    pub fn get(&self, pointer: &str) -> Option<&Value> {
+        self.value.pointer(pointer)
+    }
+
+    /// Returns the current text of the document.
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.source
+    }
+
+    /// Sets the value at a JSON Pointer.
+    ///
+    /// An existing value is replaced in place. A missing member is added after the last member
+    /// of the deepest existing object, creating the objects the pointer passes through. `-`
+    /// as the last segment appends to an array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::InvalidPointer` if the pointer is malformed, passes through a
+    /// string, number, boolean, or null, or indexes past the end of an array.
+    pub fn set(&mut self, pointer: &str, value: Value) -> EditorResult<()> {
+        let segments = pointer_segments(pointer)?;
+        let splice = self.set_splice(pointer, &segments, &value)?;
+        self.apply(splice)
+    }
+
+    /// Removes the value at a JSON Pointer, returning whether it was present.
+    ///
+    /// The entry is removed together with its comma and, when it sits on its own line, the
+    /// line and any comment trailing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::InvalidPointer` if the pointer is malformed or addresses the
+    /// document root.
+    pub fn remove(&mut self, pointer: &str) -> EditorResult<bool> {
+        let segments = pointer_segments(pointer)?;
+        let Some((last, parents)) = segments.split_last() else {
+            return Err(invalid_pointer(pointer, "the document root cannot be removed"));
+        };
+
+        let Some(container) = self.find(parents) else {
+            return Ok(false);
+        };
+        let (entries, index) = match &container.kind {
+            NodeKind::Object(entries) => {
+                (entries, entries.iter().rposition(|entry| entry.key.as_ref() == Some(last)))
+            }
+            NodeKind::Array(entries) => {
+                (entries, last.parse::<usize>().ok().filter(|index| *index < entries.len()))
+            }
+            NodeKind::Scalar => return Ok(false),
+        };
+        let Some(index) = index else {
+            return Ok(false);
+        };
+
+        let splice = self.remove_splice(container, entries, index);
+        self.apply(splice)?;
+        Ok(true)
+    }
+
+    fn find(&self, segments: &[String]) -> Option<&Node> {
+        let mut node = &self.root;
+        for segment in segments {
+            node = match &node.kind {
+                NodeKind::Object(entries) => {
+                    &entries.iter().rev().find(|entry| entry.key.as_ref() == Some(segment))?.value
+                }
+                NodeKind::Array(entries) => &entries.get(segment.parse::<usize>().ok()?)?.value,
+                NodeKind::Scalar => return None,
+            };
+        }
+        Some(node)
+    }
+
+    fn set_splice(
+        &self,
+        pointer: &str,
+        segments: &[String],
+        value: &Value,
+    ) -> EditorResult<Splice> {
+        let render_error = |error: serde_json::Error| invalid_pointer(pointer, error.to_string());
+
+        let mut node = &self.root;
+        for (index, segment) in segments.iter().enumerate() {
+            node = match &node.kind {
+                NodeKind::Object(entries) => {
+                    match entries.iter().rev().find(|entry| entry.key.as_ref() == Some(segment)) {
+                        Some(entry) => &entry.value,
+                        None => {
+                            let nested = nest(&segments[index + 1..], value.clone());
+                            return self
+                                .insert_splice(node, entries, Some(segment), &nested)
+                                .map_err(render_error);
+                        }
+                    }
+                }
+                NodeKind::Array(entries) => {
+                    if segment == "-" && index + 1 == segments.len() {
+                        return self
+                            .insert_splice(node, entries, None, value)
+                            .map_err(render_error);
+                    }
+                    match segment.parse::<usize>().ok().and_then(|position| entries.get(position)) {
+                        Some(entry) => &entry.value,
+                        None => {
+                            return Err(invalid_pointer(
+                                pointer,
+                                format!("array index '{segment}' is out of bounds"),
+                            ));
+                        }
+                    }
+                }
+                NodeKind::Scalar => {
+                    return Err(invalid_pointer(
+                        pointer,
+                        format!("'/{}' is not an object or array", segments[..index].join("/")),
+                    ));
+                }
+            };
+        }
+
+        let text = self.render(value, self.line_indent(node.start)).map_err(render_error)?;
+        Ok(Splice { start: node.start, end: node.end, text })
+    }
+
+    fn insert_splice(
+        &self,
+        container: &Node,
+        entries: &[Entry],
+        key: Option<&str>,
+        value: &Value,
+    ) -> Result<Splice, serde_json::Error> {
+        let key = match key {
+            Some(key) => format!("{}: ", serde_json::to_string(key)?),
+            None => String::new(),
+        };
+        let newline = self.newline();
+
+        let Some(last) = entries.last() else {
+            // Empty container: put the entry on its own line, keeping any comment inside
+            let outer = self.line_indent(container.start);
+            let inner = format!("{outer}{}", self.indent_unit());
+            let rendered = self.render(value, &inner)?;
+            let open = &self.source[container.start..=container.start];
+            let close = &self.source[container.end - 1..container.end];
+            let interior = self.source[container.start + 1..container.end - 1].trim_end();
+            let text =
+                format!("{open}{interior}{newline}{inner}{key}{rendered}{newline}{outer}{close}");
+            return Ok(Splice { start: container.start, end: container.end, text });
+        };
+
+        let comma = self.comma_after(last.value.end);
+        let anchor = comma.map_or(last.value.end, |comma| comma + 1);
+        let Some(inner) = self.own_line_indent(last.start) else {
+            // Inline container: append after the last entry on the same line
+            let rendered = serde_json::to_string(value)?;
+            let text = if comma.is_some() {
+                format!(" {key}{rendered},")
+            } else {
+                format!(", {key}{rendered}")
+            };
+            return Ok(Splice { start: anchor, end: anchor, text });
+        };
+
+        // Keep a comment trailing the last entry on its line
+        let line_end = self.same_line_trivia_end(anchor);
+        let rendered = self.render(value, inner)?;
+        let mut text = String::new();
+        if comma.is_none() {
+            text.push(',');
+        }
+        text.push_str(&self.source[anchor..line_end]);
+        text.push_str(newline);
+        text.push_str(inner);
+        text.push_str(&key);
+        text.push_str(&rendered);
+        if comma.is_some() {
+            // Keep the trailing-comma style of the container
+            text.push(',');
+        }
+        Ok(Splice { start: anchor, end: line_end, text })
+    }
+
+    fn remove_splice(&self, container: &Node, entries: &[Entry], index: usize) -> Splice {
+        let entry = &entries[index];
+        let comma = self.comma_after(entry.value.end);
+        let end = comma.map_or(entry.value.end, |comma| comma + 1);
+
+        // Only entry: collapse the container unless it holds comments
+        if entries.len() == 1
+            && self.source[container.start + 1..entry.start].trim().is_empty()
+            && self.source[end..container.end - 1].trim().is_empty()
+        {
+            return Splice {
+                start: container.start + 1,
+                end: container.end - 1,
+                text: String::new(),
+            };
+        }
+
+        let own_line = self.own_line_indent(entry.start).is_some();
+
+        // Last entry without a trailing comma: drop the comma of the previous entry instead
+        if comma.is_none()
+            && index > 0
+            && let Some(previous) = self.comma_after(entries[index - 1].value.end)
+        {
+            if !own_line {
+                return Splice { start: previous, end: entry.value.end, text: String::new() };
+            }
+            let line_end = self.same_line_trivia_end(entry.value.end);
+            let line_start = self.line_start(entry.start);
+            let start =
+                self.source[..line_start].trim_end_matches(['\r', '\n']).len().max(previous + 1);
+            let text = self.source[previous + 1..start].to_string();
+            return Splice { start: previous, end: line_end, text };
+        }
+
+        let line_end = self.same_line_trivia_end(end);
+        let ends_line = matches!(self.source.as_bytes().get(line_end), Some(b'\r' | b'\n') | None);
+        if own_line && ends_line {
+            let after = self.source[line_end..]
+                .strip_prefix("\r\n")
+                .or_else(|| self.source[line_end..].strip_prefix('\n'))
+                .map_or(line_end, |rest| self.source.len() - rest.len());
+            Splice { start: self.line_start(entry.start), end: after, text: String::new() }
+        } else {
+            let after = self.source[end..].trim_start_matches([' ', '\t']);
+            Splice { start: entry.start, end: self.source.len() - after.len(), text: String::new() }
+        }
+    }
+
+    fn apply(&mut self, splice: Splice) -> EditorResult<()> {
+        let mut source = String::with_capacity(self.source.len() + splice.text.len());
+        source.push_str(&self.source[..splice.start]);
+        source.push_str(&splice.text);
+        source.push_str(&self.source[splice.end..]);
+
+        let (root, value) = parse_source(&self.path, &source, self.flavor)?;
+        self.source = source;
+        self.root = root;
+        self.value = value;
+        Ok(())
+    }
+
+    /// Renders a value at the indentation of the line it is placed on.
+    fn render(&self, value: &Value, indent: &str) -> Result<String, serde_json::Error> {
+        let mut buffer = Vec::new();
+        let formatter = PrettyFormatter::with_indent(self.indent_unit().as_bytes());
+        value.serialize(&mut Serializer::with_formatter(&mut buffer, formatter))?;
+        let rendered = String::from_utf8_lossy(&buffer).replace('\n', &format!("\n{indent}"));
+        Ok(LineEnding::detect(&self.source).apply(&rendered))
+    }
+
+    fn newline(&self) -> &'static str {
+        match LineEnding::detect(&self.source) {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+
+    /// Returns the indentation step of the document, from its first entry.
+    fn indent_unit(&self) -> &str {
+        let (NodeKind::Object(entries) | NodeKind::Array(entries)) = &self.root.kind else {
+            return DEFAULT_INDENT;
+        };
+        entries
+            .first()
+            .and_then(|entry| self.own_line_indent(entry.start))
+            .and_then(|indent| indent.strip_prefix(self.line_indent(self.root.start)))
+            .filter(|unit| !unit.is_empty())
+            .unwrap_or(DEFAULT_INDENT)
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.source[..offset].rfind('\n').map_or(0, |index| index + 1)
+    }
+
+    /// Returns the leading whitespace of the line containing `offset`.
+    fn line_indent(&self, offset: usize) -> &str {
+        let line = &self.source[self.line_start(offset)..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    /// Returns the indentation before `offset` if only whitespace precedes it on its line.
+    fn own_line_indent(&self, offset: usize) -> Option<&str> {
+        let prefix = &self.source[self.line_start(offset)..offset];
+        prefix.chars().all(|c| c == ' ' || c == '\t').then_some(prefix)
+    }
+
+    /// Returns the offset of the comma following a value, if any.
+    fn comma_after(&self, offset: usize) -> Option<usize> {
+        let next = parser::skip_trivia(&self.source, offset);
+        (self.source.as_bytes().get(next) == Some(&b',')).then_some(next)
+    }
+
+    /// Returns the end of the spaces and comments following `offset` on the same line.
+    fn same_line_trivia_end(&self, mut offset: usize) -> usize {
+        let bytes = self.source.as_bytes();
+        loop {
+            match (bytes.get(offset), bytes.get(offset + 1)) {
+                (Some(b' ' | b'\t'), _) => offset += 1,
+                (Some(b'/'), Some(b'/')) => {
+                    let rest = &self.source[offset..];
+                    return offset + rest.find(['\r', '\n']).unwrap_or(rest.len());
+                }
+                (Some(b'/'), Some(b'*')) => {
+                    offset = self.source[offset + 2..]
+                        .find("*/")
+                        .map_or(bytes.len(), |index| offset + index + 4);
+                }
+                _ => return offset,
+            }
+        }
+    }
+}
+
+/// Reads and parses the JSON file at `path`, with the flavor of that file.
+///
+/// # Errors
+///
+/// Returns `EditorError::FileSystemError` if the file cannot be read, or
+/// `EditorError::Syntax` if its content is not valid for its flavor.
+pub async fn read_json_file<F: AsyncFileSystem>(fs: &F, path: &Path) -> EditorResult<JsonDocument> {
+    let content = fs.read_file_string(path).await.map_err(|e| EditorError::FileSystemError {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    })?;
+    JsonDocument::parse(path, content)
+}
+
+/// Writes a document back to its path.
+///
+/// # Errors
+///
+/// Returns `EditorError::FileSystemError` if the file cannot be written.
+pub async fn write_json_file<F: AsyncFileSystem>(
+    fs: &F,
+    document: &JsonDocument,
+) -> EditorResult<()> {
+    fs.write_file_string(&document.path, &document.source).await.map_err(|e| {
+        EditorError::FileSystemError { path: document.path.clone(), reason: e.to_string() }
+    })
+}
+
+fn parse_source(path: &Path, source: &str, flavor: JsonFlavor) -> EditorResult<(Node, Value)> {
+    parser::parse(source, flavor).map_err(|error| {
+        let before = &source[..error.offset.min(source.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        EditorError::Syntax {
+            path: path.to_path_buf(),
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            reason: error.reason,
+        }
+    })
+}
+
+/// Splits a JSON Pointer (RFC 6901) into unescaped segments.
+fn pointer_segments(pointer: &str) -> EditorResult<Vec<String>> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(invalid_pointer(pointer, "a JSON Pointer must start with '/'"));
+    };
+    Ok(rest.split('/').map(|segment| segment.replace("~1", "/").replace("~0", "~")).collect())
+}
+
+/// Wraps a value in one object per segment.
+fn nest(segments: &[String], value: Value) -> Value {
+    segments.iter().rev().fold(value, |value, segment| {
+        let mut object = serde_json::Map::new();
+        object.insert(segment.clone(), value);
+        Value::Object(object)
+    })
+}
+
+fn invalid_pointer(pointer: &str, reason: impl Into<String>) -> EditorError {
+    EditorError::InvalidPointer { pointer: pointer.to_string(), reason: reason.into() }
+}
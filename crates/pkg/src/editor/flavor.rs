@@ -0,0 +1,96 @@
+//! JSON flavors and how they are chosen for a file.
+//!
+//! **What**: Decides whether a JSON file is parsed as strict JSON or as JSONC (JSON with
+//! comments and trailing commas).
+//!
+//! **How**: By file name. `package.json` is always strict. Files that the tools reading them
+//! parse as JSONC (TypeScript and JavaScript project files, editor settings, `.jsonc` files,
+//! and a few tool configurations) are JSONC. Every other file is strict.
+//!
+//! **Why**: npm, pnpm, and yarn reject comments in `package.json`, so a comment written there
+//! breaks installs, while `tsconfig.json` and `.vscode/settings.json` commonly carry comments
+//! that must survive an edit.
+
+use std::path::Path;
+
+/// File name of the package manifest, which is always strict JSON.
+const PACKAGE_MANIFEST: &str = "package.json";
+
+/// Files parsed as JSONC by the tools that read them.
+const JSONC_FILES: &[&str] = &[
+    ".babelrc",
+    ".babelrc.json",
+    ".eslintrc.json",
+    ".swcrc",
+    "biome.json",
+    "deno.json",
+    "turbo.json",
+];
+
+/// Directories whose JSON files are parsed as JSONC.
+const JSONC_DIRECTORIES: &[&str] = &[".devcontainer", ".vscode"];
+
+/// Syntax accepted when parsing a JSON file.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::editor::JsonFlavor;
+/// use std::path::Path;
+///
+/// assert_eq!(JsonFlavor::for_path(Path::new("package.json")), JsonFlavor::Strict);
+/// assert_eq!(JsonFlavor::for_path(Path::new("tsconfig.build.json")), JsonFlavor::Jsonc);
+/// assert_eq!(JsonFlavor::for_path(Path::new(".vscode/settings.json")), JsonFlavor::Jsonc);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonFlavor {
+    /// Strict JSON (RFC 8259): comments and trailing commas are syntax errors.
+    #[default]
+    Strict,
+    /// JSON with `//` and `/* */` comments and trailing commas.
+    Jsonc,
+}
+
+impl JsonFlavor {
+    /// Returns the flavor of the file at `path`, from its name and directory.
+    #[must_use]
+    pub fn for_path(path: &Path) -> Self {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            return Self::Strict;
+        };
+        if is_package_manifest(path) {
+            return Self::Strict;
+        }
+
+        let in_jsonc_directory = path
+            .parent()
+            .and_then(Path::file_name)
+            .and_then(|dir| dir.to_str())
+            .is_some_and(|dir| JSONC_DIRECTORIES.contains(&dir));
+        let is_project_file = (name.starts_with("tsconfig") || name.starts_with("jsconfig"))
+            && name.ends_with(".json");
+
+        if name.ends_with(".jsonc")
+            || JSONC_FILES.contains(&name)
+            || is_project_file
+            || (in_jsonc_directory && name.ends_with(".json"))
+        {
+            Self::Jsonc
+        } else {
+            Self::Strict
+        }
+    }
+
+    /// Returns the flavor used for the file at `path` when `self` is requested.
+    ///
+    /// `package.json` stays strict whatever flavor is requested.
+    #[must_use]
+    pub fn for_file(self, path: &Path) -> Self {
+        if is_package_manifest(path) { Self::Strict } else { self }
+    }
+}
+
+/// Returns whether `path` names a package manifest.
+pub(crate) fn is_package_manifest(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == PACKAGE_MANIFEST)
+}
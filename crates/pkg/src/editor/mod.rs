@@ -0,0 +1,43 @@
+//! In-place editing of JSON and JSONC files.
+//!
+//! **What**: Reads JSON files the tools need to edit (`package.json`, `tsconfig.json`,
+//! `.vscode/settings.json`), and sets or removes values in them while keeping comments,
+//! key order, indentation, and line endings.
+//!
+//! **How**: `JsonFlavor::for_path` decides from the file name whether a file is strict JSON or
+//! JSONC (comments and trailing commas allowed). `JsonDocument` parses the file with a
+//! span-tracking parser and applies each edit as a splice of the original text.
+//!
+//! **Why**: Files next to `package.json` are often JSONC, and scaffolding has to edit them
+//! without discarding the comments people wrote. `package.json` itself stays strict: package
+//! managers reject comments in it, so a comment there is reported as an error rather than
+//! accepted and written back.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::editor::{read_json_file, write_json_file};
+//! use sublime_standard_tools::filesystem::FileSystemManager;
+//! use serde_json::json;
+//! use std::path::Path;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let fs = FileSystemManager::new();
+//!
+//! let mut settings = read_json_file(&fs, Path::new(".vscode/settings.json")).await?;
+//! settings.set("/editor.formatOnSave", json!(true))?;
+//! settings.remove("/typescript.tsdk")?;
+//! write_json_file(&fs, &settings).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod document;
+mod flavor;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+pub use document::{JsonDocument, read_json_file, write_json_file};
+pub use flavor::JsonFlavor;
@@ -0,0 +1,287 @@
+//! Span-tracking parser for strict JSON and JSONC.
+//!
+//! **What**: Parses a JSON document into a `serde_json::Value` together with a tree of the
+//! byte ranges each value, object member, and array element occupies in the source.
+//!
+//! **How**: A small recursive-descent parser. Strings and numbers are sliced out of the source
+//! and decoded by `serde_json`, so escapes and number forms follow the JSON specification.
+//! Comments and trailing commas are skipped in JSONC and rejected in strict JSON.
+//!
+//! **Why**: `serde_json` discards comments and layout. Edits are applied as splices at the
+//! ranges recorded here, so everything outside the edited value is written back unchanged.
+
+use serde_json::{Map, Number, Value};
+
+use super::JsonFlavor;
+
+/// Maximum nesting of objects and arrays, matching `serde_json`.
+const MAX_DEPTH: usize = 128;
+
+/// A parsed value with its byte range in the source.
+#[derive(Debug, Clone)]
+pub(crate) struct Node {
+    /// Offset of the first byte of the value.
+    pub(crate) start: usize,
+
+    /// Offset just past the last byte of the value.
+    pub(crate) end: usize,
+
+    /// Structure of the value.
+    pub(crate) kind: NodeKind,
+}
+
+/// Structure of a parsed value.
+#[derive(Debug, Clone)]
+pub(crate) enum NodeKind {
+    /// An object and its members, in source order.
+    Object(Vec<Entry>),
+    /// An array and its elements, in source order.
+    Array(Vec<Entry>),
+    /// A string, number, boolean, or null.
+    Scalar,
+}
+
+/// An object member or array element.
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    /// Key of an object member; `None` for array elements.
+    pub(crate) key: Option<String>,
+
+    /// Offset of the key of a member, or of the value of an element.
+    pub(crate) start: usize,
+
+    /// The member or element value.
+    pub(crate) value: Node,
+}
+
+/// A syntax error at a byte offset of the source.
+#[derive(Debug, Clone)]
+pub(crate) struct SyntaxError {
+    /// Offset of the error.
+    pub(crate) offset: usize,
+
+    /// Description of the error.
+    pub(crate) reason: String,
+}
+
+/// Parses a document, returning its span tree and value.
+pub(crate) fn parse(source: &str, flavor: JsonFlavor) -> Result<(Node, Value), SyntaxError> {
+    let mut parser = Parser { source, bytes: source.as_bytes(), pos: 0, depth: 0, flavor };
+    if source.starts_with('\u{feff}') {
+        parser.pos = '\u{feff}'.len_utf8();
+    }
+
+    parser.skip_trivia()?;
+    let parsed = parser.value()?;
+    parser.skip_trivia()?;
+    if parser.pos < parser.bytes.len() {
+        return Err(parser.error("unexpected content after the document"));
+    }
+    Ok(parsed)
+}
+
+/// Returns the offset of the first byte after the whitespace and comments at `pos`.
+///
+/// The source must already have parsed successfully.
+pub(crate) fn skip_trivia(source: &str, mut pos: usize) -> usize {
+    let bytes = source.as_bytes();
+    loop {
+        match (bytes.get(pos), bytes.get(pos + 1)) {
+            (Some(b' ' | b'\t' | b'\n' | b'\r'), _) => pos += 1,
+            (Some(b'/'), Some(b'/')) => {
+                pos = source[pos..].find('\n').map_or(bytes.len(), |index| pos + index);
+            }
+            (Some(b'/'), Some(b'*')) => {
+                pos = source[pos + 2..].find("*/").map_or(bytes.len(), |index| pos + index + 4);
+            }
+            _ => return pos,
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    depth: usize,
+    flavor: JsonFlavor,
+}
+
+impl Parser<'_> {
+    fn error(&self, reason: impl Into<String>) -> SyntaxError {
+        SyntaxError { offset: self.pos, reason: reason.into() }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn unexpected(&self) -> SyntaxError {
+        match self.source[self.pos..].chars().next() {
+            Some(c) => self.error(format!("unexpected character '{c}'")),
+            None => self.error("unexpected end of input"),
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), SyntaxError> {
+        loop {
+            match self.peek() {
+                Some(b' ' | b'\t' | b'\n' | b'\r') => self.pos += 1,
+                Some(b'/') => {
+                    if self.flavor == JsonFlavor::Strict {
+                        return Err(self.error("comments are not allowed in strict JSON"));
+                    }
+                    self.skip_comment()?;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) -> Result<(), SyntaxError> {
+        let rest = &self.source[self.pos..];
+        if rest.starts_with("//") {
+            self.pos += rest.find('\n').unwrap_or(rest.len());
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let Some(index) = body.find("*/") else {
+                return Err(self.error("unterminated block comment"));
+            };
+            self.pos += index + 4;
+        } else {
+            return Err(self.unexpected());
+        }
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<(Node, Value), SyntaxError> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b'{') => self.container(b'}'),
+            Some(b'[') => self.container(b']'),
+            Some(b'"') => {
+                let string = self.string()?;
+                Ok((self.scalar(start), Value::String(string)))
+            }
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn scalar(&self, start: usize) -> Node {
+        Node { start, end: self.pos, kind: NodeKind::Scalar }
+    }
+
+    fn container(&mut self, close: u8) -> Result<(Node, Value), SyntaxError> {
+        let start = self.pos;
+        let is_object = close == b'}';
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(self.error("nesting is too deep"));
+        }
+        self.pos += 1;
+
+        let mut entries = Vec::new();
+        let mut members = Map::new();
+        let mut elements = Vec::new();
+        self.skip_trivia()?;
+        while self.peek() != Some(close) {
+            let entry_start = self.pos;
+            let key = if is_object {
+                if self.peek() != Some(b'"') {
+                    return Err(self.error("expected a string key"));
+                }
+                let key = self.string()?;
+                self.skip_trivia()?;
+                if self.peek() != Some(b':') {
+                    return Err(self.error("expected ':' after the key"));
+                }
+                self.pos += 1;
+                self.skip_trivia()?;
+                Some(key)
+            } else {
+                None
+            };
+
+            let (node, value) = self.value()?;
+            match &key {
+                Some(key) => {
+                    members.insert(key.clone(), value);
+                }
+                None => elements.push(value),
+            }
+            entries.push(Entry { key, start: entry_start, value: node });
+
+            self.skip_trivia()?;
+            match self.peek() {
+                Some(b',') => {
+                    let comma = self.pos;
+                    self.pos += 1;
+                    self.skip_trivia()?;
+                    if self.peek() == Some(close) && self.flavor == JsonFlavor::Strict {
+                        self.pos = comma;
+                        return Err(self.error("trailing commas are not allowed in strict JSON"));
+                    }
+                }
+                Some(c) if c == close => {}
+                _ => return Err(self.error(format!("expected ',' or '{}'", char::from(close)))),
+            }
+        }
+        self.pos += 1;
+        self.depth -= 1;
+
+        let (kind, value) = if is_object {
+            (NodeKind::Object(entries), Value::Object(members))
+        } else {
+            (NodeKind::Array(entries), Value::Array(elements))
+        };
+        Ok((Node { start, end: self.pos, kind }, value))
+    }
+
+    fn string(&mut self) -> Result<String, SyntaxError> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => self.pos += 2,
+                Some(b'\n') | None => {
+                    self.pos = start;
+                    return Err(self.error("unterminated string"));
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+        self.pos += 1;
+
+        serde_json::from_str(&self.source[start..self.pos]).map_err(|_| SyntaxError {
+            offset: start,
+            reason: "invalid escape sequence or control character in string".to_string(),
+        })
+    }
+
+    fn number(&mut self) -> Result<(Node, Value), SyntaxError> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')) {
+            self.pos += 1;
+        }
+
+        let text = &self.source[start..self.pos];
+        let number = serde_json::from_str::<Number>(text).map_err(|_| SyntaxError {
+            offset: start,
+            reason: format!("invalid number '{text}'"),
+        })?;
+        Ok((self.scalar(start), Value::Number(number)))
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<(Node, Value), SyntaxError> {
+        let start = self.pos;
+        if !self.source[start..].starts_with(word) {
+            return Err(self.unexpected());
+        }
+        self.pos += word.len();
+        Ok((self.scalar(start), value))
+    }
+}
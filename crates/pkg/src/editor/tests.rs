@@ -0,0 +1,213 @@
+//! Tests for in-place JSON editing.
+//!
+//! **What**: Covers flavor selection, strict and JSONC parsing, and set and remove edits on
+//! documents with comments, trailing commas, tabs, and CRLF line endings.
+//!
+//! **How**: Edits documents parsed from literal sources and compares the resulting text
+//! exactly, then round-trips a file through the filesystem helpers.
+//!
+//! **Why**: Edited files are maintained by hand, so an edit must change only the value it
+//! addresses and `package.json` must never accept or gain a comment.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use super::{JsonDocument, JsonFlavor, read_json_file, write_json_file};
+use crate::error::EditorError;
+use serde_json::json;
+use std::path::Path;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tempfile::TempDir;
+
+const TSCONFIG: &str = r#"{
+  // Shared compiler settings
+  "extends": "./tsconfig.base.json",
+  "compilerOptions": {
+    "outDir": "dist", // build output
+    /* Emit declarations for consumers */
+    "declaration": true,
+  },
+}
+"#;
+
+fn tsconfig() -> JsonDocument {
+    JsonDocument::parse("tsconfig.json", TSCONFIG).unwrap()
+}
+
+#[test]
+fn test_flavor_for_path() {
+    assert_eq!(JsonFlavor::for_path(Path::new("packages/a/package.json")), JsonFlavor::Strict);
+    assert_eq!(JsonFlavor::for_path(Path::new("tsconfig.json")), JsonFlavor::Jsonc);
+    assert_eq!(JsonFlavor::for_path(Path::new("jsconfig.json")), JsonFlavor::Jsonc);
+    assert_eq!(JsonFlavor::for_path(Path::new(".vscode/extensions.json")), JsonFlavor::Jsonc);
+    assert_eq!(JsonFlavor::for_path(Path::new("settings.jsonc")), JsonFlavor::Jsonc);
+    assert_eq!(JsonFlavor::for_path(Path::new("renovate.json")), JsonFlavor::Strict);
+    assert_eq!(JsonFlavor::Jsonc.for_file(Path::new("package.json")), JsonFlavor::Strict);
+}
+
+#[test]
+fn test_parse_jsonc_value() {
+    let document = tsconfig();
+
+    assert_eq!(document.flavor(), JsonFlavor::Jsonc);
+    assert_eq!(document.get("/compilerOptions/outDir"), Some(&json!("dist")));
+    assert_eq!(document.get("/compilerOptions/declaration"), Some(&json!(true)));
+    assert_eq!(document.get("/extends"), Some(&json!("./tsconfig.base.json")));
+}
+
+#[test]
+fn test_package_json_stays_strict() {
+    let result = JsonDocument::parse("package.json", "{\n  // comment\n  \"name\": \"a\"\n}");
+    match result {
+        Err(EditorError::Syntax { line, column, reason, .. }) => {
+            assert_eq!((line, column), (2, 3));
+            assert!(reason.contains("comments"));
+        }
+        other => panic!("expected a syntax error, got {other:?}"),
+    }
+
+    let result = JsonDocument::parse_as("package.json", "{\"name\": \"a\",}", JsonFlavor::Jsonc);
+    assert!(matches!(result, Err(EditorError::Syntax { column: 13, .. })));
+}
+
+#[test]
+fn test_syntax_errors() {
+    for source in ["{\"a\": 1", "{\"a\" 1}", "[1 2]", "{\"a\": tru}", "{\"a\": 01}", "/* open"] {
+        let result = JsonDocument::parse("tsconfig.json", source);
+        assert!(matches!(result, Err(EditorError::Syntax { .. })), "{source} should not parse");
+    }
+}
+
+#[test]
+fn test_set_replaces_value_in_place() {
+    let mut document = tsconfig();
+    document.set("/compilerOptions/outDir", json!("lib")).unwrap();
+
+    assert_eq!(document.content(), TSCONFIG.replace("\"dist\"", "\"lib\""));
+}
+
+#[test]
+fn test_set_adds_member_keeping_comments_and_trailing_comma() {
+    let mut document = tsconfig();
+    document.set("/compilerOptions/strict", json!(true)).unwrap();
+    document.set("/include", json!(["src"])).unwrap();
+
+    let expected = r#"{
+  // Shared compiler settings
+  "extends": "./tsconfig.base.json",
+  "compilerOptions": {
+    "outDir": "dist", // build output
+    /* Emit declarations for consumers */
+    "declaration": true,
+    "strict": true,
+  },
+  "include": [
+    "src"
+  ],
+}
+"#;
+    assert_eq!(document.content(), expected);
+}
+
+#[test]
+fn test_set_creates_nested_objects() {
+    let mut document = JsonDocument::parse("package.json", "{\n\t\"name\": \"a\"\n}\n").unwrap();
+    document.set("/publishConfig/access", json!("public")).unwrap();
+
+    assert_eq!(
+        document.content(),
+        "{\n\t\"name\": \"a\",\n\t\"publishConfig\": {\n\t\t\"access\": \"public\"\n\t}\n}\n"
+    );
+}
+
+#[test]
+fn test_set_after_trailing_line_comment() {
+    let source = "{\r\n  \"a\": 1 // first\r\n}\r\n";
+    let mut document = JsonDocument::parse(".vscode/settings.json", source).unwrap();
+    document.set("/b", json!(2)).unwrap();
+
+    assert_eq!(document.content(), "{\r\n  \"a\": 1, // first\r\n  \"b\": 2\r\n}\r\n");
+}
+
+#[test]
+fn test_set_into_empty_and_inline_containers() {
+    let mut document = JsonDocument::parse(
+        "tsconfig.json",
+        "{\n  \"files\": [],\n  \"references\": [{ \"path\": \"a\" }]\n}",
+    )
+    .unwrap();
+    document.set("/files/-", json!("index.ts")).unwrap();
+    document.set("/references/0/prepend", json!(false)).unwrap();
+
+    assert_eq!(
+        document.content(),
+        "{\n  \"files\": [\n    \"index.ts\"\n  ],\n  \"references\": [{ \"path\": \"a\", \"prepend\": false }]\n}"
+    );
+}
+
+#[test]
+fn test_set_invalid_pointers() {
+    let mut document = tsconfig();
+
+    assert!(matches!(
+        document.set("/extends/path", json!(1)),
+        Err(EditorError::InvalidPointer { .. })
+    ));
+    assert!(matches!(document.set("extends", json!(1)), Err(EditorError::InvalidPointer { .. })));
+    assert_eq!(document.content(), TSCONFIG);
+}
+
+#[test]
+fn test_remove_member_with_its_line() {
+    let mut document = tsconfig();
+    assert!(document.remove("/compilerOptions/outDir").unwrap());
+    assert!(!document.remove("/compilerOptions/missing").unwrap());
+
+    let expected = r#"{
+  // Shared compiler settings
+  "extends": "./tsconfig.base.json",
+  "compilerOptions": {
+    /* Emit declarations for consumers */
+    "declaration": true,
+  },
+}
+"#;
+    assert_eq!(document.content(), expected);
+}
+
+#[test]
+fn test_remove_last_member_drops_previous_comma() {
+    let source = "{\n  \"name\": \"a\",\n  \"private\": true\n}\n";
+    let mut document = JsonDocument::parse("package.json", source).unwrap();
+    assert!(document.remove("/private").unwrap());
+    assert_eq!(document.content(), "{\n  \"name\": \"a\"\n}\n");
+
+    assert!(document.remove("/name").unwrap());
+    assert_eq!(document.content(), "{}\n");
+}
+
+#[test]
+fn test_remove_array_element() {
+    let mut document =
+        JsonDocument::parse("tsconfig.json", "{ \"include\": [\"src\", \"test\"] }").unwrap();
+    assert!(document.remove("/include/1").unwrap());
+    assert_eq!(document.content(), "{ \"include\": [\"src\"] }");
+}
+
+#[tokio::test]
+async fn test_read_and_write_json_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("tsconfig.json");
+    std::fs::write(&path, TSCONFIG).unwrap();
+    let fs = FileSystemManager::new();
+
+    let mut document = read_json_file(&fs, &path).await.unwrap();
+    document.set("/compilerOptions/declaration", json!(false)).unwrap();
+    write_json_file(&fs, &document).await.unwrap();
+
+    let written = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(written, TSCONFIG.replace("\"declaration\": true", "\"declaration\": false"));
+
+    let missing = read_json_file(&fs, &temp_dir.path().join("missing.json")).await;
+    assert!(matches!(missing, Err(EditorError::FileSystemError { .. })));
+}
@@ -308,6 +308,16 @@ pub enum AuditError {
         duration_secs: u64,
     },
 
+    /// The operation deadline passed before a check finished.
+    ///
+    /// This error occurs when the global deadline set with `--timeout` expires
+    /// while a check runs. Checks that finished earlier keep their results.
+    #[error("Audit check '{check}' exceeded the operation deadline")]
+    DeadlineExceeded {
+        /// Identifier of the check that was cut short.
+        check: String,
+    },
+
     /// Registry communication failed during audit.
     ///
     /// This error occurs when checking for upgrades or deprecated packages
@@ -344,6 +354,37 @@ pub enum AuditError {
         actual: usize,
     },
 
+    /// A custom audit check was registered with an id already in use.
+    ///
+    /// This error occurs when a check id matches a registered check or a built-in check.
+    #[error("Audit check '{id}' is already registered")]
+    DuplicateCheck {
+        /// The duplicated check id.
+        id: String,
+    },
+
+    /// An audit check id matches no built-in or registered check.
+    ///
+    /// This error occurs when running a check that was never registered.
+    #[error("Unknown audit check '{id}'")]
+    UnknownCheck {
+        /// The unknown check id.
+        id: String,
+    },
+
+    /// An audit fix could not be planned or applied.
+    ///
+    /// This error occurs when a fix edits a file that cannot be read or parsed, expects
+    /// content the file no longer has, or cannot be written. Fixes applied before the
+    /// failure are rolled back.
+    #[error("Failed to fix '{path}': {reason}")]
+    FixFailed {
+        /// Path of the file being fixed.
+        path: PathBuf,
+        /// Description of why the fix failed.
+        reason: String,
+    },
+
     /// Invalid workspace root for audit.
     ///
     /// This error occurs when the workspace root is invalid or cannot
@@ -355,6 +396,18 @@ pub enum AuditError {
         /// Description of why it's invalid.
         reason: String,
     },
+
+    /// Audit baseline file cannot be read or parsed.
+    ///
+    /// This error occurs when a baseline of known findings is missing,
+    /// unreadable, or not valid JSON.
+    #[error("Invalid audit baseline '{path}': {reason}")]
+    InvalidBaseline {
+        /// Path to the baseline file.
+        path: PathBuf,
+        /// Description of why it's invalid.
+        reason: String,
+    },
 }
 
 impl AsRef<str> for AuditError {
@@ -401,10 +454,15 @@ impl AsRef<str> for AuditError {
             Self::UnsupportedFormat { .. } => "unsupported format",
             Self::ExportFailed { .. } => "export failed",
             Self::Timeout { .. } => "timeout",
+            Self::DeadlineExceeded { .. } => "deadline exceeded",
             Self::RegistryError { .. } => "registry error",
             Self::DataInconsistency { .. } => "data inconsistency",
             Self::ThresholdExceeded { .. } => "threshold exceeded",
+            Self::DuplicateCheck { .. } => "duplicate check",
+            Self::UnknownCheck { .. } => "unknown check",
+            Self::FixFailed { .. } => "fix failed",
             Self::InvalidWorkspaceRoot { .. } => "invalid workspace root",
+            Self::InvalidBaseline { .. } => "invalid baseline",
         }
     }
 }
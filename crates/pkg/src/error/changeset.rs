@@ -321,6 +321,68 @@ pub enum ChangesetError {
         /// Detailed error message.
         reason: String,
     },
+
+    /// Changeset migration between storage backends failed.
+    ///
+    /// This error occurs when the target storage already holds a different changeset
+    /// under the same branch or id, or when a copied changeset does not read back
+    /// unchanged.
+    #[error("Failed to migrate changeset '{reference}': {reason}")]
+    MigrationFailed {
+        /// Branch name or id of the changeset.
+        reference: String,
+        /// Description of why the migration failed.
+        reason: String,
+    },
+
+    /// No changeset storage backend is registered under the name.
+    ///
+    /// This error occurs when a command selects a storage backend that the
+    /// backend registry does not know.
+    #[error("Unknown changeset storage backend '{name}': available backends are {available:?}")]
+    UnknownBackend {
+        /// The requested backend name.
+        name: String,
+        /// Names of the registered backends.
+        available: Vec<String>,
+    },
+
+    /// A changeset storage backend is registered twice.
+    ///
+    /// This error occurs when registering a backend under a name that is
+    /// already taken.
+    #[error("Changeset storage backend '{name}' is already registered")]
+    DuplicateBackend {
+        /// The backend name.
+        name: String,
+    },
+
+    /// Two edits of a changeset could not be merged.
+    ///
+    /// This error occurs when both sides of a merge changed the same field of a
+    /// changeset file to different values that cannot be combined.
+    #[error("Cannot merge edits of changeset '{branch}': both sides changed {fields:?}")]
+    MergeConflict {
+        /// The branch name of the changeset.
+        branch: String,
+        /// Fields both sides changed differently.
+        fields: Vec<String>,
+    },
+
+    /// Promotion into an environment was refused.
+    ///
+    /// This error occurs when a changeset does not target the environment, was already
+    /// promoted into it, or has not reached the environment that precedes it in the
+    /// configured promotion order.
+    #[error("Cannot promote changeset '{reference}' to '{environment}': {reason}")]
+    PromotionRejected {
+        /// Branch name, id, or name of the changeset.
+        reference: String,
+        /// The environment the changeset was promoted into.
+        environment: String,
+        /// Description of why the promotion was refused.
+        reason: String,
+    },
 }
 
 impl AsRef<str> for ChangesetError {
@@ -363,6 +425,11 @@ impl AsRef<str> for ChangesetError {
             Self::InvalidPath { .. } => "invalid changeset path",
             Self::LockFailed { .. } => "lock failed",
             Self::GitIntegration { .. } => "git integration error",
+            Self::MigrationFailed { .. } => "changeset migration failed",
+            Self::UnknownBackend { .. } => "unknown storage backend",
+            Self::DuplicateBackend { .. } => "duplicate storage backend",
+            Self::MergeConflict { .. } => "changeset merge conflict",
+            Self::PromotionRejected { .. } => "changeset promotion rejected",
         }
     }
 }
@@ -236,6 +236,9 @@ impl From<WithContext> for Error {
             Error::Changelog(e) => Error::Changelog(e),
             Error::Upgrade(e) => Error::Upgrade(e),
             Error::Audit(e) => Error::Audit(e),
+            Error::Hook(e) => Error::Hook(e),
+            Error::Notification(e) => Error::Notification(e),
+            Error::Editor(e) => Error::Editor(e),
             Error::FileSystem(msg) => Error::FileSystem(format!("{}: {}", ctx.context, msg)),
             Error::Git(msg) => Error::Git(format!("{}: {}", ctx.context, msg)),
             Error::IO(e) => Error::IO(e),
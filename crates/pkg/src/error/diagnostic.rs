@@ -0,0 +1,798 @@
+//! Structured diagnostics for package tools errors.
+//!
+//! **What**: Provides `Diagnostic`, a structured description of an error (stable error code,
+//! message, remediation hint, and source location), and the `Diagnose` trait implemented by
+//! every error type in this crate.
+//!
+//! **How**: Error codes are `<domain>::<variant>` in snake case (for example
+//! `config::parse_error`), derived from the variant name so they stay in sync with the error
+//! enums. Help messages are written per variant. Source locations come from the file paths
+//! carried by errors, from `line N column M` positions reported by the JSON and TOML parsers,
+//! and from offending keys such as a dependency name or configuration field.
+//! `SourceLocation::locate` turns a location into a line/column span within the file's
+//! content, so renderers can point at the offending text.
+//!
+//! **Why**: A message alone tells users what failed but not where or what to do about it.
+//! Structured diagnostics let the CLI render pointed, colored output and let automation match
+//! on codes instead of parsing messages.
+
+use super::{
+    AuditError, ChangelogError, ChangesError, ChangesetError, ConfigError, EditorError, Error,
+    HookError, NotificationError, UpgradeError, VersionError,
+};
+use crate::editor::JsonFlavor;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A structured error description.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::{ConfigError, Diagnose};
+/// use std::path::PathBuf;
+///
+/// let error = ConfigError::ParseError {
+///     path: PathBuf::from("repo.config.toml"),
+///     reason: "expected `=` at line 3 column 9".to_string(),
+/// };
+///
+/// let diagnostic = error.diagnostic();
+/// assert_eq!(diagnostic.code, "config::parse_error");
+/// assert!(diagnostic.help.is_some());
+///
+/// let location = diagnostic.location.expect("parse errors have a location");
+/// assert_eq!(location.line, Some(3));
+/// assert_eq!(location.column, Some(9));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Stable error code, `<domain>::<variant>` (e.g. `version::parse_error`).
+    pub code: String,
+
+    /// Human-readable error message.
+    pub message: String,
+
+    /// Remediation hint, if one applies.
+    pub help: Option<String>,
+
+    /// Where the error originates, if known.
+    pub location: Option<SourceLocation>,
+}
+
+/// Where an error originates: a file, a position within it, and/or an offending key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// File containing the error.
+    pub path: Option<PathBuf>,
+
+    /// Offending key, as a dotted path (e.g. `dependencies.react` or `changeset.path`).
+    pub key: Option<String>,
+
+    /// 1-based line, if reported by a parser.
+    pub line: Option<usize>,
+
+    /// 1-based column, if reported by a parser.
+    pub column: Option<usize>,
+}
+
+/// A located span within source text, as returned by `SourceLocation::locate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// 1-based line.
+    pub line: usize,
+
+    /// 1-based column, in characters.
+    pub column: usize,
+
+    /// Length of the span, in characters (at least 1).
+    pub length: usize,
+}
+
+impl SourceLocation {
+    /// Creates a location for a file.
+    #[must_use]
+    pub fn file(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), ..Self::default() }
+    }
+
+    /// Creates a location for a key whose file is unknown.
+    #[must_use]
+    pub fn key(key: impl Into<String>) -> Self {
+        Self { key: Some(key.into()), ..Self::default() }
+    }
+
+    /// Sets the offending key.
+    #[must_use]
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Sets the 1-based line and column.
+    #[must_use]
+    pub fn with_position(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Locates this location within the content of its file.
+    ///
+    /// A reported line (and column) is used as is. Otherwise the last segment of the key is
+    /// searched for as a JSON key (`"name":`) or a TOML/YAML key (`name =` / `name:`) at the
+    /// start of a line.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Content of the file
+    ///
+    /// # Returns
+    ///
+    /// The span to highlight, or `None` if the position is outside the content or the key
+    /// does not appear in it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::SourceLocation;
+    ///
+    /// let source = "{\n  \"dependencies\": {\n    \"react\": \"^x\"\n  }\n}";
+    /// let span = SourceLocation::key("dependencies.react").locate(source).expect("key found");
+    ///
+    /// assert_eq!((span.line, span.column, span.length), (3, 5, 7));
+    /// ```
+    #[must_use]
+    pub fn locate(&self, source: &str) -> Option<SourceSpan> {
+        if let Some(line) = self.line {
+            let text = source.lines().nth(line.checked_sub(1)?)?;
+            let width = text.chars().count();
+            let column = self.column.unwrap_or(1).clamp(1, width.max(1));
+            return Some(SourceSpan { line, column, length: 1 });
+        }
+
+        let key = self.key.as_deref()?.rsplit(['.', '/']).next()?;
+        if key.is_empty() {
+            return None;
+        }
+        let quoted = format!("\"{key}\"");
+
+        source.lines().enumerate().find_map(|(index, text)| {
+            let trimmed = text.trim_start();
+            let indent = text.chars().count() - trimmed.chars().count();
+
+            let length = if trimmed.starts_with(&quoted)
+                && trimmed[quoted.len()..].trim_start().starts_with(':')
+            {
+                quoted.chars().count()
+            } else if trimmed.starts_with(key)
+                && trimmed[key.len()..].trim_start().starts_with(['=', ':'])
+            {
+                key.chars().count()
+            } else {
+                return None;
+            };
+
+            Some(SourceSpan { line: index + 1, column: indent + 1, length })
+        })
+    }
+}
+
+/// Structured diagnostics for an error.
+///
+/// Implemented by `Error` and every domain error type.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::{Diagnose, Error, VersionError};
+///
+/// let error = Error::Version(VersionError::InvalidBumpType {
+///     bump_type: "huge".to_string(),
+/// });
+///
+/// assert_eq!(error.code(), "version::invalid_bump_type");
+/// assert!(error.help().is_some_and(|help| help.contains("major")));
+/// ```
+pub trait Diagnose: std::error::Error {
+    /// Returns the stable error code, `<domain>::<variant>`.
+    fn code(&self) -> String;
+
+    /// Returns a remediation hint, if one applies.
+    fn help(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns where the error originates, if known.
+    fn location(&self) -> Option<SourceLocation> {
+        None
+    }
+
+    /// Collects the code, message, help, and location into a `Diagnostic`.
+    fn diagnostic(&self) -> Diagnostic {
+        Diagnostic {
+            code: self.code(),
+            message: self.to_string(),
+            help: self.help(),
+            location: self.location(),
+        }
+    }
+}
+
+/// Builds a `<domain>::<variant>` code from the variant name in the error's `Debug` output.
+fn variant_code(domain: &str, error: &impl std::fmt::Debug) -> String {
+    let debug = format!("{error:?}");
+    let variant = debug.split(|c: char| !c.is_ascii_alphanumeric()).next().unwrap_or_default();
+
+    let mut code = format!("{domain}::");
+    for (index, c) in variant.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                code.push('_');
+            }
+            code.push(c.to_ascii_lowercase());
+        } else {
+            code.push(c);
+        }
+    }
+    code
+}
+
+/// Extracts a `line N column M` (or `line N, column M`) position from a parser message.
+fn reported_position(reason: &str) -> Option<(usize, usize)> {
+    let start = reason.rfind("line ")?;
+    let mut words = reason[start..].split_whitespace().skip(1);
+    let number = |word: &str| word.trim_matches(|c: char| !c.is_ascii_digit()).parse().ok();
+
+    let line = number(words.next()?)?;
+    if words.next()? != "column" {
+        return None;
+    }
+    let column = number(words.next()?)?;
+    Some((line, column))
+}
+
+/// Location of a file, with the parser-reported position in `reason` when there is one.
+fn file_location(path: &Path, reason: &str) -> SourceLocation {
+    let location = SourceLocation::file(path.to_path_buf());
+    match reported_position(reason) {
+        Some((line, column)) => location.with_position(line, column),
+        None => location,
+    }
+}
+
+/// Help for errors caused by invalid semantic versions.
+const SEMVER_HELP: &str = "Use semantic versioning: MAJOR.MINOR.PATCH with optional \
+                           -prerelease and +build (e.g. 1.2.3 or 2.0.0-beta.1). A leading \
+                           'v' or surrounding whitespace is not accepted.";
+
+/// Help for errors raised outside a git repository.
+const GIT_HELP: &str = "Run the command inside a git repository with at least one commit.";
+
+/// Help for operations cut short by the global deadline.
+const DEADLINE_HELP: &str = "Raise --timeout, or check the network connection and retry.";
+
+impl Diagnose for ConfigError {
+    fn code(&self) -> String {
+        variant_code("config", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::NotFound { .. } => {
+                "Create the configuration file, or point the loader at an existing one.".to_string()
+            }
+            Self::ParseError { .. } => "Fix the syntax at the reported position.".to_string(),
+            Self::ValidationFailed { .. } => {
+                "Fix each listed field; the messages name the offending keys.".to_string()
+            }
+            Self::UnsupportedFormat { .. } => {
+                "Use a TOML, JSON, or YAML configuration file.".to_string()
+            }
+            Self::EnvVarError { var_name, .. } => {
+                format!("Check the value of the {var_name} environment variable.")
+            }
+            Self::MissingField { field } => format!("Add '{field}' to the configuration."),
+            Self::InvalidFieldType { field, expected, .. } => {
+                format!("Set '{field}' to a value of type {expected}.")
+            }
+            Self::PermissionDenied { .. } => {
+                "Check that the configuration file is readable.".to_string()
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::NotFound { path } | Self::PermissionDenied { path } => {
+                Some(SourceLocation::file(path.clone()))
+            }
+            Self::ParseError { path, reason } | Self::InvalidPath { path, reason } => {
+                Some(file_location(path, reason))
+            }
+            Self::MergeConflict { field, .. }
+            | Self::MissingField { field }
+            | Self::InvalidFieldType { field, .. } => Some(SourceLocation::key(field.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for VersionError {
+    fn code(&self) -> String {
+        variant_code("version", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::InvalidVersion { .. } | Self::ParseError { .. } => SEMVER_HELP.to_string(),
+            Self::InvalidBump { .. } | Self::InvalidBumpType { .. } => {
+                "Use one of: major, minor, patch, none.".to_string()
+            }
+            Self::InvalidStrategy { .. } => "Use 'independent' or 'unified'.".to_string(),
+            Self::InvalidScheme { .. } => "Use 'semver' or 'calver'.".to_string(),
+            Self::InvalidFilter { .. } => {
+                "Use comma-separated package names or globs, prefixing a term with '!' to \
+                 exclude it (e.g. '@myorg/*,!@myorg/docs'). Partial releases require the \
+                 independent strategy."
+                    .to_string()
+            }
+            Self::CircularDependency { .. } => {
+                "Remove one of the dependencies in the cycle.".to_string()
+            }
+            Self::PackageNotFound { name, .. } => {
+                format!(
+                    "Check that '{name}' is a workspace package and its name is spelled correctly."
+                )
+            }
+            Self::StaleChangesetPackages { .. } => {
+                "Map renamed packages in changeset.package_renames, or run \
+                 'workspace changeset clean' to rename or remove the stale entries."
+                    .to_string()
+            }
+            Self::PackageJsonError { .. } => {
+                "Check that package.json exists and is valid JSON.".to_string()
+            }
+            Self::InvalidVersionSpec { dependency, .. } => {
+                format!("Fix the version range of '{dependency}' in package.json.")
+            }
+            Self::MaxDepthExceeded { .. } => {
+                "Raise dependency.max_depth, or check for an unexpectedly deep dependency chain."
+                    .to_string()
+            }
+            Self::NoPackagesToUpdate => {
+                "Add a changeset that lists the packages to release.".to_string()
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::PackageJsonError { path, reason }
+            | Self::ApplyFailed { path, reason }
+            | Self::InvalidWorkspaceRoot { path, reason }
+            | Self::FileSystemError { path, reason } => Some(file_location(path, reason)),
+            Self::InvalidVersionSpec { dependency, .. }
+            | Self::DependencyNotFound { dependency, .. } => {
+                Some(SourceLocation::key(dependency.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for ChangesetError {
+    fn code(&self) -> String {
+        variant_code("changeset", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::NotFound { .. } => "Create a changeset for this branch first.".to_string(),
+            Self::AlreadyExists { .. } => {
+                "Update the existing changeset instead of creating a new one.".to_string()
+            }
+            Self::InvalidEnvironment { available, .. } if !available.is_empty() => {
+                format!("Use one of the configured environments: {}.", available.join(", "))
+            }
+            Self::InvalidEnvironment { .. } => {
+                "Add the environment to changeset.available_environments.".to_string()
+            }
+            Self::EmptyChangeset { .. } => "Add at least one package to the changeset.".to_string(),
+            Self::ConcurrentModification { .. } | Self::LockFailed { .. } => {
+                "Another process is modifying the changeset; retry once it finishes.".to_string()
+            }
+            Self::GitError { .. } | Self::GitIntegration { .. } => GIT_HELP.to_string(),
+            Self::PermissionDenied { .. } => {
+                "Check that the changeset directory is writable.".to_string()
+            }
+            Self::MigrationFailed { .. } => {
+                "Reconcile or remove the conflicting changeset in the target storage, then \
+                 re-run the migration; changesets already copied are skipped."
+                    .to_string()
+            }
+            Self::UnknownBackend { available, .. } => {
+                format!("Use one of the registered storage backends: {}.", available.join(", "))
+            }
+            Self::MergeConflict { .. } => {
+                "Resolve the conflicting fields by hand, then run `workspace changeset edit` to \
+                 rewrite the file canonically."
+                    .to_string()
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::StorageError { path, reason } | Self::InvalidPath { path, reason } => {
+                Some(file_location(path, reason))
+            }
+            Self::AlreadyExists { path, .. } | Self::PermissionDenied { path, .. } => {
+                Some(SourceLocation::file(path.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for ChangesError {
+    fn code(&self) -> String {
+        variant_code("changes", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::GitError { .. } | Self::RepositoryNotFound { .. } => GIT_HELP.to_string(),
+            Self::InvalidCommitRef { reference, .. } => {
+                format!("Check that '{reference}' exists with 'git rev-parse {reference}'.")
+            }
+            Self::InvalidCommitRange { .. } => {
+                "Check that both refs exist and the start is an ancestor of the end.".to_string()
+            }
+            Self::NoPackagesFound { .. } => {
+                "Check the workspace patterns in package.json or pnpm-workspace.yaml.".to_string()
+            }
+            Self::PackageJsonParseError { .. } => {
+                "Fix the JSON syntax at the reported position.".to_string()
+            }
+            Self::PatternError { .. } => {
+                "Check the glob syntax: '*' matches within a directory, '**' across directories."
+                    .to_string()
+            }
+            Self::FileOutsideWorkspace { .. } => {
+                "Only files inside the workspace root can be analyzed.".to_string()
+            }
+            Self::MergeConflict { .. } => {
+                "Resolve the merge conflict before analyzing changes.".to_string()
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::InvalidPath { path, reason }
+            | Self::FileSystemError { path, reason }
+            | Self::PackageJsonParseError { path, reason }
+            | Self::InvalidWorkspaceRoot { path, reason } => Some(file_location(path, reason)),
+            Self::FileOutsideWorkspace { path, .. } | Self::RepositoryNotFound { path } => {
+                Some(SourceLocation::file(path.clone()))
+            }
+            Self::StatisticsError { file, .. } | Self::MergeConflict { file } => {
+                Some(SourceLocation::file(file.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for ChangelogError {
+    fn code(&self) -> String {
+        variant_code("changelog", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::ConventionalCommitParseError { .. } => {
+                "Use the Conventional Commits format: type(scope): description.".to_string()
+            }
+            Self::GitError { .. } => GIT_HELP.to_string(),
+            Self::InvalidVersion { .. } => SEMVER_HELP.to_string(),
+            Self::RepositoryUrlMissing { .. } => {
+                "Set changelog.repository_url or the repository field in package.json.".to_string()
+            }
+            Self::ChangelogExists { .. } => {
+                "The version is already in the changelog; bump to a new version first.".to_string()
+            }
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::NotFound { path } | Self::ChangelogExists { path, .. } => {
+                Some(SourceLocation::file(path.clone()))
+            }
+            Self::FileSystemError { path, reason }
+            | Self::InvalidPath { path, reason }
+            | Self::UpdateFailed { path, reason } => Some(file_location(path, reason)),
+            Self::ParseError { line, .. } => {
+                Some(SourceLocation { line: Some(*line), ..SourceLocation::default() })
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for UpgradeError {
+    fn code(&self) -> String {
+        variant_code("upgrade", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::AuthenticationFailed { registry, .. } => {
+                format!("Check the auth token for {registry} in .npmrc.")
+            }
+            Self::RegistryTimeout { .. } | Self::NetworkError { .. } => {
+                "Check the network connection and registry URL, then retry.".to_string()
+            }
+            Self::RateLimitExceeded { .. } => {
+                "Wait before retrying, or authenticate to raise the rate limit.".to_string()
+            }
+            Self::RateLimited { retry_after: Some(wait), .. } => {
+                format!("Retry in {}s, or authenticate to raise the rate limit.", wait.as_secs())
+            }
+            Self::RateLimited { retry_after: None, .. } => {
+                "Wait before retrying, or authenticate to raise the rate limit.".to_string()
+            }
+            Self::ServiceUnavailable { .. } => {
+                "The registry is temporarily unavailable; retry later.".to_string()
+            }
+            Self::NpmrcParseError { .. } => "Fix the syntax of .npmrc.".to_string(),
+            Self::PackageJsonError { .. } => {
+                "Fix the JSON syntax at the reported position.".to_string()
+            }
+            Self::InvalidPackageName { .. } => {
+                "Package names are lowercase and URL-safe, optionally scoped (@scope/name)."
+                    .to_string()
+            }
+            Self::InvalidVersion { .. } => SEMVER_HELP.to_string(),
+            Self::DeprecatedPackage { alternative: Some(alternative), .. } => {
+                format!("Consider migrating to {alternative}.")
+            }
+            Self::MaxBackupsExceeded { .. } => {
+                "Remove old backups or raise upgrade.backup.max_backups.".to_string()
+            }
+            Self::VerificationFailed { .. } => {
+                "Fix the failing packages, or re-run with upgrade.verify.on_failure = \"keep\"."
+                    .to_string()
+            }
+            Self::OfflineSkipped { .. } => {
+                "Disable offline mode once the registry is reachable.".to_string()
+            }
+            Self::DeadlineExceeded { .. } => DEADLINE_HELP.to_string(),
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::BackupFailed { path, reason }
+            | Self::ApplyFailed { path, reason }
+            | Self::NpmrcParseError { path, reason }
+            | Self::FileSystemError { path, reason }
+            | Self::PackageJsonError { path, reason }
+            | Self::InvalidWorkspace { path, reason }
+            | Self::BackupCorrupted { path, reason } => Some(file_location(path, reason)),
+            Self::NoBackup { path }
+            | Self::ConcurrentModification { path }
+            | Self::MaxBackupsExceeded { path, .. } => Some(SourceLocation::file(path.clone())),
+            Self::InvalidVersionSpec { package, .. } => Some(SourceLocation::key(package.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for AuditError {
+    fn code(&self) -> String {
+        variant_code("audit", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::SectionDisabled { section } => {
+                format!("Enable audit.sections.{section} in the configuration.")
+            }
+            Self::InvalidSeverity { .. } => "Use one of: critical, warning, info.".to_string(),
+            Self::DuplicateCheck { .. } => {
+                "Give each custom check a unique id that is not a built-in check id.".to_string()
+            }
+            Self::UnknownCheck { .. } => {
+                "Register the check on the AuditManager before running it.".to_string()
+            }
+            Self::FixFailed { .. } => {
+                "Run the audit again to plan fixes against the current files.".to_string()
+            }
+            Self::InvalidBaseline { .. } => {
+                "Regenerate the baseline with `workspace audit --write-baseline`.".to_string()
+            }
+            Self::GitError { .. } => GIT_HELP.to_string(),
+            Self::DeadlineExceeded { .. } => DEADLINE_HELP.to_string(),
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::FileSystemError { path, reason }
+            | Self::ExportFailed { path, reason }
+            | Self::FixFailed { path, reason }
+            | Self::InvalidBaseline { path, reason }
+            | Self::InvalidWorkspaceRoot { path, reason } => Some(file_location(path, reason)),
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for HookError {
+    fn code(&self) -> String {
+        variant_code("hook", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::UnknownHook { .. } => {
+                "Use a git hook name such as pre-commit, commit-msg, or pre-push.".to_string()
+            }
+            Self::UnmanagedHook { .. } => {
+                "Move the existing hook aside before installing the managed one.".to_string()
+            }
+            Self::DuplicateHook { .. } => {
+                "Configure each hook once; merge the commands into a single entry.".to_string()
+            }
+            Self::GitError { .. } => GIT_HELP.to_string(),
+            _ => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::UnmanagedHook { path, .. } => Some(SourceLocation::file(path.clone())),
+            Self::FileSystemError { path, reason } => Some(file_location(path, reason)),
+            _ => None,
+        }
+    }
+}
+
+impl Diagnose for NotificationError {
+    fn code(&self) -> String {
+        variant_code("notification", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::InvalidConfig { .. } => {
+                "Check the channels in the [package_tools.notifications] section.".to_string()
+            }
+            Self::DeliveryFailed { .. } => {
+                "Check the channel URL, SMTP server, and credentials; `${VAR}` references must \
+                 name variables set in the environment."
+                    .to_string()
+            }
+            Self::Timeout { .. } => {
+                "Raise notifications.timeout_secs or check that the endpoint is reachable."
+                    .to_string()
+            }
+            Self::OfflineSkipped { .. } => {
+                "Send the notification again once offline mode is disabled.".to_string()
+            }
+        };
+        Some(help)
+    }
+}
+
+impl Diagnose for EditorError {
+    fn code(&self) -> String {
+        variant_code("editor", self)
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match self {
+            Self::Syntax { path, .. } if JsonFlavor::for_path(path) == JsonFlavor::Strict => {
+                "This file must be strict JSON: remove comments and trailing commas.".to_string()
+            }
+            Self::Syntax { .. } => "Fix the JSON syntax at the reported position.".to_string(),
+            Self::InvalidPointer { .. } => {
+                "Use a JSON Pointer such as /compilerOptions/strict that passes only through \
+                 objects and arrays."
+                    .to_string()
+            }
+            Self::FileSystemError { .. } => return None,
+        };
+        Some(help)
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::Syntax { path, line, column, .. } => Some(SourceLocation {
+                path: Some(path.clone()),
+                line: Some(*line),
+                column: Some(*column),
+                ..SourceLocation::default()
+            }),
+            Self::InvalidPointer { pointer, .. } => Some(SourceLocation::key(pointer.clone())),
+            Self::FileSystemError { path, reason } => Some(file_location(path, reason)),
+        }
+    }
+}
+
+impl Diagnose for Error {
+    fn code(&self) -> String {
+        match self {
+            Self::Config(e) => e.code(),
+            Self::Version(e) => e.code(),
+            Self::Changeset(e) => e.code(),
+            Self::Changes(e) => e.code(),
+            Self::Changelog(e) => e.code(),
+            Self::Upgrade(e) => e.code(),
+            Self::Audit(e) => e.code(),
+            Self::Hook(e) => e.code(),
+            Self::Notification(e) => e.code(),
+            Self::Editor(e) => e.code(),
+            Self::FileSystem(_) => "filesystem::error".to_string(),
+            Self::Git(_) => "git::error".to_string(),
+            Self::IO(_) => "io::error".to_string(),
+            Self::Json(_) => "json::error".to_string(),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        match self {
+            Self::Config(e) => e.help(),
+            Self::Version(e) => e.help(),
+            Self::Changeset(e) => e.help(),
+            Self::Changes(e) => e.help(),
+            Self::Changelog(e) => e.help(),
+            Self::Upgrade(e) => e.help(),
+            Self::Audit(e) => e.help(),
+            Self::Hook(e) => e.help(),
+            Self::Notification(e) => e.help(),
+            Self::Editor(e) => e.help(),
+            Self::Json(_) => Some("Fix the JSON syntax at the reported position.".to_string()),
+            Self::FileSystem(_) | Self::Git(_) | Self::IO(_) => None,
+        }
+    }
+
+    fn location(&self) -> Option<SourceLocation> {
+        match self {
+            Self::Config(e) => e.location(),
+            Self::Version(e) => e.location(),
+            Self::Changeset(e) => e.location(),
+            Self::Changes(e) => e.location(),
+            Self::Changelog(e) => e.location(),
+            Self::Upgrade(e) => e.location(),
+            Self::Audit(e) => e.location(),
+            Self::Hook(e) => e.location(),
+            Self::Notification(e) => e.location(),
+            Self::Editor(e) => e.location(),
+            Self::Json(e) if e.line() > 0 => Some(SourceLocation {
+                line: Some(e.line()),
+                column: Some(e.column()),
+                ..SourceLocation::default()
+            }),
+            Self::Json(_) | Self::FileSystem(_) | Self::Git(_) | Self::IO(_) => None,
+        }
+    }
+}
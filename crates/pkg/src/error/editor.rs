@@ -0,0 +1,141 @@
+//! JSON editor error types for package tools.
+//!
+//! **What**: Defines error types for reading and editing JSON files in place with the
+//! `editor` module, including JSONC files such as `tsconfig.json`.
+//!
+//! **How**: Uses `thiserror` for error definitions with the file, position, or JSON Pointer
+//! involved in the failure. Implements `AsRef<str>` for string conversion.
+//!
+//! **Why**: To report where a file is malformed (a comment in `package.json`, a missing comma
+//! in `tsconfig.json`) and which edit could not be applied.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::error::{EditorError, EditorResult};
+//!
+//! fn check_pointer(pointer: &str) -> EditorResult<()> {
+//!     if !pointer.is_empty() && !pointer.starts_with('/') {
+//!         return Err(EditorError::InvalidPointer {
+//!             pointer: pointer.to_string(),
+//!             reason: "a JSON Pointer must start with '/'".to_string(),
+//!         });
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Result type alias for JSON editor operations.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::{EditorError, EditorResult};
+///
+/// fn edit() -> EditorResult<()> {
+///     Ok(())
+/// }
+/// ```
+pub type EditorResult<T> = Result<T, EditorError>;
+
+/// Errors that can occur while reading or editing JSON files.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::EditorError;
+/// use std::path::PathBuf;
+///
+/// let error = EditorError::Syntax {
+///     path: PathBuf::from("package.json"),
+///     line: 3,
+///     column: 5,
+///     reason: "comments are not allowed in strict JSON".to_string(),
+/// };
+///
+/// assert_eq!(error.as_ref(), "invalid json syntax");
+/// assert!(!error.is_transient());
+/// ```
+#[derive(Debug, Error, Clone)]
+pub enum EditorError {
+    /// The file is not valid JSON for its flavor.
+    ///
+    /// This error occurs for malformed JSON, and for comments or trailing commas in files
+    /// that must be strict JSON, such as `package.json`.
+    #[error("Invalid JSON in '{path}' at line {line}, column {column}: {reason}")]
+    Syntax {
+        /// The file being parsed.
+        path: PathBuf,
+        /// 1-based line of the error.
+        line: usize,
+        /// 1-based column of the error, in characters.
+        column: usize,
+        /// Description of the syntax error.
+        reason: String,
+    },
+
+    /// An edit addresses a location that does not exist or cannot hold a value.
+    #[error("Cannot edit '{pointer}': {reason}")]
+    InvalidPointer {
+        /// The JSON Pointer of the edit.
+        pointer: String,
+        /// Description of why the edit cannot be applied.
+        reason: String,
+    },
+
+    /// Filesystem operation failed.
+    #[error("Filesystem error at '{path}': {reason}")]
+    FileSystemError {
+        /// The path where the error occurred.
+        path: PathBuf,
+        /// Description of the filesystem error.
+        reason: String,
+    },
+}
+
+impl AsRef<str> for EditorError {
+    /// Returns a string representation of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::EditorError;
+    ///
+    /// let error = EditorError::InvalidPointer {
+    ///     pointer: "/name/first".to_string(),
+    ///     reason: "'/name' is not an object or array".to_string(),
+    /// };
+    /// assert_eq!(error.as_ref(), "invalid json edit");
+    /// ```
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Syntax { .. } => "invalid json syntax",
+            Self::InvalidPointer { .. } => "invalid json edit",
+            Self::FileSystemError { .. } => "json file error",
+        }
+    }
+}
+
+impl EditorError {
+    /// Returns whether this error is transient and might succeed on retry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::EditorError;
+    /// use std::path::PathBuf;
+    ///
+    /// let fs_error = EditorError::FileSystemError {
+    ///     path: PathBuf::from("tsconfig.json"),
+    ///     reason: "permission denied".to_string(),
+    /// };
+    /// assert!(fs_error.is_transient());
+    /// ```
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::FileSystemError { .. })
+    }
+}
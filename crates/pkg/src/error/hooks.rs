@@ -0,0 +1,184 @@
+//! Git hook management error types for package tools.
+//!
+//! **What**: Defines error types specific to installing, inspecting, and removing the git hooks
+//! declared in the `hooks` configuration section.
+//!
+//! **How**: Uses `thiserror` for error definitions with the hook name or path involved in the
+//! failure. Implements `AsRef<str>` for string conversion.
+//!
+//! **Why**: To give clear feedback when hooks cannot be materialized, for example when a user's
+//! own hook would be overwritten or the hooks directory is not writable.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::error::{HookError, HookResult};
+//!
+//! fn ensure_known_hook(name: &str) -> HookResult<()> {
+//!     if name != "pre-commit" {
+//!         return Err(HookError::UnknownHook { name: name.to_string() });
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Result type alias for hook operations.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::{HookError, HookResult};
+///
+/// fn install() -> HookResult<usize> {
+///     Ok(2)
+/// }
+/// ```
+pub type HookResult<T> = Result<T, HookError>;
+
+/// Errors that can occur while managing git hooks.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::HookError;
+/// use std::path::PathBuf;
+///
+/// let error = HookError::UnmanagedHook {
+///     name: "pre-commit".to_string(),
+///     path: PathBuf::from(".git/hooks/pre-commit"),
+/// };
+///
+/// assert_eq!(error.as_ref(), "unmanaged hook");
+/// assert!(error.to_string().contains("--force"));
+/// ```
+#[derive(Debug, Error, Clone)]
+pub enum HookError {
+    /// The hook name is not a git hook.
+    ///
+    /// This error occurs when the configuration declares a hook git would never run.
+    #[error("Unknown git hook '{name}'")]
+    UnknownHook {
+        /// The unrecognized hook name.
+        name: String,
+    },
+
+    /// A hook file exists that was not installed by package tools.
+    ///
+    /// This error occurs when installing would overwrite a user-provided hook.
+    #[error(
+        "Hook '{name}' at '{path}' is not managed by workspace tools; use --force to replace it"
+    )]
+    UnmanagedHook {
+        /// The hook name.
+        name: String,
+        /// The path of the existing hook file.
+        path: PathBuf,
+    },
+
+    /// Filesystem operation on a hook file failed.
+    #[error("Filesystem error at '{path}': {reason}")]
+    FileSystemError {
+        /// The path involved in the failed operation.
+        path: PathBuf,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// Git operation failed (e.g., reading or writing `core.hooksPath`).
+    #[error("Git operation '{operation}' failed: {reason}")]
+    GitError {
+        /// The git operation that failed.
+        operation: String,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// The hooks configuration is invalid.
+    #[error("Invalid hooks configuration: {reason}")]
+    InvalidConfig {
+        /// Description of why the configuration is invalid.
+        reason: String,
+    },
+
+    /// A hook with the same name is already registered.
+    ///
+    /// This error occurs when two custom hooks are registered under one name.
+    #[error("Hook '{name}' is already registered")]
+    DuplicateHook {
+        /// The duplicated hook name.
+        name: String,
+    },
+
+    /// The context shared with hook implementations could not be loaded.
+    ///
+    /// This error occurs when pending changesets or changed files cannot be read.
+    #[error("Failed to load hook context: {reason}")]
+    ContextError {
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// A hook implementation failed to run.
+    ///
+    /// This is distinct from a hook that ran and rejected the operation, which is
+    /// reported as a failed `HookOutcome`.
+    #[error("Hook '{hook}' failed to execute: {reason}")]
+    ExecutionFailed {
+        /// The name of the hook implementation.
+        hook: String,
+        /// Description of the failure.
+        reason: String,
+    },
+}
+
+impl AsRef<str> for HookError {
+    /// Returns a string representation of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::HookError;
+    ///
+    /// let error = HookError::UnknownHook { name: "pre-deploy".to_string() };
+    /// assert_eq!(error.as_ref(), "unknown hook");
+    /// ```
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::UnknownHook { .. } => "unknown hook",
+            Self::UnmanagedHook { .. } => "unmanaged hook",
+            Self::FileSystemError { .. } => "hook filesystem error",
+            Self::GitError { .. } => "hook git error",
+            Self::InvalidConfig { .. } => "invalid hooks configuration",
+            Self::DuplicateHook { .. } => "duplicate hook",
+            Self::ContextError { .. } => "hook context error",
+            Self::ExecutionFailed { .. } => "hook execution failed",
+        }
+    }
+}
+
+impl HookError {
+    /// Returns whether this error is transient and might succeed on retry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::HookError;
+    /// use std::path::PathBuf;
+    ///
+    /// let fs_error = HookError::FileSystemError {
+    ///     path: PathBuf::from(".git/hooks/pre-commit"),
+    ///     reason: "resource busy".to_string(),
+    /// };
+    /// assert!(fs_error.is_transient());
+    ///
+    /// let unknown = HookError::UnknownHook { name: "pre-deploy".to_string() };
+    /// assert!(!unknown.is_transient());
+    /// ```
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::FileSystemError { .. } | Self::GitError { .. })
+    }
+}
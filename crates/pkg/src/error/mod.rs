@@ -19,6 +19,8 @@
 //! - **Error Conversion**: Automatic conversion from standard library and dependency errors
 //! - **Display Formatting**: Human-readable error messages
 //! - **Debug Information**: Detailed debug output for troubleshooting
+//! - **Diagnostics**: Stable error codes, remediation hints, and source locations through the
+//!   [`Diagnose`] trait
 //!
 //! # Error Categories
 //!
@@ -43,6 +45,15 @@
 //! ## AuditError
 //! Errors related to audits and health checks.
 //!
+//! ## HookError
+//! Errors related to git hook installation and drift detection.
+//!
+//! ## NotificationError
+//! Errors related to delivering release and failure notifications.
+//!
+//! ## EditorError
+//! Errors related to reading and editing JSON and JSONC files in place.
+//!
 //! # Example
 //!
 //! ```rust
@@ -104,11 +115,15 @@ pub use self::changelog::{ChangelogError, ChangelogResult};
 pub use self::changes::{ChangesError, ChangesResult};
 pub use self::changeset::{ChangesetError, ChangesetResult};
 pub use self::config::{ConfigError, ConfigResult};
+pub use self::editor::{EditorError, EditorResult};
+pub use self::hooks::{HookError, HookResult};
+pub use self::notification::{NotificationError, NotificationResult};
 pub use self::upgrade::{UpgradeError, UpgradeResult};
 pub use self::version::{VersionError, VersionResult};
 
-// Re-export context and recovery types
+// Re-export diagnostics, context and recovery types
 pub use self::context::{ErrorContext, WithContext};
+pub use self::diagnostic::{Diagnose, Diagnostic, SourceLocation, SourceSpan};
 pub use self::recovery::{
     ErrorRecoveryManager, LogLevel, RecoveryResult, RecoveryStats, RecoveryStrategy,
 };
@@ -119,11 +134,15 @@ pub mod changelog;
 pub mod changes;
 pub mod changeset;
 pub mod config;
+pub mod editor;
+pub mod hooks;
+pub mod notification;
 pub mod upgrade;
 pub mod version;
 
 // Error handling utilities
 pub mod context;
+pub mod diagnostic;
 pub mod recovery;
 
 #[cfg(test)]
@@ -151,6 +170,9 @@ mod tests;
 ///         Error::Changelog(e) => eprintln!("Changelog error: {}", e),
 ///         Error::Upgrade(e) => eprintln!("Upgrade error: {}", e),
 ///         Error::Audit(e) => eprintln!("Audit error: {}", e),
+///         Error::Hook(e) => eprintln!("Hook error: {}", e),
+///         Error::Notification(e) => eprintln!("Notification error: {}", e),
+///         Error::Editor(e) => eprintln!("Editor error: {}", e),
 ///         Error::FileSystem(e) => eprintln!("Filesystem error: {}", e),
 ///         Error::Git(e) => eprintln!("Git error: {}", e),
 ///         Error::IO(e) => eprintln!("I/O error: {}", e),
@@ -218,6 +240,24 @@ pub enum Error {
     #[error("Audit error: {0}")]
     Audit(#[from] AuditError),
 
+    /// Git hook management error.
+    ///
+    /// This variant wraps errors from installing, inspecting, and removing git hooks.
+    #[error("Hook error: {0}")]
+    Hook(#[from] HookError),
+
+    /// Notification delivery error.
+    ///
+    /// This variant wraps errors from sending release and failure notifications.
+    #[error("Notification error: {0}")]
+    Notification(#[from] NotificationError),
+
+    /// JSON editing error.
+    ///
+    /// This variant wraps errors from reading and editing JSON and JSONC files.
+    #[error("Editor error: {0}")]
+    Editor(#[from] EditorError),
+
     /// Filesystem operation error from sublime_standard_tools.
     ///
     /// This variant wraps errors from filesystem operations provided by the
@@ -273,6 +313,9 @@ impl AsRef<str> for Error {
             Self::Changelog(e) => e.as_ref(),
             Self::Upgrade(e) => e.as_ref(),
             Self::Audit(e) => e.as_ref(),
+            Self::Hook(e) => e.as_ref(),
+            Self::Notification(e) => e.as_ref(),
+            Self::Editor(e) => e.as_ref(),
             Self::FileSystem(_) => "filesystem error",
             Self::Git(_) => "git error",
             Self::IO(_) => "io error",
@@ -358,6 +401,19 @@ impl From<sublime_standard_tools::error::ConfigError> for ConfigError {
                     message: format!("provider '{}': {}", provider, message),
                 }
             }
+            sublime_standard_tools::error::ConfigError::RemoteFetchError { url, message } => {
+                ConfigError::InvalidConfig {
+                    message: format!("remote configuration '{}': {}", url, message),
+                }
+            }
+            sublime_standard_tools::error::ConfigError::SignatureError { url, message } => {
+                ConfigError::InvalidConfig {
+                    message: format!(
+                        "remote configuration '{}' failed signature verification: {}",
+                        url, message
+                    ),
+                }
+            }
             sublime_standard_tools::error::ConfigError::Other(msg) => {
                 ConfigError::InvalidConfig { message: msg }
             }
@@ -398,6 +454,16 @@ impl From<sublime_git_tools::RepoError> for ChangelogError {
     }
 }
 
+/// Converts sublime_git_tools RepoError to HookError for git-related operations.
+///
+/// This implementation provides more specific error context for git operations
+/// during hook management.
+impl From<sublime_git_tools::RepoError> for HookError {
+    fn from(error: sublime_git_tools::RepoError) -> Self {
+        HookError::GitError { operation: "git operation".to_string(), reason: error.to_string() }
+    }
+}
+
 /// Converts sublime_git_tools RepoError to ChangesetError for git-related operations.
 ///
 /// This implementation provides more specific error context for git operations
@@ -445,6 +511,9 @@ impl Error {
             Self::Changelog(e) => e.is_transient(),
             Self::Upgrade(e) => e.is_transient(),
             Self::Audit(e) => e.is_transient(),
+            Self::Hook(e) => e.is_transient(),
+            Self::Notification(e) => e.is_transient(),
+            Self::Editor(e) => e.is_transient(),
             Self::FileSystem(_) | Self::Git(_) | Self::IO(_) => true,
             Self::Config(_) | Self::Json(_) => false,
         }
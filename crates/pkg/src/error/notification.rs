@@ -0,0 +1,135 @@
+//! Notification error types for package tools.
+//!
+//! **What**: Defines error types for delivering release and failure notifications to the
+//! channels declared in the `notifications` configuration section.
+//!
+//! **How**: Uses `thiserror` for error definitions with the channel involved in the failure.
+//! Implements `AsRef<str>` for string conversion.
+//!
+//! **Why**: To report why a Slack webhook, generic webhook, or SMTP server did not accept a
+//! notification without failing the release that triggered it.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::error::{NotificationError, NotificationResult};
+//!
+//! fn check_status(channel: &str, status: u16) -> NotificationResult<()> {
+//!     if status >= 400 {
+//!         return Err(NotificationError::DeliveryFailed {
+//!             channel: channel.to_string(),
+//!             reason: format!("HTTP {status}"),
+//!         });
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use thiserror::Error;
+
+/// Result type alias for notification operations.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::{NotificationError, NotificationResult};
+///
+/// fn deliver() -> NotificationResult<()> {
+///     Ok(())
+/// }
+/// ```
+pub type NotificationResult<T> = Result<T, NotificationError>;
+
+/// Errors that can occur while sending notifications.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::error::NotificationError;
+///
+/// let error = NotificationError::Timeout { channel: "slack #1".to_string(), timeout_secs: 10 };
+///
+/// assert_eq!(error.as_ref(), "notification timeout");
+/// assert!(error.is_transient());
+/// ```
+#[derive(Debug, Error, Clone)]
+pub enum NotificationError {
+    /// The notifications configuration is invalid.
+    #[error("Invalid notifications configuration: {reason}")]
+    InvalidConfig {
+        /// Description of why the configuration is invalid.
+        reason: String,
+    },
+
+    /// A channel did not accept the notification.
+    ///
+    /// This error occurs when a webhook answers with an error status or an SMTP server
+    /// rejects a command.
+    #[error("Failed to deliver notification to '{channel}': {reason}")]
+    DeliveryFailed {
+        /// The channel the notification was sent to.
+        channel: String,
+        /// Description of the failure.
+        reason: String,
+    },
+
+    /// A channel did not answer within `notifications.timeout_secs`.
+    #[error("Notification to '{channel}' timed out after {timeout_secs}s")]
+    Timeout {
+        /// The channel the notification was sent to.
+        channel: String,
+        /// The timeout that elapsed, in seconds.
+        timeout_secs: u64,
+    },
+
+    /// The notification was not sent because offline mode is enabled.
+    #[error("Notification to '{channel}' skipped: offline mode is enabled")]
+    OfflineSkipped {
+        /// The channel the notification would have been sent to.
+        channel: String,
+    },
+}
+
+impl AsRef<str> for NotificationError {
+    /// Returns a string representation of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::NotificationError;
+    ///
+    /// let error = NotificationError::InvalidConfig { reason: "no recipients".to_string() };
+    /// assert_eq!(error.as_ref(), "invalid notifications configuration");
+    /// ```
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::InvalidConfig { .. } => "invalid notifications configuration",
+            Self::DeliveryFailed { .. } => "notification delivery failed",
+            Self::Timeout { .. } => "notification timeout",
+            Self::OfflineSkipped { .. } => "notification skipped offline",
+        }
+    }
+}
+
+impl NotificationError {
+    /// Returns whether this error is transient and might succeed on retry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::error::NotificationError;
+    ///
+    /// let delivery = NotificationError::DeliveryFailed {
+    ///     channel: "webhook #1".to_string(),
+    ///     reason: "HTTP 503".to_string(),
+    /// };
+    /// assert!(delivery.is_transient());
+    ///
+    /// let config = NotificationError::InvalidConfig { reason: "bad url".to_string() };
+    /// assert!(!config.is_transient());
+    /// ```
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::DeliveryFailed { .. } | Self::Timeout { .. })
+    }
+}
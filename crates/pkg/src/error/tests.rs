@@ -6,9 +6,9 @@
 
 use crate::error::{
     AuditError, AuditResult, ChangelogError, ChangelogResult, ChangesError, ChangesResult,
-    ChangesetError, ChangesetResult, ConfigError, ConfigResult, Error, ErrorContext,
-    ErrorRecoveryManager, LogLevel, RecoveryResult, RecoveryStrategy, Result, UpgradeError,
-    UpgradeResult, VersionError, VersionResult,
+    ChangesetError, ChangesetResult, ConfigError, ConfigResult, EditorError, Error, ErrorContext,
+    ErrorRecoveryManager, HookError, HookResult, LogLevel, NotificationError, RecoveryResult,
+    RecoveryStrategy, Result, UpgradeError, UpgradeResult, VersionError, VersionResult,
 };
 use std::path::PathBuf;
 use std::time::Duration;
@@ -221,6 +221,21 @@ mod version {
         assert!(error.to_string().contains("/workspace"));
     }
 
+    #[test]
+    fn test_version_error_stale_changeset_packages() {
+        use crate::error::Diagnose;
+
+        let error = VersionError::StaleChangesetPackages {
+            packages: vec!["@myorg/old".to_string(), "@myorg/gone".to_string()],
+            workspace_root: PathBuf::from("/workspace"),
+        };
+
+        assert_eq!(error.as_ref(), "stale changeset packages");
+        assert!(error.to_string().contains("@myorg/old, @myorg/gone"));
+        assert!(error.help().is_some_and(|help| help.contains("changeset clean")));
+        assert!(!error.is_recoverable());
+    }
+
     #[test]
     fn test_version_error_resolution_failed() {
         let error = VersionError::ResolutionFailed {
@@ -327,6 +342,19 @@ mod changeset {
         assert!(!error.is_transient());
     }
 
+    #[test]
+    fn test_changeset_error_promotion_rejected() {
+        let error = ChangesetError::PromotionRejected {
+            reference: "feature/test".to_string(),
+            environment: "production".to_string(),
+            reason: "not promoted to 'staging' yet".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "changeset promotion rejected");
+        assert!(error.to_string().contains("production"));
+        assert!(!error.is_transient());
+    }
+
     #[test]
     fn test_changeset_error_invalid_branch() {
         let error = ChangesetError::InvalidBranch {
@@ -841,6 +869,35 @@ mod upgrade {
         assert!(error.is_registry_related());
     }
 
+    #[test]
+    fn test_upgrade_error_rate_limited() {
+        let error = UpgradeError::RateLimited {
+            package: "lodash".to_string(),
+            registry: "https://registry.npmjs.org".to_string(),
+            retry_after: Some(std::time::Duration::from_secs(30)),
+        };
+
+        assert_eq!(error.as_ref(), "rate limited");
+        assert!(error.to_string().contains("retry after 30s"));
+        assert_eq!(error.retry_after(), Some(std::time::Duration::from_secs(30)));
+        assert!(error.is_transient());
+        assert!(error.is_registry_related());
+    }
+
+    #[test]
+    fn test_upgrade_error_service_unavailable_without_retry_after() {
+        let error = UpgradeError::ServiceUnavailable {
+            package: "lodash".to_string(),
+            registry: "https://registry.npmjs.org".to_string(),
+            retry_after: None,
+        };
+
+        assert_eq!(error.as_ref(), "service unavailable");
+        assert!(!error.to_string().contains("retry after"));
+        assert_eq!(error.retry_after(), None);
+        assert!(error.is_transient());
+    }
+
     #[test]
     fn test_upgrade_error_authentication_failed() {
         let error = UpgradeError::AuthenticationFailed {
@@ -964,6 +1021,38 @@ mod upgrade {
         assert!(error.is_backup_related());
     }
 
+    #[test]
+    fn test_upgrade_error_aborted() {
+        let error = UpgradeError::Aborted { packages_processed: 2 };
+
+        assert_eq!(error.as_ref(), "aborted");
+        assert!(error.to_string().contains("2 package(s)"));
+        assert!(!error.is_transient());
+        assert!(!error.is_backup_related());
+    }
+
+    #[test]
+    fn test_upgrade_error_verification_failed() {
+        let error = UpgradeError::VerificationFailed {
+            command: "pnpm -r test".to_string(),
+            reason: "exit code 1".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "verification failed");
+        assert!(error.to_string().contains("pnpm -r test"));
+        assert!(!error.is_transient());
+    }
+
+    #[test]
+    fn test_upgrade_error_offline_skipped() {
+        let error = UpgradeError::OfflineSkipped { package: "lodash".to_string() };
+
+        assert_eq!(error.as_ref(), "offline skipped");
+        assert!(error.to_string().contains("lodash"));
+        assert!(error.is_registry_related());
+        assert!(!error.is_transient());
+    }
+
     #[test]
     fn test_upgrade_result_ok() {
         let result: UpgradeResult<usize> = Ok(3);
@@ -1088,6 +1177,29 @@ mod audit {
         assert!(error.is_transient());
     }
 
+    #[test]
+    fn test_audit_error_check_registration() {
+        let duplicate = AuditError::DuplicateCheck { id: "naming".to_string() };
+        assert_eq!(duplicate.as_ref(), "duplicate check");
+        assert!(duplicate.to_string().contains("naming"));
+
+        let unknown = AuditError::UnknownCheck { id: "naming".to_string() };
+        assert_eq!(unknown.as_ref(), "unknown check");
+        assert!(!unknown.is_transient());
+    }
+
+    #[test]
+    fn test_audit_error_fix_failed() {
+        let error = AuditError::FixFailed {
+            path: PathBuf::from("packages/app/package.json"),
+            reason: "file changed since the fix was planned".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "fix failed");
+        assert!(error.to_string().contains("packages/app/package.json"));
+        assert!(error.to_string().contains("changed since"));
+    }
+
     #[test]
     fn test_audit_error_workspace_analysis_failed() {
         let error = AuditError::WorkspaceAnalysisFailed { reason: "no packages found".to_string() };
@@ -1134,6 +1246,169 @@ mod audit {
     }
 }
 
+// =============================================================================
+// Hook Error Tests
+// =============================================================================
+
+mod hooks {
+    use super::*;
+
+    #[test]
+    fn test_hook_error_unknown_hook() {
+        let error = HookError::UnknownHook { name: "pre-deploy".to_string() };
+
+        assert_eq!(error.as_ref(), "unknown hook");
+        assert!(error.to_string().contains("pre-deploy"));
+        assert!(!error.is_transient());
+    }
+
+    #[test]
+    fn test_hook_error_unmanaged_hook() {
+        let error = HookError::UnmanagedHook {
+            name: "pre-commit".to_string(),
+            path: PathBuf::from(".git/hooks/pre-commit"),
+        };
+
+        assert_eq!(error.as_ref(), "unmanaged hook");
+        assert!(error.to_string().contains(".git/hooks/pre-commit"));
+        assert!(error.to_string().contains("--force"));
+    }
+
+    #[test]
+    fn test_hook_error_transient() {
+        let fs_error = HookError::FileSystemError {
+            path: PathBuf::from(".husky/pre-push"),
+            reason: "busy".to_string(),
+        };
+        let git_error = HookError::GitError {
+            operation: "set config".to_string(),
+            reason: "locked".to_string(),
+        };
+        let config_error = HookError::InvalidConfig { reason: "empty script".to_string() };
+
+        assert!(fs_error.is_transient());
+        assert!(git_error.is_transient());
+        assert!(!config_error.is_transient());
+    }
+
+    #[test]
+    fn test_hook_error_registry_variants() {
+        let duplicate = HookError::DuplicateHook { name: "commit-lint".to_string() };
+        let context = HookError::ContextError { reason: "storage unreadable".to_string() };
+        let execution = HookError::ExecutionFailed {
+            hook: "commit-lint".to_string(),
+            reason: "missing message file".to_string(),
+        };
+
+        assert_eq!(duplicate.as_ref(), "duplicate hook");
+        assert_eq!(context.as_ref(), "hook context error");
+        assert_eq!(execution.as_ref(), "hook execution failed");
+        assert!(execution.to_string().contains("commit-lint"));
+        assert!(!duplicate.is_transient());
+    }
+
+    #[test]
+    fn test_hook_result_err() {
+        let result: HookResult<()> = Err(HookError::UnknownHook { name: "x".to_string() });
+        assert!(result.is_err());
+    }
+}
+
+// =============================================================================
+// Notification Error Tests
+// =============================================================================
+
+mod notification {
+    use super::*;
+
+    #[test]
+    fn test_notification_error_delivery_failed() {
+        let error = NotificationError::DeliveryFailed {
+            channel: "slack #1".to_string(),
+            reason: "HTTP 500 Internal Server Error".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "notification delivery failed");
+        assert!(error.to_string().contains("slack #1"));
+        assert!(error.to_string().contains("HTTP 500"));
+        assert!(error.is_transient());
+    }
+
+    #[test]
+    fn test_notification_error_timeout() {
+        let error = NotificationError::Timeout { channel: "email #2".to_string(), timeout_secs: 5 };
+
+        assert_eq!(error.as_ref(), "notification timeout");
+        assert!(error.to_string().contains("5s"));
+        assert!(error.is_transient());
+    }
+
+    #[test]
+    fn test_notification_error_offline_skipped() {
+        let error = NotificationError::OfflineSkipped { channel: "slack #1".to_string() };
+
+        assert_eq!(error.as_ref(), "notification skipped offline");
+        assert!(error.to_string().contains("slack #1"));
+        assert!(!error.is_transient());
+    }
+
+    #[test]
+    fn test_notification_error_invalid_config() {
+        let error = NotificationError::InvalidConfig { reason: "no recipients".to_string() };
+
+        assert_eq!(error.as_ref(), "invalid notifications configuration");
+        assert!(!error.is_transient());
+
+        let error = Error::from(error);
+        assert_eq!(error.as_ref(), "invalid notifications configuration");
+        assert!(!error.is_transient());
+    }
+}
+
+// =============================================================================
+// Editor Error Tests
+// =============================================================================
+
+mod editor {
+    use super::*;
+    use crate::error::Diagnose;
+
+    #[test]
+    fn test_editor_error_syntax() {
+        let error = EditorError::Syntax {
+            path: PathBuf::from("package.json"),
+            line: 2,
+            column: 3,
+            reason: "comments are not allowed in strict JSON".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "invalid json syntax");
+        assert!(error.to_string().contains("line 2, column 3"));
+        assert!(!error.is_transient());
+        assert_eq!(error.code(), "editor::syntax");
+        assert!(error.help().unwrap().contains("strict JSON"));
+
+        let location = error.location().unwrap();
+        assert_eq!((location.line, location.column), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn test_editor_error_invalid_pointer() {
+        let error = EditorError::InvalidPointer {
+            pointer: "/extends/path".to_string(),
+            reason: "'/extends' is not an object or array".to_string(),
+        };
+
+        assert_eq!(error.as_ref(), "invalid json edit");
+        assert!(error.to_string().contains("/extends/path"));
+        assert_eq!(error.location().unwrap().key.as_deref(), Some("/extends/path"));
+
+        let error = Error::from(error);
+        assert_eq!(error.as_ref(), "invalid json edit");
+        assert!(!error.is_transient());
+    }
+}
+
 // =============================================================================
 // Main Error Type Tests
 // =============================================================================
@@ -1204,6 +1479,14 @@ mod main_error {
         assert!(error.to_string().contains("Audit error"));
     }
 
+    #[test]
+    fn test_error_hook_variant() {
+        let error = Error::Hook(HookError::UnknownHook { name: "pre-deploy".to_string() });
+
+        assert_eq!(error.as_ref(), "unknown hook");
+        assert!(error.to_string().contains("Hook error"));
+    }
+
     #[test]
     fn test_error_filesystem_variant() {
         let error = Error::FileSystem("file not found".to_string());
@@ -1726,3 +2009,99 @@ mod recovery {
         assert!(stats.attempts_by_error_type.is_empty());
     }
 }
+
+// =============================================================================
+// Diagnostic Tests
+// =============================================================================
+
+mod diagnostic {
+    use super::*;
+    use crate::error::{Diagnose, SourceLocation, SourceSpan};
+
+    #[test]
+    fn test_codes_follow_domain_and_variant() {
+        assert_eq!(ConfigError::NotFound { path: PathBuf::from("a") }.code(), "config::not_found");
+        assert_eq!(VersionError::NoPackagesToUpdate.code(), "version::no_packages_to_update");
+        assert_eq!(
+            ChangesError::InvalidCommitRef { reference: "x".to_string(), reason: String::new() }
+                .code(),
+            "changes::invalid_commit_ref"
+        );
+        assert_eq!(HookError::UnknownHook { name: "x".to_string() }.code(), "hook::unknown_hook");
+
+        let error = Error::Upgrade(UpgradeError::NetworkError { reason: "down".to_string() });
+        assert_eq!(error.code(), "upgrade::network_error");
+        assert_eq!(Error::Git("boom".to_string()).code(), "git::error");
+    }
+
+    #[test]
+    fn test_diagnostic_collects_message_help_and_location() {
+        let error = Error::Changes(ChangesError::PackageJsonParseError {
+            path: PathBuf::from("packages/core/package.json"),
+            reason: "expected `,` or `}` at line 4 column 3".to_string(),
+        });
+
+        let diagnostic = error.diagnostic();
+
+        assert_eq!(diagnostic.code, "changes::package_json_parse_error");
+        assert_eq!(diagnostic.message, error.to_string());
+        assert!(diagnostic.help.is_some());
+        assert_eq!(
+            diagnostic.location,
+            Some(SourceLocation::file("packages/core/package.json").with_position(4, 3))
+        );
+    }
+
+    #[test]
+    fn test_location_from_offending_key() {
+        let error = VersionError::InvalidVersionSpec {
+            package: "@org/app".to_string(),
+            dependency: "react".to_string(),
+            spec: "^x".to_string(),
+            reason: "not a range".to_string(),
+        };
+
+        assert_eq!(error.location(), Some(SourceLocation::key("react")));
+        assert!(error.help().unwrap().contains("react"));
+    }
+
+    #[test]
+    fn test_location_from_json_error() {
+        let json_error = serde_json::from_str::<serde_json::Value>("{\n  \"a\": }").unwrap_err();
+        let location = Error::Json(json_error).location().unwrap();
+
+        assert_eq!(location.line, Some(2));
+        assert!(location.path.is_none());
+    }
+
+    #[test]
+    fn test_errors_without_location_or_help() {
+        let error = AuditError::ReportGenerationFailed { reason: "oops".to_string() };
+
+        assert!(error.location().is_none());
+        assert!(error.help().is_none());
+    }
+
+    #[test]
+    fn test_locate_reported_position() {
+        let source = "[package_tools]\nversion = 1\n";
+        let location = SourceLocation::file("repo.config.toml").with_position(2, 11);
+
+        assert_eq!(location.locate(source), Some(SourceSpan { line: 2, column: 11, length: 1 }));
+        assert!(SourceLocation::file("x").with_position(9, 1).locate(source).is_none());
+    }
+
+    #[test]
+    fn test_locate_json_and_toml_keys() {
+        let json = "{\n  \"name\": \"app\",\n  \"dependencies\": {\n    \"react\": \"^x\"\n  }\n}";
+        let span = SourceLocation::key("dependencies.react").locate(json).unwrap();
+        assert_eq!(span, SourceSpan { line: 4, column: 5, length: 7 });
+
+        let toml = "[package_tools.changeset]\npath = \".changesets\"\n";
+        let span = SourceLocation::key("changeset.path").locate(toml).unwrap();
+        assert_eq!(span, SourceSpan { line: 2, column: 1, length: 4 });
+
+        assert!(SourceLocation::key("missing").locate(json).is_none());
+        assert!(SourceLocation::default().locate(json).is_none());
+    }
+}
@@ -28,6 +28,7 @@
 //! ```
 
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 /// Result type alias for upgrade operations.
@@ -132,6 +133,40 @@ pub enum UpgradeError {
         timeout_secs: u64,
     },
 
+    /// Registry rejected the request with HTTP 429 Too Many Requests.
+    ///
+    /// This error occurs when the registry keeps rate limiting a request after the
+    /// configured retries, or asks for a longer wait than the client accepts.
+    #[error(
+        "Registry '{registry}' rate limited the request for package '{package}'{}",
+        retry_hint(*.retry_after)
+    )]
+    RateLimited {
+        /// Name of the package being queried.
+        package: String,
+        /// URL of the registry.
+        registry: String,
+        /// Wait requested by the registry's `Retry-After` header, if any.
+        retry_after: Option<Duration>,
+    },
+
+    /// Registry answered with HTTP 503 Service Unavailable.
+    ///
+    /// This error occurs when the registry is still unavailable after the configured
+    /// retries, or asks for a longer wait than the client accepts.
+    #[error(
+        "Registry '{registry}' is temporarily unavailable for package '{package}'{}",
+        retry_hint(*.retry_after)
+    )]
+    ServiceUnavailable {
+        /// Name of the package being queried.
+        package: String,
+        /// URL of the registry.
+        registry: String,
+        /// Wait requested by the registry's `Retry-After` header, if any.
+        retry_after: Option<Duration>,
+    },
+
     /// Invalid registry response.
     ///
     /// This error occurs when the registry returns a response that cannot
@@ -392,6 +427,52 @@ pub enum UpgradeError {
         /// Description of the corruption.
         reason: String,
     },
+
+    /// Upgrade application was aborted.
+    ///
+    /// This error occurs when an abort signal is raised while upgrades are
+    /// being applied. Packages processed before the abort may have been modified.
+    #[error("Upgrade application aborted after {packages_processed} package(s)")]
+    Aborted {
+        /// Number of packages processed before the abort.
+        packages_processed: usize,
+    },
+
+    /// Post-upgrade verification failed.
+    ///
+    /// This error occurs when the command configured in `upgrade.verify` exits
+    /// with a non-zero status or times out, and the failure policy is `rollback`.
+    /// The applied upgrades are rolled back from the backup.
+    #[error("Upgrade verification '{command}' failed: {reason}")]
+    VerificationFailed {
+        /// The verification command that failed.
+        command: String,
+        /// Why the command failed.
+        reason: String,
+    },
+
+    /// Registry lookup skipped in offline mode.
+    ///
+    /// This error occurs when offline mode is enabled and the package metadata
+    /// is not in the registry client's cache, so no request was made.
+    #[error("Registry lookup for package '{package}' skipped: offline mode is enabled")]
+    OfflineSkipped {
+        /// Name of the package that was not looked up.
+        package: String,
+    },
+
+    /// The operation deadline passed before the operation finished.
+    ///
+    /// This error occurs when the global deadline set with `--timeout` expires
+    /// during registry lookups. Upgrade detection carries the preview of the
+    /// lookups that finished in time.
+    #[error("Operation '{operation}' exceeded its deadline")]
+    DeadlineExceeded {
+        /// The operation that was cut short.
+        operation: String,
+        /// Results gathered before the deadline, if the operation had any.
+        partial: Option<Box<crate::upgrade::UpgradePreview>>,
+    },
 }
 
 impl AsRef<str> for UpgradeError {
@@ -416,6 +497,8 @@ impl AsRef<str> for UpgradeError {
             Self::PackageNotFound { .. } => "package not found",
             Self::AuthenticationFailed { .. } => "authentication failed",
             Self::RegistryTimeout { .. } => "registry timeout",
+            Self::RateLimited { .. } => "rate limited",
+            Self::ServiceUnavailable { .. } => "service unavailable",
             Self::InvalidResponse { .. } => "invalid response",
             Self::BackupFailed { .. } => "backup failed",
             Self::NoBackup { .. } => "no backup",
@@ -439,6 +522,10 @@ impl AsRef<str> for UpgradeError {
             Self::RateLimitExceeded { .. } => "rate limit exceeded",
             Self::MaxBackupsExceeded { .. } => "max backups exceeded",
             Self::BackupCorrupted { .. } => "backup corrupted",
+            Self::Aborted { .. } => "aborted",
+            Self::VerificationFailed { .. } => "verification failed",
+            Self::OfflineSkipped { .. } => "offline skipped",
+            Self::DeadlineExceeded { .. } => "deadline exceeded",
         }
     }
 }
@@ -469,6 +556,8 @@ impl UpgradeError {
         matches!(
             self,
             Self::RegistryTimeout { .. }
+                | Self::RateLimited { .. }
+                | Self::ServiceUnavailable { .. }
                 | Self::NetworkError { .. }
                 | Self::FileSystemError { .. }
                 | Self::ConcurrentModification { .. }
@@ -476,6 +565,34 @@ impl UpgradeError {
         )
     }
 
+    /// Returns the wait a registry asked for before the request may be retried.
+    ///
+    /// Set for `RateLimited` and `ServiceUnavailable` errors whose response carried a
+    /// `Retry-After` header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use sublime_pkg_tools::error::UpgradeError;
+    ///
+    /// let error = UpgradeError::RateLimited {
+    ///     package: "react".to_string(),
+    ///     registry: "https://registry.npmjs.org".to_string(),
+    ///     retry_after: Some(Duration::from_secs(30)),
+    /// };
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    /// assert!(error.to_string().contains("retry after 30s"));
+    /// ```
+    #[must_use]
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. }
+            | Self::ServiceUnavailable { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
     /// Returns whether this error is related to registry operations.
     ///
     /// This helper method identifies errors that originate from registry
@@ -506,9 +623,13 @@ impl UpgradeError {
                 | Self::PackageNotFound { .. }
                 | Self::AuthenticationFailed { .. }
                 | Self::RegistryTimeout { .. }
+                | Self::RateLimited { .. }
+                | Self::ServiceUnavailable { .. }
                 | Self::InvalidResponse { .. }
                 | Self::NetworkError { .. }
                 | Self::RateLimitExceeded { .. }
+                | Self::OfflineSkipped { .. }
+                | Self::DeadlineExceeded { .. }
         )
     }
 
@@ -573,3 +694,8 @@ impl UpgradeError {
         }
     }
 }
+
+/// Formats the `Retry-After` suffix of rate limit errors.
+fn retry_hint(retry_after: Option<Duration>) -> String {
+    retry_after.map_or_else(String::new, |wait| format!(" (retry after {}s)", wait.as_secs()))
+}
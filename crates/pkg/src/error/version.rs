@@ -154,6 +154,21 @@ pub enum VersionError {
         workspace_root: PathBuf,
     },
 
+    /// Pending changesets list packages the workspace no longer has.
+    ///
+    /// This error occurs when a package listed in a changeset was removed or renamed
+    /// after the changeset was written, and no rename is configured for it.
+    #[error(
+        "Changesets list packages that are not in the workspace at '{workspace_root}': {}",
+        packages.join(", ")
+    )]
+    StaleChangesetPackages {
+        /// Names of the missing packages.
+        packages: Vec<String>,
+        /// Root path of the workspace.
+        workspace_root: PathBuf,
+    },
+
     /// Failed to read or parse package.json file.
     ///
     /// This error occurs when a package.json file cannot be read or contains
@@ -202,6 +217,28 @@ pub enum VersionError {
         strategy: String,
     },
 
+    /// Invalid version scheme.
+    ///
+    /// This error occurs when an unsupported version scheme is specified in configuration.
+    #[error("Invalid version scheme '{scheme}': expected 'semver' or 'calver'")]
+    InvalidScheme {
+        /// The invalid scheme string.
+        scheme: String,
+    },
+
+    /// Invalid package filter expression.
+    ///
+    /// This error occurs when a filter used to restrict a release to a subset of
+    /// packages is empty, contains an invalid glob, or cannot be used with the
+    /// configured versioning strategy.
+    #[error("Invalid package filter '{expression}': {reason}")]
+    InvalidFilter {
+        /// The filter expression as given.
+        expression: String,
+        /// Description of why the filter is invalid.
+        reason: String,
+    },
+
     /// Failed to apply version updates to package files.
     ///
     /// This error occurs when writing updated version numbers to package.json
@@ -339,10 +376,13 @@ impl AsRef<str> for VersionError {
             Self::InvalidBumpType { .. } => "invalid bump type",
             Self::CircularDependency { .. } => "circular dependency",
             Self::PackageNotFound { .. } => "package not found",
+            Self::StaleChangesetPackages { .. } => "stale changeset packages",
             Self::PackageJsonError { .. } => "package.json error",
             Self::ResolutionFailed { .. } => "version resolution failed",
             Self::PropagationFailed { .. } => "version propagation failed",
             Self::InvalidStrategy { .. } => "invalid versioning strategy",
+            Self::InvalidScheme { .. } => "invalid version scheme",
+            Self::InvalidFilter { .. } => "invalid package filter",
             Self::ApplyFailed { .. } => "failed to apply version updates",
             Self::DependencyNotFound { .. } => "dependency not found",
             Self::InvalidVersionSpec { .. } => "invalid version specification",
@@ -0,0 +1,186 @@
+//! Fuzz targets and property-test generators for version and range parsing.
+//!
+//! **What**: Provides byte-oriented fuzz targets for `Version::parse`, version bump math,
+//! snapshot formatting, and dependency range parsing, plus proptest strategies (in
+//! [`strategies`]) that generate valid, near-valid, and malformed inputs for the same code.
+//!
+//! **How**: Each target decodes arbitrary bytes into the inputs of one parser, runs it, and
+//! checks the invariants that must hold for any input (for example, a parsed version
+//! re-parses to itself). A target returns `Err` describing the violated invariant instead of
+//! panicking, so harnesses decide how to report it. The module is compiled with the
+//! `fuzzing` feature; the cargo-fuzz harness in `crates/pkg/fuzz` calls these targets.
+//!
+//! **Why**: CI automation feeds this crate version strings from third-party manifests.
+//! Malformed input must produce an error, never a panic, and fuzzing the parsers directly
+//! is the cheapest way to keep that guarantee as parsing grows.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::fuzz;
+//!
+//! assert!(fuzz::version_parse(b"1.2.3-beta.1+build.5").is_ok());
+//! assert!(fuzz::version_parse(&[0xff, 0xfe, b'.', b'1']).is_ok());
+//! ```
+
+pub mod strategies;
+
+#[cfg(test)]
+mod tests;
+
+use crate::types::dependency::{NpmAlias, VersionProtocol};
+use crate::types::{Version, VersionBump};
+use crate::version::{DependencyPropagator, SnapshotContext, SnapshotGenerator};
+
+/// Result of a fuzz target: `Err` holds a description of the violated invariant.
+pub type FuzzResult = Result<(), String>;
+
+/// Bump types exercised by the bump target, indexed by an input byte.
+const BUMPS: [VersionBump; 4] =
+    [VersionBump::Major, VersionBump::Minor, VersionBump::Patch, VersionBump::None];
+
+/// Fuzzes `Version::parse`.
+///
+/// Any input must parse or fail without panicking; a parsed version must display as a string
+/// that parses back to an equal version.
+pub fn version_parse(data: &[u8]) -> FuzzResult {
+    let input = String::from_utf8_lossy(data);
+    let Ok(version) = Version::parse(&input) else {
+        return Ok(());
+    };
+
+    let displayed = version.to_string();
+    match Version::parse(&displayed) {
+        Ok(reparsed) if reparsed == version => Ok(()),
+        Ok(reparsed) => {
+            Err(format!("'{input}' displays as '{displayed}' but re-parses as '{reparsed}'"))
+        }
+        Err(e) => Err(format!("'{input}' displays as '{displayed}', which fails to parse: {e}")),
+    }
+}
+
+/// Fuzzes version bump math.
+///
+/// The first byte selects the bump type and the remaining bytes are the version. A bump must
+/// not panic, even at `u64::MAX`; a successful major, minor, or patch bump must produce a
+/// release (no prerelease or build metadata) greater than the original version.
+pub fn version_bump(data: &[u8]) -> FuzzResult {
+    let Some((selector, rest)) = data.split_first() else {
+        return Ok(());
+    };
+    let bump = BUMPS[usize::from(*selector) % BUMPS.len()];
+    let Ok(version) = Version::parse(&String::from_utf8_lossy(rest)) else {
+        return Ok(());
+    };
+
+    check_bump(&version, bump)
+}
+
+/// Checks the invariants of bumping `version` by `bump`.
+pub(crate) fn check_bump(version: &Version, bump: VersionBump) -> FuzzResult {
+    let Ok(bumped) = version.bump(bump) else {
+        // Overflow is reported as an error, which is the expected outcome
+        return Ok(());
+    };
+
+    if bump == VersionBump::None {
+        return if &bumped == version {
+            Ok(())
+        } else {
+            Err(format!("bump none changed '{version}' to '{bumped}'"))
+        };
+    }
+
+    if bumped.is_prerelease() || !bumped.build().is_empty() {
+        return Err(format!("bump {bump} of '{version}' kept metadata: '{bumped}'"));
+    }
+    if bumped.as_semver() <= version.as_semver() {
+        return Err(format!("bump {bump} of '{version}' did not increase it: '{bumped}'"));
+    }
+
+    Ok(())
+}
+
+/// Fuzzes snapshot formatting.
+///
+/// The input is split on NUL bytes into a snapshot format template, a version, a branch
+/// name, and a commit hash. Building the generator, generating the snapshot, and
+/// `Version::snapshot` must not panic; a successful `Version::snapshot` must keep the
+/// version core and be a prerelease.
+pub fn snapshot_format(data: &[u8]) -> FuzzResult {
+    let input = String::from_utf8_lossy(data);
+    let mut parts = input.split('\0');
+    let format = parts.next().unwrap_or_default();
+    let version =
+        parts.next().and_then(|v| Version::parse(v).ok()).unwrap_or_else(|| Version::new(1, 0, 0));
+    let branch = parts.next().unwrap_or_default();
+    let commit = parts.next().unwrap_or_default();
+
+    check_snapshot(format, &version, branch, commit)
+}
+
+/// Checks the invariants of generating snapshots for the given inputs.
+pub(crate) fn check_snapshot(
+    format: &str,
+    version: &Version,
+    branch: &str,
+    commit: &str,
+) -> FuzzResult {
+    if let Ok(generator) = SnapshotGenerator::new(format) {
+        let context = SnapshotContext::with_timestamp(
+            version.clone(),
+            branch.to_string(),
+            commit.to_string(),
+            0,
+        );
+        // Invalid templates and contexts are errors; only panics are failures
+        let _ = generator.generate(&context);
+    }
+
+    if let Ok(snapshot) = version.snapshot(commit) {
+        if !snapshot.is_prerelease() {
+            return Err(format!("snapshot of '{version}' with '{commit}' is not a prerelease"));
+        }
+        if (snapshot.major(), snapshot.minor(), snapshot.patch())
+            != (version.major(), version.minor(), version.patch())
+        {
+            return Err(format!("snapshot of '{version}' changed its version core: '{snapshot}'"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Fuzzes dependency range parsing.
+///
+/// The input is a `package.json` version spec. Protocol detection and `npm:` alias parsing
+/// must not panic, a parsed alias must display as a spec that parses back to itself, and
+/// rewriting the spec's range to a new version must end with that version.
+pub fn dependency_spec(data: &[u8]) -> FuzzResult {
+    check_dependency_spec(&String::from_utf8_lossy(data))
+}
+
+/// Checks the invariants of parsing and rewriting a dependency spec.
+pub(crate) fn check_dependency_spec(spec: &str) -> FuzzResult {
+    let protocol = VersionProtocol::parse(spec);
+
+    if let Some(alias) = NpmAlias::parse(spec) {
+        if protocol != VersionProtocol::NpmAlias {
+            return Err(format!("'{spec}' parses as an alias but has protocol {protocol}"));
+        }
+        let displayed = alias.to_string();
+        if NpmAlias::parse(&displayed).as_ref() != Some(&alias) {
+            return Err(format!(
+                "alias '{spec}' displays as '{displayed}', which does not round-trip"
+            ));
+        }
+    }
+
+    let version = Version::new(2, 3, 4);
+    let rewritten = DependencyPropagator::range_with_version(spec, &version);
+    if !rewritten.ends_with(&version.to_string()) {
+        return Err(format!("rewriting '{spec}' to {version} produced '{rewritten}'"));
+    }
+
+    Ok(())
+}
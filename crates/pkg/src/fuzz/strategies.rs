@@ -0,0 +1,144 @@
+//! Proptest strategies for versions, bumps, snapshots, and dependency ranges.
+//!
+//! **What**: Generators for well-formed values (versions, bump types, commit hashes, branch
+//! names, snapshot templates, range specs) and for the malformed strings real manifests
+//! contain (`v1.2`, `1.2.3.4`, stray whitespace, non-ASCII, overflowing components).
+//!
+//! **How**: Built from proptest combinators. Malformed inputs mix mutations of valid versions
+//! with arbitrary strings, so shrinking converges on small, readable counterexamples.
+//!
+//! **Why**: Downstream crates and CI tooling can reuse the same generators in their own
+//! property tests instead of hand-writing version corpora.
+
+use crate::types::{Version, VersionBump};
+use proptest::prelude::*;
+
+/// Generates valid versions, with and without prerelease and build metadata.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use proptest::prelude::*;
+/// use sublime_pkg_tools::fuzz::strategies;
+///
+/// proptest! {
+///     #[test]
+///     fn roundtrip(version in strategies::version()) {
+///         prop_assert_eq!(Version::parse(&version.to_string()).ok(), Some(version));
+///     }
+/// }
+/// ```
+pub fn version() -> impl Strategy<Value = Version> {
+    version_string().prop_filter_map("valid version", |s| Version::parse(&s).ok())
+}
+
+/// Generates valid semantic version strings, including components near `u64::MAX`.
+pub fn version_string() -> impl Strategy<Value = String> {
+    let component = prop_oneof![4 => 0u64..1000, 1 => Just(u64::MAX), 1 => any::<u64>()];
+    (
+        component.clone(),
+        component.clone(),
+        component,
+        // Numeric prerelease identifiers cannot have leading zeros; build identifiers can
+        prop::option::of("(0|[1-9][0-9]{0,5}|[A-Za-z-][0-9A-Za-z-]{0,7})(\\.(0|[1-9][0-9]{0,5}|[A-Za-z-][0-9A-Za-z-]{0,7})){0,2}"),
+        prop::option::of("[0-9A-Za-z-]{1,8}(\\.[0-9A-Za-z-]{1,8}){0,2}"),
+    )
+        .prop_map(|(major, minor, patch, pre, build)| {
+            let mut version = format!("{major}.{minor}.{patch}");
+            if let Some(pre) = pre {
+                version.push('-');
+                version.push_str(&pre);
+            }
+            if let Some(build) = build {
+                version.push('+');
+                version.push_str(&build);
+            }
+            version
+        })
+}
+
+/// Generates malformed or surprising version strings as found in third-party manifests.
+pub fn malformed_version_string() -> impl Strategy<Value = String> {
+    prop_oneof![
+        version_string().prop_map(|v| format!("v{v}")),
+        version_string().prop_map(|v| format!(" {v} ")),
+        version_string().prop_map(|v| format!("{v}.4")),
+        version_string().prop_map(|v| v.replace('.', "..")),
+        version_string().prop_map(|v| format!("{v}-")),
+        version_string().prop_map(|v| format!("{v}+")),
+        (0u64..100, 0u64..100).prop_map(|(major, minor)| format!("{major}.{minor}")),
+        "[0-9]{20,30}\\.[0-9]\\.[0-9]",
+        "[0-9.+\\-a-z]{0,24}",
+        any::<String>(),
+    ]
+}
+
+/// Generates any bump type.
+pub fn version_bump() -> impl Strategy<Value = VersionBump> {
+    prop_oneof![
+        Just(VersionBump::Major),
+        Just(VersionBump::Minor),
+        Just(VersionBump::Patch),
+        Just(VersionBump::None),
+    ]
+}
+
+/// Generates commit hashes, from full hex SHAs to arbitrary (possibly non-ASCII) strings.
+pub fn commit_hash() -> impl Strategy<Value = String> {
+    prop_oneof![3 => "[0-9a-f]{40}", 1 => "[0-9a-f]{1,7}", 1 => any::<String>()]
+}
+
+/// Generates branch names, including names that need sanitizing.
+pub fn branch_name() -> impl Strategy<Value = String> {
+    prop_oneof![3 => "[a-z]{1,8}(/[A-Za-z0-9_.-]{1,12}){0,2}", 1 => any::<String>()]
+}
+
+/// Generates snapshot format templates, valid and invalid.
+pub fn snapshot_format() -> impl Strategy<Value = String> {
+    let variable = prop_oneof![
+        Just("{version}"),
+        Just("{branch}"),
+        Just("{commit}"),
+        Just("{short_commit}"),
+        Just("{timestamp}"),
+        Just("{unknown}"),
+        Just("{"),
+        Just("}"),
+    ];
+    let separator = prop_oneof![Just("-"), Just("."), Just("+"), Just("")];
+    prop::collection::vec((variable, separator), 0..5).prop_map(|parts| {
+        parts.into_iter().map(|(variable, separator)| format!("{variable}{separator}")).collect()
+    })
+}
+
+/// Generates semver ranges: a valid version with an optional comparison operator.
+pub fn range_spec() -> impl Strategy<Value = String> {
+    let operator = prop_oneof![
+        Just(""),
+        Just("^"),
+        Just("~"),
+        Just(">="),
+        Just(">"),
+        Just("<="),
+        Just("<"),
+        Just("="),
+    ];
+    (operator, version_string()).prop_map(|(op, v)| format!("{op}{v}"))
+}
+
+/// Generates `package.json` dependency specs: ranges, protocols, aliases, and garbage.
+pub fn dependency_spec() -> impl Strategy<Value = String> {
+    prop_oneof![
+        4 => range_spec(),
+        1 => range_spec().prop_map(|r| format!("workspace:{r}")),
+        1 => ("(@[a-z]{1,6}/)?[a-z]{1,8}", prop::option::of(range_spec()))
+            .prop_map(|(name, range)| match range {
+                Some(range) => format!("npm:{name}@{range}"),
+                None => format!("npm:{name}"),
+            }),
+        1 => "(file|link|portal):[./a-z]{0,12}",
+        1 => "[ *xX|^~<>=@:.0-9a-z-]{0,24}",
+        1 => range_spec().prop_map(|r| format!(" npm:pkg@{r} ")),
+        1 => any::<String>(),
+    ]
+}
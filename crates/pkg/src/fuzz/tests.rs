@@ -0,0 +1,90 @@
+//! Property tests running the fuzz targets over the proptest strategies.
+//!
+//! **What**: Checks that version parsing, bump math, snapshot formatting, and dependency
+//! range parsing never panic and keep their invariants for generated inputs.
+//!
+//! **How**: Each property feeds strategy output to the matching fuzz target or checker and
+//! asserts it reports no violated invariant. A few regression tests pin inputs that used to
+//! panic.
+//!
+//! **Why**: Keeps the no-panic guarantee for malformed manifest input enforced on every test
+//! run, not only when a fuzzer is running.
+
+#![allow(clippy::unwrap_used)]
+
+use super::strategies;
+use super::*;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn prop_valid_versions_roundtrip(version in strategies::version_string()) {
+        prop_assert!(Version::parse(&version).is_ok(), "failed to parse '{}'", version);
+        prop_assert_eq!(version_parse(version.as_bytes()), Ok(()));
+    }
+
+    #[test]
+    fn prop_malformed_versions_never_panic(input in strategies::malformed_version_string()) {
+        prop_assert_eq!(version_parse(input.as_bytes()), Ok(()));
+    }
+
+    #[test]
+    fn prop_arbitrary_bytes_never_panic(data in prop::collection::vec(any::<u8>(), 0..64)) {
+        prop_assert_eq!(version_parse(&data), Ok(()));
+        prop_assert_eq!(version_bump(&data), Ok(()));
+        prop_assert_eq!(snapshot_format(&data), Ok(()));
+        prop_assert_eq!(dependency_spec(&data), Ok(()));
+    }
+
+    #[test]
+    fn prop_bump_invariants(version in strategies::version(), bump in strategies::version_bump()) {
+        prop_assert_eq!(check_bump(&version, bump), Ok(()));
+    }
+
+    #[test]
+    fn prop_snapshot_invariants(
+        format in strategies::snapshot_format(),
+        version in strategies::version(),
+        branch in strategies::branch_name(),
+        commit in strategies::commit_hash(),
+    ) {
+        prop_assert_eq!(check_snapshot(&format, &version, &branch, &commit), Ok(()));
+    }
+
+    #[test]
+    fn prop_dependency_spec_invariants(spec in strategies::dependency_spec()) {
+        prop_assert_eq!(check_dependency_spec(&spec), Ok(()));
+    }
+}
+
+#[test]
+fn test_bump_overflow_is_an_error() {
+    let version = Version::new(u64::MAX, u64::MAX, u64::MAX);
+
+    assert!(version.bump(VersionBump::Major).is_err());
+    assert!(version.bump(VersionBump::Minor).is_err());
+    assert!(version.bump(VersionBump::Patch).is_err());
+    assert_eq!(check_bump(&version, VersionBump::Patch), Ok(()));
+}
+
+#[test]
+fn test_snapshot_with_multibyte_hash_does_not_panic() {
+    let version = Version::new(1, 2, 3);
+
+    // The 7-byte cut used to fall inside a multi-byte character
+    assert!(version.snapshot("abcdefé123").is_err());
+    assert_eq!(check_snapshot("{version}-{commit}", &version, "main", "abcdefé123"), Ok(()));
+}
+
+#[test]
+fn test_snapshot_generator_with_multibyte_commit() {
+    let generator = SnapshotGenerator::new("{version}-{short_commit}").unwrap();
+    let context = SnapshotContext::with_timestamp(
+        Version::new(1, 0, 0),
+        "main".to_string(),
+        "abcdefé123".to_string(),
+        0,
+    );
+
+    assert_eq!(generator.generate(&context).unwrap(), "1.0.0-abcdefé");
+}
@@ -0,0 +1,290 @@
+//! `AsyncFileSystem` backed by the commit tree of a Git reference.
+//!
+//! **What**: Defines `GitTreeFileSystem`, which reads files and lists directories as they
+//! were committed at a reference, and rejects every write.
+//!
+//! **How**: Creating the filesystem resolves the reference to a commit and indexes the paths
+//! of its tree, with their parent directories. Lookups normalize the requested path to an
+//! absolute path and match it against the index; file contents are read from the repository
+//! when requested.
+//!
+//! **Why**: Code written against `AsyncFileSystem` can then run unchanged against a past
+//! commit, including the monorepo detector, which lists directories through the filesystem.
+
+use path_clean::PathClean;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use sublime_git_tools::{Repo, RepoError};
+use sublime_standard_tools::error::{Error, FileSystemError, Result};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Kind of an entry of the commit tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryKind {
+    File,
+    Directory,
+}
+
+/// Filesystem that serves the workspace as committed at a Git reference.
+///
+/// Paths are those of the working directory: absolute paths under the repository root, or
+/// paths relative to the current directory. Reads of paths outside the repository fail with
+/// `FileSystemError::NotFound`, and writes always fail with `FileSystemError::ReadOnly`.
+///
+/// `metadata` is not available for committed files; use `exists` and `is_dir` instead.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::git_tree::GitTreeFileSystem;
+/// use sublime_standard_tools::filesystem::AsyncFileSystem;
+/// use sublime_git_tools::Repo;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fs = GitTreeFileSystem::new(Repo::open(".")?, "main~3")?;
+///
+/// let manifest = fs.read_file_string(&fs.repo_root().join("package.json")).await?;
+/// println!("package.json at {}: {manifest}", fs.commit());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GitTreeFileSystem {
+    /// Canonical working directory of the repository.
+    repo_root: PathBuf,
+    /// The reference as given.
+    git_ref: String,
+    /// SHA of the commit the reference pointed to.
+    commit: String,
+    /// Files and directories of the commit tree, by absolute path.
+    entries: Arc<BTreeMap<PathBuf, EntryKind>>,
+    /// Repository the file contents are read from.
+    repo: Arc<Mutex<Repo>>,
+}
+
+impl GitTreeFileSystem {
+    /// Creates a filesystem for the commit a reference points to.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo` - The repository to read from
+    /// * `git_ref` - Branch, tag, or commit SHA to read the workspace at
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reference cannot be resolved to a commit or its tree cannot
+    /// be read.
+    pub fn new(repo: Repo, git_ref: &str) -> std::result::Result<Self, RepoError> {
+        let commit = repo.get_sha_at_ref(git_ref)?;
+        let files = repo.get_files_at_ref(&commit)?;
+        let repo_root = repo.get_repo_path().to_path_buf();
+
+        let mut entries = BTreeMap::new();
+        entries.insert(repo_root.clone(), EntryKind::Directory);
+        for file in files {
+            let path = repo_root.join(&file);
+            for ancestor in path.ancestors().skip(1) {
+                if ancestor == repo_root
+                    || entries.insert(ancestor.to_path_buf(), EntryKind::Directory).is_some()
+                {
+                    break;
+                }
+            }
+            entries.insert(path, EntryKind::File);
+        }
+
+        log::debug!(
+            "Indexed {} entries of '{}' ({}) in {}",
+            entries.len(),
+            git_ref,
+            commit,
+            repo_root.display()
+        );
+
+        Ok(Self {
+            repo_root,
+            git_ref: git_ref.to_string(),
+            commit,
+            entries: Arc::new(entries),
+            repo: Arc::new(Mutex::new(repo)),
+        })
+    }
+
+    /// Returns the working directory of the repository, under which committed files appear.
+    #[must_use]
+    pub fn repo_root(&self) -> &Path {
+        &self.repo_root
+    }
+
+    /// Returns the reference the filesystem was created for.
+    #[must_use]
+    pub fn git_ref(&self) -> &str {
+        &self.git_ref
+    }
+
+    /// Returns the SHA of the commit the files are read from.
+    #[must_use]
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+
+    /// Normalizes a path to an absolute, clean path, resolving symlinks in the part that
+    /// exists on disk so it compares with the canonical repository root.
+    fn normalize(path: &Path) -> PathBuf {
+        let absolute = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            std::env::current_dir().unwrap_or_default().join(path)
+        }
+        .clean();
+
+        let mut missing = Vec::new();
+        let mut existing = absolute.as_path();
+        loop {
+            if let Ok(canonical) = existing.canonicalize() {
+                return missing.iter().rev().fold(canonical, |path, name| path.join(name));
+            }
+            match (existing.parent(), existing.file_name()) {
+                (Some(parent), Some(name)) => {
+                    missing.push(name.to_os_string());
+                    existing = parent;
+                }
+                _ => return absolute,
+            }
+        }
+    }
+
+    /// Returns the normalized path and the kind of its entry, if the tree has one.
+    fn entry(&self, path: &Path) -> (PathBuf, Option<EntryKind>) {
+        let path = Self::normalize(path);
+        let kind = self.entries.get(&path).copied();
+        (path, kind)
+    }
+
+    fn not_found(path: &Path) -> Error {
+        Error::FileSystem(FileSystemError::NotFound { path: path.to_path_buf() })
+    }
+
+    fn read_only(path: &Path) -> Error {
+        Error::FileSystem(FileSystemError::ReadOnly { path: path.to_path_buf() })
+    }
+
+    /// Returns the direct and, with `recursive`, indirect children of a directory.
+    fn children(&self, path: &Path, recursive: bool) -> Result<Vec<PathBuf>> {
+        let (dir, kind) = self.entry(path);
+        match kind {
+            Some(EntryKind::Directory) => {}
+            Some(EntryKind::File) => {
+                return Err(Error::FileSystem(FileSystemError::NotADirectory {
+                    path: path.to_path_buf(),
+                }));
+            }
+            None => return Err(Self::not_found(path)),
+        }
+
+        // Descendants of a path sort right after it, component by component
+        Ok(self
+            .entries
+            .range(dir.clone()..)
+            .skip(1)
+            .map(|(entry, _)| entry)
+            .take_while(|entry| entry.starts_with(&dir))
+            .filter(|entry| recursive || entry.parent() == Some(dir.as_path()))
+            .cloned()
+            .collect())
+    }
+}
+
+impl fmt::Debug for GitTreeFileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GitTreeFileSystem")
+            .field("repo_root", &self.repo_root)
+            .field("git_ref", &self.git_ref)
+            .field("commit", &self.commit)
+            .field("entries", &self.entries.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncFileSystem for GitTreeFileSystem {
+    async fn read_file(&self, path: &Path) -> Result<Vec<u8>> {
+        self.read_file_string(path).await.map(String::into_bytes)
+    }
+
+    async fn write_file(&self, path: &Path, _contents: &[u8]) -> Result<()> {
+        Err(Self::read_only(path))
+    }
+
+    async fn read_file_string(&self, path: &Path) -> Result<String> {
+        let (absolute, kind) = self.entry(path);
+        match kind {
+            Some(EntryKind::File) => {}
+            Some(EntryKind::Directory) => {
+                return Err(Error::FileSystem(FileSystemError::NotAFile {
+                    path: path.to_path_buf(),
+                }));
+            }
+            None => return Err(Self::not_found(path)),
+        }
+
+        let relative = absolute
+            .strip_prefix(&self.repo_root)
+            .map_err(|_| Self::not_found(path))?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let repo = self.repo.lock().map_err(|_| {
+            Error::FileSystem(FileSystemError::Operation(
+                "Git tree repository lock is poisoned".to_string(),
+            ))
+        })?;
+        repo.get_file_content_at_ref(&self.commit, &relative)
+            .map_err(|e| {
+                Error::FileSystem(FileSystemError::Io {
+                    path: path.to_path_buf(),
+                    message: format!("Failed to read '{relative}' at {}: {e}", self.commit),
+                })
+            })?
+            .ok_or_else(|| Self::not_found(path))
+    }
+
+    async fn write_file_string(&self, path: &Path, _contents: &str) -> Result<()> {
+        Err(Self::read_only(path))
+    }
+
+    async fn create_dir_all(&self, path: &Path) -> Result<()> {
+        Err(Self::read_only(path))
+    }
+
+    async fn remove(&self, path: &Path) -> Result<()> {
+        Err(Self::read_only(path))
+    }
+
+    async fn exists(&self, path: &Path) -> bool {
+        self.entry(path).1.is_some()
+    }
+
+    async fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.children(path, false)
+    }
+
+    async fn walk_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.children(path, true)
+    }
+
+    async fn metadata(&self, path: &Path) -> Result<std::fs::Metadata> {
+        Err(Error::FileSystem(FileSystemError::Operation(format!(
+            "Metadata is not available for '{}' at Git reference '{}'",
+            path.display(),
+            self.git_ref
+        ))))
+    }
+
+    async fn is_dir(&self, path: &Path) -> bool {
+        self.entry(path).1 == Some(EntryKind::Directory)
+    }
+}
@@ -0,0 +1,41 @@
+//! Read-only view of the workspace as committed at a Git reference.
+//!
+//! **What**: Provides `GitTreeFileSystem`, an `AsyncFileSystem` that serves files and
+//! directories from the commit tree of a Git reference instead of the working directory.
+//!
+//! **How**: The file list of the commit is read once when the filesystem is created, and the
+//! reference is pinned to its commit SHA. Paths keep the layout of the working directory, so
+//! `<repo root>/packages/core/package.json` reads that file as it was committed. File
+//! contents are read from the repository on demand. Every write fails with
+//! `FileSystemError::ReadOnly`.
+//!
+//! **Why**: Version resolution, changeset storage, and change analysis are generic over
+//! `AsyncFileSystem`. Running them on a commit tree reproduces what a release would have
+//! looked like at a past commit, for debugging and audits, without checking it out.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::config::PackageToolsConfig;
+//! use sublime_pkg_tools::git_tree::GitTreeFileSystem;
+//! use sublime_pkg_tools::version::VersionResolver;
+//! use sublime_git_tools::Repo;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let fs = GitTreeFileSystem::new(Repo::open(".")?, "v1.2.0")?;
+//! let root = fs.repo_root().to_path_buf();
+//!
+//! let resolver = VersionResolver::with_filesystem(root, fs, PackageToolsConfig::default()).await?;
+//! for package in resolver.discover_packages().await? {
+//!     println!("{}@{}", package.name(), package.version());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod filesystem;
+
+#[cfg(test)]
+mod tests;
+
+pub use filesystem::GitTreeFileSystem;
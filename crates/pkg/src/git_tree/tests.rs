@@ -0,0 +1,164 @@
+//! Tests for the Git tree filesystem.
+//!
+//! **What**: Covers reads, directory listings, and rejected writes at a past commit, and
+//! version resolution and changeset storage running on top of it.
+//!
+//! **How**: A pnpm monorepo is committed to a temporary repository, then changed and
+//! committed again, so the first commit differs from both HEAD and the working directory.
+//!
+//! **Why**: To ensure code running on `GitTreeFileSystem` sees the workspace exactly as it
+//! was committed, never the current files.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::changeset::{ChangesetStorage, FileBasedChangesetStorage};
+use crate::config::PackageToolsConfig;
+use crate::types::{Changeset, VersionBump};
+use crate::version::VersionResolver;
+use std::fs;
+use std::path::Path;
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+use tempfile::TempDir;
+
+fn write_json(path: &Path, value: &serde_json::Value) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, serde_json::to_string_pretty(value).unwrap()).unwrap();
+}
+
+/// Commits a monorepo with `@test/core@1.0.0` and a pending changeset for it, then bumps
+/// the package to 2.0.0, removes the changeset, and commits again.
+///
+/// Returns the temporary directory and the SHA of the first commit.
+fn setup_history() -> (TempDir, String) {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    let repo = Repo::create(root.to_str().unwrap()).unwrap();
+    repo.config("Test User", "test@example.com").unwrap();
+
+    write_json(
+        &root.join("package.json"),
+        &serde_json::json!({ "name": "root", "version": "0.0.0", "private": true }),
+    );
+    fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - 'packages/*'\n").unwrap();
+    write_json(
+        &root.join("packages/core/package.json"),
+        &serde_json::json!({ "name": "@test/core", "version": "1.0.0" }),
+    );
+    let mut changeset =
+        Changeset::new("feature/core", VersionBump::Minor, vec!["production".to_string()]);
+    changeset.add_package("@test/core");
+    write_json(
+        &root.join(".changesets/feature-core.json"),
+        &serde_json::to_value(&changeset).unwrap(),
+    );
+    repo.add_all().unwrap().commit("feat: add core").unwrap();
+    let first = repo.get_current_sha().unwrap();
+
+    write_json(
+        &root.join("packages/core/package.json"),
+        &serde_json::json!({ "name": "@test/core", "version": "2.0.0" }),
+    );
+    fs::remove_file(root.join(".changesets/feature-core.json")).unwrap();
+    repo.add_all().unwrap().commit("chore: release core").unwrap();
+
+    (temp, first)
+}
+
+fn open_at(root: &Path, git_ref: &str) -> GitTreeFileSystem {
+    GitTreeFileSystem::new(Repo::open(root.to_str().unwrap()).unwrap(), git_ref).unwrap()
+}
+
+mod filesystem {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reads_files_as_committed() {
+        let (temp, first) = setup_history();
+        let fs = open_at(temp.path(), &first[..8]);
+        let manifest = fs.repo_root().join("packages/core/package.json");
+
+        assert_eq!(fs.commit(), first);
+        assert_eq!(fs.git_ref(), &first[..8]);
+        assert!(fs.read_file_string(&manifest).await.unwrap().contains("1.0.0"));
+        assert!(fs.exists(&fs.repo_root().join(".changesets/feature-core.json")).await);
+
+        let head = open_at(temp.path(), "HEAD");
+        assert!(head.read_file_string(&manifest).await.unwrap().contains("2.0.0"));
+        assert!(!head.exists(&head.repo_root().join(".changesets/feature-core.json")).await);
+    }
+
+    #[tokio::test]
+    async fn test_lists_directories_of_the_tree() {
+        let (temp, first) = setup_history();
+        let fs = open_at(temp.path(), &first);
+        let root = fs.repo_root().to_path_buf();
+
+        assert!(fs.is_dir(&root.join("packages")).await);
+        assert!(!fs.is_dir(&root.join("package.json")).await);
+        assert_eq!(
+            fs.read_dir(&root.join("packages")).await.unwrap(),
+            [root.join("packages/core")]
+        );
+        assert_eq!(
+            fs.walk_dir(&root.join("packages")).await.unwrap(),
+            [root.join("packages/core"), root.join("packages/core/package.json")]
+        );
+        assert!(fs.read_dir(&root.join("package.json")).await.is_err());
+        assert!(fs.read_dir(&root.join("missing")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_writes_and_paths_outside_the_tree() {
+        let (temp, first) = setup_history();
+        let fs = open_at(temp.path(), &first);
+        let manifest = fs.repo_root().join("package.json");
+
+        assert!(fs.write_file_string(&manifest, "{}").await.is_err());
+        assert!(fs.create_dir_all(&fs.repo_root().join("dist")).await.is_err());
+        assert!(fs.remove(&manifest).await.is_err());
+        assert!(fs.metadata(&manifest).await.is_err());
+        assert!(fs.read_file_string(&fs.repo_root().join("missing.json")).await.is_err());
+        assert!(!fs.exists(&std::env::temp_dir()).await);
+    }
+
+    #[test]
+    fn test_unknown_reference_fails() {
+        let (temp, _) = setup_history();
+        let repo = Repo::open(temp.path().to_str().unwrap()).unwrap();
+
+        assert!(GitTreeFileSystem::new(repo, "no-such-ref").is_err());
+    }
+}
+
+mod resolution {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolves_versions_as_of_a_past_commit() {
+        let (temp, first) = setup_history();
+        let fs = open_at(temp.path(), &first);
+        let root = fs.repo_root().to_path_buf();
+
+        let storage = FileBasedChangesetStorage::new(
+            root.clone(),
+            ".changesets".to_string(),
+            ".changesets/history".to_string(),
+            fs.clone(),
+        );
+        let pending = storage.list_pending().await.unwrap();
+        assert_eq!(pending.len(), 1);
+
+        let resolver = VersionResolver::with_filesystem(root, fs, PackageToolsConfig::default())
+            .await
+            .unwrap();
+        assert!(resolver.is_monorepo());
+        let resolution = resolver.resolve_versions(&pending[0]).await.unwrap();
+
+        assert_eq!(resolution.updates.len(), 1);
+        assert_eq!(resolution.updates[0].current_version.to_string(), "1.0.0");
+        assert_eq!(resolution.updates[0].next_version.to_string(), "1.1.0");
+    }
+}
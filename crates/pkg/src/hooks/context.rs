@@ -0,0 +1,216 @@
+//! Shared context for hook implementations.
+//!
+//! **What**: Provides `HookContext`, which carries everything a hook implementation needs to
+//! decide whether a git operation may proceed: the git hook being run and its arguments, the
+//! detected project, the changed files, the pending changesets, and the configuration.
+//!
+//! **How**: `HookContext::load` detects the workspace once, reads the files staged in the index,
+//! and lists pending changesets. Callers that already hold this information (or tests) build the
+//! context with `HookContext::new` and the `with_*` methods instead.
+//!
+//! **Why**: So hook implementations such as pre-commit changeset validation or commit-msg linting
+//! receive the workspace state instead of each re-detecting it.
+
+use crate::changeset::ChangesetManager;
+use crate::config::PackageToolsConfig;
+use crate::error::{HookError, HookResult};
+use crate::types::Changeset;
+use std::path::{Path, PathBuf};
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use sublime_standard_tools::project::{ProjectDescriptor, ProjectDetector};
+
+/// Workspace state shared with every hook implementation run for a git hook.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::PackageToolsConfig;
+/// use sublime_pkg_tools::hooks::HookContext;
+/// use std::path::PathBuf;
+///
+/// let context = HookContext::new("commit-msg", PathBuf::from("."), PackageToolsConfig::default())
+///     .with_args(vec![".git/COMMIT_EDITMSG".to_string()])
+///     .with_changed_files(vec![PathBuf::from("packages/core/src/index.ts")]);
+///
+/// assert_eq!(context.hook_name(), "commit-msg");
+/// assert_eq!(context.args().len(), 1);
+/// assert!(context.pending_changesets().is_empty());
+/// ```
+#[derive(Debug)]
+pub struct HookContext {
+    hook_name: String,
+    args: Vec<String>,
+    repo_root: PathBuf,
+    config: PackageToolsConfig,
+    project: Option<ProjectDescriptor>,
+    changed_files: Vec<PathBuf>,
+    pending_changesets: Vec<Changeset>,
+}
+
+impl HookContext {
+    /// Creates a context without project, changed files, or pending changesets.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook_name` - The git hook being run (e.g., `pre-commit`)
+    /// * `repo_root` - Root directory of the repository
+    /// * `config` - The package tools configuration
+    #[must_use]
+    pub fn new(
+        hook_name: impl Into<String>,
+        repo_root: PathBuf,
+        config: PackageToolsConfig,
+    ) -> Self {
+        Self {
+            hook_name: hook_name.into(),
+            args: Vec::new(),
+            repo_root,
+            config,
+            project: None,
+            changed_files: Vec::new(),
+            pending_changesets: Vec::new(),
+        }
+    }
+
+    /// Loads the context for a git hook from the repository.
+    ///
+    /// Changed files are the files staged in the index, relative to the repository root.
+    /// A repository that is not a detectable Node.js project yields a context without a
+    /// project rather than an error.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook_name` - The git hook being run (e.g., `pre-commit`)
+    /// * `args` - The arguments git passed to the hook
+    /// * `repo_root` - Root directory of the repository
+    /// * `config` - The package tools configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The repository cannot be opened or its index cannot be read
+    /// - Pending changesets cannot be listed
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use sublime_pkg_tools::hooks::HookContext;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let context = HookContext::load(
+    ///     "pre-commit",
+    ///     Vec::new(),
+    ///     PathBuf::from("."),
+    ///     PackageToolsConfig::default(),
+    /// )
+    /// .await?;
+    ///
+    /// println!("{} staged files", context.changed_files().len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load(
+        hook_name: impl Into<String>,
+        args: Vec<String>,
+        repo_root: PathBuf,
+        config: PackageToolsConfig,
+    ) -> HookResult<Self> {
+        let root = repo_root.to_str().ok_or_else(|| HookError::GitError {
+            operation: "open repository".to_string(),
+            reason: format!("path is not valid UTF-8: {}", repo_root.display()),
+        })?;
+        let repo = Repo::open(root)?;
+        let changed_files = repo.get_staged_files()?.into_iter().map(PathBuf::from).collect();
+
+        let project = ProjectDetector::new().detect(&repo_root, None).await.ok();
+
+        let manager = ChangesetManager::new(&repo_root, FileSystemManager::new(), config.clone())
+            .await
+            .map_err(|e| HookError::ContextError { reason: e.to_string() })?;
+        let pending_changesets = manager
+            .list_pending()
+            .await
+            .map_err(|e| HookError::ContextError { reason: e.to_string() })?;
+
+        Ok(Self::new(hook_name, repo_root, config)
+            .with_args(args)
+            .with_changed_files(changed_files)
+            .with_pending_changesets(pending_changesets)
+            .with_project(project))
+    }
+
+    /// Sets the arguments git passed to the hook.
+    #[must_use]
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Sets the detected project.
+    #[must_use]
+    pub fn with_project(mut self, project: Option<ProjectDescriptor>) -> Self {
+        self.project = project;
+        self
+    }
+
+    /// Sets the changed files, relative to the repository root.
+    #[must_use]
+    pub fn with_changed_files(mut self, changed_files: Vec<PathBuf>) -> Self {
+        self.changed_files = changed_files;
+        self
+    }
+
+    /// Sets the pending changesets.
+    #[must_use]
+    pub fn with_pending_changesets(mut self, pending_changesets: Vec<Changeset>) -> Self {
+        self.pending_changesets = pending_changesets;
+        self
+    }
+
+    /// Returns the git hook being run.
+    #[must_use]
+    pub fn hook_name(&self) -> &str {
+        &self.hook_name
+    }
+
+    /// Returns the arguments git passed to the hook.
+    ///
+    /// For `commit-msg` the first argument is the path of the commit message file.
+    #[must_use]
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Returns the repository root directory.
+    #[must_use]
+    pub fn repo_root(&self) -> &Path {
+        &self.repo_root
+    }
+
+    /// Returns the package tools configuration.
+    #[must_use]
+    pub fn config(&self) -> &PackageToolsConfig {
+        &self.config
+    }
+
+    /// Returns the detected project, if the repository is a Node.js project.
+    #[must_use]
+    pub fn project(&self) -> Option<&ProjectDescriptor> {
+        self.project.as_ref()
+    }
+
+    /// Returns the changed files, relative to the repository root.
+    #[must_use]
+    pub fn changed_files(&self) -> &[PathBuf] {
+        &self.changed_files
+    }
+
+    /// Returns the pending changesets.
+    #[must_use]
+    pub fn pending_changesets(&self) -> &[Changeset] {
+        &self.pending_changesets
+    }
+}
@@ -0,0 +1,359 @@
+//! Installation and drift detection for managed git hooks.
+//!
+//! **What**: Provides `HookInstaller`, which writes the hooks declared in `HooksConfig` into the
+//! repository, reports their state, and removes them again.
+//!
+//! **How**: Every installed hook carries a management header with a SHA-256 hash of the script
+//! body it was generated from. Comparing the hash embedded in the file against the hash of the
+//! file's current body reveals user edits; comparing it against the hash of the configured
+//! script reveals outdated hooks. When `hooks_path` is configured, hooks are written to that
+//! directory and registered through `core.hooksPath`.
+//!
+//! **Why**: To make hooks reproducible from configuration while never silently overwriting
+//! hooks a developer wrote or edited by hand.
+
+use crate::config::HooksConfig;
+use crate::error::{HookError, HookResult};
+use crate::hooks::{HookAction, HookOperation, HookState, HookStatus, HooksStatusReport};
+use sha2::{Digest, Sha256};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// First comment line of every managed hook.
+const MANAGED_HEADER: &str =
+    "# Managed by workspace tools. Edit the hooks configuration instead of this file.";
+
+/// Prefix of the comment line holding the hash of the script body.
+const HASH_PREFIX: &str = "# workspace-tools-hash: ";
+
+/// Git configuration key for a custom hooks directory.
+const HOOKS_PATH_KEY: &str = "core.hooksPath";
+
+/// Installs, inspects, and removes the git hooks declared in configuration.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::hooks::HookInstaller;
+/// use sublime_pkg_tools::config::HooksConfig;
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut config = HooksConfig::default();
+/// config.hooks.insert("pre-commit".to_string(), "workspace changeset check".to_string());
+///
+/// let installer = HookInstaller::new(PathBuf::from("."), config, FileSystemManager::new());
+/// installer.install(false).await?;
+///
+/// let report = installer.status().await?;
+/// assert!(!report.has_drift());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HookInstaller<F: AsyncFileSystem> {
+    repo_root: PathBuf,
+    config: HooksConfig,
+    fs: F,
+}
+
+impl<F: AsyncFileSystem> HookInstaller<F> {
+    /// Creates a new `HookInstaller`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repo_root` - Root directory of the git repository
+    /// * `config` - Hooks configuration
+    /// * `fs` - Filesystem implementation
+    #[must_use]
+    pub fn new(repo_root: PathBuf, config: HooksConfig, fs: F) -> Self {
+        Self { repo_root, config, fs }
+    }
+
+    /// Returns the hooks configuration.
+    #[must_use]
+    pub fn config(&self) -> &HooksConfig {
+        &self.config
+    }
+
+    /// Reports the state of every configured hook and of managed hooks that are no longer
+    /// configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened or its configuration read.
+    pub async fn status(&self) -> HookResult<HooksStatusReport> {
+        let repo = self.open_repo()?;
+        let (hooks_dir, hooks_path_registered) = self.resolve_hooks_dir(&repo)?;
+
+        let mut hooks = Vec::new();
+        for (name, script) in &self.config.hooks {
+            let path = hooks_dir.join(name);
+            let state = match self.read_hook(&path).await {
+                None => HookState::Missing,
+                Some(content) => match parse_managed(&content) {
+                    None => HookState::Unmanaged,
+                    Some((embedded, body)) if embedded != hash_body(body) => HookState::Modified,
+                    Some((embedded, _)) if embedded != hash_body(&normalize(script)) => {
+                        HookState::Outdated
+                    }
+                    Some(_) => HookState::UpToDate,
+                },
+            };
+            hooks.push(HookStatus { name: name.clone(), path, state });
+        }
+
+        hooks.extend(self.find_orphans(&hooks_dir).await);
+
+        Ok(HooksStatusReport { hooks_dir, hooks_path_registered, hooks })
+    }
+
+    /// Installs the configured hooks.
+    ///
+    /// Missing and outdated hooks are written; up-to-date hooks are left untouched. Hooks
+    /// modified by the user are skipped unless `force` is set. Managed hooks that are no longer
+    /// configured are removed. When `hooks_path` is configured, the directory is registered as
+    /// `core.hooksPath`.
+    ///
+    /// # Arguments
+    ///
+    /// * `force` - Replace modified and unmanaged hooks
+    ///
+    /// # Returns
+    ///
+    /// The action taken for each hook. Empty when hook management is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HookError::UnmanagedHook` if a hook not written by workspace tools would be
+    /// overwritten and `force` is not set; nothing is written in that case. Returns
+    /// filesystem or git errors if hooks cannot be written or registered.
+    pub async fn install(&self, force: bool) -> HookResult<Vec<HookOperation>> {
+        if !self.config.enabled {
+            return Ok(Vec::new());
+        }
+
+        let report = self.status().await?;
+
+        if !force
+            && let Some(unmanaged) = report.hooks.iter().find(|h| h.state == HookState::Unmanaged)
+        {
+            return Err(HookError::UnmanagedHook {
+                name: unmanaged.name.clone(),
+                path: unmanaged.path.clone(),
+            });
+        }
+
+        self.fs.create_dir_all(&report.hooks_dir).await.map_err(|e| {
+            HookError::FileSystemError { path: report.hooks_dir.clone(), reason: e.to_string() }
+        })?;
+
+        if !report.hooks_path_registered
+            && let Some(hooks_path) = &self.config.hooks_path
+        {
+            self.open_repo()?.set_config_value(HOOKS_PATH_KEY, hooks_path)?;
+        }
+
+        let mut operations = Vec::new();
+        for status in report.hooks {
+            let action = match status.state {
+                HookState::UpToDate => HookAction::Unchanged,
+                HookState::Modified if !force => HookAction::Skipped,
+                HookState::Missing | HookState::Unmanaged => {
+                    self.write_hook(&status.name, &status.path).await?;
+                    HookAction::Installed
+                }
+                HookState::Outdated | HookState::Modified => {
+                    self.write_hook(&status.name, &status.path).await?;
+                    HookAction::Updated
+                }
+                HookState::Orphaned => {
+                    self.remove_hook(&status.path).await?;
+                    HookAction::Removed
+                }
+            };
+            operations.push(HookOperation { name: status.name, path: status.path, action });
+        }
+
+        Ok(operations)
+    }
+
+    /// Removes managed hooks.
+    ///
+    /// Hooks not written by workspace tools are never touched. Hooks modified by the user are
+    /// skipped unless `force` is set. If `hooks_path` is configured and registered as
+    /// `core.hooksPath`, the setting is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `force` - Also remove modified hooks
+    ///
+    /// # Returns
+    ///
+    /// The action taken for each managed hook.
+    ///
+    /// # Errors
+    ///
+    /// Returns filesystem or git errors if hooks cannot be removed or the git configuration
+    /// cannot be updated.
+    pub async fn uninstall(&self, force: bool) -> HookResult<Vec<HookOperation>> {
+        let report = self.status().await?;
+
+        let mut operations = Vec::new();
+        for status in report.hooks {
+            let action = match status.state {
+                HookState::Missing | HookState::Unmanaged => continue,
+                HookState::Modified if !force => HookAction::Skipped,
+                HookState::UpToDate
+                | HookState::Outdated
+                | HookState::Modified
+                | HookState::Orphaned => {
+                    self.remove_hook(&status.path).await?;
+                    HookAction::Removed
+                }
+            };
+            operations.push(HookOperation { name: status.name, path: status.path, action });
+        }
+
+        if let Some(hooks_path) = &self.config.hooks_path {
+            let repo = self.open_repo()?;
+            if repo.get_config_value(HOOKS_PATH_KEY)?.as_deref() == Some(hooks_path.as_str()) {
+                repo.unset_config_value(HOOKS_PATH_KEY)?;
+            }
+        }
+
+        Ok(operations)
+    }
+
+    /// Opens the git repository at the repository root.
+    fn open_repo(&self) -> HookResult<Repo> {
+        let root = self.repo_root.to_str().ok_or_else(|| HookError::GitError {
+            operation: "open repository".to_string(),
+            reason: format!("path is not valid UTF-8: {}", self.repo_root.display()),
+        })?;
+        Ok(Repo::open(root)?)
+    }
+
+    /// Resolves the directory hooks are installed into.
+    ///
+    /// Uses the configured `hooks_path` when set, then an existing `core.hooksPath` (so hooks
+    /// land where git will run them even when another tool manages the setting), and finally
+    /// the `hooks` directory inside the common git directory.
+    ///
+    /// # Returns
+    ///
+    /// The hooks directory and whether git is configured to run hooks from it.
+    fn resolve_hooks_dir(&self, repo: &Repo) -> HookResult<(PathBuf, bool)> {
+        let registered = repo.get_config_value(HOOKS_PATH_KEY)?;
+
+        if let Some(hooks_path) = &self.config.hooks_path {
+            let is_registered = registered.as_deref() == Some(hooks_path.as_str());
+            return Ok((self.repo_root.join(hooks_path), is_registered));
+        }
+
+        match registered {
+            Some(existing) => Ok((self.repo_root.join(existing), true)),
+            None => Ok((repo.get_common_git_dir().join("hooks"), true)),
+        }
+    }
+
+    /// Reads a hook file, returning `None` if it does not exist or cannot be read.
+    async fn read_hook(&self, path: &Path) -> Option<String> {
+        if !self.fs.exists(path).await {
+            return None;
+        }
+        self.fs.read_file_string(path).await.ok()
+    }
+
+    /// Finds managed hooks in `hooks_dir` that are not configured.
+    async fn find_orphans(&self, hooks_dir: &Path) -> Vec<HookStatus> {
+        let Ok(mut entries) = self.fs.read_dir(hooks_dir).await else {
+            return Vec::new();
+        };
+        entries.sort();
+
+        let mut orphans = Vec::new();
+        for path in entries {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()).map(String::from) else {
+                continue;
+            };
+            if self.config.hooks.contains_key(&name) {
+                continue;
+            }
+            if let Some(content) = self.read_hook(&path).await
+                && parse_managed(&content).is_some()
+            {
+                orphans.push(HookStatus { name, path, state: HookState::Orphaned });
+            }
+        }
+        orphans
+    }
+
+    /// Writes the managed hook `name` to `path` and makes it executable.
+    async fn write_hook(&self, name: &str, path: &Path) -> HookResult<()> {
+        let script = self.config.hooks.get(name).ok_or_else(|| HookError::InvalidConfig {
+            reason: format!("hook '{}' is not configured", name),
+        })?;
+
+        self.fs.write_file_string(path, &render_hook(script)).await.map_err(|e| {
+            HookError::FileSystemError { path: path.to_path_buf(), reason: e.to_string() }
+        })?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+                .await
+                .map_err(|e| HookError::FileSystemError {
+                    path: path.to_path_buf(),
+                    reason: e.to_string(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// Deletes a hook file.
+    async fn remove_hook(&self, path: &Path) -> HookResult<()> {
+        self.fs.remove(path).await.map_err(|e| HookError::FileSystemError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Normalizes a script body to LF line endings with a single trailing newline.
+fn normalize(script: &str) -> String {
+    let mut body = script.replace("\r\n", "\n").trim_end().to_string();
+    body.push('\n');
+    body
+}
+
+/// Returns the hex-encoded SHA-256 hash of a script body.
+pub(crate) fn hash_body(body: &str) -> String {
+    let digest = Sha256::digest(body.replace("\r\n", "\n").as_bytes());
+    digest.iter().fold(String::with_capacity(64), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
+/// Renders the full hook file for a configured script.
+pub(crate) fn render_hook(script: &str) -> String {
+    let body = normalize(script);
+    format!("#!/bin/sh\n{}\n{}{}\n\n{}", MANAGED_HEADER, HASH_PREFIX, hash_body(&body), body)
+}
+
+/// Splits a managed hook into its embedded hash and script body.
+///
+/// Returns `None` if the file was not written by workspace tools.
+pub(crate) fn parse_managed(content: &str) -> Option<(&str, &str)> {
+    let start = content.find(HASH_PREFIX)?;
+    let rest = &content[start + HASH_PREFIX.len()..];
+    let (hash, body) = rest.split_once('\n')?;
+    let body = body.strip_prefix("\r\n").or_else(|| body.strip_prefix('\n')).unwrap_or(body);
+    Some((hash.trim(), body))
+}
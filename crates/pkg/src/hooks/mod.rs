@@ -0,0 +1,59 @@
+//! Managed git hooks for workspace repositories.
+//!
+//! **What**: Materializes the git hooks declared in the `hooks` configuration section into the
+//! repository, detects drift between installed hooks and configuration, and removes them.
+//!
+//! **How**: `HookInstaller` writes each configured script as an executable hook file with a
+//! management header that embeds a content hash. On later runs the hash tells apart hooks that
+//! are up to date, outdated (configuration changed), or modified by hand. Hooks can be installed
+//! into `.git/hooks` or into a versioned directory registered through `core.hooksPath`.
+//!
+//! Code that runs inside a hook implements the `Hook` trait and is added to a `HookRegistry`.
+//! Every hook receives a `HookContext` carrying the detected project, changed files, pending
+//! changesets, and configuration, loaded once per git hook invocation.
+//!
+//! **Why**: To share hooks such as `workspace changeset check` across a team from a single
+//! source of truth, replacing ad-hoc install scripts.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::config::HooksConfig;
+//! use sublime_pkg_tools::hooks::{HookInstaller, HookState};
+//! use sublime_standard_tools::filesystem::FileSystemManager;
+//! use std::path::PathBuf;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut config = HooksConfig::default();
+//! config.hooks_path = Some(".githooks".to_string());
+//! config.hooks.insert("pre-commit".to_string(), "workspace changeset check".to_string());
+//!
+//! let installer = HookInstaller::new(PathBuf::from("."), config, FileSystemManager::new());
+//!
+//! for operation in installer.install(false).await? {
+//!     println!("{}: {}", operation.name, operation.action);
+//! }
+//!
+//! for hook in installer.status().await?.hooks {
+//!     if hook.state != HookState::UpToDate {
+//!         println!("{} is {}", hook.name, hook.state);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod context;
+mod installer;
+mod registry;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use context::HookContext;
+pub use installer::HookInstaller;
+pub use registry::{Hook, HookRegistry};
+pub use types::{
+    HookAction, HookExecution, HookOperation, HookOutcome, HookState, HookStatus, HooksStatusReport,
+};
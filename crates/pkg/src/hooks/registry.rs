@@ -0,0 +1,171 @@
+//! Hook implementations and their registry.
+//!
+//! **What**: Defines the `Hook` trait implemented by code that runs inside git hooks, and
+//! `HookRegistry`, which holds hook implementations and runs those attached to a git hook.
+//!
+//! **How**: Each `Hook` names the git hooks it runs for and receives a shared `HookContext`.
+//! `HookRegistry::run` executes every matching hook in registration order and collects their
+//! outcomes, so one rejection does not hide the others.
+//!
+//! **Why**: To let the CLI and consumers plug custom checks into git hooks without re-detecting
+//! the workspace in each of them.
+
+use crate::config::KNOWN_GIT_HOOKS;
+use crate::error::{HookError, HookResult};
+use crate::hooks::{HookContext, HookExecution, HookOutcome};
+use async_trait::async_trait;
+use std::fmt;
+
+/// Code run when a git hook fires.
+///
+/// # Examples
+///
+/// ```rust
+/// use async_trait::async_trait;
+/// use sublime_pkg_tools::error::HookResult;
+/// use sublime_pkg_tools::hooks::{Hook, HookContext, HookOutcome};
+///
+/// struct RequireChangeset;
+///
+/// #[async_trait]
+/// impl Hook for RequireChangeset {
+///     fn name(&self) -> &str {
+///         "require-changeset"
+///     }
+///
+///     fn git_hooks(&self) -> &[&str] {
+///         &["pre-commit"]
+///     }
+///
+///     async fn execute(&self, context: &HookContext) -> HookResult<HookOutcome> {
+///         if !context.changed_files().is_empty() && context.pending_changesets().is_empty() {
+///             return Ok(HookOutcome::failed("create a changeset with `workspace changeset add`"));
+///         }
+///         Ok(HookOutcome::Passed)
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Hook: Send + Sync {
+    /// Returns the unique name of this hook implementation.
+    fn name(&self) -> &str;
+
+    /// Returns the git hooks (e.g., `pre-commit`, `commit-msg`) this implementation runs for.
+    fn git_hooks(&self) -> &[&str];
+
+    /// Runs the hook against the shared context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hook cannot run. A hook that runs and rejects the git
+    /// operation returns `Ok(HookOutcome::Failed { .. })` instead.
+    async fn execute(&self, context: &HookContext) -> HookResult<HookOutcome>;
+}
+
+/// Registry of hook implementations.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::config::PackageToolsConfig;
+/// use sublime_pkg_tools::hooks::{HookContext, HookRegistry};
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut registry = HookRegistry::new();
+/// registry.register(RequireChangeset)?;
+///
+/// let context = HookContext::load(
+///     "pre-commit",
+///     Vec::new(),
+///     PathBuf::from("."),
+///     PackageToolsConfig::default(),
+/// )
+/// .await?;
+///
+/// let executions = registry.run(&context).await?;
+/// if executions.iter().any(|e| !e.outcome.is_passed()) {
+///     std::process::exit(1);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct HookRegistry {
+    hooks: Vec<Box<dyn Hook>>,
+}
+
+impl HookRegistry {
+    /// Creates an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a hook implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A hook with the same name is already registered
+    /// - The hook runs for a name that is not a git hook
+    pub fn register(&mut self, hook: impl Hook + 'static) -> HookResult<&mut Self> {
+        if self.hooks.iter().any(|h| h.name() == hook.name()) {
+            return Err(HookError::DuplicateHook { name: hook.name().to_string() });
+        }
+        if let Some(unknown) = hook.git_hooks().iter().find(|name| !KNOWN_GIT_HOOKS.contains(name))
+        {
+            return Err(HookError::UnknownHook { name: (*unknown).to_string() });
+        }
+
+        self.hooks.push(Box::new(hook));
+        Ok(self)
+    }
+
+    /// Returns the names of the hook implementations that run for a git hook.
+    #[must_use]
+    pub fn hooks_for(&self, git_hook: &str) -> Vec<&str> {
+        self.matching(git_hook).into_iter().map(|h| h.name()).collect()
+    }
+
+    /// Returns the number of registered hook implementations.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Returns whether no hook implementation is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Runs every hook implementation attached to the context's git hook.
+    ///
+    /// Hooks run in registration order. All matching hooks run even when one rejects the
+    /// operation, so the user sees every problem at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised by a hook implementation that could not run.
+    pub async fn run(&self, context: &HookContext) -> HookResult<Vec<HookExecution>> {
+        let mut executions = Vec::new();
+        for hook in self.matching(context.hook_name()) {
+            let outcome = hook.execute(context).await?;
+            executions.push(HookExecution { hook: hook.name().to_string(), outcome });
+        }
+        Ok(executions)
+    }
+
+    fn matching(&self, git_hook: &str) -> Vec<&dyn Hook> {
+        self.hooks.iter().map(AsRef::as_ref).filter(|h| h.git_hooks().contains(&git_hook)).collect()
+    }
+}
+
+impl fmt::Debug for HookRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HookRegistry")
+            .field("hooks", &self.hooks.iter().map(|h| h.name()).collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,353 @@
+//! Tests for managed git hooks.
+//!
+//! **What**: Covers hook rendering, drift detection, installation, and removal.
+//!
+//! **How**: Creates real git repositories in temporary directories and drives
+//! `HookInstaller` against them.
+//!
+//! **Why**: To ensure hooks are never overwritten silently and drift is reported accurately.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::installer::{hash_body, parse_managed, render_hook};
+use super::{Hook, HookAction, HookContext, HookInstaller, HookOutcome, HookRegistry, HookState};
+use crate::config::{HooksConfig, PackageToolsConfig};
+use crate::error::{HookError, HookResult};
+use async_trait::async_trait;
+use std::path::PathBuf;
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tempfile::TempDir;
+
+fn create_repo() -> (TempDir, Repo) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = Repo::create(temp_dir.path().to_str().unwrap()).unwrap();
+    (temp_dir, repo)
+}
+
+fn config_with(hooks: &[(&str, &str)]) -> HooksConfig {
+    let mut config = HooksConfig::default();
+    for (name, script) in hooks {
+        config.hooks.insert((*name).to_string(), (*script).to_string());
+    }
+    config
+}
+
+fn installer(temp_dir: &TempDir, config: HooksConfig) -> HookInstaller<FileSystemManager> {
+    HookInstaller::new(temp_dir.path().to_path_buf(), config, FileSystemManager::new())
+}
+
+#[test]
+fn test_render_and_parse_roundtrip() {
+    let content = render_hook("workspace changeset check\r\n\n");
+
+    assert!(content.starts_with("#!/bin/sh\n"));
+    let (hash, body) = parse_managed(&content).unwrap();
+    assert_eq!(body, "workspace changeset check\n");
+    assert_eq!(hash, hash_body(body));
+}
+
+#[test]
+fn test_parse_unmanaged_hook() {
+    assert!(parse_managed("#!/bin/sh\nnpm test\n").is_none());
+}
+
+#[test]
+fn test_hash_ignores_line_endings() {
+    assert_eq!(hash_body("a\r\nb\n"), hash_body("a\nb\n"));
+    assert_ne!(hash_body("a\n"), hash_body("b\n"));
+}
+
+#[tokio::test]
+async fn test_install_into_git_hooks_dir() {
+    let (temp_dir, _repo) = create_repo();
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo pre-commit")]));
+
+    let operations = installer.install(false).await.unwrap();
+    assert_eq!(operations.len(), 1);
+    assert_eq!(operations[0].action, HookAction::Installed);
+
+    let hook_path = temp_dir.path().join(".git/hooks/pre-commit");
+    let content = std::fs::read_to_string(&hook_path).unwrap();
+    assert!(content.contains("echo pre-commit"));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&hook_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+
+    let report = installer.status().await.unwrap();
+    assert!(!report.has_drift());
+
+    let operations = installer.install(false).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Unchanged);
+}
+
+#[tokio::test]
+async fn test_status_reports_missing_hook() {
+    let (temp_dir, _repo) = create_repo();
+    let installer = installer(&temp_dir, config_with(&[("pre-push", "echo push")]));
+
+    let report = installer.status().await.unwrap();
+
+    assert_eq!(report.hooks[0].state, HookState::Missing);
+    assert!(report.has_drift());
+}
+
+#[tokio::test]
+async fn test_outdated_hook_is_updated() {
+    let (temp_dir, _repo) = create_repo();
+    installer(&temp_dir, config_with(&[("pre-commit", "echo v1")])).install(false).await.unwrap();
+
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo v2")]));
+    assert_eq!(installer.status().await.unwrap().hooks[0].state, HookState::Outdated);
+
+    let operations = installer.install(false).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Updated);
+    assert_eq!(installer.status().await.unwrap().hooks[0].state, HookState::UpToDate);
+}
+
+#[tokio::test]
+async fn test_modified_hook_is_skipped_without_force() {
+    let (temp_dir, _repo) = create_repo();
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo managed")]));
+    installer.install(false).await.unwrap();
+
+    let hook_path = temp_dir.path().join(".git/hooks/pre-commit");
+    let mut content = std::fs::read_to_string(&hook_path).unwrap();
+    content.push_str("echo local tweak\n");
+    std::fs::write(&hook_path, content).unwrap();
+
+    assert_eq!(installer.status().await.unwrap().hooks[0].state, HookState::Modified);
+
+    let operations = installer.install(false).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Skipped);
+    assert!(std::fs::read_to_string(&hook_path).unwrap().contains("local tweak"));
+
+    let operations = installer.install(true).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Updated);
+    assert!(!std::fs::read_to_string(&hook_path).unwrap().contains("local tweak"));
+}
+
+#[tokio::test]
+async fn test_unmanaged_hook_requires_force() {
+    let (temp_dir, _repo) = create_repo();
+    let hooks_dir = temp_dir.path().join(".git/hooks");
+    std::fs::create_dir_all(&hooks_dir).unwrap();
+    std::fs::write(hooks_dir.join("pre-commit"), "#!/bin/sh\nnpm test\n").unwrap();
+
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo managed")]));
+    assert_eq!(installer.status().await.unwrap().hooks[0].state, HookState::Unmanaged);
+
+    let result = installer.install(false).await;
+    assert!(matches!(result, Err(HookError::UnmanagedHook { .. })));
+
+    let operations = installer.install(true).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Installed);
+}
+
+#[tokio::test]
+async fn test_removed_hook_is_orphaned_and_cleaned_up() {
+    let (temp_dir, _repo) = create_repo();
+    installer(&temp_dir, config_with(&[("pre-commit", "echo a"), ("pre-push", "echo b")]))
+        .install(false)
+        .await
+        .unwrap();
+
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo a")]));
+    let report = installer.status().await.unwrap();
+    let orphan = report.hooks.iter().find(|h| h.name == "pre-push").unwrap();
+    assert_eq!(orphan.state, HookState::Orphaned);
+
+    let operations = installer.install(false).await.unwrap();
+    assert!(operations.iter().any(|o| o.name == "pre-push" && o.action == HookAction::Removed));
+    assert!(!temp_dir.path().join(".git/hooks/pre-push").exists());
+}
+
+#[tokio::test]
+async fn test_hooks_path_is_registered_and_unregistered() {
+    let (temp_dir, repo) = create_repo();
+    let config = HooksConfig {
+        hooks_path: Some(".githooks".to_string()),
+        ..config_with(&[("commit-msg", "echo msg")])
+    };
+    let installer = installer(&temp_dir, config);
+
+    assert!(!installer.status().await.unwrap().hooks_path_registered);
+
+    installer.install(false).await.unwrap();
+
+    assert!(temp_dir.path().join(".githooks/commit-msg").exists());
+    assert_eq!(repo.get_config_value("core.hooksPath").unwrap().as_deref(), Some(".githooks"));
+    assert!(!installer.status().await.unwrap().has_drift());
+
+    let operations = installer.uninstall(false).await.unwrap();
+    assert_eq!(operations[0].action, HookAction::Removed);
+    assert!(!temp_dir.path().join(".githooks/commit-msg").exists());
+    assert_eq!(repo.get_config_value("core.hooksPath").unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_uninstall_leaves_unmanaged_hooks() {
+    let (temp_dir, _repo) = create_repo();
+    let hooks_dir = temp_dir.path().join(".git/hooks");
+    std::fs::create_dir_all(&hooks_dir).unwrap();
+    std::fs::write(hooks_dir.join("pre-commit"), "#!/bin/sh\nnpm test\n").unwrap();
+
+    let installer = installer(&temp_dir, config_with(&[("pre-commit", "echo managed")]));
+    let operations = installer.uninstall(true).await.unwrap();
+
+    assert!(operations.is_empty());
+    assert!(hooks_dir.join("pre-commit").exists());
+}
+
+#[tokio::test]
+async fn test_disabled_config_installs_nothing() {
+    let (temp_dir, _repo) = create_repo();
+    let config = HooksConfig { enabled: false, ..config_with(&[("pre-commit", "echo a")]) };
+
+    let operations = installer(&temp_dir, config).install(false).await.unwrap();
+
+    assert!(operations.is_empty());
+    assert!(!temp_dir.path().join(".git/hooks/pre-commit").exists());
+}
+
+struct StaticHook {
+    name: &'static str,
+    git_hooks: &'static [&'static str],
+    outcome: HookOutcome,
+}
+
+#[async_trait]
+impl Hook for StaticHook {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn git_hooks(&self) -> &[&str] {
+        self.git_hooks
+    }
+
+    async fn execute(&self, _context: &HookContext) -> HookResult<HookOutcome> {
+        Ok(self.outcome.clone())
+    }
+}
+
+struct RequireChangeset;
+
+#[async_trait]
+impl Hook for RequireChangeset {
+    fn name(&self) -> &str {
+        "require-changeset"
+    }
+
+    fn git_hooks(&self) -> &[&str] {
+        &["pre-commit"]
+    }
+
+    async fn execute(&self, context: &HookContext) -> HookResult<HookOutcome> {
+        if !context.changed_files().is_empty() && context.pending_changesets().is_empty() {
+            return Ok(HookOutcome::failed("missing changeset"));
+        }
+        Ok(HookOutcome::Passed)
+    }
+}
+
+#[test]
+fn test_registry_rejects_duplicate_and_unknown_hooks() {
+    let mut registry = HookRegistry::new();
+    registry
+        .register(StaticHook {
+            name: "a",
+            git_hooks: &["pre-commit"],
+            outcome: HookOutcome::Passed,
+        })
+        .unwrap();
+
+    let duplicate = registry.register(StaticHook {
+        name: "a",
+        git_hooks: &["pre-push"],
+        outcome: HookOutcome::Passed,
+    });
+    assert!(matches!(duplicate, Err(HookError::DuplicateHook { .. })));
+
+    let unknown = registry.register(StaticHook {
+        name: "b",
+        git_hooks: &["pre-deploy"],
+        outcome: HookOutcome::Passed,
+    });
+    assert!(matches!(unknown, Err(HookError::UnknownHook { .. })));
+    assert_eq!(registry.len(), 1);
+}
+
+#[tokio::test]
+async fn test_registry_runs_matching_hooks_in_order() {
+    let mut registry = HookRegistry::new();
+    registry
+        .register(StaticHook {
+            name: "lint",
+            git_hooks: &["commit-msg"],
+            outcome: HookOutcome::failed("bad message"),
+        })
+        .unwrap()
+        .register(StaticHook {
+            name: "other",
+            git_hooks: &["pre-push"],
+            outcome: HookOutcome::Passed,
+        })
+        .unwrap()
+        .register(StaticHook {
+            name: "trailer",
+            git_hooks: &["commit-msg", "pre-push"],
+            outcome: HookOutcome::Passed,
+        })
+        .unwrap();
+
+    assert_eq!(registry.hooks_for("commit-msg"), vec!["lint", "trailer"]);
+
+    let context = HookContext::new("commit-msg", PathBuf::from("."), PackageToolsConfig::default());
+    let executions = registry.run(&context).await.unwrap();
+
+    assert_eq!(executions.len(), 2);
+    assert_eq!(executions[0].hook, "lint");
+    assert!(!executions[0].outcome.is_passed());
+    assert!(executions[1].outcome.is_passed());
+}
+
+#[tokio::test]
+async fn test_hook_uses_injected_context() {
+    let mut registry = HookRegistry::new();
+    registry.register(RequireChangeset).unwrap();
+
+    let context = HookContext::new("pre-commit", PathBuf::from("."), PackageToolsConfig::default())
+        .with_changed_files(vec![PathBuf::from("packages/core/index.js")]);
+    let executions = registry.run(&context).await.unwrap();
+    assert_eq!(executions[0].outcome, HookOutcome::failed("missing changeset"));
+
+    let context = HookContext::new("pre-commit", PathBuf::from("."), PackageToolsConfig::default());
+    assert!(registry.run(&context).await.unwrap()[0].outcome.is_passed());
+}
+
+#[tokio::test]
+async fn test_context_load_reads_staged_files() {
+    let (temp_dir, repo) = create_repo();
+    std::fs::write(temp_dir.path().join("index.js"), "module.exports = {};\n").unwrap();
+    repo.add("index.js").unwrap();
+
+    let context = HookContext::load(
+        "pre-commit",
+        Vec::new(),
+        temp_dir.path().to_path_buf(),
+        PackageToolsConfig::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(context.hook_name(), "pre-commit");
+    assert_eq!(context.changed_files(), &[PathBuf::from("index.js")]);
+    assert!(context.pending_changesets().is_empty());
+    assert!(context.project().is_none());
+}
@@ -0,0 +1,218 @@
+//! Types describing the state of managed git hooks.
+//!
+//! **What**: Defines the per-hook state reported by drift detection and the actions taken
+//! when installing or uninstalling hooks.
+//!
+//! **How**: `HookStatus` pairs a hook with its `HookState`; `HookOperation` pairs a hook with the
+//! `HookAction` applied to it. `HooksStatusReport` aggregates the status of every hook.
+//!
+//! **Why**: To let callers (like the CLI) render status tables and decide exit codes without
+//! re-reading hook files.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::PathBuf;
+
+/// State of a hook on disk compared to its configuration.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::hooks::HookState;
+///
+/// assert!(HookState::Modified.is_drift());
+/// assert!(!HookState::UpToDate.is_drift());
+/// assert_eq!(HookState::Outdated.to_string(), "outdated");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HookState {
+    /// The installed hook matches the configured script.
+    UpToDate,
+    /// The hook is configured but not installed.
+    Missing,
+    /// The hook was installed from an older version of the configured script.
+    Outdated,
+    /// The installed hook was edited after installation.
+    Modified,
+    /// A hook file exists that was not installed by workspace tools.
+    Unmanaged,
+    /// A managed hook is installed but no longer configured.
+    Orphaned,
+}
+
+impl HookState {
+    /// Returns the state as a lowercase string.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UpToDate => "up-to-date",
+            Self::Missing => "missing",
+            Self::Outdated => "outdated",
+            Self::Modified => "modified",
+            Self::Unmanaged => "unmanaged",
+            Self::Orphaned => "orphaned",
+        }
+    }
+
+    /// Returns whether the installed hooks differ from the configuration.
+    #[must_use]
+    pub fn is_drift(&self) -> bool {
+        !matches!(self, Self::UpToDate)
+    }
+}
+
+impl fmt::Display for HookState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Status of a single hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookStatus {
+    /// Git hook name (e.g., `pre-commit`).
+    pub name: String,
+    /// Path of the hook file.
+    pub path: PathBuf,
+    /// State of the hook compared to the configuration.
+    pub state: HookState,
+}
+
+/// Status of every configured or managed hook in a repository.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::hooks::{HookState, HookStatus, HooksStatusReport};
+/// use std::path::PathBuf;
+///
+/// let report = HooksStatusReport {
+///     hooks_dir: PathBuf::from(".git/hooks"),
+///     hooks_path_registered: true,
+///     hooks: vec![HookStatus {
+///         name: "pre-commit".to_string(),
+///         path: PathBuf::from(".git/hooks/pre-commit"),
+///         state: HookState::UpToDate,
+///     }],
+/// };
+///
+/// assert!(!report.has_drift());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HooksStatusReport {
+    /// Directory the hooks are installed into.
+    pub hooks_dir: PathBuf,
+    /// Whether git is configured to run hooks from `hooks_dir`.
+    ///
+    /// Always `true` when hooks are installed into the default `.git/hooks` directory.
+    pub hooks_path_registered: bool,
+    /// Status of each hook, configured hooks first in name order, then orphaned hooks.
+    pub hooks: Vec<HookStatus>,
+}
+
+impl HooksStatusReport {
+    /// Returns whether any hook drifted from the configuration or git is not configured
+    /// to run the hooks.
+    #[must_use]
+    pub fn has_drift(&self) -> bool {
+        !self.hooks_path_registered || self.hooks.iter().any(|h| h.state.is_drift())
+    }
+}
+
+/// Action taken on a hook during installation or removal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum HookAction {
+    /// The hook was written for the first time (or replaced an unmanaged hook with `force`).
+    Installed,
+    /// An outdated or modified hook was rewritten.
+    Updated,
+    /// The hook already matched the configuration.
+    Unchanged,
+    /// The hook was left alone because it was modified by the user; use `force` to replace it.
+    Skipped,
+    /// The hook file was deleted.
+    Removed,
+}
+
+impl HookAction {
+    /// Returns the action as a lowercase string.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Installed => "installed",
+            Self::Updated => "updated",
+            Self::Unchanged => "unchanged",
+            Self::Skipped => "skipped",
+            Self::Removed => "removed",
+        }
+    }
+}
+
+impl fmt::Display for HookAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Result of applying an action to a single hook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookOperation {
+    /// Git hook name (e.g., `pre-commit`).
+    pub name: String,
+    /// Path of the hook file.
+    pub path: PathBuf,
+    /// Action taken.
+    pub action: HookAction,
+}
+
+/// Result of running a hook implementation.
+///
+/// A failed outcome means the hook ran and rejected the git operation (for example, a
+/// commit message that does not follow the convention). Failures to run at all are
+/// reported as `HookError`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::hooks::HookOutcome;
+///
+/// let outcome = HookOutcome::failed("missing changeset for @acme/core");
+/// assert!(!outcome.is_passed());
+/// assert!(HookOutcome::Passed.is_passed());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum HookOutcome {
+    /// The git operation may proceed.
+    Passed,
+    /// The git operation should be aborted.
+    Failed {
+        /// Explanation shown to the user.
+        message: String,
+    },
+}
+
+impl HookOutcome {
+    /// Creates a failed outcome with the given message.
+    #[must_use]
+    pub fn failed(message: impl Into<String>) -> Self {
+        Self::Failed { message: message.into() }
+    }
+
+    /// Returns whether the git operation may proceed.
+    #[must_use]
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Self::Passed)
+    }
+}
+
+/// Outcome of a single hook implementation run by `HookRegistry::run`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookExecution {
+    /// Name of the hook implementation.
+    pub hook: String,
+    /// Outcome reported by the hook implementation.
+    pub outcome: HookOutcome,
+}
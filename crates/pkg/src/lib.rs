@@ -29,15 +29,24 @@
 //!
 //! ## Modules
 //!
+//! - [`prelude`]: Stable re-exports of the types most consumers need
 //! - [`config`]: Configuration loading, validation, and management
 //! - [`error`]: Error types and error handling utilities
 //! - [`types`]: Core data structures (Version, VersionBump, Changeset, etc.)
 //! - [`changeset`]: Changeset creation, management, storage, and history
 //! - [`version`](mod@version): Version resolution, dependency propagation, and application
 //! - [`changes`]: Analysis of file changes and commit ranges
+//! - [`git_tree`]: Read-only filesystem serving the workspace as committed at a Git reference
 //! - [`changelog`]: Changelog generation with conventional commits support
 //! - [`upgrade`]: Dependency upgrade detection and application
 //! - [`audit`]: Health checks, dependency audits, and issue detection
+//! - [`hooks`]: Managed git hook installation, drift detection, and custom hook execution
+//! - [`notifications`]: Release and failure notifications over Slack, webhooks, and email
+//! - [`publish`]: Pre-publish checks of package visibility, publish config, and packed files
+//! - [`editor`]: In-place editing of JSON and JSONC files that keeps comments and formatting
+//! - [`workspace`]: Transaction log for reverting file-modifying workspace operations, and
+//!   import aliases generated from the dependency graph
+//! - `fuzz` (feature `fuzzing`): Fuzz targets and proptest strategies for version and range parsing
 //!
 //! ## Features
 //!
@@ -113,6 +122,17 @@
 //! propagate_dev_dependencies = false
 //! ```
 //!
+//! ## API Stability
+//!
+//! The [`prelude`] is the semver-guarded surface of this crate: its items only change
+//! incompatibly in a major release, and renamed or removed items stay available as
+//! `#[deprecated]` re-exports for at least one minor release. Consumers such as the CLI
+//! should import from it rather than from module paths, which follow the internal layout.
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::prelude::*;
+//! ```
+//!
 //! ## Design Principles
 //!
 //! 1. **Library First**: All functionality exposed as library APIs
@@ -152,10 +172,24 @@ pub mod changelog;
 pub mod changes;
 pub mod changeset;
 pub mod config;
+pub mod editor;
 pub mod error;
+pub mod git_tree;
+pub mod hooks;
+pub mod notifications;
+pub mod prelude;
+pub mod publish;
 pub mod types;
 pub mod upgrade;
 pub mod version;
+pub mod workspace;
+
+// Fuzz targets and proptest strategies, for fuzz harnesses and downstream property tests
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
+
+// Internal helpers shared across modules
+mod line_endings;
 
 /// The version of the sublime_pkg_tools crate.
 ///
@@ -204,4 +238,13 @@ mod tests {
         // Basic check that version follows semver-like format (contains at least one dot)
         assert!(ver.contains('.'), "Version should follow semver format with at least one dot");
     }
+
+    #[test]
+    fn test_prelude_reexports_module_types() {
+        use crate::prelude::{PackageToolsConfig, VersionBump, VersioningStrategy};
+
+        let config: crate::config::PackageToolsConfig = PackageToolsConfig::default();
+        assert_eq!(config.version.strategy, VersioningStrategy::Independent);
+        assert_eq!(VersionBump::Minor, crate::types::VersionBump::Minor);
+    }
 }
@@ -0,0 +1,66 @@
+//! Line-ending detection and preservation for rewritten files.
+//!
+//! **What**: Detects whether a file uses LF or CRLF line endings and converts generated
+//! content to match.
+//!
+//! **How**: Generated content (changelog sections, serialized `package.json`) is always built
+//! with `\n`. Before writing, `LineEnding::detect` inspects the original file and
+//! `LineEnding::apply` converts the new content to the same convention.
+//!
+//! **Why**: Rewriting a CRLF file with LF endings produces whole-file diffs on Windows
+//! checkouts (`core.autocrlf=false`) and mixed endings when content is prepended.
+
+/// Line-ending convention of a text file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum LineEnding {
+    /// Unix line endings (`\n`).
+    #[default]
+    Lf,
+    /// Windows line endings (`\r\n`).
+    CrLf,
+}
+
+impl LineEnding {
+    /// Detects the line ending of existing content from its first line break.
+    ///
+    /// Content without line breaks is treated as LF.
+    pub(crate) fn detect(content: &str) -> Self {
+        match content.find('\n') {
+            Some(index) if index > 0 && content.as_bytes()[index - 1] == b'\r' => Self::CrLf,
+            _ => Self::Lf,
+        }
+    }
+
+    /// Converts content to this line ending, whatever endings it currently uses.
+    pub(crate) fn apply(self, content: &str) -> String {
+        let normalized = normalize_to_lf(content);
+        match self {
+            Self::Lf => normalized,
+            Self::CrLf => normalized.replace('\n', "\r\n"),
+        }
+    }
+}
+
+/// Converts CRLF line endings to LF.
+pub(crate) fn normalize_to_lf(content: &str) -> String {
+    content.replace("\r\n", "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(LineEnding::detect("a\r\nb\r\n"), LineEnding::CrLf);
+        assert_eq!(LineEnding::detect("a\nb\n"), LineEnding::Lf);
+        assert_eq!(LineEnding::detect("\nb"), LineEnding::Lf);
+        assert_eq!(LineEnding::detect("no newline"), LineEnding::Lf);
+    }
+
+    #[test]
+    fn test_apply_does_not_double_carriage_returns() {
+        assert_eq!(LineEnding::CrLf.apply("a\nb\r\nc"), "a\r\nb\r\nc");
+        assert_eq!(LineEnding::Lf.apply("a\r\nb\n"), "a\nb\n");
+    }
+}
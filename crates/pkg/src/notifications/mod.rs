@@ -0,0 +1,48 @@
+//! Release and failure notifications.
+//!
+//! **What**: Tells Slack channels, generic webhooks, and email recipients about releases and
+//! failed operations, as configured in the `notifications` configuration section.
+//!
+//! **How**: Callers describe what happened as a `Notification`, either a release with its
+//! packages, versions, and changelog sections, or a failure with the operation and error.
+//! `Notifier` renders the configured templates and delivers the result to every channel
+//! subscribed to the event, returning a `NotificationReport` instead of failing when a
+//! channel cannot be reached.
+//!
+//! **Why**: Release jobs otherwise end with ad-hoc `curl` calls to chat webhooks that
+//! duplicate the release data and break silently.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use sublime_pkg_tools::config::PackageToolsConfig;
+//! use sublime_pkg_tools::notifications::{Notification, Notifier, ReleasedPackage};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = PackageToolsConfig::default();
+//! let notifier = Notifier::new(config.notifications.clone())?;
+//!
+//! let release = Notification::release(vec![
+//!     ReleasedPackage::new("@myorg/core", "1.3.0")
+//!         .with_previous_version("1.2.4")
+//!         .with_changelog("### Added\n\n- Retry failed uploads"),
+//! ]);
+//!
+//! if !notifier.notify(&release).await.all_delivered() {
+//!     eprintln!("Some notifications were not delivered");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod notifier;
+mod smtp;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use notifier::Notifier;
+pub use types::{
+    DeliveryOutcome, Notification, NotificationEvent, NotificationReport, ReleasedPackage,
+};
@@ -0,0 +1,250 @@
+//! Notification delivery.
+//!
+//! **What**: Provides `Notifier`, which sends a `Notification` to every configured channel
+//! subscribed to its event and reports what each channel did with it.
+//!
+//! **How**: Slack channels receive `{"text": <message>}`. Generic webhooks receive the rendered
+//! channel template, or the notification as JSON when no template is set. Email channels
+//! submit a message through `smtp`. `${VAR}` references in URLs, headers, and credentials are
+//! replaced with environment variables right before sending. Channels are tried one after
+//! another, each bounded by `notifications.timeout_secs`. In offline mode nothing is sent and
+//! each subscribed channel is reported as skipped.
+//!
+//! **Why**: A notification is a side effect of a release, so a channel that is down is
+//! reported back to the caller instead of failing the release.
+
+use crate::config::{NotificationChannelConfig, NotificationsConfig, WebhookChannelConfig};
+use crate::error::{NotificationError, NotificationResult};
+use crate::notifications::smtp::{EmailMessage, SmtpCredentials, send_email};
+use crate::notifications::types::{
+    DeliveryOutcome, Notification, NotificationEvent, NotificationReport,
+};
+use serde::Serialize;
+use std::time::Duration;
+use sublime_standard_tools::network::is_offline_mode;
+
+/// Sends notifications to the configured channels.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::config::NotificationsConfig;
+/// use sublime_pkg_tools::notifications::{Notification, Notifier, ReleasedPackage};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let notifier = Notifier::new(NotificationsConfig::default())?;
+///
+/// let notification = Notification::release(vec![
+///     ReleasedPackage::new("@myorg/core", "1.3.0").with_previous_version("1.2.4"),
+/// ]);
+///
+/// let report = notifier.notify(&notification).await;
+/// for failure in report.failures() {
+///     eprintln!("{}: {:?}", failure.channel, failure.error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Notifier {
+    config: NotificationsConfig,
+    client: reqwest::Client,
+}
+
+/// Body posted to generic webhooks without a template.
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    title: String,
+    message: String,
+    #[serde(flatten)]
+    notification: &'a Notification,
+}
+
+impl Notifier {
+    /// Creates a notifier for the given configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotificationError::InvalidConfig` if the HTTP client cannot be created.
+    pub fn new(config: NotificationsConfig) -> NotificationResult<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| NotificationError::InvalidConfig {
+                reason: format!("cannot create HTTP client: {e}"),
+            })?;
+
+        Ok(Self { config, client })
+    }
+
+    /// Returns the notifications configuration.
+    #[must_use]
+    pub fn config(&self) -> &NotificationsConfig {
+        &self.config
+    }
+
+    /// Sends a notification to every channel subscribed to its event.
+    ///
+    /// Delivery failures are reported in the returned report and never returned as errors.
+    /// Nothing is sent when notifications are disabled, and in offline mode every subscribed
+    /// channel is reported with `NotificationError::OfflineSkipped`.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification` - The notification to send
+    ///
+    /// # Returns
+    ///
+    /// One outcome per subscribed channel, in configuration order.
+    pub async fn notify(&self, notification: &Notification) -> NotificationReport {
+        let mut report = NotificationReport::default();
+        if !self.config.enabled {
+            return report;
+        }
+
+        for (index, channel) in self.config.channels.iter().enumerate() {
+            if !channel.events().contains(&notification.event) {
+                continue;
+            }
+
+            let label = format!("{} #{}", channel.kind(), index + 1);
+            if is_offline_mode() {
+                let error = NotificationError::OfflineSkipped { channel: label.clone() };
+                log::warn!("{}", error);
+                report.deliveries.push(DeliveryOutcome { channel: label, error: Some(error) });
+                continue;
+            }
+
+            let timeout = Duration::from_secs(self.config.timeout_secs);
+            let error =
+                match tokio::time::timeout(timeout, self.deliver(channel, notification)).await {
+                    Ok(Ok(())) => None,
+                    Ok(Err(reason)) => {
+                        Some(NotificationError::DeliveryFailed { channel: label.clone(), reason })
+                    }
+                    Err(_) => Some(NotificationError::Timeout {
+                        channel: label.clone(),
+                        timeout_secs: self.config.timeout_secs,
+                    }),
+                };
+
+            if let Some(error) = &error {
+                log::warn!("{}", error);
+            }
+            report.deliveries.push(DeliveryOutcome { channel: label, error });
+        }
+
+        report
+    }
+
+    /// Returns the template for the notification on the channel.
+    fn template<'a>(
+        &'a self,
+        channel: &'a NotificationChannelConfig,
+        notification: &Notification,
+    ) -> &'a str {
+        channel.template().unwrap_or(match notification.event {
+            NotificationEvent::Release => &self.config.release_template,
+            NotificationEvent::Failure => &self.config.failure_template,
+        })
+    }
+
+    async fn deliver(
+        &self,
+        channel: &NotificationChannelConfig,
+        notification: &Notification,
+    ) -> Result<(), String> {
+        match channel {
+            NotificationChannelConfig::Slack(webhook) => {
+                let message = notification.render(self.template(channel, notification), false);
+                let body = serde_json::json!({ "text": message }).to_string();
+                self.post(webhook, body).await
+            }
+            NotificationChannelConfig::Webhook(webhook) => {
+                let body = match &webhook.template {
+                    Some(template) => notification.render(template, true),
+                    None => {
+                        let payload = WebhookPayload {
+                            title: notification.title(),
+                            message: notification
+                                .render(self.template(channel, notification), false),
+                            notification,
+                        };
+                        serde_json::to_string(&payload).map_err(|e| e.to_string())?
+                    }
+                };
+                self.post(webhook, body).await
+            }
+            NotificationChannelConfig::Email(email) => {
+                let credentials = email.username.as_ref().map(|username| SmtpCredentials {
+                    username: expand_env(username),
+                    password: email.password.as_deref().map(expand_env).unwrap_or_default(),
+                });
+                let message = EmailMessage {
+                    subject: notification.render(&email.subject, false),
+                    body: notification.render(self.template(channel, notification), false),
+                };
+                send_email(email, credentials.as_ref(), &message).await
+            }
+        }
+    }
+
+    async fn post(&self, webhook: &WebhookChannelConfig, body: String) -> Result<(), String> {
+        let mut request = self
+            .client
+            .post(expand_env(&webhook.url))
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        for (name, value) in &webhook.headers {
+            request = request.header(name.as_str(), expand_env(value));
+        }
+
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let text = response.text().await.unwrap_or_default();
+            let text = text.trim();
+            Err(if text.is_empty() {
+                format!("HTTP {status}")
+            } else {
+                format!("HTTP {status}: {text}")
+            })
+        }
+    }
+}
+
+/// Replaces `${VAR}` references with the values of environment variables.
+///
+/// References to unset variables are left as they are.
+pub(crate) fn expand_env(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match std::env::var(name) {
+                    Ok(resolved) => output.push_str(&resolved),
+                    Err(_) => {
+                        output.push_str("${");
+                        output.push_str(name);
+                        output.push('}');
+                    }
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                output.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
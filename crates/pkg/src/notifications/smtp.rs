@@ -0,0 +1,207 @@
+//! Minimal SMTP client for email notifications.
+//!
+//! **What**: Sends a plain-text email through an SMTP server, with optional `STARTTLS` or
+//! implicit TLS and `AUTH PLAIN` authentication.
+//!
+//! **How**: Speaks the small subset of SMTP needed to submit one message: `EHLO`, `STARTTLS`,
+//! `AUTH PLAIN`, `MAIL FROM`, `RCPT TO`, `DATA`, and `QUIT`. TLS uses the platform's native
+//! TLS library. The message is sent as UTF-8 text with dot-stuffing; non-ASCII subjects are
+//! encoded as RFC 2047 words. Credentials are only sent over TLS, and addresses are checked
+//! before they are placed in commands and headers.
+//!
+//! **Why**: Email reaches people who are not on the team's chat, and a submission client is
+//! all notifications need, which keeps a full mail library out of the dependency tree.
+
+use crate::config::{EmailChannelConfig, SmtpSecurity};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::TlsConnector;
+
+/// A connection that can carry SMTP, before or after TLS is negotiated.
+trait SmtpStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> SmtpStream for T {}
+
+/// An email ready to be submitted.
+#[derive(Debug, Clone)]
+pub(crate) struct EmailMessage {
+    /// Subject line.
+    pub(crate) subject: String,
+    /// Plain-text body.
+    pub(crate) body: String,
+}
+
+/// Credentials for `AUTH PLAIN`, with environment references already expanded.
+#[derive(Debug, Clone)]
+pub(crate) struct SmtpCredentials {
+    pub(crate) username: String,
+    pub(crate) password: String,
+}
+
+/// An SMTP session over a buffered stream.
+struct SmtpSession {
+    stream: BufReader<Box<dyn SmtpStream>>,
+}
+
+impl SmtpSession {
+    fn new(stream: Box<dyn SmtpStream>) -> Self {
+        Self { stream: BufReader::new(stream) }
+    }
+
+    /// Reads a possibly multi-line reply and checks its code.
+    async fn expect(&mut self, expected: &[u16]) -> Result<String, String> {
+        let mut text = String::new();
+        loop {
+            let mut line = String::new();
+            let read = self.stream.read_line(&mut line).await.map_err(|e| e.to_string())?;
+            if read == 0 {
+                return Err("connection closed by server".to_string());
+            }
+
+            let line = line.trim_end();
+            let code = line.get(..3).and_then(|code| code.parse::<u16>().ok());
+            let Some(code) = code else {
+                return Err(format!("malformed reply '{line}'"));
+            };
+            text.push_str(line.get(4..).unwrap_or_default());
+            text.push('\n');
+
+            // "250-..." continues the reply, "250 ..." ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return if expected.contains(&code) {
+                    Ok(text)
+                } else {
+                    Err(format!("server replied {code}: {}", text.trim()))
+                };
+            }
+        }
+    }
+
+    async fn send(&mut self, line: &str) -> Result<(), String> {
+        let stream = self.stream.get_mut();
+        stream.write_all(line.as_bytes()).await.map_err(|e| e.to_string())?;
+        stream.write_all(b"\r\n").await.map_err(|e| e.to_string())?;
+        stream.flush().await.map_err(|e| e.to_string())
+    }
+
+    async fn command(&mut self, line: &str, expected: &[u16]) -> Result<String, String> {
+        self.send(line).await?;
+        self.expect(expected).await
+    }
+}
+
+/// Submits an email through the channel's SMTP server.
+///
+/// # Arguments
+///
+/// * `config` - The email channel
+/// * `credentials` - Expanded credentials, when the channel authenticates
+/// * `message` - The email to send
+///
+/// # Errors
+///
+/// Returns a description of the failure when credentials would be sent without TLS, an
+/// address is invalid, the server cannot be reached, TLS negotiation fails, or the server
+/// rejects a command.
+pub(crate) async fn send_email(
+    config: &EmailChannelConfig,
+    credentials: Option<&SmtpCredentials>,
+    message: &EmailMessage,
+) -> Result<(), String> {
+    if credentials.is_some() && config.security == SmtpSecurity::None {
+        return Err("refusing to send credentials without TLS or STARTTLS".to_string());
+    }
+    config.validate_addresses()?;
+
+    let tcp = TcpStream::connect((config.host.as_str(), config.port))
+        .await
+        .map_err(|e| format!("cannot connect to {}:{}: {e}", config.host, config.port))?;
+
+    let stream: Box<dyn SmtpStream> = match config.security {
+        SmtpSecurity::Tls => Box::new(wrap_tls(&config.host, tcp).await?),
+        SmtpSecurity::StartTls | SmtpSecurity::None => Box::new(tcp),
+    };
+
+    let mut session = SmtpSession::new(stream);
+    session.expect(&[220]).await?;
+    session.command("EHLO localhost", &[250]).await?;
+
+    if config.security == SmtpSecurity::StartTls {
+        session.command("STARTTLS", &[220]).await?;
+        let plain = session.stream.into_inner();
+        session = SmtpSession::new(Box::new(wrap_tls(&config.host, plain).await?));
+        session.command("EHLO localhost", &[250]).await?;
+    }
+
+    if let Some(credentials) = credentials {
+        session.command(&format!("AUTH PLAIN {}", auth_plain_token(credentials)), &[235]).await?;
+    }
+
+    session.command(&format!("MAIL FROM:<{}>", config.from), &[250]).await?;
+    for recipient in &config.to {
+        session.command(&format!("RCPT TO:<{recipient}>"), &[250, 251]).await?;
+    }
+
+    session.command("DATA", &[354]).await?;
+    session.send(&format_message(config, message)).await?;
+    session.command(".", &[250]).await?;
+
+    // The message is accepted; a failed goodbye changes nothing
+    let _ = session.command("QUIT", &[221]).await;
+    Ok(())
+}
+
+/// Encodes credentials as the `AUTH PLAIN` initial response.
+pub(crate) fn auth_plain_token(credentials: &SmtpCredentials) -> String {
+    BASE64.encode(format!("\0{}\0{}", credentials.username, credentials.password).as_bytes())
+}
+
+async fn wrap_tls<S>(host: &str, stream: S) -> Result<tokio_native_tls::TlsStream<S>, String>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let connector = tokio_native_tls::native_tls::TlsConnector::new().map_err(|e| e.to_string())?;
+    TlsConnector::from(connector)
+        .connect(host, stream)
+        .await
+        .map_err(|e| format!("TLS negotiation with {host} failed: {e}"))
+}
+
+/// Formats the headers and dot-stuffed body of the message, without the final `.` line.
+pub(crate) fn format_message(config: &EmailChannelConfig, message: &EmailMessage) -> String {
+    let mut lines = vec![
+        format!("From: <{}>", config.from),
+        format!(
+            "To: {}",
+            config.to.iter().map(|to| format!("<{to}>")).collect::<Vec<_>>().join(", ")
+        ),
+        format!("Subject: {}", encode_header(&message.subject)),
+        format!("Date: {}", chrono::Utc::now().to_rfc2822()),
+        "MIME-Version: 1.0".to_string(),
+        "Content-Type: text/plain; charset=utf-8".to_string(),
+        "Content-Transfer-Encoding: 8bit".to_string(),
+        String::new(),
+    ];
+
+    for line in message.body.lines() {
+        if line.starts_with('.') {
+            lines.push(format!(".{line}"));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+
+    lines.join("\r\n")
+}
+
+/// Encodes a header value as an RFC 2047 word when it is not plain ASCII.
+fn encode_header(value: &str) -> String {
+    let value = value.replace(['\r', '\n'], " ");
+    if value.is_ascii() {
+        value
+    } else {
+        format!("=?UTF-8?B?{}?=", BASE64.encode(value.as_bytes()))
+    }
+}
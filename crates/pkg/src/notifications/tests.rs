@@ -0,0 +1,403 @@
+//! Tests for release and failure notifications.
+//!
+//! **What**: Covers template rendering, environment expansion, and delivery to Slack, generic
+//! webhooks, and SMTP servers.
+//!
+//! **How**: Webhooks are served by `mockito`; email is delivered to a minimal SMTP server
+//! listening on a local port.
+//!
+//! **Why**: To ensure every channel receives the expected payload and failing channels are
+//! reported instead of aborting the release.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::notifier::expand_env;
+use super::smtp::{EmailMessage, SmtpCredentials, auth_plain_token, send_email};
+use super::{Notification, NotificationEvent, Notifier, ReleasedPackage};
+use crate::config::{
+    EmailChannelConfig, NotificationChannelConfig, NotificationsConfig, SmtpSecurity,
+    WebhookChannelConfig,
+};
+use crate::error::NotificationError;
+use mockito::{Matcher, Server};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+fn release() -> Notification {
+    Notification::release(vec![
+        ReleasedPackage::new("@myorg/core", "1.3.0")
+            .with_previous_version("1.2.4")
+            .with_changelog("### Added\n\n- Retry failed uploads"),
+        ReleasedPackage::new("@myorg/utils", "2.0.0"),
+    ])
+}
+
+fn webhook(url: String, events: Vec<NotificationEvent>) -> WebhookChannelConfig {
+    WebhookChannelConfig { url, headers: Default::default(), events, template: None }
+}
+
+fn config_with(channels: Vec<NotificationChannelConfig>) -> NotificationsConfig {
+    NotificationsConfig { channels, timeout_secs: 5, ..Default::default() }
+}
+
+mod rendering {
+    use super::*;
+
+    #[test]
+    fn test_title() {
+        assert_eq!(release().title(), "Released 2 packages");
+        assert_eq!(
+            Notification::release(vec![ReleasedPackage::new("a", "1.0.0")]).title(),
+            "Released a@1.0.0"
+        );
+        assert_eq!(Notification::failure("bump", "boom").title(), "bump failed");
+    }
+
+    #[test]
+    fn test_render_release() {
+        let rendered =
+            release().render("{title}: {packages} ({package_count})\n{changelog}", false);
+
+        assert_eq!(
+            rendered,
+            "Released 2 packages: @myorg/core@1.3.0, @myorg/utils@2.0.0 (2)\n\
+             @myorg/core 1.3.0\n\n### Added\n\n- Retry failed uploads"
+        );
+    }
+
+    #[test]
+    fn test_render_failure() {
+        let failure = Notification::failure("bump", "registry returned 503");
+
+        assert_eq!(
+            failure.render("[{event}] {operation}: {error}", false),
+            "[failure] bump: registry returned 503"
+        );
+    }
+
+    #[test]
+    fn test_render_json_escapes_values() {
+        let failure = Notification::failure("bump", "unexpected \"token\"\nat line 1");
+        let rendered = failure.render(r#"{"text": "{error}"}"#, true);
+
+        let value: serde_json::Value = serde_json::from_str(&rendered).unwrap();
+        assert_eq!(value["text"], "unexpected \"token\"\nat line 1");
+    }
+
+    #[test]
+    fn test_expand_env() {
+        // SAFETY: the variable name is unique to this test
+        unsafe { std::env::set_var("SUBLIME_NOTIFY_TEST_TOKEN", "secret") };
+
+        assert_eq!(expand_env("Bearer ${SUBLIME_NOTIFY_TEST_TOKEN}"), "Bearer secret");
+        assert_eq!(expand_env("${SUBLIME_NOTIFY_TEST_UNSET}"), "${SUBLIME_NOTIFY_TEST_UNSET}");
+        assert_eq!(expand_env("plain ${unterminated"), "plain ${unterminated");
+    }
+}
+
+mod delivery {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_slack_receives_text() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/slack")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "text": "Released @myorg/core@1.3.0, @myorg/utils@2.0.0\n\n\
+                         @myorg/core 1.3.0\n\n### Added\n\n- Retry failed uploads"
+            })))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let config = config_with(vec![NotificationChannelConfig::Slack(webhook(
+            format!("{}/slack", server.url()),
+            vec![NotificationEvent::Release],
+        ))]);
+        let report = Notifier::new(config).unwrap().notify(&release()).await;
+
+        mock.assert_async().await;
+        assert!(report.all_delivered());
+        assert_eq!(report.deliveries[0].channel, "slack #1");
+    }
+
+    #[tokio::test]
+    async fn test_webhook_receives_notification_json() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_header("x-token", "abc")
+            .match_body(Matcher::PartialJson(serde_json::json!({
+                "event": "failure",
+                "title": "bump failed",
+                "operation": "bump",
+                "error": "disk full"
+            })))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let mut channel =
+            webhook(format!("{}/hook", server.url()), vec![NotificationEvent::Failure]);
+        channel.headers.insert("X-Token".to_string(), "abc".to_string());
+        let config = config_with(vec![NotificationChannelConfig::Webhook(channel)]);
+        let report = Notifier::new(config)
+            .unwrap()
+            .notify(&Notification::failure("bump", "disk full"))
+            .await;
+
+        mock.assert_async().await;
+        assert!(report.all_delivered());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_template_is_request_body() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_body(Matcher::Json(serde_json::json!({ "summary": "Released 2 packages" })))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let mut channel =
+            webhook(format!("{}/hook", server.url()), vec![NotificationEvent::Release]);
+        channel.template = Some(r#"{"summary": "{title}"}"#.to_string());
+        let config = config_with(vec![NotificationChannelConfig::Webhook(channel)]);
+        let report = Notifier::new(config).unwrap().notify(&release()).await;
+
+        mock.assert_async().await;
+        assert!(report.all_delivered());
+    }
+
+    #[tokio::test]
+    async fn test_channels_only_receive_subscribed_events() {
+        let mut server = Server::new_async().await;
+        let releases = server.mock("POST", "/releases").expect(0).create_async().await;
+        let failures =
+            server.mock("POST", "/failures").with_status(200).expect(1).create_async().await;
+
+        let config = config_with(vec![
+            NotificationChannelConfig::Slack(webhook(
+                format!("{}/releases", server.url()),
+                vec![NotificationEvent::Release],
+            )),
+            NotificationChannelConfig::Slack(webhook(
+                format!("{}/failures", server.url()),
+                vec![NotificationEvent::Failure],
+            )),
+        ]);
+        let report = Notifier::new(config)
+            .unwrap()
+            .notify(&Notification::failure("bump", "disk full"))
+            .await;
+
+        releases.assert_async().await;
+        failures.assert_async().await;
+        assert_eq!(report.deliveries.len(), 1);
+        assert_eq!(report.deliveries[0].channel, "slack #2");
+    }
+
+    #[tokio::test]
+    async fn test_failed_channel_is_reported() {
+        let mut server = Server::new_async().await;
+        let _broken = server
+            .mock("POST", "/broken")
+            .with_status(500)
+            .with_body("invalid_payload")
+            .create_async()
+            .await;
+        let working = server.mock("POST", "/working").with_status(200).create_async().await;
+
+        let config = config_with(vec![
+            NotificationChannelConfig::Webhook(webhook(
+                format!("{}/broken", server.url()),
+                vec![NotificationEvent::Release],
+            )),
+            NotificationChannelConfig::Slack(webhook(
+                format!("{}/working", server.url()),
+                vec![NotificationEvent::Release],
+            )),
+        ]);
+        let report = Notifier::new(config).unwrap().notify(&release()).await;
+
+        working.assert_async().await;
+        assert!(!report.all_delivered());
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].channel, "webhook #1");
+        assert!(matches!(
+            &failures[0].error,
+            Some(NotificationError::DeliveryFailed { reason, .. })
+                if reason.contains("500") && reason.contains("invalid_payload")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_disabled_sends_nothing() {
+        let mut server = Server::new_async().await;
+        let mock = server.mock("POST", "/slack").expect(0).create_async().await;
+
+        let mut config = config_with(vec![NotificationChannelConfig::Slack(webhook(
+            format!("{}/slack", server.url()),
+            vec![NotificationEvent::Release],
+        ))]);
+        config.enabled = false;
+        let report = Notifier::new(config).unwrap().notify(&release()).await;
+
+        mock.assert_async().await;
+        assert!(report.deliveries.is_empty());
+    }
+}
+
+mod email {
+    use super::*;
+
+    /// Accepts one SMTP session and returns the commands and message data it received.
+    async fn serve_smtp(listener: TcpListener) -> Vec<String> {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        let mut received = Vec::new();
+        let mut in_data = false;
+
+        writer.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).await.unwrap() == 0 {
+                break;
+            }
+            let line = line.trim_end_matches("\r\n").to_string();
+            received.push(line.clone());
+
+            let reply: &[u8] = if in_data {
+                if line != "." {
+                    continue;
+                }
+                in_data = false;
+                b"250 queued\r\n"
+            } else if line.starts_with("EHLO") {
+                b"250-localhost\r\n250 AUTH PLAIN\r\n"
+            } else if line.starts_with("AUTH") {
+                b"235 authenticated\r\n"
+            } else if line == "DATA" {
+                in_data = true;
+                b"354 go ahead\r\n"
+            } else if line == "QUIT" {
+                writer.write_all(b"221 bye\r\n").await.unwrap();
+                break;
+            } else {
+                b"250 ok\r\n"
+            };
+            writer.write_all(reply).await.unwrap();
+        }
+
+        received
+    }
+
+    fn email_channel(port: u16) -> EmailChannelConfig {
+        EmailChannelConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            security: SmtpSecurity::None,
+            username: None,
+            password: None,
+            from: "releases@example.com".to_string(),
+            to: vec!["team@example.com".to_string(), "ops@example.com".to_string()],
+            subject: "[release] {title}".to_string(),
+            events: vec![NotificationEvent::Release],
+            template: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_email_is_submitted() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(serve_smtp(listener));
+
+        let mut release = release();
+        release.packages[0].changelog = Some(".npmrc files are now read".to_string());
+        let config = config_with(vec![NotificationChannelConfig::Email(email_channel(port))]);
+        let report = Notifier::new(config).unwrap().notify(&release).await;
+        let received = server.await.unwrap();
+
+        assert!(report.all_delivered(), "{:?}", report.deliveries);
+        assert_eq!(received[0], "EHLO localhost");
+        assert_eq!(received[1], "MAIL FROM:<releases@example.com>");
+        assert!(received.contains(&"RCPT TO:<team@example.com>".to_string()));
+        assert!(received.contains(&"RCPT TO:<ops@example.com>".to_string()));
+        assert!(received.contains(&"Subject: [release] Released 2 packages".to_string()));
+        // Lines starting with a dot are escaped
+        assert!(received.contains(&"..npmrc files are now read".to_string()));
+        assert_eq!(received.last().map(String::as_str), Some("QUIT"));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_connection_is_reported() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_all(b"550 relay denied\r\n").await.unwrap();
+        });
+
+        let config = config_with(vec![NotificationChannelConfig::Email(email_channel(port))]);
+        let report = Notifier::new(config).unwrap().notify(&release()).await;
+        server.await.unwrap();
+
+        assert!(matches!(
+            &report.deliveries[0].error,
+            Some(NotificationError::DeliveryFailed { channel, reason })
+                if channel == "email #1" && reason.contains("550")
+        ));
+    }
+
+    #[test]
+    fn test_auth_plain_token() {
+        let credentials =
+            SmtpCredentials { username: "releases".to_string(), password: "hunter2".to_string() };
+
+        // "\0releases\0hunter2"
+        assert_eq!(auth_plain_token(&credentials), "AHJlbGVhc2VzAGh1bnRlcjI=");
+    }
+
+    #[tokio::test]
+    async fn test_credentials_are_never_sent_in_cleartext() {
+        // Nothing listens on the port; the channel must fail before connecting
+        let credentials =
+            SmtpCredentials { username: "releases".to_string(), password: "hunter2".to_string() };
+        let message = EmailMessage { subject: "Released".to_string(), body: String::new() };
+
+        let result = send_email(&email_channel(1), Some(&credentials), &message).await;
+
+        assert!(matches!(result, Err(reason) if reason.contains("without TLS")));
+    }
+
+    #[tokio::test]
+    async fn test_addresses_with_line_breaks_are_rejected() {
+        let message = EmailMessage { subject: "Released".to_string(), body: String::new() };
+        let injected = [
+            EmailChannelConfig {
+                from: "releases@example.com>\r\nRCPT TO:<spam@example.com".to_string(),
+                ..email_channel(1)
+            },
+            EmailChannelConfig {
+                to: vec!["team@example.com\r\nBcc: spam@example.com".to_string()],
+                ..email_channel(1)
+            },
+            EmailChannelConfig { to: vec!["team".to_string()], ..email_channel(1) },
+        ];
+
+        for config in injected {
+            let result = send_email(&config, None, &message).await;
+            assert!(
+                matches!(&result, Err(reason) if reason.contains("Invalid email address")),
+                "{result:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Notification data and delivery results.
+//!
+//! **What**: Defines `Notification`, what happened and to which packages, `NotificationEvent`,
+//! the kinds of notifications channels subscribe to, and `NotificationReport`, what each
+//! channel did with a notification.
+//!
+//! **How**: A notification is rendered into a message by substituting its values into a
+//! template's `{placeholder}`s. Generic webhooks without a template receive the notification
+//! serialized as JSON, with the rendered title and message added.
+//!
+//! **Why**: To keep one description of a release that every channel formats in its own way.
+
+use crate::error::NotificationError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationEvent {
+    /// Packages were released.
+    Release,
+    /// An operation failed.
+    Failure,
+}
+
+impl NotificationEvent {
+    /// Returns the configuration name of the event.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Release => "release",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+impl fmt::Display for NotificationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A package included in a release notification.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::notifications::ReleasedPackage;
+///
+/// let package = ReleasedPackage::new("@myorg/core", "1.3.0")
+///     .with_previous_version("1.2.4")
+///     .with_changelog("### Added\n\n- Retry failed uploads");
+///
+/// assert_eq!(package.previous_version.as_deref(), Some("1.2.4"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleasedPackage {
+    /// Package name.
+    pub name: String,
+
+    /// Released version.
+    pub version: String,
+
+    /// Version before the release, if the package was released before.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_version: Option<String>,
+
+    /// Changelog section of the release, if one was generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<String>,
+}
+
+impl ReleasedPackage {
+    /// Creates a released package without previous version or changelog.
+    #[must_use]
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self { name: name.into(), version: version.into(), previous_version: None, changelog: None }
+    }
+
+    /// Sets the version before the release.
+    #[must_use]
+    pub fn with_previous_version(mut self, version: impl Into<String>) -> Self {
+        self.previous_version = Some(version.into());
+        self
+    }
+
+    /// Sets the changelog section of the release.
+    #[must_use]
+    pub fn with_changelog(mut self, changelog: impl Into<String>) -> Self {
+        self.changelog = Some(changelog.into());
+        self
+    }
+}
+
+/// Something worth telling the configured channels about.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::notifications::{Notification, ReleasedPackage};
+///
+/// let release = Notification::release(vec![ReleasedPackage::new("@myorg/core", "1.3.0")]);
+/// assert_eq!(release.title(), "Released @myorg/core@1.3.0");
+///
+/// let failure = Notification::failure("bump", "registry returned 503");
+/// assert_eq!(failure.title(), "bump failed");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    /// The kind of notification.
+    pub event: NotificationEvent,
+
+    /// Released packages. Empty for failures.
+    #[serde(default)]
+    pub packages: Vec<ReleasedPackage>,
+
+    /// The operation that failed. `None` for releases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+
+    /// Why the operation failed. `None` for releases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Notification {
+    /// Creates a release notification.
+    #[must_use]
+    pub fn release(packages: Vec<ReleasedPackage>) -> Self {
+        Self { event: NotificationEvent::Release, packages, operation: None, error: None }
+    }
+
+    /// Creates a failure notification.
+    #[must_use]
+    pub fn failure(operation: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            event: NotificationEvent::Failure,
+            packages: Vec::new(),
+            operation: Some(operation.into()),
+            error: Some(error.into()),
+        }
+    }
+
+    /// Returns a one-line summary, used as the `{title}` placeholder.
+    #[must_use]
+    pub fn title(&self) -> String {
+        match self.event {
+            NotificationEvent::Release if self.packages.len() == 1 => {
+                format!("Released {}", self.package_list())
+            }
+            NotificationEvent::Release => format!("Released {} packages", self.packages.len()),
+            NotificationEvent::Failure => {
+                format!("{} failed", self.operation.as_deref().unwrap_or("operation"))
+            }
+        }
+    }
+
+    /// Renders a template, replacing its placeholders with the values of the notification.
+    ///
+    /// Supported placeholders: `{event}`, `{title}`, `{packages}`, `{package_count}`,
+    /// `{changelog}`, `{operation}`, and `{error}`.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The template to render
+    /// * `json_escape` - Whether to JSON-escape the values, for templates of JSON bodies
+    ///
+    /// # Returns
+    ///
+    /// The rendered message.
+    pub(crate) fn render(&self, template: &str, json_escape: bool) -> String {
+        let escape = |value: String| {
+            if json_escape {
+                let quoted = serde_json::Value::String(value).to_string();
+                let inner = quoted.strip_prefix('"').and_then(|q| q.strip_suffix('"'));
+                inner.unwrap_or(&quoted).to_string()
+            } else {
+                value
+            }
+        };
+
+        let changelog = self
+            .packages
+            .iter()
+            .filter_map(|package| {
+                let changelog = package.changelog.as_deref()?.trim();
+                (!changelog.is_empty())
+                    .then(|| format!("{} {}\n\n{}", package.name, package.version, changelog))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        template
+            .replace("{event}", &escape(self.event.to_string()))
+            .replace("{title}", &escape(self.title()))
+            .replace("{packages}", &escape(self.package_list()))
+            .replace("{package_count}", &self.packages.len().to_string())
+            .replace("{changelog}", &escape(changelog))
+            .replace("{operation}", &escape(self.operation.clone().unwrap_or_default()))
+            .replace("{error}", &escape(self.error.clone().unwrap_or_default()))
+    }
+
+    fn package_list(&self) -> String {
+        self.packages
+            .iter()
+            .map(|package| format!("{}@{}", package.name, package.version))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// What one channel did with a notification.
+#[derive(Debug, Clone)]
+pub struct DeliveryOutcome {
+    /// Label of the channel, such as `slack #1`.
+    pub channel: String,
+
+    /// Why delivery failed. `None` when the channel accepted the notification.
+    pub error: Option<NotificationError>,
+}
+
+impl DeliveryOutcome {
+    /// Returns whether the channel accepted the notification.
+    #[must_use]
+    pub fn delivered(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// The result of sending a notification to every subscribed channel.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationReport {
+    /// One outcome per channel subscribed to the event, in configuration order.
+    pub deliveries: Vec<DeliveryOutcome>,
+}
+
+impl NotificationReport {
+    /// Returns the deliveries that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &DeliveryOutcome> {
+        self.deliveries.iter().filter(|delivery| !delivery.delivered())
+    }
+
+    /// Returns whether every subscribed channel accepted the notification.
+    #[must_use]
+    pub fn all_delivered(&self) -> bool {
+        self.deliveries.iter().all(DeliveryOutcome::delivered)
+    }
+}
@@ -0,0 +1,65 @@
+//! Stable entry points of `sublime_pkg_tools`.
+//!
+//! **What**: Re-exports the types and functions most consumers need for the changeset
+//! workflow: configuration, changesets, version resolution and application, changes
+//! analysis, changelog generation, upgrades, audits, and multi-root workspaces.
+//!
+//! **How**: `use sublime_pkg_tools::prelude::*;` brings them into scope. Each item is the
+//! same type as under its module path, so the two can be mixed freely.
+//!
+//! **Why**: Module paths follow the internal layout and may move as the crate grows. The
+//! prelude is the surface covered by the stability policy below, so CLI and daemon
+//! consumers importing from it keep compiling across minor releases.
+//!
+//! ## Stability
+//!
+//! - Items in the prelude follow semantic versioning: they are only removed or changed
+//!   incompatibly in a major release.
+//! - An item leaving the prelude or being renamed is first kept as a `#[deprecated]`
+//!   re-export for at least one minor release, with a note naming its replacement.
+//! - New items may be added in any minor release. Glob imports may then need an explicit
+//!   import to resolve a name clash with a local item.
+//!
+//! Items reachable only through module paths are also public, but may move between minor
+//! releases.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::prelude::*;
+//! use sublime_standard_tools::filesystem::FileSystemManager;
+//! use std::path::PathBuf;
+//!
+//! # async fn example() -> Result<()> {
+//! let root = PathBuf::from(".");
+//! let config = load_config().await?;
+//! let fs = FileSystemManager::new();
+//!
+//! let changesets = ChangesetManager::new(root.clone(), fs.clone(), config.clone()).await?;
+//! let changeset = changesets.load("main").await?;
+//!
+//! let resolver = VersionResolver::new(root, VersioningStrategy::Independent, fs, config).await?;
+//! let result: ApplyResult = resolver.apply_versions(&changeset, true).await?;
+//! println!("Would update {} packages", result.resolution.updates.len());
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::audit::{AuditIssue, AuditManager, AuditReport, IssueSeverity};
+pub use crate::changelog::{Changelog, ChangelogGenerator, GeneratedChangelog};
+pub use crate::changes::{ChangesAnalyzer, ChangesReport, PackageChanges};
+pub use crate::changeset::{ChangesetHistory, ChangesetManager, ChangesetQuery};
+pub use crate::config::{PackageToolsConfig, load_config, load_config_from_file};
+pub use crate::error::{Error, Result};
+pub use crate::publish::{PublishReport, PublishValidator};
+pub use crate::types::{
+    ArchivedChangeset, Changeset, PackageInfo, Version, VersionBump, VersioningStrategy, Warning,
+    WarningKind,
+};
+pub use crate::upgrade::{
+    DetectionOptions, PackageUpgrades, UpgradeManager, UpgradeResult, UpgradeSelection,
+};
+pub use crate::version::{
+    ApplyResult, DependencyGraph, PackageUpdate, VersionResolution, VersionResolver,
+};
+pub use crate::workspace::{WorkspaceSet, WorkspaceSetReport};
@@ -0,0 +1,44 @@
+//! Pre-publish checks for workspace packages.
+//!
+//! **What**: Validates that the packages of a release can be published: the `private` flag,
+//! the `publishConfig` registry and access of scoped packages, the fields the registry
+//! listing needs (license, repository), and that `files` and `exports` agree on what ends
+//! up in the tarball.
+//!
+//! **How**: Each check in `validation` reads the raw package.json, so fields the registry
+//! sees are checked as written rather than with parser defaults. `PublishValidator` runs
+//! them for the released packages and returns a `PublishReport`, skipping private packages;
+//! any critical issue in the report blocks the publish.
+//!
+//! **Why**: A scoped package published without `"access": "public"`, or an export whose
+//! file is left out of the tarball, is only found after the version is on the registry,
+//! where it cannot be republished. Checking before the release catches it while it is still
+//! a one-line fix.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use sublime_pkg_tools::publish::PublishValidator;
+//! use sublime_pkg_tools::types::PackageInfo;
+//!
+//! # async fn example(packages: Vec<PackageInfo>) {
+//! let report = PublishValidator::new().validate(&packages).await;
+//!
+//! for issue in report.blocking_issues() {
+//!     eprintln!("{}: {}", issue.package, issue.message);
+//! }
+//! assert!(!report.is_blocking());
+//! # }
+//! ```
+
+mod types;
+mod validation;
+
+#[cfg(test)]
+mod tests;
+
+pub use types::{PublishCheck, PublishIssue, PublishReport};
+pub use validation::{
+    DEFAULT_REQUIRED_FIELDS, PublishValidator, check_exports, check_files, check_publish_config,
+    check_required_fields, is_private, validate_manifest,
+};
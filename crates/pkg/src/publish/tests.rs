@@ -0,0 +1,234 @@
+//! Tests for the pre-publish checks.
+//!
+//! **What**: Covers required fields, `publishConfig` access and registry, `files`, and
+//! `exports` coverage, and the report built by `PublishValidator`.
+//!
+//! **How**: Checks run on JSON literals; the validator reads package.json files written to
+//! a temporary directory.
+//!
+//! **Why**: To ensure packages that npm would reject, or publish incomplete, block the
+//! release while valid packages pass untouched.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::*;
+use crate::audit::IssueSeverity;
+use crate::types::PackageInfo;
+use package_json::PackageJson;
+use serde_json::{Map, Value, json};
+use std::path::Path;
+
+fn object(value: Value) -> Map<String, Value> {
+    value.as_object().cloned().unwrap()
+}
+
+fn messages(issues: &[PublishIssue]) -> Vec<&str> {
+    issues.iter().map(|issue| issue.message.as_str()).collect()
+}
+
+mod required_fields {
+    use super::*;
+
+    #[test]
+    fn test_missing_and_empty_fields_are_blocking() {
+        let manifest = object(json!({ "name": "core", "license": "", "repository": {} }));
+        let fields = ["license".to_string(), "repository".to_string()];
+
+        let issues = check_required_fields("core", &manifest, &fields);
+
+        assert_eq!(
+            messages(&issues),
+            ["Missing required field 'license'", "Missing required field 'repository'"]
+        );
+        assert!(issues.iter().all(PublishIssue::is_blocking));
+    }
+
+    #[test]
+    fn test_name_and_version_are_always_required() {
+        let issues = validate_manifest("core", &json!({ "license": "MIT" }), &[]);
+
+        assert_eq!(
+            messages(&issues),
+            ["Missing required field 'name'", "Missing required field 'version'"]
+        );
+    }
+}
+
+mod publish_config {
+    use super::*;
+
+    #[test]
+    fn test_scoped_package_without_access_is_a_warning() {
+        let issues = check_publish_config("@acme/core", &object(json!({})));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, IssueSeverity::Warning);
+        assert_eq!(issues[0].check, PublishCheck::PublishConfig);
+    }
+
+    #[test]
+    fn test_unscoped_package_cannot_be_restricted() {
+        let manifest = object(json!({ "publishConfig": { "access": "restricted" } }));
+
+        let issues = check_publish_config("core", &manifest);
+
+        assert_eq!(messages(&issues), ["Unscoped packages cannot be published as restricted"]);
+        assert!(issues[0].is_blocking());
+        assert!(check_publish_config("@acme/core", &manifest).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_access_and_registry_are_blocking() {
+        let manifest =
+            object(json!({ "publishConfig": { "access": "open", "registry": "npm.acme.dev" } }));
+
+        let issues = check_publish_config("@acme/core", &manifest);
+
+        assert_eq!(
+            messages(&issues),
+            [
+                "Invalid 'publishConfig.access' \"open\"",
+                "Invalid 'publishConfig.registry' \"npm.acme.dev\""
+            ]
+        );
+        assert!(issues.iter().all(PublishIssue::is_blocking));
+    }
+
+    #[test]
+    fn test_valid_config_passes() {
+        let manifest = object(json!({
+            "publishConfig": { "access": "public", "registry": "https://npm.acme.dev/" }
+        }));
+
+        assert!(check_publish_config("@acme/core", &manifest).is_empty());
+    }
+}
+
+mod files_and_exports {
+    use super::*;
+
+    #[test]
+    fn test_empty_or_invalid_files_is_blocking() {
+        assert_eq!(check_files("core", &object(json!({ "files": [] }))).len(), 1);
+        assert_eq!(check_files("core", &object(json!({ "files": "dist" }))).len(), 1);
+        assert!(check_files("core", &object(json!({ "files": ["dist"] }))).is_empty());
+        assert!(check_files("core", &object(json!({}))).is_empty());
+    }
+
+    #[test]
+    fn test_targets_covered_by_files_pass() {
+        let manifest = object(json!({
+            "files": ["dist/", "bin/*.js"],
+            "types": "./dist/index.d.ts",
+            "exports": {
+                ".": { "import": "./dist/index.mjs", "require": ["./dist/index.cjs"] },
+                "./cli": "./bin/cli.js",
+                "./features/*": "./dist/features/*.js",
+                "./internal/*": null
+            }
+        }));
+
+        assert!(check_exports("core", &manifest).is_empty());
+    }
+
+    #[test]
+    fn test_target_outside_files_is_blocking() {
+        let manifest = object(json!({
+            "files": ["dist"],
+            "types": "types/index.d.ts",
+            "exports": { ".": "./lib/index.js" }
+        }));
+
+        let issues = check_exports("core", &manifest);
+
+        assert_eq!(
+            messages(&issues),
+            [
+                "exports[\".\"] points to ./lib/index.js, which 'files' does not include",
+                "'types' points to types/index.d.ts, which 'files' does not include"
+            ]
+        );
+        assert!(issues[0].is_blocking());
+        assert!(!issues[1].is_blocking());
+    }
+
+    #[test]
+    fn test_invalid_export_maps_are_blocking() {
+        let relative = object(json!({ "exports": "dist/index.js" }));
+        assert_eq!(
+            messages(&check_exports("core", &relative)),
+            ["exports target dist/index.js must start with './'"]
+        );
+
+        let mixed = object(json!({ "exports": { ".": "./index.js", "import": "./index.mjs" } }));
+        assert_eq!(
+            messages(&check_exports("core", &mixed)),
+            ["exports mixes subpaths and conditions at the same level"]
+        );
+    }
+}
+
+mod validator {
+    use super::*;
+
+    fn write_package(root: &Path, dir: &str, manifest: &Value) -> PackageInfo {
+        let path = root.join(dir);
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("package.json"), manifest.to_string()).unwrap();
+        let package_json: PackageJson = serde_json::from_value(manifest.clone()).unwrap();
+        PackageInfo::new(package_json, None, path)
+    }
+
+    #[tokio::test]
+    async fn test_report_skips_private_and_blocks_on_critical_issues() {
+        let temp = tempfile::tempdir().unwrap();
+        let valid = write_package(
+            temp.path(),
+            "core",
+            &json!({
+                "name": "@acme/core",
+                "version": "1.0.0",
+                "license": "MIT",
+                "repository": "github:acme/tools",
+                "publishConfig": { "access": "public" }
+            }),
+        );
+        let private = write_package(
+            temp.path(),
+            "app",
+            &json!({ "name": "@acme/app", "version": "1.0.0", "private": true }),
+        );
+        let unlicensed = write_package(
+            temp.path(),
+            "utils",
+            &json!({ "name": "utils", "version": "1.0.0", "repository": "github:acme/tools" }),
+        );
+
+        let report = PublishValidator::new().validate(&[valid, private, unlicensed]).await;
+
+        assert_eq!(report.packages, ["@acme/core", "utils"]);
+        assert_eq!(report.skipped_private, ["@acme/app"]);
+        assert!(report.is_blocking());
+        assert_eq!(report.issues_for("@acme/core").count(), 0);
+        assert_eq!(
+            report.blocking_issues().map(|issue| issue.message.as_str()).collect::<Vec<_>>(),
+            ["Missing required field 'license'"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_manifest_is_blocking() {
+        let temp = tempfile::tempdir().unwrap();
+        let package =
+            write_package(temp.path(), "core", &json!({ "name": "core", "version": "1.0.0" }));
+        std::fs::write(temp.path().join("core/package.json"), "{ not json").unwrap();
+
+        let report =
+            PublishValidator::new().with_required_fields(vec![]).validate(&[package]).await;
+
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].check, PublishCheck::Manifest);
+        assert!(report.is_blocking());
+    }
+}
@@ -0,0 +1,159 @@
+//! Issue and report types of the pre-publish checks.
+//!
+//! **What**: Defines `PublishIssue`, a problem found in one package.json, the
+//! `PublishCheck` that found it, and the `PublishReport` of a release.
+//!
+//! **How**: Issues reuse the audit `IssueSeverity`; critical issues block the publish and
+//! warnings are reported without blocking.
+//!
+//! **Why**: Callers gate a release on `PublishReport::is_blocking` and print the same issues
+//! whether they come from one package or the whole release.
+
+use crate::audit::IssueSeverity;
+use serde::{Deserialize, Serialize};
+
+/// The check that found a publish issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PublishCheck {
+    /// The package.json could not be read or is not a JSON object.
+    Manifest,
+    /// The `publishConfig` registry or access is invalid.
+    PublishConfig,
+    /// A field required for publishing is missing.
+    RequiredField,
+    /// The `files` field is invalid.
+    Files,
+    /// The `exports` field, or an entry point, is invalid or left out of the tarball.
+    Exports,
+}
+
+impl PublishCheck {
+    /// Returns the check as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::publish::PublishCheck;
+    ///
+    /// assert_eq!(PublishCheck::PublishConfig.as_str(), "publish-config");
+    /// assert_eq!(PublishCheck::RequiredField.as_str(), "required-field");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manifest => "manifest",
+            Self::PublishConfig => "publish-config",
+            Self::RequiredField => "required-field",
+            Self::Files => "files",
+            Self::Exports => "exports",
+        }
+    }
+}
+
+/// A problem that affects publishing a package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublishIssue {
+    /// Name of the package.
+    pub package: String,
+
+    /// The check that found the issue.
+    pub check: PublishCheck,
+
+    /// Critical issues block the publish; warnings do not.
+    pub severity: IssueSeverity,
+
+    /// What is wrong.
+    pub message: String,
+
+    /// How to fix it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl PublishIssue {
+    /// Creates a critical issue, which blocks the publish.
+    #[must_use]
+    pub fn blocking(
+        package: impl Into<String>,
+        check: PublishCheck,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            package: package.into(),
+            check,
+            severity: IssueSeverity::Critical,
+            message: message.into(),
+            suggestion: None,
+        }
+    }
+
+    /// Creates a warning, which is reported without blocking the publish.
+    #[must_use]
+    pub fn warning(
+        package: impl Into<String>,
+        check: PublishCheck,
+        message: impl Into<String>,
+    ) -> Self {
+        Self { severity: IssueSeverity::Warning, ..Self::blocking(package, check, message) }
+    }
+
+    /// Sets how to fix the issue.
+    #[must_use]
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Returns whether the issue blocks the publish.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::publish::{PublishCheck, PublishIssue};
+    ///
+    /// assert!(PublishIssue::blocking("core", PublishCheck::Files, "empty").is_blocking());
+    /// assert!(!PublishIssue::warning("core", PublishCheck::Files, "odd").is_blocking());
+    /// ```
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.severity == IssueSeverity::Critical
+    }
+}
+
+/// The result of checking the packages of a release.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishReport {
+    /// Packages that were checked, in the order given.
+    pub packages: Vec<String>,
+
+    /// Packages marked `"private": true`, which are never published and were not checked.
+    pub skipped_private: Vec<String>,
+
+    /// Issues found, grouped by package in the order of `packages`.
+    pub issues: Vec<PublishIssue>,
+}
+
+impl PublishReport {
+    /// Returns whether any issue blocks the publish.
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.issues.iter().any(PublishIssue::is_blocking)
+    }
+
+    /// Returns the issues that block the publish.
+    pub fn blocking_issues(&self) -> impl Iterator<Item = &PublishIssue> {
+        self.issues.iter().filter(|issue| issue.is_blocking())
+    }
+
+    /// Returns the issues that do not block the publish.
+    pub fn warnings(&self) -> impl Iterator<Item = &PublishIssue> {
+        self.issues.iter().filter(|issue| !issue.is_blocking())
+    }
+
+    /// Returns the issues of one package.
+    pub fn issues_for<'a>(&'a self, package: &'a str) -> impl Iterator<Item = &'a PublishIssue> {
+        self.issues.iter().filter(move |issue| issue.package == package)
+    }
+}
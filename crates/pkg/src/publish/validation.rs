@@ -0,0 +1,418 @@
+//! Reusable checks of a package.json before publishing.
+//!
+//! **What**: Provides one function per check, taking the raw package.json as JSON, and
+//! `PublishValidator`, which reads the manifests of the released packages and runs them all.
+//!
+//! **How**: Checks never touch the filesystem, so they can run before the build produces
+//! the files they describe. `exports` targets and `types` are compared with the `files`
+//! patterns the way npm packs them: an entry includes the path itself, everything under it,
+//! and whatever its glob matches.
+//!
+//! **Why**: The typed `PackageJson` fills in defaults such as `main`, which hides what the
+//! registry will actually receive.
+
+use super::types::{PublishCheck, PublishIssue, PublishReport};
+use crate::types::PackageInfo;
+use serde_json::{Map, Value};
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// Fields required by default: the registry listing shows them, and most organisations'
+/// policies require them.
+pub const DEFAULT_REQUIRED_FIELDS: [&str; 2] = ["license", "repository"];
+
+/// Values npm accepts for `publishConfig.access`.
+const ACCESS_LEVELS: [&str; 2] = ["public", "restricted"];
+
+/// Runs the pre-publish checks on the packages of a release.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::publish::PublishValidator;
+///
+/// let validator = PublishValidator::new().with_required_fields(vec!["license".to_string()]);
+/// assert_eq!(validator.required_fields(), ["license"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PublishValidator {
+    required_fields: Vec<String>,
+    fs: FileSystemManager,
+}
+
+impl Default for PublishValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PublishValidator {
+    /// Creates a validator requiring `DEFAULT_REQUIRED_FIELDS`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            required_fields: DEFAULT_REQUIRED_FIELDS.iter().map(ToString::to_string).collect(),
+            fs: FileSystemManager::new(),
+        }
+    }
+
+    /// Replaces the fields every package must declare, besides `name` and `version`.
+    #[must_use]
+    pub fn with_required_fields(mut self, fields: Vec<String>) -> Self {
+        self.required_fields = fields;
+        self
+    }
+
+    /// Returns the fields every package must declare, besides `name` and `version`.
+    #[must_use]
+    pub fn required_fields(&self) -> &[String] {
+        &self.required_fields
+    }
+
+    /// Checks the package.json of each package.
+    ///
+    /// Private packages are listed as skipped. A package.json that cannot be read or parsed
+    /// is reported as a blocking issue rather than an error, so one report covers the whole
+    /// release.
+    pub async fn validate<'a>(
+        &self,
+        packages: impl IntoIterator<Item = &'a PackageInfo>,
+    ) -> PublishReport {
+        let mut report = PublishReport::default();
+
+        for package in packages {
+            let name = package.name();
+            let path = package.path().join("package.json");
+            let manifest = match self.fs.read_file_string(&path).await {
+                Ok(content) => serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match manifest {
+                Ok(manifest) if is_private(&manifest) => {
+                    log::debug!("Skipping publish checks for private package {name}");
+                    report.skipped_private.push(name.to_string());
+                }
+                Ok(manifest) => {
+                    report.packages.push(name.to_string());
+                    report.issues.extend(validate_manifest(name, &manifest, &self.required_fields));
+                }
+                Err(error) => {
+                    report.packages.push(name.to_string());
+                    report.issues.push(PublishIssue::blocking(
+                        name,
+                        PublishCheck::Manifest,
+                        format!("Cannot read {}: {error}", path.display()),
+                    ));
+                }
+            }
+        }
+
+        report
+    }
+}
+
+/// Runs every check on a package.json.
+///
+/// `name` and `version` are always required, in addition to `required_fields`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::publish::validate_manifest;
+///
+/// let manifest = serde_json::json!({
+///     "name": "@acme/core",
+///     "version": "1.0.0",
+///     "license": "MIT",
+///     "publishConfig": { "access": "public" },
+/// });
+/// assert!(validate_manifest("@acme/core", &manifest, &["license".to_string()]).is_empty());
+/// ```
+#[must_use]
+pub fn validate_manifest(
+    package: &str,
+    manifest: &Value,
+    required_fields: &[String],
+) -> Vec<PublishIssue> {
+    let Some(object) = manifest.as_object() else {
+        return vec![PublishIssue::blocking(
+            package,
+            PublishCheck::Manifest,
+            "package.json is not a JSON object",
+        )];
+    };
+
+    let mut fields = vec!["name".to_string(), "version".to_string()];
+    for field in required_fields {
+        if !fields.contains(field) {
+            fields.push(field.clone());
+        }
+    }
+
+    let mut issues = check_required_fields(package, object, &fields);
+    issues.extend(check_publish_config(package, object));
+    issues.extend(check_files(package, object));
+    issues.extend(check_exports(package, object));
+    issues
+}
+
+/// Returns whether a package.json is marked `"private": true`.
+#[must_use]
+pub fn is_private(manifest: &Value) -> bool {
+    manifest.get("private").and_then(Value::as_bool).unwrap_or(false)
+}
+
+/// Checks that each field is present and not empty.
+#[must_use]
+pub fn check_required_fields(
+    package: &str,
+    manifest: &Map<String, Value>,
+    fields: &[String],
+) -> Vec<PublishIssue> {
+    fields
+        .iter()
+        .filter(|field| match manifest.get(field.as_str()) {
+            None | Some(Value::Null) => true,
+            Some(Value::String(value)) => value.trim().is_empty(),
+            Some(Value::Array(values)) => values.is_empty(),
+            Some(Value::Object(values)) => values.is_empty(),
+            Some(_) => false,
+        })
+        .map(|field| {
+            PublishIssue::blocking(
+                package,
+                PublishCheck::RequiredField,
+                format!("Missing required field '{field}'"),
+            )
+            .with_suggestion(format!("Add '{field}' to package.json"))
+        })
+        .collect()
+}
+
+/// Checks the `publishConfig` registry and access.
+///
+/// A scoped package without an access level is published as restricted, which fails on the
+/// public registry without a paid organisation, so it is reported as a warning; unscoped
+/// packages cannot be restricted at all.
+#[must_use]
+pub fn check_publish_config(package: &str, manifest: &Map<String, Value>) -> Vec<PublishIssue> {
+    let scoped = package.starts_with('@');
+    let config = match manifest.get("publishConfig") {
+        None => None,
+        Some(Value::Object(config)) => Some(config),
+        Some(_) => {
+            return vec![PublishIssue::blocking(
+                package,
+                PublishCheck::PublishConfig,
+                "'publishConfig' must be an object",
+            )];
+        }
+    };
+
+    let mut issues = Vec::new();
+    match config.and_then(|config| config.get("access")) {
+        None if scoped => issues.push(
+            PublishIssue::warning(
+                package,
+                PublishCheck::PublishConfig,
+                "Scoped package has no 'publishConfig.access'; npm publishes it as restricted",
+            )
+            .with_suggestion("Set 'publishConfig.access' to \"public\" or \"restricted\""),
+        ),
+        None => {}
+        Some(access) => match access.as_str() {
+            Some(access) if ACCESS_LEVELS.contains(&access) => {
+                if access == "restricted" && !scoped {
+                    issues.push(
+                        PublishIssue::blocking(
+                            package,
+                            PublishCheck::PublishConfig,
+                            "Unscoped packages cannot be published as restricted",
+                        )
+                        .with_suggestion(
+                            "Set 'publishConfig.access' to \"public\" or scope the package",
+                        ),
+                    );
+                }
+            }
+            _ => issues.push(
+                PublishIssue::blocking(
+                    package,
+                    PublishCheck::PublishConfig,
+                    format!("Invalid 'publishConfig.access' {access}"),
+                )
+                .with_suggestion("Use \"public\" or \"restricted\""),
+            ),
+        },
+    }
+
+    if let Some(registry) = config.and_then(|config| config.get("registry")) {
+        let valid =
+            registry.as_str().and_then(|url| reqwest::Url::parse(url).ok()).is_some_and(|url| {
+                matches!(url.scheme(), "http" | "https") && url.host_str().is_some()
+            });
+        if !valid {
+            issues.push(
+                PublishIssue::blocking(
+                    package,
+                    PublishCheck::PublishConfig,
+                    format!("Invalid 'publishConfig.registry' {registry}"),
+                )
+                .with_suggestion("Use an http(s) URL such as \"https://registry.npmjs.org/\""),
+            );
+        }
+    }
+
+    issues
+}
+
+/// Checks that `files`, when present, is a non-empty list of patterns.
+#[must_use]
+pub fn check_files(package: &str, manifest: &Map<String, Value>) -> Vec<PublishIssue> {
+    match manifest.get("files") {
+        None => vec![],
+        Some(Value::Array(files)) if files.iter().all(Value::is_string) => {
+            if files.is_empty() {
+                vec![
+                    PublishIssue::blocking(
+                        package,
+                        PublishCheck::Files,
+                        "'files' is empty; only package.json, README and LICENSE would be published",
+                    )
+                    .with_suggestion("List the build output in 'files' or remove the field"),
+                ]
+            } else {
+                vec![]
+            }
+        }
+        Some(_) => vec![PublishIssue::blocking(
+            package,
+            PublishCheck::Files,
+            "'files' must be a list of strings",
+        )],
+    }
+}
+
+/// Checks `exports` and `types` against the `files` that are published.
+///
+/// Export targets must be relative paths starting with `./`, and an export map cannot mix
+/// subpaths with conditions at the same level, or Node rejects the package at import time.
+/// Targets and the `types` entry left out by `files` are missing from the tarball.
+#[must_use]
+pub fn check_exports(package: &str, manifest: &Map<String, Value>) -> Vec<PublishIssue> {
+    let files: Option<Vec<&str>> = manifest
+        .get("files")
+        .and_then(Value::as_array)
+        .map(|files| files.iter().filter_map(Value::as_str).collect());
+
+    let mut issues = Vec::new();
+    let mut targets = Vec::new();
+    if let Some(exports) = manifest.get("exports") {
+        collect_export_targets(package, exports, "exports", &mut targets, &mut issues);
+    }
+
+    if let Some(files) = files.as_deref().filter(|files| !files.is_empty()) {
+        for (location, target) in &targets {
+            if !is_packed(target, files) {
+                issues.push(
+                    PublishIssue::blocking(
+                        package,
+                        PublishCheck::Exports,
+                        format!("{location} points to {target}, which 'files' does not include"),
+                    )
+                    .with_suggestion(format!("Add {target} or its directory to 'files'")),
+                );
+            }
+        }
+
+        for field in ["types", "typings"] {
+            if let Some(types) = manifest.get(field).and_then(Value::as_str)
+                && !is_packed(types, files)
+            {
+                issues.push(
+                    PublishIssue::warning(
+                        package,
+                        PublishCheck::Exports,
+                        format!("'{field}' points to {types}, which 'files' does not include"),
+                    )
+                    .with_suggestion(format!("Add {types} or its directory to 'files'")),
+                );
+            }
+        }
+    }
+
+    issues
+}
+
+/// Collects the file targets of an export map, reporting invalid entries.
+fn collect_export_targets(
+    package: &str,
+    value: &Value,
+    location: &str,
+    targets: &mut Vec<(String, String)>,
+    issues: &mut Vec<PublishIssue>,
+) {
+    match value {
+        Value::String(target) => {
+            if target.starts_with("./") {
+                targets.push((location.to_string(), target.clone()));
+            } else {
+                issues.push(
+                    PublishIssue::blocking(
+                        package,
+                        PublishCheck::Exports,
+                        format!("{location} target {target} must start with './'"),
+                    )
+                    .with_suggestion(format!("Use \"./{}\"", target.trim_start_matches('/'))),
+                );
+            }
+        }
+        Value::Array(fallbacks) => {
+            for fallback in fallbacks {
+                collect_export_targets(package, fallback, location, targets, issues);
+            }
+        }
+        Value::Object(entries) => {
+            let subpaths = entries.keys().filter(|key| key.starts_with('.')).count();
+            if subpaths > 0 && subpaths < entries.len() {
+                issues.push(PublishIssue::blocking(
+                    package,
+                    PublishCheck::Exports,
+                    format!("{location} mixes subpaths and conditions at the same level"),
+                ));
+                return;
+            }
+            for (key, entry) in entries {
+                let location = format!("{location}[\"{key}\"]");
+                collect_export_targets(package, entry, &location, targets, issues);
+            }
+        }
+        // `null` hides a subpath; other values are not valid targets
+        Value::Null => {}
+        _ => issues.push(PublishIssue::blocking(
+            package,
+            PublishCheck::Exports,
+            format!("{location} has an invalid target {value}"),
+        )),
+    }
+}
+
+/// Returns whether npm packs a path given the `files` patterns.
+///
+/// `*` in a subpath pattern target stands for any file name.
+fn is_packed(path: &str, files: &[&str]) -> bool {
+    let path = normalize(&path.replace('*', "_"));
+    files.iter().filter(|pattern| !pattern.starts_with('!')).map(|p| normalize(p)).any(|pattern| {
+        path == pattern
+            || path.starts_with(&format!("{pattern}/"))
+            || glob::Pattern::new(&pattern).is_ok_and(|glob| {
+                glob.matches(&path)
+                    || path.match_indices('/').any(|(index, _)| glob.matches(&path[..index]))
+            })
+    })
+}
+
+/// Strips the `./` prefix and trailing `/` of a package-relative path.
+fn normalize(path: &str) -> String {
+    path.trim_start_matches("./").trim_end_matches('/').to_string()
+}
@@ -17,12 +17,16 @@
 //! ## Changeset
 //!
 //! The primary data structure representing a set of changes to be released. It includes:
+//! - A unique id and an optional human-friendly name
 //! - Branch name identifying the changeset
 //! - Version bump type (Major, Minor, Patch, None)
 //! - Target deployment environments
 //! - List of affected package names
 //! - Commit IDs included in the changeset
 //! - Creation and update timestamps
+//! - Optional creator identity, creation branch, and revision history
+//! - Split and merge operations that produced it
+//! - Environments it was promoted into
 //!
 //! ## ArchivedChangeset
 //!
@@ -37,6 +41,12 @@
 //! - User or system that applied the release
 //! - Git commit hash of the release
 //! - Actual versions released per package
+//! - Packages left out of the release and the changelog anchors of the released ones
+//!
+//! ## ReleaseManifest
+//!
+//! A machine-readable report of what a release consumed: for each changeset, the packages
+//! released at which versions, the packages skipped and why, and their changelog anchors.
 //!
 //! # Examples
 //!
@@ -144,6 +154,7 @@ use crate::error::{ChangesetError, ChangesetResult};
 use crate::types::VersionBump;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 
 /// Represents a changeset for a branch.
@@ -157,6 +168,8 @@ use std::collections::HashMap;
 ///
 /// # Fields
 ///
+/// - `id`: Unique, stable identifier of the changeset
+/// - `name`: Optional human-friendly name, such as `brave-lions-smile`
 /// - `branch`: The git branch name this changeset is associated with
 /// - `bump`: The type of version bump to apply (Major, Minor, Patch, or None)
 /// - `environments`: Target deployment environments (e.g., ["staging", "production"])
@@ -164,6 +177,15 @@ use std::collections::HashMap;
 /// - `changes`: List of git commit hashes included in this changeset
 /// - `created_at`: Timestamp when the changeset was created
 /// - `updated_at`: Timestamp when the changeset was last modified
+/// - `author`: Who created the changeset, when author tracking is enabled
+/// - `created_on_branch`: Git branch checked out when the changeset was created
+/// - `revisions`: Who changed what, and when, after creation
+/// - `operations`: Split and merge operations that produced the changeset
+/// - `association`: Strategy last used to associate git commits with the changeset
+/// - `promotions`: Environments the changeset was promoted into, in promotion order
+///
+/// The last six fields are optional and omitted from serialized output when empty, so
+/// changeset files written before they existed still load.
 ///
 /// # Examples
 ///
@@ -184,6 +206,20 @@ use std::collections::HashMap;
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Changeset {
+    /// Unique identifier (12 lowercase hex characters).
+    ///
+    /// Generated on creation and kept for the life of the changeset, including in history.
+    /// Changeset files written before ids existed have none; storage fills in an id derived
+    /// from the branch and creation time when loading them.
+    #[serde(default)]
+    pub id: String,
+
+    /// Human-friendly name, such as `brave-lions-smile`.
+    ///
+    /// Generated from the id when `changeset.human_names` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
     /// Branch name (e.g., "feat/oauth-integration").
     ///
     /// This is the git branch that the changeset is associated with. Typically,
@@ -224,13 +260,40 @@ pub struct Changeset {
     /// UTC timestamp recording the last time this changeset was modified
     /// (e.g., packages or commits added).
     pub updated_at: DateTime<Utc>,
+
+    /// Identity of whoever created the changeset.
+    ///
+    /// Captured from the CI actor or the git user configuration when
+    /// `changeset.track_authors` is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<ChangesetAuthor>,
+
+    /// Git branch that was checked out when the changeset was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_on_branch: Option<String>,
+
+    /// Modifications made after creation, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revisions: Vec<ChangesetRevision>,
+
+    /// Split and merge operations that produced this changeset, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<ChangesetOperation>,
+
+    /// Strategy last used to associate git commits with the changeset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub association: Option<CommitAssociation>,
+
+    /// Environments the changeset was promoted into, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub promotions: Vec<EnvironmentPromotion>,
 }
 
 impl Changeset {
     /// Creates a new changeset with the specified branch, bump type, and environments.
     ///
-    /// The changeset is initialized with empty packages and changes lists, and both
-    /// timestamps are set to the current UTC time.
+    /// The changeset is initialized with a new random id, no name, empty packages and
+    /// changes lists, and both timestamps set to the current UTC time.
     ///
     /// # Arguments
     ///
@@ -256,6 +319,8 @@ impl Changeset {
     pub fn new(branch: impl Into<String>, bump: VersionBump, environments: Vec<String>) -> Self {
         let now = Utc::now();
         Self {
+            id: generate_id(),
+            name: None,
             branch: branch.into(),
             bump,
             environments,
@@ -263,7 +328,133 @@ impl Changeset {
             changes: Vec::new(),
             created_at: now,
             updated_at: now,
+            author: None,
+            created_on_branch: None,
+            revisions: Vec::new(),
+            operations: Vec::new(),
+            association: None,
+            promotions: Vec::new(),
+        }
+    }
+
+    /// Fills in the id of a changeset written before ids existed.
+    ///
+    /// The id is derived from the branch and creation time, so the same file always gets
+    /// the same id. Does nothing when the changeset already has an id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut changeset = Changeset::new("fix/auth", VersionBump::Patch, vec![]);
+    /// changeset.id.clear();
+    ///
+    /// changeset.ensure_id();
+    /// assert_eq!(changeset.id.len(), 12);
+    /// ```
+    pub fn ensure_id(&mut self) {
+        if self.id.is_empty() {
+            self.id = derive_id(&format!("{}@{}", self.branch, self.created_at.to_rfc3339()));
+        }
+    }
+
+    /// Checks whether `reference` names this changeset by id, name, or branch.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - A changeset id, name, or branch name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut changeset = Changeset::new("fix/auth", VersionBump::Patch, vec![]);
+    /// changeset.name = Some("brave-lions-smile".to_string());
+    ///
+    /// assert!(changeset.matches_reference("brave-lions-smile"));
+    /// assert!(changeset.matches_reference(&changeset.id.clone()));
+    /// assert!(changeset.matches_reference("fix/auth"));
+    /// assert!(!changeset.matches_reference("fix/other"));
+    /// ```
+    #[must_use]
+    pub fn matches_reference(&self, reference: &str) -> bool {
+        self.id == reference || self.branch == reference || self.name.as_deref() == Some(reference)
+    }
+
+    /// Describes how `self` differs from `previous`.
+    ///
+    /// Each entry is a short human-readable line such as `bump: minor -> major` or
+    /// `packages added: @myorg/core`. Timestamps and metadata fields are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The earlier state of the changeset
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let before = Changeset::new("feat/api", VersionBump::Minor, vec!["production".to_string()]);
+    /// let mut after = before.clone();
+    /// after.set_bump(VersionBump::Major);
+    /// after.add_package("@myorg/core");
+    ///
+    /// assert_eq!(
+    ///     after.describe_changes_since(&before),
+    ///     vec!["bump: minor -> major", "packages added: @myorg/core"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn describe_changes_since(&self, previous: &Changeset) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        if self.bump != previous.bump {
+            changes.push(format!(
+                "bump: {} -> {}",
+                previous.bump.to_string().to_lowercase(),
+                self.bump.to_string().to_lowercase()
+            ));
+        }
+        if self.environments != previous.environments {
+            changes.push(format!(
+                "environments: [{}] -> [{}]",
+                previous.environments.join(", "),
+                self.environments.join(", ")
+            ));
+        }
+
+        let added: Vec<&str> = self
+            .packages
+            .iter()
+            .filter(|p| !previous.packages.contains(p))
+            .map(String::as_str)
+            .collect();
+        if !added.is_empty() {
+            changes.push(format!("packages added: {}", added.join(", ")));
+        }
+        let removed: Vec<&str> = previous
+            .packages
+            .iter()
+            .filter(|p| !self.packages.contains(p))
+            .map(String::as_str)
+            .collect();
+        if !removed.is_empty() {
+            changes.push(format!("packages removed: {}", removed.join(", ")));
+        }
+
+        let commits_added = self.changes.iter().filter(|c| !previous.changes.contains(c)).count();
+        if commits_added > 0 {
+            changes.push(format!("commits added: {commits_added}"));
+        }
+        let commits_removed = previous.changes.iter().filter(|c| !self.changes.contains(c)).count();
+        if commits_removed > 0 {
+            changes.push(format!("commits removed: {commits_removed}"));
         }
+
+        changes
     }
 
     /// Adds a package to the changeset if not already present.
@@ -369,6 +560,216 @@ impl Changeset {
         }
     }
 
+    /// Splits the changeset into a released part and a part that stays pending.
+    ///
+    /// Used by partial releases: the released part lists the packages in `released`
+    /// and the pending part lists the rest. Both keep the bump, environments, and
+    /// commits. When packages remain, the released part is renamed to
+    /// `<branch>@<package>+<package>` so it can be archived without clashing with the
+    /// pending changeset, which keeps the branch name. It also gets an id derived from the
+    /// original id and its branch, so the same split always yields the same id.
+    ///
+    /// # Arguments
+    ///
+    /// * `released` - Names of the packages being released
+    ///
+    /// # Returns
+    ///
+    /// The released part and, if any package is not released, the pending part.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut changeset = Changeset::new("fix/auth", VersionBump::Patch, vec![]);
+    /// changeset.add_package("@myorg/core");
+    /// changeset.add_package("@myorg/utils");
+    ///
+    /// let (released, pending) = changeset.split_packages(&["@myorg/core".to_string()]);
+    ///
+    /// assert_eq!(released.branch, "fix/auth@@myorg/core");
+    /// assert_ne!(released.id, changeset.id);
+    /// assert_eq!(changeset.split_packages(&["@myorg/core".to_string()]).0.id, released.id);
+    /// assert_eq!(released.packages, vec!["@myorg/core".to_string()]);
+    /// assert_eq!(pending.map(|p| p.packages), Some(vec!["@myorg/utils".to_string()]));
+    /// ```
+    #[must_use]
+    pub fn split_packages(&self, released: &[String]) -> (Changeset, Option<Changeset>) {
+        let (kept, remaining): (Vec<String>, Vec<String>) =
+            self.packages.iter().cloned().partition(|package| released.contains(package));
+        if remaining.is_empty() {
+            return (self.clone(), None);
+        }
+
+        let now = Utc::now();
+        let mut released_part = self.clone();
+        released_part.branch = format!("{}@{}", self.branch, kept.join("+"));
+        released_part.id = derive_id(&format!("{}@{}", self.id, released_part.branch));
+        released_part.packages = kept;
+        released_part.updated_at = now;
+
+        let mut pending = self.clone();
+        pending.packages = remaining;
+        pending.updated_at = now;
+
+        (released_part, Some(pending))
+    }
+
+    /// Moves packages out of this changeset into a new changeset.
+    ///
+    /// The new changeset keeps this changeset's bump, environments, and commits, since
+    /// commits are not attributed to single packages. Both changesets record the split
+    /// in `operations`.
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - Names of the packages to move
+    /// * `branch` - Branch name of the new changeset
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::PackageNotInChangeset` if a package is not listed,
+    /// `ChangesetError::InvalidBranch` if `branch` is this changeset's branch, and
+    /// `ChangesetError::EmptyChangeset` if no package is given or no package would stay.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+    /// changeset.add_package("@myorg/auth");
+    /// changeset.add_package("@myorg/docs");
+    ///
+    /// let docs = changeset.split_off(&["@myorg/docs".to_string()], "feature/auth-docs")?;
+    ///
+    /// assert_eq!(changeset.packages, vec!["@myorg/auth".to_string()]);
+    /// assert_eq!(docs.packages, vec!["@myorg/docs".to_string()]);
+    /// assert_eq!(docs.bump, VersionBump::Minor);
+    /// # Ok::<(), sublime_pkg_tools::error::ChangesetError>(())
+    /// ```
+    pub fn split_off(&mut self, packages: &[String], branch: &str) -> ChangesetResult<Changeset> {
+        if branch == self.branch {
+            return Err(ChangesetError::InvalidBranch {
+                branch: branch.to_string(),
+                reason: "a split changeset needs a branch name of its own".to_string(),
+            });
+        }
+        if let Some(package) = packages.iter().find(|package| !self.has_package(package)) {
+            return Err(ChangesetError::PackageNotInChangeset {
+                branch: self.branch.clone(),
+                package: package.clone(),
+            });
+        }
+        if packages.is_empty() || self.packages.iter().all(|package| packages.contains(package)) {
+            return Err(ChangesetError::EmptyChangeset { branch: self.branch.clone() });
+        }
+
+        let now = Utc::now();
+        let operation = ChangesetOperation {
+            kind: ChangesetOperationKind::Split,
+            timestamp: now,
+            author: None,
+            from: vec![self.branch.clone()],
+            into: vec![self.branch.clone(), branch.to_string()],
+            packages: packages.to_vec(),
+        };
+
+        let mut split = Changeset::new(branch, self.bump, self.environments.clone());
+        split.packages = packages.to_vec();
+        split.changes = self.changes.clone();
+        split.created_on_branch = self.created_on_branch.clone();
+        split.operations = self.operations.clone();
+        split.operations.push(operation.clone());
+        split.promotions = self.promotions.clone();
+
+        self.packages.retain(|package| !packages.contains(package));
+        self.operations.push(operation);
+        self.updated_at = now;
+
+        Ok(split)
+    }
+
+    /// Merges changesets into one.
+    ///
+    /// The merged changeset lists every package, environment, and commit of its sources
+    /// once, in order of first appearance. Its bump is the largest source bump, so every
+    /// package keeps at least the bump it had. It keeps the earliest creation time, the
+    /// first source's author, and the revisions and operations of all sources, and
+    /// records the merge in `operations`.
+    ///
+    /// # Arguments
+    ///
+    /// * `branch` - Branch name of the merged changeset
+    /// * `sources` - The changesets to merge
+    ///
+    /// # Errors
+    ///
+    /// Returns `ChangesetError::ValidationFailed` if fewer than two changesets are given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut fix = Changeset::new("fix/login", VersionBump::Patch, vec!["production".to_string()]);
+    /// fix.add_package("@myorg/auth");
+    /// let mut feature = Changeset::new("feature/sso", VersionBump::Minor, vec!["staging".to_string()]);
+    /// feature.add_package("@myorg/auth");
+    /// feature.add_package("@myorg/core");
+    ///
+    /// let merged = Changeset::merge("release/auth", &[fix, feature])?;
+    ///
+    /// assert_eq!(merged.bump, VersionBump::Minor);
+    /// assert_eq!(merged.packages, vec!["@myorg/auth".to_string(), "@myorg/core".to_string()]);
+    /// assert_eq!(merged.environments, vec!["production".to_string(), "staging".to_string()]);
+    /// # Ok::<(), sublime_pkg_tools::error::ChangesetError>(())
+    /// ```
+    pub fn merge(branch: &str, sources: &[Changeset]) -> ChangesetResult<Changeset> {
+        let [first, _, ..] = sources else {
+            return Err(ChangesetError::ValidationFailed {
+                errors: vec!["at least two changesets are needed to merge".to_string()],
+            });
+        };
+
+        fn extend_unique(target: &mut Vec<String>, items: &[String]) {
+            for item in items {
+                if !target.contains(item) {
+                    target.push(item.clone());
+                }
+            }
+        }
+
+        let mut merged = Changeset::new(branch, first.bump, Vec::new());
+        merged.author = first.author.clone();
+        merged.created_on_branch = first.created_on_branch.clone();
+        merged.created_at = first.created_at;
+        for source in sources {
+            if source.bump.rank() > merged.bump.rank() {
+                merged.bump = source.bump;
+            }
+            merged.created_at = merged.created_at.min(source.created_at);
+            extend_unique(&mut merged.environments, &source.environments);
+            extend_unique(&mut merged.packages, &source.packages);
+            extend_unique(&mut merged.changes, &source.changes);
+            merged.revisions.extend(source.revisions.iter().cloned());
+            merged.operations.extend(source.operations.iter().cloned());
+        }
+        merged.revisions.sort_by_key(|revision| revision.timestamp);
+        merged.operations.sort_by_key(|operation| operation.timestamp);
+        merged.operations.push(ChangesetOperation {
+            kind: ChangesetOperationKind::Merge,
+            timestamp: merged.updated_at,
+            author: None,
+            from: sources.iter().map(|source| source.branch.clone()).collect(),
+            into: vec![branch.to_string()],
+            packages: merged.packages.clone(),
+        });
+
+        Ok(merged)
+    }
+
     /// Checks if the changeset contains a specific package.
     ///
     /// # Arguments
@@ -399,6 +800,48 @@ impl Changeset {
         self.packages.contains(&package.to_string())
     }
 
+    /// Checks if the changeset was promoted into an environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - The environment name to check
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Changeset, VersionBump};
+    ///
+    /// let mut changeset = Changeset::new("feat/test", VersionBump::Patch, vec![]);
+    /// changeset.record_promotion("staging", None);
+    ///
+    /// assert!(changeset.is_promoted_to("staging"));
+    /// assert!(!changeset.is_promoted_to("production"));
+    /// ```
+    #[must_use]
+    pub fn is_promoted_to(&self, environment: &str) -> bool {
+        self.promotions.iter().any(|promotion| promotion.environment == environment)
+    }
+
+    /// Records a promotion into an environment.
+    ///
+    /// The promotion order is not checked here; see `ChangesetManager::plan_promotion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - The environment the changeset was promoted into
+    /// * `author` - Who promoted the changeset, when known
+    pub fn record_promotion(
+        &mut self,
+        environment: impl Into<String>,
+        author: Option<ChangesetAuthor>,
+    ) {
+        self.promotions.push(EnvironmentPromotion {
+            environment: environment.into(),
+            timestamp: Utc::now(),
+            author,
+        });
+    }
+
     /// Checks if the changeset contains a specific commit.
     ///
     /// # Arguments
@@ -595,6 +1038,187 @@ impl Changeset {
     }
 }
 
+/// Generates a new random changeset id.
+pub(crate) fn generate_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..12].to_string()
+}
+
+/// Derives a changeset id from `seed`, so the same seed always yields the same id.
+fn derive_id(seed: &str) -> String {
+    Sha256::digest(seed.as_bytes()).iter().take(6).map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Identity of a person or automation that created or modified a changeset.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::ChangesetAuthor;
+///
+/// let author = ChangesetAuthor {
+///     name: "Jane Doe".to_string(),
+///     email: Some("jane@example.com".to_string()),
+///     source: "git".to_string(),
+/// };
+/// assert_eq!(author.to_string(), "Jane Doe <jane@example.com>");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangesetAuthor {
+    /// Display name or CI login.
+    pub name: String,
+
+    /// Email address, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Where the identity came from: `git` for the git user configuration, or the CI
+    /// provider (`github`, `gitlab`, `circleci`, `buildkite`).
+    pub source: String,
+}
+
+impl std::fmt::Display for ChangesetAuthor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.email {
+            Some(email) => write!(f, "{} <{email}>", self.name),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// A recorded modification of a changeset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangesetRevision {
+    /// When the modification was saved.
+    pub timestamp: DateTime<Utc>,
+
+    /// Who made the modification, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<ChangesetAuthor>,
+
+    /// Human-readable description of each change, as produced by
+    /// [`Changeset::describe_changes_since`].
+    pub changes: Vec<String>,
+}
+
+/// Kind of structural operation applied to changesets.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangesetOperationKind {
+    /// Packages were moved out of a changeset into a new one.
+    Split,
+    /// Several changesets were combined into one.
+    Merge,
+}
+
+impl std::fmt::Display for ChangesetOperationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Split => write!(f, "split"),
+            Self::Merge => write!(f, "merge"),
+        }
+    }
+}
+
+/// A recorded split or merge that produced a changeset.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::{Changeset, ChangesetOperationKind, VersionBump};
+///
+/// let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+/// changeset.add_package("@myorg/auth");
+/// changeset.add_package("@myorg/docs");
+/// let docs = changeset.split_off(&["@myorg/docs".to_string()], "feature/auth-docs")?;
+///
+/// let operation = &docs.operations[0];
+/// assert_eq!(operation.kind, ChangesetOperationKind::Split);
+/// assert_eq!(operation.from, vec!["feature/auth".to_string()]);
+/// # Ok::<(), sublime_pkg_tools::error::ChangesetError>(())
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangesetOperation {
+    /// Whether this was a split or a merge.
+    pub kind: ChangesetOperationKind,
+
+    /// When the operation was performed.
+    pub timestamp: DateTime<Utc>,
+
+    /// Who performed the operation, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<ChangesetAuthor>,
+
+    /// Branches of the changesets the operation read.
+    pub from: Vec<String>,
+
+    /// Branches of the changesets the operation wrote.
+    pub into: Vec<String>,
+
+    /// Packages moved by a split, or listed by a merged changeset.
+    pub packages: Vec<String>,
+}
+
+/// A recorded promotion of a changeset into a deployment environment.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::{Changeset, VersionBump};
+///
+/// let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+/// changeset.record_promotion("staging", None);
+///
+/// assert_eq!(changeset.promotions[0].environment, "staging");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnvironmentPromotion {
+    /// Environment the changeset was promoted into.
+    pub environment: String,
+
+    /// When the promotion was recorded.
+    pub timestamp: DateTime<Utc>,
+
+    /// Who promoted the changeset, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<ChangesetAuthor>,
+}
+
+/// Strategy deciding which git commits are associated with a changeset.
+///
+/// Every strategy considers the commits on the current branch since its merge-base with
+/// the base branch, and skips commits the changeset already lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::CommitAssociation;
+///
+/// assert_eq!(CommitAssociation::default(), CommitAssociation::Branch);
+/// assert_eq!(CommitAssociation::Trailer.to_string(), "trailer");
+/// ```
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitAssociation {
+    /// All commits on the branch; the packages they touch are added to the changeset.
+    #[default]
+    Branch,
+    /// Only commits touching a package the changeset already lists.
+    Packages,
+    /// Only commits with a `Changeset: <id>` trailer naming the changeset; the packages
+    /// they touch are added to the changeset.
+    Trailer,
+}
+
+impl std::fmt::Display for CommitAssociation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Branch => write!(f, "branch"),
+            Self::Packages => write!(f, "packages"),
+            Self::Trailer => write!(f, "trailer"),
+        }
+    }
+}
+
 /// Changeset after being released and archived.
 ///
 /// When a changeset is applied (packages are released), it is moved from the active
@@ -726,6 +1350,20 @@ pub struct ReleaseInfo {
     /// This captures the actual versions at release time, which may differ
     /// from calculated versions if manual adjustments were made.
     pub versions: HashMap<String, String>,
+
+    /// Packages of the changeset left out of the release.
+    ///
+    /// Filtered packages stay pending in another changeset; private packages are
+    /// versioned but not published.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedPackage>,
+
+    /// Changelog anchors of the released packages.
+    ///
+    /// Map of package names to the changelog file and heading anchor of the released
+    /// version, relative to the workspace root (e.g., `packages/core/CHANGELOG.md#120---2024-01-15`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub changelogs: HashMap<String, String>,
 }
 
 impl ReleaseInfo {
@@ -765,9 +1403,53 @@ impl ReleaseInfo {
             applied_by: applied_by.into(),
             git_commit: git_commit.into(),
             versions,
+            skipped: Vec::new(),
+            changelogs: HashMap::new(),
         }
     }
 
+    /// Records the packages of the changeset that were left out of the release.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{ReleaseInfo, ReleaseSkipReason, SkippedPackage};
+    /// use std::collections::HashMap;
+    ///
+    /// let release_info = ReleaseInfo::new("ci", "abc", HashMap::new())
+    ///     .with_skipped(vec![SkippedPackage::new("@myorg/docs", ReleaseSkipReason::Filtered)]);
+    ///
+    /// assert_eq!(release_info.skipped.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_skipped(mut self, skipped: Vec<SkippedPackage>) -> Self {
+        self.skipped = skipped;
+        self
+    }
+
+    /// Records the changelog anchors of the released packages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::ReleaseInfo;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut changelogs = HashMap::new();
+    /// changelogs.insert("@myorg/core".to_string(), "packages/core/CHANGELOG.md#120".to_string());
+    ///
+    /// let release_info = ReleaseInfo::new("ci", "abc", HashMap::new()).with_changelogs(changelogs);
+    /// assert_eq!(
+    ///     release_info.changelogs.get("@myorg/core").map(String::as_str),
+    ///     Some("packages/core/CHANGELOG.md#120")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_changelogs(mut self, changelogs: HashMap<String, String>) -> Self {
+        self.changelogs = changelogs;
+        self
+    }
+
     /// Gets the version for a specific package.
     ///
     /// # Arguments
@@ -818,6 +1500,230 @@ impl ReleaseInfo {
     }
 }
 
+/// Why a package of a changeset was left out of a release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReleaseSkipReason {
+    /// Not selected by the release filter; the package stays in a pending changeset.
+    Filtered,
+    /// Marked `"private": true` in package.json; the version is bumped but not published.
+    Private,
+}
+
+impl ReleaseSkipReason {
+    /// Returns the reason as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::ReleaseSkipReason;
+    ///
+    /// assert_eq!(ReleaseSkipReason::Filtered.as_str(), "filtered");
+    /// assert_eq!(ReleaseSkipReason::Private.as_str(), "private");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Filtered => "filtered",
+            Self::Private => "private",
+        }
+    }
+}
+
+/// A package of a changeset that was left out of a release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkippedPackage {
+    /// Package name.
+    pub name: String,
+
+    /// Why the package was skipped.
+    pub reason: ReleaseSkipReason,
+}
+
+impl SkippedPackage {
+    /// Creates a new skipped package entry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{ReleaseSkipReason, SkippedPackage};
+    ///
+    /// let skipped = SkippedPackage::new("@myorg/internal", ReleaseSkipReason::Private);
+    /// assert_eq!(skipped.name, "@myorg/internal");
+    /// ```
+    #[must_use]
+    pub fn new(name: impl Into<String>, reason: ReleaseSkipReason) -> Self {
+        Self { name: name.into(), reason }
+    }
+}
+
+/// Report of what a release consumed, written as an artifact for CI.
+///
+/// Lists, for each changeset of the release, the packages released at which versions with
+/// their changelog anchors, and the packages skipped with the reason.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::{
+///     ArchivedChangeset, Changeset, ReleaseInfo, ReleaseManifest, ReleaseSkipReason,
+///     SkippedPackage, VersionBump,
+/// };
+/// use std::collections::HashMap;
+///
+/// let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+/// changeset.add_package("@myorg/auth");
+/// changeset.add_package("@myorg/internal");
+///
+/// let mut versions = HashMap::new();
+/// versions.insert("@myorg/auth".to_string(), "1.1.0".to_string());
+/// versions.insert("@myorg/internal".to_string(), "0.2.0".to_string());
+/// let release_info = ReleaseInfo::new("ci", "abc123", versions)
+///     .with_skipped(vec![SkippedPackage::new("@myorg/internal", ReleaseSkipReason::Private)]);
+///
+/// let manifest = ReleaseManifest::from_archived(&[ArchivedChangeset::new(changeset, release_info)]);
+///
+/// assert_eq!(manifest.git_commit, "abc123");
+/// assert_eq!(manifest.changesets[0].released.len(), 1);
+/// assert_eq!(manifest.changesets[0].released[0].version.as_deref(), Some("1.1.0"));
+/// assert_eq!(manifest.changesets[0].skipped.len(), 1);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReleaseManifest {
+    /// When the release was applied.
+    pub applied_at: DateTime<Utc>,
+
+    /// Who applied the release.
+    pub applied_by: String,
+
+    /// Git commit hash of the release.
+    pub git_commit: String,
+
+    /// What each changeset of the release consumed.
+    pub changesets: Vec<ChangesetRelease>,
+}
+
+impl ReleaseManifest {
+    /// Builds the manifest from the changesets archived by a release.
+    ///
+    /// Release-wide fields are taken from the first changeset; the current time and empty
+    /// values are used when no changeset is given. Packages recorded as skipped are
+    /// reported under `skipped` only, with their version when one was applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `archived` - The changesets archived by the release, with their release info
+    #[must_use]
+    pub fn from_archived(archived: &[ArchivedChangeset]) -> Self {
+        let (applied_at, applied_by, git_commit) = archived.first().map_or_else(
+            || (Utc::now(), String::new(), String::new()),
+            |first| {
+                (
+                    first.release_info.applied_at,
+                    first.release_info.applied_by.clone(),
+                    first.release_info.git_commit.clone(),
+                )
+            },
+        );
+
+        let changesets = archived
+            .iter()
+            .map(|entry| {
+                let info = &entry.release_info;
+                let released = entry
+                    .changeset
+                    .packages
+                    .iter()
+                    .filter(|name| !info.skipped.iter().any(|skipped| &skipped.name == *name))
+                    .map(|name| ReleasedPackageEntry {
+                        name: name.clone(),
+                        version: info.versions.get(name).cloned(),
+                        changelog: info.changelogs.get(name).cloned(),
+                    })
+                    .collect();
+                let skipped = info
+                    .skipped
+                    .iter()
+                    .map(|skipped| SkippedPackageEntry {
+                        name: skipped.name.clone(),
+                        reason: skipped.reason,
+                        version: info.versions.get(&skipped.name).cloned(),
+                    })
+                    .collect();
+
+                ChangesetRelease {
+                    id: entry.changeset.id.clone(),
+                    branch: entry.changeset.branch.clone(),
+                    bump: entry.changeset.bump,
+                    released,
+                    skipped,
+                }
+            })
+            .collect();
+
+        Self { applied_at, applied_by, git_commit, changesets }
+    }
+
+    /// Returns the number of packages released across all changesets.
+    #[must_use]
+    pub fn released_count(&self) -> usize {
+        self.changesets.iter().map(|changeset| changeset.released.len()).sum()
+    }
+
+    /// Returns the number of packages skipped across all changesets.
+    #[must_use]
+    pub fn skipped_count(&self) -> usize {
+        self.changesets.iter().map(|changeset| changeset.skipped.len()).sum()
+    }
+}
+
+/// What a single changeset consumed in a release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChangesetRelease {
+    /// Id of the archived changeset.
+    pub id: String,
+
+    /// Branch of the archived changeset.
+    pub branch: String,
+
+    /// Bump declared by the changeset.
+    pub bump: VersionBump,
+
+    /// Packages released from the changeset.
+    pub released: Vec<ReleasedPackageEntry>,
+
+    /// Packages of the changeset left out of the release.
+    pub skipped: Vec<SkippedPackageEntry>,
+}
+
+/// A package released from a changeset.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReleasedPackageEntry {
+    /// Package name.
+    pub name: String,
+
+    /// Version released, if the release recorded one.
+    pub version: Option<String>,
+
+    /// Changelog file and heading anchor of the released version.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<String>,
+}
+
+/// A package of a changeset left out of a release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkippedPackageEntry {
+    /// Package name.
+    pub name: String,
+
+    /// Why the package was skipped.
+    pub reason: ReleaseSkipReason,
+
+    /// Version applied to the package anyway (private packages are still versioned).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
 /// Summary of updates made when adding commits from Git.
 ///
 /// This structure provides detailed information about what changed when commits
@@ -17,6 +17,7 @@
 //!
 //! - [`VersionProtocol`]: Enum representing different version specification protocols
 //! - [`LocalLinkType`]: Specific types of local file protocols
+//! - [`NpmAlias`]: Target package and range of an `npm:` alias
 //!
 //! ## Dependency Tracking
 //!
@@ -37,6 +38,11 @@
 //! assert!(is_local_protocol("link:./shared"));
 //! ```
 //!
+//! # No-op Detection
+//!
+//! [`is_same_spec`] and [`spec_satisfied_by`] tell whether rewriting a spec would change what
+//! it resolves to, so propagation and upgrades can leave manifests untouched otherwise.
+//!
 //! # Examples
 //!
 //! ## Working with Dependency Updates
@@ -66,7 +72,7 @@
 //! };
 //! ```
 
-use crate::types::DependencyType;
+use crate::types::{DependencyType, Version};
 use serde::{Deserialize, Serialize};
 
 /// Version specification protocol type.
@@ -94,6 +100,8 @@ pub enum VersionProtocol {
     Workspace,
     /// Local file/link protocol (file:, link:, portal:)
     Local(LocalLinkType),
+    /// npm alias protocol (npm:@scope/pkg@^1.0.0), see [`NpmAlias`]
+    NpmAlias,
     /// Standard semantic version or range
     Semver,
 }
@@ -117,6 +125,7 @@ impl VersionProtocol {
         match self {
             Self::Workspace => "workspace:",
             Self::Local(link_type) => link_type.as_str(),
+            Self::NpmAlias => "npm:",
             Self::Semver => "",
         }
     }
@@ -157,6 +166,8 @@ impl VersionProtocol {
     /// ```
     #[must_use]
     pub fn parse(version_spec: &str) -> Self {
+        // Leading whitespace is ignored, as in `NpmAlias::parse`
+        let version_spec = version_spec.trim_start();
         if version_spec.starts_with("workspace:") {
             Self::Workspace
         } else if version_spec.starts_with("file:") {
@@ -165,6 +176,8 @@ impl VersionProtocol {
             Self::Local(LocalLinkType::Link)
         } else if version_spec.starts_with("portal:") {
             Self::Local(LocalLinkType::Portal)
+        } else if version_spec.starts_with("npm:") {
+            Self::NpmAlias
         } else {
             Self::Semver
         }
@@ -173,7 +186,8 @@ impl VersionProtocol {
     /// Returns `true` if this protocol should be skipped during version resolution.
     ///
     /// Workspace and local protocols are typically skipped because they refer
-    /// to packages within the same workspace or local filesystem.
+    /// to packages within the same workspace or local filesystem. npm aliases carry
+    /// a semver range for their target package and are not skipped.
     ///
     /// # Examples
     ///
@@ -182,10 +196,11 @@ impl VersionProtocol {
     ///
     /// assert!(VersionProtocol::Workspace.should_skip());
     /// assert!(!VersionProtocol::Semver.should_skip());
+    /// assert!(!VersionProtocol::NpmAlias.should_skip());
     /// ```
     #[must_use]
     pub fn should_skip(&self) -> bool {
-        !matches!(self, Self::Semver)
+        !matches!(self, Self::Semver | Self::NpmAlias)
     }
 }
 
@@ -194,11 +209,78 @@ impl std::fmt::Display for VersionProtocol {
         match self {
             Self::Workspace => write!(f, "workspace"),
             Self::Local(link_type) => write!(f, "{}", link_type),
+            Self::NpmAlias => write!(f, "npm"),
             Self::Semver => write!(f, "semver"),
         }
     }
 }
 
+/// Target of an `npm:` alias dependency.
+///
+/// A dependency declared as `"foo": "npm:@scope/foo@^2.0.0"` installs `@scope/foo` under
+/// the name `foo`. The dependency key is the alias; version resolution, propagation, and
+/// upgrade detection must use the target name and range instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::dependency::NpmAlias;
+///
+/// let alias = NpmAlias::parse("npm:@scope/foo@^2.0.0").expect("valid alias");
+/// assert_eq!(alias.name, "@scope/foo");
+/// assert_eq!(alias.range.as_deref(), Some("^2.0.0"));
+/// assert_eq!(alias.with_range("^3.0.0"), "npm:@scope/foo@^3.0.0");
+///
+/// assert!(NpmAlias::parse("^2.0.0").is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NpmAlias {
+    /// Name of the aliased package (e.g., `@scope/foo`).
+    pub name: String,
+    /// Version range of the aliased package, `None` when the alias has no range.
+    pub range: Option<String>,
+}
+
+impl NpmAlias {
+    /// Parses an `npm:` alias specification.
+    ///
+    /// Returns `None` if the spec does not use the `npm:` protocol or names no package.
+    #[must_use]
+    pub fn parse(version_spec: &str) -> Option<Self> {
+        let target = version_spec.trim().strip_prefix("npm:")?;
+
+        // The version separator is the last `@` that is not the leading scope marker
+        let (name, range) = match target.rfind('@') {
+            Some(index) if index > 0 => (&target[..index], Some(&target[index + 1..])),
+            _ => (target, None),
+        };
+
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            range: range.filter(|r| !r.is_empty()).map(str::to_string),
+        })
+    }
+
+    /// Builds the alias specification for the same target with a new range.
+    #[must_use]
+    pub fn with_range(&self, range: &str) -> String {
+        format!("npm:{}@{}", self.name, range)
+    }
+}
+
+impl std::fmt::Display for NpmAlias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.range {
+            Some(range) => write!(f, "npm:{}@{}", self.name, range),
+            None => write!(f, "npm:{}", self.name),
+        }
+    }
+}
+
 /// Type of local link protocol.
 ///
 /// Represents different ways to reference local packages or files
@@ -499,6 +581,136 @@ impl std::fmt::Display for CircularDependency {
     }
 }
 
+/// A dependency declared by a workspace package.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::DependencyType;
+/// use sublime_pkg_tools::types::dependency::DependencyRequirement;
+///
+/// let requirement = DependencyRequirement {
+///     dependent: "app".to_string(),
+///     dependency: "lodash".to_string(),
+///     version_spec: "^4.17.21".to_string(),
+///     dependency_type: DependencyType::Regular,
+/// };
+///
+/// assert_eq!(requirement.to_string(), "app requires lodash@^4.17.21 (dependencies)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyRequirement {
+    /// Workspace package declaring the dependency
+    pub dependent: String,
+    /// Package being depended on; npm aliases are resolved to their target
+    pub dependency: String,
+    /// Version spec as written in package.json
+    pub version_spec: String,
+    /// Section of package.json declaring the dependency
+    pub dependency_type: DependencyType,
+}
+
+impl std::fmt::Display for DependencyRequirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} requires {}@{} ({})",
+            self.dependent,
+            self.dependency,
+            self.version_spec,
+            self.dependency_type.as_str()
+        )
+    }
+}
+
+/// Explains why a package is part of the workspace dependency tree.
+///
+/// Returned by `DependencyGraph::why`. Lists the workspace packages that declare the
+/// package directly and every chain of workspace packages leading to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::dependency::DependencyExplanation;
+///
+/// let explanation = DependencyExplanation {
+///     package: "lodash".to_string(),
+///     internal: false,
+///     required_by: vec![],
+///     chains: vec![vec!["app".to_string(), "ui".to_string(), "lodash".to_string()]],
+/// };
+///
+/// assert_eq!(explanation.display_chains(), vec!["app → ui → lodash"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyExplanation {
+    /// Package being explained
+    pub package: String,
+    /// Whether the package is a workspace package
+    pub internal: bool,
+    /// Direct declarations of the package by workspace packages
+    pub required_by: Vec<DependencyRequirement>,
+    /// Dependency chains from a top-level workspace package down to the package
+    pub chains: Vec<Vec<String>>,
+}
+
+impl DependencyExplanation {
+    /// Returns each chain formatted as `a → b → c`.
+    #[must_use]
+    pub fn display_chains(&self) -> Vec<String> {
+        self.chains.iter().map(|chain| chain.join(" → ")).collect()
+    }
+}
+
+/// Workspace packages impacted by a dependency.
+///
+/// Returned by `DependencyGraph::impact`. Answers questions like "which workspace packages
+/// are affected if lodash has a vulnerability": the packages declaring the dependency, and
+/// the workspace packages depending on those.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::dependency::DependencyImpact;
+///
+/// let impact = DependencyImpact {
+///     dependency: "lodash".to_string(),
+///     external: true,
+///     direct: vec!["ui".to_string()],
+///     transitive: vec!["app".to_string()],
+/// };
+///
+/// assert_eq!(impact.packages(), vec!["ui", "app"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyImpact {
+    /// Dependency whose impact is described
+    pub dependency: String,
+    /// Whether the dependency is an external (registry) package
+    pub external: bool,
+    /// Workspace packages declaring the dependency, sorted by name
+    pub direct: Vec<String>,
+    /// Workspace packages depending on it only through other workspace packages, sorted by name
+    pub transitive: Vec<String>,
+}
+
+impl DependencyImpact {
+    /// Returns every impacted workspace package, direct ones first.
+    #[must_use]
+    pub fn packages(&self) -> Vec<&str> {
+        self.direct.iter().chain(&self.transitive).map(String::as_str).collect()
+    }
+
+    /// Returns `true` if no workspace package is impacted.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.direct.is_empty() && self.transitive.is_empty()
+    }
+}
+
 /// Reason why a package is being updated.
 ///
 /// Used during version resolution to track whether a package is being
@@ -646,7 +858,7 @@ impl std::fmt::Display for UpdateReason {
 ///
 /// ```rust,ignore
 /// use sublime_pkg_tools::types::dependency::{PackageUpdate, UpdateReason};
-/// use sublime_pkg_tools::types::Version;
+/// use sublime_pkg_tools::types::{Version, VersioningStrategy};
 /// use std::path::PathBuf;
 ///
 /// let update = PackageUpdate {
@@ -656,6 +868,8 @@ impl std::fmt::Display for UpdateReason {
 ///     next_version: Version::parse("1.1.0")?,
 ///     reason: UpdateReason::DirectChange,
 ///     dependency_updates: vec![],
+///     strategy: VersioningStrategy::Independent,
+///     skipped_dependency_updates: vec![],
 /// };
 /// ```
 ///
@@ -778,3 +992,139 @@ pub fn extract_protocol_path(version_spec: &str) -> &str {
 pub fn parse_protocol(version_spec: &str) -> VersionProtocol {
     VersionProtocol::parse(version_spec)
 }
+
+/// Checks if two version specifications are the same once whitespace is ignored.
+///
+/// Rewriting a spec to a spelling that differs only in whitespace does not change what it
+/// resolves to, so the rewrite is a no-op.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::dependency::is_same_spec;
+///
+/// assert!(is_same_spec("^1.2.0", "^1.2.0"));
+/// assert!(is_same_spec(">= 1.2.0 < 2.0.0", ">=1.2.0 <2.0.0"));
+/// assert!(!is_same_spec("^1.2.0", "^1.3.0"));
+/// assert!(!is_same_spec("1.2.0", "^1.2.0"));
+/// ```
+#[must_use]
+pub fn is_same_spec(old_spec: &str, new_spec: &str) -> bool {
+    old_spec
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .eq(new_spec.chars().filter(|c| !c.is_whitespace()))
+}
+
+/// Checks if a version satisfies a version specification.
+///
+/// Supports npm ranges made of comparators (`^`, `~`, `>=`, `<`, `=`, or a bare version,
+/// which matches exactly), space-separated comparator sets, `||` unions, and the range of
+/// an `npm:` alias. Protocol specs (`workspace:`, `file:`, ...), dist-tags, and ranges that
+/// cannot be parsed are never satisfied, so callers fall back to rewriting them.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::Version;
+/// use sublime_pkg_tools::types::dependency::spec_satisfied_by;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let version = Version::parse("1.4.0")?;
+///
+/// assert!(spec_satisfied_by("^1.2.0", &version));
+/// assert!(spec_satisfied_by(">= 1.0.0 < 2.0.0", &version));
+/// assert!(spec_satisfied_by("~0.9.0 || ^1.0.0", &version));
+/// assert!(spec_satisfied_by("npm:@scope/foo@^1.0.0", &version));
+/// assert!(!spec_satisfied_by("~1.2.0", &version));
+/// assert!(!spec_satisfied_by("1.2.0", &version));
+/// assert!(!spec_satisfied_by("workspace:*", &version));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn spec_satisfied_by(version_spec: &str, version: &Version) -> bool {
+    let range = match NpmAlias::parse(version_spec) {
+        Some(alias) => match alias.range {
+            Some(range) => range,
+            None => return false,
+        },
+        None => version_spec.to_string(),
+    };
+    if should_skip_protocol(&range) {
+        return false;
+    }
+
+    range.split("||").any(|set| comparator_set_matches(set, version.as_semver()))
+}
+
+/// Returns the highest version that satisfies a version specification.
+///
+/// Matching follows [`spec_satisfied_by`], so protocol specs, dist-tags, and ranges that
+/// cannot be parsed match nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::Version;
+/// use sublime_pkg_tools::types::dependency::max_satisfying;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let versions = ["1.2.0", "1.4.2", "2.0.0"]
+///     .iter()
+///     .map(|v| Version::parse(v))
+///     .collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(max_satisfying(&versions, "^1.0.0").map(ToString::to_string), Some("1.4.2".into()));
+/// assert_eq!(max_satisfying(&versions, "^3.0.0"), None);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn max_satisfying<'a>(versions: &'a [Version], version_spec: &str) -> Option<&'a Version> {
+    versions.iter().filter(|version| spec_satisfied_by(version_spec, version)).max()
+}
+
+/// Checks if a version satisfies every comparator of a space-separated npm comparator set.
+fn comparator_set_matches(set: &str, version: &semver::Version) -> bool {
+    // Join operators written apart from their versions: `>= 1.0.0` is `>=1.0.0`
+    let mut comparators = Vec::new();
+    let mut operator = String::new();
+    for token in set.split_whitespace() {
+        if token.chars().all(|c| matches!(c, '<' | '>' | '=' | '^' | '~')) {
+            operator.push_str(token);
+            continue;
+        }
+        let comparator = format!("{operator}{token}");
+        operator.clear();
+
+        // A bare npm version matches exactly, where `semver` would read it as a caret range
+        if comparator.starts_with(|c: char| c.is_ascii_digit()) {
+            comparators.push(format!("={comparator}"));
+        } else {
+            comparators.push(comparator);
+        }
+    }
+    if comparators.is_empty() || !operator.is_empty() {
+        return false;
+    }
+
+    semver::VersionReq::parse(&comparators.join(", ")).is_ok_and(|req| req.matches(version))
+}
+
+/// Returns the name of the package a dependency actually resolves to.
+///
+/// For `npm:` aliases this is the aliased target; otherwise it is the dependency name.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::dependency::dependency_target_name;
+///
+/// assert_eq!(dependency_target_name("foo", "npm:@scope/foo@^2.0.0"), "@scope/foo");
+/// assert_eq!(dependency_target_name("foo", "^2.0.0"), "foo");
+/// ```
+#[must_use]
+pub fn dependency_target_name(dependency_name: &str, version_spec: &str) -> String {
+    NpmAlias::parse(version_spec).map_or_else(|| dependency_name.to_string(), |alias| alias.name)
+}
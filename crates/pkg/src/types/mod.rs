@@ -175,7 +175,7 @@ pub type BranchName = String;
 
 // Version types (Story 4.1)
 mod version;
-pub use version::{Version, VersionBump, VersioningStrategy};
+pub use version::{CalverFormat, Version, VersionBump, VersionScheme, VersioningStrategy};
 
 // Package types (Story 4.2)
 mod package;
@@ -183,16 +183,27 @@ pub use package::{DependencyType, PackageInfo};
 
 // Changeset types (Story 4.3)
 mod changeset;
-pub use changeset::{ArchivedChangeset, Changeset, ReleaseInfo, UpdateSummary};
+pub(crate) use changeset::generate_id;
+pub use changeset::{
+    ArchivedChangeset, Changeset, ChangesetAuthor, ChangesetOperation, ChangesetOperationKind,
+    ChangesetRelease, ChangesetRevision, CommitAssociation, EnvironmentPromotion, ReleaseInfo,
+    ReleaseManifest, ReleaseSkipReason, ReleasedPackageEntry, SkippedPackage, SkippedPackageEntry,
+    UpdateSummary,
+};
 
 // Dependency types (Story 4.4)
 pub mod dependency;
 pub use dependency::{
-    CircularDependency, DependencyUpdate, LocalLinkType, UpdateReason, VersionProtocol,
-    extract_protocol_path, is_local_protocol, is_workspace_protocol, parse_protocol,
-    should_skip_protocol,
+    CircularDependency, DependencyExplanation, DependencyImpact, DependencyRequirement,
+    DependencyUpdate, LocalLinkType, NpmAlias, UpdateReason, VersionProtocol,
+    dependency_target_name, extract_protocol_path, is_local_protocol, is_same_spec,
+    is_workspace_protocol, max_satisfying, parse_protocol, should_skip_protocol, spec_satisfied_by,
 };
 
+// Warning types
+mod warning;
+pub use warning::{Warning, WarningKind};
+
 // Re-export PackageUpdate from version module to avoid duplication
 // The canonical definition is in version::resolution
 pub use crate::version::PackageUpdate;
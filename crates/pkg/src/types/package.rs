@@ -599,7 +599,7 @@ impl PackageInfo {
 /// let dev_type = DependencyType::Dev;
 /// assert_eq!(dev_type.as_str(), "devDependencies");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum DependencyType {
     /// Production dependency (from `dependencies` field)
     Regular,
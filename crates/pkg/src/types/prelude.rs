@@ -34,6 +34,7 @@
 //! - [`Version`] - Semantic version representation
 //! - [`VersionBump`] - Version bump types (Major, Minor, Patch, None)
 //! - [`VersioningStrategy`] - Independent or Unified versioning
+//! - [`VersionScheme`] - Semantic or calendar versioning of a package
 //!
 //! ## Changeset Types
 //! - [`Changeset`] - The central changeset data structure
@@ -50,8 +51,13 @@
 //! - [`CircularDependency`] - Circular dependency detection result
 //! - [`UpdateReason`] - Why a package is being updated
 //! - [`VersionProtocol`] - Version specification protocols
+//! - [`NpmAlias`] - Target of an `npm:` alias dependency
 //! - [`LocalLinkType`] - Types of local links (File, Link, Portal)
 //!
+//! ## Warning Types
+//! - [`Warning`] - Non-fatal issue reported in an operation result
+//! - [`WarningKind`] - Kind of a non-fatal issue
+//!
 //! ## Traits
 //! - [`Named`] - Trait for types with names
 //! - [`Versionable`] - Trait for types with versions
@@ -93,7 +99,7 @@
 //! modules to keep the prelude focused and avoid namespace pollution.
 
 // Re-export core version types
-pub use crate::types::{Version, VersionBump, VersioningStrategy};
+pub use crate::types::{Version, VersionBump, VersionScheme, VersioningStrategy};
 
 // Re-export changeset types
 pub use crate::types::{ArchivedChangeset, Changeset, ReleaseInfo, UpdateSummary};
@@ -103,9 +109,12 @@ pub use crate::types::{DependencyType, PackageInfo};
 
 // Re-export dependency types
 pub use crate::types::{
-    CircularDependency, DependencyUpdate, LocalLinkType, UpdateReason, VersionProtocol,
+    CircularDependency, DependencyUpdate, LocalLinkType, NpmAlias, UpdateReason, VersionProtocol,
 };
 
+// Re-export warning types
+pub use crate::types::{Warning, WarningKind};
+
 // Re-export PackageUpdate from version module
 pub use crate::types::PackageUpdate;
 
@@ -117,6 +126,6 @@ pub use crate::types::{BranchName, CommitHash, PackageName, VersionSpec};
 
 // Re-export helper functions for protocol handling
 pub use crate::types::{
-    extract_protocol_path, is_local_protocol, is_workspace_protocol, parse_protocol,
-    should_skip_protocol,
+    dependency_target_name, extract_protocol_path, is_local_protocol, is_workspace_protocol,
+    parse_protocol, should_skip_protocol,
 };
@@ -12,7 +12,11 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::panic)]
 
-use super::{ArchivedChangeset, Changeset, ReleaseInfo, Version, VersionBump, VersioningStrategy};
+use super::{
+    ArchivedChangeset, CalverFormat, Changeset, ChangesetOperationKind, ReleaseInfo,
+    ReleaseManifest, ReleaseSkipReason, ReleasedPackageEntry, SkippedPackage, SkippedPackageEntry,
+    Version, VersionBump, VersioningStrategy,
+};
 use crate::error::{ChangesetError, VersionError};
 use package_json::PackageJson;
 use proptest::prelude::*;
@@ -150,6 +154,20 @@ fn test_version_bump_none() {
     assert_eq!(bumped.to_string(), "1.2.3");
 }
 
+#[test]
+fn test_version_diff() {
+    let version = Version::parse("1.2.3").unwrap();
+    let diff = |other: &str| version.diff(&Version::parse(other).unwrap());
+
+    assert_eq!(diff("2.0.0"), VersionBump::Major);
+    assert_eq!(diff("0.9.9"), VersionBump::Major);
+    assert_eq!(diff("1.3.0"), VersionBump::Minor);
+    assert_eq!(diff("1.2.4"), VersionBump::Patch);
+    assert_eq!(diff("1.2.3-rc.1"), VersionBump::Patch);
+    assert_eq!(diff("1.2.3+build.1"), VersionBump::None);
+    assert_eq!(diff("1.2.3"), VersionBump::None);
+}
+
 #[test]
 fn test_version_bump_removes_prerelease() {
     let version = Version::parse("1.0.0-beta.1").unwrap();
@@ -199,6 +217,48 @@ fn test_version_bump_overflow_protection() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_version_bump_calver_year_month_micro() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+    let format = CalverFormat::YearMonthMicro;
+    let bump = |version: &str, bump_type| {
+        Version::parse(version).unwrap().bump_calver(bump_type, date, format).unwrap().to_string()
+    };
+
+    // A new month resets the micro version whatever the bump type
+    assert_eq!(bump("2024.5.3", VersionBump::Patch), "2024.6.0");
+    assert_eq!(bump("2023.12.9", VersionBump::Major), "2024.6.0");
+    // A semver package adopting calver moves to the current month
+    assert_eq!(bump("1.4.2", VersionBump::Minor), "2024.6.0");
+    // Within the same month the micro version is incremented
+    assert_eq!(bump("2024.6.0", VersionBump::Major), "2024.6.1");
+    assert_eq!(bump("2024.6.1-beta.1", VersionBump::Patch), "2024.6.2");
+    // A version ahead of the release date never goes backwards
+    assert_eq!(bump("2024.7.2", VersionBump::Patch), "2024.7.3");
+    assert_eq!(bump("2024.5.3", VersionBump::None), "2024.5.3");
+}
+
+#[test]
+fn test_version_bump_calver_year_month_day() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+    let format = CalverFormat::YearMonthDay;
+    let bump = |version: &str| {
+        Version::parse(version)
+            .unwrap()
+            .bump_calver(VersionBump::Patch, date, format)
+            .unwrap()
+            .to_string()
+    };
+
+    assert_eq!(bump("2024.6.3"), "2024.6.12");
+    assert_eq!(bump("2024.5.30"), "2024.6.12");
+    // A second release on the same day
+    assert_eq!(bump("2024.6.12"), "2024.6.13");
+
+    let max_micro = Version::new(2024, 6, u64::MAX);
+    assert!(max_micro.bump_calver(VersionBump::Patch, date, format).is_err());
+}
+
 #[test]
 fn test_version_comparison() {
     let v1 = Version::parse("1.0.0").unwrap();
@@ -1459,6 +1519,92 @@ mod changeset_tests {
         let changeset2 = changeset1.clone();
         assert_eq!(changeset1, changeset2);
     }
+
+    #[test]
+    fn test_changeset_split_off() {
+        let mut changeset =
+            Changeset::new("feature/auth", VersionBump::Minor, vec!["production".to_string()]);
+        changeset.add_package("@myorg/auth");
+        changeset.add_package("@myorg/docs");
+        changeset.add_commit("abc123");
+
+        let docs = changeset.split_off(&["@myorg/docs".to_string()], "feature/docs").unwrap();
+
+        assert_eq!(changeset.packages, vec!["@myorg/auth"]);
+        assert_eq!(docs.branch, "feature/docs");
+        assert_eq!(docs.packages, vec!["@myorg/docs"]);
+        assert_eq!(docs.bump, VersionBump::Minor);
+        assert_eq!(docs.environments, vec!["production"]);
+        assert_eq!(docs.changes, vec!["abc123"]);
+        assert_eq!(changeset.operations, docs.operations);
+        assert_eq!(docs.operations[0].kind, ChangesetOperationKind::Split);
+        assert_eq!(docs.operations[0].into, vec!["feature/auth", "feature/docs"]);
+    }
+
+    #[test]
+    fn test_changeset_split_off_rejects_invalid_requests() {
+        let mut changeset = Changeset::new("feature/auth", VersionBump::Minor, vec![]);
+        changeset.add_package("@myorg/auth");
+        changeset.add_package("@myorg/docs");
+
+        let missing = changeset.split_off(&["@myorg/core".to_string()], "feature/core");
+        assert!(matches!(missing, Err(ChangesetError::PackageNotInChangeset { .. })));
+
+        let all = vec!["@myorg/auth".to_string(), "@myorg/docs".to_string()];
+        let everything = changeset.split_off(&all, "feature/all");
+        assert!(matches!(everything, Err(ChangesetError::EmptyChangeset { .. })));
+
+        let same = changeset.split_off(&["@myorg/docs".to_string()], "feature/auth");
+        assert!(matches!(same, Err(ChangesetError::InvalidBranch { .. })));
+
+        assert_eq!(changeset.packages.len(), 2);
+        assert!(changeset.operations.is_empty());
+    }
+
+    #[test]
+    fn test_changeset_merge() {
+        let mut fix =
+            Changeset::new("fix/login", VersionBump::Patch, vec!["production".to_string()]);
+        fix.add_package("@myorg/auth");
+        fix.add_commit("abc123");
+        let mut feature =
+            Changeset::new("feature/sso", VersionBump::Major, vec!["staging".to_string()]);
+        feature.add_package("@myorg/auth");
+        feature.add_package("@myorg/core");
+        feature.add_commit("abc123");
+        feature.add_commit("def456");
+        feature.created_at = fix.created_at - chrono::Duration::hours(1);
+
+        let merged = Changeset::merge("release/auth", &[fix, feature.clone()]).unwrap();
+
+        assert_eq!(merged.branch, "release/auth");
+        assert_eq!(merged.bump, VersionBump::Major);
+        assert_eq!(merged.packages, vec!["@myorg/auth", "@myorg/core"]);
+        assert_eq!(merged.environments, vec!["production", "staging"]);
+        assert_eq!(merged.changes, vec!["abc123", "def456"]);
+        assert_eq!(merged.created_at, feature.created_at);
+
+        let operation = merged.operations.last().unwrap();
+        assert_eq!(operation.kind, ChangesetOperationKind::Merge);
+        assert_eq!(operation.from, vec!["fix/login", "feature/sso"]);
+        assert_eq!(operation.into, vec!["release/auth"]);
+    }
+
+    #[test]
+    fn test_changeset_merge_requires_two_sources() {
+        let changeset = Changeset::new("fix/login", VersionBump::Patch, vec![]);
+
+        let result = Changeset::merge("release/auth", &[changeset]);
+        assert!(matches!(result, Err(ChangesetError::ValidationFailed { .. })));
+    }
+
+    #[test]
+    fn test_changeset_without_operations_omits_field() {
+        let changeset = Changeset::new("feat/test", VersionBump::Minor, vec![]);
+
+        let json = serde_json::to_string(&changeset).unwrap();
+        assert!(!json.contains("operations"));
+    }
 }
 
 // =============================================================================
@@ -1693,6 +1839,141 @@ mod release_info_tests {
             assert_eq!(release_info.applied_by, applied_by);
         }
     }
+
+    #[test]
+    fn test_release_info_without_consumption_details_deserializes() {
+        let json = r#"{
+            "applied_at": "2024-01-15T10:00:00Z",
+            "applied_by": "ci",
+            "git_commit": "abc123",
+            "versions": {"@myorg/core": "1.0.0"}
+        }"#;
+
+        let release_info: ReleaseInfo = serde_json::from_str(json).unwrap();
+
+        assert!(release_info.skipped.is_empty());
+        assert!(release_info.changelogs.is_empty());
+        let serialized = serde_json::to_string(&release_info).unwrap();
+        assert!(!serialized.contains("skipped"));
+        assert!(!serialized.contains("changelogs"));
+    }
+
+    #[test]
+    fn test_release_info_skipped_serialization() {
+        let release_info = ReleaseInfo::new("ci", "abc", HashMap::new()).with_skipped(vec![
+            SkippedPackage::new("@myorg/docs", ReleaseSkipReason::Filtered),
+            SkippedPackage::new("@myorg/internal", ReleaseSkipReason::Private),
+        ]);
+
+        let json = serde_json::to_value(&release_info).unwrap();
+
+        assert_eq!(
+            json["skipped"],
+            serde_json::json!([
+                {"name": "@myorg/docs", "reason": "filtered"},
+                {"name": "@myorg/internal", "reason": "private"}
+            ])
+        );
+    }
+}
+
+// =============================================================================
+// ReleaseManifest Tests
+// =============================================================================
+
+mod release_manifest_tests {
+    use super::*;
+
+    fn archived(branch: &str, packages: &[&str], release_info: ReleaseInfo) -> ArchivedChangeset {
+        let mut changeset = Changeset::new(branch, VersionBump::Minor, vec![]);
+        for package in packages {
+            changeset.add_package(*package);
+        }
+        ArchivedChangeset::new(changeset, release_info)
+    }
+
+    #[test]
+    fn test_release_manifest_from_archived() {
+        let mut versions = HashMap::new();
+        versions.insert("@myorg/core".to_string(), "1.1.0".to_string());
+        versions.insert("@myorg/internal".to_string(), "0.3.0".to_string());
+        let mut changelogs = HashMap::new();
+        changelogs.insert(
+            "@myorg/core".to_string(),
+            "packages/core/CHANGELOG.md#110---2024-01-15".to_string(),
+        );
+        let release_info = ReleaseInfo::new("ci", "abc123", versions)
+            .with_skipped(vec![
+                SkippedPackage::new("@myorg/docs", ReleaseSkipReason::Filtered),
+                SkippedPackage::new("@myorg/internal", ReleaseSkipReason::Private),
+            ])
+            .with_changelogs(changelogs);
+
+        let manifest = ReleaseManifest::from_archived(&[archived(
+            "feature/core",
+            &["@myorg/core", "@myorg/internal"],
+            release_info,
+        )]);
+
+        assert_eq!(manifest.applied_by, "ci");
+        assert_eq!(manifest.git_commit, "abc123");
+        assert_eq!(manifest.released_count(), 1);
+        assert_eq!(manifest.skipped_count(), 2);
+
+        let changeset = &manifest.changesets[0];
+        assert_eq!(changeset.branch, "feature/core");
+        assert_eq!(changeset.bump, VersionBump::Minor);
+        assert_eq!(
+            changeset.released,
+            vec![ReleasedPackageEntry {
+                name: "@myorg/core".to_string(),
+                version: Some("1.1.0".to_string()),
+                changelog: Some("packages/core/CHANGELOG.md#110---2024-01-15".to_string()),
+            }]
+        );
+        assert_eq!(
+            changeset.skipped,
+            vec![
+                SkippedPackageEntry {
+                    name: "@myorg/docs".to_string(),
+                    reason: ReleaseSkipReason::Filtered,
+                    version: None,
+                },
+                SkippedPackageEntry {
+                    name: "@myorg/internal".to_string(),
+                    reason: ReleaseSkipReason::Private,
+                    version: Some("0.3.0".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_release_manifest_multiple_changesets() {
+        let mut versions = HashMap::new();
+        versions.insert("@myorg/core".to_string(), "2.0.0".to_string());
+        versions.insert("@myorg/utils".to_string(), "1.0.1".to_string());
+        let release_info = ReleaseInfo::new("ci", "abc123", versions);
+
+        let manifest = ReleaseManifest::from_archived(&[
+            archived("feature/core", &["@myorg/core"], release_info.clone()),
+            archived("fix/utils", &["@myorg/utils"], release_info),
+        ]);
+
+        assert_eq!(manifest.changesets.len(), 2);
+        assert_eq!(manifest.released_count(), 2);
+        assert_eq!(manifest.skipped_count(), 0);
+        assert_eq!(manifest.changesets[1].released[0].version.as_deref(), Some("1.0.1"));
+    }
+
+    #[test]
+    fn test_release_manifest_empty() {
+        let manifest = ReleaseManifest::from_archived(&[]);
+
+        assert!(manifest.changesets.is_empty());
+        assert!(manifest.git_commit.is_empty());
+        assert_eq!(manifest.released_count(), 0);
+    }
 }
 
 // =============================================================================
@@ -1703,9 +1984,10 @@ mod dependency_tests {
     use super::*;
     use crate::types::DependencyType;
     use crate::types::dependency::{
-        CircularDependency, DependencyUpdate, LocalLinkType, UpdateReason, VersionProtocol,
-        extract_protocol_path, is_local_protocol, is_workspace_protocol, parse_protocol,
-        should_skip_protocol,
+        CircularDependency, DependencyUpdate, LocalLinkType, NpmAlias, UpdateReason,
+        VersionProtocol, dependency_target_name, extract_protocol_path, is_local_protocol,
+        is_same_spec, is_workspace_protocol, max_satisfying, parse_protocol, should_skip_protocol,
+        spec_satisfied_by,
     };
     use crate::version::PackageUpdate;
 
@@ -1757,6 +2039,60 @@ mod dependency_tests {
         assert!(VersionProtocol::Local(LocalLinkType::Link).should_skip());
         assert!(VersionProtocol::Local(LocalLinkType::Portal).should_skip());
         assert!(!VersionProtocol::Semver.should_skip());
+        assert!(!VersionProtocol::NpmAlias.should_skip());
+    }
+
+    // =========================================================================
+    // NpmAlias Tests
+    // =========================================================================
+
+    #[test]
+    fn test_version_protocol_parse_npm_alias() {
+        assert_eq!(VersionProtocol::parse("npm:foo@^1.0.0"), VersionProtocol::NpmAlias);
+        assert_eq!(VersionProtocol::NpmAlias.as_str(), "npm:");
+        assert_eq!(VersionProtocol::NpmAlias.to_string(), "npm");
+    }
+
+    #[test]
+    fn test_npm_alias_parse_scoped_and_unscoped() {
+        let scoped = NpmAlias::parse("npm:@scope/foo@^2").unwrap();
+        assert_eq!(scoped.name, "@scope/foo");
+        assert_eq!(scoped.range.as_deref(), Some("^2"));
+
+        let unscoped = NpmAlias::parse("npm:foo@~1.2.3").unwrap();
+        assert_eq!(unscoped.name, "foo");
+        assert_eq!(unscoped.range.as_deref(), Some("~1.2.3"));
+    }
+
+    #[test]
+    fn test_npm_alias_parse_without_range() {
+        let scoped = NpmAlias::parse("npm:@scope/foo").unwrap();
+        assert_eq!(scoped.name, "@scope/foo");
+        assert_eq!(scoped.range, None);
+        assert_eq!(scoped.to_string(), "npm:@scope/foo");
+
+        assert_eq!(NpmAlias::parse("npm:foo@").map(|a| a.range), Some(None));
+    }
+
+    #[test]
+    fn test_npm_alias_parse_rejects_other_specs() {
+        assert!(NpmAlias::parse("^1.0.0").is_none());
+        assert!(NpmAlias::parse("workspace:*").is_none());
+        assert!(NpmAlias::parse("npm:").is_none());
+    }
+
+    #[test]
+    fn test_npm_alias_with_range_roundtrip() {
+        let alias = NpmAlias::parse("npm:@scope/foo@^2.0.0").unwrap();
+        assert_eq!(alias.to_string(), "npm:@scope/foo@^2.0.0");
+        assert_eq!(alias.with_range(">=3.0.0"), "npm:@scope/foo@>=3.0.0");
+    }
+
+    #[test]
+    fn test_dependency_target_name() {
+        assert_eq!(dependency_target_name("foo", "npm:@scope/foo@^2"), "@scope/foo");
+        assert_eq!(dependency_target_name("foo", "npm:bar"), "bar");
+        assert_eq!(dependency_target_name("foo", "^2.0.0"), "foo");
     }
 
     #[test]
@@ -1836,6 +2172,50 @@ mod dependency_tests {
         assert_eq!(update.new_version_spec, "^2.0.0");
     }
 
+    #[test]
+    fn test_is_same_spec() {
+        assert!(is_same_spec("^1.0.0", "^1.0.0"));
+        assert!(is_same_spec(" ~1.0.0", "~1.0.0 "));
+        assert!(is_same_spec(">= 1.0.0 < 2.0.0", ">=1.0.0 <2.0.0"));
+        assert!(!is_same_spec("^1.0.0", "~1.0.0"));
+        assert!(!is_same_spec("1.0.0", "=1.0.1"));
+    }
+
+    #[test]
+    fn test_spec_satisfied_by() {
+        let version = Version::parse("1.2.3").unwrap();
+
+        for spec in ["^1.0.0", "~1.2.0", ">=1.0.0", ">= 1.0.0 <2.0.0", "1.2.3", "=1.2.3", "*"] {
+            assert!(spec_satisfied_by(spec, &version), "{spec} should be satisfied");
+        }
+        assert!(spec_satisfied_by("^0.9.0 || ^1.0.0", &version));
+        assert!(spec_satisfied_by("npm:@scope/foo@^1.1.0", &version));
+
+        for spec in ["^2.0.0", "~1.1.0", "1.2.2", "<1.2.3", "1.0.0 - 2.0.0", "latest", ">="] {
+            assert!(!spec_satisfied_by(spec, &version), "{spec} should not be satisfied");
+        }
+        assert!(!spec_satisfied_by("workspace:^1.0.0", &version));
+        assert!(!spec_satisfied_by("file:../core", &version));
+        assert!(!spec_satisfied_by("npm:@scope/foo", &version));
+    }
+
+    #[test]
+    fn test_max_satisfying() {
+        let versions: Vec<Version> = ["1.2.0", "1.10.1", "1.4.2", "2.0.0-beta.1", "2.0.0"]
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect();
+
+        let max = |spec| max_satisfying(&versions, spec).map(ToString::to_string);
+        assert_eq!(max("^1.0.0").as_deref(), Some("1.10.1"));
+        assert_eq!(max("~1.4.0").as_deref(), Some("1.4.2"));
+        assert_eq!(max(">=1.0.0").as_deref(), Some("2.0.0"));
+        assert_eq!(max("1.2.0").as_deref(), Some("1.2.0"));
+        assert_eq!(max("^3.0.0"), None);
+        assert_eq!(max("workspace:*"), None);
+        assert_eq!(max_satisfying(&[], "*"), None);
+    }
+
     #[test]
     fn test_dependency_update_is_workspace_protocol() {
         let update = DependencyUpdate::new(
@@ -2073,6 +2453,8 @@ mod dependency_tests {
             next_version: Version::new(2, 0, 0),
             reason: UpdateReason::DirectChange,
             dependency_updates: vec![],
+            strategy: VersioningStrategy::Unified,
+            skipped_dependency_updates: vec![],
         };
 
         let json = serde_json::to_string(&update).unwrap();
@@ -2094,6 +2476,8 @@ mod dependency_tests {
                 "^1.0.0",
                 "^2.0.0",
             )],
+            strategy: VersioningStrategy::Independent,
+            skipped_dependency_updates: vec![],
         };
 
         assert_eq!(update.dependency_updates.len(), 1);
@@ -2199,3 +2583,43 @@ mod dependency_tests {
         }
     }
 }
+
+// =============================================================================
+// Warning Tests
+// =============================================================================
+
+mod warning_tests {
+    use crate::types::{Warning, WarningKind};
+
+    #[test]
+    fn test_warning_serializes_kind_in_kebab_case() {
+        let warning = Warning::new(WarningKind::PatchedDependencyWithheld, "lodash is patched")
+            .with_package("lodash")
+            .with_path("patches/lodash+4.17.21.patch");
+
+        let json = serde_json::to_value(&warning).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "kind": "patched-dependency-withheld",
+                "message": "lodash is patched",
+                "package": "lodash",
+                "path": "patches/lodash+4.17.21.patch"
+            })
+        );
+        assert_eq!(json["kind"], WarningKind::PatchedDependencyWithheld.as_str());
+
+        let deserialized: Warning = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, warning);
+    }
+
+    #[test]
+    fn test_warning_omits_missing_package_and_path() {
+        let warning = Warning::new(WarningKind::CircularDependency, "a -> b -> a");
+
+        let json = serde_json::to_string(&warning).unwrap();
+        assert!(!json.contains("package"));
+        assert!(!json.contains("path"));
+        assert_eq!(warning.to_string(), "a -> b -> a");
+    }
+}
@@ -97,7 +97,7 @@
 //! ```
 
 use crate::error::{VersionError, VersionResult};
-use chrono::Utc;
+use chrono::{Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
@@ -374,6 +374,73 @@ impl Version {
         Ok(Self { inner: bumped })
     }
 
+    /// Bumps a calendar version for a release on `date`.
+    ///
+    /// With `CalverFormat::YearMonthMicro`, any bump other than `None` moves the version to
+    /// the year and month of `date` with `MICRO` reset to `0`, or increments `MICRO` when the
+    /// version already is in that month. With `CalverFormat::YearMonthDay` the version moves
+    /// to the year, month and day of `date`. A version already at or ahead of `date` (a second
+    /// release the same day, or a clock change) increments its last component so the version
+    /// never goes backwards. Prerelease and build metadata are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidVersion` if the last component would overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use sublime_pkg_tools::types::{CalverFormat, Version, VersionBump};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let june = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+    /// let format = CalverFormat::YearMonthMicro;
+    ///
+    /// // First release of the month
+    /// let version = Version::parse("2024.5.3")?;
+    /// assert_eq!(version.bump_calver(VersionBump::Major, june, format)?.to_string(), "2024.6.0");
+    ///
+    /// // Another release in the same month
+    /// let version = Version::parse("2024.6.0")?;
+    /// assert_eq!(version.bump_calver(VersionBump::Patch, june, format)?.to_string(), "2024.6.1");
+    ///
+    /// // Day-based versions
+    /// let version = Version::parse("2024.5.3")?;
+    /// let next = version.bump_calver(VersionBump::Patch, june, CalverFormat::YearMonthDay)?;
+    /// assert_eq!(next.to_string(), "2024.6.12");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bump_calver(
+        &self,
+        bump_type: VersionBump,
+        date: NaiveDate,
+        format: CalverFormat,
+    ) -> VersionResult<Self> {
+        if bump_type == VersionBump::None {
+            return Ok(self.clone());
+        }
+
+        let year = u64::try_from(date.year()).unwrap_or_default();
+        let month = u64::from(date.month());
+        let release = match format {
+            CalverFormat::YearMonthMicro => semver::Version::new(year, month, 0),
+            CalverFormat::YearMonthDay => semver::Version::new(year, month, u64::from(date.day())),
+        };
+        let current = semver::Version::new(self.inner.major, self.inner.minor, self.inner.patch);
+        if current < release {
+            return Ok(Self { inner: release });
+        }
+
+        let new_micro =
+            self.inner.patch.checked_add(1).ok_or_else(|| VersionError::InvalidVersion {
+                version: self.to_string(),
+                reason: "micro version overflow".to_string(),
+            })?;
+        Ok(Self { inner: semver::Version::new(self.inner.major, self.inner.minor, new_micro) })
+    }
+
     /// Generates a snapshot version for testing or pre-release builds.
     ///
     /// Snapshot versions follow the format: `MAJOR.MINOR.PATCH-snapshot-TIMESTAMP-HASH`
@@ -410,7 +477,8 @@ impl Version {
         }
 
         let timestamp = Utc::now().timestamp();
-        let short_hash = if hash.len() > 7 { &hash[..7] } else { hash };
+        // Shorten on a character boundary so non-ASCII input yields an error, not a panic
+        let short_hash = hash.char_indices().nth(7).map_or(hash, |(index, _)| &hash[..index]);
 
         let prerelease = format!("snapshot-{}-{}", timestamp, short_hash);
 
@@ -469,6 +537,42 @@ impl Version {
     pub fn as_semver(&self) -> &semver::Version {
         &self.inner
     }
+
+    /// Returns the bump separating this version from `other`.
+    ///
+    /// The highest of major, minor, and patch that differs decides the bump. Versions with
+    /// the same core that differ only in prerelease are a patch apart, and versions that
+    /// differ only in build metadata are not apart at all. The order of the two versions
+    /// does not matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::{Version, VersionBump};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let version = Version::parse("1.2.3")?;
+    ///
+    /// assert_eq!(version.diff(&Version::parse("2.0.0")?), VersionBump::Major);
+    /// assert_eq!(version.diff(&Version::parse("1.3.0")?), VersionBump::Minor);
+    /// assert_eq!(version.diff(&Version::parse("1.2.3-beta.1")?), VersionBump::Patch);
+    /// assert_eq!(version.diff(&Version::parse("1.2.3+build.5")?), VersionBump::None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Version) -> VersionBump {
+        let (a, b) = (&self.inner, &other.inner);
+        if a.major != b.major {
+            VersionBump::Major
+        } else if a.minor != b.minor {
+            VersionBump::Minor
+        } else if a.patch != b.patch || a.pre != b.pre {
+            VersionBump::Patch
+        } else {
+            VersionBump::None
+        }
+    }
 }
 
 impl fmt::Display for Version {
@@ -612,6 +716,16 @@ impl VersionBump {
             Self::None => "none",
         }
     }
+
+    /// Ranks bump types so that larger bumps compare greater.
+    pub(crate) const fn rank(self) -> u8 {
+        match self {
+            Self::Major => 3,
+            Self::Minor => 2,
+            Self::Patch => 1,
+            Self::None => 0,
+        }
+    }
 }
 
 impl fmt::Display for VersionBump {
@@ -793,3 +907,116 @@ impl Default for VersioningStrategy {
         Self::Independent
     }
 }
+
+/// Scheme a package's version numbers follow.
+///
+/// - **Semver**: `MAJOR.MINOR.PATCH`, bumped according to the changes
+/// - **Calver**: derived from the release date (see `CalverFormat`), for applications whose
+///   versions carry no compatibility promise
+///
+/// Calendar versions are valid semantic versions, so both schemes share `Version`.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::VersionScheme;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(VersionScheme::parse("calver")?, VersionScheme::Calver);
+/// assert_eq!(VersionScheme::default(), VersionScheme::Semver);
+/// assert_eq!(VersionScheme::Calver.to_string(), "calver");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionScheme {
+    /// Semantic versioning.
+    #[default]
+    Semver,
+    /// Calendar versioning.
+    Calver,
+}
+
+impl VersionScheme {
+    /// Parses a version scheme from a string (`"semver"` or `"calver"`, case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidScheme` if the string is not a valid scheme.
+    pub fn parse(s: &str) -> VersionResult<Self> {
+        match s.to_lowercase().as_str() {
+            "semver" => Ok(Self::Semver),
+            "calver" => Ok(Self::Calver),
+            _ => Err(VersionError::InvalidScheme { scheme: s.to_string() }),
+        }
+    }
+
+    /// Returns the string representation of the scheme.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Semver => "semver",
+            Self::Calver => "calver",
+        }
+    }
+}
+
+impl fmt::Display for VersionScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for VersionScheme {
+    type Err = VersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Layout of calendar versions.
+///
+/// npm requires valid semantic versions, which forbid leading zeros, so months and days are
+/// written without padding: June 2024 is `2024.6`, not `2024.06`.
+///
+/// - **YearMonthMicro** (`YYYY.MM.MICRO`): year and month of the release, and a counter of
+///   the releases made that month, e.g. `2024.6.0`, `2024.6.1`
+/// - **YearMonthDay** (`YYYY.MM.DD`): the release date, e.g. `2024.6.12`
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::CalverFormat;
+///
+/// assert_eq!(CalverFormat::default(), CalverFormat::YearMonthMicro);
+/// assert_eq!(CalverFormat::YearMonthDay.as_str(), "YYYY.MM.DD");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CalverFormat {
+    /// `YYYY.MM.MICRO`: a counter of the releases within the month.
+    #[default]
+    #[serde(rename = "YYYY.MM.MICRO")]
+    YearMonthMicro,
+    /// `YYYY.MM.DD`: the day of the release.
+    #[serde(rename = "YYYY.MM.DD")]
+    YearMonthDay,
+}
+
+impl CalverFormat {
+    /// Returns the string representation of the format.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::YearMonthMicro => "YYYY.MM.MICRO",
+            Self::YearMonthDay => "YYYY.MM.DD",
+        }
+    }
+}
+
+impl fmt::Display for CalverFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
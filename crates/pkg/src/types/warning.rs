@@ -0,0 +1,144 @@
+//! Non-fatal issues reported by operations.
+//!
+//! **What**: Provides `Warning` and `WarningKind`, the structured warnings carried by the
+//! results of version resolution, version application, change analysis, and upgrade
+//! detection and application.
+//!
+//! **How**: Each warning has a kind, a human-readable message, and optionally the package and
+//! the file it concerns. Operations push warnings into the `warnings` field of their result
+//! where they previously only logged the issue, and keep going.
+//!
+//! **Why**: Partial issues such as a cycle in the dependency graph, a file no package owns,
+//! or a registry lookup that failed do not stop an operation, but callers need to see them.
+//! Logs are easy to miss and cannot be consumed by tools; a typed, serializable channel
+//! separate from errors can be rendered by the CLI and read from JSON output.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::types::{Warning, WarningKind};
+//!
+//! let warning = Warning::new(WarningKind::UnattributedFile, "docs/notes.md is in no package")
+//!     .with_path("docs/notes.md");
+//! assert_eq!(warning.to_string(), "docs/notes.md is in no package");
+//! assert!(warning.package.is_none());
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Kind of a non-fatal issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WarningKind {
+    /// The dependency graph contains a cycle.
+    CircularDependency,
+
+    /// A changeset package was resolved under the name it was renamed to.
+    RenamedPackage,
+
+    /// A package manifest holds no valid semantic version.
+    InvalidVersion,
+
+    /// A changed file is outside every package and no rule attributes it.
+    UnattributedFile,
+
+    /// A package was skipped because its manifest could not be read, parsed, or written.
+    SkippedPackage,
+
+    /// A registry lookup failed, so the dependency was not checked.
+    RegistryLookupFailed,
+
+    /// An upgrade of a dependency with a local patch was held back.
+    PatchedDependencyWithheld,
+
+    /// A selected upgrade was not written because the manifest already holds its spec.
+    UnchangedSpec,
+
+    /// The post-upgrade verification command failed and the upgrades were kept.
+    VerificationFailed,
+}
+
+impl WarningKind {
+    /// Returns the kind as written in JSON output, e.g. `unattributed-file`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::types::WarningKind;
+    ///
+    /// assert_eq!(WarningKind::CircularDependency.as_str(), "circular-dependency");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CircularDependency => "circular-dependency",
+            Self::RenamedPackage => "renamed-package",
+            Self::InvalidVersion => "invalid-version",
+            Self::UnattributedFile => "unattributed-file",
+            Self::SkippedPackage => "skipped-package",
+            Self::RegistryLookupFailed => "registry-lookup-failed",
+            Self::PatchedDependencyWithheld => "patched-dependency-withheld",
+            Self::UnchangedSpec => "unchanged-spec",
+            Self::VerificationFailed => "verification-failed",
+        }
+    }
+}
+
+impl std::fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A non-fatal issue found by an operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warning {
+    /// What kind of issue this is.
+    pub kind: WarningKind,
+
+    /// Human-readable description, complete on its own.
+    pub message: String,
+
+    /// The package the issue concerns, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+
+    /// The file the issue concerns, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+}
+
+impl Warning {
+    /// Creates a warning concerning no particular package or file.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of issue
+    /// * `message` - Human-readable description
+    #[must_use]
+    pub fn new(kind: WarningKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into(), package: None, path: None }
+    }
+
+    /// Sets the package the warning concerns.
+    #[must_use]
+    pub fn with_package(mut self, package: impl Into<String>) -> Self {
+        self.package = Some(package.into());
+        self
+    }
+
+    /// Sets the file the warning concerns.
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
@@ -6,14 +6,17 @@
 //! **How**: This module reads package.json files, filters available upgrades based on
 //! selection criteria, updates dependency version specifications, and writes the modified
 //! files back while preserving original JSON formatting (indentation, spacing, etc.).
-//! Supports both dry-run preview and actual file modification.
+//! Supports both dry-run preview and actual file modification. Upgrades that would write the
+//! spec a manifest already holds are reported as skipped, and a manifest is only written when
+//! its content changes.
 //!
 //! **Why**: To enable safe, controlled application of dependency upgrades with proper
 //! formatting preservation, ensuring package.json files remain readable and consistent
 //! with project conventions.
 
 use crate::error::UpgradeError;
-use crate::types::DependencyType;
+use crate::line_endings::LineEnding;
+use crate::types::{DependencyType, NpmAlias, Warning, WarningKind, is_same_spec};
 use crate::upgrade::UpgradeSelection;
 use crate::upgrade::detection::{DependencyUpgrade, PackageUpgrades};
 use crate::upgrade::registry::UpgradeType;
@@ -23,6 +26,7 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 use sublime_standard_tools::filesystem::AsyncFileSystem;
 
+use super::progress::{UpgradeAbortSignal, UpgradeProgressEvent, UpgradeProgressListener};
 use super::result::{AppliedUpgrade, ApplySummary, UpgradeResult};
 use crate::error::UpgradeResult as ErrorResult;
 
@@ -67,6 +71,63 @@ pub async fn apply_upgrades<F: AsyncFileSystem>(
     selection: UpgradeSelection,
     dry_run: bool,
     fs: &F,
+) -> ErrorResult<UpgradeResult> {
+    apply_upgrades_with_progress(
+        available_upgrades,
+        selection,
+        dry_run,
+        fs,
+        &|_: &UpgradeProgressEvent| {},
+        &UpgradeAbortSignal::new(),
+    )
+    .await
+}
+
+/// Applies upgrades like [`apply_upgrades`], reporting progress and honouring an abort signal.
+///
+/// A `PackageStarted` event is emitted before each package and a `PackageFinished` or
+/// `PackageFailed` event after it. The abort signal is checked before each package; once it is
+/// raised no further package is touched and `UpgradeError::Aborted` is returned, so callers
+/// holding a backup can restore the packages already written.
+///
+/// # Arguments
+///
+/// * `available_upgrades` - List of available upgrades from detection
+/// * `selection` - Selection criteria for filtering upgrades
+/// * `dry_run` - If true, preview changes without writing files
+/// * `fs` - Filesystem implementation for reading/writing files
+/// * `listener` - Receives progress events
+/// * `abort` - Signal that stops the application between packages
+///
+/// # Errors
+///
+/// Returns `UpgradeError::Aborted` if the abort signal is raised before all packages were
+/// processed.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::upgrade::{
+///     apply_upgrades_with_progress, UpgradeAbortSignal, UpgradeProgressEvent, UpgradeSelection,
+/// };
+///
+/// # async fn example(fs: &sublime_standard_tools::filesystem::FileSystemManager) -> Result<(), Box<dyn std::error::Error>> {
+/// let abort = UpgradeAbortSignal::new();
+/// let listener = |event: &UpgradeProgressEvent| println!("{event:?}");
+///
+/// let result =
+///     apply_upgrades_with_progress(vec![], UpgradeSelection::all(), false, fs, &listener, &abort)
+///         .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn apply_upgrades_with_progress<F: AsyncFileSystem>(
+    available_upgrades: Vec<PackageUpgrades>,
+    selection: UpgradeSelection,
+    dry_run: bool,
+    fs: &F,
+    listener: &dyn UpgradeProgressListener,
+    abort: &UpgradeAbortSignal,
 ) -> ErrorResult<UpgradeResult> {
     // Filter upgrades based on selection criteria
     let filtered = filter_upgrades(available_upgrades, &selection);
@@ -83,42 +144,89 @@ pub async fn apply_upgrades<F: AsyncFileSystem>(
 
     // Group upgrades by package for batch processing
     let mut applied_upgrades = Vec::new();
+    let mut skipped_upgrades = Vec::new();
     let mut modified_files = Vec::new();
     let mut packages_modified = HashSet::new();
+    let mut warnings = Vec::new();
 
     // Process each package
-    for package_upgrades in filtered {
+    for (processed, package_upgrades) in filtered.into_iter().enumerate() {
+        if abort.is_aborted() {
+            return Err(UpgradeError::Aborted { packages_processed: processed });
+        }
+
+        let package_name = package_upgrades.package_name.clone();
         let package_path = package_upgrades.package_path.clone();
+        listener.on_event(&UpgradeProgressEvent::PackageStarted {
+            package_name: package_name.clone(),
+            package_path: package_path.clone(),
+            upgrades: package_upgrades.upgrades.len(),
+        });
 
         // Apply upgrades to this package
         match apply_package_upgrades(package_upgrades, dry_run, fs).await {
             Ok(package_result) => {
+                listener.on_event(&UpgradeProgressEvent::PackageFinished {
+                    package_name,
+                    package_path,
+                    applied: package_result.applied.len(),
+                });
+
+                skipped_upgrades.extend(package_result.skipped);
                 if !package_result.applied.is_empty() {
                     packages_modified.insert(package_result.package_path.clone());
                     applied_upgrades.extend(package_result.applied);
 
-                    if !dry_run {
+                    if package_result.written {
                         modified_files.push(package_result.package_path);
                     }
                 }
             }
             Err(e) => {
-                // Log error but continue with other packages
-                eprintln!("Failed to apply upgrades to {}: {}", package_path.display(), e);
+                // Report the failure but continue with other packages
+                log::warn!("Failed to apply upgrades to {}: {}", package_path.display(), e);
+                warnings.push(
+                    Warning::new(
+                        WarningKind::SkippedPackage,
+                        format!("Upgrades of {package_name} were not applied: {e}"),
+                    )
+                    .with_package(package_name.clone())
+                    .with_path(package_path.join("package.json")),
+                );
+                listener.on_event(&UpgradeProgressEvent::PackageFailed {
+                    package_name,
+                    package_path,
+                    error: e.to_string(),
+                });
             }
         }
     }
 
     // Build summary statistics
-    let summary = build_summary(&applied_upgrades, packages_modified.len());
+    let mut summary = build_summary(&applied_upgrades, packages_modified.len());
+    summary.skipped_writes = skipped_upgrades.len();
 
-    Ok(if dry_run {
+    let mut result = if dry_run {
         UpgradeResult::dry_run(applied_upgrades, summary)
     } else {
         // Note: backup_path and changeset_id will be set by higher-level manager
         // (Story 9.5 and 9.6 respectively)
         UpgradeResult::applied(applied_upgrades, modified_files, None, None, summary)
-    })
+    };
+    warnings.extend(skipped_upgrades.iter().map(|upgrade| {
+        Warning::new(
+            WarningKind::UnchangedSpec,
+            format!(
+                "{} in {} already holds {}; nothing was written",
+                upgrade.dependency_name, upgrade.package_name, upgrade.new_version
+            ),
+        )
+        .with_package(upgrade.package_name.clone())
+        .with_path(upgrade.manifest_path.clone())
+    }));
+    result.skipped = skipped_upgrades;
+    result.warnings = warnings;
+    Ok(result)
 }
 
 /// Filters available upgrades based on selection criteria.
@@ -138,8 +246,8 @@ fn filter_upgrades(
     available
         .into_iter()
         .filter_map(|package| {
-            // Filter by package name if specified
-            if !selection.matches_package(&package.package_name) {
+            // Filter by package name, or the root selector for the root manifest
+            if !selection.matches_target(&package.package_name, package.workspace_root) {
                 return None;
             }
 
@@ -166,12 +274,7 @@ fn filter_upgrades(
             if filtered_upgrades.is_empty() {
                 None
             } else {
-                Some(PackageUpgrades {
-                    package_name: package.package_name,
-                    package_path: package.package_path,
-                    current_version: package.current_version,
-                    upgrades: filtered_upgrades,
-                })
+                Some(PackageUpgrades { upgrades: filtered_upgrades, ..package })
             }
         })
         .collect()
@@ -181,6 +284,19 @@ fn filter_upgrades(
 struct PackageApplyResult {
     package_path: PathBuf,
     applied: Vec<AppliedUpgrade>,
+    skipped: Vec<AppliedUpgrade>,
+    written: bool,
+}
+
+/// Outcome of applying a single upgrade to a manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpecWrite {
+    /// The spec was rewritten.
+    Written,
+    /// The manifest already held the spec the upgrade would write.
+    Unchanged,
+    /// The dependency is not declared in the manifest.
+    NotFound,
 }
 
 /// Applies upgrades to a single package.json file.
@@ -215,36 +331,47 @@ async fn apply_package_upgrades<F: AsyncFileSystem>(
         UpgradeError::PackageJsonError { path: package_json_path.clone(), reason: e.to_string() }
     })?;
 
-    // Track applied upgrades
+    // Track applied and skipped upgrades
     let mut applied = Vec::new();
+    let mut skipped = Vec::new();
 
     // Apply each upgrade
     for upgrade in package.upgrades {
-        if apply_single_upgrade(&mut pkg_json, &upgrade) {
-            applied.push(AppliedUpgrade {
-                package_path: package.package_path.clone(),
-                dependency_name: upgrade.name,
-                dependency_type: upgrade.dependency_type,
-                old_version: upgrade.current_version,
-                new_version: upgrade.latest_version,
-                upgrade_type: upgrade.upgrade_type,
-            });
+        let outcome = apply_single_upgrade(&mut pkg_json, &upgrade);
+        let entry = AppliedUpgrade {
+            package_path: package.package_path.clone(),
+            package_name: package.package_name.clone(),
+            manifest_path: package_json_path.clone(),
+            workspace_root: package.workspace_root,
+            dependency_name: upgrade.name,
+            dependency_type: upgrade.dependency_type,
+            old_version: upgrade.current_version,
+            new_version: upgrade.latest_version,
+            upgrade_type: upgrade.upgrade_type,
+        };
+        match outcome {
+            SpecWrite::Written => applied.push(entry),
+            SpecWrite::Unchanged => skipped.push(entry),
+            SpecWrite::NotFound => {}
         }
     }
 
-    // Write updated package.json if not dry-run and changes were made
+    // Write updated package.json if not dry-run and its content changes
+    let mut written = false;
     if !dry_run && !applied.is_empty() {
         let updated_content = serialize_package_json(&pkg_json, &content)?;
-
-        fs.write_file(package_json_path.as_path(), updated_content.as_bytes()).await.map_err(
-            |e| UpgradeError::ApplyFailed {
-                path: package_json_path.clone(),
-                reason: e.to_string(),
-            },
-        )?;
+        if updated_content != content {
+            fs.write_file(package_json_path.as_path(), updated_content.as_bytes()).await.map_err(
+                |e| UpgradeError::ApplyFailed {
+                    path: package_json_path.clone(),
+                    reason: e.to_string(),
+                },
+            )?;
+            written = true;
+        }
     }
 
-    Ok(PackageApplyResult { package_path: package.package_path, applied })
+    Ok(PackageApplyResult { package_path: package.package_path, applied, skipped, written })
 }
 
 /// Applies a single upgrade to a PackageJson object.
@@ -256,8 +383,8 @@ async fn apply_package_upgrades<F: AsyncFileSystem>(
 ///
 /// # Returns
 ///
-/// `true` if the upgrade was applied, `false` if the dependency wasn't found
-fn apply_single_upgrade(pkg_json: &mut PackageJson, upgrade: &DependencyUpgrade) -> bool {
+/// Whether the spec was rewritten, already matched the upgrade, or is not declared
+fn apply_single_upgrade(pkg_json: &mut PackageJson, upgrade: &DependencyUpgrade) -> SpecWrite {
     let deps = match upgrade.dependency_type {
         DependencyType::Regular => &mut pkg_json.dependencies,
         DependencyType::Dev => &mut pkg_json.dev_dependencies,
@@ -270,11 +397,14 @@ fn apply_single_upgrade(pkg_json: &mut PackageJson, upgrade: &DependencyUpgrade)
     {
         // Preserve the version prefix (^, ~, etc.) if present
         let new_spec = preserve_version_prefix(&upgrade.current_version, &upgrade.latest_version);
+        if is_same_spec(version, &new_spec) {
+            return SpecWrite::Unchanged;
+        }
         *version = new_spec;
-        return true;
+        return SpecWrite::Written;
     }
 
-    false
+    SpecWrite::NotFound
 }
 
 /// Preserves version prefix from old version when applying new version.
@@ -284,6 +414,9 @@ fn apply_single_upgrade(pkg_json: &mut PackageJson, upgrade: &DependencyUpgrade)
 /// - `~` (tilde) - Compatible with patch updates only
 /// - Exact versions (no prefix)
 ///
+/// For `npm:` aliases the alias target is kept and the prefix of its range is preserved,
+/// so `npm:@scope/foo@^1.0.0` becomes `npm:@scope/foo@^2.0.0`.
+///
 /// # Arguments
 ///
 /// * `old_version` - Current version specification
@@ -293,6 +426,11 @@ fn apply_single_upgrade(pkg_json: &mut PackageJson, upgrade: &DependencyUpgrade)
 ///
 /// New version with appropriate prefix preserved
 pub fn preserve_version_prefix(old_version: &str, new_version: &str) -> String {
+    if let Some(alias) = NpmAlias::parse(old_version) {
+        let range = alias.range.as_deref().unwrap_or_default();
+        return alias.with_range(&preserve_version_prefix(range, new_version));
+    }
+
     let old_trimmed = old_version.trim();
 
     // Check for common prefixes
@@ -329,7 +467,10 @@ pub fn preserve_version_prefix(old_version: &str, new_version: &str) -> String {
 /// # Errors
 ///
 /// Returns error if serialization fails
-fn serialize_package_json(pkg_json: &PackageJson, original_content: &str) -> ErrorResult<String> {
+pub(crate) fn serialize_package_json(
+    pkg_json: &PackageJson,
+    original_content: &str,
+) -> ErrorResult<String> {
     // Detect indentation from original content
     let indent = detect_indentation(original_content);
 
@@ -373,7 +514,7 @@ fn serialize_package_json(pkg_json: &PackageJson, original_content: &str) -> Err
         serialized.push('\n');
     }
 
-    Ok(serialized)
+    Ok(LineEnding::detect(original_content).apply(&serialized))
 }
 
 /// Detects the indentation style used in JSON content.
@@ -437,6 +578,7 @@ fn build_summary(applied: &[AppliedUpgrade], packages_modified: usize) -> ApplyS
         major_upgrades: major_count,
         minor_upgrades: minor_count,
         patch_upgrades: patch_count,
+        skipped_writes: 0,
         applied_at: Utc::now(),
     }
 }
@@ -16,6 +16,7 @@ use super::creator::create_changeset_for_upgrades;
 use crate::changeset::ChangesetManager;
 use crate::config::UpgradeConfig;
 use crate::error::UpgradeResult;
+use crate::types::VersionBump;
 use crate::upgrade::UpgradeSelection;
 use crate::upgrade::detection::PackageUpgrades;
 use std::collections::HashSet;
@@ -37,7 +38,8 @@ use crate::upgrade::application::result::UpgradeResult as UpgradeResultType;
 /// 2. If `auto_changeset` is enabled and not dry-run:
 ///    - Get current git branch
 ///    - Create or update changeset with affected packages
-///    - Set version bump type from config
+///    - Set version bump type from config (see [`changeset_bump_for`])
+///    - Record the upgraded dependencies as a changeset revision
 /// 3. Return result with changeset ID
 ///
 /// # Arguments
@@ -114,8 +116,30 @@ where
     S: crate::changeset::ChangesetStorage + Send + Sync,
 {
     // Apply upgrades using the core applier
-    let mut result = apply_upgrades(available_upgrades, selection, dry_run, fs).await?;
+    let result = apply_upgrades(available_upgrades, selection, dry_run, fs).await?;
 
+    attach_changeset(result, dry_run, workspace_root, config, changeset_manager).await
+}
+
+/// Records applied upgrades in a changeset and stores its ID in the result.
+///
+/// Does nothing for dry runs, when `auto_changeset` is disabled, or when no changeset
+/// manager is given.
+///
+/// # Errors
+///
+/// Returns `UpgradeError::ChangesetCreationFailed` if the changeset cannot be created or
+/// updated.
+pub(crate) async fn attach_changeset<S>(
+    mut result: UpgradeResultType,
+    dry_run: bool,
+    workspace_root: &Path,
+    config: &UpgradeConfig,
+    changeset_manager: Option<&ChangesetManager<S>>,
+) -> UpgradeResult<UpgradeResultType>
+where
+    S: crate::changeset::ChangesetStorage + Send + Sync,
+{
     // If not dry run and auto_changeset is enabled, create changeset
     if !dry_run
         && config.auto_changeset
@@ -130,7 +154,8 @@ where
                 manager,
                 affected_packages,
                 workspace_root,
-                &config.changeset_bump,
+                changeset_bump_for(&result, config),
+                describe_upgrades(&result),
             )
             .await?;
 
@@ -142,6 +167,73 @@ where
     Ok(result)
 }
 
+/// Returns the changeset bump type for the applied upgrades.
+///
+/// Each applied upgrade maps to a bump through
+/// [`UpgradeConfig::bump_for_upgrade`]; the largest one wins. Falls back to
+/// `changeset_bump` when nothing was applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::config::UpgradeConfig;
+/// use sublime_pkg_tools::types::DependencyType;
+/// use sublime_pkg_tools::upgrade::{
+///     changeset_bump_for, AppliedUpgrade, ApplySummary, UpgradeResult, UpgradeType,
+/// };
+/// use std::path::PathBuf;
+///
+/// let mut config = UpgradeConfig::default();
+/// config.changeset_bump_by_upgrade.insert("major".to_string(), "minor".to_string());
+///
+/// let upgrade = AppliedUpgrade {
+///     package_path: PathBuf::from("packages/core"),
+///     package_name: "core".to_string(),
+///     manifest_path: PathBuf::from("packages/core/package.json"),
+///     workspace_root: false,
+///     dependency_name: "react".to_string(),
+///     dependency_type: DependencyType::Regular,
+///     old_version: "17.0.0".to_string(),
+///     new_version: "18.0.0".to_string(),
+///     upgrade_type: UpgradeType::Major,
+/// };
+/// let result = UpgradeResult::applied(vec![upgrade], vec![], None, None, ApplySummary::new());
+///
+/// assert_eq!(changeset_bump_for(&result, &config), "minor");
+/// ```
+#[must_use]
+pub fn changeset_bump_for<'a>(result: &UpgradeResultType, config: &'a UpgradeConfig) -> &'a str {
+    result
+        .applied
+        .iter()
+        .map(|upgrade| config.bump_for_upgrade(upgrade.upgrade_type.as_str()))
+        .max_by_key(|bump| VersionBump::parse(bump).map_or(0, VersionBump::rank))
+        .unwrap_or(&config.changeset_bump)
+}
+
+/// Describes each applied upgrade as one line, sorted.
+///
+/// Lines read `<package>: <dependency> <old> -> <new> (<type>)`.
+pub(crate) fn describe_upgrades(result: &UpgradeResultType) -> Vec<String> {
+    let mut lines: Vec<String> = result
+        .applied
+        .iter()
+        .map(|upgrade| {
+            let package = extract_package_name(&upgrade.package_path)
+                .unwrap_or_else(|| upgrade.package_path.display().to_string());
+            format!(
+                "{package}: {} {} -> {} ({})",
+                upgrade.dependency_name,
+                upgrade.old_version,
+                upgrade.new_version,
+                upgrade.upgrade_type.as_str()
+            )
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
 /// Extracts unique package names from upgrade results.
 ///
 /// # Arguments
@@ -33,6 +33,9 @@ use sublime_git_tools::Repo;
 /// - **Environments**: Empty (user can update later)
 /// - **Packages**: All packages that had dependencies upgraded
 /// - **Changes**: Empty (commits are added later via `add_commits_from_git`)
+/// - **Revisions**: A revision listing `upgrades`, when author tracking is enabled
+///
+/// An existing changeset keeps its bump unless `bump_type_str` is larger.
 ///
 /// # Arguments
 ///
@@ -40,6 +43,7 @@ use sublime_git_tools::Repo;
 /// * `affected_packages` - Set of package names that were modified
 /// * `workspace_root` - Root directory of the workspace
 /// * `bump_type_str` - String representation of version bump type ("major", "minor", "patch", "none")
+/// * `upgrades` - One line per upgraded dependency, recorded as the revision description
 ///
 /// # Returns
 ///
@@ -71,7 +75,8 @@ use sublime_git_tools::Repo;
 ///     &manager,
 ///     packages,
 ///     &PathBuf::from("."),
-///     "patch"
+///     "patch",
+///     vec!["@myorg/core: lodash 4.17.20 -> 4.17.21 (patch)".to_string()],
 /// ).await?;
 ///
 /// if let Some(id) = changeset_id {
@@ -85,6 +90,7 @@ pub async fn create_changeset_for_upgrades<S>(
     affected_packages: HashSet<String>,
     workspace_root: &Path,
     bump_type_str: &str,
+    upgrades: Vec<String>,
 ) -> UpgradeResult<Option<String>>
 where
     S: crate::changeset::ChangesetStorage + Send + Sync,
@@ -123,10 +129,11 @@ where
 
     if changeset_exists {
         // Update existing changeset with new packages
-        update_existing_changeset(changeset_manager, &branch_name, package_list).await?;
+        update_existing_changeset(changeset_manager, &branch_name, bump, package_list, upgrades)
+            .await?;
     } else {
         // Create new changeset
-        create_new_changeset(changeset_manager, &branch_name, bump, package_list).await?;
+        create_new_changeset(changeset_manager, &branch_name, bump, package_list, upgrades).await?;
     }
 
     Ok(Some(branch_name))
@@ -140,6 +147,7 @@ where
 /// * `branch_name` - Name of the git branch
 /// * `bump` - Version bump type
 /// * `packages` - List of affected package names
+/// * `upgrades` - Description of the upgraded dependencies
 ///
 /// # Errors
 ///
@@ -149,6 +157,7 @@ async fn create_new_changeset<S>(
     branch_name: &str,
     bump: VersionBump,
     packages: Vec<String>,
+    upgrades: Vec<String>,
 ) -> UpgradeResult<()>
 where
     S: crate::changeset::ChangesetStorage + Send + Sync,
@@ -163,6 +172,7 @@ where
     let mut sorted_packages = packages;
     sorted_packages.sort();
     changeset.packages = sorted_packages;
+    changeset_manager.record_revision(&mut changeset, upgrades);
 
     // Update the changeset with the packages
     changeset_manager
@@ -176,13 +186,16 @@ where
 
 /// Updates an existing changeset with additional packages.
 ///
-/// This function merges new packages with existing ones, avoiding duplicates.
+/// This function merges new packages with existing ones, avoiding duplicates, and raises
+/// the bump when the upgrades require a larger one.
 ///
 /// # Arguments
 ///
 /// * `changeset_manager` - Manager instance for changeset operations
 /// * `branch_name` - Name of the git branch
+/// * `bump` - Version bump required by the upgrades
 /// * `new_packages` - List of newly affected package names
+/// * `upgrades` - Description of the upgraded dependencies
 ///
 /// # Errors
 ///
@@ -190,7 +203,9 @@ where
 async fn update_existing_changeset<S>(
     changeset_manager: &ChangesetManager<S>,
     branch_name: &str,
+    bump: VersionBump,
     new_packages: Vec<String>,
+    upgrades: Vec<String>,
 ) -> UpgradeResult<()>
 where
     S: crate::changeset::ChangesetStorage + Send + Sync,
@@ -211,8 +226,14 @@ where
     changeset.packages = existing_packages.into_iter().collect();
     changeset.packages.sort(); // Keep packages sorted for consistency
 
+    // Never lower a bump chosen earlier
+    if bump.rank() > changeset.bump.rank() {
+        changeset.bump = bump;
+    }
+
     // Update timestamp
     changeset.updated_at = chrono::Utc::now();
+    changeset_manager.record_revision(&mut changeset, upgrades);
 
     // Save updated changeset
     changeset_manager
@@ -23,4 +23,6 @@ mod creator;
 mod tests;
 
 // Re-export public API
-pub use applier::apply_with_changeset;
+pub use applier::{apply_with_changeset, changeset_bump_for};
+pub(crate) use applier::{attach_changeset, extract_affected_packages};
+pub(crate) use creator::create_changeset_for_upgrades;
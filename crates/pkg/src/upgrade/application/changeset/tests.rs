@@ -3,7 +3,7 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
 
-use super::applier::{apply_with_changeset, extract_affected_packages};
+use super::applier::{apply_with_changeset, changeset_bump_for, extract_affected_packages};
 use super::creator::create_changeset_for_upgrades;
 use crate::changeset::{ChangesetManager, FileBasedChangesetStorage};
 use crate::config::{ChangesetConfig, UpgradeConfig};
@@ -115,8 +115,14 @@ fn create_test_upgrades(workspace_root: &Path) -> Vec<PackageUpgrades> {
                 latest_prerelease: None,
                 deprecated: None,
                 published_at: Some(Utc::now()),
+                current_deprecated: None,
+                target_deprecated: None,
+                downloads: None,
+                repository: None,
             },
+            patch: None,
         }],
+        workspace_root: false,
     }]
 }
 
@@ -139,7 +145,8 @@ async fn test_create_new_changeset() {
     packages.insert("@myorg/utils".to_string());
 
     // Create changeset
-    let result = create_changeset_for_upgrades(&manager, packages, &repo_path, "patch").await;
+    let result =
+        create_changeset_for_upgrades(&manager, packages, &repo_path, "patch", Vec::new()).await;
 
     assert!(result.is_ok());
     let changeset_id = result.expect("Failed to create changeset");
@@ -169,7 +176,7 @@ async fn test_update_existing_changeset() {
     let mut packages1 = HashSet::new();
     packages1.insert("@myorg/core".to_string());
 
-    create_changeset_for_upgrades(&manager, packages1, &repo_path, "patch")
+    create_changeset_for_upgrades(&manager, packages1, &repo_path, "patch", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -178,7 +185,8 @@ async fn test_update_existing_changeset() {
     packages2.insert("@myorg/utils".to_string());
     packages2.insert("@myorg/cli".to_string());
 
-    let result = create_changeset_for_upgrades(&manager, packages2, &repo_path, "patch").await;
+    let result =
+        create_changeset_for_upgrades(&manager, packages2, &repo_path, "patch", Vec::new()).await;
 
     assert!(result.is_ok());
 
@@ -204,7 +212,7 @@ async fn test_no_duplicate_packages() {
     let mut packages1 = HashSet::new();
     packages1.insert("@myorg/core".to_string());
 
-    create_changeset_for_upgrades(&manager, packages1, &repo_path, "patch")
+    create_changeset_for_upgrades(&manager, packages1, &repo_path, "patch", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -213,7 +221,7 @@ async fn test_no_duplicate_packages() {
     packages2.insert("@myorg/core".to_string()); // Duplicate
     packages2.insert("@myorg/utils".to_string());
 
-    create_changeset_for_upgrades(&manager, packages2, &repo_path, "patch")
+    create_changeset_for_upgrades(&manager, packages2, &repo_path, "patch", Vec::new())
         .await
         .expect("Failed to update changeset");
 
@@ -232,7 +240,8 @@ async fn test_empty_packages() {
     let packages = HashSet::new();
 
     // Should return None for empty packages
-    let result = create_changeset_for_upgrades(&manager, packages, &repo_path, "patch").await;
+    let result =
+        create_changeset_for_upgrades(&manager, packages, &repo_path, "patch", Vec::new()).await;
 
     assert!(result.is_ok());
     assert!(result.expect("Failed").is_none());
@@ -252,7 +261,8 @@ async fn test_invalid_bump_type() {
     packages.insert("@myorg/core".to_string());
 
     // Should fail with invalid bump type
-    let result = create_changeset_for_upgrades(&manager, packages, &repo_path, "invalid").await;
+    let result =
+        create_changeset_for_upgrades(&manager, packages, &repo_path, "invalid", Vec::new()).await;
 
     assert!(result.is_err());
     match result {
@@ -275,7 +285,7 @@ async fn test_different_bump_types() {
     let mut packages = HashSet::new();
     packages.insert("@myorg/core".to_string());
 
-    create_changeset_for_upgrades(&manager, packages.clone(), &repo_path, "patch")
+    create_changeset_for_upgrades(&manager, packages.clone(), &repo_path, "patch", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -287,7 +297,7 @@ async fn test_different_bump_types() {
     repo.create_branch("feature/minor").expect("Failed to create branch");
     repo.checkout("feature/minor").expect("Failed to checkout branch");
 
-    create_changeset_for_upgrades(&manager, packages.clone(), &repo_path, "minor")
+    create_changeset_for_upgrades(&manager, packages.clone(), &repo_path, "minor", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -299,7 +309,7 @@ async fn test_different_bump_types() {
     repo.create_branch("feature/major").expect("Failed to create branch");
     repo.checkout("feature/major").expect("Failed to checkout branch");
 
-    create_changeset_for_upgrades(&manager, packages, &repo_path, "major")
+    create_changeset_for_upgrades(&manager, packages, &repo_path, "major", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -323,7 +333,7 @@ async fn test_packages_sorted() {
     packages.insert("apple".to_string());
     packages.insert("mango".to_string());
 
-    create_changeset_for_upgrades(&manager, packages, &repo_path, "patch")
+    create_changeset_for_upgrades(&manager, packages, &repo_path, "patch", Vec::new())
         .await
         .expect("Failed to create changeset");
 
@@ -487,6 +497,9 @@ async fn test_extract_affected_packages() {
         vec![
             AppliedUpgrade {
                 package_path: workspace_root.join("packages").join("core"),
+                package_name: "@test/core".to_string(),
+                manifest_path: workspace_root.join("packages").join("core").join("package.json"),
+                workspace_root: false,
                 dependency_name: "lodash".to_string(),
                 dependency_type: DependencyType::Regular,
                 old_version: "4.17.20".to_string(),
@@ -495,6 +508,9 @@ async fn test_extract_affected_packages() {
             },
             AppliedUpgrade {
                 package_path: workspace_root.join("packages").join("core"),
+                package_name: "@test/core".to_string(),
+                manifest_path: workspace_root.join("packages").join("core").join("package.json"),
+                workspace_root: false,
                 dependency_name: "react".to_string(),
                 dependency_type: DependencyType::Regular,
                 old_version: "17.0.0".to_string(),
@@ -546,3 +562,87 @@ async fn test_different_changeset_bump_types() {
     let changeset = manager.load("feature/minor-upgrade").await.expect("Failed to load changeset");
     assert_eq!(changeset.bump, VersionBump::Minor);
 }
+
+#[tokio::test]
+async fn test_bump_by_upgrade_type_and_revision() {
+    let (_temp, workspace_root, repo) = setup_test_workspace().await;
+
+    repo.create_branch("feature/patch-as-minor").expect("Failed to create branch");
+    repo.checkout("feature/patch-as-minor").expect("Failed to checkout");
+
+    let manager = setup_changeset_manager(&workspace_root).await;
+    let fs = FileSystemManager::new();
+    let mut config = UpgradeConfig::default();
+    config.changeset_bump_by_upgrade.insert("patch".to_string(), "minor".to_string());
+
+    apply_with_changeset(
+        create_test_upgrades(&workspace_root),
+        UpgradeSelection::all(),
+        false,
+        &workspace_root,
+        &config,
+        Some(&manager),
+        &fs,
+    )
+    .await
+    .expect("Failed to apply with changeset");
+
+    let changeset = manager.load("feature/patch-as-minor").await.expect("Failed to load");
+    assert_eq!(changeset.bump, VersionBump::Minor);
+    assert_eq!(changeset.revisions.len(), 1);
+    assert_eq!(
+        changeset.revisions[0].changes,
+        vec!["@myorg/core: lodash 4.17.20 -> 4.17.21 (patch)".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_existing_changeset_bump_is_never_lowered() {
+    let (_temp, repo_path, repo) = setup_test_repo().await;
+
+    repo.create_branch("feature/raise").expect("Failed to create branch");
+    repo.checkout("feature/raise").expect("Failed to checkout branch");
+
+    let manager = setup_changeset_manager(&repo_path).await;
+    let packages = HashSet::from(["@myorg/core".to_string()]);
+
+    for bump in ["patch", "minor", "patch"] {
+        create_changeset_for_upgrades(&manager, packages.clone(), &repo_path, bump, Vec::new())
+            .await
+            .expect("Failed to create changeset");
+    }
+
+    let changeset = manager.load("feature/raise").await.expect("Failed to load");
+    assert_eq!(changeset.bump, VersionBump::Minor);
+}
+
+#[test]
+fn test_changeset_bump_for_uses_largest_bump() {
+    let upgrade = |upgrade_type| AppliedUpgrade {
+        package_path: PathBuf::from("packages/core"),
+        package_name: "core".to_string(),
+        manifest_path: PathBuf::from("packages/core/package.json"),
+        workspace_root: false,
+        dependency_name: "dep".to_string(),
+        dependency_type: DependencyType::Regular,
+        old_version: "1.0.0".to_string(),
+        new_version: "2.0.0".to_string(),
+        upgrade_type,
+    };
+    let result = UpgradeResultType::applied(
+        vec![upgrade(UpgradeType::Patch), upgrade(UpgradeType::Major)],
+        vec![],
+        None,
+        None,
+        ApplySummary::new(),
+    );
+
+    let mut config = UpgradeConfig::default();
+    assert_eq!(changeset_bump_for(&result, &config), "patch");
+
+    config.changeset_bump_by_upgrade.insert("major".to_string(), "major".to_string());
+    assert_eq!(changeset_bump_for(&result, &config), "major");
+
+    let empty = UpgradeResultType::applied(vec![], vec![], None, None, ApplySummary::new());
+    assert_eq!(changeset_bump_for(&empty, &config), "patch");
+}
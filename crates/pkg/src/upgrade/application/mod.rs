@@ -17,6 +17,8 @@
 //! - `applier`: Core logic for applying upgrades to package.json files
 //! - `selection`: Selection criteria for filtering which upgrades to apply
 //! - `result`: Result types containing applied upgrade details and statistics
+//! - `progress`: Progress events and abort signal for long-running applications
+//! - `verification`: Post-upgrade verification command
 //!
 //! # Examples
 //!
@@ -51,14 +53,21 @@
 
 pub(crate) mod applier;
 mod changeset;
+mod progress;
 pub(crate) mod result;
 mod selection;
+mod verification;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
-pub use applier::apply_upgrades;
-pub use changeset::apply_with_changeset;
+pub use applier::{apply_upgrades, apply_upgrades_with_progress};
+pub use changeset::{apply_with_changeset, changeset_bump_for};
+pub(crate) use changeset::{attach_changeset, create_changeset_for_upgrades};
+pub use progress::{UpgradeAbortSignal, UpgradeProgressEvent, UpgradeProgressListener};
 pub use result::{AppliedUpgrade, ApplySummary, UpgradeResult};
-pub use selection::UpgradeSelection;
+pub(crate) use selection::matches_package_target;
+pub use selection::{ROOT_PACKAGE_SELECTOR, UpgradeSelection};
+pub(crate) use verification::verify_upgrades;
+pub use verification::{UPGRADED_PACKAGES_ENV, VerificationOutcome};
@@ -11,7 +11,8 @@
 //! understand exactly what changed, track modifications for rollback, and review statistics
 //! for reporting and audit purposes.
 
-use crate::types::DependencyType;
+use crate::types::{DependencyType, Warning};
+use crate::upgrade::application::verification::VerificationOutcome;
 use crate::upgrade::registry::UpgradeType;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -82,6 +83,30 @@ pub struct UpgradeResult {
     ///
     /// Aggregated counts and metrics about the upgrade operation.
     pub summary: ApplySummary,
+
+    /// Outcome of the post-upgrade verification command.
+    ///
+    /// `None` for dry-run operations or when no verification command is configured
+    /// for the applied upgrades. A failed outcome is only returned when the failure
+    /// policy is `keep`; with `rollback` the operation fails instead.
+    #[serde(default)]
+    pub verification: Option<VerificationOutcome>,
+
+    /// Selected upgrades that were not written because they would not change the spec.
+    ///
+    /// The manifest already held the spec the upgrade would write, for example because
+    /// the upgrade was detected before the file was updated by hand. Such upgrades leave
+    /// the file untouched and are not counted in `applied`.
+    #[serde(default)]
+    pub skipped: Vec<AppliedUpgrade>,
+
+    /// Non-fatal issues found while detecting and applying the upgrades.
+    ///
+    /// Lists failed registry lookups, upgrades held back for a local patch, packages
+    /// skipped because their manifest could not be updated, upgrades in `skipped`, and a
+    /// failed verification kept under the `keep` policy.
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
 }
 
 impl UpgradeResult {
@@ -112,6 +137,9 @@ impl UpgradeResult {
             backup_path: None,
             changeset_id: None,
             summary,
+            verification: None,
+            skipped: Vec::new(),
+            warnings: Vec::new(),
         }
     }
 
@@ -132,7 +160,17 @@ impl UpgradeResult {
         changeset_id: Option<String>,
         summary: ApplySummary,
     ) -> Self {
-        Self { dry_run: false, applied, modified_files, backup_path, changeset_id, summary }
+        Self {
+            dry_run: false,
+            applied,
+            modified_files,
+            backup_path,
+            changeset_id,
+            summary,
+            verification: None,
+            skipped: Vec::new(),
+            warnings: Vec::new(),
+        }
     }
 
     /// Returns whether any upgrades were applied.
@@ -196,6 +234,9 @@ impl UpgradeResult {
 ///
 /// let upgrade = AppliedUpgrade {
 ///     package_path: PathBuf::from("packages/my-package"),
+///     package_name: "my-package".to_string(),
+///     manifest_path: PathBuf::from("packages/my-package/package.json"),
+///     workspace_root: false,
 ///     dependency_name: "lodash".to_string(),
 ///     dependency_type: DependencyType::Regular,
 ///     old_version: "4.17.20".to_string(),
@@ -214,6 +255,21 @@ pub struct AppliedUpgrade {
     /// this upgrade was applied.
     pub package_path: PathBuf,
 
+    /// Name of the package whose manifest was upgraded.
+    #[serde(default)]
+    pub package_name: String,
+
+    /// The package.json file the new spec was written to.
+    #[serde(default)]
+    pub manifest_path: PathBuf,
+
+    /// Whether the manifest is the one at the workspace root.
+    ///
+    /// Dependencies declared only at the root of a monorepo, such as the shared
+    /// devDependencies of a pnpm workspace, are upgraded there rather than in a package.
+    #[serde(default)]
+    pub workspace_root: bool,
+
     /// Name of the upgraded dependency.
     ///
     /// The package name as it appears in the dependencies section
@@ -257,6 +313,9 @@ impl AppliedUpgrade {
     ///
     /// let upgrade = AppliedUpgrade {
     ///     package_path: PathBuf::from("."),
+    ///     package_name: "app".to_string(),
+    ///     manifest_path: PathBuf::from("package.json"),
+    ///     workspace_root: true,
     ///     dependency_name: "lodash".to_string(),
     ///     dependency_type: DependencyType::Regular,
     ///     old_version: "4.17.20".to_string(),
@@ -282,6 +341,9 @@ impl AppliedUpgrade {
     ///
     /// let upgrade = AppliedUpgrade {
     ///     package_path: PathBuf::from("."),
+    ///     package_name: "app".to_string(),
+    ///     manifest_path: PathBuf::from("package.json"),
+    ///     workspace_root: true,
     ///     dependency_name: "react".to_string(),
     ///     dependency_type: DependencyType::Regular,
     ///     old_version: "17.0.0".to_string(),
@@ -309,6 +371,9 @@ impl AppliedUpgrade {
     ///
     /// let upgrade = AppliedUpgrade {
     ///     package_path: PathBuf::from("."),
+    ///     package_name: "app".to_string(),
+    ///     manifest_path: PathBuf::from("package.json"),
+    ///     workspace_root: true,
     ///     dependency_name: "webpack".to_string(),
     ///     dependency_type: DependencyType::Dev,
     ///     old_version: "4.46.0".to_string(),
@@ -338,6 +403,9 @@ impl AppliedUpgrade {
     ///
     /// let upgrade = AppliedUpgrade {
     ///     package_path: PathBuf::from("."),
+    ///     package_name: "app".to_string(),
+    ///     manifest_path: PathBuf::from("package.json"),
+    ///     workspace_root: true,
     ///     dependency_name: "express".to_string(),
     ///     dependency_type: DependencyType::Regular,
     ///     old_version: "4.18.0".to_string(),
@@ -363,6 +431,9 @@ impl AppliedUpgrade {
     ///
     /// let upgrade = AppliedUpgrade {
     ///     package_path: PathBuf::from("."),
+    ///     package_name: "app".to_string(),
+    ///     manifest_path: PathBuf::from("package.json"),
+    ///     workspace_root: true,
     ///     dependency_name: "lodash".to_string(),
     ///     dependency_type: DependencyType::Regular,
     ///     old_version: "4.17.20".to_string(),
@@ -397,6 +468,7 @@ impl AppliedUpgrade {
 ///     major_upgrades: 1,
 ///     minor_upgrades: 2,
 ///     patch_upgrades: 2,
+///     skipped_writes: 0,
 ///     applied_at: chrono::Utc::now(),
 /// };
 ///
@@ -448,6 +520,10 @@ pub struct ApplySummary {
     /// Count of dependencies upgraded to a new patch version (e.g., 1.2.3 → 1.2.4).
     pub patch_upgrades: usize,
 
+    /// Number of selected upgrades left unwritten because the spec would not change.
+    #[serde(default)]
+    pub skipped_writes: usize,
+
     /// Timestamp when the operation was performed.
     ///
     /// For dry-run operations, this is when the preview was generated.
@@ -477,6 +553,7 @@ impl ApplySummary {
             major_upgrades: 0,
             minor_upgrades: 0,
             patch_upgrades: 0,
+            skipped_writes: 0,
             applied_at: Utc::now(),
         }
     }
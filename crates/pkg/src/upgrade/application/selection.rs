@@ -13,6 +13,25 @@
 use crate::upgrade::registry::UpgradeType;
 use serde::{Deserialize, Serialize};
 
+/// Package filter entry that selects the workspace root manifest.
+///
+/// The root of a monorepo often has no `name`, so it is targeted with `.` instead.
+pub const ROOT_PACKAGE_SELECTOR: &str = ".";
+
+/// Returns whether a package filter selects the given manifest.
+///
+/// An entry matches the package `name`, and [`ROOT_PACKAGE_SELECTOR`] matches the workspace
+/// root manifest.
+pub(crate) fn matches_package_target(
+    filter: &[String],
+    package_name: &str,
+    workspace_root: bool,
+) -> bool {
+    filter
+        .iter()
+        .any(|entry| entry == package_name || (workspace_root && entry == ROOT_PACKAGE_SELECTOR))
+}
+
 /// Selection criteria for applying upgrades.
 ///
 /// Allows filtering upgrades by type, package, or specific dependencies.
@@ -78,7 +97,10 @@ pub struct UpgradeSelection {
     /// Specific packages to upgrade (filter by package name).
     ///
     /// When set, only package.json files matching these package names
-    /// (from the `name` field) will have upgrades applied.
+    /// (from the `name` field) will have upgrades applied. The workspace root
+    /// manifest is matched by its name or by [`ROOT_PACKAGE_SELECTOR`]; dependencies
+    /// declared only at the root, such as the shared devDependencies of a pnpm
+    /// workspace, are left alone unless the root is selected.
     ///
     /// # Default: `None` (all packages)
     pub packages: Option<Vec<String>>,
@@ -99,6 +121,15 @@ pub struct UpgradeSelection {
     ///
     /// # Default: `None` (no limit)
     pub max_upgrade_type: Option<UpgradeType>,
+
+    /// Confirms upgrades of dependencies with a local patch.
+    ///
+    /// Only consulted when `upgrade.patched_dependencies` is `"confirm"`; without the
+    /// confirmation those upgrades are held back.
+    ///
+    /// # Default: `false`
+    #[serde(default)]
+    pub include_patched: bool,
 }
 
 impl UpgradeSelection {
@@ -290,11 +321,33 @@ impl UpgradeSelection {
     /// ```
     #[must_use]
     pub fn matches_package(&self, package_name: &str) -> bool {
-        if let Some(ref packages) = self.packages {
-            packages.iter().any(|p| p == package_name)
-        } else {
-            true
-        }
+        self.matches_target(package_name, false)
+    }
+
+    /// Checks if this selection matches a manifest, which may be the workspace root.
+    ///
+    /// Like [`UpgradeSelection::matches_package`], and the workspace root manifest is
+    /// also matched by [`ROOT_PACKAGE_SELECTOR`].
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - The package name to check
+    /// * `workspace_root` - Whether the manifest is at the workspace root
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::{ROOT_PACKAGE_SELECTOR, UpgradeSelection};
+    ///
+    /// let selection = UpgradeSelection::packages(vec![ROOT_PACKAGE_SELECTOR.to_string()]);
+    /// assert!(selection.matches_target("my-monorepo", true));
+    /// assert!(!selection.matches_target("@org/core", false));
+    /// ```
+    #[must_use]
+    pub fn matches_target(&self, package_name: &str, workspace_root: bool) -> bool {
+        self.packages
+            .as_deref()
+            .is_none_or(|packages| matches_package_target(packages, package_name, workspace_root))
     }
 
     /// Checks if this selection matches the given dependency name.
@@ -327,6 +380,35 @@ impl UpgradeSelection {
         }
     }
 
+    /// Checks if this selection lets upgrades of patched dependencies through.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The `upgrade.patched_dependencies` policy ("warn", "confirm", "block")
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::UpgradeSelection;
+    ///
+    /// let mut selection = UpgradeSelection::all();
+    /// assert!(selection.allows_patched("warn"));
+    /// assert!(!selection.allows_patched("confirm"));
+    /// assert!(!selection.allows_patched("block"));
+    ///
+    /// selection.include_patched = true;
+    /// assert!(selection.allows_patched("confirm"));
+    /// assert!(!selection.allows_patched("block"));
+    /// ```
+    #[must_use]
+    pub fn allows_patched(&self, policy: &str) -> bool {
+        match policy {
+            "block" => false,
+            "confirm" => self.include_patched,
+            _ => true,
+        }
+    }
+
     /// Checks if this selection has any active filters.
     ///
     /// Returns `true` if at least one filter criterion is specified,
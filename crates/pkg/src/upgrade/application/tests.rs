@@ -13,14 +13,19 @@
 
 #![allow(clippy::unwrap_used)]
 
+use crate::error::UpgradeError;
 use crate::types::DependencyType;
 use crate::upgrade::application::applier::preserve_version_prefix;
-use crate::upgrade::application::{UpgradeSelection, apply_upgrades};
+use crate::upgrade::application::{
+    ROOT_PACKAGE_SELECTOR, UpgradeAbortSignal, UpgradeProgressEvent, UpgradeSelection,
+    apply_upgrades, apply_upgrades_with_progress,
+};
 use crate::upgrade::detection::{DependencyUpgrade, PackageUpgrades, VersionInfo};
 use crate::upgrade::registry::UpgradeType;
 use chrono::Utc;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use sublime_standard_tools::error::{FileSystemError, Result as StandardResult};
 use sublime_standard_tools::filesystem::AsyncFileSystem;
 
@@ -168,7 +173,12 @@ fn create_test_upgrade(
             latest_prerelease: None,
             deprecated: None,
             published_at: Some(Utc::now()),
+            current_deprecated: None,
+            target_deprecated: None,
+            downloads: None,
+            repository: None,
         },
+        patch: None,
     }
 }
 
@@ -182,6 +192,7 @@ fn create_package_upgrades(
         package_path,
         current_version: Some("1.0.0".to_string()),
         upgrades,
+        workspace_root: false,
     }
 }
 
@@ -249,6 +260,53 @@ async fn test_apply_upgrades_actual() {
     assert_eq!(result.summary.packages_modified, 1);
 }
 
+#[tokio::test]
+async fn test_apply_upgrades_skips_unchanged_specs() {
+    let mut fs = MockFileSystem::new();
+    let package_path = PathBuf::from("packages/test-package");
+    let json_path = package_path.join("package.json");
+
+    // lodash was already raised by hand after the upgrade was detected
+    fs.add_file(json_path.clone(), create_test_package_json().replace("^4.17.20", "^4.17.21"));
+
+    let lodash = || {
+        create_test_upgrade(
+            "lodash",
+            "^4.17.20",
+            "4.17.21",
+            UpgradeType::Patch,
+            DependencyType::Regular,
+        )
+    };
+    let react = create_test_upgrade(
+        "react",
+        "^17.0.0",
+        "17.0.2",
+        UpgradeType::Patch,
+        DependencyType::Regular,
+    );
+
+    let upgrades =
+        vec![create_package_upgrades("test-package", package_path.clone(), vec![lodash(), react])];
+    let result = apply_upgrades(upgrades, UpgradeSelection::all(), false, &fs).await.unwrap();
+
+    assert_eq!(result.applied.len(), 1);
+    assert_eq!(result.applied[0].dependency_name, "react");
+    assert_eq!(result.skipped.len(), 1);
+    assert_eq!(result.skipped[0].dependency_name, "lodash");
+    assert_eq!(result.summary.skipped_writes, 1);
+    assert_eq!(result.modified_files, vec![package_path.clone()]);
+
+    // With nothing left to change, the manifest is not written at all
+    let upgrades = vec![create_package_upgrades("test-package", package_path, vec![lodash()])];
+    let result = apply_upgrades(upgrades, UpgradeSelection::all(), false, &fs).await.unwrap();
+
+    assert!(result.applied.is_empty());
+    assert_eq!(result.skipped.len(), 1);
+    assert!(result.modified_files.is_empty());
+    assert_eq!(result.summary.packages_modified, 0);
+}
+
 #[tokio::test]
 async fn test_apply_upgrades_patch_only_filter() {
     let mut fs = MockFileSystem::new();
@@ -388,6 +446,97 @@ async fn test_apply_upgrades_package_filter() {
     assert_eq!(result.summary.packages_modified, 1);
 }
 
+#[tokio::test]
+async fn test_apply_upgrades_root_selector_targets_root_manifest() {
+    let mut fs = MockFileSystem::new();
+    let root_path = PathBuf::from(".");
+    let leaf_path = PathBuf::from("packages/leaf");
+
+    fs.add_file(root_path.join("package.json"), create_test_package_json());
+    fs.add_file(leaf_path.join("package.json"), create_test_package_json());
+
+    let root = PackageUpgrades {
+        workspace_root: true,
+        ..create_package_upgrades(
+            "monorepo",
+            root_path.clone(),
+            vec![create_test_upgrade(
+                "webpack",
+                "^4.46.0",
+                "4.47.0",
+                UpgradeType::Minor,
+                DependencyType::Dev,
+            )],
+        )
+    };
+    let leaf = create_package_upgrades(
+        "leaf",
+        leaf_path.clone(),
+        vec![create_test_upgrade(
+            "lodash",
+            "^4.17.20",
+            "4.17.21",
+            UpgradeType::Patch,
+            DependencyType::Regular,
+        )],
+    );
+
+    let selection = UpgradeSelection::packages(vec![ROOT_PACKAGE_SELECTOR.to_string()]);
+    let result = apply_upgrades(vec![root, leaf], selection, true, &fs).await.unwrap();
+
+    assert_eq!(result.applied.len(), 1);
+    let applied = &result.applied[0];
+    assert_eq!(applied.dependency_name, "webpack");
+    assert_eq!(applied.package_name, "monorepo");
+    assert!(applied.workspace_root);
+    assert_eq!(applied.manifest_path, root_path.join("package.json"));
+}
+
+#[tokio::test]
+async fn test_apply_upgrades_leaf_filter_leaves_root_dev_dependencies() {
+    let mut fs = MockFileSystem::new();
+    let root_path = PathBuf::from(".");
+    let leaf_path = PathBuf::from("packages/group/leaf");
+
+    fs.add_file(root_path.join("package.json"), create_test_package_json());
+    fs.add_file(leaf_path.join("package.json"), create_test_package_json());
+
+    let root = PackageUpgrades {
+        workspace_root: true,
+        ..create_package_upgrades(
+            "monorepo",
+            root_path,
+            vec![create_test_upgrade(
+                "webpack",
+                "^4.46.0",
+                "4.47.0",
+                UpgradeType::Minor,
+                DependencyType::Dev,
+            )],
+        )
+    };
+    let leaf = create_package_upgrades(
+        "leaf",
+        leaf_path.clone(),
+        vec![create_test_upgrade(
+            "webpack",
+            "^4.46.0",
+            "4.47.0",
+            UpgradeType::Minor,
+            DependencyType::Dev,
+        )],
+    );
+
+    let selection = UpgradeSelection::packages(vec!["leaf".to_string()]);
+    let result = apply_upgrades(vec![root, leaf], selection, true, &fs).await.unwrap();
+
+    assert_eq!(result.applied.len(), 1);
+    let applied = &result.applied[0];
+    assert_eq!(applied.package_name, "leaf");
+    assert!(!applied.workspace_root);
+    assert_eq!(applied.manifest_path, leaf_path.join("package.json"));
+}
+
 #[tokio::test]
 async fn test_apply_upgrades_dependency_filter() {
     let mut fs = MockFileSystem::new();
@@ -527,6 +676,129 @@ async fn test_apply_upgrades_file_read_error() {
     assert_eq!(result.applied.len(), 0);
 }
 
+#[tokio::test]
+async fn test_apply_upgrades_with_progress_reports_each_package() {
+    let mut fs = MockFileSystem::new();
+    let ok_path = PathBuf::from("packages/ok");
+    let broken_path = PathBuf::from("packages/broken");
+
+    fs.add_file(ok_path.join("package.json"), create_test_package_json());
+    fs.add_read_error(broken_path.join("package.json"), "Permission denied".to_string());
+
+    let lodash = || {
+        create_test_upgrade(
+            "lodash",
+            "^4.17.20",
+            "4.17.21",
+            UpgradeType::Patch,
+            DependencyType::Regular,
+        )
+    };
+    let upgrades = vec![
+        create_package_upgrades("ok", ok_path.clone(), vec![lodash()]),
+        create_package_upgrades("broken", broken_path.clone(), vec![lodash()]),
+    ];
+
+    let events = Mutex::new(Vec::new());
+    let listener = |event: &UpgradeProgressEvent| events.lock().unwrap().push(event.clone());
+    let result = apply_upgrades_with_progress(
+        upgrades,
+        UpgradeSelection::all(),
+        true,
+        &fs,
+        &listener,
+        &UpgradeAbortSignal::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.applied.len(), 1);
+    let events = events.into_inner().unwrap();
+    assert_eq!(events.len(), 4);
+    assert_eq!(
+        events[0],
+        UpgradeProgressEvent::PackageStarted {
+            package_name: "ok".to_string(),
+            package_path: ok_path.clone(),
+            upgrades: 1,
+        }
+    );
+    assert_eq!(
+        events[1],
+        UpgradeProgressEvent::PackageFinished {
+            package_name: "ok".to_string(),
+            package_path: ok_path,
+            applied: 1,
+        }
+    );
+    assert!(matches!(
+        &events[3],
+        UpgradeProgressEvent::PackageFailed { package_name, .. } if package_name == "broken"
+    ));
+}
+
+#[tokio::test]
+async fn test_apply_upgrades_with_progress_stops_when_aborted() {
+    let mut fs = MockFileSystem::new();
+    let first_path = PathBuf::from("packages/first");
+    let second_path = PathBuf::from("packages/second");
+
+    fs.add_file(first_path.join("package.json"), create_test_package_json());
+    fs.add_file(second_path.join("package.json"), create_test_package_json());
+
+    let lodash = || {
+        create_test_upgrade(
+            "lodash",
+            "^4.17.20",
+            "4.17.21",
+            UpgradeType::Patch,
+            DependencyType::Regular,
+        )
+    };
+    let upgrades = vec![
+        create_package_upgrades("first", first_path, vec![lodash()]),
+        create_package_upgrades("second", second_path, vec![lodash()]),
+    ];
+
+    // Abort as soon as the first package finishes
+    let abort = UpgradeAbortSignal::new();
+    let handle = abort.clone();
+    let started = Mutex::new(Vec::new());
+    let listener = |event: &UpgradeProgressEvent| match event {
+        UpgradeProgressEvent::PackageStarted { package_name, .. } => {
+            started.lock().unwrap().push(package_name.clone());
+        }
+        UpgradeProgressEvent::PackageFinished { .. } => handle.abort(),
+        UpgradeProgressEvent::PackageFailed { .. } => {}
+    };
+
+    let result = apply_upgrades_with_progress(
+        upgrades,
+        UpgradeSelection::all(),
+        true,
+        &fs,
+        &listener,
+        &abort,
+    )
+    .await;
+
+    assert!(matches!(result, Err(UpgradeError::Aborted { packages_processed: 1 })));
+    assert_eq!(started.into_inner().unwrap(), vec!["first".to_string()]);
+}
+
+#[test]
+fn test_upgrade_progress_event_serializes_with_type_tag() {
+    let event = UpgradeProgressEvent::PackageFailed {
+        package_name: "core".to_string(),
+        package_path: PathBuf::from("packages/core"),
+        error: "boom".to_string(),
+    };
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["type"], "package_failed");
+    assert_eq!(json["package_name"], "core");
+}
+
 #[tokio::test]
 async fn test_preserve_version_prefix_caret() {
     assert_eq!(preserve_version_prefix("^1.2.3", "1.2.4"), "^1.2.4");
@@ -755,6 +1027,18 @@ mod selection_tests {
         assert!(!selection.matches_type(UpgradeType::Major));
     }
 
+    #[test]
+    fn test_allows_patched_by_policy() {
+        let mut selection = UpgradeSelection::all();
+        assert!(selection.allows_patched("warn"));
+        assert!(!selection.allows_patched("confirm"));
+        assert!(!selection.allows_patched("block"));
+
+        selection.include_patched = true;
+        assert!(selection.allows_patched("confirm"));
+        assert!(!selection.allows_patched("block"));
+    }
+
     #[test]
     fn test_matches_type_minor_and_patch() {
         let selection = UpgradeSelection::minor_and_patch();
@@ -863,6 +1147,9 @@ mod result_tests {
     fn test_applied_upgrade_is_patch() {
         let upgrade = AppliedUpgrade {
             package_path: PathBuf::from("."),
+            package_name: "app".to_string(),
+            manifest_path: PathBuf::from("package.json"),
+            workspace_root: true,
             dependency_name: "lodash".to_string(),
             dependency_type: DependencyType::Regular,
             old_version: "4.17.20".to_string(),
@@ -880,6 +1167,9 @@ mod result_tests {
     fn test_applied_upgrade_is_minor() {
         let upgrade = AppliedUpgrade {
             package_path: PathBuf::from("."),
+            package_name: "app".to_string(),
+            manifest_path: PathBuf::from("package.json"),
+            workspace_root: true,
             dependency_name: "react".to_string(),
             dependency_type: DependencyType::Regular,
             old_version: "17.0.0".to_string(),
@@ -897,6 +1187,9 @@ mod result_tests {
     fn test_applied_upgrade_is_major() {
         let upgrade = AppliedUpgrade {
             package_path: PathBuf::from("."),
+            package_name: "app".to_string(),
+            manifest_path: PathBuf::from("package.json"),
+            workspace_root: true,
             dependency_name: "webpack".to_string(),
             dependency_type: DependencyType::Dev,
             old_version: "4.46.0".to_string(),
@@ -914,6 +1207,9 @@ mod result_tests {
     fn test_applied_upgrade_version_change() {
         let upgrade = AppliedUpgrade {
             package_path: PathBuf::from("."),
+            package_name: "app".to_string(),
+            manifest_path: PathBuf::from("package.json"),
+            workspace_root: true,
             dependency_name: "lodash".to_string(),
             dependency_type: DependencyType::Regular,
             old_version: "4.17.20".to_string(),
@@ -975,7 +1271,10 @@ mod result_tests {
 // ============================================================================
 
 mod applier_tests {
-    use crate::upgrade::application::applier::{detect_indentation, preserve_version_prefix};
+    use crate::upgrade::application::applier::{
+        detect_indentation, preserve_version_prefix, serialize_package_json,
+    };
+    use package_json::PackageJson;
 
     #[test]
     fn test_preserve_version_prefix_caret() {
@@ -1002,6 +1301,15 @@ mod applier_tests {
         assert_eq!(preserve_version_prefix("=3.0.0", "3.0.1"), "=3.0.1");
     }
 
+    #[test]
+    fn test_preserve_version_prefix_npm_alias() {
+        assert_eq!(
+            preserve_version_prefix("npm:@scope/foo@^1.2.3", "2.0.0"),
+            "npm:@scope/foo@^2.0.0"
+        );
+        assert_eq!(preserve_version_prefix("npm:foo@1.2.3", "1.3.0"), "npm:foo@1.3.0");
+    }
+
     #[test]
     fn test_detect_indentation_spaces() {
         let content = r#"{
@@ -1031,4 +1339,134 @@ mod applier_tests {
         let content = r#"{"name":"test","version":"1.0.0"}"#;
         assert_eq!(detect_indentation(content), "  ");
     }
+
+    #[test]
+    fn test_serialize_package_json_preserves_crlf() {
+        let original = "{\r\n  \"name\": \"test\",\r\n  \"version\": \"1.0.0\"\r\n}\r\n";
+        let pkg_json: PackageJson = serde_json::from_str(original).unwrap();
+
+        let serialized = serialize_package_json(&pkg_json, original).unwrap();
+
+        assert!(serialized.contains("\"name\": \"test\",\r\n"));
+        assert_eq!(serialized.matches('\n').count(), serialized.matches("\r\n").count());
+    }
+}
+
+// ============================================================================
+// Verification Tests
+// ============================================================================
+
+mod verification_tests {
+    use crate::config::{UpgradeVerifyConfig, UpgradeVerifyRule};
+    use crate::types::DependencyType;
+    use crate::upgrade::application::verification::{verification_level, verify_upgrades};
+    use crate::upgrade::application::{AppliedUpgrade, ApplySummary, UpgradeResult};
+    use crate::upgrade::registry::UpgradeType;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn applied(package_path: &Path, upgrade_type: UpgradeType) -> AppliedUpgrade {
+        AppliedUpgrade {
+            package_path: package_path.to_path_buf(),
+            package_name: "core".to_string(),
+            manifest_path: package_path.join("package.json"),
+            workspace_root: false,
+            dependency_name: "lodash".to_string(),
+            dependency_type: DependencyType::Regular,
+            old_version: "4.17.20".to_string(),
+            new_version: "4.17.21".to_string(),
+            upgrade_type,
+        }
+    }
+
+    fn result_with(upgrades: Vec<AppliedUpgrade>) -> UpgradeResult {
+        UpgradeResult::applied(upgrades, vec![], None, None, ApplySummary::new())
+    }
+
+    #[test]
+    fn test_verification_level_is_largest_upgrade() {
+        let path = Path::new(".");
+        assert_eq!(verification_level(&[]), None);
+        assert_eq!(
+            verification_level(&[
+                applied(path, UpgradeType::Patch),
+                applied(path, UpgradeType::Minor)
+            ]),
+            Some(UpgradeType::Minor)
+        );
+        assert_eq!(
+            verification_level(&[
+                applied(path, UpgradeType::Major),
+                applied(path, UpgradeType::Patch)
+            ]),
+            Some(UpgradeType::Major)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_without_command_is_skipped() {
+        let temp = TempDir::new().unwrap();
+        let result = result_with(vec![applied(temp.path(), UpgradeType::Patch)]);
+
+        let outcome = verify_upgrades(&result, &UpgradeVerifyConfig::default(), temp.path()).await;
+
+        assert!(outcome.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_passes_upgraded_packages() {
+        let temp = TempDir::new().unwrap();
+        let package = temp.path().join("packages/core");
+        std::fs::create_dir_all(&package).unwrap();
+        std::fs::write(package.join("package.json"), r#"{"name":"@myorg/core"}"#).unwrap();
+
+        let config = UpgradeVerifyConfig {
+            command: Some("test \"$WORKSPACE_UPGRADED_PACKAGES\" = \"@myorg/core\"".to_string()),
+            ..Default::default()
+        };
+        let result = result_with(vec![applied(&package, UpgradeType::Minor)]);
+
+        let outcome = verify_upgrades(&result, &config, temp.path()).await.unwrap();
+
+        assert!(outcome.passed, "{:?}", outcome.reason);
+        assert_eq!(outcome.upgrade_type, UpgradeType::Minor);
+        assert!(outcome.reason.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_failure_with_rule_for_level() {
+        let temp = TempDir::new().unwrap();
+        let mut config =
+            UpgradeVerifyConfig { command: Some("true".to_string()), ..Default::default() };
+        config.by_upgrade.insert(
+            "major".to_string(),
+            UpgradeVerifyRule {
+                command: Some("echo broken >&2; exit 3".to_string()),
+                on_failure: Some("keep".to_string()),
+            },
+        );
+        let result = result_with(vec![applied(temp.path(), UpgradeType::Major)]);
+
+        let outcome = verify_upgrades(&result, &config, temp.path()).await.unwrap();
+
+        assert!(!outcome.passed);
+        assert_eq!(outcome.command, "echo broken >&2; exit 3");
+        assert_eq!(outcome.reason.as_deref(), Some("exit code 3: broken"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_times_out() {
+        let temp = TempDir::new().unwrap();
+        let config = UpgradeVerifyConfig {
+            command: Some("sleep 5".to_string()),
+            timeout_secs: 1,
+            ..Default::default()
+        };
+        let result = result_with(vec![applied(temp.path(), UpgradeType::Patch)]);
+
+        let outcome = verify_upgrades(&result, &config, temp.path()).await.unwrap();
+
+        assert!(!outcome.passed);
+        assert!(outcome.reason.unwrap().contains("timed out"));
+    }
 }
@@ -0,0 +1,131 @@
+//! Post-upgrade verification.
+//!
+//! **What**: Runs the command configured in `upgrade.verify` after upgrades are applied and
+//! reports whether it passed as a `VerificationOutcome`.
+//!
+//! **How**: The command is picked for the largest semver level among the applied upgrades
+//! and run with `sh -c` from the workspace root, bounded by `upgrade.verify.timeout_secs`.
+//! The names of the upgraded packages are passed in `WORKSPACE_UPGRADED_PACKAGES`, comma
+//! separated, so the command can limit itself to them. `UpgradeManager` rolls the upgrades
+//! back when the command fails and the policy for the level is `rollback`.
+//!
+//! **Why**: A dependency upgrade that type-checks can still break a package's tests. Running
+//! the affected tests right after the upgrade, and restoring the backup when they fail, keeps
+//! broken upgrades out of the workspace.
+
+use crate::config::UpgradeVerifyConfig;
+use crate::upgrade::application::changeset::extract_affected_packages;
+use crate::upgrade::application::result::{AppliedUpgrade, UpgradeResult};
+use crate::upgrade::registry::UpgradeType;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use sublime_standard_tools::command::{CommandBuilder, DefaultCommandExecutor, Executor};
+use sublime_standard_tools::error::{CommandError, Error as StandardError};
+
+/// Environment variable holding the comma-separated names of the upgraded packages.
+pub const UPGRADED_PACKAGES_ENV: &str = "WORKSPACE_UPGRADED_PACKAGES";
+
+/// The result of running the post-upgrade verification command.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::{UpgradeType, VerificationOutcome};
+///
+/// let outcome = VerificationOutcome {
+///     command: "pnpm -r test".to_string(),
+///     upgrade_type: UpgradeType::Minor,
+///     passed: false,
+///     duration_ms: 1200,
+///     reason: Some("exit code 1".to_string()),
+/// };
+///
+/// assert!(!outcome.passed);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationOutcome {
+    /// The command that was run.
+    pub command: String,
+
+    /// The largest semver level among the applied upgrades, used to pick the command and
+    /// failure policy.
+    pub upgrade_type: UpgradeType,
+
+    /// Whether the command exited successfully within the timeout.
+    pub passed: bool,
+
+    /// How long the command ran, in milliseconds.
+    pub duration_ms: u64,
+
+    /// Why the command failed. `None` when it passed.
+    pub reason: Option<String>,
+}
+
+/// Returns the largest semver level among the applied upgrades.
+///
+/// Returns `None` when nothing was applied.
+pub(crate) fn verification_level(applied: &[AppliedUpgrade]) -> Option<UpgradeType> {
+    let rank = |upgrade_type: UpgradeType| match upgrade_type {
+        UpgradeType::Major => 2,
+        UpgradeType::Minor => 1,
+        UpgradeType::Patch => 0,
+    };
+
+    applied.iter().map(|upgrade| upgrade.upgrade_type).max_by_key(|level| rank(*level))
+}
+
+/// Runs the verification command for the applied upgrades.
+///
+/// # Parameters
+///
+/// * `result` - The result of applying the upgrades
+/// * `config` - The verification configuration
+/// * `workspace_root` - Directory the command runs in
+///
+/// # Returns
+///
+/// The outcome of the command, or `None` when nothing was applied or no command is
+/// configured for the level of the upgrades.
+pub(crate) async fn verify_upgrades(
+    result: &UpgradeResult,
+    config: &UpgradeVerifyConfig,
+    workspace_root: &Path,
+) -> Option<VerificationOutcome> {
+    let upgrade_type = verification_level(&result.applied)?;
+    let command = config.command_for(upgrade_type.as_str())?.to_string();
+
+    let mut packages: Vec<String> = extract_affected_packages(result).into_iter().collect();
+    packages.sort();
+
+    let cmd = CommandBuilder::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .current_dir(workspace_root)
+        .env(UPGRADED_PACKAGES_ENV, packages.join(","))
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .build();
+
+    let started = Instant::now();
+    let reason = match DefaultCommandExecutor::new().execute(cmd).await {
+        Ok(_) => None,
+        Err(StandardError::Command(CommandError::NonZeroExitCode { code, stderr, .. })) => {
+            let status =
+                code.map_or_else(|| "terminated".to_string(), |c| format!("exit code {c}"));
+            let stderr = stderr.trim();
+            Some(if stderr.is_empty() { status } else { format!("{status}: {stderr}") })
+        }
+        Err(StandardError::Command(CommandError::Timeout { duration })) => {
+            Some(format!("timed out after {}s", duration.as_secs()))
+        }
+        Err(e) => Some(e.to_string()),
+    };
+
+    Some(VerificationOutcome {
+        command,
+        upgrade_type,
+        passed: reason.is_none(),
+        duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        reason,
+    })
+}
@@ -7,6 +7,10 @@
 //! maintains metadata about backups, and provides functions to restore from backups or
 //! clean up old backups based on configuration limits.
 //!
+//! Each backed-up file is checksummed. Restoring verifies every checksum first and refuses to
+//! touch the workspace if any backup file is missing or altered. Cleanup enforces the count,
+//! age, and total size limits from `BackupConfig`, always keeping the most recent backup.
+//!
 //! **Why**: To enable safe dependency upgrades with the ability to recover from failures
 //! by restoring the previous state of package.json files.
 //!
@@ -42,7 +46,12 @@
 //!         "/workspace/package.json",
 //!         "/workspace/packages/core/package.json"
 //!       ],
-//!       "success": true
+//!       "success": true,
+//!       "checksums": {
+//!         "/workspace/package.json": "9f86d081884c7d65...",
+//!         "/workspace/packages/core/package.json": "60303ae22b998861..."
+//!       },
+//!       "size_bytes": 2048
 //!     }
 //!   ]
 //! }
@@ -85,8 +94,11 @@
 
 use crate::config::BackupConfig;
 use crate::error::{UpgradeError, UpgradeResult};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use sublime_standard_tools::filesystem::AsyncFileSystem;
 
@@ -132,6 +144,7 @@ pub struct BackupManager<F: AsyncFileSystem> {
 /// ```rust
 /// use sublime_pkg_tools::upgrade::BackupMetadata;
 /// use chrono::Utc;
+/// use std::collections::BTreeMap;
 /// use std::path::PathBuf;
 ///
 /// let metadata = BackupMetadata {
@@ -140,6 +153,9 @@ pub struct BackupManager<F: AsyncFileSystem> {
 ///     operation: "upgrade".to_string(),
 ///     files: vec![PathBuf::from("package.json")],
 ///     success: true,
+///     created_files: vec![],
+///     checksums: BTreeMap::new(),
+///     size_bytes: 0,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -165,6 +181,23 @@ pub struct BackupMetadata {
     /// - `true`: Operation succeeded, backup can be cleaned up if configured
     /// - `false`: Operation failed, backup should be kept for rollback
     pub success: bool,
+
+    /// Absolute paths of files that did not exist when the backup was taken.
+    ///
+    /// Restoring the backup deletes these files, so operations that create files (such as a
+    /// first changelog) can be reverted as well.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub created_files: Vec<PathBuf>,
+
+    /// Hex-encoded SHA-256 checksum of each backed-up file, keyed by its absolute path.
+    ///
+    /// Backups created before checksums were recorded have none and are restored unverified.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checksums: BTreeMap<PathBuf, String>,
+
+    /// Total size of the backed-up files in bytes.
+    #[serde(default)]
+    pub size_bytes: u64,
 }
 
 /// Collection of all backup metadata.
@@ -242,6 +275,28 @@ impl<F: AsyncFileSystem> BackupManager<F> {
     /// # }
     /// ```
     pub async fn create_backup(&self, files: &[PathBuf], operation: &str) -> UpgradeResult<String> {
+        self.create_backup_inner(files, operation, false).await
+    }
+
+    /// Creates a backup in which missing files are recorded as created by the operation.
+    ///
+    /// Files that do not exist yet are listed in `created_files` instead of failing the
+    /// backup; restoring it deletes them.
+    pub(crate) async fn create_backup_allowing_missing(
+        &self,
+        files: &[PathBuf],
+        operation: &str,
+    ) -> UpgradeResult<String> {
+        self.create_backup_inner(files, operation, true).await
+    }
+
+    /// Copies `files` into a new backup, optionally tolerating files that do not exist yet.
+    async fn create_backup_inner(
+        &self,
+        files: &[PathBuf],
+        operation: &str,
+        allow_missing: bool,
+    ) -> UpgradeResult<String> {
         if !self.config.enabled {
             return Err(UpgradeError::BackupFailed {
                 path: self.backup_dir(),
@@ -261,12 +316,22 @@ impl<F: AsyncFileSystem> BackupManager<F> {
 
         // Copy each file to backup directory, preserving directory structure
         let mut backed_up_files = Vec::new();
+        let mut created_files = Vec::new();
+        let mut checksums = BTreeMap::new();
+        let mut size_bytes = 0u64;
         for file in files {
             let absolute_path = self.normalize_fs_path(&self.resolve_path(file));
 
             // Check if file exists before backing up
             let exists = self.fs.exists(&absolute_path).await;
 
+            if !exists && allow_missing {
+                if !created_files.contains(&absolute_path) {
+                    created_files.push(absolute_path);
+                }
+                continue;
+            }
+
             if !exists {
                 return Err(UpgradeError::FileSystemError {
                     path: absolute_path,
@@ -354,6 +419,8 @@ impl<F: AsyncFileSystem> BackupManager<F> {
                 }
             })?;
 
+            checksums.insert(absolute_path.clone(), checksum(&content));
+            size_bytes += content.len() as u64;
             backed_up_files.push(absolute_path);
         }
 
@@ -364,6 +431,9 @@ impl<F: AsyncFileSystem> BackupManager<F> {
             operation: operation.to_string(),
             files: backed_up_files,
             success: false, // Will be updated by mark_success
+            created_files,
+            checksums,
+            size_bytes,
         };
 
         // Update metadata file
@@ -414,6 +484,9 @@ impl<F: AsyncFileSystem> BackupManager<F> {
         // Load metadata to get file list
         let metadata = self.get_backup_metadata(backup_id).await?;
 
+        // Refuse to restore anything if a backup file is missing or altered
+        self.verify_metadata(&metadata).await?;
+
         // Restore each file
         for file_path in &metadata.files {
             let relative = file_path.strip_prefix(&self.workspace_root).map_err(|_| {
@@ -449,9 +522,51 @@ impl<F: AsyncFileSystem> BackupManager<F> {
             })?;
         }
 
+        // Remove files the operation created
+        for created in &metadata.created_files {
+            let target_file = self.normalize_fs_path(created);
+            if self.fs.exists(&target_file).await {
+                self.fs.remove(&target_file).await.map_err(|e| UpgradeError::RollbackFailed {
+                    reason: format!(
+                        "Failed to remove created file {}: {}",
+                        target_file.display(),
+                        e
+                    ),
+                })?;
+            }
+        }
+
         Ok(())
     }
 
+    /// Verifies the integrity of a backup.
+    ///
+    /// Every backed-up file must exist and match the checksum recorded when the backup was
+    /// created. Backups without recorded checksums only need their files to exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `backup_id` - ID of the backup to verify
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError::NoBackup` if the backup doesn't exist, or
+    /// `UpgradeError::BackupCorrupted` naming the first file that is missing or altered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # use sublime_pkg_tools::upgrade::backup::BackupManager;
+    /// # async fn example(manager: BackupManager<impl sublime_standard_tools::filesystem::AsyncFileSystem>) -> Result<(), Box<dyn std::error::Error>> {
+    /// manager.verify_backup("2024-01-15T10-30-45-upgrade").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_backup(&self, backup_id: &str) -> UpgradeResult<()> {
+        let metadata = self.get_backup_metadata(backup_id).await?;
+        self.verify_metadata(&metadata).await
+    }
+
     /// Restores files from the most recent backup.
     ///
     /// Convenience method to restore from the newest backup without needing
@@ -617,32 +732,30 @@ impl<F: AsyncFileSystem> BackupManager<F> {
     /// # }
     /// ```
     pub async fn cleanup_old_backups(&self) -> UpgradeResult<()> {
-        let mut collection = self.load_metadata_collection().await?;
-        let mut to_remove_all: Vec<String> = Vec::new();
+        self.prune().await.map(|_| ())
+    }
 
-        // Collect successful backups to remove if configured
-        if !self.config.keep_after_success {
-            let to_remove: Vec<String> =
-                collection.backups.iter().filter(|b| b.success).map(|b| b.id.clone()).collect();
-            to_remove_all.extend(to_remove);
-        }
+    /// Returns the backups that cleanup would remove, without removing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if metadata cannot be read.
+    pub async fn prune_candidates(&self) -> UpgradeResult<Vec<BackupMetadata>> {
+        let collection = self.load_metadata_collection().await?;
+        let to_remove = self.select_for_removal(&collection.backups, Utc::now());
+        Ok(collection.backups.into_iter().filter(|b| to_remove.contains(&b.id)).collect())
+    }
 
-        // Determine how many more to remove based on max_backups limit
-        // Calculate this based on the collection BEFORE removing successful backups
-        let remaining_after_success = collection.backups.len() - to_remove_all.len();
-        if remaining_after_success > self.config.max_backups {
-            let to_remove_count = remaining_after_success - self.config.max_backups;
-            // Get oldest backups that aren't already marked for removal
-            let to_remove: Vec<String> = collection
-                .backups
-                .iter()
-                .rev() // Oldest first (collection is sorted newest first)
-                .filter(|b| !to_remove_all.contains(&b.id))
-                .take(to_remove_count)
-                .map(|b| b.id.clone())
-                .collect();
-            to_remove_all.extend(to_remove);
-        }
+    /// Removes backups that exceed the configured count, age, or total size limits.
+    ///
+    /// Returns the IDs of the removed backups, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if metadata cannot be read or updated.
+    pub async fn prune(&self) -> UpgradeResult<Vec<String>> {
+        let mut collection = self.load_metadata_collection().await?;
+        let to_remove_all = self.select_for_removal(&collection.backups, Utc::now());
 
         // Remove all backups from filesystem
         for backup_id in &to_remove_all {
@@ -657,16 +770,116 @@ impl<F: AsyncFileSystem> BackupManager<F> {
 
         self.save_metadata_collection(&collection).await?;
 
+        Ok(to_remove_all)
+    }
+
+    /// Selects the backups to remove, in collection order (newest first).
+    ///
+    /// Successful backups go first unless `keep_after_success` is set. The remaining ones are
+    /// then limited by age, count, and total size. The newest remaining backup is never
+    /// removed by the age or size limits.
+    fn select_for_removal(&self, backups: &[BackupMetadata], now: DateTime<Utc>) -> Vec<String> {
+        let mut remove = vec![false; backups.len()];
+
+        // Collect successful backups to remove if configured
+        if !self.config.keep_after_success {
+            for (index, backup) in backups.iter().enumerate() {
+                remove[index] = backup.success;
+            }
+        }
+
+        let newest_kept = remove.iter().position(|removed| !removed);
+
+        // Remove backups older than the age limit
+        if let Some(days) = self.config.max_age_days {
+            let cutoff = now - Duration::days(i64::from(days));
+            for (index, backup) in backups.iter().enumerate() {
+                if Some(index) != newest_kept && backup.created_at < cutoff {
+                    remove[index] = true;
+                }
+            }
+        }
+
+        // Keep at most max_backups of the remaining backups
+        let mut kept = 0usize;
+        for flag in &mut remove {
+            if !*flag {
+                kept += 1;
+                if kept > self.config.max_backups {
+                    *flag = true;
+                }
+            }
+        }
+
+        // Remove the oldest backups until the total size fits
+        if let Some(limit_mb) = self.config.max_total_size_mb {
+            let limit = limit_mb.saturating_mul(1024 * 1024);
+            let mut total = 0u64;
+            for (index, backup) in backups.iter().enumerate() {
+                if remove[index] {
+                    continue;
+                }
+                total = total.saturating_add(backup.size_bytes);
+                if total > limit && Some(index) != newest_kept {
+                    remove[index] = true;
+                }
+            }
+        }
+
+        backups
+            .iter()
+            .zip(remove)
+            .filter(|(_, removed)| *removed)
+            .map(|(b, _)| b.id.clone())
+            .collect()
+    }
+
+    /// Checks that every file recorded in `metadata` is present and unaltered.
+    async fn verify_metadata(&self, metadata: &BackupMetadata) -> UpgradeResult<()> {
+        let backup_path = self.normalize_fs_path(&self.backup_path(&metadata.id));
+
+        for file_path in &metadata.files {
+            let relative = file_path.strip_prefix(&self.workspace_root).map_err(|_| {
+                UpgradeError::RollbackFailed {
+                    reason: format!("File path not within workspace: {}", file_path.display()),
+                }
+            })?;
+            let backup_file = self.normalize_fs_path(&backup_path.join(relative));
+
+            if !self.fs.exists(&backup_file).await {
+                return Err(UpgradeError::BackupCorrupted {
+                    path: backup_file,
+                    reason: "Backed-up file is missing".to_string(),
+                });
+            }
+
+            let Some(expected) = metadata.checksums.get(file_path) else {
+                continue;
+            };
+            let content = self.fs.read_file(&backup_file).await.map_err(|e| {
+                UpgradeError::BackupCorrupted {
+                    path: backup_file.clone(),
+                    reason: format!("Failed to read backed-up file: {}", e),
+                }
+            })?;
+            if &checksum(&content) != expected {
+                return Err(UpgradeError::BackupCorrupted {
+                    path: backup_file,
+                    reason: "Checksum mismatch; the backed-up file was modified".to_string(),
+                });
+            }
+        }
+
         Ok(())
     }
 
     /// Returns the backup directory path.
-    fn backup_dir(&self) -> PathBuf {
+    pub(crate) fn backup_dir(&self) -> PathBuf {
         self.workspace_root.join(&self.config.backup_dir)
     }
 
     /// Returns the path for a specific backup.
-    fn backup_path(&self, backup_id: &str) -> PathBuf {
+    pub(crate) fn backup_path(&self, backup_id: &str) -> PathBuf {
         self.backup_dir().join(backup_id)
     }
 
@@ -783,5 +996,13 @@ impl<F: AsyncFileSystem> BackupManager<F> {
     }
 }
 
+/// Returns the hex-encoded SHA-256 checksum of `content`.
+fn checksum(content: &[u8]) -> String {
+    Sha256::digest(content).iter().fold(String::with_capacity(64), |mut hex, byte| {
+        let _ = write!(hex, "{:02x}", byte);
+        hex
+    })
+}
+
 #[cfg(test)]
 mod tests;
@@ -225,6 +225,8 @@ async fn test_create_backup_success() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: false,
         max_backups: 5,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -278,6 +280,8 @@ async fn test_create_backup_disabled() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: false,
         max_backups: 5,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -338,6 +342,30 @@ async fn test_restore_backup_success() {
     assert!(content.contains(r#""name": "test""#));
 }
 
+#[tokio::test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "Windows path normalization issues - tracked in WOR-TSK-141"
+)]
+async fn test_restore_backup_removes_created_files() {
+    let config = BackupConfig::default();
+    let manager = create_test_manager(config);
+
+    manager.fs.add_file(manager.workspace_root.join("package.json"), r#"{"name": "test"}"#);
+
+    let files = vec![PathBuf::from("package.json"), PathBuf::from("CHANGELOG.md")];
+    let backup_id = manager.create_backup_allowing_missing(&files, "bump").await.unwrap();
+
+    let backups = manager.list_backups().await.unwrap();
+    assert_eq!(backups[0].files, vec![manager.workspace_root.join("package.json")]);
+    assert_eq!(backups[0].created_files, vec![manager.workspace_root.join("CHANGELOG.md")]);
+
+    manager.fs.add_file(manager.workspace_root.join("CHANGELOG.md"), "# Changelog");
+    manager.restore_backup(&backup_id).await.unwrap();
+
+    assert!(!manager.fs.file_exists(&manager.workspace_root.join("CHANGELOG.md")));
+}
+
 #[tokio::test]
 async fn test_restore_backup_nonexistent() {
     let config = BackupConfig::default();
@@ -512,6 +540,8 @@ async fn test_cleanup_removes_successful_backups() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: false,
         max_backups: 5,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -542,6 +572,8 @@ async fn test_cleanup_keeps_successful_backups() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: true,
         max_backups: 5,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -559,6 +591,135 @@ async fn test_cleanup_keeps_successful_backups() {
     assert_eq!(backups.len(), 1);
 }
 
+/// Creates three backups of `package.json`, oldest first, and returns their IDs.
+async fn create_backups(manager: &BackupManager<MockFileSystem>) -> Vec<String> {
+    manager.fs.add_file(manager.workspace_root.join("package.json"), r#"{"name": "test"}"#);
+    let mut ids = Vec::new();
+    for _ in 0..3 {
+        ids.push(manager.create_backup(&[PathBuf::from("package.json")], "upgrade").await.unwrap());
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+    }
+    ids
+}
+
+#[tokio::test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "Windows path normalization issues - tracked in WOR-TSK-141"
+)]
+async fn test_create_backup_records_checksums_and_size() {
+    let manager = create_test_manager(BackupConfig::default());
+    let content = r#"{"name": "test"}"#;
+    manager.fs.add_file(manager.workspace_root.join("package.json"), content);
+
+    manager.create_backup(&[PathBuf::from("package.json")], "upgrade").await.unwrap();
+
+    let backup = &manager.list_backups().await.unwrap()[0];
+    assert_eq!(backup.size_bytes, content.len() as u64);
+    assert_eq!(
+        backup.checksums.get(&manager.workspace_root.join("package.json")),
+        Some(&checksum(content.as_bytes()))
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "Windows path normalization issues - tracked in WOR-TSK-141"
+)]
+async fn test_restore_refuses_corrupted_backup() {
+    let manager = create_test_manager(BackupConfig::default());
+    let manifest = manager.workspace_root.join("package.json");
+    manager.fs.add_file(manifest.clone(), r#"{"name": "test"}"#);
+
+    let backup_id =
+        manager.create_backup(&[PathBuf::from("package.json")], "upgrade").await.unwrap();
+
+    // Tamper with the backed-up copy, then modify the workspace file
+    let backed_up = manager.backup_path(&backup_id).join("package.json");
+    manager.fs.add_file(backed_up, r#"{"name": "tampered"}"#);
+    manager.fs.add_file(manifest.clone(), r#"{"name": "modified"}"#);
+
+    let result = manager.restore_backup(&backup_id).await;
+
+    assert!(matches!(result, Err(UpgradeError::BackupCorrupted { .. })));
+    assert!(manager.verify_backup(&backup_id).await.is_err());
+    assert_eq!(manager.fs.get_file(&manifest).unwrap(), r#"{"name": "modified"}"#);
+}
+
+#[tokio::test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "Windows path normalization issues - tracked in WOR-TSK-141"
+)]
+async fn test_prune_by_age_keeps_newest() {
+    let config =
+        BackupConfig { keep_after_success: true, max_age_days: Some(7), ..Default::default() };
+    let manager = create_test_manager(config);
+    let ids = create_backups(&manager).await;
+
+    // Age every backup beyond the limit
+    let mut collection = manager.load_metadata_collection().await.unwrap();
+    for backup in &mut collection.backups {
+        backup.created_at -= Duration::days(30);
+    }
+    manager.save_metadata_collection(&collection).await.unwrap();
+
+    let candidates = manager.prune_candidates().await.unwrap();
+    assert_eq!(candidates.len(), 2);
+    assert_eq!(manager.list_backups().await.unwrap().len(), 3);
+
+    let removed = manager.prune().await.unwrap();
+    assert_eq!(removed, vec![ids[1].clone(), ids[0].clone()]);
+
+    let remaining = manager.list_backups().await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, ids[2]);
+}
+
+#[tokio::test]
+#[cfg_attr(
+    target_os = "windows",
+    ignore = "Windows path normalization issues - tracked in WOR-TSK-141"
+)]
+async fn test_prune_by_total_size_removes_oldest() {
+    let config =
+        BackupConfig { keep_after_success: true, max_total_size_mb: Some(1), ..Default::default() };
+    let manager = create_test_manager(config);
+    let ids = create_backups(&manager).await;
+
+    // Pretend each backup is 400 KiB so only two fit into 1 MiB
+    let mut collection = manager.load_metadata_collection().await.unwrap();
+    for backup in &mut collection.backups {
+        backup.size_bytes = 400 * 1024;
+    }
+    manager.save_metadata_collection(&collection).await.unwrap();
+
+    let removed = manager.prune().await.unwrap();
+
+    assert_eq!(removed, vec![ids[0].clone()]);
+    assert_eq!(manager.list_backups().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_prune_by_total_size_keeps_oversized_newest() {
+    let config =
+        BackupConfig { keep_after_success: true, max_total_size_mb: Some(1), ..Default::default() };
+    let manager = create_test_manager(config);
+    let backups = vec![BackupMetadata {
+        id: "large".to_string(),
+        created_at: Utc::now(),
+        operation: "upgrade".to_string(),
+        files: vec![],
+        success: true,
+        created_files: vec![],
+        checksums: BTreeMap::new(),
+        size_bytes: 10 * 1024 * 1024,
+    }];
+
+    assert!(manager.select_for_removal(&backups, Utc::now()).is_empty());
+}
+
 #[tokio::test]
 async fn test_cleanup_removes_old_backups() {
     let config = BackupConfig {
@@ -566,6 +727,8 @@ async fn test_cleanup_removes_old_backups() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: true,
         max_backups: 3,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -588,6 +751,8 @@ async fn test_cleanup_priority_removes_successful_before_count() {
         backup_dir: ".workspace-backups".to_string(),
         keep_after_success: false,
         max_backups: 2,
+        max_age_days: None,
+        max_total_size_mb: None,
     };
     let manager = create_test_manager(config);
 
@@ -731,6 +896,9 @@ async fn test_backup_metadata_serialization() {
         operation: "upgrade".to_string(),
         files: vec![PathBuf::from("/workspace/package.json")],
         success: true,
+        created_files: vec![],
+        checksums: BTreeMap::new(),
+        size_bytes: 0,
     };
 
     let json = serde_json::to_string(&metadata).unwrap();
@@ -0,0 +1,229 @@
+//! Resumable upgrade detection.
+//!
+//! **What**: Provides `DetectionCheckpoint`, which records the result of each registry lookup
+//! made during upgrade detection so that an interrupted detection can resume.
+//!
+//! **How**: Results are keyed by dependency name, version spec, dependency type and the
+//! registries the dependency resolves to, and kept with the time of the lookup in a JSON file. The file is written atomically every
+//! `SAVE_INTERVAL` results and when detection ends, so a detection killed midway loses at
+//! most one interval. Loading discards results older than the maximum age, and every result
+//! of a file recorded with other detection or registry settings. Lookups that failed are not recorded
+//! and are retried. The file is removed once a detection completes without skipped or
+//! failed lookups.
+//!
+//! **Why**: Workspaces with thousands of external dependencies need thousands of registry
+//! requests. Without a checkpoint, a detection cut short by a timeout, a network failure or
+//! Ctrl-C starts over from the first dependency.
+
+use crate::upgrade::detection::detector::{DependencyToCheck, DependencyUpgrade, DetectionOptions};
+use crate::upgrade::registry::RegistryClient;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// Name of the checkpoint file kept by the upgrade manager in the backup directory.
+pub(crate) const CHECKPOINT_FILE: &str = "detection-checkpoint.json";
+
+/// Number of recorded results after which the checkpoint is written.
+const SAVE_INTERVAL: usize = 50;
+
+/// Contents of a checkpoint file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckpointFile {
+    /// Detection settings the results were recorded with.
+    settings: String,
+
+    /// Lookup results, keyed by [`DetectionCheckpoint::entry_key`].
+    entries: BTreeMap<String, CheckpointEntry>,
+}
+
+/// Result of one registry lookup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CheckpointEntry {
+    /// When the lookup was made.
+    checked_at: DateTime<Utc>,
+
+    /// The upgrade found, or `None` when the dependency was up to date.
+    upgrade: Option<DependencyUpgrade>,
+}
+
+/// Lookup results of a detection, persisted so that it can resume.
+pub(crate) struct DetectionCheckpoint<'a> {
+    /// Checkpoint file.
+    path: PathBuf,
+
+    /// Filesystem the file is read from and written to.
+    fs: &'a FileSystemManager,
+
+    /// Client resolving the registries a dependency is looked up in.
+    registry_client: &'a RegistryClient,
+
+    /// Recorded results.
+    file: CheckpointFile,
+
+    /// Number of results recorded since the file was last written.
+    unsaved: usize,
+
+    /// Number of results loaded from the file.
+    resumed: usize,
+}
+
+impl<'a> DetectionCheckpoint<'a> {
+    /// Loads the checkpoint at `path`, keeping the results younger than `max_age` that were
+    /// recorded with the same settings as `options` and the registry configuration of
+    /// `registry_client`.
+    ///
+    /// A missing or unreadable file yields an empty checkpoint.
+    pub(crate) async fn load(
+        path: PathBuf,
+        max_age: Duration,
+        options: &DetectionOptions,
+        registry_client: &'a RegistryClient,
+        fs: &'a FileSystemManager,
+    ) -> Self {
+        let settings = settings_of(options, registry_client);
+        let mut file = CheckpointFile { settings: settings.clone(), entries: BTreeMap::new() };
+
+        if fs.exists(&path).await {
+            match read_file(&path, fs).await {
+                Ok(stored) if stored.settings == settings => {
+                    let now = Utc::now();
+                    file.entries = stored
+                        .entries
+                        .into_iter()
+                        .filter(|(_, entry)| {
+                            now.signed_duration_since(entry.checked_at)
+                                .to_std()
+                                .map_or(true, |age| age <= max_age)
+                        })
+                        .collect();
+                }
+                Ok(_) => {
+                    log::debug!(
+                        "Ignoring detection checkpoint {} recorded with other settings",
+                        path.display()
+                    );
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Ignoring unreadable detection checkpoint {}: {}",
+                        path.display(),
+                        e
+                    );
+                }
+            }
+        }
+
+        let resumed = file.entries.len();
+        if resumed > 0 {
+            log::info!("Resuming upgrade detection with {} recorded lookups", resumed);
+        }
+        Self { path, fs, registry_client, file, unsaved: 0, resumed }
+    }
+
+    /// Returns the recorded result of a lookup, if any.
+    ///
+    /// The outer `Option` is `None` when the lookup was not recorded; the inner one is the
+    /// recorded result.
+    pub(crate) fn lookup(
+        &self,
+        dependency: &DependencyToCheck,
+    ) -> Option<Option<DependencyUpgrade>> {
+        self.file.entries.get(&self.entry_key(dependency)).map(|entry| entry.upgrade.clone())
+    }
+
+    /// Returns the number of results loaded from the file.
+    pub(crate) fn resumed(&self) -> usize {
+        self.resumed
+    }
+
+    /// Records the result of a lookup, writing the file every `SAVE_INTERVAL` results.
+    pub(crate) async fn record(
+        &mut self,
+        dependency: &DependencyToCheck,
+        upgrade: Option<&DependencyUpgrade>,
+    ) {
+        self.file.entries.insert(
+            self.entry_key(dependency),
+            CheckpointEntry { checked_at: Utc::now(), upgrade: upgrade.cloned() },
+        );
+        self.unsaved += 1;
+        if self.unsaved >= SAVE_INTERVAL {
+            self.save().await;
+        }
+    }
+
+    /// Writes the recorded results, if any changed since the last write.
+    ///
+    /// Failures are logged: a checkpoint that cannot be written only costs the ability to
+    /// resume.
+    pub(crate) async fn save(&mut self) {
+        if self.unsaved == 0 {
+            return;
+        }
+        match self.write().await {
+            Ok(()) => self.unsaved = 0,
+            Err(e) => {
+                log::warn!("Failed to write detection checkpoint {}: {}", self.path.display(), e);
+            }
+        }
+    }
+
+    /// Writes the file atomically, creating its directory if needed.
+    async fn write(&self) -> Result<(), String> {
+        let content = serde_json::to_string(&self.file).map_err(|e| e.to_string())?;
+        if let Some(parent) = self.path.parent() {
+            self.fs.create_dir_all(parent).await.map_err(|e| e.to_string())?;
+        }
+        self.fs.write_file_atomic(&self.path, &content).await.map_err(|e| e.to_string())
+    }
+
+    /// Returns the key of a lookup result.
+    fn entry_key(&self, dependency: &DependencyToCheck) -> String {
+        let (registry, fallback) = self.registry_client.resolve_registry_order(&dependency.name);
+        let mut key = format!(
+            "{}@{}#{}@{registry}",
+            dependency.name,
+            dependency.version_spec,
+            dependency.dependency_type.as_str()
+        );
+        if let Some(fallback) = fallback {
+            key.push_str(&format!("|{fallback}"));
+        }
+        key
+    }
+
+    /// Removes the checkpoint file once a detection completed.
+    pub(crate) async fn remove(self) {
+        if self.fs.exists(&self.path).await
+            && let Err(e) = self.fs.remove(&self.path).await
+        {
+            log::warn!("Failed to remove detection checkpoint {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Reads and parses a checkpoint file.
+async fn read_file(path: &Path, fs: &FileSystemManager) -> Result<CheckpointFile, String> {
+    let content = fs.read_file_string(path).await.map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| e.to_string())
+}
+
+/// Returns the detection and registry settings that change lookup results.
+///
+/// The registry configuration is stored as a digest so that auth tokens never reach the file.
+fn settings_of(options: &DetectionOptions, registry_client: &RegistryClient) -> String {
+    let registry = serde_json::to_vec(registry_client.config()).unwrap_or_default();
+    let digest: String =
+        Sha256::digest(&registry).iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "prereleases={};downloads={};registry={digest}",
+        options.include_prereleases, options.include_download_stats
+    )
+}
@@ -3,26 +3,38 @@
 //! **What**: Provides functionality to detect available upgrades for external npm packages
 //! by scanning package.json files and querying package registries.
 //!
-//! **How**: This module scans the workspace for package.json files, extracts external
+//! **How**: This module scans the workspace root and every workspace package found by the
+//! monorepo detector, nested ones included, for package.json files, extracts external
 //! dependencies (filtering out workspace:, file:, link:, and portal: protocols), queries
 //! npm registries concurrently for available versions, and classifies upgrades by type
 //! (major, minor, patch). It supports filtering by package name, dependency name, and
-//! dependency type.
+//! dependency type. Upgrades of dependencies with a local patch are flagged. Lookup results
+//! can be recorded in a checkpoint so that an interrupted detection resumes.
 //!
 //! **Why**: To enable developers to discover available dependency upgrades with fine-grained
 //! control over what to detect, supporting both security patches and feature updates while
 //! providing clear classification of upgrade impact.
 
 use crate::error::UpgradeError;
-use crate::types::DependencyType;
-use crate::upgrade::registry::{RegistryClient, UpgradeType};
+use crate::types::{DependencyType, NpmAlias, Warning, WarningKind};
+use crate::upgrade::application::matches_package_target;
+use crate::upgrade::detection::checkpoint::DetectionCheckpoint;
+use crate::upgrade::detection::patches::{PatchedDependency, find_patched_dependencies};
+use crate::upgrade::registry::{
+    DownloadStats, MetadataDetail, RegistryClient, RepositoryInfo, UpgradeType,
+};
 use chrono::{DateTime, Utc};
 use futures::stream::{self, StreamExt};
 use package_json::PackageJson;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
+use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait};
+
+/// Default longest age of a checkpointed lookup that is reused.
+const DEFAULT_CHECKPOINT_MAX_AGE: Duration = Duration::from_secs(86_400);
 
 /// Options for controlling upgrade detection.
 ///
@@ -75,7 +87,8 @@ pub struct DetectionOptions {
     /// Filter detection to specific package names.
     ///
     /// When set, only package.json files matching these package names will be scanned.
-    /// Package name is matched from the `name` field in package.json.
+    /// Package name is matched from the `name` field in package.json; the workspace root
+    /// manifest is also matched by [`ROOT_PACKAGE_SELECTOR`](crate::upgrade::ROOT_PACKAGE_SELECTOR).
     ///
     /// # Default: `None` (all packages)
     pub package_filter: Option<Vec<String>>,
@@ -102,6 +115,38 @@ pub struct DetectionOptions {
     ///
     /// # Default: `10`
     pub concurrency: usize,
+
+    /// Fetch weekly download statistics for each upgradable dependency.
+    ///
+    /// Download statistics require an additional request to the npm downloads API per
+    /// dependency, so they are only fetched when explicitly requested. Results are cached
+    /// by the registry client across packages.
+    ///
+    /// # Default: `false`
+    pub include_download_stats: bool,
+
+    /// File recording each registry lookup so an interrupted detection can resume.
+    ///
+    /// Lookups recorded within `checkpoint_max_age` are reused instead of being sent to the
+    /// registry again. The file is removed once a detection completes without skipped or
+    /// failed lookups. `UpgradeManager` uses `detection-checkpoint.json` in the backup
+    /// directory when this is not set and `resume` is.
+    ///
+    /// # Default: `None` (no checkpoint)
+    pub checkpoint: Option<PathBuf>,
+
+    /// Longest age of a recorded lookup that is reused.
+    ///
+    /// # Default: one day
+    pub checkpoint_max_age: Duration,
+
+    /// Record lookups in the upgrade manager's checkpoint and reuse the ones left by an
+    /// interrupted detection.
+    ///
+    /// Only read by `UpgradeManager::detect_upgrades`; `detect_upgrades` uses `checkpoint`.
+    ///
+    /// # Default: `false`
+    pub resume: bool,
 }
 
 impl Default for DetectionOptions {
@@ -115,6 +160,10 @@ impl Default for DetectionOptions {
             dependency_filter: None,
             include_prereleases: false,
             concurrency: 10,
+            include_download_stats: false,
+            checkpoint: None,
+            checkpoint_max_age: DEFAULT_CHECKPOINT_MAX_AGE,
+            resume: false,
         }
     }
 }
@@ -177,10 +226,10 @@ impl DetectionOptions {
         Self { include_dev_dependencies: true, concurrency: 10, ..Default::default() }
     }
 
-    /// Returns whether the given package name matches the filter.
-    pub(crate) fn matches_package_filter(&self, package_name: &str) -> bool {
+    /// Returns whether the given package matches the filter.
+    pub(crate) fn matches_package_filter(&self, package_name: &str, workspace_root: bool) -> bool {
         match &self.package_filter {
-            Some(filter) => filter.iter().any(|name| name == package_name),
+            Some(filter) => matches_package_target(filter, package_name, workspace_root),
             None => true,
         }
     }
@@ -221,6 +270,29 @@ pub struct UpgradePreview {
 
     /// Summary statistics.
     pub summary: UpgradeSummary,
+
+    /// Dependencies whose registry lookup was skipped in offline mode.
+    ///
+    /// Upgrades of these dependencies are unknown, so the preview may be incomplete.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub offline_skipped: Vec<String>,
+
+    /// Dependencies whose registry lookup was cut short by the global deadline.
+    ///
+    /// Only set on the partial preview carried by `UpgradeError::DeadlineExceeded`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deadline_skipped: Vec<String>,
+
+    /// Number of lookups reused from the checkpoint of an interrupted detection.
+    #[serde(default)]
+    pub resumed_lookups: usize,
+
+    /// Non-fatal issues found during detection.
+    ///
+    /// Lists each dependency whose registry lookup failed; upgrades of these dependencies
+    /// are unknown, so the preview may be incomplete.
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
 }
 
 /// Available upgrades for a single package.
@@ -251,6 +323,13 @@ pub struct PackageUpgrades {
 
     /// List of available upgrades for dependencies in this package.
     pub upgrades: Vec<DependencyUpgrade>,
+
+    /// Whether the manifest is the one at the workspace root.
+    ///
+    /// In a monorepo the root manifest holds shared tooling, such as the root-only
+    /// devDependencies of a pnpm workspace.
+    #[serde(default)]
+    pub workspace_root: bool,
 }
 
 /// Details of a single dependency upgrade.
@@ -293,6 +372,10 @@ pub struct DependencyUpgrade {
 
     /// Additional version information.
     pub version_info: VersionInfo,
+
+    /// Local patch of the dependency, which the upgrade would invalidate.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub patch: Option<PatchedDependency>,
 }
 
 /// Additional version information from registry.
@@ -327,6 +410,23 @@ pub struct VersionInfo {
 
     /// Publication date of latest version.
     pub published_at: Option<DateTime<Utc>>,
+
+    /// Deprecation notice of the currently used version (if deprecated).
+    #[serde(default)]
+    pub current_deprecated: Option<String>,
+
+    /// Deprecation notice of the upgrade target version (if deprecated).
+    #[serde(default)]
+    pub target_deprecated: Option<String>,
+
+    /// Weekly download statistics (only when requested via
+    /// [`DetectionOptions::include_download_stats`]).
+    #[serde(default)]
+    pub downloads: Option<DownloadStats>,
+
+    /// Source repository of the package, when the registry publishes one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<RepositoryInfo>,
 }
 
 /// Summary statistics for upgrades.
@@ -371,12 +471,21 @@ pub struct UpgradeSummary {
 
     /// Number of deprecated dependencies found.
     pub deprecated_dependencies: usize,
+
+    /// Number of upgrades of dependencies with a local patch.
+    #[serde(default)]
+    pub patched_upgrades: usize,
 }
 
 /// Detects available upgrades for external dependencies.
 ///
 /// This function scans the workspace for package.json files and queries registries
-/// to detect available upgrades according to the provided options.
+/// to detect available upgrades according to the provided options. Upgrades of
+/// dependencies patched with `patch-package` or pnpm carry the patch in
+/// [`DependencyUpgrade::patch`]. In offline mode, dependencies the registry client has
+/// no cached metadata for are listed in [`UpgradePreview::offline_skipped`]. With
+/// [`DetectionOptions::checkpoint`] set, lookups recorded by an interrupted detection are
+/// reused and counted in [`UpgradePreview::resumed_lookups`].
 ///
 /// # Arguments
 ///
@@ -395,6 +504,8 @@ pub struct UpgradeSummary {
 /// - Failed to read workspace files
 /// - Failed to parse package.json files
 /// - Registry queries fail (network, authentication, etc.)
+/// - The global deadline passes before every lookup finished (`DeadlineExceeded`, carrying
+///   the preview of the lookups that did)
 ///
 /// # Example
 ///
@@ -429,15 +540,9 @@ pub async fn detect_upgrades(
     // Find all package.json files
     let package_files = find_package_json_files(workspace_root, fs).await?;
 
-    // Process each package
-    let mut all_packages = Vec::new();
+    // Read every package first so registry lookups from all packages share one queue
+    let mut scanned = Vec::new();
     let mut total_dependencies = 0;
-    let mut upgrades_available = 0;
-    let mut major_upgrades = 0;
-    let mut minor_upgrades = 0;
-    let mut patch_upgrades = 0;
-    let mut deprecated_dependencies = 0;
-
     for package_json_path in package_files {
         // Read and parse package.json
         let package_json = read_package_json(&package_json_path, fs).await?;
@@ -450,7 +555,8 @@ pub async fn detect_upgrades(
         };
 
         // Check package filter
-        if !options.matches_package_filter(&package_name) {
+        let workspace_root_manifest = package_json_path.parent() == Some(workspace_root);
+        if !options.matches_package_filter(&package_name, workspace_root_manifest) {
             continue;
         }
 
@@ -458,9 +564,56 @@ pub async fn detect_upgrades(
         let dependencies = extract_dependencies(&package_json, &options);
         total_dependencies += dependencies.len();
 
-        // Detect upgrades for this package
-        let upgrades = detect_package_upgrades(&dependencies, registry_client, &options).await?;
+        scanned.push((package_json_path, package_json, package_name, dependencies));
+    }
 
+    let patched = find_patched_dependencies(workspace_root, fs).await;
+
+    let mut checkpoint = match &options.checkpoint {
+        Some(path) => Some(
+            DetectionCheckpoint::load(
+                path.clone(),
+                options.checkpoint_max_age,
+                &options,
+                registry_client,
+                fs,
+            )
+            .await,
+        ),
+        None => None,
+    };
+    let resumed_lookups = checkpoint.as_ref().map_or(0, DetectionCheckpoint::resumed);
+
+    // Detect upgrades for all packages
+    let (upgrades_per_package, skipped) = detect_workspace_upgrades(
+        scanned.iter().map(|(_, _, _, dependencies)| dependencies.as_slice()),
+        registry_client,
+        &options,
+        checkpoint.as_mut(),
+    )
+    .await;
+
+    // Keep the checkpoint until every lookup has run to completion
+    if let Some(mut checkpoint) = checkpoint {
+        if skipped.is_empty() {
+            checkpoint.remove().await;
+        } else {
+            checkpoint.save().await;
+        }
+    }
+
+    // Process each package
+    let mut all_packages = Vec::new();
+    let mut upgrades_available = 0;
+    let mut major_upgrades = 0;
+    let mut minor_upgrades = 0;
+    let mut patch_upgrades = 0;
+    let mut deprecated_dependencies = 0;
+    let mut patched_upgrades = 0;
+
+    for ((package_json_path, package_json, package_name, _), mut upgrades) in
+        scanned.into_iter().zip(upgrades_per_package)
+    {
         // Update statistics
         upgrades_available += upgrades.len();
         for upgrade in &upgrades {
@@ -473,6 +626,12 @@ pub async fn detect_upgrades(
                 deprecated_dependencies += 1;
             }
         }
+        for upgrade in &mut upgrades {
+            upgrade.patch = patched.iter().find(|patch| patch.name == upgrade.name).cloned();
+            if upgrade.patch.is_some() {
+                patched_upgrades += 1;
+            }
+        }
 
         // Add to results if there are upgrades or we're including all packages
         if !upgrades.is_empty() {
@@ -486,6 +645,7 @@ pub async fn detect_upgrades(
                 .to_path_buf();
 
             all_packages.push(PackageUpgrades {
+                workspace_root: package_path == workspace_root,
                 package_name,
                 package_path,
                 current_version: if package_json.version.is_empty() {
@@ -506,12 +666,33 @@ pub async fn detect_upgrades(
         minor_upgrades,
         patch_upgrades,
         deprecated_dependencies,
+        patched_upgrades,
     };
 
-    Ok(UpgradePreview { detected_at, packages: all_packages, summary })
+    let preview = UpgradePreview {
+        detected_at,
+        packages: all_packages,
+        summary,
+        offline_skipped: skipped.offline,
+        deadline_skipped: skipped.deadline,
+        resumed_lookups,
+        warnings: skipped.failed,
+    };
+    if preview.deadline_skipped.is_empty() {
+        Ok(preview)
+    } else {
+        Err(UpgradeError::DeadlineExceeded {
+            operation: "upgrade detection".to_string(),
+            partial: Some(Box::new(preview)),
+        })
+    }
 }
 
 /// Finds all package.json files in the workspace.
+///
+/// The root manifest comes first, followed by the manifest of every workspace package the
+/// monorepo detector finds, nested packages included. A workspace that is not a monorepo
+/// has only its root manifest.
 pub(crate) async fn find_package_json_files(
     workspace_root: &Path,
     fs: &FileSystemManager,
@@ -524,14 +705,14 @@ pub(crate) async fn find_package_json_files(
         package_files.push(root_package_json);
     }
 
-    // Look for packages in common monorepo locations
-    let common_patterns = vec!["packages/*/package.json", "apps/*/package.json"];
-
-    for pattern in common_patterns {
-        let _pattern_path = workspace_root.join(pattern);
-        // This is a simplified implementation - in a real scenario,
-        // we'd use glob patterns or the monorepo detector from standard_tools
-        // For now, we'll just check the root package.json
+    let detector = MonorepoDetector::with_filesystem(fs.clone());
+    if let Ok(monorepo) = detector.detect_monorepo(workspace_root).await {
+        for package in monorepo.packages() {
+            let package_json = package.absolute_path.join("package.json");
+            if !package_files.contains(&package_json) && fs.exists(&package_json).await {
+                package_files.push(package_json);
+            }
+        }
     }
 
     if package_files.is_empty() {
@@ -645,34 +826,88 @@ pub(crate) fn is_internal_dependency(version_spec: &str) -> bool {
         || version_spec.starts_with("portal:")
 }
 
-/// Detects upgrades for a package's dependencies.
-async fn detect_package_upgrades(
-    dependencies: &[DependencyToCheck],
+/// Dependencies whose registry lookup did not run to completion.
+#[derive(Debug, Default)]
+struct SkippedLookups {
+    /// Skipped in offline mode.
+    offline: Vec<String>,
+    /// Cut short by the global deadline.
+    deadline: Vec<String>,
+    /// Lookups that failed with another error.
+    failed: Vec<Warning>,
+}
+
+impl SkippedLookups {
+    /// Returns `true` if every lookup ran to completion.
+    fn is_empty(&self) -> bool {
+        self.offline.is_empty() && self.deadline.is_empty() && self.failed.is_empty()
+    }
+}
+
+/// Detects upgrades for the dependencies of several packages.
+///
+/// Lookups from all packages go through one queue limited to `options.concurrency`, so a
+/// package with many dependencies does not hold back the others. Lookups recorded in the
+/// checkpoint are reused, and completed lookups are recorded in it. Returns the upgrades of
+/// each package, in the order the packages were given, the sorted names of the
+/// dependencies skipped in offline mode or by the deadline, and a warning per failed lookup.
+async fn detect_workspace_upgrades<'a>(
+    packages: impl Iterator<Item = &'a [DependencyToCheck]>,
     registry_client: &RegistryClient,
     options: &DetectionOptions,
-) -> Result<Vec<DependencyUpgrade>, UpgradeError> {
+    mut checkpoint: Option<&mut DetectionCheckpoint<'_>>,
+) -> (Vec<Vec<DependencyUpgrade>>, SkippedLookups) {
+    let mut per_package = Vec::new();
+    let mut lookups = Vec::new();
+    for (index, dependencies) in packages.enumerate() {
+        per_package.push(Vec::new());
+        for dependency in dependencies {
+            match checkpoint.as_deref().and_then(|checkpoint| checkpoint.lookup(dependency)) {
+                Some(recorded) => per_package[index].extend(recorded),
+                None => lookups.push((index, dependency)),
+            }
+        }
+    }
+
     // Query registry concurrently with controlled concurrency
-    let upgrades = stream::iter(dependencies)
-        .map(|dep| async move { detect_single_upgrade(dep, registry_client, options).await })
-        .buffer_unordered(options.concurrency)
-        .collect::<Vec<_>>()
-        .await;
+    let mut results = stream::iter(lookups)
+        .map(|(index, dep)| async move {
+            (index, dep, detect_single_upgrade(dep, registry_client, options).await)
+        })
+        .buffer_unordered(options.concurrency.max(1));
 
     // Filter out errors and None results
-    let mut valid_upgrades = Vec::new();
-    for result in upgrades {
+    let mut skipped = SkippedLookups::default();
+    while let Some((index, dep, result)) = results.next().await {
         match result {
-            Ok(Some(upgrade)) => valid_upgrades.push(upgrade),
-            Ok(None) => {}
+            Ok(upgrade) => {
+                if let Some(checkpoint) = checkpoint.as_deref_mut() {
+                    checkpoint.record(dep, upgrade.as_ref()).await;
+                }
+                per_package[index].extend(upgrade);
+            }
+            Err(UpgradeError::OfflineSkipped { package }) => skipped.offline.push(package),
+            Err(UpgradeError::DeadlineExceeded { .. }) => skipped.deadline.push(dep.name.clone()),
             Err(e) => {
-                // Log error but continue with other dependencies
-                // In production, we might want to collect these errors
-                eprintln!("Warning: Failed to check upgrade: {}", e);
+                log::warn!("Failed to check upgrade of {}: {}", dep.name, e);
+                skipped.failed.push(
+                    Warning::new(
+                        WarningKind::RegistryLookupFailed,
+                        format!("Failed to check for upgrades of {}: {}", dep.name, e),
+                    )
+                    .with_package(dep.name.clone()),
+                );
             }
         }
     }
 
-    Ok(valid_upgrades)
+    for names in [&mut skipped.offline, &mut skipped.deadline] {
+        names.sort();
+        names.dedup();
+    }
+    skipped.failed.sort_by(|a, b| a.message.cmp(&b.message));
+    skipped.failed.dedup();
+    (per_package, skipped)
 }
 
 /// Detects upgrade for a single dependency.
@@ -681,11 +916,22 @@ async fn detect_single_upgrade(
     registry_client: &RegistryClient,
     options: &DetectionOptions,
 ) -> Result<Option<DependencyUpgrade>, UpgradeError> {
-    // Get package metadata from registry
-    let metadata = registry_client.get_package_info(&dependency.name).await?;
+    // npm aliases (`"foo": "npm:@scope/foo@^1"`) are looked up under their target package
+    let alias = NpmAlias::parse(&dependency.version_spec);
+    let registry_name = alias.as_ref().map_or(dependency.name.as_str(), |a| a.name.as_str());
+    let version_spec = match &alias {
+        Some(NpmAlias { range: Some(range), .. }) => range.as_str(),
+        // An alias without a range always installs the latest release
+        Some(NpmAlias { range: None, .. }) => return Ok(None),
+        None => dependency.version_spec.as_str(),
+    };
+
+    // Versions and dist-tags are enough to decide whether an upgrade exists
+    let metadata =
+        registry_client.get_package_metadata(registry_name, MetadataDetail::Abbreviated).await?;
 
     // Extract current version from version spec
-    let current_version = extract_version_from_spec(&dependency.version_spec)?;
+    let current_version = extract_version_from_spec(version_spec)?;
 
     // Determine latest version
     let latest_version = if options.include_prereleases {
@@ -712,15 +958,29 @@ async fn detect_single_upgrade(
         return Ok(None);
     }
 
+    // Publish times are only part of the full document
+    let metadata = if registry_client.uses_abbreviated_metadata() {
+        registry_client.get_package_info(registry_name).await?
+    } else {
+        metadata
+    };
+
     // Determine upgrade type
     let upgrade_type =
-        registry_client.compare_versions(&dependency.name, &current_version, &latest_version)?;
+        registry_client.compare_versions(registry_name, &current_version, &latest_version)?;
 
     // Find latest prerelease
     let latest_prerelease = find_latest_prerelease(&metadata.versions);
 
     // Get registry URL
-    let registry_url = registry_client.resolve_registry_url(&dependency.name);
+    let registry_url = registry_client.resolve_registry_url(registry_name);
+
+    // Download statistics are fetched lazily, only for dependencies with an upgrade
+    let downloads = if options.include_download_stats {
+        registry_client.get_download_stats(registry_name).await
+    } else {
+        None
+    };
 
     // Build version info
     let version_info = VersionInfo {
@@ -729,6 +989,10 @@ async fn detect_single_upgrade(
         latest_prerelease,
         deprecated: metadata.deprecated.clone(),
         published_at: metadata.version_published_at(&latest_version),
+        current_deprecated: metadata.version_deprecation(&current_version).map(str::to_string),
+        target_deprecated: metadata.version_deprecation(&latest_version).map(str::to_string),
+        downloads,
+        repository: metadata.repository.clone(),
     };
 
     Ok(Some(DependencyUpgrade {
@@ -739,6 +1003,7 @@ async fn detect_single_upgrade(
         dependency_type: dependency.dependency_type,
         registry_url,
         version_info,
+        patch: None,
     }))
 }
 
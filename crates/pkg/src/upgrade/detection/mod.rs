@@ -12,13 +12,18 @@
 //! control over what to detect, supporting both security patches and feature updates while
 //! providing clear classification of upgrade impact.
 
+mod checkpoint;
 mod detector;
+mod patches;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
+pub(crate) use checkpoint::CHECKPOINT_FILE;
 pub use detector::{
     DependencyUpgrade, DetectionOptions, PackageUpgrades, UpgradePreview, UpgradeSummary,
     VersionInfo, detect_upgrades,
 };
+pub(crate) use detector::{find_package_json_files, read_package_json};
+pub use patches::{PatchSource, PatchedDependency};
@@ -0,0 +1,140 @@
+//! Detection of dependencies carrying a local patch.
+//!
+//! **What**: Finds the dependencies the workspace patches locally, with `patch-package`
+//! (`patches/*.patch`) or pnpm (`pnpm.patchedDependencies` in the root package.json).
+//!
+//! **How**: `patch-package` names each patch file after the package and the version it was
+//! made against (`@scope+name+1.2.3.patch`), so the patched package is read from the file name.
+//! pnpm lists its patches explicitly, keyed by `name@version`.
+//!
+//! **Why**: A patch is made against one version of a package. Upgrading the package leaves
+//! the patch failing to apply or silently dropped, so upgrades of patched dependencies are
+//! flagged and can be held back.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Directory `patch-package` reads patches from, relative to the workspace root.
+pub(crate) const PATCH_PACKAGE_DIR: &str = "patches";
+
+/// Tool that applies a local patch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PatchSource {
+    /// A `patches/*.patch` file applied by `patch-package`.
+    PatchPackage,
+    /// An entry of `pnpm.patchedDependencies` in the root package.json.
+    Pnpm,
+}
+
+/// A dependency with a local patch.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::{PatchSource, PatchedDependency};
+/// use std::path::PathBuf;
+///
+/// let patch = PatchedDependency {
+///     name: "lodash".to_string(),
+///     version: Some("4.17.21".to_string()),
+///     patch_file: PathBuf::from("patches/lodash+4.17.21.patch"),
+///     source: PatchSource::PatchPackage,
+/// };
+///
+/// assert_eq!(patch.name, "lodash");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PatchedDependency {
+    /// Name of the patched package.
+    pub name: String,
+
+    /// Version the patch was made against, when the patch names one.
+    pub version: Option<String>,
+
+    /// Patch file, relative to the workspace root.
+    pub patch_file: PathBuf,
+
+    /// Tool that applies the patch.
+    pub source: PatchSource,
+}
+
+/// Finds the dependencies patched in the workspace.
+///
+/// Missing or unreadable patch locations are treated as having no patches.
+pub(crate) async fn find_patched_dependencies<F: AsyncFileSystem>(
+    workspace_root: &Path,
+    fs: &F,
+) -> Vec<PatchedDependency> {
+    let mut patched = Vec::new();
+
+    let patch_dir = workspace_root.join(PATCH_PACKAGE_DIR);
+    if fs.exists(&patch_dir).await
+        && let Ok(entries) = fs.read_dir(&patch_dir).await
+    {
+        for entry in entries {
+            let Some(file_name) = entry.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if let Some((name, version)) = parse_patch_file_name(file_name) {
+                patched.push(PatchedDependency {
+                    name,
+                    version,
+                    patch_file: Path::new(PATCH_PACKAGE_DIR).join(file_name),
+                    source: PatchSource::PatchPackage,
+                });
+            }
+        }
+    }
+
+    let manifest = workspace_root.join("package.json");
+    if let Ok(content) = fs.read_file_string(&manifest).await
+        && let Ok(json) = serde_json::from_str::<serde_json::Value>(&content)
+        && let Some(entries) =
+            json.pointer("/pnpm/patchedDependencies").and_then(serde_json::Value::as_object)
+    {
+        for (key, file) in entries {
+            let (name, version) = split_package_key(key);
+            patched.push(PatchedDependency {
+                name,
+                version,
+                patch_file: PathBuf::from(file.as_str().unwrap_or_default()),
+                source: PatchSource::Pnpm,
+            });
+        }
+    }
+
+    patched.sort_by(|a, b| a.patch_file.cmp(&b.patch_file));
+    patched
+}
+
+/// Reads the package name and version from a `patch-package` file name.
+///
+/// Handles scoped packages (`@scope+name+1.0.0.patch`), nested dependencies
+/// (`parent++child+1.0.0.patch`, which patch `child`), and dev-only patches
+/// (`name+1.0.0.dev.patch`). Returns `None` for files that are not patches.
+pub(crate) fn parse_patch_file_name(file_name: &str) -> Option<(String, Option<String>)> {
+    let stem = file_name.strip_suffix(".patch")?;
+    let stem = stem.strip_suffix(".dev").unwrap_or(stem);
+    let package = stem.rsplit("++").next()?;
+
+    let mut parts = package.split('+');
+    let first = parts.next().filter(|part| !part.is_empty())?;
+    let name = if first.starts_with('@') {
+        format!("{first}/{}", parts.next().filter(|part| !part.is_empty())?)
+    } else {
+        first.to_string()
+    };
+    let version = parts.next().filter(|part| !part.is_empty()).map(str::to_string);
+
+    Some((name, version))
+}
+
+/// Splits a `name@version` key, keeping the `@` of scoped names.
+fn split_package_key(key: &str) -> (String, Option<String>) {
+    match key.rfind('@') {
+        Some(index) if index > 0 => (key[..index].to_string(), Some(key[index + 1..].to_string())),
+        _ => (key.to_string(), None),
+    }
+}
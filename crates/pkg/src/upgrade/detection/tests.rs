@@ -12,14 +12,17 @@
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::field_reassign_with_default)]
 
+use super::checkpoint::DetectionCheckpoint;
+use super::detector::DependencyToCheck;
 use super::detector::{
     extract_dependencies, extract_version_from_spec, find_latest_prerelease, find_latest_version,
     find_package_json_files, is_internal_dependency, read_package_json,
 };
+use super::patches::{find_patched_dependencies, parse_patch_file_name};
 use super::*;
 use crate::error::UpgradeError;
 use crate::types::DependencyType;
-use crate::upgrade::registry::UpgradeType;
+use crate::upgrade::registry::{RegistryClient, UpgradeType};
 use chrono::Utc;
 use package_json::PackageJson;
 use std::collections::HashMap;
@@ -190,13 +193,22 @@ fn test_detection_options_package_filtering() {
     let mut options = DetectionOptions::default();
     options.package_filter = Some(vec!["my-package".to_string(), "other-package".to_string()]);
 
-    assert!(options.matches_package_filter("my-package"));
-    assert!(options.matches_package_filter("other-package"));
-    assert!(!options.matches_package_filter("unknown-package"));
+    assert!(options.matches_package_filter("my-package", false));
+    assert!(options.matches_package_filter("other-package", false));
+    assert!(!options.matches_package_filter("unknown-package", false));
 
     // No filter means match all
     let options_no_filter = DetectionOptions::default();
-    assert!(options_no_filter.matches_package_filter("any-package"));
+    assert!(options_no_filter.matches_package_filter("any-package", false));
+}
+
+#[test]
+fn test_detection_options_package_filtering_root_selector() {
+    let mut options = DetectionOptions::default();
+    options.package_filter = Some(vec![crate::upgrade::ROOT_PACKAGE_SELECTOR.to_string()]);
+
+    assert!(options.matches_package_filter("monorepo", true));
+    assert!(!options.matches_package_filter("leaf", false));
 }
 
 #[test]
@@ -381,6 +393,34 @@ async fn test_find_package_json_files_single_package() {
     assert_eq!(files[0], package_json_path);
 }
 
+#[tokio::test]
+async fn test_find_package_json_files_nested_workspace() {
+    let temp_dir = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+    let root_json = temp_dir.path().join("package.json");
+    let nested_json = temp_dir.path().join("packages/group/core/package.json");
+
+    fs.write_file_string(
+        &root_json,
+        r#"{"name": "monorepo", "private": true, "workspaces": ["packages/*/*"]}"#,
+    )
+    .await
+    .unwrap();
+    fs.write_file_string(
+        &temp_dir.path().join("pnpm-workspace.yaml"),
+        "packages:\n  - 'packages/*/*'\n",
+    )
+    .await
+    .unwrap();
+    fs.create_dir_all(nested_json.parent().unwrap()).await.unwrap();
+    create_test_package_json(&fs, &nested_json, "@group/core", "1.0.0", None, None).await.unwrap();
+
+    let files = find_package_json_files(temp_dir.path(), &fs).await.unwrap();
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0], root_json);
+    assert!(files.iter().any(|file| file.ends_with("packages/group/core/package.json")));
+}
+
 #[tokio::test]
 async fn test_find_package_json_files_no_package() {
     let temp_dir = TempDir::new().unwrap();
@@ -404,7 +444,12 @@ fn test_upgrade_preview_serialization() {
             minor_upgrades: 1,
             patch_upgrades: 1,
             deprecated_dependencies: 0,
+            patched_upgrades: 0,
         },
+        offline_skipped: vec![],
+        deadline_skipped: vec![],
+        resumed_lookups: 0,
+        warnings: vec![],
     };
 
     let json = serde_json::to_string(&preview);
@@ -429,7 +474,12 @@ fn test_dependency_upgrade_serialization() {
             latest_prerelease: None,
             deprecated: None,
             published_at: None,
+            current_deprecated: None,
+            target_deprecated: None,
+            downloads: None,
+            repository: None,
         },
+        patch: None,
     };
 
     let json = serde_json::to_string(&upgrade);
@@ -455,3 +505,239 @@ fn test_extract_version_from_spec_edge_cases() {
     assert_eq!(extract_version_from_spec("0.0.1").unwrap(), "0.0.1");
     assert_eq!(extract_version_from_spec("1.0.0-rc.1").unwrap(), "1.0.0-rc.1");
 }
+
+#[test]
+fn test_parse_patch_file_name() {
+    assert_eq!(
+        parse_patch_file_name("lodash+4.17.21.patch"),
+        Some(("lodash".to_string(), Some("4.17.21".to_string())))
+    );
+    assert_eq!(
+        parse_patch_file_name("@babel+core+7.24.0.patch"),
+        Some(("@babel/core".to_string(), Some("7.24.0".to_string())))
+    );
+    assert_eq!(
+        parse_patch_file_name("react-scripts++webpack+5.90.0.patch"),
+        Some(("webpack".to_string(), Some("5.90.0".to_string())))
+    );
+    assert_eq!(
+        parse_patch_file_name("jest+29.7.0.dev.patch"),
+        Some(("jest".to_string(), Some("29.7.0".to_string())))
+    );
+    assert_eq!(parse_patch_file_name("README.md"), None);
+    assert_eq!(parse_patch_file_name(".patch"), None);
+}
+
+#[tokio::test]
+async fn test_find_patched_dependencies() {
+    let temp = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+    let root = temp.path();
+
+    fs.create_dir_all(&root.join("patches")).await.unwrap();
+    fs.write_file_string(&root.join("patches/lodash+4.17.21.patch"), "diff").await.unwrap();
+    fs.write_file_string(&root.join("patches/notes.txt"), "notes").await.unwrap();
+    fs.write_file_string(
+        &root.join("package.json"),
+        r#"{
+            "name": "root",
+            "pnpm": {
+                "patchedDependencies": {
+                    "@scope/pkg@1.2.3": "patches/@scope__pkg@1.2.3.patch"
+                }
+            }
+        }"#,
+    )
+    .await
+    .unwrap();
+
+    let patched = find_patched_dependencies(root, &fs).await;
+
+    assert_eq!(patched.len(), 2);
+    let pnpm = patched.iter().find(|patch| patch.source == PatchSource::Pnpm).unwrap();
+    assert_eq!(pnpm.name, "@scope/pkg");
+    assert_eq!(pnpm.version.as_deref(), Some("1.2.3"));
+    let patch_package =
+        patched.iter().find(|patch| patch.source == PatchSource::PatchPackage).unwrap();
+    assert_eq!(patch_package.name, "lodash");
+    assert_eq!(patch_package.patch_file, Path::new("patches").join("lodash+4.17.21.patch"));
+}
+
+#[tokio::test]
+async fn test_find_patched_dependencies_without_patches() {
+    let temp = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+
+    assert!(find_patched_dependencies(temp.path(), &fs).await.is_empty());
+}
+
+/// Mocks a registry document with a single `latest` version.
+async fn mock_registry_package(
+    registry: &mut mockito::Server,
+    name: &str,
+    latest: &str,
+) -> mockito::Mock {
+    registry
+        .mock("GET", format!("/{name}").as_str())
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "name": name,
+                "versions": { latest: {} },
+                "dist-tags": { "latest": latest },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+async fn checkpoint_test_client(workspace: &Path, registry: &mockito::Server) -> RegistryClient {
+    let mut config = crate::config::RegistryConfig::default();
+    config.read_npmrc = false;
+    config.retry_attempts = 0;
+    config.default_registry = registry.url();
+    RegistryClient::new(workspace, config).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_detect_upgrades_resumes_from_checkpoint() {
+    let mut registry = mockito::Server::new_async().await;
+    let express = mock_registry_package(&mut registry, "express", "4.18.2").await;
+    let failing = registry.mock("GET", "/lodash").with_status(500).create_async().await;
+
+    let temp = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+    fs.write_file_string(
+        &temp.path().join("package.json"),
+        r#"{ "name": "app", "version": "1.0.0",
+             "dependencies": { "express": "^4.17.0", "lodash": "^4.17.20" } }"#,
+    )
+    .await
+    .unwrap();
+    let checkpoint = temp.path().join(".workspace-backups/detection-checkpoint.json");
+    let options =
+        DetectionOptions { checkpoint: Some(checkpoint.clone()), ..DetectionOptions::all() };
+
+    // The failed lookup keeps the checkpoint with the lookups that succeeded
+    let client = checkpoint_test_client(temp.path(), &registry).await;
+    let first = detect_upgrades(temp.path(), &client, &fs, options.clone()).await.unwrap();
+    assert_eq!(first.resumed_lookups, 0);
+    assert_eq!(first.summary.upgrades_available, 1);
+    assert!(checkpoint.exists());
+
+    failing.remove_async().await;
+    mock_registry_package(&mut registry, "lodash", "4.17.21").await;
+    let express_hits = express.expect(2);
+
+    // A new client has no cached documents, so express is answered by the checkpoint
+    let client = checkpoint_test_client(temp.path(), &registry).await;
+    let second = detect_upgrades(temp.path(), &client, &fs, options).await.unwrap();
+    assert_eq!(second.resumed_lookups, 1);
+    assert_eq!(second.summary.upgrades_available, 2);
+    assert!(!checkpoint.exists());
+    express_hits.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_detect_upgrades_ignores_stale_checkpoint() {
+    let mut registry = mockito::Server::new_async().await;
+    mock_registry_package(&mut registry, "express", "4.18.2").await;
+
+    let temp = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+    fs.write_file_string(
+        &temp.path().join("package.json"),
+        r#"{ "name": "app", "version": "1.0.0", "dependencies": { "express": "^4.17.0" } }"#,
+    )
+    .await
+    .unwrap();
+    let checkpoint = temp.path().join("detection-checkpoint.json");
+    let options =
+        DetectionOptions { checkpoint: Some(checkpoint.clone()), ..DetectionOptions::all() };
+    let client = checkpoint_test_client(temp.path(), &registry).await;
+    record_up_to_date(&checkpoint, &options, &client, &fs, "express", "^4.17.0").await;
+
+    // Backdate the recorded lookup past the maximum age
+    let mut recorded: serde_json::Value =
+        serde_json::from_str(&fs.read_file_string(&checkpoint).await.unwrap()).unwrap();
+    for entry in recorded["entries"].as_object_mut().unwrap().values_mut() {
+        entry["checkedAt"] = (Utc::now() - chrono::Duration::days(2)).to_rfc3339().into();
+    }
+    fs.write_file_string(&checkpoint, &recorded.to_string()).await.unwrap();
+
+    let preview = detect_upgrades(temp.path(), &client, &fs, options).await.unwrap();
+
+    assert_eq!(preview.resumed_lookups, 0);
+    assert_eq!(preview.summary.upgrades_available, 1);
+    assert!(!checkpoint.exists());
+}
+
+/// Records an up-to-date lookup of a regular dependency in the checkpoint file.
+async fn record_up_to_date(
+    path: &Path,
+    options: &DetectionOptions,
+    client: &RegistryClient,
+    fs: &FileSystemManager,
+    name: &str,
+    version_spec: &str,
+) {
+    let dependency = DependencyToCheck {
+        name: name.to_string(),
+        version_spec: version_spec.to_string(),
+        dependency_type: DependencyType::Regular,
+    };
+    let mut checkpoint = DetectionCheckpoint::load(
+        path.to_path_buf(),
+        options.checkpoint_max_age,
+        options,
+        client,
+        fs,
+    )
+    .await;
+    checkpoint.record(&dependency, None).await;
+    checkpoint.save().await;
+}
+
+#[tokio::test]
+async fn test_checkpoint_is_not_reused_with_another_registry() {
+    let registry = mockito::Server::new_async().await;
+    let other_registry = mockito::Server::new_async().await;
+
+    let temp = TempDir::new().unwrap();
+    let fs = FileSystemManager::new();
+    let checkpoint = temp.path().join("detection-checkpoint.json");
+    let options =
+        DetectionOptions { checkpoint: Some(checkpoint.clone()), ..DetectionOptions::all() };
+    let dependency = DependencyToCheck {
+        name: "express".to_string(),
+        version_spec: "^4.17.0".to_string(),
+        dependency_type: DependencyType::Regular,
+    };
+
+    let client = checkpoint_test_client(temp.path(), &registry).await;
+    record_up_to_date(&checkpoint, &options, &client, &fs, "express", "^4.17.0").await;
+    let same = DetectionCheckpoint::load(
+        checkpoint.clone(),
+        options.checkpoint_max_age,
+        &options,
+        &client,
+        &fs,
+    )
+    .await;
+    assert_eq!(same.resumed(), 1);
+    assert!(same.lookup(&dependency).is_some());
+
+    // A lookup answered by one registry says nothing about another
+    let other_client = checkpoint_test_client(temp.path(), &other_registry).await;
+    let other = DetectionCheckpoint::load(
+        checkpoint,
+        options.checkpoint_max_age,
+        &options,
+        &other_client,
+        &fs,
+    )
+    .await;
+    assert_eq!(other.resumed(), 0);
+    assert!(other.lookup(&dependency).is_none());
+}
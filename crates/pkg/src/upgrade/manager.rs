@@ -13,14 +13,25 @@
 //! with minimal code while benefiting from automatic backups, rollback, and changeset integration.
 
 use crate::changeset::ChangesetManager;
-use crate::config::{PackageToolsConfig, UpgradeConfig};
+use crate::config::{ChangesetConfig, PackageToolsConfig, UpgradeConfig};
 use crate::error::{UpgradeError, UpgradeResult};
-use crate::upgrade::application::{apply_upgrades, apply_with_changeset};
-use crate::upgrade::backup::BackupManager;
-use crate::upgrade::detection::{DetectionOptions, UpgradePreview, detect_upgrades};
+use crate::types::{Warning, WarningKind};
+use crate::upgrade::application::{
+    UpgradeAbortSignal, UpgradeProgressEvent, UpgradeProgressListener,
+    apply_upgrades_with_progress, attach_changeset, create_changeset_for_upgrades, verify_upgrades,
+};
+use crate::upgrade::detection::{
+    CHECKPOINT_FILE, DetectionOptions, UpgradePreview, detect_upgrades,
+};
 use crate::upgrade::registry::RegistryClient;
+use crate::upgrade::toolchain::{
+    ToolchainPreview, ToolchainResult, ToolchainUpgrade, apply_toolchain_upgrades,
+    detect_toolchain_upgrades,
+};
 use crate::upgrade::{UpgradeResult as UpgradeResultType, UpgradeSelection};
+use crate::workspace::{OperationKind, TransactionLog};
 use std::path::PathBuf;
+use std::time::Duration;
 use sublime_standard_tools::filesystem::FileSystemManager;
 
 /// High-level manager for dependency upgrade operations.
@@ -61,8 +72,9 @@ use sublime_standard_tools::filesystem::FileSystemManager;
 pub struct UpgradeManager {
     workspace_root: PathBuf,
     config: UpgradeConfig,
+    changeset_config: ChangesetConfig,
     registry_client: RegistryClient,
-    backup_manager: BackupManager<FileSystemManager>,
+    transaction_log: TransactionLog<FileSystemManager>,
     fs: FileSystemManager,
     last_backup_id: Option<String>,
 }
@@ -109,26 +121,58 @@ impl UpgradeManager {
         // Initialize registry client
         let registry_client = RegistryClient::new(&workspace_root, config.registry.clone()).await?;
 
-        // Initialize backup manager
-        let backup_manager =
-            BackupManager::new(workspace_root.clone(), config.backup.clone(), fs.clone());
+        // Initialize transaction log used for backups
+        let transaction_log =
+            TransactionLog::new(workspace_root.clone(), config.backup.clone(), fs.clone());
 
         Ok(Self {
             workspace_root,
             config,
+            changeset_config: ChangesetConfig::default(),
             registry_client,
-            backup_manager,
+            transaction_log,
             fs,
             last_backup_id: None,
         })
     }
 
+    /// Sets the changeset configuration used for automatic changeset creation.
+    ///
+    /// Without it, automatic changesets use `ChangesetConfig::default()`, which may not
+    /// match the workspace's changeset directory or author tracking settings.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::upgrade::UpgradeManager;
+    /// use sublime_pkg_tools::config::PackageToolsConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example(config: PackageToolsConfig) -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = UpgradeManager::new(PathBuf::from("."), config.upgrade.clone())
+    ///     .await?
+    ///     .with_changeset_config(config.changeset.clone());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_changeset_config(mut self, changeset_config: ChangesetConfig) -> Self {
+        self.changeset_config = changeset_config;
+        self
+    }
+
     /// Detects available upgrades for dependencies in the workspace.
     ///
     /// Scans the workspace for package.json files, extracts external dependencies,
     /// and queries the configured registries to find available upgrades. The detection
     /// can be controlled using `DetectionOptions` to filter what dependencies to check.
     ///
+    /// With `DetectionOptions::resume` set and no checkpoint named by the options, lookups are
+    /// recorded in `detection-checkpoint.json` in the backup directory, so a detection
+    /// interrupted by a timeout, a network failure or Ctrl-C resumes where it stopped when
+    /// run again with `resume`. Recorded lookups are reused for
+    /// `upgrade.detection_checkpoint_max_age_secs`; `0` disables the checkpoint.
+    ///
     /// # Arguments
     ///
     /// * `options` - Detection options controlling which dependencies to check
@@ -164,19 +208,34 @@ impl UpgradeManager {
     /// ```
     pub async fn detect_upgrades(
         &self,
-        options: DetectionOptions,
+        mut options: DetectionOptions,
     ) -> UpgradeResult<UpgradePreview> {
+        if options.resume
+            && options.checkpoint.is_none()
+            && self.config.detection_checkpoint_max_age_secs > 0
+        {
+            options.checkpoint = Some(
+                self.workspace_root.join(&self.config.backup.backup_dir).join(CHECKPOINT_FILE),
+            );
+            options.checkpoint_max_age =
+                Duration::from_secs(self.config.detection_checkpoint_max_age_secs);
+        }
         detect_upgrades(&self.workspace_root, &self.registry_client, &self.fs, options).await
     }
 
     /// Applies selected upgrades to package.json files.
     ///
     /// This is the main method for applying dependency upgrades. It:
-    /// 1. Creates automatic backups (if configured)
-    /// 2. Applies the selected upgrades to package.json files
-    /// 3. Creates or updates a changeset (if configured)
-    /// 4. Cleans up backups on success (if configured)
-    /// 5. Automatically rolls back on failure
+    /// 1. Holds back upgrades of patched dependencies, as `upgrade.patched_dependencies`
+    ///    and `UpgradeSelection::include_patched` decide
+    /// 2. Creates automatic backups (if configured)
+    /// 3. Applies the selected upgrades to package.json files
+    /// 4. Runs the verification command from `upgrade.verify` (if configured)
+    /// 5. Creates or updates a changeset (if configured), with a bump chosen per upgrade
+    ///    type and a revision listing the upgraded dependencies
+    /// 6. Cleans up backups on success (if configured)
+    /// 7. Automatically rolls back on failure, including a failed verification under the
+    ///    `rollback` policy and a failed changeset creation
     ///
     /// The upgrade selection can be controlled using `UpgradeSelection` to filter which
     /// upgrades to apply (by type, package, dependency, etc.). Dry-run mode allows
@@ -199,6 +258,7 @@ impl UpgradeManager {
     /// - Files cannot be read or written
     /// - Changeset creation fails
     /// - JSON parsing fails
+    /// - The verification command fails and the failure policy is `rollback`
     ///
     /// On error, any changes are automatically rolled back from the backup.
     ///
@@ -228,34 +288,117 @@ impl UpgradeManager {
         &mut self,
         selection: UpgradeSelection,
         dry_run: bool,
+    ) -> UpgradeResult<UpgradeResultType> {
+        self.apply_upgrades_with_progress(
+            selection,
+            dry_run,
+            &|_: &UpgradeProgressEvent| {},
+            &UpgradeAbortSignal::new(),
+        )
+        .await
+    }
+
+    /// Applies upgrades like [`apply_upgrades`](Self::apply_upgrades), reporting progress
+    /// and honouring an abort signal.
+    ///
+    /// `listener` receives a started and a finished (or failed) event for every package.
+    /// Raising `abort` stops the application before the next package; the backup taken for
+    /// the operation is then restored and `UpgradeError::Aborted` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - Selection criteria for filtering which upgrades to apply
+    /// * `dry_run` - If true, preview changes without modifying files
+    /// * `listener` - Receives progress events
+    /// * `abort` - Signal that cancels the operation between packages
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `apply_upgrades`, plus `UpgradeError::Aborted` when the
+    /// abort signal is raised.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::upgrade::{UpgradeAbortSignal, UpgradeProgressEvent, UpgradeSelection};
+    ///
+    /// # async fn example(mut manager: sublime_pkg_tools::upgrade::UpgradeManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let abort = UpgradeAbortSignal::new();
+    /// let cancel = abort.clone(); // hand to the UI
+    ///
+    /// let result = manager
+    ///     .apply_upgrades_with_progress(
+    ///         UpgradeSelection::all(),
+    ///         false,
+    ///         &|event: &UpgradeProgressEvent| println!("{event:?}"),
+    ///         &abort,
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_upgrades_with_progress(
+        &mut self,
+        selection: UpgradeSelection,
+        dry_run: bool,
+        listener: &dyn UpgradeProgressListener,
+        abort: &UpgradeAbortSignal,
     ) -> UpgradeResult<UpgradeResultType> {
         // First, detect available upgrades
         let detection_options = self.selection_to_detection_options(&selection);
-        let preview = self.detect_upgrades(detection_options).await?;
+        let mut preview = self.detect_upgrades(detection_options).await?;
+        let mut warnings = std::mem::take(&mut preview.warnings);
+
+        // Hold back upgrades that would invalidate a local patch, unless allowed
+        if !selection.allows_patched(&self.config.patched_dependencies) {
+            warnings.extend(withhold_patched(&mut preview.packages, &selection));
+        }
 
         if preview.packages.is_empty() {
-            return apply_upgrades(vec![], selection, dry_run, &self.fs).await;
+            return apply_upgrades_with_progress(
+                vec![],
+                selection,
+                dry_run,
+                &self.fs,
+                listener,
+                abort,
+            )
+            .await
+            .map(|result| prepend_warnings(result, warnings));
         }
 
         // In dry-run mode, just apply without backup or changeset
         if dry_run {
-            return apply_upgrades(preview.packages, selection, dry_run, &self.fs).await;
+            return apply_upgrades_with_progress(
+                preview.packages,
+                selection,
+                dry_run,
+                &self.fs,
+                listener,
+                abort,
+            )
+            .await
+            .map(|result| prepend_warnings(result, warnings));
         }
 
         // Create backup if enabled
         let backup_id = if self.config.backup.enabled {
             let files_to_backup = self.collect_package_json_files(&preview.packages)?;
-            let backup_id = self.backup_manager.create_backup(&files_to_backup, "upgrade").await?;
+            let backup_id =
+                self.transaction_log.begin(OperationKind::UpgradeApply, &files_to_backup).await?;
             Some(backup_id)
         } else {
             None
         };
 
-        // Apply upgrades with changeset integration
-        let result = if self.config.auto_changeset {
-            // Create a PackageToolsConfig with the current upgrade config
-            let pkg_config =
-                PackageToolsConfig { upgrade: self.config.clone(), ..Default::default() };
+        // Create the changeset manager up front so a failure leaves the workspace untouched
+        let changeset_manager = if self.config.auto_changeset {
+            // Create a PackageToolsConfig with the current upgrade and changeset config
+            let pkg_config = PackageToolsConfig {
+                upgrade: self.config.clone(),
+                changeset: self.changeset_config.clone(),
+                ..Default::default()
+            };
 
             let changeset_manager =
                 ChangesetManager::new(&self.workspace_root, self.fs.clone(), pkg_config)
@@ -263,40 +406,81 @@ impl UpgradeManager {
                     .map_err(|e| UpgradeError::ChangesetCreationFailed {
                         reason: format!("Failed to initialize changeset manager: {}", e.as_ref()),
                     })?;
+            Some(changeset_manager)
+        } else {
+            None
+        };
 
-            apply_with_changeset(
+        // Apply upgrades, verify them, then record the changeset
+        let result = async {
+            let mut result = apply_upgrades_with_progress(
                 preview.packages,
                 selection,
                 dry_run,
-                &self.workspace_root,
-                &self.config,
-                Some(&changeset_manager),
                 &self.fs,
+                listener,
+                abort,
             )
-            .await
-        } else {
-            apply_upgrades(preview.packages, selection, dry_run, &self.fs).await
-        };
+            .await?;
+
+            if let Some(outcome) =
+                verify_upgrades(&result, &self.config.verify, &self.workspace_root).await
+            {
+                let policy = self.config.verify.on_failure_for(outcome.upgrade_type.as_str());
+                if !outcome.passed && policy == "rollback" {
+                    return Err(UpgradeError::VerificationFailed {
+                        command: outcome.command,
+                        reason: outcome.reason.unwrap_or_default(),
+                    });
+                }
+                if !outcome.passed {
+                    result.warnings.push(Warning::new(
+                        WarningKind::VerificationFailed,
+                        format!(
+                            "Verification command `{}` failed and the upgrades were kept: {}",
+                            outcome.command,
+                            outcome.reason.as_deref().unwrap_or("no reason given")
+                        ),
+                    ));
+                }
+                result.verification = Some(outcome);
+            }
+
+            match &changeset_manager {
+                Some(changeset_manager) => {
+                    attach_changeset(
+                        result,
+                        dry_run,
+                        &self.workspace_root,
+                        &self.config,
+                        Some(changeset_manager),
+                    )
+                    .await
+                }
+                None => Ok(result),
+            }
+        }
+        .await;
 
         // Handle result
         match result {
             Ok(upgrade_result) => {
+                let mut upgrade_result = prepend_warnings(upgrade_result, warnings);
                 // Store backup ID for potential rollback
                 if let Some(id) = backup_id.clone() {
                     self.last_backup_id = Some(id.clone());
                 }
 
-                // Clean up backup if configured
-                if self.config.backup.enabled
-                    && !self.config.backup.keep_after_success
-                    && let Some(id) = backup_id
-                {
-                    let _ = self.backup_manager.delete_backup(&id).await;
-                }
-
-                // Clean up old backups
-                if self.config.backup.enabled {
-                    let _ = self.backup_manager.cleanup_old_backups().await;
+                // Commit the transaction if configured, otherwise discard it
+                if let Some(id) = backup_id {
+                    if self.config.backup.keep_after_success {
+                        let _ = self.transaction_log.commit(&id).await;
+                        upgrade_result.backup_path = Some(
+                            self.workspace_root.join(&self.config.backup.backup_dir).join(&id),
+                        );
+                    } else {
+                        let _ = self.transaction_log.discard(&id).await;
+                    }
                 }
 
                 Ok(upgrade_result)
@@ -304,8 +488,175 @@ impl UpgradeManager {
             Err(e) => {
                 // Rollback on failure
                 if let Some(id) = backup_id {
-                    let _ = self.backup_manager.restore_backup(&id).await;
-                    self.last_backup_id = Some(id);
+                    let _ = self.transaction_log.rollback(&id).await;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Detects available upgrades of the toolchain pins in the workspace.
+    ///
+    /// Covers `engines.node`, the `packageManager` field, and Volta pins. See
+    /// [`detect_toolchain_upgrades`](crate::upgrade::detect_toolchain_upgrades) for how each
+    /// pin is compared and rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError` if package.json files cannot be found or read.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// # async fn example(manager: sublime_pkg_tools::upgrade::UpgradeManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let preview = manager.detect_toolchain_upgrades().await?;
+    /// for upgrade in &preview.upgrades {
+    ///     println!("{}: {} -> {}", upgrade.field(), upgrade.current_spec, upgrade.new_spec);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn detect_toolchain_upgrades(&self) -> UpgradeResult<ToolchainPreview> {
+        detect_toolchain_upgrades(&self.workspace_root, &self.registry_client, &self.fs).await
+    }
+
+    /// Applies selected toolchain pin upgrades to package.json files.
+    ///
+    /// Detects toolchain upgrades and keeps those matching the selection: by upgrade type,
+    /// by package, and by tool through the dependency filter (e.g., `["node"]`). Outside dry
+    /// runs the modified files are backed up first, a changeset is created or updated when
+    /// `auto_changeset` is enabled, and any failure restores the backup, exactly as for
+    /// dependency upgrades. Verification commands are not run for toolchain upgrades.
+    ///
+    /// # Arguments
+    ///
+    /// * `selection` - Selection criteria for filtering which upgrades to apply
+    /// * `dry_run` - If true, preview changes without modifying files
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError` if:
+    /// - Backup creation fails
+    /// - Files cannot be read or written
+    /// - Changeset creation fails
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::upgrade::UpgradeSelection;
+    ///
+    /// # async fn example(mut manager: sublime_pkg_tools::upgrade::UpgradeManager) -> Result<(), Box<dyn std::error::Error>> {
+    /// let selection = UpgradeSelection::dependencies(vec!["pnpm".to_string()]);
+    /// let result = manager.apply_toolchain_upgrades(selection, false).await?;
+    /// println!("Updated {} pins", result.applied.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_toolchain_upgrades(
+        &mut self,
+        selection: UpgradeSelection,
+        dry_run: bool,
+    ) -> UpgradeResult<ToolchainResult> {
+        let preview = self.detect_toolchain_upgrades().await?;
+        let upgrades: Vec<ToolchainUpgrade> = preview
+            .upgrades
+            .into_iter()
+            .filter(|upgrade| {
+                selection.matches_type(upgrade.upgrade_type)
+                    && selection.matches_package(&upgrade.package_name)
+                    && selection.matches_dependency(&upgrade.tool)
+            })
+            .collect();
+
+        if dry_run || upgrades.is_empty() {
+            return apply_toolchain_upgrades(upgrades, dry_run, &self.fs).await;
+        }
+
+        // Create backup if enabled
+        let backup_id = if self.config.backup.enabled {
+            let mut files: Vec<PathBuf> =
+                upgrades.iter().map(|upgrade| upgrade.package_path.join("package.json")).collect();
+            files.sort();
+            files.dedup();
+            Some(self.transaction_log.begin(OperationKind::UpgradeApply, &files).await?)
+        } else {
+            None
+        };
+
+        let result = async {
+            let mut result = apply_toolchain_upgrades(upgrades, dry_run, &self.fs).await?;
+
+            if self.config.auto_changeset && !result.applied.is_empty() {
+                let pkg_config = PackageToolsConfig {
+                    upgrade: self.config.clone(),
+                    changeset: self.changeset_config.clone(),
+                    ..Default::default()
+                };
+                let changeset_manager =
+                    ChangesetManager::new(&self.workspace_root, self.fs.clone(), pkg_config)
+                        .await
+                        .map_err(|e| UpgradeError::ChangesetCreationFailed {
+                            reason: format!(
+                                "Failed to initialize changeset manager: {}",
+                                e.as_ref()
+                            ),
+                        })?;
+
+                let bump = result
+                    .highest_upgrade_type()
+                    .map_or(self.config.changeset_bump.as_str(), |upgrade_type| {
+                        self.config.bump_for_upgrade(upgrade_type.as_str())
+                    });
+                let packages =
+                    result.applied.iter().map(|upgrade| upgrade.package_name.clone()).collect();
+                let mut lines: Vec<String> = result
+                    .applied
+                    .iter()
+                    .map(|upgrade| {
+                        format!(
+                            "{}: {} {} -> {} ({})",
+                            upgrade.package_name,
+                            upgrade.field(),
+                            upgrade.current_spec,
+                            upgrade.new_spec,
+                            upgrade.upgrade_type.as_str()
+                        )
+                    })
+                    .collect();
+                lines.sort();
+
+                result.changeset_id = create_changeset_for_upgrades(
+                    &changeset_manager,
+                    packages,
+                    &self.workspace_root,
+                    bump,
+                    lines,
+                )
+                .await?;
+            }
+
+            Ok(result)
+        }
+        .await;
+
+        match result {
+            Ok(mut toolchain_result) => {
+                if let Some(id) = backup_id {
+                    self.last_backup_id = Some(id.clone());
+                    if self.config.backup.keep_after_success {
+                        let _ = self.transaction_log.commit(&id).await;
+                        toolchain_result.backup_path = Some(
+                            self.workspace_root.join(&self.config.backup.backup_dir).join(&id),
+                        );
+                    } else {
+                        let _ = self.transaction_log.discard(&id).await;
+                    }
+                }
+                Ok(toolchain_result)
+            }
+            Err(e) => {
+                if let Some(id) = backup_id {
+                    let _ = self.transaction_log.rollback(&id).await;
                 }
                 Err(e)
             }
@@ -349,13 +700,8 @@ impl UpgradeManager {
             path: self.workspace_root.join(&self.config.backup.backup_dir),
         })?;
 
-        self.backup_manager.restore_backup(backup_id).await?;
-
-        // Get list of restored files from metadata
-        let metadata = self.backup_manager.list_backups().await?;
-        let backup_meta = metadata.iter().find(|b| &b.id == backup_id);
-
-        Ok(backup_meta.map(|m| m.files.clone()).unwrap_or_default())
+        let entry = self.transaction_log.undo(backup_id).await?;
+        Ok(entry.files)
     }
 
     /// Gets the workspace root path.
@@ -377,6 +723,12 @@ impl UpgradeManager {
         &self.workspace_root
     }
 
+    /// Gets the changeset configuration used for automatic changesets.
+    #[must_use]
+    pub fn changeset_config(&self) -> &ChangesetConfig {
+        &self.changeset_config
+    }
+
     /// Gets the current configuration.
     ///
     /// # Returns
@@ -463,10 +815,124 @@ impl UpgradeManager {
     }
 }
 
+/// Removes upgrades of dependencies with a local patch, and packages left without upgrades.
+///
+/// Returns a `PatchedDependencyWithheld` warning for each removed upgrade the selection
+/// matches.
+pub(crate) fn withhold_patched(
+    packages: &mut Vec<crate::upgrade::detection::PackageUpgrades>,
+    selection: &UpgradeSelection,
+) -> Vec<Warning> {
+    let mut warnings = Vec::new();
+    for package in packages.iter_mut() {
+        let selected = selection.matches_target(&package.package_name, package.workspace_root);
+        package.upgrades.retain(|upgrade| {
+            let Some(patch) = &upgrade.patch else {
+                return true;
+            };
+            if selected
+                && selection.matches_type(upgrade.upgrade_type)
+                && selection.matches_dependency(&upgrade.name)
+            {
+                warnings.push(
+                    Warning::new(
+                        WarningKind::PatchedDependencyWithheld,
+                        format!(
+                            "{} {} -> {} in {} was held back: it is patched by {}",
+                            upgrade.name,
+                            upgrade.current_version,
+                            upgrade.latest_version,
+                            package.package_name,
+                            patch.patch_file.display()
+                        ),
+                    )
+                    .with_package(package.package_name.clone())
+                    .with_path(patch.patch_file.clone()),
+                );
+            }
+            false
+        });
+    }
+    packages.retain(|package| !package.upgrades.is_empty());
+    warnings
+}
+
+/// Puts the warnings of upgrade detection before those of the application.
+fn prepend_warnings(
+    mut result: UpgradeResultType,
+    mut warnings: Vec<Warning>,
+) -> UpgradeResultType {
+    warnings.append(&mut result.warnings);
+    result.warnings = warnings;
+    result
+}
+
 #[cfg(test)]
 mod tests {
     // Note: Full integration tests should be in the tests/ directory
-    // Unit tests for internal helper methods would go here, but the current
-    // implementation doesn't have testable private methods that warrant unit tests.
     // The public API is better tested through integration tests with real fixtures.
+
+    use super::withhold_patched;
+    use crate::types::DependencyType;
+    use crate::upgrade::UpgradeSelection;
+    use crate::upgrade::detection::{
+        DependencyUpgrade, PackageUpgrades, PatchSource, PatchedDependency, VersionInfo,
+    };
+    use crate::upgrade::registry::UpgradeType;
+    use std::path::PathBuf;
+
+    fn upgrade(name: &str, patched: bool) -> DependencyUpgrade {
+        DependencyUpgrade {
+            name: name.to_string(),
+            current_version: "1.0.0".to_string(),
+            latest_version: "2.0.0".to_string(),
+            upgrade_type: UpgradeType::Major,
+            dependency_type: DependencyType::Regular,
+            registry_url: "https://registry.npmjs.org".to_string(),
+            version_info: VersionInfo {
+                available_versions: vec!["2.0.0".to_string()],
+                latest_stable: "2.0.0".to_string(),
+                latest_prerelease: None,
+                deprecated: None,
+                published_at: None,
+                current_deprecated: None,
+                target_deprecated: None,
+                downloads: None,
+                repository: None,
+            },
+            patch: patched.then(|| PatchedDependency {
+                name: name.to_string(),
+                version: Some("1.0.0".to_string()),
+                patch_file: PathBuf::from(format!("patches/{name}+1.0.0.patch")),
+                source: PatchSource::PatchPackage,
+            }),
+        }
+    }
+
+    fn package(name: &str, upgrades: Vec<DependencyUpgrade>) -> PackageUpgrades {
+        PackageUpgrades {
+            package_name: name.to_string(),
+            package_path: PathBuf::from(name),
+            current_version: Some("1.0.0".to_string()),
+            upgrades,
+            workspace_root: false,
+        }
+    }
+
+    #[test]
+    fn test_withhold_patched_removes_patched_upgrades() {
+        let mut packages = vec![
+            package("app", vec![upgrade("lodash", true), upgrade("react", false)]),
+            package("lib", vec![upgrade("lodash", true)]),
+        ];
+
+        let warnings = withhold_patched(&mut packages, &UpgradeSelection::all());
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].package.as_deref(), Some("app"));
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].package_name, "app");
+        assert_eq!(packages[0].upgrades.len(), 1);
+        assert_eq!(packages[0].upgrades[0].name, "react");
+    }
 }
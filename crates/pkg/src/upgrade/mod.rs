@@ -17,9 +17,15 @@
 //! - **Selective Upgrades**: Filter by patch/minor/major, specific packages, or dependencies
 //! - **Registry Support**: Support for npm registry, private registries, and scoped packages
 //! - **.npmrc Integration**: Read authentication and registry configuration from .npmrc
+//! - **Patched Dependencies**: Flag, confirm, or block upgrades of dependencies patched with
+//!   `patch-package` or pnpm
 //! - **Dry-Run Mode**: Preview changes before applying them
 //! - **Automatic Changeset**: Optionally create changesets for applied upgrades
 //! - **Backup/Rollback**: Automatic backup and rollback on failure
+//! - **Toolchain Pins**: Detect and apply upgrades of `engines.node`, `packageManager`, and
+//!   Volta pins against the Node.js and package manager release feeds
+//! - **Pull Request Descriptions**: Render applied upgrades, their risks, and the changesets
+//!   created as a Markdown pull request body
 //! - **Concurrency**: Parallel package metadata fetching for performance
 //!
 //! # Example
@@ -151,22 +157,20 @@
 //! Automatic rollback when upgrades fail:
 //!
 //! ```rust,ignore
-//! use sublime_pkg_tools::upgrade::UpgradeManager;
+//! use sublime_pkg_tools::upgrade::{UpgradeManager, UpgradeSelection};
+//!
+//! # async fn example(mut manager: UpgradeManager) -> Result<(), Box<dyn std::error::Error>> {
+//! // Upgrades are automatically rolled back on failure
+//! match manager.apply_upgrades(UpgradeSelection::all(), false).await {
+//!     Ok(result) => println!("Success: {} upgrades applied", result.applied.len()),
+//!     Err(e) => {
+//!         // Automatic rollback has occurred
+//!         println!("Upgrade failed and was rolled back: {}", e);
+//!     }
+//! }
 //!
-//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-//! # let manager: UpgradeManager = todo!();
-//! // TODO: will be implemented on story 9.5
-//! // // Upgrades are automatically rolled back on failure
-//! // match manager.apply_upgrades(selection, false).await {
-//! //     Ok(result) => println!("Success: {} upgrades applied", result.applied.len()),
-//! //     Err(e) => {
-//! //         // Automatic rollback has occurred
-//! //         println!("Upgrade failed and was rolled back: {}", e);
-//! //     }
-//! // }
-//! //
-//! // // Or manually rollback the last operation
-//! // manager.rollback_last().await?;
+//! // Or manually rollback the last operation
+//! manager.rollback_last().await?;
 //! # Ok(())
 //! # }
 //! ```
@@ -181,8 +185,6 @@
 //! - `result`: Result types for upgrade operations
 //! - `backup`: Backup and rollback mechanisms
 
-#![allow(clippy::todo)]
-
 // Registry module for NPM package metadata queries (Story 9.1 - IMPLEMENTED)
 mod registry;
 
@@ -193,22 +195,32 @@ mod detection;
 mod application;
 
 // Re-export registry public types
+pub(crate) use registry::transport::resolve_transport;
 pub use registry::{
-    PackageMetadata, RegistryClient, RepositoryInfo, UpgradeType, npmrc::NpmrcConfig,
+    DownloadStats, DownloadTrend, MetadataDetail, NodeRelease, PackageMetadata, RegistryClient,
+    RegistryRetry, RegistryRetryListener, RepositoryInfo, UpgradeType, npmrc::NpmrcConfig,
 };
 
 // Re-export detection public types and functions
 pub use detection::{
-    DependencyUpgrade, DetectionOptions, PackageUpgrades, UpgradePreview, UpgradeSummary,
-    VersionInfo, detect_upgrades,
+    DependencyUpgrade, DetectionOptions, PackageUpgrades, PatchSource, PatchedDependency,
+    UpgradePreview, UpgradeSummary, VersionInfo, detect_upgrades,
 };
 
 // Re-export application public types and functions
+pub(crate) use application::applier::serialize_package_json;
 pub use application::{
-    AppliedUpgrade, ApplySummary, UpgradeResult, UpgradeSelection, apply_upgrades,
-    apply_with_changeset,
+    AppliedUpgrade, ApplySummary, ROOT_PACKAGE_SELECTOR, UPGRADED_PACKAGES_ENV, UpgradeAbortSignal,
+    UpgradeProgressEvent, UpgradeProgressListener, UpgradeResult, UpgradeSelection,
+    VerificationOutcome, apply_upgrades, apply_upgrades_with_progress, apply_with_changeset,
+    changeset_bump_for,
 };
 
+// Pull request descriptions for applied upgrades
+mod pr_body;
+
+pub use pr_body::render_pr_body;
+
 // Backup module for backup and rollback (Story 9.5 - IMPLEMENTED)
 mod backup;
 
@@ -218,6 +230,14 @@ pub use backup::{BackupManager, BackupMetadata};
 // Automatic changeset creation (Story 9.6 - IMPLEMENTED)
 // Integrated in application module via apply_with_changeset function
 
+// Toolchain pin upgrades (engines.node, packageManager, volta)
+mod toolchain;
+
+pub use toolchain::{
+    ToolchainPin, ToolchainPreview, ToolchainResult, ToolchainUpgrade, apply_toolchain_upgrades,
+    detect_toolchain_upgrades,
+};
+
 // Manager module for orchestrating upgrade operations (Story 9.7 - IMPLEMENTED)
 mod manager;
 
@@ -0,0 +1,447 @@
+//! Pull request descriptions for applied dependency upgrades.
+//!
+//! **What**: Provides `render_pr_body`, which turns the result of applying a batch of upgrades
+//! into a Markdown pull request description.
+//!
+//! **How**: Applied upgrades are grouped per dependency and version change, listing the
+//! workspace packages they touched. The registry metadata gathered during detection supplies
+//! the release notes link and deprecation notices. Major upgrades, deprecated versions, and
+//! upgrades of locally patched dependencies are called out as risks, followed by the
+//! verification outcome and the changesets the upgrade created.
+//!
+//! **Why**: Automated upgrade pipelines open pull requests that reviewers have to assess
+//! quickly. A description assembled from the same data the upgrade used avoids hand-written
+//! summaries that drift from what was actually changed.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::upgrade::application::{AppliedUpgrade, UpgradeResult};
+use crate::upgrade::detection::{DependencyUpgrade, PackageUpgrades};
+use crate::upgrade::registry::UpgradeType;
+
+/// Default npm registry, whose package pages serve as release notes fallback.
+const NPM_REGISTRY: &str = "registry.npmjs.org";
+
+/// One dependency version change, with every workspace package it was applied to.
+struct UpgradeRow<'a> {
+    applied: &'a AppliedUpgrade,
+    detected: Option<&'a DependencyUpgrade>,
+    packages: BTreeSet<String>,
+}
+
+/// Renders a Markdown pull request description for a batch of applied upgrades.
+///
+/// The description holds a summary line, a table of upgraded dependencies (old and new
+/// version, semver level, affected packages, release notes link), the risks reviewers
+/// should look at, the verification outcome, and the changesets created by the upgrade.
+///
+/// # Arguments
+///
+/// * `result` - The result of applying the upgrades
+/// * `detected` - The upgrades detected before applying, used for registry metadata and
+///   package names
+///
+/// # Returns
+///
+/// The pull request description, ending with a newline.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::config::UpgradeConfig;
+/// use sublime_pkg_tools::upgrade::{render_pr_body, DetectionOptions, UpgradeManager, UpgradeSelection};
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut manager = UpgradeManager::new(PathBuf::from("."), UpgradeConfig::default()).await?;
+/// let preview = manager.detect_upgrades(DetectionOptions::all()).await?;
+/// let result = manager.apply_upgrades(UpgradeSelection::patch_only(), false).await?;
+///
+/// println!("{}", render_pr_body(&result, &preview.packages));
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn render_pr_body(result: &UpgradeResult, detected: &[PackageUpgrades]) -> String {
+    let rows = collect_rows(result, detected);
+    let mut body = String::new();
+
+    let _ = writeln!(body, "## Dependency upgrades");
+    let _ = writeln!(body);
+    if rows.is_empty() {
+        let _ = writeln!(body, "No dependencies were upgraded.");
+        return body;
+    }
+    let _ = writeln!(body, "{}", summary_line(result, &rows));
+    if result.dry_run {
+        let _ = writeln!(body);
+        let _ = writeln!(body, "> Dry run: these upgrades were not written.");
+    }
+
+    let _ = writeln!(body);
+    let _ = writeln!(body, "| Dependency | From | To | Level | Packages | Release notes |");
+    let _ = writeln!(body, "| --- | --- | --- | --- | --- | --- |");
+    for row in &rows {
+        let packages = row.packages.iter().map(String::as_str).collect::<Vec<_>>().join(", ");
+        let notes = release_notes_link(row)
+            .map_or_else(|| "-".to_string(), |url| format!("[release notes]({url})"));
+        let _ = writeln!(
+            body,
+            "| `{}` | {} | {} | {} | {} | {} |",
+            row.applied.dependency_name,
+            row.applied.old_version,
+            row.applied.new_version,
+            row.applied.upgrade_type,
+            escape_cell(&packages),
+            notes
+        );
+    }
+
+    let risks = collect_risks(&rows);
+    if !risks.is_empty() {
+        let _ = writeln!(body);
+        let _ = writeln!(body, "### Risks");
+        let _ = writeln!(body);
+        for risk in risks {
+            let _ = writeln!(body, "- {risk}");
+        }
+    }
+
+    if let Some(verification) = &result.verification {
+        let _ = writeln!(body);
+        let _ = writeln!(body, "### Verification");
+        let _ = writeln!(body);
+        let status = if verification.passed { "passed" } else { "failed" };
+        let _ = write!(body, "`{}` {status}", verification.command);
+        if let Some(reason) = &verification.reason {
+            let _ = write!(body, ": {reason}");
+        }
+        let _ = writeln!(body);
+    }
+
+    let _ = writeln!(body);
+    let _ = writeln!(body, "### Changesets");
+    let _ = writeln!(body);
+    match &result.changeset_id {
+        Some(id) => {
+            let _ = writeln!(body, "- `{id}`");
+        }
+        None => {
+            let _ = writeln!(body, "No changeset was created.");
+        }
+    }
+
+    body
+}
+
+/// Groups applied upgrades per dependency and version change, in table order.
+fn collect_rows<'a>(
+    result: &'a UpgradeResult,
+    detected: &'a [PackageUpgrades],
+) -> Vec<UpgradeRow<'a>> {
+    let mut rows: Vec<UpgradeRow<'a>> = Vec::new();
+    for applied in &result.applied {
+        let package = detected.iter().find(|package| package.package_path == applied.package_path);
+        let package_name = package.map_or_else(
+            || display_path(&applied.package_path),
+            |package| package.package_name.clone(),
+        );
+        let upgrade = package.and_then(|package| {
+            package.upgrades.iter().find(|upgrade| upgrade.name == applied.dependency_name)
+        });
+
+        match rows.iter_mut().find(|row| {
+            row.applied.dependency_name == applied.dependency_name
+                && row.applied.old_version == applied.old_version
+                && row.applied.new_version == applied.new_version
+        }) {
+            Some(row) => {
+                row.packages.insert(package_name);
+                row.detected = row.detected.or(upgrade);
+            }
+            None => rows.push(UpgradeRow {
+                applied,
+                detected: upgrade,
+                packages: BTreeSet::from([package_name]),
+            }),
+        }
+    }
+
+    rows.sort_by(|a, b| {
+        level_rank(b.applied.upgrade_type)
+            .cmp(&level_rank(a.applied.upgrade_type))
+            .then_with(|| a.applied.dependency_name.cmp(&b.applied.dependency_name))
+    });
+    rows
+}
+
+/// Describes the size of the batch in one sentence.
+fn summary_line(result: &UpgradeResult, rows: &[UpgradeRow<'_>]) -> String {
+    let count =
+        |level: UpgradeType| rows.iter().filter(|row| row.applied.upgrade_type == level).count();
+    let packages: BTreeSet<&Path> =
+        result.applied.iter().map(|applied| applied.package_path.as_path()).collect();
+    format!(
+        "Upgrades {} dependenc{} ({} major, {} minor, {} patch) across {} package{}.",
+        rows.len(),
+        if rows.len() == 1 { "y" } else { "ies" },
+        count(UpgradeType::Major),
+        count(UpgradeType::Minor),
+        count(UpgradeType::Patch),
+        packages.len(),
+        if packages.len() == 1 { "" } else { "s" }
+    )
+}
+
+/// Lists the major, deprecated, and patched upgrades reviewers should look at.
+fn collect_risks(rows: &[UpgradeRow<'_>]) -> Vec<String> {
+    let mut risks = Vec::new();
+    for row in rows {
+        let name = &row.applied.dependency_name;
+        if row.applied.upgrade_type == UpgradeType::Major {
+            risks.push(format!(
+                "**Major**: `{name}` {} → {} may contain breaking changes",
+                row.applied.old_version, row.applied.new_version
+            ));
+        }
+
+        let Some(upgrade) = row.detected else { continue };
+        if let Some(notice) = upgrade
+            .version_info
+            .target_deprecated
+            .as_ref()
+            .or(upgrade.version_info.deprecated.as_ref())
+        {
+            risks.push(format!(
+                "**Deprecated**: `{name}` {} is deprecated: {}",
+                row.applied.new_version,
+                notice.trim()
+            ));
+        }
+        if let Some(patch) = &upgrade.patch {
+            risks.push(format!(
+                "**Patched**: `{name}` has a local patch (`{}`) that may no longer apply",
+                display_path(&patch.patch_file)
+            ));
+        }
+    }
+    risks
+}
+
+/// Returns the release notes link of a row, falling back to the npm package page.
+fn release_notes_link(row: &UpgradeRow<'_>) -> Option<String> {
+    let upgrade = row.detected?;
+    upgrade.version_info.repository.as_ref().and_then(|repo| repo.release_notes_url()).or_else(
+        || {
+            upgrade.registry_url.contains(NPM_REGISTRY).then(|| {
+                format!(
+                    "https://www.npmjs.com/package/{}/v/{}",
+                    row.applied.dependency_name, row.applied.new_version
+                )
+            })
+        },
+    )
+}
+
+/// Orders upgrade levels from least to most risky.
+fn level_rank(level: UpgradeType) -> u8 {
+    match level {
+        UpgradeType::Patch => 0,
+        UpgradeType::Minor => 1,
+        UpgradeType::Major => 2,
+    }
+}
+
+/// Formats a path with forward slashes, as it appears in the repository.
+fn display_path(path: &Path) -> String {
+    path.display().to_string().replace('\\', "/")
+}
+
+/// Escapes characters that would break a Markdown table cell.
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::render_pr_body;
+    use crate::types::DependencyType;
+    use crate::upgrade::application::{
+        AppliedUpgrade, ApplySummary, UpgradeResult, VerificationOutcome,
+    };
+    use crate::upgrade::detection::{
+        DependencyUpgrade, PackageUpgrades, PatchSource, PatchedDependency, VersionInfo,
+    };
+    use crate::upgrade::registry::{RepositoryInfo, UpgradeType};
+    use std::path::PathBuf;
+
+    fn detected(name: &str, from: &str, to: &str, level: UpgradeType) -> DependencyUpgrade {
+        DependencyUpgrade {
+            name: name.to_string(),
+            current_version: from.to_string(),
+            latest_version: to.to_string(),
+            upgrade_type: level,
+            dependency_type: DependencyType::Regular,
+            registry_url: "https://registry.npmjs.org".to_string(),
+            version_info: VersionInfo {
+                available_versions: vec![to.to_string()],
+                latest_stable: to.to_string(),
+                latest_prerelease: None,
+                deprecated: None,
+                published_at: None,
+                current_deprecated: None,
+                target_deprecated: None,
+                downloads: None,
+                repository: None,
+            },
+            patch: None,
+        }
+    }
+
+    fn applied(package: &str, upgrade: &DependencyUpgrade) -> AppliedUpgrade {
+        AppliedUpgrade {
+            package_path: PathBuf::from(package),
+            package_name: package.to_string(),
+            manifest_path: PathBuf::from(package).join("package.json"),
+            workspace_root: false,
+            dependency_name: upgrade.name.clone(),
+            dependency_type: upgrade.dependency_type,
+            old_version: upgrade.current_version.clone(),
+            new_version: upgrade.latest_version.clone(),
+            upgrade_type: upgrade.upgrade_type,
+        }
+    }
+
+    fn package(name: &str, path: &str, upgrades: Vec<DependencyUpgrade>) -> PackageUpgrades {
+        PackageUpgrades {
+            package_name: name.to_string(),
+            package_path: PathBuf::from(path),
+            current_version: Some("1.0.0".to_string()),
+            upgrades,
+            workspace_root: false,
+        }
+    }
+
+    fn result(applied: Vec<AppliedUpgrade>, changeset_id: Option<&str>) -> UpgradeResult {
+        let mut result = UpgradeResult::dry_run(applied, ApplySummary::new());
+        result.dry_run = false;
+        result.changeset_id = changeset_id.map(str::to_string);
+        result
+    }
+
+    #[test]
+    fn test_table_groups_packages_and_links_release_notes() {
+        let mut react = detected("react", "^17.0.2", "18.2.0", UpgradeType::Major);
+        react.version_info.repository = Some(RepositoryInfo {
+            type_: "git".to_string(),
+            url: "git+https://github.com/facebook/react.git".to_string(),
+        });
+        let lodash = detected("lodash", "^4.17.20", "4.17.21", UpgradeType::Patch);
+        let packages = vec![
+            package("@acme/web", "packages/web", vec![react.clone(), lodash.clone()]),
+            package("@acme/docs", "packages/docs", vec![react.clone()]),
+        ];
+        let result = result(
+            vec![
+                applied("packages/web", &lodash),
+                applied("packages/web", &react),
+                applied("packages/docs", &react),
+            ],
+            Some("deps-upgrade-1"),
+        );
+
+        let body = render_pr_body(&result, &packages);
+
+        assert!(
+            body.contains("Upgrades 2 dependencies (1 major, 0 minor, 1 patch) across 2 packages.")
+        );
+        assert!(body.contains(
+            "| `react` | ^17.0.2 | 18.2.0 | major | @acme/docs, @acme/web | \
+             [release notes](https://github.com/facebook/react/releases) |"
+        ));
+        assert!(body.contains(
+            "| `lodash` | ^4.17.20 | 4.17.21 | patch | @acme/web | \
+             [release notes](https://www.npmjs.com/package/lodash/v/4.17.21) |"
+        ));
+        // Riskier upgrades come first
+        assert!(body.find("`react`").unwrap() < body.find("`lodash`").unwrap());
+        assert!(body.contains("### Changesets\n\n- `deps-upgrade-1`\n"));
+        assert!(!body.contains("Dry run"));
+    }
+
+    #[test]
+    fn test_risks_flag_major_deprecated_and_patched() {
+        let major = detected("webpack", "^4.0.0", "5.0.0", UpgradeType::Major);
+        let mut deprecated = detected("request", "^2.87.0", "2.88.2", UpgradeType::Minor);
+        deprecated.version_info.target_deprecated = Some("request has been deprecated".to_string());
+        let mut patched = detected("left-pad", "^1.2.0", "1.3.0", UpgradeType::Minor);
+        patched.patch = Some(PatchedDependency {
+            name: "left-pad".to_string(),
+            version: Some("1.2.0".to_string()),
+            patch_file: PathBuf::from("patches/left-pad+1.2.0.patch"),
+            source: PatchSource::PatchPackage,
+        });
+        let packages =
+            vec![package("app", "app", vec![major.clone(), deprecated.clone(), patched.clone()])];
+        let result = result(
+            vec![applied("app", &major), applied("app", &deprecated), applied("app", &patched)],
+            None,
+        );
+
+        let body = render_pr_body(&result, &packages);
+
+        assert!(body.contains("### Risks"));
+        assert!(
+            body.contains("- **Major**: `webpack` ^4.0.0 → 5.0.0 may contain breaking changes")
+        );
+        assert!(body.contains(
+            "- **Deprecated**: `request` 2.88.2 is deprecated: request has been deprecated"
+        ));
+        assert!(body.contains(
+            "- **Patched**: `left-pad` has a local patch (`patches/left-pad+1.2.0.patch`) that may no longer apply"
+        ));
+        assert!(body.contains("No changeset was created."));
+    }
+
+    #[test]
+    fn test_verification_and_dry_run_are_reported() {
+        let upgrade = detected("vite", "^5.0.0", "5.1.0", UpgradeType::Minor);
+        let packages = vec![package("app", "app", vec![upgrade.clone()])];
+        let mut result = result(vec![applied("app", &upgrade)], None);
+        result.dry_run = true;
+        result.verification = Some(VerificationOutcome {
+            command: "npm test".to_string(),
+            upgrade_type: UpgradeType::Minor,
+            passed: false,
+            duration_ms: 1200,
+            reason: Some("exit code 1".to_string()),
+        });
+
+        let body = render_pr_body(&result, &packages);
+
+        assert!(body.contains("> Dry run: these upgrades were not written."));
+        assert!(body.contains("### Verification\n\n`npm test` failed: exit code 1\n"));
+        assert!(!body.contains("### Risks"));
+    }
+
+    #[test]
+    fn test_empty_batch() {
+        let body = render_pr_body(&result(vec![], None), &[]);
+
+        assert_eq!(body, "## Dependency upgrades\n\nNo dependencies were upgraded.\n");
+    }
+
+    #[test]
+    fn test_undetected_upgrade_falls_back_to_path() {
+        let upgrade = detected("typescript", "^5.3.0", "5.4.0", UpgradeType::Minor);
+        let result = result(vec![applied("tools/cli", &upgrade)], None);
+
+        let body = render_pr_body(&result, &[]);
+
+        assert!(body.contains("| `typescript` | ^5.3.0 | 5.4.0 | minor | tools/cli | - |"));
+    }
+}
@@ -5,23 +5,46 @@
 //!
 //! **How**: Uses reqwest with retry middleware to communicate with NPM registries,
 //! handling authentication, timeouts, and scoped packages. Supports both public
-//! NPM registry and private registries with authentication.
+//! NPM registry and private registries with authentication. Documents can be requested
+//! in abbreviated form, and documents returned with an `ETag` are revalidated with
+//! `If-None-Match` on later requests. In offline mode no requests are made: documents
+//! cached by the client are returned and other lookups fail with `OfflineSkipped`.
+//! Rate limited (429) and unavailable (503) responses are retried after their
+//! `Retry-After` wait, and reported as `RateLimited` or `ServiceUnavailable` when retries
+//! run out. With `requests_per_second` set, requests are spaced out to stay under it.
 //!
 //! **Why**: To enable reliable package metadata fetching with proper error handling,
-//! retry logic, and support for enterprise private registries.
+//! retry logic, and support for enterprise private registries, without downloading
+//! multi-megabyte documents for packages with thousands of versions.
 
 use crate::config::RegistryConfig;
 use crate::error::UpgradeError;
 use crate::upgrade::registry::npmrc::NpmrcConfig;
-use crate::upgrade::registry::types::{PackageMetadata, RepositoryInfo, UpgradeType};
-use reqwest::header::AUTHORIZATION;
+use crate::upgrade::registry::rate_limit::RateLimitMiddleware;
+use crate::upgrade::registry::retry::{
+    ListenerSlot, RegistryRetryListener, RegistryRetryStrategy, RetryAfterMiddleware, retry_after,
+};
+use crate::upgrade::registry::transport::resolve_transport;
+use crate::upgrade::registry::types::{
+    DownloadStats, MetadataDetail, NodeRelease, PackageMetadata, RepositoryInfo, UpgradeType,
+};
+use chrono::{Duration as ChronoDuration, NaiveDate, Utc};
+use reqwest::StatusCode;
+use reqwest::header::{ACCEPT, AUTHORIZATION, ETAG, IF_NONE_MATCH};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
 use semver::Version;
 use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
+use sublime_standard_tools::deadline::global_deadline;
+use sublime_standard_tools::network::is_offline_mode;
+use tokio::sync::RwLock;
+
+/// Number of days in each of the two windows compared for download trends.
+const DOWNLOAD_WINDOW_DAYS: i64 = 7;
 
 /// Custom deserializer for HashMap<String, String> that skips null values.
 ///
@@ -71,12 +94,41 @@ pub struct RegistryClient {
 
     /// .npmrc configuration loaded from workspace
     npmrc: Option<NpmrcConfig>,
+
+    /// Download statistics already fetched by this client, keyed by package name.
+    ///
+    /// Failed lookups are cached as `None` so a package is never queried twice.
+    download_stats_cache: RwLock<HashMap<String, Option<DownloadStats>>>,
+
+    /// Package documents returned with an `ETag`, keyed by document URL and detail level.
+    ///
+    /// Later requests for the same document send `If-None-Match` and reuse the cached
+    /// metadata when the registry answers `304 Not Modified`.
+    document_cache: RwLock<HashMap<(String, MetadataDetail), CachedDocument>>,
+
+    /// Node.js releases already fetched by this client.
+    node_releases_cache: RwLock<Option<Vec<NodeRelease>>>,
+
+    /// Listener notified by the `Retry-After` middleware before each wait.
+    retry_listener: ListenerSlot,
+}
+
+/// A package document kept for conditional requests.
+#[derive(Debug, Clone)]
+struct CachedDocument {
+    /// Entity tag returned by the registry.
+    etag: String,
+
+    /// Metadata parsed from the document.
+    metadata: PackageMetadata,
 }
 
 /// Internal structure for deserializing registry responses.
 ///
 /// The NPM registry API returns a complex JSON structure. This struct
-/// represents the top-level response for package metadata queries.
+/// represents the top-level response for package metadata queries, in both
+/// full and abbreviated form. Abbreviated documents have no `time` map and
+/// report the last modification in a top-level `modified` field instead.
 ///
 /// Uses `deny_unknown_fields = false` (default) to allow extra fields from
 /// different registry implementations (Artifactory, Verdaccio, etc).
@@ -92,6 +144,30 @@ struct RegistryResponse {
     time: HashMap<String, String>,
     #[serde(default)]
     repository: Option<RepositoryInfo>,
+    #[serde(default)]
+    modified: Option<String>,
+    #[serde(default)]
+    maintainers: Vec<Maintainer>,
+}
+
+/// Entry of the `maintainers` list: a person object, or a `"name <email>"` string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Maintainer {
+    Person { name: String },
+    Text(String),
+}
+
+impl Maintainer {
+    /// Returns the maintainer's user name.
+    fn into_name(self) -> String {
+        match self {
+            Self::Person { name } => name,
+            Self::Text(text) => {
+                text.split(['<', '(']).next().unwrap_or_default().trim().to_string()
+            }
+        }
+    }
 }
 
 /// Version-specific information from registry.
@@ -101,12 +177,37 @@ struct VersionInfo {
     deprecated: Option<String>,
 }
 
+/// Response of the npm downloads API range endpoint.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct DownloadsRangeResponse {
+    downloads: Vec<DailyDownloads>,
+}
+
+/// Download count for a single day.
+#[derive(Debug, Deserialize)]
+struct DailyDownloads {
+    day: NaiveDate,
+    downloads: u64,
+}
+
+/// Entry of the Node.js distribution index (`index.json`).
+///
+/// `lts` is `false` for releases outside an LTS line and the codename otherwise.
+#[derive(Debug, Deserialize)]
+struct NodeIndexEntry {
+    version: String,
+    #[serde(default)]
+    lts: serde_json::Value,
+}
+
 impl RegistryClient {
     /// Creates a new registry client.
     ///
     /// Initializes the HTTP client with retry logic and authentication.
-    /// If `config.read_npmrc` is true, will attempt to read .npmrc configuration
-    /// from the workspace (to be implemented in story 9.2).
+    /// If `config.read_npmrc` is true, reads .npmrc configuration from the workspace.
+    /// Proxy, CA certificate, and certificate verification settings are taken from
+    /// `config.tls`, then .npmrc, then the `HTTPS_PROXY`/`NO_PROXY` environment variables.
     ///
     /// # Arguments
     ///
@@ -121,7 +222,7 @@ impl RegistryClient {
     ///
     /// Returns `UpgradeError` if:
     /// - HTTP client construction fails
-    /// - .npmrc reading fails (when implemented)
+    /// - The proxy URL is invalid or the CA file cannot be read
     ///
     /// # Example
     ///
@@ -136,34 +237,11 @@ impl RegistryClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn new(_workspace_root: &Path, config: RegistryConfig) -> Result<Self, UpgradeError> {
-        // Build base reqwest client with timeout
-        // Note: Not setting Accept header here as different registries support different formats.
-        // We'll set it per-request with fallback handling for compatibility.
-        let reqwest_client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .build()
-            .map_err(|e| UpgradeError::NetworkError {
-                reason: format!("Failed to build HTTP client: {}", e),
-            })?;
-
-        // Configure retry policy
-        let retry_policy = ExponentialBackoff::builder()
-            .retry_bounds(
-                Duration::from_millis(config.retry_delay_ms),
-                Duration::from_secs(config.timeout_secs / 2),
-            )
-            .build_with_max_retries(config.retry_attempts as u32);
-
-        // Build client with retry middleware
-        let http_client = ClientBuilder::new(reqwest_client)
-            .with(RetryTransientMiddleware::new_with_policy(retry_policy))
-            .build();
-
+    pub async fn new(workspace_root: &Path, config: RegistryConfig) -> Result<Self, UpgradeError> {
         // Load .npmrc if configured
         let npmrc = if config.read_npmrc {
             match NpmrcConfig::from_workspace(
-                _workspace_root,
+                workspace_root,
                 &sublime_standard_tools::filesystem::FileSystemManager::new(),
             )
             .await
@@ -179,7 +257,86 @@ impl RegistryClient {
             None
         };
 
-        Ok(Self { config, http_client, npmrc })
+        // Proxy and TLS settings from config, .npmrc, and the environment
+        let transport = resolve_transport(&config.tls, npmrc.as_ref(), workspace_root, |name| {
+            std::env::var(name).ok()
+        });
+
+        // Build base reqwest client with timeout
+        // Note: Not setting Accept header here as different registries support different formats.
+        // We'll set it per-request with fallback handling for compatibility.
+        let reqwest_client = transport
+            .apply(reqwest::Client::builder().timeout(Duration::from_secs(config.timeout_secs)))
+            .await
+            .map_err(|reason| UpgradeError::InvalidConfig { reason })?
+            .build()
+            .map_err(|e| UpgradeError::NetworkError {
+                reason: format!("Failed to build HTTP client: {}", e),
+            })?;
+
+        // Configure retry policy
+        let retry_policy = ExponentialBackoff::builder()
+            .retry_bounds(
+                Duration::from_millis(config.retry_delay_ms),
+                Duration::from_secs(config.timeout_secs / 2),
+            )
+            .build_with_max_retries(config.retry_attempts as u32);
+
+        // Build client with retry middleware. Responses with `Retry-After` are retried by the
+        // outer middleware after the requested wait, everything else with exponential backoff.
+        // The rate limit is innermost, so every attempt waits for its slot.
+        let retry_listener = ListenerSlot::default();
+        let mut builder = ClientBuilder::new(reqwest_client)
+            .with(RetryAfterMiddleware::new(
+                config.retry_attempts as u32,
+                Duration::from_secs(config.max_retry_after_secs),
+                Arc::clone(&retry_listener),
+            ))
+            .with(RetryTransientMiddleware::new_with_policy_and_strategy(
+                retry_policy,
+                RegistryRetryStrategy,
+            ));
+        if let Some(requests_per_second) = config.requests_per_second {
+            builder = builder.with(RateLimitMiddleware::new(requests_per_second));
+        }
+        let http_client = builder.build();
+
+        Ok(Self {
+            config,
+            http_client,
+            npmrc,
+            download_stats_cache: RwLock::new(HashMap::new()),
+            document_cache: RwLock::new(HashMap::new()),
+            node_releases_cache: RwLock::new(None),
+            retry_listener,
+        })
+    }
+
+    /// Sets the listener notified before a rate limited request is retried.
+    ///
+    /// The listener receives the URL, status, attempt, and the wait requested by the
+    /// registry's `Retry-After` header, e.g. to show "retrying in 30s".
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::upgrade::RegistryClient;
+    /// use sublime_pkg_tools::config::RegistryConfig;
+    /// use std::path::PathBuf;
+    /// use std::sync::Arc;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = RegistryClient::new(&PathBuf::from("."), RegistryConfig::default()).await?;
+    /// client.set_retry_listener(Arc::new(|retry| {
+    ///     eprintln!("Rate limited, retrying in {}s", retry.wait.as_secs());
+    /// }));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_retry_listener(&self, listener: RegistryRetryListener) {
+        if let Ok(mut slot) = self.retry_listener.write() {
+            *slot = Some(listener);
+        }
     }
 
     /// Queries package metadata from the registry.
@@ -226,53 +383,198 @@ impl RegistryClient {
         &self,
         package_name: &str,
     ) -> Result<PackageMetadata, UpgradeError> {
-        let registry_url = self.resolve_registry_url(package_name);
-        let package_url = format!("{}/{}", registry_url.trim_end_matches('/'), package_name);
+        self.get_package_metadata(package_name, MetadataDetail::Full).await
+    }
 
-        // Build request with authentication if available
-        let mut request = self.http_client.get(&package_url);
+    /// Queries package metadata with the given level of detail.
+    ///
+    /// Abbreviated documents are much smaller for packages with many versions but contain
+    /// no per-version publish times and no repository information; the returned metadata
+    /// then only has the `modified` time and no repository. Abbreviated requests are sent
+    /// as full requests when `abbreviated_metadata` is disabled in the registry
+    /// configuration, and are retried as full requests when the registry rejects them.
+    ///
+    /// Documents returned with an `ETag` are cached for the lifetime of the client and
+    /// revalidated with `If-None-Match`, so unchanged documents are not downloaded again.
+    ///
+    /// When `scope_fallback` is configured for the package's scope, a package that is not
+    /// found on the first registry is requested from the second, and the registry that
+    /// answered is logged.
+    ///
+    /// In offline mode a document cached from either registry is returned, a full
+    /// document also answering an abbreviated request; otherwise the lookup fails with
+    /// `UpgradeError::OfflineSkipped` without a request. A lookup still running when the
+    /// global deadline passes fails with `UpgradeError::DeadlineExceeded`.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - Name of the package (e.g., "express" or "@scope/package")
+    /// * `detail` - Level of detail to request
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`RegistryClient::get_package_info`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::upgrade::{MetadataDetail, RegistryClient};
+    /// use sublime_pkg_tools::config::RegistryConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = RegistryClient::new(&PathBuf::from("."), RegistryConfig::default()).await?;
+    /// let metadata = client.get_package_metadata("typescript", MetadataDetail::Abbreviated).await?;
+    /// println!("Latest version: {}", metadata.latest);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_package_metadata(
+        &self,
+        package_name: &str,
+        detail: MetadataDetail,
+    ) -> Result<PackageMetadata, UpgradeError> {
+        let (registry_url, fallback_url) = self.resolve_registry_order(package_name);
+        if is_offline_mode() {
+            let registries = std::iter::once(registry_url.as_str()).chain(fallback_url.as_deref());
+            return self
+                .cached_metadata(package_name, registries, detail)
+                .await
+                .ok_or_else(|| UpgradeError::OfflineSkipped { package: package_name.to_string() });
+        }
+
+        let operation = format!("registry lookup for '{package_name}'");
+        global_deadline()
+            .run(
+                &operation,
+                self.fetch_with_fallback(package_name, &registry_url, fallback_url, detail),
+            )
+            .await
+            .map_err(|_| UpgradeError::DeadlineExceeded { operation, partial: None })?
+    }
 
-        // Add Accept header - use standard application/json for compatibility
-        // with both npm registry and enterprise proxies like Artifactory
-        request = request.header("Accept", "application/json");
+    /// Requests the metadata from the registry, then from the fallback registry if the
+    /// package is not found on the first.
+    async fn fetch_with_fallback(
+        &self,
+        package_name: &str,
+        registry_url: &str,
+        fallback_url: Option<String>,
+        detail: MetadataDetail,
+    ) -> Result<PackageMetadata, UpgradeError> {
+        let result = self.fetch_package_metadata(package_name, registry_url, detail).await;
 
-        if let Some(cred) = self.resolve_auth_token(&registry_url) {
-            use crate::upgrade::registry::npmrc::AuthType;
+        let (Err(UpgradeError::PackageNotFound { .. }), Some(fallback_url)) =
+            (&result, fallback_url)
+        else {
+            return result;
+        };
 
-            let auth_header = match cred.auth_type {
-                AuthType::Bearer => format!("Bearer {}", cred.value),
-                AuthType::Basic => format!("Basic {}", cred.value),
-            };
-            request = request.header(AUTHORIZATION, auth_header);
+        log::info!(
+            "Package '{}' not found on {}, trying fallback registry {}",
+            package_name,
+            registry_url,
+            fallback_url
+        );
+        match self.fetch_package_metadata(package_name, &fallback_url, detail).await {
+            Ok(metadata) => {
+                log::info!(
+                    "Package '{}' resolved from fallback registry {}",
+                    package_name,
+                    fallback_url
+                );
+                Ok(metadata)
+            }
+            Err(UpgradeError::PackageNotFound { .. }) => Err(UpgradeError::PackageNotFound {
+                package: package_name.to_string(),
+                registry: format!("{registry_url}, {fallback_url}"),
+            }),
+            Err(e) => Err(e),
         }
+    }
 
-        // Execute request
-        let response = request.send().await.map_err(|e| {
-            if e.is_timeout() {
-                UpgradeError::RegistryTimeout {
-                    package: package_name.to_string(),
-                    timeout_secs: self.config.timeout_secs,
-                }
-            } else {
-                UpgradeError::NetworkError {
-                    reason: format!("Failed to query registry for '{}': {}", package_name, e),
+    /// Returns metadata cached from an earlier request to one of the registries.
+    ///
+    /// A full document also answers a request for abbreviated metadata.
+    async fn cached_metadata<'a>(
+        &self,
+        package_name: &str,
+        registries: impl Iterator<Item = &'a str>,
+        detail: MetadataDetail,
+    ) -> Option<PackageMetadata> {
+        let cache = self.document_cache.read().await;
+        for registry_url in registries {
+            let package_url = format!("{}/{}", registry_url.trim_end_matches('/'), package_name);
+            for candidate in [detail, MetadataDetail::Full] {
+                if let Some(cached) = cache.get(&(package_url.clone(), candidate)) {
+                    return Some(cached.metadata.clone());
                 }
             }
-        })?;
+        }
+        None
+    }
+
+    /// Queries package metadata from one registry.
+    pub(crate) async fn fetch_package_metadata(
+        &self,
+        package_name: &str,
+        registry_url: &str,
+        detail: MetadataDetail,
+    ) -> Result<PackageMetadata, UpgradeError> {
+        let package_url = format!("{}/{}", registry_url.trim_end_matches('/'), package_name);
+
+        let mut detail =
+            if self.uses_abbreviated_metadata() { detail } else { MetadataDetail::Full };
+        let mut response =
+            self.send_document_request(package_name, &package_url, registry_url, detail).await?;
+
+        // Registries that cannot serve abbreviated documents may reject the request outright
+        if detail == MetadataDetail::Abbreviated
+            && matches!(
+                response.status(),
+                StatusCode::NOT_ACCEPTABLE | StatusCode::UNSUPPORTED_MEDIA_TYPE
+            )
+        {
+            log::debug!(
+                "Registry rejected abbreviated metadata for '{}', requesting full document",
+                package_name
+            );
+            detail = MetadataDetail::Full;
+            response = self
+                .send_document_request(package_name, &package_url, registry_url, detail)
+                .await?;
+        }
+
+        let cache_key = (package_url, detail);
 
         // Handle HTTP errors
         let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            return self
+                .document_cache
+                .read()
+                .await
+                .get(&cache_key)
+                .map(|cached| cached.metadata.clone())
+                .ok_or_else(|| UpgradeError::InvalidResponse {
+                    package: package_name.to_string(),
+                    reason: "Registry returned 304 Not Modified for an uncached document"
+                        .to_string(),
+                });
+        }
         if !status.is_success() {
             if status.as_u16() == 404 {
                 return Err(UpgradeError::PackageNotFound {
                     package: package_name.to_string(),
-                    registry: registry_url,
+                    registry: registry_url.to_string(),
                 });
             } else if status.as_u16() == 401 || status.as_u16() == 403 {
                 return Err(UpgradeError::AuthenticationFailed {
-                    registry: registry_url,
+                    registry: registry_url.to_string(),
                     reason: format!("HTTP {}: Authentication required", status.as_u16()),
                 });
+            } else if let Some(error) = rate_limit_error(package_name, registry_url, &response) {
+                return Err(error);
             } else {
                 return Err(UpgradeError::RegistryError {
                     package: package_name.to_string(),
@@ -285,6 +587,9 @@ impl RegistryClient {
             }
         }
 
+        let etag =
+            response.headers().get(ETAG).and_then(|value| value.to_str().ok()).map(str::to_string);
+
         // Parse response
         let registry_response: RegistryResponse =
             response.json().await.map_err(|e| UpgradeError::InvalidResponse {
@@ -293,7 +598,71 @@ impl RegistryClient {
             })?;
 
         // Convert to PackageMetadata
-        self.convert_to_metadata(registry_response, package_name)
+        let metadata = self.convert_to_metadata(registry_response, package_name)?;
+
+        if let Some(etag) = etag {
+            self.document_cache
+                .write()
+                .await
+                .insert(cache_key, CachedDocument { etag, metadata: metadata.clone() });
+        }
+
+        Ok(metadata)
+    }
+
+    /// Returns the registry configuration the client was created with.
+    pub(crate) fn config(&self) -> &RegistryConfig {
+        &self.config
+    }
+
+    /// Returns whether abbreviated package documents are requested.
+    pub(crate) fn uses_abbreviated_metadata(&self) -> bool {
+        self.config.abbreviated_metadata
+    }
+
+    /// Sends a request for a package document.
+    ///
+    /// Adds authentication when available, and `If-None-Match` when a document for the
+    /// same URL and detail level was cached with an `ETag`.
+    async fn send_document_request(
+        &self,
+        package_name: &str,
+        package_url: &str,
+        registry_url: &str,
+        detail: MetadataDetail,
+    ) -> Result<reqwest::Response, UpgradeError> {
+        // Build request with authentication if available
+        let mut request = self.http_client.get(package_url).header(ACCEPT, detail.accept_header());
+
+        if let Some(cached) =
+            self.document_cache.read().await.get(&(package_url.to_string(), detail))
+        {
+            request = request.header(IF_NONE_MATCH, cached.etag.as_str());
+        }
+
+        if let Some(cred) = self.resolve_auth_token(registry_url) {
+            use crate::upgrade::registry::npmrc::AuthType;
+
+            let auth_header = match cred.auth_type {
+                AuthType::Bearer => format!("Bearer {}", cred.value),
+                AuthType::Basic => format!("Basic {}", cred.value),
+            };
+            request = request.header(AUTHORIZATION, auth_header);
+        }
+
+        // Execute request
+        request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                UpgradeError::RegistryTimeout {
+                    package: package_name.to_string(),
+                    timeout_secs: self.config.timeout_secs,
+                }
+            } else {
+                UpgradeError::NetworkError {
+                    reason: format!("Failed to query registry for '{}': {}", package_name, e),
+                }
+            }
+        })
     }
 
     /// Gets the latest version for a package.
@@ -333,6 +702,215 @@ impl RegistryClient {
         Ok(metadata.latest)
     }
 
+    /// Fetches weekly download statistics for a package.
+    ///
+    /// Queries the npm downloads API for the last two weeks and compares them to compute
+    /// a trend. Results are cached for the lifetime of the client, including failed lookups,
+    /// so repeated calls for the same package never hit the network twice.
+    ///
+    /// Statistics are only requested for packages resolved against the default registry,
+    /// and never in offline mode.
+    /// Packages served from scoped or `.npmrc` registries return `None` so private package
+    /// names are never disclosed to the public downloads API.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - Name of the package (e.g., "express" or "@scope/package")
+    ///
+    /// # Returns
+    ///
+    /// The download statistics, or `None` when they are unavailable. Download statistics are
+    /// informational only, so network or parsing failures are logged and reported as `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::upgrade::RegistryClient;
+    /// use sublime_pkg_tools::config::RegistryConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = RegistryClient::new(&PathBuf::from("."), RegistryConfig::default()).await?;
+    /// if let Some(stats) = client.get_download_stats("react").await {
+    ///     println!("{} weekly downloads ({})", stats.weekly_downloads, stats.trend);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_download_stats(&self, package_name: &str) -> Option<DownloadStats> {
+        if let Some(cached) = self.download_stats_cache.read().await.get(package_name) {
+            return *cached;
+        }
+        if is_offline_mode() {
+            return None;
+        }
+
+        let stats = if self.is_public_package(package_name) {
+            let operation = format!("download stats for '{package_name}'");
+            match global_deadline().run(&operation, self.fetch_download_stats(package_name)).await {
+                Ok(Ok(stats)) => Some(stats),
+                Ok(Err(e)) => {
+                    log::warn!("Failed to fetch download stats for '{}': {}", package_name, e);
+                    None
+                }
+                // Not cached, so a later run with more time fetches them
+                Err(e) => {
+                    log::warn!("{e}");
+                    return None;
+                }
+            }
+        } else {
+            None
+        };
+
+        self.download_stats_cache.write().await.insert(package_name.to_string(), stats);
+        stats
+    }
+
+    /// Returns whether a package resolves to the default (public) registry.
+    fn is_public_package(&self, package_name: &str) -> bool {
+        self.resolve_registry_url(package_name).trim_end_matches('/')
+            == self.config.default_registry.trim_end_matches('/')
+    }
+
+    /// Queries the downloads API for the last two weeks of daily downloads.
+    async fn fetch_download_stats(
+        &self,
+        package_name: &str,
+    ) -> Result<DownloadStats, UpgradeError> {
+        // The downloads API only has complete data up to the previous day
+        let end = Utc::now().date_naive() - ChronoDuration::days(1);
+        let previous_start = end - ChronoDuration::days(DOWNLOAD_WINDOW_DAYS * 2 - 1);
+        let current_start = end - ChronoDuration::days(DOWNLOAD_WINDOW_DAYS - 1);
+
+        let url = format!(
+            "{}/downloads/range/{}:{}/{}",
+            self.config.downloads_api_url.trim_end_matches('/'),
+            previous_start.format("%Y-%m-%d"),
+            end.format("%Y-%m-%d"),
+            package_name
+        );
+
+        let response =
+            self.http_client.get(&url).header("Accept", "application/json").send().await.map_err(
+                |e| UpgradeError::NetworkError {
+                    reason: format!("Failed to query download stats for '{}': {}", package_name, e),
+                },
+            )?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(UpgradeError::RegistryError {
+                package: package_name.to_string(),
+                reason: format!("Downloads API returned HTTP {}", status.as_u16()),
+            });
+        }
+
+        let range: DownloadsRangeResponse =
+            response.json().await.map_err(|e| UpgradeError::InvalidResponse {
+                package: package_name.to_string(),
+                reason: format!("Failed to parse downloads response: {}", e),
+            })?;
+
+        let (current, previous) =
+            range.downloads.iter().fold((0u64, 0u64), |(current, previous), day| {
+                if day.day >= current_start {
+                    (current.saturating_add(day.downloads), previous)
+                } else {
+                    (current, previous.saturating_add(day.downloads))
+                }
+            });
+
+        Ok(DownloadStats::from_weeks(current, previous))
+    }
+
+    /// Fetches the Node.js releases listed by the distribution index.
+    ///
+    /// Queries `index.json` under `node_dist_url`. The list is cached for the lifetime of
+    /// the client. In offline mode the cached list is returned if there is one.
+    ///
+    /// # Returns
+    ///
+    /// The releases, newest first, with their LTS codename.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError` if:
+    /// - The index cannot be fetched or parsed
+    /// - Offline mode is enabled and no list is cached (`OfflineSkipped`)
+    /// - The global deadline passes before the index is fetched (`DeadlineExceeded`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sublime_pkg_tools::upgrade::RegistryClient;
+    /// use sublime_pkg_tools::config::RegistryConfig;
+    /// use std::path::PathBuf;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = RegistryClient::new(&PathBuf::from("."), RegistryConfig::default()).await?;
+    /// let releases = client.get_node_releases().await?;
+    /// if let Some(lts) = releases.iter().find(|release| release.is_lts()) {
+    ///     println!("Latest LTS: {}", lts.version);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_node_releases(&self) -> Result<Vec<NodeRelease>, UpgradeError> {
+        if let Some(cached) = self.node_releases_cache.read().await.as_ref() {
+            return Ok(cached.clone());
+        }
+        if is_offline_mode() {
+            return Err(UpgradeError::OfflineSkipped { package: "node".to_string() });
+        }
+
+        let operation = "Node.js release lookup".to_string();
+        let releases = global_deadline()
+            .run(&operation, self.fetch_node_releases())
+            .await
+            .map_err(|_| UpgradeError::DeadlineExceeded { operation, partial: None })??;
+
+        *self.node_releases_cache.write().await = Some(releases.clone());
+        Ok(releases)
+    }
+
+    /// Downloads and parses the Node.js distribution index.
+    async fn fetch_node_releases(&self) -> Result<Vec<NodeRelease>, UpgradeError> {
+        let url = format!("{}/index.json", self.config.node_dist_url.trim_end_matches('/'));
+
+        let response =
+            self.http_client.get(&url).header("Accept", "application/json").send().await.map_err(
+                |e| UpgradeError::NetworkError {
+                    reason: format!("Failed to query Node.js releases: {}", e),
+                },
+            )?;
+
+        let status = response.status();
+        if let Some(error) = rate_limit_error("node", &self.config.node_dist_url, &response) {
+            return Err(error);
+        }
+        if !status.is_success() {
+            return Err(UpgradeError::RegistryError {
+                package: "node".to_string(),
+                reason: format!("Node.js distribution index returned HTTP {}", status.as_u16()),
+            });
+        }
+
+        let entries: Vec<NodeIndexEntry> =
+            response.json().await.map_err(|e| UpgradeError::InvalidResponse {
+                package: "node".to_string(),
+                reason: format!("Failed to parse Node.js distribution index: {}", e),
+            })?;
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| NodeRelease {
+                version: entry.version.trim_start_matches('v').to_string(),
+                lts: entry.lts.as_str().map(String::from),
+            })
+            .collect())
+    }
+
     /// Compares two versions and determines the upgrade type.
     ///
     /// Uses semantic versioning to classify the upgrade as major, minor, or patch.
@@ -459,6 +1037,29 @@ impl RegistryClient {
         self.config.default_registry.clone()
     }
 
+    /// Resolves the registries queried for a package, in order.
+    ///
+    /// The first registry is the one from [`resolve_registry_url`](Self::resolve_registry_url),
+    /// or the default registry under a `default-first` scope fallback. The second is the
+    /// registry queried when the package is not found on the first, if a fallback is
+    /// configured for the package's scope and it differs from the first.
+    pub(crate) fn resolve_registry_order(&self, package_name: &str) -> (String, Option<String>) {
+        let scope_registry = self.resolve_registry_url(package_name);
+        let default_registry = self.config.default_registry.clone();
+
+        let (first, second) = match self.config.fallback_for(package_name) {
+            Some("scope-first") => (scope_registry, default_registry),
+            Some("default-first") => (default_registry, scope_registry),
+            _ => return (scope_registry, None),
+        };
+
+        if first.trim_end_matches('/') == second.trim_end_matches('/') {
+            (first, None)
+        } else {
+            (first, Some(second))
+        }
+    }
+
     /// Resolves the authentication credential for a registry URL.
     ///
     /// Checks the auth_tokens configuration for a matching credential.
@@ -529,11 +1130,20 @@ impl RegistryClient {
         // Check if any version is deprecated
         let deprecated = response.versions.get(&latest).and_then(|v| v.deprecated.clone());
 
-        // Parse time metadata
+        let version_deprecations: HashMap<String, String> = response
+            .versions
+            .iter()
+            .filter_map(|(version, info)| {
+                info.deprecated.as_ref().map(|message| (version.clone(), message.clone()))
+            })
+            .collect();
+
+        // Parse time metadata; abbreviated documents only report the last modification
         let mut time = HashMap::new();
-        for (key, value) in response.time {
+        let modified = response.modified.map(|value| ("modified".to_string(), value));
+        for (key, value) in response.time.into_iter().chain(modified) {
             if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(&value) {
-                time.insert(key, dt.with_timezone(&chrono::Utc));
+                time.entry(key).or_insert_with(|| dt.with_timezone(&chrono::Utc));
             }
         }
 
@@ -544,6 +1154,28 @@ impl RegistryClient {
             deprecated,
             time,
             repository: response.repository,
+            version_deprecations,
+            maintainers: response.maintainers.into_iter().map(Maintainer::into_name).collect(),
         })
     }
 }
+
+/// Maps a 429 or 503 response to its error, carrying the `Retry-After` wait.
+fn rate_limit_error(
+    package_name: &str,
+    registry_url: &str,
+    response: &reqwest::Response,
+) -> Option<UpgradeError> {
+    let package = package_name.to_string();
+    let registry = registry_url.to_string();
+    let retry_after = retry_after(response);
+    match response.status() {
+        StatusCode::TOO_MANY_REQUESTS => {
+            Some(UpgradeError::RateLimited { package, registry, retry_after })
+        }
+        StatusCode::SERVICE_UNAVAILABLE => {
+            Some(UpgradeError::ServiceUnavailable { package, registry, retry_after })
+        }
+        _ => None,
+    }
+}
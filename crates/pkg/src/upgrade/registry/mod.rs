@@ -19,6 +19,13 @@
 //! - **Authentication**: Bearer token authentication for private packages
 //! - **Timeout Handling**: Configurable timeouts with proper error reporting
 //! - **Version Comparison**: Semantic versioning comparison and upgrade type detection
+//! - **Abbreviated Metadata**: Requests compact `application/vnd.npm.install-v1+json` documents
+//!   when publish times and repository information are not needed
+//! - **Proxies and Custom CAs**: Honors `[package_tools.upgrade.registry.tls]`, `.npmrc`
+//!   `proxy`/`https-proxy`/`noproxy`/`cafile`/`strict-ssl`, and `HTTPS_PROXY`/`NO_PROXY`
+//! - **Conditional Requests**: Revalidates previously fetched documents with `If-None-Match`,
+//!   reusing them when the registry answers `304 Not Modified`
+//! - **Rate Limiting**: Spaces requests out to at most `requests_per_second` per second
 //!
 //! # Example
 //!
@@ -98,6 +105,8 @@
 //! retry_attempts = 3
 //! retry_delay_ms = 1000
 //! read_npmrc = true
+//! abbreviated_metadata = true
+//! requests_per_second = 20
 //!
 //! [package_tools.upgrade.registry.scoped]
 //! "@myorg" = "https://npm.myorg.com"
@@ -120,6 +129,8 @@
 //! This module is private with public types re-exported through the parent `upgrade` module:
 //!
 //! - `client`: Main `RegistryClient` implementation (pub(crate))
+//! - `rate_limit`: Client-side request rate limiting (pub(crate))
+//! - `transport`: Proxy and TLS settings for registry requests (pub(crate))
 //! - `types`: Data structures for registry responses and upgrade types (pub(crate))
 //! - `tests`: Integration tests with mock HTTP server
 //!
@@ -127,6 +138,9 @@
 
 pub(crate) mod client;
 pub mod npmrc;
+pub(crate) mod rate_limit;
+pub(crate) mod retry;
+pub(crate) mod transport;
 pub(crate) mod types;
 
 #[cfg(test)]
@@ -134,4 +148,8 @@ mod tests;
 
 // Re-export public API
 pub use self::client::RegistryClient;
-pub use self::types::{PackageMetadata, RepositoryInfo, UpgradeType};
+pub use self::retry::{RegistryRetry, RegistryRetryListener};
+pub use self::types::{
+    DownloadStats, DownloadTrend, MetadataDetail, NodeRelease, PackageMetadata, RepositoryInfo,
+    UpgradeType,
+};
@@ -19,6 +19,7 @@
 //! - **Authentication**: Extracts auth tokens with environment variable support
 //! - **Comment Handling**: Ignores # and // style comments
 //! - **Environment Variables**: Substitutes ${VAR_NAME} placeholders
+//! - **Network Settings**: Exposes `https-proxy`, `proxy`, `noproxy`, `cafile`, and `strict-ssl`
 //!
 //! # .npmrc Format
 //!
@@ -344,6 +345,52 @@ impl NpmrcConfig {
         None
     }
 
+    /// Returns the proxy URL for registry requests.
+    ///
+    /// Prefers `https-proxy` over `proxy`, as npm does for HTTPS registries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::NpmrcConfig;
+    ///
+    /// let mut config = NpmrcConfig::default();
+    /// config.other.insert("proxy".to_string(), "http://proxy:8080".to_string());
+    /// assert_eq!(config.proxy(), Some("http://proxy:8080"));
+    /// ```
+    pub fn proxy(&self) -> Option<&str> {
+        self.other.get("https-proxy").or_else(|| self.other.get("proxy")).map(String::as_str)
+    }
+
+    /// Returns the hosts that bypass the proxy (`noproxy`).
+    pub fn no_proxy(&self) -> Option<&str> {
+        self.other.get("noproxy").map(String::as_str)
+    }
+
+    /// Returns the path of the CA certificate file (`cafile`).
+    pub fn cafile(&self) -> Option<&str> {
+        self.other.get("cafile").map(String::as_str)
+    }
+
+    /// Returns whether TLS certificates are verified (`strict-ssl`).
+    ///
+    /// Returns `None` when the setting is absent or not a boolean.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::NpmrcConfig;
+    ///
+    /// let mut config = NpmrcConfig::default();
+    /// assert_eq!(config.strict_ssl(), None);
+    ///
+    /// config.other.insert("strict-ssl".to_string(), "false".to_string());
+    /// assert_eq!(config.strict_ssl(), Some(false));
+    /// ```
+    pub fn strict_ssl(&self) -> Option<bool> {
+        self.other.get("strict-ssl").and_then(|value| value.parse().ok())
+    }
+
     /// Parses a single .npmrc file.
     ///
     /// Reads the file content and parses line by line, handling comments,
@@ -0,0 +1,66 @@
+//! Client-side rate limiting of registry requests.
+//!
+//! **What**: Provides the middleware that spaces registry requests out to at most
+//! `requests_per_second` per second.
+//!
+//! **How**: Each request reserves the next start slot of an evenly spaced schedule shared by
+//! all requests of a client, and waits for it before it is sent. Concurrent lookups therefore
+//! queue up instead of bursting. The middleware runs inside the retry middlewares, so every
+//! retry takes a slot too.
+//!
+//! **Why**: Corporate proxies and private registries often cap the requests a client may
+//! make. Staying under the cap avoids rate limited responses and blocked scans on workspaces
+//! with thousands of dependencies.
+
+use http::Extensions;
+use reqwest::{Request, Response};
+use reqwest_middleware::{Middleware, Next};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Middleware starting at most `requests_per_second` requests per second.
+pub(crate) struct RateLimitMiddleware {
+    /// Time between two request starts.
+    interval: Duration,
+    /// Earliest start of the next request.
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimitMiddleware {
+    /// Creates the middleware. A rate of `0` is treated as one request per second.
+    pub(crate) fn new(requests_per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / requests_per_second.max(1),
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Waits for the next free start slot.
+    pub(crate) async fn acquire(&self) {
+        let now = Instant::now();
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let slot = next_slot.map_or(now, |next| next.max(now));
+            *next_slot = Some(slot + self.interval);
+            slot
+        };
+        if slot > now {
+            log::trace!("Registry rate limit: waiting {:?}", slot - now);
+            tokio::time::sleep_until(slot).await;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        self.acquire().await;
+        next.run(req, extensions).await
+    }
+}
@@ -0,0 +1,150 @@
+//! `Retry-After` handling for registry requests.
+//!
+//! **What**: Provides the middleware that retries rate limited (HTTP 429) and unavailable
+//! (HTTP 503) registry responses after the wait requested by their `Retry-After` header,
+//! and the `RegistryRetry` notices it sends to an optional listener.
+//!
+//! **How**: The middleware wraps the transient retry middleware. Responses with a
+//! `Retry-After` header are left to it by the transient middleware's strategy, so they are
+//! retried after the requested wait instead of an exponential backoff. Requests are retried
+//! at most `retry_attempts` times, and never when the registry asks for longer than
+//! `max_retry_after_secs`; the last response is then returned to the client, which turns
+//! it into `UpgradeError::RateLimited` or `UpgradeError::ServiceUnavailable`.
+//!
+//! **Why**: Registries that rate limit a large scan say exactly when to come back. Retrying
+//! sooner only extends the limit, and retrying blindly hides from the user why the scan
+//! stalled.
+
+use chrono::{DateTime, Utc};
+use http::Extensions;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::{
+    Retryable, RetryableStrategy, default_on_request_failure, default_on_request_success,
+};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// A registry request about to be retried after a `Retry-After` wait.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryRetry {
+    /// URL of the request.
+    pub url: String,
+    /// HTTP status of the response, 429 or 503.
+    pub status: u16,
+    /// Number of the retry about to be made, starting at 1.
+    pub attempt: u32,
+    /// Maximum number of retries.
+    pub max_retries: u32,
+    /// Wait before the retry, as requested by the registry.
+    pub wait: Duration,
+}
+
+/// Callback notified before each `Retry-After` wait.
+pub type RegistryRetryListener = Arc<dyn Fn(&RegistryRetry) + Send + Sync>;
+
+/// Listener slot shared by a registry client and its middleware.
+pub(crate) type ListenerSlot = Arc<RwLock<Option<RegistryRetryListener>>>;
+
+/// Middleware retrying 429 and 503 responses after their `Retry-After` wait.
+pub(crate) struct RetryAfterMiddleware {
+    /// Maximum number of retries per request.
+    max_retries: u32,
+    /// Longest wait honored.
+    max_wait: Duration,
+    /// Listener notified before each wait.
+    listener: ListenerSlot,
+}
+
+impl RetryAfterMiddleware {
+    /// Creates the middleware.
+    pub(crate) fn new(max_retries: u32, max_wait: Duration, listener: ListenerSlot) -> Self {
+        Self { max_retries, max_wait, listener }
+    }
+
+    /// Notifies the listener, if any, and logs the retry.
+    fn notify(&self, retry: &RegistryRetry) {
+        log::warn!(
+            "Registry returned HTTP {} for {}; retrying in {}s (attempt {}/{})",
+            retry.status,
+            retry.url,
+            retry.wait.as_secs(),
+            retry.attempt,
+            retry.max_retries
+        );
+        let listener = self.listener.read().ok().and_then(|slot| slot.clone());
+        if let Some(listener) = listener {
+            listener(retry);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryAfterMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut attempt = 0;
+        loop {
+            // Requests with streaming bodies cannot be replayed
+            let Some(retry_request) = req.try_clone() else {
+                return next.run(req, extensions).await;
+            };
+            let response = next.clone().run(retry_request, extensions).await?;
+
+            let wait = match retry_after(&response) {
+                Some(wait) if attempt < self.max_retries && wait <= self.max_wait => wait,
+                _ => return Ok(response),
+            };
+
+            attempt += 1;
+            self.notify(&RegistryRetry {
+                url: req.url().to_string(),
+                status: response.status().as_u16(),
+                attempt,
+                max_retries: self.max_retries,
+                wait,
+            });
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Transient retry strategy that leaves `Retry-After` responses to `RetryAfterMiddleware`.
+pub(crate) struct RegistryRetryStrategy;
+
+impl RetryableStrategy for RegistryRetryStrategy {
+    fn handle(&self, res: &Result<Response, reqwest_middleware::Error>) -> Option<Retryable> {
+        match res {
+            Ok(response) if retry_after(response).is_some() => None,
+            Ok(response) => default_on_request_success(response),
+            Err(error) => default_on_request_failure(error),
+        }
+    }
+}
+
+/// Returns the `Retry-After` wait of a 429 or 503 response.
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    if !matches!(response.status(), StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE)
+    {
+        return None;
+    }
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+    parse_retry_after(value, Utc::now())
+}
+
+/// Parses a `Retry-After` value: delay seconds, or an HTTP date relative to `now`.
+///
+/// Dates in the past yield a zero wait.
+pub(crate) fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    Some((date - now).to_std().unwrap_or(Duration::ZERO))
+}
@@ -18,7 +18,7 @@ mod integration_tests {
     use crate::config::RegistryConfig;
     use crate::error::UpgradeError;
     use crate::upgrade::registry::npmrc::AuthType;
-    use crate::upgrade::{RegistryClient, UpgradeType};
+    use crate::upgrade::{DownloadTrend, MetadataDetail, RegistryClient, UpgradeType};
     use mockito::Server;
     use std::path::PathBuf;
 
@@ -688,6 +688,114 @@ mod integration_tests {
         assert_eq!(url, config.default_registry);
     }
 
+    #[tokio::test]
+    async fn test_resolve_registry_order_with_scope_fallback() {
+        let mut config = test_config();
+        config.scoped_registries.insert("myorg".to_string(), "https://npm.myorg.com".to_string());
+        config.scope_fallback.insert("myorg".to_string(), "default-first".to_string());
+        config.scope_fallback.insert("other".to_string(), "scope-first".to_string());
+
+        let client = RegistryClient::new(std::path::Path::new("."), config.clone())
+            .await
+            .expect("Failed to create client");
+
+        assert_eq!(
+            client.resolve_registry_order("@myorg/package"),
+            (config.default_registry.clone(), Some("https://npm.myorg.com".to_string()))
+        );
+        // No scope registry, so both orders resolve to the default registry
+        assert_eq!(
+            client.resolve_registry_order("@other/package"),
+            (config.default_registry.clone(), None)
+        );
+        assert_eq!(client.resolve_registry_order("lodash"), (config.default_registry, None));
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_falls_back_to_default_registry() {
+        let mut scope_server = Server::new_async().await;
+        let mut default_server = Server::new_async().await;
+
+        let not_found = scope_server
+            .mock("GET", "/@myorg/utils")
+            .with_status(404)
+            .with_body("Not found")
+            .create_async()
+            .await;
+        let response = create_package_response("@myorg/utils", &["1.0.0", "1.1.0"], "1.1.0", None);
+        let found = default_server
+            .mock("GET", "/@myorg/utils")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = default_server.url();
+        config.scoped_registries.insert("myorg".to_string(), scope_server.url());
+        config.scope_fallback.insert("myorg".to_string(), "scope-first".to_string());
+
+        let client = RegistryClient::new(&PathBuf::from("."), config)
+            .await
+            .expect("Failed to create client");
+
+        let metadata = client.get_package_info("@myorg/utils").await.unwrap();
+
+        not_found.assert_async().await;
+        found.assert_async().await;
+        assert_eq!(metadata.latest, "1.1.0");
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_not_found_on_either_registry() {
+        let mut scope_server = Server::new_async().await;
+        let mut default_server = Server::new_async().await;
+
+        let _scope =
+            scope_server.mock("GET", "/@myorg/utils").with_status(404).create_async().await;
+        let _default =
+            default_server.mock("GET", "/@myorg/utils").with_status(404).create_async().await;
+
+        let mut config = test_config();
+        config.default_registry = default_server.url();
+        config.scoped_registries.insert("myorg".to_string(), scope_server.url());
+        config.scope_fallback.insert("myorg".to_string(), "default-first".to_string());
+
+        let client = RegistryClient::new(&PathBuf::from("."), config)
+            .await
+            .expect("Failed to create client");
+
+        match client.get_package_info("@myorg/utils").await {
+            Err(UpgradeError::PackageNotFound { registry, .. }) => {
+                assert_eq!(registry, format!("{}, {}", default_server.url(), scope_server.url()));
+            }
+            other => panic!("Expected PackageNotFound, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_without_fallback_does_not_retry() {
+        let mut scope_server = Server::new_async().await;
+        let mut default_server = Server::new_async().await;
+
+        let _scope =
+            scope_server.mock("GET", "/@myorg/utils").with_status(404).create_async().await;
+        let default =
+            default_server.mock("GET", mockito::Matcher::Any).expect(0).create_async().await;
+
+        let mut config = test_config();
+        config.default_registry = default_server.url();
+        config.scoped_registries.insert("myorg".to_string(), scope_server.url());
+
+        let client = RegistryClient::new(&PathBuf::from("."), config)
+            .await
+            .expect("Failed to create client");
+
+        assert!(client.get_package_info("@myorg/utils").await.is_err());
+        default.assert_async().await;
+    }
+
     #[tokio::test]
     async fn test_resolve_auth_token_exact_match() {
         let mut config = test_config();
@@ -730,6 +838,295 @@ mod integration_tests {
         let cred = client.resolve_auth_token("https://unknown.com");
         assert_eq!(cred, None);
     }
+
+    #[tokio::test]
+    async fn test_get_package_info_version_deprecations() {
+        let mut server = Server::new_async().await;
+
+        let mut response =
+            create_package_response("request", &["2.87.0", "2.88.0"], "2.88.0", None);
+        response["versions"]["2.87.0"]["deprecated"] = serde_json::json!("security issue");
+
+        let _mock = server
+            .mock("GET", "/request")
+            .with_status(200)
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata = client.get_package_info("request").await.unwrap();
+
+        assert!(!metadata.is_deprecated());
+        assert_eq!(metadata.version_deprecation("2.87.0"), Some("security issue"));
+        assert_eq!(metadata.version_deprecation("2.88.0"), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_maintainers() {
+        let mut server = Server::new_async().await;
+
+        let mut response = create_package_response("@acme/core", &["1.0.0"], "1.0.0", None);
+        response["maintainers"] = serde_json::json!([
+            { "name": "acme", "email": "npm@acme.dev" },
+            "jane <jane@acme.dev> (https://acme.dev)",
+        ]);
+
+        let _mock = server
+            .mock("GET", "/@acme/core")
+            .with_status(200)
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata = client.get_package_info("@acme/core").await.unwrap();
+
+        assert_eq!(metadata.maintainers, ["acme", "jane"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_download_stats_computes_trend_and_caches() {
+        let mut server = Server::new_async().await;
+
+        let days: Vec<serde_json::Value> = (0..14)
+            .map(|idx| {
+                let day = chrono::Utc::now().date_naive() - chrono::Duration::days(14 - idx);
+                let downloads = if idx < 7 { 100 } else { 200 };
+                serde_json::json!({ "day": day.format("%Y-%m-%d").to_string(), "downloads": downloads })
+            })
+            .collect();
+
+        let mock = server
+            .mock("GET", mockito::Matcher::Regex(r"^/downloads/range/.+:.+/express$".to_string()))
+            .with_status(200)
+            .with_body(serde_json::json!({ "package": "express", "downloads": days }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.downloads_api_url = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let stats = client.get_download_stats("express").await.expect("Should fetch stats");
+        let cached = client.get_download_stats("express").await.expect("Should use cache");
+
+        mock.assert_async().await;
+        assert_eq!(stats.weekly_downloads, 1_400);
+        assert_eq!(stats.previous_week_downloads, 700);
+        assert_eq!(stats.trend, DownloadTrend::Rising);
+        assert_eq!(stats, cached);
+    }
+
+    #[tokio::test]
+    async fn test_get_download_stats_failure_returns_none() {
+        let mut server = Server::new_async().await;
+
+        let _mock = server.mock("GET", mockito::Matcher::Any).with_status(404).create_async().await;
+
+        let mut config = test_config();
+        config.downloads_api_url = server.url();
+        config.retry_attempts = 0;
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        assert!(client.get_download_stats("missing-package").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_download_stats_skips_private_registries() {
+        let mut server = Server::new_async().await;
+
+        let mock = server.mock("GET", mockito::Matcher::Any).expect(0).create_async().await;
+
+        let mut config = test_config();
+        config.downloads_api_url = server.url();
+        config.scoped_registries.insert("myorg".to_string(), "https://npm.myorg.com".to_string());
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        assert!(client.get_download_stats("@myorg/private").await.is_none());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_package_metadata_abbreviated() {
+        let mut server = Server::new_async().await;
+
+        let response = serde_json::json!({
+            "name": "typescript",
+            "modified": "2024-06-01T00:00:00.000Z",
+            "dist-tags": { "latest": "5.4.0" },
+            "versions": {
+                "5.3.0": { "deprecated": "use 5.4.0" },
+                "5.4.0": {}
+            }
+        });
+
+        let mock = server
+            .mock("GET", "/typescript")
+            .match_header(
+                "accept",
+                mockito::Matcher::Regex(r"^application/vnd\.npm\.install-v1\+json".to_string()),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/vnd.npm.install-v1+json")
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata = client
+            .get_package_metadata("typescript", MetadataDetail::Abbreviated)
+            .await
+            .expect("Failed to get abbreviated metadata");
+
+        mock.assert_async().await;
+        assert_eq!(metadata.latest, "5.4.0");
+        assert_eq!(metadata.versions, vec!["5.3.0".to_string(), "5.4.0".to_string()]);
+        assert_eq!(metadata.version_deprecation("5.3.0"), Some("use 5.4.0"));
+        assert!(metadata.modified_at().is_some());
+        assert!(metadata.version_published_at("5.4.0").is_none());
+        assert!(metadata.repository.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_package_metadata_abbreviated_disabled() {
+        let mut server = Server::new_async().await;
+
+        let response = create_package_response("express", &["4.18.1"], "4.18.1", None);
+        let mock = server
+            .mock("GET", "/express")
+            .match_header("accept", "application/json")
+            .with_status(200)
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        config.abbreviated_metadata = false;
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata =
+            client.get_package_metadata("express", MetadataDetail::Abbreviated).await.unwrap();
+
+        mock.assert_async().await;
+        assert!(metadata.repository.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_package_metadata_falls_back_when_abbreviated_rejected() {
+        let mut server = Server::new_async().await;
+
+        let rejected = server
+            .mock("GET", "/express")
+            .match_header(
+                "accept",
+                mockito::Matcher::Regex(r"^application/vnd\.npm\.install-v1\+json".to_string()),
+            )
+            .with_status(406)
+            .create_async()
+            .await;
+        let response = create_package_response("express", &["4.18.1"], "4.18.1", None);
+        let full = server
+            .mock("GET", "/express")
+            .match_header("accept", "application/json")
+            .with_status(200)
+            .with_body(response.to_string())
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        config.retry_attempts = 0;
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata =
+            client.get_package_metadata("express", MetadataDetail::Abbreviated).await.unwrap();
+
+        rejected.assert_async().await;
+        full.assert_async().await;
+        assert_eq!(metadata.latest, "4.18.1");
+        assert!(metadata.repository.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_revalidates_with_etag() {
+        let mut server = Server::new_async().await;
+
+        let response = create_package_response("express", &["4.18.1"], "4.18.1", None);
+        let first = server
+            .mock("GET", "/express")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body(response.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let revalidated = server
+            .mock("GET", "/express")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let fetched = client.get_package_info("express").await.unwrap();
+        let cached = client.get_package_info("express").await.unwrap();
+
+        first.assert_async().await;
+        revalidated.assert_async().await;
+        assert_eq!(fetched, cached);
+    }
+
+    #[tokio::test]
+    async fn test_get_package_info_without_etag_is_not_cached() {
+        let mut server = Server::new_async().await;
+
+        let response = create_package_response("express", &["4.18.1"], "4.18.1", None);
+        let mock = server
+            .mock("GET", "/express")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_body(response.to_string())
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut config = test_config();
+        config.default_registry = server.url();
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        client.get_package_info("express").await.unwrap();
+        client.get_package_info("express").await.unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_download_trend_classification() {
+        assert_eq!(DownloadTrend::from_weeks(0, 0), DownloadTrend::Stable);
+        assert_eq!(DownloadTrend::from_weeks(5, 0), DownloadTrend::Rising);
+        assert_eq!(DownloadTrend::from_weeks(109, 100), DownloadTrend::Stable);
+        assert_eq!(DownloadTrend::from_weeks(91, 100), DownloadTrend::Stable);
+        assert_eq!(DownloadTrend::from_weeks(110, 100), DownloadTrend::Rising);
+        assert_eq!(DownloadTrend::from_weeks(90, 100), DownloadTrend::Declining);
+        assert_eq!(DownloadTrend::from_weeks(u64::MAX, 1), DownloadTrend::Rising);
+    }
 }
 
 // ============================================================================
@@ -1125,6 +1522,30 @@ package-lock=false
         assert_eq!(config.other.get("package-lock"), Some(&"false".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_parse_network_settings() {
+        let mut fs = MockFileSystem::new();
+        fs.add_file(
+            "/workspace/.npmrc",
+            r#"
+proxy=http://proxy.corp:8080
+https-proxy=http://secure-proxy.corp:8443
+noproxy=localhost,.corp
+cafile=certs/corp.pem
+strict-ssl=false
+"#,
+        );
+
+        let config = NpmrcConfig::from_workspace(Path::new("/workspace"), &fs)
+            .await
+            .expect("Should parse network settings");
+
+        assert_eq!(config.proxy(), Some("http://secure-proxy.corp:8443"));
+        assert_eq!(config.no_proxy(), Some("localhost,.corp"));
+        assert_eq!(config.cafile(), Some("certs/corp.pem"));
+        assert_eq!(config.strict_ssl(), Some(false));
+    }
+
     #[tokio::test]
     async fn test_environment_variable_substitution() {
         unsafe {
@@ -1376,3 +1797,341 @@ legacy-peer-deps=false
         assert!(config.auth_tokens.is_empty());
     }
 }
+
+// ============================================================================
+// Transport Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+#[allow(clippy::panic)]
+mod transport_tests {
+    use crate::config::{RegistryConfig, RegistryTlsConfig};
+    use crate::error::UpgradeError;
+    use crate::upgrade::RegistryClient;
+    use crate::upgrade::registry::npmrc::NpmrcConfig;
+    use crate::upgrade::registry::transport::resolve_transport;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> =
+            vars.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    fn npmrc(settings: &[(&str, &str)]) -> NpmrcConfig {
+        let mut config = NpmrcConfig::default();
+        for (key, value) in settings {
+            config.other.insert((*key).to_string(), (*value).to_string());
+        }
+        config
+    }
+
+    #[test]
+    fn test_resolve_defaults() {
+        let settings = resolve_transport(
+            &RegistryTlsConfig::default(),
+            None,
+            Path::new("/workspace"),
+            env(&[]),
+        );
+
+        assert_eq!(settings.proxy, None);
+        assert_eq!(settings.no_proxy, None);
+        assert_eq!(settings.ca_file, None);
+        assert!(settings.strict_ssl);
+    }
+
+    #[test]
+    fn test_resolve_from_environment() {
+        let settings = resolve_transport(
+            &RegistryTlsConfig::default(),
+            None,
+            Path::new("/workspace"),
+            env(&[
+                ("HTTPS_PROXY", ""),
+                ("https_proxy", "http://https-proxy:3128"),
+                ("HTTP_PROXY", "http://http-proxy:3128"),
+                ("NO_PROXY", "localhost"),
+            ]),
+        );
+
+        assert_eq!(settings.proxy.as_deref(), Some("http://https-proxy:3128"));
+        assert_eq!(settings.no_proxy.as_deref(), Some("localhost"));
+    }
+
+    #[test]
+    fn test_resolve_npmrc_overrides_environment() {
+        let npmrc = npmrc(&[
+            ("proxy", "http://npmrc-proxy:8080"),
+            ("cafile", "certs/corp.pem"),
+            ("strict-ssl", "false"),
+        ]);
+
+        let settings = resolve_transport(
+            &RegistryTlsConfig::default(),
+            Some(&npmrc),
+            Path::new("/workspace"),
+            env(&[("HTTPS_PROXY", "http://env-proxy:3128"), ("NO_PROXY", "localhost")]),
+        );
+
+        assert_eq!(settings.proxy.as_deref(), Some("http://npmrc-proxy:8080"));
+        assert_eq!(settings.no_proxy.as_deref(), Some("localhost"));
+        assert_eq!(settings.ca_file, Some(PathBuf::from("/workspace/certs/corp.pem")));
+        assert!(!settings.strict_ssl);
+    }
+
+    #[test]
+    fn test_resolve_config_overrides_npmrc() {
+        let tls = RegistryTlsConfig {
+            proxy: Some("http://config-proxy:8080".to_string()),
+            no_proxy: Some(".corp".to_string()),
+            ca_file: Some("/etc/ssl/corp.pem".to_string()),
+            strict_ssl: Some(true),
+        };
+        let npmrc = npmrc(&[
+            ("https-proxy", "http://npmrc-proxy:8080"),
+            ("noproxy", "localhost"),
+            ("cafile", "certs/corp.pem"),
+            ("strict-ssl", "false"),
+        ]);
+
+        let settings = resolve_transport(&tls, Some(&npmrc), Path::new("/workspace"), env(&[]));
+
+        assert_eq!(settings.proxy.as_deref(), Some("http://config-proxy:8080"));
+        assert_eq!(settings.no_proxy.as_deref(), Some(".corp"));
+        assert_eq!(settings.ca_file, Some(PathBuf::from("/etc/ssl/corp.pem")));
+        assert!(settings.strict_ssl);
+    }
+
+    #[tokio::test]
+    async fn test_client_rejects_invalid_proxy() {
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.tls.proxy = Some("not a url".to_string());
+
+        let result = RegistryClient::new(&PathBuf::from("."), config).await;
+
+        assert!(matches!(result, Err(UpgradeError::InvalidConfig { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_client_rejects_missing_ca_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.tls.ca_file = Some("missing.pem".to_string());
+
+        let result = RegistryClient::new(temp.path(), config).await;
+
+        let Err(UpgradeError::InvalidConfig { reason }) = result else {
+            panic!("Expected InvalidConfig error");
+        };
+        assert!(reason.contains("missing.pem"));
+    }
+
+    #[tokio::test]
+    async fn test_client_rejects_ca_file_without_certificates() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("empty.pem"), "not a certificate").unwrap();
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.tls.ca_file = Some("empty.pem".to_string());
+
+        let result = RegistryClient::new(temp.path(), config).await;
+
+        assert!(matches!(result, Err(UpgradeError::InvalidConfig { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_client_sends_requests_through_proxy() {
+        let mut proxy = mockito::Server::new_async().await;
+        let body = serde_json::json!({
+            "name": "express",
+            "dist-tags": { "latest": "4.18.1" },
+            "versions": { "4.18.1": {} }
+        });
+        let mock = proxy
+            .mock("GET", mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.retry_attempts = 0;
+        config.default_registry = "http://registry.invalid".to_string();
+        config.tls.proxy = Some(proxy.url());
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let metadata = client.get_package_info("express").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(metadata.latest, "4.18.1");
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::field_reassign_with_default)]
+mod retry_tests {
+    use crate::config::RegistryConfig;
+    use crate::error::UpgradeError;
+    use crate::upgrade::registry::retry::parse_retry_after;
+    use crate::upgrade::{MetadataDetail, RegistryClient};
+    use chrono::{TimeZone, Utc};
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn test_config(registry: String) -> RegistryConfig {
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.retry_attempts = 2;
+        config.retry_delay_ms = 1;
+        config.default_registry = registry;
+        config
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds() {
+        assert_eq!(parse_retry_after("30", Utc::now()), Some(Duration::from_secs(30)));
+        assert_eq!(parse_retry_after(" 0 ", Utc::now()), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 27, 30).unwrap();
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_retry_after_invalid() {
+        assert_eq!(parse_retry_after("soon", Utc::now()), None);
+        assert_eq!(parse_retry_after("-5", Utc::now()), None);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_request_is_retried_after_wait() {
+        let mut server = mockito::Server::new_async().await;
+        let limited = server
+            .mock("GET", "/express")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let body = serde_json::json!({
+            "name": "express",
+            "dist-tags": { "latest": "4.18.1" },
+            "versions": { "4.18.1": {} }
+        });
+        let ok = server
+            .mock("GET", "/express")
+            .with_status(200)
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+
+        let client =
+            RegistryClient::new(&PathBuf::from("."), test_config(server.url())).await.unwrap();
+        let retries = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&retries);
+        client.set_retry_listener(Arc::new(move |retry| {
+            seen.lock().unwrap().push(retry.clone());
+        }));
+
+        let metadata = client.get_package_info("express").await.unwrap();
+
+        limited.assert_async().await;
+        ok.assert_async().await;
+        assert_eq!(metadata.latest, "4.18.1");
+        let retries = retries.lock().unwrap();
+        assert_eq!(retries.len(), 1);
+        assert_eq!(retries[0].status, 429);
+        assert_eq!(retries[0].attempt, 1);
+        assert_eq!(retries[0].max_retries, 2);
+        assert_eq!(retries[0].wait, Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_beyond_max_wait_fails_with_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/express")
+            .with_status(429)
+            .with_header("retry-after", "120")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client =
+            RegistryClient::new(&PathBuf::from("."), test_config(server.url())).await.unwrap();
+
+        let error = client.get_package_info("express").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(error, UpgradeError::RateLimited { .. }));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(120)));
+    }
+
+    #[tokio::test]
+    async fn test_service_unavailable_after_retries_run_out() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/express")
+            .with_status(503)
+            .with_header("retry-after", "0")
+            .expect(3)
+            .create_async()
+            .await;
+
+        let client =
+            RegistryClient::new(&PathBuf::from("."), test_config(server.url())).await.unwrap();
+
+        let error = client.get_package_info("express").await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(error, UpgradeError::ServiceUnavailable { .. }));
+        assert_eq!(error.retry_after(), Some(Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_spaced_out_by_rate_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let body = serde_json::json!({
+            "name": "express",
+            "dist-tags": { "latest": "4.18.1" },
+            "versions": { "4.18.1": {} }
+        });
+        let mock = server
+            .mock("GET", "/express")
+            .with_status(200)
+            .with_body(body.to_string())
+            .expect(3)
+            .create_async()
+            .await;
+
+        let mut config = test_config(server.url());
+        config.requests_per_second = Some(10);
+        let client = RegistryClient::new(&PathBuf::from("."), config).await.unwrap();
+
+        let started = std::time::Instant::now();
+        let lookups = futures::future::join_all(
+            (0..3).map(|_| client.get_package_metadata("express", MetadataDetail::Full)),
+        )
+        .await;
+
+        mock.assert_async().await;
+        assert!(lookups.iter().all(Result::is_ok));
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,47 @@
+//! Proxy and TLS settings for registry HTTP requests.
+//!
+//! **What**: Resolves the standard tools `TransportSettings`, the proxy, CA certificate, and
+//! certificate verification settings applied to the HTTP client used by `RegistryClient` and
+//! to the fetch of a remote base configuration.
+//!
+//! **How**: Each setting is taken from `[package_tools.upgrade.registry.tls]` first, then from
+//! `.npmrc` (`https-proxy`, `proxy`, `noproxy`, `cafile`, `strict-ssl`), then from the
+//! `HTTPS_PROXY`/`HTTP_PROXY` and `NO_PROXY` environment variables. The resolved settings
+//! replace reqwest's own proxy detection, so the precedence is the same on every platform.
+//!
+//! **Why**: Enterprise networks route registry traffic through proxies that re-sign TLS
+//! connections. Without honoring the same settings npm uses, upgrade detection fails there.
+
+use crate::config::RegistryTlsConfig;
+use crate::upgrade::registry::npmrc::NpmrcConfig;
+use std::path::Path;
+use sublime_standard_tools::network::TransportSettings;
+
+/// Resolves the transport settings from configuration, `.npmrc`, and the environment.
+///
+/// # Arguments
+///
+/// * `tls` - Explicit settings from the registry configuration
+/// * `npmrc` - Parsed `.npmrc`, when `read_npmrc` is enabled
+/// * `workspace_root` - Base directory for relative CA file paths
+/// * `env` - Looks up an environment variable
+pub(crate) fn resolve_transport(
+    tls: &RegistryTlsConfig,
+    npmrc: Option<&NpmrcConfig>,
+    workspace_root: &Path,
+    env: impl Fn(&str) -> Option<String>,
+) -> TransportSettings {
+    let proxy =
+        tls.proxy.clone().or_else(|| npmrc.and_then(NpmrcConfig::proxy).map(str::to_string));
+    let no_proxy =
+        tls.no_proxy.clone().or_else(|| npmrc.and_then(NpmrcConfig::no_proxy).map(str::to_string));
+    let ca_file = tls
+        .ca_file
+        .as_deref()
+        .or_else(|| npmrc.and_then(NpmrcConfig::cafile))
+        .map(|path| workspace_root.join(path));
+    let strict_ssl =
+        tls.strict_ssl.or_else(|| npmrc.and_then(NpmrcConfig::strict_ssl)).unwrap_or(true);
+
+    TransportSettings { proxy, no_proxy, ca_file, strict_ssl }.with_env_fallback(env)
+}
@@ -65,6 +65,19 @@ pub struct PackageMetadata {
     ///
     /// Contains the source code repository details for the package.
     pub repository: Option<RepositoryInfo>,
+
+    /// Per-version deprecation notices.
+    ///
+    /// Maps version strings to the deprecation message published for that specific
+    /// version. Only deprecated versions are present in the map.
+    #[serde(default)]
+    pub version_deprecations: HashMap<String, String>,
+
+    /// User names of the package maintainers.
+    ///
+    /// Empty in abbreviated documents, which do not list maintainers.
+    #[serde(default)]
+    pub maintainers: Vec<String>,
 }
 
 /// Repository information from package metadata.
@@ -91,6 +104,128 @@ pub struct RepositoryInfo {
     pub url: String,
 }
 
+impl RepositoryInfo {
+    /// Returns the browsable web address of the repository.
+    ///
+    /// Normalizes the forms npm accepts in `repository.url`: `git+https://`, `git://`,
+    /// `ssh://git@`, `git@host:owner/repo`, and the `github:`, `gitlab:`, and `bitbucket:`
+    /// shorthands, dropping any trailing `.git`. Returns `None` for URLs that do not
+    /// point at a web host.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::RepositoryInfo;
+    ///
+    /// let repo = RepositoryInfo {
+    ///     type_: "git".to_string(),
+    ///     url: "git+https://github.com/facebook/react.git".to_string(),
+    /// };
+    /// assert_eq!(repo.web_url().as_deref(), Some("https://github.com/facebook/react"));
+    /// ```
+    #[must_use]
+    pub fn web_url(&self) -> Option<String> {
+        let url = self.url.trim();
+        let url = if let Some(path) = url.strip_prefix("github:") {
+            format!("https://github.com/{path}")
+        } else if let Some(path) = url.strip_prefix("gitlab:") {
+            format!("https://gitlab.com/{path}")
+        } else if let Some(path) = url.strip_prefix("bitbucket:") {
+            format!("https://bitbucket.org/{path}")
+        } else if let Some(rest) = url.strip_prefix("git@") {
+            format!("https://{}", rest.replacen(':', "/", 1))
+        } else {
+            let rest = url
+                .trim_start_matches("git+")
+                .trim_start_matches("https://")
+                .trim_start_matches("http://")
+                .trim_start_matches("ssh://")
+                .trim_start_matches("git://")
+                .trim_start_matches("git@");
+            if rest.len() == url.trim_start_matches("git+").len() {
+                return None;
+            }
+            format!("https://{rest}")
+        };
+
+        let url = url.trim_end_matches('/');
+        let url = url.strip_suffix(".git").unwrap_or(url);
+        Some(url.to_string())
+    }
+
+    /// Returns where the release notes of the repository are published.
+    ///
+    /// GitHub and GitLab repositories link to their releases page; other hosts link to
+    /// the repository itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::RepositoryInfo;
+    ///
+    /// let repo = RepositoryInfo {
+    ///     type_: "git".to_string(),
+    ///     url: "github:vitejs/vite".to_string(),
+    /// };
+    /// assert_eq!(
+    ///     repo.release_notes_url().as_deref(),
+    ///     Some("https://github.com/vitejs/vite/releases")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn release_notes_url(&self) -> Option<String> {
+        let web_url = self.web_url()?;
+        if web_url.starts_with("https://github.com/") {
+            Some(format!("{web_url}/releases"))
+        } else if web_url.starts_with("https://gitlab.com/") {
+            Some(format!("{web_url}/-/releases"))
+        } else {
+            Some(web_url)
+        }
+    }
+}
+
+/// Level of detail requested for a package document.
+///
+/// Abbreviated documents (`application/vnd.npm.install-v1+json`) contain versions, dist-tags,
+/// and per-version deprecation notices, and are much smaller for packages with many releases.
+/// They lack per-version publish times and repository information, which require the full
+/// document.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::MetadataDetail;
+///
+/// assert_eq!(MetadataDetail::Abbreviated.accept_header(), "application/vnd.npm.install-v1+json; q=1.0, application/json; q=0.8, */*");
+/// assert_eq!(MetadataDetail::Full.accept_header(), "application/json");
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataDetail {
+    /// Versions, dist-tags, and deprecation notices only.
+    Abbreviated,
+
+    /// The complete package document, including publish times and repository information.
+    Full,
+}
+
+impl MetadataDetail {
+    /// Returns the `Accept` header used to request this level of detail.
+    ///
+    /// Abbreviated requests also accept plain JSON so registries without support for
+    /// abbreviated documents answer with the full document.
+    #[must_use]
+    pub fn accept_header(self) -> &'static str {
+        match self {
+            Self::Abbreviated => {
+                "application/vnd.npm.install-v1+json; q=1.0, application/json; q=0.8, */*"
+            }
+            Self::Full => "application/json",
+        }
+    }
+}
+
 /// Classification of version upgrade type.
 ///
 /// Determines the semantic versioning magnitude of an upgrade.
@@ -205,6 +340,144 @@ impl UpgradeType {
     }
 }
 
+/// Weekly download statistics for a package from the npm downloads API.
+///
+/// Compares the downloads of the most recent seven days with the seven days
+/// before them so users can judge whether a dependency is gaining or losing adoption.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::{DownloadStats, DownloadTrend};
+///
+/// let stats = DownloadStats::from_weeks(1_200, 1_000);
+/// assert_eq!(stats.weekly_downloads, 1_200);
+/// assert_eq!(stats.trend, DownloadTrend::Rising);
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DownloadStats {
+    /// Downloads over the last seven days.
+    pub weekly_downloads: u64,
+
+    /// Downloads over the seven days preceding the last week.
+    pub previous_week_downloads: u64,
+
+    /// Direction of the download trend between both weeks.
+    pub trend: DownloadTrend,
+}
+
+/// Direction of a package's weekly download trend.
+///
+/// A change of less than ten percent between two consecutive weeks is considered stable.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::DownloadTrend;
+///
+/// assert_eq!(DownloadTrend::from_weeks(50, 100), DownloadTrend::Declining);
+/// assert_eq!(DownloadTrend::Declining.as_str(), "declining");
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadTrend {
+    /// Downloads grew by ten percent or more week over week.
+    Rising,
+
+    /// Downloads changed by less than ten percent week over week.
+    Stable,
+
+    /// Downloads dropped by ten percent or more week over week.
+    Declining,
+}
+
+/// Relative change (in percent) below which a download trend is reported as stable.
+const DOWNLOAD_TREND_THRESHOLD_PERCENT: u64 = 10;
+
+impl DownloadTrend {
+    /// Classifies the trend between the current and the previous week.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Downloads in the most recent week
+    /// * `previous` - Downloads in the week before
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::DownloadTrend;
+    ///
+    /// assert_eq!(DownloadTrend::from_weeks(105, 100), DownloadTrend::Stable);
+    /// assert_eq!(DownloadTrend::from_weeks(10, 0), DownloadTrend::Rising);
+    /// ```
+    #[must_use]
+    pub fn from_weeks(current: u64, previous: u64) -> Self {
+        if previous == 0 {
+            return if current == 0 { Self::Stable } else { Self::Rising };
+        }
+
+        let delta = current.abs_diff(previous).saturating_mul(100);
+        if delta < previous.saturating_mul(DOWNLOAD_TREND_THRESHOLD_PERCENT) {
+            Self::Stable
+        } else if current > previous {
+            Self::Rising
+        } else {
+            Self::Declining
+        }
+    }
+
+    /// Returns the string representation of this trend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::DownloadTrend;
+    ///
+    /// assert_eq!(DownloadTrend::Rising.as_str(), "rising");
+    /// assert_eq!(DownloadTrend::Stable.as_str(), "stable");
+    /// ```
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Rising => "rising",
+            Self::Stable => "stable",
+            Self::Declining => "declining",
+        }
+    }
+}
+
+impl std::fmt::Display for DownloadTrend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl DownloadStats {
+    /// Builds download statistics from two consecutive weekly totals.
+    ///
+    /// # Arguments
+    ///
+    /// * `weekly_downloads` - Downloads in the most recent week
+    /// * `previous_week_downloads` - Downloads in the week before
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::{DownloadStats, DownloadTrend};
+    ///
+    /// let stats = DownloadStats::from_weeks(800, 1_000);
+    /// assert_eq!(stats.trend, DownloadTrend::Declining);
+    /// ```
+    #[must_use]
+    pub fn from_weeks(weekly_downloads: u64, previous_week_downloads: u64) -> Self {
+        Self {
+            weekly_downloads,
+            previous_week_downloads,
+            trend: DownloadTrend::from_weeks(weekly_downloads, previous_week_downloads),
+        }
+    }
+}
+
 impl std::fmt::Display for UpgradeType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.as_str())
@@ -303,4 +576,52 @@ impl PackageMetadata {
     pub fn version_published_at(&self, version: &str) -> Option<DateTime<Utc>> {
         self.time.get(version).copied()
     }
+
+    /// Returns the deprecation notice of a specific version if it is deprecated.
+    ///
+    /// Unlike [`deprecation_message`](Self::deprecation_message), which reflects the
+    /// `latest` dist-tag, this looks up the notice attached to the given version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::PackageMetadata;
+    ///
+    /// # fn example(metadata: PackageMetadata) {
+    /// if let Some(notice) = metadata.version_deprecation("1.2.3") {
+    ///     println!("Version 1.2.3 is deprecated: {}", notice);
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn version_deprecation(&self, version: &str) -> Option<&str> {
+        self.version_deprecations.get(version).map(String::as_str)
+    }
+}
+
+/// A Node.js release listed by the Node.js distribution index.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::NodeRelease;
+///
+/// let release = NodeRelease { version: "22.11.0".to_string(), lts: Some("Jod".to_string()) };
+/// assert!(release.is_lts());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NodeRelease {
+    /// Version without the `v` prefix (e.g., "22.11.0").
+    pub version: String,
+
+    /// LTS codename (e.g., "Jod"), or `None` for releases outside an LTS line.
+    pub lts: Option<String>,
+}
+
+impl NodeRelease {
+    /// Returns whether the release belongs to an LTS line.
+    #[must_use]
+    pub fn is_lts(&self) -> bool {
+        self.lts.is_some()
+    }
 }
@@ -0,0 +1,131 @@
+//! Application of toolchain pin upgrades.
+//!
+//! **What**: Writes the new specs of toolchain pin upgrades to package.json files.
+//!
+//! **How**: Upgrades are grouped by package. Each package.json is read once, every pin whose
+//! value still matches the detected spec is replaced, and the file is written back with its
+//! original indentation and line endings. Dry runs report the upgrades without reading or
+//! writing files.
+//!
+//! **Why**: Keeping the write path next to the dependency applier's formatting rules means a
+//! toolchain upgrade produces the same diff style as a dependency upgrade.
+
+use crate::error::UpgradeError;
+use crate::upgrade::serialize_package_json;
+use crate::upgrade::toolchain::types::{ToolchainPin, ToolchainResult, ToolchainUpgrade};
+use package_json::PackageJson;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Applies toolchain pin upgrades to package.json files.
+///
+/// A pin is only replaced if its value still equals the spec seen during detection; pins
+/// changed since then are left alone and not reported as applied.
+///
+/// # Arguments
+///
+/// * `upgrades` - Upgrades to apply, as detected by `detect_toolchain_upgrades`
+/// * `dry_run` - If true, report the upgrades without modifying files
+/// * `fs` - Filesystem implementation
+///
+/// # Errors
+///
+/// Returns `UpgradeError` if a package.json file cannot be read, parsed, or written.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::upgrade::apply_toolchain_upgrades;
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+///
+/// # async fn example(preview: sublime_pkg_tools::upgrade::ToolchainPreview) -> Result<(), Box<dyn std::error::Error>> {
+/// let result = apply_toolchain_upgrades(preview.upgrades, false, &FileSystemManager::new()).await?;
+/// println!("Updated {} pins", result.applied.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn apply_toolchain_upgrades<F: AsyncFileSystem>(
+    upgrades: Vec<ToolchainUpgrade>,
+    dry_run: bool,
+    fs: &F,
+) -> Result<ToolchainResult, UpgradeError> {
+    if dry_run {
+        return Ok(ToolchainResult {
+            dry_run,
+            applied: upgrades,
+            modified_files: Vec::new(),
+            backup_path: None,
+            changeset_id: None,
+        });
+    }
+
+    let mut by_package: BTreeMap<PathBuf, Vec<ToolchainUpgrade>> = BTreeMap::new();
+    for upgrade in upgrades {
+        by_package.entry(upgrade.package_path.clone()).or_default().push(upgrade);
+    }
+
+    let mut applied = Vec::new();
+    let mut modified_files = Vec::new();
+    for (package_path, upgrades) in by_package {
+        let package_json_path = package_path.join("package.json");
+
+        let content = fs.read_file_string(&package_json_path).await.map_err(|e| {
+            UpgradeError::FileSystemError { path: package_json_path.clone(), reason: e.to_string() }
+        })?;
+        let mut pkg_json: PackageJson =
+            serde_json::from_str(&content).map_err(|e| UpgradeError::PackageJsonError {
+                path: package_json_path.clone(),
+                reason: e.to_string(),
+            })?;
+
+        let written: Vec<ToolchainUpgrade> =
+            upgrades.into_iter().filter(|upgrade| set_pin(&mut pkg_json, upgrade)).collect();
+        if written.is_empty() {
+            continue;
+        }
+
+        let updated_content = serialize_package_json(&pkg_json, &content)?;
+        fs.write_file(&package_json_path, updated_content.as_bytes()).await.map_err(|e| {
+            UpgradeError::ApplyFailed { path: package_json_path.clone(), reason: e.to_string() }
+        })?;
+
+        modified_files.push(package_json_path);
+        applied.extend(written);
+    }
+
+    Ok(ToolchainResult { dry_run, applied, modified_files, backup_path: None, changeset_id: None })
+}
+
+/// Replaces the value of a pin with the new spec of an upgrade.
+///
+/// Returns whether the pin was found with the detected spec and replaced.
+pub(crate) fn set_pin(pkg_json: &mut PackageJson, upgrade: &ToolchainUpgrade) -> bool {
+    let value = match upgrade.pin {
+        ToolchainPin::EnginesNode => {
+            let Some(node) = pkg_json.engines.as_mut().and_then(|engines| engines.get_mut("node"))
+            else {
+                return false;
+            };
+            if *node != upgrade.current_spec {
+                return false;
+            }
+            *node = upgrade.new_spec.clone();
+            return true;
+        }
+        ToolchainPin::PackageManager => pkg_json.unknowns.get_mut("packageManager"),
+        ToolchainPin::Volta => pkg_json
+            .unknowns
+            .get_mut("volta")
+            .and_then(|volta| volta.as_object_mut())
+            .and_then(|volta| volta.get_mut(&upgrade.tool)),
+    };
+
+    match value {
+        Some(value) if value.as_str() == Some(upgrade.current_spec.as_str()) => {
+            *value = serde_json::Value::String(upgrade.new_spec.clone());
+            true
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,339 @@
+//! Detection of toolchain pin upgrades.
+//!
+//! **What**: Finds `engines.node`, `packageManager`, and Volta pins in package.json files and
+//! compares them against the Node.js release index and the npm releases of package managers.
+//!
+//! **How**: Pins are collected from the same package.json files as dependency upgrades. Each
+//! release feed is queried once per detection: Node.js pins are compared with the latest LTS
+//! release, package manager pins with the `latest` tag of the package that publishes the tool
+//! (`@yarnpkg/cli-dist` for Yarn 2 and later). The replacement spec keeps the shape of the
+//! current one.
+//!
+//! **Why**: Toolchain pins drift just like dependencies, but they are not dependencies, so the
+//! dependency detector never looks at them.
+
+use crate::error::UpgradeError;
+use crate::upgrade::detection::{find_package_json_files, read_package_json};
+use crate::upgrade::registry::{NodeRelease, RegistryClient, UpgradeType};
+use crate::upgrade::toolchain::types::{ToolchainPin, ToolchainPreview, ToolchainUpgrade};
+use chrono::Utc;
+use package_json::PackageJson;
+use semver::Version;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use sublime_standard_tools::filesystem::FileSystemManager;
+
+/// Tools that may be pinned in the `volta` section.
+const VOLTA_TOOLS: [&str; 4] = ["node", "npm", "pnpm", "yarn"];
+
+/// A toolchain pin read from a package.json file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PinToCheck {
+    pub(crate) pin: ToolchainPin,
+    pub(crate) tool: String,
+    pub(crate) spec: String,
+}
+
+/// Detects available upgrades of the toolchain pins in the workspace.
+///
+/// Node.js pins (`engines.node`, `volta.node`) are compared with the latest LTS release from
+/// the Node.js distribution index. `engines.node` is only upgraded when a newer LTS major is
+/// available: its floor moves to that release, keeping the operator and precision of the
+/// range (`>=18.12.0` becomes `>=22.11.0`, `18.x` becomes `22.x`). Ranges with several
+/// comparators are left alone.
+///
+/// `packageManager` and Volta pins of npm, pnpm, and Yarn are compared with the latest release
+/// of the tool on the npm registry. The `+sha` integrity suffix of `packageManager` is dropped
+/// on upgrade, since it only matches the current version.
+///
+/// Release feeds that cannot be queried are logged and skipped. In offline mode, tools without
+/// cached releases are listed in [`ToolchainPreview::offline_skipped`].
+///
+/// # Arguments
+///
+/// * `workspace_root` - Root directory of the workspace
+/// * `registry_client` - Client used to query the release feeds
+/// * `fs` - Filesystem used to read package.json files
+///
+/// # Errors
+///
+/// Returns `UpgradeError` if no package.json file is found or one cannot be read.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::upgrade::{detect_toolchain_upgrades, RegistryClient};
+/// use sublime_pkg_tools::config::RegistryConfig;
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let root = PathBuf::from(".");
+/// let client = RegistryClient::new(&root, RegistryConfig::default()).await?;
+/// let preview = detect_toolchain_upgrades(&root, &client, &FileSystemManager::new()).await?;
+///
+/// for upgrade in &preview.upgrades {
+///     println!("{}: {} -> {}", upgrade.field(), upgrade.current_spec, upgrade.new_spec);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn detect_toolchain_upgrades(
+    workspace_root: &Path,
+    registry_client: &RegistryClient,
+    fs: &FileSystemManager,
+) -> Result<ToolchainPreview, UpgradeError> {
+    let detected_at = Utc::now();
+
+    let mut packages = Vec::new();
+    for path in find_package_json_files(workspace_root, fs).await? {
+        let package_json = read_package_json(&path, fs).await?;
+        let package_path =
+            path.parent().map_or_else(|| workspace_root.to_path_buf(), Path::to_path_buf);
+        let pins = extract_pins(&package_json);
+        if !pins.is_empty() {
+            packages.push((package_json.name.clone(), package_path, pins));
+        }
+    }
+
+    let mut feeds = ReleaseFeeds::new(registry_client);
+    let mut upgrades = Vec::new();
+    for (package_name, package_path, pins) in packages {
+        for pin in pins {
+            if let Some(upgrade) = feeds.check(&pin, &package_name, &package_path).await {
+                upgrades.push(upgrade);
+            }
+        }
+    }
+    upgrades.sort_by(|a, b| (&a.package_path, a.field()).cmp(&(&b.package_path, b.field())));
+
+    let mut offline_skipped = feeds.offline_skipped;
+    offline_skipped.sort();
+    offline_skipped.dedup();
+
+    Ok(ToolchainPreview { detected_at, upgrades, offline_skipped })
+}
+
+/// Latest releases of each tool, queried once per detection.
+struct ReleaseFeeds<'a> {
+    registry_client: &'a RegistryClient,
+    /// Latest release by feed (`node` or the npm package of a tool); `None` if unavailable.
+    latest: HashMap<String, Option<String>>,
+    offline_skipped: Vec<String>,
+}
+
+impl<'a> ReleaseFeeds<'a> {
+    fn new(registry_client: &'a RegistryClient) -> Self {
+        Self { registry_client, latest: HashMap::new(), offline_skipped: Vec::new() }
+    }
+
+    /// Returns the upgrade of a pin, if its tool has a newer release.
+    async fn check(
+        &mut self,
+        pin: &PinToCheck,
+        package_name: &str,
+        package_path: &Path,
+    ) -> Option<ToolchainUpgrade> {
+        let current = pinned_version(pin)?;
+        let feed = release_feed(&pin.tool, &current)?;
+        let latest = self.latest_release(feed, &pin.tool).await?;
+
+        let (new_spec, upgrade_type) = match pin.pin {
+            ToolchainPin::EnginesNode => upgrade_engines_range(&pin.spec, &latest)?,
+            ToolchainPin::PackageManager => {
+                (format!("{}@{latest}", pin.tool), classify(&current, &latest)?)
+            }
+            ToolchainPin::Volta => (latest.clone(), classify(&current, &latest)?),
+        };
+
+        Some(ToolchainUpgrade {
+            package_name: package_name.to_string(),
+            package_path: PathBuf::from(package_path),
+            pin: pin.pin,
+            tool: pin.tool.clone(),
+            current_spec: pin.spec.clone(),
+            current_version: current.to_string(),
+            latest_version: latest,
+            new_spec,
+            upgrade_type,
+        })
+    }
+
+    /// Returns the latest release of a feed, querying it on first use.
+    async fn latest_release(&mut self, feed: &str, tool: &str) -> Option<String> {
+        if let Some(latest) = self.latest.get(feed) {
+            return latest.clone();
+        }
+
+        let result = if feed == "node" {
+            self.registry_client
+                .get_node_releases()
+                .await
+                .map(|releases| latest_lts(&releases).map(|release| release.version.clone()))
+        } else {
+            self.registry_client.get_latest_version(feed).await.map(Some)
+        };
+
+        let latest = match result {
+            Ok(latest) => latest,
+            Err(UpgradeError::OfflineSkipped { .. }) => {
+                self.offline_skipped.push(tool.to_string());
+                None
+            }
+            Err(e) => {
+                log::warn!("Failed to check latest release of '{}': {}", tool, e);
+                None
+            }
+        };
+
+        self.latest.insert(feed.to_string(), latest.clone());
+        latest
+    }
+}
+
+/// Collects the toolchain pins of a package.json file.
+pub(crate) fn extract_pins(package_json: &PackageJson) -> Vec<PinToCheck> {
+    let mut pins = Vec::new();
+
+    if let Some(node) = package_json.engines.as_ref().and_then(|engines| engines.get("node")) {
+        pins.push(PinToCheck {
+            pin: ToolchainPin::EnginesNode,
+            tool: "node".to_string(),
+            spec: node.clone(),
+        });
+    }
+
+    if let Some(spec) = package_json.unknowns.get("packageManager").and_then(|v| v.as_str())
+        && let Some((tool, _)) = spec.split_once('@')
+    {
+        pins.push(PinToCheck {
+            pin: ToolchainPin::PackageManager,
+            tool: tool.to_string(),
+            spec: spec.to_string(),
+        });
+    }
+
+    if let Some(volta) = package_json.unknowns.get("volta").and_then(|v| v.as_object()) {
+        for tool in VOLTA_TOOLS {
+            if let Some(spec) = volta.get(tool).and_then(|v| v.as_str()) {
+                pins.push(PinToCheck {
+                    pin: ToolchainPin::Volta,
+                    tool: tool.to_string(),
+                    spec: spec.to_string(),
+                });
+            }
+        }
+    }
+
+    pins
+}
+
+/// Returns the version a pin is compared with: the floor of `engines.node`, or the pinned
+/// version of the other pins.
+pub(crate) fn pinned_version(pin: &PinToCheck) -> Option<Version> {
+    match pin.pin {
+        ToolchainPin::EnginesNode => {
+            let (_, components) = parse_engines_range(&pin.spec)?;
+            let mut floor = components.iter().map(|c| c.parse::<u64>().unwrap_or(0));
+            Some(Version::new(
+                floor.next().unwrap_or(0),
+                floor.next().unwrap_or(0),
+                floor.next().unwrap_or(0),
+            ))
+        }
+        ToolchainPin::PackageManager => {
+            let (_, version) = pin.spec.split_once('@')?;
+            // Drop the integrity suffix (`+sha512.<hash>`)
+            Version::parse(version.split('+').next().unwrap_or(version)).ok()
+        }
+        ToolchainPin::Volta => Version::parse(pin.spec.trim()).ok(),
+    }
+}
+
+/// Returns the release feed of a tool: `node`, or the npm package that publishes it.
+///
+/// Yarn 2 and later are published as `@yarnpkg/cli-dist`, Yarn 1 as `yarn`. Unknown tools
+/// have no feed.
+pub(crate) fn release_feed(tool: &str, current: &Version) -> Option<&'static str> {
+    match tool {
+        "node" => Some("node"),
+        "npm" => Some("npm"),
+        "pnpm" => Some("pnpm"),
+        "bun" => Some("bun"),
+        "yarn" if current.major >= 2 => Some("@yarnpkg/cli-dist"),
+        "yarn" => Some("yarn"),
+        _ => None,
+    }
+}
+
+/// Returns the newest LTS release of the Node.js distribution index.
+pub(crate) fn latest_lts(releases: &[NodeRelease]) -> Option<&NodeRelease> {
+    releases
+        .iter()
+        .filter(|release| release.is_lts())
+        .filter_map(|release| Version::parse(&release.version).ok().map(|v| (v, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+/// Splits a single-comparator `engines.node` range into its operator and version components.
+///
+/// Accepts an optional `>=`, `^`, or `~` followed by one to three components, the first one
+/// numeric and the others numeric or a wildcard (`x`, `X`, `*`). Returns `None` for any other
+/// range, such as `>=18 <23` or `^18 || ^20`.
+pub(crate) fn parse_engines_range(spec: &str) -> Option<(&str, Vec<&str>)> {
+    let spec = spec.trim();
+    let operator = [">=", "^", "~"].into_iter().find(|op| spec.starts_with(op)).unwrap_or("");
+    let version = spec[operator.len()..].trim_start();
+
+    let components: Vec<&str> = version.split('.').collect();
+    let numeric = |c: &str| !c.is_empty() && c.bytes().all(|b| b.is_ascii_digit());
+    let valid = (1..=3).contains(&components.len())
+        && numeric(components[0])
+        && components[1..].iter().all(|c| numeric(c) || matches!(*c, "x" | "X" | "*"));
+
+    valid.then_some((operator, components))
+}
+
+/// Moves the floor of an `engines.node` range to a newer LTS major release.
+///
+/// Numeric components are replaced by those of `latest` and wildcards are kept, so the range
+/// keeps its operator and precision. Returns the new range and the upgrade type, or `None`
+/// if the range is not supported or already allows the major of `latest`.
+pub(crate) fn upgrade_engines_range(spec: &str, latest: &str) -> Option<(String, UpgradeType)> {
+    let (operator, components) = parse_engines_range(spec)?;
+    let latest = Version::parse(latest).ok()?;
+    if components[0].parse::<u64>().ok()? >= latest.major {
+        return None;
+    }
+
+    let latest_components = [latest.major, latest.minor, latest.patch];
+    let version = components
+        .iter()
+        .zip(latest_components)
+        .map(|(component, latest)| {
+            if matches!(*component, "x" | "X" | "*") {
+                (*component).to_string()
+            } else {
+                latest.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".");
+
+    Some((format!("{operator}{version}"), UpgradeType::Major))
+}
+
+/// Classifies the upgrade from `current` to `latest`, or `None` if `latest` is not newer.
+fn classify(current: &Version, latest: &str) -> Option<UpgradeType> {
+    let latest = Version::parse(latest).ok()?;
+    if latest <= *current {
+        None
+    } else if latest.major > current.major {
+        Some(UpgradeType::Major)
+    } else if latest.minor > current.minor {
+        Some(UpgradeType::Minor)
+    } else {
+        Some(UpgradeType::Patch)
+    }
+}
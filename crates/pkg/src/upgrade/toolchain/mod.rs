@@ -0,0 +1,24 @@
+//! Toolchain pin upgrades.
+//!
+//! **What**: Detects and applies upgrades of the toolchain pins of package.json files: the
+//! `engines.node` range, the `packageManager` field (e.g., `pnpm@9.1.0`), and Volta pins.
+//!
+//! **How**: Node.js pins are compared with the latest LTS release from the Node.js
+//! distribution index, package manager pins with the latest release on the npm registry.
+//! Upgrades carry the spec to write, and applying them rewrites only the pins whose value
+//! is unchanged since detection. `UpgradeManager` wraps both steps with the same backup,
+//! rollback, and changeset handling as dependency upgrades.
+//!
+//! **Why**: Outdated runtime and package manager pins are as much maintenance debt as outdated
+//! dependencies, and bumping them by hand is easy to forget across a workspace.
+
+mod applier;
+mod detector;
+mod types;
+
+#[cfg(test)]
+mod tests;
+
+pub use applier::apply_toolchain_upgrades;
+pub use detector::detect_toolchain_upgrades;
+pub use types::{ToolchainPin, ToolchainPreview, ToolchainResult, ToolchainUpgrade};
@@ -0,0 +1,334 @@
+//! Tests for toolchain pin upgrades.
+//!
+//! **What**: Covers parsing and rewriting of `engines.node`, `packageManager`, and Volta pins,
+//! detection against mocked release feeds, and application to package.json files.
+//!
+//! **How**: Pure helpers are tested directly. Detection runs against a mockito server that
+//! serves both the Node.js distribution index and npm registry documents, and application
+//! writes to package.json files in temporary directories.
+//!
+//! **Why**: To ensure pins are only upgraded to newer releases, keep the shape of the spec as
+//! written, and never clobber pins that changed after detection.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+#![allow(clippy::field_reassign_with_default)]
+
+use super::applier::set_pin;
+use super::detector::{
+    PinToCheck, extract_pins, latest_lts, parse_engines_range, pinned_version, release_feed,
+    upgrade_engines_range,
+};
+use super::*;
+use crate::config::RegistryConfig;
+use crate::upgrade::registry::{NodeRelease, RegistryClient, UpgradeType};
+use mockito::Server;
+use package_json::PackageJson;
+use semver::Version;
+use std::path::Path;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tempfile::TempDir;
+
+fn pin(kind: ToolchainPin, tool: &str, spec: &str) -> PinToCheck {
+    PinToCheck { pin: kind, tool: tool.to_string(), spec: spec.to_string() }
+}
+
+fn upgrade(kind: ToolchainPin, tool: &str, current: &str, new: &str) -> ToolchainUpgrade {
+    ToolchainUpgrade {
+        package_name: "root".to_string(),
+        package_path: Path::new(".").to_path_buf(),
+        pin: kind,
+        tool: tool.to_string(),
+        current_spec: current.to_string(),
+        current_version: current.to_string(),
+        latest_version: new.to_string(),
+        new_spec: new.to_string(),
+        upgrade_type: UpgradeType::Major,
+    }
+}
+
+fn write_manifest(root: &Path, value: &serde_json::Value) {
+    std::fs::write(root.join("package.json"), serde_json::to_string_pretty(value).unwrap())
+        .unwrap();
+}
+
+fn read_manifest(root: &Path) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(root.join("package.json")).unwrap()).unwrap()
+}
+
+mod pins {
+    use super::*;
+
+    #[test]
+    fn test_extract_pins() {
+        let package_json: PackageJson = serde_json::from_value(serde_json::json!({
+            "name": "root",
+            "version": "1.0.0",
+            "engines": { "node": ">=18", "npm": ">=9" },
+            "packageManager": "pnpm@8.15.0",
+            "volta": { "node": "20.10.0", "yarn": "1.22.19", "extends": "../package.json" }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            extract_pins(&package_json),
+            vec![
+                pin(ToolchainPin::EnginesNode, "node", ">=18"),
+                pin(ToolchainPin::PackageManager, "pnpm", "pnpm@8.15.0"),
+                pin(ToolchainPin::Volta, "node", "20.10.0"),
+                pin(ToolchainPin::Volta, "yarn", "1.22.19"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pinned_version() {
+        let version = |kind, spec| pinned_version(&pin(kind, "pnpm", spec));
+
+        assert_eq!(
+            version(ToolchainPin::PackageManager, "pnpm@8.15.0+sha512.abc123"),
+            Some(Version::new(8, 15, 0))
+        );
+        assert_eq!(version(ToolchainPin::EnginesNode, ">=18.12"), Some(Version::new(18, 12, 0)));
+        assert_eq!(version(ToolchainPin::EnginesNode, "18.x"), Some(Version::new(18, 0, 0)));
+        assert_eq!(version(ToolchainPin::Volta, "20.10.0"), Some(Version::new(20, 10, 0)));
+        assert_eq!(version(ToolchainPin::PackageManager, "pnpm"), None);
+        assert_eq!(version(ToolchainPin::EnginesNode, "^18 || ^20"), None);
+    }
+
+    #[test]
+    fn test_release_feed() {
+        assert_eq!(release_feed("node", &Version::new(20, 0, 0)), Some("node"));
+        assert_eq!(release_feed("pnpm", &Version::new(8, 0, 0)), Some("pnpm"));
+        assert_eq!(release_feed("yarn", &Version::new(1, 22, 19)), Some("yarn"));
+        assert_eq!(release_feed("yarn", &Version::new(4, 0, 2)), Some("@yarnpkg/cli-dist"));
+        assert_eq!(release_feed("deno", &Version::new(1, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_latest_lts() {
+        let release = |version: &str, lts: Option<&str>| NodeRelease {
+            version: version.to_string(),
+            lts: lts.map(String::from),
+        };
+        let releases = vec![
+            release("23.1.0", None),
+            release("20.18.0", Some("Iron")),
+            release("22.11.0", Some("Jod")),
+        ];
+
+        assert_eq!(latest_lts(&releases).map(|r| r.version.as_str()), Some("22.11.0"));
+        assert!(latest_lts(&[release("23.1.0", None)]).is_none());
+    }
+
+    #[test]
+    fn test_parse_engines_range() {
+        assert_eq!(parse_engines_range(">=18.12.0"), Some((">=", vec!["18", "12", "0"])));
+        assert_eq!(parse_engines_range("18.x"), Some(("", vec!["18", "x"])));
+        assert_eq!(parse_engines_range("^20"), Some(("^", vec!["20"])));
+        assert_eq!(parse_engines_range(">=18 <23"), None);
+        assert_eq!(parse_engines_range("^18 || ^20"), None);
+        assert_eq!(parse_engines_range("x"), None);
+        assert_eq!(parse_engines_range("lts/*"), None);
+    }
+
+    #[test]
+    fn test_upgrade_engines_range_keeps_shape() {
+        let upgraded = |spec| upgrade_engines_range(spec, "22.11.0").map(|(spec, _)| spec);
+
+        assert_eq!(upgraded(">=18.12.0").as_deref(), Some(">=22.11.0"));
+        assert_eq!(upgraded("18").as_deref(), Some("22"));
+        assert_eq!(upgraded("18.x").as_deref(), Some("22.x"));
+        assert_eq!(upgraded("^18.12").as_deref(), Some("^22.11"));
+        assert_eq!(upgraded("~18.x.x").as_deref(), Some("~22.x.x"));
+        assert_eq!(
+            upgrade_engines_range(">=18", "22.11.0").map(|(_, kind)| kind),
+            Some(UpgradeType::Major)
+        );
+    }
+
+    #[test]
+    fn test_upgrade_engines_range_requires_newer_major() {
+        assert!(upgrade_engines_range(">=22.0.0", "22.11.0").is_none());
+        assert!(upgrade_engines_range(">=23", "22.11.0").is_none());
+        assert!(upgrade_engines_range(">=18 <23", "22.11.0").is_none());
+    }
+}
+
+mod detection {
+    use super::*;
+
+    async fn client(server: &Server) -> RegistryClient {
+        let mut config = RegistryConfig::default();
+        config.read_npmrc = false;
+        config.retry_attempts = 0;
+        config.default_registry = server.url();
+        config.node_dist_url = server.url();
+        RegistryClient::new(Path::new("."), config).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_detects_pins_against_release_feeds() {
+        let mut server = Server::new_async().await;
+        let node_index = server
+            .mock("GET", "/index.json")
+            .with_status(200)
+            .with_body(
+                serde_json::json!([
+                    { "version": "v23.1.0", "lts": false },
+                    { "version": "v22.11.0", "lts": "Jod" },
+                    { "version": "v20.18.0", "lts": "Iron" }
+                ])
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let pnpm = server
+            .mock("GET", "/pnpm")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "name": "pnpm",
+                    "dist-tags": { "latest": "9.12.0" },
+                    "versions": { "8.15.0": {}, "9.12.0": {} }
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        let temp = TempDir::new().unwrap();
+        write_manifest(
+            temp.path(),
+            &serde_json::json!({
+                "name": "root",
+                "version": "1.0.0",
+                "engines": { "node": ">=18.12.0" },
+                "packageManager": "pnpm@8.15.0+sha512.abc123",
+                "volta": { "node": "22.11.0", "pnpm": "8.15.0" }
+            }),
+        );
+
+        let preview = detect_toolchain_upgrades(
+            temp.path(),
+            &client(&server).await,
+            &FileSystemManager::new(),
+        )
+        .await
+        .unwrap();
+
+        node_index.assert_async().await;
+        pnpm.assert_async().await;
+        let found: Vec<(String, &str, &str, UpgradeType)> = preview
+            .upgrades
+            .iter()
+            .map(|u| (u.field(), u.current_spec.as_str(), u.new_spec.as_str(), u.upgrade_type))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("engines.node".to_string(), ">=18.12.0", ">=22.11.0", UpgradeType::Major),
+                (
+                    "packageManager".to_string(),
+                    "pnpm@8.15.0+sha512.abc123",
+                    "pnpm@9.12.0",
+                    UpgradeType::Major
+                ),
+                ("volta.pnpm".to_string(), "8.15.0", "9.12.0", UpgradeType::Major),
+            ]
+        );
+        assert!(preview.offline_skipped.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_feed_skips_its_pins() {
+        let mut server = Server::new_async().await;
+        let _index = server.mock("GET", "/index.json").with_status(500).create_async().await;
+
+        let temp = TempDir::new().unwrap();
+        write_manifest(
+            temp.path(),
+            &serde_json::json!({ "name": "root", "version": "1.0.0", "engines": { "node": "18" } }),
+        );
+
+        let preview = detect_toolchain_upgrades(
+            temp.path(),
+            &client(&server).await,
+            &FileSystemManager::new(),
+        )
+        .await
+        .unwrap();
+
+        assert!(preview.upgrades.is_empty());
+    }
+}
+
+mod application {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_applies_pins_and_keeps_other_fields() {
+        let temp = TempDir::new().unwrap();
+        write_manifest(
+            temp.path(),
+            &serde_json::json!({
+                "name": "root",
+                "version": "1.0.0",
+                "engines": { "node": ">=18", "npm": ">=9" },
+                "packageManager": "pnpm@8.15.0",
+                "volta": { "node": "20.10.0" }
+            }),
+        );
+        let at_root = |mut upgrade: ToolchainUpgrade| {
+            upgrade.package_path = temp.path().to_path_buf();
+            upgrade
+        };
+        let upgrades = vec![
+            at_root(upgrade(ToolchainPin::EnginesNode, "node", ">=18", ">=22")),
+            at_root(upgrade(ToolchainPin::PackageManager, "pnpm", "pnpm@8.15.0", "pnpm@9.12.0")),
+            at_root(upgrade(ToolchainPin::Volta, "node", "20.10.0", "22.11.0")),
+        ];
+
+        let fs = FileSystemManager::new();
+        let preview = apply_toolchain_upgrades(upgrades.clone(), true, &fs).await.unwrap();
+        assert_eq!(preview.applied.len(), 3);
+        assert_eq!(read_manifest(temp.path())["packageManager"], "pnpm@8.15.0");
+
+        let result = apply_toolchain_upgrades(upgrades, false, &fs).await.unwrap();
+        let manifest = read_manifest(temp.path());
+
+        assert_eq!(result.applied.len(), 3);
+        assert_eq!(result.modified_files, vec![temp.path().join("package.json")]);
+        assert_eq!(manifest["engines"]["node"], ">=22");
+        assert_eq!(manifest["engines"]["npm"], ">=9");
+        assert_eq!(manifest["packageManager"], "pnpm@9.12.0");
+        assert_eq!(manifest["volta"]["node"], "22.11.0");
+    }
+
+    #[test]
+    fn test_set_pin_skips_pins_changed_since_detection() {
+        let mut package_json: PackageJson = serde_json::from_value(serde_json::json!({
+            "name": "root",
+            "version": "1.0.0",
+            "packageManager": "pnpm@9.0.0",
+            "volta": { "node": "20.10.0" }
+        }))
+        .unwrap();
+
+        assert!(!set_pin(
+            &mut package_json,
+            &upgrade(ToolchainPin::PackageManager, "pnpm", "pnpm@8.15.0", "pnpm@9.12.0")
+        ));
+        assert!(!set_pin(
+            &mut package_json,
+            &upgrade(ToolchainPin::EnginesNode, "node", ">=18", ">=22")
+        ));
+        assert!(!set_pin(
+            &mut package_json,
+            &upgrade(ToolchainPin::Volta, "pnpm", "8.15.0", "9.12.0")
+        ));
+        assert_eq!(package_json.unknowns["packageManager"], "pnpm@9.0.0");
+    }
+}
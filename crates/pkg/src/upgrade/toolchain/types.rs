@@ -0,0 +1,153 @@
+//! Types for toolchain pin upgrades.
+//!
+//! **What**: Defines the kinds of toolchain pins found in package.json, the upgrades detected
+//! for them, and the outcome of applying those upgrades.
+//!
+//! **How**: A `ToolchainUpgrade` records where a pin lives, the spec as written, and the spec
+//! that replaces it, so applying an upgrade never has to query a release feed again.
+//!
+//! **Why**: Toolchain pins are not dependencies, so they carry their own field and spec
+//! instead of being squeezed into `DependencyUpgrade`.
+
+use crate::upgrade::registry::UpgradeType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Kind of toolchain pin in a package.json file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ToolchainPin {
+    /// Node.js version range in `engines.node`.
+    EnginesNode,
+
+    /// Package manager and version in `packageManager` (e.g., "pnpm@9.1.0").
+    PackageManager,
+
+    /// Exact tool version in the `volta` section (e.g., `volta.node`).
+    Volta,
+}
+
+impl ToolchainPin {
+    /// Returns the package.json field of the pin for a tool.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::upgrade::ToolchainPin;
+    ///
+    /// assert_eq!(ToolchainPin::EnginesNode.field("node"), "engines.node");
+    /// assert_eq!(ToolchainPin::PackageManager.field("pnpm"), "packageManager");
+    /// assert_eq!(ToolchainPin::Volta.field("yarn"), "volta.yarn");
+    /// ```
+    #[must_use]
+    pub fn field(&self, tool: &str) -> String {
+        match self {
+            Self::EnginesNode => "engines.node".to_string(),
+            Self::PackageManager => "packageManager".to_string(),
+            Self::Volta => format!("volta.{tool}"),
+        }
+    }
+}
+
+/// An available upgrade of a single toolchain pin.
+///
+/// # Example
+///
+/// ```rust
+/// use sublime_pkg_tools::upgrade::ToolchainUpgrade;
+///
+/// # fn example(upgrade: ToolchainUpgrade) {
+/// println!(
+///     "{}: {} {} -> {} ({})",
+///     upgrade.package_name,
+///     upgrade.field(),
+///     upgrade.current_spec,
+///     upgrade.new_spec,
+///     upgrade.upgrade_type
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolchainUpgrade {
+    /// Package name from package.json `name` field.
+    pub package_name: String,
+
+    /// Directory containing the package.json file.
+    pub package_path: PathBuf,
+
+    /// Kind of pin.
+    pub pin: ToolchainPin,
+
+    /// Pinned tool (e.g., "node", "pnpm", "yarn").
+    pub tool: String,
+
+    /// Value of the pin as written (e.g., ">=18.12.0", "pnpm@8.15.0+sha512.abc").
+    pub current_spec: String,
+
+    /// Version the current spec resolves to for comparison (e.g., "18.12.0").
+    pub current_version: String,
+
+    /// Latest release of the tool (the latest LTS release for Node.js).
+    pub latest_version: String,
+
+    /// Value written to the pin by the upgrade (e.g., ">=22.11.0", "pnpm@9.12.0").
+    pub new_spec: String,
+
+    /// Type of upgrade (major, minor, patch).
+    pub upgrade_type: UpgradeType,
+}
+
+impl ToolchainUpgrade {
+    /// Returns the package.json field of the pin (e.g., "engines.node", "volta.pnpm").
+    #[must_use]
+    pub fn field(&self) -> String {
+        self.pin.field(&self.tool)
+    }
+}
+
+/// Preview of available toolchain pin upgrades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolchainPreview {
+    /// Timestamp when detection was performed.
+    pub detected_at: DateTime<Utc>,
+
+    /// Available upgrades, ordered by package and field.
+    pub upgrades: Vec<ToolchainUpgrade>,
+
+    /// Tools whose release feed was not queried in offline mode.
+    ///
+    /// Upgrades of these tools are unknown, so the preview may be incomplete.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub offline_skipped: Vec<String>,
+}
+
+/// Outcome of applying toolchain pin upgrades.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolchainResult {
+    /// Whether this was a dry run, in which case no files were modified.
+    pub dry_run: bool,
+
+    /// Upgrades applied, or that would be applied in a dry run.
+    pub applied: Vec<ToolchainUpgrade>,
+
+    /// package.json files written.
+    pub modified_files: Vec<PathBuf>,
+
+    /// Directory of the backup kept after success, if any.
+    pub backup_path: Option<PathBuf>,
+
+    /// Changeset created or updated for the upgrades, if any.
+    pub changeset_id: Option<String>,
+}
+
+impl ToolchainResult {
+    /// Returns the largest upgrade type among the applied upgrades.
+    #[must_use]
+    pub fn highest_upgrade_type(&self) -> Option<UpgradeType> {
+        self.applied
+            .iter()
+            .map(|upgrade| upgrade.upgrade_type)
+            .max_by_key(|upgrade_type| upgrade_type.priority())
+    }
+}
@@ -59,6 +59,7 @@
 
 use std::path::PathBuf;
 
+use crate::types::Warning;
 use crate::version::resolution::VersionResolution;
 
 /// Result of applying version changes to package.json files.
@@ -73,6 +74,7 @@ use crate::version::resolution::VersionResolution;
 /// * `resolution` - The version resolution details including all updates
 /// * `modified_files` - List of package.json files that were modified (empty if dry_run)
 /// * `summary` - Statistical summary of the application
+/// * `warnings` - Non-fatal issues found while resolving and applying the versions
 ///
 /// # Examples
 ///
@@ -112,6 +114,11 @@ pub struct ApplyResult {
 
     /// Summary of changes applied or previewed.
     pub summary: ApplySummary,
+
+    /// Non-fatal issues found while resolving and applying the versions.
+    ///
+    /// Starts with the warnings of `resolution`.
+    pub warnings: Vec<Warning>,
 }
 
 impl ApplyResult {
@@ -138,8 +145,9 @@ impl ApplyResult {
     #[must_use]
     pub fn new(dry_run: bool, resolution: VersionResolution, modified_files: Vec<PathBuf>) -> Self {
         let summary = ApplySummary::from_resolution(&resolution, modified_files.len());
+        let warnings = resolution.warnings.clone();
 
-        Self { dry_run, resolution, modified_files, summary }
+        Self { dry_run, resolution, modified_files, summary, warnings }
     }
 
     /// Returns whether any packages were updated.
@@ -230,6 +238,7 @@ impl ApplyResult {
 /// * `direct_updates` - Number of packages updated directly from changeset
 /// * `propagated_updates` - Number of packages updated via dependency propagation
 /// * `dependency_updates` - Total number of dependency reference updates
+/// * `skipped_dependency_updates` - Number of dependency references left unchanged
 /// * `circular_dependencies` - Number of circular dependencies detected
 ///
 /// # Examples
@@ -270,6 +279,12 @@ pub struct ApplySummary {
     /// files (dependencies, devDependencies, peerDependencies).
     pub dependency_updates: usize,
 
+    /// Number of dependency references propagation left unchanged.
+    ///
+    /// Counts specs whose rewrite would not change what they resolve to, which are
+    /// not written to avoid noisy diffs and lockfile churn.
+    pub skipped_dependency_updates: usize,
+
     /// Number of circular dependencies detected during resolution.
     ///
     /// Circular dependencies are reported but do not prevent version
@@ -310,6 +325,9 @@ impl ApplySummary {
         let dependency_updates =
             resolution.updates.iter().map(|u| u.dependency_updates.len()).sum();
 
+        let skipped_dependency_updates =
+            resolution.updates.iter().map(|u| u.skipped_dependency_updates.len()).sum();
+
         let circular_dependencies = resolution.circular_dependencies.len();
 
         Self {
@@ -317,6 +335,7 @@ impl ApplySummary {
             direct_updates,
             propagated_updates,
             dependency_updates,
+            skipped_dependency_updates,
             circular_dependencies,
         }
     }
@@ -412,6 +431,7 @@ impl Default for ApplySummary {
             direct_updates: 0,
             propagated_updates: 0,
             dependency_updates: 0,
+            skipped_dependency_updates: 0,
             circular_dependencies: 0,
         }
     }
@@ -0,0 +1,183 @@
+//! Cache of version resolutions.
+//!
+//! **What**: Stores the `VersionResolution` computed for a changeset so that resolving the
+//! same changeset again against an unchanged workspace reads it back instead of recomputing it.
+//!
+//! **How**: Entries are JSON files in the `resolution-cache` directory of
+//! `upgrade.backup.backup_dir`, named `<changeset hash>-<workspace hash>.json`. The changeset
+//! hash covers the bump and the packages of the changeset. The workspace hash covers the path
+//! and raw `package.json` contents of every workspace package, the versioning strategy and its
+//! independent packages, the dependency settings, the version schemes with the release date
+//! when a package uses calendar versioning, and the version of this crate. Any change to
+//! a manifest or setting gives a new workspace hash, so stale entries are never read; they are
+//! removed the next time an entry is stored. Cache failures are logged and never fail a resolution.
+//!
+//! **Why**: A CI run often plans, previews, and applies the same changeset. Each call
+//! otherwise rediscovers the workspace and rebuilds the dependency graph from scratch.
+
+use crate::config::DependencyConfig;
+use crate::types::{Changeset, PackageInfo, VersioningStrategy};
+use crate::version::resolution::{VersionResolution, VersionSchemes};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+/// Name of the cache directory inside the workspace backup directory.
+pub(crate) const RESOLUTION_CACHE_DIR: &str = "resolution-cache";
+
+/// Identifies a cached resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ResolutionCacheKey {
+    /// Hash of the bump and packages of the changeset.
+    pub(crate) changeset: String,
+    /// Hash of the workspace manifests and resolution settings.
+    pub(crate) workspace: String,
+}
+
+impl ResolutionCacheKey {
+    /// Computes the key of a changeset resolved against the given packages.
+    ///
+    /// # Arguments
+    ///
+    /// * `fs` - Filesystem used to read the package manifests
+    /// * `changeset` - The changeset being resolved
+    /// * `packages` - The workspace packages
+    /// * `strategy` - The versioning strategy
+    /// * `independent` - Packages versioned independently under the unified strategy
+    /// * `dependency` - The dependency propagation settings
+    /// * `schemes` - The version schemes of the packages
+    ///
+    /// # Returns
+    ///
+    /// The key, or `None` when a manifest cannot be read.
+    pub(crate) async fn compute<F: AsyncFileSystem>(
+        fs: &F,
+        changeset: &Changeset,
+        packages: &[PackageInfo],
+        strategy: VersioningStrategy,
+        independent: &[String],
+        dependency: &DependencyConfig,
+        schemes: &VersionSchemes,
+    ) -> Option<Self> {
+        let mut changed: Vec<&str> = changeset.packages.iter().map(String::as_str).collect();
+        changed.sort_unstable();
+        changed.dedup();
+
+        let mut hasher = Sha256::new();
+        hasher.update(changeset.bump.as_str().as_bytes());
+        for name in changed {
+            hasher.update([0]);
+            hasher.update(name.as_bytes());
+        }
+        let changeset = hex_digest(hasher);
+
+        let mut manifests: Vec<&PackageInfo> = packages.iter().collect();
+        manifests.sort_by(|a, b| a.path().cmp(b.path()));
+
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+        hasher.update(serde_json::to_vec(&strategy).ok()?);
+        hasher.update(serde_json::to_vec(independent).ok()?);
+        hasher.update(serde_json::to_vec(dependency).ok()?);
+        if !schemes.is_empty() {
+            hasher.update(serde_json::to_vec(schemes).ok()?);
+        }
+        for package in manifests {
+            let contents = fs.read_file_string(&package.path().join("package.json")).await.ok()?;
+            hasher.update([0]);
+            hasher.update(package.path().to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(contents.as_bytes());
+        }
+        let workspace = hex_digest(hasher);
+
+        Some(Self { changeset, workspace })
+    }
+
+    fn file_name(&self) -> String {
+        format!("{}-{}.json", self.changeset, self.workspace)
+    }
+}
+
+/// Reads and writes cached resolutions in a cache directory.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolutionCache<F> {
+    dir: PathBuf,
+    fs: F,
+}
+
+impl<F: AsyncFileSystem> ResolutionCache<F> {
+    /// Creates a cache stored in `dir`.
+    pub(crate) fn new(dir: PathBuf, fs: F) -> Self {
+        Self { dir, fs }
+    }
+
+    /// Returns the cached resolution for `key`, if any.
+    ///
+    /// Unreadable or corrupt entries are treated as missing.
+    pub(crate) async fn get(&self, key: &ResolutionCacheKey) -> Option<VersionResolution> {
+        let path = self.dir.join(key.file_name());
+        if !self.fs.exists(&path).await {
+            return None;
+        }
+
+        let contents = self.fs.read_file_string(&path).await.ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(resolution) => Some(resolution),
+            Err(e) => {
+                log::debug!("Ignoring corrupt resolution cache entry {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Stores `resolution` under `key` and removes entries for other workspace states.
+    ///
+    /// Failures are logged and otherwise ignored.
+    pub(crate) async fn put(&self, key: &ResolutionCacheKey, resolution: &VersionResolution) {
+        let contents = match serde_json::to_string(resolution) {
+            Ok(contents) => contents,
+            Err(e) => {
+                log::debug!("Failed to serialize resolution for the cache: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.fs.create_dir_all(&self.dir).await {
+            log::debug!("Failed to create resolution cache {:?}: {}", self.dir, e);
+            return;
+        }
+
+        let path = self.dir.join(key.file_name());
+        if let Err(e) = self.fs.write_file_string(&path, &contents).await {
+            log::debug!("Failed to write resolution cache entry {:?}: {}", path, e);
+            return;
+        }
+
+        self.prune(key).await;
+    }
+
+    /// Removes entries computed against a different workspace state than `key`.
+    async fn prune(&self, key: &ResolutionCacheKey) {
+        let Ok(entries) = self.fs.read_dir(&self.dir).await else {
+            return;
+        };
+
+        let current = format!("-{}.json", key.workspace);
+        for entry in entries {
+            let Some(name) = entry.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if name.ends_with(".json")
+                && !name.ends_with(&current)
+                && let Err(e) = self.fs.remove(&entry).await
+            {
+                log::debug!("Failed to remove stale resolution cache entry {:?}: {}", entry, e);
+            }
+        }
+    }
+}
+
+fn hex_digest(hasher: Sha256) -> String {
+    hasher.finalize().iter().take(12).map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,179 @@
+//! Package filters for partial releases.
+//!
+//! **What**: Provides `PackageFilter`, a parsed filter expression selecting the packages a
+//! release applies to, and the helpers that narrow changesets to the selected packages.
+//!
+//! **How**: An expression is a comma-separated list of terms. Each term is a package name or
+//! a glob (`*`, `?`, `[...]`) matched against package names; a term prefixed with `!`
+//! excludes the packages it matches. A package is selected when it matches at least one
+//! include term (or there are only exclude terms) and no exclude term.
+//!
+//! **Why**: Hotfixing one package from a multi-package changeset should not force releasing
+//! every package in it. Narrowing the changeset before resolution releases only the selected
+//! packages; the rest stay pending.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use sublime_pkg_tools::version::PackageFilter;
+//!
+//! let filter = PackageFilter::parse("@myorg/*, !@myorg/docs").expect("valid filter");
+//!
+//! assert!(filter.matches("@myorg/core"));
+//! assert!(!filter.matches("@myorg/docs"));
+//! assert!(!filter.matches("lodash"));
+//! ```
+
+use crate::error::{VersionError, VersionResult};
+use crate::types::Changeset;
+use glob::Pattern;
+use std::fmt;
+
+/// A single term of a filter expression.
+#[derive(Debug, Clone)]
+enum FilterTerm {
+    /// Exact package name.
+    Name(String),
+    /// Glob pattern over package names.
+    Glob(Pattern),
+}
+
+impl FilterTerm {
+    /// Parses a term, treating names with glob metacharacters as patterns.
+    fn parse(term: &str, expression: &str) -> VersionResult<Self> {
+        if term.contains(['*', '?', '[']) {
+            Pattern::new(term).map(Self::Glob).map_err(|e| VersionError::InvalidFilter {
+                expression: expression.to_string(),
+                reason: format!("invalid glob '{term}': {e}"),
+            })
+        } else {
+            Ok(Self::Name(term.to_string()))
+        }
+    }
+
+    /// Checks whether the term matches a package name.
+    fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Name(term) => term == name,
+            Self::Glob(pattern) => pattern.matches(name),
+        }
+    }
+}
+
+/// Filter selecting the packages a release applies to.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::types::{Changeset, VersionBump};
+/// use sublime_pkg_tools::version::PackageFilter;
+///
+/// let mut changeset = Changeset::new("feature/auth", VersionBump::Patch, vec![]);
+/// changeset.add_package("@myorg/core");
+/// changeset.add_package("@myorg/utils");
+///
+/// let filter = PackageFilter::parse("@myorg/core").expect("valid filter");
+/// let narrowed = filter.narrow(&changeset).expect("core is selected");
+///
+/// assert_eq!(narrowed.packages, vec!["@myorg/core".to_string()]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PackageFilter {
+    expression: String,
+    include: Vec<FilterTerm>,
+    exclude: Vec<FilterTerm>,
+}
+
+impl PackageFilter {
+    /// Parses a filter expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - Comma-separated package names or globs; `!` excludes a term
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidFilter` if the expression has no terms, a term is
+    /// empty, or a glob is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_pkg_tools::version::PackageFilter;
+    ///
+    /// assert!(PackageFilter::parse("@myorg/core,@myorg/utils").is_ok());
+    /// assert!(PackageFilter::parse("!legacy-*").is_ok());
+    /// assert!(PackageFilter::parse(" , ").is_err());
+    /// ```
+    pub fn parse(expression: &str) -> VersionResult<Self> {
+        let invalid = |reason: &str| VersionError::InvalidFilter {
+            expression: expression.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let terms: Vec<&str> = expression.split(',').map(str::trim).collect();
+        if terms.iter().all(|term| term.is_empty()) {
+            return Err(invalid("the filter selects no packages"));
+        }
+
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        for term in terms {
+            match term.strip_prefix('!').map(str::trim) {
+                Some("") => return Err(invalid("'!' must be followed by a package name or glob")),
+                Some(excluded) => exclude.push(FilterTerm::parse(excluded, expression)?),
+                None if term.is_empty() => return Err(invalid("empty term")),
+                None => include.push(FilterTerm::parse(term, expression)?),
+            }
+        }
+
+        Ok(Self { expression: expression.trim().to_string(), include, exclude })
+    }
+
+    /// Returns the expression the filter was parsed from.
+    #[must_use]
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Checks whether a package is selected by the filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The package name
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        let included =
+            self.include.is_empty() || self.include.iter().any(|term| term.matches(name));
+        included && !self.exclude.iter().any(|term| term.matches(name))
+    }
+
+    /// Narrows a changeset to the packages selected by the filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset to narrow
+    ///
+    /// # Returns
+    ///
+    /// A copy of the changeset listing only the selected packages, or `None` if it lists
+    /// none of them.
+    #[must_use]
+    pub fn narrow(&self, changeset: &Changeset) -> Option<Changeset> {
+        let packages: Vec<String> =
+            changeset.packages.iter().filter(|name| self.matches(name)).cloned().collect();
+        if packages.is_empty() {
+            return None;
+        }
+
+        let mut narrowed = changeset.clone();
+        narrowed.packages = packages;
+        Some(narrowed)
+    }
+}
+
+impl fmt::Display for PackageFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
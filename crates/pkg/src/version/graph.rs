@@ -9,14 +9,25 @@
 //! and local/workspace protocol dependencies that should not be tracked. It provides efficient
 //! lookup of packages and their relationships through a hash map index.
 //!
+//! Every dependency declared by a workspace package, external and protocol ones included, is
+//! also kept as a `DependencyRequirement` so `why` can explain how any package ends up in the
+//! tree.
+//!
+//! `from_packages_with_external` additionally adds external (registry) dependencies as nodes,
+//! so `impact` can answer which workspace packages are affected by a third-party package by
+//! plain graph traversal. Queries about workspace packages ignore those nodes.
+//!
 //! **Why**: To enable dependency propagation, circular dependency detection, and impact analysis
 //! when resolving versions. Understanding the dependency graph is crucial for determining which
 //! packages need version updates when their dependencies change.
 
 use crate::error::{VersionError, VersionResult};
-use crate::types::{CircularDependency, PackageInfo};
+use crate::types::{
+    CircularDependency, DependencyExplanation, DependencyImpact, DependencyRequirement,
+    DependencyType, PackageInfo, dependency_target_name, should_skip_protocol,
+};
 use petgraph::graph::{DiGraph, NodeIndex};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::PathBuf;
 
 /// Dependency graph representing package relationships.
@@ -36,6 +47,8 @@ use std::path::PathBuf;
 ///
 /// * `graph` - The underlying directed graph structure
 /// * `node_map` - Maps package names to their node indices for efficient lookup
+/// * `external_map` - Maps external dependency names to their node indices, when included
+/// * `requirements` - Every dependency declared by a workspace package
 ///
 /// # Examples
 ///
@@ -70,6 +83,17 @@ pub struct DependencyGraph {
 
     /// Maps package names to their node indices for O(1) lookup.
     node_map: HashMap<String, NodeIndex>,
+
+    /// Maps external dependency names to their node indices. Empty unless the graph was built
+    /// with `from_packages_with_external`.
+    external_map: HashMap<String, NodeIndex>,
+
+    /// Node indices of the external dependencies, for filtering them out of traversals.
+    external_nodes: HashSet<NodeIndex>,
+
+    /// Every dependency declared by a workspace package, including external dependencies and
+    /// workspace or local protocol specs that are not graph edges.
+    requirements: Vec<DependencyRequirement>,
 }
 
 impl DependencyGraph {
@@ -115,8 +139,45 @@ impl DependencyGraph {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn from_packages(packages: &[PackageInfo]) -> VersionResult<Self> {
+        Self::build(packages, false)
+    }
+
+    /// Builds a dependency graph that also has a node for every external dependency.
+    ///
+    /// External dependencies are the ones declared by a workspace package that are neither
+    /// another workspace package nor a workspace or local protocol spec. npm aliases point at
+    /// their target package. Edges go from each declaring workspace package to the external
+    /// node, so `dependents`, `transitive_dependents` and `impact` work for external packages
+    /// too. Queries about workspace packages (`package_count`, `all_packages`,
+    /// `dependencies`, `topological_order`, ...) are unaffected by the external nodes.
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - A slice of `PackageInfo` representing all packages in the workspace
+    ///
+    /// # Errors
+    ///
+    /// Same as `from_packages`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::DependencyGraph;
+    ///
+    /// let graph = DependencyGraph::from_packages_with_external(&packages)?;
+    /// if let Some(impact) = graph.impact("lodash") {
+    ///     println!("lodash affects {}", impact.packages().join(", "));
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_packages_with_external(packages: &[PackageInfo]) -> VersionResult<Self> {
+        Self::build(packages, true)
+    }
+
+    fn build(packages: &[PackageInfo], include_external: bool) -> VersionResult<Self> {
         let mut graph = DiGraph::new();
         let mut node_map = HashMap::new();
+        let mut external_map: HashMap<String, NodeIndex> = HashMap::new();
 
         // Phase 1: Add all packages as nodes
         for pkg in packages {
@@ -127,7 +188,10 @@ impl DependencyGraph {
 
         // Phase 2: Add dependency edges
         // Only add edges for internal dependencies (dependencies between workspace packages)
+        let mut requirements = Vec::new();
         for pkg in packages {
+            requirements.extend(declared_requirements(pkg));
+
             let from_name = pkg.name();
             let from_idx =
                 node_map.get(from_name).copied().ok_or_else(|| VersionError::PackageNotFound {
@@ -138,17 +202,29 @@ impl DependencyGraph {
             // Get all dependencies for this package
             let dependencies = pkg.all_dependencies();
 
-            for (dep_name, _version_spec, _dep_type) in dependencies {
+            for (dep_name, version_spec, _dep_type) in dependencies {
+                // npm aliases (`"foo": "npm:@scope/foo@^1"`) point at their target package
+                let target_name = dependency_target_name(&dep_name, &version_spec);
+
                 // Only add edge if the dependency is another package in the workspace
-                if let Some(&to_idx) = node_map.get(&dep_name) {
+                if let Some(&to_idx) = node_map.get(&target_name) {
                     // Add edge from dependent to dependency (A -> B means A depends on B)
                     graph.add_edge(from_idx, to_idx, ());
+                } else if include_external && !should_skip_protocol(&version_spec) {
+                    let to_idx = *external_map
+                        .entry(target_name)
+                        .or_insert_with_key(|name| graph.add_node(name.clone()));
+                    // A package may declare the same dependency in several sections
+                    graph.update_edge(from_idx, to_idx, ());
                 }
-                // External dependencies are not added to the graph
             }
         }
 
-        Ok(Self { graph, node_map })
+        requirements.sort();
+        requirements.dedup();
+
+        let external_nodes = external_map.values().copied().collect();
+        Ok(Self { graph, node_map, external_map, external_nodes, requirements })
     }
 
     /// Returns all packages that depend on the given package.
@@ -178,7 +254,7 @@ impl DependencyGraph {
     /// ```
     #[must_use]
     pub fn dependents(&self, package: &str) -> Vec<String> {
-        if let Some(&idx) = self.node_map.get(package) {
+        if let Some(idx) = self.node_index(package) {
             // Get all nodes that have edges pointing to this package
             // (incoming edges represent packages that depend on this one)
             self.graph
@@ -222,6 +298,7 @@ impl DependencyGraph {
             // (outgoing edges represent dependencies)
             self.graph
                 .neighbors_directed(idx, petgraph::Direction::Outgoing)
+                .filter(|neighbor_idx| !self.external_nodes.contains(neighbor_idx))
                 .map(|neighbor_idx| self.graph[neighbor_idx].clone())
                 .collect()
         } else {
@@ -229,6 +306,29 @@ impl DependencyGraph {
         }
     }
 
+    /// Returns the external dependencies the given package declares.
+    ///
+    /// Always empty unless the graph was built with `from_packages_with_external`.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The name of the workspace package
+    #[must_use]
+    pub fn external_dependencies(&self, package: &str) -> Vec<String> {
+        let Some(&idx) = self.node_map.get(package) else {
+            return Vec::new();
+        };
+
+        let mut dependencies: Vec<String> = self
+            .graph
+            .neighbors_directed(idx, petgraph::Direction::Outgoing)
+            .filter(|neighbor_idx| self.external_nodes.contains(neighbor_idx))
+            .map(|neighbor_idx| self.graph[neighbor_idx].clone())
+            .collect();
+        dependencies.sort();
+        dependencies
+    }
+
     /// Checks if a package exists in the graph.
     ///
     /// # Arguments
@@ -271,6 +371,22 @@ impl DependencyGraph {
         self.node_map.len()
     }
 
+    /// Returns `true` if the package is an external dependency node of the graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The name of the package to check
+    #[must_use]
+    pub fn is_external(&self, package: &str) -> bool {
+        self.external_map.contains_key(package)
+    }
+
+    /// Returns the number of external dependency nodes in the graph.
+    #[must_use]
+    pub fn external_count(&self) -> usize {
+        self.external_map.len()
+    }
+
     /// Returns the total number of dependency relationships in the graph.
     ///
     /// # Examples
@@ -283,7 +399,14 @@ impl DependencyGraph {
     /// ```
     #[must_use]
     pub fn edge_count(&self) -> usize {
-        self.graph.edge_count()
+        if self.external_nodes.is_empty() {
+            return self.graph.edge_count();
+        }
+        self.graph
+            .raw_edges()
+            .iter()
+            .filter(|edge| !self.external_nodes.contains(&edge.target()))
+            .count()
     }
 
     /// Returns all package names in the graph.
@@ -354,6 +477,86 @@ impl DependencyGraph {
             .collect()
     }
 
+    /// Returns the length of the longest dependency chain in the graph.
+    ///
+    /// The depth is measured in edges: a package without internal dependencies has depth 0,
+    /// and a package depending on it has depth 1. Packages that form a cycle are collapsed
+    /// into a single node so cycles do not inflate the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::DependencyGraph;
+    ///
+    /// # let graph: DependencyGraph = todo!();
+    /// // app -> ui -> core
+    /// assert_eq!(graph.max_depth(), 2);
+    /// ```
+    #[must_use]
+    pub fn max_depth(&self) -> usize {
+        use petgraph::algo::tarjan_scc;
+
+        // SCCs come back in reverse topological order, so every dependency component is
+        // visited before the components that depend on it.
+        let sccs = tarjan_scc(&self.graph);
+
+        let mut component_of = HashMap::new();
+        for (component, scc) in sccs.iter().enumerate() {
+            for &idx in scc {
+                component_of.insert(idx, component);
+            }
+        }
+
+        let mut depths = vec![0usize; sccs.len()];
+        for (component, scc) in sccs.iter().enumerate() {
+            let depth = scc
+                .iter()
+                .flat_map(|&idx| self.graph.neighbors_directed(idx, petgraph::Direction::Outgoing))
+                .filter(|neighbor| !self.external_nodes.contains(neighbor))
+                .filter_map(|neighbor| component_of.get(&neighbor).copied())
+                .filter(|&target| target != component)
+                .map(|target| depths[target] + 1)
+                .max()
+                .unwrap_or(0);
+            depths[component] = depth;
+        }
+
+        depths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Returns every package ordered so that dependencies come before their dependents.
+    ///
+    /// This is the order packages must be built or published in. Packages that form a cycle
+    /// have no valid order between them; they are emitted next to each other, sorted by name,
+    /// after the packages the cycle depends on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::DependencyGraph;
+    ///
+    /// # let graph: DependencyGraph = todo!();
+    /// // app -> ui -> core
+    /// assert_eq!(graph.topological_order(), vec!["core", "ui", "app"]);
+    /// ```
+    #[must_use]
+    pub fn topological_order(&self) -> Vec<String> {
+        use petgraph::algo::tarjan_scc;
+
+        // SCCs come back in reverse topological order, which for dependent -> dependency
+        // edges is exactly dependencies first.
+        tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| !scc.iter().any(|idx| self.external_nodes.contains(idx)))
+            .flat_map(|scc| {
+                let mut names: Vec<String> =
+                    scc.into_iter().map(|idx| self.graph[idx].clone()).collect();
+                names.sort();
+                names
+            })
+            .collect()
+    }
+
     /// Finds all transitive dependents of a package.
     ///
     /// This method performs a breadth-first traversal to find all packages that
@@ -382,9 +585,8 @@ impl DependencyGraph {
     #[must_use]
     pub fn transitive_dependents(&self, package: &str) -> Vec<String> {
         use petgraph::visit::Bfs;
-        use std::collections::HashSet;
 
-        let Some(&start_idx) = self.node_map.get(package) else {
+        let Some(start_idx) = self.node_index(package) else {
             return Vec::new();
         };
 
@@ -455,9 +657,198 @@ impl DependencyGraph {
 
         // Collect all reachable nodes following outgoing edges (dependencies)
         while let Some(node_idx) = bfs.next(&self.graph) {
-            result.push(self.graph[node_idx].clone());
+            if !self.external_nodes.contains(&node_idx) {
+                result.push(self.graph[node_idx].clone());
+            }
         }
 
         result
     }
+
+    /// Returns the workspace packages impacted by a dependency.
+    ///
+    /// The packages declaring the dependency are `direct`; the workspace packages reaching
+    /// it only through them are `transitive`. For an external dependency this requires a
+    /// graph built with `from_packages_with_external`. A workspace package works with either
+    /// graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `dependency` - A workspace package or external dependency
+    ///
+    /// # Returns
+    ///
+    /// `None` if the dependency is not a node of the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::DependencyGraph;
+    ///
+    /// # let graph: DependencyGraph = todo!();
+    /// // app -> ui -> lodash
+    /// let impact = graph.impact("lodash").expect("lodash is declared");
+    /// assert_eq!(impact.direct, vec!["ui"]);
+    /// assert_eq!(impact.transitive, vec!["app"]);
+    /// ```
+    #[must_use]
+    pub fn impact(&self, dependency: &str) -> Option<DependencyImpact> {
+        self.node_index(dependency)?;
+
+        let mut direct = self.dependents(dependency);
+        direct.sort();
+        let mut transitive: Vec<String> = self
+            .transitive_dependents(dependency)
+            .into_iter()
+            .filter(|package| !direct.contains(package))
+            .collect();
+        transitive.sort();
+
+        Some(DependencyImpact {
+            dependency: dependency.to_string(),
+            external: self.is_external(dependency),
+            direct,
+            transitive,
+        })
+    }
+
+    /// Returns the node of a workspace package or external dependency.
+    fn node_index(&self, name: &str) -> Option<NodeIndex> {
+        self.node_map.get(name).or_else(|| self.external_map.get(name)).copied()
+    }
+
+    /// Explains why a package is part of the workspace dependency tree.
+    ///
+    /// Like `pnpm why`, this lists the workspace packages that declare the package directly,
+    /// with the spec and section they use, and every chain of workspace packages leading to
+    /// it. Works for workspace packages and external dependencies alike, and follows
+    /// `workspace:` and local protocol links that are not graph edges.
+    ///
+    /// Chains run from a workspace package nothing else depends on down to `package`. With
+    /// `dependent`, only chains starting at that workspace package are kept, and the direct
+    /// declarations are narrowed to those chains.
+    ///
+    /// # Arguments
+    ///
+    /// * `package` - The package to explain
+    /// * `dependent` - Optional workspace package the chains must start from
+    ///
+    /// # Returns
+    ///
+    /// `None` if `package` is neither a workspace package nor declared by one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::DependencyGraph;
+    ///
+    /// # let graph: DependencyGraph = todo!();
+    /// // app -> ui -> lodash
+    /// let explanation = graph.why("lodash", None).expect("lodash is declared");
+    /// assert_eq!(explanation.display_chains(), vec!["app → ui → lodash"]);
+    /// for requirement in &explanation.required_by {
+    ///     println!("{requirement}");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn why(&self, package: &str, dependent: Option<&str>) -> Option<DependencyExplanation> {
+        let internal = self.node_map.contains_key(package);
+        if !internal && !self.requirements.iter().any(|r| r.dependency == package) {
+            return None;
+        }
+
+        let mut dependents_of: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        for requirement in &self.requirements {
+            dependents_of
+                .entry(requirement.dependency.as_str())
+                .or_default()
+                .insert(requirement.dependent.as_str());
+        }
+
+        let mut chains = Vec::new();
+        let mut path = vec![package];
+        collect_chains(&dependents_of, dependent, &mut path, &mut chains);
+        chains.sort();
+        chains.dedup();
+
+        // Direct declarations are the last hop of each chain
+        let declaring: BTreeSet<&str> = chains
+            .iter()
+            .filter_map(|chain| chain.iter().rev().nth(1).map(String::as_str))
+            .collect();
+        let required_by = self
+            .requirements
+            .iter()
+            .filter(|r| {
+                r.dependency == package
+                    && (dependent.is_none() || declaring.contains(r.dependent.as_str()))
+            })
+            .cloned()
+            .collect();
+
+        Some(DependencyExplanation { package: package.to_string(), internal, required_by, chains })
+    }
+}
+
+/// Collects every dependency a package declares, in all four package.json sections.
+fn declared_requirements(package: &PackageInfo) -> Vec<DependencyRequirement> {
+    let json = package.package_json();
+    let sections = [
+        (&json.dependencies, DependencyType::Regular),
+        (&json.dev_dependencies, DependencyType::Dev),
+        (&json.peer_dependencies, DependencyType::Peer),
+        (&json.optional_dependencies, DependencyType::Optional),
+    ];
+
+    sections
+        .into_iter()
+        .filter_map(|(deps, dependency_type)| deps.as_ref().map(|deps| (deps, dependency_type)))
+        .flat_map(|(deps, dependency_type)| {
+            deps.iter().map(move |(name, version_spec)| DependencyRequirement {
+                dependent: package.name().to_string(),
+                dependency: dependency_target_name(name, version_spec),
+                version_spec: version_spec.clone(),
+                dependency_type,
+            })
+        })
+        .collect()
+}
+
+/// Walks from the end of `path` up through its dependents, recording finished chains.
+///
+/// A chain is finished at a package nothing depends on, or at `dependent` when given.
+/// Packages already on the path are skipped so cycles terminate. Chains are recorded
+/// top-down.
+fn collect_chains<'a>(
+    dependents_of: &HashMap<&str, BTreeSet<&'a str>>,
+    dependent: Option<&str>,
+    path: &mut Vec<&'a str>,
+    chains: &mut Vec<Vec<String>>,
+) {
+    let Some(&current) = path.last() else {
+        return;
+    };
+
+    if path.len() > 1 && dependent == Some(current) {
+        chains.push(path.iter().rev().map(ToString::to_string).collect());
+        return;
+    }
+
+    let parents: Vec<&'a str> = dependents_of
+        .get(current)
+        .map(|parents| parents.iter().copied().filter(|p| !path.contains(p)).collect())
+        .unwrap_or_default();
+
+    if parents.is_empty() {
+        if path.len() > 1 && dependent.is_none() {
+            chains.push(path.iter().rev().map(ToString::to_string).collect());
+        }
+        return;
+    }
+
+    for parent in parents {
+        path.push(parent);
+        collect_chains(dependents_of, dependent, path, chains);
+        path.pop();
+    }
 }
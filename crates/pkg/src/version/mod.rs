@@ -20,6 +20,7 @@
 //! - **Circular Dependency Detection**: Detect and report circular dependencies
 //! - **Snapshot Versions**: Generate snapshot versions for pre-release testing
 //! - **Dry-Run Mode**: Preview version changes without modifying files
+//! - **Partial Releases**: Restrict a release to packages matched by a `PackageFilter`
 //! - **Version Spec Management**: Handle workspace:, file:, link:, and portal: protocols
 //! - **Monorepo Support**: Handle both monorepo and single-package configurations
 //!
@@ -35,6 +36,11 @@
 //! All packages share the same version number and are incremented together, even if only
 //! one package changes. This is simpler but may result in more version bumps.
 //!
+//! Packages listed in `independent_packages` (names or globs such as `@myorg/plugin-*`) are
+//! left out of the unified group and versioned independently, so a core group can move
+//! together while plugins keep their own release cadence. Each `PackageUpdate` records the
+//! strategy its version was resolved with.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -163,6 +169,7 @@
 //! strategy = "independent"
 //! default_bump = "patch"
 //! snapshot_format = "{version}-{branch}.{timestamp}"
+//! resolution_cache = true
 //!
 //! [package_tools.dependency]
 //! propagation_bump = "patch"
@@ -186,11 +193,14 @@
 //! - `propagation`: Dependency propagation logic
 //! - `resolution`: Version resolution results and types
 //! - `snapshot`: Snapshot version generation
+//! - `cache`: Cache of resolutions keyed by changeset and workspace hashes
 //! - `application`: Version application to package.json files
 
 #![allow(clippy::todo)]
 
 mod application;
+mod cache;
+mod filter;
 mod graph;
 mod propagation;
 mod resolution;
@@ -201,8 +211,9 @@ mod snapshot;
 mod tests;
 
 pub use application::{ApplyResult, ApplySummary};
+pub use filter::PackageFilter;
 pub use graph::DependencyGraph;
 pub use propagation::DependencyPropagator;
-pub use resolution::{PackageUpdate, VersionResolution};
+pub use resolution::{PackageUpdate, VersionResolution, resolve_versions};
 pub use resolver::VersionResolver;
 pub use snapshot::{SnapshotContext, SnapshotGenerator, SnapshotVariable};
@@ -31,7 +31,9 @@
 //! 3. **Filter**: Filter dependents by dependency type and protocol rules
 //! 4. **Bump Version**: Apply propagation bump to dependent packages
 //! 5. **Track Chain**: Record the propagation chain for transparency
-//! 6. **Update Dependencies**: Calculate new dependency version specs
+//! 6. **Update Dependencies**: Calculate new dependency version specs, leaving specs whose
+//!    rewrite would not change them (or, with `range_policy = "keep-range"`, that the new
+//!    version already satisfies) untouched and reporting them as skipped
 //! 7. **Recurse**: Continue propagation until max depth or no more dependents
 //!
 //! # Configuration
@@ -49,6 +51,7 @@
 //! skip_file_protocol = true
 //! skip_link_protocol = true
 //! skip_portal_protocol = true
+//! range_policy = "update"
 //! ```
 //!
 //! # Examples
@@ -88,12 +91,15 @@
 
 use crate::config::DependencyConfig;
 use crate::error::{VersionError, VersionResult};
-use crate::types::dependency::{is_local_protocol, is_workspace_protocol};
+use crate::types::dependency::{
+    NpmAlias, dependency_target_name, is_local_protocol, is_same_spec, is_workspace_protocol,
+    spec_satisfied_by,
+};
 use crate::types::{
     DependencyType, DependencyUpdate, PackageInfo, UpdateReason, Version, VersionBump,
 };
 use crate::version::DependencyGraph;
-use crate::version::resolution::{PackageUpdate, VersionResolution};
+use crate::version::resolution::{PackageUpdate, VersionResolution, VersionSchemes};
 use std::collections::{HashMap, HashSet};
 
 /// Dependency propagator for version changes.
@@ -140,6 +146,8 @@ pub struct DependencyPropagator<'a> {
     packages: &'a HashMap<String, PackageInfo>,
     /// Dependency configuration.
     config: &'a DependencyConfig,
+    /// Version schemes of the packages; `None` bumps every package as semver.
+    schemes: Option<&'a VersionSchemes>,
 }
 
 impl<'a> DependencyPropagator<'a> {
@@ -171,7 +179,14 @@ impl<'a> DependencyPropagator<'a> {
         packages: &'a HashMap<String, PackageInfo>,
         config: &'a DependencyConfig,
     ) -> Self {
-        Self { graph, packages, config }
+        Self { graph, packages, config, schemes: None }
+    }
+
+    /// Bumps propagated packages according to their version schemes.
+    #[must_use]
+    pub(crate) fn with_schemes(mut self, schemes: &'a VersionSchemes) -> Self {
+        self.schemes = Some(schemes);
+        self
     }
 
     /// Propagates version changes through the dependency graph.
@@ -261,7 +276,12 @@ impl<'a> DependencyPropagator<'a> {
                     // Calculate next version for dependent
                     let current_version = dependent_pkg.version();
                     let propagation_bump = self.parse_propagation_bump()?;
-                    let next_version = current_version.bump(propagation_bump)?;
+                    let next_version = match self.schemes {
+                        Some(schemes) => {
+                            schemes.bump(&dependent_name, &current_version, propagation_bump)?
+                        }
+                        None => current_version.bump(propagation_bump)?,
+                    };
 
                     // Create package update for this dependent
                     let update = PackageUpdate::new(
@@ -315,7 +335,7 @@ impl<'a> DependencyPropagator<'a> {
 
         // Find the dependency and check its type
         for (dep_name, version_spec, dep_type) in all_deps {
-            if dep_name != dependency_name {
+            if dependency_target_name(&dep_name, &version_spec) != dependency_name {
                 continue;
             }
 
@@ -432,22 +452,30 @@ impl<'a> DependencyPropagator<'a> {
             // Find dependencies that need updating
             let all_deps = pkg.all_dependencies();
             let mut dep_updates: Vec<DependencyUpdate> = Vec::new();
+            let mut skipped: Vec<DependencyUpdate> = Vec::new();
 
             for (dep_name, old_spec, dep_type) in all_deps {
-                // Check if this dependency was updated
-                if let Some(new_version) = updated_packages.get(&dep_name) {
+                // Check if this dependency (or the target of its npm alias) was updated
+                let target_name = dependency_target_name(&dep_name, &old_spec);
+                if let Some(new_version) = updated_packages.get(&target_name) {
                     // Skip if protocol should be skipped
                     if self.should_skip_version_spec(&old_spec) {
                         continue;
                     }
 
                     // Calculate new version spec
-                    let new_spec = self.calculate_new_version_spec(&old_spec, new_version);
-
-                    // Only add if the spec actually changes
-                    if new_spec != old_spec {
-                        dep_updates
-                            .push(DependencyUpdate::new(dep_name, dep_type, old_spec, new_spec));
+                    let new_spec =
+                        self.calculate_new_version_spec(&target_name, &old_spec, new_version);
+
+                    // Only write the spec if that changes what it resolves to
+                    let unchanged = is_same_spec(&old_spec, &new_spec)
+                        || (self.config.keeps_satisfied_ranges()
+                            && spec_satisfied_by(&old_spec, new_version));
+                    let dep_update = DependencyUpdate::new(dep_name, dep_type, old_spec, new_spec);
+                    if unchanged {
+                        skipped.push(dep_update);
+                    } else {
+                        dep_updates.push(dep_update);
                     }
                 }
             }
@@ -456,6 +484,9 @@ impl<'a> DependencyPropagator<'a> {
             for dep_update in dep_updates {
                 resolution.updates[i].add_dependency_update(dep_update);
             }
+            for dep_update in skipped {
+                resolution.updates[i].add_skipped_dependency_update(dep_update);
+            }
         }
 
         Ok(())
@@ -464,10 +495,12 @@ impl<'a> DependencyPropagator<'a> {
     /// Calculates the new version spec for a dependency.
     ///
     /// This method attempts to preserve the range operator from the old spec
-    /// while updating to the new version.
+    /// while updating to the new version. The range of a calendar-versioned
+    /// dependency is replaced according to `version.calver_range` instead.
     ///
     /// # Arguments
     ///
+    /// * `dependency` - The name of the updated dependency
     /// * `old_spec` - The old version specification
     /// * `new_version` - The new version to use
     ///
@@ -481,7 +514,33 @@ impl<'a> DependencyPropagator<'a> {
     /// - `~1.0.0` with new version `1.1.0` -> `~1.1.0`
     /// - `>=1.0.0` with new version `2.0.0` -> `>=2.0.0`
     /// - `1.0.0` with new version `2.0.0` -> `2.0.0`
-    fn calculate_new_version_spec(&self, old_spec: &str, new_version: &Version) -> String {
+    /// - `npm:@scope/foo@^1.0.0` with new version `2.0.0` -> `npm:@scope/foo@^2.0.0`
+    /// - `^2024.5.0` with calendar version `2024.6.0` -> `2024.6.0` (`pin`)
+    fn calculate_new_version_spec(
+        &self,
+        dependency: &str,
+        old_spec: &str,
+        new_version: &Version,
+    ) -> String {
+        let calver_range =
+            self.schemes.and_then(|schemes| schemes.dependent_range(dependency, new_version));
+        let new_range = |range: &str| {
+            calver_range.clone().unwrap_or_else(|| Self::range_with_version(range, new_version))
+        };
+
+        if let Some(alias) = NpmAlias::parse(old_spec) {
+            // An alias without a range follows the latest release and needs no rewrite
+            return match &alias.range {
+                Some(range) => alias.with_range(&new_range(range)),
+                None => old_spec.to_string(),
+            };
+        }
+
+        new_range(old_spec)
+    }
+
+    /// Replaces the version of a range, keeping its operator.
+    pub(crate) fn range_with_version(old_spec: &str, new_version: &Version) -> String {
         let trimmed = old_spec.trim();
 
         // Detect range operator
@@ -21,13 +21,27 @@
 //! 3. **Update Creation**: Create `PackageUpdate` entries with reasons
 //! 4. **Result Assembly**: Return complete `VersionResolution` with all updates
 //!
+//! # Unified Groups With Exceptions
+//!
+//! With the unified strategy, packages matching the `independent_packages` filter are
+//! excluded from the unified group. The group moves together to one version computed from
+//! the group's highest current version, while each exception in the changeset is bumped on
+//! its own. Every `PackageUpdate` records the strategy that produced its version.
+//!
+//! # Calendar Versioning
+//!
+//! Packages matching `calver_packages`, or whose override sets the `calver` scheme, are
+//! bumped from the release date following `calver_format` rather than by the semver rules.
+//! Under the unified strategy they are excluded from the unified group like the
+//! `independent_packages` exceptions.
+//!
 //! # Examples
 //!
 //! ## Basic Resolution
 //!
 //! ```rust,ignore
-//! use sublime_pkg_tools::version::resolution::{resolve_versions, VersionResolution};
-//! use sublime_pkg_tools::types::{Changeset, VersionBump, PackageInfo};
+//! use sublime_pkg_tools::version::{resolve_versions, VersionResolution};
+//! use sublime_pkg_tools::types::{Changeset, VersionBump, PackageInfo, VersioningStrategy};
 //! use std::collections::HashMap;
 //!
 //! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -37,7 +51,7 @@
 //!
 //! let packages = HashMap::new(); // Load from filesystem
 //!
-//! let resolution = resolve_versions(&changeset, &packages).await?;
+//! let resolution = resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None).await?;
 //!
 //! for update in &resolution.updates {
 //!     println!("{}: {} -> {}",
@@ -50,13 +64,16 @@
 //! # }
 //! ```
 
+use crate::config::{CalverRangePolicy, VersionConfig};
 use crate::error::{VersionError, VersionResult};
 use crate::types::{
-    Changeset, CircularDependency, DependencyUpdate, PackageInfo, UpdateReason, Version,
-    VersioningStrategy,
+    CalverFormat, Changeset, CircularDependency, DependencyUpdate, PackageInfo, UpdateReason,
+    Version, VersionBump, VersionScheme, VersioningStrategy, Warning,
 };
+use crate::version::PackageFilter;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 /// Result of version resolution operation.
@@ -69,6 +86,7 @@ use std::path::PathBuf;
 ///
 /// * `updates` - All packages to be updated with their version changes
 /// * `circular_dependencies` - Circular dependencies detected during resolution (if any)
+/// * `warnings` - Non-fatal issues found during resolution
 ///
 /// # Examples
 ///
@@ -106,6 +124,13 @@ pub struct VersionResolution {
     /// Contains cycles in the dependency graph. Empty if no circular
     /// dependencies were found.
     pub circular_dependencies: Vec<CircularDependency>,
+
+    /// Non-fatal issues found during resolution.
+    ///
+    /// Lists each circular dependency and each changeset package resolved under its new
+    /// name. Empty when resolution found nothing worth reporting.
+    #[serde(default)]
+    pub warnings: Vec<Warning>,
 }
 
 impl VersionResolution {
@@ -119,10 +144,11 @@ impl VersionResolution {
     /// let resolution = VersionResolution::new();
     /// assert!(resolution.updates.is_empty());
     /// assert!(resolution.circular_dependencies.is_empty());
+    /// assert!(resolution.warnings.is_empty());
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        Self { updates: Vec::new(), circular_dependencies: Vec::new() }
+        Self { updates: Vec::new(), circular_dependencies: Vec::new(), warnings: Vec::new() }
     }
 
     /// Returns whether any packages will be updated.
@@ -218,12 +244,14 @@ impl Default for VersionResolution {
 /// * `next_version` - Next version after applying the bump
 /// * `reason` - Why this package is being updated
 /// * `dependency_updates` - Dependency version updates in this package (populated by propagation)
+/// * `strategy` - Strategy the next version was resolved with
+/// * `skipped_dependency_updates` - Dependency specs left unchanged (populated by propagation)
 ///
 /// # Examples
 ///
 /// ```rust,ignore
 /// use sublime_pkg_tools::version::resolution::{PackageUpdate, UpdateReason};
-/// use sublime_pkg_tools::types::Version;
+/// use sublime_pkg_tools::types::{Version, VersioningStrategy};
 /// use std::path::PathBuf;
 ///
 /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -234,6 +262,8 @@ impl Default for VersionResolution {
 ///     next_version: Version::parse("1.3.0")?,
 ///     reason: UpdateReason::DirectChange,
 ///     dependency_updates: vec![],
+///     strategy: VersioningStrategy::Independent,
+///     skipped_dependency_updates: vec![],
 /// };
 ///
 /// println!("{}: {} -> {}",
@@ -266,10 +296,26 @@ pub struct PackageUpdate {
     /// This field is populated during dependency propagation (Story 5.5).
     /// It contains updates to dependency version specs in package.json.
     pub dependency_updates: Vec<DependencyUpdate>,
+
+    /// Strategy the next version was resolved with.
+    ///
+    /// `Unified` for members of the unified group, `Independent` for packages bumped on
+    /// their own: every package under the independent strategy, and the exceptions and
+    /// propagated dependents under the unified strategy.
+    #[serde(default)]
+    pub strategy: VersioningStrategy,
+
+    /// Dependency specs propagation left unchanged.
+    ///
+    /// Each entry is a spec whose rewrite would not change what it resolves to, or that the
+    /// new version already satisfies under `range_policy = "keep-range"`. `new_version_spec`
+    /// holds the spec that was not written.
+    #[serde(default)]
+    pub skipped_dependency_updates: Vec<DependencyUpdate>,
 }
 
 impl PackageUpdate {
-    /// Creates a new package update.
+    /// Creates a new package update resolved with the independent strategy.
     ///
     /// # Arguments
     ///
@@ -305,7 +351,22 @@ impl PackageUpdate {
         next_version: Version,
         reason: UpdateReason,
     ) -> Self {
-        Self { name, path, current_version, next_version, reason, dependency_updates: Vec::new() }
+        Self {
+            name,
+            path,
+            current_version,
+            next_version,
+            reason,
+            dependency_updates: Vec::new(),
+            strategy: VersioningStrategy::Independent,
+            skipped_dependency_updates: Vec::new(),
+        }
+    }
+
+    /// Returns whether the version was resolved as a member of the unified group.
+    #[must_use]
+    pub fn is_unified(&self) -> bool {
+        self.strategy == VersioningStrategy::Unified
     }
 
     /// Returns whether this is a direct change from the changeset.
@@ -357,6 +418,104 @@ impl PackageUpdate {
     pub(crate) fn add_dependency_update(&mut self, dep_update: DependencyUpdate) {
         self.dependency_updates.push(dep_update);
     }
+
+    /// Records a dependency spec that propagation left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `dep_update` - The rewrite that was not written
+    pub(crate) fn add_skipped_dependency_update(&mut self, dep_update: DependencyUpdate) {
+        self.skipped_dependency_updates.push(dep_update);
+    }
+}
+
+/// Version schemes of the workspace packages.
+///
+/// Holds the scheme overrides and calendar versioning settings of `VersionConfig`, and the
+/// release date calendar versions are derived from. The default has no overrides: every
+/// package uses semver.
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct VersionSchemes {
+    /// Packages whose override sets a scheme, by name.
+    overrides: BTreeMap<String, VersionScheme>,
+    /// Filter terms selecting the packages using calendar versioning.
+    calver_packages: Vec<String>,
+    /// Parsed `calver_packages`.
+    #[serde(skip)]
+    calver_filter: Option<PackageFilter>,
+    /// Layout of calendar versions.
+    format: CalverFormat,
+    /// How dependents reference calendar-versioned packages.
+    range: CalverRangePolicy,
+    /// Release date calendar versions are derived from.
+    date: NaiveDate,
+}
+
+impl VersionSchemes {
+    /// Collects the version schemes of `config` for a release on `date`.
+    ///
+    /// An invalid `calver_packages` filter selects no package; configuration validation
+    /// reports it.
+    pub(crate) fn new(config: &VersionConfig, date: NaiveDate) -> Self {
+        let overrides = config
+            .packages
+            .iter()
+            .filter_map(|(name, overrides)| Some((name.clone(), overrides.scheme?)))
+            .collect();
+        Self {
+            overrides,
+            calver_packages: config.calver_packages.clone(),
+            calver_filter: config.calver_filter().ok().flatten(),
+            format: config.calver_format,
+            range: config.calver_range,
+            date,
+        }
+    }
+
+    /// Returns `true` if every package uses semantic versioning.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.calver_filter.is_none()
+            && self.overrides.values().all(|scheme| *scheme == VersionScheme::Semver)
+    }
+
+    /// Returns the version scheme of a package.
+    pub(crate) fn scheme(&self, package: &str) -> VersionScheme {
+        if let Some(scheme) = self.overrides.get(package) {
+            return *scheme;
+        }
+        match &self.calver_filter {
+            Some(filter) if filter.matches(package) => VersionScheme::Calver,
+            _ => VersionScheme::Semver,
+        }
+    }
+
+    /// Returns `true` if a package uses calendar versioning.
+    pub(crate) fn is_calver(&self, package: &str) -> bool {
+        self.scheme(package) == VersionScheme::Calver
+    }
+
+    /// Bumps the version of a package according to its scheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version cannot be bumped.
+    pub(crate) fn bump(
+        &self,
+        package: &str,
+        current: &Version,
+        bump: VersionBump,
+    ) -> VersionResult<Version> {
+        match self.scheme(package) {
+            VersionScheme::Semver => current.bump(bump),
+            VersionScheme::Calver => current.bump_calver(bump, self.date, self.format),
+        }
+    }
+
+    /// Returns the range dependents use for a new version of a calendar-versioned package,
+    /// or `None` when the package uses semantic versioning and keeps its range operator.
+    pub(crate) fn dependent_range(&self, package: &str, version: &Version) -> Option<String> {
+        self.is_calver(package).then(|| self.range.range(version))
+    }
 }
 
 /// Resolves versions for packages in a changeset.
@@ -369,6 +528,8 @@ impl PackageUpdate {
 /// * `changeset` - The changeset containing packages and bump type
 /// * `packages` - Map of package name to package info (with current versions)
 /// * `strategy` - Versioning strategy (independent or unified)
+/// * `independent` - Packages versioned independently under the unified strategy; ignored
+///   with the independent strategy
 ///
 /// # Returns
 ///
@@ -386,7 +547,7 @@ impl PackageUpdate {
 /// # Examples
 ///
 /// ```rust,ignore
-/// use sublime_pkg_tools::version::resolution::resolve_versions;
+/// use sublime_pkg_tools::version::resolve_versions;
 /// use sublime_pkg_tools::types::{Changeset, VersionBump, VersioningStrategy};
 /// use std::collections::HashMap;
 ///
@@ -400,6 +561,7 @@ impl PackageUpdate {
 ///     &changeset,
 ///     &packages,
 ///     VersioningStrategy::Independent,
+///     None,
 /// ).await?;
 ///
 /// for update in &resolution.updates {
@@ -416,14 +578,43 @@ pub async fn resolve_versions(
     changeset: &Changeset,
     packages: &HashMap<String, PackageInfo>,
     strategy: VersioningStrategy,
+    independent: Option<&PackageFilter>,
+) -> VersionResult<VersionResolution> {
+    resolve_versions_with_schemes(
+        changeset,
+        packages,
+        strategy,
+        independent,
+        &VersionSchemes::default(),
+    )
+    .await
+}
+
+/// Resolves versions for packages in a changeset, bumping each package by its scheme.
+///
+/// Behaves like [`resolve_versions`], except that packages with a non-semver scheme in
+/// `schemes` are bumped by that scheme, and calendar versioned packages are left out of
+/// the unified group.
+///
+/// # Errors
+///
+/// Returns the same errors as [`resolve_versions`].
+pub(crate) async fn resolve_versions_with_schemes(
+    changeset: &Changeset,
+    packages: &HashMap<String, PackageInfo>,
+    strategy: VersioningStrategy,
+    independent: Option<&PackageFilter>,
+    schemes: &VersionSchemes,
 ) -> VersionResult<VersionResolution> {
     // Validate all packages exist
     validate_packages_exist(changeset, packages)?;
 
     // Resolve based on strategy
     match strategy {
-        VersioningStrategy::Independent => resolve_independent(changeset, packages).await,
-        VersioningStrategy::Unified => resolve_unified(changeset, packages).await,
+        VersioningStrategy::Independent => resolve_independent(changeset, packages, schemes).await,
+        VersioningStrategy::Unified => {
+            resolve_unified(changeset, packages, independent, schemes).await
+        }
     }
 }
 
@@ -461,6 +652,7 @@ fn validate_packages_exist(
 ///
 /// * `changeset` - The changeset containing packages and bump type
 /// * `packages` - Map of package info
+/// * `schemes` - Version schemes of the packages
 ///
 /// # Returns
 ///
@@ -472,6 +664,7 @@ fn validate_packages_exist(
 async fn resolve_independent(
     changeset: &Changeset,
     packages: &HashMap<String, PackageInfo>,
+    schemes: &VersionSchemes,
 ) -> VersionResult<VersionResolution> {
     let mut resolution = VersionResolution::new();
 
@@ -483,7 +676,7 @@ async fn resolve_independent(
             })?;
 
         let current_version = package_info.version();
-        let next_version = current_version.bump(changeset.bump)?;
+        let next_version = schemes.bump(package_name, &current_version, changeset.bump)?;
 
         let update = PackageUpdate::new(
             package_name.clone(),
@@ -501,18 +694,22 @@ async fn resolve_independent(
 
 /// Resolves versions using unified strategy.
 ///
-/// All packages are bumped to the same version, which is the highest next version
-/// among all packages after applying the bump.
+/// All packages of the unified group are bumped to the same version: the highest current
+/// version in the group after applying the bump. Packages matching `independent` and
+/// packages using calendar versioning are not part of the group; those in the changeset are
+/// bumped on their own.
 ///
 /// # Arguments
 ///
 /// * `changeset` - The changeset containing packages and bump type
 /// * `packages` - Map of package info
+/// * `independent` - Packages excluded from the unified group
+/// * `schemes` - Version schemes of the packages
 ///
 /// # Returns
 ///
-/// Returns a `VersionResolution` with updates for all packages in the changeset,
-/// all using the same next version.
+/// Returns a `VersionResolution` with updates for every package of the group, all using
+/// the same next version, and for the exceptions in the changeset.
 ///
 /// # Errors
 ///
@@ -520,13 +717,41 @@ async fn resolve_independent(
 async fn resolve_unified(
     changeset: &Changeset,
     packages: &HashMap<String, PackageInfo>,
+    independent: Option<&PackageFilter>,
+    schemes: &VersionSchemes,
 ) -> VersionResult<VersionResolution> {
+    let is_exception = |name: &str| {
+        independent.is_some_and(|filter| filter.matches(name)) || schemes.is_calver(name)
+    };
+    let (exceptions, group): (Vec<_>, Vec<_>) =
+        packages.iter().partition(|(name, _)| is_exception(name));
+
+    // Exceptions in the changeset are bumped on their own
     let mut resolution = VersionResolution::new();
+    for (package_name, package_info) in exceptions {
+        if !changeset.packages.contains(package_name) {
+            continue;
+        }
+        let current_version = package_info.version();
+        let next_version = schemes.bump(package_name, &current_version, changeset.bump)?;
+        resolution.add_update(PackageUpdate::new(
+            package_name.clone(),
+            package_info.path().to_path_buf(),
+            current_version,
+            next_version,
+            UpdateReason::DirectChange,
+        ));
+    }
 
-    // Find the highest current version across ALL workspace packages
+    // Only exceptions changed: the unified group stays where it is
+    if !changeset.packages.is_empty() && changeset.packages.iter().all(|name| is_exception(name)) {
+        return Ok(resolution);
+    }
+
+    // Find the highest current version across the unified group
     let mut highest_version: Option<Version> = None;
 
-    for package_info in packages.values() {
+    for (_, package_info) in &group {
         let current_version = package_info.version();
 
         highest_version = match highest_version {
@@ -549,9 +774,9 @@ async fn resolve_unified(
         return Ok(resolution);
     };
 
-    // Apply unified version to ALL workspace packages (not just those in changeset)
+    // Apply unified version to ALL packages of the group (not just those in changeset)
     // This is the core principle of unified strategy: all packages move together
-    for (package_name, package_info) in packages {
+    for (package_name, package_info) in group {
         let current_version = package_info.version();
 
         // Determine update reason: packages in changeset are direct changes,
@@ -562,13 +787,14 @@ async fn resolve_unified(
             UpdateReason::UnifiedStrategy
         };
 
-        let update = PackageUpdate::new(
+        let mut update = PackageUpdate::new(
             package_name.clone(),
             package_info.path().to_path_buf(),
             current_version,
             unified_next_version.clone(),
             reason,
         );
+        update.strategy = VersioningStrategy::Unified;
 
         resolution.add_update(update);
     }
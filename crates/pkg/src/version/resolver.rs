@@ -15,13 +15,20 @@
 
 use crate::config::PackageToolsConfig;
 use crate::error::{VersionError, VersionResult};
-use crate::types::{Changeset, DependencyType, PackageInfo, VersioningStrategy};
+use crate::line_endings::LineEnding;
+use crate::types::{
+    Changeset, DependencyType, PackageInfo, VersioningStrategy, Warning, WarningKind,
+};
 use crate::version::application::ApplyResult;
+use crate::version::cache::{RESOLUTION_CACHE_DIR, ResolutionCache, ResolutionCacheKey};
+use crate::version::filter::PackageFilter;
 use crate::version::graph::DependencyGraph;
 use crate::version::propagation::DependencyPropagator;
-use crate::version::resolution::{PackageUpdate, VersionResolution, resolve_versions};
+use crate::version::resolution::{
+    PackageUpdate, VersionResolution, VersionSchemes, resolve_versions_with_schemes,
+};
 use package_json::PackageJson;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager};
 use sublime_standard_tools::monorepo::{MonorepoDetector, MonorepoDetectorTrait, WorkspacePackage};
@@ -87,6 +94,8 @@ pub struct VersionResolver<F: AsyncFileSystem = FileSystemManager> {
     workspace_root: PathBuf,
     /// Versioning strategy (independent or unified).
     strategy: VersioningStrategy,
+    /// Packages versioned independently under the unified strategy.
+    independent: Option<PackageFilter>,
     /// Filesystem implementation for I/O operations.
     fs: F,
     /// Complete package tools configuration.
@@ -160,6 +169,7 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     ///
     /// Returns `VersionError::InvalidWorkspaceRoot` if the workspace root is invalid.
     /// Returns `VersionError::PackageJsonError` if package.json cannot be read in single-package mode.
+    /// Returns `VersionError::InvalidFilter` if `version.independent_packages` is not a valid filter.
     ///
     /// # Examples
     ///
@@ -204,8 +214,12 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
             crate::config::VersioningStrategy::Independent => VersioningStrategy::Independent,
             crate::config::VersioningStrategy::Unified => VersioningStrategy::Unified,
         };
+        let independent = match strategy {
+            VersioningStrategy::Unified => config.version.independent_filter()?,
+            VersioningStrategy::Independent => None,
+        };
 
-        Ok(Self { workspace_root, strategy, fs, config, is_monorepo })
+        Ok(Self { workspace_root, strategy, independent, fs, config, is_monorepo })
     }
 
     /// Returns whether the project is detected as a monorepo.
@@ -514,6 +528,9 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     /// configured versioning strategy (independent or unified) to determine how versions
     /// are calculated.
     ///
+    /// When `version.resolution_cache` is enabled, the result is cached in the workspace
+    /// backup directory and reused while the changeset and the package manifests are unchanged.
+    ///
     /// # Arguments
     ///
     /// * `changeset` - The changeset containing packages and bump type
@@ -525,8 +542,11 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     ///
     /// # Errors
     ///
+    /// Packages renamed in `changeset.package_renames` are resolved under their new name.
+    ///
     /// This method will return an error if:
-    /// - A package in the changeset is not found in the workspace
+    /// - A package in the changeset is not in the workspace and was not renamed
+    ///   (`VersionError::StaleChangesetPackages`, listing every such package)
     /// - A package has an invalid version in package.json
     /// - Version bump calculation fails
     /// - Filesystem operations fail
@@ -570,7 +590,111 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     ) -> VersionResult<VersionResolution> {
         // Discover all packages in the workspace
         let package_list = self.discover_packages().await?;
+        let (changeset, renamed) = self.remap_renamed_packages(changeset, &package_list)?;
+        let changeset = &changeset;
+        let schemes = VersionSchemes::new(
+            &self.config.version,
+            self.config.changelog.date.release_date().date_naive(),
+        );
+
+        let cache = self.resolution_cache();
+        let key = match cache {
+            Some(_) => {
+                ResolutionCacheKey::compute(
+                    &self.fs,
+                    changeset,
+                    &package_list,
+                    self.strategy,
+                    &self.config.version.independent_packages,
+                    &self.config.dependency,
+                    &schemes,
+                )
+                .await
+            }
+            None => None,
+        };
+        if let (Some(cache), Some(key)) = (&cache, &key)
+            && let Some(mut resolution) = cache.get(key).await
+        {
+            resolution.warnings.extend(renamed);
+            return Ok(resolution);
+        }
+
+        let mut resolution = self.compute_resolution(changeset, package_list, &schemes).await?;
 
+        if let (Some(cache), Some(key)) = (&cache, &key) {
+            cache.put(key, &resolution).await;
+        }
+
+        resolution.warnings.extend(renamed);
+        Ok(resolution)
+    }
+
+    /// Replaces renamed packages of a changeset with their new names.
+    ///
+    /// Returns the remapped changeset with a `RenamedPackage` warning per renamed package.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::StaleChangesetPackages` if, after renaming, the changeset
+    /// lists packages that are not in the workspace.
+    fn remap_renamed_packages(
+        &self,
+        changeset: &Changeset,
+        package_list: &[PackageInfo],
+    ) -> VersionResult<(Changeset, Vec<Warning>)> {
+        let mut changeset = changeset.clone();
+        let mut warnings = Vec::new();
+        for package in changeset.packages.clone() {
+            if let Some(renamed) = self.config.changeset.renamed_package(&package) {
+                log::debug!("Resolving renamed package {package} as {renamed}");
+                warnings.push(
+                    Warning::new(
+                        WarningKind::RenamedPackage,
+                        format!("Changeset package {package} was resolved as {renamed}"),
+                    )
+                    .with_package(renamed),
+                );
+                changeset.remove_package(&package);
+                changeset.add_package(renamed);
+            }
+        }
+
+        let known: HashSet<&str> = package_list.iter().map(PackageInfo::name).collect();
+        let stale: Vec<String> = changeset
+            .packages
+            .iter()
+            .filter(|package| !known.contains(package.as_str()))
+            .cloned()
+            .collect();
+        if !stale.is_empty() {
+            return Err(VersionError::StaleChangesetPackages {
+                packages: stale,
+                workspace_root: self.workspace_root.clone(),
+            });
+        }
+
+        Ok((changeset, warnings))
+    }
+
+    /// Returns the resolution cache, or `None` when `version.resolution_cache` is disabled.
+    fn resolution_cache(&self) -> Option<ResolutionCache<F>> {
+        self.config.version.resolution_cache.then(|| {
+            let dir = self
+                .workspace_root
+                .join(&self.config.upgrade.backup.backup_dir)
+                .join(RESOLUTION_CACHE_DIR);
+            ResolutionCache::new(dir, self.fs.clone())
+        })
+    }
+
+    /// Resolves a changeset against the discovered packages, without the cache.
+    async fn compute_resolution(
+        &self,
+        changeset: &Changeset,
+        package_list: Vec<PackageInfo>,
+        schemes: &VersionSchemes,
+    ) -> VersionResult<VersionResolution> {
         // Build dependency graph for propagation (before consuming package_list)
         let (graph, circular_deps) = if self.config.dependency.propagation_bump != "none" {
             let g = DependencyGraph::from_packages(&package_list)?;
@@ -588,15 +712,27 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
         }
 
         // Step 1: Resolve direct version changes from changeset
-        let mut resolution = resolve_versions(changeset, &packages, self.strategy).await?;
+        let mut resolution = resolve_versions_with_schemes(
+            changeset,
+            &packages,
+            self.strategy,
+            self.independent.as_ref(),
+            schemes,
+        )
+        .await?;
 
         // Step 2: Add circular dependencies to resolution
+        resolution.warnings.extend(circular_deps.iter().map(|cycle| {
+            Warning::new(WarningKind::CircularDependency, cycle.to_string())
+                .with_package(cycle.cycle.first().cloned().unwrap_or_default())
+        }));
         resolution.circular_dependencies = circular_deps;
 
         // Step 3: Apply dependency propagation if configured
         if let Some(graph) = graph {
             // Create propagator and apply propagation
-            let propagator = DependencyPropagator::new(&graph, &packages, &self.config.dependency);
+            let propagator = DependencyPropagator::new(&graph, &packages, &self.config.dependency)
+                .with_schemes(schemes);
             propagator.propagate(&mut resolution)?;
         }
 
@@ -738,6 +874,71 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
         Ok(ApplyResult::new(false, resolution, modified_files))
     }
 
+    /// Applies version changes for the changeset packages selected by a filter.
+    ///
+    /// The changeset is narrowed to the packages matching `filter` before resolution, so
+    /// only those packages (and, with dependency propagation enabled, their dependents)
+    /// are bumped. Callers keep the unselected packages pending, for example with
+    /// `ChangesetManager::archive_packages`.
+    ///
+    /// # Arguments
+    ///
+    /// * `changeset` - The changeset containing packages and version bump information
+    /// * `filter` - The filter selecting the packages to release
+    /// * `dry_run` - If true, only preview changes without modifying files
+    ///
+    /// # Returns
+    ///
+    /// The `ApplyResult` for the selected packages. When the filter selects no package of
+    /// the changeset, the resolution is empty and no file is modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns `VersionError::InvalidFilter` with the unified strategy when the filter
+    /// selects a package of the unified group, which shares one version and cannot be
+    /// released partially. Filters selecting only `version.independent_packages` are
+    /// allowed. Otherwise fails like [`apply_versions`](Self::apply_versions).
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use sublime_pkg_tools::version::{PackageFilter, VersionResolver};
+    ///
+    /// # async fn example(resolver: VersionResolver, changeset: sublime_pkg_tools::types::Changeset) -> Result<(), Box<dyn std::error::Error>> {
+    /// let filter = PackageFilter::parse("@myorg/core")?;
+    /// let result = resolver.apply_versions_filtered(&changeset, &filter, false).await?;
+    ///
+    /// println!("Released {} package(s)", result.summary.packages_updated);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_versions_filtered(
+        &self,
+        changeset: &Changeset,
+        filter: &PackageFilter,
+        dry_run: bool,
+    ) -> VersionResult<ApplyResult> {
+        let narrowed = filter.narrow(changeset);
+        if self.strategy == VersioningStrategy::Unified {
+            let is_exception =
+                |name: &String| self.independent.as_ref().is_some_and(|f| f.matches(name));
+            if !narrowed.as_ref().is_some_and(|narrowed| narrowed.packages.iter().all(is_exception))
+            {
+                return Err(VersionError::InvalidFilter {
+                    expression: filter.expression().to_string(),
+                    reason: "partial releases are only supported for independent packages with \
+                             the unified strategy"
+                        .to_string(),
+                });
+            }
+        }
+
+        match narrowed {
+            Some(narrowed) => self.apply_versions(&narrowed, dry_run).await,
+            None => Ok(ApplyResult::new(dry_run, VersionResolution::new(), vec![])),
+        }
+    }
+
     /// Applies version updates to all packages in the resolution.
     ///
     /// This internal method iterates through all package updates and writes
@@ -767,9 +968,11 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
                     workspace_root: self.workspace_root.clone(),
                 })?;
 
-            let package_json_path = self.write_package_json(package_info, update, backups).await?;
-
-            modified_files.push(package_json_path);
+            if let Some(package_json_path) =
+                self.write_package_json(package_info, update, backups).await?
+            {
+                modified_files.push(package_json_path);
+            }
         }
 
         Ok(())
@@ -779,7 +982,8 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     ///
     /// This method reads the current package.json, creates a backup, updates
     /// the version field and dependency references, then writes the file back
-    /// with preserved formatting.
+    /// with preserved formatting. The file is not written when its content would
+    /// not change.
     ///
     /// # Arguments
     ///
@@ -789,7 +993,8 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
     ///
     /// # Returns
     ///
-    /// Returns the path to the modified package.json file.
+    /// Returns the path to the modified package.json file, or `None` when it was
+    /// left untouched.
     ///
     /// # Errors
     ///
@@ -803,7 +1008,7 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
         package: &PackageInfo,
         update: &PackageUpdate,
         backups: &mut Vec<(PathBuf, Vec<u8>)>,
-    ) -> VersionResult<PathBuf> {
+    ) -> VersionResult<Option<PathBuf>> {
         let package_json_path = package.path().join("package.json");
 
         // Read current package.json content
@@ -872,12 +1077,22 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
         }
 
         // Serialize with pretty formatting
-        let json_string =
+        let mut json_string =
             serde_json::to_string_pretty(&pkg_json).map_err(|e| VersionError::ApplyFailed {
                 path: package_json_path.clone(),
                 reason: format!("Failed to serialize JSON: {}", e),
             })?;
 
+        // Keep the trailing newline and line endings of the original file
+        let original = String::from_utf8_lossy(&current_content);
+        if original.ends_with('\n') {
+            json_string.push('\n');
+        }
+        let json_string = LineEnding::detect(&original).apply(&json_string);
+        if json_string == original {
+            return Ok(None);
+        }
+
         // Write to file using filesystem manager
         self.fs.write_file_string(&package_json_path, &json_string).await.map_err(|e| {
             VersionError::ApplyFailed {
@@ -886,7 +1101,7 @@ impl<F: AsyncFileSystem + Clone + Send + Sync + 'static> VersionResolver<F> {
             }
         })?;
 
-        Ok(package_json_path)
+        Ok(Some(package_json_path))
     }
 
     /// Checks if a version spec should be skipped (workspace protocols and local references).
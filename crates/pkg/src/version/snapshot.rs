@@ -381,7 +381,8 @@ impl SnapshotGenerator {
     /// Extracts a short hash from a full commit hash.
     ///
     /// Returns the first 7 characters of the commit hash, or the entire hash
-    /// if it's shorter than 7 characters.
+    /// if it's shorter than 7 characters. The hash is cut on a character boundary,
+    /// so non-ASCII input never panics.
     ///
     /// # Arguments
     ///
@@ -400,7 +401,7 @@ impl SnapshotGenerator {
     /// assert_eq!(short, "abc123d");
     /// ```
     fn short_hash(commit: &str) -> &str {
-        if commit.len() > 7 { &commit[..7] } else { commit }
+        commit.char_indices().nth(7).map_or(commit, |(index, _)| &commit[..index])
     }
 
     /// Validates that a generated snapshot version is semver-compatible.
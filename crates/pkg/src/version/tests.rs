@@ -18,7 +18,7 @@
 
 use crate::config::PackageToolsConfig;
 use crate::error::VersionError;
-use crate::types::PackageInfo;
+use crate::types::{DependencyType, PackageInfo};
 use crate::version::{DependencyGraph, VersionResolver};
 use package_json::PackageJson;
 use std::collections::HashMap;
@@ -525,6 +525,21 @@ fn test_graph_file_protocol_filtered() {
     assert_eq!(graph.edge_count(), 0);
 }
 
+#[test]
+fn test_graph_npm_alias_links_target_package() {
+    // An alias key differs from the package name; the edge follows the alias target
+    let packages = vec![
+        create_package_info("@scope/core", "1.0.0", vec![]),
+        create_package_info("app", "1.0.0", vec![("core-v1", "npm:@scope/core@^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(graph.dependencies("app"), vec!["@scope/core".to_string()]);
+    assert_eq!(graph.dependents("@scope/core"), vec!["app".to_string()]);
+}
+
 #[test]
 fn test_graph_dev_dependencies_included() {
     // Dev dependencies should be included in the graph
@@ -630,6 +645,81 @@ fn test_graph_multiple_circular_dependencies() {
     }
 }
 
+#[test]
+fn test_graph_max_depth_linear_chain() {
+    // package-c -> package-b -> package-a
+    let packages = vec![
+        create_package_info("package-a", "1.0.0", vec![]),
+        create_package_info("package-b", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-c", "1.0.0", vec![("package-b", "^1.0.0")]),
+        create_package_info("package-d", "1.0.0", vec![("package-a", "^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert_eq!(graph.max_depth(), 2);
+}
+
+#[test]
+fn test_graph_max_depth_collapses_cycles() {
+    // package-c -> (package-a <-> package-b)
+    let packages = vec![
+        create_package_info("package-a", "1.0.0", vec![("package-b", "^1.0.0")]),
+        create_package_info("package-b", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-c", "1.0.0", vec![("package-a", "^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert_eq!(graph.max_depth(), 1);
+}
+
+#[test]
+fn test_graph_max_depth_without_edges() {
+    let packages = vec![
+        create_package_info("package-a", "1.0.0", vec![]),
+        create_package_info("package-b", "1.0.0", vec![]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert_eq!(graph.max_depth(), 0);
+}
+
+#[test]
+fn test_graph_topological_order_puts_dependencies_first() {
+    // package-c -> package-b -> package-a, package-d -> package-a
+    let packages = vec![
+        create_package_info("package-c", "1.0.0", vec![("package-b", "^1.0.0")]),
+        create_package_info("package-d", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-b", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-a", "1.0.0", vec![]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let order = graph.topological_order();
+    let position = |name: &str| order.iter().position(|p| p == name).expect("missing package");
+
+    assert_eq!(order.len(), 4);
+    assert!(position("package-a") < position("package-b"));
+    assert!(position("package-b") < position("package-c"));
+    assert!(position("package-a") < position("package-d"));
+}
+
+#[test]
+fn test_graph_topological_order_groups_cycles() {
+    // package-c -> (package-b <-> package-a)
+    let packages = vec![
+        create_package_info("package-c", "1.0.0", vec![("package-b", "^1.0.0")]),
+        create_package_info("package-b", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-a", "1.0.0", vec![("package-b", "^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert_eq!(graph.topological_order(), vec!["package-a", "package-b", "package-c"]);
+}
+
 #[test]
 fn test_graph_contains_existing_package() {
     let packages = vec![create_package_info("package-a", "1.0.0", vec![])];
@@ -668,6 +758,93 @@ fn test_graph_dependencies_nonexistent_package() {
     assert!(deps.is_empty());
 }
 
+#[test]
+fn test_graph_why_external_dependency() {
+    // app -> ui -> lodash, tools -(dev)-> lodash
+    let packages = vec![
+        create_package_info("app", "1.0.0", vec![("ui", "^1.0.0")]),
+        create_package_info("ui", "1.0.0", vec![("lodash", "^4.17.0")]),
+        create_package_info_with_dev_deps("tools", "1.0.0", vec![], vec![("lodash", "^4.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let explanation = graph.why("lodash", None).expect("lodash is declared");
+
+    assert!(!explanation.internal);
+    assert_eq!(explanation.required_by.len(), 2);
+    assert_eq!(explanation.required_by[0].dependent, "tools");
+    assert_eq!(explanation.required_by[0].dependency_type, DependencyType::Dev);
+    assert_eq!(explanation.required_by[1].dependent, "ui");
+    assert_eq!(explanation.required_by[1].version_spec, "^4.17.0");
+    assert_eq!(explanation.display_chains(), vec!["app → ui → lodash", "tools → lodash"]);
+}
+
+#[test]
+fn test_graph_why_follows_workspace_protocol() {
+    // app -> core through workspace:*, which is not a graph edge
+    let packages = vec![
+        create_package_info("core", "1.0.0", vec![]),
+        create_package_info("app", "1.0.0", vec![("core", "workspace:*")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let explanation = graph.why("core", None).expect("core is a workspace package");
+
+    assert_eq!(graph.edge_count(), 0);
+    assert!(explanation.internal);
+    assert_eq!(explanation.required_by[0].version_spec, "workspace:*");
+    assert_eq!(explanation.display_chains(), vec!["app → core"]);
+}
+
+#[test]
+fn test_graph_why_from_dependent() {
+    // app -> ui -> core, cli -> core
+    let packages = vec![
+        create_package_info("core", "1.0.0", vec![]),
+        create_package_info("ui", "1.0.0", vec![("core", "^1.0.0")]),
+        create_package_info("app", "1.0.0", vec![("ui", "^1.0.0")]),
+        create_package_info("cli", "1.0.0", vec![("core", "^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let explanation = graph.why("core", Some("app")).expect("core is a workspace package");
+
+    assert_eq!(explanation.display_chains(), vec!["app → ui → core"]);
+    assert_eq!(explanation.required_by.len(), 1);
+    assert_eq!(explanation.required_by[0].dependent, "ui");
+
+    let unrelated = graph.why("core", Some("core")).expect("core is a workspace package");
+    assert!(unrelated.chains.is_empty());
+    assert!(unrelated.required_by.is_empty());
+}
+
+#[test]
+fn test_graph_why_terminates_on_cycles() {
+    // app -> package-b <-> package-a
+    let packages = vec![
+        create_package_info("app", "1.0.0", vec![("package-b", "^1.0.0")]),
+        create_package_info("package-b", "1.0.0", vec![("package-a", "^1.0.0")]),
+        create_package_info("package-a", "1.0.0", vec![("package-b", "^1.0.0")]),
+    ];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let explanation = graph.why("package-a", None).expect("package-a is a workspace package");
+
+    assert_eq!(explanation.display_chains(), vec!["app → package-b → package-a"]);
+}
+
+#[test]
+fn test_graph_why_unknown_package() {
+    let packages = vec![create_package_info("package-a", "1.0.0", vec![])];
+
+    let graph = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+
+    assert!(graph.why("missing", None).is_none());
+    let explanation = graph.why("package-a", None).expect("package-a is a workspace package");
+    assert!(explanation.required_by.is_empty());
+    assert!(explanation.chains.is_empty());
+}
+
 #[test]
 fn test_graph_all_packages() {
     let packages = vec![
@@ -838,6 +1015,60 @@ fn test_graph_diamond_dependency_structure() {
     assert!(transitive_a.contains(&"package-d".to_string()));
 }
 
+#[test]
+fn test_graph_with_external_dependencies_impact() {
+    // app -> ui -> lodash, tools -(dev)-> lodash, api -> express
+    let packages = vec![
+        create_package_info("app", "1.0.0", vec![("ui", "^1.0.0")]),
+        create_package_info("ui", "1.0.0", vec![("lodash", "^4.17.0")]),
+        create_package_info_with_dev_deps("tools", "1.0.0", vec![], vec![("lodash", "^4.0.0")]),
+        create_package_info("api", "1.0.0", vec![("express", "^4.0.0")]),
+    ];
+
+    let graph =
+        DependencyGraph::from_packages_with_external(&packages).expect("Failed to create graph");
+
+    assert!(graph.is_external("lodash"));
+    assert!(!graph.is_external("ui"));
+    assert_eq!(graph.external_count(), 2);
+    assert_eq!(graph.external_dependencies("ui"), vec!["lodash".to_string()]);
+
+    let impact = graph.impact("lodash").expect("lodash is a node");
+    assert!(impact.external);
+    assert_eq!(impact.direct, vec!["tools".to_string(), "ui".to_string()]);
+    assert_eq!(impact.transitive, vec!["app".to_string()]);
+    assert_eq!(impact.packages(), vec!["tools", "ui", "app"]);
+
+    let internal = graph.impact("ui").expect("ui is a workspace package");
+    assert!(!internal.external);
+    assert_eq!(internal.direct, vec!["app".to_string()]);
+    assert!(graph.impact("react").is_none());
+}
+
+#[test]
+fn test_graph_external_nodes_do_not_change_workspace_queries() {
+    let packages = vec![
+        create_package_info("core", "1.0.0", vec![("lodash", "^4.17.0")]),
+        create_package_info("app", "1.0.0", vec![("core", "^1.0.0"), ("react", "^18.0.0")]),
+    ];
+
+    let plain = DependencyGraph::from_packages(&packages).expect("Failed to create graph");
+    let external =
+        DependencyGraph::from_packages_with_external(&packages).expect("Failed to create graph");
+
+    assert_eq!(external.package_count(), plain.package_count());
+    assert_eq!(external.edge_count(), plain.edge_count());
+    assert_eq!(external.max_depth(), plain.max_depth());
+    assert_eq!(external.topological_order(), plain.topological_order());
+    assert_eq!(external.dependencies("app"), vec!["core".to_string()]);
+    assert_eq!(external.transitive_dependencies("app"), vec!["core".to_string()]);
+    assert!(!external.contains("lodash"));
+
+    // Without external nodes, external packages have no impact to report
+    assert!(plain.impact("lodash").is_none());
+    assert!(plain.external_dependencies("core").is_empty());
+}
+
 // ============================================================================
 // Comprehensive Circular Dependency Detection Tests (Story 5.3)
 // ============================================================================
@@ -1349,10 +1580,16 @@ mod circular_dependency_property_tests {
     #[allow(clippy::unwrap_used)]
     mod resolution_tests {
         use super::*;
+        use crate::config::VersionConfig;
         use crate::types::{
-            Changeset, DependencyType, UpdateReason, Version, VersionBump, VersioningStrategy,
+            CalverFormat, Changeset, DependencyType, UpdateReason, Version, VersionBump,
+            VersioningStrategy,
+        };
+        use crate::version::PackageFilter;
+        use crate::version::resolution::{
+            PackageUpdate, VersionResolution, VersionSchemes, resolve_versions,
+            resolve_versions_with_schemes,
         };
-        use crate::version::resolution::{PackageUpdate, VersionResolution, resolve_versions};
         use std::collections::HashMap;
 
         /// Test resolving versions with independent strategy and minor bump
@@ -1377,7 +1614,7 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1415,7 +1652,7 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1443,7 +1680,7 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1471,7 +1708,7 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1509,7 +1746,9 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Unified).await.unwrap();
+                resolve_versions(&changeset, &packages, VersioningStrategy::Unified, None)
+                    .await
+                    .unwrap();
 
             assert_eq!(resolution.updates.len(), 3);
 
@@ -1552,7 +1791,9 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Unified).await.unwrap();
+                resolve_versions(&changeset, &packages, VersioningStrategy::Unified, None)
+                    .await
+                    .unwrap();
 
             assert_eq!(resolution.updates.len(), 2);
 
@@ -1561,9 +1802,81 @@ mod circular_dependency_property_tests {
 
             for update in &resolution.updates {
                 assert_eq!(update.next_version, expected_next);
+                assert!(update.is_unified());
             }
         }
 
+        fn hybrid_packages() -> HashMap<String, PackageInfo> {
+            let mut packages = HashMap::new();
+            for (name, version) in [
+                ("@myorg/core", "2.1.0"),
+                ("@myorg/utils", "2.1.0"),
+                ("@myorg/plugin-auth", "0.4.2"),
+                ("@myorg/plugin-cache", "3.0.0"),
+            ] {
+                packages.insert(name.to_string(), create_package_info(name, version, vec![]));
+            }
+            packages
+        }
+
+        /// Test unified strategy with independent exceptions
+        #[tokio::test]
+        async fn test_resolve_unified_with_independent_exceptions() {
+            let mut changeset =
+                Changeset::new("feature/auth", VersionBump::Minor, vec!["production".to_string()]);
+            changeset.add_package("@myorg/core");
+            changeset.add_package("@myorg/plugin-auth");
+
+            let independent = PackageFilter::parse("@myorg/plugin-*").unwrap();
+            let resolution = resolve_versions(
+                &changeset,
+                &hybrid_packages(),
+                VersioningStrategy::Unified,
+                Some(&independent),
+            )
+            .await
+            .unwrap();
+
+            // The group ignores the plugins' versions: 2.1.0 -> 2.2.0, not 3.0.0 -> 3.1.0
+            assert_eq!(resolution.updates.len(), 3);
+            for name in ["@myorg/core", "@myorg/utils"] {
+                let update = resolution.updates.iter().find(|u| u.name == name).unwrap();
+                assert_eq!(update.next_version, Version::parse("2.2.0").unwrap());
+                assert_eq!(update.strategy, VersioningStrategy::Unified);
+            }
+
+            let auth = resolution.updates.iter().find(|u| u.name == "@myorg/plugin-auth").unwrap();
+            assert_eq!(auth.next_version, Version::parse("0.5.0").unwrap());
+            assert_eq!(auth.strategy, VersioningStrategy::Independent);
+            assert!(auth.is_direct_change());
+
+            // An unchanged exception is not bumped with the group
+            assert!(!resolution.updates.iter().any(|u| u.name == "@myorg/plugin-cache"));
+        }
+
+        /// Test that a changeset of exceptions only leaves the unified group unchanged
+        #[tokio::test]
+        async fn test_resolve_unified_exceptions_only() {
+            let mut changeset =
+                Changeset::new("fix/cache", VersionBump::Patch, vec!["production".to_string()]);
+            changeset.add_package("@myorg/plugin-cache");
+
+            let independent = PackageFilter::parse("@myorg/plugin-*").unwrap();
+            let resolution = resolve_versions(
+                &changeset,
+                &hybrid_packages(),
+                VersioningStrategy::Unified,
+                Some(&independent),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(resolution.updates.len(), 1);
+            assert_eq!(resolution.updates[0].name, "@myorg/plugin-cache");
+            assert_eq!(resolution.updates[0].next_version, Version::parse("3.0.1").unwrap());
+            assert!(!resolution.updates[0].is_unified());
+        }
+
         /// Test error when package not found
         #[tokio::test]
         async fn test_resolve_package_not_found() {
@@ -1574,7 +1887,8 @@ mod circular_dependency_property_tests {
             let packages = HashMap::new();
 
             let result =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent).await;
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
+                    .await;
 
             assert!(result.is_err());
             match result.unwrap_err() {
@@ -1600,7 +1914,8 @@ mod circular_dependency_property_tests {
             );
 
             let result =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent).await;
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
+                    .await;
 
             assert!(result.is_err());
             match result.unwrap_err() {
@@ -1620,7 +1935,7 @@ mod circular_dependency_property_tests {
             let packages = HashMap::new();
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1720,7 +2035,7 @@ mod circular_dependency_property_tests {
             );
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1750,7 +2065,7 @@ mod circular_dependency_property_tests {
                 .insert("@myorg/c".to_string(), create_package_info("@myorg/c", "0.1.0", vec![]));
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Independent)
+                resolve_versions(&changeset, &packages, VersioningStrategy::Independent, None)
                     .await
                     .unwrap();
 
@@ -1781,7 +2096,9 @@ mod circular_dependency_property_tests {
                 .insert("@myorg/b".to_string(), create_package_info("@myorg/b", "1.0.0", vec![]));
 
             let resolution =
-                resolve_versions(&changeset, &packages, VersioningStrategy::Unified).await.unwrap();
+                resolve_versions(&changeset, &packages, VersioningStrategy::Unified, None)
+                    .await
+                    .unwrap();
 
             assert_eq!(resolution.updates.len(), 2);
 
@@ -1791,6 +2108,105 @@ mod circular_dependency_property_tests {
             }
         }
 
+        /// Creates version schemes using calver for `@myorg/app-*` on 2024-06-12.
+        fn calver_schemes(format: CalverFormat) -> VersionSchemes {
+            let config = VersionConfig {
+                calver_packages: vec!["@myorg/app-*".to_string()],
+                calver_format: format,
+                ..Default::default()
+            };
+            VersionSchemes::new(&config, chrono::NaiveDate::from_ymd_opt(2024, 6, 12).unwrap())
+        }
+
+        /// Test calver packages are bumped from the release date
+        #[tokio::test]
+        async fn test_resolve_calver_packages() {
+            let mut changeset =
+                Changeset::new("feature/test", VersionBump::Minor, vec!["production".to_string()]);
+            changeset.add_package("@myorg/app-web");
+            changeset.add_package("@myorg/app-admin");
+            changeset.add_package("@myorg/core");
+
+            let mut packages = HashMap::new();
+            packages.insert(
+                "@myorg/app-web".to_string(),
+                create_package_info("@myorg/app-web", "2024.5.3", vec![]),
+            );
+            packages.insert(
+                "@myorg/app-admin".to_string(),
+                create_package_info("@myorg/app-admin", "2024.6.0", vec![]),
+            );
+            packages.insert(
+                "@myorg/core".to_string(),
+                create_package_info("@myorg/core", "1.2.3", vec![]),
+            );
+
+            let resolution = resolve_versions_with_schemes(
+                &changeset,
+                &packages,
+                VersioningStrategy::Independent,
+                None,
+                &calver_schemes(CalverFormat::YearMonthMicro),
+            )
+            .await
+            .unwrap();
+
+            let next = |name: &str| {
+                resolution.updates.iter().find(|u| u.name == name).unwrap().next_version.to_string()
+            };
+            assert_eq!(next("@myorg/app-web"), "2024.6.0");
+            assert_eq!(next("@myorg/app-admin"), "2024.6.1");
+            assert_eq!(next("@myorg/core"), "1.3.0");
+
+            let resolution = resolve_versions_with_schemes(
+                &changeset,
+                &packages,
+                VersioningStrategy::Independent,
+                None,
+                &calver_schemes(CalverFormat::YearMonthDay),
+            )
+            .await
+            .unwrap();
+            let web = resolution.updates.iter().find(|u| u.name == "@myorg/app-web").unwrap();
+            assert_eq!(web.next_version.to_string(), "2024.6.12");
+        }
+
+        /// Test calver packages are excluded from the unified group
+        #[tokio::test]
+        async fn test_resolve_unified_excludes_calver_packages() {
+            let mut changeset =
+                Changeset::new("feature/test", VersionBump::Patch, vec!["production".to_string()]);
+            changeset.add_package("@myorg/core");
+
+            let mut packages = HashMap::new();
+            packages.insert(
+                "@myorg/core".to_string(),
+                create_package_info("@myorg/core", "1.2.3", vec![]),
+            );
+            packages.insert(
+                "@myorg/utils".to_string(),
+                create_package_info("@myorg/utils", "1.2.3", vec![]),
+            );
+            packages.insert(
+                "@myorg/app-web".to_string(),
+                create_package_info("@myorg/app-web", "2024.5.3", vec![]),
+            );
+
+            let resolution = resolve_versions_with_schemes(
+                &changeset,
+                &packages,
+                VersioningStrategy::Unified,
+                None,
+                &calver_schemes(CalverFormat::YearMonthMicro),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(resolution.updates.len(), 2);
+            assert!(resolution.updates.iter().all(|u| u.next_version.to_string() == "1.2.4"));
+            assert!(!resolution.updates.iter().any(|u| u.name == "@myorg/app-web"));
+        }
+
         /// Test DependencyType enum
         #[test]
         fn test_dependency_type() {
@@ -1883,9 +2299,10 @@ mod circular_dependency_property_tests {
     #[allow(clippy::unwrap_used)]
     mod propagation_tests {
         use super::*;
-        use crate::config::DependencyConfig;
+        use crate::config::{CalverRangePolicy, DependencyConfig, VersionConfig};
         use crate::types::{DependencyType, UpdateReason, Version};
         use crate::version::propagation::DependencyPropagator;
+        use crate::version::resolution::VersionSchemes;
         use crate::version::{PackageUpdate, VersionResolution};
 
         /// Creates a simple monorepo structure for propagation testing.
@@ -1960,6 +2377,43 @@ mod circular_dependency_property_tests {
             assert!(pkg_c_update.is_propagated());
         }
 
+        #[test]
+        fn test_propagation_calver_dependent_ranges() {
+            let (packages, graph) = create_propagation_packages();
+            let config = DependencyConfig { max_depth: 1, ..Default::default() };
+            let date = chrono::NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+
+            for (policy, expected) in
+                [(CalverRangePolicy::Pin, "2024.6.0"), (CalverRangePolicy::AtLeast, ">=2024.6.0")]
+            {
+                let version_config = VersionConfig {
+                    calver_packages: vec!["@test/pkg-a".to_string()],
+                    calver_range: policy,
+                    ..Default::default()
+                };
+                let schemes = VersionSchemes::new(&version_config, date);
+                let propagator =
+                    DependencyPropagator::new(&graph, &packages, &config).with_schemes(&schemes);
+
+                let mut resolution = VersionResolution::new();
+                resolution.add_update(PackageUpdate::new(
+                    "@test/pkg-a".to_string(),
+                    PathBuf::from("/test/pkg-a"),
+                    Version::new(1, 0, 0),
+                    Version::new(2024, 6, 0),
+                    UpdateReason::DirectChange,
+                ));
+                propagator.propagate(&mut resolution).unwrap();
+
+                // The semver dependent keeps its scheme and replaces its caret range
+                let pkg_b = resolution.updates.iter().find(|u| u.name == "@test/pkg-b").unwrap();
+                assert_eq!(pkg_b.next_version, Version::new(1, 0, 1));
+                assert_eq!(pkg_b.dependency_updates.len(), 1);
+                assert_eq!(pkg_b.dependency_updates[0].old_version_spec, "^1.0.0");
+                assert_eq!(pkg_b.dependency_updates[0].new_version_spec, expected);
+            }
+        }
+
         #[test]
         fn test_propagation_with_minor_bump() {
             let (packages, graph) = create_propagation_packages();
@@ -2194,6 +2648,118 @@ mod circular_dependency_property_tests {
             assert_eq!(pkg_b_update.dependency_updates[0].new_version_spec, "~2.0.0");
         }
 
+        #[test]
+        fn test_propagation_keep_range_skips_satisfied_specs() {
+            let deps = vec![("@test/pkg-a", "^1.0.0"), ("@test/pkg-c", "~1.0.0")];
+            let package_list = vec![
+                create_package_info("@test/pkg-a", "1.0.0", vec![]),
+                create_package_info("@test/pkg-c", "1.0.0", vec![]),
+                create_package_info("@test/pkg-b", "1.0.0", deps),
+            ];
+            let graph = DependencyGraph::from_packages(&package_list).unwrap();
+            let packages: HashMap<String, PackageInfo> =
+                package_list.into_iter().map(|p| (p.name().to_string(), p)).collect();
+            let config =
+                DependencyConfig { range_policy: "keep-range".to_string(), ..Default::default() };
+            let propagator = DependencyPropagator::new(&graph, &packages, &config);
+
+            let mut resolution = VersionResolution::new();
+            for name in ["@test/pkg-a", "@test/pkg-c"] {
+                resolution.add_update(PackageUpdate::new(
+                    name.to_string(),
+                    PathBuf::from("/test").join(name),
+                    Version::new(1, 0, 0),
+                    Version::new(1, 1, 0),
+                    UpdateReason::DirectChange,
+                ));
+            }
+
+            propagator.propagate(&mut resolution).unwrap();
+
+            let pkg_b_update = resolution.updates.iter().find(|u| u.name == "@test/pkg-b").unwrap();
+
+            // ~1.0.0 does not allow 1.1.0 and is rewritten; ^1.0.0 still does and is kept
+            assert_eq!(pkg_b_update.dependency_updates.len(), 1);
+            assert_eq!(pkg_b_update.dependency_updates[0].new_version_spec, "~1.1.0");
+            assert_eq!(pkg_b_update.skipped_dependency_updates.len(), 1);
+            let skipped = &pkg_b_update.skipped_dependency_updates[0];
+            assert_eq!(skipped.dependency_name, "@test/pkg-a");
+            assert_eq!(skipped.old_version_spec, "^1.0.0");
+            assert_eq!(skipped.new_version_spec, "^1.1.0");
+
+            let summary = crate::version::ApplySummary::from_resolution(&resolution, 0);
+            assert_eq!(summary.dependency_updates, 1);
+            assert_eq!(summary.skipped_dependency_updates, 1);
+        }
+
+        #[test]
+        fn test_propagation_reports_specs_already_current() {
+            let deps = vec![("@test/pkg-a", "^2.0.0")];
+            let package_list = vec![
+                create_package_info("@test/pkg-a", "1.0.0", vec![]),
+                create_package_info("@test/pkg-b", "1.0.0", deps),
+            ];
+            let graph = DependencyGraph::from_packages(&package_list).unwrap();
+            let packages: HashMap<String, PackageInfo> =
+                package_list.into_iter().map(|p| (p.name().to_string(), p)).collect();
+            let config = DependencyConfig::default();
+            let propagator = DependencyPropagator::new(&graph, &packages, &config);
+
+            let mut resolution = VersionResolution::new();
+            resolution.add_update(PackageUpdate::new(
+                "@test/pkg-a".to_string(),
+                PathBuf::from("/test/pkg-a"),
+                Version::new(1, 0, 0),
+                Version::new(2, 0, 0),
+                UpdateReason::DirectChange,
+            ));
+
+            propagator.propagate(&mut resolution).unwrap();
+
+            let pkg_b_update = resolution.updates.iter().find(|u| u.name == "@test/pkg-b").unwrap();
+            assert!(pkg_b_update.dependency_updates.is_empty());
+            assert_eq!(pkg_b_update.skipped_dependency_updates.len(), 1);
+        }
+
+        #[test]
+        fn test_propagation_rewrites_npm_alias_specs() {
+            let alias_deps = vec![("pkg-a-legacy", "npm:@test/pkg-a@^1.0.0")];
+            let pkg_a = create_package_info("@test/pkg-a", "1.0.0", vec![]);
+            let pkg_b = create_package_info("@test/pkg-b", "1.0.0", alias_deps.clone());
+
+            let packages_for_graph = vec![
+                create_package_info("@test/pkg-a", "1.0.0", vec![]),
+                create_package_info("@test/pkg-b", "1.0.0", alias_deps),
+            ];
+
+            let mut packages = HashMap::new();
+            packages.insert("@test/pkg-a".to_string(), pkg_a);
+            packages.insert("@test/pkg-b".to_string(), pkg_b);
+
+            let graph = DependencyGraph::from_packages(&packages_for_graph).unwrap();
+            let config = DependencyConfig::default();
+            let propagator = DependencyPropagator::new(&graph, &packages, &config);
+
+            let mut resolution = VersionResolution::new();
+            resolution.add_update(PackageUpdate::new(
+                "@test/pkg-a".to_string(),
+                PathBuf::from("/test/pkg-a"),
+                Version::new(1, 0, 0),
+                Version::new(2, 0, 0),
+                UpdateReason::DirectChange,
+            ));
+
+            propagator.propagate(&mut resolution).unwrap();
+
+            let pkg_b_update = resolution.updates.iter().find(|u| u.name == "@test/pkg-b").unwrap();
+            let dep_update = &pkg_b_update.dependency_updates[0];
+
+            // The alias key is kept and the target range is bumped
+            assert_eq!(dep_update.dependency_name, "pkg-a-legacy");
+            assert_eq!(dep_update.old_version_spec, "npm:@test/pkg-a@^1.0.0");
+            assert_eq!(dep_update.new_version_spec, "npm:@test/pkg-a@^2.0.0");
+        }
+
         #[test]
         fn test_propagation_with_none_bump() {
             let (packages, graph) = create_propagation_packages();
@@ -3074,18 +3640,40 @@ mod application_tests {
             Changeset::new("main", VersionBump::Minor, vec!["production".to_string()]);
         changeset.add_package("nonexistent-package");
 
-        // Should fail with package not found
+        // Should fail, naming the stale package
         let result = resolver.apply_versions(&changeset, false).await;
         assert!(result.is_err(), "Should return error for nonexistent package");
 
         if let Err(e) = result {
             assert!(
-                matches!(e, VersionError::PackageNotFound { .. }),
-                "Should be PackageNotFound error"
+                matches!(
+                    &e,
+                    VersionError::StaleChangesetPackages { packages, .. }
+                        if packages == &["nonexistent-package".to_string()]
+                ),
+                "Should be StaleChangesetPackages error, got {e:?}"
             );
         }
     }
 
+    #[tokio::test]
+    async fn test_resolve_versions_follows_package_renames() {
+        let (_temp, root) = create_test_package_for_application("@test/pkg", "1.0.0").await;
+        let mut config = PackageToolsConfig::default();
+        config.changeset.package_renames.insert("@test/old".to_string(), "@test/pkg".to_string());
+        let resolver = VersionResolver::new(root, config).await.expect("Should create resolver");
+
+        let mut changeset =
+            Changeset::new("main", VersionBump::Minor, vec!["production".to_string()]);
+        changeset.add_package("@test/old");
+
+        let resolution =
+            resolver.resolve_versions(&changeset).await.expect("Renamed package should resolve");
+        assert_eq!(resolution.updates.len(), 1);
+        assert_eq!(resolution.updates[0].name, "@test/pkg");
+        assert_eq!(resolution.updates[0].next_version.to_string(), "1.1.0");
+    }
+
     #[tokio::test]
     async fn test_apply_result_methods() {
         let (_temp, root) = create_test_package_for_application("@test/pkg-a", "1.0.0").await;
@@ -3244,3 +3832,271 @@ mod application_tests {
         assert!(content.contains(r#""version": "1.1.0""#), "Version should be updated");
     }
 }
+
+// ============================================================================
+// Partial Release Tests
+// ============================================================================
+
+mod filter_tests {
+    use super::*;
+    use crate::config::VersioningStrategy;
+    use crate::types::{Changeset, VersionBump};
+    use crate::version::PackageFilter;
+
+    fn changeset_with(packages: &[&str]) -> Changeset {
+        let mut changeset = Changeset::new("fix/auth", VersionBump::Patch, vec![]);
+        for package in packages {
+            changeset.add_package(*package);
+        }
+        changeset
+    }
+
+    #[test]
+    fn test_filter_names_globs_and_exclusions() {
+        let filter = PackageFilter::parse("@myorg/*, !@myorg/docs, lodash").expect("valid filter");
+
+        assert!(filter.matches("@myorg/core"));
+        assert!(filter.matches("lodash"));
+        assert!(!filter.matches("@myorg/docs"));
+        assert!(!filter.matches("react"));
+        assert_eq!(filter.to_string(), "@myorg/*, !@myorg/docs, lodash");
+    }
+
+    #[test]
+    fn test_filter_with_only_exclusions_selects_the_rest() {
+        let filter = PackageFilter::parse("!@myorg/docs").expect("valid filter");
+
+        assert!(filter.matches("@myorg/core"));
+        assert!(!filter.matches("@myorg/docs"));
+    }
+
+    #[test]
+    fn test_filter_rejects_invalid_expressions() {
+        for expression in ["", " , ", "core,,utils", "!", "[core"] {
+            assert!(
+                matches!(PackageFilter::parse(expression), Err(VersionError::InvalidFilter { .. })),
+                "'{expression}' should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_narrow_keeps_selected_packages() {
+        let filter = PackageFilter::parse("@myorg/core").expect("valid filter");
+
+        let narrowed =
+            filter.narrow(&changeset_with(&["@myorg/core", "@myorg/utils"])).expect("selected");
+        assert_eq!(narrowed.packages, vec!["@myorg/core".to_string()]);
+        assert_eq!(narrowed.branch, "fix/auth");
+        assert!(filter.narrow(&changeset_with(&["@myorg/utils"])).is_none());
+    }
+
+    #[test]
+    fn test_split_packages() {
+        let changeset = changeset_with(&["@myorg/core", "@myorg/utils"]);
+
+        let (released, pending) = changeset.split_packages(&["@myorg/core".to_string()]);
+        assert_eq!(released.branch, "fix/auth@@myorg/core");
+        assert_eq!(released.packages, vec!["@myorg/core".to_string()]);
+        let pending = pending.expect("utils stays pending");
+        assert_eq!(pending.branch, "fix/auth");
+        assert_eq!(pending.packages, vec!["@myorg/utils".to_string()]);
+
+        let all = vec!["@myorg/core".to_string(), "@myorg/utils".to_string()];
+        let (released, pending) = changeset.split_packages(&all);
+        assert_eq!(released.branch, "fix/auth");
+        assert!(pending.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_apply_versions_filtered_releases_selected_packages() {
+        let (_temp, root) = create_monorepo_workspace().await;
+        // Monorepo detection needs a lockfile next to the workspaces declaration
+        let lock = r#"{"name": "monorepo-root", "lockfileVersion": 3, "packages": {}}"#;
+        tokio::fs::write(root.join("package-lock.json"), lock).await.expect("write lockfile");
+        let mut config = PackageToolsConfig::default();
+        config.dependency.propagation_bump = "none".to_string();
+        let resolver = VersionResolver::new(root.clone(), config).await.expect("resolver");
+
+        let changeset = changeset_with(&["@monorepo/pkg-a", "@monorepo/pkg-b"]);
+        let filter = PackageFilter::parse("@monorepo/pkg-b").expect("valid filter");
+        let result =
+            resolver.apply_versions_filtered(&changeset, &filter, false).await.expect("applied");
+
+        let updated: Vec<&str> =
+            result.resolution.updates.iter().map(|update| update.name.as_str()).collect();
+        assert_eq!(updated, vec!["@monorepo/pkg-b"]);
+
+        let pkg_a = tokio::fs::read_to_string(root.join("packages/pkg-a/package.json"))
+            .await
+            .expect("read pkg-a");
+        assert!(pkg_a.contains(r#""version": "1.0.0""#));
+        let pkg_b = tokio::fs::read_to_string(root.join("packages/pkg-b/package.json"))
+            .await
+            .expect("read pkg-b");
+        assert!(pkg_b.contains(r#""version": "1.0.1""#));
+    }
+
+    #[tokio::test]
+    async fn test_apply_versions_filtered_without_match_is_empty() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let resolver =
+            VersionResolver::new(root, PackageToolsConfig::default()).await.expect("resolver");
+
+        let filter = PackageFilter::parse("other").expect("valid filter");
+        let result = resolver
+            .apply_versions_filtered(&changeset_with(&["my-package"]), &filter, false)
+            .await
+            .expect("applied");
+
+        assert!(result.resolution.updates.is_empty());
+        assert!(result.modified_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_apply_versions_filtered_rejects_unified_strategy() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let mut config = PackageToolsConfig::default();
+        config.version.strategy = VersioningStrategy::Unified;
+        let resolver = VersionResolver::new(root, config).await.expect("resolver");
+
+        let filter = PackageFilter::parse("my-package").expect("valid filter");
+        let result =
+            resolver.apply_versions_filtered(&changeset_with(&["my-package"]), &filter, true).await;
+
+        assert!(matches!(result, Err(VersionError::InvalidFilter { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_apply_versions_filtered_allows_unified_exceptions() {
+        let (_temp, root) = create_monorepo_workspace().await;
+        let lock = r#"{"name": "monorepo-root", "lockfileVersion": 3, "packages": {}}"#;
+        tokio::fs::write(root.join("package-lock.json"), lock).await.expect("write lockfile");
+        let mut config = PackageToolsConfig::default();
+        config.version.strategy = VersioningStrategy::Unified;
+        config.version.independent_packages = vec!["@monorepo/pkg-b".to_string()];
+        config.dependency.propagation_bump = "none".to_string();
+        let resolver = VersionResolver::new(root, config).await.expect("resolver");
+
+        let changeset = changeset_with(&["@monorepo/pkg-a", "@monorepo/pkg-b"]);
+        let filter = PackageFilter::parse("@monorepo/pkg-b").expect("valid filter");
+        let result =
+            resolver.apply_versions_filtered(&changeset, &filter, true).await.expect("applied");
+
+        let updated: Vec<&str> =
+            result.resolution.updates.iter().map(|update| update.name.as_str()).collect();
+        assert_eq!(updated, vec!["@monorepo/pkg-b"]);
+
+        let filter = PackageFilter::parse("@monorepo/pkg-a").expect("valid filter");
+        let result = resolver.apply_versions_filtered(&changeset, &filter, true).await;
+        assert!(matches!(result, Err(VersionError::InvalidFilter { .. })));
+    }
+}
+
+mod resolution_cache_tests {
+    use super::*;
+    use crate::types::{Changeset, Version, VersionBump};
+
+    fn cache_dir(root: &std::path::Path) -> PathBuf {
+        root.join(".workspace-backups").join("resolution-cache")
+    }
+
+    fn changeset() -> Changeset {
+        let mut changeset = Changeset::new("feature/cache", VersionBump::Minor, vec![]);
+        changeset.add_package("my-package");
+        changeset
+    }
+
+    fn next_version_of(resolution: &crate::version::VersionResolution, name: &str) -> Version {
+        resolution
+            .updates
+            .iter()
+            .find(|update| update.name == name)
+            .map(|update| update.next_version.clone())
+            .expect("package should be resolved")
+    }
+
+    async fn cache_entries(root: &std::path::Path) -> Vec<PathBuf> {
+        let mut entries = Vec::new();
+        let mut dir = tokio::fs::read_dir(cache_dir(root)).await.expect("cache dir should exist");
+        while let Some(entry) = dir.next_entry().await.expect("readable cache dir") {
+            entries.push(entry.path());
+        }
+        entries
+    }
+
+    #[tokio::test]
+    async fn test_resolution_is_reused_from_cache() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let resolver = VersionResolver::new(root.clone(), PackageToolsConfig::default())
+            .await
+            .expect("resolver");
+
+        let first = resolver.resolve_versions(&changeset()).await.expect("resolution");
+        assert_eq!(next_version_of(&first, "my-package"), Version::new(1, 1, 0));
+
+        let entries = cache_entries(&root).await;
+        assert_eq!(entries.len(), 1);
+
+        // Tamper with the entry to prove the second call reads it back
+        let cached = tokio::fs::read_to_string(&entries[0]).await.expect("cache entry");
+        tokio::fs::write(&entries[0], cached.replace("1.1.0", "9.9.9"))
+            .await
+            .expect("rewrite cache entry");
+
+        let second = resolver.resolve_versions(&changeset()).await.expect("resolution");
+        assert_eq!(next_version_of(&second, "my-package"), Version::new(9, 9, 9));
+    }
+
+    #[tokio::test]
+    async fn test_resolution_cache_invalidated_by_manifest_change() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let resolver = VersionResolver::new(root.clone(), PackageToolsConfig::default())
+            .await
+            .expect("resolver");
+
+        resolver.resolve_versions(&changeset()).await.expect("resolution");
+
+        tokio::fs::write(
+            root.join("package.json"),
+            r#"{ "name": "my-package", "version": "2.0.0" }"#,
+        )
+        .await
+        .expect("update package.json");
+
+        let resolution = resolver.resolve_versions(&changeset()).await.expect("resolution");
+        assert_eq!(next_version_of(&resolution, "my-package"), Version::new(2, 1, 0));
+
+        // The entry for the previous manifests is pruned
+        assert_eq!(cache_entries(&root).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resolution_cache_keyed_by_changeset() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let resolver = VersionResolver::new(root.clone(), PackageToolsConfig::default())
+            .await
+            .expect("resolver");
+
+        resolver.resolve_versions(&changeset()).await.expect("resolution");
+
+        let mut major = changeset();
+        major.bump = VersionBump::Major;
+        let resolution = resolver.resolve_versions(&major).await.expect("resolution");
+
+        assert_eq!(next_version_of(&resolution, "my-package"), Version::new(2, 0, 0));
+        assert_eq!(cache_entries(&root).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resolution_cache_disabled() {
+        let (_temp, root) = create_single_package_workspace().await;
+        let mut config = PackageToolsConfig::default();
+        config.version.resolution_cache = false;
+        let resolver = VersionResolver::new(root.clone(), config).await.expect("resolver");
+
+        resolver.resolve_versions(&changeset()).await.expect("resolution");
+
+        assert!(!cache_dir(&root).exists());
+    }
+}
@@ -0,0 +1,587 @@
+//! Import aliases generated from the workspace dependency graph.
+//!
+//! **What**: Provides `AliasGenerator`, which computes the `compilerOptions.paths` of a
+//! TypeScript project file and, optionally, the `imports` map of each `package.json` from the
+//! internal dependencies of the workspace, and `AliasPlan`, how those files drift from what
+//! the dependencies call for.
+//!
+//! **How**: Every workspace package another package depends on gets two `paths` entries, the
+//! bare name pointing at its entry file and `name/*` pointing at its source directory, relative
+//! to the project file's `baseUrl`. With package imports enabled, each package gets a
+//! `#name` and `#name/*` entry for every workspace package it depends on. Only entries keyed
+//! by a workspace package are managed: they are added, corrected, or removed when stale, and
+//! every other entry is left as written. Files are edited with `JsonDocument`, so comments and
+//! formatting survive. `AliasGenerator::apply` records an `alias-sync` transaction in the
+//! workspace transaction log (when backups are enabled) before writing.
+//!
+//! **Why**: Internal packages should resolve to their sources during development without
+//! being built or published. Maintained by hand, these maps drift from the declared
+//! dependencies; generating them, and checking them in CI, keeps both in step.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+use crate::config::{AliasesConfig, BackupConfig};
+use crate::editor::JsonDocument;
+use crate::error::{EditorError, EditorResult};
+use crate::types::PackageInfo;
+use crate::workspace::{OperationKind, TransactionLog};
+
+/// The alias map a file holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AliasFileKind {
+    /// `compilerOptions.paths` of a TypeScript project file.
+    TsconfigPaths,
+    /// `imports` of a `package.json`.
+    PackageImports,
+}
+
+impl AliasFileKind {
+    /// Returns the JSON Pointer of the map in the file.
+    #[must_use]
+    pub fn pointer(self) -> &'static str {
+        match self {
+            Self::TsconfigPaths => "/compilerOptions/paths",
+            Self::PackageImports => "/imports",
+        }
+    }
+
+    /// Returns the name of the map.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TsconfigPaths => "paths",
+            Self::PackageImports => "imports",
+        }
+    }
+}
+
+impl fmt::Display for AliasFileKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How an alias entry differs from what the dependencies call for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AliasDriftKind {
+    /// The entry is missing.
+    Missing,
+    /// The entry points somewhere else.
+    Changed,
+    /// The entry names a workspace package that is no longer a dependency.
+    Stale,
+}
+
+impl AliasDriftKind {
+    /// Returns the name of the drift kind.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Missing => "missing",
+            Self::Changed => "changed",
+            Self::Stale => "stale",
+        }
+    }
+}
+
+impl fmt::Display for AliasDriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An alias entry that drifted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasDrift {
+    /// Key of the entry, such as `@scope/utils/*`.
+    pub key: String,
+
+    /// How the entry drifted.
+    pub kind: AliasDriftKind,
+
+    /// Value the entry should have, `None` if it should be removed.
+    pub expected: Option<Value>,
+
+    /// Value the entry has, `None` if it is missing.
+    pub actual: Option<Value>,
+}
+
+/// The planned change of one alias map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasFile {
+    /// Absolute path of the file.
+    pub path: PathBuf,
+
+    /// Map the file holds.
+    pub kind: AliasFileKind,
+
+    /// Entries that drifted, sorted by key.
+    pub drift: Vec<AliasDrift>,
+
+    /// Content of the file when the plan was made, `None` if the plan creates it.
+    pub original: Option<String>,
+
+    /// Content of the file with the drift corrected.
+    pub updated: String,
+}
+
+/// The alias maps of a workspace that drifted from its dependencies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AliasPlan {
+    /// Root of the workspace the plan was made for.
+    pub workspace_root: PathBuf,
+
+    /// Files whose maps drifted, tsconfig first, then packages by path.
+    pub files: Vec<AliasFile>,
+}
+
+impl AliasPlan {
+    /// Returns whether every alias map matches the dependencies.
+    #[must_use]
+    pub fn is_in_sync(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Returns the number of drifted entries across all files.
+    #[must_use]
+    pub fn drift_count(&self) -> usize {
+        self.files.iter().map(|file| file.drift.len()).sum()
+    }
+}
+
+/// The result of applying an [`AliasPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasOutcome {
+    /// Absolute paths of the files written.
+    pub files: Vec<PathBuf>,
+
+    /// Transaction recorded for `workspace undo`, `None` when backups are disabled.
+    pub transaction_id: Option<String>,
+}
+
+/// Generates import aliases for the internal dependencies of a workspace.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_pkg_tools::config::PackageToolsConfig;
+/// use sublime_pkg_tools::types::PackageInfo;
+/// use sublime_pkg_tools::workspace::AliasGenerator;
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+/// use std::path::PathBuf;
+///
+/// # async fn example(packages: Vec<PackageInfo>) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = PackageToolsConfig::default();
+/// let generator = AliasGenerator::new(
+///     PathBuf::from("."),
+///     config.aliases,
+///     config.upgrade.backup,
+///     FileSystemManager::new(),
+/// );
+///
+/// let plan = generator.plan(&packages).await?;
+/// if !plan.is_in_sync() {
+///     generator.apply(&plan).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AliasGenerator<F: AsyncFileSystem + Clone> {
+    workspace_root: PathBuf,
+    config: AliasesConfig,
+    backup: BackupConfig,
+    fs: F,
+}
+
+impl<F: AsyncFileSystem + Clone> AliasGenerator<F> {
+    /// Creates a generator for the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_root` - Root of the workspace
+    /// * `config` - Where aliases are written and what they point at
+    /// * `backup` - Backup settings of the transaction recorded by `apply`
+    /// * `fs` - Filesystem used to read and write files
+    #[must_use]
+    pub fn new(
+        workspace_root: PathBuf,
+        config: AliasesConfig,
+        backup: BackupConfig,
+        fs: F,
+    ) -> Self {
+        Self { workspace_root, config, backup, fs }
+    }
+
+    /// Compares the alias maps with the internal dependencies without writing anything.
+    ///
+    /// The tsconfig is created in the plan if it does not exist and some package depends on
+    /// another one. Package `imports` are only planned when `package_imports` is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `packages` - Every package of the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::FileSystemError` if a file cannot be read, and
+    /// `EditorError::Syntax` or `EditorError::InvalidPointer` if a file is malformed or its
+    /// map is not an object.
+    pub async fn plan(&self, packages: &[PackageInfo]) -> EditorResult<AliasPlan> {
+        let names: BTreeSet<&str> = packages.iter().map(PackageInfo::name).collect();
+        let dependencies: BTreeMap<&str, BTreeSet<String>> = packages
+            .iter()
+            .map(|package| {
+                let internal = declared_dependencies(package)
+                    .filter(|name| *name != package.name() && names.contains(name))
+                    .map(str::to_string)
+                    .collect();
+                (package.name(), internal)
+            })
+            .collect();
+
+        let mut files = Vec::new();
+
+        let depended_on: BTreeSet<&str> =
+            dependencies.values().flatten().map(String::as_str).collect();
+        let targets: Vec<&PackageInfo> =
+            packages.iter().filter(|package| depended_on.contains(package.name())).collect();
+        if let Some(file) = self.plan_tsconfig(&targets, &names).await? {
+            files.push(file);
+        }
+
+        if self.config.package_imports {
+            let mut sorted: Vec<&PackageInfo> = packages.iter().collect();
+            sorted.sort_by(|a, b| a.path().cmp(b.path()));
+            for package in sorted {
+                let internal = dependencies.get(package.name()).cloned().unwrap_or_default();
+                if let Some(file) = self.plan_imports(package, &internal, &names).await? {
+                    files.push(file);
+                }
+            }
+        }
+
+        Ok(AliasPlan { workspace_root: self.workspace_root.clone(), files })
+    }
+
+    /// Writes the files of a plan as one transaction.
+    ///
+    /// When backups are enabled the previous content is recorded as an `alias-sync`
+    /// transaction that `workspace undo` can revert. If a file changed since the plan was
+    /// made, nothing is written; if a write fails, the transaction is rolled back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EditorError::FileSystemError` if a file changed since the plan was made, the
+    /// transaction cannot be recorded, or a file cannot be written.
+    pub async fn apply(&self, plan: &AliasPlan) -> EditorResult<AliasOutcome> {
+        for file in &plan.files {
+            if self.read(&file.path).await? != file.original {
+                return Err(EditorError::FileSystemError {
+                    path: file.path.clone(),
+                    reason: "file changed since the aliases were planned".to_string(),
+                });
+            }
+        }
+
+        let paths: Vec<PathBuf> = plan.files.iter().map(|file| file.path.clone()).collect();
+        let transaction = if self.backup.enabled && !paths.is_empty() {
+            let log = TransactionLog::new(
+                self.workspace_root.clone(),
+                self.backup.clone(),
+                self.fs.clone(),
+            );
+            let id = log.begin(OperationKind::AliasSync, &paths).await.map_err(|e| {
+                EditorError::FileSystemError {
+                    path: self.workspace_root.clone(),
+                    reason: format!("cannot record transaction: {e}"),
+                }
+            })?;
+            Some((log, id))
+        } else {
+            None
+        };
+
+        for file in &plan.files {
+            if let Err(e) = self.write(&file.path, &file.updated).await {
+                if let Some((log, id)) = &transaction
+                    && let Err(rollback_error) = log.rollback(id).await
+                {
+                    log::warn!("Failed to roll back alias changes: {}", rollback_error);
+                }
+                return Err(e);
+            }
+        }
+
+        let transaction_id = match transaction {
+            Some((log, id)) => {
+                log.commit(&id).await.map_err(|e| EditorError::FileSystemError {
+                    path: self.workspace_root.clone(),
+                    reason: format!("cannot commit transaction '{id}': {e}"),
+                })?;
+                Some(id)
+            }
+            None => None,
+        };
+
+        Ok(AliasOutcome { files: paths, transaction_id })
+    }
+
+    /// Plans the `paths` of the tsconfig for the packages other packages depend on.
+    async fn plan_tsconfig(
+        &self,
+        targets: &[&PackageInfo],
+        names: &BTreeSet<&str>,
+    ) -> EditorResult<Option<AliasFile>> {
+        let path = self.workspace_root.join(&self.config.tsconfig);
+        let original = self.read(&path).await?;
+        if original.is_none() && targets.is_empty() {
+            return Ok(None);
+        }
+        let document = JsonDocument::parse(&path, original.clone().unwrap_or("{}\n".to_string()))?;
+
+        // Paths resolve against `baseUrl` when set, and against the tsconfig otherwise
+        let tsconfig_dir = Path::new(&self.config.tsconfig).parent().unwrap_or(Path::new(""));
+        let base_url = document
+            .get("/compilerOptions/baseUrl")
+            .and_then(Value::as_str)
+            .map_or_else(PathBuf::new, PathBuf::from);
+        let base = normalize(&tsconfig_dir.join(base_url));
+
+        let mut expected = BTreeMap::new();
+        for package in targets {
+            let dir = self.source_path(package, &base);
+            expected.insert(package.name().to_string(), json!([join(&dir, &self.config.entry)]));
+            expected.insert(format!("{}/*", package.name()), json!([join(&dir, "*")]));
+        }
+
+        let managed = |key: &str| {
+            names.contains(key) || key.strip_suffix("/*").is_some_and(|name| names.contains(name))
+        };
+        plan_file(path, AliasFileKind::TsconfigPaths, document, original, &expected, managed)
+    }
+
+    /// Plans the `imports` of a package for its internal dependencies.
+    async fn plan_imports(
+        &self,
+        package: &PackageInfo,
+        dependencies: &BTreeSet<String>,
+        names: &BTreeSet<&str>,
+    ) -> EditorResult<Option<AliasFile>> {
+        let path = package.path().join("package.json");
+        let original = self.read(&path).await?;
+        let Some(content) = original.clone() else {
+            return Ok(None);
+        };
+        let document = JsonDocument::parse(&path, content)?;
+
+        let mut expected = BTreeMap::new();
+        for dependency in dependencies {
+            let key = self.import_key(dependency);
+            expected.insert(format!("{key}/*"), json!(format!("{dependency}/*")));
+            expected.insert(key, json!(dependency));
+        }
+
+        let managed_keys: BTreeSet<String> = names
+            .iter()
+            .flat_map(|name| {
+                let key = self.import_key(name);
+                [format!("{key}/*"), key]
+            })
+            .collect();
+        let managed = |key: &str| managed_keys.contains(key);
+        plan_file(path, AliasFileKind::PackageImports, document, original, &expected, managed)
+    }
+
+    /// Returns the source directory of a package, relative to `base`, as written in `paths`.
+    fn source_path(&self, package: &PackageInfo, base: &Path) -> String {
+        let Ok(relative) = package.path().strip_prefix(&self.workspace_root) else {
+            return join(&slashes(package.path()), &self.config.source_dir);
+        };
+        let relative = slashes(&relative_path(base, &normalize(relative)));
+        let relative = if relative.is_empty() {
+            ".".to_string()
+        } else if relative.starts_with("..") {
+            relative
+        } else {
+            format!("./{relative}")
+        };
+        join(&relative, &self.config.source_dir)
+    }
+
+    /// Returns the `imports` key of a workspace package, e.g. `#scope/utils` for
+    /// `@scope/utils`.
+    fn import_key(&self, name: &str) -> String {
+        format!("{}{}", self.config.imports_prefix, name.trim_start_matches('@'))
+    }
+
+    async fn read(&self, path: &Path) -> EditorResult<Option<String>> {
+        if !self.fs.exists(path).await {
+            return Ok(None);
+        }
+        self.fs.read_file_string(path).await.map(Some).map_err(|e| EditorError::FileSystemError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+    }
+
+    async fn write(&self, path: &Path, content: &str) -> EditorResult<()> {
+        if let Some(parent) = path.parent() {
+            self.fs.create_dir_all(parent).await.map_err(|e| EditorError::FileSystemError {
+                path: path.to_path_buf(),
+                reason: format!("cannot create directory: {e}"),
+            })?;
+        }
+        self.fs.write_file_string(path, content).await.map_err(|e| EditorError::FileSystemError {
+            path: path.to_path_buf(),
+            reason: e.to_string(),
+        })
+    }
+}
+
+/// Computes the drift of one map and the content that corrects it.
+fn plan_file(
+    path: PathBuf,
+    kind: AliasFileKind,
+    mut document: JsonDocument,
+    original: Option<String>,
+    expected: &BTreeMap<String, Value>,
+    managed: impl Fn(&str) -> bool,
+) -> EditorResult<Option<AliasFile>> {
+    let empty = Map::new();
+    let actual = document.get(kind.pointer()).and_then(Value::as_object).unwrap_or(&empty);
+    let drift = drift(expected, actual, managed);
+    if drift.is_empty() {
+        return Ok(None);
+    }
+
+    for entry in &drift {
+        let pointer = format!("{}/{}", kind.pointer(), escape_pointer(&entry.key));
+        match &entry.expected {
+            Some(value) => document.set(&pointer, value.clone())?,
+            None => {
+                document.remove(&pointer)?;
+            }
+        }
+    }
+
+    Ok(Some(AliasFile { path, kind, drift, original, updated: document.content().to_string() }))
+}
+
+/// Compares the managed entries of a map with the expected ones, sorted by key.
+fn drift(
+    expected: &BTreeMap<String, Value>,
+    actual: &Map<String, Value>,
+    managed: impl Fn(&str) -> bool,
+) -> Vec<AliasDrift> {
+    let mut drift: Vec<AliasDrift> = expected
+        .iter()
+        .filter_map(|(key, value)| {
+            let kind = match actual.get(key) {
+                None => AliasDriftKind::Missing,
+                Some(current) if current != value => AliasDriftKind::Changed,
+                Some(_) => return None,
+            };
+            Some(AliasDrift {
+                key: key.clone(),
+                kind,
+                expected: Some(value.clone()),
+                actual: actual.get(key).cloned(),
+            })
+        })
+        .collect();
+
+    drift.extend(
+        actual.iter().filter(|(key, _)| managed(key) && !expected.contains_key(*key)).map(
+            |(key, value)| AliasDrift {
+                key: key.clone(),
+                kind: AliasDriftKind::Stale,
+                expected: None,
+                actual: Some(value.clone()),
+            },
+        ),
+    );
+    drift.sort_by(|a, b| a.key.cmp(&b.key));
+    drift
+}
+
+/// Returns the names of every dependency a package declares, whatever the protocol.
+///
+/// `PackageInfo::all_dependencies` leaves out `workspace:` and local specs, which are the
+/// usual way of depending on another workspace package.
+fn declared_dependencies(package: &PackageInfo) -> impl Iterator<Item = &str> {
+    let manifest = package.package_json();
+    [
+        &manifest.dependencies,
+        &manifest.dev_dependencies,
+        &manifest.peer_dependencies,
+        &manifest.optional_dependencies,
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(|section| section.keys().map(String::as_str))
+}
+
+/// Escapes a key for use as a JSON Pointer segment.
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Resolves `.` and `..` components without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(normalized.components().next_back(), Some(Component::Normal(_))) {
+                    normalized.pop();
+                } else {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Returns the path leading from `from` to `to`, both relative to the same directory.
+fn relative_path(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &to[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+/// Renders a path with forward slashes, as tsconfig and package.json expect.
+fn slashes(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Joins two slash-separated path fragments, skipping an empty second fragment.
+fn join(base: &str, child: &str) -> String {
+    let child = child.trim_matches('/');
+    if child.is_empty() {
+        base.to_string()
+    } else {
+        format!("{}/{child}", base.trim_end_matches('/'))
+    }
+}
@@ -0,0 +1,45 @@
+//! Workspace-wide operation tracking and multi-workspace aggregation.
+//!
+//! **What**: Provides the transaction log shared by every operation that rewrites workspace
+//! files: version application, dependency upgrades, constraint fixes, and package moves. Also
+//! provides `WorkspaceSet`, which runs changes analysis, audits, and upgrade detection across
+//! the workspace roots of a meta-repository, and `AliasGenerator`, which keeps tsconfig `paths`
+//! and package `imports` in step with the internal dependencies.
+//!
+//! **How**: `TransactionLog` builds on the upgrade backup storage. An operation begins a typed
+//! transaction with the files it will touch, commits it on success, and rolls it back on
+//! failure. Committed transactions stay in the log so they can be listed and undone later.
+//!
+//! **Why**: Each command used to manage (or skip) its own backups, so only dependency upgrades
+//! could be rolled back. A single log gives users one place to see and revert recent changes.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use sublime_pkg_tools::config::BackupConfig;
+//! use sublime_pkg_tools::workspace::{OperationKind, TransactionLog};
+//! use sublime_standard_tools::filesystem::FileSystemManager;
+//! use std::path::PathBuf;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let log = TransactionLog::new(PathBuf::from("."), BackupConfig::default(), FileSystemManager::new());
+//!
+//! for entry in log.list().await? {
+//!     println!("{} {} ({} files)", entry.id, entry.kind, entry.files.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod aliases;
+mod set;
+mod transaction;
+
+#[cfg(test)]
+mod tests;
+
+pub use aliases::{
+    AliasDrift, AliasDriftKind, AliasFile, AliasFileKind, AliasGenerator, AliasOutcome, AliasPlan,
+};
+pub use set::{RootOutcome, WorkspaceRoot, WorkspaceSet, WorkspaceSetReport};
+pub use transaction::{OperationKind, TransactionEntry, TransactionLog};
@@ -0,0 +1,241 @@
+//! Aggregation of several independent workspaces under one meta-repository.
+//!
+//! **What**: Provides `WorkspaceSet`, the workspace roots listed in the `roots` configuration,
+//! and `WorkspaceSetReport`, the per-root results of running an operation across them.
+//!
+//! **How**: Roots are resolved against the meta-repository root and visited in configured
+//! order. Each root runs independently: a failing root records its error and the remaining
+//! roots still run. Changes analysis, audit checks, and upgrade detection have ready-made
+//! entry points; `run` accepts any other per-root operation.
+//!
+//! **Why**: A meta-repository holding several workspaces otherwise needs one invocation
+//! per workspace and leaves the caller to stitch the results together.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use sublime_git_tools::Repo;
+use sublime_standard_tools::filesystem::FileSystemManager;
+
+use crate::audit::{AuditManager, AuditRun};
+use crate::changes::{ChangesAnalyzer, ChangesReport};
+use crate::config::{PackageToolsConfig, UpgradeConfig, WorkspaceRootConfig};
+use crate::upgrade::{DetectionOptions, UpgradeManager, UpgradePreview};
+
+/// One workspace of a `WorkspaceSet`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkspaceRoot {
+    /// Name results are grouped under.
+    pub name: String,
+    /// Directory of the workspace.
+    pub path: PathBuf,
+}
+
+/// The result of an operation on one workspace root.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RootOutcome<T> {
+    /// Name of the root.
+    pub name: String,
+    /// Directory of the root.
+    pub path: PathBuf,
+    /// The result, when the operation succeeded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<T>,
+    /// The error, when the operation failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl<T> RootOutcome<T> {
+    /// Returns whether the operation succeeded on this root.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// The results of an operation across a `WorkspaceSet`, grouped per root.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkspaceSetReport<T> {
+    /// One outcome per root, in configured order.
+    pub roots: Vec<RootOutcome<T>>,
+}
+
+impl<T> WorkspaceSetReport<T> {
+    /// Returns whether the operation succeeded on every root.
+    #[must_use]
+    pub fn all_succeeded(&self) -> bool {
+        self.roots.iter().all(RootOutcome::is_ok)
+    }
+
+    /// Returns the outcomes of the roots the operation failed on.
+    pub fn failed(&self) -> impl Iterator<Item = &RootOutcome<T>> {
+        self.roots.iter().filter(|outcome| !outcome.is_ok())
+    }
+
+    /// Returns the outcome for the root with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&RootOutcome<T>> {
+        self.roots.iter().find(|outcome| outcome.name == name)
+    }
+}
+
+/// The workspaces aggregated by a meta-repository.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::config::PackageToolsConfig;
+/// use sublime_pkg_tools::workspace::WorkspaceSet;
+/// use std::path::Path;
+///
+/// # async fn example(config: PackageToolsConfig) {
+/// let set = WorkspaceSet::from_config(Path::new("."), &config.roots);
+///
+/// let report = set.analyze_changes(&config).await;
+/// for outcome in &report.roots {
+///     match &outcome.result {
+///         Some(changes) => println!("{}: {} packages", outcome.name, changes.packages.len()),
+///         None => println!("{}: {}", outcome.name, outcome.error.as_deref().unwrap_or("")),
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceSet {
+    roots: Vec<WorkspaceRoot>,
+}
+
+impl WorkspaceSet {
+    /// Creates a set from already resolved roots.
+    #[must_use]
+    pub fn new(roots: Vec<WorkspaceRoot>) -> Self {
+        Self { roots }
+    }
+
+    /// Creates a set from the configured roots, resolving their paths against the
+    /// meta-repository root.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta_root` - Root directory of the meta-repository
+    /// * `roots` - The configured roots, usually `PackageToolsConfig::roots`
+    #[must_use]
+    pub fn from_config(meta_root: &Path, roots: &[WorkspaceRootConfig]) -> Self {
+        let roots = roots
+            .iter()
+            .map(|root| WorkspaceRoot {
+                name: root.display_name().to_string(),
+                path: meta_root.join(&root.path),
+            })
+            .collect();
+        Self { roots }
+    }
+
+    /// Returns the roots in configured order.
+    #[must_use]
+    pub fn roots(&self) -> &[WorkspaceRoot] {
+        &self.roots
+    }
+
+    /// Returns whether the set has no roots.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Runs an operation on every root, one after another.
+    ///
+    /// A failing root records its error; the remaining roots still run.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - The operation, given each root in turn
+    pub async fn run<T, E, F, Fut>(&self, operation: F) -> WorkspaceSetReport<T>
+    where
+        F: Fn(WorkspaceRoot) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Display,
+    {
+        let mut outcomes = Vec::with_capacity(self.roots.len());
+        for root in &self.roots {
+            let (result, error) = match operation(root.clone()).await {
+                Ok(result) => (Some(result), None),
+                Err(error) => {
+                    log::warn!("Workspace root '{}' failed: {error}", root.name);
+                    (None, Some(error.to_string()))
+                }
+            };
+            outcomes.push(RootOutcome {
+                name: root.name.clone(),
+                path: root.path.clone(),
+                result,
+                error,
+            });
+        }
+        WorkspaceSetReport { roots: outcomes }
+    }
+
+    /// Analyzes the working directory changes of every root.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration used for every root
+    pub async fn analyze_changes(
+        &self,
+        config: &PackageToolsConfig,
+    ) -> WorkspaceSetReport<ChangesReport> {
+        self.run(|root| async move {
+            let path = root.path.to_str().ok_or("workspace path contains invalid UTF-8")?;
+            let repo = Repo::open(path).map_err(|e| e.to_string())?;
+            let analyzer =
+                ChangesAnalyzer::new(root.path, repo, FileSystemManager::new(), config.clone())
+                    .await
+                    .map_err(|e| e.to_string())?;
+            analyzer.analyze_working_directory().await.map_err(|e| e.to_string())
+        })
+        .await
+    }
+
+    /// Runs audit checks on every root.
+    ///
+    /// Check failures inside a root are reported in its `AuditRun`; the root itself only
+    /// fails when the audit cannot start.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration used for every root
+    /// * `checks` - Ids of the checks to run
+    pub async fn run_audit_checks<S: AsRef<str> + Sync>(
+        &self,
+        config: &PackageToolsConfig,
+        checks: &[S],
+    ) -> WorkspaceSetReport<AuditRun> {
+        self.run(|root| async move {
+            let manager = Box::pin(AuditManager::new(root.path, config.clone())).await?;
+            Ok::<_, crate::error::AuditError>(manager.run_checks(checks).await)
+        })
+        .await
+    }
+
+    /// Detects available dependency upgrades in every root.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Upgrade configuration used for every root
+    /// * `options` - Which dependencies to check
+    pub async fn detect_upgrades(
+        &self,
+        config: &UpgradeConfig,
+        options: &DetectionOptions,
+    ) -> WorkspaceSetReport<UpgradePreview> {
+        self.run(|root| async move {
+            let manager = Box::pin(UpgradeManager::new(root.path, config.clone())).await?;
+            manager.detect_upgrades(options.clone()).await
+        })
+        .await
+    }
+}
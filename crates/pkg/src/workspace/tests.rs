@@ -0,0 +1,456 @@
+//! Tests for the workspace transaction log.
+//!
+//! **What**: Covers operation typing, commit and rollback, undo by ID and by kind, created file
+//! removal, and detection of newer transactions touching the same files.
+//!
+//! **How**: Drives `TransactionLog` against real files in temporary directories.
+//!
+//! **Why**: Undo rewrites user files, so restores must be exact and must never pick the wrong
+//! transaction.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::expect_used)]
+
+use super::{OperationKind, TransactionEntry, TransactionLog};
+use crate::config::BackupConfig;
+use crate::upgrade::BackupMetadata;
+use chrono::Utc;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use sublime_standard_tools::filesystem::FileSystemManager;
+use tempfile::TempDir;
+
+fn create_log() -> (TempDir, TransactionLog<FileSystemManager>) {
+    let temp_dir = TempDir::new().unwrap();
+    let log = TransactionLog::new(
+        temp_dir.path().to_path_buf(),
+        BackupConfig::default(),
+        FileSystemManager::new(),
+    );
+    (temp_dir, log)
+}
+
+fn write(root: &Path, name: &str, content: &str) -> PathBuf {
+    let path = root.join(name);
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap()
+}
+
+#[test]
+fn test_operation_kind_round_trip() {
+    for kind in [
+        OperationKind::VersionApply,
+        OperationKind::UpgradeApply,
+        OperationKind::ConstraintFix,
+        OperationKind::PackageMove,
+        OperationKind::AuditFix,
+        OperationKind::AliasSync,
+        OperationKind::Other("custom".to_string()),
+    ] {
+        assert_eq!(OperationKind::from(kind.as_str()), kind);
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(serde_json::from_str::<OperationKind>(&json).unwrap(), kind);
+    }
+}
+
+#[test]
+fn test_entry_from_legacy_upgrade_backup() {
+    let entry = TransactionEntry::from(BackupMetadata {
+        id: "2024-01-15T10-30-45-000-upgrade".to_string(),
+        created_at: Utc::now(),
+        operation: "upgrade".to_string(),
+        files: vec![PathBuf::from("/workspace/package.json")],
+        success: true,
+        created_files: vec![],
+        checksums: BTreeMap::new(),
+        size_bytes: 0,
+    });
+
+    assert_eq!(entry.kind, OperationKind::UpgradeApply);
+    assert!(entry.committed);
+}
+
+#[tokio::test]
+async fn test_commit_keeps_transaction() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", "{}");
+
+    let id = log.begin(OperationKind::VersionApply, &[manifest]).await.unwrap();
+    log.commit(&id).await.unwrap();
+
+    let entries = log.list().await.unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].kind, OperationKind::VersionApply);
+    assert!(entries[0].committed);
+}
+
+#[tokio::test]
+async fn test_rollback_restores_and_removes_entry() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", r#"{"version":"1.0.0"}"#);
+
+    let id = log.begin(OperationKind::VersionApply, std::slice::from_ref(&manifest)).await.unwrap();
+    std::fs::write(&manifest, r#"{"version":"2.0.0"}"#).unwrap();
+    log.rollback(&id).await.unwrap();
+
+    assert_eq!(read(&manifest), r#"{"version":"1.0.0"}"#);
+    assert!(log.list().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_undo_deletes_created_files() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", "{}");
+    let changelog = temp.path().join("CHANGELOG.md");
+
+    let id = log.begin(OperationKind::VersionApply, &[manifest, changelog.clone()]).await.unwrap();
+    std::fs::write(&changelog, "# Changelog").unwrap();
+    log.commit(&id).await.unwrap();
+
+    let undone = log.undo(&id).await.unwrap();
+
+    assert_eq!(undone.created_files, vec![changelog.clone()]);
+    assert!(!changelog.exists());
+}
+
+#[tokio::test]
+async fn test_undo_last_by_kind() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", "bump-before");
+    let lockfile = write(temp.path(), "deps.json", "upgrade-before");
+
+    let bump =
+        log.begin(OperationKind::VersionApply, std::slice::from_ref(&manifest)).await.unwrap();
+    std::fs::write(&manifest, "bump-after").unwrap();
+    log.commit(&bump).await.unwrap();
+
+    let upgrade =
+        log.begin(OperationKind::UpgradeApply, std::slice::from_ref(&lockfile)).await.unwrap();
+    std::fs::write(&lockfile, "upgrade-after").unwrap();
+    log.commit(&upgrade).await.unwrap();
+
+    let undone = log.undo_last(Some(&OperationKind::VersionApply)).await.unwrap();
+
+    assert_eq!(undone.id, bump);
+    assert_eq!(read(&manifest), "bump-before");
+    assert_eq!(read(&lockfile), "upgrade-after");
+    assert_eq!(log.list().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_last_ignores_uncommitted() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", "{}");
+
+    log.begin(OperationKind::VersionApply, &[manifest]).await.unwrap();
+
+    assert!(log.last(None).await.unwrap().is_none());
+    assert!(log.undo_last(None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_superseded_by_reports_overlapping_newer_transactions() {
+    let (temp, log) = create_log();
+    let manifest = write(temp.path(), "package.json", "{}");
+    let other = write(temp.path(), "other.json", "{}");
+
+    let first =
+        log.begin(OperationKind::VersionApply, std::slice::from_ref(&manifest)).await.unwrap();
+    log.commit(&first).await.unwrap();
+    let unrelated = log.begin(OperationKind::ConstraintFix, &[other]).await.unwrap();
+    log.commit(&unrelated).await.unwrap();
+    let overlapping = log.begin(OperationKind::UpgradeApply, &[manifest]).await.unwrap();
+    log.commit(&overlapping).await.unwrap();
+
+    let superseding = log.superseded_by(&first).await.unwrap();
+
+    assert_eq!(superseding.len(), 1);
+    assert_eq!(superseding[0].id, overlapping);
+    assert!(log.superseded_by(&overlapping).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_unknown_transaction() {
+    let (_temp, log) = create_log();
+
+    assert!(log.get("missing").await.is_err());
+    assert!(log.undo("missing").await.is_err());
+}
+
+// =============================================================================
+// Workspace Set Tests
+// =============================================================================
+
+mod workspace_set_tests {
+    use super::super::{WorkspaceRoot, WorkspaceSet};
+    use crate::config::{PackageToolsConfig, WorkspaceRootConfig};
+    use std::path::{Path, PathBuf};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_from_config_resolves_paths_and_names() {
+        let roots = vec![
+            WorkspaceRootConfig::new("frontend"),
+            WorkspaceRootConfig { path: "services/api".to_string(), name: Some("api".to_string()) },
+        ];
+
+        let set = WorkspaceSet::from_config(Path::new("/meta"), &roots);
+
+        assert_eq!(
+            set.roots(),
+            &[
+                WorkspaceRoot {
+                    name: "frontend".to_string(),
+                    path: PathBuf::from("/meta/frontend")
+                },
+                WorkspaceRoot {
+                    name: "api".to_string(),
+                    path: PathBuf::from("/meta/services/api")
+                },
+            ]
+        );
+        assert!(!set.is_empty());
+        assert!(WorkspaceSet::from_config(Path::new("/meta"), &[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_groups_results_and_continues_after_failure() {
+        let set = WorkspaceSet::from_config(
+            Path::new("/meta"),
+            &[
+                WorkspaceRootConfig::new("a"),
+                WorkspaceRootConfig::new("broken"),
+                WorkspaceRootConfig::new("c"),
+            ],
+        );
+
+        let report = set
+            .run(|root| async move {
+                if root.name == "broken" {
+                    Err(format!("cannot read {}", root.path.display()))
+                } else {
+                    Ok(root.name.len())
+                }
+            })
+            .await;
+
+        assert_eq!(report.roots.len(), 3);
+        assert!(!report.all_succeeded());
+        assert_eq!(report.get("a").unwrap().result, Some(1));
+        assert_eq!(report.get("c").unwrap().result, Some(1));
+
+        let failed: Vec<_> = report.failed().collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].name, "broken");
+        assert_eq!(failed[0].error.as_deref(), Some("cannot read /meta/broken"));
+        assert!(failed[0].result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_report_serializes_per_root() {
+        let set = WorkspaceSet::from_config(
+            Path::new("meta"),
+            &[WorkspaceRootConfig::new("ok"), WorkspaceRootConfig::new("bad")],
+        );
+
+        let report = set
+            .run(|root| async move { if root.name == "ok" { Ok(1) } else { Err("boom") } })
+            .await;
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["roots"][0]["name"], "ok");
+        assert_eq!(json["roots"][0]["result"], 1);
+        assert!(json["roots"][0].get("error").is_none());
+        assert_eq!(json["roots"][1]["error"], "boom");
+        assert!(json["roots"][1].get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_analyze_changes_reports_roots_without_repository() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("frontend")).unwrap();
+        let set = WorkspaceSet::from_config(temp.path(), &[WorkspaceRootConfig::new("frontend")]);
+
+        let report = set.analyze_changes(&PackageToolsConfig::default()).await;
+
+        assert_eq!(report.roots.len(), 1);
+        assert!(!report.all_succeeded());
+        assert!(report.roots[0].error.is_some());
+    }
+}
+
+mod alias_generator_tests {
+    use super::super::{AliasDriftKind, AliasFileKind, AliasGenerator};
+    use crate::config::{AliasesConfig, BackupConfig};
+    use crate::editor::JsonDocument;
+    use crate::types::PackageInfo;
+    use package_json::PackageJson;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::path::Path;
+    use sublime_standard_tools::filesystem::FileSystemManager;
+    use tempfile::TempDir;
+
+    /// Writes a package under `packages/<dir>` and returns its `PackageInfo`.
+    fn package(root: &Path, dir: &str, name: &str, dependencies: &[&str]) -> PackageInfo {
+        let path = root.join("packages").join(dir);
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(
+            path.join("package.json"),
+            format!("{{\n  \"name\": \"{name}\",\n  \"version\": \"1.0.0\"\n}}\n"),
+        )
+        .unwrap();
+
+        let dependencies: HashMap<String, String> = dependencies
+            .iter()
+            .map(|dep| ((*dep).to_string(), "workspace:*".to_string()))
+            .collect();
+        let package_json = PackageJson {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            dependencies: Some(dependencies),
+            ..Default::default()
+        };
+        PackageInfo::new(package_json, None, path)
+    }
+
+    fn workspace(root: &Path) -> Vec<PackageInfo> {
+        vec![
+            package(root, "app", "@test/app", &["@test/utils", "lodash"]),
+            package(root, "utils", "@test/utils", &[]),
+        ]
+    }
+
+    fn generator(root: &Path, config: AliasesConfig) -> AliasGenerator<FileSystemManager> {
+        AliasGenerator::new(
+            root.to_path_buf(),
+            config,
+            BackupConfig { enabled: false, ..BackupConfig::default() },
+            FileSystemManager::new(),
+        )
+    }
+
+    fn read_json(path: &Path) -> serde_json::Value {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_plan_creates_tsconfig_paths_for_dependencies() {
+        let temp = TempDir::new().unwrap();
+        let packages = workspace(temp.path());
+        let generator = generator(temp.path(), AliasesConfig::default());
+
+        let plan = generator.plan(&packages).await.unwrap();
+
+        assert_eq!(plan.files.len(), 1);
+        assert_eq!(plan.files[0].kind, AliasFileKind::TsconfigPaths);
+        assert!(plan.files[0].original.is_none());
+        assert_eq!(plan.drift_count(), 2);
+        assert!(plan.files[0].drift.iter().all(|drift| drift.kind == AliasDriftKind::Missing));
+
+        generator.apply(&plan).await.unwrap();
+        let tsconfig = read_json(&temp.path().join("tsconfig.json"));
+        assert_eq!(
+            tsconfig["compilerOptions"]["paths"],
+            json!({
+                "@test/utils": ["./packages/utils/src/index.ts"],
+                "@test/utils/*": ["./packages/utils/src/*"],
+            })
+        );
+        assert!(generator.plan(&packages).await.unwrap().is_in_sync());
+    }
+
+    #[tokio::test]
+    async fn test_plan_keeps_unmanaged_entries_and_comments() {
+        let temp = TempDir::new().unwrap();
+        let packages = workspace(temp.path());
+        std::fs::write(
+            temp.path().join("tsconfig.json"),
+            "{\n  // Shared options\n  \"compilerOptions\": {\n    \"baseUrl\": \"packages\",\n    \
+             \"paths\": {\n      \"~/*\": [\"./src/*\"],\n      \"@test/app\": [\"app/src/index.ts\"],\n      \
+             \"@test/utils\": [\"utils/lib/index.js\"]\n    }\n  }\n}\n",
+        )
+        .unwrap();
+        let generator = generator(temp.path(), AliasesConfig::default());
+
+        let plan = generator.plan(&packages).await.unwrap();
+        let kinds: Vec<(&str, AliasDriftKind)> =
+            plan.files[0].drift.iter().map(|drift| (drift.key.as_str(), drift.kind)).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("@test/app", AliasDriftKind::Stale),
+                ("@test/utils", AliasDriftKind::Changed),
+                ("@test/utils/*", AliasDriftKind::Missing),
+            ]
+        );
+
+        generator.apply(&plan).await.unwrap();
+        let content = std::fs::read_to_string(temp.path().join("tsconfig.json")).unwrap();
+        assert!(content.contains("// Shared options"));
+        let document = JsonDocument::parse("tsconfig.json", content).unwrap();
+        assert_eq!(
+            document.get("/compilerOptions/paths"),
+            Some(&json!({
+                "~/*": ["./src/*"],
+                "@test/utils": ["./utils/src/index.ts"],
+                "@test/utils/*": ["./utils/src/*"],
+            }))
+        );
+        assert!(generator.plan(&packages).await.unwrap().is_in_sync());
+    }
+
+    #[tokio::test]
+    async fn test_plan_package_imports() {
+        let temp = TempDir::new().unwrap();
+        let packages = workspace(temp.path());
+        let config = AliasesConfig { package_imports: true, ..AliasesConfig::default() };
+        let generator = generator(temp.path(), config);
+
+        let plan = generator.plan(&packages).await.unwrap();
+        let imports: Vec<_> =
+            plan.files.iter().filter(|file| file.kind == AliasFileKind::PackageImports).collect();
+        assert_eq!(imports.len(), 1);
+        assert!(imports[0].path.ends_with("packages/app/package.json"));
+
+        generator.apply(&plan).await.unwrap();
+        let manifest = read_json(&temp.path().join("packages/app/package.json"));
+        assert_eq!(
+            manifest["imports"],
+            json!({ "#test/utils": "@test/utils", "#test/utils/*": "@test/utils/*" })
+        );
+        assert!(generator.plan(&packages).await.unwrap().is_in_sync());
+    }
+
+    #[tokio::test]
+    async fn test_plan_without_dependencies_leaves_missing_tsconfig() {
+        let temp = TempDir::new().unwrap();
+        let packages = vec![package(temp.path(), "utils", "@test/utils", &[])];
+
+        let plan = generator(temp.path(), AliasesConfig::default()).plan(&packages).await.unwrap();
+
+        assert!(plan.is_in_sync());
+    }
+
+    #[tokio::test]
+    async fn test_apply_records_transaction() {
+        let temp = TempDir::new().unwrap();
+        let packages = workspace(temp.path());
+        let generator = AliasGenerator::new(
+            temp.path().to_path_buf(),
+            AliasesConfig::default(),
+            BackupConfig::default(),
+            FileSystemManager::new(),
+        );
+
+        let plan = generator.plan(&packages).await.unwrap();
+        let outcome = generator.apply(&plan).await.unwrap();
+
+        assert!(outcome.transaction_id.is_some());
+        assert_eq!(outcome.files, vec![temp.path().join("tsconfig.json")]);
+    }
+}
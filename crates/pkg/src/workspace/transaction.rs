@@ -0,0 +1,319 @@
+//! Transaction log for file-modifying workspace operations.
+//!
+//! **What**: Provides `TransactionLog`, which records a snapshot of the files an operation is
+//! about to touch, and `TransactionEntry`, the typed view of one recorded operation.
+//!
+//! **How**: Each transaction is a backup taken through `BackupManager`, tagged with an
+//! `OperationKind`. Committed transactions are kept (bounded by `max_backups`) so they can be
+//! undone later; failed ones are rolled back immediately. Files that do not exist when the
+//! transaction begins are recorded as created and are deleted on undo.
+//!
+//! **Why**: Version application, dependency upgrades, constraint fixes, package moves, and
+//! audit fixes all rewrite workspace files. Sharing one log lets a user list what happened
+//! recently and revert a specific operation, regardless of which command performed it.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sublime_standard_tools::filesystem::AsyncFileSystem;
+
+use crate::config::BackupConfig;
+use crate::error::{UpgradeError, UpgradeResult};
+use crate::upgrade::{BackupManager, BackupMetadata};
+
+/// The kind of operation recorded by a transaction.
+///
+/// The kind is stored as the backup `operation` string, so transactions written by earlier
+/// releases (always `"upgrade"`) are read back as [`OperationKind::UpgradeApply`].
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_pkg_tools::workspace::OperationKind;
+///
+/// assert_eq!(OperationKind::VersionApply.as_str(), "bump");
+/// assert_eq!(OperationKind::from("upgrade"), OperationKind::UpgradeApply);
+/// assert_eq!(OperationKind::from("version"), OperationKind::VersionApply);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OperationKind {
+    /// Version bumps applied from changesets.
+    VersionApply,
+    /// External dependency upgrades.
+    UpgradeApply,
+    /// Automatic fixes of dependency version constraints.
+    ConstraintFix,
+    /// Moving or renaming a workspace package.
+    PackageMove,
+    /// Fixes of audit issues.
+    AuditFix,
+    /// Regeneration of import aliases from the dependency graph.
+    AliasSync,
+    /// Any other operation, identified by name.
+    Other(String),
+}
+
+impl OperationKind {
+    /// Returns the name under which the operation is recorded.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::VersionApply => "bump",
+            Self::UpgradeApply => "upgrade",
+            Self::ConstraintFix => "constraint-fix",
+            Self::PackageMove => "package-move",
+            Self::AuditFix => "audit-fix",
+            Self::AliasSync => "alias-sync",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<&str> for OperationKind {
+    fn from(value: &str) -> Self {
+        match value {
+            "bump" | "version" => Self::VersionApply,
+            "upgrade" => Self::UpgradeApply,
+            "constraint-fix" => Self::ConstraintFix,
+            "package-move" | "move" => Self::PackageMove,
+            "audit-fix" => Self::AuditFix,
+            "alias-sync" => Self::AliasSync,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for OperationKind {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<OperationKind> for String {
+    fn from(kind: OperationKind) -> Self {
+        kind.as_str().to_string()
+    }
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A recorded operation in the transaction log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionEntry {
+    /// Unique identifier of the transaction.
+    pub id: String,
+
+    /// Kind of operation that was recorded.
+    pub kind: OperationKind,
+
+    /// When the transaction began.
+    pub created_at: DateTime<Utc>,
+
+    /// Absolute paths of files whose previous content is recorded.
+    pub files: Vec<PathBuf>,
+
+    /// Absolute paths of files the operation created. Undo deletes them.
+    pub created_files: Vec<PathBuf>,
+
+    /// Whether the operation completed and was committed.
+    pub committed: bool,
+}
+
+impl TransactionEntry {
+    /// Returns every path touched by the transaction, modified or created.
+    pub fn touched_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files.iter().chain(self.created_files.iter())
+    }
+}
+
+impl From<BackupMetadata> for TransactionEntry {
+    fn from(metadata: BackupMetadata) -> Self {
+        Self {
+            kind: OperationKind::from(metadata.operation.as_str()),
+            id: metadata.id,
+            created_at: metadata.created_at,
+            files: metadata.files,
+            created_files: metadata.created_files,
+            committed: metadata.success,
+        }
+    }
+}
+
+/// Records workspace operations so they can be rolled back or undone.
+///
+/// Transactions share the backup directory and metadata of [`BackupManager`], so backups made
+/// by earlier releases appear in the log as upgrade transactions.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use sublime_pkg_tools::config::BackupConfig;
+/// use sublime_pkg_tools::workspace::{OperationKind, TransactionLog};
+/// use sublime_standard_tools::filesystem::FileSystemManager;
+/// use std::path::PathBuf;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let log = TransactionLog::new(PathBuf::from("."), BackupConfig::default(), FileSystemManager::new());
+///
+/// let files = vec![PathBuf::from("package.json"), PathBuf::from("CHANGELOG.md")];
+/// let id = log.begin(OperationKind::VersionApply, &files).await?;
+/// // ... write the files ...
+/// log.commit(&id).await?;
+///
+/// // Later: revert the most recent version bump
+/// let undone = log.undo_last(Some(&OperationKind::VersionApply)).await?;
+/// println!("Reverted {}", undone.id);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransactionLog<F: AsyncFileSystem> {
+    backups: BackupManager<F>,
+}
+
+impl<F: AsyncFileSystem> TransactionLog<F> {
+    /// Creates a transaction log stored in the configured backup directory.
+    ///
+    /// Committed transactions are always kept until `max_backups` is exceeded; callers that
+    /// do not want to keep one call [`TransactionLog::discard`].
+    #[must_use]
+    pub fn new(workspace_root: PathBuf, config: BackupConfig, fs: F) -> Self {
+        let config = BackupConfig { keep_after_success: true, ..config };
+        Self { backups: BackupManager::new(workspace_root, config, fs) }
+    }
+
+    /// Records the current content of `files` and returns the transaction ID.
+    ///
+    /// Files that do not exist yet are recorded as created by the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if backups are disabled or the snapshot cannot be written.
+    pub async fn begin(&self, kind: OperationKind, files: &[PathBuf]) -> UpgradeResult<String> {
+        self.backups.create_backup_allowing_missing(files, kind.as_str()).await
+    }
+
+    /// Marks a transaction as completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction does not exist or metadata cannot be written.
+    pub async fn commit(&self, id: &str) -> UpgradeResult<()> {
+        self.backups.mark_success(id).await
+    }
+
+    /// Restores the files of a transaction and removes it from the log.
+    ///
+    /// Used when the operation fails before it is committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction does not exist or files cannot be restored.
+    pub async fn rollback(&self, id: &str) -> UpgradeResult<()> {
+        self.backups.restore_backup(id).await?;
+        self.backups.delete_backup(id).await
+    }
+
+    /// Removes a transaction from the log without restoring it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction does not exist or cannot be deleted.
+    pub async fn discard(&self, id: &str) -> UpgradeResult<()> {
+        self.backups.delete_backup(id).await
+    }
+
+    /// Lists recorded transactions, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log metadata cannot be read.
+    pub async fn list(&self) -> UpgradeResult<Vec<TransactionEntry>> {
+        Ok(self.backups.list_backups().await?.into_iter().map(TransactionEntry::from).collect())
+    }
+
+    /// Returns a transaction by ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError::NoBackup` if the transaction does not exist.
+    pub async fn get(&self, id: &str) -> UpgradeResult<TransactionEntry> {
+        self.list()
+            .await?
+            .into_iter()
+            .find(|entry| entry.id == id)
+            .ok_or_else(|| UpgradeError::NoBackup { path: self.backups.backup_path(id) })
+    }
+
+    /// Returns the most recent committed transaction, optionally restricted to one kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the log metadata cannot be read.
+    pub async fn last(
+        &self,
+        kind: Option<&OperationKind>,
+    ) -> UpgradeResult<Option<TransactionEntry>> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .find(|entry| entry.committed && kind.is_none_or(|kind| &entry.kind == kind)))
+    }
+
+    /// Returns committed transactions newer than `id` that touched any of its files.
+    ///
+    /// Undoing `id` also discards the changes those transactions made to the shared files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError::NoBackup` if the transaction does not exist.
+    pub async fn superseded_by(&self, id: &str) -> UpgradeResult<Vec<TransactionEntry>> {
+        let entries = self.list().await?;
+        let position = entries
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or_else(|| UpgradeError::NoBackup { path: self.backups.backup_path(id) })?;
+
+        let touched: HashSet<&PathBuf> = entries[position].touched_files().collect();
+        Ok(entries[..position]
+            .iter()
+            .filter(|entry| entry.committed && entry.touched_files().any(|f| touched.contains(f)))
+            .cloned()
+            .collect())
+    }
+
+    /// Reverts a committed transaction and removes it from the log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction does not exist or files cannot be restored.
+    pub async fn undo(&self, id: &str) -> UpgradeResult<TransactionEntry> {
+        let entry = self.get(id).await?;
+        self.rollback(id).await?;
+        Ok(entry)
+    }
+
+    /// Reverts the most recent committed transaction, optionally restricted to one kind.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UpgradeError::NoBackup` if no matching transaction exists, or an error if
+    /// files cannot be restored.
+    pub async fn undo_last(&self, kind: Option<&OperationKind>) -> UpgradeResult<TransactionEntry> {
+        let entry = self
+            .last(kind)
+            .await?
+            .ok_or_else(|| UpgradeError::NoBackup { path: self.backups.backup_dir() })?;
+        self.rollback(&entry.id).await?;
+        Ok(entry)
+    }
+}
@@ -0,0 +1,75 @@
+//! # Operation Deadline Integration Tests
+//!
+//! **What**: Verifies that registry lookups and upgrade detection honour the process-wide
+//! deadline of `sublime_standard_tools::deadline`.
+//!
+//! **How**: Looks up a package from a mock registry, then sets a deadline that has already
+//! passed and checks that lookups fail with `DeadlineExceeded` and that upgrade detection
+//! returns the preview it gathered as a partial result.
+//!
+//! **Why**: The deadline is a global setting, so it is tested in its own test binary where
+//! it cannot interfere with tests that reach mock servers.
+
+#![allow(clippy::expect_used)]
+#![allow(clippy::panic)]
+
+use std::time::Duration;
+use sublime_pkg_tools::config::RegistryConfig;
+use sublime_pkg_tools::error::UpgradeError;
+use sublime_pkg_tools::upgrade::{DetectionOptions, RegistryClient, detect_upgrades};
+use sublime_standard_tools::deadline::set_global_deadline;
+use sublime_standard_tools::filesystem::FileSystemManager;
+
+#[tokio::test]
+async fn test_passed_deadline_stops_registry_lookups() {
+    let mut registry = mockito::Server::new_async().await;
+    registry
+        .mock("GET", "/express")
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "name": "express",
+                "versions": { "4.18.2": {} },
+                "dist-tags": { "latest": "4.18.2" },
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let workspace = tempfile::tempdir().expect("Failed to create temp dir");
+    std::fs::write(
+        workspace.path().join("package.json"),
+        r#"{ "name": "app", "version": "1.0.0", "dependencies": { "express": "^4.17.0" } }"#,
+    )
+    .expect("Failed to write package.json");
+
+    let config = RegistryConfig {
+        read_npmrc: false,
+        default_registry: registry.url(),
+        ..Default::default()
+    };
+    let client =
+        RegistryClient::new(workspace.path(), config).await.expect("Failed to create client");
+    client.get_package_info("express").await.expect("Lookup without a deadline should succeed");
+
+    set_global_deadline(Some(Duration::ZERO));
+    let lookup = client.get_package_info("express").await;
+    let detection = detect_upgrades(
+        workspace.path(),
+        &client,
+        &FileSystemManager::new(),
+        DetectionOptions::all(),
+    )
+    .await;
+    set_global_deadline(None);
+
+    assert!(matches!(lookup, Err(UpgradeError::DeadlineExceeded { partial: None, .. })));
+    let Err(UpgradeError::DeadlineExceeded { operation, partial: Some(preview) }) = detection
+    else {
+        panic!("Expected a partial preview, got {detection:?}");
+    };
+    assert_eq!(operation, "upgrade detection");
+    assert_eq!(preview.deadline_skipped, vec!["express".to_string()]);
+    assert!(preview.packages.is_empty());
+}
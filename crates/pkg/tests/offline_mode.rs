@@ -0,0 +1,90 @@
+//! # Offline Mode Integration Tests
+//!
+//! **What**: Verifies that registry lookups and notifications honour the process-wide
+//! offline mode of `sublime_standard_tools::network`.
+//!
+//! **How**: Fills the registry client's document cache from a mock registry, enables the
+//! mode, and checks that cached documents are served, uncached lookups are skipped with
+//! `OfflineSkipped`, and notifications are reported as skipped without a request.
+//!
+//! **Why**: The mode is a global switch, so it is tested in its own test binary where it
+//! cannot interfere with tests that reach mock servers.
+
+#![allow(clippy::expect_used)]
+
+use std::path::PathBuf;
+use sublime_pkg_tools::config::{
+    NotificationChannelConfig, NotificationsConfig, RegistryConfig, WebhookChannelConfig,
+};
+use sublime_pkg_tools::error::{NotificationError, UpgradeError};
+use sublime_pkg_tools::notifications::{
+    Notification, NotificationEvent, Notifier, ReleasedPackage,
+};
+use sublime_pkg_tools::upgrade::RegistryClient;
+use sublime_standard_tools::network::set_offline_mode;
+
+fn package_document(name: &str, latest: &str) -> String {
+    serde_json::json!({
+        "name": name,
+        "versions": { latest: {} },
+        "dist-tags": { "latest": latest },
+        "time": { "modified": "2024-01-01T00:00:00.000Z", latest: "2024-01-01T00:00:00.000Z" },
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_offline_mode_uses_cache_and_skips_network() {
+    let mut registry = mockito::Server::new_async().await;
+    let cached = registry
+        .mock("GET", "/express")
+        .with_header("content-type", "application/json")
+        .with_header("etag", "\"v1\"")
+        .with_body(package_document("express", "4.18.2"))
+        .expect(1)
+        .create_async()
+        .await;
+    let uncached = registry.mock("GET", "/lodash").expect(0).create_async().await;
+
+    let config = RegistryConfig {
+        read_npmrc: false,
+        default_registry: registry.url(),
+        ..Default::default()
+    };
+    let client =
+        RegistryClient::new(&PathBuf::from("."), config).await.expect("Failed to create client");
+    client.get_package_info("express").await.expect("Failed to fetch express");
+
+    let mut webhook = mockito::Server::new_async().await;
+    let hook = webhook.mock("POST", "/hook").expect(0).create_async().await;
+    let notifier = Notifier::new(NotificationsConfig {
+        channels: vec![NotificationChannelConfig::Webhook(WebhookChannelConfig {
+            url: format!("{}/hook", webhook.url()),
+            headers: Default::default(),
+            events: vec![NotificationEvent::Release],
+            template: None,
+        })],
+        ..Default::default()
+    })
+    .expect("Failed to create notifier");
+
+    set_offline_mode(true);
+    let express = client.get_package_info("express").await;
+    let lodash = client.get_package_info("lodash").await;
+    let stats = client.get_download_stats("lodash").await;
+    let report =
+        notifier.notify(&Notification::release(vec![ReleasedPackage::new("core", "1.0.0")])).await;
+    set_offline_mode(false);
+
+    assert_eq!(express.expect("Expected cached metadata").latest, "4.18.2");
+    assert!(
+        matches!(lodash, Err(UpgradeError::OfflineSkipped { ref package }) if package == "lodash")
+    );
+    assert!(stats.is_none());
+    assert_eq!(report.deliveries.len(), 1);
+    assert!(matches!(report.deliveries[0].error, Some(NotificationError::OfflineSkipped { .. })));
+
+    cached.assert_async().await;
+    uncached.assert_async().await;
+    hook.assert_async().await;
+}
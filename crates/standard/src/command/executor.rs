@@ -22,6 +22,7 @@
 use std::{path::Path, process::Stdio, time::Instant};
 
 use crate::config::{ConfigManager, StandardConfig, traits::Configurable};
+use crate::deadline::global_deadline;
 use crate::error::{CommandError, Error, Result};
 use crate::filesystem::{AsyncFileSystem, FileSystemManager};
 
@@ -327,6 +328,7 @@ impl Executor for DefaultCommandExecutor {
     /// # Errors
     ///
     /// Returns an error if the command fails to execute, times out, or returns a non-zero exit code.
+    /// Returns `Error::DeadlineExceeded` if the process deadline passes before the command ends.
     /// * `Err(Error)` - If the command failed to execute
     ///
     /// # Examples
@@ -354,6 +356,8 @@ impl Executor for DefaultCommandExecutor {
         let cmd_str = command.program.clone(); // For error reporting
 
         let timeout_duration = command.timeout.unwrap_or(self.config.default_timeout);
+        let deadline = global_deadline();
+        deadline.check(&format!("run {cmd_str}"))?;
         let child = cmd.spawn().map_err(|e| {
             Error::Command(CommandError::SpawnFailed {
                 cmd: cmd_str.clone(),
@@ -364,8 +368,17 @@ impl Executor for DefaultCommandExecutor {
         // Get PID before potentially consuming child with wait_with_output
         let child_pid = child.id();
 
-        // Wait for completion with timeout
-        let output_result = timeout(timeout_duration, child.wait_with_output()).await;
+        // Wait for completion with timeout, stopping early when the process deadline passes
+        let output_result = match deadline
+            .run(&format!("run {cmd_str}"), timeout(timeout_duration, child.wait_with_output()))
+            .await
+        {
+            Ok(result) => result,
+            Err(exceeded) => {
+                log::warn!("{exceeded}; the process (PID: {child_pid:?}) may need manual cleanup");
+                return Err(exceeded.into());
+            }
+        };
 
         let output = match output_result {
             Ok(Ok(output)) => output,
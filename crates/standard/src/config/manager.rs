@@ -17,6 +17,7 @@ use crate::error::{ConfigError, ConfigResult};
 use crate::filesystem::AsyncFileSystem;
 
 use super::format::ConfigFormat;
+use super::remote::{RemoteConfig, RemoteProvider};
 use super::source::{
     ConfigSource, ConfigSourcePriority, DefaultProvider, EnvironmentProvider, FileProvider,
     MemoryProvider,
@@ -129,6 +130,33 @@ impl<T: Configurable + Clone> ConfigManager<T> {
             }
         }
 
+        let config = self.load_unvalidated().await?;
+
+        // Validate the configuration
+        config.validate()?;
+
+        // Cache the result
+        {
+            let mut cache = self.cache.write().await;
+            *cache = Some(config.clone());
+        }
+
+        Ok(config)
+    }
+
+    /// Loads and merges configuration from all sources without validating it.
+    ///
+    /// The result is not cached. This is meant for tools that report validation errors
+    /// themselves and need the configuration even when it is invalid.
+    ///
+    /// # Returns
+    ///
+    /// The merged configuration, which may not pass `validate()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the merged sources cannot be deserialized.
+    pub async fn load_unvalidated(&self) -> ConfigResult<T> {
         // Load from all providers
         let mut merged_value = ConfigValue::Map(HashMap::default());
 
@@ -145,19 +173,8 @@ impl<T: Configurable + Clone> ConfigManager<T> {
         }
 
         // Deserialize the merged value
-        let config: T = serde_json::from_value(serde_json::to_value(&merged_value)?)
-            .map_err(|e| ConfigError::parse("configuration", e.to_string()))?;
-
-        // Validate the configuration
-        config.validate()?;
-
-        // Cache the result
-        {
-            let mut cache = self.cache.write().await;
-            *cache = Some(config.clone());
-        }
-
-        Ok(config)
+        serde_json::from_value(serde_json::to_value(&merged_value)?)
+            .map_err(|e| ConfigError::parse("configuration", e.to_string()))
     }
 
     /// Loads configuration from all sources without using cache.
@@ -475,6 +492,31 @@ impl<T: Configurable + Clone> ConfigBuilder<T> {
         self
     }
 
+    /// Adds a remote configuration source.
+    ///
+    /// Remote sources use the global priority, so they are merged under project files
+    /// added with the same or a higher priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Location and trust settings of the document
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::config::{ConfigManager, RemoteConfig};
+    /// use sublime_standard_tools::config::standard::StandardConfig;
+    ///
+    /// let builder = ConfigManager::<StandardConfig>::builder()
+    ///     .with_remote(RemoteConfig::new("s3://acme-policy/workspace.toml"))
+    ///     .with_file(".sublime.toml");
+    /// ```
+    #[must_use]
+    pub fn with_remote(mut self, config: RemoteConfig) -> Self {
+        self.sources.push(ConfigSource::remote(config, ConfigSourcePriority::Global));
+        self
+    }
+
     /// Adds an environment variable source.
     ///
     /// # Arguments
@@ -570,6 +612,9 @@ impl<T: Configurable + Clone> ConfigBuilder<T> {
                 ConfigSource::Memory { values, priority } => {
                     providers.push(Box::new(MemoryProvider::new(values, priority)));
                 }
+                ConfigSource::Remote { config, priority } => {
+                    providers.push(Box::new(RemoteProvider::new(config, priority, fs.clone())));
+                }
             }
         }
 
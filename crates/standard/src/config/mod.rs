@@ -13,6 +13,8 @@
 //! - **ConfigProvider**: Trait for configuration sources
 //! - **ConfigManager**: Generic manager for any Configurable type
 //! - **ConfigSource**: Different sources of configuration data
+//! - **RemoteProvider**: Configuration fetched from HTTP(S) or S3, cached on disk and
+//!   optionally verified against an Ed25519 signature
 //! - **StandardConfig**: The standard configuration for this crate
 //!
 //! # Example
@@ -37,6 +39,7 @@
 
 pub mod format;
 pub mod manager;
+pub mod remote;
 pub mod source;
 pub mod standard;
 pub mod traits;
@@ -46,11 +49,15 @@ pub mod value;
 pub use crate::error::{ConfigError, ConfigResult};
 pub use format::ConfigFormat;
 pub use manager::{ConfigBuilder, ConfigManager};
+pub use remote::{RemoteConfig, RemoteProvider};
 pub use source::{ConfigSource, ConfigSourcePriority};
 pub use standard::StandardConfig;
 pub use traits::{ConfigProvider, Configurable};
 pub use value::ConfigValue;
 
+#[cfg(test)]
+mod tests;
+
 // Re-export commonly used types from submodules
 pub use standard::{
     CommandConfig, FilesystemConfig, MonorepoConfig, PackageManagerConfig, ValidationConfig,
@@ -0,0 +1,526 @@
+//! Remote configuration providers.
+//!
+//! This module provides a provider that fetches a configuration document from an HTTP(S)
+//! URL or an S3 bucket, caches it on disk, and verifies its detached Ed25519 signature
+//! when a public key is configured. Organizations use it to manage shared policy in one
+//! place while each workspace keeps its own local overrides. In offline mode the cached
+//! copy is used whatever its age, and nothing is fetched. Requests go through the shared
+//! `TransportSettings`, so proxies and custom CAs apply as they do to registry requests.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ring::{digest, hmac, signature};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ConfigError, ConfigResult};
+use crate::filesystem::AsyncFileSystem;
+use crate::network::TransportSettings;
+
+use super::format::ConfigFormat;
+use super::source::ConfigSourcePriority;
+use super::traits::ConfigProvider;
+use super::value::ConfigValue;
+
+/// Default number of seconds a fetched document is reused.
+const DEFAULT_CACHE_TTL_SECS: u64 = 3600;
+
+/// Default request timeout in seconds.
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+/// Region used for `s3://` URLs when none is configured.
+const DEFAULT_S3_REGION: &str = "us-east-1";
+
+/// DER prefix of an Ed25519 `SubjectPublicKeyInfo`, followed by the 32-byte key.
+const ED25519_SPKI_PREFIX: [u8; 12] =
+    [0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+
+/// SHA-256 of an empty request body, sent with every S3 request.
+const EMPTY_PAYLOAD_SHA256: &str =
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Location and trust settings of a remote configuration document.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::config::RemoteConfig;
+///
+/// let mut remote = RemoteConfig::new("https://config.example.com/workspace.toml");
+/// remote.public_key = Some("11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo=".to_string());
+/// remote.cache_ttl_secs = 600;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RemoteConfig {
+    /// URL of the document: `https://…`, `http://…`, or `s3://bucket/key`.
+    pub url: String,
+
+    /// Format of the document (`toml`, `json`, `yaml`); detected from the URL when unset.
+    pub format: Option<String>,
+
+    /// Base64-encoded Ed25519 public key, raw or DER (`openssl pkey -pubout -outform DER`);
+    /// when set, the document must be signed with it.
+    pub public_key: Option<String>,
+
+    /// URL of the base64-encoded detached signature; defaults to `<url>.sig`.
+    pub signature_url: Option<String>,
+
+    /// Seconds a fetched document is reused before it is fetched again.
+    pub cache_ttl_secs: u64,
+
+    /// Directory fetched documents are cached in; defaults to the user cache directory.
+    pub cache_dir: Option<PathBuf>,
+
+    /// Timeout of each request, in seconds.
+    pub timeout_secs: u64,
+
+    /// AWS region of `s3://` URLs; defaults to `AWS_REGION`, `AWS_DEFAULT_REGION`, then
+    /// `us-east-1`.
+    pub region: Option<String>,
+
+    /// S3-compatible endpoint addressed path-style, such as a MinIO server.
+    pub endpoint: Option<String>,
+}
+
+impl Default for RemoteConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            format: None,
+            public_key: None,
+            signature_url: None,
+            cache_ttl_secs: DEFAULT_CACHE_TTL_SECS,
+            cache_dir: None,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            region: None,
+            endpoint: None,
+        }
+    }
+}
+
+impl RemoteConfig {
+    /// Creates a remote configuration for the given URL with default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL of the document (`https://…` or `s3://bucket/key`)
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into(), ..Self::default() }
+    }
+
+    /// Returns the format of the document, from the setting or the URL extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format is not recognized.
+    pub fn resolve_format(&self) -> ConfigResult<ConfigFormat> {
+        match &self.format {
+            Some(format) => ConfigFormat::from_extension(format),
+            None => {
+                let path = self.url.split(['?', '#']).next().unwrap_or_default();
+                ConfigFormat::from_path(Path::new(path))
+            }
+        }
+        .ok_or_else(|| {
+            ConfigError::other(format!(
+                "Cannot determine format for remote configuration: {}",
+                self.url
+            ))
+        })
+    }
+
+    /// Returns the URL of the detached signature.
+    #[must_use]
+    pub fn signature_location(&self) -> String {
+        self.signature_url.clone().unwrap_or_else(|| format!("{}.sig", self.url))
+    }
+
+    /// Returns the directory fetched documents are cached in.
+    #[must_use]
+    pub fn cache_directory(&self) -> PathBuf {
+        self.cache_dir.clone().unwrap_or_else(|| {
+            dirs::cache_dir()
+                .unwrap_or_else(std::env::temp_dir)
+                .join("sublime")
+                .join("remote-config")
+        })
+    }
+}
+
+/// AWS credentials used to sign S3 requests.
+#[derive(Debug, Clone)]
+pub(crate) struct S3Credentials {
+    pub(crate) access_key_id: String,
+    pub(crate) secret_access_key: String,
+    pub(crate) session_token: Option<String>,
+}
+
+impl S3Credentials {
+    /// Reads credentials from the standard AWS environment variables.
+    fn from_env() -> Option<Self> {
+        Some(Self {
+            access_key_id: std::env::var("AWS_ACCESS_KEY_ID").ok()?,
+            secret_access_key: std::env::var("AWS_SECRET_ACCESS_KEY").ok()?,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+        })
+    }
+}
+
+/// Remote configuration provider.
+///
+/// Fetched documents are cached for `cache_ttl_secs`. When the remote cannot be reached,
+/// a stale cached copy is used instead. A signature is checked on every load, cached or
+/// not, so a tampered cache is never trusted.
+pub struct RemoteProvider<FS: AsyncFileSystem> {
+    config: RemoteConfig,
+    priority: ConfigSourcePriority,
+    fs: FS,
+    transport: TransportSettings,
+}
+
+impl<FS: AsyncFileSystem> RemoteProvider<FS> {
+    /// Creates a new remote provider.
+    ///
+    /// Requests use the proxy settings of the environment until `with_transport` sets
+    /// others.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Location and trust settings of the document
+    /// * `priority` - Priority of this provider
+    /// * `fs` - Filesystem used for the cache
+    pub fn new(config: RemoteConfig, priority: ConfigSourcePriority, fs: FS) -> Self {
+        Self { config, priority, fs, transport: TransportSettings::from_env() }
+    }
+
+    /// Sets the proxy and TLS settings of the requests.
+    #[must_use]
+    pub fn with_transport(mut self, transport: TransportSettings) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Fetches, verifies, and parses the document.
+    async fn load_document(&self) -> ConfigResult<ConfigValue> {
+        let format = self.config.resolve_format()?;
+        let cache_dir = self.config.cache_directory();
+        let key = hex(digest::digest(&digest::SHA256, self.config.url.as_bytes()).as_ref());
+        let body_path = cache_dir.join(format!("{key}.{}", format.extension()));
+        let signature_path = cache_dir.join(format!("{key}.sig"));
+
+        if self.is_fresh(&body_path).await
+            && let Some(content) = self.read_cached(&body_path, &signature_path).await
+        {
+            log::debug!("Using cached remote configuration for {}", self.config.url);
+            return format.parse(&content);
+        }
+
+        if crate::network::is_offline_mode() {
+            return match self.read_cached(&body_path, &signature_path).await {
+                Some(content) => {
+                    log::warn!(
+                        "Offline mode: using the cached remote configuration for {}, which may be stale",
+                        self.config.url
+                    );
+                    format.parse(&content)
+                }
+                None => Err(self.fetch_error("offline mode is enabled and no cached copy exists")),
+            };
+        }
+
+        match self.fetch().await {
+            Ok((content, signature)) => {
+                self.verify(&content, signature.as_deref())?;
+                self.write_cache(&cache_dir, &body_path, &content, &signature_path, signature)
+                    .await;
+                format.parse(&content)
+            }
+            Err(error @ ConfigError::RemoteFetchError { .. }) => {
+                match self.read_cached(&body_path, &signature_path).await {
+                    Some(content) => {
+                        log::warn!("{error}; using the cached copy");
+                        format.parse(&content)
+                    }
+                    None => Err(error),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns whether a cached document is younger than the cache TTL.
+    async fn is_fresh(&self, path: &Path) -> bool {
+        let Ok(metadata) = self.fs.metadata(path).await else {
+            return false;
+        };
+        metadata
+            .modified()
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age < Duration::from_secs(self.config.cache_ttl_secs))
+    }
+
+    /// Reads a cached document, if one exists and passes signature verification.
+    async fn read_cached(&self, body_path: &Path, signature_path: &Path) -> Option<String> {
+        let content = self.fs.read_file_string(body_path).await.ok()?;
+        let signature = self.fs.read_file_string(signature_path).await.ok();
+        match self.verify(&content, signature.as_deref()) {
+            Ok(()) => Some(content),
+            Err(error) => {
+                log::warn!("Ignoring cached remote configuration: {error}");
+                None
+            }
+        }
+    }
+
+    /// Stores a fetched document; failures only cost a later re-fetch.
+    async fn write_cache(
+        &self,
+        cache_dir: &Path,
+        body_path: &Path,
+        content: &str,
+        signature_path: &Path,
+        signature: Option<String>,
+    ) {
+        let result = async {
+            self.fs.create_dir_all(cache_dir).await?;
+            self.fs.write_file_string(body_path, content).await?;
+            if let Some(signature) = signature {
+                self.fs.write_file_string(signature_path, &signature).await?;
+            }
+            Ok::<_, crate::error::Error>(())
+        }
+        .await;
+
+        if let Err(error) = result {
+            log::debug!("Cannot cache remote configuration {}: {error}", self.config.url);
+        }
+    }
+
+    /// Fetches the document and, when a public key is configured, its signature.
+    async fn fetch(&self) -> ConfigResult<(String, Option<String>)> {
+        let builder =
+            reqwest::Client::builder().timeout(Duration::from_secs(self.config.timeout_secs));
+        let client = self
+            .transport
+            .apply(builder)
+            .await
+            .map_err(|message| {
+                ConfigError::validation(format!("remote {}: {message}", self.config.url))
+            })?
+            .build()
+            .map_err(|e| self.fetch_error(e.to_string()))?;
+
+        let content = self.get(&client, &self.config.url).await?;
+        let signature = match self.config.public_key {
+            Some(_) => Some(self.get(&client, &self.config.signature_location()).await?),
+            None => None,
+        };
+        Ok((content, signature))
+    }
+
+    /// Performs a GET request, signing it when the URL points to S3.
+    async fn get(&self, client: &reqwest::Client, url: &str) -> ConfigResult<String> {
+        let request = match url.strip_prefix("s3://") {
+            Some(location) => self.s3_request(client, location)?,
+            None => client.get(url),
+        };
+
+        let response = request.send().await.map_err(|e| self.fetch_error(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(self.fetch_error(format!("{url} returned HTTP {status}")));
+        }
+        response.text().await.map_err(|e| self.fetch_error(e.to_string()))
+    }
+
+    /// Builds a GET request for an S3 object, signed with Signature Version 4 when AWS
+    /// credentials are set in the environment.
+    fn s3_request(
+        &self,
+        client: &reqwest::Client,
+        location: &str,
+    ) -> ConfigResult<reqwest::RequestBuilder> {
+        let (bucket, key) = location
+            .split_once('/')
+            .filter(|(bucket, key)| !bucket.is_empty() && !key.is_empty())
+            .ok_or_else(|| self.fetch_error(format!("invalid S3 location 's3://{location}'")))?;
+
+        let region = self
+            .config
+            .region
+            .clone()
+            .or_else(|| std::env::var("AWS_REGION").ok())
+            .or_else(|| std::env::var("AWS_DEFAULT_REGION").ok())
+            .unwrap_or_else(|| DEFAULT_S3_REGION.to_string());
+
+        let encoded_key = key.split('/').map(uri_encode).collect::<Vec<_>>().join("/");
+        let (url, canonical_uri) = match &self.config.endpoint {
+            Some(endpoint) => (
+                format!("{}/{bucket}/{encoded_key}", endpoint.trim_end_matches('/')),
+                format!("/{}/{encoded_key}", uri_encode(bucket)),
+            ),
+            None => (
+                format!("https://{bucket}.s3.{region}.amazonaws.com/{encoded_key}"),
+                format!("/{encoded_key}"),
+            ),
+        };
+
+        let mut request = client.get(&url);
+        let Some(credentials) = S3Credentials::from_env() else {
+            return Ok(request);
+        };
+
+        let parsed = reqwest::Url::parse(&url).map_err(|e| self.fetch_error(e.to_string()))?;
+        let host = match (parsed.host_str(), parsed.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(self.fetch_error(format!("invalid S3 URL '{url}'"))),
+        };
+
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let authorization =
+            s3_authorization(&host, &canonical_uri, &region, &credentials, &amz_date);
+
+        request = request
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", EMPTY_PAYLOAD_SHA256)
+            .header(reqwest::header::AUTHORIZATION, authorization);
+        if let Some(token) = credentials.session_token {
+            request = request.header("x-amz-security-token", token);
+        }
+        Ok(request)
+    }
+
+    /// Checks the document against the configured public key.
+    fn verify(&self, content: &str, signature: Option<&str>) -> ConfigResult<()> {
+        let Some(public_key) = &self.config.public_key else {
+            return Ok(());
+        };
+        let signature = signature.ok_or_else(|| self.signature_error("signature is missing"))?;
+        verify_signature(public_key, content.as_bytes(), signature)
+            .map_err(|message| self.signature_error(message))
+    }
+
+    fn fetch_error(&self, message: impl Into<String>) -> ConfigError {
+        ConfigError::RemoteFetchError { url: self.config.url.clone(), message: message.into() }
+    }
+
+    fn signature_error(&self, message: impl Into<String>) -> ConfigError {
+        ConfigError::SignatureError { url: self.config.url.clone(), message: message.into() }
+    }
+}
+
+#[async_trait]
+impl<FS: AsyncFileSystem> ConfigProvider for RemoteProvider<FS> {
+    async fn load(&self) -> ConfigResult<ConfigValue> {
+        self.load_document().await
+    }
+
+    async fn save(&self, _value: &ConfigValue) -> ConfigResult<()> {
+        // Remote configuration is read-only
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        &self.config.url
+    }
+
+    fn supports_save(&self) -> bool {
+        false
+    }
+
+    fn priority(&self) -> i32 {
+        self.priority as i32
+    }
+}
+
+/// Verifies a base64-encoded detached Ed25519 signature.
+pub(crate) fn verify_signature(
+    public_key: &str,
+    content: &[u8],
+    signature: &str,
+) -> Result<(), String> {
+    let public_key =
+        BASE64.decode(public_key.trim()).map_err(|e| format!("invalid public key: {e}"))?;
+    let public_key = public_key.strip_prefix(&ED25519_SPKI_PREFIX[..]).unwrap_or(&public_key);
+    let signature =
+        BASE64.decode(signature.trim()).map_err(|e| format!("invalid signature encoding: {e}"))?;
+
+    signature::UnparsedPublicKey::new(&signature::ED25519, public_key)
+        .verify(content, &signature)
+        .map_err(|_| "signature does not match the document".to_string())
+}
+
+/// Builds the Signature Version 4 `Authorization` header of an S3 GET request.
+pub(crate) fn s3_authorization(
+    host: &str,
+    canonical_uri: &str,
+    region: &str,
+    credentials: &S3Credentials,
+    amz_date: &str,
+) -> String {
+    let date = amz_date.get(..8).unwrap_or(amz_date);
+
+    let mut canonical_headers = format!(
+        "host:{host}\nx-amz-content-sha256:{EMPTY_PAYLOAD_SHA256}\nx-amz-date:{amz_date}\n"
+    );
+    let mut signed_headers = "host;x-amz-content-sha256;x-amz-date".to_string();
+    if let Some(token) = &credentials.session_token {
+        let _ = writeln!(canonical_headers, "x-amz-security-token:{token}");
+        signed_headers.push_str(";x-amz-security-token");
+    }
+
+    let canonical_request = format!(
+        "GET\n{canonical_uri}\n\n{canonical_headers}\n{signed_headers}\n{EMPTY_PAYLOAD_SHA256}"
+    );
+    let scope = format!("{date}/{region}/s3/aws4_request");
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex(digest::digest(&digest::SHA256, canonical_request.as_bytes()).as_ref())
+    );
+
+    let key = signing_key(&credentials.secret_access_key, date, region, "s3");
+    let signature = hex(&hmac_sha256(&key, string_to_sign.as_bytes()));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        credentials.access_key_id
+    )
+}
+
+/// Derives the Signature Version 4 signing key.
+pub(crate) fn signing_key(secret: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac_sha256(format!("AWS4{secret}").as_bytes(), date.as_bytes());
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), data).as_ref().to_vec()
+}
+
+/// Percent-encodes a path segment, keeping RFC 3986 unreserved characters.
+fn uri_encode(segment: &str) -> String {
+    segment.bytes().fold(String::new(), |mut encoded, byte| {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+        encoded
+    })
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    })
+}
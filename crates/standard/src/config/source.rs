@@ -1,7 +1,7 @@
 //! Configuration sources.
 //!
 //! This module defines different sources from which configuration can be loaded,
-//! such as files, environment variables, and defaults.
+//! such as files, remote documents, environment variables, and defaults.
 
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -11,6 +11,7 @@ use crate::error::{ConfigError, ConfigResult};
 use crate::filesystem::AsyncFileSystem;
 
 use super::format::ConfigFormat;
+use super::remote::RemoteConfig;
 use super::traits::ConfigProvider;
 use super::value::ConfigValue;
 
@@ -64,6 +65,13 @@ pub enum ConfigSource {
         /// Priority of this source
         priority: ConfigSourcePriority,
     },
+    /// Configuration fetched from an HTTP(S) URL or S3
+    Remote {
+        /// Location and trust settings of the document
+        config: RemoteConfig,
+        /// Priority of this source
+        priority: ConfigSourcePriority,
+    },
 }
 
 impl ConfigSource {
@@ -171,6 +179,27 @@ impl ConfigSource {
         Self::Memory { values, priority }
     }
 
+    /// Creates a new remote configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Location and trust settings of the document
+    /// * `priority` - Priority of this source
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::config::{ConfigSource, ConfigSourcePriority, RemoteConfig};
+    ///
+    /// let source = ConfigSource::remote(
+    ///     RemoteConfig::new("https://config.example.com/workspace.toml"),
+    ///     ConfigSourcePriority::Global,
+    /// );
+    /// ```
+    pub fn remote(config: RemoteConfig, priority: ConfigSourcePriority) -> Self {
+        Self::Remote { config, priority }
+    }
+
     /// Gets the priority of this source.
     #[must_use]
     #[allow(clippy::match_same_arms)] // Each variant has its own priority field
@@ -180,6 +209,7 @@ impl ConfigSource {
             Self::Environment { priority, .. } => *priority,
             Self::Default { priority, .. } => *priority,
             Self::Memory { priority, .. } => *priority,
+            Self::Remote { priority, .. } => *priority,
         }
     }
 }
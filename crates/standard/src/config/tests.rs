@@ -0,0 +1,259 @@
+//! # Configuration Module Tests
+//!
+//! ## What
+//! Tests for remote configuration: S3 request signing, signature verification, caching,
+//! and merging remote documents under local files.
+//!
+//! ## How
+//! Remote documents are served by a local mock HTTP server and cached in temporary
+//! directories. Signatures are produced with a fixed Ed25519 key pair.
+//!
+//! ## Why
+//! Remote configuration carries organization-wide policy, so a tampered or unreachable
+//! document must never be applied silently.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde::{Deserialize, Serialize};
+use tempfile::TempDir;
+
+use super::remote::{S3Credentials, hex, s3_authorization, signing_key, verify_signature};
+use super::*;
+use crate::filesystem::FileSystemManager;
+use crate::network::TransportSettings;
+
+const DOCUMENT: &str = "name = \"acme\"\ntimeout = 5\n";
+
+fn key_pair() -> Ed25519KeyPair {
+    Ed25519KeyPair::from_seed_unchecked(&[7u8; 32]).unwrap()
+}
+
+fn sign(content: &str) -> String {
+    BASE64.encode(key_pair().sign(content.as_bytes()).as_ref())
+}
+
+fn public_key() -> String {
+    BASE64.encode(key_pair().public_key().as_ref())
+}
+
+fn remote(server: &mockito::Server, cache: &TempDir) -> RemoteConfig {
+    let mut config = RemoteConfig::new(format!("{}/policy.toml", server.url()));
+    config.cache_dir = Some(cache.path().to_path_buf());
+    config
+}
+
+async fn load(config: RemoteConfig) -> ConfigResult<ConfigValue> {
+    RemoteProvider::new(config, ConfigSourcePriority::Global, FileSystemManager::new()).load().await
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct PolicyConfig {
+    name: String,
+    timeout: u64,
+}
+
+impl Configurable for PolicyConfig {
+    fn validate(&self) -> ConfigResult<()> {
+        Ok(())
+    }
+
+    fn merge_with(&mut self, other: Self) -> ConfigResult<()> {
+        *self = other;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_signing_key_matches_aws_example() {
+    let key =
+        signing_key("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY", "20120215", "us-east-1", "iam");
+    assert_eq!(hex(&key), "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d");
+}
+
+#[test]
+fn test_s3_authorization() {
+    let credentials = S3Credentials {
+        access_key_id: "AKIDEXAMPLE".to_string(),
+        secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+        session_token: None,
+    };
+
+    let authorization = s3_authorization(
+        "policies.s3.eu-west-1.amazonaws.com",
+        "/org/workspace.toml",
+        "eu-west-1",
+        &credentials,
+        "20240101T000000Z",
+    );
+
+    assert_eq!(
+        authorization,
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20240101/eu-west-1/s3/aws4_request, \
+         SignedHeaders=host;x-amz-content-sha256;x-amz-date, \
+         Signature=0536edfec4b3c800d742da783652364e8f64abfed5c0ab8cfd140651a7009c2d"
+    );
+}
+
+#[test]
+fn test_verify_signature() {
+    let signature = sign(DOCUMENT);
+    assert!(verify_signature(&public_key(), DOCUMENT.as_bytes(), &signature).is_ok());
+
+    let mut der = vec![0x30, 0x2a, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x03, 0x21, 0x00];
+    der.extend_from_slice(key_pair().public_key().as_ref());
+    assert!(verify_signature(&BASE64.encode(der), DOCUMENT.as_bytes(), &signature).is_ok());
+
+    assert!(verify_signature(&public_key(), b"name = \"evil\"", &signature).is_err());
+    assert!(verify_signature(&public_key(), DOCUMENT.as_bytes(), "not base64!").is_err());
+}
+
+#[test]
+fn test_remote_config_format() {
+    assert_eq!(
+        RemoteConfig::new("https://example.com/policy.json?v=2").resolve_format().unwrap(),
+        ConfigFormat::Json
+    );
+    assert_eq!(
+        RemoteConfig::new("s3://bucket/policy.toml").resolve_format().unwrap(),
+        ConfigFormat::Toml
+    );
+
+    let mut config = RemoteConfig::new("https://example.com/policy");
+    assert!(config.resolve_format().is_err());
+    config.format = Some("yaml".to_string());
+    assert_eq!(config.resolve_format().unwrap(), ConfigFormat::Yaml);
+
+    assert_eq!(config.signature_location(), "https://example.com/policy.sig");
+}
+
+#[tokio::test]
+async fn test_remote_provider_fetches_and_caches() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    let mock =
+        server.mock("GET", "/policy.toml").with_body(DOCUMENT).expect(1).create_async().await;
+
+    let value = load(remote(&server, &cache)).await.unwrap();
+    assert_eq!(value.get("name").and_then(ConfigValue::as_string), Some("acme"));
+
+    // Served from the cache within the TTL
+    let value = load(remote(&server, &cache)).await.unwrap();
+    assert_eq!(value.get("timeout").and_then(ConfigValue::as_integer), Some(5));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_remote_provider_uses_stale_cache_when_unreachable() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    let mut config = remote(&server, &cache);
+    config.cache_ttl_secs = 0;
+
+    let ok = server.mock("GET", "/policy.toml").with_body(DOCUMENT).create_async().await;
+    assert!(load(config.clone()).await.is_ok());
+    ok.remove_async().await;
+
+    server.mock("GET", "/policy.toml").with_status(503).create_async().await;
+    let value = load(config).await.unwrap();
+    assert_eq!(value.get("name").and_then(ConfigValue::as_string), Some("acme"));
+}
+
+#[tokio::test]
+async fn test_remote_provider_fails_without_cache() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    server.mock("GET", "/policy.toml").with_status(404).create_async().await;
+
+    let result = load(remote(&server, &cache)).await;
+    assert!(matches!(result, Err(ConfigError::RemoteFetchError { .. })));
+}
+
+#[tokio::test]
+async fn test_remote_provider_fetches_through_proxy() {
+    let mut proxy = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    let mock = proxy.mock("GET", mockito::Matcher::Any).with_body(DOCUMENT).create_async().await;
+    let mut config = RemoteConfig::new("http://config.invalid/policy.toml");
+    config.cache_dir = Some(cache.path().to_path_buf());
+    let transport = TransportSettings { proxy: Some(proxy.url()), ..TransportSettings::default() };
+
+    let value = RemoteProvider::new(config, ConfigSourcePriority::Global, FileSystemManager::new())
+        .with_transport(transport)
+        .load()
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(value.get("name").and_then(ConfigValue::as_string), Some("acme"));
+}
+
+#[tokio::test]
+async fn test_remote_provider_rejects_missing_ca_file() {
+    let server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    let transport = TransportSettings {
+        ca_file: Some(cache.path().join("missing.pem")),
+        ..TransportSettings::default()
+    };
+
+    let result = RemoteProvider::new(
+        remote(&server, &cache),
+        ConfigSourcePriority::Global,
+        FileSystemManager::new(),
+    )
+    .with_transport(transport)
+    .load()
+    .await;
+
+    let Err(ConfigError::ValidationError { message }) = result else {
+        panic!("Expected a validation error, got {result:?}");
+    };
+    assert!(message.contains("missing.pem"));
+}
+
+#[tokio::test]
+async fn test_remote_provider_verifies_signature() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    let mut config = remote(&server, &cache);
+    config.public_key = Some(public_key());
+
+    server.mock("GET", "/policy.toml").with_body(DOCUMENT).create_async().await;
+    let signature =
+        server.mock("GET", "/policy.toml.sig").with_body(sign(DOCUMENT)).create_async().await;
+    assert!(load(config.clone()).await.is_ok());
+    signature.remove_async().await;
+
+    // A signature made for another document is rejected, and nothing is cached
+    let other_cache = TempDir::new().unwrap();
+    config.cache_dir = Some(other_cache.path().to_path_buf());
+    server.mock("GET", "/policy.toml.sig").with_body(sign("name = \"other\"")).create_async().await;
+    let result = load(config).await;
+    assert!(matches!(result, Err(ConfigError::SignatureError { .. })));
+    assert_eq!(std::fs::read_dir(other_cache.path()).map(Iterator::count).unwrap_or(0), 0);
+}
+
+#[tokio::test]
+async fn test_remote_source_merges_under_local_file() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = TempDir::new().unwrap();
+    server.mock("GET", "/policy.toml").with_body(DOCUMENT).create_async().await;
+
+    let local = cache.path().join("local.toml");
+    std::fs::write(&local, "timeout = 30\n").unwrap();
+
+    let manager = ConfigManager::<PolicyConfig>::builder()
+        .with_remote(remote(&server, &cache))
+        .with_file(&local)
+        .build(FileSystemManager::new())
+        .unwrap();
+    let config = manager.load().await.unwrap();
+
+    assert_eq!(config.name, "acme");
+    assert_eq!(config.timeout, 30);
+}
@@ -0,0 +1,222 @@
+//! # Deadline Module
+//!
+//! ## What
+//! This module provides `Deadline`, a point in time shared by every operation of a run
+//! together with a cancellation token, and the process-wide deadline set from the CLI's
+//! `--timeout`.
+//!
+//! ## How
+//! `set_global_deadline` installs the process deadline and `global_deadline` hands out
+//! clones of it, so cancelling one cancels all. Operations with their own timeout (registry
+//! requests, commands, audit checks) keep it and run inside the deadline, and
+//! `Deadline::child` combines both into one that ends at whichever comes first. `Deadline::run` races a future against the deadline and its token
+//! and returns `DeadlineExceeded` when the deadline wins; when time runs out it also
+//! cancels the token, so sibling operations stop without waiting for their own timers.
+//!
+//! ## Why
+//! A single hung network call must not stall a CI job indefinitely. Per-request timeouts
+//! only bound one attempt; retries and many sequential requests can still add up. A shared
+//! deadline bounds the whole run and lets each operation report what it finished before
+//! time ran out.
+
+use crate::error::DeadlineExceeded;
+use std::future::Future;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+#[cfg(test)]
+mod tests;
+
+/// Process-wide deadline, unset unless a timeout was requested.
+static GLOBAL_DEADLINE: RwLock<Option<Deadline>> = RwLock::new(None);
+
+/// Sets or clears the process-wide deadline.
+///
+/// `Some(timeout)` starts a new deadline that ends `timeout` from now; `None` removes it.
+/// Deadlines already handed out by `global_deadline` are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use sublime_standard_tools::deadline::{global_deadline, set_global_deadline};
+///
+/// set_global_deadline(Some(Duration::from_secs(60)));
+/// assert!(global_deadline().remaining().is_some());
+/// set_global_deadline(None);
+/// assert!(global_deadline().remaining().is_none());
+/// ```
+pub fn set_global_deadline(timeout: Option<Duration>) {
+    let deadline = timeout.map(Deadline::after);
+    match GLOBAL_DEADLINE.write() {
+        Ok(mut global) => *global = deadline,
+        Err(poisoned) => *poisoned.into_inner() = deadline,
+    }
+}
+
+/// Returns the process-wide deadline, or an unbounded one if none is set.
+///
+/// Every call returns a handle to the same deadline and token.
+#[must_use]
+pub fn global_deadline() -> Deadline {
+    let global = match GLOBAL_DEADLINE.read() {
+        Ok(global) => global.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    };
+    global.unwrap_or_default()
+}
+
+/// A point in time after which operations stop, with a token to stop them earlier.
+///
+/// Clones share the token: cancelling one cancels all of them and their children.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use sublime_standard_tools::deadline::Deadline;
+///
+/// # async fn example() {
+/// let deadline = Deadline::after(Duration::from_millis(10));
+/// let result = deadline
+///     .run("sleep", tokio::time::sleep(Duration::from_secs(60)))
+///     .await;
+///
+/// assert_eq!(result.unwrap_err().operation, "sleep");
+/// assert!(deadline.is_expired());
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Deadline {
+    /// When the deadline passes, `None` if it never does.
+    expires_at: Option<Instant>,
+
+    /// Cancelled when the deadline passes or on request.
+    token: CancellationToken,
+}
+
+impl Deadline {
+    /// Creates a deadline that never passes on its own.
+    #[must_use]
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Creates a deadline that passes `timeout` from now.
+    #[must_use]
+    pub fn after(timeout: Duration) -> Self {
+        Self { expires_at: Instant::now().checked_add(timeout), token: CancellationToken::new() }
+    }
+
+    /// Derives the deadline of an operation with its own timeout.
+    ///
+    /// The child passes at whichever comes first, this deadline or `timeout` from now, and
+    /// is cancelled with this deadline. Cancelling the child does not cancel its parent.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The operation's own timeout, `None` to inherit this deadline only
+    #[must_use]
+    pub fn child(&self, timeout: Option<Duration>) -> Self {
+        let own = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let expires_at = match (self.expires_at, own) {
+            (Some(parent), Some(own)) => Some(parent.min(own)),
+            (parent, own) => parent.or(own),
+        };
+        Self { expires_at, token: self.token.child_token() }
+    }
+
+    /// Returns when the deadline passes, if it ever does.
+    #[must_use]
+    pub fn expires_at(&self) -> Option<Instant> {
+        self.expires_at
+    }
+
+    /// Returns the time left, `None` if the deadline never passes.
+    ///
+    /// Returns `Duration::ZERO` once the deadline has passed or was cancelled.
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        if self.token.is_cancelled() {
+            return Some(Duration::ZERO);
+        }
+        self.expires_at.map(|at| at.saturating_duration_since(Instant::now()))
+    }
+
+    /// Bounds an operation's own timeout by the time left.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The operation's own timeout
+    #[must_use]
+    pub fn bound(&self, timeout: Duration) -> Duration {
+        self.remaining().map_or(timeout, |remaining| remaining.min(timeout))
+    }
+
+    /// Returns whether the deadline has passed or was cancelled.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.token.is_cancelled() || self.expires_at.is_some_and(|at| Instant::now() >= at)
+    }
+
+    /// Cancels the deadline, its clones, and their children.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Fails if the deadline has passed or was cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Name of the operation about to start, used in the error
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeadlineExceeded` if the deadline has passed or was cancelled.
+    pub fn check(&self, operation: &str) -> Result<(), DeadlineExceeded> {
+        if self.is_expired() {
+            self.token.cancel();
+            return Err(DeadlineExceeded::new(operation));
+        }
+        Ok(())
+    }
+
+    /// Runs a future until it completes or the deadline passes.
+    ///
+    /// When time runs out the token is cancelled, so every operation sharing it stops too.
+    /// The future is dropped, which cancels whatever it was waiting on.
+    ///
+    /// # Arguments
+    ///
+    /// * `operation` - Name of the operation, used in the error
+    /// * `future` - The work to bound
+    ///
+    /// # Errors
+    ///
+    /// Returns `DeadlineExceeded` if the deadline passes or is cancelled first.
+    pub async fn run<F: Future>(
+        &self,
+        operation: &str,
+        future: F,
+    ) -> Result<F::Output, DeadlineExceeded> {
+        self.check(operation)?;
+
+        let expiry = async {
+            match self.expires_at {
+                Some(at) => tokio::time::sleep_until(at.into()).await,
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            output = future => Ok(output),
+            () = self.token.cancelled() => Err(DeadlineExceeded::new(operation)),
+            () = expiry => {
+                self.token.cancel();
+                Err(DeadlineExceeded::new(operation))
+            }
+        }
+    }
+}
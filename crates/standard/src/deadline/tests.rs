@@ -0,0 +1,107 @@
+//! # Deadline Module Tests
+//!
+//! ## What
+//! Unit tests for `Deadline`: expiry, child deadlines, cancellation, and bounding futures.
+//!
+//! ## How
+//! Deadlines are created with short timeouts and raced against futures that finish either
+//! well before or well after them. The process-wide deadline is not touched, so tests can
+//! run in parallel.
+//!
+//! ## Why
+//! Every network, git, and command operation relies on these semantics to stop on time
+//! and to stop together.
+
+#[allow(clippy::unwrap_used)]
+#[allow(clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use crate::deadline::Deadline;
+    use std::time::Duration;
+
+    #[test]
+    fn test_unbounded_deadline_never_expires() {
+        let deadline = Deadline::none();
+
+        assert!(!deadline.is_expired());
+        assert_eq!(deadline.remaining(), None);
+        assert_eq!(deadline.bound(Duration::from_secs(5)), Duration::from_secs(5));
+        assert!(deadline.check("noop").is_ok());
+    }
+
+    #[test]
+    fn test_child_ends_at_the_earlier_deadline() {
+        let parent = Deadline::after(Duration::from_secs(60));
+
+        let shorter = parent.child(Some(Duration::from_secs(1)));
+        assert!(shorter.remaining().unwrap() <= Duration::from_secs(1));
+
+        let longer = parent.child(Some(Duration::from_secs(600)));
+        assert_eq!(longer.expires_at(), parent.expires_at());
+
+        let inherited = parent.child(None);
+        assert_eq!(inherited.expires_at(), parent.expires_at());
+
+        let own = Deadline::none().child(Some(Duration::from_secs(1)));
+        assert!(own.expires_at().is_some());
+    }
+
+    #[test]
+    fn test_cancel_reaches_clones_and_children_only() {
+        let parent = Deadline::none();
+        let clone = parent.clone();
+        let child = parent.child(None);
+
+        child.cancel();
+        assert!(child.is_expired());
+        assert!(!parent.is_expired());
+
+        let child = parent.child(None);
+        clone.cancel();
+        assert!(parent.is_expired());
+        assert!(child.is_expired());
+        assert_eq!(parent.remaining(), Some(Duration::ZERO));
+        assert_eq!(parent.check("fetch").unwrap_err().operation, "fetch");
+    }
+
+    #[tokio::test]
+    async fn test_run_returns_output_before_deadline() {
+        let deadline = Deadline::after(Duration::from_secs(5));
+
+        let output = deadline.run("quick", async { 42 }).await;
+
+        assert_eq!(output, Ok(42));
+        assert!(!deadline.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_run_stops_at_deadline_and_cancels_siblings() {
+        let deadline = Deadline::after(Duration::from_millis(20));
+        let sibling = deadline.clone();
+
+        let result = deadline.run("slow", tokio::time::sleep(Duration::from_secs(60))).await;
+
+        assert_eq!(result.unwrap_err().operation, "slow");
+        assert!(sibling.is_expired());
+
+        // Later operations fail without running
+        let result = sibling.run("next", async { 1 }).await;
+        assert_eq!(result.unwrap_err().operation, "next");
+    }
+
+    #[tokio::test]
+    async fn test_run_stops_when_cancelled() {
+        let deadline = Deadline::none();
+        let canceller = deadline.clone();
+
+        let (result, ()) = tokio::join!(
+            deadline.run("wait", tokio::time::sleep(Duration::from_secs(60))),
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                canceller.cancel();
+            }
+        );
+
+        assert!(result.is_err());
+    }
+}
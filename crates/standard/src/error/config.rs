@@ -109,6 +109,24 @@ pub enum ConfigError {
         message: String,
     },
 
+    /// Remote configuration could not be fetched.
+    #[error("Failed to fetch remote configuration '{url}': {message}")]
+    RemoteFetchError {
+        /// URL of the remote configuration.
+        url: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// Remote configuration failed signature verification.
+    #[error("Signature verification failed for remote configuration '{url}': {message}")]
+    SignatureError {
+        /// URL of the remote configuration.
+        url: String,
+        /// Error message.
+        message: String,
+    },
+
     /// Generic configuration error.
     #[error("Configuration error: {0}")]
     Other(String),
@@ -187,6 +205,8 @@ impl AsRef<str> for ConfigError {
             ConfigError::KeyNotFound { .. } => "ConfigError::KeyNotFound",
             ConfigError::MergeConflict { .. } => "ConfigError::MergeConflict",
             ConfigError::ProviderError { .. } => "ConfigError::ProviderError",
+            ConfigError::RemoteFetchError { .. } => "ConfigError::RemoteFetchError",
+            ConfigError::SignatureError { .. } => "ConfigError::SignatureError",
             ConfigError::Other(_) => "ConfigError::Other",
         }
     }
@@ -0,0 +1,43 @@
+//! # Deadline error type
+//!
+//! ## What
+//! This module defines `DeadlineExceeded`, the error returned by operations stopped because
+//! the process deadline (or their own, shorter one) passed or was cancelled.
+//!
+//! ## How
+//! `Deadline::run` and `Deadline::check` return it with the name of the interrupted
+//! operation. Dependent crates wrap it in their own error types so callers can tell a
+//! deadline apart from an ordinary timeout or failure.
+//!
+//! ## Why
+//! CI jobs bounded by `--timeout` need to distinguish "ran out of time" from "broke", and
+//! report which operation was cut short.
+
+use thiserror::Error as ThisError;
+
+/// An operation was stopped because its deadline passed or was cancelled.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::error::{DeadlineExceeded, Error};
+///
+/// let error = DeadlineExceeded::new("registry lookup");
+/// assert_eq!(error.to_string(), "Operation 'registry lookup' exceeded its deadline");
+///
+/// let general_error: Error = error.into();
+/// assert_eq!(general_error.as_ref(), "Error::DeadlineExceeded");
+/// ```
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("Operation '{operation}' exceeded its deadline")]
+pub struct DeadlineExceeded {
+    /// The operation that was stopped
+    pub operation: String,
+}
+
+impl DeadlineExceeded {
+    /// Creates a deadline error for an operation.
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self { operation: operation.into() }
+    }
+}
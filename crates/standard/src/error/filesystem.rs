@@ -91,6 +91,22 @@ pub enum FileSystemError {
     /// Operation failed (e.g., timeout, concurrency limit exceeded).
     #[error("Operation failed: {0}")]
     Operation(String),
+
+    /// Attempted to write while read-only mode is active.
+    #[error("Write to '{path}' blocked: filesystem is in read-only mode")]
+    ReadOnly {
+        /// The path that would have been modified.
+        path: PathBuf,
+    },
+
+    /// Failed to watch a path for changes.
+    #[error("Failed to watch '{path}': {message}")]
+    Watch {
+        /// The path being watched
+        path: PathBuf,
+        /// The watcher error message
+        message: String,
+    },
 }
 
 impl FileSystemError {
@@ -137,6 +153,8 @@ impl AsRef<str> for FileSystemError {
             FileSystemError::Utf8Decode { .. } => "FileSystemError::Utf8Decode",
             FileSystemError::Validation { .. } => "FileSystemError::Validation",
             FileSystemError::Operation(_) => "FileSystemError::Operation",
+            FileSystemError::ReadOnly { .. } => "FileSystemError::ReadOnly",
+            FileSystemError::Watch { .. } => "FileSystemError::Watch",
         }
     }
 }
@@ -18,6 +18,7 @@
 
 mod command;
 mod config;
+mod deadline;
 mod filesystem;
 mod monorepo;
 mod recovery;
@@ -33,6 +34,7 @@ use thiserror::Error as ThisError;
 // Re-export error types
 pub use command::{CommandError, CommandResult};
 pub use config::{ConfigError, ConfigResult};
+pub use deadline::DeadlineExceeded;
 pub use filesystem::FileSystemError;
 pub use monorepo::{MonorepoError, MonorepoResult};
 pub use recovery::{ErrorRecoveryManager, LogLevel, RecoveryResult, RecoveryStrategy};
@@ -95,7 +97,7 @@ pub enum Error {
     #[error("Monorepo execution error")]
     Monorepo(#[from] MonorepoError),
     /// Filesystem-related error.
-    #[error("FileSystem execution error")]
+    #[error("FileSystem execution error: {0}")]
     FileSystem(#[from] FileSystemError),
     /// Workspace-related error.
     #[error("Workspace execution error")]
@@ -106,6 +108,9 @@ pub enum Error {
     /// Configuration-related error.
     #[error("Configuration error")]
     Config(#[from] ConfigError),
+    /// An operation ran past its deadline.
+    #[error("{0}")]
+    DeadlineExceeded(#[from] DeadlineExceeded),
     /// General purpose errors with a custom message.
     #[error("Operation error: {0}")]
     Operation(String),
@@ -126,6 +131,7 @@ impl AsRef<str> for Error {
             Error::Workspace(_) => "Error::Workspace",
             Error::Command(_) => "Error::Command",
             Error::Config(_) => "Error::Config",
+            Error::DeadlineExceeded(_) => "Error::DeadlineExceeded",
             Error::Operation(_) => "Error::Operation",
         }
     }
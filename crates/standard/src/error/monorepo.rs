@@ -66,6 +66,14 @@ pub enum MonorepoError {
     /// Failed to find a package manager for the monorepo.
     #[error("Failed to find package manager")]
     ManagerNotFound,
+    /// A workspace pattern is not a valid glob.
+    #[error("Invalid workspace pattern '{pattern}': {reason}")]
+    InvalidPattern {
+        /// The pattern as declared
+        pattern: String,
+        /// Why the pattern is invalid
+        reason: String,
+    },
 }
 
 /// Result type for monorepo operations.
@@ -96,6 +104,7 @@ impl AsRef<str> for MonorepoError {
             MonorepoError::Reading { .. } => "MonorepoError::Reading",
             MonorepoError::Writing { .. } => "MonorepoError::Writing",
             MonorepoError::ManagerNotFound => "MonorepoError::ManagerNotFound",
+            MonorepoError::InvalidPattern { .. } => "MonorepoError::InvalidPattern",
         }
     }
 }
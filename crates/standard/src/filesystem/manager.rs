@@ -15,12 +15,14 @@
 //! thousands of files need to be processed. This unified async-only approach eliminates
 //! confusion and provides the foundation for concurrent operations.
 
+use super::read_only::ensure_writable;
 use super::types::{AsyncFileSystem, AsyncFileSystemConfig};
 use crate::config::{ConfigManager, StandardConfig, traits::Configurable};
 use crate::error::{Error, FileSystemError, Result};
 use async_trait::async_trait;
 use std::{
     path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
     time::Duration,
 };
 use tokio::{fs, time::timeout};
@@ -346,6 +348,8 @@ impl AsyncFileSystem for FileSystemManager {
     }
 
     async fn write_file(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        ensure_writable(path)?;
+
         let operation = async {
             // Create parent directories if they don't exist
             if let Some(parent) = path.parent()
@@ -376,6 +380,8 @@ impl AsyncFileSystem for FileSystemManager {
     }
 
     async fn write_file_string(&self, path: &Path, contents: &str) -> Result<()> {
+        ensure_writable(path)?;
+
         let operation = async {
             // Create parent directories if they don't exist
             if let Some(parent) = path.parent()
@@ -394,7 +400,40 @@ impl AsyncFileSystem for FileSystemManager {
         self.with_timeout(operation, self.config.write_timeout).await
     }
 
+    async fn write_file_atomic(&self, path: &Path, contents: &str) -> Result<()> {
+        ensure_writable(path)?;
+
+        let operation = async {
+            if let Some(parent) = path.parent()
+                && !self.exists(parent).await
+            {
+                self.create_dir_all(parent).await?;
+            }
+
+            let temp_path = atomic_temp_path(path);
+            fs::write(&temp_path, contents)
+                .await
+                .map_err(|e| Error::FileSystem(FileSystemError::from_io(e, &temp_path)))?;
+
+            // Keep the permissions of the file being replaced
+            if let Ok(metadata) = fs::metadata(path).await {
+                let _ = fs::set_permissions(&temp_path, metadata.permissions()).await;
+            }
+
+            if let Err(e) = fs::rename(&temp_path, path).await {
+                let _ = fs::remove_file(&temp_path).await;
+                return Err(Error::FileSystem(FileSystemError::from_io(e, path)));
+            }
+
+            Ok(())
+        };
+
+        self.with_timeout(operation, self.config.write_timeout).await
+    }
+
     async fn create_dir_all(&self, path: &Path) -> Result<()> {
+        ensure_writable(path)?;
+
         let operation = async {
             fs::create_dir_all(path)
                 .await
@@ -407,6 +446,8 @@ impl AsyncFileSystem for FileSystemManager {
     }
 
     async fn remove(&self, path: &Path) -> Result<()> {
+        ensure_writable(path)?;
+
         let operation = async {
             self.validate_path(path).await?;
 
@@ -527,3 +568,16 @@ impl FileSystemManager {
         })
     }
 }
+
+/// Counter keeping the temporary files of concurrent atomic writes apart.
+static ATOMIC_WRITE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Returns a hidden temporary path next to `path` for an atomic write.
+///
+/// The temporary file lives in the same directory so the final rename never crosses
+/// filesystems.
+fn atomic_temp_path(path: &Path) -> PathBuf {
+    let name = path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default();
+    let id = ATOMIC_WRITE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{name}.{}.{id}.tmp", std::process::id()))
+}
@@ -7,7 +7,9 @@
 //! ## How
 //! The module exposes an `AsyncFileSystem` trait that defines async filesystem operations
 //! and a concrete `FileSystemManager` implementation that performs real filesystem
-//! operations using tokio::fs for maximum performance.
+//! operations using tokio::fs for maximum performance. A process-wide read-only mode and the
+//! `ReadOnlyFileSystem` wrapper reject writes for side-effect free runs. `FileWatcher` reports
+//! debounced, glob-filtered batches of changes under a directory.
 //!
 //! ## Why
 //! Async filesystem operations are essential for performance in large repositories.
@@ -16,10 +18,14 @@
 
 mod manager;
 mod paths;
+mod read_only;
 mod types;
+mod watcher;
 
 #[cfg(test)]
 mod tests;
 
 pub use manager::FileSystemManager;
+pub use read_only::{ReadOnlyFileSystem, is_read_only_mode, set_read_only_mode};
 pub use types::{AsyncFileSystem, AsyncFileSystemConfig, NodePathKind, PathExt, PathUtils};
+pub use watcher::{FileChange, FileChangeKind, FileWatcher, FileWatcherConfig};
@@ -0,0 +1,147 @@
+//! # Read-Only Filesystem Guard
+//!
+//! ## What
+//! This module provides a process-wide read-only mode and a `ReadOnlyFileSystem` wrapper
+//! that reject every filesystem write with `FileSystemError::ReadOnly`, naming the path
+//! that would have been modified.
+//!
+//! ## How
+//! `set_read_only_mode` flips a process-wide flag checked by every write operation of
+//! `FileSystemManager`, so code that creates its own manager is covered too.
+//! `ReadOnlyFileSystem` wraps any `AsyncFileSystem` and is always read-only, independent
+//! of the process-wide flag.
+//!
+//! ## Why
+//! CI consumers running inspection commands (plans, audits, change reports) in locked-down
+//! environments need proof that those commands have no side effects. Failing loudly on the
+//! first attempted write turns an accidental mutation into a test failure.
+
+use super::AsyncFileSystem;
+use crate::error::{Error, FileSystemError, Result};
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide read-only flag checked by `FileSystemManager` writes.
+static READ_ONLY_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the process-wide read-only mode.
+///
+/// While enabled, every write, directory creation, and removal performed through
+/// `FileSystemManager` fails with `FileSystemError::ReadOnly`.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::filesystem::{is_read_only_mode, set_read_only_mode};
+///
+/// set_read_only_mode(true);
+/// assert!(is_read_only_mode());
+/// set_read_only_mode(false);
+/// ```
+pub fn set_read_only_mode(enabled: bool) {
+    READ_ONLY_MODE.store(enabled, Ordering::SeqCst);
+}
+
+/// Returns whether the process-wide read-only mode is enabled.
+#[must_use]
+pub fn is_read_only_mode() -> bool {
+    READ_ONLY_MODE.load(Ordering::SeqCst)
+}
+
+/// Fails with `FileSystemError::ReadOnly` if read-only mode is enabled.
+pub(crate) fn ensure_writable(path: &Path) -> Result<()> {
+    if is_read_only_mode() {
+        return Err(read_only_error(path));
+    }
+    Ok(())
+}
+
+fn read_only_error(path: &Path) -> Error {
+    Error::FileSystem(FileSystemError::ReadOnly { path: path.to_path_buf() })
+}
+
+/// Filesystem wrapper that allows reads and rejects every write.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::filesystem::{AsyncFileSystem, FileSystemManager, ReadOnlyFileSystem};
+/// use std::path::Path;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let fs = ReadOnlyFileSystem::new(FileSystemManager::new());
+///
+/// let manifest = fs.read_file_string(Path::new("package.json")).await?;
+/// assert!(fs.write_file_string(Path::new("package.json"), &manifest).await.is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReadOnlyFileSystem<F: AsyncFileSystem> {
+    inner: F,
+}
+
+impl<F: AsyncFileSystem> ReadOnlyFileSystem<F> {
+    /// Wraps a filesystem so that all writes are rejected.
+    #[must_use]
+    pub fn new(inner: F) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped filesystem.
+    #[must_use]
+    pub fn inner(&self) -> &F {
+        &self.inner
+    }
+}
+
+#[async_trait]
+impl<F: AsyncFileSystem> AsyncFileSystem for ReadOnlyFileSystem<F> {
+    async fn read_file(&self, path: &Path) -> Result<Vec<u8>> {
+        self.inner.read_file(path).await
+    }
+
+    async fn write_file(&self, path: &Path, _contents: &[u8]) -> Result<()> {
+        Err(read_only_error(path))
+    }
+
+    async fn read_file_string(&self, path: &Path) -> Result<String> {
+        self.inner.read_file_string(path).await
+    }
+
+    async fn write_file_string(&self, path: &Path, _contents: &str) -> Result<()> {
+        Err(read_only_error(path))
+    }
+
+    async fn write_file_atomic(&self, path: &Path, _contents: &str) -> Result<()> {
+        Err(read_only_error(path))
+    }
+
+    async fn create_dir_all(&self, path: &Path) -> Result<()> {
+        Err(read_only_error(path))
+    }
+
+    async fn remove(&self, path: &Path) -> Result<()> {
+        Err(read_only_error(path))
+    }
+
+    async fn exists(&self, path: &Path) -> bool {
+        self.inner.exists(path).await
+    }
+
+    async fn read_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.inner.read_dir(path).await
+    }
+
+    async fn walk_dir(&self, path: &Path) -> Result<Vec<PathBuf>> {
+        self.inner.walk_dir(path).await
+    }
+
+    async fn metadata(&self, path: &Path) -> Result<std::fs::Metadata> {
+        self.inner.metadata(path).await
+    }
+    async fn is_dir(&self, path: &Path) -> bool {
+        self.inner.is_dir(path).await
+    }
+}
@@ -20,7 +20,12 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tempfile::TempDir;
 
-use crate::filesystem::{AsyncFileSystem, FileSystemManager, NodePathKind, PathExt, PathUtils};
+use crate::error::{Error, FileSystemError};
+use crate::filesystem::watcher::coalesce;
+use crate::filesystem::{
+    AsyncFileSystem, FileChange, FileChangeKind, FileSystemManager, FileWatcher, FileWatcherConfig,
+    NodePathKind, PathExt, PathUtils, ReadOnlyFileSystem,
+};
 
 #[allow(clippy::expect_used)]
 #[allow(clippy::unwrap_used)]
@@ -97,6 +102,39 @@ mod tests {
         assert_eq!(read_result.unwrap(), test_content);
     }
 
+    #[tokio::test]
+    async fn test_write_file_atomic_replaces_contents() {
+        let temp_dir = setup_test_dir();
+        let fs = FileSystemManager::new();
+
+        let test_path = temp_dir.path().join("nested").join("CHANGELOG.md");
+        fs.write_file_atomic(&test_path, "first").await.unwrap();
+        fs.write_file_atomic(&test_path, "second").await.unwrap();
+
+        assert_eq!(fs.read_file_string(&test_path).await.unwrap(), "second");
+        // No temporary files are left next to the target
+        let entries = fs.read_dir(temp_dir.path().join("nested").as_path()).await.unwrap();
+        assert_eq!(entries, vec![test_path]);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_write_file_atomic_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = setup_test_dir();
+        let fs = FileSystemManager::new();
+        let test_path = temp_dir.path().join("script.sh");
+        create_file(&test_path, "old");
+        fs::set_permissions(&test_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        fs.write_file_atomic(&test_path, "new").await.unwrap();
+
+        let mode = fs::metadata(&test_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        assert_eq!(fs::read_to_string(&test_path).unwrap(), "new");
+    }
+
     #[tokio::test]
     async fn test_filesystem_manager_comprehensive() {
         let fs = FileSystemManager::new();
@@ -636,4 +674,135 @@ mod tests {
         let file_count = all_files.iter().filter(|p| p.is_file()).count();
         assert_eq!(file_count, 100);
     }
+
+    // =============================================================================
+    // READ-ONLY GUARD
+    // =============================================================================
+
+    #[tokio::test]
+    async fn test_read_only_filesystem_allows_reads() {
+        let temp_dir = setup_test_dir();
+        let file_path = temp_dir.path().join("package.json");
+        create_file(&file_path, "{}");
+
+        let fs = ReadOnlyFileSystem::new(FileSystemManager::new());
+
+        assert!(fs.exists(&file_path).await);
+        assert_eq!(fs.read_file_string(&file_path).await.unwrap(), "{}");
+        assert_eq!(fs.read_dir(temp_dir.path()).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_read_only_filesystem_rejects_writes() {
+        let temp_dir = setup_test_dir();
+        let file_path = temp_dir.path().join("package.json");
+        create_file(&file_path, "{}");
+        let new_dir = temp_dir.path().join("packages");
+
+        let fs = ReadOnlyFileSystem::new(FileSystemManager::new());
+
+        let result = fs.write_file_string(&file_path, "{\"name\":\"x\"}").await;
+        assert!(matches!(
+            result,
+            Err(Error::FileSystem(FileSystemError::ReadOnly { ref path })) if *path == file_path
+        ));
+        assert!(fs.write_file(&file_path, b"{}").await.is_err());
+        assert!(fs.write_file_atomic(&file_path, "{}").await.is_err());
+        assert!(fs.create_dir_all(&new_dir).await.is_err());
+        assert!(fs.remove(&file_path).await.is_err());
+
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "{}");
+        assert!(!new_dir.exists());
+    }
+
+    // =============================================================================
+    // FILE WATCHER
+    // =============================================================================
+
+    /// Waits for the next batch of a watcher, failing the test after a few seconds.
+    async fn next_changes(watcher: &mut FileWatcher) -> Vec<FileChange> {
+        tokio::time::timeout(std::time::Duration::from_secs(5), watcher.next())
+            .await
+            .expect("Timed out waiting for file changes")
+            .expect("Watcher failed")
+            .expect("Watcher stopped")
+    }
+
+    #[test]
+    fn test_coalesce_file_changes() {
+        use FileChangeKind::{Created, Modified, Removed};
+
+        assert_eq!(coalesce(None, Modified), Some(Modified));
+        assert_eq!(coalesce(Some(Created), Modified), Some(Created));
+        assert_eq!(coalesce(Some(Created), Removed), None);
+        assert_eq!(coalesce(Some(Modified), Removed), Some(Removed));
+        assert_eq!(coalesce(Some(Removed), Created), Some(Modified));
+    }
+
+    #[tokio::test]
+    async fn test_file_watcher_reports_debounced_batch() {
+        let temp_dir = setup_test_dir();
+        let root = temp_dir.path().canonicalize().unwrap();
+        let kept = root.join("package.json");
+        let transient = root.join("transient.json");
+        create_file(&root.join("existing.txt"), "old");
+
+        let config = FileWatcherConfig::new().with_debounce(std::time::Duration::from_millis(300));
+        let mut watcher = FileWatcher::new(&root, &config).unwrap();
+        assert_eq!(watcher.root(), root);
+
+        create_file(&kept, "{}");
+        create_file(&kept, "{\"name\":\"app\"}");
+        create_file(&transient, "{}");
+        fs::remove_file(&transient).unwrap();
+        create_file(&root.join("existing.txt"), "new");
+
+        let changes = next_changes(&mut watcher).await;
+        assert_eq!(
+            changes,
+            [
+                FileChange { path: root.join("existing.txt"), kind: FileChangeKind::Modified },
+                FileChange { path: kept, kind: FileChangeKind::Created },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_watcher_filters_paths() {
+        let temp_dir = setup_test_dir();
+        let root = temp_dir.path().canonicalize().unwrap();
+        fs::create_dir_all(root.join("packages/core")).unwrap();
+        fs::create_dir_all(root.join("node_modules/dep")).unwrap();
+
+        let config = FileWatcherConfig::new()
+            .with_debounce(std::time::Duration::from_millis(200))
+            .with_include("**/package.json");
+        let mut watcher = FileWatcher::new(&root, &config).unwrap();
+
+        create_file(&root.join("node_modules/dep/package.json"), "{}");
+        create_file(&root.join("packages/core/index.js"), "");
+        create_file(&root.join("packages/core/package.json"), "{}");
+
+        let changes = next_changes(&mut watcher).await;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].path, root.join("packages/core/package.json"));
+    }
+
+    #[test]
+    fn test_file_watcher_rejects_invalid_pattern() {
+        let temp_dir = setup_test_dir();
+        let config = FileWatcherConfig::new().with_include("packages/[");
+
+        let result = FileWatcher::new(temp_dir.path(), &config);
+        assert!(matches!(result, Err(Error::FileSystem(FileSystemError::Operation(_)))));
+    }
+
+    #[test]
+    fn test_file_watcher_missing_directory_fails() {
+        let temp_dir = setup_test_dir();
+        let missing = temp_dir.path().join("missing");
+
+        let result = FileWatcher::new(&missing, &FileWatcherConfig::new());
+        assert!(matches!(result, Err(Error::FileSystem(FileSystemError::Watch { .. }))));
+    }
 }
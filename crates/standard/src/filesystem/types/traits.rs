@@ -161,6 +161,38 @@ pub trait AsyncFileSystem: Send + Sync {
     /// Returns an error if the file cannot be written.
     async fn write_file_string(&self, path: &Path, contents: &str) -> Result<()>;
 
+    /// Asynchronously replaces the contents of a file atomically.
+    ///
+    /// The contents are written to a temporary file next to `path`, which is then renamed
+    /// over it, so concurrent readers and interrupted runs see either the old or the new
+    /// contents, never a partial write. The default implementation falls back to
+    /// `write_file_string`; filesystems backed by the disk override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file to write
+    /// * `contents` - The string to write to the file
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_standard_tools::filesystem::{AsyncFileSystem, AsyncFileSystemManager};
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let fs = AsyncFileSystemManager::new();
+    /// fs.write_file_atomic(Path::new("CHANGELOG.md"), "# Changelog\n").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the temporary file cannot be written or renamed over `path`.
+    async fn write_file_atomic(&self, path: &Path, contents: &str) -> Result<()> {
+        self.write_file_string(path, contents).await
+    }
+
     /// Asynchronously creates a directory and all of its parent directories if they don't exist.
     ///
     /// # Arguments
@@ -340,4 +372,34 @@ pub trait AsyncFileSystem: Send + Sync {
     ///
     /// Returns an error if the metadata cannot be read.
     async fn metadata(&self, path: &Path) -> Result<std::fs::Metadata>;
+
+    /// Asynchronously checks if a path is an existing directory.
+    ///
+    /// The default implementation reads the metadata of the path. Filesystems that are not
+    /// backed by the disk, and cannot produce `std::fs::Metadata`, override it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the path exists and is a directory
+    /// * `false` - Otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use sublime_standard_tools::filesystem::{AsyncFileSystem, AsyncFileSystemManager};
+    /// use std::path::Path;
+    ///
+    /// # async fn example() {
+    /// let fs = AsyncFileSystemManager::new();
+    /// assert!(fs.is_dir(Path::new("src")).await);
+    /// assert!(!fs.is_dir(Path::new("Cargo.toml")).await);
+    /// # }
+    /// ```
+    async fn is_dir(&self, path: &Path) -> bool {
+        self.metadata(path).await.is_ok_and(|metadata| metadata.is_dir())
+    }
 }
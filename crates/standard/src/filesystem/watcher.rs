@@ -0,0 +1,357 @@
+//! # Filesystem Watcher
+//!
+//! ## What
+//! This module provides `FileWatcher`, a cross-platform watcher that reports changes under
+//! a directory as debounced batches of `FileChange`s, filtered by glob patterns.
+//!
+//! ## How
+//! A `notify` watcher forwards raw events to a channel. `FileWatcher::next` waits for the
+//! first relevant event, then keeps collecting until no event arrives for the debounce
+//! period. Events for the same path are coalesced on the way: a file created and then
+//! modified is reported once as created, and a file created and removed within one batch
+//! is not reported at all. Paths are matched relative to the watched root against the
+//! include and exclude patterns of `FileWatcherConfig`.
+//!
+//! ## Why
+//! Editors and package managers emit bursts of events for a single logical change.
+//! Watching consumers such as a changes watcher, configuration hot-reload, or a daemon
+//! need one stable batch per burst, and sharing one implementation keeps platform
+//! differences and `node_modules` noise out of each of them.
+
+use crate::error::{Error, FileSystemError, Result};
+use glob::{MatchOptions, Pattern};
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+/// Kind of change reported for a path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileChangeKind {
+    /// The path did not exist before the batch.
+    Created,
+    /// The path existed before the batch and still exists.
+    Modified,
+    /// The path existed before the batch and no longer exists.
+    Removed,
+}
+
+/// A change to a single path, as reported by `FileWatcher`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    /// The changed path, under the watched root
+    pub path: PathBuf,
+    /// What happened to the path
+    pub kind: FileChangeKind,
+}
+
+/// Configuration for `FileWatcher`.
+///
+/// Patterns are globs matched against paths relative to the watched root, using `/` as
+/// separator. A path is reported if it matches an include pattern, or if there are none,
+/// and neither it nor any of its parent directories matches an exclude pattern.
+///
+/// # Examples
+///
+/// ```rust
+/// use sublime_standard_tools::filesystem::FileWatcherConfig;
+/// use std::time::Duration;
+///
+/// let config = FileWatcherConfig::new()
+///     .with_debounce(Duration::from_millis(500))
+///     .with_include("**/package.json")
+///     .with_exclude("**/dist");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileWatcherConfig {
+    /// Quiet period that ends a batch of changes
+    pub debounce: Duration,
+    /// Glob patterns a path must match to be reported; empty reports every path
+    pub include: Vec<String>,
+    /// Glob patterns of paths and directories never reported
+    pub exclude: Vec<String>,
+    /// Whether subdirectories are watched
+    pub recursive: bool,
+}
+
+impl Default for FileWatcherConfig {
+    fn default() -> Self {
+        Self {
+            debounce: Duration::from_millis(200),
+            include: Vec::new(),
+            exclude: vec!["**/node_modules".to_string(), "**/.git".to_string()],
+            recursive: true,
+        }
+    }
+}
+
+impl FileWatcherConfig {
+    /// Creates a new configuration with default values.
+    ///
+    /// The defaults debounce for 200ms, watch recursively, and exclude `node_modules`
+    /// and `.git` directories.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the quiet period that ends a batch of changes.
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Adds a glob pattern paths must match to be reported.
+    #[must_use]
+    pub fn with_include(mut self, pattern: impl Into<String>) -> Self {
+        self.include.push(pattern.into());
+        self
+    }
+
+    /// Adds a glob pattern of paths and directories never reported.
+    #[must_use]
+    pub fn with_exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.exclude.push(pattern.into());
+        self
+    }
+
+    /// Sets whether subdirectories are watched.
+    #[must_use]
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+}
+
+/// Include and exclude patterns compiled from a `FileWatcherConfig`.
+#[derive(Debug)]
+struct PathFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl PathFilter {
+    const OPTIONS: MatchOptions = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    fn new(config: &FileWatcherConfig) -> Result<Self> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|pattern| {
+                    Pattern::new(pattern).map_err(|e| {
+                        Error::FileSystem(FileSystemError::Operation(format!(
+                            "Invalid watch pattern '{pattern}': {e}"
+                        )))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        Ok(Self { include: compile(&config.include)?, exclude: compile(&config.exclude)? })
+    }
+
+    /// Returns whether a path relative to the watched root is reported.
+    fn matches(&self, relative: &Path) -> bool {
+        let matches = |patterns: &[Pattern], path: &Path| {
+            let path = path.to_string_lossy().replace('\\', "/");
+            patterns.iter().any(|pattern| pattern.matches_with(&path, Self::OPTIONS))
+        };
+
+        let excluded = relative
+            .ancestors()
+            .filter(|ancestor| !ancestor.as_os_str().is_empty())
+            .any(|ancestor| matches(&self.exclude, ancestor));
+
+        !excluded && (self.include.is_empty() || matches(&self.include, relative))
+    }
+}
+
+/// Watches a directory and reports debounced, filtered batches of changes.
+///
+/// The watcher stops when it is dropped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use sublime_standard_tools::filesystem::{FileWatcher, FileWatcherConfig};
+///
+/// # async fn example() -> sublime_standard_tools::error::Result<()> {
+/// let config = FileWatcherConfig::new().with_include("**/package.json");
+/// let mut watcher = FileWatcher::new(".", &config)?;
+///
+/// while let Some(changes) = watcher.next().await? {
+///     for change in changes {
+///         println!("{:?} {}", change.kind, change.path.display());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FileWatcher {
+    root: PathBuf,
+    debounce: Duration,
+    filter: PathFilter,
+    events: mpsc::UnboundedReceiver<notify::Result<Event>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Starts watching a directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory to watch
+    /// * `config` - Debounce period, patterns, and recursion
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a pattern is not a valid glob, or if the directory cannot
+    /// be watched.
+    pub fn new(root: impl Into<PathBuf>, config: &FileWatcherConfig) -> Result<Self> {
+        let root = root.into();
+        let filter = PathFilter::new(config)?;
+
+        let (sender, events) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            // The receiver is gone once the FileWatcher is dropped
+            let _ = sender.send(event);
+        })
+        .map_err(|e| watch_error(&root, &e))?;
+
+        let mode =
+            if config.recursive { RecursiveMode::Recursive } else { RecursiveMode::NonRecursive };
+        watcher.watch(&root, mode).map_err(|e| watch_error(&root, &e))?;
+
+        log::debug!("Watching {} (debounce {:?})", root.display(), config.debounce);
+
+        Ok(Self { root, debounce: config.debounce, filter, events, _watcher: watcher })
+    }
+
+    /// Returns the watched directory.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Waits for the next batch of changes.
+    ///
+    /// The batch ends once no event arrives for the debounce period. Changes are sorted
+    /// by path, with at most one change per path. Events that are filtered out or that
+    /// cancel each other out do not end the wait.
+    ///
+    /// # Returns
+    ///
+    /// The changes, or `None` if the watcher stopped delivering events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform watcher reports a failure before any change of
+    /// the batch was collected. Later failures are logged and the batch is returned.
+    pub async fn next(&mut self) -> Result<Option<Vec<FileChange>>> {
+        loop {
+            let Some(first) = self.events.recv().await else {
+                return Ok(None);
+            };
+
+            let mut pending = BTreeMap::new();
+            self.collect(first, &mut pending)?;
+
+            while let Ok(Some(event)) = timeout(self.debounce, self.events.recv()).await {
+                if let Err(e) = self.collect(event, &mut pending) {
+                    log::warn!("File watcher error on {}: {e}", self.root.display());
+                }
+            }
+
+            if !pending.is_empty() {
+                return Ok(Some(
+                    pending.into_iter().map(|(path, kind)| FileChange { path, kind }).collect(),
+                ));
+            }
+        }
+    }
+
+    /// Adds the changes of a raw event to the pending batch.
+    fn collect(
+        &self,
+        event: notify::Result<Event>,
+        pending: &mut BTreeMap<PathBuf, FileChangeKind>,
+    ) -> Result<()> {
+        let event = event.map_err(|e| watch_error(&self.root, &e))?;
+
+        for (path, kind) in event_changes(&event) {
+            let relative = path.strip_prefix(&self.root).unwrap_or(&path);
+            if !self.filter.matches(relative) {
+                continue;
+            }
+            match coalesce(pending.get(&path).copied(), kind) {
+                Some(kind) => pending.insert(path, kind),
+                None => pending.remove(&path),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+/// Maps a raw event to the changes of its paths.
+fn event_changes(event: &Event) -> Vec<(PathBuf, FileChangeKind)> {
+    let all = |kind| event.paths.iter().map(|path| (path.clone(), kind)).collect();
+
+    match event.kind {
+        EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            all(FileChangeKind::Created)
+        }
+        EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            all(FileChangeKind::Removed)
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => match event.paths.as_slice() {
+            [from, to] => {
+                vec![(from.clone(), FileChangeKind::Removed), (to.clone(), FileChangeKind::Created)]
+            }
+            _ => all(FileChangeKind::Modified),
+        },
+        // Some platforms report renames without telling which side a path is on
+        EventKind::Modify(ModifyKind::Name(_)) => event
+            .paths
+            .iter()
+            .map(|path| {
+                let kind =
+                    if path.exists() { FileChangeKind::Created } else { FileChangeKind::Removed };
+                (path.clone(), kind)
+            })
+            .collect(),
+        EventKind::Modify(_) | EventKind::Any => all(FileChangeKind::Modified),
+        EventKind::Access(_) | EventKind::Other => Vec::new(),
+    }
+}
+
+/// Combines the pending change of a path with a new one.
+///
+/// Returns `None` when the two cancel out, such as a path created and then removed.
+pub(crate) fn coalesce(
+    pending: Option<FileChangeKind>,
+    next: FileChangeKind,
+) -> Option<FileChangeKind> {
+    use FileChangeKind::{Created, Modified, Removed};
+
+    match (pending, next) {
+        (None, next) => Some(next),
+        (Some(Created), Removed) => None,
+        (Some(Created), _) => Some(Created),
+        (Some(Removed | Modified), Removed) => Some(Removed),
+        (Some(Removed | Modified), _) => Some(Modified),
+    }
+}
+
+fn watch_error(root: &Path, error: &notify::Error) -> Error {
+    let path = error.paths.first().cloned().unwrap_or_else(|| root.to_path_buf());
+    Error::FileSystem(FileSystemError::Watch { path, message: error.to_string() })
+}
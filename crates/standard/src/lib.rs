@@ -17,7 +17,10 @@
 //! - **`monorepo`**: Monorepo-specific functionality and workspace management
 //! - **`command`**: Robust command execution framework
 //! - **`filesystem`**: Safe filesystem operations and path utilities
+//! - **`network`**: Process-wide offline mode and shared proxy and TLS settings
+//! - **`deadline`**: Process-wide deadline and cancellation shared by long-running operations
 //! - **`error`**: Comprehensive error handling
+//! - **`prelude`**: Stable re-exports of the most used types, covered by semver
 //!
 //! ### Architecture Overview
 //! ```text
@@ -150,10 +153,13 @@
 
 pub mod command;
 pub mod config;
+pub mod deadline;
 pub mod error;
 pub mod filesystem;
 pub mod monorepo;
+pub mod network;
 pub mod node;
+pub mod prelude;
 pub mod project;
 
 /// Version of the crate
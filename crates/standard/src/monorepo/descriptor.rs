@@ -83,6 +83,7 @@ impl MonorepoDescriptor {
             package_manager,
             package_json,
             validation_status,
+            nested_workspace_roots: Vec::new(),
         }
     }
 
@@ -430,6 +431,49 @@ impl MonorepoDescriptor {
 
         self.packages.iter().find(|pkg| abs_path.starts_with(&pkg.absolute_path))
     }
+
+    /// Returns the absolute paths of packages that are nested workspace roots.
+    ///
+    /// A nested workspace root is a package of this monorepo that declares its own
+    /// workspaces (e.g., an example app with its own `packages/*`). The packages of the
+    /// nested workspace are not included in [`packages`](Self::packages).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::path::PathBuf;
+    /// # use sublime_standard_tools::monorepo::{MonorepoDescriptor, MonorepoKind};
+    /// #
+    /// # let descriptor = MonorepoDescriptor::minimal(
+    /// #     MonorepoKind::NpmWorkSpace,
+    /// #     PathBuf::from("/projects/monorepo"),
+    /// #     vec![]
+    /// # );
+    /// #
+    /// for root in descriptor.nested_workspace_roots() {
+    ///     println!("Nested workspace: {}", root.display());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn nested_workspace_roots(&self) -> &[PathBuf] {
+        &self.nested_workspace_roots
+    }
+
+    /// Checks whether a path belongs to a nested workspace rather than this monorepo.
+    ///
+    /// Returns `true` for paths inside a nested workspace root, excluding the root itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to check (absolute or relative to the monorepo root)
+    #[must_use]
+    pub fn is_in_nested_workspace(&self, path: &Path) -> bool {
+        let abs_path = if path.is_absolute() { path.to_path_buf() } else { self.root.join(path) };
+
+        self.nested_workspace_roots
+            .iter()
+            .any(|nested| abs_path != *nested && abs_path.starts_with(nested))
+    }
 }
 
 impl ProjectInfo for MonorepoDescriptor {
@@ -533,6 +577,15 @@ impl MonorepoDescriptor {
         self.validation_status = validation_status;
     }
 
+    /// Sets the nested workspace roots of this monorepo.
+    ///
+    /// # Arguments
+    ///
+    /// * `roots` - Absolute paths of packages that declare their own workspaces
+    pub fn set_nested_workspace_roots(&mut self, roots: Vec<PathBuf>) {
+        self.nested_workspace_roots = roots;
+    }
+
     /// Gets a mutable reference to the validation status.
     ///
     /// This is useful for validators that need to update the status in place.
@@ -595,6 +648,7 @@ impl Clone for MonorepoDescriptor {
             package_manager: self.package_manager.clone(),
             package_json: json,
             validation_status: self.validation_status.clone(),
+            nested_workspace_roots: self.nested_workspace_roots.clone(),
         }
     }
 }
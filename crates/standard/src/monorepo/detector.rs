@@ -15,6 +15,7 @@
 //! where thousands of packages need to be detected concurrently. This unified async-only
 //! approach eliminates confusion and provides consistent API across all operations.
 
+use super::pattern::{Segment, WorkspacePatterns};
 use super::{MonorepoDescriptor, MonorepoKind, WorkspacePackage};
 use crate::config::{ConfigManager, StandardConfig, traits::Configurable};
 use crate::error::{Error, Result};
@@ -22,6 +23,7 @@ use crate::filesystem::{AsyncFileSystem, FileSystemManager};
 use crate::project::ProjectValidationStatus;
 use async_trait::async_trait;
 use glob;
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 /// Async trait for monorepo detection.
@@ -726,20 +728,83 @@ impl<F: AsyncFileSystem + Clone> MonorepoDetectorTrait for MonorepoDetector<F> {
         })?;
 
         // Detect packages within the monorepo
-        let packages = self.detect_packages(path).await?;
+        let scan = self.scan_workspace(path).await?;
 
-        Ok(MonorepoDescriptor::new(
+        let mut descriptor = MonorepoDescriptor::new(
             kind,
             path.to_path_buf(),
-            packages,
+            scan.packages,
             None, // package_manager
             None, // package_json
             ProjectValidationStatus::NotValidated,
-        ))
+        );
+        descriptor.set_nested_workspace_roots(scan.nested_workspace_roots);
+        Ok(descriptor)
     }
 
-    #[allow(clippy::assigning_clones)]
     async fn detect_packages(&self, root: &Path) -> Result<Vec<WorkspacePackage>> {
+        Ok(self.scan_workspace(root).await?.packages)
+    }
+
+    async fn has_multiple_packages(&self, path: &Path) -> bool {
+        if let Ok(packages) = self.detect_packages(path).await { packages.len() > 1 } else { false }
+    }
+}
+
+#[async_trait]
+impl<F: AsyncFileSystem + Clone> MonorepoDetectorWithFs<F> for MonorepoDetector<F> {
+    fn filesystem(&self) -> &F {
+        &self.fs
+    }
+
+    async fn detect_packages_multiple(
+        &self,
+        roots: &[&Path],
+    ) -> Vec<Result<Vec<WorkspacePackage>>> {
+        let mut results = Vec::with_capacity(roots.len());
+
+        // Process all roots concurrently
+        let futures = roots.iter().map(|root| self.detect_packages(root));
+
+        // Collect all results
+        for future in futures {
+            results.push(future.await);
+        }
+
+        results
+    }
+
+    async fn detect_packages_parallel(
+        &self,
+        root: &Path,
+        _max_concurrent: usize,
+    ) -> Result<Vec<WorkspacePackage>> {
+        // For now, use the standard detect_packages
+        // In a more sophisticated implementation, we would use semaphores
+        // to limit concurrency
+        self.detect_packages(root).await
+    }
+}
+
+/// Packages found under a monorepo root, with the nested workspace roots among them.
+struct WorkspaceScan {
+    packages: Vec<WorkspacePackage>,
+    nested_workspace_roots: Vec<PathBuf>,
+}
+
+impl<F: AsyncFileSystem + Clone> MonorepoDetector<F> {
+    /// Finds the packages of a monorepo and the nested workspace roots among them.
+    ///
+    /// Symlinked package directories are only followed when `follow_symlinks` is enabled
+    /// in the monorepo configuration. Directories are deduplicated by their canonical path,
+    /// so symlink cycles and aliases of the same package are visited once. Packages located
+    /// inside a nested workspace root are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root path of the monorepo
+    #[allow(clippy::assigning_clones)]
+    async fn scan_workspace(&self, root: &Path) -> Result<WorkspaceScan> {
         let mut packages = Vec::new();
 
         // First, discover internal scopes by scanning existing packages
@@ -798,79 +863,186 @@ impl<F: AsyncFileSystem + Clone> MonorepoDetectorTrait for MonorepoDetector<F> {
 
         // Early return if still no patterns
         if workspace_patterns.is_empty() {
-            return Ok(packages);
+            return Ok(WorkspaceScan { packages, nested_workspace_roots: Vec::new() });
         }
 
+        let root_canonical = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
+        let mut visited = HashSet::new();
+        let mut nested_workspace_roots = Vec::new();
+
         // Find all package.json files in workspace directories
-        for pattern in workspace_patterns {
-            let full_pattern = root.join(&pattern).to_string_lossy().to_string();
-            if let Ok(paths) = glob::glob(&full_pattern) {
-                for dir_path in paths.flatten() {
-                    // Check if the path should be excluded based on config
-                    if self.should_exclude_path(&dir_path) {
-                        continue;
-                    }
+        let patterns = WorkspacePatterns::new(&workspace_patterns);
+        for dir_path in self.expand_workspace_patterns(root, &patterns).await {
+            // Check if the path should be excluded based on config
+            if self.should_exclude_path(&dir_path) {
+                continue;
+            }
 
-                    if let Ok(metadata) = self.fs.metadata(&dir_path).await
-                        && metadata.is_dir()
-                    {
-                        let package_json_path = dir_path.join("package.json");
-                        if self.fs.exists(&package_json_path).await
-                            && let Ok(package) = self
-                                .load_workspace_package(&package_json_path, &discovered_scopes)
-                                .await
-                        {
-                            packages.push(package);
-                        }
+            if !self.config.follow_symlinks && Self::traverses_symlink(root, &dir_path) {
+                log::debug!("Skipping symlinked package path {}", dir_path.display());
+                continue;
+            }
+
+            // A symlink back to the root (or above it) would loop, and two paths
+            // resolving to the same directory are the same package
+            let canonical = dir_path.canonicalize().unwrap_or_else(|_| dir_path.clone());
+            if root_canonical.starts_with(&canonical) || !visited.insert(canonical) {
+                continue;
+            }
+
+            if self.fs.is_dir(&dir_path).await {
+                let package_json_path = dir_path.join("package.json");
+                if self.fs.exists(&package_json_path).await
+                    && let Ok(package) =
+                        self.load_workspace_package(&package_json_path, &discovered_scopes).await
+                {
+                    if self.declares_workspaces(&dir_path).await {
+                        nested_workspace_roots.push(package.absolute_path.clone());
                     }
+                    packages.push(package);
                 }
             }
         }
 
-        Ok(packages)
-    }
+        // Members of a nested workspace belong to it, not to this monorepo
+        packages.retain(|package| {
+            !nested_workspace_roots.iter().any(|nested| {
+                package.absolute_path != *nested && package.absolute_path.starts_with(nested)
+            })
+        });
+        nested_workspace_roots.sort();
 
-    async fn has_multiple_packages(&self, path: &Path) -> bool {
-        if let Ok(packages) = self.detect_packages(path).await { packages.len() > 1 } else { false }
+        Ok(WorkspaceScan { packages, nested_workspace_roots })
     }
-}
 
-#[async_trait]
-impl<F: AsyncFileSystem + Clone> MonorepoDetectorWithFs<F> for MonorepoDetector<F> {
-    fn filesystem(&self) -> &F {
-        &self.fs
+    /// Expands workspace patterns relative to `root` into the directories they match.
+    ///
+    /// Directories matching a negated pattern are left out. A directory matched by several
+    /// patterns is returned once per pattern.
+    async fn expand_workspace_patterns(
+        &self,
+        root: &Path,
+        patterns: &WorkspacePatterns,
+    ) -> Vec<PathBuf> {
+        let mut matches = Vec::new();
+        for pattern in patterns.include() {
+            for segments in pattern.alternatives() {
+                matches.extend(self.expand_segments(root, segments).await.into_iter().filter(
+                    |dir| {
+                        dir.strip_prefix(root).is_ok_and(|relative| {
+                            !patterns.is_excluded(&relative.to_string_lossy())
+                        })
+                    },
+                ));
+            }
+        }
+        matches
     }
 
-    async fn detect_packages_multiple(
-        &self,
-        roots: &[&Path],
-    ) -> Vec<Result<Vec<WorkspacePackage>>> {
-        let mut results = Vec::with_capacity(roots.len());
+    /// Expands the segments of a workspace pattern relative to `root` into directories.
+    ///
+    /// Unlike `glob::glob`, recursive `**` segments expand every directory at most once by
+    /// canonical path and only descend into symlinked directories when `follow_symlinks` is
+    /// enabled, so symlink cycles cannot make detection loop. Directories are listed through
+    /// the filesystem abstraction, so patterns also expand on filesystems not backed by the
+    /// disk.
+    async fn expand_segments(&self, root: &Path, segments: &[Segment]) -> Vec<PathBuf> {
+        let mut matches = Vec::new();
+        let mut expanded = HashSet::new();
+        // Depth-first, in the order a recursive expansion would visit the directories
+        let mut pending = vec![(root.to_path_buf(), 0)];
+        while let Some((dir, index)) = pending.pop() {
+            let Some(segment) = segments.get(index) else {
+                matches.push(dir);
+                continue;
+            };
+
+            if *segment == Segment::Recursive {
+                let canonical = dir.canonicalize().unwrap_or_else(|_| dir.clone());
+                if !expanded.insert((canonical, segments.len() - index - 1)) {
+                    continue;
+                }
+                let children = self.child_directories(&dir).await;
+                pending.push((dir, index + 1));
+                pending.extend(children.into_iter().rev().map(|child| (child, index)));
+                continue;
+            }
 
-        // Process all roots concurrently
-        let futures = roots.iter().map(|root| self.detect_packages(root));
+            let children = self.child_directories(&dir).await;
+            pending.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .filter(|child| {
+                        child
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .is_some_and(|name| segment.matches_name(name))
+                    })
+                    .map(|child| (child, index + 1)),
+            );
+        }
+        matches
+    }
 
-        // Collect all results
-        for future in futures {
-            results.push(future.await);
+    /// Lists the subdirectories of `dir` in name order, skipping symlinks unless followed.
+    async fn child_directories(&self, dir: &Path) -> Vec<PathBuf> {
+        let Ok(entries) = self.fs.read_dir(dir).await else {
+            return Vec::new();
+        };
+
+        let mut children = Vec::with_capacity(entries.len());
+        for path in entries {
+            let is_symlink =
+                std::fs::symlink_metadata(&path).is_ok_and(|m| m.file_type().is_symlink());
+            if (self.config.follow_symlinks || !is_symlink) && self.fs.is_dir(&path).await {
+                children.push(path);
+            }
         }
+        children.sort();
+        children
+    }
 
-        results
+    /// Checks whether any component of `path` below `root` is a symbolic link.
+    fn traverses_symlink(root: &Path, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(root) else {
+            return false;
+        };
+
+        let mut current = root.to_path_buf();
+        relative.components().any(|component| {
+            current.push(component);
+            std::fs::symlink_metadata(&current).is_ok_and(|m| m.file_type().is_symlink())
+        })
     }
 
-    async fn detect_packages_parallel(
-        &self,
-        root: &Path,
-        _max_concurrent: usize,
-    ) -> Result<Vec<WorkspacePackage>> {
-        // For now, use the standard detect_packages
-        // In a more sophisticated implementation, we would use semaphores
-        // to limit concurrency
-        self.detect_packages(root).await
+    /// Checks whether a package directory declares workspaces of its own.
+    ///
+    /// A package is a nested workspace root when its `package.json` has a non-empty
+    /// `workspaces` field (array or `{ "packages": [...] }` form) or when it contains a
+    /// `pnpm-workspace.yaml` file.
+    async fn declares_workspaces(&self, dir: &Path) -> bool {
+        if self.fs.exists(&dir.join("pnpm-workspace.yaml")).await {
+            return true;
+        }
+
+        let Ok(content) = self.fs.read_file_string(&dir.join("package.json")).await else {
+            return false;
+        };
+        let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&content) else {
+            return false;
+        };
+
+        match json_value.get("workspaces") {
+            Some(serde_json::Value::Array(patterns)) => !patterns.is_empty(),
+            Some(serde_json::Value::Object(config)) => config
+                .get("packages")
+                .and_then(serde_json::Value::as_array)
+                .is_some_and(|patterns| !patterns.is_empty()),
+            _ => false,
+        }
     }
-}
 
-impl<F: AsyncFileSystem + Clone> MonorepoDetector<F> {
     /// Discovers internal workspace scopes by analyzing existing packages.
     ///
     /// This method scans all packages in the workspace and extracts their scopes
@@ -917,33 +1089,27 @@ impl<F: AsyncFileSystem + Clone> MonorepoDetector<F> {
         }
 
         // Scan workspace directories for packages and extract scopes
-        for pattern in workspace_patterns {
-            let full_pattern = root.join(&pattern).to_string_lossy().to_string();
-            if let Ok(paths) = glob::glob(&full_pattern) {
-                for dir_path in paths.flatten() {
-                    if self.should_exclude_path(&dir_path) {
-                        continue;
-                    }
+        let patterns = WorkspacePatterns::new(&workspace_patterns);
+        for dir_path in self.expand_workspace_patterns(root, &patterns).await {
+            if self.should_exclude_path(&dir_path)
+                || (!self.config.follow_symlinks && Self::traverses_symlink(root, &dir_path))
+            {
+                continue;
+            }
 
-                    if let Ok(metadata) = self.fs.metadata(&dir_path).await
-                        && metadata.is_dir()
+            if self.fs.is_dir(&dir_path).await {
+                let package_json_path = dir_path.join("package.json");
+                if self.fs.exists(&package_json_path).await
+                    && let Ok(pkg_content) = self.fs.read_file_string(&package_json_path).await
+                    && let Ok(pkg_json) = serde_json::from_str::<serde_json::Value>(&pkg_content)
+                    && let Some(name) = pkg_json.get("name").and_then(|v| v.as_str())
+                {
+                    // Extract scope from package name (e.g., "@scope/lib" -> "@scope/")
+                    if name.starts_with('@')
+                        && let Some(slash_pos) = name.find('/')
                     {
-                        let package_json_path = dir_path.join("package.json");
-                        if self.fs.exists(&package_json_path).await
-                            && let Ok(pkg_content) =
-                                self.fs.read_file_string(&package_json_path).await
-                            && let Ok(pkg_json) =
-                                serde_json::from_str::<serde_json::Value>(&pkg_content)
-                            && let Some(name) = pkg_json.get("name").and_then(|v| v.as_str())
-                        {
-                            // Extract scope from package name (e.g., "@scope/lib" -> "@scope/")
-                            if name.starts_with('@')
-                                && let Some(slash_pos) = name.find('/')
-                            {
-                                let scope = format!("{}/", &name[..slash_pos]);
-                                discovered_scopes.insert(scope);
-                            }
-                        }
+                        let scope = format!("{}/", &name[..slash_pos]);
+                        discovered_scopes.insert(scope);
                     }
                 }
             }
@@ -21,10 +21,12 @@ mod descriptor;
 mod detector;
 mod kinds;
 mod manager;
+mod pattern;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 pub use detector::{MonorepoDetector, MonorepoDetectorTrait, MonorepoDetectorWithFs};
+pub use pattern::{WorkspacePattern, WorkspacePatterns};
 pub use types::{MonorepoDescriptor, MonorepoKind, PnpmWorkspaceConfig, WorkspacePackage};
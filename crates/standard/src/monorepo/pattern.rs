@@ -0,0 +1,563 @@
+//! # Workspace Pattern Matching
+//!
+//! ## What
+//! This file provides `WorkspacePattern` and `WorkspacePatterns`, the glob matchers for the
+//! patterns a workspace declares its packages with (`workspaces` in `package.json`,
+//! `monorepo.workspace_patterns`). Besides `*`, `?`, `[...]` and `**`, they support brace
+//! expansion (`{app,lib}`, `{1..3}`), extglobs (`@(a|b)`, `?(a)`, `*(a)`, `+(a)`, `!(a)`)
+//! and negated patterns (`!packages/internal`).
+//!
+//! ## How
+//! Braces are expanded first, so a pattern becomes a set of alternatives; patterns expanding
+//! past `MAX_BRACE_ALTERNATIVES` are rejected rather than allocated. Each alternative is
+//! split into path segments, `**` segments match any number of directories, and every other
+//! segment is compiled into tokens matched against one directory name with backtracking.
+//! Negated patterns are collected separately by `WorkspacePatterns` and exclude the paths
+//! they match.
+//!
+//! ## Why
+//! Package managers accept the full glob syntax of their matching libraries. Workspace
+//! detection and the package mapping of the package tools must agree with them, or packages
+//! declared with braces or extglobs go missing.
+
+use crate::error::{MonorepoError, MonorepoResult};
+
+/// Largest number of alternatives a pattern may expand its braces into.
+///
+/// Ranges are expanded eagerly, so `{1..1000000000}` would otherwise allocate every value.
+pub(crate) const MAX_BRACE_ALTERNATIVES: usize = 4096;
+
+/// A workspace glob pattern, such as `packages/*` or `packages/{app,lib}-*`.
+///
+/// Patterns are matched against paths relative to the workspace root, with `/` or `\` as
+/// separators. A leading `!` marks the pattern as negated; `matches` ignores it, and
+/// [`WorkspacePatterns`] uses it to exclude paths.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::monorepo::WorkspacePattern;
+///
+/// let pattern = WorkspacePattern::new("packages/{app,lib}-*")?;
+/// assert!(pattern.matches("packages/app-web"));
+/// assert!(pattern.matches("packages/lib-core"));
+/// assert!(!pattern.matches("packages/tool-cli"));
+///
+/// let pattern = WorkspacePattern::new("packages/!(legacy|old)")?;
+/// assert!(pattern.matches("packages/core"));
+/// assert!(!pattern.matches("packages/legacy"));
+/// # Ok::<(), sublime_standard_tools::error::MonorepoError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspacePattern {
+    source: String,
+    negated: bool,
+    alternatives: Vec<Vec<Segment>>,
+}
+
+impl WorkspacePattern {
+    /// Compiles a workspace pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern as declared, optionally prefixed with `!` or `./`
+    ///
+    /// # Errors
+    ///
+    /// Returns `MonorepoError::InvalidPattern` if an extglob group is not closed, the
+    /// pattern ends with an escape character, or its braces expand to more than 4096
+    /// alternatives.
+    pub fn new(pattern: &str) -> MonorepoResult<Self> {
+        let trimmed = pattern.trim();
+        // `!(` opens an extglob; any other leading `!` negates the pattern
+        let (negated, body) = match trimmed.strip_prefix('!') {
+            Some(rest) if !rest.starts_with('(') => (true, rest),
+            _ => (false, trimmed),
+        };
+
+        let alternatives = expand_braces(body)
+            .and_then(|expanded| {
+                expanded.iter().map(|alternative| parse_segments(alternative)).collect()
+            })
+            .map_err(|reason| MonorepoError::InvalidPattern {
+                pattern: pattern.to_string(),
+                reason,
+            })?;
+
+        Ok(Self { source: pattern.to_string(), negated, alternatives })
+    }
+
+    /// Returns the pattern as declared.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns whether the pattern is negated with a leading `!`.
+    #[must_use]
+    pub fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    /// Returns whether a path relative to the workspace root matches the pattern.
+    ///
+    /// A leading `./` and empty or `.` segments are ignored. The negation of the pattern is
+    /// not applied.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        let names: Vec<&str> = split_path(path).collect();
+        self.alternatives.iter().any(|segments| matches_segments(segments, &names))
+    }
+
+    /// Returns the brace-expanded alternatives of the pattern, as path segments.
+    pub(crate) fn alternatives(&self) -> &[Vec<Segment>] {
+        &self.alternatives
+    }
+}
+
+/// A set of workspace patterns, where negated patterns exclude what the others include.
+///
+/// Invalid patterns are logged and skipped, like package managers skip patterns matching
+/// nothing.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::monorepo::WorkspacePatterns;
+///
+/// let patterns = WorkspacePatterns::new(["packages/**", "!**/test/**"]);
+/// assert!(patterns.matches("packages/core"));
+/// assert!(!patterns.matches("packages/core/test/fixture"));
+/// assert!(!patterns.matches("apps/web"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspacePatterns {
+    include: Vec<WorkspacePattern>,
+    exclude: Vec<WorkspacePattern>,
+}
+
+impl WorkspacePatterns {
+    /// Compiles a list of workspace patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The patterns as declared; a leading `!` excludes
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut set = Self::default();
+        for pattern in patterns {
+            match WorkspacePattern::new(pattern.as_ref()) {
+                Ok(pattern) if pattern.is_negated() => set.exclude.push(pattern),
+                Ok(pattern) => set.include.push(pattern),
+                Err(e) => log::warn!("Skipping workspace pattern: {e}"),
+            }
+        }
+        set
+    }
+
+    /// Returns the patterns that include paths.
+    #[must_use]
+    pub fn include(&self) -> &[WorkspacePattern] {
+        &self.include
+    }
+
+    /// Returns whether no pattern includes any path.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty()
+    }
+
+    /// Returns whether a negated pattern excludes a path.
+    #[must_use]
+    pub fn is_excluded(&self, path: &str) -> bool {
+        self.exclude.iter().any(|pattern| pattern.matches(path))
+    }
+
+    /// Returns whether a path is included by a pattern and not excluded by a negated one.
+    #[must_use]
+    pub fn matches(&self, path: &str) -> bool {
+        self.include.iter().any(|pattern| pattern.matches(path)) && !self.is_excluded(path)
+    }
+}
+
+/// One path segment of a compiled pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    /// `**`: any number of directories, including none.
+    Recursive,
+    /// A glob matched against one directory name.
+    Name(Vec<Token>),
+}
+
+impl Segment {
+    /// Returns whether a directory name matches a `Name` segment.
+    ///
+    /// Always `false` for `Recursive`, which matches whole directory chains.
+    pub(crate) fn matches_name(&self, name: &str) -> bool {
+        match self {
+            Self::Recursive => false,
+            Self::Name(tokens) => {
+                let chars: Vec<char> = name.chars().collect();
+                matches_tokens(tokens, &chars)
+            }
+        }
+    }
+}
+
+/// One element of a segment glob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token {
+    /// A literal character.
+    Char(char),
+    /// `?`: any single character.
+    Any,
+    /// `*`: any run of characters.
+    Star,
+    /// `[...]`: one character in (or, when negated, not in) the ranges.
+    Class { negated: bool, ranges: Vec<(char, char)> },
+    /// An extglob group with its alternatives.
+    Ext(ExtKind, Vec<Vec<Token>>),
+}
+
+/// Repetition of an extglob group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExtKind {
+    /// `?(...)`: zero or one alternative.
+    ZeroOrOne,
+    /// `*(...)`: zero or more alternatives.
+    ZeroOrMore,
+    /// `+(...)`: one or more alternatives.
+    OneOrMore,
+    /// `@(...)`: exactly one alternative.
+    ExactlyOne,
+    /// `!(...)`: anything but the alternatives.
+    Not,
+}
+
+impl ExtKind {
+    /// Returns the kind an extglob prefix character opens.
+    fn from_prefix(c: char) -> Option<Self> {
+        match c {
+            '?' => Some(Self::ZeroOrOne),
+            '*' => Some(Self::ZeroOrMore),
+            '+' => Some(Self::OneOrMore),
+            '@' => Some(Self::ExactlyOne),
+            '!' => Some(Self::Not),
+            _ => None,
+        }
+    }
+}
+
+/// Splits a path into its directory names, skipping `.` and empty segments.
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['/', '\\']).filter(|name| !name.is_empty() && *name != ".")
+}
+
+/// Expands the brace groups of a pattern into its alternatives.
+///
+/// Groups need a top-level comma (`{a,b}`) or a range (`{1..3}`, `{a..c}`); other braces
+/// are literal. Nested groups are expanded too. Fails when the pattern expands to more than
+/// `MAX_BRACE_ALTERNATIVES` alternatives.
+fn expand_braces(pattern: &str) -> Result<Vec<String>, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 2,
+            '{' => {
+                if let Some((close, parts)) = brace_group(&chars, index)? {
+                    let prefix: String = chars[..index].iter().collect();
+                    let suffix: String = chars[close + 1..].iter().collect();
+                    let mut expanded = Vec::new();
+                    for part in &parts {
+                        expanded.extend(expand_braces(&format!("{prefix}{part}{suffix}"))?);
+                        if expanded.len() > MAX_BRACE_ALTERNATIVES {
+                            return Err(too_many_alternatives());
+                        }
+                    }
+                    return Ok(expanded);
+                }
+                index += 1;
+            }
+            _ => index += 1,
+        }
+    }
+    Ok(vec![pattern.to_string()])
+}
+
+/// Returns the closing index and the alternatives of the brace group opened at `open`.
+fn brace_group(chars: &[char], open: usize) -> Result<Option<(usize, Vec<String>)>, String> {
+    let mut depth = 0;
+    let mut parts = Vec::new();
+    let mut start = open + 1;
+    let mut index = open + 1;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 1,
+            '{' => depth += 1,
+            '}' if depth > 0 => depth -= 1,
+            '}' => {
+                let last: String = chars[start..index].iter().collect();
+                if parts.is_empty() {
+                    return Ok(brace_range(&last)?.map(|range| (index, range)));
+                }
+                parts.push(last);
+                return Ok(Some((index, parts)));
+            }
+            ',' if depth == 0 => {
+                parts.push(chars[start..index].iter().collect());
+                start = index + 1;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    Ok(None)
+}
+
+/// Expands the body of a `{start..end}` range of integers or single characters.
+///
+/// Fails when an integer range holds more than `MAX_BRACE_ALTERNATIVES` values.
+fn brace_range(body: &str) -> Result<Option<Vec<String>>, String> {
+    let Some((start, end)) = body.split_once("..") else {
+        return Ok(None);
+    };
+    if let (Ok(start), Ok(end)) = (start.parse::<i64>(), end.parse::<i64>()) {
+        if start.abs_diff(end) >= MAX_BRACE_ALTERNATIVES as u64 {
+            return Err(too_many_alternatives());
+        }
+        let values: Vec<i64> =
+            if start <= end { (start..=end).collect() } else { (end..=start).rev().collect() };
+        return Ok(Some(values.iter().map(ToString::to_string).collect()));
+    }
+
+    let mut start_chars = start.chars();
+    let mut end_chars = end.chars();
+    let (Some(start), None, Some(end), None) =
+        (start_chars.next(), start_chars.next(), end_chars.next(), end_chars.next())
+    else {
+        return Ok(None);
+    };
+    if !start.is_ascii_alphabetic() || !end.is_ascii_alphabetic() {
+        return Ok(None);
+    }
+    let values: Vec<char> =
+        if start <= end { (start..=end).collect() } else { (end..=start).rev().collect() };
+    Ok(Some(values.iter().map(ToString::to_string).collect()))
+}
+
+fn too_many_alternatives() -> String {
+    format!("braces expand to more than {MAX_BRACE_ALTERNATIVES} alternatives")
+}
+
+/// Splits a brace-free pattern into compiled segments.
+fn parse_segments(pattern: &str) -> Result<Vec<Segment>, String> {
+    let pattern = pattern.trim_start_matches("./");
+    split_top_level(&pattern.chars().collect::<Vec<_>>(), &['/'])
+        .into_iter()
+        .filter(|segment| !segment.is_empty() && segment.as_slice() != ['.'])
+        .map(|segment| {
+            if segment.as_slice() == ['*', '*'] {
+                Ok(Segment::Recursive)
+            } else {
+                parse_tokens(&segment).map(Segment::Name)
+            }
+        })
+        .collect()
+}
+
+/// Splits characters on separators outside of extglob groups and escapes.
+fn split_top_level(chars: &[char], separators: &[char]) -> Vec<Vec<char>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0usize;
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '\\' => {
+                // Escapes are kept for `parse_tokens`
+                if let Some(part) = parts.last_mut() {
+                    part.extend(&chars[index..chars.len().min(index + 2)]);
+                }
+                index += 2;
+                continue;
+            }
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ if depth == 0 && separators.contains(&c) => {
+                parts.push(Vec::new());
+                index += 1;
+                continue;
+            }
+            _ => {}
+        }
+        if let Some(part) = parts.last_mut() {
+            part.push(c);
+        }
+        index += 1;
+    }
+    parts
+}
+
+/// Compiles the glob of one segment.
+fn parse_tokens(chars: &[char]) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if chars.get(index + 1) == Some(&'(')
+            && let Some(kind) = ExtKind::from_prefix(c)
+        {
+            let close =
+                closing_paren(chars, index + 1).ok_or_else(|| format!("unclosed '{c}(' group"))?;
+            let alternatives = split_top_level(&chars[index + 2..close], &['|'])
+                .iter()
+                .map(|alternative| parse_tokens(alternative))
+                .collect::<Result<Vec<_>, String>>()?;
+            tokens.push(Token::Ext(kind, alternatives));
+            index = close + 1;
+            continue;
+        }
+
+        match c {
+            '\\' => {
+                let escaped =
+                    chars.get(index + 1).ok_or_else(|| "trailing escape character".to_string())?;
+                tokens.push(Token::Char(*escaped));
+                index += 2;
+                continue;
+            }
+            '?' => tokens.push(Token::Any),
+            '*' => {
+                if tokens.last() != Some(&Token::Star) {
+                    tokens.push(Token::Star);
+                }
+            }
+            '[' => {
+                if let Some((class, close)) = parse_class(chars, index) {
+                    tokens.push(class);
+                    index = close + 1;
+                    continue;
+                }
+                tokens.push(Token::Char('['));
+            }
+            _ => tokens.push(Token::Char(c)),
+        }
+        index += 1;
+    }
+    Ok(tokens)
+}
+
+/// Returns the index of the `)` closing the group opened at `open`.
+fn closing_paren(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut index = open;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 1,
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Parses the character class opened at `open`, returning it and its closing index.
+fn parse_class(chars: &[char], open: usize) -> Option<(Token, usize)> {
+    let mut index = open + 1;
+    let negated = matches!(chars.get(index), Some('!' | '^'));
+    if negated {
+        index += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let mut first = true;
+    while index < chars.len() {
+        let mut c = chars[index];
+        if c == ']' && !first {
+            return Some((Token::Class { negated, ranges }, index));
+        }
+        first = false;
+        if c == '\\' {
+            index += 1;
+            c = *chars.get(index)?;
+        }
+        if chars.get(index + 1) == Some(&'-')
+            && let Some(&end) = chars.get(index + 2)
+            && end != ']'
+        {
+            ranges.push((c, end));
+            index += 3;
+        } else {
+            ranges.push((c, c));
+            index += 1;
+        }
+    }
+    None
+}
+
+/// Returns whether directory names match compiled segments.
+fn matches_segments(segments: &[Segment], names: &[&str]) -> bool {
+    let Some((first, rest)) = segments.split_first() else {
+        return names.is_empty();
+    };
+    match first {
+        Segment::Recursive => (0..=names.len()).any(|skip| matches_segments(rest, &names[skip..])),
+        Segment::Name(_) => {
+            names.first().is_some_and(|name| first.matches_name(name))
+                && matches_segments(rest, &names[1..])
+        }
+    }
+}
+
+/// Returns whether text matches compiled tokens entirely.
+fn matches_tokens(tokens: &[Token], text: &[char]) -> bool {
+    let Some((first, rest)) = tokens.split_first() else {
+        return text.is_empty();
+    };
+    match first {
+        Token::Char(c) => text.first() == Some(c) && matches_tokens(rest, &text[1..]),
+        Token::Any => !text.is_empty() && matches_tokens(rest, &text[1..]),
+        Token::Star => (0..=text.len()).any(|end| matches_tokens(rest, &text[end..])),
+        Token::Class { negated, ranges } => {
+            text.first().is_some_and(|c| {
+                ranges.iter().any(|(low, high)| (low..=high).contains(&c)) != *negated
+            }) && matches_tokens(rest, &text[1..])
+        }
+        Token::Ext(kind, alternatives) => {
+            let group_matches =
+                |end: usize| alternatives.iter().any(|alt| matches_tokens(alt, &text[..end]));
+            match kind {
+                ExtKind::ExactlyOne => (0..=text.len())
+                    .any(|end| group_matches(end) && matches_tokens(rest, &text[end..])),
+                ExtKind::ZeroOrOne => {
+                    matches_tokens(rest, text)
+                        || (0..=text.len())
+                            .any(|end| group_matches(end) && matches_tokens(rest, &text[end..]))
+                }
+                ExtKind::ZeroOrMore => {
+                    matches_tokens(rest, text)
+                        || (1..=text.len())
+                            .any(|end| group_matches(end) && matches_tokens(tokens, &text[end..]))
+                }
+                ExtKind::OneOrMore => (0..=text.len()).any(|end| {
+                    group_matches(end)
+                        && (matches_tokens(rest, &text[end..])
+                            || (end > 0 && matches_tokens(tokens, &text[end..])))
+                }),
+                ExtKind::Not => (0..=text.len())
+                    .any(|end| !group_matches(end) && matches_tokens(rest, &text[end..])),
+            }
+        }
+    }
+}
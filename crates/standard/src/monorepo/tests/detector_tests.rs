@@ -0,0 +1,132 @@
+//! # MonorepoDetector Tests
+//!
+//! ## What
+//! This module tests package detection for workspaces with symlinked packages
+//! and nested workspaces.
+//!
+//! ## How
+//! Tests build npm workspaces in temporary directories and verify which
+//! packages and nested workspace roots the detector reports.
+//!
+//! ## Why
+//! Symlinked packages must not cause duplicate or looping detection, and the
+//! members of a nested workspace must not be versioned with the outer one.
+
+use crate::config::MonorepoConfig;
+use crate::monorepo::{MonorepoDetector, MonorepoDetectorTrait};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write_package(dir: &Path, name: &str, workspaces: Option<&[&str]>) {
+    fs::create_dir_all(dir).unwrap();
+    let mut json = serde_json::json!({ "name": name, "version": "1.0.0" });
+    if let Some(patterns) = workspaces {
+        json["workspaces"] = serde_json::json!(patterns);
+    }
+    fs::write(dir.join("package.json"), serde_json::to_string_pretty(&json).unwrap()).unwrap();
+}
+
+fn package_names(packages: &[crate::monorepo::WorkspacePackage]) -> Vec<String> {
+    let mut names: Vec<String> = packages.iter().map(|p| p.name.clone()).collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn test_nested_workspace_members_are_excluded() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_package(root, "root", Some(&["packages/*", "examples/**"]));
+    fs::write(root.join("package-lock.json"), "{}").unwrap();
+    write_package(&root.join("packages/core"), "@scope/core", None);
+    write_package(&root.join("examples/app"), "example-app", Some(&["packages/*"]));
+    write_package(&root.join("examples/app/packages/ui"), "example-ui", None);
+
+    let detector = MonorepoDetector::new();
+    let descriptor = detector.detect_monorepo(root).await.unwrap();
+
+    assert_eq!(package_names(descriptor.packages()), vec!["@scope/core", "example-app"]);
+    let app = root.join("examples/app").canonicalize().unwrap();
+    assert_eq!(descriptor.nested_workspace_roots(), std::slice::from_ref(&app));
+    assert!(descriptor.is_in_nested_workspace(&app.join("packages/ui/index.js")));
+    assert!(!descriptor.is_in_nested_workspace(&app));
+}
+
+#[tokio::test]
+async fn test_pnpm_workspace_file_marks_nested_root() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_package(root, "root", Some(&["packages/*", "packages/*/packages/*"]));
+    write_package(&root.join("packages/tools"), "tools", None);
+    fs::write(root.join("packages/tools/pnpm-workspace.yaml"), "packages:\n  - packages/*\n")
+        .unwrap();
+    write_package(&root.join("packages/tools/packages/cli"), "tools-cli", None);
+
+    let detector = MonorepoDetector::new();
+    let packages = detector.detect_packages(root).await.unwrap();
+
+    assert_eq!(package_names(&packages), vec!["tools"]);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlinked_packages_follow_configuration() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path().join("repo");
+    write_package(&root, "root", Some(&["packages/*"]));
+    write_package(&root.join("packages/core"), "core", None);
+    let external = temp.path().join("external/linked");
+    write_package(&external, "linked", None);
+    std::os::unix::fs::symlink(&external, root.join("packages/linked")).unwrap();
+
+    let detector = MonorepoDetector::new();
+    let packages = detector.detect_packages(&root).await.unwrap();
+    assert_eq!(package_names(&packages), vec!["core"]);
+
+    let config = MonorepoConfig { follow_symlinks: true, ..MonorepoConfig::default() };
+    let detector = MonorepoDetector::new_with_config(config);
+    let packages = detector.detect_packages(&root).await.unwrap();
+    assert_eq!(package_names(&packages), vec!["core", "linked"]);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_symlink_cycles_and_aliases_are_detected_once() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_package(root, "root", Some(&["packages/**"]));
+    write_package(&root.join("packages/core"), "core", None);
+    std::os::unix::fs::symlink(root.join("packages/core"), root.join("packages/core-alias"))
+        .unwrap();
+    std::os::unix::fs::symlink(root, root.join("packages/core/loop")).unwrap();
+
+    let config = MonorepoConfig { follow_symlinks: true, ..MonorepoConfig::default() };
+    let detector = MonorepoDetector::new_with_config(config);
+    let packages = detector.detect_packages(root).await.unwrap();
+
+    assert_eq!(package_names(&packages), vec!["core"]);
+}
+
+#[tokio::test]
+async fn test_brace_extglob_and_negated_patterns() {
+    let temp = TempDir::new().unwrap();
+    let root = temp.path();
+    write_package(
+        root,
+        "root",
+        Some(&["workspaces/{app,lib}-*", "tools/@(cli|lint)", "!workspaces/lib-legacy"]),
+    );
+    fs::write(root.join("package-lock.json"), "{}").unwrap();
+    write_package(&root.join("workspaces/app-web"), "app-web", None);
+    write_package(&root.join("workspaces/lib-core"), "lib-core", None);
+    write_package(&root.join("workspaces/lib-legacy"), "lib-legacy", None);
+    write_package(&root.join("workspaces/other"), "other", None);
+    write_package(&root.join("tools/cli"), "tools-cli", None);
+    write_package(&root.join("tools/docs"), "tools-docs", None);
+
+    let detector = MonorepoDetector::new();
+    let packages = detector.detect_packages(root).await.unwrap();
+
+    assert_eq!(package_names(&packages), vec!["app-web", "lib-core", "tools-cli"]);
+}
@@ -11,6 +11,7 @@
 //! - `monorepo_descriptor_tests`: Tests for MonorepoDescriptor functionality
 //! - `package_manager_tests`: Tests for PackageManager operations
 //! - `error_tests`: Tests for error handling and display
+//! - `pattern_tests`: Tests for workspace pattern matching
 //!
 //! ## Why
 //! Modular test organization improves maintainability, reduces cognitive load,
@@ -33,3 +34,11 @@ mod package_manager_tests;
 
 #[cfg(test)]
 mod error_tests;
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod detector_tests;
+
+#[allow(clippy::unwrap_used)]
+#[cfg(test)]
+mod pattern_tests;
@@ -0,0 +1,126 @@
+//! # Workspace Pattern Tests
+//!
+//! ## What
+//! This module tests workspace pattern matching against a corpus of patterns
+//! taken from the workspace configurations of public monorepos, plus the brace,
+//! extglob, and negation forms package managers accept.
+//!
+//! ## How
+//! Each corpus entry pairs a pattern with paths it must and must not match.
+//! Negations are tested through `WorkspacePatterns`, which combines patterns
+//! the way `package.json` and `pnpm-workspace.yaml` lists do.
+//!
+//! ## Why
+//! Detection and package mapping must match what the package manager links,
+//! or packages go missing from versioning and changesets.
+
+use crate::error::MonorepoError;
+use crate::monorepo::pattern::MAX_BRACE_ALTERNATIVES;
+use crate::monorepo::{WorkspacePattern, WorkspacePatterns};
+
+/// Patterns with paths they match and paths they do not match.
+const CORPUS: &[(&str, &[&str], &[&str])] = &[
+    // babel, jest, react: one level of packages
+    ("packages/*", &["packages/core"], &["packages", "packages/core/src"]),
+    // vite, vue: leading `./` and trailing slash
+    ("./packages/*/", &["packages/vite"], &["apps/vite"]),
+    // turborepo examples: literal directories
+    ("docs", &["docs"], &["docs/guide", "doc"]),
+    // pnpm docs: recursive components
+    ("components/**", &["components", "components/ui/button"], &["apps/ui"]),
+    // nx, angular: nested scopes
+    ("packages/*/*", &["packages/angular/core"], &["packages/angular"]),
+    ("**/packages/*", &["packages/a", "examples/app/packages/ui"], &["examples/app"]),
+    // braces
+    ("packages/{app,lib}-*", &["packages/app-web", "packages/lib-core"], &["packages/tool-cli"]),
+    ("{apps,packages}/*", &["apps/web", "packages/core"], &["libs/core"]),
+    ("apps/*/{web,api}", &["apps/shop/web", "apps/shop/api"], &["apps/shop/worker"]),
+    ("{apps/*,tools}", &["apps/web", "tools"], &["tools/lint"]),
+    ("packages/{a,{b,c}d}", &["packages/a", "packages/bd", "packages/cd"], &["packages/b"]),
+    ("packages/v{1..3}", &["packages/v1", "packages/v3"], &["packages/v4"]),
+    ("packages/{a..c}", &["packages/b"], &["packages/d"]),
+    ("packages/{core}", &["packages/{core}"], &["packages/core"]),
+    // extglobs
+    ("packages/@(core|utils)", &["packages/core", "packages/utils"], &["packages/cli"]),
+    ("packages/!(legacy|old)", &["packages/core", "packages/legacy-v2"], &["packages/legacy"]),
+    (
+        "packages/plugin-?(a)b",
+        &["packages/plugin-b", "packages/plugin-ab"],
+        &["packages/plugin-aab"],
+    ),
+    ("packages/x*(ab)y", &["packages/xy", "packages/xababy"], &["packages/xay"]),
+    ("packages/+(a|b)-lib", &["packages/a-lib", "packages/abba-lib"], &["packages/-lib"]),
+    ("packages/*-@(web|node)", &["packages/sdk-web", "packages/sdk-node"], &["packages/sdk"]),
+    // classes and escapes
+    ("packages/[a-c]*", &["packages/alpha", "packages/core"], &["packages/delta"]),
+    ("packages/[!a-c]*", &["packages/delta"], &["packages/alpha"]),
+    ("packages/\\*", &["packages/*"], &["packages/core"]),
+];
+
+#[test]
+fn test_pattern_corpus() {
+    for (pattern, matching, non_matching) in CORPUS {
+        let compiled = WorkspacePattern::new(pattern).unwrap();
+        for path in *matching {
+            assert!(compiled.matches(path), "'{pattern}' should match '{path}'");
+        }
+        for path in *non_matching {
+            assert!(!compiled.matches(path), "'{pattern}' should not match '{path}'");
+        }
+    }
+}
+
+#[test]
+fn test_negated_patterns_exclude_paths() {
+    // pnpm docs: all packages except test fixtures
+    let patterns = WorkspacePatterns::new(["packages/*", "components/**", "!**/test/**"]);
+    assert!(patterns.matches("components/ui"));
+    assert!(!patterns.matches("components/test/fixture"));
+    assert!(!patterns.matches("components/test"));
+
+    let patterns = WorkspacePatterns::new(["packages/{app,lib}-*", "!packages/lib-legacy"]);
+    assert!(patterns.matches("packages/lib-core"));
+    assert!(!patterns.matches("packages/lib-legacy"));
+    assert!(patterns.is_excluded("packages/lib-legacy"));
+    assert_eq!(patterns.include().len(), 1);
+}
+
+#[test]
+fn test_leading_extglob_is_not_a_negation() {
+    let pattern = WorkspacePattern::new("!(legacy)").unwrap();
+    assert!(!pattern.is_negated());
+    assert!(pattern.matches("core"));
+    assert!(!pattern.matches("legacy"));
+}
+
+#[test]
+fn test_invalid_patterns_are_rejected_and_skipped() {
+    let error = WorkspacePattern::new("packages/@(core").unwrap_err();
+    assert!(matches!(error, MonorepoError::InvalidPattern { .. }));
+    assert!(error.to_string().contains("packages/@(core"));
+
+    let patterns = WorkspacePatterns::new(["packages/@(core", "apps/*"]);
+    assert_eq!(patterns.include().len(), 1);
+    assert!(patterns.matches("apps/web"));
+}
+
+#[test]
+fn test_brace_expansion_is_capped() {
+    let largest = format!("packages/v{{1..{MAX_BRACE_ALTERNATIVES}}}");
+    let pattern = WorkspacePattern::new(&largest).unwrap();
+    assert!(pattern.matches(&format!("packages/v{MAX_BRACE_ALTERNATIVES}")));
+
+    for oversized in [
+        format!("packages/v{{0..{MAX_BRACE_ALTERNATIVES}}}"),
+        format!("packages/v{{{MAX_BRACE_ALTERNATIVES}..-1}}"),
+        "packages/v{1..9223372036854775807}".to_string(),
+        "packages/{1..100}-{1..100}".to_string(),
+    ] {
+        let error = WorkspacePattern::new(&oversized).unwrap_err();
+        assert!(matches!(error, MonorepoError::InvalidPattern { .. }), "{oversized}");
+    }
+
+    let patterns = WorkspacePatterns::new(["packages/v{1..1000000000}", "apps/*"]);
+    assert_eq!(patterns.include().len(), 1);
+    assert!(patterns.matches("apps/web"));
+}
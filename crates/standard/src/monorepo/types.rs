@@ -136,6 +136,8 @@ pub struct MonorepoDescriptor {
     pub(crate) package_json: Option<package_json::PackageJson>,
     /// Validation status of the monorepo
     pub(crate) validation_status: crate::project::ProjectValidationStatus,
+    /// Packages that declare their own workspaces; their members are not part of this monorepo
+    pub(crate) nested_workspace_roots: Vec<PathBuf>,
 }
 
 /// Configuration structure for PNPM workspaces.
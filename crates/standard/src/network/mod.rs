@@ -0,0 +1,49 @@
+//! # Network Access Module
+//!
+//! ## What
+//! This module provides the process-wide offline mode shared by every component that
+//! reaches the network: remote configuration, package registries, and notifications. It
+//! also provides `TransportSettings`, the proxy and TLS settings their HTTP clients share.
+//!
+//! ## How
+//! `set_offline_mode` flips a process-wide flag. Components check `is_offline_mode` before
+//! issuing a request and fall back to cached data, or skip the request and report it, so
+//! callers can tell which results may be stale.
+//!
+//! ## Why
+//! Regulated environments run CI without internet access. Failing each request on a
+//! timeout is slow and hides which results are incomplete; an explicit mode makes the
+//! behavior immediate and visible.
+
+mod transport;
+
+pub use transport::TransportSettings;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide offline flag checked before network requests.
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Enables or disables the process-wide offline mode.
+///
+/// While enabled, remote configuration is served from its cache regardless of age, and
+/// components of dependent crates skip their network requests.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::network::{is_offline_mode, set_offline_mode};
+///
+/// set_offline_mode(true);
+/// assert!(is_offline_mode());
+/// set_offline_mode(false);
+/// ```
+pub fn set_offline_mode(enabled: bool) {
+    OFFLINE_MODE.store(enabled, Ordering::SeqCst);
+}
+
+/// Returns whether the process-wide offline mode is enabled.
+#[must_use]
+pub fn is_offline_mode() -> bool {
+    OFFLINE_MODE.load(Ordering::SeqCst)
+}
@@ -0,0 +1,129 @@
+//! Proxy and TLS settings for HTTP requests.
+//!
+//! **What**: Provides `TransportSettings`, the proxy, CA certificate, and certificate
+//! verification settings applied to every HTTP client the tools build: remote configuration
+//! and package registries.
+//!
+//! **How**: Callers resolve the settings from their own configuration and fall back to the
+//! `HTTPS_PROXY`/`HTTP_PROXY` and `NO_PROXY` environment variables. `apply` replaces reqwest's
+//! own proxy detection with the resolved settings, so the precedence is the same on every
+//! platform.
+//!
+//! **Why**: Enterprise networks route traffic through proxies that re-sign TLS connections.
+//! Resolving the settings in one place keeps every request of a workspace on the same route.
+
+use std::path::PathBuf;
+
+use crate::filesystem::{AsyncFileSystem, FileSystemManager};
+
+/// Environment variables checked for a proxy URL, in order.
+const PROXY_ENV_VARS: [&str; 4] = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"];
+
+/// Environment variables checked for proxy exclusions, in order.
+const NO_PROXY_ENV_VARS: [&str; 2] = ["NO_PROXY", "no_proxy"];
+
+/// Resolved proxy and TLS settings for HTTP requests.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::network::TransportSettings;
+///
+/// let settings = TransportSettings {
+///     proxy: Some("http://proxy.corp.example:8080".to_string()),
+///     ..TransportSettings::default()
+/// };
+/// assert!(settings.strict_ssl);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportSettings {
+    /// Proxy URL, if requests go through a proxy.
+    pub proxy: Option<String>,
+
+    /// Comma-separated hosts that bypass the proxy.
+    pub no_proxy: Option<String>,
+
+    /// PEM file with additional trusted CA certificates.
+    pub ca_file: Option<PathBuf>,
+
+    /// Whether TLS certificates are verified.
+    pub strict_ssl: bool,
+}
+
+impl Default for TransportSettings {
+    fn default() -> Self {
+        Self { proxy: None, no_proxy: None, ca_file: None, strict_ssl: true }
+    }
+}
+
+impl TransportSettings {
+    /// Returns the settings of the `HTTPS_PROXY`/`HTTP_PROXY` and `NO_PROXY` environment
+    /// variables.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env_fallback(|name| std::env::var(name).ok())
+    }
+
+    /// Fills the proxy settings that are unset from the environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - Looks up an environment variable
+    #[must_use]
+    pub fn with_env_fallback(mut self, env: impl Fn(&str) -> Option<String>) -> Self {
+        let first_env = |names: &[&str]| {
+            names.iter().filter_map(|name| env(name)).find(|value| !value.trim().is_empty())
+        };
+
+        if self.proxy.is_none() {
+            self.proxy = first_env(&PROXY_ENV_VARS);
+        }
+        if self.no_proxy.is_none() {
+            self.no_proxy = first_env(&NO_PROXY_ENV_VARS);
+        }
+        self
+    }
+
+    /// Applies the settings to an HTTP client builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if the proxy URL is invalid or the CA file cannot be read or
+    /// contains no valid certificate.
+    pub async fn apply(
+        &self,
+        builder: reqwest::ClientBuilder,
+    ) -> Result<reqwest::ClientBuilder, String> {
+        // Proxy settings were resolved by the caller, including the environment
+        let mut builder = builder.no_proxy();
+
+        if let Some(proxy_url) = &self.proxy {
+            let proxy = reqwest::Proxy::all(proxy_url)
+                .map_err(|e| format!("Invalid proxy URL '{proxy_url}': {e}"))?;
+            let no_proxy = self.no_proxy.as_deref().and_then(reqwest::NoProxy::from_string);
+            builder = builder.proxy(proxy.no_proxy(no_proxy));
+        }
+
+        if let Some(ca_file) = &self.ca_file {
+            let pem = FileSystemManager::new()
+                .read_file(ca_file)
+                .await
+                .map_err(|e| format!("Failed to read CA file '{}': {e}", ca_file.display()))?;
+            let certificates = reqwest::Certificate::from_pem_bundle(&pem)
+                .map_err(|e| format!("Invalid CA file '{}': {e}", ca_file.display()))?;
+            if certificates.is_empty() {
+                return Err(format!("CA file '{}' contains no certificates", ca_file.display()));
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        if !self.strict_ssl {
+            log::warn!("TLS certificate verification is disabled");
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        Ok(builder)
+    }
+}
@@ -0,0 +1,37 @@
+//! Stable entry points of `sublime_standard_tools`.
+//!
+//! ## What
+//! Re-exports the types most consumers need: command execution, filesystem access,
+//! project and monorepo detection, package managers, and the crate's error types.
+//!
+//! ## How
+//! `use sublime_standard_tools::prelude::*;` brings them into scope. Each item is the same
+//! type as under its module path, so the two can be mixed freely.
+//!
+//! ## Why
+//! Module paths follow the internal layout and may move as the crate grows. The prelude
+//! is the semver-guarded surface: its items only change incompatibly in a major release,
+//! and renamed or removed items stay available as `#[deprecated]` re-exports for at least
+//! one minor release.
+//!
+//! ## Example
+//! ```rust
+//! use sublime_standard_tools::prelude::*;
+//!
+//! let fs = FileSystemManager::new();
+//! let detector = MonorepoDetector::new();
+//! let executor = DefaultCommandExecutor::new();
+//! let command = CommandBuilder::new("node").arg("--version").build();
+//! # let _ = (fs, detector, executor, command);
+//! ```
+
+pub use crate::command::{
+    Command, CommandBuilder, CommandOutput, DefaultCommandExecutor, Executor, SyncCommandExecutor,
+};
+pub use crate::error::{Error, Result};
+pub use crate::filesystem::{AsyncFileSystem, FileSystemManager};
+pub use crate::monorepo::{
+    MonorepoDescriptor, MonorepoDetector, MonorepoDetectorTrait, MonorepoKind, WorkspacePackage,
+};
+pub use crate::node::{PackageManager, PackageManagerKind, RepoKind};
+pub use crate::project::{ProjectDetector, ProjectInfo, ProjectKind};
@@ -8,7 +8,9 @@
 //! ## How
 //! The manager uses the project detector to identify project types and
 //! delegates to appropriate handlers for project-specific operations.
-//! It provides methods for project creation, validation, and management.
+//! It provides methods for project creation, validation, and management,
+//! plus lifecycle operations that read and write the root manifest,
+//! enumerate package scripts, and refresh detected project information.
 //!
 //! ## Why
 //! Project management should be consistent across all project types.
@@ -16,13 +18,18 @@
 //! leveraging the specialized functionality of different project types.
 
 use super::detector::ProjectDetector;
-use super::types::ProjectDescriptor;
+use super::types::{PackageScripts, ProjectDescriptor, ProjectValidationStatus};
 use super::validator::ProjectValidator;
 use crate::config::StandardConfig;
 use crate::error::{Error, Result};
 use crate::filesystem::{AsyncFileSystem, FileSystemManager};
+use package_json::PackageJson;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+/// Default value the package-json crate gives to a missing `main` field.
+const DEFAULT_MAIN: &str = "index.js";
+
 /// Manages Node.js projects with a unified interface.
 ///
 /// This struct provides methods for creating, validating, and managing
@@ -59,6 +66,8 @@ pub struct ProjectManager<F: AsyncFileSystem = FileSystemManager> {
     detector: ProjectDetector<F>,
     /// Project validator for validation operations
     validator: ProjectValidator<F>,
+    /// Filesystem used for manifest operations
+    fs: F,
 }
 
 impl ProjectManager<FileSystemManager> {
@@ -79,7 +88,7 @@ impl ProjectManager<FileSystemManager> {
     pub fn new() -> Self {
         let detector = ProjectDetector::new();
         let validator = ProjectValidator::new();
-        Self { detector, validator }
+        Self { detector, validator, fs: FileSystemManager::new() }
     }
 }
 
@@ -106,8 +115,8 @@ impl<F: AsyncFileSystem + Clone + 'static> ProjectManager<F> {
     #[must_use]
     pub fn with_filesystem(fs: F) -> Self {
         let detector = ProjectDetector::with_filesystem(fs.clone());
-        let validator = ProjectValidator::with_filesystem(fs);
-        Self { detector, validator }
+        let validator = ProjectValidator::with_filesystem(fs.clone());
+        Self { detector, validator, fs }
     }
 
     /// Creates and initializes a project descriptor for the given path.
@@ -341,6 +350,315 @@ impl<F: AsyncFileSystem + Clone + 'static> ProjectManager<F> {
         self.create_project(root, config).await
     }
 
+    /// Detects and validates the project at the given path, returning only
+    /// the resulting validation status.
+    ///
+    /// This is a shortcut for callers that need to know whether a project's
+    /// structure is sound without keeping the full descriptor around.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the project directory
+    /// * `config` - Configuration options for project detection
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if:
+    /// - The path does not exist or cannot be accessed
+    /// - The path does not contain a valid Node.js project
+    /// - Project files cannot be read or parsed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ProjectValidationStatus)` - The validation status of the project
+    /// * `Err(Error)` - If the project could not be detected
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let status = manager.validate_structure(Path::new("."), None).await?;
+    /// if status.has_errors() {
+    ///     println!("Project structure has errors: {:?}", status.errors());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate_structure(
+        &self,
+        path: impl AsRef<Path>,
+        config: Option<&StandardConfig>,
+    ) -> Result<ProjectValidationStatus> {
+        let project = self.create_project(path, config).await?;
+        Ok(project.as_project_info().validation_status().clone())
+    }
+
+    /// Reads the root package.json of a project.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root directory of the project
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if:
+    /// - The root does not contain a package.json file
+    /// - The package.json file cannot be read or parsed
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PackageJson)` - The parsed root manifest
+    /// * `Err(Error)` - If the manifest could not be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let manifest = manager.read_root_manifest(Path::new(".")).await?;
+    /// println!("{}@{}", manifest.name, manifest.version);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_root_manifest(&self, root: impl AsRef<Path>) -> Result<PackageJson> {
+        let path = root.as_ref().join("package.json");
+        if !self.fs.exists(&path).await {
+            return Err(Error::operation(format!("No package.json found at {}", path.display())));
+        }
+
+        let content = self.fs.read_file_string(&path).await?;
+        serde_json::from_str::<PackageJson>(&content).map_err(|e| {
+            Error::operation(format!("Invalid package.json at {}: {e}", path.display()))
+        })
+    }
+
+    /// Writes the root package.json of a project.
+    ///
+    /// The manifest is written atomically as pretty-printed JSON with a
+    /// trailing newline. Keys are written in the order `serde_json` produces,
+    /// so the original key order of the file is not preserved.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root directory of the project
+    /// * `manifest` - The manifest to write
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the manifest cannot be serialized or written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let mut manifest = manager.read_root_manifest(Path::new(".")).await?;
+    /// manifest.scripts.insert("lint".to_string(), "eslint .".to_string());
+    /// manager.write_root_manifest(Path::new("."), &manifest).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_root_manifest(
+        &self,
+        root: impl AsRef<Path>,
+        manifest: &PackageJson,
+    ) -> Result<()> {
+        let path = root.as_ref().join("package.json");
+        let mut value = serde_json::to_value(manifest)
+            .map_err(|e| Error::operation(format!("Failed to serialize package.json: {e}")))?;
+
+        // The package-json crate fills `main` with its default when the field is
+        // absent, so keep it out of manifests that never declared one.
+        if manifest.main == DEFAULT_MAIN
+            && !self.declares_field(&path, "main").await
+            && let Some(object) = value.as_object_mut()
+        {
+            object.remove("main");
+        }
+
+        let mut content = serde_json::to_string_pretty(&value)
+            .map_err(|e| Error::operation(format!("Failed to serialize package.json: {e}")))?;
+        content.push('\n');
+        self.fs.write_file_atomic(&path, &content).await
+    }
+
+    /// Enumerates the scripts available to each package of a project.
+    ///
+    /// The root package comes first, followed by the workspace packages in
+    /// detection order. Packages without a package.json are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The project whose packages to inspect
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if a package.json file cannot be read or parsed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PackageScripts>)` - The scripts declared by each package
+    /// * `Err(Error)` - If a manifest could not be read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let project = manager.create_project(Path::new("."), None).await?;
+    ///
+    /// for package in manager.package_scripts(&project).await? {
+    ///     if package.has_script("test") {
+    ///         println!("{} can be tested", package.name);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn package_scripts(
+        &self,
+        project: &ProjectDescriptor,
+    ) -> Result<Vec<PackageScripts>> {
+        let ProjectDescriptor::NodeJs(project) = project;
+
+        let mut packages = Vec::with_capacity(project.internal_dependencies.len() + 1);
+        let root_name = project.package_json.as_ref().map_or("", |manifest| &manifest.name);
+        if let Some(scripts) = self.load_scripts(project.root(), root_name).await? {
+            packages.push(scripts);
+        }
+        for package in &project.internal_dependencies {
+            if let Some(scripts) = self.load_scripts(&package.absolute_path, &package.name).await? {
+                packages.push(scripts);
+            }
+        }
+
+        Ok(packages)
+    }
+
+    /// Re-detects and re-validates a project in place.
+    ///
+    /// Use this after changes that may affect the project layout, such as
+    /// adding or removing workspace packages or switching package managers.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The project descriptor to refresh
+    /// * `config` - Configuration options for project detection
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if the project root is no longer a valid
+    /// Node.js project or its files cannot be read. The descriptor is left
+    /// untouched in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let mut project = manager.create_project(Path::new("."), None).await?;
+    /// // ... packages are added to the workspace ...
+    /// manager.refresh_project(&mut project, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn refresh_project(
+        &self,
+        project: &mut ProjectDescriptor,
+        config: Option<&StandardConfig>,
+    ) -> Result<()> {
+        let root = project.as_project_info().root().to_path_buf();
+        *project = self.create_project(&root, config).await?;
+        Ok(())
+    }
+
+    /// Rescans the root manifest and the named workspace packages of a project.
+    ///
+    /// Unlike [`refresh_project`](Self::refresh_project), workspace discovery
+    /// is skipped: only the root package.json and the manifests of the given
+    /// packages are re-read, updating their names, versions, and workspace
+    /// dependencies. The project is re-validated afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `project` - The project descriptor to update
+    /// * `packages` - Names of the workspace packages to rescan
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error`] if:
+    /// - A named package is not part of the project
+    /// - A package.json file cannot be read or parsed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sublime_standard_tools::project::ProjectManager;
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let manager = ProjectManager::new();
+    /// let mut project = manager.create_project(Path::new("."), None).await?;
+    /// // ... the version of @scope/core is bumped ...
+    /// manager.rescan_packages(&mut project, &["@scope/core"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rescan_packages(
+        &self,
+        project: &mut ProjectDescriptor,
+        packages: &[&str],
+    ) -> Result<()> {
+        let ProjectDescriptor::NodeJs(inner) = &mut *project;
+
+        let root_manifest = inner.root().join("package.json");
+        inner.package_json = if self.fs.exists(&root_manifest).await {
+            Some(self.read_root_manifest(inner.root()).await?)
+        } else {
+            None
+        };
+
+        let internal: Vec<String> =
+            inner.internal_dependencies.iter().map(|package| package.name.clone()).collect();
+        for name in packages {
+            let package = inner
+                .internal_dependencies
+                .iter_mut()
+                .find(|package| package.name == *name)
+                .ok_or_else(|| {
+                    Error::operation(format!("Package '{name}' is not part of the project"))
+                })?;
+
+            let json = self.read_json(&package.absolute_path.join("package.json")).await?;
+            if let Some(name) = json.get("name").and_then(|v| v.as_str()) {
+                package.name = name.to_string();
+            }
+            package.version =
+                json.get("version").and_then(|v| v.as_str()).unwrap_or("0.0.0").to_string();
+            package.workspace_dependencies = workspace_names(&json, "dependencies", &internal);
+            package.workspace_dev_dependencies =
+                workspace_names(&json, "devDependencies", &internal);
+        }
+
+        self.validator.validate_project(project).await
+    }
+
     /// Gets access to the underlying project detector.
     ///
     /// This method provides access to the detector for advanced
@@ -394,6 +712,47 @@ impl<F: AsyncFileSystem + Clone + 'static> ProjectManager<F> {
     pub fn validator(&self) -> &ProjectValidator<F> {
         &self.validator
     }
+
+    /// Reads a JSON file into an untyped value.
+    async fn read_json(&self, path: &Path) -> Result<serde_json::Value> {
+        let content = self.fs.read_file_string(path).await?;
+        serde_json::from_str(&content)
+            .map_err(|e| Error::operation(format!("Invalid JSON in {}: {e}", path.display())))
+    }
+
+    /// Checks whether the JSON object in a file declares the given field.
+    async fn declares_field(&self, path: &Path, field: &str) -> bool {
+        self.read_json(path).await.is_ok_and(|json| json.get(field).is_some())
+    }
+
+    /// Loads the scripts declared by the package.json in `dir`, if any.
+    ///
+    /// `fallback_name` is used when the manifest does not declare a name.
+    async fn load_scripts(
+        &self,
+        dir: &Path,
+        fallback_name: &str,
+    ) -> Result<Option<PackageScripts>> {
+        let path = dir.join("package.json");
+        if !self.fs.exists(&path).await {
+            return Ok(None);
+        }
+
+        let json = self.read_json(&path).await?;
+        let name = json.get("name").and_then(|v| v.as_str()).unwrap_or(fallback_name);
+        let scripts: BTreeMap<String, String> = json
+            .get("scripts")
+            .and_then(|v| v.as_object())
+            .map(|scripts| {
+                scripts
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.clone(), value.as_str()?.to_string())))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Some(PackageScripts::new(name, dir, scripts)))
+    }
 }
 
 impl<F: AsyncFileSystem + Clone> Default for ProjectManager<F>
@@ -403,6 +762,14 @@ where
     fn default() -> Self {
         let detector = ProjectDetector::default();
         let validator = ProjectValidator::default();
-        Self { detector, validator }
+        Self { detector, validator, fs: F::default() }
     }
 }
+
+/// Returns the dependencies listed under `field` that name workspace packages.
+fn workspace_names(json: &serde_json::Value, field: &str, internal: &[String]) -> Vec<String> {
+    json.get(field)
+        .and_then(|v| v.as_object())
+        .map(|deps| deps.keys().filter(|dep| internal.contains(dep)).cloned().collect())
+        .unwrap_or_default()
+}
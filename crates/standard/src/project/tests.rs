@@ -598,6 +598,135 @@ mod tests {
         assert!(root.is_none());
     }
 
+    /// Creates an npm workspace with two packages, `@test/app` depending on `@test/core`
+    async fn create_workspace(root: &Path) {
+        create_package_json_with_content(
+            root,
+            r#"{"name": "root", "version": "1.0.0", "workspaces": ["packages/*"], "scripts": {"build": "turbo build"}}"#,
+        )
+        .await;
+        create_lock_file(root, PackageManagerKind::Npm).await;
+
+        let core = root.join("packages/core");
+        std::fs::create_dir_all(&core).unwrap();
+        create_package_json_with_content(
+            &core,
+            r#"{"name": "@test/core", "version": "1.0.0", "scripts": {"test": "vitest", "build": "tsc"}}"#,
+        )
+        .await;
+
+        let app = root.join("packages/app");
+        std::fs::create_dir_all(&app).unwrap();
+        create_package_json_with_content(
+            &app,
+            r#"{"name": "@test/app", "version": "1.0.0", "dependencies": {"@test/core": "*"}}"#,
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_validate_structure() {
+        let temp_dir = setup_test_dir();
+        let manager = ProjectManager::new();
+
+        create_package_json(temp_dir.path(), "test-project", "1.0.0").await;
+        create_lock_file(temp_dir.path(), PackageManagerKind::Npm).await;
+
+        let status = manager.validate_structure(temp_dir.path(), None).await.unwrap();
+        assert!(!status.has_errors());
+
+        let missing = manager.validate_structure(temp_dir.path().join("missing"), None).await;
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_root_manifest_round_trip() {
+        let temp_dir = setup_test_dir();
+        let manager = ProjectManager::new();
+
+        assert!(manager.read_root_manifest(temp_dir.path()).await.is_err());
+
+        create_package_json_with_content(
+            temp_dir.path(),
+            r#"{"name": "test-project", "version": "1.0.0", "custom": {"keep": true}}"#,
+        )
+        .await;
+
+        let mut manifest = manager.read_root_manifest(temp_dir.path()).await.unwrap();
+        assert_eq!(manifest.name, "test-project");
+        manifest.version = "1.1.0".to_string();
+        manifest.scripts.insert("lint".to_string(), "eslint .".to_string());
+        manager.write_root_manifest(temp_dir.path(), &manifest).await.unwrap();
+
+        let content = std::fs::read_to_string(temp_dir.path().join("package.json")).unwrap();
+        assert!(content.ends_with("}\n"));
+        let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+        assert_eq!(json["version"], "1.1.0");
+        assert_eq!(json["scripts"]["lint"], "eslint .");
+        assert_eq!(json["custom"]["keep"], true);
+        assert!(json.get("main").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_package_scripts() {
+        let temp_dir = setup_test_dir();
+        let manager = ProjectManager::new();
+        create_workspace(temp_dir.path()).await;
+
+        let project = manager.create_project(temp_dir.path(), None).await.unwrap();
+        let packages = manager.package_scripts(&project).await.unwrap();
+
+        assert_eq!(packages.len(), 3);
+        assert_eq!(packages[0].name, "root");
+        assert_eq!(packages[0].script("build"), Some("turbo build"));
+
+        let core = packages.iter().find(|p| p.name == "@test/core").unwrap();
+        assert_eq!(core.names().collect::<Vec<_>>(), vec!["build", "test"]);
+        let app = packages.iter().find(|p| p.name == "@test/app").unwrap();
+        assert!(app.scripts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rescan_packages() {
+        let temp_dir = setup_test_dir();
+        let manager = ProjectManager::new();
+        create_workspace(temp_dir.path()).await;
+
+        let mut project = manager.create_project(temp_dir.path(), None).await.unwrap();
+
+        create_package_json_with_content(
+            &temp_dir.path().join("packages/core"),
+            r#"{"name": "@test/core", "version": "2.0.0", "devDependencies": {"@test/app": "*"}}"#,
+        )
+        .await;
+        manager.rescan_packages(&mut project, &["@test/core"]).await.unwrap();
+
+        let ProjectDescriptor::NodeJs(inner) = &project;
+        let core = inner.internal_dependencies.iter().find(|p| p.name == "@test/core").unwrap();
+        assert_eq!(core.version, "2.0.0");
+        assert_eq!(core.workspace_dev_dependencies, vec!["@test/app".to_string()]);
+
+        let unknown = manager.rescan_packages(&mut project, &["@test/missing"]).await;
+        assert!(unknown.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_project() {
+        let temp_dir = setup_test_dir();
+        let manager = ProjectManager::new();
+        create_workspace(temp_dir.path()).await;
+
+        let mut project = manager.create_project(temp_dir.path(), None).await.unwrap();
+
+        let utils = temp_dir.path().join("packages/utils");
+        std::fs::create_dir_all(&utils).unwrap();
+        create_package_json(&utils, "@test/utils", "1.0.0").await;
+        manager.refresh_project(&mut project, None).await.unwrap();
+
+        let ProjectDescriptor::NodeJs(inner) = &project;
+        assert!(inner.internal_dependencies.iter().any(|p| p.name == "@test/utils"));
+    }
+
     // =============================================================================
     // PROJECT VALIDATOR TESTS
     // =============================================================================
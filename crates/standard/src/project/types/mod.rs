@@ -9,6 +9,7 @@
 //! - `project`: Core project types and traits
 //! - `validation`: Validation status and related types
 //! - `descriptor`: Project descriptor enum
+//! - `scripts`: Scripts declared by each package
 //!
 //! ## Why
 //! Modular organization improves maintainability, reduces cognitive load,
@@ -16,9 +17,11 @@
 
 pub mod descriptor;
 pub mod project;
+pub mod scripts;
 pub mod validation;
 
 // Re-export all public types for backward compatibility
 pub use descriptor::*;
 pub use project::*;
+pub use scripts::*;
 pub use validation::*;
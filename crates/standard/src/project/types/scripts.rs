@@ -0,0 +1,85 @@
+//! # Package Script Types
+//!
+//! ## What
+//! This module defines `PackageScripts`, the scripts a single package of a
+//! project declares in its package.json.
+//!
+//! ## How
+//! Scripts are kept in a sorted map keyed by script name, together with the
+//! package name and directory, so callers can look up or list the scripts
+//! available to each package without reading manifests themselves.
+//!
+//! ## Why
+//! Running, validating, or listing scripts across a workspace is needed by
+//! several tools; a shared type keeps them from re-parsing package.json files.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Scripts declared by one package of a project.
+///
+/// # Examples
+///
+/// ```
+/// use sublime_standard_tools::project::PackageScripts;
+/// use std::collections::BTreeMap;
+/// use std::path::PathBuf;
+///
+/// let mut scripts = BTreeMap::new();
+/// scripts.insert("build".to_string(), "tsc".to_string());
+///
+/// let package = PackageScripts::new("@scope/core", PathBuf::from("/repo/packages/core"), scripts);
+/// assert!(package.has_script("build"));
+/// assert_eq!(package.script("build"), Some("tsc"));
+/// assert!(!package.has_script("test"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageScripts {
+    /// Name of the package
+    pub name: String,
+    /// Absolute path to the package directory
+    pub path: PathBuf,
+    /// Script commands keyed by script name
+    pub scripts: BTreeMap<String, String>,
+}
+
+impl PackageScripts {
+    /// Creates a new `PackageScripts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the package
+    /// * `path` - Directory containing the package's package.json
+    /// * `scripts` - Script commands keyed by script name
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        path: impl Into<PathBuf>,
+        scripts: BTreeMap<String, String>,
+    ) -> Self {
+        Self { name: name.into(), path: path.into(), scripts }
+    }
+
+    /// Returns the package directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Checks if the package declares the given script.
+    #[must_use]
+    pub fn has_script(&self, script: &str) -> bool {
+        self.scripts.contains_key(script)
+    }
+
+    /// Returns the command of the given script, if declared.
+    #[must_use]
+    pub fn script(&self, script: &str) -> Option<&str> {
+        self.scripts.get(script).map(String::as_str)
+    }
+
+    /// Returns the names of the declared scripts in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.scripts.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,42 @@
+//! # Process-Wide Operation Deadline
+//!
+//! ## What
+//! Verifies that commands run by `DefaultCommandExecutor` stop at the process-wide deadline
+//! with `Error::DeadlineExceeded`, well before their own timeout.
+//!
+//! ## How
+//! Sets a short global deadline and runs a command that sleeps far longer, then checks the
+//! error and the elapsed time. A command started after the deadline is never spawned.
+//!
+//! ## Why
+//! The deadline is a global setting, so it is tested in its own test binary where it cannot
+//! interfere with other command tests.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use std::time::{Duration, Instant};
+use sublime_standard_tools::command::{CommandBuilder, DefaultCommandExecutor, Executor};
+use sublime_standard_tools::deadline::set_global_deadline;
+use sublime_standard_tools::error::Error;
+
+#[tokio::test]
+async fn test_global_deadline_stops_running_commands() {
+    let executor = DefaultCommandExecutor::new();
+    set_global_deadline(Some(Duration::from_millis(300)));
+
+    let started = Instant::now();
+    let result = executor
+        .execute(CommandBuilder::new("sleep").arg("10").timeout(Duration::from_secs(30)).build())
+        .await;
+    let elapsed = started.elapsed();
+    let after = executor.execute(CommandBuilder::new("echo").arg("late").build()).await;
+    set_global_deadline(None);
+
+    let Err(Error::DeadlineExceeded(exceeded)) = result else {
+        panic!("Expected DeadlineExceeded, got {result:?}");
+    };
+    assert_eq!(exceeded.operation, "run sleep");
+    assert!(elapsed < Duration::from_secs(5), "command ran for {elapsed:?}");
+    assert!(matches!(after, Err(Error::DeadlineExceeded(_))));
+}
@@ -0,0 +1,58 @@
+//! # Process-Wide Offline Mode
+//!
+//! ## What
+//! Verifies that enabling the process-wide offline mode makes remote configuration use its
+//! cached copy, whatever its age, without contacting the server.
+//!
+//! ## How
+//! Fetches a document from a mock server to fill the cache, enables the mode, and loads the
+//! document again with a server that expects no further requests.
+//!
+//! ## Why
+//! The mode is a global switch, so it is tested in its own test binary where it cannot
+//! interfere with tests that reach the network.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use sublime_standard_tools::config::{
+    ConfigProvider, ConfigSourcePriority, ConfigValue, RemoteConfig, RemoteProvider,
+};
+use sublime_standard_tools::error::ConfigError;
+use sublime_standard_tools::filesystem::FileSystemManager;
+use sublime_standard_tools::network::{is_offline_mode, set_offline_mode};
+
+#[tokio::test]
+async fn test_offline_mode_serves_remote_config_from_cache() {
+    let mut server = mockito::Server::new_async().await;
+    let cache = tempfile::tempdir().unwrap();
+    let mut config = RemoteConfig::new(format!("{}/policy.toml", server.url()));
+    config.cache_dir = Some(cache.path().to_path_buf());
+    config.cache_ttl_secs = 0;
+    let mut uncached = RemoteConfig::new(format!("{}/other.toml", server.url()));
+    uncached.cache_dir = Some(cache.path().to_path_buf());
+
+    let fetched = server
+        .mock("GET", "/policy.toml")
+        .with_body("name = \"acme\"\n")
+        .expect(1)
+        .create_async()
+        .await;
+    let provider =
+        RemoteProvider::new(config, ConfigSourcePriority::Global, FileSystemManager::new());
+    assert!(provider.load().await.is_ok());
+
+    set_offline_mode(true);
+    assert!(is_offline_mode());
+
+    let value = provider.load().await.unwrap();
+    assert_eq!(value.get("name").and_then(ConfigValue::as_string), Some("acme"));
+    let missing =
+        RemoteProvider::new(uncached, ConfigSourcePriority::Global, FileSystemManager::new())
+            .load()
+            .await;
+    assert!(matches!(missing, Err(ConfigError::RemoteFetchError { .. })));
+
+    set_offline_mode(false);
+    fetched.assert_async().await;
+}
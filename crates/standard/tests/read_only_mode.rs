@@ -0,0 +1,46 @@
+//! # Process-Wide Read-Only Mode
+//!
+//! ## What
+//! Verifies that enabling the process-wide read-only mode makes every write through
+//! `FileSystemManager` fail with `FileSystemError::ReadOnly` while reads keep working.
+//!
+//! ## How
+//! Enables the mode, attempts writes, directory creation, and removal against a temporary
+//! directory, then disables it again.
+//!
+//! ## Why
+//! The mode is a global switch, so it is tested in its own test binary where it cannot
+//! interfere with tests that write files.
+
+#![allow(clippy::unwrap_used)]
+#![allow(clippy::panic)]
+
+use sublime_standard_tools::error::{Error, FileSystemError};
+use sublime_standard_tools::filesystem::{
+    AsyncFileSystem, FileSystemManager, is_read_only_mode, set_read_only_mode,
+};
+
+#[tokio::test]
+async fn test_read_only_mode_blocks_file_system_manager_writes() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let existing = temp_dir.path().join("package.json");
+    std::fs::write(&existing, "{}").unwrap();
+    let new_file = temp_dir.path().join("CHANGELOG.md");
+    let fs = FileSystemManager::new();
+
+    set_read_only_mode(true);
+    assert!(is_read_only_mode());
+
+    match fs.write_file_string(&new_file, "# Changelog").await {
+        Err(Error::FileSystem(FileSystemError::ReadOnly { path })) => assert_eq!(path, new_file),
+        other => panic!("Expected read-only error, got {other:?}"),
+    }
+    assert!(fs.create_dir_all(&temp_dir.path().join("packages")).await.is_err());
+    assert!(fs.remove(&existing).await.is_err());
+    assert_eq!(fs.read_file_string(&existing).await.unwrap(), "{}");
+
+    set_read_only_mode(false);
+
+    assert!(!new_file.exists());
+    assert!(fs.write_file_string(&new_file, "# Changelog").await.is_ok());
+}